@@ -219,14 +219,17 @@ impl Agent {
     }
 
     pub fn from_config(config: &Config) -> Result<Self> {
-        let observer: Arc<dyn Observer> =
-            Arc::from(observability::create_observer(&config.observability, config.delegation_log_path()));
+        let observer: Arc<dyn Observer> = Arc::from(observability::create_observer(
+            &config.observability,
+            &config.events,
+            config.delegation_log_path(),
+        ));
         let runtime: Arc<dyn runtime::RuntimeAdapter> =
             Arc::from(runtime::create_runtime(&config.runtime)?);
-        let security = Arc::new(SecurityPolicy::from_config(
-            &config.autonomy,
-            &config.workspace_dir,
-        ));
+        let security = Arc::new(
+            SecurityPolicy::from_config(&config.autonomy, &config.workspace_dir)
+                .with_audit(&config.security.audit, config.zeroclaw_dir()),
+        );
 
         let memory: Arc<dyn Memory> = Arc::from(memory::create_memory_with_storage_and_routes(
             &config.memory,
@@ -279,6 +282,25 @@ impl Agent {
             &config.model_routes,
             &model_name,
         )?;
+        let provider = providers::maybe_wrap_balanced(
+            provider,
+            provider_name,
+            &config.providers,
+            &config.reliability,
+            &providers::ProviderRuntimeOptions::default(),
+        )?;
+        let provider = providers::rate_limit::RateLimitedProvider::maybe_wrap(
+            provider,
+            provider_name,
+            &config.providers.rate_limits,
+        );
+        let provider = providers::cache::maybe_wrap(
+            provider,
+            provider_name,
+            &config.cache,
+            config.zeroclaw_dir(),
+            Some(observer.clone()),
+        );
 
         let dispatcher_choice = config.agent.tool_dispatcher.as_str();
         let tool_dispatcher: Box<dyn ToolDispatcher> = match dispatcher_choice {
@@ -367,6 +389,7 @@ impl Agent {
                         tool: call.name.clone(),
                         duration: start.elapsed(),
                         success: r.success,
+                        output_bytes: Some(r.output.len() as u64),
                     });
                     if r.success {
                         r.output
@@ -379,6 +402,7 @@ impl Agent {
                         tool: call.name.clone(),
                         duration: start.elapsed(),
                         success: false,
+                        output_bytes: None,
                     });
                     format!("Error executing {}: {e}", call.name)
                 }
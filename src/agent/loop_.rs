@@ -121,6 +121,38 @@ fn autosave_memory_key(prefix: &str) -> String {
 
 /// Trim conversation history to prevent unbounded growth.
 /// Preserves the system prompt (first message if role=system) and the most recent messages.
+/// Print the running session token/cost footer after an interactive turn.
+///
+/// Reads the same `CostTracker` accounting the observer sees, so the ticker
+/// matches `zeroclaw delegations` reports. Warns once per session when the
+/// cost crosses `[cost].session_warn_usd`.
+fn print_cost_ticker(
+    tracker: Option<&crate::cost::CostTracker>,
+    warn_usd: Option<f64>,
+    warned: &mut bool,
+) {
+    let Some(summary) = tracker.and_then(|t| t.get_summary().ok()) else {
+        return;
+    };
+    if summary.request_count == 0 {
+        return;
+    }
+    println!(
+        "💰 session: {} tokens · ${:.4}",
+        summary.total_tokens, summary.session_cost_usd
+    );
+    if let Some(threshold) = warn_usd {
+        if !*warned && threshold > 0.0 && summary.session_cost_usd >= threshold {
+            *warned = true;
+            println!(
+                "⚠️  Session cost ${:.4} crossed the ${threshold:.2} warning threshold \
+                 ([cost].session_warn_usd).",
+                summary.session_cost_usd
+            );
+        }
+    }
+}
+
 fn trim_history(history: &mut Vec<ChatMessage>, max_history: usize) {
     // Nothing to trim if within limit
     let has_system = history.first().map_or(false, |m| m.role == "system");
@@ -139,6 +171,26 @@ fn trim_history(history: &mut Vec<ChatMessage>, max_history: usize) {
     history.drain(start..start + to_remove);
 }
 
+/// Message-count threshold for auto-compaction, tightened when the cached
+/// catalog reports a context window the estimated token count is close to
+/// filling (~75%). Without catalog metadata the configured cap is used as-is.
+fn compaction_trigger_messages(
+    configured_max: usize,
+    context_length: Option<u64>,
+    model: &str,
+    history: &[ChatMessage],
+) -> usize {
+    let Some(context_length) = context_length else {
+        return configured_max;
+    };
+    let estimated = crate::tokens::estimate_chat_tokens(model, history) as u64;
+    if estimated.saturating_mul(4) >= context_length.saturating_mul(3) {
+        COMPACTION_KEEP_RECENT_MESSAGES.min(configured_max)
+    } else {
+        configured_max
+    }
+}
+
 fn build_compaction_transcript(messages: &[ChatMessage]) -> String {
     let mut transcript = String::new();
     for msg in messages {
@@ -839,6 +891,120 @@ struct ParsedToolCall {
     arguments: serde_json::Value,
 }
 
+/// Extra system instruction for `--plan-only` runs.
+const PLAN_ONLY_SYSTEM_NOTE: &str = "\n\n## Plan-Only Mode\n\
+This is a dry run. Lay out every tool call you would make to complete the \
+task — the commands, files, and APIs you would touch — in order. Nothing \
+will be executed.";
+
+/// Render the model's intended actions as a human-readable plan.
+fn format_plan(display_text: &str, calls: &[ParsedToolCall]) -> String {
+    let mut plan = String::new();
+    if !display_text.trim().is_empty() {
+        plan.push_str(display_text.trim());
+        plan.push_str("\n\n");
+    }
+    if calls.is_empty() {
+        plan.push_str("Planned tool calls: none (the agent would respond with text only).");
+    } else {
+        let _ = writeln!(
+            plan,
+            "Planned tool calls ({} — nothing was executed):",
+            calls.len()
+        );
+        for (index, call) in calls.iter().enumerate() {
+            let arguments = serde_json::to_string_pretty(&call.arguments)
+                .unwrap_or_else(|_| call.arguments.to_string());
+            let _ = writeln!(plan, "{}. {}\n{arguments}", index + 1, call.name);
+        }
+    }
+    plan.trim_end().to_string()
+}
+
+/// One provider round-trip for `--plan-only`: report the tool calls and
+/// actions the agent intends without executing any of them.
+async fn run_plan_only_turn(
+    provider: &dyn Provider,
+    history: &mut Vec<ChatMessage>,
+    tools_registry: &[Box<dyn Tool>],
+    observer: &dyn Observer,
+    provider_name: &str,
+    model: &str,
+    temperature: f64,
+) -> Result<String> {
+    let tool_specs: Vec<crate::tools::ToolSpec> =
+        tools_registry.iter().map(|tool| tool.spec()).collect();
+    let use_native_tools = provider.supports_native_tools() && !tool_specs.is_empty();
+
+    // Same outbound redaction pass as the real loop — a dry run still
+    // sends the prompt to the provider.
+    let redactor = crate::security::redaction::runtime_redactor();
+    for message in history.iter_mut() {
+        let (scrubbed, count) = redactor.redact(&message.content);
+        if count > 0 {
+            message.content = scrubbed;
+        }
+    }
+
+    observer.record_event(&ObserverEvent::LlmRequest {
+        provider: provider_name.to_string(),
+        model: model.to_string(),
+        messages_count: history.len(),
+    });
+    let llm_started_at = Instant::now();
+
+    let chat_result = provider
+        .chat(
+            ChatRequest {
+                messages: history,
+                tools: if use_native_tools {
+                    Some(tool_specs.as_slice())
+                } else {
+                    None
+                },
+            },
+            model,
+            temperature,
+        )
+        .await;
+
+    let response = match chat_result {
+        Ok(response) => {
+            observer.record_event(&ObserverEvent::LlmResponse {
+                provider: provider_name.to_string(),
+                model: model.to_string(),
+                duration: llm_started_at.elapsed(),
+                success: true,
+                error_message: None,
+            });
+            response
+        }
+        Err(e) => {
+            observer.record_event(&ObserverEvent::LlmResponse {
+                provider: provider_name.to_string(),
+                model: model.to_string(),
+                duration: llm_started_at.elapsed(),
+                success: false,
+                error_message: Some(crate::providers::sanitize_api_error(&e.to_string())),
+            });
+            return Err(e);
+        }
+    };
+
+    let response_text = response.text_or_empty().to_string();
+    let mut calls = parse_structured_tool_calls(&response.tool_calls);
+    let mut display_text = response_text.clone();
+    if calls.is_empty() {
+        let (fallback_text, fallback_calls) = parse_tool_calls(&response_text);
+        if !fallback_text.is_empty() {
+            display_text = fallback_text;
+        }
+        calls = fallback_calls;
+    }
+
+    Ok(format_plan(&display_text, &calls))
+}
+
 #[derive(Debug)]
 pub(crate) struct ToolLoopCancelled;
 
@@ -901,6 +1067,24 @@ async fn execute_one_tool(
         return Ok(format!("Unknown tool: {call_name}"));
     };
 
+    // Demoted tools (repeated consecutive failures) are skipped with a notice
+    // instead of executed, so one broken integration doesn't degrade every run.
+    if let Some(remaining) = crate::health::tool_demotion_remaining(call_name) {
+        return Ok(format!(
+            "Error: tool '{call_name}' is temporarily disabled after repeated failures; retry in {}s",
+            remaining.as_secs().max(1)
+        ));
+    }
+
+    // Chaos mode (ZEROCLAW_CHAOS): fail the call as a synthetic timeout so
+    // the model-facing tool error path is exercised without running the tool.
+    if crate::chaos::inject_tool_timeout() {
+        tracing::warn!(tool = call_name, "chaos: injecting synthetic tool timeout");
+        return Ok(format!(
+            "Error: chaos: injected tool timeout for '{call_name}'"
+        ));
+    }
+
     observer.record_event(&ObserverEvent::ToolCallStart {
         tool: call_name.to_string(),
     });
@@ -922,9 +1106,25 @@ async fn execute_one_tool(
                 tool: call_name.to_string(),
                 duration: start.elapsed(),
                 success: r.success,
+                output_bytes: Some(r.output.len() as u64),
             });
+            crate::health::record_tool_call(
+                call_name,
+                r.success,
+                start.elapsed(),
+                r.error.as_deref(),
+            );
             if r.success {
-                Ok(scrub_credentials(&r.output))
+                let scrubbed = scrub_credentials(&r.output);
+                let (scrubbed, redactions) =
+                    crate::security::redaction::runtime_redactor().redact(&scrubbed);
+                if redactions > 0 {
+                    observer.record_event(&ObserverEvent::SecretsRedacted {
+                        scope: "tool_output".to_string(),
+                        count: redactions as u64,
+                    });
+                }
+                Ok(crate::tools::truncation::truncate_output(scrubbed))
             } else {
                 Ok(format!("Error: {}", r.error.unwrap_or_else(|| r.output)))
             }
@@ -934,7 +1134,14 @@ async fn execute_one_tool(
                 tool: call_name.to_string(),
                 duration: start.elapsed(),
                 success: false,
+                output_bytes: None,
             });
+            crate::health::record_tool_call(
+                call_name,
+                false,
+                start.elapsed(),
+                Some(&e.to_string()),
+            );
             Ok(format!("Error executing {call_name}: {e}"))
         }
     }
@@ -995,6 +1202,10 @@ async fn execute_tools_sequential(
     for call in tool_calls {
         if let Some(mgr) = approval {
             if mgr.needs_approval(&call.name) {
+                observer.record_event(&crate::observability::ObserverEvent::ApprovalRequested {
+                    tool: call.name.clone(),
+                    channel: channel_name.to_string(),
+                });
                 let request = ApprovalRequest {
                     tool_name: call.name.clone(),
                     arguments: call.arguments.clone(),
@@ -1071,6 +1282,24 @@ pub(crate) async fn run_tool_call_loop(
         tools_registry.iter().map(|tool| tool.spec()).collect();
     let use_native_tools = provider.supports_native_tools() && !tool_specs.is_empty();
 
+    // Redaction pass over the outbound prompt: scrub secret-shaped text from
+    // the accumulated history before anything reaches the provider.
+    let redactor = crate::security::redaction::runtime_redactor();
+    let mut prompt_redactions = 0usize;
+    for message in history.iter_mut() {
+        let (scrubbed, count) = redactor.redact(&message.content);
+        if count > 0 {
+            message.content = scrubbed;
+            prompt_redactions += count;
+        }
+    }
+    if prompt_redactions > 0 {
+        observer.record_event(&ObserverEvent::SecretsRedacted {
+            scope: "prompt".to_string(),
+            count: prompt_redactions as u64,
+        });
+    }
+
     for _iteration in 0..max_iterations {
         if cancellation_token
             .as_ref()
@@ -1139,12 +1368,29 @@ pub(crate) async fn run_tool_call_loop(
                         error_message: None,
                     });
 
-                    if let (Some(ref usage), Some(ref tracker)) = (&resp.usage, &cost_tracker) {
-                        if let Err(e) = tracker.record_model_usage(
-                            model,
-                            usage.prompt_tokens,
-                            usage.completion_tokens,
-                        ) {
+                    if let Some(ref tracker) = cost_tracker {
+                        // Providers that don't report usage would otherwise
+                        // bill zero tokens and evade the budget limits, so
+                        // fall back to an estimate (see `crate::tokens`).
+                        let (prompt_tokens, completion_tokens) = match &resp.usage {
+                            Some(usage) => (usage.prompt_tokens, usage.completion_tokens),
+                            None => {
+                                let prompt = crate::tokens::estimate_chat_tokens(
+                                    model,
+                                    &prepared_messages.messages,
+                                ) as u64;
+                                let completion =
+                                    crate::tokens::estimate_text(resp.text_or_empty()) as u64;
+                                tracing::debug!(
+                                    model,
+                                    "Provider reported no usage; recording token estimate"
+                                );
+                                (prompt, completion)
+                            }
+                        };
+                        if let Err(e) =
+                            tracker.record_model_usage(model, prompt_tokens, completion_tokens)
+                        {
                             tracing::warn!("Failed to record model usage: {e}");
                         }
                     }
@@ -1328,7 +1574,19 @@ pub(crate) fn build_tool_instructions(tools_registry: &[Box<dyn Tool>]) -> Strin
 // interactive REPL mode. The interactive loop manages history compaction
 // and hard trimming to keep the context window bounded.
 
-#[allow(clippy::too_many_lines)]
+#[allow(clippy::too_many_lines, clippy::too_many_arguments)]
+/// Append `[IMAGE:...]` markers for CLI-attached images so they flow through
+/// the standard multimodal pipeline (vision checks, size limits, data URIs).
+fn append_image_markers(message: &str, images: &[std::path::PathBuf]) -> String {
+    let mut parts = vec![message.to_string()];
+    parts.extend(
+        images
+            .iter()
+            .map(|image| format!("[IMAGE:{}]", image.display())),
+    );
+    parts.join(" ")
+}
+
 pub async fn run(
     config: Config,
     message: Option<String>,
@@ -1336,16 +1594,52 @@ pub async fn run(
     model_override: Option<String>,
     temperature: f64,
     peripheral_overrides: Vec<String>,
+    plan_only: bool,
+    tags: Vec<String>,
+    images: Vec<std::path::PathBuf>,
 ) -> Result<String> {
+    if plan_only && message.is_none() {
+        anyhow::bail!("--plan-only requires a one-shot message (use --message)");
+    }
+    if !images.is_empty() && message.is_none() {
+        anyhow::bail!("--image requires a one-shot message (use --message)");
+    }
+    for image in &images {
+        if !image.is_file() {
+            anyhow::bail!("image file not found: {}", image.display());
+        }
+    }
+    let message = message.map(|msg| append_image_markers(&msg, &images));
+
+    // Tag this run so journaled mutations can be rolled back together
+    // with `zeroclaw undo --run <id>` and the transcript can be exported
+    // with `zeroclaw sessions export <id>`.
+    let run_id = uuid::Uuid::new_v4().to_string();
+    crate::undo::set_current_run_id(&run_id);
+    tracing::info!(run_id = %run_id, "Agent run started");
+    let session_started_at = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs());
+
     // ── Wire up agnostic subsystems ──────────────────────────────
-    let base_observer = observability::create_observer(&config.observability, config.delegation_log_path());
+    let base_observer = observability::create_observer_with_tags(
+        &config.observability,
+        &config.events,
+        config.delegation_log_path(),
+        tags,
+    );
     let observer: Arc<dyn Observer> = Arc::from(base_observer);
     let runtime: Arc<dyn runtime::RuntimeAdapter> =
         Arc::from(runtime::create_runtime(&config.runtime)?);
-    let security = Arc::new(SecurityPolicy::from_config(
-        &config.autonomy,
-        &config.workspace_dir,
-    ));
+    let security = Arc::new(
+        SecurityPolicy::from_config(&config.autonomy, &config.workspace_dir)
+            .with_audit(&config.security.audit, config.zeroclaw_dir()),
+    );
+    crate::tools::truncation::set_policy(crate::tools::truncation::TruncationPolicy {
+        max_bytes: config.agent.tool_output_max_bytes,
+        max_lines: config.agent.tool_output_max_lines,
+        spill_dir: Some(config.workspace_dir.join("state").join("tool-output")),
+    });
 
     // ── Memory (the brain) ────────────────────────────────────────
     let mem: Arc<dyn Memory> = Arc::from(memory::create_memory_with_storage(
@@ -1407,6 +1701,36 @@ pub async fn run(
         .or(config.default_model.as_deref())
         .unwrap_or("anthropic/claude-sonnet-4");
 
+    // Catalog-backed model checks (advisory only — the cache may be stale and
+    // providers accept models the catalog has never seen).
+    if model_override.is_some() {
+        let known = crate::onboard::cached_model_ids(&config.workspace_dir, provider_name);
+        if !known.is_empty() && !known.iter().any(|id| id == model_name) {
+            tracing::warn!(
+                model = model_name,
+                provider = provider_name,
+                "Model not found in cached catalog; run `zeroclaw models refresh` if this is unexpected"
+            );
+        }
+    }
+    let model_metadata: Option<crate::onboard::ModelMetadata> =
+        crate::onboard::lookup_model_metadata(&config.workspace_dir, provider_name, model_name);
+    if let Some(meta) = &model_metadata {
+        if meta.supports_image_input() == Some(false) {
+            tracing::warn!(
+                model = model_name,
+                "Catalog reports no image input support for this model; [IMAGE:...] attachments may be rejected"
+            );
+        }
+        if meta.supports_tools == Some(false) {
+            tracing::warn!(
+                model = model_name,
+                "Catalog reports no native tool support for this model; tool calls will use the prompt-based fallback"
+            );
+        }
+    }
+    let model_context_length = model_metadata.as_ref().and_then(|meta| meta.context_length);
+
     let provider_runtime_options = providers::ProviderRuntimeOptions {
         auth_profile_override: None,
         zeroclaw_dir: config.config_path.parent().map(std::path::PathBuf::from),
@@ -1423,6 +1747,25 @@ pub async fn run(
         model_name,
         &provider_runtime_options,
     )?;
+    let provider = providers::maybe_wrap_balanced(
+        provider,
+        provider_name,
+        &config.providers,
+        &config.reliability,
+        &provider_runtime_options,
+    )?;
+    let provider = providers::rate_limit::RateLimitedProvider::maybe_wrap(
+        provider,
+        provider_name,
+        &config.providers.rate_limits,
+    );
+    let provider = providers::cache::maybe_wrap(
+        provider,
+        provider_name,
+        &config.cache,
+        config.zeroclaw_dir(),
+        Some(observer.clone()),
+    );
 
     observer.record_event(&ObserverEvent::AgentStart {
         provider: provider_name.to_string(),
@@ -1591,14 +1934,19 @@ pub async fn run(
     if !native_tools {
         system_prompt.push_str(&build_tool_instructions(&tools_registry));
     }
+    if plan_only {
+        system_prompt.push_str(PLAN_ONLY_SYSTEM_NOTE);
+    }
 
     // ── Approval manager (supervised mode) ───────────────────────
-    let approval_manager = ApprovalManager::from_config(&config.autonomy);
+    let approval_manager =
+        ApprovalManager::from_config(&config.autonomy).with_audit(security.audit.clone());
 
     // ── Execute ──────────────────────────────────────────────────
     let start = Instant::now();
 
     let mut final_output = String::new();
+    let session_messages: Vec<ChatMessage>;
 
     if let Some(msg) = message {
         // Auto-save user message to memory (skip short/trivial messages)
@@ -1607,6 +1955,7 @@ pub async fn run(
             let _ = mem
                 .store(&user_key, &msg, MemoryCategory::Conversation, None)
                 .await;
+            crate::tools::capture_note::capture_from_autosave(&tools_registry, &msg).await;
         }
 
         // Inject memory + hardware RAG context into user message
@@ -1629,27 +1978,41 @@ pub async fn run(
             ChatMessage::user(&enriched),
         ];
 
-        let response = run_tool_call_loop(
-            provider.as_ref(),
-            &mut history,
-            &tools_registry,
-            observer.as_ref(),
-            provider_name,
-            model_name,
-            temperature,
-            false,
-            Some(&approval_manager),
-            "cli",
-            &config.multimodal,
-            config.agent.max_tool_iterations,
-            None,
-            None,
-            cost_tracker.clone(),
-        )
-        .await?;
+        let response = if plan_only {
+            run_plan_only_turn(
+                provider.as_ref(),
+                &mut history,
+                &tools_registry,
+                observer.as_ref(),
+                provider_name,
+                model_name,
+                temperature,
+            )
+            .await?
+        } else {
+            run_tool_call_loop(
+                provider.as_ref(),
+                &mut history,
+                &tools_registry,
+                observer.as_ref(),
+                provider_name,
+                model_name,
+                temperature,
+                false,
+                Some(&approval_manager),
+                "cli",
+                &config.multimodal,
+                config.agent.max_tool_iterations,
+                None,
+                None,
+                cost_tracker.clone(),
+            )
+            .await?
+        };
         final_output = response.clone();
         println!("{response}");
         observer.record_event(&ObserverEvent::TurnComplete);
+        session_messages = history;
     } else {
         println!("🦀 ZeroClaw Interactive Mode");
         println!("Type /help for commands.\n");
@@ -1657,6 +2020,7 @@ pub async fn run(
 
         // Persistent conversation history across turns
         let mut history = vec![ChatMessage::system(&system_prompt)];
+        let mut session_cost_warned = false;
 
         loop {
             print!("> ");
@@ -1682,7 +2046,8 @@ pub async fn run(
                     println!("Available commands:");
                     println!("  /help        Show this help message");
                     println!("  /clear /new  Clear conversation history");
-                    println!("  /quit /exit  Exit interactive mode\n");
+                    println!("  /quit /exit  Exit interactive mode");
+                    println!("  Ctrl-C       Cancel the current turn\n");
                     continue;
                 }
                 "/clear" | "/new" => {
@@ -1730,6 +2095,8 @@ pub async fn run(
                 let _ = mem
                     .store(&user_key, &user_input, MemoryCategory::Conversation, None)
                     .await;
+                crate::tools::capture_note::capture_from_autosave(&tools_registry, &user_input)
+                    .await;
             }
 
             // Inject memory + hardware RAG context into user message
@@ -1747,28 +2114,52 @@ pub async fn run(
                 format!("{context}{user_input}")
             };
 
+            let pre_turn_len = history.len();
             history.push(ChatMessage::user(&enriched));
 
-            let response = match run_tool_call_loop(
-                provider.as_ref(),
-                &mut history,
-                &tools_registry,
-                observer.as_ref(),
-                provider_name,
-                model_name,
-                temperature,
-                false,
-                Some(&approval_manager),
-                "cli",
-                &config.multimodal,
-                config.agent.max_tool_iterations,
-                None,
-                None,
-                cost_tracker.clone(),
-            )
-            .await
-            {
+            // Race the turn against Ctrl-C so an in-flight provider stream or
+            // tool run can be aborted without killing the whole session.
+            let turn_cancellation = CancellationToken::new();
+            let result = {
+                let turn = run_tool_call_loop(
+                    provider.as_ref(),
+                    &mut history,
+                    &tools_registry,
+                    observer.as_ref(),
+                    provider_name,
+                    model_name,
+                    temperature,
+                    false,
+                    Some(&approval_manager),
+                    "cli",
+                    &config.multimodal,
+                    config.agent.max_tool_iterations,
+                    Some(turn_cancellation.clone()),
+                    None,
+                    cost_tracker.clone(),
+                );
+                tokio::pin!(turn);
+                tokio::select! {
+                    res = &mut turn => res,
+                    _ = tokio::signal::ctrl_c() => {
+                        turn_cancellation.cancel();
+                        Err(ToolLoopCancelled.into())
+                    }
+                }
+            };
+
+            let response = match result {
                 Ok(resp) => resp,
+                Err(e) if is_tool_loop_cancelled(&e) => {
+                    // Drop the partial turn so the cancelled exchange doesn't
+                    // pollute the next prompt's context.
+                    history.truncate(pre_turn_len);
+                    observer.record_event(&ObserverEvent::RunCancelled {
+                        scope: "cli".to_string(),
+                    });
+                    println!("\n🛑 Turn cancelled.\n");
+                    continue;
+                }
                 Err(e) => {
                     eprintln!("\nError: {e}\n");
                     continue;
@@ -1784,13 +2175,26 @@ pub async fn run(
                 eprintln!("\nError sending CLI response: {e}\n");
             }
             observer.record_event(&ObserverEvent::TurnComplete);
+            print_cost_ticker(
+                cost_tracker.as_deref(),
+                config.cost.session_warn_usd,
+                &mut session_cost_warned,
+            );
 
             // Auto-compaction before hard trimming to preserve long-context signal.
+            // Token pressure against the catalog-reported context window can
+            // trigger compaction before the message-count cap is reached.
+            let compaction_trigger = compaction_trigger_messages(
+                config.agent.max_history_messages,
+                model_context_length,
+                model_name,
+                &history,
+            );
             if let Ok(compacted) = auto_compact_history(
                 &mut history,
                 provider.as_ref(),
                 model_name,
-                config.agent.max_history_messages,
+                compaction_trigger,
             )
             .await
             {
@@ -1802,6 +2206,8 @@ pub async fn run(
             // Hard cap as a safety net.
             trim_history(&mut history, config.agent.max_history_messages);
         }
+
+        session_messages = history;
     }
 
     let duration = start.elapsed();
@@ -1810,6 +2216,22 @@ pub async fn run(
         .and_then(|t: &Arc<crate::cost::CostTracker>| t.get_summary().ok())
         .map(|s| (Some(s.total_tokens), Some(s.session_cost_usd)))
         .unwrap_or((None, None));
+
+    // Persist the transcript for `zeroclaw sessions export` (best-effort).
+    if !session_messages.is_empty() {
+        crate::sessions::SessionStore::new(&config.workspace_dir).save(
+            &crate::sessions::SessionRecord {
+                id: run_id.clone(),
+                started_at_unix: session_started_at,
+                provider: provider_name.to_string(),
+                model: model_name.to_string(),
+                messages: session_messages,
+                tokens_used,
+                cost_usd,
+            },
+        );
+    }
+
     observer.record_event(&ObserverEvent::AgentEnd {
         provider: provider_name.to_string(),
         model: model_name.to_string(),
@@ -1824,14 +2246,22 @@ pub async fn run(
 /// Process a single message through the full agent (with tools, peripherals, memory).
 /// Used by channels (Telegram, Discord, etc.) to enable hardware and tool use.
 pub async fn process_message(config: Config, message: &str) -> Result<String> {
-    let observer: Arc<dyn Observer> =
-        Arc::from(observability::create_observer(&config.observability, config.delegation_log_path()));
+    let observer: Arc<dyn Observer> = Arc::from(observability::create_observer(
+        &config.observability,
+        &config.events,
+        config.delegation_log_path(),
+    ));
     let runtime: Arc<dyn runtime::RuntimeAdapter> =
         Arc::from(runtime::create_runtime(&config.runtime)?);
-    let security = Arc::new(SecurityPolicy::from_config(
-        &config.autonomy,
-        &config.workspace_dir,
-    ));
+    let security = Arc::new(
+        SecurityPolicy::from_config(&config.autonomy, &config.workspace_dir)
+            .with_audit(&config.security.audit, config.zeroclaw_dir()),
+    );
+    crate::tools::truncation::set_policy(crate::tools::truncation::TruncationPolicy {
+        max_bytes: config.agent.tool_output_max_bytes,
+        max_lines: config.agent.tool_output_max_lines,
+        spill_dir: Some(config.workspace_dir.join("state").join("tool-output")),
+    });
     let mem: Arc<dyn Memory> = Arc::from(memory::create_memory_with_storage(
         &config.memory,
         Some(&config.storage.provider.config),
@@ -1886,6 +2316,25 @@ pub async fn process_message(config: Config, message: &str) -> Result<String> {
         &model_name,
         &provider_runtime_options,
     )?;
+    let provider = providers::maybe_wrap_balanced(
+        provider,
+        provider_name,
+        &config.providers,
+        &config.reliability,
+        &provider_runtime_options,
+    )?;
+    let provider = providers::rate_limit::RateLimitedProvider::maybe_wrap(
+        provider,
+        provider_name,
+        &config.providers.rate_limits,
+    );
+    let provider = providers::cache::maybe_wrap(
+        provider,
+        provider_name,
+        &config.cache,
+        config.zeroclaw_dir(),
+        Some(observer.clone()),
+    );
 
     let hardware_rag: Option<crate::rag::HardwareRag> = config
         .peripherals
@@ -2008,6 +2457,66 @@ mod tests {
     use std::sync::{Arc, Mutex};
     use std::time::Duration;
 
+    #[test]
+    fn append_image_markers_appends_each_image() {
+        let images = vec![
+            std::path::PathBuf::from("/tmp/a.png"),
+            std::path::PathBuf::from("/tmp/b.jpg"),
+        ];
+        let content = append_image_markers("Describe these", &images);
+        assert_eq!(
+            content,
+            "Describe these [IMAGE:/tmp/a.png] [IMAGE:/tmp/b.jpg]"
+        );
+    }
+
+    #[test]
+    fn append_image_markers_without_images_is_identity() {
+        assert_eq!(append_image_markers("hello", &[]), "hello");
+    }
+
+    #[test]
+    fn print_cost_ticker_warns_once_when_threshold_crossed() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let config = crate::config::CostConfig {
+            enabled: true,
+            ..Default::default()
+        };
+        let tracker = crate::cost::CostTracker::new(config, tmp.path()).unwrap();
+        tracker
+            .record_usage(crate::cost::TokenUsage::new(
+                "test/model",
+                10_000,
+                5_000,
+                1.0,
+                2.0,
+            ))
+            .unwrap();
+
+        let mut warned = false;
+        print_cost_ticker(Some(&tracker), Some(0.0001), &mut warned);
+        assert!(warned, "crossing the threshold should set the warned flag");
+        // Flag stays set so the warning is only printed once per session.
+        print_cost_ticker(Some(&tracker), Some(0.0001), &mut warned);
+        assert!(warned);
+    }
+
+    #[test]
+    fn print_cost_ticker_is_silent_without_tracker_or_threshold() {
+        let mut warned = false;
+        print_cost_ticker(None, Some(0.01), &mut warned);
+        assert!(!warned);
+
+        let tmp = tempfile::TempDir::new().unwrap();
+        let config = crate::config::CostConfig {
+            enabled: true,
+            ..Default::default()
+        };
+        let tracker = crate::cost::CostTracker::new(config, tmp.path()).unwrap();
+        print_cost_ticker(Some(&tracker), None, &mut warned);
+        assert!(!warned, "no threshold configured means no warning");
+    }
+
     #[test]
     fn test_scrub_credentials() {
         let input = "API_KEY=sk-1234567890abcdef; token: 1234567890; password=\"secret123456\"";
@@ -2060,6 +2569,7 @@ mod tests {
             ProviderCapabilities {
                 native_tool_calling: false,
                 vision: true,
+                structured_output: false,
             }
         }
 
@@ -2864,6 +3374,56 @@ Done."#;
         assert_eq!(history.len(), 3);
     }
 
+    #[test]
+    fn compaction_trigger_uses_configured_max_without_metadata() {
+        let history = vec![ChatMessage::user("hello")];
+        assert_eq!(
+            compaction_trigger_messages(50, None, "vendor/model", &history),
+            50
+        );
+    }
+
+    #[test]
+    fn compaction_trigger_tightens_under_context_window_pressure() {
+        let history: Vec<ChatMessage> = (0..10)
+            .map(|_| ChatMessage::user("word ".repeat(200)))
+            .collect();
+        // Tiny context window: the token estimate is well past 75%.
+        let tightened = compaction_trigger_messages(50, Some(100), "vendor/model", &history);
+        assert_eq!(tightened, COMPACTION_KEEP_RECENT_MESSAGES);
+        // Huge context window: the configured cap stands.
+        let relaxed = compaction_trigger_messages(50, Some(10_000_000), "vendor/model", &history);
+        assert_eq!(relaxed, 50);
+    }
+
+    #[test]
+    fn format_plan_lists_tool_calls_in_order_without_executing() {
+        let calls = vec![
+            ParsedToolCall {
+                name: "shell".into(),
+                arguments: serde_json::json!({"command": "ls"}),
+            },
+            ParsedToolCall {
+                name: "file_write".into(),
+                arguments: serde_json::json!({"path": "notes.md"}),
+            },
+        ];
+        let plan = format_plan("First I would inspect the workspace.", &calls);
+        assert!(plan.contains("Planned tool calls (2 — nothing was executed):"));
+        assert!(plan.contains("1. shell"));
+        assert!(plan.contains("2. file_write"));
+        let shell_index = plan.find("1. shell").unwrap();
+        let write_index = plan.find("2. file_write").unwrap();
+        assert!(shell_index < write_index);
+    }
+
+    #[test]
+    fn format_plan_without_tool_calls_notes_text_only_response() {
+        let plan = format_plan("Just an answer.", &[]);
+        assert!(plan.contains("Just an answer."));
+        assert!(plan.contains("Planned tool calls: none"));
+    }
+
     #[test]
     fn build_compaction_transcript_formats_roles() {
         let messages = vec![
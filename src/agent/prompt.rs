@@ -284,6 +284,7 @@ mod tests {
         .unwrap();
 
         let identity_config = crate::config::IdentityConfig {
+            users: Vec::new(),
             format: "aieos".into(),
             aieos_path: None,
             aieos_inline: Some(r#"{"identity":{"names":{"first":"Nova"}}}"#.into()),
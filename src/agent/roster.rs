@@ -0,0 +1,180 @@
+//! CLI surface for the configured sub-agent roster (`[agents.<name>]`).
+//!
+//! The roster is the set of named sub-agents the `delegate` tool (and the
+//! workflow engine) can target. These commands make the roster inspectable
+//! without reading `config.toml`: which agents exist, which provider/model
+//! they run, and what tool scope they are allowed in agentic mode.
+
+use crate::config::{Config, DelegateAgentConfig};
+use anyhow::{bail, Result};
+
+fn mode_summary(agent: &DelegateAgentConfig) -> String {
+    if agent.agentic {
+        format!(
+            "agentic ({} tool{})",
+            agent.allowed_tools.len(),
+            if agent.allowed_tools.len() == 1 {
+                ""
+            } else {
+                "s"
+            }
+        )
+    } else {
+        "single-shot".to_string()
+    }
+}
+
+/// Handle the `agents` CLI command
+pub fn handle_command(command: crate::AgentCommands, config: &Config) -> Result<()> {
+    match command {
+        crate::AgentCommands::List => {
+            if config.agents.is_empty() {
+                println!("No sub-agents configured.");
+                println!("\nDefine them in config.toml:");
+                println!("  [agents.researcher]");
+                println!("  provider = \"openrouter\"");
+                println!("  model = \"anthropic/claude-sonnet-4\"");
+                return Ok(());
+            }
+            let mut names: Vec<&String> = config.agents.keys().collect();
+            names.sort();
+            println!("🤝 Sub-agents ({}):", names.len());
+            for name in names {
+                let agent = &config.agents[name];
+                println!(
+                    "- {name} — {}/{} ({})",
+                    agent.provider,
+                    agent.model,
+                    mode_summary(agent)
+                );
+            }
+            Ok(())
+        }
+        crate::AgentCommands::Show { name } => {
+            let Some(agent) = config.agents.get(&name) else {
+                let mut names: Vec<&String> = config.agents.keys().collect();
+                names.sort();
+                bail!(
+                    "No sub-agent named '{name}'. Configured: {}",
+                    if names.is_empty() {
+                        "(none)".to_string()
+                    } else {
+                        names
+                            .iter()
+                            .map(|s| s.as_str())
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    }
+                );
+            };
+
+            println!("🤝 {name}");
+            println!("  provider:    {}", agent.provider);
+            println!("  model:       {}", agent.model);
+            println!(
+                "  temperature: {}",
+                agent
+                    .temperature
+                    .map_or_else(|| "(default)".to_string(), |t| t.to_string())
+            );
+            println!(
+                "  api_key:     {}",
+                if agent.api_key.is_some() {
+                    "set (redacted)"
+                } else {
+                    "inherits default"
+                }
+            );
+            println!("  max_depth:   {}", agent.max_depth);
+            println!("  mode:        {}", mode_summary(agent));
+            if agent.agentic {
+                println!("  max_iterations: {}", agent.max_iterations);
+                println!(
+                    "  allowed_tools:  {}",
+                    if agent.allowed_tools.is_empty() {
+                        "(none — agentic runs will be rejected)".to_string()
+                    } else {
+                        agent.allowed_tools.join(", ")
+                    }
+                );
+            }
+            if let Some(prompt) = &agent.system_prompt {
+                println!(
+                    "  system_prompt: {}",
+                    crate::util::truncate_with_ellipsis(prompt, 120)
+                );
+            }
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roster_config() -> Config {
+        let mut config = Config::default();
+        config.agents.insert(
+            "researcher".to_string(),
+            DelegateAgentConfig {
+                provider: "openrouter".to_string(),
+                model: "model-a".to_string(),
+                system_prompt: Some("You are a research assistant.".to_string()),
+                api_key: Some("roster-test-credential".to_string()),
+                temperature: Some(0.3),
+                max_depth: 3,
+                agentic: true,
+                allowed_tools: vec!["web_search".to_string(), "http_request".to_string()],
+                max_iterations: 8,
+                node: None,
+            },
+        );
+        config
+    }
+
+    #[test]
+    fn list_handles_empty_and_populated_roster() {
+        let empty = Config::default();
+        assert!(handle_command(crate::AgentCommands::List, &empty).is_ok());
+        assert!(handle_command(crate::AgentCommands::List, &roster_config()).is_ok());
+    }
+
+    #[test]
+    fn show_prints_known_agent() {
+        let result = handle_command(
+            crate::AgentCommands::Show {
+                name: "researcher".to_string(),
+            },
+            &roster_config(),
+        );
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn show_errors_for_unknown_agent() {
+        let result = handle_command(
+            crate::AgentCommands::Show {
+                name: "nonexistent".to_string(),
+            },
+            &roster_config(),
+        );
+        assert!(result.is_err());
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("No sub-agent named"));
+        assert!(err.contains("researcher"));
+    }
+
+    #[test]
+    fn mode_summary_reflects_agentic_scope() {
+        let config = roster_config();
+        let agent = &config.agents["researcher"];
+        assert_eq!(mode_summary(agent), "agentic (2 tools)");
+
+        let single = DelegateAgentConfig {
+            agentic: false,
+            ..agent.clone()
+        };
+        assert_eq!(mode_summary(&single), "single-shot");
+    }
+}
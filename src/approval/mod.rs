@@ -4,12 +4,13 @@
 //! with session-scoped "Always" allowlists and audit logging.
 
 use crate::config::AutonomyConfig;
-use crate::security::AutonomyLevel;
+use crate::security::{AuditEvent, AuditEventType, AuditLogger, AutonomyLevel};
 use chrono::Utc;
 use parking_lot::Mutex;
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 use std::io::{self, BufRead, Write};
+use std::sync::Arc;
 
 // ── Types ────────────────────────────────────────────────────────
 
@@ -54,12 +55,18 @@ pub struct ApprovalManager {
     auto_approve: HashSet<String>,
     /// Tools that always need approval, ignoring session allowlist.
     always_ask: HashSet<String>,
+    /// Fine-grained policy rules (`[[autonomy.policies]]`); a matching
+    /// `require-approval` rule forces a prompt, a matching `allow` skips it.
+    policies: Vec<crate::config::PolicyRuleConfig>,
     /// Autonomy level from config.
     autonomy_level: AutonomyLevel,
     /// Session-scoped allowlist built from "Always" responses.
     session_allowlist: Mutex<HashSet<String>>,
     /// Audit trail of approval decisions.
     audit_log: Mutex<Vec<ApprovalLogEntry>>,
+    /// Hash-chained audit logger; decisions are mirrored into the
+    /// persistent audit log when attached.
+    audit: Option<Arc<AuditLogger>>,
 }
 
 impl ApprovalManager {
@@ -68,12 +75,20 @@ impl ApprovalManager {
         Self {
             auto_approve: config.auto_approve.iter().cloned().collect(),
             always_ask: config.always_ask.iter().cloned().collect(),
+            policies: config.policies.clone(),
             autonomy_level: config.level,
             session_allowlist: Mutex::new(HashSet::new()),
             audit_log: Mutex::new(Vec::new()),
+            audit: None,
         }
     }
 
+    /// Attach the hash-chained audit logger from `[security.audit]`.
+    pub fn with_audit(mut self, audit: Option<Arc<AuditLogger>>) -> Self {
+        self.audit = audit;
+        self
+    }
+
     /// Check whether a tool call requires interactive approval.
     ///
     /// Returns `true` if the call needs a prompt, `false` if it can proceed.
@@ -93,6 +108,33 @@ impl ApprovalManager {
             return true;
         }
 
+        // Policy rules (matched on tool name) rank just below always_ask:
+        // require-approval forces a prompt even for auto_approve tools,
+        // allow skips it. Deny rules are enforced at execution time by
+        // SecurityPolicy, so no prompt is shown for them. A malformed rule
+        // set fails safe: prompt.
+        let policy_action = crate::security::policy_engine::PolicyAction {
+            tool: tool_name,
+            ..Default::default()
+        };
+        match crate::security::policy_engine::evaluate(
+            &self.policies,
+            &policy_action,
+            chrono::Local::now().time(),
+        ) {
+            Ok(Some(m)) => match m.rule.outcome {
+                crate::config::PolicyOutcome::RequireApproval => return true,
+                crate::config::PolicyOutcome::Allow | crate::config::PolicyOutcome::Deny => {
+                    return false
+                }
+            },
+            Ok(None) => {}
+            Err(e) => {
+                tracing::warn!("Autonomy policy configuration error, requiring approval: {e:#}");
+                return true;
+            }
+        }
+
         // auto_approve skips the prompt.
         if self.auto_approve.contains(tool_name) {
             return false;
@@ -133,6 +175,28 @@ impl ApprovalManager {
         };
         let mut log = self.audit_log.lock();
         log.push(entry);
+        drop(log);
+
+        // Mirror into the persistent hash-chained audit log.
+        if let Some(audit) = &self.audit {
+            let label = match decision {
+                ApprovalResponse::Yes => "yes",
+                ApprovalResponse::No => "no",
+                ApprovalResponse::Always => "always",
+            };
+            let allowed = decision != ApprovalResponse::No;
+            let event = AuditEvent::new(AuditEventType::ApprovalDecision)
+                .with_actor(channel.to_string(), None, None)
+                .with_action(
+                    format!("{tool_name}: {label}"),
+                    "medium".to_string(),
+                    allowed,
+                    allowed,
+                );
+            if let Err(e) = audit.log(&event) {
+                tracing::warn!("Failed to write audit log entry: {e:#}");
+            }
+        }
     }
 
     /// Get a snapshot of the audit log.
@@ -18,6 +18,8 @@ const ANTHROPIC_PROVIDER: &str = "anthropic";
 const DEFAULT_PROFILE_NAME: &str = "default";
 const OPENAI_REFRESH_SKEW_SECS: u64 = 90;
 const OPENAI_REFRESH_FAILURE_BACKOFF_SECS: u64 = 10;
+/// How far ahead of expiry `auth doctor` and the daemon refresh task act.
+const DOCTOR_REFRESH_SKEW_SECS: u64 = 600;
 static REFRESH_BACKOFFS: OnceLock<Mutex<HashMap<String, Instant>>> = OnceLock::new();
 
 #[derive(Clone)]
@@ -132,6 +134,15 @@ impl AuthService {
     pub async fn get_valid_openai_access_token(
         &self,
         profile_override: Option<&str>,
+    ) -> Result<Option<String>> {
+        self.get_valid_openai_access_token_with_skew(profile_override, OPENAI_REFRESH_SKEW_SECS)
+            .await
+    }
+
+    async fn get_valid_openai_access_token_with_skew(
+        &self,
+        profile_override: Option<&str>,
+        skew_secs: u64,
     ) -> Result<Option<String>> {
         let data = tokio::task::spawn_blocking({
             let store = self.store.clone();
@@ -152,7 +163,7 @@ impl AuthService {
             anyhow::bail!("OpenAI Codex auth profile is not OAuth-based: {profile_id}");
         };
 
-        if !token_set.is_expiring_within(Duration::from_secs(OPENAI_REFRESH_SKEW_SECS)) {
+        if !token_set.is_expiring_within(Duration::from_secs(skew_secs)) {
             return Ok(Some(token_set.access_token.clone()));
         }
 
@@ -178,7 +189,7 @@ impl AuthService {
             anyhow::bail!("OpenAI Codex auth profile is missing token set: {profile_id}");
         };
 
-        if !latest_tokens.is_expiring_within(Duration::from_secs(OPENAI_REFRESH_SKEW_SECS)) {
+        if !latest_tokens.is_expiring_within(Duration::from_secs(skew_secs)) {
             return Ok(Some(latest_tokens.access_token.clone()));
         }
 
@@ -231,6 +242,114 @@ impl AuthService {
 
         Ok(updated.token_set.map(|t| t.access_token))
     }
+
+    /// Check every stored auth profile for impending expiry, proactively
+    /// refreshing refreshable OAuth tokens ahead of time. Profiles that
+    /// cannot be refreshed automatically are reported as needing manual
+    /// re-authentication; details never contain token material.
+    pub async fn check_profiles(&self) -> Result<Vec<AuthProfileCheck>> {
+        let data = tokio::task::spawn_blocking({
+            let store = self.store.clone();
+            move || store.load()
+        })
+        .await
+        .map_err(|err| anyhow::anyhow!("Auth profile load task failed: {err}"))??;
+
+        let mut checks = Vec::with_capacity(data.profiles.len());
+        for (id, profile) in &data.profiles {
+            checks.push(self.check_profile(id, profile).await);
+        }
+        Ok(checks)
+    }
+
+    async fn check_profile(&self, id: &str, profile: &AuthProfile) -> AuthProfileCheck {
+        let check = |status, detail: String| AuthProfileCheck {
+            profile_id: id.to_string(),
+            provider: profile.provider.clone(),
+            status,
+            detail,
+        };
+
+        let token_set = match (profile.kind, profile.token_set.as_ref()) {
+            (AuthProfileKind::Token, _) => {
+                return check(
+                    AuthProfileStatus::Healthy,
+                    "static token (no expiry tracking)".into(),
+                );
+            }
+            (AuthProfileKind::OAuth, None) => {
+                return check(
+                    AuthProfileStatus::NeedsReauth,
+                    "OAuth profile has no stored tokens; re-run `zeroclaw auth login`".into(),
+                );
+            }
+            (AuthProfileKind::OAuth, Some(token_set)) => token_set,
+        };
+
+        let Some(expires_at) = token_set.expires_at else {
+            return check(
+                AuthProfileStatus::Healthy,
+                "no expiry recorded for access token".into(),
+            );
+        };
+
+        if !token_set.is_expiring_within(Duration::from_secs(DOCTOR_REFRESH_SKEW_SECS)) {
+            return check(
+                AuthProfileStatus::Healthy,
+                format!("access token valid until {}", expires_at.to_rfc3339()),
+            );
+        }
+
+        if profile.provider != OPENAI_CODEX_PROVIDER || token_set.refresh_token.is_none() {
+            return check(
+                AuthProfileStatus::NeedsReauth,
+                format!(
+                    "access token expires at {} and cannot be refreshed automatically; \
+                     re-authenticate with `zeroclaw auth login`",
+                    expires_at.to_rfc3339()
+                ),
+            );
+        }
+
+        match self
+            .get_valid_openai_access_token_with_skew(Some(id), DOCTOR_REFRESH_SKEW_SECS)
+            .await
+        {
+            Ok(Some(_)) => check(
+                AuthProfileStatus::Refreshed,
+                "access token refreshed ahead of expiry".into(),
+            ),
+            Ok(None) => check(
+                AuthProfileStatus::NeedsReauth,
+                "profile disappeared during refresh; re-run `zeroclaw auth login`".into(),
+            ),
+            Err(err) => check(
+                AuthProfileStatus::NeedsReauth,
+                format!("token refresh failed: {err}"),
+            ),
+        }
+    }
+}
+
+/// Outcome of a single auth profile health check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuthProfileStatus {
+    /// Token is valid and not close to expiry (or has no expiry to track).
+    Healthy,
+    /// Token was close to expiry and has been refreshed proactively.
+    Refreshed,
+    /// Token is expiring or unusable and requires manual re-authentication.
+    NeedsReauth,
+}
+
+/// Per-profile result from [`AuthService::check_profiles`].
+#[derive(Debug, Clone)]
+pub struct AuthProfileCheck {
+    pub profile_id: String,
+    pub provider: String,
+    pub status: AuthProfileStatus,
+    /// Human-readable explanation; never contains token material.
+    pub detail: String,
 }
 
 pub fn normalize_provider(provider: &str) -> Result<String> {
@@ -332,7 +451,7 @@ fn clear_refresh_backoff(profile_id: &str) {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::auth::profiles::{AuthProfile, AuthProfileKind};
+    use crate::auth::profiles::{AuthProfile, AuthProfileKind, TokenSet};
 
     #[test]
     fn normalize_provider_aliases() {
@@ -392,4 +511,81 @@ mod tests {
             Some(id_active)
         );
     }
+
+    fn oauth_token_set(expires_in: chrono::Duration, refresh_token: Option<&str>) -> TokenSet {
+        TokenSet {
+            access_token: "secret-access-456".into(),
+            refresh_token: refresh_token.map(Into::into),
+            id_token: None,
+            expires_at: Some(chrono::Utc::now() + expires_in),
+            token_type: Some("Bearer".into()),
+            scope: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn check_profiles_classifies_expiry_states() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let service = AuthService::new(tmp.path(), false);
+
+        service
+            .store_provider_token("anthropic", "default", "token-abc", HashMap::new(), true)
+            .unwrap();
+        service
+            .store_openai_tokens(
+                "healthy",
+                oauth_token_set(chrono::Duration::hours(6), Some("refresh")),
+                None,
+                false,
+            )
+            .unwrap();
+        service
+            .store_openai_tokens(
+                "expiring",
+                oauth_token_set(chrono::Duration::seconds(60), None),
+                None,
+                false,
+            )
+            .unwrap();
+
+        let checks = service.check_profiles().await.unwrap();
+        let status_of = |id: &str| {
+            checks
+                .iter()
+                .find(|c| c.profile_id == id)
+                .unwrap_or_else(|| panic!("missing check for {id}"))
+                .status
+        };
+
+        assert_eq!(status_of("anthropic:default"), AuthProfileStatus::Healthy);
+        assert_eq!(
+            status_of("openai-codex:healthy"),
+            AuthProfileStatus::Healthy
+        );
+        assert_eq!(
+            status_of("openai-codex:expiring"),
+            AuthProfileStatus::NeedsReauth
+        );
+    }
+
+    #[tokio::test]
+    async fn check_profiles_details_never_contain_token_material() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let service = AuthService::new(tmp.path(), false);
+
+        service
+            .store_openai_tokens(
+                "expiring",
+                oauth_token_set(chrono::Duration::seconds(60), None),
+                None,
+                true,
+            )
+            .unwrap();
+
+        let checks = service.check_profiles().await.unwrap();
+        assert!(!checks.is_empty());
+        for check in checks {
+            assert!(!check.detail.contains("secret-access-456"));
+        }
+    }
 }
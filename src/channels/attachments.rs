@@ -0,0 +1,124 @@
+//! Workspace inbox for files received over channels.
+//!
+//! Channels save inbound attachments (PDFs, CSVs, logs, ...) here and append
+//! an `[attachment saved: <name>]` note to the message content; the agent can
+//! then inspect them with the `read_attachment` tool.
+
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+/// Largest inbound attachment accepted into the inbox (matches the Telegram
+/// Bot API download ceiling).
+pub const INBOX_MAX_FILE_BYTES: usize = 20 * 1024 * 1024;
+
+static INBOX_DIR: OnceLock<PathBuf> = OnceLock::new();
+
+/// Set the inbox location (`<workspace>/inbox`) once at channel startup.
+pub fn set_inbox_dir(workspace_dir: &Path) {
+    let _ = INBOX_DIR.set(workspace_dir.join("inbox"));
+}
+
+/// Inbox directory for inbound channel files. Falls back to a temp-dir inbox
+/// when channels run outside a configured workspace (e.g. tests).
+pub fn inbox_dir() -> PathBuf {
+    INBOX_DIR
+        .get()
+        .cloned()
+        .unwrap_or_else(|| std::env::temp_dir().join("zeroclaw-inbox"))
+}
+
+/// Strip path components, control characters, and leading dots from an
+/// attacker-controlled file name so it cannot escape the inbox or hide.
+pub fn sanitize_file_name(name: &str) -> String {
+    let base = name.rsplit(['/', '\\']).next().unwrap_or(name);
+    let cleaned: String = base.chars().filter(|c| !c.is_control()).collect();
+    let cleaned = cleaned.trim().trim_start_matches('.').to_string();
+    if cleaned.is_empty() {
+        "attachment.bin".to_string()
+    } else {
+        cleaned
+    }
+}
+
+/// Save inbound bytes into the inbox under a sanitized name; existing names
+/// get a short unique suffix instead of being overwritten.
+pub async fn save_to_inbox(file_name: &str, bytes: &[u8]) -> anyhow::Result<PathBuf> {
+    if bytes.len() > INBOX_MAX_FILE_BYTES {
+        anyhow::bail!(
+            "attachment too large: {} bytes (limit: {INBOX_MAX_FILE_BYTES} bytes)",
+            bytes.len()
+        );
+    }
+
+    let dir = inbox_dir();
+    tokio::fs::create_dir_all(&dir).await?;
+
+    let safe = sanitize_file_name(file_name);
+    let mut path = dir.join(&safe);
+    if path.exists() {
+        let base = Path::new(&safe);
+        let stem = base
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or("attachment");
+        let suffix = uuid::Uuid::new_v4().simple().to_string();
+        let unique = match base.extension().and_then(|e| e.to_str()) {
+            Some(ext) => format!("{stem}-{}.{ext}", &suffix[..8]),
+            None => format!("{stem}-{}", &suffix[..8]),
+        };
+        path = dir.join(unique);
+    }
+
+    tokio::fs::write(&path, bytes).await?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sanitize_file_name_strips_path_components() {
+        assert_eq!(sanitize_file_name("../../etc/passwd"), "passwd");
+        assert_eq!(sanitize_file_name("..\\..\\boot.ini"), "boot.ini");
+        assert_eq!(sanitize_file_name("reports/q3.csv"), "q3.csv");
+    }
+
+    #[test]
+    fn sanitize_file_name_removes_leading_dots_and_controls() {
+        assert_eq!(sanitize_file_name(".hidden"), "hidden");
+        assert_eq!(sanitize_file_name("a\nb.txt"), "ab.txt");
+    }
+
+    #[test]
+    fn sanitize_file_name_defaults_when_empty() {
+        assert_eq!(sanitize_file_name(""), "attachment.bin");
+        assert_eq!(sanitize_file_name("..."), "attachment.bin");
+    }
+
+    #[tokio::test]
+    async fn save_to_inbox_rejects_oversized_payload() {
+        let bytes = vec![0u8; INBOX_MAX_FILE_BYTES + 1];
+        let error = save_to_inbox("big.bin", &bytes)
+            .await
+            .expect_err("oversized attachment should be rejected");
+        assert!(error.to_string().contains("attachment too large"));
+    }
+
+    #[tokio::test]
+    async fn save_to_inbox_avoids_collisions() {
+        let first = save_to_inbox("zeroclaw-collision-test.txt", b"one")
+            .await
+            .unwrap();
+        let second = save_to_inbox("zeroclaw-collision-test.txt", b"two")
+            .await
+            .unwrap();
+
+        assert_ne!(first, second);
+        assert_eq!(std::fs::read(&first).unwrap(), b"one");
+        assert_eq!(std::fs::read(&second).unwrap(), b"two");
+
+        let _ = std::fs::remove_file(first);
+        let _ = std::fs::remove_file(second);
+    }
+}
@@ -0,0 +1,115 @@
+//! Deduplication cache for inbound channel events.
+//!
+//! Platforms that deliver over webhooks (Telegram, Slack, ...) redeliver
+//! events when an acknowledgement times out, which would trigger duplicate
+//! agent runs. The dispatch loop consults this cache keyed by the platform
+//! message/event ID and drops repeats seen within the configured TTL.
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Prune expired entries once the cache grows past this many keys, so a busy
+/// runtime does not accumulate stale IDs between quiet periods.
+const PRUNE_THRESHOLD: usize = 1024;
+
+/// TTL-bounded set of recently seen event keys.
+///
+/// Single-owner by design: the dispatch loop is the only ingress point for
+/// channel messages, so no locking is needed.
+pub(crate) struct EventDedupCache {
+    ttl: Duration,
+    seen: HashMap<String, Instant>,
+}
+
+impl EventDedupCache {
+    /// A zero TTL disables deduplication entirely.
+    pub(crate) fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            seen: HashMap::new(),
+        }
+    }
+
+    /// Record the key and report whether it was already seen within the TTL.
+    /// Empty keys are never deduplicated: some channels synthesize messages
+    /// without a platform ID and those must always pass through.
+    pub(crate) fn is_duplicate(&mut self, key: &str) -> bool {
+        self.check_at(key, Instant::now())
+    }
+
+    fn check_at(&mut self, key: &str, now: Instant) -> bool {
+        if self.ttl.is_zero() || key.is_empty() {
+            return false;
+        }
+
+        if self.seen.len() > PRUNE_THRESHOLD {
+            let ttl = self.ttl;
+            self.seen
+                .retain(|_, first_seen| now.duration_since(*first_seen) < ttl);
+        }
+
+        match self.seen.get(key) {
+            Some(first_seen) if now.duration_since(*first_seen) < self.ttl => true,
+            _ => {
+                self.seen.insert(key.to_string(), now);
+                false
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repeat_within_ttl_is_duplicate() {
+        let mut cache = EventDedupCache::new(Duration::from_secs(60));
+        let now = Instant::now();
+
+        assert!(!cache.check_at("telegram:42", now));
+        assert!(cache.check_at("telegram:42", now + Duration::from_secs(5)));
+        assert!(!cache.check_at("telegram:43", now + Duration::from_secs(5)));
+    }
+
+    #[test]
+    fn expired_entry_passes_again() {
+        let mut cache = EventDedupCache::new(Duration::from_secs(60));
+        let now = Instant::now();
+
+        assert!(!cache.check_at("slack:ev-1", now));
+        assert!(!cache.check_at("slack:ev-1", now + Duration::from_secs(61)));
+    }
+
+    #[test]
+    fn zero_ttl_disables_deduplication() {
+        let mut cache = EventDedupCache::new(Duration::ZERO);
+        let now = Instant::now();
+
+        assert!(!cache.check_at("telegram:42", now));
+        assert!(!cache.check_at("telegram:42", now));
+    }
+
+    #[test]
+    fn empty_key_is_never_deduplicated() {
+        let mut cache = EventDedupCache::new(Duration::from_secs(60));
+        let now = Instant::now();
+
+        assert!(!cache.check_at("", now));
+        assert!(!cache.check_at("", now));
+    }
+
+    #[test]
+    fn prune_drops_only_expired_entries() {
+        let mut cache = EventDedupCache::new(Duration::from_secs(60));
+        let now = Instant::now();
+
+        for i in 0..=PRUNE_THRESHOLD {
+            assert!(!cache.check_at(&format!("old:{i}"), now));
+        }
+        // Next check runs the prune pass: expired keys go, fresh keys stay.
+        assert!(!cache.check_at("fresh:1", now + Duration::from_secs(61)));
+        assert!(cache.check_at("fresh:1", now + Duration::from_secs(62)));
+        assert!(!cache.check_at("old:0", now + Duration::from_secs(62)));
+    }
+}
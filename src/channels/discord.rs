@@ -1,4 +1,4 @@
-use super::traits::{Channel, ChannelMessage, SendMessage};
+use super::traits::{Channel, ChannelCapabilities, ChannelMessage, MarkdownDialect, SendMessage};
 use async_trait::async_trait;
 use futures_util::{SinkExt, StreamExt};
 use parking_lot::Mutex;
@@ -51,6 +51,32 @@ impl DiscordChannel {
         let part = token.split('.').next()?;
         base64_decode(part)
     }
+
+    /// Download an incoming image attachment to a temp file so it can be
+    /// passed to the agent as a local `[IMAGE:...]` marker.
+    async fn download_image_to_temp(&self, url: &str) -> anyhow::Result<std::path::PathBuf> {
+        let bytes = self
+            .http_client()
+            .get(url)
+            .send()
+            .await?
+            .error_for_status()?
+            .bytes()
+            .await?;
+
+        let extension = url
+            .split('?')
+            .next()
+            .unwrap_or(url)
+            .rsplit('.')
+            .next()
+            .filter(|ext| matches!(*ext, "png" | "jpg" | "jpeg" | "webp" | "gif" | "bmp"))
+            .unwrap_or("png");
+        let path =
+            std::env::temp_dir().join(format!("zeroclaw-discord-{}.{extension}", Uuid::new_v4()));
+        tokio::fs::write(&path, &bytes).await?;
+        Ok(path)
+    }
 }
 
 const BASE64_ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
@@ -117,6 +143,30 @@ fn contains_bot_mention(content: &str, bot_user_id: &str) -> bool {
     content.contains(&tags[0]) || content.contains(&tags[1])
 }
 
+/// Collect CDN URLs of image attachments from a MESSAGE_CREATE payload.
+fn image_attachment_urls(d: &serde_json::Value) -> Vec<String> {
+    d.get("attachments")
+        .and_then(serde_json::Value::as_array)
+        .map(|attachments| {
+            attachments
+                .iter()
+                .filter(|attachment| {
+                    attachment
+                        .get("content_type")
+                        .and_then(serde_json::Value::as_str)
+                        .is_some_and(|content_type| content_type.starts_with("image/"))
+                })
+                .filter_map(|attachment| {
+                    attachment
+                        .get("url")
+                        .and_then(serde_json::Value::as_str)
+                        .map(ToString::to_string)
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
 fn normalize_incoming_content(
     content: &str,
     mention_only: bool,
@@ -189,6 +239,16 @@ impl Channel for DiscordChannel {
         "discord"
     }
 
+    fn capabilities(&self) -> ChannelCapabilities {
+        ChannelCapabilities {
+            supports_images: false,
+            supports_buttons: false,
+            supports_threads: false,
+            max_message_length: Some(DISCORD_MAX_MESSAGE_LENGTH),
+            markdown: MarkdownDialect::Standard,
+        }
+    }
+
     async fn send(&self, message: &SendMessage) -> anyhow::Result<()> {
         let chunks = split_message_for_discord(&message.content);
 
@@ -389,11 +449,35 @@ impl Channel for DiscordChannel {
                     }
 
                     let content = d.get("content").and_then(|c| c.as_str()).unwrap_or("");
-                    let Some(clean_content) =
-                        normalize_incoming_content(content, self.mention_only, &bot_user_id)
-                    else {
+                    let image_urls = image_attachment_urls(d);
+                    let mut clean_content =
+                        match normalize_incoming_content(content, self.mention_only, &bot_user_id) {
+                            Some(clean) => clean,
+                            // Attachment-only messages have no text; in
+                            // mention-only mode they still require a mention.
+                            None if !image_urls.is_empty() && !self.mention_only => String::new(),
+                            None => continue,
+                        };
+
+                    // Incoming image attachments: download and reference them
+                    // as local [IMAGE:...] attachments for vision-capable models.
+                    for url in &image_urls {
+                        match self.download_image_to_temp(url).await {
+                            Ok(path) => {
+                                clean_content =
+                                    format!("{clean_content} [IMAGE:{}]", path.display())
+                                        .trim()
+                                        .to_string();
+                            }
+                            Err(e) => {
+                                tracing::warn!("Discord: failed to download image attachment: {e}");
+                            }
+                        }
+                    }
+                    if clean_content.is_empty() {
                         continue;
-                    };
+                    }
+                    let clean_content = clean_content;
 
                     let message_id = d.get("id").and_then(|i| i.as_str()).unwrap_or("");
                     let channel_id = d.get("channel_id").and_then(|c| c.as_str()).unwrap_or("").to_string();
@@ -490,6 +574,31 @@ mod tests {
         assert_eq!(decoded, Some("123456".to_string()));
     }
 
+    #[test]
+    fn image_attachment_urls_filters_non_images() {
+        let payload = serde_json::json!({
+            "attachments": [
+                { "url": "https://example.com/shot.png", "content_type": "image/png" },
+                { "url": "https://example.com/notes.pdf", "content_type": "application/pdf" },
+                { "url": "https://example.com/photo.jpg", "content_type": "image/jpeg" }
+            ]
+        });
+
+        let urls = image_attachment_urls(&payload);
+        assert_eq!(
+            urls,
+            vec![
+                "https://example.com/shot.png".to_string(),
+                "https://example.com/photo.jpg".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn image_attachment_urls_empty_without_attachments() {
+        assert!(image_attachment_urls(&serde_json::json!({})).is_empty());
+    }
+
     #[test]
     fn bot_user_id_extraction() {
         // Token format: base64(user_id).timestamp.hmac
@@ -1,4 +1,6 @@
-use crate::channels::traits::{Channel, ChannelMessage, SendMessage};
+use crate::channels::traits::{
+    Channel, ChannelCapabilities, ChannelMessage, MarkdownDialect, SendMessage,
+};
 use async_trait::async_trait;
 use matrix_sdk::{
     authentication::matrix::MatrixSession,
@@ -480,6 +482,16 @@ impl Channel for MatrixChannel {
         "matrix"
     }
 
+    fn capabilities(&self) -> ChannelCapabilities {
+        ChannelCapabilities {
+            supports_images: false,
+            supports_buttons: false,
+            supports_threads: false,
+            max_message_length: None,
+            markdown: MarkdownDialect::Standard,
+        }
+    }
+
     async fn send(&self, message: &SendMessage) -> anyhow::Result<()> {
         let client = self.matrix_client().await?;
         let target_room_id = self.target_room_id().await?;
@@ -14,7 +14,9 @@
 //! To add a new channel, implement [`Channel`] in a new submodule and wire it into
 //! [`start_channels`]. See `AGENTS.md` §7.2 for the full change playbook.
 
+pub mod attachments;
 pub mod cli;
+mod dedup;
 pub mod dingtalk;
 pub mod discord;
 pub mod email_channel;
@@ -51,7 +53,7 @@ pub use qq::QQChannel;
 pub use signal::SignalChannel;
 pub use slack::SlackChannel;
 pub use telegram::TelegramChannel;
-pub use traits::{Channel, SendMessage};
+pub use traits::{Channel, ChannelCapabilities, MarkdownDialect, SendMessage};
 pub use whatsapp::WhatsAppChannel;
 #[cfg(feature = "whatsapp-web")]
 pub use whatsapp_web::WhatsAppWebChannel;
@@ -67,15 +69,17 @@ use crate::security::SecurityPolicy;
 use crate::tools::{self, Tool};
 use crate::util::truncate_with_ellipsis;
 use anyhow::{Context, Result};
+use futures::FutureExt as _;
 use serde::Deserialize;
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::fmt::Write;
 use std::path::{Path, PathBuf};
 use std::process::Command;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
-use std::sync::{Arc, Mutex, OnceLock};
+use std::sync::{Arc, Mutex, OnceLock, RwLock};
 use std::time::{Duration, Instant, SystemTime};
 use tokio_util::sync::CancellationToken;
+use tracing::Instrument as _;
 
 /// Per-sender conversation history for channel messages.
 type ConversationHistoryMap = Arc<Mutex<HashMap<String, Vec<ChatMessage>>>>;
@@ -100,8 +104,19 @@ const CHANNEL_MESSAGE_TIMEOUT_SCALE_CAP: u64 = 4;
 const CHANNEL_PARALLELISM_PER_CHANNEL: usize = 4;
 const CHANNEL_MIN_IN_FLIGHT_MESSAGES: usize = 8;
 const CHANNEL_MAX_IN_FLIGHT_MESSAGES: usize = 64;
+/// Max messages queued behind a conversation's in-flight message before new
+/// ones are dropped; bounds memory while preserving in-order replies.
+const CHANNEL_MAX_QUEUED_PER_CONVERSATION: usize = 16;
 const CHANNEL_TYPING_REFRESH_INTERVAL_SECS: u64 = 4;
+/// How often the optional progress-update message reports the running tool.
+const CHANNEL_PROGRESS_UPDATE_SECS: u64 = 30;
 const CHANNEL_HEALTH_HEARTBEAT_SECS: u64 = 30;
+/// How often a running channel server re-reads the config file to apply
+/// `channel add`/`channel remove` changes without a restart.
+const CHANNEL_CONFIG_POLL_SECS: u64 = 15;
+/// How long `channel add`/`channel remove` waits for a running daemon to
+/// confirm the change via its state file before giving up on reporting.
+const CHANNEL_APPLY_REPORT_TIMEOUT_SECS: u64 = 45;
 const MODEL_CACHE_FILE: &str = "models_cache.json";
 const MODEL_CACHE_PREVIEW_LIMIT: usize = 10;
 const MEMORY_CONTEXT_MAX_ENTRIES: usize = 4;
@@ -112,6 +127,10 @@ const CHANNEL_HISTORY_COMPACT_CONTENT_CHARS: usize = 600;
 
 type ProviderCacheMap = Arc<Mutex<HashMap<String, Arc<dyn Provider>>>>;
 type RouteSelectionMap = Arc<Mutex<HashMap<String, ChannelRouteSelection>>>;
+/// Channels currently attached to the dispatch loop, keyed by channel name.
+/// Shared mutably so the lifecycle manager can attach/detach channels while
+/// the dispatch loop is running.
+type SharedChannelMap = Arc<RwLock<HashMap<String, Arc<dyn Channel>>>>;
 
 fn effective_channel_message_timeout_secs(configured: u64) -> u64 {
     configured.max(MIN_CHANNEL_MESSAGE_TIMEOUT_SECS)
@@ -138,6 +157,12 @@ enum ChannelRuntimeCommand {
     SetProvider(String),
     ShowModel,
     SetModel(String),
+    ShowStatus,
+    ShowCost,
+    CronList,
+    Pause,
+    Resume,
+    CancelRun,
 }
 
 #[derive(Debug, Clone, Default, Deserialize)]
@@ -179,8 +204,9 @@ fn runtime_config_store() -> &'static Mutex<HashMap<PathBuf, RuntimeConfigState>
 }
 
 #[derive(Clone)]
+#[allow(clippy::struct_excessive_bools)]
 struct ChannelRuntimeContext {
-    channels_by_name: Arc<HashMap<String, Arc<dyn Channel>>>,
+    channels_by_name: SharedChannelMap,
     provider: Arc<dyn Provider>,
     default_provider: Arc<String>,
     memory: Arc<dyn Memory>,
@@ -202,7 +228,25 @@ struct ChannelRuntimeContext {
     workspace_dir: Arc<PathBuf>,
     message_timeout_secs: u64,
     interrupt_on_new_message: bool,
+    /// Send a one-time "still working" notice when a message queues behind
+    /// an in-flight request in the same conversation.
+    queue_busy_notice: bool,
+    /// Send periodic progress updates with the currently running tool while
+    /// the agent works on a long task.
+    progress_updates: bool,
+    /// Conversations paused via the inline `/pause` command; messages into a
+    /// paused chat get a short notice instead of an agent run.
+    paused_conversations: Arc<std::sync::Mutex<std::collections::HashSet<String>>>,
+    /// Cancellation tokens for in-flight agent runs keyed by
+    /// [`conversation_scope_key`]; the inline `/cancel` command aborts the
+    /// chat's current run through this map. The task id guards removal so a
+    /// finished run cannot evict its replacement.
+    active_runs: Arc<std::sync::Mutex<HashMap<String, (u64, CancellationToken)>>>,
     multimodal: crate::config::MultimodalConfig,
+    /// Channel users with roles (`[[identity.users]]`); empty = roles disabled.
+    identity_users: Arc<Vec<crate::config::UserBindingConfig>>,
+    /// Usage quota enforcement (`[quota]`); `None` = quotas disabled.
+    quota: Option<Arc<crate::quota::QuotaTracker>>,
 }
 
 #[derive(Clone)]
@@ -250,24 +294,58 @@ fn interruption_scope_key(msg: &traits::ChannelMessage) -> String {
     format!("{}_{}_{}", msg.channel, msg.reply_target, msg.sender)
 }
 
-fn channel_delivery_instructions(channel_name: &str) -> Option<&'static str> {
-    match channel_name {
-        "telegram" => Some(
-            "When responding on Telegram, include media markers for files or URLs that should be sent as attachments. Use one marker per attachment with this exact syntax: [IMAGE:<path-or-url>], [DOCUMENT:<path-or-url>], [VIDEO:<path-or-url>], [AUDIO:<path-or-url>], or [VOICE:<path-or-url>]. Keep normal user-facing text outside markers and never wrap markers in code fences.",
+/// One chat/thread regardless of sender: replies into the same conversation
+/// are serialized on this key so they cannot interleave.
+fn conversation_scope_key(msg: &traits::ChannelMessage) -> String {
+    format!("{}_{}", msg.channel, msg.reply_target)
+}
+
+/// Derive rendering guidance from a channel's declared capabilities so the
+/// agent never plans content the destination cannot render.
+fn capability_instructions(caps: &ChannelCapabilities) -> String {
+    let mut lines: Vec<String> = Vec::new();
+
+    match caps.markdown {
+        MarkdownDialect::Plain => lines.push(
+            "This channel renders plain text only. Do not use Markdown formatting (headings, bold, tables, code fences)."
+                .to_string(),
         ),
-        _ => None,
+        MarkdownDialect::Slack => lines.push(
+            "This channel renders Slack mrkdwn: *bold*, _italic_, `code`, and ``` blocks. Markdown headings and tables do not render."
+                .to_string(),
+        ),
+        MarkdownDialect::Standard => {}
     }
-}
 
-fn build_channel_system_prompt(base_prompt: &str, channel_name: &str) -> String {
-    if let Some(instructions) = channel_delivery_instructions(channel_name) {
-        if base_prompt.is_empty() {
-            instructions.to_string()
-        } else {
-            format!("{base_prompt}\n\n{instructions}")
-        }
+    if let Some(limit) = caps.max_message_length {
+        lines.push(format!(
+            "Messages longer than {limit} characters are split before delivery; keep responses concise when possible."
+        ));
+    }
+
+    if caps.supports_images {
+        lines.push(
+            "To send files or URLs as attachments, include one media marker per attachment with this exact syntax: [IMAGE:<path-or-url>], [DOCUMENT:<path-or-url>], [VIDEO:<path-or-url>], [AUDIO:<path-or-url>], or [VOICE:<path-or-url>]. Keep normal user-facing text outside markers and never wrap markers in code fences."
+                .to_string(),
+        );
     } else {
+        lines.push(
+            "This channel cannot deliver file or image attachments; reference content by path or URL in text instead."
+                .to_string(),
+        );
+    }
+
+    lines.join("\n")
+}
+
+fn build_channel_system_prompt(base_prompt: &str, caps: &ChannelCapabilities) -> String {
+    let instructions = capability_instructions(caps);
+    if instructions.is_empty() {
         base_prompt.to_string()
+    } else if base_prompt.is_empty() {
+        instructions
+    } else {
+        format!("{base_prompt}\n\n{instructions}")
     }
 }
 
@@ -308,11 +386,14 @@ fn supports_runtime_model_switch(channel_name: &str) -> bool {
     matches!(channel_name, "telegram" | "discord")
 }
 
-fn parse_runtime_command(channel_name: &str, content: &str) -> Option<ChannelRuntimeCommand> {
-    if !supports_runtime_model_switch(channel_name) {
-        return None;
-    }
+/// Channels where inline admin commands (`/status`, `/cost`, `/cron list`,
+/// `/pause`, `/resume`, `/cancel`) are routed instead of being sent to the
+/// agent.
+fn supports_inline_commands(channel_name: &str) -> bool {
+    matches!(channel_name, "telegram" | "discord" | "slack")
+}
 
+fn parse_runtime_command(channel_name: &str, content: &str) -> Option<ChannelRuntimeCommand> {
     let trimmed = content.trim();
     if !trimmed.starts_with('/') {
         return None;
@@ -327,7 +408,7 @@ fn parse_runtime_command(channel_name: &str, content: &str) -> Option<ChannelRun
         .to_ascii_lowercase();
 
     match base_command.as_str() {
-        "/models" => {
+        "/models" if supports_runtime_model_switch(channel_name) => {
             if let Some(provider) = parts.next() {
                 Some(ChannelRuntimeCommand::SetProvider(
                     provider.trim().to_string(),
@@ -336,7 +417,7 @@ fn parse_runtime_command(channel_name: &str, content: &str) -> Option<ChannelRun
                 Some(ChannelRuntimeCommand::ShowProviders)
             }
         }
-        "/model" => {
+        "/model" if supports_runtime_model_switch(channel_name) => {
             let model = parts.collect::<Vec<_>>().join(" ").trim().to_string();
             if model.is_empty() {
                 Some(ChannelRuntimeCommand::ShowModel)
@@ -344,10 +425,70 @@ fn parse_runtime_command(channel_name: &str, content: &str) -> Option<ChannelRun
                 Some(ChannelRuntimeCommand::SetModel(model))
             }
         }
+        "/status" if supports_inline_commands(channel_name) => {
+            Some(ChannelRuntimeCommand::ShowStatus)
+        }
+        // Optional scope argument (e.g. `/cost today`) is accepted; the
+        // report always covers the current day per scope.
+        "/cost" if supports_inline_commands(channel_name) => Some(ChannelRuntimeCommand::ShowCost),
+        // Only `/cron list` is routed inline; other cron operations go
+        // through the agent so tool-level role scoping applies.
+        "/cron" if supports_inline_commands(channel_name) && parts.next() == Some("list") => {
+            Some(ChannelRuntimeCommand::CronList)
+        }
+        "/pause" if supports_inline_commands(channel_name) => Some(ChannelRuntimeCommand::Pause),
+        "/resume" if supports_inline_commands(channel_name) => Some(ChannelRuntimeCommand::Resume),
+        "/cancel" if supports_inline_commands(channel_name) => {
+            Some(ChannelRuntimeCommand::CancelRun)
+        }
         _ => None,
     }
 }
 
+/// Whether a sender role may run an inline command. `None` means the role
+/// system is disabled; channel allowlists are then the only gate.
+fn inline_command_allowed(
+    role: Option<crate::config::UserRole>,
+    command: &ChannelRuntimeCommand,
+) -> bool {
+    let Some(role) = role else {
+        return true;
+    };
+    match command {
+        // Pausing or cancelling affects every sender in the chat: operator
+        // and above. Read-only reports and model switching stay open to any
+        // role.
+        ChannelRuntimeCommand::Pause
+        | ChannelRuntimeCommand::Resume
+        | ChannelRuntimeCommand::CancelRun => !matches!(role, crate::config::UserRole::Viewer),
+        _ => true,
+    }
+}
+
+/// Render the `/status` reply from the process-wide health registry.
+fn build_status_response() -> String {
+    let snapshot = crate::health::snapshot();
+    let mut lines = vec![format!(
+        "📊 ZeroClaw status — uptime {}s",
+        snapshot.uptime_seconds
+    )];
+    if snapshot.components.is_empty() {
+        lines.push("No components reported yet.".to_string());
+    }
+    for (name, component) in &snapshot.components {
+        lines.push(format!("• {name}: {}", component.status));
+    }
+    if !snapshot.channel_messages.is_empty() {
+        let counts: Vec<String> = snapshot
+            .channel_messages
+            .iter()
+            .map(|(channel, count)| format!("{channel}={count}"))
+            .collect();
+        lines.push(format!("Messages: {}", counts.join(", ")));
+    }
+    lines.join("\n")
+}
+
 fn resolve_provider_alias(name: &str) -> Option<String> {
     let candidate = name.trim();
     if candidate.is_empty() {
@@ -782,11 +923,80 @@ async fn handle_runtime_command_if_needed(
         return true;
     };
 
+    let sender_role = crate::identity::resolve_role(&ctx.identity_users, &msg.channel, &msg.sender);
+    if !inline_command_allowed(sender_role, &command) {
+        let denial = "⛔ This command requires the operator role.";
+        if let Err(err) = channel
+            .send(&SendMessage::new(denial, &msg.reply_target).in_thread(msg.thread_ts.clone()))
+            .await
+        {
+            tracing::warn!(
+                "Failed to send runtime command response on {}: {err}",
+                channel.name()
+            );
+        }
+        return true;
+    }
+
     let sender_key = conversation_history_key(msg);
     let mut current = get_route_selection(ctx, &sender_key);
 
     let response = match command {
         ChannelRuntimeCommand::ShowProviders => build_providers_help_response(&current),
+        ChannelRuntimeCommand::ShowStatus => build_status_response(),
+        ChannelRuntimeCommand::ShowCost => match ctx.quota.as_ref() {
+            Some(quota) => {
+                let lines = quota.summary_lines();
+                if lines.is_empty() {
+                    "💰 No usage recorded yet today.".to_string()
+                } else {
+                    format!("💰 Usage today:\n{}", lines.join("\n"))
+                }
+            }
+            None => "Cost reporting requires `[quota]` tracking to be enabled.".to_string(),
+        },
+        ChannelRuntimeCommand::CronList => {
+            match ctx
+                .tools_registry
+                .iter()
+                .find(|tool| tool.name() == "cron_list")
+            {
+                Some(tool) => match tool.execute(serde_json::json!({})).await {
+                    Ok(result) if result.success => result.output,
+                    Ok(result) => format!(
+                        "Failed to list cron jobs: {}",
+                        result.error.unwrap_or(result.output)
+                    ),
+                    Err(e) => format!("Failed to list cron jobs: {e}"),
+                },
+                None => "The cron_list tool is not available in this runtime.".to_string(),
+            }
+        }
+        ChannelRuntimeCommand::Pause => {
+            ctx.paused_conversations
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .insert(conversation_scope_key(msg));
+            "⏸️ Agent paused for this chat. Send /resume to continue.".to_string()
+        }
+        ChannelRuntimeCommand::Resume => {
+            let was_paused = ctx
+                .paused_conversations
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .remove(&conversation_scope_key(msg));
+            if was_paused {
+                "▶️ Agent resumed for this chat.".to_string()
+            } else {
+                "Agent is not paused for this chat.".to_string()
+            }
+        }
+        // `/cancel` is normally intercepted by the dispatch loop before it
+        // can queue; reaching this point means no other run is in flight for
+        // the conversation.
+        ChannelRuntimeCommand::CancelRun => {
+            "Nothing to cancel — no request is in flight for this chat.".to_string()
+        }
         ChannelRuntimeCommand::SetProvider(raw_provider) => {
             match resolve_provider_alias(&raw_provider) {
                 Some(provider_name) => match get_or_create_provider(ctx, &provider_name).await {
@@ -1030,35 +1240,53 @@ fn spawn_supervised_listener_with_health_interval(
             crate::health::mark_component_ok(&component);
             let mut health = tokio::time::interval(health_interval);
             health.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
-            let result = {
+            // Contain panics from channel internals: without `catch_unwind` a
+            // panicking listener kills its supervisor task and the channel
+            // stays down (with stale "ok" health) until the daemon restarts.
+            let result = std::panic::AssertUnwindSafe(async {
                 let listen_future = ch.listen(tx.clone());
                 tokio::pin!(listen_future);
 
                 loop {
                     tokio::select! {
                         _ = health.tick() => {
+                            // Chaos mode (ZEROCLAW_CHAOS): kill the listener so the
+                            // restart/backoff path below is exercised for real.
+                            if crate::chaos::inject_channel_disconnect() {
+                                break Err(anyhow::anyhow!("chaos: injected channel disconnect"));
+                            }
                             crate::health::mark_component_ok(&component);
                         }
                         result = &mut listen_future => break result,
                     }
                 }
-            };
+            })
+            .catch_unwind()
+            .await;
 
             if tx.is_closed() {
                 break;
             }
 
             match result {
-                Ok(()) => {
+                Ok(Ok(())) => {
                     tracing::warn!("Channel {} exited unexpectedly; restarting", ch.name());
                     crate::health::mark_component_error(&component, "listener exited unexpectedly");
                     // Clean exit — reset backoff since the listener ran successfully
                     backoff = initial_backoff_secs.max(1);
                 }
-                Err(e) => {
+                Ok(Err(e)) => {
                     tracing::error!("Channel {} error: {e}; restarting", ch.name());
                     crate::health::mark_component_error(&component, e.to_string());
                 }
+                Err(payload) => {
+                    let reason = format!(
+                        "listener panicked: {}",
+                        crate::util::panic_message(payload.as_ref())
+                    );
+                    tracing::error!("Channel {} {reason}; restarting", ch.name());
+                    crate::health::mark_component_error(&component, &reason);
+                }
             }
 
             crate::health::bump_component_restart(&component);
@@ -1069,7 +1297,198 @@ fn spawn_supervised_listener_with_health_interval(
     })
 }
 
-fn compute_max_in_flight_messages(channel_count: usize) -> usize {
+/// A channel listener currently supervised by the lifecycle manager.
+struct ActiveChannelListener {
+    handle: tokio::task::JoinHandle<()>,
+    /// Serialized config section used to detect in-place changes
+    /// (for example a rebound bot token) between applies.
+    fingerprint: Option<String>,
+}
+
+/// Outcome of one incremental channel config apply.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+struct ChannelApplyReport {
+    started: Vec<String>,
+    stopped: Vec<String>,
+    restarted: Vec<String>,
+}
+
+impl ChannelApplyReport {
+    fn is_empty(&self) -> bool {
+        self.started.is_empty() && self.stopped.is_empty() && self.restarted.is_empty()
+    }
+}
+
+/// Applies channel configuration changes to a running channel server without
+/// a full restart: newly added channels are started, removed channels are
+/// stopped (intake ends immediately; messages already dispatched hold their
+/// own channel handle and finish normally), and channels whose config section
+/// changed — for example a rebound bot token — are restarted with the new
+/// settings.
+struct ChannelLifecycleManager {
+    tx: tokio::sync::mpsc::Sender<traits::ChannelMessage>,
+    channels_by_name: SharedChannelMap,
+    listeners: Mutex<HashMap<String, ActiveChannelListener>>,
+    initial_backoff_secs: u64,
+    max_backoff_secs: u64,
+}
+
+impl ChannelLifecycleManager {
+    fn new(
+        tx: tokio::sync::mpsc::Sender<traits::ChannelMessage>,
+        channels_by_name: SharedChannelMap,
+        initial_backoff_secs: u64,
+        max_backoff_secs: u64,
+    ) -> Self {
+        Self {
+            tx,
+            channels_by_name,
+            listeners: Mutex::new(HashMap::new()),
+            initial_backoff_secs,
+            max_backoff_secs,
+        }
+    }
+
+    /// Diff the desired config against running listeners and reconcile.
+    /// Report vectors are sorted for deterministic logs.
+    fn apply(&self, channels_config: &crate::config::ChannelsConfig) -> ChannelApplyReport {
+        let desired: HashMap<String, Arc<dyn Channel>> = build_runtime_channels(channels_config)
+            .into_iter()
+            .map(|ch| (ch.name().to_string(), ch))
+            .collect();
+
+        let mut listeners = self.listeners.lock().unwrap_or_else(|e| e.into_inner());
+        let mut report = ChannelApplyReport::default();
+
+        let removed: Vec<String> = listeners
+            .keys()
+            .filter(|name| !desired.contains_key(*name))
+            .cloned()
+            .collect();
+        for name in removed {
+            if let Some(active) = listeners.remove(&name) {
+                active.handle.abort();
+            }
+            self.channels_by_name
+                .write()
+                .unwrap_or_else(|e| e.into_inner())
+                .remove(&name);
+            crate::health::mark_component_error(
+                &format!("channel:{name}"),
+                "stopped: removed from config",
+            );
+            report.stopped.push(name);
+        }
+
+        for (name, channel) in desired {
+            let fingerprint = channel_config_fingerprint(channels_config, &name);
+            match listeners.get(&name) {
+                Some(active) if active.fingerprint == fingerprint => continue,
+                Some(_) => {
+                    if let Some(active) = listeners.remove(&name) {
+                        active.handle.abort();
+                    }
+                    report.restarted.push(name.clone());
+                }
+                None => report.started.push(name.clone()),
+            }
+
+            let handle = spawn_supervised_listener(
+                Arc::clone(&channel),
+                self.tx.clone(),
+                self.initial_backoff_secs,
+                self.max_backoff_secs,
+            );
+            self.channels_by_name
+                .write()
+                .unwrap_or_else(|e| e.into_inner())
+                .insert(name.clone(), channel);
+            listeners.insert(
+                name,
+                ActiveChannelListener {
+                    handle,
+                    fingerprint,
+                },
+            );
+        }
+
+        report.started.sort();
+        report.stopped.sort();
+        report.restarted.sort();
+        report
+    }
+
+    /// Names of currently attached channels, sorted.
+    fn active_channel_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self
+            .listeners
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .keys()
+            .cloned()
+            .collect();
+        names.sort();
+        names
+    }
+
+    /// Stop all listeners and detach all channels.
+    fn shutdown(&self) {
+        let mut listeners = self.listeners.lock().unwrap_or_else(|e| e.into_inner());
+        for (_, active) in listeners.drain() {
+            active.handle.abort();
+        }
+        self.channels_by_name
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .clear();
+    }
+}
+
+/// Serialized form of the config section backing the named channel, used to
+/// detect in-place changes (token rebinds) between config applies.
+fn channel_config_fingerprint(
+    channels_config: &crate::config::ChannelsConfig,
+    channel_name: &str,
+) -> Option<String> {
+    fn section<T: serde::Serialize>(value: Option<&T>) -> Option<String> {
+        value.and_then(|v| serde_json::to_string(v).ok())
+    }
+
+    match channel_name {
+        "telegram" => section(channels_config.telegram.as_ref()),
+        "discord" => section(channels_config.discord.as_ref()),
+        "slack" => section(channels_config.slack.as_ref()),
+        "mattermost" => section(channels_config.mattermost.as_ref()),
+        "imessage" => section(channels_config.imessage.as_ref()),
+        "matrix" => section(channels_config.matrix.as_ref()),
+        "signal" => section(channels_config.signal.as_ref()),
+        "whatsapp" => section(channels_config.whatsapp.as_ref()),
+        "linq" => section(channels_config.linq.as_ref()),
+        "email" => section(channels_config.email.as_ref()),
+        "irc" => section(channels_config.irc.as_ref()),
+        "lark" => section(channels_config.lark.as_ref()),
+        "dingtalk" => section(channels_config.dingtalk.as_ref()),
+        "qq" => section(channels_config.qq.as_ref()),
+        _ => None,
+    }
+}
+
+/// Re-read only the channel sections from the config file on disk. Channel
+/// tokens are stored as-is in the config (they are not routed through the
+/// secret store), so no decryption pass is needed here.
+async fn load_channels_config_from_file(path: &Path) -> Result<crate::config::ChannelsConfig> {
+    let contents = tokio::fs::read_to_string(path)
+        .await
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    let parsed: Config =
+        toml::from_str(&contents).with_context(|| format!("Failed to parse {}", path.display()))?;
+    Ok(parsed.channels_config)
+}
+
+fn compute_max_in_flight_messages(channel_count: usize, configured: usize) -> usize {
+    if configured > 0 {
+        return configured;
+    }
     channel_count
         .saturating_mul(CHANNEL_PARALLELISM_PER_CHANNEL)
         .clamp(
@@ -1084,6 +1503,93 @@ fn log_worker_join_result(result: Result<(), tokio::task::JoinError>) {
     }
 }
 
+/// Per-message observer wrapper that tracks which tool is currently executing
+/// so the progress-update task can report it. All events are forwarded to the
+/// shared observer stack unchanged.
+struct ProgressTrackingObserver {
+    inner: Arc<dyn Observer>,
+    current_tool: Arc<std::sync::Mutex<Option<String>>>,
+}
+
+impl Observer for ProgressTrackingObserver {
+    fn record_event(&self, event: &observability::ObserverEvent) {
+        match event {
+            observability::ObserverEvent::ToolCallStart { tool } => {
+                *self.current_tool.lock().unwrap_or_else(|e| e.into_inner()) = Some(tool.clone());
+            }
+            observability::ObserverEvent::ToolCall { .. } => {
+                *self.current_tool.lock().unwrap_or_else(|e| e.into_inner()) = None;
+            }
+            _ => {}
+        }
+        self.inner.record_event(event);
+    }
+
+    fn record_metric(&self, metric: &observability::traits::ObserverMetric) {
+        self.inner.record_metric(metric);
+    }
+
+    fn flush(&self) {
+        self.inner.flush();
+    }
+
+    fn run_id(&self) -> Option<&str> {
+        self.inner.run_id()
+    }
+
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+/// Periodically tell the chat what the agent is doing during a long task
+/// (e.g. "Running `shell`… 40s"). Only fires while a tool is executing, so
+/// quick turns never see it; typing indicators cover the rest.
+fn spawn_progress_update_task(
+    channel: Arc<dyn Channel>,
+    recipient: String,
+    thread_ts: Option<String>,
+    cancellation_token: CancellationToken,
+    current_tool: Arc<std::sync::Mutex<Option<String>>>,
+) -> tokio::task::JoinHandle<()> {
+    let started = Instant::now();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(CHANNEL_PROGRESS_UPDATE_SECS));
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+        // The first tick completes immediately; skip it so the first update
+        // only goes out after a full interval of real work.
+        interval.tick().await;
+
+        loop {
+            tokio::select! {
+                () = cancellation_token.cancelled() => break,
+                _ = interval.tick() => {
+                    let running = current_tool
+                        .lock()
+                        .unwrap_or_else(|e| e.into_inner())
+                        .clone();
+                    if let Some(tool) = running {
+                        let update = format!(
+                            "⚙️ Running `{tool}`… {}s",
+                            started.elapsed().as_secs()
+                        );
+                        if let Err(e) = channel
+                            .send(&SendMessage::new(&update, &recipient).in_thread(thread_ts.clone()))
+                            .await
+                        {
+                            tracing::debug!("Failed to send progress update on {}: {e}", channel.name());
+                        }
+                    }
+                }
+            }
+        }
+    })
+}
+
 fn spawn_scoped_typing_task(
     channel: Arc<dyn Channel>,
     recipient: String,
@@ -1123,6 +1629,13 @@ async fn process_channel_message(
         return;
     }
 
+    crate::health::bump_channel_message(&msg.channel);
+    ctx.observer
+        .record_event(&observability::ObserverEvent::ChannelMessage {
+            channel: msg.channel.clone(),
+            direction: "inbound".to_string(),
+        });
+
     println!(
         "  💬 [{}] from {}: {}",
         msg.channel,
@@ -1130,7 +1643,12 @@ async fn process_channel_message(
         truncate_with_ellipsis(&msg.content, 80)
     );
 
-    let target_channel = ctx.channels_by_name.get(&msg.channel).cloned();
+    let target_channel = ctx
+        .channels_by_name
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(&msg.channel)
+        .cloned();
     if let Err(err) = maybe_apply_runtime_config_update(ctx.as_ref()).await {
         tracing::warn!("Failed to apply runtime config update: {err}");
     }
@@ -1138,6 +1656,48 @@ async fn process_channel_message(
         return;
     }
 
+    // Paused chats answer with a short notice instead of an agent run;
+    // `/resume` is still routed above so the chat can be un-paused.
+    let chat_paused = ctx
+        .paused_conversations
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .contains(&conversation_scope_key(&msg));
+    if chat_paused {
+        if let Some(channel) = target_channel.as_ref() {
+            let _ = channel
+                .send(
+                    &SendMessage::new(
+                        "⏸️ Agent is paused for this chat. Send /resume to continue.",
+                        &msg.reply_target,
+                    )
+                    .in_thread(msg.thread_ts.clone()),
+                )
+                .await;
+        }
+        return;
+    }
+
+    // Quota gate: enforced before any provider work so an exhausted scope
+    // costs nothing beyond the friendly reply.
+    if let Some(quota) = ctx.quota.as_ref() {
+        if let Some(exceeded) = quota.check(&msg.channel, &msg.sender) {
+            println!(
+                "  ⏳ Quota exceeded for {} (sender {}): {}",
+                msg.channel, msg.sender, exceeded.reason
+            );
+            if let Some(channel) = target_channel.as_ref() {
+                let _ = channel
+                    .send(
+                        &SendMessage::new(exceeded.user_message(), &msg.reply_target)
+                            .in_thread(msg.thread_ts.clone()),
+                    )
+                    .await;
+            }
+            return;
+        }
+    }
+
     let history_key = conversation_history_key(&msg);
     let route = get_route_selection(ctx.as_ref(), &history_key);
     let runtime_defaults = runtime_defaults_snapshot(ctx.as_ref());
@@ -1171,6 +1731,7 @@ async fn process_channel_message(
                 None,
             )
             .await;
+        crate::tools::capture_note::capture_from_autosave(&ctx.tools_registry, &msg.content).await;
     }
 
     println!("  ⏳ Processing message...");
@@ -1208,7 +1769,12 @@ async fn process_channel_message(
         }
     }
 
-    let system_prompt = build_channel_system_prompt(ctx.system_prompt.as_str(), &msg.channel);
+    let system_prompt = match target_channel.as_ref() {
+        Some(channel) => {
+            build_channel_system_prompt(ctx.system_prompt.as_str(), &channel.capabilities())
+        }
+        None => ctx.system_prompt.to_string(),
+    };
     let mut history = vec![ChatMessage::system(system_prompt)];
     history.extend(prior_turns);
     let use_streaming = target_channel
@@ -1277,6 +1843,29 @@ async fn process_channel_message(
         _ => None,
     };
 
+    // Optional progress updates: track the running tool via a per-message
+    // observer wrapper and report it on a slow interval during long tasks.
+    let current_tool: Arc<std::sync::Mutex<Option<String>>> = Arc::new(std::sync::Mutex::new(None));
+    let progress_observer = ProgressTrackingObserver {
+        inner: Arc::clone(&ctx.observer),
+        current_tool: Arc::clone(&current_tool),
+    };
+    let observer_for_loop: &dyn Observer = if ctx.progress_updates {
+        &progress_observer
+    } else {
+        ctx.observer.as_ref()
+    };
+    let progress_task = match (target_channel.as_ref(), typing_cancellation.as_ref()) {
+        (Some(channel), Some(token)) if ctx.progress_updates => Some(spawn_progress_update_task(
+            Arc::clone(channel),
+            msg.reply_target.clone(),
+            msg.thread_ts.clone(),
+            token.clone(),
+            Arc::clone(&current_tool),
+        )),
+        _ => None,
+    };
+
     // Record history length before tool loop so we can extract tool context after.
     let history_len_before_tools = history.len();
 
@@ -1285,8 +1874,30 @@ async fn process_channel_message(
         Cancelled,
     }
 
+    // Scope the tool registry to the sender's role when multi-user roles are
+    // configured ([[identity.users]]). Disallowed tools are omitted entirely,
+    // so the model never sees them for this sender.
+    let sender_role = crate::identity::resolve_role(&ctx.identity_users, &msg.channel, &msg.sender);
+    let role_scoped_tools =
+        sender_role.map(|role| crate::identity::role_scoped_tools(&ctx.tools_registry, role));
+    if let (Some(role), Some(scoped)) = (sender_role, role_scoped_tools.as_ref()) {
+        tracing::debug!(
+            channel = %msg.channel,
+            role = ?role,
+            tools = scoped.len(),
+            "Applied role-scoped tool registry for sender"
+        );
+    }
+    let tools_for_sender: &[Box<dyn Tool>] = role_scoped_tools
+        .as_deref()
+        .unwrap_or_else(|| ctx.tools_registry.as_ref());
+
     let timeout_budget_secs =
         channel_message_timeout_budget_secs(ctx.message_timeout_secs, ctx.max_tool_iterations);
+    // Attribute delegation events from this run to the originating channel
+    // (ambient slot, best-effort — see delegation_logger::set_current_channel),
+    // so `zeroclaw delegations channels` can break down cost per channel.
+    observability::delegation_logger::set_current_channel(Some(&msg.channel));
     let llm_result = tokio::select! {
         () = cancellation_token.cancelled() => LlmExecutionResult::Cancelled,
         result = tokio::time::timeout(
@@ -1294,8 +1905,8 @@ async fn process_channel_message(
             run_tool_call_loop(
                 active_provider.as_ref(),
                 &mut history,
-                ctx.tools_registry.as_ref(),
-                ctx.observer.as_ref(),
+                tools_for_sender,
+                observer_for_loop,
                 route.provider.as_str(),
                 route.model.as_str(),
                 runtime_defaults.temperature,
@@ -1310,6 +1921,7 @@ async fn process_channel_message(
             ),
         ) => LlmExecutionResult::Completed(result),
     };
+    observability::delegation_logger::set_current_channel(None);
 
     if let Some(handle) = draft_updater {
         let _ = handle.await;
@@ -1321,14 +1933,21 @@ async fn process_channel_message(
     if let Some(handle) = typing_task {
         log_worker_join_result(handle.await);
     }
+    if let Some(handle) = progress_task {
+        log_worker_join_result(handle.await);
+    }
 
     match llm_result {
         LlmExecutionResult::Cancelled => {
             tracing::info!(
                 channel = %msg.channel,
                 sender = %msg.sender,
-                "Cancelled in-flight channel request due to newer message"
+                "Cancelled in-flight channel request (newer message or /cancel)"
             );
+            ctx.observer
+                .record_event(&observability::ObserverEvent::RunCancelled {
+                    scope: msg.channel.clone(),
+                });
             if let (Some(channel), Some(draft_id)) =
                 (target_channel.as_ref(), draft_message_id.as_deref())
             {
@@ -1353,6 +1972,15 @@ async fn process_channel_message(
                 &history_key,
                 ChatMessage::assistant(&history_response),
             );
+            if let Some(quota) = ctx.quota.as_ref() {
+                quota.record(
+                    &msg.channel,
+                    &msg.sender,
+                    route.model.as_str(),
+                    crate::tokens::estimate_text(&msg.content) as u64,
+                    crate::tokens::estimate_text(&response) as u64,
+                );
+            }
             println!(
                 "  🤖 Reply ({}ms): {}",
                 started_at.elapsed().as_millis(),
@@ -1389,8 +2017,12 @@ async fn process_channel_message(
                 tracing::info!(
                     channel = %msg.channel,
                     sender = %msg.sender,
-                    "Cancelled in-flight channel request due to newer message"
+                    "Cancelled in-flight channel request (newer message or /cancel)"
                 );
+                ctx.observer
+                    .record_event(&observability::ObserverEvent::RunCancelled {
+                        scope: msg.channel.clone(),
+                    });
                 if let (Some(channel), Some(draft_id)) =
                     (target_channel.as_ref(), draft_message_id.as_deref())
                 {
@@ -1479,74 +2111,280 @@ async fn process_channel_message(
     }
 }
 
+/// Messages waiting behind a conversation's in-flight message, keyed by
+/// [`conversation_scope_key`]. Each entry keeps its shutdown guard so the
+/// drain window accounts for accepted-but-queued work.
+type ConversationBacklog = HashMap<
+    String,
+    VecDeque<(
+        traits::ChannelMessage,
+        crate::daemon::shutdown::RunGuard<'static>,
+    )>,
+>;
+
+/// Handle the inline `/cancel` command directly from the dispatch loop: in
+/// queued mode it would otherwise wait behind the very run it is meant to
+/// abort. Role gating matches the other operator-level inline commands.
+fn spawn_cancel_run_response(ctx: &Arc<ChannelRuntimeContext>, msg: &traits::ChannelMessage) {
+    let ctx = Arc::clone(ctx);
+    let msg = msg.clone();
+    tokio::spawn(async move {
+        let sender_role =
+            crate::identity::resolve_role(&ctx.identity_users, &msg.channel, &msg.sender);
+        let notice = if inline_command_allowed(sender_role, &ChannelRuntimeCommand::CancelRun) {
+            let in_flight = {
+                let active = ctx.active_runs.lock().unwrap_or_else(|e| e.into_inner());
+                active
+                    .get(&conversation_scope_key(&msg))
+                    .map(|(_, token)| token.clone())
+            };
+            match in_flight {
+                Some(token) => {
+                    token.cancel();
+                    "🛑 Cancelling the in-flight request for this chat.".to_string()
+                }
+                None => "Nothing to cancel — no request is in flight for this chat.".to_string(),
+            }
+        } else {
+            "⛔ This command requires the operator role.".to_string()
+        };
+
+        let channel = ctx
+            .channels_by_name
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(&msg.channel)
+            .cloned();
+        if let Some(channel) = channel {
+            if let Err(e) = channel
+                .send(&SendMessage::new(&notice, &msg.reply_target).in_thread(msg.thread_ts))
+                .await
+            {
+                tracing::debug!("Failed to send cancel response on {}: {e}", msg.channel);
+            }
+        }
+    });
+}
+
+/// Fire-and-forget "still working" notice for a message that just queued
+/// behind an in-flight request in the same conversation. Sent at most once
+/// per busy period (only when the backlog transitions from empty).
+fn spawn_queue_busy_notice(ctx: &Arc<ChannelRuntimeContext>, msg: &traits::ChannelMessage) {
+    let ctx = Arc::clone(ctx);
+    let channel_name = msg.channel.clone();
+    let reply_target = msg.reply_target.clone();
+    let thread_ts = msg.thread_ts.clone();
+    tokio::spawn(async move {
+        let channel = ctx
+            .channels_by_name
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(&channel_name)
+            .cloned();
+        if let Some(channel) = channel {
+            let notice =
+                "⏳ Still working on your previous request — this message is queued and will be handled next.";
+            if let Err(e) = channel
+                .send(&SendMessage::new(notice, &reply_target).in_thread(thread_ts))
+                .await
+            {
+                tracing::debug!("Failed to send queue-busy notice on {channel_name}: {e}");
+            }
+        }
+    });
+}
+
 async fn run_message_dispatch_loop(
     mut rx: tokio::sync::mpsc::Receiver<traits::ChannelMessage>,
     ctx: Arc<ChannelRuntimeContext>,
     max_in_flight_messages: usize,
+    dedup_ttl: Duration,
 ) {
     let semaphore = Arc::new(tokio::sync::Semaphore::new(max_in_flight_messages));
+    // Webhook redelivery guard: platforms resend events when an ack times
+    // out; repeats of the same platform event ID within the TTL are dropped
+    // here, the single ingress point for all channels.
+    let mut dedup_cache = dedup::EventDedupCache::new(dedup_ttl);
     let mut workers = tokio::task::JoinSet::new();
     let in_flight_by_sender = Arc::new(tokio::sync::Mutex::new(HashMap::<
         String,
         InFlightSenderTaskState,
     >::new()));
+    // Per-conversation FIFO backlog: while a chat has a message in flight,
+    // follow-ups queue behind it so replies cannot interleave within one
+    // conversation and one chatty chat occupies at most one worker slot.
+    let pending_by_conversation = Arc::new(tokio::sync::Mutex::new(ConversationBacklog::new()));
     let task_sequence = Arc::new(AtomicU64::new(1));
 
     while let Some(msg) = rx.recv().await {
-        let permit = match Arc::clone(&semaphore).acquire_owned().await {
-            Ok(permit) => permit,
-            Err(_) => break,
+        // Messages without a platform ID (some channels synthesize them)
+        // always pass through; a composed key would otherwise collide.
+        if !msg.id.is_empty() && dedup_cache.is_duplicate(&format!("{}:{}", msg.channel, msg.id)) {
+            tracing::info!(
+                channel = %msg.channel,
+                message_id = %msg.id,
+                "Dropping redelivered channel event within dedup window"
+            );
+            ctx.observer
+                .record_event(&observability::ObserverEvent::ChannelMessageDeduplicated {
+                    channel: msg.channel.clone(),
+                });
+            continue;
+        }
+
+        // Daemon shutdown: stop starting new agent runs so the drain window
+        // only has to wait for work that is already in flight.
+        let Some(run_guard) = crate::daemon::shutdown::coordinator().begin_run() else {
+            tracing::info!(
+                channel = %msg.channel,
+                "Draining for shutdown; dropping new channel message"
+            );
+            continue;
         };
 
-        let worker_ctx = Arc::clone(&ctx);
-        let in_flight = Arc::clone(&in_flight_by_sender);
-        let task_sequence = Arc::clone(&task_sequence);
-        workers.spawn(async move {
-            let _permit = permit;
-            let interrupt_enabled =
-                worker_ctx.interrupt_on_new_message && msg.channel == "telegram";
-            let sender_scope_key = interruption_scope_key(&msg);
-            let cancellation_token = CancellationToken::new();
-            let completion = Arc::new(InFlightTaskCompletion::new());
-            let task_id = task_sequence.fetch_add(1, Ordering::Relaxed);
-
-            if interrupt_enabled {
-                let previous = {
-                    let mut active = in_flight.lock().await;
-                    active.insert(
-                        sender_scope_key.clone(),
-                        InFlightSenderTaskState {
-                            task_id,
-                            cancellation: cancellation_token.clone(),
-                            completion: Arc::clone(&completion),
-                        },
-                    )
-                };
+        // `/cancel` must bypass the per-conversation backlog: queued behind
+        // the in-flight run it would only execute once that run finished.
+        if parse_runtime_command(&msg.channel, &msg.content)
+            == Some(ChannelRuntimeCommand::CancelRun)
+        {
+            spawn_cancel_run_response(&ctx, &msg);
+            continue;
+        }
 
-                if let Some(previous) = previous {
-                    tracing::info!(
+        // Telegram's interrupt-on-new-message mode replaces the in-flight
+        // request instead of queueing behind it, so it keeps the old path.
+        let interrupt_enabled = ctx.interrupt_on_new_message && msg.channel == "telegram";
+        let conversation_key = conversation_scope_key(&msg);
+        if !interrupt_enabled {
+            let mut pending = pending_by_conversation.lock().await;
+            if let Some(queue) = pending.get_mut(&conversation_key) {
+                if queue.len() >= CHANNEL_MAX_QUEUED_PER_CONVERSATION {
+                    tracing::warn!(
                         channel = %msg.channel,
                         sender = %msg.sender,
-                        "Interrupting previous in-flight request for sender"
+                        "Per-conversation backlog full; dropping channel message"
                     );
-                    previous.cancellation.cancel();
-                    previous.completion.wait().await;
+                } else {
+                    if ctx.queue_busy_notice && queue.is_empty() {
+                        spawn_queue_busy_notice(&ctx, &msg);
+                    }
+                    queue.push_back((msg, run_guard));
                 }
+                continue;
             }
+            pending.insert(conversation_key.clone(), VecDeque::new());
+        }
+
+        let permit = match Arc::clone(&semaphore).acquire_owned().await {
+            Ok(permit) => permit,
+            Err(_) => break,
+        };
+
+        let worker_ctx = Arc::clone(&ctx);
+        let in_flight = Arc::clone(&in_flight_by_sender);
+        let worker_pending = Arc::clone(&pending_by_conversation);
+        let task_sequence = Arc::clone(&task_sequence);
+        workers.spawn(async move {
+            let _permit = permit;
+            let mut current = Some((msg, run_guard));
+            while let Some((msg, run_guard)) = current.take() {
+                let _run_guard = run_guard;
+                let interrupt_enabled =
+                    worker_ctx.interrupt_on_new_message && msg.channel == "telegram";
+                let sender_scope_key = interruption_scope_key(&msg);
+                let conversation_key = conversation_scope_key(&msg);
+                let cancellation_token = CancellationToken::new();
+                let completion = Arc::new(InFlightTaskCompletion::new());
+                let task_id = task_sequence.fetch_add(1, Ordering::Relaxed);
+
+                if interrupt_enabled {
+                    let previous = {
+                        let mut active = in_flight.lock().await;
+                        active.insert(
+                            sender_scope_key.clone(),
+                            InFlightSenderTaskState {
+                                task_id,
+                                cancellation: cancellation_token.clone(),
+                                completion: Arc::clone(&completion),
+                            },
+                        )
+                    };
+
+                    if let Some(previous) = previous {
+                        tracing::info!(
+                            channel = %msg.channel,
+                            sender = %msg.sender,
+                            "Interrupting previous in-flight request for sender"
+                        );
+                        previous.cancellation.cancel();
+                        previous.completion.wait().await;
+                    }
+                }
+
+                // Expose this run's token so the inline `/cancel` command can
+                // abort it from the dispatch loop.
+                worker_ctx
+                    .active_runs
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .insert(
+                        conversation_key.clone(),
+                        (task_id, cancellation_token.clone()),
+                    );
 
-            process_channel_message(worker_ctx, msg, cancellation_token).await;
+                // Correlation span: every log line from this message's
+                // processing carries the channel (and delegation-log run_id)
+                // in JSON log mode.
+                let span = tracing::info_span!(
+                    "channel_message",
+                    channel = %msg.channel,
+                    run_id = worker_ctx.observer.run_id(),
+                );
+                process_channel_message(worker_ctx.clone(), msg, cancellation_token)
+                    .instrument(span)
+                    .await;
 
-            if interrupt_enabled {
-                let mut active = in_flight.lock().await;
-                if active
-                    .get(&sender_scope_key)
-                    .is_some_and(|state| state.task_id == task_id)
                 {
-                    active.remove(&sender_scope_key);
+                    let mut active = worker_ctx
+                        .active_runs
+                        .lock()
+                        .unwrap_or_else(|e| e.into_inner());
+                    if active
+                        .get(&conversation_key)
+                        .is_some_and(|(id, _)| *id == task_id)
+                    {
+                        active.remove(&conversation_key);
+                    }
                 }
-            }
 
-            completion.mark_done();
+                if interrupt_enabled {
+                    let mut active = in_flight.lock().await;
+                    if active
+                        .get(&sender_scope_key)
+                        .is_some_and(|state| state.task_id == task_id)
+                    {
+                        active.remove(&sender_scope_key);
+                    }
+                }
+
+                completion.mark_done();
+
+                if !interrupt_enabled {
+                    // Drain this conversation's backlog in the same worker so
+                    // its replies stay ordered; remove the entry once empty so
+                    // the next message dispatches a fresh worker.
+                    let mut pending = worker_pending.lock().await;
+                    match pending
+                        .get_mut(&conversation_key)
+                        .and_then(VecDeque::pop_front)
+                    {
+                        Some(next) => current = Some(next),
+                        None => {
+                            pending.remove(&conversation_key);
+                        }
+                    }
+                }
+            }
         });
 
         while let Some(result) = workers.try_join_next() {
@@ -1993,21 +2831,182 @@ pub async fn handle_command(command: crate::ChannelCommands, config: &Config) ->
         }
         crate::ChannelCommands::Add {
             channel_type,
-            config: _,
-        } => {
-            anyhow::bail!(
-                "Channel type '{channel_type}' — use `zeroclaw onboard` to configure channels"
-            );
-        }
-        crate::ChannelCommands::Remove { name } => {
-            anyhow::bail!("Remove channel '{name}' — edit ~/.zeroclaw/config.toml directly");
-        }
+            config: channel_json,
+        } => add_channel_from_json(config, &channel_type, &channel_json).await,
+        crate::ChannelCommands::Remove { name } => remove_channel_by_name(config, &name).await,
         crate::ChannelCommands::BindTelegram { identity } => {
             bind_telegram_identity(config, &identity).await
         }
     }
 }
 
+/// `zeroclaw channel add <type> <json>` — parse the JSON into the typed
+/// config section, persist it, then report whether a running daemon picked
+/// the change up.
+async fn add_channel_from_json(config: &Config, channel_type: &str, raw_json: &str) -> Result<()> {
+    fn parse<T: serde::de::DeserializeOwned>(channel_type: &str, raw: &str) -> Result<T> {
+        serde_json::from_str(raw)
+            .with_context(|| format!("Invalid {channel_type} channel config JSON"))
+    }
+
+    let mut updated = config.clone();
+    let name = match channel_type.to_ascii_lowercase().as_str() {
+        "telegram" => {
+            updated.channels_config.telegram = Some(parse(channel_type, raw_json)?);
+            "telegram"
+        }
+        "discord" => {
+            updated.channels_config.discord = Some(parse(channel_type, raw_json)?);
+            "discord"
+        }
+        "slack" => {
+            updated.channels_config.slack = Some(parse(channel_type, raw_json)?);
+            "slack"
+        }
+        "whatsapp" => {
+            updated.channels_config.whatsapp = Some(parse(channel_type, raw_json)?);
+            "whatsapp"
+        }
+        "matrix" => {
+            updated.channels_config.matrix = Some(parse(channel_type, raw_json)?);
+            "matrix"
+        }
+        "imessage" => {
+            updated.channels_config.imessage = Some(parse(channel_type, raw_json)?);
+            "imessage"
+        }
+        "email" => {
+            updated.channels_config.email = Some(parse(channel_type, raw_json)?);
+            "email"
+        }
+        other => anyhow::bail!(
+            "Unsupported channel type '{other}'. Supported: telegram, discord, slack, whatsapp, matrix, imessage, email"
+        ),
+    };
+
+    updated.save().await.context("Failed to save config")?;
+    println!(
+        "✅ Saved {name} channel config to {}",
+        updated.config_path.display()
+    );
+    report_channel_apply_result(config, name, true).await;
+    Ok(())
+}
+
+/// `zeroclaw channel remove <name>` — drop the named channel section from
+/// the config, persist it, then report whether a running daemon stopped the
+/// channel.
+async fn remove_channel_by_name(config: &Config, name: &str) -> Result<()> {
+    let mut updated = config.clone();
+    let cc = &mut updated.channels_config;
+    let normalized = name.to_ascii_lowercase();
+    let removed = match normalized.as_str() {
+        "telegram" => cc.telegram.take().is_some(),
+        "discord" => cc.discord.take().is_some(),
+        "slack" => cc.slack.take().is_some(),
+        "mattermost" => cc.mattermost.take().is_some(),
+        "webhook" => cc.webhook.take().is_some(),
+        "imessage" => cc.imessage.take().is_some(),
+        "matrix" => cc.matrix.take().is_some(),
+        "signal" => cc.signal.take().is_some(),
+        "whatsapp" => cc.whatsapp.take().is_some(),
+        "linq" => cc.linq.take().is_some(),
+        "email" => cc.email.take().is_some(),
+        "irc" => cc.irc.take().is_some(),
+        "lark" => cc.lark.take().is_some(),
+        "dingtalk" => cc.dingtalk.take().is_some(),
+        "qq" => cc.qq.take().is_some(),
+        other => anyhow::bail!("Unknown channel '{other}'. See `zeroclaw channel list`"),
+    };
+
+    if !removed {
+        anyhow::bail!("Channel '{normalized}' is not configured");
+    }
+
+    updated.save().await.context("Failed to save config")?;
+    println!(
+        "✅ Removed {normalized} channel config from {}",
+        updated.config_path.display()
+    );
+    report_channel_apply_result(config, &normalized, false).await;
+    Ok(())
+}
+
+/// After a `channel add`/`remove` config write, watch the daemon state file
+/// so the CLI can report whether a running daemon actually applied the
+/// change instead of asking the operator to restart and hope.
+///
+/// Best-effort: the config change is already persisted, so a missing or
+/// stale daemon only downgrades the message, never fails the command.
+async fn report_channel_apply_result(config: &Config, channel: &str, expect_running: bool) {
+    let state_path = crate::daemon::state_file_path(config);
+    if !daemon_state_is_fresh(&state_path).await {
+        println!(
+            "   No running daemon detected; the change takes effect on the next `zeroclaw daemon` or `zeroclaw channel start`."
+        );
+        return;
+    }
+
+    println!("   Running daemon detected; waiting for it to apply the change...");
+    let deadline =
+        tokio::time::Instant::now() + Duration::from_secs(CHANNEL_APPLY_REPORT_TIMEOUT_SECS);
+    while tokio::time::Instant::now() < deadline {
+        tokio::time::sleep(Duration::from_secs(2)).await;
+        match daemon_channel_component_status(&state_path, channel).await {
+            Some(status) if expect_running && status == "ok" => {
+                println!("   ✅ Running daemon applied the {channel} channel change.");
+                return;
+            }
+            Some(status) if !expect_running && status != "ok" => {
+                println!("   ✅ Running daemon stopped the {channel} channel.");
+                return;
+            }
+            None if !expect_running => {
+                println!("   ✅ Running daemon stopped the {channel} channel.");
+                return;
+            }
+            _ => {}
+        }
+    }
+    println!(
+        "   ⚠️ No confirmation from the daemon within {CHANNEL_APPLY_REPORT_TIMEOUT_SECS}s; check `zeroclaw status` (config change is saved either way)."
+    );
+}
+
+/// True when the daemon state file exists and was written recently enough to
+/// indicate a live daemon (the state writer flushes every few seconds).
+async fn daemon_state_is_fresh(state_path: &Path) -> bool {
+    const DAEMON_STATE_FRESH_SECS: u64 = 30;
+
+    let Ok(contents) = tokio::fs::read_to_string(state_path).await else {
+        return false;
+    };
+    let Ok(json) = serde_json::from_str::<serde_json::Value>(&contents) else {
+        return false;
+    };
+    let Some(written_at) = json.get("written_at").and_then(|v| v.as_str()) else {
+        return false;
+    };
+    let Ok(written_at) = chrono::DateTime::parse_from_rfc3339(written_at) else {
+        return false;
+    };
+    let age = chrono::Utc::now().signed_duration_since(written_at);
+    age.num_seconds() >= 0
+        && age.num_seconds() <= i64::try_from(DAEMON_STATE_FRESH_SECS).unwrap_or(i64::MAX)
+}
+
+/// Status string of the `channel:<name>` component in the daemon state file,
+/// or `None` when the component (or the file) is absent.
+async fn daemon_channel_component_status(state_path: &Path, channel: &str) -> Option<String> {
+    let contents = tokio::fs::read_to_string(state_path).await.ok()?;
+    let json = serde_json::from_str::<serde_json::Value>(&contents).ok()?;
+    json.get("components")?
+        .get(format!("channel:{channel}"))?
+        .get("status")?
+        .as_str()
+        .map(str::to_string)
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum ChannelHealthState {
     Healthy,
@@ -2025,6 +3024,74 @@ fn classify_health_result(
     }
 }
 
+/// Send a single message through a configured channel without starting
+/// listeners. Used by one-shot senders (cron announce delivery, infra
+/// summaries) that need to post a result and exit.
+pub async fn send_once(config: &Config, channel: &str, target: &str, content: &str) -> Result<()> {
+    match channel.to_ascii_lowercase().as_str() {
+        "telegram" => {
+            let tg = config
+                .channels_config
+                .telegram
+                .as_ref()
+                .ok_or_else(|| anyhow::anyhow!("telegram channel not configured"))?;
+            let channel = TelegramChannel::new(
+                tg.bot_token.clone(),
+                tg.allowed_users.clone(),
+                tg.mention_only,
+            );
+            channel.send(&SendMessage::new(content, target)).await?;
+        }
+        "discord" => {
+            let dc = config
+                .channels_config
+                .discord
+                .as_ref()
+                .ok_or_else(|| anyhow::anyhow!("discord channel not configured"))?;
+            let channel = DiscordChannel::new(
+                dc.bot_token.clone(),
+                dc.guild_id.clone(),
+                dc.allowed_users.clone(),
+                dc.listen_to_bots,
+                dc.mention_only,
+            );
+            channel.send(&SendMessage::new(content, target)).await?;
+        }
+        "slack" => {
+            let sl = config
+                .channels_config
+                .slack
+                .as_ref()
+                .ok_or_else(|| anyhow::anyhow!("slack channel not configured"))?;
+            let channel = SlackChannel::new(
+                sl.bot_token.clone(),
+                sl.channel_id.clone(),
+                sl.allowed_users.clone(),
+            );
+            channel.send(&SendMessage::new(content, target)).await?;
+        }
+        "mattermost" => {
+            let mm = config
+                .channels_config
+                .mattermost
+                .as_ref()
+                .ok_or_else(|| anyhow::anyhow!("mattermost channel not configured"))?;
+            let channel = MattermostChannel::new(
+                mm.url.clone(),
+                mm.bot_token.clone(),
+                mm.channel_id.clone(),
+                mm.allowed_users.clone(),
+                mm.thread_replies.unwrap_or(true),
+                mm.mention_only.unwrap_or(false),
+            );
+            channel.send(&SendMessage::new(content, target)).await?;
+        }
+        other => anyhow::bail!("unsupported delivery channel: {other}"),
+    }
+
+    Ok(())
+}
+
 /// Run health checks for configured channels.
 pub async fn doctor_channels(config: Config) -> Result<()> {
     let mut channels: Vec<(&'static str, Arc<dyn Channel>)> = Vec::new();
@@ -2261,222 +3328,62 @@ pub async fn doctor_channels(config: Config) -> Result<()> {
     Ok(())
 }
 
-/// Start all configured channels and route messages to the agent
+/// Construct channel instances for every configured channel section.
+///
+/// Pure over the config: callers (initial startup and the lifecycle
+/// manager's incremental applies) decide what to do with the result.
 #[allow(clippy::too_many_lines)]
-pub async fn start_channels(config: Config) -> Result<()> {
-    let provider_name = resolved_default_provider(&config);
-    let provider_runtime_options = providers::ProviderRuntimeOptions {
-        auth_profile_override: None,
-        zeroclaw_dir: config.config_path.parent().map(std::path::PathBuf::from),
-        secrets_encrypt: config.secrets.encrypt,
-        reasoning_enabled: config.runtime.reasoning_enabled,
-    };
-    let provider: Arc<dyn Provider> = Arc::from(providers::create_resilient_provider_with_options(
-        &provider_name,
-        config.api_key.as_deref(),
-        config.api_url.as_deref(),
-        &config.reliability,
-        &provider_runtime_options,
-    )?);
+fn build_runtime_channels(
+    channels_config: &crate::config::ChannelsConfig,
+) -> Vec<Arc<dyn Channel>> {
+    let mut channels: Vec<Arc<dyn Channel>> = Vec::new();
 
-    // Warm up the provider connection pool (TLS handshake, DNS, HTTP/2 setup)
-    // so the first real message doesn't hit a cold-start timeout.
-    if let Err(e) = provider.warmup().await {
-        tracing::warn!("Provider warmup failed (non-fatal): {e}");
+    if let Some(ref tg) = channels_config.telegram {
+        channels.push(Arc::new(
+            TelegramChannel::new(
+                tg.bot_token.clone(),
+                tg.allowed_users.clone(),
+                tg.mention_only,
+            )
+            .with_streaming(tg.stream_mode, tg.draft_update_interval_ms),
+        ));
     }
 
-    let initial_stamp = config_file_stamp(&config.config_path).await;
-    {
-        let mut store = runtime_config_store()
-            .lock()
-            .unwrap_or_else(|e| e.into_inner());
-        store.insert(
-            config.config_path.clone(),
-            RuntimeConfigState {
-                defaults: runtime_defaults_from_config(&config),
-                last_applied_stamp: initial_stamp,
-            },
-        );
+    if let Some(ref dc) = channels_config.discord {
+        channels.push(Arc::new(DiscordChannel::new(
+            dc.bot_token.clone(),
+            dc.guild_id.clone(),
+            dc.allowed_users.clone(),
+            dc.listen_to_bots,
+            dc.mention_only,
+        )));
     }
 
-    let observer: Arc<dyn Observer> =
-        Arc::from(observability::create_observer(&config.observability, config.delegation_log_path()));
-    let runtime: Arc<dyn runtime::RuntimeAdapter> =
-        Arc::from(runtime::create_runtime(&config.runtime)?);
-    let security = Arc::new(SecurityPolicy::from_config(
-        &config.autonomy,
-        &config.workspace_dir,
-    ));
-    let model = resolved_default_model(&config);
-    let temperature = config.default_temperature;
-    let mem: Arc<dyn Memory> = Arc::from(memory::create_memory_with_storage(
-        &config.memory,
-        Some(&config.storage.provider.config),
-        &config.workspace_dir,
-        config.api_key.as_deref(),
-    )?);
-    let (composio_key, composio_entity_id) = if config.composio.enabled {
-        (
-            config.composio.api_key.as_deref(),
-            Some(config.composio.entity_id.as_str()),
-        )
-    } else {
-        (None, None)
-    };
-    // Build system prompt from workspace identity files + skills
-    let workspace = config.workspace_dir.clone();
-    let tools_registry = Arc::new(tools::all_tools_with_runtime(
-        Arc::new(config.clone()),
-        &security,
-        runtime,
-        Arc::clone(&mem),
-        observer.clone(),
-        composio_key,
-        composio_entity_id,
-        &config.browser,
-        &config.http_request,
-        &workspace,
-        &config.agents,
-        config.api_key.as_deref(),
-        &config,
-    ));
+    if let Some(ref sl) = channels_config.slack {
+        channels.push(Arc::new(SlackChannel::new(
+            sl.bot_token.clone(),
+            sl.channel_id.clone(),
+            sl.allowed_users.clone(),
+        )));
+    }
 
-    let skills = crate::skills::load_skills_with_config(&workspace, &config);
+    if let Some(ref mm) = channels_config.mattermost {
+        channels.push(Arc::new(MattermostChannel::new(
+            mm.url.clone(),
+            mm.bot_token.clone(),
+            mm.channel_id.clone(),
+            mm.allowed_users.clone(),
+            mm.thread_replies.unwrap_or(true),
+            mm.mention_only.unwrap_or(false),
+        )));
+    }
 
-    // Collect tool descriptions for the prompt
-    let mut tool_descs: Vec<(&str, &str)> = vec![
-        (
-            "shell",
-            "Execute terminal commands. Use when: running local checks, build/test commands, diagnostics. Don't use when: a safer dedicated tool exists, or command is destructive without approval.",
-        ),
-        (
-            "file_read",
-            "Read file contents. Use when: inspecting project files, configs, logs. Don't use when: a targeted search is enough.",
-        ),
-        (
-            "file_write",
-            "Write file contents. Use when: applying focused edits, scaffolding files, updating docs/code. Don't use when: side effects are unclear or file ownership is uncertain.",
-        ),
-        (
-            "memory_store",
-            "Save to memory. Use when: preserving durable preferences, decisions, key context. Don't use when: information is transient/noisy/sensitive without need.",
-        ),
-        (
-            "memory_recall",
-            "Search memory. Use when: retrieving prior decisions, user preferences, historical context. Don't use when: answer is already in current context.",
-        ),
-        (
-            "memory_forget",
-            "Delete a memory entry. Use when: memory is incorrect/stale or explicitly requested for removal. Don't use when: impact is uncertain.",
-        ),
-    ];
-
-    if config.browser.enabled {
-        tool_descs.push((
-            "browser_open",
-            "Open approved HTTPS URLs in Brave Browser (allowlist-only, no scraping)",
-        ));
-    }
-    if config.composio.enabled {
-        tool_descs.push((
-            "composio",
-            "Execute actions on 1000+ apps via Composio (Gmail, Notion, GitHub, Slack, etc.). Use action='list' to discover actions, 'list_accounts' to retrieve connected account IDs, 'execute' to run (optionally with connected_account_id), and 'connect' for OAuth.",
-        ));
-    }
-    tool_descs.push((
-        "schedule",
-        "Manage scheduled tasks (create/list/get/cancel/pause/resume). Supports recurring cron and one-shot delays.",
-    ));
-    tool_descs.push((
-        "pushover",
-        "Send a Pushover notification to your device. Requires PUSHOVER_TOKEN and PUSHOVER_USER_KEY in .env file.",
-    ));
-    if !config.agents.is_empty() {
-        tool_descs.push((
-            "delegate",
-            "Delegate a subtask to a specialized agent. Use when: a task benefits from a different model (e.g. fast summarization, deep reasoning, code generation). The sub-agent runs a single prompt and returns its response.",
-        ));
-    }
-
-    let bootstrap_max_chars = if config.agent.compact_context {
-        Some(6000)
-    } else {
-        None
-    };
-    let native_tools = provider.supports_native_tools();
-    let mut system_prompt = build_system_prompt_with_mode(
-        &workspace,
-        &model,
-        &tool_descs,
-        &skills,
-        Some(&config.identity),
-        bootstrap_max_chars,
-        native_tools,
-    );
-    if !native_tools {
-        system_prompt.push_str(&build_tool_instructions(tools_registry.as_ref()));
-    }
-
-    if !skills.is_empty() {
-        println!(
-            "  🧩 Skills:   {}",
-            skills
-                .iter()
-                .map(|s| s.name.as_str())
-                .collect::<Vec<_>>()
-                .join(", ")
-        );
-    }
-
-    // Collect active channels
-    let mut channels: Vec<Arc<dyn Channel>> = Vec::new();
-
-    if let Some(ref tg) = config.channels_config.telegram {
-        channels.push(Arc::new(
-            TelegramChannel::new(
-                tg.bot_token.clone(),
-                tg.allowed_users.clone(),
-                tg.mention_only,
-            )
-            .with_streaming(tg.stream_mode, tg.draft_update_interval_ms),
-        ));
-    }
-
-    if let Some(ref dc) = config.channels_config.discord {
-        channels.push(Arc::new(DiscordChannel::new(
-            dc.bot_token.clone(),
-            dc.guild_id.clone(),
-            dc.allowed_users.clone(),
-            dc.listen_to_bots,
-            dc.mention_only,
-        )));
-    }
-
-    if let Some(ref sl) = config.channels_config.slack {
-        channels.push(Arc::new(SlackChannel::new(
-            sl.bot_token.clone(),
-            sl.channel_id.clone(),
-            sl.allowed_users.clone(),
-        )));
-    }
-
-    if let Some(ref mm) = config.channels_config.mattermost {
-        channels.push(Arc::new(MattermostChannel::new(
-            mm.url.clone(),
-            mm.bot_token.clone(),
-            mm.channel_id.clone(),
-            mm.allowed_users.clone(),
-            mm.thread_replies.unwrap_or(true),
-            mm.mention_only.unwrap_or(false),
-        )));
-    }
-
-    if let Some(ref im) = config.channels_config.imessage {
+    if let Some(ref im) = channels_config.imessage {
         channels.push(Arc::new(IMessageChannel::new(im.allowed_contacts.clone())));
     }
 
     #[cfg(feature = "channel-matrix")]
-    if let Some(ref mx) = config.channels_config.matrix {
+    if let Some(ref mx) = channels_config.matrix {
         channels.push(Arc::new(MatrixChannel::new_with_session_hint(
             mx.homeserver.clone(),
             mx.access_token.clone(),
@@ -2488,13 +3395,13 @@ pub async fn start_channels(config: Config) -> Result<()> {
     }
 
     #[cfg(not(feature = "channel-matrix"))]
-    if config.channels_config.matrix.is_some() {
+    if channels_config.matrix.is_some() {
         tracing::warn!(
             "Matrix channel is configured but this build was compiled without `channel-matrix`; skipping Matrix runtime startup."
         );
     }
 
-    if let Some(ref sig) = config.channels_config.signal {
+    if let Some(ref sig) = channels_config.signal {
         channels.push(Arc::new(SignalChannel::new(
             sig.http_url.clone(),
             sig.account.clone(),
@@ -2505,7 +3412,7 @@ pub async fn start_channels(config: Config) -> Result<()> {
         )));
     }
 
-    if let Some(ref wa) = config.channels_config.whatsapp {
+    if let Some(ref wa) = channels_config.whatsapp {
         if wa.is_ambiguous_config() {
             tracing::warn!(
                 "WhatsApp config has both phone_number_id and session_path set; preferring Cloud API mode. Remove one selector to avoid ambiguity."
@@ -2550,7 +3457,7 @@ pub async fn start_channels(config: Config) -> Result<()> {
         }
     }
 
-    if let Some(ref lq) = config.channels_config.linq {
+    if let Some(ref lq) = channels_config.linq {
         channels.push(Arc::new(LinqChannel::new(
             lq.api_token.clone(),
             lq.from_phone.clone(),
@@ -2558,11 +3465,11 @@ pub async fn start_channels(config: Config) -> Result<()> {
         )));
     }
 
-    if let Some(ref email_cfg) = config.channels_config.email {
+    if let Some(ref email_cfg) = channels_config.email {
         channels.push(Arc::new(EmailChannel::new(email_cfg.clone())));
     }
 
-    if let Some(ref irc) = config.channels_config.irc {
+    if let Some(ref irc) = channels_config.irc {
         channels.push(Arc::new(IrcChannel::new(irc::IrcChannelConfig {
             server: irc.server.clone(),
             port: irc.port,
@@ -2577,11 +3484,11 @@ pub async fn start_channels(config: Config) -> Result<()> {
         })));
     }
 
-    if let Some(ref lk) = config.channels_config.lark {
+    if let Some(ref lk) = channels_config.lark {
         channels.push(Arc::new(LarkChannel::from_config(lk)));
     }
 
-    if let Some(ref dt) = config.channels_config.dingtalk {
+    if let Some(ref dt) = channels_config.dingtalk {
         channels.push(Arc::new(DingTalkChannel::new(
             dt.client_id.clone(),
             dt.client_secret.clone(),
@@ -2589,7 +3496,7 @@ pub async fn start_channels(config: Config) -> Result<()> {
         )));
     }
 
-    if let Some(ref qq) = config.channels_config.qq {
+    if let Some(ref qq) = channels_config.qq {
         channels.push(Arc::new(QQChannel::new(
             qq.app_id.clone(),
             qq.app_secret.clone(),
@@ -2597,112 +3504,343 @@ pub async fn start_channels(config: Config) -> Result<()> {
         )));
     }
 
-    if channels.is_empty() {
-        println!("No channels configured. Run `zeroclaw onboard` to set up channels.");
-        return Ok(());
-    }
-
-    println!("🦀 ZeroClaw Channel Server");
-    println!("  🤖 Model:    {model}");
-    let effective_backend = memory::effective_memory_backend_name(
-        &config.memory.backend,
-        Some(&config.storage.provider.config),
-    );
-    println!(
-        "  🧠 Memory:   {} (auto-save: {})",
-        effective_backend,
-        if config.memory.auto_save { "on" } else { "off" }
-    );
-    println!(
-        "  📡 Channels: {}",
-        channels
-            .iter()
-            .map(|c| c.name())
-            .collect::<Vec<_>>()
-            .join(", ")
-    );
-    println!();
-    println!("  Listening for messages... (Ctrl+C to stop)");
-    println!();
-
-    crate::health::mark_component_ok("channels");
+    channels
+}
 
-    let initial_backoff_secs = config
-        .reliability
-        .channel_initial_backoff_secs
-        .max(DEFAULT_CHANNEL_INITIAL_BACKOFF_SECS);
-    let max_backoff_secs = config
-        .reliability
-        .channel_max_backoff_secs
-        .max(DEFAULT_CHANNEL_MAX_BACKOFF_SECS);
+/// Start all configured channels and route messages to the agent
+#[allow(clippy::too_many_lines)]
+pub async fn start_channels(config: Config) -> Result<()> {
+    crate::tools::truncation::set_policy(crate::tools::truncation::TruncationPolicy {
+        max_bytes: config.agent.tool_output_max_bytes,
+        max_lines: config.agent.tool_output_max_lines,
+        spill_dir: Some(config.workspace_dir.join("state").join("tool-output")),
+    });
+    attachments::set_inbox_dir(&config.workspace_dir);
+    let provider_name = resolved_default_provider(&config);
+    let provider_runtime_options = providers::ProviderRuntimeOptions {
+        auth_profile_override: None,
+        zeroclaw_dir: config.config_path.parent().map(std::path::PathBuf::from),
+        secrets_encrypt: config.secrets.encrypt,
+        reasoning_enabled: config.runtime.reasoning_enabled,
+    };
+    let provider: Arc<dyn Provider> = Arc::from(providers::create_resilient_provider_with_options(
+        &provider_name,
+        config.api_key.as_deref(),
+        config.api_url.as_deref(),
+        &config.reliability,
+        &provider_runtime_options,
+    )?);
 
-    // Single message bus — all channels send messages here
-    let (tx, rx) = tokio::sync::mpsc::channel::<traits::ChannelMessage>(100);
+    // Warm up the provider connection pool (TLS handshake, DNS, HTTP/2 setup)
+    // so the first real message doesn't hit a cold-start timeout.
+    if let Err(e) = provider.warmup().await {
+        tracing::warn!("Provider warmup failed (non-fatal): {e}");
+    }
 
-    // Spawn a listener for each channel
-    let mut handles = Vec::new();
-    for ch in &channels {
-        handles.push(spawn_supervised_listener(
-            ch.clone(),
-            tx.clone(),
-            initial_backoff_secs,
-            max_backoff_secs,
-        ));
+    let initial_stamp = config_file_stamp(&config.config_path).await;
+    {
+        let mut store = runtime_config_store()
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        store.insert(
+            config.config_path.clone(),
+            RuntimeConfigState {
+                defaults: runtime_defaults_from_config(&config),
+                last_applied_stamp: initial_stamp,
+            },
+        );
     }
-    drop(tx); // Drop our copy so rx closes when all channels stop
 
-    let channels_by_name = Arc::new(
-        channels
-            .iter()
-            .map(|ch| (ch.name().to_string(), Arc::clone(ch)))
-            .collect::<HashMap<_, _>>(),
+    let observer: Arc<dyn Observer> = Arc::from(observability::create_observer(
+        &config.observability,
+        &config.events,
+        config.delegation_log_path(),
+    ));
+    let runtime: Arc<dyn runtime::RuntimeAdapter> =
+        Arc::from(runtime::create_runtime(&config.runtime)?);
+    let security = Arc::new(
+        SecurityPolicy::from_config(&config.autonomy, &config.workspace_dir)
+            .with_audit(&config.security.audit, config.zeroclaw_dir()),
     );
-    let max_in_flight_messages = compute_max_in_flight_messages(channels.len());
-
-    println!("  🚦 In-flight message limit: {max_in_flight_messages}");
+    let model = resolved_default_model(&config);
+    let temperature = config.default_temperature;
+    let mem: Arc<dyn Memory> = Arc::from(memory::create_memory_with_storage(
+        &config.memory,
+        Some(&config.storage.provider.config),
+        &config.workspace_dir,
+        config.api_key.as_deref(),
+    )?);
+    let (composio_key, composio_entity_id) = if config.composio.enabled {
+        (
+            config.composio.api_key.as_deref(),
+            Some(config.composio.entity_id.as_str()),
+        )
+    } else {
+        (None, None)
+    };
+    // Build system prompt from workspace identity files + skills
+    let workspace = config.workspace_dir.clone();
+    let tools_registry = Arc::new(tools::all_tools_with_runtime(
+        Arc::new(config.clone()),
+        &security,
+        runtime,
+        Arc::clone(&mem),
+        observer.clone(),
+        composio_key,
+        composio_entity_id,
+        &config.browser,
+        &config.http_request,
+        &workspace,
+        &config.agents,
+        config.api_key.as_deref(),
+        &config,
+    ));
 
-    let mut provider_cache_seed: HashMap<String, Arc<dyn Provider>> = HashMap::new();
-    provider_cache_seed.insert(provider_name.clone(), Arc::clone(&provider));
-    let message_timeout_secs =
-        effective_channel_message_timeout_secs(config.channels_config.message_timeout_secs);
-    let interrupt_on_new_message = config
-        .channels_config
-        .telegram
-        .as_ref()
-        .is_some_and(|tg| tg.interrupt_on_new_message);
+    let skills = crate::skills::load_skills_with_config(&workspace, &config);
 
-    let runtime_ctx = Arc::new(ChannelRuntimeContext {
-        channels_by_name,
-        provider: Arc::clone(&provider),
-        default_provider: Arc::new(provider_name),
-        memory: Arc::clone(&mem),
-        tools_registry: Arc::clone(&tools_registry),
-        observer,
-        system_prompt: Arc::new(system_prompt),
-        model: Arc::new(model.clone()),
-        temperature,
-        auto_save_memory: config.memory.auto_save,
-        max_tool_iterations: config.agent.max_tool_iterations,
-        min_relevance_score: config.memory.min_relevance_score,
-        conversation_histories: Arc::new(Mutex::new(HashMap::new())),
-        provider_cache: Arc::new(Mutex::new(provider_cache_seed)),
-        route_overrides: Arc::new(Mutex::new(HashMap::new())),
-        api_key: config.api_key.clone(),
-        api_url: config.api_url.clone(),
-        reliability: Arc::new(config.reliability.clone()),
-        provider_runtime_options,
-        workspace_dir: Arc::new(config.workspace_dir.clone()),
-        message_timeout_secs,
-        interrupt_on_new_message,
+    // Collect tool descriptions for the prompt
+    let mut tool_descs: Vec<(&str, &str)> = vec![
+        (
+            "shell",
+            "Execute terminal commands. Use when: running local checks, build/test commands, diagnostics. Don't use when: a safer dedicated tool exists, or command is destructive without approval.",
+        ),
+        (
+            "file_read",
+            "Read file contents. Use when: inspecting project files, configs, logs. Don't use when: a targeted search is enough.",
+        ),
+        (
+            "file_write",
+            "Write file contents. Use when: applying focused edits, scaffolding files, updating docs/code. Don't use when: side effects are unclear or file ownership is uncertain.",
+        ),
+        (
+            "memory_store",
+            "Save to memory. Use when: preserving durable preferences, decisions, key context. Don't use when: information is transient/noisy/sensitive without need.",
+        ),
+        (
+            "memory_recall",
+            "Search memory. Use when: retrieving prior decisions, user preferences, historical context. Don't use when: answer is already in current context.",
+        ),
+        (
+            "memory_forget",
+            "Delete a memory entry. Use when: memory is incorrect/stale or explicitly requested for removal. Don't use when: impact is uncertain.",
+        ),
+    ];
+
+    if config.browser.enabled {
+        tool_descs.push((
+            "browser_open",
+            "Open approved HTTPS URLs in Brave Browser (allowlist-only, no scraping)",
+        ));
+    }
+    if config.composio.enabled {
+        tool_descs.push((
+            "composio",
+            "Execute actions on 1000+ apps via Composio (Gmail, Notion, GitHub, Slack, etc.). Use action='list' to discover actions, 'list_accounts' to retrieve connected account IDs, 'execute' to run (optionally with connected_account_id), and 'connect' for OAuth.",
+        ));
+    }
+    tool_descs.push((
+        "schedule",
+        "Manage scheduled tasks (create/list/get/cancel/pause/resume). Supports recurring cron and one-shot delays.",
+    ));
+    tool_descs.push((
+        "delegate_async",
+        "Enqueue a long-running task as a background job and return immediately with its id. Use when: a task is too slow to run inline. Set notify_channel/notify_to to deliver the result back to this chat.",
+    ));
+    tool_descs.push((
+        "pushover",
+        "Send a Pushover notification to your device. Requires PUSHOVER_TOKEN and PUSHOVER_USER_KEY in .env file.",
+    ));
+    if !config.agents.is_empty() {
+        tool_descs.push((
+            "delegate",
+            "Delegate a subtask to a specialized agent. Use when: a task benefits from a different model (e.g. fast summarization, deep reasoning, code generation). The sub-agent runs a single prompt and returns its response.",
+        ));
+    }
+
+    let bootstrap_max_chars = if config.agent.compact_context {
+        Some(6000)
+    } else {
+        None
+    };
+    let native_tools = provider.supports_native_tools();
+    let mut system_prompt = build_system_prompt_with_mode(
+        &workspace,
+        &model,
+        &tool_descs,
+        &skills,
+        Some(&config.identity),
+        bootstrap_max_chars,
+        native_tools,
+    );
+    if !native_tools {
+        system_prompt.push_str(&build_tool_instructions(tools_registry.as_ref()));
+    }
+
+    if !skills.is_empty() {
+        println!(
+            "  🧩 Skills:   {}",
+            skills
+                .iter()
+                .map(|s| s.name.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+    }
+
+    let initial_backoff_secs = config
+        .reliability
+        .channel_initial_backoff_secs
+        .max(DEFAULT_CHANNEL_INITIAL_BACKOFF_SECS);
+    let max_backoff_secs = config
+        .reliability
+        .channel_max_backoff_secs
+        .max(DEFAULT_CHANNEL_MAX_BACKOFF_SECS);
+
+    // Single message bus — all channels send messages here. The lifecycle
+    // manager keeps a sender clone so channels added to the config later can
+    // be attached; the dispatch loop runs until the server future is dropped.
+    let (tx, rx) = tokio::sync::mpsc::channel::<traits::ChannelMessage>(100);
+
+    let channels_by_name: SharedChannelMap = Arc::new(RwLock::new(HashMap::new()));
+    let lifecycle = Arc::new(ChannelLifecycleManager::new(
+        tx,
+        Arc::clone(&channels_by_name),
+        initial_backoff_secs,
+        max_backoff_secs,
+    ));
+    lifecycle.apply(&config.channels_config);
+
+    let channel_names = lifecycle.active_channel_names();
+    if channel_names.is_empty() {
+        println!("No channels configured. Run `zeroclaw onboard` to set up channels.");
+        return Ok(());
+    }
+
+    println!("🦀 ZeroClaw Channel Server");
+    println!("  🤖 Model:    {model}");
+    let effective_backend = memory::effective_memory_backend_name(
+        &config.memory.backend,
+        Some(&config.storage.provider.config),
+    );
+    println!(
+        "  🧠 Memory:   {} (auto-save: {})",
+        effective_backend,
+        if config.memory.auto_save { "on" } else { "off" }
+    );
+    println!("  📡 Channels: {}", channel_names.join(", "));
+    println!();
+    println!("  Listening for messages... (Ctrl+C to stop)");
+    println!();
+
+    crate::health::mark_component_ok("channels");
+
+    let max_in_flight_messages = compute_max_in_flight_messages(
+        channel_names.len(),
+        config.channels_config.max_concurrent_messages,
+    );
+
+    println!("  🚦 In-flight message limit: {max_in_flight_messages}");
+
+    let mut provider_cache_seed: HashMap<String, Arc<dyn Provider>> = HashMap::new();
+    provider_cache_seed.insert(provider_name.clone(), Arc::clone(&provider));
+    let message_timeout_secs =
+        effective_channel_message_timeout_secs(config.channels_config.message_timeout_secs);
+    let interrupt_on_new_message = config
+        .channels_config
+        .telegram
+        .as_ref()
+        .is_some_and(|tg| tg.interrupt_on_new_message);
+
+    let quota = crate::quota::QuotaTracker::from_config(
+        &config.quota,
+        &config.cost.prices,
+        &config.workspace_dir,
+    )
+    .map(Arc::new);
+    if quota.is_some() {
+        println!("  ⏳ Usage quotas: enabled");
+    }
+
+    let runtime_ctx = Arc::new(ChannelRuntimeContext {
+        identity_users: Arc::new(config.identity.users.clone()),
+        quota,
+        channels_by_name,
+        provider: Arc::clone(&provider),
+        default_provider: Arc::new(provider_name),
+        memory: Arc::clone(&mem),
+        tools_registry: Arc::clone(&tools_registry),
+        observer,
+        system_prompt: Arc::new(system_prompt),
+        model: Arc::new(model.clone()),
+        temperature,
+        auto_save_memory: config.memory.auto_save,
+        max_tool_iterations: config.agent.max_tool_iterations,
+        min_relevance_score: config.memory.min_relevance_score,
+        conversation_histories: Arc::new(Mutex::new(HashMap::new())),
+        provider_cache: Arc::new(Mutex::new(provider_cache_seed)),
+        route_overrides: Arc::new(Mutex::new(HashMap::new())),
+        api_key: config.api_key.clone(),
+        api_url: config.api_url.clone(),
+        reliability: Arc::new(config.reliability.clone()),
+        provider_runtime_options,
+        workspace_dir: Arc::new(config.workspace_dir.clone()),
+        message_timeout_secs,
+        interrupt_on_new_message,
+        queue_busy_notice: config.channels_config.queue_busy_notice,
+        progress_updates: config.channels_config.progress_updates,
+        paused_conversations: Arc::new(std::sync::Mutex::new(std::collections::HashSet::new())),
+        active_runs: Arc::new(std::sync::Mutex::new(HashMap::new())),
         multimodal: config.multimodal.clone(),
     });
 
-    run_message_dispatch_loop(rx, runtime_ctx, max_in_flight_messages).await;
+    // Re-apply channel config from disk so `zeroclaw channel add`/`remove`
+    // takes effect in a running server without a restart.
+    let config_poll = {
+        let lifecycle = Arc::clone(&lifecycle);
+        let config_path = config.config_path.clone();
+        tokio::spawn(async move {
+            let mut last_stamp = config_file_stamp(&config_path).await;
+            loop {
+                tokio::time::sleep(Duration::from_secs(CHANNEL_CONFIG_POLL_SECS)).await;
+                let Some(stamp) = config_file_stamp(&config_path).await else {
+                    continue;
+                };
+                if last_stamp == Some(stamp) {
+                    continue;
+                }
+                last_stamp = Some(stamp);
+                match load_channels_config_from_file(&config_path).await {
+                    Ok(channels_config) => {
+                        let report = lifecycle.apply(&channels_config);
+                        if !report.is_empty() {
+                            tracing::info!(
+                                started = ?report.started,
+                                stopped = ?report.stopped,
+                                restarted = ?report.restarted,
+                                "Applied channel config changes without restart"
+                            );
+                        }
+                    }
+                    Err(e) => {
+                        tracing::warn!(
+                            "Channel config reload failed; keeping current channels: {e}"
+                        );
+                    }
+                }
+            }
+        })
+    };
 
-    // Wait for all channel tasks
-    for h in handles {
-        let _ = h.await;
-    }
+    run_message_dispatch_loop(
+        rx,
+        runtime_ctx,
+        max_in_flight_messages,
+        Duration::from_secs(config.channels_config.dedup_ttl_secs),
+    )
+    .await;
+
+    config_poll.abort();
+    lifecycle.shutdown();
 
     Ok(())
 }
@@ -2847,7 +3985,9 @@ mod tests {
         );
 
         let ctx = ChannelRuntimeContext {
-            channels_by_name: Arc::new(HashMap::new()),
+            identity_users: Arc::new(Vec::new()),
+            quota: None,
+            channels_by_name: Arc::new(RwLock::new(HashMap::new())),
             provider: Arc::new(DummyProvider),
             default_provider: Arc::new("test-provider".to_string()),
             memory: Arc::new(NoopMemory),
@@ -2866,6 +4006,10 @@ mod tests {
             api_url: None,
             reliability: Arc::new(crate::config::ReliabilityConfig::default()),
             interrupt_on_new_message: false,
+            queue_busy_notice: false,
+            progress_updates: false,
+            paused_conversations: Arc::new(std::sync::Mutex::new(std::collections::HashSet::new())),
+            active_runs: Arc::new(std::sync::Mutex::new(HashMap::new())),
             multimodal: crate::config::MultimodalConfig::default(),
             provider_runtime_options: providers::ProviderRuntimeOptions::default(),
             workspace_dir: Arc::new(std::env::temp_dir()),
@@ -2923,6 +4067,15 @@ mod tests {
             "telegram"
         }
 
+        fn capabilities(&self) -> ChannelCapabilities {
+            ChannelCapabilities {
+                supports_images: true,
+                max_message_length: Some(4096),
+                markdown: MarkdownDialect::Standard,
+                ..ChannelCapabilities::default()
+            }
+        }
+
         async fn send(&self, message: &SendMessage) -> anyhow::Result<()> {
             self.sent_messages
                 .lock()
@@ -3267,7 +4420,9 @@ mod tests {
         channels_by_name.insert(channel.name().to_string(), channel);
 
         let runtime_ctx = Arc::new(ChannelRuntimeContext {
-            channels_by_name: Arc::new(channels_by_name),
+            identity_users: Arc::new(Vec::new()),
+            quota: None,
+            channels_by_name: Arc::new(RwLock::new(channels_by_name)),
             provider: Arc::new(ToolCallingProvider),
             default_provider: Arc::new("test-provider".to_string()),
             memory: Arc::new(NoopMemory),
@@ -3289,6 +4444,10 @@ mod tests {
             workspace_dir: Arc::new(std::env::temp_dir()),
             message_timeout_secs: CHANNEL_MESSAGE_TIMEOUT_SECS,
             interrupt_on_new_message: false,
+            queue_busy_notice: false,
+            progress_updates: false,
+            paused_conversations: Arc::new(std::sync::Mutex::new(std::collections::HashSet::new())),
+            active_runs: Arc::new(std::sync::Mutex::new(HashMap::new())),
             multimodal: crate::config::MultimodalConfig::default(),
         });
 
@@ -3324,7 +4483,9 @@ mod tests {
         channels_by_name.insert(channel.name().to_string(), channel);
 
         let runtime_ctx = Arc::new(ChannelRuntimeContext {
-            channels_by_name: Arc::new(channels_by_name),
+            identity_users: Arc::new(Vec::new()),
+            quota: None,
+            channels_by_name: Arc::new(RwLock::new(channels_by_name)),
             provider: Arc::new(ToolCallingAliasProvider),
             default_provider: Arc::new("test-provider".to_string()),
             memory: Arc::new(NoopMemory),
@@ -3346,6 +4507,10 @@ mod tests {
             workspace_dir: Arc::new(std::env::temp_dir()),
             message_timeout_secs: CHANNEL_MESSAGE_TIMEOUT_SECS,
             interrupt_on_new_message: false,
+            queue_busy_notice: false,
+            progress_updates: false,
+            paused_conversations: Arc::new(std::sync::Mutex::new(std::collections::HashSet::new())),
+            active_runs: Arc::new(std::sync::Mutex::new(HashMap::new())),
             multimodal: crate::config::MultimodalConfig::default(),
         });
 
@@ -3390,7 +4555,9 @@ mod tests {
         provider_cache_seed.insert("openrouter".to_string(), fallback_provider);
 
         let runtime_ctx = Arc::new(ChannelRuntimeContext {
-            channels_by_name: Arc::new(channels_by_name),
+            identity_users: Arc::new(Vec::new()),
+            quota: None,
+            channels_by_name: Arc::new(RwLock::new(channels_by_name)),
             provider: Arc::clone(&default_provider),
             default_provider: Arc::new("test-provider".to_string()),
             memory: Arc::new(NoopMemory),
@@ -3412,6 +4579,10 @@ mod tests {
             workspace_dir: Arc::new(std::env::temp_dir()),
             message_timeout_secs: CHANNEL_MESSAGE_TIMEOUT_SECS,
             interrupt_on_new_message: false,
+            queue_busy_notice: false,
+            progress_updates: false,
+            paused_conversations: Arc::new(std::sync::Mutex::new(std::collections::HashSet::new())),
+            active_runs: Arc::new(std::sync::Mutex::new(HashMap::new())),
             multimodal: crate::config::MultimodalConfig::default(),
         });
 
@@ -3477,7 +4648,9 @@ mod tests {
         );
 
         let runtime_ctx = Arc::new(ChannelRuntimeContext {
-            channels_by_name: Arc::new(channels_by_name),
+            identity_users: Arc::new(Vec::new()),
+            quota: None,
+            channels_by_name: Arc::new(RwLock::new(channels_by_name)),
             provider: Arc::clone(&default_provider),
             default_provider: Arc::new("test-provider".to_string()),
             memory: Arc::new(NoopMemory),
@@ -3499,6 +4672,10 @@ mod tests {
             workspace_dir: Arc::new(std::env::temp_dir()),
             message_timeout_secs: CHANNEL_MESSAGE_TIMEOUT_SECS,
             interrupt_on_new_message: false,
+            queue_busy_notice: false,
+            progress_updates: false,
+            paused_conversations: Arc::new(std::sync::Mutex::new(std::collections::HashSet::new())),
+            active_runs: Arc::new(std::sync::Mutex::new(HashMap::new())),
             multimodal: crate::config::MultimodalConfig::default(),
         });
 
@@ -3546,7 +4723,9 @@ mod tests {
         provider_cache_seed.insert("test-provider".to_string(), reloaded_provider);
 
         let runtime_ctx = Arc::new(ChannelRuntimeContext {
-            channels_by_name: Arc::new(channels_by_name),
+            identity_users: Arc::new(Vec::new()),
+            quota: None,
+            channels_by_name: Arc::new(RwLock::new(channels_by_name)),
             provider: Arc::clone(&startup_provider),
             default_provider: Arc::new("test-provider".to_string()),
             memory: Arc::new(NoopMemory),
@@ -3568,6 +4747,10 @@ mod tests {
             workspace_dir: Arc::new(std::env::temp_dir()),
             message_timeout_secs: CHANNEL_MESSAGE_TIMEOUT_SECS,
             interrupt_on_new_message: false,
+            queue_busy_notice: false,
+            progress_updates: false,
+            paused_conversations: Arc::new(std::sync::Mutex::new(std::collections::HashSet::new())),
+            active_runs: Arc::new(std::sync::Mutex::new(HashMap::new())),
             multimodal: crate::config::MultimodalConfig::default(),
         });
 
@@ -3627,7 +4810,9 @@ mod tests {
         }
 
         let runtime_ctx = Arc::new(ChannelRuntimeContext {
-            channels_by_name: Arc::new(channels_by_name),
+            identity_users: Arc::new(Vec::new()),
+            quota: None,
+            channels_by_name: Arc::new(RwLock::new(channels_by_name)),
             provider: Arc::clone(&provider),
             default_provider: Arc::new("test-provider".to_string()),
             memory: Arc::new(NoopMemory),
@@ -3652,6 +4837,10 @@ mod tests {
             workspace_dir: Arc::new(std::env::temp_dir()),
             message_timeout_secs: CHANNEL_MESSAGE_TIMEOUT_SECS,
             interrupt_on_new_message: false,
+            queue_busy_notice: false,
+            progress_updates: false,
+            paused_conversations: Arc::new(std::sync::Mutex::new(std::collections::HashSet::new())),
+            active_runs: Arc::new(std::sync::Mutex::new(HashMap::new())),
             multimodal: crate::config::MultimodalConfig::default(),
         });
 
@@ -3697,7 +4886,9 @@ mod tests {
         channels_by_name.insert(channel.name().to_string(), channel);
 
         let runtime_ctx = Arc::new(ChannelRuntimeContext {
-            channels_by_name: Arc::new(channels_by_name),
+            identity_users: Arc::new(Vec::new()),
+            quota: None,
+            channels_by_name: Arc::new(RwLock::new(channels_by_name)),
             provider: Arc::new(IterativeToolProvider {
                 required_tool_iterations: 11,
             }),
@@ -3721,6 +4912,10 @@ mod tests {
             workspace_dir: Arc::new(std::env::temp_dir()),
             message_timeout_secs: CHANNEL_MESSAGE_TIMEOUT_SECS,
             interrupt_on_new_message: false,
+            queue_busy_notice: false,
+            progress_updates: false,
+            paused_conversations: Arc::new(std::sync::Mutex::new(std::collections::HashSet::new())),
+            active_runs: Arc::new(std::sync::Mutex::new(HashMap::new())),
             multimodal: crate::config::MultimodalConfig::default(),
         });
 
@@ -3755,7 +4950,9 @@ mod tests {
         channels_by_name.insert(channel.name().to_string(), channel);
 
         let runtime_ctx = Arc::new(ChannelRuntimeContext {
-            channels_by_name: Arc::new(channels_by_name),
+            identity_users: Arc::new(Vec::new()),
+            quota: None,
+            channels_by_name: Arc::new(RwLock::new(channels_by_name)),
             provider: Arc::new(IterativeToolProvider {
                 required_tool_iterations: 20,
             }),
@@ -3779,144 +4976,499 @@ mod tests {
             workspace_dir: Arc::new(std::env::temp_dir()),
             message_timeout_secs: CHANNEL_MESSAGE_TIMEOUT_SECS,
             interrupt_on_new_message: false,
+            queue_busy_notice: false,
+            progress_updates: false,
+            paused_conversations: Arc::new(std::sync::Mutex::new(std::collections::HashSet::new())),
+            active_runs: Arc::new(std::sync::Mutex::new(HashMap::new())),
+            multimodal: crate::config::MultimodalConfig::default(),
+        });
+
+        process_channel_message(
+            runtime_ctx,
+            traits::ChannelMessage {
+                id: "msg-iter-fail".to_string(),
+                sender: "bob".to_string(),
+                reply_target: "chat-iter-fail".to_string(),
+                content: "Loop forever".to_string(),
+                channel: "test-channel".to_string(),
+                timestamp: 2,
+                thread_ts: None,
+            },
+            CancellationToken::new(),
+        )
+        .await;
+
+        let sent_messages = channel_impl.sent_messages.lock().await;
+        assert_eq!(sent_messages.len(), 1);
+        assert!(sent_messages[0].starts_with("chat-iter-fail:"));
+        assert!(sent_messages[0].contains("⚠️ Error: Agent exceeded maximum tool iterations (3)"));
+    }
+
+    struct NoopMemory;
+
+    #[async_trait::async_trait]
+    impl Memory for NoopMemory {
+        fn name(&self) -> &str {
+            "noop"
+        }
+
+        async fn store(
+            &self,
+            _key: &str,
+            _content: &str,
+            _category: crate::memory::MemoryCategory,
+            _session_id: Option<&str>,
+        ) -> anyhow::Result<()> {
+            Ok(())
+        }
+
+        async fn recall(
+            &self,
+            _query: &str,
+            _limit: usize,
+            _session_id: Option<&str>,
+        ) -> anyhow::Result<Vec<crate::memory::MemoryEntry>> {
+            Ok(Vec::new())
+        }
+
+        async fn get(&self, _key: &str) -> anyhow::Result<Option<crate::memory::MemoryEntry>> {
+            Ok(None)
+        }
+
+        async fn list(
+            &self,
+            _category: Option<&crate::memory::MemoryCategory>,
+            _session_id: Option<&str>,
+        ) -> anyhow::Result<Vec<crate::memory::MemoryEntry>> {
+            Ok(Vec::new())
+        }
+
+        async fn forget(&self, _key: &str) -> anyhow::Result<bool> {
+            Ok(false)
+        }
+
+        async fn count(&self) -> anyhow::Result<usize> {
+            Ok(0)
+        }
+
+        async fn health_check(&self) -> bool {
+            true
+        }
+    }
+
+    struct RecallMemory;
+
+    #[async_trait::async_trait]
+    impl Memory for RecallMemory {
+        fn name(&self) -> &str {
+            "recall-memory"
+        }
+
+        async fn store(
+            &self,
+            _key: &str,
+            _content: &str,
+            _category: crate::memory::MemoryCategory,
+            _session_id: Option<&str>,
+        ) -> anyhow::Result<()> {
+            Ok(())
+        }
+
+        async fn recall(
+            &self,
+            _query: &str,
+            _limit: usize,
+            _session_id: Option<&str>,
+        ) -> anyhow::Result<Vec<crate::memory::MemoryEntry>> {
+            Ok(vec![crate::memory::MemoryEntry {
+                id: "entry-1".to_string(),
+                key: "memory_key_1".to_string(),
+                content: "Age is 45".to_string(),
+                category: crate::memory::MemoryCategory::Conversation,
+                timestamp: "2026-02-20T00:00:00Z".to_string(),
+                session_id: None,
+                score: Some(0.9),
+            }])
+        }
+
+        async fn get(&self, _key: &str) -> anyhow::Result<Option<crate::memory::MemoryEntry>> {
+            Ok(None)
+        }
+
+        async fn list(
+            &self,
+            _category: Option<&crate::memory::MemoryCategory>,
+            _session_id: Option<&str>,
+        ) -> anyhow::Result<Vec<crate::memory::MemoryEntry>> {
+            Ok(Vec::new())
+        }
+
+        async fn forget(&self, _key: &str) -> anyhow::Result<bool> {
+            Ok(false)
+        }
+
+        async fn count(&self) -> anyhow::Result<usize> {
+            Ok(1)
+        }
+
+        async fn health_check(&self) -> bool {
+            true
+        }
+    }
+
+    #[tokio::test]
+    async fn message_dispatch_processes_messages_in_parallel() {
+        let channel_impl = Arc::new(RecordingChannel::default());
+        let channel: Arc<dyn Channel> = channel_impl.clone();
+
+        let mut channels_by_name = HashMap::new();
+        channels_by_name.insert(channel.name().to_string(), channel);
+
+        let runtime_ctx = Arc::new(ChannelRuntimeContext {
+            identity_users: Arc::new(Vec::new()),
+            quota: None,
+            channels_by_name: Arc::new(RwLock::new(channels_by_name)),
+            provider: Arc::new(SlowProvider {
+                delay: Duration::from_millis(250),
+            }),
+            default_provider: Arc::new("test-provider".to_string()),
+            memory: Arc::new(NoopMemory),
+            tools_registry: Arc::new(vec![]),
+            observer: Arc::new(NoopObserver),
+            system_prompt: Arc::new("test-system-prompt".to_string()),
+            model: Arc::new("test-model".to_string()),
+            temperature: 0.0,
+            auto_save_memory: false,
+            max_tool_iterations: 10,
+            min_relevance_score: 0.0,
+            conversation_histories: Arc::new(Mutex::new(HashMap::new())),
+            provider_cache: Arc::new(Mutex::new(HashMap::new())),
+            route_overrides: Arc::new(Mutex::new(HashMap::new())),
+            api_key: None,
+            api_url: None,
+            reliability: Arc::new(crate::config::ReliabilityConfig::default()),
+            provider_runtime_options: providers::ProviderRuntimeOptions::default(),
+            workspace_dir: Arc::new(std::env::temp_dir()),
+            message_timeout_secs: CHANNEL_MESSAGE_TIMEOUT_SECS,
+            interrupt_on_new_message: false,
+            queue_busy_notice: false,
+            progress_updates: false,
+            paused_conversations: Arc::new(std::sync::Mutex::new(std::collections::HashSet::new())),
+            active_runs: Arc::new(std::sync::Mutex::new(HashMap::new())),
+            multimodal: crate::config::MultimodalConfig::default(),
+        });
+
+        let (tx, rx) = tokio::sync::mpsc::channel::<traits::ChannelMessage>(4);
+        tx.send(traits::ChannelMessage {
+            id: "1".to_string(),
+            sender: "alice".to_string(),
+            reply_target: "alice".to_string(),
+            content: "hello".to_string(),
+            channel: "test-channel".to_string(),
+            timestamp: 1,
+            thread_ts: None,
+        })
+        .await
+        .unwrap();
+        tx.send(traits::ChannelMessage {
+            id: "2".to_string(),
+            sender: "bob".to_string(),
+            reply_target: "bob".to_string(),
+            content: "world".to_string(),
+            channel: "test-channel".to_string(),
+            timestamp: 2,
+            thread_ts: None,
+        })
+        .await
+        .unwrap();
+        drop(tx);
+
+        let started = Instant::now();
+        run_message_dispatch_loop(rx, runtime_ctx, 2, Duration::ZERO).await;
+        let elapsed = started.elapsed();
+
+        assert!(
+            elapsed < Duration::from_millis(430),
+            "expected parallel dispatch (<430ms), got {:?}",
+            elapsed
+        );
+
+        let sent_messages = channel_impl.sent_messages.lock().await;
+        assert_eq!(sent_messages.len(), 2);
+    }
+
+    #[test]
+    fn progress_tracking_observer_tracks_running_tool() {
+        let current_tool = Arc::new(std::sync::Mutex::new(None));
+        let observer = ProgressTrackingObserver {
+            inner: Arc::new(NoopObserver),
+            current_tool: Arc::clone(&current_tool),
+        };
+
+        observer.record_event(&observability::ObserverEvent::ToolCallStart {
+            tool: "shell".to_string(),
+        });
+        assert_eq!(
+            current_tool.lock().unwrap().as_deref(),
+            Some("shell"),
+            "tool start should be tracked"
+        );
+
+        observer.record_event(&observability::ObserverEvent::ToolCall {
+            tool: "shell".to_string(),
+            duration: Duration::from_millis(10),
+            success: true,
+            output_bytes: Some(4),
+        });
+        assert_eq!(
+            *current_tool.lock().unwrap(),
+            None,
+            "tool completion should clear the tracked tool"
+        );
+    }
+
+    #[test]
+    fn parse_runtime_command_routes_inline_commands() {
+        assert!(matches!(
+            parse_runtime_command("slack", "/status"),
+            Some(ChannelRuntimeCommand::ShowStatus)
+        ));
+        assert!(matches!(
+            parse_runtime_command("telegram", "/cost today"),
+            Some(ChannelRuntimeCommand::ShowCost)
+        ));
+        assert!(matches!(
+            parse_runtime_command("discord", "/cron list"),
+            Some(ChannelRuntimeCommand::CronList)
+        ));
+        // Other cron operations go through the agent so tool-level role
+        // scoping applies.
+        assert!(parse_runtime_command("discord", "/cron add").is_none());
+        assert!(matches!(
+            parse_runtime_command("telegram", "/pause"),
+            Some(ChannelRuntimeCommand::Pause)
+        ));
+        assert!(matches!(
+            parse_runtime_command("telegram", "/resume"),
+            Some(ChannelRuntimeCommand::Resume)
+        ));
+        assert!(matches!(
+            parse_runtime_command("slack", "/cancel"),
+            Some(ChannelRuntimeCommand::CancelRun)
+        ));
+        // Channels without inline command support pass slash text to the agent.
+        assert!(parse_runtime_command("irc", "/status").is_none());
+        assert!(parse_runtime_command("irc", "/cancel").is_none());
+        // Model switching stays limited to telegram/discord.
+        assert!(parse_runtime_command("slack", "/models").is_none());
+    }
+
+    #[test]
+    fn inline_command_permissions_follow_roles() {
+        use crate::config::UserRole;
+
+        assert!(
+            inline_command_allowed(None, &ChannelRuntimeCommand::Pause),
+            "disabled role system leaves allowlists as the only gate"
+        );
+        assert!(inline_command_allowed(
+            Some(UserRole::Viewer),
+            &ChannelRuntimeCommand::ShowStatus
+        ));
+        assert!(
+            !inline_command_allowed(Some(UserRole::Viewer), &ChannelRuntimeCommand::Pause),
+            "viewers must not pause the chat"
+        );
+        assert!(
+            !inline_command_allowed(Some(UserRole::Viewer), &ChannelRuntimeCommand::CancelRun),
+            "viewers must not cancel the chat's in-flight run"
+        );
+        assert!(inline_command_allowed(
+            Some(UserRole::Operator),
+            &ChannelRuntimeCommand::Resume
+        ));
+        assert!(inline_command_allowed(
+            Some(UserRole::Admin),
+            &ChannelRuntimeCommand::Pause
+        ));
+    }
+
+    #[test]
+    fn compute_max_in_flight_messages_prefers_configured_limit() {
+        assert_eq!(compute_max_in_flight_messages(2, 3), 3);
+        assert_eq!(compute_max_in_flight_messages(2, 128), 128);
+        // 0 = auto: scale with channel count within the clamp band.
+        assert_eq!(
+            compute_max_in_flight_messages(1, 0),
+            CHANNEL_MIN_IN_FLIGHT_MESSAGES
+        );
+        assert_eq!(
+            compute_max_in_flight_messages(100, 0),
+            CHANNEL_MAX_IN_FLIGHT_MESSAGES
+        );
+    }
+
+    #[tokio::test]
+    async fn message_dispatch_preserves_per_sender_ordering() {
+        let channel_impl = Arc::new(RecordingChannel::default());
+        let channel: Arc<dyn Channel> = channel_impl.clone();
+
+        let mut channels_by_name = HashMap::new();
+        channels_by_name.insert(channel.name().to_string(), channel);
+
+        let runtime_ctx = Arc::new(ChannelRuntimeContext {
+            identity_users: Arc::new(Vec::new()),
+            quota: None,
+            channels_by_name: Arc::new(RwLock::new(channels_by_name)),
+            provider: Arc::new(SlowProvider {
+                delay: Duration::from_millis(150),
+            }),
+            default_provider: Arc::new("test-provider".to_string()),
+            memory: Arc::new(NoopMemory),
+            tools_registry: Arc::new(vec![]),
+            observer: Arc::new(NoopObserver),
+            system_prompt: Arc::new("test-system-prompt".to_string()),
+            model: Arc::new("test-model".to_string()),
+            temperature: 0.0,
+            auto_save_memory: false,
+            max_tool_iterations: 10,
+            min_relevance_score: 0.0,
+            conversation_histories: Arc::new(Mutex::new(HashMap::new())),
+            provider_cache: Arc::new(Mutex::new(HashMap::new())),
+            route_overrides: Arc::new(Mutex::new(HashMap::new())),
+            api_key: None,
+            api_url: None,
+            reliability: Arc::new(crate::config::ReliabilityConfig::default()),
+            provider_runtime_options: providers::ProviderRuntimeOptions::default(),
+            workspace_dir: Arc::new(std::env::temp_dir()),
+            message_timeout_secs: CHANNEL_MESSAGE_TIMEOUT_SECS,
+            interrupt_on_new_message: false,
+            queue_busy_notice: false,
+            progress_updates: false,
+            paused_conversations: Arc::new(std::sync::Mutex::new(std::collections::HashSet::new())),
+            active_runs: Arc::new(std::sync::Mutex::new(HashMap::new())),
+            multimodal: crate::config::MultimodalConfig::default(),
+        });
+
+        let (tx, rx) = tokio::sync::mpsc::channel::<traits::ChannelMessage>(4);
+        tx.send(traits::ChannelMessage {
+            id: "1".to_string(),
+            sender: "alice".to_string(),
+            reply_target: "alice".to_string(),
+            content: "first message".to_string(),
+            channel: "test-channel".to_string(),
+            timestamp: 1,
+            thread_ts: None,
+        })
+        .await
+        .unwrap();
+        tx.send(traits::ChannelMessage {
+            id: "2".to_string(),
+            sender: "alice".to_string(),
+            reply_target: "alice".to_string(),
+            content: "second message".to_string(),
+            channel: "test-channel".to_string(),
+            timestamp: 2,
+            thread_ts: None,
+        })
+        .await
+        .unwrap();
+        drop(tx);
+
+        let started = Instant::now();
+        run_message_dispatch_loop(rx, runtime_ctx, 4, Duration::ZERO).await;
+        let elapsed = started.elapsed();
+
+        // Same sender, same chat: messages must run sequentially even though
+        // the worker pool has spare capacity.
+        assert!(
+            elapsed >= Duration::from_millis(280),
+            "expected sequential dispatch for one sender (>=280ms), got {:?}",
+            elapsed
+        );
+
+        let sent_messages = channel_impl.sent_messages.lock().await;
+        assert_eq!(sent_messages.len(), 2);
+        assert!(
+            sent_messages[0].contains("first message"),
+            "first reply out of order: {:?}",
+            *sent_messages
+        );
+        assert!(
+            sent_messages[1].contains("second message"),
+            "second reply out of order: {:?}",
+            *sent_messages
+        );
+    }
+
+    #[tokio::test]
+    async fn message_dispatch_drops_redelivered_event_ids() {
+        let channel_impl = Arc::new(RecordingChannel::default());
+        let channel: Arc<dyn Channel> = channel_impl.clone();
+
+        let mut channels_by_name = HashMap::new();
+        channels_by_name.insert(channel.name().to_string(), channel);
+
+        let runtime_ctx = Arc::new(ChannelRuntimeContext {
+            identity_users: Arc::new(Vec::new()),
+            quota: None,
+            channels_by_name: Arc::new(RwLock::new(channels_by_name)),
+            provider: Arc::new(DummyProvider),
+            default_provider: Arc::new("test-provider".to_string()),
+            memory: Arc::new(NoopMemory),
+            tools_registry: Arc::new(vec![]),
+            observer: Arc::new(NoopObserver),
+            system_prompt: Arc::new("test-system-prompt".to_string()),
+            model: Arc::new("test-model".to_string()),
+            temperature: 0.0,
+            auto_save_memory: false,
+            max_tool_iterations: 10,
+            min_relevance_score: 0.0,
+            conversation_histories: Arc::new(Mutex::new(HashMap::new())),
+            provider_cache: Arc::new(Mutex::new(HashMap::new())),
+            route_overrides: Arc::new(Mutex::new(HashMap::new())),
+            api_key: None,
+            api_url: None,
+            reliability: Arc::new(crate::config::ReliabilityConfig::default()),
+            provider_runtime_options: providers::ProviderRuntimeOptions::default(),
+            workspace_dir: Arc::new(std::env::temp_dir()),
+            message_timeout_secs: CHANNEL_MESSAGE_TIMEOUT_SECS,
+            interrupt_on_new_message: false,
+            queue_busy_notice: false,
+            progress_updates: false,
+            paused_conversations: Arc::new(std::sync::Mutex::new(std::collections::HashSet::new())),
+            active_runs: Arc::new(std::sync::Mutex::new(HashMap::new())),
             multimodal: crate::config::MultimodalConfig::default(),
         });
 
-        process_channel_message(
-            runtime_ctx,
-            traits::ChannelMessage {
-                id: "msg-iter-fail".to_string(),
-                sender: "bob".to_string(),
-                reply_target: "chat-iter-fail".to_string(),
-                content: "Loop forever".to_string(),
-                channel: "test-channel".to_string(),
-                timestamp: 2,
-                thread_ts: None,
-            },
-            CancellationToken::new(),
-        )
-        .await;
-
-        let sent_messages = channel_impl.sent_messages.lock().await;
-        assert_eq!(sent_messages.len(), 1);
-        assert!(sent_messages[0].starts_with("chat-iter-fail:"));
-        assert!(sent_messages[0].contains("⚠️ Error: Agent exceeded maximum tool iterations (3)"));
-    }
-
-    struct NoopMemory;
-
-    #[async_trait::async_trait]
-    impl Memory for NoopMemory {
-        fn name(&self) -> &str {
-            "noop"
-        }
-
-        async fn store(
-            &self,
-            _key: &str,
-            _content: &str,
-            _category: crate::memory::MemoryCategory,
-            _session_id: Option<&str>,
-        ) -> anyhow::Result<()> {
-            Ok(())
-        }
-
-        async fn recall(
-            &self,
-            _query: &str,
-            _limit: usize,
-            _session_id: Option<&str>,
-        ) -> anyhow::Result<Vec<crate::memory::MemoryEntry>> {
-            Ok(Vec::new())
-        }
-
-        async fn get(&self, _key: &str) -> anyhow::Result<Option<crate::memory::MemoryEntry>> {
-            Ok(None)
-        }
-
-        async fn list(
-            &self,
-            _category: Option<&crate::memory::MemoryCategory>,
-            _session_id: Option<&str>,
-        ) -> anyhow::Result<Vec<crate::memory::MemoryEntry>> {
-            Ok(Vec::new())
-        }
-
-        async fn forget(&self, _key: &str) -> anyhow::Result<bool> {
-            Ok(false)
-        }
-
-        async fn count(&self) -> anyhow::Result<usize> {
-            Ok(0)
-        }
-
-        async fn health_check(&self) -> bool {
-            true
-        }
-    }
-
-    struct RecallMemory;
-
-    #[async_trait::async_trait]
-    impl Memory for RecallMemory {
-        fn name(&self) -> &str {
-            "recall-memory"
-        }
-
-        async fn store(
-            &self,
-            _key: &str,
-            _content: &str,
-            _category: crate::memory::MemoryCategory,
-            _session_id: Option<&str>,
-        ) -> anyhow::Result<()> {
-            Ok(())
-        }
-
-        async fn recall(
-            &self,
-            _query: &str,
-            _limit: usize,
-            _session_id: Option<&str>,
-        ) -> anyhow::Result<Vec<crate::memory::MemoryEntry>> {
-            Ok(vec![crate::memory::MemoryEntry {
-                id: "entry-1".to_string(),
-                key: "memory_key_1".to_string(),
-                content: "Age is 45".to_string(),
-                category: crate::memory::MemoryCategory::Conversation,
-                timestamp: "2026-02-20T00:00:00Z".to_string(),
-                session_id: None,
-                score: Some(0.9),
-            }])
-        }
-
-        async fn get(&self, _key: &str) -> anyhow::Result<Option<crate::memory::MemoryEntry>> {
-            Ok(None)
-        }
-
-        async fn list(
-            &self,
-            _category: Option<&crate::memory::MemoryCategory>,
-            _session_id: Option<&str>,
-        ) -> anyhow::Result<Vec<crate::memory::MemoryEntry>> {
-            Ok(Vec::new())
-        }
+        let redelivered = traits::ChannelMessage {
+            id: "evt-1".to_string(),
+            sender: "zeroclaw_user".to_string(),
+            reply_target: "zeroclaw_user".to_string(),
+            content: "hello".to_string(),
+            channel: "test-channel".to_string(),
+            timestamp: 1,
+            thread_ts: None,
+        };
 
-        async fn forget(&self, _key: &str) -> anyhow::Result<bool> {
-            Ok(false)
-        }
+        let (tx, rx) = tokio::sync::mpsc::channel::<traits::ChannelMessage>(4);
+        tx.send(redelivered.clone()).await.unwrap();
+        tx.send(redelivered).await.unwrap();
+        drop(tx);
 
-        async fn count(&self) -> anyhow::Result<usize> {
-            Ok(1)
-        }
+        run_message_dispatch_loop(rx, runtime_ctx, 4, Duration::from_secs(60)).await;
 
-        async fn health_check(&self) -> bool {
-            true
-        }
+        let sent_messages = channel_impl.sent_messages.lock().await;
+        assert_eq!(
+            sent_messages.len(),
+            1,
+            "redelivered event must not trigger a second agent run: {:?}",
+            *sent_messages
+        );
     }
 
     #[tokio::test]
-    async fn message_dispatch_processes_messages_in_parallel() {
+    async fn message_dispatch_serializes_same_chat_and_sends_busy_notice() {
         let channel_impl = Arc::new(RecordingChannel::default());
         let channel: Arc<dyn Channel> = channel_impl.clone();
 
@@ -3924,9 +5476,11 @@ mod tests {
         channels_by_name.insert(channel.name().to_string(), channel);
 
         let runtime_ctx = Arc::new(ChannelRuntimeContext {
-            channels_by_name: Arc::new(channels_by_name),
+            identity_users: Arc::new(Vec::new()),
+            quota: None,
+            channels_by_name: Arc::new(RwLock::new(channels_by_name)),
             provider: Arc::new(SlowProvider {
-                delay: Duration::from_millis(250),
+                delay: Duration::from_millis(150),
             }),
             default_provider: Arc::new("test-provider".to_string()),
             memory: Arc::new(NoopMemory),
@@ -3948,15 +5502,21 @@ mod tests {
             workspace_dir: Arc::new(std::env::temp_dir()),
             message_timeout_secs: CHANNEL_MESSAGE_TIMEOUT_SECS,
             interrupt_on_new_message: false,
+            queue_busy_notice: true,
+            progress_updates: false,
+            paused_conversations: Arc::new(std::sync::Mutex::new(std::collections::HashSet::new())),
+            active_runs: Arc::new(std::sync::Mutex::new(HashMap::new())),
             multimodal: crate::config::MultimodalConfig::default(),
         });
 
+        // Two different senders in the same chat: replies must not interleave,
+        // and the second message should trigger one "still working" notice.
         let (tx, rx) = tokio::sync::mpsc::channel::<traits::ChannelMessage>(4);
         tx.send(traits::ChannelMessage {
             id: "1".to_string(),
             sender: "alice".to_string(),
-            reply_target: "alice".to_string(),
-            content: "hello".to_string(),
+            reply_target: "group-1".to_string(),
+            content: "first message".to_string(),
             channel: "test-channel".to_string(),
             timestamp: 1,
             thread_ts: None,
@@ -3966,8 +5526,8 @@ mod tests {
         tx.send(traits::ChannelMessage {
             id: "2".to_string(),
             sender: "bob".to_string(),
-            reply_target: "bob".to_string(),
-            content: "world".to_string(),
+            reply_target: "group-1".to_string(),
+            content: "second message".to_string(),
             channel: "test-channel".to_string(),
             timestamp: 2,
             thread_ts: None,
@@ -3977,17 +5537,40 @@ mod tests {
         drop(tx);
 
         let started = Instant::now();
-        run_message_dispatch_loop(rx, runtime_ctx, 2).await;
+        run_message_dispatch_loop(rx, runtime_ctx, 4, Duration::ZERO).await;
         let elapsed = started.elapsed();
 
         assert!(
-            elapsed < Duration::from_millis(430),
-            "expected parallel dispatch (<430ms), got {:?}",
+            elapsed >= Duration::from_millis(280),
+            "expected sequential dispatch within one chat (>=280ms), got {:?}",
             elapsed
         );
 
         let sent_messages = channel_impl.sent_messages.lock().await;
-        assert_eq!(sent_messages.len(), 2);
+        let notices: Vec<_> = sent_messages
+            .iter()
+            .filter(|m| m.contains("Still working on your previous request"))
+            .collect();
+        assert_eq!(
+            notices.len(),
+            1,
+            "expected one busy notice: {sent_messages:?}"
+        );
+        let replies: Vec<_> = sent_messages
+            .iter()
+            .filter(|m| !m.contains("Still working on your previous request"))
+            .collect();
+        assert_eq!(replies.len(), 2);
+        assert!(
+            replies[0].contains("first message"),
+            "first reply out of order: {:?}",
+            *sent_messages
+        );
+        assert!(
+            replies[1].contains("second message"),
+            "second reply out of order: {:?}",
+            *sent_messages
+        );
     }
 
     #[tokio::test]
@@ -4004,7 +5587,9 @@ mod tests {
         });
 
         let runtime_ctx = Arc::new(ChannelRuntimeContext {
-            channels_by_name: Arc::new(channels_by_name),
+            identity_users: Arc::new(Vec::new()),
+            quota: None,
+            channels_by_name: Arc::new(RwLock::new(channels_by_name)),
             provider: provider_impl.clone(),
             default_provider: Arc::new("test-provider".to_string()),
             memory: Arc::new(NoopMemory),
@@ -4026,6 +5611,10 @@ mod tests {
             workspace_dir: Arc::new(std::env::temp_dir()),
             message_timeout_secs: CHANNEL_MESSAGE_TIMEOUT_SECS,
             interrupt_on_new_message: true,
+            queue_busy_notice: false,
+            progress_updates: false,
+            paused_conversations: Arc::new(std::sync::Mutex::new(std::collections::HashSet::new())),
+            active_runs: Arc::new(std::sync::Mutex::new(HashMap::new())),
             multimodal: crate::config::MultimodalConfig::default(),
         });
 
@@ -4056,7 +5645,7 @@ mod tests {
             .unwrap();
         });
 
-        run_message_dispatch_loop(rx, runtime_ctx, 4).await;
+        run_message_dispatch_loop(rx, runtime_ctx, 4, Duration::ZERO).await;
         send_task.await.unwrap();
 
         let sent_messages = channel_impl.sent_messages.lock().await;
@@ -4092,7 +5681,9 @@ mod tests {
         channels_by_name.insert(channel.name().to_string(), channel);
 
         let runtime_ctx = Arc::new(ChannelRuntimeContext {
-            channels_by_name: Arc::new(channels_by_name),
+            identity_users: Arc::new(Vec::new()),
+            quota: None,
+            channels_by_name: Arc::new(RwLock::new(channels_by_name)),
             provider: Arc::new(SlowProvider {
                 delay: Duration::from_millis(180),
             }),
@@ -4116,6 +5707,10 @@ mod tests {
             workspace_dir: Arc::new(std::env::temp_dir()),
             message_timeout_secs: CHANNEL_MESSAGE_TIMEOUT_SECS,
             interrupt_on_new_message: true,
+            queue_busy_notice: false,
+            progress_updates: false,
+            paused_conversations: Arc::new(std::sync::Mutex::new(std::collections::HashSet::new())),
+            active_runs: Arc::new(std::sync::Mutex::new(HashMap::new())),
             multimodal: crate::config::MultimodalConfig::default(),
         });
 
@@ -4146,7 +5741,7 @@ mod tests {
             .unwrap();
         });
 
-        run_message_dispatch_loop(rx, runtime_ctx, 4).await;
+        run_message_dispatch_loop(rx, runtime_ctx, 4, Duration::ZERO).await;
         send_task.await.unwrap();
 
         let sent_messages = channel_impl.sent_messages.lock().await;
@@ -4164,7 +5759,9 @@ mod tests {
         channels_by_name.insert(channel.name().to_string(), channel);
 
         let runtime_ctx = Arc::new(ChannelRuntimeContext {
-            channels_by_name: Arc::new(channels_by_name),
+            identity_users: Arc::new(Vec::new()),
+            quota: None,
+            channels_by_name: Arc::new(RwLock::new(channels_by_name)),
             provider: Arc::new(SlowProvider {
                 delay: Duration::from_millis(20),
             }),
@@ -4188,6 +5785,10 @@ mod tests {
             workspace_dir: Arc::new(std::env::temp_dir()),
             message_timeout_secs: CHANNEL_MESSAGE_TIMEOUT_SECS,
             interrupt_on_new_message: false,
+            queue_busy_notice: false,
+            progress_updates: false,
+            paused_conversations: Arc::new(std::sync::Mutex::new(std::collections::HashSet::new())),
+            active_runs: Arc::new(std::sync::Mutex::new(HashMap::new())),
             multimodal: crate::config::MultimodalConfig::default(),
         });
 
@@ -4506,6 +6107,58 @@ mod tests {
         );
     }
 
+    #[test]
+    fn capability_instructions_plain_channel_discourages_markdown() {
+        let instructions = capability_instructions(&ChannelCapabilities::default());
+
+        assert!(instructions.contains("plain text only"));
+        assert!(instructions.contains("cannot deliver file or image attachments"));
+    }
+
+    #[test]
+    fn capability_instructions_image_channel_includes_media_markers() {
+        let caps = ChannelCapabilities {
+            supports_images: true,
+            max_message_length: Some(4096),
+            markdown: MarkdownDialect::Standard,
+            ..ChannelCapabilities::default()
+        };
+        let instructions = capability_instructions(&caps);
+
+        assert!(instructions.contains("[IMAGE:<path-or-url>]"));
+        assert!(instructions.contains("4096 characters"));
+        assert!(
+            !instructions.contains("plain text only"),
+            "standard-markdown channels need no formatting restriction"
+        );
+    }
+
+    #[test]
+    fn capability_instructions_slack_dialect_describes_mrkdwn() {
+        let caps = ChannelCapabilities {
+            markdown: MarkdownDialect::Slack,
+            ..ChannelCapabilities::default()
+        };
+
+        assert!(capability_instructions(&caps).contains("mrkdwn"));
+    }
+
+    #[test]
+    fn build_channel_system_prompt_appends_capability_guidance() {
+        let prompt = build_channel_system_prompt("Base prompt.", &ChannelCapabilities::default());
+
+        assert!(prompt.starts_with("Base prompt.\n\n"));
+        assert!(prompt.contains("plain text only"));
+    }
+
+    #[test]
+    fn build_channel_system_prompt_empty_base_is_instructions_only() {
+        let prompt = build_channel_system_prompt("", &ChannelCapabilities::default());
+
+        assert!(!prompt.starts_with('\n'));
+        assert!(prompt.contains("plain text only"));
+    }
+
     #[test]
     fn prompt_workspace_path() {
         let ws = make_workspace();
@@ -4627,7 +6280,9 @@ mod tests {
         let provider_impl = Arc::new(HistoryCaptureProvider::default());
 
         let runtime_ctx = Arc::new(ChannelRuntimeContext {
-            channels_by_name: Arc::new(channels_by_name),
+            identity_users: Arc::new(Vec::new()),
+            quota: None,
+            channels_by_name: Arc::new(RwLock::new(channels_by_name)),
             provider: provider_impl.clone(),
             default_provider: Arc::new("test-provider".to_string()),
             memory: Arc::new(NoopMemory),
@@ -4649,6 +6304,10 @@ mod tests {
             workspace_dir: Arc::new(std::env::temp_dir()),
             message_timeout_secs: CHANNEL_MESSAGE_TIMEOUT_SECS,
             interrupt_on_new_message: false,
+            queue_busy_notice: false,
+            progress_updates: false,
+            paused_conversations: Arc::new(std::sync::Mutex::new(std::collections::HashSet::new())),
+            active_runs: Arc::new(std::sync::Mutex::new(HashMap::new())),
             multimodal: crate::config::MultimodalConfig::default(),
         });
 
@@ -4710,7 +6369,9 @@ mod tests {
 
         let provider_impl = Arc::new(HistoryCaptureProvider::default());
         let runtime_ctx = Arc::new(ChannelRuntimeContext {
-            channels_by_name: Arc::new(channels_by_name),
+            identity_users: Arc::new(Vec::new()),
+            quota: None,
+            channels_by_name: Arc::new(RwLock::new(channels_by_name)),
             provider: provider_impl.clone(),
             default_provider: Arc::new("test-provider".to_string()),
             memory: Arc::new(RecallMemory),
@@ -4732,6 +6393,10 @@ mod tests {
             workspace_dir: Arc::new(std::env::temp_dir()),
             message_timeout_secs: CHANNEL_MESSAGE_TIMEOUT_SECS,
             interrupt_on_new_message: false,
+            queue_busy_notice: false,
+            progress_updates: false,
+            paused_conversations: Arc::new(std::sync::Mutex::new(std::collections::HashSet::new())),
+            active_runs: Arc::new(std::sync::Mutex::new(HashMap::new())),
             multimodal: crate::config::MultimodalConfig::default(),
         });
 
@@ -4793,7 +6458,9 @@ mod tests {
         );
 
         let runtime_ctx = Arc::new(ChannelRuntimeContext {
-            channels_by_name: Arc::new(channels_by_name),
+            identity_users: Arc::new(Vec::new()),
+            quota: None,
+            channels_by_name: Arc::new(RwLock::new(channels_by_name)),
             provider: provider_impl.clone(),
             default_provider: Arc::new("test-provider".to_string()),
             memory: Arc::new(NoopMemory),
@@ -4815,6 +6482,10 @@ mod tests {
             workspace_dir: Arc::new(std::env::temp_dir()),
             message_timeout_secs: CHANNEL_MESSAGE_TIMEOUT_SECS,
             interrupt_on_new_message: false,
+            queue_busy_notice: false,
+            progress_updates: false,
+            paused_conversations: Arc::new(std::sync::Mutex::new(std::collections::HashSet::new())),
+            active_runs: Arc::new(std::sync::Mutex::new(HashMap::new())),
             multimodal: crate::config::MultimodalConfig::default(),
         });
 
@@ -4846,10 +6517,8 @@ mod tests {
             .collect::<Vec<_>>();
         assert_eq!(roles, vec!["system", "user", "assistant", "user"]);
         assert!(
-            calls[0][0]
-                .1
-                .contains("When responding on Telegram, include media markers"),
-            "telegram delivery instruction should live in the system prompt"
+            calls[0][0].1.contains("[IMAGE:<path-or-url>]"),
+            "media-marker delivery instruction should live in the system prompt"
         );
         assert!(!calls[0].iter().skip(1).any(|(role, _)| role == "system"));
     }
@@ -4941,6 +6610,7 @@ Mon Feb 20
 
         // Create identity config pointing to the file
         let config = IdentityConfig {
+            users: Vec::new(),
             format: "aieos".into(),
             aieos_path: Some("aieos_identity.json".into()),
             aieos_inline: None,
@@ -4975,6 +6645,7 @@ Mon Feb 20
         use crate::config::IdentityConfig;
 
         let config = IdentityConfig {
+            users: Vec::new(),
             format: "aieos".into(),
             aieos_path: None,
             aieos_inline: Some(r#"{"identity":{"names":{"first":"Claw"}}}"#.into()),
@@ -4998,6 +6669,7 @@ Mon Feb 20
         use crate::config::IdentityConfig;
 
         let config = IdentityConfig {
+            users: Vec::new(),
             format: "aieos".into(),
             aieos_path: Some("nonexistent.json".into()),
             aieos_inline: None,
@@ -5017,6 +6689,7 @@ Mon Feb 20
 
         // Format is "aieos" but neither path nor inline is set
         let config = IdentityConfig {
+            users: Vec::new(),
             format: "aieos".into(),
             aieos_path: None,
             aieos_inline: None,
@@ -5035,6 +6708,7 @@ Mon Feb 20
         use crate::config::IdentityConfig;
 
         let config = IdentityConfig {
+            users: Vec::new(),
             format: "openclaw".into(),
             aieos_path: Some("identity.json".into()),
             aieos_inline: None,
@@ -5159,6 +6833,56 @@ Mon Feb 20
         assert!(calls.load(Ordering::SeqCst) >= 1);
     }
 
+    struct PanickingChannel {
+        name: &'static str,
+        calls: Arc<AtomicUsize>,
+    }
+
+    #[async_trait::async_trait]
+    impl Channel for PanickingChannel {
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        async fn send(&self, _message: &SendMessage) -> anyhow::Result<()> {
+            Ok(())
+        }
+
+        async fn listen(
+            &self,
+            _tx: tokio::sync::mpsc::Sender<traits::ChannelMessage>,
+        ) -> anyhow::Result<()> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            panic!("listener kaboom")
+        }
+    }
+
+    #[tokio::test]
+    async fn supervised_listener_contains_panics_and_restarts() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let channel: Arc<dyn Channel> = Arc::new(PanickingChannel {
+            name: "test-supervised-panic",
+            calls: Arc::clone(&calls),
+        });
+
+        let (tx, rx) = tokio::sync::mpsc::channel::<traits::ChannelMessage>(1);
+        let handle = spawn_supervised_listener(channel, tx, 1, 1);
+
+        tokio::time::sleep(Duration::from_millis(80)).await;
+        drop(rx);
+        handle.abort();
+        let _ = handle.await;
+
+        let snapshot = crate::health::snapshot_json();
+        let component = &snapshot["components"]["channel:test-supervised-panic"];
+        assert_eq!(component["status"], "error");
+        assert!(component["restart_count"].as_u64().unwrap_or(0) >= 1);
+        let last_error = component["last_error"].as_str().unwrap_or("");
+        assert!(last_error.contains("listener panicked"));
+        assert!(last_error.contains("listener kaboom"));
+        assert!(calls.load(Ordering::SeqCst) >= 1);
+    }
+
     #[tokio::test]
     async fn supervised_listener_refreshes_health_while_running() {
         let calls = Arc::new(AtomicUsize::new(0));
@@ -5203,4 +6927,110 @@ Mon Feb 20
         assert!(join.is_ok(), "listener should stop after channel shutdown");
         assert!(calls.load(Ordering::SeqCst) >= 1);
     }
+
+    fn telegram_section(token: &str) -> crate::config::TelegramConfig {
+        crate::config::TelegramConfig {
+            bot_token: token.to_string(),
+            allowed_users: vec!["zeroclaw_user".to_string()],
+            stream_mode: crate::config::StreamMode::default(),
+            draft_update_interval_ms: 1000,
+            interrupt_on_new_message: false,
+            mention_only: false,
+        }
+    }
+
+    #[test]
+    fn channel_config_fingerprint_tracks_token_rebind() {
+        let mut channels_config = crate::config::ChannelsConfig::default();
+        assert_eq!(
+            channel_config_fingerprint(&channels_config, "telegram"),
+            None
+        );
+
+        channels_config.telegram = Some(telegram_section("token-a"));
+        let before = channel_config_fingerprint(&channels_config, "telegram");
+        assert!(before.is_some());
+
+        channels_config.telegram = Some(telegram_section("token-b"));
+        let after = channel_config_fingerprint(&channels_config, "telegram");
+        assert!(after.is_some());
+        assert_ne!(before, after);
+
+        assert_eq!(
+            channel_config_fingerprint(&channels_config, "discord"),
+            None
+        );
+        assert_eq!(
+            channel_config_fingerprint(&channels_config, "unknown"),
+            None
+        );
+    }
+
+    #[test]
+    fn build_runtime_channels_builds_configured_sections_only() {
+        let empty = crate::config::ChannelsConfig::default();
+        assert!(build_runtime_channels(&empty).is_empty());
+
+        let channels_config = crate::config::ChannelsConfig {
+            telegram: Some(telegram_section("token-a")),
+            slack: Some(crate::config::SlackConfig {
+                bot_token: "xoxb-test".to_string(),
+                app_token: None,
+                channel_id: None,
+                allowed_users: vec![],
+            }),
+            ..Default::default()
+        };
+
+        let names: Vec<String> = build_runtime_channels(&channels_config)
+            .iter()
+            .map(|ch| ch.name().to_string())
+            .collect();
+        assert_eq!(names.len(), 2);
+        assert!(names.contains(&"telegram".to_string()));
+        assert!(names.contains(&"slack".to_string()));
+    }
+
+    #[tokio::test]
+    async fn lifecycle_manager_applies_add_change_and_remove_incrementally() {
+        let (tx, _rx) = tokio::sync::mpsc::channel::<traits::ChannelMessage>(8);
+        let channels_by_name: SharedChannelMap = Arc::new(RwLock::new(HashMap::new()));
+        let manager = ChannelLifecycleManager::new(tx, Arc::clone(&channels_by_name), 1, 1);
+
+        // iMessage keeps this test fully local: its listener fails fast on
+        // non-macOS hosts and the supervisor just backs off.
+        let mut channels_config = crate::config::ChannelsConfig {
+            imessage: Some(crate::config::IMessageConfig {
+                allowed_contacts: vec!["zeroclaw_user".to_string()],
+            }),
+            ..Default::default()
+        };
+
+        let report = manager.apply(&channels_config);
+        assert_eq!(report.started, vec!["imessage".to_string()]);
+        assert!(report.stopped.is_empty() && report.restarted.is_empty());
+        assert_eq!(manager.active_channel_names(), vec!["imessage".to_string()]);
+        assert!(channels_by_name.read().unwrap().contains_key("imessage"));
+
+        // Unchanged config is a no-op.
+        let report = manager.apply(&channels_config);
+        assert!(report.is_empty());
+
+        // In-place section change restarts the listener with new settings.
+        channels_config.imessage = Some(crate::config::IMessageConfig {
+            allowed_contacts: vec!["zeroclaw_operator".to_string()],
+        });
+        let report = manager.apply(&channels_config);
+        assert_eq!(report.restarted, vec!["imessage".to_string()]);
+        assert!(report.started.is_empty() && report.stopped.is_empty());
+
+        // Removing the section stops and detaches the channel.
+        channels_config.imessage = None;
+        let report = manager.apply(&channels_config);
+        assert_eq!(report.stopped, vec!["imessage".to_string()]);
+        assert!(manager.active_channel_names().is_empty());
+        assert!(channels_by_name.read().unwrap().is_empty());
+
+        manager.shutdown();
+    }
 }
@@ -1,6 +1,54 @@
-use super::traits::{Channel, ChannelMessage, SendMessage};
+use super::traits::{Channel, ChannelCapabilities, ChannelMessage, MarkdownDialect, SendMessage};
 use async_trait::async_trait;
 
+/// Slack truncates `chat.postMessage` text beyond 40,000 characters.
+const SLACK_MAX_MESSAGE_LENGTH: usize = 40_000;
+
+/// Extract `[FILE:path]` / `[IMAGE:path]` markers from outbound text so the
+/// referenced files can be uploaded as Slack attachments. Markers with empty
+/// targets (or unknown kinds) are left in the text untouched.
+fn parse_outbound_file_markers(message: &str) -> (String, Vec<String>) {
+    let mut cleaned = String::with_capacity(message.len());
+    let mut targets = Vec::new();
+    let mut cursor = 0;
+
+    while cursor < message.len() {
+        let Some(open_rel) = message[cursor..].find('[') else {
+            cleaned.push_str(&message[cursor..]);
+            break;
+        };
+
+        let open = cursor + open_rel;
+        cleaned.push_str(&message[cursor..open]);
+
+        let Some(close_rel) = message[open..].find(']') else {
+            cleaned.push_str(&message[open..]);
+            break;
+        };
+
+        let close = open + close_rel;
+        let marker = &message[open + 1..close];
+
+        let parsed = marker.split_once(':').and_then(|(kind, target)| {
+            let target = target.trim();
+            if target.is_empty() || !matches!(kind, "FILE" | "IMAGE") {
+                return None;
+            }
+            Some(target.to_string())
+        });
+
+        if let Some(target) = parsed {
+            targets.push(target);
+        } else {
+            cleaned.push_str(&message[open..=close]);
+        }
+
+        cursor = close + 1;
+    }
+
+    (cleaned.trim().to_string(), targets)
+}
+
 /// Slack channel — polls conversations.history via Web API
 pub struct SlackChannel {
     bot_token: String,
@@ -54,21 +102,64 @@ impl SlackChannel {
             .or(if ts.is_empty() { None } else { Some(ts) })
             .map(str::to_string)
     }
-}
 
-#[async_trait]
-impl Channel for SlackChannel {
-    fn name(&self) -> &str {
-        "slack"
+    /// Pull `(name, url_private_download)` pairs for files shared in a message.
+    fn inbound_file_refs(msg: &serde_json::Value) -> Vec<(String, String)> {
+        msg.get("files")
+            .and_then(|files| files.as_array())
+            .map(|files| {
+                files
+                    .iter()
+                    .filter_map(|file| {
+                        let url = file
+                            .get("url_private_download")
+                            .and_then(|u| u.as_str())?
+                            .to_string();
+                        let name = file
+                            .get("name")
+                            .and_then(|n| n.as_str())
+                            .unwrap_or("attachment.bin")
+                            .to_string();
+                        Some((name, url))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
     }
 
-    async fn send(&self, message: &SendMessage) -> anyhow::Result<()> {
+    /// Download a shared file into the workspace inbox. Slack private URLs
+    /// require the bot token, so downloading here keeps the token out of
+    /// message content.
+    async fn download_file_to_inbox(
+        &self,
+        name: &str,
+        url: &str,
+    ) -> anyhow::Result<std::path::PathBuf> {
+        let bytes = self
+            .http_client()
+            .get(url)
+            .bearer_auth(&self.bot_token)
+            .send()
+            .await?
+            .error_for_status()?
+            .bytes()
+            .await?;
+        super::attachments::save_to_inbox(name, &bytes).await
+    }
+
+    /// Post plain text to a channel (optionally threaded) via `chat.postMessage`.
+    async fn post_text(
+        &self,
+        recipient: &str,
+        text: &str,
+        thread_ts: Option<&str>,
+    ) -> anyhow::Result<()> {
         let mut body = serde_json::json!({
-            "channel": message.recipient,
-            "text": message.content
+            "channel": recipient,
+            "text": text
         });
 
-        if let Some(ref ts) = message.thread_ts {
+        if let Some(ts) = thread_ts {
             body["thread_ts"] = serde_json::json!(ts);
         }
 
@@ -103,6 +194,139 @@ impl Channel for SlackChannel {
         Ok(())
     }
 
+    /// Upload a local file to a channel via the external-upload flow
+    /// (`files.getUploadURLExternal` → raw POST → `files.completeUploadExternal`).
+    async fn upload_file(
+        &self,
+        recipient: &str,
+        path: &std::path::Path,
+        thread_ts: Option<&str>,
+    ) -> anyhow::Result<()> {
+        let bytes = tokio::fs::read(path).await?;
+        let file_name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "attachment.bin".to_string());
+
+        let ticket: serde_json::Value = self
+            .http_client()
+            .get("https://slack.com/api/files.getUploadURLExternal")
+            .bearer_auth(&self.bot_token)
+            .query(&[
+                ("filename", file_name.as_str()),
+                ("length", &bytes.len().to_string()),
+            ])
+            .send()
+            .await?
+            .json()
+            .await?;
+
+        if ticket.get("ok") != Some(&serde_json::Value::Bool(true)) {
+            let err = ticket
+                .get("error")
+                .and_then(|e| e.as_str())
+                .unwrap_or("unknown");
+            anyhow::bail!("Slack files.getUploadURLExternal failed: {err}");
+        }
+
+        let upload_url = ticket
+            .get("upload_url")
+            .and_then(|u| u.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Slack upload ticket missing upload_url"))?;
+        let file_id = ticket
+            .get("file_id")
+            .and_then(|id| id.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Slack upload ticket missing file_id"))?;
+
+        self.http_client()
+            .post(upload_url)
+            .body(bytes)
+            .send()
+            .await?
+            .error_for_status()?;
+
+        let mut complete_body = serde_json::json!({
+            "files": [{ "id": file_id, "title": file_name }],
+            "channel_id": recipient
+        });
+        if let Some(ts) = thread_ts {
+            complete_body["thread_ts"] = serde_json::json!(ts);
+        }
+
+        let completed: serde_json::Value = self
+            .http_client()
+            .post("https://slack.com/api/files.completeUploadExternal")
+            .bearer_auth(&self.bot_token)
+            .json(&complete_body)
+            .send()
+            .await?
+            .json()
+            .await?;
+
+        if completed.get("ok") != Some(&serde_json::Value::Bool(true)) {
+            let err = completed
+                .get("error")
+                .and_then(|e| e.as_str())
+                .unwrap_or("unknown");
+            anyhow::bail!("Slack files.completeUploadExternal failed: {err}");
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Channel for SlackChannel {
+    fn name(&self) -> &str {
+        "slack"
+    }
+
+    fn capabilities(&self) -> ChannelCapabilities {
+        ChannelCapabilities {
+            supports_images: false,
+            supports_buttons: false,
+            supports_threads: true,
+            max_message_length: Some(SLACK_MAX_MESSAGE_LENGTH),
+            markdown: MarkdownDialect::Slack,
+        }
+    }
+
+    async fn send(&self, message: &SendMessage) -> anyhow::Result<()> {
+        let thread_ts = message.thread_ts.as_deref();
+        let (text_without_markers, targets) = parse_outbound_file_markers(&message.content);
+
+        // Only upload markers that point at existing local files; anything
+        // else (URLs, stale paths) stays in the text for the reader to see.
+        let (uploads, passthrough): (Vec<_>, Vec<_>) = targets
+            .into_iter()
+            .partition(|target| std::path::Path::new(target).is_file());
+
+        if uploads.is_empty() {
+            return self
+                .post_text(&message.recipient, &message.content, thread_ts)
+                .await;
+        }
+
+        let mut text = text_without_markers;
+        for target in passthrough {
+            if text.is_empty() {
+                text = target;
+            } else {
+                text = format!("{text} {target}");
+            }
+        }
+        if !text.is_empty() {
+            self.post_text(&message.recipient, &text, thread_ts).await?;
+        }
+
+        for target in &uploads {
+            self.upload_file(&message.recipient, std::path::Path::new(target), thread_ts)
+                .await?;
+        }
+
+        Ok(())
+    }
+
     async fn listen(&self, tx: tokio::sync::mpsc::Sender<ChannelMessage>) -> anyhow::Result<()> {
         let channel_id = self
             .channel_id
@@ -166,18 +390,42 @@ impl Channel for SlackChannel {
                         continue;
                     }
 
-                    // Skip empty or already-seen
-                    if text.is_empty() || ts <= last_ts.as_str() {
+                    // Skip already-seen, and empty messages with no files
+                    let file_refs = Self::inbound_file_refs(msg);
+                    if (text.is_empty() && file_refs.is_empty()) || ts <= last_ts.as_str() {
                         continue;
                     }
 
                     last_ts = ts.to_string();
 
+                    // Shared files go into the workspace inbox where the agent
+                    // can inspect them with the `read_attachment` tool.
+                    let mut content = text.to_string();
+                    for (name, url) in &file_refs {
+                        match self.download_file_to_inbox(name, url).await {
+                            Ok(path) => {
+                                let saved = path
+                                    .file_name()
+                                    .map(|n| n.to_string_lossy().into_owned())
+                                    .unwrap_or_else(|| name.clone());
+                                content = format!("{content} [attachment saved: {saved}]")
+                                    .trim()
+                                    .to_string();
+                            }
+                            Err(e) => {
+                                tracing::warn!("Slack: failed to download shared file: {e}");
+                            }
+                        }
+                    }
+                    if content.is_empty() {
+                        continue;
+                    }
+
                     let channel_msg = ChannelMessage {
                         id: format!("slack_{channel_id}_{ts}"),
                         sender: user.to_string(),
                         reply_target: channel_id.clone(),
-                        content: text.to_string(),
+                        content,
                         channel: "slack".to_string(),
                         timestamp: std::time::SystemTime::now()
                             .duration_since(std::time::UNIX_EPOCH)
@@ -346,4 +594,56 @@ mod tests {
         let thread_ts = SlackChannel::inbound_thread_ts(&msg, "");
         assert_eq!(thread_ts, None);
     }
+
+    // ── File attachment handling ──────────────────────────────────
+
+    #[test]
+    fn inbound_file_refs_extracts_name_and_url() {
+        let msg = serde_json::json!({
+            "text": "here are the logs",
+            "files": [
+                {
+                    "name": "server.log",
+                    "url_private_download": "https://files.slack.com/x/server.log"
+                },
+                {
+                    "url_private_download": "https://files.slack.com/x/unnamed"
+                },
+                { "name": "no-url.txt" }
+            ]
+        });
+
+        let refs = SlackChannel::inbound_file_refs(&msg);
+        assert_eq!(refs.len(), 2);
+        assert_eq!(
+            refs[0],
+            (
+                "server.log".to_string(),
+                "https://files.slack.com/x/server.log".to_string()
+            )
+        );
+        assert_eq!(refs[1].0, "attachment.bin");
+    }
+
+    #[test]
+    fn inbound_file_refs_empty_without_files() {
+        let msg = serde_json::json!({ "text": "plain message" });
+        assert!(SlackChannel::inbound_file_refs(&msg).is_empty());
+    }
+
+    #[test]
+    fn parse_outbound_file_markers_extracts_targets() {
+        let message = "Report attached [FILE:/tmp/report.csv] and [IMAGE:/tmp/chart.png]";
+        let (cleaned, targets) = parse_outbound_file_markers(message);
+        assert_eq!(cleaned, "Report attached  and");
+        assert_eq!(targets, vec!["/tmp/report.csv", "/tmp/chart.png"]);
+    }
+
+    #[test]
+    fn parse_outbound_file_markers_keeps_invalid_markers_in_text() {
+        let message = "See [FILE:] and [NOTE:keep me]";
+        let (cleaned, targets) = parse_outbound_file_markers(message);
+        assert_eq!(cleaned, message);
+        assert!(targets.is_empty());
+    }
 }
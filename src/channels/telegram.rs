@@ -1,4 +1,4 @@
-use super::traits::{Channel, ChannelMessage, SendMessage};
+use super::traits::{Channel, ChannelCapabilities, ChannelMessage, MarkdownDialect, SendMessage};
 use crate::config::{Config, StreamMode};
 use crate::security::pairing::PairingGuard;
 use anyhow::Context;
@@ -740,10 +740,115 @@ Allowlist Telegram username (without '@') or numeric user ID.",
         }
     }
 
+    /// Pick the `file_id` of the largest rendition of an incoming photo.
+    fn largest_photo_file_id(message: &serde_json::Value) -> Option<String> {
+        message
+            .get("photo")?
+            .as_array()?
+            .iter()
+            .max_by_key(|size| {
+                size.get("file_size")
+                    .and_then(serde_json::Value::as_i64)
+                    .unwrap_or(0)
+            })?
+            .get("file_id")
+            .and_then(serde_json::Value::as_str)
+            .map(ToString::to_string)
+    }
+
+    /// Pull the `file_id` and original file name of an incoming document
+    /// (PDF, CSV, log, ...) so it can be saved into the workspace inbox.
+    fn document_file_info(message: &serde_json::Value) -> Option<(String, String)> {
+        let document = message.get("document")?;
+        let file_id = document
+            .get("file_id")
+            .and_then(serde_json::Value::as_str)?
+            .to_string();
+        let file_name = document
+            .get("file_name")
+            .and_then(serde_json::Value::as_str)
+            .unwrap_or("attachment.bin")
+            .to_string();
+        Some((file_id, file_name))
+    }
+
+    /// Resolve a `file_id` via `getFile` and download its bytes. Downloading
+    /// here keeps the bot token out of message content (Telegram file URLs
+    /// embed the token).
+    async fn download_file_bytes(&self, file_id: &str) -> anyhow::Result<(Vec<u8>, String)> {
+        let response: serde_json::Value = self
+            .http_client()
+            .post(self.api_url("getFile"))
+            .json(&serde_json::json!({ "file_id": file_id }))
+            .send()
+            .await?
+            .json()
+            .await?;
+        let file_path = response
+            .get("result")
+            .and_then(|result| result.get("file_path"))
+            .and_then(serde_json::Value::as_str)
+            .ok_or_else(|| anyhow::anyhow!("Telegram getFile returned no file_path"))?;
+
+        let url = format!(
+            "https://api.telegram.org/file/bot{}/{file_path}",
+            self.bot_token
+        );
+        let bytes = self
+            .http_client()
+            .get(&url)
+            .send()
+            .await?
+            .error_for_status()?
+            .bytes()
+            .await?;
+        Ok((bytes.to_vec(), file_path.to_string()))
+    }
+
+    /// Download an incoming photo to a temp file so it can be passed to the
+    /// agent as a local `[IMAGE:...]` marker.
+    async fn download_photo_to_temp(&self, file_id: &str) -> anyhow::Result<std::path::PathBuf> {
+        let (bytes, file_path) = self.download_file_bytes(file_id).await?;
+        let extension = std::path::Path::new(&file_path)
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .unwrap_or("jpg");
+        let path = std::env::temp_dir().join(format!(
+            "zeroclaw-telegram-{}.{extension}",
+            uuid::Uuid::new_v4()
+        ));
+        tokio::fs::write(&path, &bytes).await?;
+        Ok(path)
+    }
+
+    /// Download an incoming document into the workspace inbox where the agent
+    /// can inspect it with the `read_attachment` tool.
+    async fn download_document_to_inbox(
+        &self,
+        file_id: &str,
+        file_name: &str,
+    ) -> anyhow::Result<std::path::PathBuf> {
+        let (bytes, _) = self.download_file_bytes(file_id).await?;
+        super::attachments::save_to_inbox(file_name, &bytes).await
+    }
+
     fn parse_update_message(&self, update: &serde_json::Value) -> Option<ChannelMessage> {
         let message = update.get("message")?;
 
-        let text = message.get("text").and_then(serde_json::Value::as_str)?;
+        // Photo/document messages have no "text"; use the caption (possibly
+        // empty) so the attachment still reaches the agent.
+        let text = match message.get("text").and_then(serde_json::Value::as_str) {
+            Some(text) => text,
+            None if Self::largest_photo_file_id(message).is_some()
+                || Self::document_file_info(message).is_some() =>
+            {
+                message
+                    .get("caption")
+                    .and_then(serde_json::Value::as_str)
+                    .unwrap_or("")
+            }
+            None => return None,
+        };
 
         let username = message
             .get("from")
@@ -1422,6 +1527,17 @@ impl Channel for TelegramChannel {
         "telegram"
     }
 
+    fn capabilities(&self) -> ChannelCapabilities {
+        ChannelCapabilities {
+            supports_images: true,
+            supports_buttons: false,
+            // Forum topics via `chat_id:thread_id` reply targets.
+            supports_threads: true,
+            max_message_length: Some(TELEGRAM_MAX_MESSAGE_LENGTH),
+            markdown: MarkdownDialect::Standard,
+        }
+    }
+
     fn supports_draft_updates(&self) -> bool {
         self.stream_mode != StreamMode::Off
     }
@@ -1778,10 +1894,53 @@ Ensure only one `zeroclaw` process is using this bot token."
                         offset = uid + 1;
                     }
 
-                    let Some(msg) = self.parse_update_message(update) else {
+                    let Some(mut msg) = self.parse_update_message(update) else {
                         self.handle_unauthorized_message(update).await;
                         continue;
                     };
+
+                    // Incoming photo: download it and reference it as a local
+                    // [IMAGE:...] attachment for vision-capable models.
+                    if let Some(file_id) =
+                        update.get("message").and_then(Self::largest_photo_file_id)
+                    {
+                        match self.download_photo_to_temp(&file_id).await {
+                            Ok(path) => {
+                                msg.content = format!("{} [IMAGE:{}]", msg.content, path.display())
+                                    .trim()
+                                    .to_string();
+                            }
+                            Err(e) => {
+                                tracing::warn!(
+                                    "Telegram: failed to download photo attachment: {e}"
+                                );
+                            }
+                        }
+                    }
+
+                    // Incoming document: save it into the workspace inbox and
+                    // tell the agent where to find it.
+                    if let Some((file_id, file_name)) =
+                        update.get("message").and_then(Self::document_file_info)
+                    {
+                        match self.download_document_to_inbox(&file_id, &file_name).await {
+                            Ok(path) => {
+                                let saved = path
+                                    .file_name()
+                                    .map(|name| name.to_string_lossy().into_owned())
+                                    .unwrap_or(file_name);
+                                msg.content =
+                                    format!("{} [attachment saved: {saved}]", msg.content)
+                                        .trim()
+                                        .to_string();
+                            }
+                            Err(e) => {
+                                tracing::warn!(
+                                    "Telegram: failed to download document attachment: {e}"
+                                );
+                            }
+                        }
+                    }
                     // Send "typing" indicator immediately when we receive a message
                     let typing_body = serde_json::json!({
                         "chat_id": &msg.reply_target,
@@ -2211,6 +2370,118 @@ mod tests {
         assert_eq!(msg.id, "telegram_-100200300_42");
     }
 
+    #[test]
+    fn parse_update_message_accepts_photo_with_caption() {
+        let ch = TelegramChannel::new("token".into(), vec!["*".into()], false);
+        let update = serde_json::json!({
+            "update_id": 4,
+            "message": {
+                "message_id": 50,
+                "caption": "what is this?",
+                "photo": [
+                    { "file_id": "small", "file_size": 100 },
+                    { "file_id": "large", "file_size": 900 }
+                ],
+                "from": {
+                    "id": 555,
+                    "username": "zeroclaw_user"
+                },
+                "chat": {
+                    "id": 12345
+                }
+            }
+        });
+
+        let msg = ch
+            .parse_update_message(&update)
+            .expect("photo message should parse");
+
+        assert_eq!(msg.content, "what is this?");
+    }
+
+    #[test]
+    fn parse_update_message_still_rejects_non_text_non_photo() {
+        let ch = TelegramChannel::new("token".into(), vec!["*".into()], false);
+        let update = serde_json::json!({
+            "update_id": 5,
+            "message": {
+                "message_id": 51,
+                "sticker": { "file_id": "abc" },
+                "from": { "id": 555, "username": "zeroclaw_user" },
+                "chat": { "id": 12345 }
+            }
+        });
+
+        assert!(ch.parse_update_message(&update).is_none());
+    }
+
+    #[test]
+    fn parse_update_message_accepts_document_with_caption() {
+        let ch = TelegramChannel::new("token".into(), vec!["*".into()], false);
+        let update = serde_json::json!({
+            "update_id": 6,
+            "message": {
+                "message_id": 52,
+                "caption": "summarize this",
+                "document": {
+                    "file_id": "doc123",
+                    "file_name": "report.pdf"
+                },
+                "from": {
+                    "id": 555,
+                    "username": "zeroclaw_user"
+                },
+                "chat": {
+                    "id": 12345
+                }
+            }
+        });
+
+        let msg = ch
+            .parse_update_message(&update)
+            .expect("document message should parse");
+
+        assert_eq!(msg.content, "summarize this");
+    }
+
+    #[test]
+    fn document_file_info_extracts_id_and_name() {
+        let message = serde_json::json!({
+            "document": { "file_id": "doc123", "file_name": "logs.csv" }
+        });
+        assert_eq!(
+            TelegramChannel::document_file_info(&message),
+            Some(("doc123".to_string(), "logs.csv".to_string()))
+        );
+
+        let unnamed = serde_json::json!({
+            "document": { "file_id": "doc456" }
+        });
+        assert_eq!(
+            TelegramChannel::document_file_info(&unnamed),
+            Some(("doc456".to_string(), "attachment.bin".to_string()))
+        );
+
+        assert!(TelegramChannel::document_file_info(&serde_json::json!({})).is_none());
+    }
+
+    #[test]
+    fn largest_photo_file_id_picks_biggest_rendition() {
+        let message = serde_json::json!({
+            "photo": [
+                { "file_id": "small", "file_size": 100 },
+                { "file_id": "large", "file_size": 900 },
+                { "file_id": "medium", "file_size": 400 }
+            ]
+        });
+
+        assert_eq!(
+            TelegramChannel::largest_photo_file_id(&message).as_deref(),
+            Some("large")
+        );
+        assert!(TelegramChannel::largest_photo_file_id(&serde_json::json!({})).is_none());
+    }
+
     // ── File sending API URL tests ──────────────────────────────────
 
     #[test]
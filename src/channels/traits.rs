@@ -56,6 +56,39 @@ impl SendMessage {
     }
 }
 
+/// Markdown dialect a channel renders.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MarkdownDialect {
+    /// No formatting — content is shown as plain text.
+    #[default]
+    Plain,
+    /// Standard Markdown (bold, italics, links, code fences).
+    Standard,
+    /// Slack mrkdwn (`*bold*`, `_italic_`; headings and tables do not render).
+    Slack,
+}
+
+/// Channel rendering capabilities declaration.
+///
+/// Describes what the destination platform can display, so the agent's
+/// response planning never generates content the channel cannot render.
+/// The default is the conservative plain-text baseline.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ChannelCapabilities {
+    /// Whether the channel can deliver file/image attachments produced by
+    /// the agent (via media markers in the response text).
+    pub supports_images: bool,
+    /// Whether the channel can render interactive buttons.
+    pub supports_buttons: bool,
+    /// Whether the channel supports threaded replies (`thread_ts`).
+    pub supports_threads: bool,
+    /// Hard per-message character limit, when the platform has one.
+    /// Channels are expected to split longer messages before sending.
+    pub max_message_length: Option<usize>,
+    /// Markdown dialect the channel renders.
+    pub markdown: MarkdownDialect,
+}
+
 /// Core channel trait — implement for any messaging platform
 #[async_trait]
 pub trait Channel: Send + Sync {
@@ -84,6 +117,14 @@ pub trait Channel: Send + Sync {
         Ok(())
     }
 
+    /// Rendering capabilities of this channel.
+    ///
+    /// Default implementation returns the plain-text baseline. Channels
+    /// should override this to advertise richer rendering support.
+    fn capabilities(&self) -> ChannelCapabilities {
+        ChannelCapabilities::default()
+    }
+
     /// Whether this channel supports progressive message updates via draft edits.
     fn supports_draft_updates(&self) -> bool {
         false
@@ -188,6 +229,17 @@ mod tests {
             .is_ok());
     }
 
+    #[test]
+    fn default_capabilities_are_plain_text_baseline() {
+        let caps = DummyChannel.capabilities();
+
+        assert!(!caps.supports_images);
+        assert!(!caps.supports_buttons);
+        assert!(!caps.supports_threads);
+        assert_eq!(caps.max_message_length, None);
+        assert_eq!(caps.markdown, MarkdownDialect::Plain);
+    }
+
     #[tokio::test]
     async fn default_draft_methods_return_success() {
         let channel = DummyChannel;
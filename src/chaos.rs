@@ -0,0 +1,203 @@
+//! Chaos testing mode for resilience verification.
+//!
+//! When the `ZEROCLAW_CHAOS` environment variable is set, synthetic failures
+//! are randomly injected into the provider, channel, and tool paths so
+//! maintainers and power users can verify that retry, failover, and listener
+//! restart logic actually works before relying on the daemon in production.
+//!
+//! Spec format (rates are probabilities in `0.0..=1.0`):
+//!
+//! - `ZEROCLAW_CHAOS=0.2` — one rate for all surfaces
+//! - `ZEROCLAW_CHAOS=provider=0.3,channel=0.05,tool=0.1` — per-surface rates;
+//!   omitted surfaces stay at `0` (no injection)
+//!
+//! Injection semantics:
+//!
+//! - `provider` — probability per provider call of a synthetic retryable
+//!   error (exercises [`ReliableProvider`](crate::providers::reliable) retry
+//!   and failover)
+//! - `channel` — probability per listener health tick of a synthetic
+//!   disconnect (exercises the supervised listener restart/backoff loop)
+//! - `tool` — probability per tool call of a synthetic timeout
+//!
+//! A malformed spec disables chaos entirely with an error log rather than
+//! injecting based on a half-parsed value; chaos is never "partially on".
+//! Injected failures are plain errors on paths that already handle real
+//! failures — chaos mode adds no new capabilities or bypasses.
+
+use std::sync::OnceLock;
+
+/// Environment variable holding the chaos spec.
+pub const ENV_VAR: &str = "ZEROCLAW_CHAOS";
+
+/// Per-surface injection rates, each a probability in `0.0..=1.0`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChaosConfig {
+    pub provider: f64,
+    pub channel: f64,
+    pub tool: f64,
+}
+
+/// Parse a chaos spec: either a single rate applied to every surface, or a
+/// comma-separated list of `surface=rate` pairs.
+pub fn parse_spec(spec: &str) -> anyhow::Result<ChaosConfig> {
+    let spec = spec.trim();
+    if spec.is_empty() {
+        anyhow::bail!("empty chaos spec (expected a rate or surface=rate pairs)");
+    }
+
+    if !spec.contains('=') {
+        let rate = parse_rate(spec)?;
+        return Ok(ChaosConfig {
+            provider: rate,
+            channel: rate,
+            tool: rate,
+        });
+    }
+
+    let mut config = ChaosConfig {
+        provider: 0.0,
+        channel: 0.0,
+        tool: 0.0,
+    };
+    for part in spec.split(',') {
+        let Some((key, value)) = part.split_once('=') else {
+            anyhow::bail!("invalid chaos entry '{part}' (expected surface=rate)");
+        };
+        match key.trim() {
+            "provider" => config.provider = parse_rate(value)?,
+            "channel" => config.channel = parse_rate(value)?,
+            "tool" => config.tool = parse_rate(value)?,
+            other => {
+                anyhow::bail!(
+                    "unknown chaos surface '{other}' (expected provider, channel, or tool)"
+                )
+            }
+        }
+    }
+    Ok(config)
+}
+
+fn parse_rate(raw: &str) -> anyhow::Result<f64> {
+    let raw = raw.trim();
+    let rate: f64 = raw
+        .parse()
+        .map_err(|_| anyhow::anyhow!("invalid chaos rate '{raw}' (expected a number)"))?;
+    if !rate.is_finite() || !(0.0..=1.0).contains(&rate) {
+        anyhow::bail!("chaos rate {raw} out of range (expected 0.0..=1.0)");
+    }
+    Ok(rate)
+}
+
+/// The active chaos config, parsed once from `ZEROCLAW_CHAOS`. `None` when the
+/// variable is unset or malformed.
+fn active() -> Option<&'static ChaosConfig> {
+    static CONFIG: OnceLock<Option<ChaosConfig>> = OnceLock::new();
+    CONFIG
+        .get_or_init(|| {
+            let spec = std::env::var(ENV_VAR).ok()?;
+            match parse_spec(&spec) {
+                Ok(config) => {
+                    tracing::warn!(
+                        provider_rate = config.provider,
+                        channel_rate = config.channel,
+                        tool_rate = config.tool,
+                        "Chaos mode active ({ENV_VAR}) — synthetic failures will be injected"
+                    );
+                    Some(config)
+                }
+                Err(e) => {
+                    tracing::error!("Ignoring invalid {ENV_VAR} spec: {e}; chaos mode disabled");
+                    None
+                }
+            }
+        })
+        .as_ref()
+}
+
+/// Whether chaos mode is active at all.
+pub fn enabled() -> bool {
+    active().is_some()
+}
+
+fn roll(rate: f64) -> bool {
+    rate > 0.0 && rand::random::<f64>() < rate
+}
+
+/// Roll for a synthetic provider failure on this call.
+pub fn inject_provider_failure() -> bool {
+    active().is_some_and(|c| roll(c.provider))
+}
+
+/// Roll for a synthetic channel disconnect on this health tick.
+pub fn inject_channel_disconnect() -> bool {
+    active().is_some_and(|c| roll(c.channel))
+}
+
+/// Roll for a synthetic tool timeout on this call.
+pub fn inject_tool_timeout() -> bool {
+    active().is_some_and(|c| roll(c.tool))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_spec_single_rate_applies_to_all_surfaces() {
+        let config = parse_spec("0.25").unwrap();
+        assert_eq!(config.provider, 0.25);
+        assert_eq!(config.channel, 0.25);
+        assert_eq!(config.tool, 0.25);
+    }
+
+    #[test]
+    fn parse_spec_per_surface_rates_default_omitted_to_zero() {
+        let config = parse_spec("provider=0.5,tool=0.1").unwrap();
+        assert_eq!(config.provider, 0.5);
+        assert_eq!(config.channel, 0.0);
+        assert_eq!(config.tool, 0.1);
+    }
+
+    #[test]
+    fn parse_spec_tolerates_whitespace() {
+        let config = parse_spec(" provider = 0.3 , channel = 0.2 ").unwrap();
+        assert_eq!(config.provider, 0.3);
+        assert_eq!(config.channel, 0.2);
+    }
+
+    #[test]
+    fn parse_spec_rejects_unknown_surface() {
+        let err = parse_spec("gateway=0.5").unwrap_err();
+        assert!(err.to_string().contains("unknown chaos surface"));
+    }
+
+    #[test]
+    fn parse_spec_rejects_out_of_range_rate() {
+        assert!(parse_spec("1.5").is_err());
+        assert!(parse_spec("provider=-0.1").is_err());
+        assert!(parse_spec("NaN").is_err());
+    }
+
+    #[test]
+    fn parse_spec_rejects_empty_and_malformed() {
+        assert!(parse_spec("").is_err());
+        assert!(parse_spec("provider=").is_err());
+        assert!(parse_spec("provider=abc").is_err());
+        assert!(parse_spec("provider=0.2,bogus").is_err());
+    }
+
+    #[test]
+    fn roll_zero_rate_never_injects() {
+        for _ in 0..100 {
+            assert!(!roll(0.0));
+        }
+    }
+
+    #[test]
+    fn roll_full_rate_always_injects() {
+        for _ in 0..100 {
+            assert!(roll(1.0));
+        }
+    }
+}
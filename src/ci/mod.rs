@@ -0,0 +1,551 @@
+//! CI failure triage: webhook-driven root-cause analysis for failed pipelines.
+//!
+//! `POST /hooks/ci` on the gateway receives CI failure webhooks — GitHub
+//! Actions `workflow_run` events or GitLab CI Pipeline Hooks — fetches the
+//! failing job log via the forge API, has the agent produce a root-cause
+//! hypothesis with a suggested fix, and posts the result as a PR/MR comment
+//! (when the run belongs to one and a forge token is configured) or to a
+//! configured channel otherwise.
+
+use crate::config::CiConfig;
+use anyhow::{bail, Context, Result};
+use serde_json::Value;
+
+/// Maximum bytes of job log tail fed into the triage prompt.
+const MAX_LOG_BYTES: usize = 16_384;
+/// Forge API request timeout in seconds.
+const API_TIMEOUT_SECS: u64 = 30;
+
+/// A failed CI run extracted from a forge webhook payload.
+#[derive(Debug, Clone)]
+pub enum CiFailure {
+    GitHub {
+        /// Repository as `owner/name`.
+        repo: String,
+        run_id: u64,
+        workflow: String,
+        branch: String,
+        url: String,
+        /// Pull request number when the run belongs to one.
+        pr_number: Option<u64>,
+    },
+    GitLab {
+        /// Instance API base derived from the project URL (e.g. `https://gitlab.example.com/api/v4`).
+        api_base: String,
+        project_id: u64,
+        pipeline_id: u64,
+        ref_name: String,
+        url: String,
+        /// Failed jobs listed in the pipeline payload.
+        failed_jobs: Vec<FailedJob>,
+        /// Merge request IID when the pipeline belongs to one.
+        mr_iid: Option<u64>,
+    },
+}
+
+/// One failed job within a pipeline.
+#[derive(Debug, Clone)]
+pub struct FailedJob {
+    pub id: u64,
+    pub name: String,
+}
+
+impl CiFailure {
+    /// One-line description used in prompts and log lines.
+    pub fn describe(&self) -> String {
+        match self {
+            Self::GitHub {
+                repo,
+                workflow,
+                branch,
+                url,
+                ..
+            } => format!("GitHub Actions run '{workflow}' failed on {repo}@{branch} ({url})"),
+            Self::GitLab {
+                pipeline_id,
+                ref_name,
+                url,
+                ..
+            } => format!("GitLab CI pipeline #{pipeline_id} failed on ref {ref_name} ({url})"),
+        }
+    }
+}
+
+/// Parse a CI webhook payload into a failure, keyed by forge event headers.
+///
+/// Returns `Ok(None)` for recognized events that need no triage (successful
+/// or still-running pipelines, non-completion actions) and an error for
+/// payloads that are not CI events at all.
+pub fn parse_event(
+    github_event: Option<&str>,
+    gitlab_event: Option<&str>,
+    payload: &Value,
+) -> Result<Option<CiFailure>> {
+    if github_event == Some("workflow_run") {
+        return parse_github_workflow_run(payload);
+    }
+    if gitlab_event == Some("Pipeline Hook")
+        || payload.get("object_kind").and_then(Value::as_str) == Some("pipeline")
+    {
+        return parse_gitlab_pipeline(payload);
+    }
+    bail!(
+        "Unsupported CI event. Expected a GitHub `workflow_run` webhook \
+         (X-GitHub-Event) or a GitLab Pipeline Hook (X-Gitlab-Event)"
+    );
+}
+
+fn parse_github_workflow_run(payload: &Value) -> Result<Option<CiFailure>> {
+    if payload.get("action").and_then(Value::as_str) != Some("completed") {
+        return Ok(None);
+    }
+    let run = payload
+        .get("workflow_run")
+        .context("workflow_run event missing `workflow_run` object")?;
+    if run.get("conclusion").and_then(Value::as_str) != Some("failure") {
+        return Ok(None);
+    }
+
+    let repo = payload
+        .pointer("/repository/full_name")
+        .and_then(Value::as_str)
+        .context("workflow_run event missing `repository.full_name`")?
+        .to_string();
+    let run_id = run
+        .get("id")
+        .and_then(Value::as_u64)
+        .context("workflow_run event missing `workflow_run.id`")?;
+    let workflow = run
+        .get("name")
+        .and_then(Value::as_str)
+        .unwrap_or("(unnamed workflow)")
+        .to_string();
+    let branch = run
+        .get("head_branch")
+        .and_then(Value::as_str)
+        .unwrap_or("(unknown)")
+        .to_string();
+    let url = run
+        .get("html_url")
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .to_string();
+    let pr_number = run
+        .pointer("/pull_requests/0/number")
+        .and_then(Value::as_u64);
+
+    Ok(Some(CiFailure::GitHub {
+        repo,
+        run_id,
+        workflow,
+        branch,
+        url,
+        pr_number,
+    }))
+}
+
+fn parse_gitlab_pipeline(payload: &Value) -> Result<Option<CiFailure>> {
+    let attributes = payload
+        .get("object_attributes")
+        .context("pipeline event missing `object_attributes`")?;
+    if attributes.get("status").and_then(Value::as_str) != Some("failed") {
+        return Ok(None);
+    }
+
+    let project_id = payload
+        .pointer("/project/id")
+        .and_then(Value::as_u64)
+        .context("pipeline event missing `project.id`")?;
+    let pipeline_id = attributes
+        .get("id")
+        .and_then(Value::as_u64)
+        .context("pipeline event missing `object_attributes.id`")?;
+    let ref_name = attributes
+        .get("ref")
+        .and_then(Value::as_str)
+        .unwrap_or("(unknown)")
+        .to_string();
+    let web_url = payload
+        .pointer("/project/web_url")
+        .and_then(Value::as_str)
+        .context("pipeline event missing `project.web_url`")?;
+    let api_base = api_base_from_project_url(web_url)?;
+    let url = attributes
+        .get("url")
+        .and_then(Value::as_str)
+        .unwrap_or(web_url)
+        .to_string();
+    let failed_jobs: Vec<FailedJob> = payload
+        .get("builds")
+        .and_then(Value::as_array)
+        .map(|builds| {
+            builds
+                .iter()
+                .filter(|b| b.get("status").and_then(Value::as_str) == Some("failed"))
+                .filter_map(|b| {
+                    Some(FailedJob {
+                        id: b.get("id").and_then(Value::as_u64)?,
+                        name: b
+                            .get("name")
+                            .and_then(Value::as_str)
+                            .unwrap_or("(unnamed job)")
+                            .to_string(),
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    let mr_iid = payload
+        .pointer("/merge_request/iid")
+        .and_then(Value::as_u64);
+
+    Ok(Some(CiFailure::GitLab {
+        api_base,
+        project_id,
+        pipeline_id,
+        ref_name,
+        url,
+        failed_jobs,
+        mr_iid,
+    }))
+}
+
+/// Derive the GitLab API base (`scheme://host[:port]/api/v4`) from a project web URL.
+fn api_base_from_project_url(web_url: &str) -> Result<String> {
+    let url = reqwest::Url::parse(web_url)
+        .with_context(|| format!("Invalid project web_url in pipeline event: {web_url}"))?;
+    let host = url
+        .host_str()
+        .context("Project web_url has no host component")?;
+    let base = match url.port() {
+        Some(port) => format!("{}://{host}:{port}/api/v4", url.scheme()),
+        None => format!("{}://{host}/api/v4", url.scheme()),
+    };
+    Ok(base)
+}
+
+/// Keep the tail of a job log, bounded to `max_bytes` on a char boundary.
+fn log_tail(log: &str, max_bytes: usize) -> &str {
+    if log.len() <= max_bytes {
+        return log;
+    }
+    let mut start = log.len() - max_bytes;
+    while !log.is_char_boundary(start) {
+        start += 1;
+    }
+    &log[start..]
+}
+
+fn http_client() -> reqwest::Client {
+    crate::config::build_runtime_proxy_client_with_timeouts("integration.ci", API_TIMEOUT_SECS, 10)
+}
+
+/// Fetch the log of the first failed job for a run via the forge API.
+pub async fn fetch_failure_log(ci: &CiConfig, failure: &CiFailure) -> Result<String> {
+    match failure {
+        CiFailure::GitHub { repo, run_id, .. } => {
+            let token = ci
+                .github_token
+                .as_deref()
+                .context("[ci] github_token is required to fetch GitHub job logs")?;
+            let client = http_client();
+            let jobs: Value = client
+                .get(format!(
+                    "https://api.github.com/repos/{repo}/actions/runs/{run_id}/jobs?filter=latest"
+                ))
+                .bearer_auth(token)
+                .header("Accept", "application/vnd.github+json")
+                .header("User-Agent", "zeroclaw")
+                .send()
+                .await
+                .context("Failed to list workflow run jobs")?
+                .error_for_status()
+                .context("GitHub jobs API returned an error")?
+                .json()
+                .await
+                .context("Failed to parse GitHub jobs response")?;
+            let job = jobs
+                .get("jobs")
+                .and_then(Value::as_array)
+                .and_then(|jobs| {
+                    jobs.iter()
+                        .find(|j| j.get("conclusion").and_then(Value::as_str) == Some("failure"))
+                })
+                .context("No failed job found in workflow run")?;
+            let job_id = job
+                .get("id")
+                .and_then(Value::as_u64)
+                .context("Failed job is missing an id")?;
+            let job_name = job
+                .get("name")
+                .and_then(Value::as_str)
+                .unwrap_or("(unnamed job)");
+            let log = client
+                .get(format!(
+                    "https://api.github.com/repos/{repo}/actions/jobs/{job_id}/logs"
+                ))
+                .bearer_auth(token)
+                .header("User-Agent", "zeroclaw")
+                .send()
+                .await
+                .context("Failed to fetch job log")?
+                .error_for_status()
+                .context("GitHub job log API returned an error")?
+                .text()
+                .await
+                .context("Failed to read job log body")?;
+            Ok(format!(
+                "Failed job: {job_name}\n{}",
+                log_tail(&log, MAX_LOG_BYTES)
+            ))
+        }
+        CiFailure::GitLab {
+            api_base,
+            project_id,
+            failed_jobs,
+            ..
+        } => {
+            let token = ci
+                .gitlab_token
+                .as_deref()
+                .context("[ci] gitlab_token is required to fetch GitLab job logs")?;
+            let job = failed_jobs
+                .first()
+                .context("No failed job listed in pipeline event")?;
+            let log = http_client()
+                .get(format!(
+                    "{api_base}/projects/{project_id}/jobs/{}/trace",
+                    job.id
+                ))
+                .header("PRIVATE-TOKEN", token)
+                .send()
+                .await
+                .context("Failed to fetch job trace")?
+                .error_for_status()
+                .context("GitLab job trace API returned an error")?
+                .text()
+                .await
+                .context("Failed to read job trace body")?;
+            Ok(format!(
+                "Failed job: {}\n{}",
+                job.name,
+                log_tail(&log, MAX_LOG_BYTES)
+            ))
+        }
+    }
+}
+
+/// Post the triage result as a PR/MR comment.
+///
+/// Returns `Ok(false)` when the run has no PR/MR context or no forge token
+/// is configured — the caller should fall back to channel delivery.
+pub async fn post_comment(ci: &CiConfig, failure: &CiFailure, body: &str) -> Result<bool> {
+    match failure {
+        CiFailure::GitHub {
+            repo,
+            pr_number: Some(pr_number),
+            ..
+        } => {
+            let Some(token) = ci.github_token.as_deref() else {
+                return Ok(false);
+            };
+            http_client()
+                .post(format!(
+                    "https://api.github.com/repos/{repo}/issues/{pr_number}/comments"
+                ))
+                .bearer_auth(token)
+                .header("Accept", "application/vnd.github+json")
+                .header("User-Agent", "zeroclaw")
+                .json(&serde_json::json!({ "body": body }))
+                .send()
+                .await
+                .context("Failed to post PR comment")?
+                .error_for_status()
+                .context("GitHub comment API returned an error")?;
+            Ok(true)
+        }
+        CiFailure::GitLab {
+            api_base,
+            project_id,
+            mr_iid: Some(mr_iid),
+            ..
+        } => {
+            let Some(token) = ci.gitlab_token.as_deref() else {
+                return Ok(false);
+            };
+            http_client()
+                .post(format!(
+                    "{api_base}/projects/{project_id}/merge_requests/{mr_iid}/notes"
+                ))
+                .header("PRIVATE-TOKEN", token)
+                .json(&serde_json::json!({ "body": body }))
+                .send()
+                .await
+                .context("Failed to post MR note")?
+                .error_for_status()
+                .context("GitLab notes API returned an error")?;
+            Ok(true)
+        }
+        _ => Ok(false),
+    }
+}
+
+/// System prompt for the failure triage summary.
+pub const TRIAGE_SYSTEM_PROMPT: &str = "You are a CI failure triage assistant. Given a failed \
+    pipeline description and the tail of the failing job log, produce a short report: (1) the \
+    most likely root cause, (2) the log lines that support it, (3) a concrete suggested fix. \
+    If the log is inconclusive, say so and list what to check next. Do not invent errors that \
+    are not in the log.";
+
+/// Build the user prompt for the triage request.
+pub fn build_triage_prompt(failure: &CiFailure, log_excerpt: &str) -> String {
+    format!(
+        "{}\n\nJob log (tail):\n```\n{log_excerpt}\n```",
+        failure.describe()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn github_failure_payload() -> Value {
+        serde_json::json!({
+            "action": "completed",
+            "workflow_run": {
+                "id": 42,
+                "name": "ci",
+                "head_branch": "feature-x",
+                "conclusion": "failure",
+                "html_url": "https://example.com/zeroclaw_project/actions/runs/42",
+                "pull_requests": [{"number": 7}]
+            },
+            "repository": {"full_name": "zeroclaw_user/zeroclaw_project"}
+        })
+    }
+
+    fn gitlab_failure_payload() -> Value {
+        serde_json::json!({
+            "object_kind": "pipeline",
+            "object_attributes": {
+                "id": 99,
+                "ref": "main",
+                "status": "failed",
+                "url": "https://gitlab.example.com/zeroclaw_project/-/pipelines/99"
+            },
+            "project": {
+                "id": 12,
+                "web_url": "https://gitlab.example.com/group/zeroclaw_project"
+            },
+            "merge_request": {"iid": 3},
+            "builds": [
+                {"id": 500, "name": "test", "status": "failed"},
+                {"id": 501, "name": "lint", "status": "success"}
+            ]
+        })
+    }
+
+    #[test]
+    fn parses_github_workflow_run_failure() {
+        let failure = parse_event(Some("workflow_run"), None, &github_failure_payload())
+            .unwrap()
+            .expect("failure should be extracted");
+        match failure {
+            CiFailure::GitHub {
+                repo,
+                run_id,
+                branch,
+                pr_number,
+                ..
+            } => {
+                assert_eq!(repo, "zeroclaw_user/zeroclaw_project");
+                assert_eq!(run_id, 42);
+                assert_eq!(branch, "feature-x");
+                assert_eq!(pr_number, Some(7));
+            }
+            CiFailure::GitLab { .. } => panic!("expected GitHub failure"),
+        }
+    }
+
+    #[test]
+    fn ignores_github_success_and_in_progress_runs() {
+        let mut payload = github_failure_payload();
+        payload["workflow_run"]["conclusion"] = Value::String("success".into());
+        let result = parse_event(Some("workflow_run"), None, &payload).unwrap();
+        assert!(result.is_none());
+
+        let mut payload = github_failure_payload();
+        payload["action"] = Value::String("requested".into());
+        let result = parse_event(Some("workflow_run"), None, &payload).unwrap();
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn parses_gitlab_pipeline_failure() {
+        let failure = parse_event(None, Some("Pipeline Hook"), &gitlab_failure_payload())
+            .unwrap()
+            .expect("failure should be extracted");
+        match failure {
+            CiFailure::GitLab {
+                api_base,
+                project_id,
+                pipeline_id,
+                failed_jobs,
+                mr_iid,
+                ..
+            } => {
+                assert_eq!(api_base, "https://gitlab.example.com/api/v4");
+                assert_eq!(project_id, 12);
+                assert_eq!(pipeline_id, 99);
+                assert_eq!(failed_jobs.len(), 1);
+                assert_eq!(failed_jobs[0].id, 500);
+                assert_eq!(mr_iid, Some(3));
+            }
+            CiFailure::GitHub { .. } => panic!("expected GitLab failure"),
+        }
+    }
+
+    #[test]
+    fn ignores_gitlab_non_failed_pipelines() {
+        let mut payload = gitlab_failure_payload();
+        payload["object_attributes"]["status"] = Value::String("success".into());
+        let result = parse_event(None, Some("Pipeline Hook"), &payload).unwrap();
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn rejects_unrecognized_events() {
+        let payload = serde_json::json!({"message": "hello"});
+        assert!(parse_event(None, None, &payload).is_err());
+        assert!(parse_event(Some("push"), None, &payload).is_err());
+    }
+
+    #[test]
+    fn api_base_handles_custom_ports() {
+        assert_eq!(
+            api_base_from_project_url("https://gitlab.example.com:8443/group/proj").unwrap(),
+            "https://gitlab.example.com:8443/api/v4"
+        );
+        assert!(api_base_from_project_url("not a url").is_err());
+    }
+
+    #[test]
+    fn log_tail_keeps_last_bytes_on_char_boundary() {
+        assert_eq!(log_tail("short", 100), "short");
+        let log = format!("{}END", "x".repeat(200));
+        assert_eq!(log_tail(&log, 3), "END");
+        // Multi-byte char straddling the cut point is dropped, not split.
+        let log = format!("{}é tail", "x".repeat(100));
+        assert!(log_tail(&log, 7).is_char_boundary(0));
+    }
+
+    #[test]
+    fn triage_prompt_includes_run_context_and_log() {
+        let failure = parse_event(Some("workflow_run"), None, &github_failure_payload())
+            .unwrap()
+            .unwrap();
+        let prompt = build_triage_prompt(&failure, "error: it broke");
+        assert!(prompt.contains("zeroclaw_user/zeroclaw_project"));
+        assert!(prompt.contains("error: it broke"));
+    }
+}
@@ -0,0 +1,247 @@
+//! Comment-preserving edits for `config.toml` (`zeroclaw config get/set/unset`).
+//!
+//! Edits operate on the raw file text via `toml_edit` so user comments and
+//! formatting survive, then the result is re-validated against the typed
+//! [`Config`](crate::config::Config) schema before anything is written.
+
+use anyhow::{Context, Result};
+use std::fmt::Write as _;
+use toml_edit::{DocumentMut, Item, Table};
+
+/// Split a dotted key path (`gateway.port`) into segments, rejecting empty
+/// segments so typos like `gateway..port` fail fast.
+fn split_key(key: &str) -> Result<Vec<&str>> {
+    let parts: Vec<&str> = key.split('.').collect();
+    if key.trim().is_empty() || parts.iter().any(|part| part.trim().is_empty()) {
+        anyhow::bail!("Invalid config key '{key}' (expected a dotted path like 'gateway.port')");
+    }
+    Ok(parts)
+}
+
+fn parse_document(contents: &str) -> Result<DocumentMut> {
+    contents
+        .parse::<DocumentMut>()
+        .context("Failed to parse config file")
+}
+
+/// Look up a dotted key; returns the TOML-rendered value when present.
+pub fn get_value(contents: &str, key: &str) -> Result<Option<String>> {
+    let doc = parse_document(contents)?;
+    let parts = split_key(key)?;
+
+    let mut item: &Item = doc.as_item();
+    for part in parts {
+        let Some(next) = item.as_table_like().and_then(|table| table.get(part)) else {
+            return Ok(None);
+        };
+        item = next;
+    }
+    Ok(Some(item.to_string().trim().to_string()))
+}
+
+/// Set a dotted key, creating intermediate tables as needed. The value is
+/// parsed as TOML (`true`, `0.9`, `["a"]`, `"quoted"`); anything that does
+/// not parse is treated as a plain string so bare words work unquoted.
+pub fn set_value(contents: &str, key: &str, raw_value: &str) -> Result<String> {
+    let mut doc = parse_document(contents)?;
+    let parts = split_key(key)?;
+    let (last, parents) = parts
+        .split_last()
+        .expect("split_key returns at least one segment");
+
+    let value = raw_value
+        .parse::<toml_edit::Value>()
+        .unwrap_or_else(|_| toml_edit::Value::from(raw_value));
+
+    let mut table = doc.as_table_mut();
+    for part in parents {
+        let item = table.entry(part).or_insert_with(|| {
+            let mut implicit = Table::new();
+            implicit.set_implicit(true);
+            Item::Table(implicit)
+        });
+        table = item.as_table_mut().ok_or_else(|| {
+            anyhow::anyhow!("Config key '{key}' conflicts with a non-table value at '{part}'")
+        })?;
+    }
+    table[last] = toml_edit::value(value);
+
+    Ok(doc.to_string())
+}
+
+/// Remove a dotted key; errors when the key is not set.
+pub fn unset_value(contents: &str, key: &str) -> Result<String> {
+    let mut doc = parse_document(contents)?;
+    let parts = split_key(key)?;
+    let (last, parents) = parts
+        .split_last()
+        .expect("split_key returns at least one segment");
+
+    let mut table = doc.as_table_mut();
+    for part in parents {
+        table = table
+            .get_mut(part)
+            .and_then(Item::as_table_mut)
+            .ok_or_else(|| anyhow::anyhow!("Config key '{key}' is not set"))?;
+    }
+    if table.remove(last).is_none() {
+        anyhow::bail!("Config key '{key}' is not set");
+    }
+
+    Ok(doc.to_string())
+}
+
+/// Validate edited contents against the typed config schema before writing.
+///
+/// When `expect_key` is given, the key must survive a deserialize/serialize
+/// round-trip — keys that serde silently drops are not part of the schema,
+/// so a typo like `gatway.port` becomes a hard error instead of dead config.
+pub fn validate_contents(contents: &str, expect_key: Option<&str>) -> Result<()> {
+    let config: super::Config =
+        toml::from_str(contents).context("Edited config does not match the schema")?;
+    config.validate()?;
+
+    if let Some(key) = expect_key {
+        let round_trip =
+            toml::Value::try_from(&config).context("Failed to re-serialize edited config")?;
+        let mut value = Some(&round_trip);
+        for part in split_key(key)? {
+            value = value.and_then(|v| v.get(part));
+        }
+        if value.is_none() {
+            anyhow::bail!("Config key '{key}' is not recognized by the config schema");
+        }
+    }
+    Ok(())
+}
+
+/// Render a unified-style line diff (`-`/`+` prefixes) between old and new
+/// contents. Uses a one-line lookahead to resync after single insertions or
+/// removals, which covers the single-key edits made by `set`/`unset`.
+pub fn render_diff(old: &str, new: &str) -> String {
+    let old_lines: Vec<&str> = old.lines().collect();
+    let new_lines: Vec<&str> = new.lines().collect();
+    let mut diff = String::new();
+    let (mut i, mut j) = (0, 0);
+
+    while i < old_lines.len() && j < new_lines.len() {
+        if old_lines[i] == new_lines[j] {
+            i += 1;
+            j += 1;
+        } else if j + 1 < new_lines.len() && old_lines[i] == new_lines[j + 1] {
+            let _ = writeln!(diff, "+ {}", new_lines[j]);
+            j += 1;
+        } else if i + 1 < old_lines.len() && old_lines[i + 1] == new_lines[j] {
+            let _ = writeln!(diff, "- {}", old_lines[i]);
+            i += 1;
+        } else {
+            let _ = writeln!(diff, "- {}", old_lines[i]);
+            let _ = writeln!(diff, "+ {}", new_lines[j]);
+            i += 1;
+            j += 1;
+        }
+    }
+    for line in &old_lines[i..] {
+        let _ = writeln!(diff, "- {line}");
+    }
+    for line in &new_lines[j..] {
+        let _ = writeln!(diff, "+ {line}");
+    }
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "\
+# my base config
+default_provider = \"openrouter\"
+default_model = \"base-model\"
+default_temperature = 0.7
+
+[gateway]
+# keep loopback unless tunneled
+host = \"127.0.0.1\"
+port = 3000
+";
+
+    #[test]
+    fn get_reads_top_level_and_nested_keys() {
+        assert_eq!(
+            get_value(SAMPLE, "default_model").unwrap().as_deref(),
+            Some("\"base-model\"")
+        );
+        assert_eq!(
+            get_value(SAMPLE, "gateway.port").unwrap().as_deref(),
+            Some("3000")
+        );
+        assert_eq!(get_value(SAMPLE, "gateway.missing").unwrap(), None);
+    }
+
+    #[test]
+    fn set_preserves_comments_and_parses_toml_values() {
+        let updated = set_value(SAMPLE, "gateway.port", "4242").unwrap();
+        assert!(updated.contains("# my base config"));
+        assert!(updated.contains("# keep loopback unless tunneled"));
+        assert!(updated.contains("port = 4242"));
+
+        let updated = set_value(SAMPLE, "default_model", "gpt-5").unwrap();
+        assert!(
+            updated.contains("default_model = \"gpt-5\""),
+            "bare words become strings: {updated}"
+        );
+    }
+
+    #[test]
+    fn set_creates_missing_intermediate_tables() {
+        let updated = set_value(SAMPLE, "web_search.enabled", "true").unwrap();
+        assert!(updated.contains("[web_search]"));
+        assert!(updated.contains("enabled = true"));
+    }
+
+    #[test]
+    fn unset_removes_key_and_errors_when_absent() {
+        let updated = unset_value(SAMPLE, "gateway.port").unwrap();
+        assert!(!updated.contains("port = 3000"));
+        assert!(updated.contains("host = \"127.0.0.1\""));
+
+        assert!(unset_value(SAMPLE, "gateway.port.nested").is_err());
+        assert!(unset_value(SAMPLE, "no_such_key").is_err());
+    }
+
+    #[test]
+    fn invalid_key_paths_are_rejected() {
+        assert!(get_value(SAMPLE, "").is_err());
+        assert!(get_value(SAMPLE, "gateway..port").is_err());
+    }
+
+    #[test]
+    fn validate_rejects_wrong_types_and_unknown_keys() {
+        let bad_type = set_value(SAMPLE, "gateway.port", "\"not-a-port\"").unwrap();
+        assert!(validate_contents(&bad_type, Some("gateway.port")).is_err());
+
+        let typo = set_value(SAMPLE, "gatway.port", "4242").unwrap();
+        assert!(validate_contents(&typo, Some("gatway.port")).is_err());
+
+        let good = set_value(SAMPLE, "gateway.port", "4242").unwrap();
+        validate_contents(&good, Some("gateway.port")).unwrap();
+    }
+
+    #[test]
+    fn render_diff_marks_changed_inserted_and_removed_lines() {
+        let updated = set_value(SAMPLE, "gateway.port", "4242").unwrap();
+        let diff = render_diff(SAMPLE, &updated);
+        assert!(diff.contains("- port = 3000"));
+        assert!(diff.contains("+ port = 4242"));
+        assert!(
+            !diff.contains("host"),
+            "unchanged lines stay out of the diff: {diff}"
+        );
+
+        let removed = unset_value(SAMPLE, "gateway.port").unwrap();
+        let diff = render_diff(SAMPLE, &removed);
+        assert!(diff.contains("- port = 3000"));
+        assert!(!diff.contains('+'));
+    }
+}
@@ -1,19 +1,28 @@
+pub mod edit;
 pub mod schema;
+pub mod validate;
+pub mod workspaces;
 
 #[allow(unused_imports)]
 pub use schema::{
     apply_runtime_proxy_to_builder, build_runtime_proxy_client,
     build_runtime_proxy_client_with_timeouts, runtime_proxy_config, set_runtime_proxy_config,
-    AgentConfig, AuditConfig, AutonomyConfig, BrowserComputerUseConfig, BrowserConfig,
-    ChannelsConfig, ClassificationRule, ComposioConfig, Config, CostConfig, CronConfig,
-    DelegateAgentConfig, DiscordConfig, DockerRuntimeConfig, EmbeddingRouteConfig, GatewayConfig,
-    HardwareConfig, HardwareTransport, HeartbeatConfig, HttpRequestConfig, IMessageConfig,
-    IdentityConfig, LarkConfig, MatrixConfig, MemoryConfig, ModelRouteConfig, MultimodalConfig,
-    ObservabilityConfig, PeripheralBoardConfig, PeripheralsConfig, ProxyConfig, ProxyScope,
-    QueryClassificationConfig, ReliabilityConfig, ResourceLimitsConfig, RuntimeConfig,
-    SandboxBackend, SandboxConfig, SchedulerConfig, SecretsConfig, SecurityConfig, SkillsConfig,
-    SlackConfig, StorageConfig, StorageProviderConfig, StorageProviderSection, StreamMode,
-    TelegramConfig, TunnelConfig, WebSearchConfig, WebhookConfig,
+    AgentConfig, AuditConfig, AutonomyConfig, BrowserComputerUseConfig, BrowserConfig, CacheConfig,
+    ChannelsConfig, CiConfig, ClassificationRule, ComposioConfig, Config, ContainersConfig,
+    CostConfig, CronConfig, DelegateAgentConfig, DelegationConfig, DigestConfig, DiscordConfig,
+    DockerRuntimeConfig, EmbeddingRouteConfig, EventBusConfig, EventsConfig, GatewayConfig,
+    GatewayOidcConfig, HardwareConfig, HardwareTransport, HeartbeatConfig, HeartbeatProbeConfig,
+    HookRouteConfig, HttpRequestConfig, IMessageConfig, IdentityConfig, IntegrationSettings,
+    IntegrationsConfig, LarkConfig, MatrixConfig, MemoryConfig, ModelPricing, ModelRouteConfig,
+    MonitorsConfig, MultimodalConfig, NetworkScanConfig, NodesConfig, NotesConfig,
+    ObservabilityConfig, PagerConfig, PeripheralBoardConfig, PeripheralsConfig, PolicyOutcome,
+    PolicyRuleConfig, ProviderPoolEntry, ProviderRateLimit, ProvidersConfig, ProxyConfig,
+    ProxyScope, QueryClassificationConfig, QuotaConfig, QuotaLimits, RateLimitsConfig,
+    RedactionConfig, ReliabilityConfig, ResourceLimitsConfig, RunCodeConfig, RuntimeConfig,
+    SandboxBackend, SandboxConfig, SchedulerConfig, SecretsBackend, SecretsConfig, SecurityConfig,
+    SelfReportConfig, SkillsConfig, SlackConfig, StorageConfig, StorageProviderConfig,
+    StorageProviderSection, StreamMode, TelegramConfig, TunnelConfig, UiConfig, UserBindingConfig,
+    UserRole, WebSearchConfig, WebhookConfig, WorkerNodeConfig,
 };
 
 #[cfg(test)]
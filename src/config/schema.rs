@@ -74,6 +74,14 @@ pub struct Config {
     #[serde(default)]
     pub observability: ObservabilityConfig,
 
+    /// Outbound event publishing configuration (`[events]`).
+    #[serde(default)]
+    pub events: EventsConfig,
+
+    /// CLI output configuration (`[ui]`).
+    #[serde(default)]
+    pub ui: UiConfig,
+
     /// Autonomy and security policy configuration (`[autonomy]`).
     #[serde(default)]
     pub autonomy: AutonomyConfig,
@@ -106,6 +114,10 @@ pub struct Config {
     #[serde(default)]
     pub embedding_routes: Vec<EmbeddingRouteConfig>,
 
+    /// Multi-provider load balancing (`[providers]`).
+    #[serde(default)]
+    pub providers: ProvidersConfig,
+
     /// Automatic query classification — maps user messages to model hints.
     #[serde(default)]
     pub query_classification: QueryClassificationConfig,
@@ -142,6 +154,14 @@ pub struct Config {
     #[serde(default)]
     pub composio: ComposioConfig,
 
+    /// First-party integrations: GitHub, Google Calendar, Notion (`[integrations]`).
+    #[serde(default)]
+    pub integrations: IntegrationsConfig,
+
+    /// Note capture backend for the `capture_note` tool (`[notes]`).
+    #[serde(default)]
+    pub notes: NotesConfig,
+
     /// Secrets encryption configuration (`[secrets]`).
     #[serde(default)]
     pub secrets: SecretsConfig,
@@ -154,6 +174,38 @@ pub struct Config {
     #[serde(default)]
     pub http_request: HttpRequestConfig,
 
+    /// Code execution sandbox tool configuration (`[run_code]`).
+    #[serde(default)]
+    pub run_code: RunCodeConfig,
+
+    /// Container management tool configuration (`[containers]`).
+    #[serde(default)]
+    pub containers: ContainersConfig,
+
+    /// CI failure triage configuration (`[ci]`).
+    #[serde(default)]
+    pub ci: CiConfig,
+
+    /// On-call pager integration configuration (`[pager]`).
+    #[serde(default)]
+    pub pager: PagerConfig,
+
+    /// Local network scanner configuration (`[network_scan]`).
+    #[serde(default)]
+    pub network_scan: NetworkScanConfig,
+
+    /// Domain monitors configuration (`[monitors]`).
+    #[serde(default)]
+    pub monitors: MonitorsConfig,
+
+    /// Weekly daemon self-report configuration (`[self_report]`).
+    #[serde(default)]
+    pub self_report: SelfReportConfig,
+
+    /// Daily activity digest configuration (`[digest]`).
+    #[serde(default)]
+    pub digest: DigestConfig,
+
     /// Multimodal (image) handling configuration (`[multimodal]`).
     #[serde(default)]
     pub multimodal: MultimodalConfig,
@@ -174,6 +226,14 @@ pub struct Config {
     #[serde(default)]
     pub cost: CostConfig,
 
+    /// Per-channel / per-sender usage quota configuration (`[quota]`).
+    #[serde(default)]
+    pub quota: QuotaConfig,
+
+    /// Provider response cache configuration (`[cache]`).
+    #[serde(default)]
+    pub cache: CacheConfig,
+
     /// Peripheral board configuration for hardware integration (`[peripherals]`).
     #[serde(default)]
     pub peripherals: PeripheralsConfig,
@@ -182,6 +242,19 @@ pub struct Config {
     #[serde(default)]
     pub agents: HashMap<String, DelegateAgentConfig>,
 
+    /// Delegation guard configuration (`[delegation]`).
+    #[serde(default)]
+    pub delegation: DelegationConfig,
+
+    /// Federated worker node configuration (`[nodes]`).
+    #[serde(default)]
+    pub nodes: NodesConfig,
+
+    /// Security configuration (`[security]`): sandboxing, resource
+    /// limits, and hash-chained audit logging.
+    #[serde(default)]
+    pub security: SecurityConfig,
+
     /// Hardware configuration (wizard-driven physical world setup).
     #[serde(default)]
     pub hardware: HardwareConfig,
@@ -217,6 +290,10 @@ pub struct DelegateAgentConfig {
     /// Maximum tool-call iterations in agentic mode.
     #[serde(default = "default_max_tool_iterations")]
     pub max_iterations: usize,
+    /// Worker node that should execute this agent remotely (see `[nodes]`).
+    /// `None` runs the agent locally.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<String>,
 }
 
 fn default_max_depth() -> u32 {
@@ -227,6 +304,96 @@ fn default_max_tool_iterations() -> usize {
     10
 }
 
+/// Delegation guard configuration (`[delegation]` section).
+///
+/// Global caps enforced by the `delegate` tool on top of each agent's
+/// `max_depth`, so runaway recursive delegation cannot burn budget. Blocked
+/// attempts are recorded in the delegation log. Set a limit to `0` to
+/// disable that guard.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DelegationConfig {
+    /// Global cap on delegation nesting depth; the effective limit is the
+    /// smaller of this and the target agent's `max_depth`
+    #[serde(default = "default_delegation_max_depth")]
+    pub max_depth: u32,
+    /// Max sub-agents running concurrently within one run
+    #[serde(default = "default_delegation_max_concurrent")]
+    pub max_concurrent: usize,
+    /// Max delegation attempts per run
+    #[serde(default = "default_delegation_max_per_run")]
+    pub max_per_run: usize,
+}
+
+impl Default for DelegationConfig {
+    fn default() -> Self {
+        Self {
+            max_depth: default_delegation_max_depth(),
+            max_concurrent: default_delegation_max_concurrent(),
+            max_per_run: default_delegation_max_per_run(),
+        }
+    }
+}
+
+fn default_delegation_max_depth() -> u32 {
+    5
+}
+
+fn default_delegation_max_concurrent() -> usize {
+    4
+}
+
+fn default_delegation_max_per_run() -> usize {
+    32
+}
+
+// ── Federated Worker Nodes ───────────────────────────────────────
+
+/// A remote ZeroClaw worker that can execute delegations (`[[nodes.workers]]`).
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct WorkerNodeConfig {
+    /// Node name referenced by agents via `node = "..."`.
+    pub name: String,
+    /// Base URL of the worker's gateway (e.g. `http://gpu-box:3000`).
+    pub url: String,
+    /// Bearer token accepted by the worker's gateway (a paired token on the
+    /// worker). Required unless the worker runs with pairing disabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+}
+
+/// Federated multi-node configuration (`[nodes]` section).
+///
+/// Lets a primary daemon dispatch delegations to worker ZeroClaw instances
+/// on other machines (e.g. a GPU box running local models) over their
+/// authenticated gateways. Agents opt in per-agent via `node = "<name>"`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct NodesConfig {
+    /// Master switch for remote dispatch. Default: `false`.
+    #[serde(default)]
+    pub enabled: bool,
+    /// Fall back to local execution when a worker is unreachable.
+    /// Default: `true`.
+    #[serde(default = "default_nodes_fallback_local")]
+    pub fallback_local: bool,
+    /// Worker node definitions.
+    #[serde(default)]
+    pub workers: Vec<WorkerNodeConfig>,
+}
+
+impl Default for NodesConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            fallback_local: default_nodes_fallback_local(),
+            workers: Vec::new(),
+        }
+    }
+}
+
+fn default_nodes_fallback_local() -> bool {
+    true
+}
+
 // ── Hardware Config (wizard-driven) ─────────────────────────────
 
 /// Hardware transport mode.
@@ -316,6 +483,15 @@ pub struct AgentConfig {
     /// Tool dispatch strategy (e.g. `"auto"`). Default: `"auto"`.
     #[serde(default = "default_agent_tool_dispatcher")]
     pub tool_dispatcher: String,
+    /// Max bytes of a single tool result kept in the conversation; larger
+    /// outputs are middle-elided and spilled to the workspace. `0` disables
+    /// the byte limit. Default: `65536`.
+    #[serde(default = "default_agent_tool_output_max_bytes")]
+    pub tool_output_max_bytes: usize,
+    /// Max lines of a single tool result kept in the conversation. `0`
+    /// disables the line limit. Default: `1000`.
+    #[serde(default = "default_agent_tool_output_max_lines")]
+    pub tool_output_max_lines: usize,
 }
 
 fn default_agent_max_tool_iterations() -> usize {
@@ -330,6 +506,14 @@ fn default_agent_tool_dispatcher() -> String {
     "auto".into()
 }
 
+fn default_agent_tool_output_max_bytes() -> usize {
+    65_536
+}
+
+fn default_agent_tool_output_max_lines() -> usize {
+    1_000
+}
+
 impl Default for AgentConfig {
     fn default() -> Self {
         Self {
@@ -338,6 +522,8 @@ impl Default for AgentConfig {
             max_history_messages: default_agent_max_history_messages(),
             parallel_tools: false,
             tool_dispatcher: default_agent_tool_dispatcher(),
+            tool_output_max_bytes: default_agent_tool_output_max_bytes(),
+            tool_output_max_lines: default_agent_tool_output_max_lines(),
         }
     }
 }
@@ -421,6 +607,38 @@ pub struct IdentityConfig {
     /// Inline AIEOS JSON (alternative to file path)
     #[serde(default)]
     pub aieos_inline: Option<String>,
+    /// Channel users with roles (`[[identity.users]]`). Empty = role system
+    /// disabled; every allowed channel sender keeps full tool access.
+    #[serde(default)]
+    pub users: Vec<UserBindingConfig>,
+}
+
+/// One channel user bound to a role (`[[identity.users]]` entry).
+///
+/// Once any user is configured, senders without a matching binding are
+/// treated as `viewer` (least privilege).
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct UserBindingConfig {
+    /// Display name used in logs and errors (neutral label, not identity data)
+    pub name: String,
+    /// Role granted to this user
+    pub role: UserRole,
+    /// Channel identity bindings as `"channel:identity"` pairs,
+    /// e.g. `"telegram:123456789"` or `"slack:U0AAAAAAA"`
+    #[serde(default)]
+    pub bindings: Vec<String>,
+}
+
+/// Role granted to a bound channel user. Determines tool access per message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum UserRole {
+    /// Full tool access, including runtime-altering tools (cron, proxy config)
+    Admin,
+    /// All tools except runtime-altering admin tools
+    Operator,
+    /// Read-only tools only
+    Viewer,
 }
 
 fn default_identity_format() -> String {
@@ -433,6 +651,7 @@ impl Default for IdentityConfig {
             format: default_identity_format(),
             aieos_path: None,
             aieos_inline: None,
+            users: Vec::new(),
         }
     }
 }
@@ -462,6 +681,11 @@ pub struct CostConfig {
     #[serde(default)]
     pub allow_override: bool,
 
+    /// Warn once when a single interactive session's cost crosses this USD
+    /// threshold (default: off)
+    #[serde(default)]
+    pub session_warn_usd: Option<f64>,
+
     /// Per-model pricing (USD per 1M tokens)
     #[serde(default)]
     pub prices: std::collections::HashMap<String, ModelPricing>,
@@ -479,6 +703,82 @@ pub struct ModelPricing {
     pub output: f64,
 }
 
+// ── Usage quotas ────────────────────────────────────────────────────
+
+/// Usage quota configuration (`[quota]` section).
+///
+/// Caps message/token/cost consumption per channel and per sender before a
+/// message is dispatched to the provider. Top-level limits apply to every
+/// channel and sender; `[quota.channels.<name>]` and `[quota.users.<id>]`
+/// entries override individual limits for one scope. Token counts are
+/// heuristic estimates and cost uses the `[cost].prices` table. Off by
+/// default.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct QuotaConfig {
+    /// Enable quota enforcement (default: false)
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Default limits for every channel and sender
+    #[serde(default, flatten)]
+    pub defaults: QuotaLimits,
+
+    /// Per-channel limit overrides, keyed by channel name (e.g. "telegram")
+    #[serde(default)]
+    pub channels: std::collections::HashMap<String, QuotaLimits>,
+
+    /// Per-sender limit overrides, keyed by sender ID
+    #[serde(default)]
+    pub users: std::collections::HashMap<String, QuotaLimits>,
+}
+
+/// One scope's quota limits; unset fields fall back to the `[quota]`
+/// defaults (and an unset default means unlimited).
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct QuotaLimits {
+    /// Max messages per rolling hour
+    #[serde(default)]
+    pub messages_per_hour: Option<u32>,
+
+    /// Max estimated tokens per UTC day
+    #[serde(default)]
+    pub tokens_per_day: Option<u64>,
+
+    /// Max estimated cost in USD per UTC day
+    #[serde(default)]
+    pub cost_per_day_usd: Option<f64>,
+}
+
+/// Provider response cache configuration (`[cache]` section).
+///
+/// When enabled, identical provider requests (same provider, model,
+/// normalized messages, and tool state) within the TTL are served from a
+/// local disk cache instead of re-billing tokens — useful for cron tasks
+/// and retried runs. Off by default.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CacheConfig {
+    /// Enable response caching (default: false)
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Seconds a cached response stays valid (default: 900)
+    #[serde(default = "default_cache_ttl_secs")]
+    pub ttl_secs: u64,
+}
+
+fn default_cache_ttl_secs() -> u64 {
+    900
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            ttl_secs: default_cache_ttl_secs(),
+        }
+    }
+}
+
 fn default_daily_limit() -> f64 {
     10.0
 }
@@ -499,6 +799,7 @@ impl Default for CostConfig {
             monthly_limit_usd: default_monthly_limit(),
             warn_at_percent: default_warn_percent(),
             allow_override: false,
+            session_warn_usd: None,
             prices: get_default_pricing(),
         }
     }
@@ -597,6 +898,10 @@ pub struct PeripheralsConfig {
     /// Place .md/.txt files named by board (e.g. nucleo-f401re.md, rpi-gpio.md).
     #[serde(default)]
     pub datasheet_dir: Option<String>,
+    /// Seconds between background telemetry samples pushed into the
+    /// telemetry buffer (0 disables sampling)
+    #[serde(default)]
+    pub telemetry_interval_secs: u64,
 }
 
 /// Configuration for a single peripheral board (e.g. STM32, RPi GPIO).
@@ -604,15 +909,19 @@ pub struct PeripheralsConfig {
 pub struct PeripheralBoardConfig {
     /// Board type: "nucleo-f401re", "rpi-gpio", "esp32", etc.
     pub board: String,
-    /// Transport: "serial", "native", "websocket"
+    /// Transport: "serial", "native", "network"
     #[serde(default = "default_peripheral_transport")]
     pub transport: String,
-    /// Path for serial: "/dev/ttyACM0", "/dev/ttyUSB0"
+    /// Path for serial ("/dev/ttyACM0") or network ("192.168.1.50:9900")
     #[serde(default)]
     pub path: Option<String>,
     /// Baud rate for serial (default: 115200)
     #[serde(default = "default_peripheral_baud")]
     pub baud: u32,
+    /// Auth token for network boards (stored encrypted when secrets.encrypt =
+    /// true). Network boards without a token are refused.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
 }
 
 fn default_peripheral_transport() -> String {
@@ -630,6 +939,7 @@ impl Default for PeripheralBoardConfig {
             transport: default_peripheral_transport(),
             path: None,
             baud: default_peripheral_baud(),
+            token: None,
         }
     }
 }
@@ -699,6 +1009,116 @@ pub struct GatewayConfig {
     /// Directory to serve when `serve_static_files = true` (default: `"./web-ui/dist"`).
     #[serde(default = "default_static_dir")]
     pub static_dir: String,
+
+    /// OIDC login for dashboard routes (`[gateway.oidc]` section).
+    #[serde(default)]
+    pub oidc: GatewayOidcConfig,
+
+    /// Inbound webhook routing rules (`[[gateway.hooks]]` entries).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub hooks: Vec<HookRouteConfig>,
+}
+
+/// A single inbound webhook routing rule (`[[gateway.hooks]]` entry).
+///
+/// Maps arbitrary inbound webhooks (GitHub, Stripe, Grafana alerts, ...)
+/// to an agent prompt or workflow invocation. Requests arrive at
+/// `POST /hooks/route/<path>`; the first rule whose `path` and header
+/// matchers both match handles the request.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct HookRouteConfig {
+    /// Route segment under `/hooks/route/` (e.g. `"stripe"`)
+    pub path: String,
+    /// Exact-match header requirements (e.g. `X-GitHub-Event = "push"`)
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub headers: HashMap<String, String>,
+    /// HMAC-SHA256 signing secret for this source; when set, the signature
+    /// header is verified against the raw request body before any processing
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secret: Option<String>,
+    /// Header carrying the HMAC signature (default `X-Hub-Signature-256`;
+    /// the value may be raw hex or `sha256=<hex>`)
+    #[serde(default = "default_hook_signature_header")]
+    pub signature_header: String,
+    /// Variables extracted from the JSON payload as dotted paths
+    /// (e.g. `title = "issue.title"`); referenced as `{title}` in `prompt`
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub extract: HashMap<String, String>,
+    /// Agent prompt template; `{var}` placeholders are filled from `extract`
+    /// and `{payload}` expands to the full JSON body
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prompt: Option<String>,
+    /// Workflow to invoke instead of a direct prompt; the rendered `prompt`
+    /// template (or the raw payload when unset) becomes the workflow input
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workflow: Option<String>,
+}
+
+fn default_hook_signature_header() -> String {
+    "X-Hub-Signature-256".to_string()
+}
+
+/// OIDC login configuration for gateway dashboard routes (`[gateway.oidc]`).
+///
+/// Generic OpenID Connect authorization-code flow with PKCE (S256) against
+/// any standards-compliant provider. Login is deny-by-default: only subjects
+/// or emails listed in `allowed_users` may sign in; `admin_users` maps a
+/// subset to the `admin` role, everyone else gets `viewer`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GatewayOidcConfig {
+    /// Enable OIDC login on dashboard routes (default: false)
+    #[serde(default)]
+    pub enabled: bool,
+    /// Issuer URL (e.g. `https://accounts.example.com`); discovery is
+    /// resolved from `{issuer}/.well-known/openid-configuration`
+    #[serde(default)]
+    pub issuer: String,
+    /// OAuth client ID registered with the provider
+    #[serde(default)]
+    pub client_id: String,
+    /// OAuth client secret; omit for public clients (PKCE is always used)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_secret: Option<String>,
+    /// External base URL the provider redirects back to (e.g. the tunnel
+    /// URL); the callback is `{redirect_base_url}/auth/callback`
+    #[serde(default)]
+    pub redirect_base_url: String,
+    /// Scopes requested at login
+    #[serde(default = "default_oidc_scopes")]
+    pub scopes: Vec<String>,
+    /// Emails or subjects allowed to sign in (empty = nobody can sign in)
+    #[serde(default)]
+    pub allowed_users: Vec<String>,
+    /// Subset of `allowed_users` granted the `admin` role
+    #[serde(default)]
+    pub admin_users: Vec<String>,
+    /// Session lifetime in minutes (default: 480 = 8 hours)
+    #[serde(default = "default_oidc_session_ttl_minutes")]
+    pub session_ttl_minutes: u64,
+}
+
+fn default_oidc_scopes() -> Vec<String> {
+    vec!["openid".into(), "email".into(), "profile".into()]
+}
+
+fn default_oidc_session_ttl_minutes() -> u64 {
+    480
+}
+
+impl Default for GatewayOidcConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            issuer: String::new(),
+            client_id: String::new(),
+            client_secret: None,
+            redirect_base_url: String::new(),
+            scopes: default_oidc_scopes(),
+            allowed_users: Vec::new(),
+            admin_users: Vec::new(),
+            session_ttl_minutes: default_oidc_session_ttl_minutes(),
+        }
+    }
 }
 
 fn default_static_dir() -> String {
@@ -755,6 +1175,8 @@ impl Default for GatewayConfig {
             cors_allowed_origins: Vec::new(),
             serve_static_files: false,
             static_dir: default_static_dir(),
+            oidc: GatewayOidcConfig::default(),
+            hooks: Vec::new(),
         }
     }
 }
@@ -791,6 +1213,123 @@ impl Default for ComposioConfig {
     }
 }
 
+// ── First-party integrations ────────────────────────────────────
+
+/// Settings for one first-party integration (`[integrations.<name>]` section).
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct IntegrationSettings {
+    /// Whether the integration's tools are registered with the agent
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// API token/credential (stored encrypted when secrets.encrypt = true)
+    #[serde(default)]
+    pub token: Option<String>,
+    /// Webhook secret verified on inbound automation hooks such as
+    /// `/hooks/github` (stored encrypted when secrets.encrypt = true)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook_secret: Option<String>,
+    /// Repositories (`owner/name`) allowed to trigger webhook automation;
+    /// empty denies all inbound events
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allowed_repos: Vec<String>,
+}
+
+impl Default for IntegrationSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            token: None,
+            webhook_secret: None,
+            allowed_repos: Vec::new(),
+        }
+    }
+}
+
+/// First-party integrations that register tools with the agent
+/// (`[integrations]` section). Each entry is configured via
+/// `zeroclaw integrations configure <name>` and toggled with
+/// `enable`/`disable`; see `src/integrations/` for the implementations.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct IntegrationsConfig {
+    /// GitHub issues/PRs via personal access token
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub github: Option<IntegrationSettings>,
+    /// Google Calendar events via OAuth access token
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub google_calendar: Option<IntegrationSettings>,
+    /// Notion pages/search via internal integration token
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notion: Option<IntegrationSettings>,
+}
+
+impl IntegrationsConfig {
+    /// All entries as `(config key, settings)` pairs, configured or not.
+    pub fn entries(&self) -> [(&'static str, Option<&IntegrationSettings>); 3] {
+        [
+            ("github", self.github.as_ref()),
+            ("google_calendar", self.google_calendar.as_ref()),
+            ("notion", self.notion.as_ref()),
+        ]
+    }
+
+    /// Mutable access to all entries, for secret encrypt/decrypt passes.
+    pub fn entries_mut(&mut self) -> [(&'static str, &mut Option<IntegrationSettings>); 3] {
+        [
+            ("github", &mut self.github),
+            ("google_calendar", &mut self.google_calendar),
+            ("notion", &mut self.notion),
+        ]
+    }
+
+    /// Look up one entry by config key; `None` for unknown names.
+    pub fn entry_mut(&mut self, name: &str) -> Option<&mut Option<IntegrationSettings>> {
+        match name {
+            "github" => Some(&mut self.github),
+            "google_calendar" => Some(&mut self.google_calendar),
+            "notion" => Some(&mut self.notion),
+            _ => None,
+        }
+    }
+}
+
+// ── Note capture (`capture_note` tool) ──────────────────────────
+
+/// Note capture configuration (`[notes]` section).
+///
+/// Selects where the `capture_note` tool appends agent-captured notes: a
+/// local Obsidian vault or a Notion database (reusing the
+/// `[integrations.notion]` token).
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct NotesConfig {
+    /// Register the `capture_note` tool with the agent
+    #[serde(default)]
+    pub enabled: bool,
+    /// Capture backend: "obsidian" (default) or "notion"
+    #[serde(default = "default_notes_backend")]
+    pub backend: String,
+    /// Obsidian vault directory notes are appended under (obsidian backend)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub obsidian_vault_dir: Option<String>,
+    /// Notion database pages are created in (notion backend)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notion_database_id: Option<String>,
+}
+
+fn default_notes_backend() -> String {
+    "obsidian".to_string()
+}
+
+impl Default for NotesConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            backend: default_notes_backend(),
+            obsidian_vault_dir: None,
+            notion_database_id: None,
+        }
+    }
+}
+
 // ── Secrets (encrypted credential store) ────────────────────────
 
 /// Secrets encryption configuration (`[secrets]` section).
@@ -799,14 +1338,44 @@ pub struct SecretsConfig {
     /// Enable encryption for API keys and tokens in config.toml
     #[serde(default = "default_true")]
     pub encrypt: bool,
+    /// Where secrets are written: the encrypted key file (default) or the
+    /// OS keychain (macOS Keychain / Linux Secret Service).
+    #[serde(default)]
+    pub backend: SecretsBackend,
 }
 
 impl Default for SecretsConfig {
     fn default() -> Self {
-        Self { encrypt: true }
+        Self {
+            encrypt: true,
+            backend: SecretsBackend::default(),
+        }
     }
 }
 
+/// Secret storage backend selection (`[secrets] backend`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum SecretsBackend {
+    /// ChaCha20-Poly1305 ciphertext in config.toml, key in `.secret_key` (default)
+    #[default]
+    EncryptedFile,
+    /// OS credential vault; config.toml holds only `keychain:<account>` references
+    Keychain,
+}
+
+// ── UI (CLI output) ─────────────────────────────────────────────
+
+/// CLI output configuration (`[ui]` section).
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct UiConfig {
+    /// Screen-reader friendly output: replaces emoji and box-drawing
+    /// tables in `status`, `doctor`, and `delegations` reports with plain
+    /// labeled text. Also available per-invocation via `--accessible`.
+    #[serde(default)]
+    pub accessible: bool,
+}
+
 // ── Browser (friendly-service browsing only) ───────────────────
 
 /// Computer-use sidecar configuration (`[browser.computer_use]` section).
@@ -938,8 +1507,427 @@ fn default_http_max_response_size() -> usize {
     1_000_000 // 1MB
 }
 
-fn default_http_timeout_secs() -> u64 {
-    30
+fn default_http_timeout_secs() -> u64 {
+    30
+}
+
+// ── Code execution sandbox ──────────────────────────────────────
+
+/// Code execution sandbox tool configuration (`[run_code]` section).
+///
+/// Runs short Python/JavaScript/Rust snippets inside a disposable container
+/// (Docker/Podman CLI) with CPU, memory, and time limits. Network access is
+/// disabled by default. Disabled unless explicitly enabled.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct RunCodeConfig {
+    /// Enable the `run_code` tool
+    #[serde(default)]
+    pub enabled: bool,
+    /// Container CLI binary ("docker" or "podman")
+    #[serde(default = "default_run_code_engine")]
+    pub engine: String,
+    /// Snippet execution timeout in seconds (default: 30)
+    #[serde(default = "default_run_code_timeout_secs")]
+    pub timeout_secs: u64,
+    /// Container memory limit in MB (default: 256)
+    #[serde(default = "default_run_code_memory_limit_mb")]
+    pub memory_limit_mb: u64,
+    /// Container CPU limit (default: 1.0)
+    #[serde(default = "default_run_code_cpu_limit")]
+    pub cpu_limit: f64,
+    /// Container network mode (default: "none" — no network access)
+    #[serde(default = "default_run_code_network")]
+    pub network: String,
+    /// Container image for Python snippets
+    #[serde(default = "default_run_code_python_image")]
+    pub python_image: String,
+    /// Container image for JavaScript snippets
+    #[serde(default = "default_run_code_javascript_image")]
+    pub javascript_image: String,
+    /// Container image for Rust snippets
+    #[serde(default = "default_run_code_rust_image")]
+    pub rust_image: String,
+}
+
+impl Default for RunCodeConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            engine: default_run_code_engine(),
+            timeout_secs: default_run_code_timeout_secs(),
+            memory_limit_mb: default_run_code_memory_limit_mb(),
+            cpu_limit: default_run_code_cpu_limit(),
+            network: default_run_code_network(),
+            python_image: default_run_code_python_image(),
+            javascript_image: default_run_code_javascript_image(),
+            rust_image: default_run_code_rust_image(),
+        }
+    }
+}
+
+fn default_run_code_engine() -> String {
+    "docker".to_string()
+}
+
+fn default_run_code_timeout_secs() -> u64 {
+    30
+}
+
+fn default_run_code_memory_limit_mb() -> u64 {
+    256
+}
+
+fn default_run_code_cpu_limit() -> f64 {
+    1.0
+}
+
+fn default_run_code_network() -> String {
+    "none".to_string()
+}
+
+fn default_run_code_python_image() -> String {
+    "python:3.12-alpine".to_string()
+}
+
+fn default_run_code_javascript_image() -> String {
+    "node:22-alpine".to_string()
+}
+
+fn default_run_code_rust_image() -> String {
+    "rust:1-alpine".to_string()
+}
+
+// ── Container management ────────────────────────────────────────
+
+/// Container management tool configuration (`[containers]` section).
+///
+/// Exposes read-only container operations (list, images, logs, inspect) and
+/// approval-gated mutations (restart, prune) via the Docker/Podman CLI.
+/// Disabled unless explicitly enabled.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ContainersConfig {
+    /// Enable the `containers` tool
+    #[serde(default)]
+    pub enabled: bool,
+    /// Container CLI binary ("docker" or "podman")
+    #[serde(default = "default_containers_engine")]
+    pub engine: String,
+    /// Engine endpoint passed as `DOCKER_HOST` (e.g. "unix:///run/user/1000/docker.sock");
+    /// omit to use the engine's default socket
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub endpoint: Option<String>,
+    /// Engine command timeout in seconds (default: 30)
+    #[serde(default = "default_containers_timeout_secs")]
+    pub timeout_secs: u64,
+}
+
+impl Default for ContainersConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            engine: default_containers_engine(),
+            endpoint: None,
+            timeout_secs: default_containers_timeout_secs(),
+        }
+    }
+}
+
+fn default_containers_engine() -> String {
+    "docker".to_string()
+}
+
+fn default_containers_timeout_secs() -> u64 {
+    30
+}
+
+// ── CI failure triage ────────────────────────────────────────────
+
+/// CI failure triage configuration (`[ci]` section).
+///
+/// Enables the gateway's `POST /hooks/ci` endpoint, which receives GitHub
+/// Actions / GitLab CI failure webhooks, fetches the failing job log via the
+/// forge API, and posts an agent triage as a PR/MR comment or channel
+/// message. Disabled unless explicitly enabled.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct CiConfig {
+    /// Enable the `/hooks/ci` gateway endpoint
+    #[serde(default)]
+    pub enabled: bool,
+    /// Forge webhook secret: verified against GitHub's `X-Hub-Signature-256`
+    /// HMAC or GitLab's `X-Gitlab-Token` header. When unset, the endpoint
+    /// falls back to the standard `/webhook` auth layers (pairing token /
+    /// `X-Webhook-Secret`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook_secret: Option<String>,
+    /// GitHub token used to fetch job logs and post PR comments
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub github_token: Option<String>,
+    /// GitLab token used to fetch job traces and post MR notes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gitlab_token: Option<String>,
+    /// Fallback delivery channel when the run has no PR/MR context
+    /// (e.g. "telegram", "slack")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel: Option<String>,
+    /// Recipient/target within the fallback channel
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+}
+
+// ── On-call pager ────────────────────────────────────────────────
+
+/// On-call pager integration configuration (`[pager]` section).
+///
+/// Enables the gateway's `POST /hooks/pager` endpoint, which receives
+/// PagerDuty v3 / Opsgenie incident webhooks and delivers an agent
+/// first-responder brief to the configured channel, plus the approval-gated
+/// `pager` tool for acknowledge/resolve actions. Disabled unless explicitly
+/// enabled.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PagerConfig {
+    /// Enable the `/hooks/pager` gateway endpoint and the `pager` tool
+    #[serde(default)]
+    pub enabled: bool,
+    /// Paging provider: "pagerduty" (default) or "opsgenie"
+    #[serde(default = "default_pager_provider")]
+    pub provider: String,
+    /// Webhook secret: verified against PagerDuty's `X-PagerDuty-Signature`
+    /// HMAC or a shared `X-Webhook-Token` header (Opsgenie custom header).
+    /// When unset, the endpoint falls back to the standard `/webhook` auth
+    /// layers (pairing token / `X-Webhook-Secret`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook_secret: Option<String>,
+    /// REST API key used for acknowledge/resolve actions
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_key: Option<String>,
+    /// PagerDuty user email the API attributes actions to (`From` header);
+    /// required for PagerDuty acknowledge/resolve
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from_email: Option<String>,
+    /// Delivery channel for first-responder briefs (e.g. "telegram", "slack")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel: Option<String>,
+    /// Recipient/target within the delivery channel
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+}
+
+fn default_pager_provider() -> String {
+    "pagerduty".to_string()
+}
+
+// ── Network scan ─────────────────────────────────────────────────
+
+/// Local network scanner configuration (`[network_scan]` section).
+///
+/// Opt-in `network_scan` tool for RFC1918 subnet sweeps and per-host port
+/// checks. Disabled unless explicitly enabled; targets outside private
+/// ranges are always refused.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct NetworkScanConfig {
+    /// Enable the `network_scan` tool
+    #[serde(default)]
+    pub enabled: bool,
+    /// Maximum addresses a single sweep may cover
+    #[serde(default = "default_network_scan_max_hosts")]
+    pub max_hosts: usize,
+    /// Minimum seconds between scans (rate limit)
+    #[serde(default = "default_network_scan_min_interval_secs")]
+    pub min_scan_interval_secs: u64,
+    /// Per-connection probe timeout in milliseconds
+    #[serde(default = "default_network_scan_connect_timeout_ms")]
+    pub connect_timeout_ms: u64,
+}
+
+fn default_network_scan_max_hosts() -> usize {
+    256
+}
+
+fn default_network_scan_min_interval_secs() -> u64 {
+    30
+}
+
+fn default_network_scan_connect_timeout_ms() -> u64 {
+    500
+}
+
+impl Default for NetworkScanConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_hosts: default_network_scan_max_hosts(),
+            min_scan_interval_secs: default_network_scan_min_interval_secs(),
+            connect_timeout_ms: default_network_scan_connect_timeout_ms(),
+        }
+    }
+}
+
+impl Default for PagerConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            provider: default_pager_provider(),
+            webhook_secret: None,
+            api_key: None,
+            from_email: None,
+            channel: None,
+            to: None,
+        }
+    }
+}
+
+/// Built-in domain monitors (`[monitors]` section): TLS certificate expiry,
+/// domain registration expiry (RDAP), and DNS record drift for listed
+/// domains, with alerts to a channel.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct MonitorsConfig {
+    /// Enable the monitor loop in daemon mode
+    #[serde(default)]
+    pub enabled: bool,
+    /// Domains to check (e.g. `["example.com"]`)
+    #[serde(default)]
+    pub domains: Vec<String>,
+    /// Minutes between check rounds (minimum 15)
+    #[serde(default = "default_monitors_interval_minutes")]
+    pub interval_minutes: u32,
+    /// Alert when the TLS certificate expires within this many days
+    #[serde(default = "default_monitors_cert_expiry_days")]
+    pub cert_expiry_days: u32,
+    /// Alert when the domain registration expires within this many days
+    #[serde(default = "default_monitors_domain_expiry_days")]
+    pub domain_expiry_days: u32,
+    /// Channel for alerts (e.g. "telegram"); logged only if unset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel: Option<String>,
+    /// Recipient/chat id for alert delivery
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+}
+
+fn default_monitors_interval_minutes() -> u32 {
+    360
+}
+
+fn default_monitors_cert_expiry_days() -> u32 {
+    14
+}
+
+fn default_monitors_domain_expiry_days() -> u32 {
+    30
+}
+
+impl Default for MonitorsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            domains: Vec::new(),
+            interval_minutes: default_monitors_interval_minutes(),
+            cert_expiry_days: default_monitors_cert_expiry_days(),
+            domain_expiry_days: default_monitors_domain_expiry_days(),
+            channel: None,
+            to: None,
+        }
+    }
+}
+
+// ── Self-report ─────────────────────────────────────────────────
+
+/// Weekly daemon self-report configuration (`[self_report]` section).
+///
+/// When enabled, the daemon generates a weekly markdown summary of runtime
+/// health, component restarts, channel volumes, scheduler state, and top
+/// delegation costs; stores it under `reports/` in the workspace and delivers
+/// it to the configured channel.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct SelfReportConfig {
+    /// Enable the weekly self-report in daemon mode
+    #[serde(default)]
+    pub enabled: bool,
+    /// Channel for delivery (e.g. "telegram"); report is stored only if unset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel: Option<String>,
+    /// Recipient/chat id for report delivery
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+}
+
+// ── Outbound events ─────────────────────────────────────────────
+
+/// Outbound event publishing configuration (`[events]` section).
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct EventsConfig {
+    /// Event-bus publisher (`[events.bus]`); unset disables publishing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bus: Option<EventBusConfig>,
+}
+
+/// Event-bus publisher configuration (`[events.bus]` section).
+///
+/// Significant runtime events (delegation end, task finished, approval
+/// requested, alerts) are published as JSON to MQTT or NATS topics so
+/// home-automation and other systems can react to ZeroClaw activity
+/// without polling.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct EventBusConfig {
+    /// Bus protocol: `"mqtt"` or `"nats"`
+    pub protocol: String,
+    /// Broker address as `host:port` (e.g. `127.0.0.1:1883`)
+    pub url: String,
+    /// Topic prefix; events land on `<prefix>/delegation/end`,
+    /// `<prefix>/task/finished`, `<prefix>/approval/requested`, and
+    /// `<prefix>/alert` (`.`-separated for NATS)
+    #[serde(default = "default_event_bus_topic_prefix")]
+    pub topic_prefix: String,
+    /// Broker username (MQTT only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    /// Broker password (MQTT only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+}
+
+fn default_event_bus_topic_prefix() -> String {
+    "zeroclaw".to_string()
+}
+
+// ── Daily digest ────────────────────────────────────────────────
+
+/// Daily activity digest configuration (`[digest]` section).
+///
+/// When enabled, the daemon aggregates the last 24 hours of delegation
+/// stats, cron outcomes, channel activity, and cost once per day at the
+/// configured UTC time, asks the default model for a short natural-language
+/// summary, and delivers it to the configured channel. The raw stats and
+/// summary are stored under `reports/` in the workspace.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DigestConfig {
+    /// Enable the daily digest in daemon mode
+    #[serde(default)]
+    pub enabled: bool,
+    /// UTC time of day to send the digest, "HH:MM" (default "08:00")
+    #[serde(default = "default_digest_time")]
+    pub time: String,
+    /// Channel for delivery (e.g. "telegram"); digest is stored only if unset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel: Option<String>,
+    /// Recipient/chat id for digest delivery
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+}
+
+impl Default for DigestConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            time: default_digest_time(),
+            channel: None,
+            to: None,
+        }
+    }
+}
+
+fn default_digest_time() -> String {
+    "08:00".to_string()
 }
 
 // ── Web search ───────────────────────────────────────────────────
@@ -1687,6 +2675,11 @@ pub struct ObservabilityConfig {
     /// Service name reported to the OTel collector. Defaults to "zeroclaw".
     #[serde(default)]
     pub otel_service_name: Option<String>,
+
+    /// Emit logs as JSON lines with correlation fields (run_id, channel,
+    /// agent) from tracing spans, for shipping to Loki/Elastic. Default: false.
+    #[serde(default)]
+    pub json_logs: bool,
 }
 
 impl Default for ObservabilityConfig {
@@ -1695,6 +2688,7 @@ impl Default for ObservabilityConfig {
             backend: "none".into(),
             otel_endpoint: None,
             otel_service_name: None,
+            json_logs: false,
         }
     }
 }
@@ -1735,6 +2729,11 @@ pub struct AutonomyConfig {
     /// Tools that always require interactive approval, even after "Always".
     #[serde(default = "default_always_ask")]
     pub always_ask: Vec<String>,
+
+    /// Fine-grained policy rules (`[[autonomy.policies]]`), evaluated in
+    /// order before the flat allowlist; first match wins. Empty by default.
+    #[serde(default)]
+    pub policies: Vec<PolicyRuleConfig>,
 }
 
 fn default_auto_approve() -> Vec<String> {
@@ -1745,6 +2744,49 @@ fn default_always_ask() -> Vec<String> {
     vec![]
 }
 
+/// One autonomy policy rule (`[[autonomy.policies]]`).
+///
+/// All conditions present on a rule must match (AND). Rules are evaluated
+/// in config order; the first match decides the outcome. Actions with no
+/// matching rule fall through to `allowed_commands` and the risk gates.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PolicyRuleConfig {
+    /// Optional rule name shown in errors and `zeroclaw policy test` output.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Tool name pattern (`*` wildcard), e.g. `"shell"`, `"memory_*"`, `"*"`.
+    pub tool: String,
+    /// Pattern matched against the command/argument text (`*` wildcard),
+    /// e.g. `"*push*"`. Only matches actions that carry argument text.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub args: Option<String>,
+    /// Path globs (`*` wildcard); the rule matches when the action touches
+    /// a path matching any of them.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub paths: Vec<String>,
+    /// Local-time window `"HH:MM-HH:MM"` during which the rule applies.
+    /// An end before the start wraps midnight (e.g. `"22:00-06:00"`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hours: Option<String>,
+    /// Minimum estimated action cost in cents for the rule to fire.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_cost_cents: Option<u32>,
+    /// Outcome when the rule matches: `allow`, `deny`, or `require-approval`.
+    pub outcome: PolicyOutcome,
+}
+
+/// Outcome of a matched autonomy policy rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum PolicyOutcome {
+    /// Skip the approval prompt; for shell, also bypass the flat allowlist.
+    Allow,
+    /// Block the action outright.
+    Deny,
+    /// Require explicit approval before the action runs.
+    RequireApproval,
+}
+
 impl Default for AutonomyConfig {
     fn default() -> Self {
         Self {
@@ -1790,6 +2832,7 @@ impl Default for AutonomyConfig {
             block_high_risk_commands: true,
             auto_approve: default_auto_approve(),
             always_ask: default_always_ask(),
+            policies: vec![],
         }
     }
 }
@@ -1927,6 +2970,16 @@ pub struct ReliabilityConfig {
     /// Max retries for cron job execution attempts.
     #[serde(default = "default_scheduler_retries")]
     pub scheduler_retries: u32,
+    /// Seconds to wait for in-flight agent runs to finish on daemon shutdown.
+    #[serde(default = "default_shutdown_drain_secs")]
+    pub shutdown_drain_secs: u64,
+    /// Consecutive failures against one provider before its circuit breaker
+    /// opens and the provider is skipped while cooling down.
+    #[serde(default = "default_circuit_breaker_failures")]
+    pub circuit_breaker_failures: u32,
+    /// Seconds an open circuit skips its provider before a half-open probe.
+    #[serde(default = "default_circuit_breaker_cooldown_secs")]
+    pub circuit_breaker_cooldown_secs: u64,
 }
 
 fn default_provider_retries() -> u32 {
@@ -1953,6 +3006,18 @@ fn default_scheduler_retries() -> u32 {
     2
 }
 
+fn default_circuit_breaker_failures() -> u32 {
+    5
+}
+
+fn default_circuit_breaker_cooldown_secs() -> u64 {
+    30
+}
+
+fn default_shutdown_drain_secs() -> u64 {
+    30
+}
+
 impl Default for ReliabilityConfig {
     fn default() -> Self {
         Self {
@@ -1965,6 +3030,9 @@ impl Default for ReliabilityConfig {
             channel_max_backoff_secs: default_channel_backoff_max_secs(),
             scheduler_poll_secs: default_scheduler_poll_secs(),
             scheduler_retries: default_scheduler_retries(),
+            shutdown_drain_secs: default_shutdown_drain_secs(),
+            circuit_breaker_failures: default_circuit_breaker_failures(),
+            circuit_breaker_cooldown_secs: default_circuit_breaker_cooldown_secs(),
         }
     }
 }
@@ -2037,6 +3105,94 @@ pub struct ModelRouteConfig {
     pub api_key: Option<String>,
 }
 
+// ── Provider load balancing ─────────────────────────────────────
+
+/// Spread stateless requests across several providers serving the same
+/// model family. Disabled unless `strategy` is set.
+///
+/// ```toml
+/// [providers]
+/// strategy = "least_cost"   # round_robin | least_cost | lowest_latency
+///
+/// [[providers.pool]]
+/// name = "openrouter"
+/// cost_per_mtok = 3.0
+///
+/// [[providers.pool]]
+/// name = "groq"
+/// cost_per_mtok = 0.6
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, Default, JsonSchema)]
+pub struct ProvidersConfig {
+    /// Balancing strategy: `round_robin`, `least_cost`, or `lowest_latency`.
+    /// Empty (default) disables load balancing.
+    #[serde(default)]
+    pub strategy: String,
+    /// Providers participating in the balanced pool.
+    #[serde(default)]
+    pub pool: Vec<ProviderPoolEntry>,
+    /// Request/token rate limits (`[providers.rate_limits]`).
+    #[serde(default)]
+    pub rate_limits: RateLimitsConfig,
+}
+
+/// One member of the balanced provider pool.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProviderPoolEntry {
+    /// Provider name (must match a known provider name)
+    pub name: String,
+    /// Optional API key override for this pool member
+    #[serde(default)]
+    pub api_key: Option<String>,
+    /// Nominal blended price per million tokens, used by the `least_cost`
+    /// strategy to rank members. Default: `1.0`.
+    #[serde(default = "default_pool_cost_per_mtok")]
+    pub cost_per_mtok: f64,
+}
+
+fn default_pool_cost_per_mtok() -> f64 {
+    1.0
+}
+
+/// Token-bucket rate limits for provider calls. Calls over the budget are
+/// queued (the caller waits for the bucket to refill), not failed, so bursty
+/// cron schedules don't trip provider 429 bans. A zero rate means unlimited.
+///
+/// ```toml
+/// [providers.rate_limits]
+/// requests_per_minute = 60
+/// tokens_per_minute = 200000
+///
+/// [[providers.rate_limits.per_provider]]
+/// name = "openrouter"
+/// requests_per_minute = 30
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, Default, JsonSchema)]
+pub struct RateLimitsConfig {
+    /// Global request budget per minute across all providers. Default: `0` (unlimited).
+    #[serde(default)]
+    pub requests_per_minute: u64,
+    /// Global token budget per minute across all providers. Default: `0` (unlimited).
+    #[serde(default)]
+    pub tokens_per_minute: u64,
+    /// Per-provider limits applied in addition to the global budget.
+    #[serde(default)]
+    pub per_provider: Vec<ProviderRateLimit>,
+}
+
+/// Rate limit for a single provider, applied on top of the global budget.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProviderRateLimit {
+    /// Provider name (must match a known provider name)
+    pub name: String,
+    /// Request budget per minute for this provider. Default: `0` (unlimited).
+    #[serde(default)]
+    pub requests_per_minute: u64,
+    /// Token budget per minute for this provider. Default: `0` (unlimited).
+    #[serde(default)]
+    pub tokens_per_minute: u64,
+}
+
 // ── Embedding routing ───────────────────────────────────────────
 
 /// Route an embedding hint to a specific provider + model.
@@ -2112,6 +3268,10 @@ pub struct HeartbeatConfig {
     pub enabled: bool,
     /// Interval in minutes between heartbeat pings. Default: `30`.
     pub interval_minutes: u32,
+    /// Custom probes run by the daemon alongside heartbeat ticks
+    /// (`[[heartbeat.probes]]`). Default: none.
+    #[serde(default)]
+    pub probes: Vec<HeartbeatProbeConfig>,
 }
 
 impl Default for HeartbeatConfig {
@@ -2119,10 +3279,50 @@ impl Default for HeartbeatConfig {
         Self {
             enabled: false,
             interval_minutes: 30,
+            probes: Vec::new(),
         }
     }
 }
 
+/// A custom heartbeat probe (`[[heartbeat.probes]]` entry).
+///
+/// Each probe runs on its own interval in the daemon and reports into
+/// component health as `probe:<name>` (visible in
+/// `zeroclaw status --components`), turning the heartbeat into a
+/// lightweight uptime monitor for the host ZeroClaw runs on.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct HeartbeatProbeConfig {
+    /// Probe name; the health component becomes `probe:<name>`
+    pub name: String,
+    /// Probe kind: "http" (GET expecting 200), "command" (shell command
+    /// expecting exit 0), or "peripheral" (configured board reachable)
+    pub kind: String,
+    /// Probe target: URL for `http`, command line for `command`, board
+    /// name for `peripheral`
+    pub target: String,
+    /// Seconds between probe runs. Default: `60`.
+    #[serde(default = "default_probe_interval_secs")]
+    pub interval_secs: u64,
+    /// Failure action: "log" (default) or "alert" (deliver a message via
+    /// `channel`/`to` on down/recovery transitions)
+    #[serde(default = "default_probe_on_failure")]
+    pub on_failure: String,
+    /// Channel name for `on_failure = "alert"` (e.g. "telegram")
+    #[serde(default)]
+    pub channel: Option<String>,
+    /// Recipient for `on_failure = "alert"` (chat/user id)
+    #[serde(default)]
+    pub to: Option<String>,
+}
+
+fn default_probe_interval_secs() -> u64 {
+    60
+}
+
+fn default_probe_on_failure() -> String {
+    "log".into()
+}
+
 // ── Cron ────────────────────────────────────────────────────────
 
 /// Cron job configuration (`[cron]` section).
@@ -2269,12 +3469,38 @@ pub struct ChannelsConfig {
     /// Default: 300s for on-device LLMs (Ollama) which are slower than cloud APIs.
     #[serde(default = "default_channel_message_timeout_secs")]
     pub message_timeout_secs: u64,
+    /// Worker-pool size for concurrent channel message handling. `0` (default)
+    /// scales automatically with the number of enabled channels. Messages into
+    /// the same chat are always processed in order.
+    #[serde(default)]
+    pub max_concurrent_messages: usize,
+    /// Send a one-time "still working on your previous request" notice when a
+    /// message queues behind an in-flight request in the same conversation.
+    /// Default: `false`.
+    #[serde(default)]
+    pub queue_busy_notice: bool,
+    /// Send periodic progress updates (currently running tool + elapsed time)
+    /// while the agent works on a long task. Typing indicators are always on
+    /// for channels that support them. Default: `false`.
+    #[serde(default)]
+    pub progress_updates: bool,
+    /// Deduplication window in seconds for inbound channel events. Platforms
+    /// that deliver over webhooks redeliver events on acknowledgement
+    /// timeouts; repeats of the same platform message/event ID seen within
+    /// this window are dropped instead of triggering duplicate agent runs.
+    /// `0` disables deduplication. Default: 300s.
+    #[serde(default = "default_channel_dedup_ttl_secs")]
+    pub dedup_ttl_secs: u64,
 }
 
 fn default_channel_message_timeout_secs() -> u64 {
     300
 }
 
+fn default_channel_dedup_ttl_secs() -> u64 {
+    300
+}
+
 impl Default for ChannelsConfig {
     fn default() -> Self {
         Self {
@@ -2295,6 +3521,10 @@ impl Default for ChannelsConfig {
             dingtalk: None,
             qq: None,
             message_timeout_secs: default_channel_message_timeout_secs(),
+            max_concurrent_messages: 0,
+            queue_busy_notice: false,
+            progress_updates: false,
+            dedup_ttl_secs: default_channel_dedup_ttl_secs(),
         }
     }
 }
@@ -2624,6 +3854,10 @@ pub struct SecurityConfig {
     /// Audit logging configuration
     #[serde(default)]
     pub audit: AuditConfig,
+
+    /// Secret redaction configuration
+    #[serde(default)]
+    pub redaction: RedactionConfig,
 }
 
 /// Sandbox configuration for OS-level isolation
@@ -2761,6 +3995,36 @@ impl Default for AuditConfig {
     }
 }
 
+/// Secret redaction configuration.
+///
+/// Controls the redaction pass that scrubs API keys, tokens, and custom
+/// regex matches from prompts and tool outputs before they reach a provider.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct RedactionConfig {
+    /// Enable the redaction pass (built-in credential patterns plus
+    /// `patterns` below)
+    #[serde(default = "default_redaction_enabled")]
+    pub enabled: bool,
+
+    /// Additional regex patterns to redact; invalid patterns are skipped
+    /// with a warning
+    #[serde(default)]
+    pub patterns: Vec<String>,
+}
+
+fn default_redaction_enabled() -> bool {
+    true
+}
+
+impl Default for RedactionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_redaction_enabled(),
+            patterns: Vec::new(),
+        }
+    }
+}
+
 /// DingTalk configuration for Stream Mode messaging
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct DingTalkConfig {
@@ -2802,6 +4066,7 @@ impl Default for Config {
             default_model: Some("anthropic/claude-sonnet-4.6".to_string()),
             default_temperature: 0.7,
             observability: ObservabilityConfig::default(),
+            ui: UiConfig::default(),
             autonomy: AutonomyConfig::default(),
             runtime: RuntimeConfig::default(),
             reliability: ReliabilityConfig::default(),
@@ -2809,6 +4074,7 @@ impl Default for Config {
             agent: AgentConfig::default(),
             skills: SkillsConfig::default(),
             model_routes: Vec::new(),
+            providers: ProvidersConfig::default(),
             embedding_routes: Vec::new(),
             heartbeat: HeartbeatConfig::default(),
             cron: CronConfig::default(),
@@ -2818,14 +4084,30 @@ impl Default for Config {
             tunnel: TunnelConfig::default(),
             gateway: GatewayConfig::default(),
             composio: ComposioConfig::default(),
+            integrations: IntegrationsConfig::default(),
+            notes: NotesConfig::default(),
             secrets: SecretsConfig::default(),
             browser: BrowserConfig::default(),
             http_request: HttpRequestConfig::default(),
+            run_code: RunCodeConfig::default(),
+            containers: ContainersConfig::default(),
+            ci: CiConfig::default(),
+            pager: PagerConfig::default(),
+            network_scan: NetworkScanConfig::default(),
+            monitors: MonitorsConfig::default(),
+            self_report: SelfReportConfig::default(),
+            digest: DigestConfig::default(),
+            events: EventsConfig::default(),
+            delegation: DelegationConfig::default(),
+            nodes: NodesConfig::default(),
+            security: SecurityConfig::default(),
             multimodal: MultimodalConfig::default(),
             web_search: WebSearchConfig::default(),
             proxy: ProxyConfig::default(),
             identity: IdentityConfig::default(),
             cost: CostConfig::default(),
+            quota: QuotaConfig::default(),
+            cache: CacheConfig::default(),
             peripherals: PeripheralsConfig::default(),
             agents: HashMap::new(),
             hardware: HardwareConfig::default(),
@@ -2846,7 +4128,7 @@ struct ActiveWorkspaceState {
     config_dir: String,
 }
 
-fn default_config_dir() -> Result<PathBuf> {
+pub(crate) fn default_config_dir() -> Result<PathBuf> {
     let home = UserDirs::new()
         .map(|u| u.home_dir().to_path_buf())
         .context("Could not find home directory")?;
@@ -2857,7 +4139,7 @@ fn active_workspace_state_path(default_dir: &Path) -> PathBuf {
     default_dir.join(ACTIVE_WORKSPACE_STATE_FILE)
 }
 
-async fn load_persisted_workspace_dirs(
+pub(crate) async fn load_persisted_workspace_dirs(
     default_config_dir: &Path,
 ) -> Result<Option<(PathBuf, PathBuf)>> {
     let state_path = active_workspace_state_path(default_config_dir);
@@ -3044,6 +4326,159 @@ async fn resolve_runtime_config_dirs(
     ))
 }
 
+/// Environment variable selecting a named config profile overlay
+/// (`~/.zeroclaw/profiles/<name>.toml`). The `--profile` CLI flag sets
+/// this variable so daemon config reloads keep the same profile.
+pub const PROFILE_ENV_VAR: &str = "ZEROCLAW_PROFILE";
+
+fn active_profile_name() -> Option<String> {
+    std::env::var(PROFILE_ENV_VAR)
+        .ok()
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+}
+
+/// Reject profile names that could escape the profiles directory.
+fn validate_profile_name(name: &str) -> Result<()> {
+    let valid = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if !valid {
+        anyhow::bail!(
+            "Invalid config profile name '{name}' (allowed: ASCII letters, digits, '-', '_')"
+        );
+    }
+    Ok(())
+}
+
+/// Deep-merge `overlay` into `base`: tables merge recursively, every other
+/// value (including arrays) is replaced wholesale by the overlay.
+pub(crate) fn merge_toml_values(base: &mut toml::Value, overlay: toml::Value) {
+    match (base, overlay) {
+        (toml::Value::Table(base_table), toml::Value::Table(overlay_table)) => {
+            for (key, value) in overlay_table {
+                match base_table.get_mut(&key) {
+                    Some(existing) if existing.is_table() && value.is_table() => {
+                        merge_toml_values(existing, value);
+                    }
+                    _ => {
+                        base_table.insert(key, value);
+                    }
+                }
+            }
+        }
+        (base, overlay) => *base = overlay,
+    }
+}
+
+/// Resolve `${env:VAR}` and `${secret:name}` placeholders in a config string.
+/// Only these two markers are interpolated; any other `${...}` text (prompt
+/// templates, shell snippets) passes through untouched. Unresolvable
+/// placeholders are hard errors — error messages name the placeholder but
+/// never include resolved values.
+fn interpolate_string(input: &str) -> Result<String> {
+    const ENV_MARKER: &str = "${env:";
+    const SECRET_MARKER: &str = "${secret:";
+
+    let mut result = String::new();
+    let mut rest = input;
+    loop {
+        let (start, marker) = match (rest.find(ENV_MARKER), rest.find(SECRET_MARKER)) {
+            (None, None) => break,
+            (Some(e), None) => (e, ENV_MARKER),
+            (Some(e), Some(s)) if e < s => (e, ENV_MARKER),
+            (_, Some(s)) => (s, SECRET_MARKER),
+        };
+        let after = &rest[start + marker.len()..];
+        let Some(end) = after.find('}') else {
+            anyhow::bail!("Unclosed config placeholder '{marker}...' (missing '}}')");
+        };
+        let name = &after[..end];
+        let resolved = if marker == ENV_MARKER {
+            std::env::var(name).map_err(|_| {
+                anyhow::anyhow!(
+                    "Config placeholder ${{env:{name}}} references an unset environment variable"
+                )
+            })?
+        } else {
+            crate::security::keychain::lookup(name).with_context(|| {
+                format!(
+                    "Config placeholder ${{secret:{name}}} could not be resolved from the OS keychain"
+                )
+            })?
+        };
+        result.push_str(&rest[..start]);
+        result.push_str(&resolved);
+        rest = &after[end + 1..];
+    }
+    result.push_str(rest);
+    Ok(result)
+}
+
+fn contains_placeholder(value: &str) -> bool {
+    value.contains("${env:") || value.contains("${secret:")
+}
+
+/// Walk a parsed TOML tree and interpolate placeholders in every string value.
+fn interpolate_value_placeholders(value: &mut toml::Value) -> Result<()> {
+    match value {
+        toml::Value::String(s) if contains_placeholder(s) => {
+            *s = interpolate_string(s)?;
+        }
+        toml::Value::Array(items) => {
+            for item in items {
+                interpolate_value_placeholders(item)?;
+            }
+        }
+        toml::Value::Table(table) => {
+            for (_, item) in table.iter_mut() {
+                interpolate_value_placeholders(item)?;
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Parse the base config, layering the active profile overlay on top when
+/// `ZEROCLAW_PROFILE` is set, then resolving `${env:VAR}` / `${secret:name}`
+/// placeholders in string values. Precedence: base config < profile overlay <
+/// environment variable overrides (applied later by the caller).
+async fn parse_config_with_profile(contents: &str, zeroclaw_dir: &Path) -> Result<Config> {
+    let mut merged: toml::Value =
+        toml::from_str(contents).context("Failed to parse config file")?;
+
+    if let Some(profile) = active_profile_name() {
+        validate_profile_name(&profile)?;
+
+        let profile_path = zeroclaw_dir
+            .join("profiles")
+            .join(format!("{profile}.toml"));
+        let overlay_contents = fs::read_to_string(&profile_path).await.with_context(|| {
+            format!(
+                "Config profile '{profile}' not found at {}",
+                profile_path.display()
+            )
+        })?;
+        let overlay: toml::Value = toml::from_str(&overlay_contents).with_context(|| {
+            format!(
+                "Failed to parse config profile at {}",
+                profile_path.display()
+            )
+        })?;
+        merge_toml_values(&mut merged, overlay);
+        tracing::info!(
+            profile = %profile,
+            path = %profile_path.display(),
+            "Config profile overlay applied"
+        );
+    }
+
+    interpolate_value_placeholders(&mut merged)?;
+    merged.try_into().context("Failed to parse config file")
+}
+
 fn decrypt_optional_secret(
     store: &crate::security::SecretStore,
     value: &mut Option<String>,
@@ -3115,12 +4550,12 @@ impl Config {
             let contents = fs::read_to_string(&config_path)
                 .await
                 .context("Failed to read config file")?;
-            let mut config: Config =
-                toml::from_str(&contents).context("Failed to parse config file")?;
+            let mut config: Config = parse_config_with_profile(&contents, &zeroclaw_dir).await?;
             // Set computed paths that are skipped during serialization
             config.config_path = config_path.clone();
             config.workspace_dir = workspace_dir;
-            let store = crate::security::SecretStore::new(&zeroclaw_dir, config.secrets.encrypt);
+            let store = crate::security::SecretStore::new(&zeroclaw_dir, config.secrets.encrypt)
+                .with_backend(config.secrets.backend);
             decrypt_optional_secret(&store, &mut config.api_key, "config.api_key")?;
             decrypt_optional_secret(
                 &store,
@@ -3149,6 +4584,29 @@ impl Config {
             for agent in config.agents.values_mut() {
                 decrypt_optional_secret(&store, &mut agent.api_key, "config.agents.*.api_key")?;
             }
+
+            for (name, entry) in config.integrations.entries_mut() {
+                if let Some(settings) = entry {
+                    decrypt_optional_secret(
+                        &store,
+                        &mut settings.token,
+                        &format!("config.integrations.{name}.token"),
+                    )?;
+                    decrypt_optional_secret(
+                        &store,
+                        &mut settings.webhook_secret,
+                        &format!("config.integrations.{name}.webhook_secret"),
+                    )?;
+                }
+            }
+
+            for board in &mut config.peripherals.boards {
+                decrypt_optional_secret(
+                    &store,
+                    &mut board.token,
+                    "config.peripherals.boards.*.token",
+                )?;
+            }
             config.apply_env_overrides();
             config.validate()?;
             tracing::info!(
@@ -3160,6 +4618,12 @@ impl Config {
             );
             Ok(config)
         } else {
+            if let Some(profile) = active_profile_name() {
+                anyhow::bail!(
+                    "Config profile '{profile}' requested but no base config exists; \
+                     run `zeroclaw onboard` first"
+                );
+            }
             let mut config = Config::default();
             config.config_path = config_path.clone();
             config.workspace_dir = workspace_dir;
@@ -3221,6 +4685,29 @@ impl Config {
             }
         }
 
+        // Provider load balancing
+        if !self.providers.strategy.is_empty() {
+            crate::providers::balancer::BalanceStrategy::parse(&self.providers.strategy)?;
+            if self.providers.pool.is_empty() {
+                anyhow::bail!(
+                    "providers.strategy is set but providers.pool is empty; add at least one [[providers.pool]] entry"
+                );
+            }
+        }
+        for (i, entry) in self.providers.pool.iter().enumerate() {
+            if entry.name.trim().is_empty() {
+                anyhow::bail!("providers.pool[{i}].name must not be empty");
+            }
+            if entry.cost_per_mtok <= 0.0 {
+                anyhow::bail!("providers.pool[{i}].cost_per_mtok must be greater than 0");
+            }
+        }
+        for (i, limit) in self.providers.rate_limits.per_provider.iter().enumerate() {
+            if limit.name.trim().is_empty() {
+                anyhow::bail!("providers.rate_limits.per_provider[{i}].name must not be empty");
+            }
+        }
+
         // Embedding routes
         for (i, route) in self.embedding_routes.iter().enumerate() {
             if route.hint.trim().is_empty() {
@@ -3506,6 +4993,7 @@ impl Config {
         }
 
         set_runtime_proxy_config(self.proxy.clone());
+        crate::security::redaction::set_runtime_redactor(&self.security.redaction);
     }
 
     /// Return the path to the delegation event log (`delegation.jsonl`).
@@ -3522,6 +5010,21 @@ impl Config {
             .join("delegation.jsonl")
     }
 
+    /// Directory that contains `config.toml` (typically `~/.zeroclaw`);
+    /// shared root for audit and other zeroclaw state.
+    pub fn zeroclaw_dir(&self) -> PathBuf {
+        self.config_path
+            .parent()
+            .unwrap_or(self.workspace_dir.as_path())
+            .to_path_buf()
+    }
+
+    /// Path to the hash-chained audit log (`[security.audit].log_path`,
+    /// relative to the zeroclaw dir).
+    pub fn audit_log_path(&self) -> PathBuf {
+        self.zeroclaw_dir().join(&self.security.audit.log_path)
+    }
+
     pub async fn save(&self) -> Result<()> {
         // Encrypt secrets before serialization
         let mut config_to_save = self.clone();
@@ -3529,7 +5032,8 @@ impl Config {
             .config_path
             .parent()
             .context("Config path must have a parent directory")?;
-        let store = crate::security::SecretStore::new(zeroclaw_dir, self.secrets.encrypt);
+        let store = crate::security::SecretStore::new(zeroclaw_dir, self.secrets.encrypt)
+            .with_backend(self.secrets.backend);
 
         encrypt_optional_secret(&store, &mut config_to_save.api_key, "config.api_key")?;
         encrypt_optional_secret(
@@ -3560,6 +5064,29 @@ impl Config {
             encrypt_optional_secret(&store, &mut agent.api_key, "config.agents.*.api_key")?;
         }
 
+        for (name, entry) in config_to_save.integrations.entries_mut() {
+            if let Some(settings) = entry {
+                encrypt_optional_secret(
+                    &store,
+                    &mut settings.token,
+                    &format!("config.integrations.{name}.token"),
+                )?;
+                encrypt_optional_secret(
+                    &store,
+                    &mut settings.webhook_secret,
+                    &format!("config.integrations.{name}.webhook_secret"),
+                )?;
+            }
+        }
+
+        for board in &mut config_to_save.peripherals.boards {
+            encrypt_optional_secret(
+                &store,
+                &mut board.token,
+                "config.peripherals.boards.*.token",
+            )?;
+        }
+
         let toml_str =
             toml::to_string_pretty(&config_to_save).context("Failed to serialize config")?;
 
@@ -3830,6 +5357,7 @@ default_temperature = 0.7
                 backend: "log".into(),
                 ..ObservabilityConfig::default()
             },
+            ui: UiConfig::default(),
             autonomy: AutonomyConfig {
                 level: AutonomyLevel::Full,
                 workspace_only: false,
@@ -3841,6 +5369,7 @@ default_temperature = 0.7
                 block_high_risk_commands: true,
                 auto_approve: vec!["file_read".into()],
                 always_ask: vec![],
+                policies: vec![],
             },
             runtime: RuntimeConfig {
                 kind: "docker".into(),
@@ -3850,11 +5379,13 @@ default_temperature = 0.7
             scheduler: SchedulerConfig::default(),
             skills: SkillsConfig::default(),
             model_routes: Vec::new(),
+            providers: ProvidersConfig::default(),
             embedding_routes: Vec::new(),
             query_classification: QueryClassificationConfig::default(),
             heartbeat: HeartbeatConfig {
                 enabled: true,
                 interval_minutes: 15,
+                probes: Vec::new(),
             },
             cron: CronConfig::default(),
             channels_config: ChannelsConfig {
@@ -3882,21 +5413,41 @@ default_temperature = 0.7
                 dingtalk: None,
                 qq: None,
                 message_timeout_secs: 300,
+                max_concurrent_messages: 0,
+                queue_busy_notice: false,
+                progress_updates: false,
+                dedup_ttl_secs: 300,
             },
             memory: MemoryConfig::default(),
             storage: StorageConfig::default(),
             tunnel: TunnelConfig::default(),
             gateway: GatewayConfig::default(),
             composio: ComposioConfig::default(),
+            integrations: IntegrationsConfig::default(),
+            notes: NotesConfig::default(),
             secrets: SecretsConfig::default(),
             browser: BrowserConfig::default(),
             http_request: HttpRequestConfig::default(),
+            run_code: RunCodeConfig::default(),
+            containers: ContainersConfig::default(),
+            ci: CiConfig::default(),
+            pager: PagerConfig::default(),
+            network_scan: NetworkScanConfig::default(),
+            monitors: MonitorsConfig::default(),
+            self_report: SelfReportConfig::default(),
+            digest: DigestConfig::default(),
+            events: EventsConfig::default(),
+            delegation: DelegationConfig::default(),
+            nodes: NodesConfig::default(),
+            security: SecurityConfig::default(),
             multimodal: MultimodalConfig::default(),
             web_search: WebSearchConfig::default(),
             proxy: ProxyConfig::default(),
             agent: AgentConfig::default(),
             identity: IdentityConfig::default(),
             cost: CostConfig::default(),
+            quota: QuotaConfig::default(),
+            cache: CacheConfig::default(),
             peripherals: PeripheralsConfig::default(),
             agents: HashMap::new(),
             hardware: HardwareConfig::default(),
@@ -4041,12 +5592,14 @@ tool_dispatcher = "xml"
             default_model: Some("test-model".into()),
             default_temperature: 0.9,
             observability: ObservabilityConfig::default(),
+            ui: UiConfig::default(),
             autonomy: AutonomyConfig::default(),
             runtime: RuntimeConfig::default(),
             reliability: ReliabilityConfig::default(),
             scheduler: SchedulerConfig::default(),
             skills: SkillsConfig::default(),
             model_routes: Vec::new(),
+            providers: ProvidersConfig::default(),
             embedding_routes: Vec::new(),
             query_classification: QueryClassificationConfig::default(),
             heartbeat: HeartbeatConfig::default(),
@@ -4057,15 +5610,31 @@ tool_dispatcher = "xml"
             tunnel: TunnelConfig::default(),
             gateway: GatewayConfig::default(),
             composio: ComposioConfig::default(),
+            integrations: IntegrationsConfig::default(),
+            notes: NotesConfig::default(),
             secrets: SecretsConfig::default(),
             browser: BrowserConfig::default(),
             http_request: HttpRequestConfig::default(),
+            run_code: RunCodeConfig::default(),
+            containers: ContainersConfig::default(),
+            ci: CiConfig::default(),
+            pager: PagerConfig::default(),
+            network_scan: NetworkScanConfig::default(),
+            monitors: MonitorsConfig::default(),
+            self_report: SelfReportConfig::default(),
+            digest: DigestConfig::default(),
+            events: EventsConfig::default(),
+            delegation: DelegationConfig::default(),
+            nodes: NodesConfig::default(),
+            security: SecurityConfig::default(),
             multimodal: MultimodalConfig::default(),
             web_search: WebSearchConfig::default(),
             proxy: ProxyConfig::default(),
             agent: AgentConfig::default(),
             identity: IdentityConfig::default(),
             cost: CostConfig::default(),
+            quota: QuotaConfig::default(),
+            cache: CacheConfig::default(),
             peripherals: PeripheralsConfig::default(),
             agents: HashMap::new(),
             hardware: HardwareConfig::default(),
@@ -4118,6 +5687,7 @@ tool_dispatcher = "xml"
                 agentic: false,
                 allowed_tools: Vec::new(),
                 max_iterations: 10,
+                node: None,
             },
         );
 
@@ -4425,6 +5995,10 @@ allowed_users = ["@ops:matrix.org"]
             dingtalk: None,
             qq: None,
             message_timeout_secs: 300,
+            max_concurrent_messages: 0,
+            queue_busy_notice: false,
+            progress_updates: false,
+            dedup_ttl_secs: 300,
         };
         let toml_str = toml::to_string_pretty(&c).unwrap();
         let parsed: ChannelsConfig = toml::from_str(&toml_str).unwrap();
@@ -4634,6 +6208,10 @@ channel_id = "C123"
             dingtalk: None,
             qq: None,
             message_timeout_secs: 300,
+            max_concurrent_messages: 0,
+            queue_busy_notice: false,
+            progress_updates: false,
+            dedup_ttl_secs: 300,
         };
         let toml_str = toml::to_string_pretty(&c).unwrap();
         let parsed: ChannelsConfig = toml::from_str(&toml_str).unwrap();
@@ -4716,6 +6294,8 @@ channel_id = "C123"
             cors_allowed_origins: vec![],
             serve_static_files: false,
             static_dir: "./web-ui/dist".into(),
+            oidc: GatewayOidcConfig::default(),
+            hooks: Vec::new(),
         };
         let toml_str = toml::to_string(&g).unwrap();
         let parsed: GatewayConfig = toml::from_str(&toml_str).unwrap();
@@ -4842,10 +6422,14 @@ enable = true
 
     #[test]
     async fn secrets_config_serde_roundtrip() {
-        let s = SecretsConfig { encrypt: false };
+        let s = SecretsConfig {
+            encrypt: false,
+            backend: SecretsBackend::Keychain,
+        };
         let toml_str = toml::to_string(&s).unwrap();
         let parsed: SecretsConfig = toml::from_str(&toml_str).unwrap();
         assert!(!parsed.encrypt);
+        assert_eq!(parsed.backend, SecretsBackend::Keychain);
     }
 
     #[test]
@@ -4862,6 +6446,27 @@ default_temperature = 0.7
         );
     }
 
+    #[test]
+    async fn ui_config_defaults_off_and_roundtrips() {
+        assert!(
+            !UiConfig::default().accessible,
+            "Accessible output must be opt-in"
+        );
+
+        let parsed: UiConfig = toml::from_str("accessible = true").unwrap();
+        assert!(parsed.accessible);
+
+        let minimal: Config = toml::from_str(
+            r#"
+workspace_dir = "/tmp/ws"
+config_path = "/tmp/config.toml"
+default_temperature = 0.7
+"#,
+        )
+        .unwrap();
+        assert!(!minimal.ui.accessible, "Missing [ui] must default to off");
+    }
+
     #[test]
     async fn config_default_has_composio_and_secrets() {
         let c = Config::default();
@@ -4945,6 +6550,157 @@ default_temperature = 0.7
         assert!(parsed.browser.allowed_domains.is_empty());
     }
 
+    // ── Config profiles (layered overrides) ─────────
+
+    #[test]
+    async fn merge_toml_values_merges_nested_tables_and_replaces_scalars() {
+        let mut base: toml::Value = toml::from_str(
+            r#"
+default_model = "base-model"
+
+[gateway]
+port = 3000
+host = "127.0.0.1"
+"#,
+        )
+        .unwrap();
+        let overlay: toml::Value = toml::from_str(
+            r#"
+default_model = "work-model"
+
+[gateway]
+port = 4242
+"#,
+        )
+        .unwrap();
+
+        merge_toml_values(&mut base, overlay);
+
+        assert_eq!(
+            base["default_model"].as_str(),
+            Some("work-model"),
+            "overlay scalar replaces base scalar"
+        );
+        assert_eq!(base["gateway"]["port"].as_integer(), Some(4242));
+        assert_eq!(
+            base["gateway"]["host"].as_str(),
+            Some("127.0.0.1"),
+            "base keys not present in overlay survive"
+        );
+    }
+
+    #[test]
+    async fn merge_toml_values_replaces_arrays_wholesale() {
+        let mut base: toml::Value = toml::from_str(r#"items = ["a", "b"]"#).unwrap();
+        let overlay: toml::Value = toml::from_str(r#"items = ["c"]"#).unwrap();
+
+        merge_toml_values(&mut base, overlay);
+
+        let items: Vec<&str> = base["items"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter_map(toml::Value::as_str)
+            .collect();
+        assert_eq!(items, vec!["c"]);
+    }
+
+    #[test]
+    async fn profile_name_validation_rejects_path_traversal() {
+        assert!(validate_profile_name("work").is_ok());
+        assert!(validate_profile_name("home_2").is_ok());
+        assert!(validate_profile_name("../etc/passwd").is_err());
+        assert!(validate_profile_name("work/../../other").is_err());
+        assert!(validate_profile_name("").is_err());
+    }
+
+    #[test]
+    async fn profile_overlay_layers_over_base_config() {
+        let _env_guard = env_override_lock().await;
+        let tmp = tempfile::TempDir::new().unwrap();
+        let profiles_dir = tmp.path().join("profiles");
+        std::fs::create_dir_all(&profiles_dir).unwrap();
+        std::fs::write(
+            profiles_dir.join("zeroclaw_work.toml"),
+            "default_model = \"work-model\"\n\n[gateway]\nport = 4242\n",
+        )
+        .unwrap();
+
+        let base = "default_provider = \"openrouter\"\ndefault_model = \"base-model\"\ndefault_temperature = 0.7\n";
+
+        std::env::set_var(PROFILE_ENV_VAR, "zeroclaw_work");
+        let result = parse_config_with_profile(base, tmp.path()).await;
+        std::env::remove_var(PROFILE_ENV_VAR);
+
+        let config = result.unwrap();
+        assert_eq!(config.default_provider.as_deref(), Some("openrouter"));
+        assert_eq!(config.default_model.as_deref(), Some("work-model"));
+        assert_eq!(config.gateway.port, 4242);
+    }
+
+    #[test]
+    async fn profile_overlay_missing_profile_fails_fast() {
+        let _env_guard = env_override_lock().await;
+        let tmp = tempfile::TempDir::new().unwrap();
+
+        std::env::set_var(PROFILE_ENV_VAR, "does_not_exist");
+        let result = parse_config_with_profile("default_temperature = 0.7\n", tmp.path()).await;
+        std::env::remove_var(PROFILE_ENV_VAR);
+
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("does_not_exist"), "unexpected error: {err}");
+    }
+
+    #[test]
+    async fn interpolate_string_resolves_env_placeholders() {
+        let _env_guard = env_override_lock().await;
+
+        std::env::set_var("ZEROCLAW_TEST_INTERP_TOKEN", "tok-123");
+        let result = interpolate_string("Bearer ${env:ZEROCLAW_TEST_INTERP_TOKEN}/suffix");
+        std::env::remove_var("ZEROCLAW_TEST_INTERP_TOKEN");
+
+        assert_eq!(result.unwrap(), "Bearer tok-123/suffix");
+    }
+
+    #[test]
+    async fn interpolate_string_errors_on_unset_env_and_unclosed_placeholder() {
+        let _env_guard = env_override_lock().await;
+
+        std::env::remove_var("ZEROCLAW_TEST_INTERP_MISSING");
+        let err = interpolate_string("${env:ZEROCLAW_TEST_INTERP_MISSING}")
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains("ZEROCLAW_TEST_INTERP_MISSING"),
+            "unexpected error: {err}"
+        );
+
+        assert!(interpolate_string("${env:UNCLOSED").is_err());
+    }
+
+    #[test]
+    async fn interpolate_string_leaves_other_dollar_brace_text_untouched() {
+        let input = "run ${HOME}/bin/tool with ${unknown:x}";
+        assert_eq!(interpolate_string(input).unwrap(), input);
+    }
+
+    #[test]
+    async fn config_load_resolves_env_placeholders_in_string_values() {
+        let _env_guard = env_override_lock().await;
+        let tmp = tempfile::TempDir::new().unwrap();
+
+        let base = "default_provider = \"openrouter\"\ndefault_model = \"${env:ZEROCLAW_TEST_INTERP_MODEL}\"\ndefault_temperature = 0.7\n";
+
+        std::env::set_var("ZEROCLAW_TEST_INTERP_MODEL", "interp-model");
+        let result = parse_config_with_profile(base, tmp.path()).await;
+        std::env::remove_var("ZEROCLAW_TEST_INTERP_MODEL");
+
+        assert_eq!(
+            result.unwrap().default_model.as_deref(),
+            Some("interp-model")
+        );
+    }
+
     // ── Environment variable overrides (Docker support) ─────────
 
     async fn env_override_lock() -> MutexGuard<'static, ()> {
@@ -5794,6 +7550,7 @@ default_model = "legacy-model"
         assert_eq!(b.transport, "serial");
         assert!(b.path.is_none());
         assert_eq!(b.baud, 115_200);
+        assert!(b.token.is_none());
     }
 
     #[test]
@@ -5805,8 +7562,9 @@ default_model = "legacy-model"
                 transport: "serial".into(),
                 path: Some("/dev/ttyACM0".into()),
                 baud: 115_200,
+                token: None,
             }],
-            datasheet_dir: None,
+            ..Default::default()
         };
         let toml_str = toml::to_string(&p).unwrap();
         let parsed: PeripheralsConfig = toml::from_str(&toml_str).unwrap();
@@ -0,0 +1,348 @@
+//! Config linting for `zeroclaw config validate`.
+//!
+//! Goes beyond the load-time checks in [`Config::validate`]: detects keys the
+//! schema does not recognize, verifies referenced paths exist, and checks
+//! that configured channels carry their required fields. Intended for CI
+//! before deploying a daemon config — errors make the command exit non-zero.
+
+use super::Config;
+use anyhow::{Context, Result};
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+#[derive(Debug, Clone)]
+pub struct Finding {
+    pub severity: Severity,
+    pub message: String,
+}
+
+impl Finding {
+    fn error(message: impl Into<String>) -> Self {
+        Self {
+            severity: Severity::Error,
+            message: message.into(),
+        }
+    }
+
+    fn warning(message: impl Into<String>) -> Self {
+        Self {
+            severity: Severity::Warning,
+            message: message.into(),
+        }
+    }
+}
+
+/// Lint raw config file contents. Returns `Err` only when the file cannot be
+/// parsed at all; everything else is reported as findings.
+pub fn lint(contents: &str) -> Result<Vec<Finding>> {
+    let config: Config = toml::from_str(contents).context("Config does not match the schema")?;
+
+    let mut findings = Vec::new();
+    if let Err(e) = config.validate() {
+        findings.push(Finding::error(format!("{e:#}")));
+    }
+    check_unknown_keys(contents, &config, &mut findings);
+    check_referenced_paths(&config, &mut findings);
+    check_channels(&config, &mut findings);
+    Ok(findings)
+}
+
+/// Print findings doctor-style and fail when any errors were found.
+pub fn print_report(contents: &str) -> Result<()> {
+    let findings = lint(contents)?;
+
+    let mut errors = 0usize;
+    let mut warnings = 0usize;
+    for finding in &findings {
+        match finding.severity {
+            Severity::Error => {
+                errors += 1;
+                println!("❌ {}", finding.message);
+            }
+            Severity::Warning => {
+                warnings += 1;
+                println!("⚠️  {}", finding.message);
+            }
+        }
+    }
+
+    if errors > 0 {
+        anyhow::bail!("Config validation failed: {errors} error(s), {warnings} warning(s)");
+    }
+    if warnings > 0 {
+        println!("Config is valid ({warnings} warning(s)).");
+    } else {
+        println!("✅ Config is valid.");
+    }
+    Ok(())
+}
+
+/// Keys that are computed at runtime and ignored when present in the file.
+const COMPUTED_KEYS: &[&str] = &["workspace_dir", "config_path"];
+
+/// Detect keys in the raw file that the typed schema does not recognize by
+/// comparing against a deserialize/serialize round-trip: keys serde silently
+/// dropped are not part of the schema.
+fn check_unknown_keys(contents: &str, config: &Config, findings: &mut Vec<Finding>) {
+    let Ok(raw) = toml::from_str::<toml::Value>(contents) else {
+        return; // unreachable: contents already parsed into Config
+    };
+    let Ok(round_trip) = toml::Value::try_from(config) else {
+        findings.push(Finding::warning(
+            "Could not re-serialize config; unknown-key detection skipped",
+        ));
+        return;
+    };
+    walk_unknown_keys(&raw, Some(&round_trip), "", findings);
+}
+
+fn walk_unknown_keys(
+    raw: &toml::Value,
+    known: Option<&toml::Value>,
+    path: &str,
+    findings: &mut Vec<Finding>,
+) {
+    let toml::Value::Table(table) = raw else {
+        return;
+    };
+    for (key, value) in table {
+        let child_path = if path.is_empty() {
+            key.clone()
+        } else {
+            format!("{path}.{key}")
+        };
+        let child_known = known.and_then(|k| k.get(key));
+
+        if child_known.is_none() {
+            if COMPUTED_KEYS.contains(&child_path.as_str()) {
+                findings.push(Finding::warning(format!(
+                    "Key '{child_path}' is computed at runtime; the value in the file is ignored"
+                )));
+            } else if is_empty_value(value) {
+                // Explicit empty arrays/tables matching the default are
+                // dropped on re-serialization; harmless but dead config.
+                findings.push(Finding::warning(format!(
+                    "Key '{child_path}' is empty and has no effect"
+                )));
+            } else {
+                findings.push(Finding::error(format!(
+                    "Unknown config key '{child_path}' (not recognized by the schema)"
+                )));
+            }
+        } else if value.is_table() {
+            walk_unknown_keys(value, child_known, &child_path, findings);
+        }
+    }
+}
+
+fn is_empty_value(value: &toml::Value) -> bool {
+    match value {
+        toml::Value::Array(items) => items.is_empty(),
+        toml::Value::Table(table) => table.is_empty(),
+        _ => false,
+    }
+}
+
+/// Verify that configured paths which must pre-exist actually do.
+fn check_referenced_paths(config: &Config, findings: &mut Vec<Finding>) {
+    let mut check = |key: &str, value: Option<&str>| {
+        let Some(raw) = value else { return };
+        if raw.trim().is_empty() {
+            return;
+        }
+        let expanded = shellexpand::tilde(raw);
+        if !Path::new(expanded.as_ref()).exists() {
+            findings.push(Finding::error(format!(
+                "Key '{key}' references a path that does not exist: {raw}"
+            )));
+        }
+    };
+
+    if config.skills.open_skills_enabled {
+        check(
+            "skills.open_skills_dir",
+            config.skills.open_skills_dir.as_deref(),
+        );
+    }
+    check("identity.aieos_path", config.identity.aieos_path.as_deref());
+    check(
+        "peripherals.datasheet_dir",
+        config.peripherals.datasheet_dir.as_deref(),
+    );
+    check(
+        "browser.native_chrome_path",
+        config.browser.native_chrome_path.as_deref(),
+    );
+}
+
+/// Verify configured channels carry their required fields and warn about
+/// empty allowlists (empty = deny all).
+fn check_channels(config: &Config, findings: &mut Vec<Finding>) {
+    fn require(findings: &mut Vec<Finding>, key: &str, value: &str) {
+        if value.trim().is_empty() {
+            findings.push(Finding::error(format!("Key '{key}' is required but empty")));
+        }
+    }
+    fn warn_empty_allowlist(findings: &mut Vec<Finding>, channel: &str, allowed: &[String]) {
+        if allowed.is_empty() {
+            findings.push(Finding::warning(format!(
+                "channels_config.{channel}.allowed_users is empty; every sender will be denied"
+            )));
+        }
+    }
+
+    let channels = &config.channels_config;
+    if let Some(telegram) = &channels.telegram {
+        require(
+            findings,
+            "channels_config.telegram.bot_token",
+            &telegram.bot_token,
+        );
+        warn_empty_allowlist(findings, "telegram", &telegram.allowed_users);
+    }
+    if let Some(discord) = &channels.discord {
+        require(
+            findings,
+            "channels_config.discord.bot_token",
+            &discord.bot_token,
+        );
+        warn_empty_allowlist(findings, "discord", &discord.allowed_users);
+    }
+    if let Some(slack) = &channels.slack {
+        require(
+            findings,
+            "channels_config.slack.bot_token",
+            &slack.bot_token,
+        );
+        warn_empty_allowlist(findings, "slack", &slack.allowed_users);
+    }
+    if let Some(mattermost) = &channels.mattermost {
+        require(findings, "channels_config.mattermost.url", &mattermost.url);
+        require(
+            findings,
+            "channels_config.mattermost.bot_token",
+            &mattermost.bot_token,
+        );
+        warn_empty_allowlist(findings, "mattermost", &mattermost.allowed_users);
+    }
+    if let Some(lark) = &channels.lark {
+        require(findings, "channels_config.lark.app_id", &lark.app_id);
+        require(
+            findings,
+            "channels_config.lark.app_secret",
+            &lark.app_secret,
+        );
+        warn_empty_allowlist(findings, "lark", &lark.allowed_users);
+    }
+    if let Some(dingtalk) = &channels.dingtalk {
+        require(
+            findings,
+            "channels_config.dingtalk.client_id",
+            &dingtalk.client_id,
+        );
+        require(
+            findings,
+            "channels_config.dingtalk.client_secret",
+            &dingtalk.client_secret,
+        );
+        warn_empty_allowlist(findings, "dingtalk", &dingtalk.allowed_users);
+    }
+    if let Some(qq) = &channels.qq {
+        require(findings, "channels_config.qq.app_id", &qq.app_id);
+        require(findings, "channels_config.qq.app_secret", &qq.app_secret);
+        warn_empty_allowlist(findings, "qq", &qq.allowed_users);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MINIMAL: &str = "\
+default_provider = \"openrouter\"
+default_model = \"base-model\"
+default_temperature = 0.7
+";
+
+    fn errors(findings: &[Finding]) -> Vec<&str> {
+        findings
+            .iter()
+            .filter(|f| f.severity == Severity::Error)
+            .map(|f| f.message.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn minimal_config_lints_clean() {
+        let findings = lint(MINIMAL).unwrap();
+        assert!(errors(&findings).is_empty(), "findings: {findings:?}");
+    }
+
+    #[test]
+    fn unknown_keys_are_errors() {
+        let contents = format!("{MINIMAL}\n[gatway]\nport = 3000\n");
+        let findings = lint(&contents).unwrap();
+        assert!(
+            errors(&findings)
+                .iter()
+                .any(|m| m.contains("Unknown config key 'gatway'")),
+            "findings: {findings:?}"
+        );
+    }
+
+    #[test]
+    fn wrong_value_type_fails_parse() {
+        let contents = format!("{MINIMAL}\n[gateway]\nport = \"not-a-port\"\n");
+        assert!(lint(&contents).is_err());
+    }
+
+    #[test]
+    fn missing_referenced_path_is_error() {
+        let contents = format!(
+            "{MINIMAL}\n[peripherals]\ndatasheet_dir = \"/nonexistent/zeroclaw-datasheets\"\n"
+        );
+        let findings = lint(&contents).unwrap();
+        assert!(
+            errors(&findings)
+                .iter()
+                .any(|m| m.contains("peripherals.datasheet_dir")),
+            "findings: {findings:?}"
+        );
+    }
+
+    #[test]
+    fn channel_required_fields_and_allowlists_are_checked() {
+        let contents = format!(
+            "{MINIMAL}\n[channels_config]\ncli = true\n\n[channels_config.telegram]\nbot_token = \"\"\nallowed_users = []\n"
+        );
+        let findings = lint(&contents).unwrap();
+        assert!(
+            errors(&findings)
+                .iter()
+                .any(|m| m.contains("channels_config.telegram.bot_token")),
+            "findings: {findings:?}"
+        );
+        assert!(
+            findings.iter().any(|f| f.severity == Severity::Warning
+                && f.message.contains("telegram")
+                && f.message.contains("allowed_users")),
+            "findings: {findings:?}"
+        );
+    }
+
+    #[test]
+    fn computed_keys_warn_instead_of_error() {
+        let contents = format!("{MINIMAL}workspace_dir = \"/tmp/ws\"\n");
+        let findings = lint(&contents).unwrap();
+        assert!(errors(&findings).is_empty(), "findings: {findings:?}");
+        assert!(
+            findings.iter().any(|f| f.message.contains("workspace_dir")),
+            "findings: {findings:?}"
+        );
+    }
+}
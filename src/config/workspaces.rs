@@ -0,0 +1,147 @@
+//! Multi-workspace management for `zeroclaw workspace create/list/use`.
+//!
+//! Named workspaces live at `~/.zeroclaw/workspaces/<name>/`, each a full
+//! config directory with its own `config.toml`, `workspace/` files, memory,
+//! and cron state. The default workspace is `~/.zeroclaw` itself. Switching
+//! persists the `active_workspace.toml` marker used by config resolution,
+//! so every later command runs against the selected workspace.
+
+use super::schema;
+use anyhow::{Context, Result};
+use std::path::PathBuf;
+use tokio::fs;
+
+/// Name reserved for the base `~/.zeroclaw` workspace.
+pub const DEFAULT_WORKSPACE_NAME: &str = "default";
+
+#[derive(Debug, Clone)]
+pub struct WorkspaceEntry {
+    pub name: String,
+    pub config_dir: PathBuf,
+    pub active: bool,
+}
+
+/// Reject workspace names that could escape the workspaces directory.
+fn validate_workspace_name(name: &str) -> Result<()> {
+    let valid = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if !valid {
+        anyhow::bail!("Invalid workspace name '{name}' (allowed: ASCII letters, digits, '-', '_')");
+    }
+    if name == DEFAULT_WORKSPACE_NAME {
+        anyhow::bail!("Workspace name '{DEFAULT_WORKSPACE_NAME}' is reserved for ~/.zeroclaw");
+    }
+    Ok(())
+}
+
+fn workspaces_root() -> Result<PathBuf> {
+    Ok(schema::default_config_dir()?.join("workspaces"))
+}
+
+/// Create a new named workspace directory. Seeds `config.toml` (and the
+/// secret key it may reference) from the base workspace when present, so
+/// provider credentials carry over; otherwise the workspace starts empty
+/// and `zeroclaw onboard` initializes it after `workspace use`.
+pub async fn create(name: &str) -> Result<PathBuf> {
+    validate_workspace_name(name)?;
+
+    let config_dir = workspaces_root()?.join(name);
+    if config_dir.exists() {
+        anyhow::bail!(
+            "Workspace '{name}' already exists at {} (switch with `zeroclaw workspace use {name}`)",
+            config_dir.display()
+        );
+    }
+    fs::create_dir_all(config_dir.join("workspace"))
+        .await
+        .with_context(|| format!("Failed to create workspace directory for '{name}'"))?;
+
+    let base_dir = schema::default_config_dir()?;
+    for file in ["config.toml", ".secret_key"] {
+        let source = base_dir.join(file);
+        if source.exists() {
+            fs::copy(&source, config_dir.join(file))
+                .await
+                .with_context(|| format!("Failed to seed {file} into workspace '{name}'"))?;
+        }
+    }
+
+    Ok(config_dir)
+}
+
+/// List the default workspace plus every named workspace, marking the one
+/// the `active_workspace.toml` marker currently points at.
+pub async fn list() -> Result<Vec<WorkspaceEntry>> {
+    let base_dir = schema::default_config_dir()?;
+    let active_config_dir = schema::load_persisted_workspace_dirs(&base_dir)
+        .await?
+        .map_or_else(|| base_dir.clone(), |(config_dir, _)| config_dir);
+
+    let mut entries = vec![WorkspaceEntry {
+        name: DEFAULT_WORKSPACE_NAME.to_string(),
+        config_dir: base_dir.clone(),
+        active: active_config_dir == base_dir,
+    }];
+
+    let root = workspaces_root()?;
+    if root.is_dir() {
+        let mut dir = fs::read_dir(&root)
+            .await
+            .with_context(|| format!("Failed to read workspaces directory {}", root.display()))?;
+        while let Some(entry) = dir.next_entry().await? {
+            if !entry.file_type().await?.is_dir() {
+                continue;
+            }
+            let Ok(name) = entry.file_name().into_string() else {
+                continue;
+            };
+            let config_dir = entry.path();
+            entries.push(WorkspaceEntry {
+                active: active_config_dir == config_dir,
+                name,
+                config_dir,
+            });
+        }
+    }
+
+    entries[1..].sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(entries)
+}
+
+/// Switch the active workspace by persisting the `active_workspace.toml`
+/// marker. `use default` switches back to the base `~/.zeroclaw` workspace.
+pub async fn use_workspace(name: &str) -> Result<PathBuf> {
+    let base_dir = schema::default_config_dir()?;
+    let config_dir = if name == DEFAULT_WORKSPACE_NAME {
+        base_dir
+    } else {
+        validate_workspace_name(name)?;
+        let config_dir = workspaces_root()?.join(name);
+        if !config_dir.is_dir() {
+            anyhow::bail!(
+                "Workspace '{name}' does not exist (create it with `zeroclaw workspace create {name}`)"
+            );
+        }
+        config_dir
+    };
+
+    schema::persist_active_workspace_config_dir(&config_dir).await?;
+    Ok(config_dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn workspace_name_validation_rejects_traversal_and_reserved_names() {
+        assert!(validate_workspace_name("ops-bot").is_ok());
+        assert!(validate_workspace_name("research_2").is_ok());
+        assert!(validate_workspace_name("../escape").is_err());
+        assert!(validate_workspace_name("a/b").is_err());
+        assert!(validate_workspace_name("").is_err());
+        assert!(validate_workspace_name(DEFAULT_WORKSPACE_NAME).is_err());
+    }
+}
@@ -0,0 +1,370 @@
+//! Contact book: map people to channel identities, email, and timezone.
+//!
+//! Contacts are stored in a SQLite database under the workspace
+//! (`contacts/contacts.db`), mirroring the cron store layout. The store is
+//! exposed through the `zeroclaw contacts` CLI and the `contacts_add` /
+//! `contacts_list` tools so the agent can resolve "message Alice on Slack"
+//! to a concrete channel identity, or reason about meeting times using a
+//! contact's IANA timezone.
+
+use crate::config::Config;
+use anyhow::{bail, Context, Result};
+use chrono::Utc;
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use uuid::Uuid;
+
+/// A single contact entry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Contact {
+    /// Stable unique ID (UUID v4).
+    pub id: String,
+    /// Display name, unique case-insensitively (e.g. "Alice").
+    pub name: String,
+    /// Channel identities keyed by channel type (e.g. "slack" -> "U12345").
+    pub channels: BTreeMap<String, String>,
+    /// Email address, if known.
+    pub email: Option<String>,
+    /// IANA timezone name (e.g. "Europe/London"), validated on write.
+    pub timezone: Option<String>,
+    /// Free-form notes.
+    pub notes: Option<String>,
+    /// RFC 3339 creation timestamp.
+    pub created_at: String,
+}
+
+impl Contact {
+    /// One-line human summary used by CLI and tool output.
+    pub fn summary(&self) -> String {
+        let mut parts: Vec<String> = Vec::new();
+        for (channel, identity) in &self.channels {
+            parts.push(format!("{channel}:{identity}"));
+        }
+        if let Some(email) = &self.email {
+            parts.push(format!("email:{email}"));
+        }
+        if let Some(tz) = &self.timezone {
+            parts.push(format!("tz:{tz}"));
+        }
+        if parts.is_empty() {
+            self.name.clone()
+        } else {
+            format!("{} — {}", self.name, parts.join(", "))
+        }
+    }
+}
+
+/// Parse a `channel:identity` pair as accepted by `--channel` and the
+/// `contacts_add` tool (e.g. `slack:U12345`, `telegram:zeroclaw_user`).
+pub fn parse_channel_binding(raw: &str) -> Result<(String, String)> {
+    let (channel, identity) = raw.split_once(':').with_context(|| {
+        format!("Invalid channel binding '{raw}': expected <channel>:<identity>")
+    })?;
+    let channel = channel.trim().to_lowercase();
+    let identity = identity.trim().to_string();
+    if channel.is_empty() || identity.is_empty() {
+        bail!("Invalid channel binding '{raw}': expected <channel>:<identity>");
+    }
+    Ok((channel, identity))
+}
+
+fn validate_timezone(tz: &str) -> Result<()> {
+    if tz.parse::<chrono_tz::Tz>().is_err() {
+        bail!("Unknown IANA timezone: '{tz}' (expected e.g. 'America/New_York')");
+    }
+    Ok(())
+}
+
+/// Add a new contact. Fails if a contact with the same name already exists.
+pub fn add_contact(
+    config: &Config,
+    name: &str,
+    channels: BTreeMap<String, String>,
+    email: Option<String>,
+    timezone: Option<String>,
+    notes: Option<String>,
+) -> Result<Contact> {
+    let name = name.trim();
+    if name.is_empty() {
+        bail!("Contact name cannot be empty");
+    }
+    if let Some(tz) = &timezone {
+        validate_timezone(tz)?;
+    }
+    if get_contact(config, name)?.is_some() {
+        bail!("Contact '{name}' already exists. Remove it first with: zeroclaw contacts remove '{name}'");
+    }
+
+    let contact = Contact {
+        id: Uuid::new_v4().to_string(),
+        name: name.to_string(),
+        channels,
+        email,
+        timezone,
+        notes,
+        created_at: Utc::now().to_rfc3339(),
+    };
+
+    with_connection(config, |conn| {
+        conn.execute(
+            "INSERT INTO contacts (id, name, channels, email, timezone, notes, created_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            params![
+                contact.id,
+                contact.name,
+                serde_json::to_string(&contact.channels)?,
+                contact.email,
+                contact.timezone,
+                contact.notes,
+                contact.created_at,
+            ],
+        )
+        .context("Failed to insert contact")?;
+        Ok(())
+    })?;
+
+    Ok(contact)
+}
+
+/// List all contacts, sorted by name.
+pub fn list_contacts(config: &Config) -> Result<Vec<Contact>> {
+    with_connection(config, |conn| {
+        let mut stmt = conn.prepare(
+            "SELECT id, name, channels, email, timezone, notes, created_at
+             FROM contacts ORDER BY name COLLATE NOCASE ASC",
+        )?;
+        let rows = stmt.query_map([], map_contact_row)?;
+        let mut contacts = Vec::new();
+        for row in rows {
+            contacts.push(row?);
+        }
+        Ok(contacts)
+    })
+}
+
+/// Look up a single contact by name (case-insensitive exact match).
+pub fn get_contact(config: &Config, name: &str) -> Result<Option<Contact>> {
+    with_connection(config, |conn| {
+        let contact = conn
+            .query_row(
+                "SELECT id, name, channels, email, timezone, notes, created_at
+                 FROM contacts WHERE name = ?1 COLLATE NOCASE",
+                params![name.trim()],
+                map_contact_row,
+            )
+            .optional()?;
+        Ok(contact)
+    })
+}
+
+/// Remove a contact by name. Returns true if a contact was removed.
+pub fn remove_contact(config: &Config, name: &str) -> Result<bool> {
+    with_connection(config, |conn| {
+        let removed = conn.execute(
+            "DELETE FROM contacts WHERE name = ?1 COLLATE NOCASE",
+            params![name.trim()],
+        )?;
+        Ok(removed > 0)
+    })
+}
+
+fn map_contact_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<Contact> {
+    let channels_json: String = row.get(2)?;
+    let channels: BTreeMap<String, String> =
+        serde_json::from_str(&channels_json).unwrap_or_default();
+    Ok(Contact {
+        id: row.get(0)?,
+        name: row.get(1)?,
+        channels,
+        email: row.get(3)?,
+        timezone: row.get(4)?,
+        notes: row.get(5)?,
+        created_at: row.get(6)?,
+    })
+}
+
+fn with_connection<T>(config: &Config, f: impl FnOnce(&Connection) -> Result<T>) -> Result<T> {
+    let db_path = config.workspace_dir.join("contacts").join("contacts.db");
+    if let Some(parent) = db_path.parent() {
+        std::fs::create_dir_all(parent).with_context(|| {
+            format!("Failed to create contacts directory: {}", parent.display())
+        })?;
+    }
+
+    let conn = Connection::open(&db_path)
+        .with_context(|| format!("Failed to open contacts DB: {}", db_path.display()))?;
+
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS contacts (
+            id         TEXT PRIMARY KEY,
+            name       TEXT NOT NULL UNIQUE,
+            channels   TEXT NOT NULL DEFAULT '{}',
+            email      TEXT,
+            timezone   TEXT,
+            notes      TEXT,
+            created_at TEXT NOT NULL
+        );",
+    )?;
+
+    f(&conn)
+}
+
+#[allow(clippy::needless_pass_by_value)]
+pub fn handle_command(command: crate::ContactCommands, config: &Config) -> Result<()> {
+    match command {
+        crate::ContactCommands::Add {
+            name,
+            channel,
+            email,
+            timezone,
+            notes,
+        } => {
+            let mut channels = BTreeMap::new();
+            for binding in &channel {
+                let (channel_type, identity) = parse_channel_binding(binding)?;
+                channels.insert(channel_type, identity);
+            }
+            let contact = add_contact(config, &name, channels, email, timezone, notes)?;
+            println!("✅ Added contact: {}", contact.summary());
+            Ok(())
+        }
+        crate::ContactCommands::List => {
+            let contacts = list_contacts(config)?;
+            if contacts.is_empty() {
+                println!("No contacts yet.");
+                println!("\nUsage:");
+                println!("  zeroclaw contacts add 'Alice' --channel slack:U12345 --timezone America/New_York");
+                return Ok(());
+            }
+            println!("📇 Contacts ({}):", contacts.len());
+            for contact in contacts {
+                println!("- {}", contact.summary());
+                if let Some(notes) = &contact.notes {
+                    println!("    notes: {notes}");
+                }
+            }
+            Ok(())
+        }
+        crate::ContactCommands::Show { name } => match get_contact(config, &name)? {
+            Some(contact) => {
+                println!("{}", serde_json::to_string_pretty(&contact)?);
+                Ok(())
+            }
+            None => bail!("No contact named '{name}'"),
+        },
+        crate::ContactCommands::Remove { name } => {
+            if remove_contact(config, &name)? {
+                println!("🗑️  Removed contact '{name}'");
+                Ok(())
+            } else {
+                bail!("No contact named '{name}'")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn test_config(tmp: &TempDir) -> Config {
+        Config {
+            workspace_dir: tmp.path().join("workspace"),
+            config_path: tmp.path().join("config.toml"),
+            ..Config::default()
+        }
+    }
+
+    #[test]
+    fn add_and_get_roundtrip() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp);
+        let mut channels = BTreeMap::new();
+        channels.insert("slack".to_string(), "U12345".to_string());
+
+        let added = add_contact(
+            &config,
+            "user_a",
+            channels,
+            Some("user_a@example.com".into()),
+            Some("America/New_York".into()),
+            None,
+        )
+        .unwrap();
+
+        let fetched = get_contact(&config, "user_a").unwrap().unwrap();
+        assert_eq!(fetched.id, added.id);
+        assert_eq!(
+            fetched.channels.get("slack").map(String::as_str),
+            Some("U12345")
+        );
+        assert_eq!(fetched.timezone.as_deref(), Some("America/New_York"));
+    }
+
+    #[test]
+    fn get_is_case_insensitive() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp);
+        add_contact(&config, "User_A", BTreeMap::new(), None, None, None).unwrap();
+        assert!(get_contact(&config, "user_a").unwrap().is_some());
+    }
+
+    #[test]
+    fn duplicate_name_rejected() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp);
+        add_contact(&config, "user_a", BTreeMap::new(), None, None, None).unwrap();
+        let result = add_contact(&config, "user_a", BTreeMap::new(), None, None, None);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn invalid_timezone_rejected() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp);
+        let result = add_contact(
+            &config,
+            "user_a",
+            BTreeMap::new(),
+            None,
+            Some("Not/AZone".into()),
+            None,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn remove_returns_false_when_missing() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp);
+        assert!(!remove_contact(&config, "nobody").unwrap());
+    }
+
+    #[test]
+    fn list_sorted_by_name() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp);
+        add_contact(&config, "user_b", BTreeMap::new(), None, None, None).unwrap();
+        add_contact(&config, "user_a", BTreeMap::new(), None, None, None).unwrap();
+        let contacts = list_contacts(&config).unwrap();
+        assert_eq!(contacts.len(), 2);
+        assert_eq!(contacts[0].name, "user_a");
+        assert_eq!(contacts[1].name, "user_b");
+    }
+
+    #[test]
+    fn parse_channel_binding_valid() {
+        let (channel, identity) = parse_channel_binding("Slack:U12345").unwrap();
+        assert_eq!(channel, "slack");
+        assert_eq!(identity, "U12345");
+    }
+
+    #[test]
+    fn parse_channel_binding_invalid() {
+        assert!(parse_channel_binding("no-separator").is_err());
+        assert!(parse_channel_binding(":identity").is_err());
+        assert!(parse_channel_binding("channel:").is_err());
+    }
+}
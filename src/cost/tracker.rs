@@ -130,7 +130,13 @@ impl CostTracker {
             .get(model)
             .map(|p| (p.input, p.output))
             .unwrap_or((0.0, 0.0));
-        let usage = TokenUsage::new(model, prompt_tokens, completion_tokens, input_price, output_price);
+        let usage = TokenUsage::new(
+            model,
+            prompt_tokens,
+            completion_tokens,
+            input_price,
+            output_price,
+        );
         self.record_usage(usage)
     }
 
@@ -4,6 +4,7 @@ use anyhow::{bail, Result};
 
 mod schedule;
 mod store;
+mod template;
 mod types;
 
 pub mod scheduler;
@@ -129,7 +130,8 @@ pub fn handle_command(command: crate::CronCommands, config: &Config) -> Result<(
             };
 
             if let Some(ref cmd) = command {
-                let security = SecurityPolicy::from_config(&config.autonomy, &config.workspace_dir);
+                let security = SecurityPolicy::from_config(&config.autonomy, &config.workspace_dir)
+                    .with_audit(&config.security.audit, config.zeroclaw_dir());
                 if !security.is_command_allowed(cmd) {
                     bail!("Command blocked by security policy: {cmd}");
                 }
@@ -64,6 +64,7 @@ pub fn add_agent_job(
     prompt: &str,
     session_target: SessionTarget,
     model: Option<String>,
+    tags: Vec<String>,
     delivery: Option<DeliveryConfig>,
     delete_after_run: bool,
 ) -> Result<CronJob> {
@@ -79,8 +80,8 @@ pub fn add_agent_job(
         conn.execute(
             "INSERT INTO cron_jobs (
                 id, expression, command, schedule, job_type, prompt, name, session_target, model,
-                enabled, delivery, delete_after_run, created_at, next_run
-             ) VALUES (?1, ?2, '', ?3, 'agent', ?4, ?5, ?6, ?7, 1, ?8, ?9, ?10, ?11)",
+                tags, enabled, delivery, delete_after_run, created_at, next_run
+             ) VALUES (?1, ?2, '', ?3, 'agent', ?4, ?5, ?6, ?7, ?8, 1, ?9, ?10, ?11, ?12)",
             params![
                 id,
                 expression,
@@ -89,6 +90,7 @@ pub fn add_agent_job(
                 name,
                 session_target.as_str(),
                 model,
+                encode_tags(&tags)?,
                 serde_json::to_string(&delivery)?,
                 if delete_after_run { 1 } else { 0 },
                 now.to_rfc3339(),
@@ -106,7 +108,8 @@ pub fn list_jobs(config: &Config) -> Result<Vec<CronJob>> {
     with_connection(config, |conn| {
         let mut stmt = conn.prepare(
             "SELECT id, expression, command, schedule, job_type, prompt, name, session_target, model,
-                    enabled, delivery, delete_after_run, created_at, next_run, last_run, last_status, last_output
+                    enabled, delivery, delete_after_run, created_at, next_run, last_run, last_status, last_output,
+                    tags
              FROM cron_jobs ORDER BY next_run ASC",
         )?;
 
@@ -124,7 +127,8 @@ pub fn get_job(config: &Config, job_id: &str) -> Result<CronJob> {
     with_connection(config, |conn| {
         let mut stmt = conn.prepare(
             "SELECT id, expression, command, schedule, job_type, prompt, name, session_target, model,
-                    enabled, delivery, delete_after_run, created_at, next_run, last_run, last_status, last_output
+                    enabled, delivery, delete_after_run, created_at, next_run, last_run, last_status, last_output,
+                    tags
              FROM cron_jobs WHERE id = ?1",
         )?;
 
@@ -157,7 +161,8 @@ pub fn due_jobs(config: &Config, now: DateTime<Utc>) -> Result<Vec<CronJob>> {
     with_connection(config, |conn| {
         let mut stmt = conn.prepare(
             "SELECT id, expression, command, schedule, job_type, prompt, name, session_target, model,
-                    enabled, delivery, delete_after_run, created_at, next_run, last_run, last_status, last_output
+                    enabled, delivery, delete_after_run, created_at, next_run, last_run, last_status, last_output,
+                    tags
              FROM cron_jobs
              WHERE enabled = 1 AND next_run <= ?1
              ORDER BY next_run ASC
@@ -202,6 +207,9 @@ pub fn update_job(config: &Config, job_id: &str, patch: CronJobPatch) -> Result<
     if let Some(model) = patch.model {
         job.model = Some(model);
     }
+    if let Some(tags) = patch.tags {
+        job.tags = tags;
+    }
     if let Some(target) = patch.session_target {
         job.session_target = target;
     }
@@ -217,9 +225,9 @@ pub fn update_job(config: &Config, job_id: &str, patch: CronJobPatch) -> Result<
         conn.execute(
             "UPDATE cron_jobs
              SET expression = ?1, command = ?2, schedule = ?3, job_type = ?4, prompt = ?5, name = ?6,
-                 session_target = ?7, model = ?8, enabled = ?9, delivery = ?10, delete_after_run = ?11,
-                 next_run = ?12
-             WHERE id = ?13",
+                 session_target = ?7, model = ?8, tags = ?9, enabled = ?10, delivery = ?11,
+                 delete_after_run = ?12, next_run = ?13
+             WHERE id = ?14",
             params![
                 job.expression,
                 job.command,
@@ -229,6 +237,7 @@ pub fn update_job(config: &Config, job_id: &str, patch: CronJobPatch) -> Result<
                 job.name,
                 job.session_target.as_str(),
                 job.model,
+                encode_tags(&job.tags)?,
                 if job.enabled { 1 } else { 0 },
                 serde_json::to_string(&job.delivery)?,
                 if job.delete_after_run { 1 } else { 0 },
@@ -414,6 +423,9 @@ fn map_cron_job_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<CronJob> {
     let delivery_raw: Option<String> = row.get(10)?;
     let delivery = decode_delivery(delivery_raw.as_deref()).map_err(sql_conversion_error)?;
 
+    let tags_raw: Option<String> = row.get(17)?;
+    let tags = decode_tags(tags_raw.as_deref()).map_err(sql_conversion_error)?;
+
     let next_run_raw: String = row.get(13)?;
     let last_run_raw: Option<String> = row.get(14)?;
     let created_at_raw: String = row.get(12)?;
@@ -428,6 +440,7 @@ fn map_cron_job_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<CronJob> {
         name: row.get(6)?,
         session_target: SessionTarget::parse(&row.get::<_, String>(7)?),
         model: row.get(8)?,
+        tags,
         enabled: row.get::<_, i64>(9)? != 0,
         delivery,
         delete_after_run: row.get::<_, i64>(11)? != 0,
@@ -461,6 +474,25 @@ fn decode_schedule(schedule_raw: Option<&str>, expression: &str) -> Result<Sched
     })
 }
 
+fn encode_tags(tags: &[String]) -> Result<Option<String>> {
+    if tags.is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(serde_json::to_string(tags)?))
+    }
+}
+
+fn decode_tags(tags_raw: Option<&str>) -> Result<Vec<String>> {
+    if let Some(raw) = tags_raw {
+        let trimmed = raw.trim();
+        if !trimmed.is_empty() {
+            return serde_json::from_str(trimmed)
+                .with_context(|| format!("Failed to parse cron tags JSON: {trimmed}"));
+        }
+    }
+    Ok(Vec::new())
+}
+
 fn decode_delivery(delivery_raw: Option<&str>) -> Result<DeliveryConfig> {
     if let Some(raw) = delivery_raw {
         let trimmed = raw.trim();
@@ -531,7 +563,8 @@ fn with_connection<T>(config: &Config, f: impl FnOnce(&Connection) -> Result<T>)
             next_run         TEXT NOT NULL,
             last_run         TEXT,
             last_status      TEXT,
-            last_output      TEXT
+            last_output      TEXT,
+            tags             TEXT
         );
         CREATE INDEX IF NOT EXISTS idx_cron_jobs_next_run ON cron_jobs(next_run);
 
@@ -560,6 +593,7 @@ fn with_connection<T>(config: &Config, f: impl FnOnce(&Connection) -> Result<T>)
     add_column_if_missing(&conn, "enabled", "INTEGER NOT NULL DEFAULT 1")?;
     add_column_if_missing(&conn, "delivery", "TEXT")?;
     add_column_if_missing(&conn, "delete_after_run", "INTEGER NOT NULL DEFAULT 0")?;
+    add_column_if_missing(&conn, "tags", "TEXT")?;
 
     f(&conn)
 }
@@ -0,0 +1,195 @@
+//! Fire-time template interpolation for cron agent prompts.
+//!
+//! Agent job prompts may embed `{{date}}`, `{{weather:<city>}}`, and
+//! `{{memory:<topic>}}` variables. They are resolved just before the job is
+//! handed to the agent so recurring reports carry fresh context instead of
+//! whatever was true when the job was created.
+
+use crate::config::Config;
+use tokio::time::Duration;
+
+const WEATHER_TIMEOUT_SECS: u64 = 10;
+const MEMORY_RECALL_LIMIT: usize = 5;
+
+/// Resolve all `{{...}}` variables in `input`.
+///
+/// Unknown variables are left verbatim so typos stay visible in the agent
+/// prompt instead of silently disappearing. Lookup failures are replaced with
+/// an explicit `(unavailable)` marker and logged — a stale weather endpoint
+/// must not block the scheduled run itself.
+pub async fn resolve(config: &Config, input: &str) -> String {
+    let mut output = String::with_capacity(input.len());
+    let mut rest = input;
+    while let Some(start) = rest.find("{{") {
+        output.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let Some(end) = after.find("}}") else {
+            // Unterminated variable: keep the remainder as-is.
+            output.push_str(&rest[start..]);
+            return output;
+        };
+        let var = after[..end].trim();
+        match resolve_var(config, var).await {
+            Some(value) => output.push_str(&value),
+            None => {
+                output.push_str("{{");
+                output.push_str(&after[..end]);
+                output.push_str("}}");
+            }
+        }
+        rest = &after[end + 2..];
+    }
+    output.push_str(rest);
+    output
+}
+
+async fn resolve_var(config: &Config, var: &str) -> Option<String> {
+    let (name, arg) = match var.split_once(':') {
+        Some((name, arg)) => (name.trim(), Some(arg.trim())),
+        None => (var, None),
+    };
+    match (name, arg) {
+        ("date", None) => Some(chrono::Local::now().format("%Y-%m-%d").to_string()),
+        ("weather", Some(city)) if !city.is_empty() => Some(fetch_weather(city).await),
+        ("memory", Some(topic)) if !topic.is_empty() => Some(recall_memory(config, topic).await),
+        _ => None,
+    }
+}
+
+async fn fetch_weather(city: &str) -> String {
+    match fetch_weather_inner(city).await {
+        Ok(report) => report,
+        Err(e) => {
+            tracing::warn!("Cron template weather lookup failed for '{city}': {e}");
+            format!("(weather for {city} unavailable)")
+        }
+    }
+}
+
+async fn fetch_weather_inner(city: &str) -> anyhow::Result<String> {
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_secs(WEATHER_TIMEOUT_SECS))
+        .build()?;
+    let url = format!("https://wttr.in/{}?format=3", urlencoding::encode(city));
+    let response = client.get(&url).send().await?;
+    if !response.status().is_success() {
+        anyhow::bail!("weather service returned {}", response.status());
+    }
+    Ok(response.text().await?.trim().to_string())
+}
+
+async fn recall_memory(config: &Config, topic: &str) -> String {
+    match recall_memory_inner(config, topic).await {
+        Ok(Some(text)) => text,
+        Ok(None) => format!("(no stored memories match \"{topic}\")"),
+        Err(e) => {
+            tracing::warn!("Cron template memory recall failed for '{topic}': {e}");
+            format!("(memory recall for \"{topic}\" unavailable)")
+        }
+    }
+}
+
+async fn recall_memory_inner(config: &Config, topic: &str) -> anyhow::Result<Option<String>> {
+    let memory = crate::memory::create_memory(
+        &config.memory,
+        &config.workspace_dir,
+        config.api_key.as_deref(),
+    )?;
+    let entries = memory.recall(topic, MEMORY_RECALL_LIMIT, None).await?;
+    if entries.is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(
+        entries
+            .iter()
+            .map(|entry| format!("- {}: {}", entry.key, entry.content))
+            .collect::<Vec<_>>()
+            .join("\n"),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::MemoryCategory;
+    use tempfile::TempDir;
+
+    async fn test_config(tmp: &TempDir) -> Config {
+        let config = Config {
+            workspace_dir: tmp.path().join("workspace"),
+            config_path: tmp.path().join("config.toml"),
+            ..Config::default()
+        };
+        tokio::fs::create_dir_all(&config.workspace_dir)
+            .await
+            .unwrap();
+        config
+    }
+
+    #[tokio::test]
+    async fn resolve_substitutes_date() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp).await;
+
+        let resolved = resolve(&config, "Daily report for {{date}}").await;
+        let today = chrono::Local::now().format("%Y-%m-%d").to_string();
+        assert_eq!(resolved, format!("Daily report for {today}"));
+    }
+
+    #[tokio::test]
+    async fn resolve_leaves_unknown_variables_verbatim() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp).await;
+
+        let resolved = resolve(&config, "Use {{unknown}} and {{weather:}} as-is").await;
+        assert_eq!(resolved, "Use {{unknown}} and {{weather:}} as-is");
+    }
+
+    #[tokio::test]
+    async fn resolve_keeps_unterminated_braces() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp).await;
+
+        let resolved = resolve(&config, "Broken {{date").await;
+        assert_eq!(resolved, "Broken {{date");
+    }
+
+    #[tokio::test]
+    async fn resolve_passes_through_plain_text() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp).await;
+
+        let resolved = resolve(&config, "No variables here").await;
+        assert_eq!(resolved, "No variables here");
+    }
+
+    #[tokio::test]
+    async fn resolve_inlines_memory_recall() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp).await;
+        let memory =
+            crate::memory::create_memory(&config.memory, &config.workspace_dir, None).unwrap();
+        memory
+            .store(
+                "standup_notes",
+                "ship the quarterly report",
+                MemoryCategory::Core,
+                None,
+            )
+            .await
+            .unwrap();
+
+        let resolved = resolve(&config, "Context: {{memory:standup_notes}}").await;
+        assert!(resolved.contains("standup_notes"));
+        assert!(resolved.contains("ship the quarterly report"));
+    }
+
+    #[tokio::test]
+    async fn resolve_reports_empty_memory_match() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp).await;
+
+        let resolved = resolve(&config, "{{memory:nothing_stored_here}}").await;
+        assert!(resolved.contains("no stored memories match"));
+    }
+}
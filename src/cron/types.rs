@@ -105,6 +105,8 @@ pub struct CronJob {
     pub job_type: JobType,
     pub session_target: SessionTarget,
     pub model: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
     pub enabled: bool,
     pub delivery: DeliveryConfig,
     pub delete_after_run: bool,
@@ -135,6 +137,7 @@ pub struct CronJobPatch {
     pub enabled: Option<bool>,
     pub delivery: Option<DeliveryConfig>,
     pub model: Option<String>,
+    pub tags: Option<Vec<String>>,
     pub session_target: Option<SessionTarget>,
     pub delete_after_run: Option<bool>,
 }
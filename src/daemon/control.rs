@@ -0,0 +1,182 @@
+//! Local control socket for CLI ↔ daemon communication.
+//!
+//! The daemon listens on a Unix-domain socket next to the config file and
+//! answers newline-delimited JSON requests (`{"method": "..."}` in, one JSON
+//! object out). CLI commands that inspect a running daemon — `status
+//! --components`, `top` — query the socket first for a live snapshot and fall
+//! back to the periodically flushed state file when the socket is absent:
+//! the daemon not running is a normal state, not an error.
+//!
+//! The protocol is deliberately read-only (no method mutates daemon state)
+//! and the socket is created with owner-only permissions, so exposure stays
+//! limited to what the state file already reveals. On non-Unix platforms
+//! there is no socket transport and clients always use the file fallback.
+
+use crate::config::Config;
+use serde_json::{json, Value};
+use std::path::PathBuf;
+
+/// Path of the daemon control socket (next to the state file).
+pub fn socket_path(config: &Config) -> PathBuf {
+    config
+        .config_path
+        .parent()
+        .map_or_else(|| PathBuf::from("."), PathBuf::from)
+        .join("daemon_control.sock")
+}
+
+/// Answer one control request. Read-only by design: every method reports
+/// state, none changes it.
+fn handle_request(raw: &str) -> Value {
+    let method = serde_json::from_str::<Value>(raw)
+        .ok()
+        .and_then(|v| v.get("method").and_then(Value::as_str).map(str::to_string));
+    match method.as_deref() {
+        Some("ping") => json!({ "ok": true, "pid": std::process::id() }),
+        Some("status") => {
+            let mut snapshot = crate::health::snapshot_json();
+            if let Some(obj) = snapshot.as_object_mut() {
+                // Mirrors the state-file field so clients can reuse the same
+                // staleness check; a live answer is by definition fresh.
+                obj.insert("written_at".into(), json!(chrono::Utc::now().to_rfc3339()));
+            }
+            snapshot
+        }
+        Some(other) => json!({ "error": format!("unknown method: {other}") }),
+        None => json!({ "error": "expected a JSON object with a \"method\" field" }),
+    }
+}
+
+/// Query the running daemon's control socket. Returns `None` when the daemon
+/// is not running, the socket is unsupported on this platform, or the
+/// request fails for any reason — callers fall back to file inspection.
+#[cfg(unix)]
+pub fn query(config: &Config, method: &str) -> Option<Value> {
+    query_at(&socket_path(config), method)
+}
+
+#[cfg(not(unix))]
+pub fn query(_config: &Config, _method: &str) -> Option<Value> {
+    // Named-pipe transport is not implemented; file fallback applies.
+    None
+}
+
+#[cfg(unix)]
+fn query_at(path: &std::path::Path, method: &str) -> Option<Value> {
+    use std::io::{BufRead, BufReader, Write};
+
+    let stream = std::os::unix::net::UnixStream::connect(path).ok()?;
+    let timeout = Some(std::time::Duration::from_secs(2));
+    stream.set_read_timeout(timeout).ok()?;
+    stream.set_write_timeout(timeout).ok()?;
+
+    let mut writer = stream.try_clone().ok()?;
+    writeln!(writer, "{}", json!({ "method": method })).ok()?;
+
+    let mut line = String::new();
+    BufReader::new(stream).read_line(&mut line).ok()?;
+    serde_json::from_str(&line).ok()
+}
+
+/// Control socket server loop. Daemon component entry point (Unix only).
+#[cfg(unix)]
+pub async fn serve(config: Config) -> anyhow::Result<()> {
+    serve_at(&socket_path(&config)).await
+}
+
+#[cfg(unix)]
+async fn serve_at(path: &std::path::Path) -> anyhow::Result<()> {
+    use anyhow::Context as _;
+    use std::os::unix::fs::PermissionsExt as _;
+    use tokio::io::{AsyncBufReadExt as _, AsyncWriteExt as _};
+
+    // A socket file left over from a previous run would make bind fail.
+    let _ = std::fs::remove_file(path);
+    let listener = tokio::net::UnixListener::bind(path)
+        .with_context(|| format!("Failed to bind control socket at {}", path.display()))?;
+    // Owner-only: the socket exposes daemon state to the local user.
+    std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o600))
+        .with_context(|| format!("Failed to restrict control socket at {}", path.display()))?;
+
+    loop {
+        let (stream, _) = listener.accept().await?;
+        tokio::spawn(async move {
+            let (reader, mut writer) = stream.into_split();
+            let mut line = String::new();
+            if tokio::io::BufReader::new(reader)
+                .read_line(&mut line)
+                .await
+                .is_err()
+            {
+                return;
+            }
+            let response = handle_request(line.trim());
+            let _ = writer.write_all(response.to_string().as_bytes()).await;
+            let _ = writer.write_all(b"\n").await;
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handle_request_answers_ping_with_pid() {
+        let response = handle_request(r#"{"method":"ping"}"#);
+        assert_eq!(response.get("ok"), Some(&json!(true)));
+        assert_eq!(response.get("pid"), Some(&json!(std::process::id())));
+    }
+
+    #[test]
+    fn handle_request_status_returns_fresh_health_snapshot() {
+        crate::health::mark_component_ok("control-test");
+        let response = handle_request(r#"{"method":"status"}"#);
+        assert!(response.get("written_at").is_some());
+        assert!(response
+            .get("components")
+            .and_then(|c| c.get("control-test"))
+            .is_some());
+    }
+
+    #[test]
+    fn handle_request_rejects_unknown_method_and_bad_input() {
+        let response = handle_request(r#"{"method":"shutdown"}"#);
+        assert!(response["error"]
+            .as_str()
+            .unwrap()
+            .contains("unknown method"));
+        assert!(handle_request("not json").get("error").is_some());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn server_answers_client_query_over_socket() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("control.sock");
+        let server_path = path.clone();
+        let server = tokio::spawn(async move { serve_at(&server_path).await });
+
+        // Wait for the socket file to appear before connecting.
+        for _ in 0..50 {
+            if path.exists() {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+
+        let query_path = path.clone();
+        let response = tokio::task::spawn_blocking(move || query_at(&query_path, "ping"))
+            .await
+            .unwrap();
+        assert_eq!(response.unwrap().get("ok"), Some(&json!(true)));
+        server.abort();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn query_returns_none_without_running_daemon() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(query_at(&dir.path().join("missing.sock"), "ping").is_none());
+    }
+}
@@ -0,0 +1,368 @@
+//! Daily activity digest (`[digest]` section).
+//!
+//! Once per day at the configured UTC time the daemon aggregates the last
+//! 24 hours of delegation stats, cron outcomes, channel activity, and cost,
+//! asks the default model for a short natural-language summary, and delivers
+//! it to the configured channel. The summary plus the raw stats are stored
+//! as a markdown artifact under `reports/` in the workspace.
+
+use crate::config::Config;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Duration as ChronoDuration, NaiveTime, Utc};
+use std::path::PathBuf;
+
+/// How often the worker checks whether the digest is due.
+const TICK_SECS: u64 = 300;
+/// Hours covered by each digest.
+const WINDOW_HOURS: i64 = 24;
+/// Agents listed in the delegation stats.
+const TOP_COST_AGENTS: usize = 5;
+
+/// Instructions for the model pass that turns raw stats into prose.
+const SUMMARY_SYSTEM_PROMPT: &str = "You write ZeroClaw's daily activity digest. \
+Summarize the following 24-hour runtime stats as a short, friendly plain-text \
+message (under 150 words). Lead with the most notable item, then cover channel \
+activity, cron outcomes, delegations, and spend. Do not invent numbers; omit \
+sections with no data. No markdown tables.";
+
+/// Digest worker loop (runs until cancelled). Daemon entry point.
+///
+/// The digest fires once per calendar day (UTC) at or after the configured
+/// time; the dated artifact doubles as the cadence marker so a daemon
+/// restart does not cause a duplicate send.
+pub async fn run(config: Config) -> Result<()> {
+    let send_at = parse_send_time(&config.digest.time)?;
+    let mut interval = tokio::time::interval(std::time::Duration::from_secs(TICK_SECS));
+    loop {
+        interval.tick().await;
+        let now = Utc::now();
+        if now.time() < send_at {
+            continue;
+        }
+        match last_digest_date(&config) {
+            Ok(Some(last)) if last >= now.date_naive() => continue,
+            Ok(_) => {}
+            Err(e) => {
+                tracing::warn!("Digest: failed to read reports directory: {e:#}");
+                continue;
+            }
+        }
+
+        let stats = collect_stats(&config, now);
+        // The model pass is best-effort: on provider failure the raw stats
+        // digest is delivered instead so the daily signal still arrives.
+        let (message, artifact) = match summarize(&config, &stats).await {
+            Ok(summary) => {
+                let artifact = format!("{summary}\n\n---\n\n{stats}");
+                (summary, artifact)
+            }
+            Err(e) => {
+                tracing::warn!("Digest: model summary failed, sending raw stats: {e:#}");
+                (stats.clone(), stats)
+            }
+        };
+        match write_artifact(&config, now, &artifact) {
+            Ok(path) => tracing::info!("Digest stored at {}", path.display()),
+            Err(e) => {
+                tracing::warn!("Digest: failed to store artifact: {e:#}");
+                // Without the artifact the cadence marker is missing; skip
+                // delivery too rather than re-sending every tick.
+                continue;
+            }
+        }
+        deliver(&config, &message).await;
+    }
+}
+
+/// Parse the configured `[digest].time` ("HH:MM", UTC).
+fn parse_send_time(time: &str) -> Result<NaiveTime> {
+    NaiveTime::parse_from_str(time, "%H:%M")
+        .with_context(|| format!("Invalid [digest] time '{time}' (expected HH:MM, e.g. \"08:00\")"))
+}
+
+fn reports_dir(config: &Config) -> PathBuf {
+    config.workspace_dir.join("reports")
+}
+
+fn artifact_path(config: &Config, now: DateTime<Utc>) -> PathBuf {
+    reports_dir(config).join(format!("digest-{}.md", now.format("%Y-%m-%d")))
+}
+
+/// Date of the newest stored digest artifact, if any.
+fn last_digest_date(config: &Config) -> Result<Option<chrono::NaiveDate>> {
+    let dir = reports_dir(config);
+    if !dir.exists() {
+        return Ok(None);
+    }
+    let mut newest: Option<chrono::NaiveDate> = None;
+    for entry in std::fs::read_dir(&dir)
+        .with_context(|| format!("Failed to read reports directory: {}", dir.display()))?
+    {
+        let name = entry?.file_name();
+        let Some(date_part) = name
+            .to_str()
+            .and_then(|n| n.strip_prefix("digest-"))
+            .and_then(|n| n.strip_suffix(".md"))
+        else {
+            continue;
+        };
+        if let Ok(date) = chrono::NaiveDate::parse_from_str(date_part, "%Y-%m-%d") {
+            newest = Some(newest.map_or(date, |prev| prev.max(date)));
+        }
+    }
+    Ok(newest)
+}
+
+fn write_artifact(config: &Config, now: DateTime<Utc>, digest: &str) -> Result<PathBuf> {
+    let dir = reports_dir(config);
+    std::fs::create_dir_all(&dir)
+        .with_context(|| format!("Failed to create reports directory: {}", dir.display()))?;
+    let path = artifact_path(config, now);
+    std::fs::write(&path, digest)
+        .with_context(|| format!("Failed to write digest: {}", path.display()))?;
+    Ok(path)
+}
+
+async fn deliver(config: &Config, digest: &str) {
+    let section = &config.digest;
+    if let (Some(channel), Some(to)) = (section.channel.as_deref(), section.to.as_deref()) {
+        if let Err(e) = crate::channels::send_once(config, channel, to, digest).await {
+            tracing::error!("Digest: channel delivery failed: {e}");
+        }
+    } else {
+        tracing::info!("Digest stored only ([digest] channel/to not configured)");
+    }
+}
+
+/// Ask the default provider/model to compress the raw stats into prose.
+async fn summarize(config: &Config, stats: &str) -> Result<String> {
+    let provider_name = config
+        .default_provider
+        .as_deref()
+        .context("No default provider configured")?;
+    let model = config
+        .default_model
+        .as_deref()
+        .context("No default model configured")?;
+    let provider = crate::providers::create_provider_with_options(
+        provider_name,
+        config.api_key.as_deref(),
+        &crate::providers::ProviderRuntimeOptions {
+            auth_profile_override: None,
+            zeroclaw_dir: config.config_path.parent().map(PathBuf::from),
+            secrets_encrypt: config.secrets.encrypt,
+            reasoning_enabled: config.runtime.reasoning_enabled,
+        },
+    )
+    .with_context(|| format!("Failed to create provider '{provider_name}'"))?;
+    provider
+        .chat_with_system(
+            Some(SUMMARY_SYSTEM_PROMPT),
+            stats,
+            model,
+            config.default_temperature,
+        )
+        .await
+}
+
+/// Build the raw stats digest from the health registry, cron store,
+/// delegation log, and cost tracker. Data-source failures degrade to a
+/// note in the relevant section rather than aborting the whole digest.
+fn collect_stats(config: &Config, now: DateTime<Utc>) -> String {
+    use std::fmt::Write as _;
+
+    let mut out = String::new();
+    let _ = writeln!(out, "# ZeroClaw Daily Digest");
+    let _ = writeln!(out);
+    let _ = writeln!(out, "Generated: {}", now.format("%Y-%m-%d %H:%M UTC"));
+    let _ = writeln!(out, "Window: last {WINDOW_HOURS} hours");
+
+    // ── Channel activity ──
+    let volumes = crate::health::channel_message_counts();
+    let _ = writeln!(out);
+    let _ = writeln!(out, "## Channel activity (since daemon start)");
+    let _ = writeln!(out);
+    if volumes.is_empty() {
+        let _ = writeln!(out, "No inbound channel messages recorded.");
+    } else {
+        for (channel, count) in &volumes {
+            let _ = writeln!(out, "- {channel}: {count} inbound message(s)");
+        }
+    }
+
+    // ── Cron outcomes ──
+    let _ = writeln!(out);
+    let _ = writeln!(out, "## Cron outcomes");
+    let _ = writeln!(out);
+    match crate::cron::list_jobs(config) {
+        Ok(jobs) => {
+            let enabled = jobs.iter().filter(|j| j.enabled).count();
+            let _ = writeln!(out, "- Jobs: {} total, {enabled} enabled", jobs.len());
+            let errored: Vec<&str> = jobs
+                .iter()
+                .filter(|j| j.last_status.as_deref() == Some("error"))
+                .map(|j| j.name.as_deref().unwrap_or(j.id.as_str()))
+                .collect();
+            if errored.is_empty() {
+                let _ = writeln!(out, "- Last-run errors: none");
+            } else {
+                let _ = writeln!(
+                    out,
+                    "- Last-run errors: {} job(s) — {}",
+                    errored.len(),
+                    errored.join(", ")
+                );
+            }
+        }
+        Err(e) => {
+            let _ = writeln!(out, "Scheduler state unavailable: {e:#}");
+        }
+    }
+
+    // ── Delegations ──
+    let _ = writeln!(out);
+    let _ = writeln!(out, "## Delegations (last {WINDOW_HOURS} hours)");
+    let _ = writeln!(out);
+    let since = now - ChronoDuration::hours(WINDOW_HOURS);
+    match crate::observability::delegation_report::agent_costs_since(
+        &config.delegation_log_path(),
+        since,
+    ) {
+        Ok(costs) if costs.is_empty() => {
+            let _ = writeln!(out, "No delegations recorded in the window.");
+        }
+        Ok(costs) => {
+            let delegations: usize = costs.iter().map(|c| c.delegations).sum();
+            let tokens: u64 = costs.iter().map(|c| c.total_tokens).sum();
+            let cost: f64 = costs.iter().map(|c| c.total_cost_usd).sum();
+            let _ = writeln!(
+                out,
+                "- Delegations: {delegations}, tokens: {tokens}, cost: ${cost:.4}"
+            );
+            for agent in costs.iter().take(TOP_COST_AGENTS) {
+                let _ = writeln!(
+                    out,
+                    "- {}: {} delegation(s), {} tokens, ${:.4}",
+                    agent.agent_name, agent.delegations, agent.total_tokens, agent.total_cost_usd,
+                );
+            }
+        }
+        Err(e) => {
+            let _ = writeln!(out, "Delegation log unavailable: {e:#}");
+        }
+    }
+
+    // ── Cost ──
+    let _ = writeln!(out);
+    let _ = writeln!(out, "## Cost");
+    let _ = writeln!(out);
+    if config.cost.enabled {
+        match crate::cost::CostTracker::new(config.cost.clone(), &config.workspace_dir)
+            .and_then(|tracker| tracker.get_daily_cost(now.date_naive()))
+        {
+            Ok(daily) => {
+                let _ = writeln!(out, "- Spend today: ${daily:.4}");
+            }
+            Err(e) => {
+                let _ = writeln!(out, "Cost tracker unavailable: {e:#}");
+            }
+        }
+    } else {
+        let _ = writeln!(out, "Cost tracking disabled.");
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn test_config(tmp: &TempDir) -> Config {
+        let config = Config {
+            workspace_dir: tmp.path().join("workspace"),
+            config_path: tmp.path().join("config.toml"),
+            ..Config::default()
+        };
+        std::fs::create_dir_all(&config.workspace_dir).unwrap();
+        config
+    }
+
+    #[test]
+    fn parse_send_time_accepts_hh_mm() {
+        assert_eq!(
+            parse_send_time("08:00").unwrap(),
+            NaiveTime::from_hms_opt(8, 0, 0).unwrap()
+        );
+        assert_eq!(
+            parse_send_time("23:45").unwrap(),
+            NaiveTime::from_hms_opt(23, 45, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_send_time_rejects_malformed_values() {
+        assert!(parse_send_time("8am").is_err());
+        assert!(parse_send_time("25:00").is_err());
+        assert!(parse_send_time("").is_err());
+    }
+
+    #[test]
+    fn last_digest_date_finds_newest_artifact() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp);
+        assert!(last_digest_date(&config).unwrap().is_none());
+
+        let dir = reports_dir(&config);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("digest-2026-03-01.md"), "old").unwrap();
+        std::fs::write(dir.join("digest-2026-03-02.md"), "new").unwrap();
+        std::fs::write(dir.join("self-report-2026-03-03.md"), "ignored").unwrap();
+
+        let newest = last_digest_date(&config).unwrap().unwrap();
+        assert_eq!(newest.to_string(), "2026-03-02");
+    }
+
+    #[test]
+    fn write_artifact_creates_dated_markdown_file() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp);
+        let now = DateTime::parse_from_rfc3339("2026-03-02T08:05:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        let path = write_artifact(&config, now, "# digest").unwrap();
+        assert!(path.ends_with("reports/digest-2026-03-02.md"));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "# digest");
+        assert_eq!(
+            last_digest_date(&config).unwrap().unwrap().to_string(),
+            "2026-03-02"
+        );
+    }
+
+    #[test]
+    fn collect_stats_contains_all_sections() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp);
+
+        let stats = collect_stats(&config, Utc::now());
+        assert!(stats.contains("# ZeroClaw Daily Digest"));
+        assert!(stats.contains("## Channel activity"));
+        assert!(stats.contains("## Cron outcomes"));
+        assert!(stats.contains("## Delegations"));
+        assert!(stats.contains("## Cost"));
+    }
+
+    #[test]
+    fn collect_stats_reports_cron_errors() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp);
+
+        let job = crate::cron::add_job(&config, "0 * * * *", "echo check").unwrap();
+        crate::cron::record_last_run(&config, &job.id, Utc::now(), false, "boom").unwrap();
+
+        let stats = collect_stats(&config, Utc::now());
+        assert!(stats.contains("Last-run errors: 1 job(s)"));
+    }
+}
@@ -1,20 +1,26 @@
+pub mod control;
+pub mod digest;
+pub mod sd_notify;
+pub mod self_report;
+pub mod shutdown;
+
 use crate::config::Config;
 use anyhow::Result;
 use chrono::Utc;
+use futures::FutureExt as _;
 use std::future::Future;
+use std::panic::AssertUnwindSafe;
 use std::path::PathBuf;
 use tokio::task::JoinHandle;
 use tokio::time::Duration;
 
 const STATUS_FLUSH_SECONDS: u64 = 5;
+const AUTH_CHECK_INTERVAL_SECONDS: u64 = 30 * 60;
+/// Snapshot older than this is reported as stale by `components_report`
+/// (several missed state-writer flushes).
+const STATE_STALE_SECONDS: i64 = 30;
 
 pub async fn run(config: Config, host: String, port: u16) -> Result<()> {
-    let initial_backoff = config.reliability.channel_initial_backoff_secs.max(1);
-    let max_backoff = config
-        .reliability
-        .channel_max_backoff_secs
-        .max(initial_backoff);
-
     crate::health::mark_component_ok("daemon");
 
     if config.heartbeat.enabled {
@@ -23,11 +29,122 @@ pub async fn run(config: Config, host: String, port: u16) -> Result<()> {
                 .await;
     }
 
-    let mut handles: Vec<JoinHandle<()>> = vec![spawn_state_writer(config.clone())];
+    let state_writer = spawn_state_writer(config.clone());
+    let mut handles = spawn_components(&config, &host, port);
+
+    println!("🧠 ZeroClaw daemon started");
+    println!("   Gateway:  http://{host}:{port}");
+    println!("   Components: gateway, channels, heartbeat, scheduler, jobs");
+    println!("   SIGHUP to reload config, Ctrl+C to stop");
+
+    // Under a `Type=notify` systemd unit this completes startup; elsewhere
+    // it is a no-op.
+    sd_notify::ready();
+
+    wait_for_shutdown(&mut handles, &host, port).await?;
+
+    // Stop accepting new webhook/channel messages, then give in-flight agent
+    // runs a bounded window to finish so a restart doesn't cut a delegation
+    // or memory write off mid-stream.
+    shutdown::coordinator().begin_drain();
+    sd_notify::stopping();
+    crate::health::mark_component_error("daemon", "shutdown requested (draining)");
+    let in_flight = shutdown::coordinator().in_flight_runs();
+    if in_flight > 0 {
+        let drain_secs = config.reliability.shutdown_drain_secs;
+        println!("⏳ Draining {in_flight} in-flight run(s) (up to {drain_secs}s)...");
+        if !shutdown::coordinator()
+            .wait_for_drain(Duration::from_secs(drain_secs))
+            .await
+        {
+            let remaining = shutdown::coordinator().in_flight_runs();
+            tracing::warn!("Drain timeout expired with {remaining} run(s) still active");
+        }
+    }
+    crate::health::mark_component_error("daemon", "shutdown requested");
+
+    handles.push(state_writer);
+    for handle in &handles {
+        handle.abort();
+    }
+    for handle in handles {
+        let _ = handle.await;
+    }
+
+    // Final state flush: the periodic writer is gone, so persist the shutdown
+    // snapshot for `status --components` and `service status`.
+    let _ = tokio::fs::write(state_file_path(&config), state_snapshot_bytes()).await;
+
+    // Remove the control socket so clients fall back to the state file
+    // instead of timing out against a dead socket.
+    #[cfg(unix)]
+    let _ = std::fs::remove_file(control::socket_path(&config));
+
+    Ok(())
+}
+
+/// Block until Ctrl+C (or SIGTERM on Unix, as sent by service managers on
+/// stop/restart). On Unix, SIGHUP re-reads the config file and restarts
+/// all supervised components with the new config (skills are re-read per agent
+/// run, and the scheduler re-reads cron jobs on restart). A failed reload
+/// keeps the previous components running.
+#[cfg(unix)]
+async fn wait_for_shutdown(handles: &mut Vec<JoinHandle<()>>, host: &str, port: u16) -> Result<()> {
+    let mut sighup = tokio::signal::unix::signal(tokio::signal::unix::SignalKind::hangup())?;
+    let mut sigterm = tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())?;
+    loop {
+        tokio::select! {
+            result = tokio::signal::ctrl_c() => {
+                result?;
+                return Ok(());
+            }
+            _ = sigterm.recv() => {
+                tracing::info!("SIGTERM received; shutting down");
+                return Ok(());
+            }
+            _ = sighup.recv() => {
+                tracing::info!("SIGHUP received; reloading config");
+                match Config::load_or_init().await {
+                    Ok(new_config) => {
+                        for handle in handles.drain(..) {
+                            handle.abort();
+                            let _ = handle.await;
+                        }
+                        *handles = spawn_components(&new_config, host, port);
+                        println!("🔄 Config reloaded; components restarted");
+                    }
+                    Err(e) => {
+                        crate::health::mark_component_error("daemon", format!("reload failed: {e}"));
+                        tracing::error!("Config reload failed; keeping previous config: {e}");
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(not(unix))]
+async fn wait_for_shutdown(
+    _handles: &mut Vec<JoinHandle<()>>,
+    _host: &str,
+    _port: u16,
+) -> Result<()> {
+    tokio::signal::ctrl_c().await?;
+    Ok(())
+}
+
+fn spawn_components(config: &Config, host: &str, port: u16) -> Vec<JoinHandle<()>> {
+    let initial_backoff = config.reliability.channel_initial_backoff_secs.max(1);
+    let max_backoff = config
+        .reliability
+        .channel_max_backoff_secs
+        .max(initial_backoff);
+
+    let mut handles: Vec<JoinHandle<()>> = Vec::new();
 
     {
         let gateway_cfg = config.clone();
-        let gateway_host = host.clone();
+        let gateway_host = host.to_string();
         handles.push(spawn_component_supervisor(
             "gateway",
             initial_backoff,
@@ -41,7 +158,7 @@ pub async fn run(config: Config, host: String, port: u16) -> Result<()> {
     }
 
     {
-        if has_supervised_channels(&config) {
+        if has_supervised_channels(config) {
             let channels_cfg = config.clone();
             handles.push(spawn_component_supervisor(
                 "channels",
@@ -71,6 +188,19 @@ pub async fn run(config: Config, host: String, port: u16) -> Result<()> {
         ));
     }
 
+    if config.heartbeat.enabled && !config.heartbeat.probes.is_empty() {
+        let probes_cfg = config.clone();
+        handles.push(spawn_component_supervisor(
+            "heartbeat-probes",
+            initial_backoff,
+            max_backoff,
+            move || {
+                let cfg = probes_cfg.clone();
+                async move { crate::heartbeat::probes::run_probe_worker(cfg).await }
+            },
+        ));
+    }
+
     if config.cron.enabled {
         let scheduler_cfg = config.clone();
         handles.push(spawn_component_supervisor(
@@ -87,22 +217,97 @@ pub async fn run(config: Config, host: String, port: u16) -> Result<()> {
         tracing::info!("Cron disabled; scheduler supervisor not started");
     }
 
-    println!("🧠 ZeroClaw daemon started");
-    println!("   Gateway:  http://{host}:{port}");
-    println!("   Components: gateway, channels, heartbeat, scheduler");
-    println!("   Ctrl+C to stop");
+    {
+        let jobs_cfg = config.clone();
+        handles.push(spawn_component_supervisor(
+            "jobs",
+            initial_backoff,
+            max_backoff,
+            move || {
+                let cfg = jobs_cfg.clone();
+                async move { Box::pin(crate::jobs::worker::run(cfg)).await }
+            },
+        ));
+    }
 
-    tokio::signal::ctrl_c().await?;
-    crate::health::mark_component_error("daemon", "shutdown requested");
+    if config.monitors.enabled {
+        let monitors_cfg = config.clone();
+        handles.push(spawn_component_supervisor(
+            "monitors",
+            initial_backoff,
+            max_backoff,
+            move || {
+                let cfg = monitors_cfg.clone();
+                async move { crate::monitors::run(cfg).await }
+            },
+        ));
 
-    for handle in &handles {
-        handle.abort();
+        let uptime_cfg = config.clone();
+        handles.push(spawn_component_supervisor(
+            "uptime",
+            initial_backoff,
+            max_backoff,
+            move || {
+                let cfg = uptime_cfg.clone();
+                async move { crate::monitors::uptime::run(cfg).await }
+            },
+        ));
     }
-    for handle in handles {
-        let _ = handle.await;
+
+    {
+        let auth_cfg = config.clone();
+        handles.push(spawn_component_supervisor(
+            "auth_refresh",
+            initial_backoff,
+            max_backoff,
+            move || {
+                let cfg = auth_cfg.clone();
+                async move { run_auth_refresh_worker(cfg).await }
+            },
+        ));
     }
 
-    Ok(())
+    if config.self_report.enabled {
+        let report_cfg = config.clone();
+        handles.push(spawn_component_supervisor(
+            "self_report",
+            initial_backoff,
+            max_backoff,
+            move || {
+                let cfg = report_cfg.clone();
+                async move { self_report::run(cfg).await }
+            },
+        ));
+    }
+
+    if config.digest.enabled {
+        let digest_cfg = config.clone();
+        handles.push(spawn_component_supervisor(
+            "digest",
+            initial_backoff,
+            max_backoff,
+            move || {
+                let cfg = digest_cfg.clone();
+                async move { digest::run(cfg).await }
+            },
+        ));
+    }
+
+    #[cfg(unix)]
+    {
+        let control_cfg = config.clone();
+        handles.push(spawn_component_supervisor(
+            "control",
+            initial_backoff,
+            max_backoff,
+            move || {
+                let cfg = control_cfg.clone();
+                async move { control::serve(cfg).await }
+            },
+        ));
+    }
+
+    handles
 }
 
 pub fn state_file_path(config: &Config) -> PathBuf {
@@ -113,6 +318,207 @@ pub fn state_file_path(config: &Config) -> PathBuf {
         .join("daemon_state.json")
 }
 
+/// Per-component supervision report for `zeroclaw status --components`.
+///
+/// Asks the running daemon over the control socket first (live data), then
+/// falls back to the state file written by the daemon's state writer. When
+/// the daemon is not running — or the snapshot is stale — the report says so
+/// instead of presenting dead data as live.
+pub fn components_report(config: &Config, accessible: bool) -> String {
+    if let Some(snapshot) = control::query(config, "status") {
+        return render_component_table(&snapshot, accessible);
+    }
+    let path = state_file_path(config);
+    let raw = match std::fs::read_to_string(&path) {
+        Ok(raw) => raw,
+        Err(_) => {
+            return format!(
+                "  daemon not running (no state file at {})\n",
+                path.display()
+            );
+        }
+    };
+    match serde_json::from_str::<serde_json::Value>(&raw) {
+        Ok(snapshot) => render_component_table(&snapshot, accessible),
+        Err(e) => format!("  daemon state file is not valid JSON: {e}\n"),
+    }
+}
+
+fn render_component_table(snapshot: &serde_json::Value, accessible: bool) -> String {
+    use std::fmt::Write as _;
+
+    let mut out = String::new();
+
+    let written_at = snapshot
+        .get("written_at")
+        .or_else(|| snapshot.get("updated_at"))
+        .and_then(serde_json::Value::as_str)
+        .unwrap_or("");
+    if let Ok(ts) = chrono::DateTime::parse_from_rfc3339(written_at) {
+        let age = Utc::now()
+            .signed_duration_since(ts.with_timezone(&Utc))
+            .num_seconds();
+        if age > STATE_STALE_SECONDS {
+            let _ = writeln!(
+                out,
+                "  {}state is {age}s old — daemon may have stopped",
+                if accessible { "warning: " } else { "⚠️  " }
+            );
+        }
+    }
+
+    let Some(components) = snapshot
+        .get("components")
+        .and_then(serde_json::Value::as_object)
+    else {
+        out.push_str("  no components tracked yet\n");
+        return out;
+    };
+    if components.is_empty() {
+        out.push_str("  no components tracked yet\n");
+        return out;
+    }
+
+    for (name, component) in components {
+        let status = component
+            .get("status")
+            .and_then(serde_json::Value::as_str)
+            .unwrap_or("unknown");
+        let restarts = component
+            .get("restart_count")
+            .and_then(serde_json::Value::as_u64)
+            .unwrap_or(0);
+        let marker = if accessible {
+            format!("[{status}]")
+        } else if status == "ok" {
+            "✅".to_string()
+        } else {
+            "❌".to_string()
+        };
+
+        let detail = if status == "ok" {
+            component
+                .get("last_ok")
+                .and_then(serde_json::Value::as_str)
+                .and_then(|s| chrono::DateTime::parse_from_rfc3339(s).ok())
+                .map(|ts| {
+                    let age = Utc::now()
+                        .signed_duration_since(ts.with_timezone(&Utc))
+                        .num_seconds();
+                    format!("last ok {age}s ago")
+                })
+                .unwrap_or_default()
+        } else {
+            component
+                .get("last_error")
+                .and_then(serde_json::Value::as_str)
+                .map(|e| crate::util::truncate_with_ellipsis(e, 60))
+                .unwrap_or_default()
+        };
+
+        let _ = writeln!(
+            out,
+            "  {marker} {name:<24} restarts: {restarts:<4} {detail}"
+        );
+    }
+
+    out
+}
+
+/// Per-tool execution report for `zeroclaw status --tools`.
+///
+/// Same sourcing as `components_report`: live control-socket snapshot first,
+/// then the daemon state file, with an honest "not running" message when
+/// neither is available.
+pub fn tools_report(config: &Config, accessible: bool) -> String {
+    if let Some(snapshot) = control::query(config, "status") {
+        return render_tool_table(&snapshot, accessible);
+    }
+    let path = state_file_path(config);
+    let raw = match std::fs::read_to_string(&path) {
+        Ok(raw) => raw,
+        Err(_) => {
+            return format!(
+                "  daemon not running (no state file at {})\n",
+                path.display()
+            );
+        }
+    };
+    match serde_json::from_str::<serde_json::Value>(&raw) {
+        Ok(snapshot) => render_tool_table(&snapshot, accessible),
+        Err(e) => format!("  daemon state file is not valid JSON: {e}\n"),
+    }
+}
+
+fn render_tool_table(snapshot: &serde_json::Value, accessible: bool) -> String {
+    use std::fmt::Write as _;
+
+    let mut out = String::new();
+
+    let Some(tools) = snapshot
+        .get("tool_metrics")
+        .and_then(serde_json::Value::as_object)
+    else {
+        out.push_str("  no tool calls recorded yet\n");
+        return out;
+    };
+    if tools.is_empty() {
+        out.push_str("  no tool calls recorded yet\n");
+        return out;
+    }
+
+    for (name, tool) in tools {
+        let calls = tool
+            .get("calls")
+            .and_then(serde_json::Value::as_u64)
+            .unwrap_or(0);
+        let failures = tool
+            .get("failures")
+            .and_then(serde_json::Value::as_u64)
+            .unwrap_or(0);
+        let total_ms = tool
+            .get("total_duration_ms")
+            .and_then(serde_json::Value::as_u64)
+            .unwrap_or(0);
+        let success_rate = (100 * calls.saturating_sub(failures))
+            .checked_div(calls)
+            .unwrap_or(0);
+        let avg_ms = total_ms.checked_div(calls).unwrap_or(0);
+
+        let demoted = tool
+            .get("demoted_until")
+            .and_then(serde_json::Value::as_str)
+            .and_then(|s| chrono::DateTime::parse_from_rfc3339(s).ok())
+            .is_some_and(|until| until.with_timezone(&Utc) > Utc::now());
+        let marker = if accessible {
+            if demoted {
+                "[demoted]".to_string()
+            } else {
+                format!("[{success_rate}%]")
+            }
+        } else if demoted {
+            "⛔".to_string()
+        } else if failures == 0 {
+            "✅".to_string()
+        } else {
+            "⚠️ ".to_string()
+        };
+
+        let detail = tool
+            .get("last_error")
+            .and_then(serde_json::Value::as_str)
+            .map(|e| crate::util::truncate_with_ellipsis(e, 60))
+            .unwrap_or_default();
+
+        let _ = writeln!(
+            out,
+            "  {marker} {name:<24} calls: {calls:<5} ok: {success_rate:>3}%  avg: {avg_ms}ms  {detail}"
+        );
+    }
+
+    out
+}
+
 fn spawn_state_writer(config: Config) -> JoinHandle<()> {
     tokio::spawn(async move {
         let path = state_file_path(&config);
@@ -120,22 +526,36 @@ fn spawn_state_writer(config: Config) -> JoinHandle<()> {
             let _ = tokio::fs::create_dir_all(parent).await;
         }
 
+        // The state writer is the daemon's own heartbeat: each flush also
+        // feeds the systemd watchdog when one is armed, so a wedged runtime
+        // (no flushes) gets restarted by systemd within `WatchdogSec`.
+        let watchdog = sd_notify::watchdog_enabled();
         let mut interval = tokio::time::interval(Duration::from_secs(STATUS_FLUSH_SECONDS));
         loop {
             interval.tick().await;
-            let mut json = crate::health::snapshot_json();
-            if let Some(obj) = json.as_object_mut() {
-                obj.insert(
-                    "written_at".into(),
-                    serde_json::json!(Utc::now().to_rfc3339()),
-                );
+            let _ = tokio::fs::write(&path, state_snapshot_bytes()).await;
+            if watchdog {
+                sd_notify::watchdog_ping();
             }
-            let data = serde_json::to_vec_pretty(&json).unwrap_or_else(|_| b"{}".to_vec());
-            let _ = tokio::fs::write(&path, data).await;
         }
     })
 }
 
+/// Serialized health snapshot in the daemon state file format, shared by the
+/// periodic state writer and the final flush on shutdown.
+fn state_snapshot_bytes() -> Vec<u8> {
+    let mut json = crate::health::snapshot_json();
+    if let Some(obj) = json.as_object_mut() {
+        obj.insert(
+            "written_at".into(),
+            serde_json::json!(Utc::now().to_rfc3339()),
+        );
+        // Used by `zeroclaw service reload` to target SIGHUP
+        obj.insert("pid".into(), serde_json::json!(std::process::id()));
+    }
+    serde_json::to_vec_pretty(&json).unwrap_or_else(|_| b"{}".to_vec())
+}
+
 fn spawn_component_supervisor<F, Fut>(
     name: &'static str,
     initial_backoff_secs: u64,
@@ -152,17 +572,26 @@ where
 
         loop {
             crate::health::mark_component_ok(name);
-            match run_component().await {
-                Ok(()) => {
+            // Contain panics from component internals: without this a single
+            // panicking component kills its supervisor task and the component
+            // stays down (with stale "ok" health) until the daemon restarts.
+            match AssertUnwindSafe(run_component()).catch_unwind().await {
+                Ok(Ok(())) => {
                     crate::health::mark_component_error(name, "component exited unexpectedly");
                     tracing::warn!("Daemon component '{name}' exited unexpectedly");
                     // Clean exit — reset backoff since the component ran successfully
                     backoff = initial_backoff_secs.max(1);
                 }
-                Err(e) => {
+                Ok(Err(e)) => {
                     crate::health::mark_component_error(name, e.to_string());
                     tracing::error!("Daemon component '{name}' failed: {e}");
                 }
+                Err(payload) => {
+                    let reason =
+                        format!("panicked: {}", crate::util::panic_message(payload.as_ref()));
+                    crate::health::mark_component_error(name, &reason);
+                    tracing::error!("Daemon component '{name}' {reason}");
+                }
             }
 
             crate::health::bump_component_restart(name);
@@ -175,7 +604,11 @@ where
 
 async fn run_heartbeat_worker(config: Config) -> Result<()> {
     let observer: std::sync::Arc<dyn crate::observability::Observer> =
-        std::sync::Arc::from(crate::observability::create_observer(&config.observability, config.delegation_log_path()));
+        std::sync::Arc::from(crate::observability::create_observer(
+            &config.observability,
+            &config.events,
+            config.delegation_log_path(),
+        ));
     let engine = crate::heartbeat::engine::HeartbeatEngine::new(
         config.heartbeat.clone(),
         config.workspace_dir.clone(),
@@ -196,8 +629,18 @@ async fn run_heartbeat_worker(config: Config) -> Result<()> {
         for task in tasks {
             let prompt = format!("[Heartbeat Task] {task}");
             let temp = config.default_temperature;
-            if let Err(e) =
-                crate::agent::run(config.clone(), Some(prompt), None, None, temp, vec![]).await
+            if let Err(e) = crate::agent::run(
+                config.clone(),
+                Some(prompt),
+                None,
+                None,
+                temp,
+                vec![],
+                false,
+                vec![],
+                vec![],
+            )
+            .await
             {
                 crate::health::mark_component_error("heartbeat", e.to_string());
                 tracing::warn!("Heartbeat task failed: {e}");
@@ -208,6 +651,63 @@ async fn run_heartbeat_worker(config: Config) -> Result<()> {
     }
 }
 
+/// Periodically check auth profiles for impending expiry, refreshing
+/// refreshable tokens ahead of time. Profiles that need manual
+/// re-authentication raise an observability `Error` event so operators
+/// are alerted before requests start failing.
+async fn run_auth_refresh_worker(config: Config) -> Result<()> {
+    let observer: std::sync::Arc<dyn crate::observability::Observer> =
+        std::sync::Arc::from(crate::observability::create_observer(
+            &config.observability,
+            &config.events,
+            config.delegation_log_path(),
+        ));
+    let auth_service = crate::auth::AuthService::from_config(&config);
+
+    let mut interval = tokio::time::interval(Duration::from_secs(AUTH_CHECK_INTERVAL_SECONDS));
+    loop {
+        interval.tick().await;
+
+        let checks = auth_service.check_profiles().await?;
+        let mut needs_reauth = false;
+        for check in &checks {
+            match check.status {
+                crate::auth::AuthProfileStatus::Healthy => {}
+                crate::auth::AuthProfileStatus::Refreshed => {
+                    tracing::info!(
+                        "Auth profile {} refreshed ahead of expiry",
+                        check.profile_id
+                    );
+                }
+                crate::auth::AuthProfileStatus::NeedsReauth => {
+                    needs_reauth = true;
+                    tracing::warn!(
+                        "Auth profile {} needs manual re-authentication: {}",
+                        check.profile_id,
+                        check.detail
+                    );
+                    observer.record_event(&crate::observability::ObserverEvent::Error {
+                        component: "auth".into(),
+                        message: format!(
+                            "Auth profile {} needs manual re-authentication: {}",
+                            check.profile_id, check.detail
+                        ),
+                    });
+                }
+            }
+        }
+
+        if needs_reauth {
+            crate::health::mark_component_error(
+                "auth_refresh",
+                "one or more auth profiles need manual re-authentication",
+            );
+        } else {
+            crate::health::mark_component_ok("auth_refresh");
+        }
+    }
+}
+
 fn has_supervised_channels(config: &Config) -> bool {
     let crate::config::ChannelsConfig {
         cli: _,     // `cli` is used only when running the CLI manually
@@ -307,6 +807,117 @@ mod tests {
             .contains("component exited unexpectedly"));
     }
 
+    #[tokio::test]
+    async fn supervisor_contains_panics_and_keeps_restarting() {
+        let handle =
+            spawn_component_supervisor("daemon-test-panic", 1, 1, || async { panic!("kaboom") });
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        handle.abort();
+        let _ = handle.await;
+
+        let snapshot = crate::health::snapshot_json();
+        let component = &snapshot["components"]["daemon-test-panic"];
+        assert_eq!(component["status"], "error");
+        assert!(component["restart_count"].as_u64().unwrap_or(0) >= 1);
+        let last_error = component["last_error"].as_str().unwrap_or("");
+        assert!(last_error.contains("panicked"));
+        assert!(last_error.contains("kaboom"));
+    }
+
+    #[test]
+    fn component_table_renders_ok_and_error_rows() {
+        let snapshot = serde_json::json!({
+            "written_at": Utc::now().to_rfc3339(),
+            "components": {
+                "gateway": {
+                    "status": "ok",
+                    "last_ok": Utc::now().to_rfc3339(),
+                    "restart_count": 0,
+                },
+                "channel:telegram": {
+                    "status": "error",
+                    "last_error": "listener panicked: kaboom",
+                    "restart_count": 3,
+                },
+            },
+        });
+
+        let rendered = render_component_table(&snapshot, true);
+        assert!(rendered.contains("[ok] gateway"));
+        assert!(rendered.contains("last ok"));
+        assert!(rendered.contains("[error] channel:telegram"));
+        assert!(rendered.contains("restarts: 3"));
+        assert!(rendered.contains("listener panicked: kaboom"));
+        assert!(!rendered.contains("daemon may have stopped"));
+    }
+
+    #[test]
+    fn component_table_flags_stale_snapshot() {
+        let snapshot = serde_json::json!({
+            "written_at": (Utc::now() - chrono::Duration::seconds(120)).to_rfc3339(),
+            "components": {
+                "gateway": { "status": "ok", "restart_count": 0 },
+            },
+        });
+
+        let rendered = render_component_table(&snapshot, true);
+        assert!(rendered.contains("daemon may have stopped"));
+    }
+
+    #[test]
+    fn components_report_without_state_file_says_daemon_not_running() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp);
+
+        let report = components_report(&config, true);
+        assert!(report.contains("daemon not running"));
+    }
+
+    #[test]
+    fn tool_table_renders_rates_latency_and_demotion() {
+        let snapshot = serde_json::json!({
+            "tool_metrics": {
+                "shell": {
+                    "calls": 4,
+                    "failures": 1,
+                    "total_duration_ms": 200,
+                    "last_error": "command timed out",
+                },
+                "web_fetch": {
+                    "calls": 3,
+                    "failures": 3,
+                    "total_duration_ms": 30,
+                    "last_error": "connection refused",
+                    "demoted_until": (Utc::now() + chrono::Duration::seconds(120)).to_rfc3339(),
+                },
+            },
+        });
+
+        let rendered = render_tool_table(&snapshot, true);
+        assert!(rendered.contains("[75%] shell"));
+        assert!(rendered.contains("avg: 50ms"));
+        assert!(rendered.contains("command timed out"));
+        assert!(rendered.contains("[demoted] web_fetch"));
+        assert!(rendered.contains("connection refused"));
+    }
+
+    #[test]
+    fn tool_table_without_metrics_says_nothing_recorded() {
+        let snapshot = serde_json::json!({ "components": {} });
+        let rendered = render_tool_table(&snapshot, true);
+        assert!(rendered.contains("no tool calls recorded yet"));
+    }
+
+    #[test]
+    fn tools_report_without_state_file_says_daemon_not_running() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp);
+
+        let report = tools_report(&config, true);
+        assert!(report.contains("daemon not running"));
+    }
+
     #[test]
     fn detects_no_supervised_channels() {
         let config = Config::default();
@@ -0,0 +1,103 @@
+//! Minimal systemd `sd_notify` client (no libsystemd dependency).
+//!
+//! When the daemon runs under a systemd unit with `Type=notify`, systemd sets
+//! `$NOTIFY_SOCKET` and expects readiness/watchdog datagrams on it. Everywhere
+//! else (no socket, non-Linux, manual runs) every call here is a no-op, so the
+//! daemon behaves identically outside systemd.
+
+/// Tell systemd the daemon finished initializing (`Type=notify` readiness).
+pub fn ready() {
+    notify("READY=1");
+}
+
+/// Watchdog keep-alive ping; systemd restarts the unit when these stop
+/// arriving within `WatchdogSec`.
+pub fn watchdog_ping() {
+    notify("WATCHDOG=1");
+}
+
+/// Tell systemd the daemon began shutting down (drain phase).
+pub fn stopping() {
+    notify("STOPPING=1");
+}
+
+/// Whether systemd expects watchdog pings from this process: `WATCHDOG_USEC`
+/// is set and `WATCHDOG_PID` (when present) names this pid.
+pub fn watchdog_enabled() -> bool {
+    watchdog_enabled_from(
+        std::env::var("WATCHDOG_USEC").ok().as_deref(),
+        std::env::var("WATCHDOG_PID").ok().as_deref(),
+        std::process::id(),
+    )
+}
+
+fn watchdog_enabled_from(usec: Option<&str>, pid: Option<&str>, my_pid: u32) -> bool {
+    let usec_ok = usec
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .is_some_and(|v| v > 0);
+    let pid_ok = pid.is_none_or(|v| v.trim() == my_pid.to_string());
+    usec_ok && pid_ok
+}
+
+#[cfg(unix)]
+fn notify(state: &str) {
+    let Ok(socket) = std::env::var("NOTIFY_SOCKET") else {
+        return;
+    };
+    if socket.is_empty() {
+        return;
+    }
+    // Abstract-namespace sockets (leading '@') need unstable std support;
+    // systemd services use filesystem paths, so skip explicitly instead of
+    // sending to the wrong address.
+    if socket.starts_with('@') {
+        tracing::debug!("NOTIFY_SOCKET uses the abstract namespace; sd_notify skipped");
+        return;
+    }
+    if let Err(e) = notify_path(std::path::Path::new(&socket), state) {
+        tracing::debug!("sd_notify to {socket} failed: {e}");
+    }
+}
+
+#[cfg(unix)]
+fn notify_path(socket: &std::path::Path, state: &str) -> std::io::Result<()> {
+    let sock = std::os::unix::net::UnixDatagram::unbound()?;
+    sock.send_to(state.as_bytes(), socket)?;
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn notify(_state: &str) {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn watchdog_enabled_requires_positive_usec() {
+        assert!(watchdog_enabled_from(Some("30000000"), None, 42));
+        assert!(!watchdog_enabled_from(Some("0"), None, 42));
+        assert!(!watchdog_enabled_from(Some("not-a-number"), None, 42));
+        assert!(!watchdog_enabled_from(None, None, 42));
+    }
+
+    #[test]
+    fn watchdog_enabled_checks_pid_when_present() {
+        assert!(watchdog_enabled_from(Some("30000000"), Some("42"), 42));
+        assert!(!watchdog_enabled_from(Some("30000000"), Some("41"), 42));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn notify_path_delivers_state_datagram() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let socket_path = tmp.path().join("notify.sock");
+        let receiver = std::os::unix::net::UnixDatagram::bind(&socket_path).unwrap();
+
+        notify_path(&socket_path, "READY=1").unwrap();
+
+        let mut buf = [0u8; 64];
+        let len = receiver.recv(&mut buf).unwrap();
+        assert_eq!(&buf[..len], b"READY=1");
+    }
+}
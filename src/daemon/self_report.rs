@@ -0,0 +1,362 @@
+//! Weekly daemon self-report (`[self_report]` section).
+//!
+//! Once every seven days the daemon summarizes its own behavior: uptime,
+//! per-component status and restarts, inbound channel message volumes,
+//! scheduler health (job errors and missed schedules), and the week's top
+//! delegation costs. The report is stored as a markdown artifact under
+//! `reports/` in the workspace and delivered to the configured channel.
+
+use crate::config::Config;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
+use std::path::PathBuf;
+
+/// Days covered by (and between) reports.
+const REPORT_PERIOD_DAYS: i64 = 7;
+/// How often the worker checks whether a report is due.
+const TICK_SECS: u64 = 3600;
+/// An enabled cron job counts as missed when overdue by this many minutes.
+const MISSED_SCHEDULE_SLACK_MINUTES: i64 = 10;
+/// Agents listed in the top-costs table.
+const TOP_COST_AGENTS: usize = 5;
+
+/// Self-report worker loop (runs until cancelled). Daemon entry point.
+///
+/// A report is generated when none exists yet or when the newest stored
+/// artifact is at least [`REPORT_PERIOD_DAYS`] old, so the cadence survives
+/// daemon restarts.
+pub async fn run(config: Config) -> Result<()> {
+    let mut interval = tokio::time::interval(std::time::Duration::from_secs(TICK_SECS));
+    loop {
+        interval.tick().await;
+        let now = Utc::now();
+        match last_report_date(&config) {
+            Ok(Some(last))
+                if now.date_naive() - last < ChronoDuration::days(REPORT_PERIOD_DAYS) =>
+            {
+                continue;
+            }
+            Ok(_) => {}
+            Err(e) => {
+                tracing::warn!("Self-report: failed to read reports directory: {e:#}");
+                continue;
+            }
+        }
+
+        let report = generate_report(&config, now);
+        match write_artifact(&config, now, &report) {
+            Ok(path) => tracing::info!("Self-report stored at {}", path.display()),
+            Err(e) => {
+                tracing::warn!("Self-report: failed to store artifact: {e:#}");
+                // Without the artifact the cadence marker is missing; skip
+                // delivery too rather than sending the same report hourly.
+                continue;
+            }
+        }
+        deliver(&config, &report).await;
+    }
+}
+
+fn reports_dir(config: &Config) -> PathBuf {
+    config.workspace_dir.join("reports")
+}
+
+fn artifact_path(config: &Config, now: DateTime<Utc>) -> PathBuf {
+    reports_dir(config).join(format!("self-report-{}.md", now.format("%Y-%m-%d")))
+}
+
+/// Date of the newest stored self-report artifact, if any.
+fn last_report_date(config: &Config) -> Result<Option<chrono::NaiveDate>> {
+    let dir = reports_dir(config);
+    if !dir.exists() {
+        return Ok(None);
+    }
+    let mut newest: Option<chrono::NaiveDate> = None;
+    for entry in std::fs::read_dir(&dir)
+        .with_context(|| format!("Failed to read reports directory: {}", dir.display()))?
+    {
+        let name = entry?.file_name();
+        let Some(date_part) = name
+            .to_str()
+            .and_then(|n| n.strip_prefix("self-report-"))
+            .and_then(|n| n.strip_suffix(".md"))
+        else {
+            continue;
+        };
+        if let Ok(date) = chrono::NaiveDate::parse_from_str(date_part, "%Y-%m-%d") {
+            newest = Some(newest.map_or(date, |prev| prev.max(date)));
+        }
+    }
+    Ok(newest)
+}
+
+fn write_artifact(config: &Config, now: DateTime<Utc>, report: &str) -> Result<PathBuf> {
+    let dir = reports_dir(config);
+    std::fs::create_dir_all(&dir)
+        .with_context(|| format!("Failed to create reports directory: {}", dir.display()))?;
+    let path = artifact_path(config, now);
+    std::fs::write(&path, report)
+        .with_context(|| format!("Failed to write self-report: {}", path.display()))?;
+    Ok(path)
+}
+
+async fn deliver(config: &Config, report: &str) {
+    let section = &config.self_report;
+    if let (Some(channel), Some(to)) = (section.channel.as_deref(), section.to.as_deref()) {
+        if let Err(e) = crate::channels::send_once(config, channel, to, report).await {
+            tracing::error!("Self-report: channel delivery failed: {e}");
+        }
+    } else {
+        tracing::info!("Self-report stored only ([self_report] channel/to not configured)");
+    }
+}
+
+/// Build the markdown report from the health registry, cron store, and
+/// delegation log. Data-source failures degrade to a note in the relevant
+/// section rather than aborting the whole report.
+fn generate_report(config: &Config, now: DateTime<Utc>) -> String {
+    use std::fmt::Write as _;
+
+    let mut out = String::new();
+    let _ = writeln!(out, "# ZeroClaw Weekly Self-Report");
+    let _ = writeln!(out);
+    let _ = writeln!(out, "Generated: {}", now.format("%Y-%m-%d %H:%M UTC"));
+    let _ = writeln!(out, "Window: last {REPORT_PERIOD_DAYS} days");
+
+    // ── Runtime + components ──
+    let snapshot = crate::health::snapshot();
+    let _ = writeln!(out);
+    let _ = writeln!(out, "## Runtime");
+    let _ = writeln!(out);
+    let _ = writeln!(
+        out,
+        "- Daemon uptime: {} (pid {})",
+        fmt_uptime(snapshot.uptime_seconds),
+        snapshot.pid
+    );
+
+    let _ = writeln!(out);
+    let _ = writeln!(out, "## Components");
+    let _ = writeln!(out);
+    if snapshot.components.is_empty() {
+        let _ = writeln!(out, "No supervised components registered.");
+    } else {
+        let _ = writeln!(out, "| component | status | restarts | last error |");
+        let _ = writeln!(out, "|---|---|---|---|");
+        for (name, health) in &snapshot.components {
+            let _ = writeln!(
+                out,
+                "| {name} | {} | {} | {} |",
+                health.status,
+                health.restart_count,
+                health.last_error.as_deref().unwrap_or("—"),
+            );
+        }
+        let degraded: Vec<&str> = snapshot
+            .components
+            .iter()
+            .filter(|(_, h)| h.status != "ok")
+            .map(|(n, _)| n.as_str())
+            .collect();
+        let _ = writeln!(out);
+        if degraded.is_empty() {
+            let _ = writeln!(out, "All components healthy.");
+        } else {
+            let _ = writeln!(out, "Degraded: {}", degraded.join(", "));
+        }
+    }
+
+    // ── Channel volumes ──
+    let volumes = crate::health::channel_message_counts();
+    let _ = writeln!(out);
+    let _ = writeln!(out, "## Channel volumes (since daemon start)");
+    let _ = writeln!(out);
+    if volumes.is_empty() {
+        let _ = writeln!(out, "No inbound channel messages recorded.");
+    } else {
+        for (channel, count) in &volumes {
+            let _ = writeln!(out, "- {channel}: {count} inbound message(s)");
+        }
+    }
+
+    // ── Scheduler ──
+    let _ = writeln!(out);
+    let _ = writeln!(out, "## Scheduler");
+    let _ = writeln!(out);
+    match crate::cron::list_jobs(config) {
+        Ok(jobs) => {
+            let enabled = jobs.iter().filter(|j| j.enabled).count();
+            let _ = writeln!(out, "- Jobs: {} total, {enabled} enabled", jobs.len());
+            let errored: Vec<&str> = jobs
+                .iter()
+                .filter(|j| j.last_status.as_deref() == Some("error"))
+                .map(|j| j.name.as_deref().unwrap_or(j.id.as_str()))
+                .collect();
+            if errored.is_empty() {
+                let _ = writeln!(out, "- Last-run errors: none");
+            } else {
+                let _ = writeln!(
+                    out,
+                    "- Last-run errors: {} job(s) — {}",
+                    errored.len(),
+                    errored.join(", ")
+                );
+            }
+            let slack = ChronoDuration::minutes(MISSED_SCHEDULE_SLACK_MINUTES);
+            let missed: Vec<&str> = jobs
+                .iter()
+                .filter(|j| j.enabled && j.next_run + slack < now)
+                .map(|j| j.name.as_deref().unwrap_or(j.id.as_str()))
+                .collect();
+            if missed.is_empty() {
+                let _ = writeln!(out, "- Missed schedules: none");
+            } else {
+                let _ = writeln!(
+                    out,
+                    "- Missed schedules: {} job(s) overdue — {}",
+                    missed.len(),
+                    missed.join(", ")
+                );
+            }
+        }
+        Err(e) => {
+            let _ = writeln!(out, "Scheduler state unavailable: {e:#}");
+        }
+    }
+
+    // ── Delegation costs ──
+    let _ = writeln!(out);
+    let _ = writeln!(out, "## Delegation costs (last {REPORT_PERIOD_DAYS} days)");
+    let _ = writeln!(out);
+    let since = now - ChronoDuration::days(REPORT_PERIOD_DAYS);
+    match crate::observability::delegation_report::agent_costs_since(
+        &config.delegation_log_path(),
+        since,
+    ) {
+        Ok(costs) if costs.is_empty() => {
+            let _ = writeln!(out, "No delegations recorded in the window.");
+        }
+        Ok(costs) => {
+            let delegations: usize = costs.iter().map(|c| c.delegations).sum();
+            let tokens: u64 = costs.iter().map(|c| c.total_tokens).sum();
+            let cost: f64 = costs.iter().map(|c| c.total_cost_usd).sum();
+            let _ = writeln!(
+                out,
+                "- Delegations: {delegations}, tokens: {tokens}, cost: ${cost:.4}"
+            );
+            let _ = writeln!(out);
+            let _ = writeln!(out, "| agent | delegations | tokens | cost |");
+            let _ = writeln!(out, "|---|---|---|---|");
+            for agent in costs.iter().take(TOP_COST_AGENTS) {
+                let _ = writeln!(
+                    out,
+                    "| {} | {} | {} | ${:.4} |",
+                    agent.agent_name, agent.delegations, agent.total_tokens, agent.total_cost_usd,
+                );
+            }
+        }
+        Err(e) => {
+            let _ = writeln!(out, "Delegation log unavailable: {e:#}");
+        }
+    }
+
+    out
+}
+
+fn fmt_uptime(secs: u64) -> String {
+    let days = secs / 86_400;
+    let hours = (secs % 86_400) / 3600;
+    let minutes = (secs % 3600) / 60;
+    if days > 0 {
+        format!("{days}d {hours}h")
+    } else if hours > 0 {
+        format!("{hours}h {minutes}m")
+    } else {
+        format!("{minutes}m")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn test_config(tmp: &TempDir) -> Config {
+        let config = Config {
+            workspace_dir: tmp.path().join("workspace"),
+            config_path: tmp.path().join("config.toml"),
+            ..Config::default()
+        };
+        std::fs::create_dir_all(&config.workspace_dir).unwrap();
+        config
+    }
+
+    #[test]
+    fn fmt_uptime_picks_readable_units() {
+        assert_eq!(fmt_uptime(90), "1m");
+        assert_eq!(fmt_uptime(3660), "1h 1m");
+        assert_eq!(fmt_uptime(90_000), "1d 1h");
+    }
+
+    #[test]
+    fn last_report_date_finds_newest_artifact() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp);
+        assert!(last_report_date(&config).unwrap().is_none());
+
+        let dir = reports_dir(&config);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("self-report-2026-02-09.md"), "old").unwrap();
+        std::fs::write(dir.join("self-report-2026-02-16.md"), "new").unwrap();
+        std::fs::write(dir.join("notes.txt"), "ignored").unwrap();
+
+        let newest = last_report_date(&config).unwrap().unwrap();
+        assert_eq!(newest.to_string(), "2026-02-16");
+    }
+
+    #[test]
+    fn write_artifact_creates_dated_markdown_file() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp);
+        let now = DateTime::parse_from_rfc3339("2026-02-16T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        let path = write_artifact(&config, now, "# report").unwrap();
+        assert!(path.ends_with("reports/self-report-2026-02-16.md"));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "# report");
+        assert_eq!(
+            last_report_date(&config).unwrap().unwrap().to_string(),
+            "2026-02-16"
+        );
+    }
+
+    #[test]
+    fn generate_report_contains_all_sections() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp);
+
+        let report = generate_report(&config, Utc::now());
+        assert!(report.contains("# ZeroClaw Weekly Self-Report"));
+        assert!(report.contains("## Runtime"));
+        assert!(report.contains("## Components"));
+        assert!(report.contains("## Channel volumes"));
+        assert!(report.contains("## Scheduler"));
+        assert!(report.contains("## Delegation costs"));
+    }
+
+    #[test]
+    fn generate_report_flags_missed_and_errored_jobs() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp);
+
+        // An enabled hourly job created in the past is overdue by now.
+        let job = crate::cron::add_job(&config, "0 * * * *", "echo check").unwrap();
+        crate::cron::record_last_run(&config, &job.id, Utc::now(), false, "boom").unwrap();
+
+        let later = Utc::now() + ChronoDuration::days(1);
+        let report = generate_report(&config, later);
+        assert!(report.contains("Last-run errors: 1 job(s)"));
+        assert!(report.contains("Missed schedules: 1 job(s)"));
+    }
+}
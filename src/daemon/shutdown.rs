@@ -0,0 +1,171 @@
+//! Coordinated daemon shutdown: drain in-flight agent runs before exit.
+//!
+//! On SIGINT/SIGTERM the daemon flips the process-wide drain flag. Intake
+//! surfaces (gateway webhook, channel message dispatch) stop accepting new
+//! work, while runs already in flight — tracked through RAII [`RunGuard`]s —
+//! finish up to the configured drain timeout. This keeps a restart from
+//! cutting a delegation or memory write off mid-stream.
+
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use tokio::time::Duration;
+
+/// How often `wait_for_drain` re-checks the in-flight counter.
+const DRAIN_POLL_MILLIS: u64 = 100;
+
+/// Tracks the drain flag and the number of in-flight agent runs.
+///
+/// Intake surfaces call [`DrainCoordinator::begin_run`] before starting work;
+/// once [`DrainCoordinator::begin_drain`] has been called, new runs are
+/// refused and the daemon waits for the remaining guards to drop.
+pub struct DrainCoordinator {
+    draining: AtomicBool,
+    in_flight: AtomicU64,
+}
+
+impl DrainCoordinator {
+    pub const fn new() -> Self {
+        Self {
+            draining: AtomicBool::new(false),
+            in_flight: AtomicU64::new(0),
+        }
+    }
+
+    /// Flip into drain mode: from now on `begin_run` refuses new work.
+    pub fn begin_drain(&self) {
+        self.draining.store(true, Ordering::SeqCst);
+    }
+
+    /// Whether shutdown has been requested.
+    pub fn is_draining(&self) -> bool {
+        self.draining.load(Ordering::SeqCst)
+    }
+
+    /// Number of guarded runs currently in flight.
+    pub fn in_flight_runs(&self) -> u64 {
+        self.in_flight.load(Ordering::SeqCst)
+    }
+
+    /// Register a new agent run. Returns `None` once draining — callers must
+    /// reject the work instead of starting it.
+    #[must_use]
+    pub fn begin_run(&self) -> Option<RunGuard<'_>> {
+        if self.is_draining() {
+            return None;
+        }
+        self.in_flight.fetch_add(1, Ordering::SeqCst);
+        // Re-check: drain may have started between the check and the
+        // increment. Undo rather than let a late run slip through.
+        if self.is_draining() {
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+            return None;
+        }
+        Some(RunGuard { coordinator: self })
+    }
+
+    /// Wait until every guarded run finishes, up to `timeout`. Returns `true`
+    /// when fully drained, `false` when the timeout expired with runs still
+    /// active.
+    pub async fn wait_for_drain(&self, timeout: Duration) -> bool {
+        let deadline = tokio::time::Instant::now() + timeout;
+        while self.in_flight_runs() > 0 {
+            if tokio::time::Instant::now() >= deadline {
+                return false;
+            }
+            tokio::time::sleep(Duration::from_millis(DRAIN_POLL_MILLIS)).await;
+        }
+        true
+    }
+}
+
+impl Default for DrainCoordinator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// RAII marker for one in-flight agent run; dropping it releases the slot.
+pub struct RunGuard<'a> {
+    coordinator: &'a DrainCoordinator,
+}
+
+impl Drop for RunGuard<'_> {
+    fn drop(&mut self) {
+        self.coordinator.in_flight.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+static COORDINATOR: DrainCoordinator = DrainCoordinator::new();
+
+/// The process-wide coordinator used by the daemon and its intake surfaces.
+///
+/// Outside the daemon (one-shot CLI runs) drain mode is never entered, so
+/// guards are always granted and the bookkeeping is inert.
+pub fn coordinator() -> &'static DrainCoordinator {
+    &COORDINATOR
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn run_guards_track_in_flight_count() {
+        let coordinator = DrainCoordinator::new();
+        assert_eq!(coordinator.in_flight_runs(), 0);
+
+        let first = coordinator.begin_run().expect("run should be granted");
+        let second = coordinator.begin_run().expect("run should be granted");
+        assert_eq!(coordinator.in_flight_runs(), 2);
+
+        drop(first);
+        assert_eq!(coordinator.in_flight_runs(), 1);
+        drop(second);
+        assert_eq!(coordinator.in_flight_runs(), 0);
+    }
+
+    #[test]
+    fn begin_run_refuses_new_work_while_draining() {
+        let coordinator = DrainCoordinator::new();
+        let in_flight = coordinator.begin_run().expect("run should be granted");
+
+        coordinator.begin_drain();
+        assert!(coordinator.is_draining());
+        assert!(coordinator.begin_run().is_none());
+        // The pre-drain run is still tracked until its guard drops.
+        assert_eq!(coordinator.in_flight_runs(), 1);
+        drop(in_flight);
+        assert_eq!(coordinator.in_flight_runs(), 0);
+    }
+
+    #[tokio::test]
+    async fn wait_for_drain_returns_immediately_when_idle() {
+        let coordinator = DrainCoordinator::new();
+        coordinator.begin_drain();
+        assert!(coordinator.wait_for_drain(Duration::from_secs(1)).await);
+    }
+
+    #[tokio::test]
+    async fn wait_for_drain_times_out_with_runs_still_active() {
+        let coordinator = DrainCoordinator::new();
+        let _in_flight = coordinator.begin_run().expect("run should be granted");
+        coordinator.begin_drain();
+        assert!(!coordinator.wait_for_drain(Duration::from_millis(50)).await);
+    }
+
+    #[tokio::test]
+    async fn wait_for_drain_completes_once_guard_drops() {
+        let coordinator = std::sync::Arc::new(DrainCoordinator::new());
+        coordinator.in_flight.fetch_add(1, Ordering::SeqCst);
+        coordinator.begin_drain();
+
+        // Simulate an in-flight run finishing mid-drain. A real `RunGuard`
+        // borrows the coordinator, so release the raw counter from a task.
+        let release = std::sync::Arc::clone(&coordinator);
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            release.in_flight.fetch_sub(1, Ordering::SeqCst);
+        });
+
+        assert!(coordinator.wait_for_drain(Duration::from_secs(2)).await);
+    }
+}
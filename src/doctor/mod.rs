@@ -1,6 +1,8 @@
 use crate::config::Config;
 use anyhow::Result;
 use chrono::{DateTime, Utc};
+use std::fmt::Write as _;
+use std::fs;
 use std::io::Write;
 use std::path::Path;
 
@@ -54,20 +56,38 @@ impl DiagItem {
             Severity::Error => "❌",
         }
     }
+
+    /// Plain-text severity marker for accessible (screen-reader) output.
+    fn label(&self) -> &'static str {
+        match self.severity {
+            Severity::Ok => "ok:",
+            Severity::Warn => "warning:",
+            Severity::Error => "error:",
+        }
+    }
 }
 
 // ── Public entry point ───────────────────────────────────────────
 
-pub fn run(config: &Config) -> Result<()> {
+pub fn run(config: &Config, accessible: bool) -> Result<()> {
     let mut items: Vec<DiagItem> = Vec::new();
 
     check_config_semantics(config, &mut items);
     check_workspace(config, &mut items);
+    check_gateway_port(config, &mut items);
+    check_peripheral_devices(config, &mut items);
+    check_cron_timezones(config, &mut items);
+    check_memory_store(config, &mut items);
     check_daemon_state(config, &mut items);
     check_environment(&mut items);
 
-    // Print report
-    println!("🩺 ZeroClaw Doctor (enhanced)");
+    // Print report. Accessible mode announces severity as a word instead
+    // of an emoji so screen readers read each finding correctly.
+    if accessible {
+        println!("ZeroClaw Doctor (enhanced)");
+    } else {
+        println!("🩺 ZeroClaw Doctor (enhanced)");
+    }
     println!();
 
     let mut current_cat = "";
@@ -76,7 +96,11 @@ pub fn run(config: &Config) -> Result<()> {
             current_cat = item.category;
             println!("  [{current_cat}]");
         }
-        println!("    {} {}", item.icon(), item.message);
+        if accessible {
+            println!("    {} {}", item.label(), item.message);
+        } else {
+            println!("    {} {}", item.icon(), item.message);
+        }
     }
 
     let errors = items
@@ -93,7 +117,11 @@ pub fn run(config: &Config) -> Result<()> {
     println!("  Summary: {oks} ok, {warns} warnings, {errors} errors");
 
     if errors > 0 {
-        println!("  💡 Fix the errors above, then run `zeroclaw doctor` again.");
+        if accessible {
+            println!("  Tip: fix the errors above, then run `zeroclaw doctor` again.");
+        } else {
+            println!("  💡 Fix the errors above, then run `zeroclaw doctor` again.");
+        }
     }
 
     Ok(())
@@ -147,14 +175,23 @@ fn doctor_model_targets(provider_override: Option<&str>) -> Vec<String> {
         .collect()
 }
 
-pub fn run_models(config: &Config, provider_override: Option<&str>, use_cache: bool) -> Result<()> {
+pub fn run_models(
+    config: &Config,
+    provider_override: Option<&str>,
+    use_cache: bool,
+    accessible: bool,
+) -> Result<()> {
     let targets = doctor_model_targets(provider_override);
 
     if targets.is_empty() {
         anyhow::bail!("No providers available for model probing");
     }
 
-    println!("🩺 ZeroClaw Doctor — Model Catalog Probe");
+    if accessible {
+        println!("ZeroClaw Doctor - Model Catalog Probe");
+    } else {
+        println!("🩺 ZeroClaw Doctor — Model Catalog Probe");
+    }
     println!("  Providers to probe: {}", targets.len());
     println!(
         "  Mode: {}",
@@ -177,25 +214,34 @@ pub fn run_models(config: &Config, provider_override: Option<&str>, use_cache: b
         match crate::onboard::run_models_refresh(config, Some(provider_name), !use_cache) {
             Ok(()) => {
                 ok_count += 1;
-                println!("    ✅ model catalog check passed");
+                let marker = if accessible { "ok:" } else { "✅" };
+                println!("    {marker} model catalog check passed");
             }
             Err(error) => {
                 let error_text = format_error_chain(&error);
                 match classify_model_probe_error(&error_text) {
                     ModelProbeOutcome::Skipped => {
                         skipped_count += 1;
-                        println!("    ⚪ skipped: {}", truncate_for_display(&error_text, 160));
+                        let marker = if accessible {
+                            "skipped:"
+                        } else {
+                            "⚪ skipped:"
+                        };
+                        println!("    {marker} {}", truncate_for_display(&error_text, 160));
                     }
                     ModelProbeOutcome::AuthOrAccess => {
                         auth_count += 1;
-                        println!(
-                            "    ⚠️  auth/access: {}",
-                            truncate_for_display(&error_text, 160)
-                        );
+                        let marker = if accessible {
+                            "auth/access:"
+                        } else {
+                            "⚠️  auth/access:"
+                        };
+                        println!("    {marker} {}", truncate_for_display(&error_text, 160));
                     }
                     ModelProbeOutcome::Error => {
                         error_count += 1;
-                        println!("    ❌ error: {}", truncate_for_display(&error_text, 160));
+                        let marker = if accessible { "error:" } else { "❌ error:" };
+                        println!("    {marker} {}", truncate_for_display(&error_text, 160));
                     }
                     ModelProbeOutcome::Ok => {
                         ok_count += 1;
@@ -213,8 +259,9 @@ pub fn run_models(config: &Config, provider_override: Option<&str>, use_cache: b
     );
 
     if auth_count > 0 {
+        let marker = if accessible { "Tip:" } else { "💡" };
         println!(
-            "  💡 Some providers need valid API keys/plan access before `/models` can be fetched."
+            "  {marker} Some providers need valid API keys/plan access before `/models` can be fetched."
         );
     }
 
@@ -225,6 +272,600 @@ pub fn run_models(config: &Config, provider_override: Option<&str>, use_cache: b
     Ok(())
 }
 
+// ── Provider smoke test (`doctor providers`) ─────────────────────
+
+const PROVIDER_PROBE_TIMEOUT_SECS: u64 = 30;
+const PROVIDER_PROBE_PROMPT: &str = "Reply with the single word: pong";
+const TOOL_PROBE_PROMPT: &str = "Call the ping tool exactly once.";
+
+/// One provider/model/credential combination to smoke-test.
+struct ProviderProbeTarget {
+    label: String,
+    provider: String,
+    model: Option<String>,
+    api_key: Option<String>,
+    api_url: Option<String>,
+}
+
+enum ProbeStatus {
+    Pass { latency_ms: u128 },
+    Fail { reason: String },
+    Skipped { reason: String },
+}
+
+impl ProbeStatus {
+    fn fail(reason: impl Into<String>) -> Self {
+        Self::Fail {
+            reason: truncate_for_display(&reason.into(), 60),
+        }
+    }
+
+    fn render(&self, accessible: bool) -> String {
+        match self {
+            Self::Pass { latency_ms } => {
+                if accessible {
+                    format!("pass ({latency_ms}ms)")
+                } else {
+                    format!("✅ {latency_ms}ms")
+                }
+            }
+            Self::Fail { reason } => {
+                if accessible {
+                    format!("fail: {reason}")
+                } else {
+                    format!("❌ {reason}")
+                }
+            }
+            Self::Skipped { reason } => {
+                if accessible {
+                    format!("skipped: {reason}")
+                } else {
+                    format!("⚪ {reason}")
+                }
+            }
+        }
+    }
+}
+
+/// Collect probe targets declared directly in the config: the default
+/// provider, model routes, reliability fallbacks, and delegate agents.
+fn collect_config_probe_targets(config: &Config) -> Vec<ProviderProbeTarget> {
+    let mut targets = Vec::new();
+
+    if let Some(provider) = config.default_provider.as_deref() {
+        targets.push(ProviderProbeTarget {
+            label: "default".into(),
+            provider: provider.to_string(),
+            model: config.default_model.clone(),
+            api_key: config.api_key.clone(),
+            api_url: config.api_url.clone(),
+        });
+    }
+
+    for route in &config.model_routes {
+        targets.push(ProviderProbeTarget {
+            label: format!("route:{}", route.hint),
+            provider: route.provider.clone(),
+            model: Some(route.model.clone()),
+            api_key: route.api_key.clone().or_else(|| config.api_key.clone()),
+            api_url: None,
+        });
+    }
+
+    for fallback in &config.reliability.fallback_providers {
+        targets.push(ProviderProbeTarget {
+            label: format!("fallback:{fallback}"),
+            provider: fallback.clone(),
+            model: config.default_model.clone(),
+            api_key: config.api_key.clone(),
+            api_url: None,
+        });
+    }
+
+    let mut agent_names: Vec<_> = config.agents.keys().collect();
+    agent_names.sort();
+    for name in agent_names {
+        let agent = &config.agents[name];
+        targets.push(ProviderProbeTarget {
+            label: format!("agent:{name}"),
+            provider: agent.provider.clone(),
+            model: Some(agent.model.clone()),
+            api_key: agent.api_key.clone().or_else(|| config.api_key.clone()),
+            api_url: None,
+        });
+    }
+
+    targets
+}
+
+/// Collect probe targets from stored auth profiles. Profiles without a
+/// usable credential are skipped; models fall back to the configured default
+/// when the profile's provider matches the default provider.
+fn collect_auth_probe_targets(
+    config: &Config,
+    data: &crate::auth::profiles::AuthProfilesData,
+) -> Vec<ProviderProbeTarget> {
+    let mut targets = Vec::new();
+
+    for (profile_id, profile) in &data.profiles {
+        let token = match profile.kind {
+            crate::auth::profiles::AuthProfileKind::Token => profile.token.clone(),
+            crate::auth::profiles::AuthProfileKind::OAuth => profile
+                .token_set
+                .as_ref()
+                .map(|set| set.access_token.clone()),
+        };
+        let Some(token) = token.filter(|t| !t.trim().is_empty()) else {
+            continue;
+        };
+
+        let model = if config.default_provider.as_deref() == Some(profile.provider.as_str()) {
+            config.default_model.clone()
+        } else {
+            None
+        };
+
+        targets.push(ProviderProbeTarget {
+            label: format!("auth:{profile_id}"),
+            provider: profile.provider.clone(),
+            model,
+            api_key: Some(token),
+            api_url: None,
+        });
+    }
+
+    targets
+}
+
+/// Drop targets that repeat an earlier provider/model/credential combination
+/// so the matrix probes each distinct configuration once.
+fn dedupe_probe_targets(targets: Vec<ProviderProbeTarget>) -> Vec<ProviderProbeTarget> {
+    let mut seen = std::collections::HashSet::new();
+    targets
+        .into_iter()
+        .filter(|target| {
+            seen.insert(format!(
+                "{}\u{0}{}\u{0}{}",
+                target.provider,
+                target.model.as_deref().unwrap_or(""),
+                target.api_key.as_deref().unwrap_or("")
+            ))
+        })
+        .collect()
+}
+
+/// Run a tiny live completion (and, where supported, a native tool-calling
+/// round trip) against one target. Returns (chat status, tools status).
+async fn probe_provider_target(target: &ProviderProbeTarget) -> (ProbeStatus, ProbeStatus) {
+    let Some(model) = target.model.as_deref() else {
+        let skipped = || ProbeStatus::Skipped {
+            reason: "no model configured".into(),
+        };
+        return (skipped(), skipped());
+    };
+
+    let provider = match crate::providers::create_provider_with_url(
+        &target.provider,
+        target.api_key.as_deref(),
+        target.api_url.as_deref(),
+    ) {
+        Ok(provider) => provider,
+        Err(error) => {
+            return (
+                ProbeStatus::fail(format_error_chain(&error)),
+                ProbeStatus::Skipped {
+                    reason: "provider init failed".into(),
+                },
+            );
+        }
+    };
+
+    let timeout = std::time::Duration::from_secs(PROVIDER_PROBE_TIMEOUT_SECS);
+    let started = std::time::Instant::now();
+    let chat_status = match tokio::time::timeout(
+        timeout,
+        provider.simple_chat(PROVIDER_PROBE_PROMPT, model, 0.0),
+    )
+    .await
+    {
+        Ok(Ok(_)) => ProbeStatus::Pass {
+            latency_ms: started.elapsed().as_millis(),
+        },
+        Ok(Err(error)) => ProbeStatus::fail(format_error_chain(&error)),
+        Err(_) => ProbeStatus::fail(format!("timed out after {PROVIDER_PROBE_TIMEOUT_SECS}s")),
+    };
+
+    if !matches!(chat_status, ProbeStatus::Pass { .. }) {
+        return (
+            chat_status,
+            ProbeStatus::Skipped {
+                reason: "chat failed".into(),
+            },
+        );
+    }
+
+    if !provider.supports_native_tools() {
+        return (
+            chat_status,
+            ProbeStatus::Skipped {
+                reason: "prompt-guided tools".into(),
+            },
+        );
+    }
+
+    let ping_tool = [crate::tools::ToolSpec {
+        name: "ping".into(),
+        description: "Reply check: responds with pong".into(),
+        parameters: serde_json::json!({
+            "type": "object",
+            "properties": {},
+            "required": []
+        }),
+    }];
+    let messages = [crate::providers::ChatMessage::user(TOOL_PROBE_PROMPT)];
+    let request = crate::providers::ChatRequest {
+        messages: &messages,
+        tools: Some(&ping_tool),
+    };
+
+    let started = std::time::Instant::now();
+    let tools_status = match tokio::time::timeout(timeout, provider.chat(request, model, 0.0)).await
+    {
+        Ok(Ok(response)) if response.has_tool_calls() => ProbeStatus::Pass {
+            latency_ms: started.elapsed().as_millis(),
+        },
+        Ok(Ok(_)) => ProbeStatus::fail("no tool call returned"),
+        Ok(Err(error)) => ProbeStatus::fail(format_error_chain(&error)),
+        Err(_) => ProbeStatus::fail(format!("timed out after {PROVIDER_PROBE_TIMEOUT_SECS}s")),
+    };
+
+    (chat_status, tools_status)
+}
+
+pub async fn run_providers(
+    config: &Config,
+    provider_filter: Option<&str>,
+    accessible: bool,
+) -> Result<()> {
+    let mut targets = collect_config_probe_targets(config);
+    match crate::auth::AuthService::from_config(config).load_profiles() {
+        Ok(data) => targets.extend(collect_auth_probe_targets(config, &data)),
+        Err(_) => {
+            // No readable auth profiles; config-declared targets still run.
+        }
+    }
+    let mut targets = dedupe_probe_targets(targets);
+
+    if let Some(filter) = provider_filter.map(str::trim).filter(|p| !p.is_empty()) {
+        targets.retain(|target| target.provider == filter);
+        if targets.is_empty() {
+            anyhow::bail!("No configured targets found for provider '{filter}'");
+        }
+    }
+
+    if targets.is_empty() {
+        anyhow::bail!(
+            "No providers configured — set default_provider or add model routes, then retry"
+        );
+    }
+
+    if accessible {
+        println!("ZeroClaw Doctor - Provider Smoke Test");
+    } else {
+        println!("🩺 ZeroClaw Doctor — Provider Smoke Test");
+    }
+    println!("  Targets: {} (live completions)", targets.len());
+    println!();
+    println!(
+        "  {:<20} {:<14} {:<26} {:<24} tools",
+        "target", "provider", "model", "chat"
+    );
+
+    let mut pass_count = 0usize;
+    let mut fail_count = 0usize;
+    let mut skipped_count = 0usize;
+
+    for target in &targets {
+        let (chat_status, tools_status) = probe_provider_target(target).await;
+        match chat_status {
+            ProbeStatus::Pass { .. } => pass_count += 1,
+            ProbeStatus::Fail { .. } => fail_count += 1,
+            ProbeStatus::Skipped { .. } => skipped_count += 1,
+        }
+
+        println!(
+            "  {:<20} {:<14} {:<26} {:<24} {}",
+            truncate_for_display(&target.label, 18),
+            truncate_for_display(&target.provider, 12),
+            truncate_for_display(target.model.as_deref().unwrap_or("-"), 24),
+            chat_status.render(accessible),
+            tools_status.render(accessible)
+        );
+    }
+
+    println!();
+    println!("  Summary: {pass_count} pass, {fail_count} fail, {skipped_count} skipped");
+
+    if fail_count > 0 {
+        let marker = if accessible { "Tip:" } else { "💡" };
+        println!("  {marker} Failing targets usually need a valid API key, plan access, or a reachable endpoint.");
+    }
+
+    Ok(())
+}
+
+// ── Report bundle (`doctor bundle`) ──────────────────────────────
+
+/// Most recent bytes of a log file to include in a bundle.
+const BUNDLE_LOG_TAIL_BYTES: u64 = 64 * 1024;
+
+/// One file staged for inclusion in the report bundle.
+struct BundleEntry {
+    name: &'static str,
+    description: &'static str,
+    contents: Vec<u8>,
+}
+
+/// Mask values of secret-like config keys (`api_key`, `token`, ...) and run
+/// the result through the runtime secret redactor. Used so the bundled
+/// config is safe to attach to a public bug report.
+fn redact_config_text(raw: &str) -> String {
+    const SECRET_KEY_HINTS: [&str; 5] = ["key", "token", "secret", "password", "credential"];
+
+    let mut out = String::with_capacity(raw.len());
+    for line in raw.lines() {
+        let masked = line.split_once('=').and_then(|(key_part, value_part)| {
+            let key = key_part.trim().trim_matches('"').to_ascii_lowercase();
+            let has_value = !value_part.trim().is_empty();
+            if has_value && SECRET_KEY_HINTS.iter().any(|hint| key.contains(hint)) {
+                Some(format!("{key_part}= \"[REDACTED]\""))
+            } else {
+                None
+            }
+        });
+        out.push_str(masked.as_deref().unwrap_or(line));
+        out.push('\n');
+    }
+
+    crate::security::redaction::runtime_redactor()
+        .redact(&out)
+        .0
+}
+
+/// Read the last `BUNDLE_LOG_TAIL_BYTES` of a file, redacted.
+fn read_log_tail(path: &Path) -> std::io::Result<Vec<u8>> {
+    use std::io::{Read, Seek, SeekFrom};
+
+    let mut file = std::fs::File::open(path)?;
+    let len = file.metadata()?.len();
+    if len > BUNDLE_LOG_TAIL_BYTES {
+        file.seek(SeekFrom::End(
+            -(i64::try_from(BUNDLE_LOG_TAIL_BYTES).unwrap_or(i64::MAX)),
+        ))?;
+    }
+    let mut contents = Vec::new();
+    file.read_to_end(&mut contents)?;
+
+    let text = String::from_utf8_lossy(&contents);
+    let (redacted, _) = crate::security::redaction::runtime_redactor().redact(&text);
+    Ok(redacted.into_bytes())
+}
+
+/// Render channel component health from a daemon state snapshot.
+fn render_channel_health(state: &serde_json::Value) -> String {
+    let Some(components) = state
+        .get("components")
+        .and_then(serde_json::Value::as_object)
+    else {
+        return "no components tracked in daemon state\n".to_string();
+    };
+
+    let mut out = String::new();
+    for (name, component) in components {
+        if !name.starts_with("channel:") {
+            continue;
+        }
+        let status = component
+            .get("status")
+            .and_then(serde_json::Value::as_str)
+            .unwrap_or("unknown");
+        let last_ok = component
+            .get("last_ok")
+            .and_then(serde_json::Value::as_str)
+            .unwrap_or("never");
+        let _ = writeln!(out, "{name}: status={status} last_ok={last_ok}");
+    }
+
+    if out.is_empty() {
+        "no channel components tracked in daemon state\n".to_string()
+    } else {
+        out
+    }
+}
+
+/// Render delegation log aggregate statistics.
+fn render_delegation_stats(log_path: &Path) -> String {
+    match crate::observability::delegation_report::get_log_summary(log_path) {
+        Ok(Some(summary)) => format!(
+            "runs: {}\ndelegations: {}\ntotal_tokens: {}\ntotal_cost_usd: {:.4}\nlatest_run: {}\n",
+            summary.run_count,
+            summary.total_delegations,
+            summary.total_tokens,
+            summary.total_cost_usd,
+            summary
+                .latest_run_time
+                .map_or_else(|| "unknown".to_string(), |t| t.to_rfc3339()),
+        ),
+        Ok(None) => "no delegation log data\n".to_string(),
+        Err(error) => format!("failed to read delegation log: {error}\n"),
+    }
+}
+
+/// Collect everything that would go into the bundle. Pure staging — nothing
+/// is written to disk here, so the confirmation prompt can list contents
+/// before anything is created.
+fn collect_bundle_entries(config: &Config) -> Vec<BundleEntry> {
+    let mut entries = Vec::new();
+
+    if let Ok(raw) = fs::read_to_string(&config.config_path) {
+        entries.push(BundleEntry {
+            name: "config.redacted.toml",
+            description: "config with secret-like values masked",
+            contents: redact_config_text(&raw).into_bytes(),
+        });
+    }
+
+    let mut system = String::new();
+    let _ = writeln!(system, "zeroclaw_version: {}", env!("CARGO_PKG_VERSION"));
+    let _ = writeln!(system, "os: {}", std::env::consts::OS);
+    let _ = writeln!(system, "arch: {}", std::env::consts::ARCH);
+    if let Ok(output) = std::process::Command::new("uname").arg("-a").output() {
+        if output.status.success() {
+            let _ = writeln!(
+                system,
+                "uname: {}",
+                String::from_utf8_lossy(&output.stdout).trim()
+            );
+        }
+    }
+    entries.push(BundleEntry {
+        name: "system.txt",
+        description: "version and OS information",
+        contents: system.into_bytes(),
+    });
+
+    let state_file = crate::daemon::state_file_path(config);
+    if let Ok(raw) = fs::read_to_string(&state_file) {
+        if let Ok(state) = serde_json::from_str::<serde_json::Value>(&raw) {
+            entries.push(BundleEntry {
+                name: "channel-health.txt",
+                description: "channel component health from daemon state",
+                contents: render_channel_health(&state).into_bytes(),
+            });
+        }
+        entries.push(BundleEntry {
+            name: "daemon-state.json",
+            description: "daemon heartbeat and component snapshot",
+            contents: raw.into_bytes(),
+        });
+    }
+
+    let logs_dir = config
+        .config_path
+        .parent()
+        .map_or_else(|| std::path::PathBuf::from("."), Path::to_path_buf)
+        .join("logs");
+    for (file, name, description) in [
+        (
+            "daemon.stdout.log",
+            "daemon.stdout.log",
+            "recent daemon stdout (redacted tail)",
+        ),
+        (
+            "daemon.stderr.log",
+            "daemon.stderr.log",
+            "recent daemon stderr (redacted tail)",
+        ),
+    ] {
+        if let Ok(contents) = read_log_tail(&logs_dir.join(file)) {
+            entries.push(BundleEntry {
+                name,
+                description,
+                contents,
+            });
+        }
+    }
+
+    entries.push(BundleEntry {
+        name: "delegation-stats.txt",
+        description: "aggregate delegation log statistics",
+        contents: render_delegation_stats(&config.delegation_log_path()).into_bytes(),
+    });
+
+    entries
+}
+
+/// Prompt for confirmation after listing bundle contents. Any read failure
+/// counts as a decline (fail-safe for non-interactive runs without `--yes`).
+fn confirm_bundle() -> bool {
+    print!("Create the bundle with the files listed above? [y/N] ");
+    let _ = std::io::stdout().flush();
+
+    let mut line = String::new();
+    if std::io::stdin().read_line(&mut line).is_err() {
+        return false;
+    }
+    matches!(line.trim().to_ascii_lowercase().as_str(), "y" | "yes")
+}
+
+pub fn run_bundle(config: &Config, output: Option<&Path>, assume_yes: bool) -> Result<()> {
+    let entries = collect_bundle_entries(config);
+
+    println!("🩺 ZeroClaw Doctor — Report Bundle");
+    println!("  The bundle will contain:");
+    for entry in &entries {
+        println!(
+            "    {:<24} {:>8} B  {}",
+            entry.name,
+            entry.contents.len(),
+            entry.description
+        );
+    }
+    println!("  Secrets are redacted, but review the archive before sharing.");
+    println!();
+
+    if !assume_yes && !confirm_bundle() {
+        println!("Aborted — nothing was written.");
+        return Ok(());
+    }
+
+    let output_path = output.map_or_else(
+        || {
+            std::path::PathBuf::from(format!(
+                "zeroclaw-doctor-bundle-{}.tar.gz",
+                chrono::Local::now().format("%Y%m%d-%H%M%S")
+            ))
+        },
+        Path::to_path_buf,
+    );
+
+    // Stage files in a private temp dir, then archive with the system tar.
+    let staging = std::env::temp_dir().join(format!(
+        "zeroclaw-bundle-{}-{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_nanos())
+    ));
+    fs::create_dir_all(&staging)?;
+
+    let result = (|| -> Result<()> {
+        for entry in &entries {
+            fs::write(staging.join(entry.name), &entry.contents)?;
+        }
+
+        let status = std::process::Command::new("tar")
+            .arg("-czf")
+            .arg(&output_path)
+            .arg("-C")
+            .arg(&staging)
+            .arg(".")
+            .status()
+            .map_err(|error| anyhow::anyhow!("failed to run tar: {error}"))?;
+        if !status.success() {
+            anyhow::bail!("tar exited with status {status}");
+        }
+        Ok(())
+    })();
+
+    let _ = fs::remove_dir_all(&staging);
+    result?;
+
+    println!("✅ Bundle written: {}", output_path.display());
+    println!("  Attach it to your bug report after a final review.");
+    Ok(())
+}
+
 // ── Config semantic validation ───────────────────────────────────
 
 fn check_config_semantics(config: &Config, items: &mut Vec<DiagItem>) {
@@ -493,29 +1134,26 @@ fn check_workspace(config: &Config, items: &mut Vec<DiagItem>) {
     }
 
     // Writable check
-    let probe = workspace_probe_path(ws);
-    match std::fs::OpenOptions::new()
-        .write(true)
-        .create_new(true)
-        .open(&probe)
-    {
-        Ok(mut probe_file) => {
-            let write_result = probe_file.write_all(b"probe");
-            drop(probe_file);
-            let _ = std::fs::remove_file(&probe);
-            match write_result {
-                Ok(()) => items.push(DiagItem::ok(cat, "directory is writable")),
-                Err(e) => items.push(DiagItem::error(
-                    cat,
-                    format!("directory write probe failed: {e}"),
-                )),
-            }
-        }
-        Err(e) => {
-            items.push(DiagItem::error(
+    match dir_write_probe_error(ws) {
+        None => items.push(DiagItem::ok(cat, "directory is writable")),
+        Some(e) => items.push(DiagItem::error(cat, format!("directory {e}"))),
+    }
+
+    // State path (config dir): the daemon state file, control socket, and
+    // secrets live next to the config file, so it must be writable too.
+    if let Some(state_dir) = config.config_path.parent().filter(|p| p.exists()) {
+        match dir_write_probe_error(state_dir) {
+            None => items.push(DiagItem::ok(
                 cat,
-                format!("directory is not writable: {e}"),
-            ));
+                format!("state dir is writable: {}", state_dir.display()),
+            )),
+            Some(e) => items.push(DiagItem::error(
+                cat,
+                format!(
+                    "state dir {} {e} — fix its permissions or the daemon cannot persist state",
+                    state_dir.display()
+                ),
+            )),
         }
     }
 
@@ -586,6 +1224,244 @@ fn workspace_probe_path(workspace_dir: &Path) -> std::path::PathBuf {
     ))
 }
 
+/// Create-write-delete probe; `None` when the directory is writable,
+/// otherwise a short reason ("is not writable: …").
+fn dir_write_probe_error(dir: &Path) -> Option<String> {
+    let probe = workspace_probe_path(dir);
+    match std::fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&probe)
+    {
+        Ok(mut probe_file) => {
+            let write_result = probe_file.write_all(b"probe");
+            drop(probe_file);
+            let _ = std::fs::remove_file(&probe);
+            match write_result {
+                Ok(()) => None,
+                Err(e) => Some(format!("write probe failed: {e}")),
+            }
+        }
+        Err(e) => Some(format!("is not writable: {e}")),
+    }
+}
+
+// ── Live resource checks ─────────────────────────────────────────
+
+/// Bind probe for the configured gateway listen address. "In use" is only a
+/// warning: it is the expected state while the daemon is running.
+fn check_gateway_port(config: &Config, items: &mut Vec<DiagItem>) {
+    let cat = "gateway";
+    let host = config.gateway.host.as_str();
+    let port = config.gateway.port;
+    if port == 0 {
+        // Already reported as a config error.
+        return;
+    }
+
+    match std::net::TcpListener::bind((host, port)) {
+        Ok(listener) => {
+            drop(listener);
+            items.push(DiagItem::ok(cat, format!("port {port} is free on {host}")));
+        }
+        Err(e) if e.kind() == std::io::ErrorKind::AddrInUse => {
+            items.push(DiagItem::warn(
+                cat,
+                format!(
+                    "port {port} on {host} is already in use — expected if the daemon is running; otherwise stop the conflicting process or change [gateway] port"
+                ),
+            ));
+        }
+        Err(e) => {
+            items.push(DiagItem::error(
+                cat,
+                format!("cannot bind {host}:{port}: {e} — check [gateway] host"),
+            ));
+        }
+    }
+}
+
+/// Device existence/permission checks for configured peripheral boards
+/// (serial ports, RPi GPIO character device).
+fn check_peripheral_devices(config: &Config, items: &mut Vec<DiagItem>) {
+    let cat = "peripherals";
+    let peripherals = &config.peripherals;
+    if !peripherals.enabled {
+        return;
+    }
+    if peripherals.boards.is_empty() {
+        items.push(DiagItem::warn(
+            cat,
+            "peripherals enabled but no boards configured — add a [[peripherals.boards]] entry",
+        ));
+        return;
+    }
+
+    for board in &peripherals.boards {
+        match board.transport.as_str() {
+            "serial" => {
+                let Some(path) = board
+                    .path
+                    .as_deref()
+                    .map(str::trim)
+                    .filter(|p| !p.is_empty())
+                else {
+                    items.push(DiagItem::error(
+                        cat,
+                        format!(
+                            "board \"{}\" has no serial path — set path = \"/dev/ttyACM0\" (or similar) in [[peripherals.boards]]",
+                            board.board
+                        ),
+                    ));
+                    continue;
+                };
+                check_device_access(
+                    cat,
+                    &board.board,
+                    Path::new(path),
+                    "add your user to the device's group (often `dialout`) or adjust udev rules",
+                    items,
+                );
+            }
+            "native" if board.board == "rpi-gpio" || board.board == "raspberry-pi" => {
+                check_device_access(
+                    cat,
+                    &board.board,
+                    Path::new("/dev/gpiochip0"),
+                    "add your user to the `gpio` group, or enable GPIO on this host",
+                    items,
+                );
+            }
+            _ => {}
+        }
+    }
+}
+
+fn check_device_access(
+    cat: &'static str,
+    board: &str,
+    device: &Path,
+    fix_hint: &str,
+    items: &mut Vec<DiagItem>,
+) {
+    if !device.exists() {
+        items.push(DiagItem::error(
+            cat,
+            format!(
+                "board \"{board}\": device {} not found — check the cable and configured path",
+                device.display()
+            ),
+        ));
+        return;
+    }
+
+    match std::fs::OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open(device)
+    {
+        Ok(_) => items.push(DiagItem::ok(
+            cat,
+            format!("board \"{board}\": device {} accessible", device.display()),
+        )),
+        Err(e) if e.kind() == std::io::ErrorKind::PermissionDenied => {
+            items.push(DiagItem::error(
+                cat,
+                format!(
+                    "board \"{board}\": permission denied on {} — {fix_hint}",
+                    device.display()
+                ),
+            ));
+        }
+        Err(e) => items.push(DiagItem::warn(
+            cat,
+            format!("board \"{board}\": cannot open {}: {e}", device.display()),
+        )),
+    }
+}
+
+/// Validate IANA timezones on stored cron tasks. Only reads an existing
+/// store; never creates one as a side effect of running doctor.
+fn check_cron_timezones(config: &Config, items: &mut Vec<DiagItem>) {
+    use std::str::FromStr as _;
+
+    let cat = "cron";
+    if !config.workspace_dir.join("cron").join("jobs.db").exists() {
+        return;
+    }
+
+    let jobs = match crate::cron::list_jobs(config) {
+        Ok(jobs) => jobs,
+        Err(e) => {
+            items.push(DiagItem::warn(cat, format!("cannot read cron store: {e}")));
+            return;
+        }
+    };
+    if jobs.is_empty() {
+        return;
+    }
+
+    let mut invalid = 0u32;
+    for job in &jobs {
+        if let crate::cron::Schedule::Cron { tz: Some(tz), .. } = &job.schedule {
+            if chrono_tz::Tz::from_str(tz).is_err() {
+                invalid += 1;
+                items.push(DiagItem::error(
+                    cat,
+                    format!(
+                        "task \"{}\" has invalid timezone \"{tz}\" — use an IANA name like America/New_York (`zeroclaw cron update <id> --tz <tz>`)",
+                        job.name.as_deref().unwrap_or(&job.id)
+                    ),
+                ));
+            }
+        }
+    }
+    if invalid == 0 {
+        items.push(DiagItem::ok(
+            cat,
+            format!("{} task(s), all timezones valid", jobs.len()),
+        ));
+    }
+}
+
+/// Disk headroom for the memory/RAG store, which may live on a different
+/// mount than the workspace root on some setups.
+fn check_memory_store(config: &Config, items: &mut Vec<DiagItem>) {
+    let cat = "memory";
+    if config.memory.backend.trim() == "none" {
+        return;
+    }
+
+    let store_dir = config.workspace_dir.join("memory");
+    if !store_dir.exists() {
+        return;
+    }
+
+    let db_path = store_dir.join("brain.db");
+    if let Ok(meta) = std::fs::metadata(&db_path) {
+        items.push(DiagItem::ok(
+            cat,
+            format!("store size: {} MB (brain.db)", meta.len() / (1024 * 1024)),
+        ));
+    }
+
+    if let Some(avail_mb) = disk_available_mb(&store_dir) {
+        if avail_mb >= 100 {
+            items.push(DiagItem::ok(
+                cat,
+                format!("disk space: {avail_mb} MB available for the store"),
+            ));
+        } else {
+            items.push(DiagItem::warn(
+                cat,
+                format!(
+                    "low disk space: only {avail_mb} MB available — free disk so the memory store can grow"
+                ),
+            ));
+        }
+    }
+}
+
 // ── Daemon state (original logic, preserved) ─────────────────────
 
 fn check_daemon_state(config: &Config, items: &mut Vec<DiagItem>) {
@@ -834,6 +1710,13 @@ mod tests {
         assert_eq!(DiagItem::error("t", "m").icon(), "❌");
     }
 
+    #[test]
+    fn diag_item_accessible_labels_are_plain_text() {
+        assert_eq!(DiagItem::ok("t", "m").label(), "ok:");
+        assert_eq!(DiagItem::warn("t", "m").label(), "warning:");
+        assert_eq!(DiagItem::error("t", "m").label(), "error:");
+    }
+
     #[test]
     fn classify_model_probe_error_marks_unsupported_as_skipped() {
         let outcome = classify_model_probe_error(
@@ -1047,6 +1930,145 @@ mod tests {
         assert_eq!(preview, "🙂ex…");
     }
 
+    #[test]
+    fn gateway_port_check_warns_when_port_in_use() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        let mut config = Config::default();
+        config.gateway.host = "127.0.0.1".into();
+        config.gateway.port = port;
+
+        let mut items = Vec::new();
+        check_gateway_port(&config, &mut items);
+
+        assert!(items
+            .iter()
+            .any(|i| i.severity == Severity::Warn && i.message.contains("already in use")));
+    }
+
+    #[test]
+    fn gateway_port_check_skips_invalid_port_zero() {
+        let mut config = Config::default();
+        config.gateway.port = 0;
+
+        let mut items = Vec::new();
+        check_gateway_port(&config, &mut items);
+        assert!(items.is_empty());
+    }
+
+    #[test]
+    fn peripheral_check_is_silent_when_disabled() {
+        let config = Config::default();
+        let mut items = Vec::new();
+        check_peripheral_devices(&config, &mut items);
+        assert!(items.is_empty());
+    }
+
+    #[test]
+    fn peripheral_serial_board_without_path_is_error() {
+        let mut config = Config::default();
+        config.peripherals.enabled = true;
+        config.peripherals.boards = vec![crate::config::PeripheralBoardConfig {
+            board: "nucleo-f401re".into(),
+            transport: "serial".into(),
+            path: None,
+            ..Default::default()
+        }];
+
+        let mut items = Vec::new();
+        check_peripheral_devices(&config, &mut items);
+
+        assert!(items
+            .iter()
+            .any(|i| i.severity == Severity::Error && i.message.contains("has no serial path")));
+    }
+
+    #[test]
+    fn peripheral_missing_device_is_error() {
+        let tmp = TempDir::new().unwrap();
+        let mut config = Config::default();
+        config.peripherals.enabled = true;
+        config.peripherals.boards = vec![crate::config::PeripheralBoardConfig {
+            board: "nucleo-f401re".into(),
+            transport: "serial".into(),
+            path: Some(tmp.path().join("ttyMISSING").display().to_string()),
+            ..Default::default()
+        }];
+
+        let mut items = Vec::new();
+        check_peripheral_devices(&config, &mut items);
+
+        assert!(items
+            .iter()
+            .any(|i| i.severity == Severity::Error && i.message.contains("not found")));
+    }
+
+    #[test]
+    fn cron_timezone_check_is_silent_without_store() {
+        let tmp = TempDir::new().unwrap();
+        let mut config = Config::default();
+        config.workspace_dir = tmp.path().to_path_buf();
+
+        let mut items = Vec::new();
+        check_cron_timezones(&config, &mut items);
+        assert!(items.is_empty());
+    }
+
+    #[test]
+    fn cron_timezone_check_reports_valid_store() {
+        let tmp = TempDir::new().unwrap();
+        let mut config = Config::default();
+        config.workspace_dir = tmp.path().to_path_buf();
+
+        crate::cron::add_shell_job(
+            &config,
+            None,
+            crate::cron::Schedule::Cron {
+                expr: "0 9 * * *".into(),
+                tz: Some("America/New_York".into()),
+            },
+            "echo ok",
+        )
+        .unwrap();
+
+        let mut items = Vec::new();
+        check_cron_timezones(&config, &mut items);
+
+        assert!(items
+            .iter()
+            .any(|i| i.severity == Severity::Ok && i.message.contains("all timezones valid")));
+    }
+
+    #[test]
+    fn memory_store_check_is_silent_for_none_backend() {
+        let tmp = TempDir::new().unwrap();
+        let mut config = Config::default();
+        config.workspace_dir = tmp.path().to_path_buf();
+        config.memory.backend = "none".into();
+        std::fs::create_dir_all(tmp.path().join("memory")).unwrap();
+
+        let mut items = Vec::new();
+        check_memory_store(&config, &mut items);
+        assert!(items.is_empty());
+    }
+
+    #[test]
+    fn state_dir_writability_is_reported_with_workspace() {
+        let tmp = TempDir::new().unwrap();
+        let mut config = Config::default();
+        config.workspace_dir = tmp.path().join("workspace");
+        std::fs::create_dir_all(&config.workspace_dir).unwrap();
+        config.config_path = tmp.path().join("config.toml");
+
+        let mut items = Vec::new();
+        check_workspace(&config, &mut items);
+
+        assert!(items
+            .iter()
+            .any(|i| i.severity == Severity::Ok && i.message.contains("state dir is writable")));
+    }
+
     #[test]
     fn workspace_probe_path_is_hidden_and_unique() {
         let tmp = TempDir::new().unwrap();
@@ -1060,6 +2082,142 @@ mod tests {
             .is_some_and(|name| name.starts_with(".zeroclaw_doctor_probe_")));
     }
 
+    #[test]
+    fn probe_targets_cover_default_routes_fallbacks_and_agents() {
+        let mut config = Config::default();
+        config.default_provider = Some("openrouter".into());
+        config.default_model = Some("model-a".into());
+        config.api_key = Some("key-a".into());
+        config.reliability.fallback_providers = vec!["groq".into()];
+        config.model_routes = vec![crate::config::ModelRouteConfig {
+            hint: "fast".into(),
+            provider: "groq".into(),
+            model: "model-b".into(),
+            api_key: None,
+        }];
+
+        let targets = collect_config_probe_targets(&config);
+        let labels: Vec<_> = targets.iter().map(|t| t.label.as_str()).collect();
+        assert_eq!(labels, vec!["default", "route:fast", "fallback:groq"]);
+
+        // Route inherits the top-level API key when it has none of its own.
+        assert_eq!(targets[1].api_key.as_deref(), Some("key-a"));
+    }
+
+    #[test]
+    fn probe_target_dedupe_drops_repeated_combinations() {
+        let mut config = Config::default();
+        config.default_provider = Some("groq".into());
+        config.default_model = Some("model-b".into());
+        config.model_routes = vec![crate::config::ModelRouteConfig {
+            hint: "fast".into(),
+            provider: "groq".into(),
+            model: "model-b".into(),
+            api_key: None,
+        }];
+
+        let targets = dedupe_probe_targets(collect_config_probe_targets(&config));
+        assert_eq!(targets.len(), 1);
+        assert_eq!(targets[0].label, "default");
+    }
+
+    #[test]
+    fn auth_probe_targets_skip_empty_tokens_and_reuse_default_model() {
+        let mut config = Config::default();
+        config.default_provider = Some("openai".into());
+        config.default_model = Some("model-a".into());
+
+        let mut data = crate::auth::profiles::AuthProfilesData::default();
+        let with_token =
+            crate::auth::profiles::AuthProfile::new_token("openai", "work", "token-value".into());
+        data.profiles.insert(with_token.id.clone(), with_token);
+        let mut without_token =
+            crate::auth::profiles::AuthProfile::new_token("anthropic", "empty", "  ".into());
+        without_token.token = Some("  ".into());
+        data.profiles
+            .insert(without_token.id.clone(), without_token);
+
+        let targets = collect_auth_probe_targets(&config, &data);
+        assert_eq!(targets.len(), 1);
+        assert!(targets[0].label.starts_with("auth:openai"));
+        assert_eq!(targets[0].model.as_deref(), Some("model-a"));
+    }
+
+    #[test]
+    fn redact_config_text_masks_secret_like_keys_only() {
+        let raw =
+            "api_key = \"sk-zeroclaw-test-value\"\nmodel = \"model-a\"\nbot_token = \"123:abc\"\n";
+        let redacted = redact_config_text(raw);
+
+        assert!(redacted.contains("api_key = \"[REDACTED]\""));
+        assert!(redacted.contains("bot_token = \"[REDACTED]\""));
+        assert!(redacted.contains("model = \"model-a\""));
+        assert!(!redacted.contains("sk-zeroclaw-test-value"));
+    }
+
+    #[test]
+    fn channel_health_renders_channel_components_only() {
+        let state = serde_json::json!({
+            "components": {
+                "scheduler": { "status": "ok" },
+                "channel:telegram": { "status": "ok", "last_ok": "2026-01-01T00:00:00Z" }
+            }
+        });
+        let rendered = render_channel_health(&state);
+        assert!(rendered.contains("channel:telegram: status=ok"));
+        assert!(!rendered.contains("scheduler"));
+
+        let empty = render_channel_health(&serde_json::json!({}));
+        assert!(empty.contains("no components tracked"));
+    }
+
+    #[test]
+    fn bundle_entries_include_redacted_config_and_system_info() {
+        let tmp = TempDir::new().unwrap();
+        let config_path = tmp.path().join("config.toml");
+        std::fs::write(&config_path, "api_key = \"sk-zeroclaw-test-value\"\n").unwrap();
+
+        let mut config = Config::default();
+        config.config_path = config_path;
+        config.workspace_dir = tmp.path().join("workspace");
+
+        let entries = collect_bundle_entries(&config);
+        let names: Vec<_> = entries.iter().map(|e| e.name).collect();
+        assert!(names.contains(&"config.redacted.toml"));
+        assert!(names.contains(&"system.txt"));
+        assert!(names.contains(&"delegation-stats.txt"));
+
+        let config_entry = entries
+            .iter()
+            .find(|e| e.name == "config.redacted.toml")
+            .unwrap();
+        let text = String::from_utf8_lossy(&config_entry.contents);
+        assert!(!text.contains("sk-zeroclaw-test-value"));
+    }
+
+    #[test]
+    fn log_tail_reads_only_recent_bytes() {
+        let tmp = TempDir::new().unwrap();
+        let log = tmp.path().join("daemon.stdout.log");
+        let mut contents = vec![b'a'; (BUNDLE_LOG_TAIL_BYTES + 10) as usize];
+        contents.extend_from_slice(b"tail-marker");
+        std::fs::write(&log, &contents).unwrap();
+
+        let tail = read_log_tail(&log).unwrap();
+        assert!(tail.len() <= BUNDLE_LOG_TAIL_BYTES as usize);
+        assert!(String::from_utf8_lossy(&tail).ends_with("tail-marker"));
+    }
+
+    #[test]
+    fn probe_status_renders_accessible_and_emoji_forms() {
+        let pass = ProbeStatus::Pass { latency_ms: 42 };
+        assert_eq!(pass.render(true), "pass (42ms)");
+        assert_eq!(pass.render(false), "✅ 42ms");
+
+        let fail = ProbeStatus::fail("401 unauthorized");
+        assert_eq!(fail.render(true), "fail: 401 unauthorized");
+    }
+
     #[test]
     fn config_validation_reports_delegate_agents_in_sorted_order() {
         let mut config = Config::default();
@@ -1075,6 +2233,7 @@ mod tests {
                 agentic: false,
                 allowed_tools: Vec::new(),
                 max_iterations: 10,
+                node: None,
             },
         );
         config.agents.insert(
@@ -1089,6 +2248,7 @@ mod tests {
                 agentic: false,
                 allowed_tools: Vec::new(),
                 max_iterations: 10,
+                node: None,
             },
         );
 
@@ -0,0 +1,582 @@
+//! Agent eval harness: YAML suites of prompt/expectation cases.
+//!
+//! `zeroclaw eval run <suite.yaml>` executes each case against the configured
+//! provider (optionally across several models), scores the responses, and
+//! writes a JSON report under `<workspace>/state/eval/`. Use it to validate
+//! provider/model/prompt changes before deploying the daemon — the command
+//! exits non-zero when any case fails.
+//!
+//! Example suite:
+//!
+//! ```yaml
+//! description: Pre-deploy smoke checks
+//! models: ["anthropic/claude-sonnet-4"]
+//! temperature: 0.2
+//! cases:
+//!   - name: arithmetic
+//!     prompt: "What is 2 + 2? Answer with just the number."
+//!     expect: { contains: "4" }
+//!   - name: format
+//!     prompt: "Reply with an ISO 8601 date for 1 Jan 2026."
+//!     expect: { regex: "2026-01-01" }
+//!   - name: refusal
+//!     prompt: "Summarize our refund policy."
+//!     expect: { llm_judge: "The response admits it has no policy document rather than inventing one." }
+//! ```
+
+use crate::config::Config;
+use crate::providers::{self, Provider};
+use anyhow::{bail, Context, Result};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// Per-case provider call timeout.
+const CASE_TIMEOUT_SECS: u64 = 120;
+
+/// Temperature used when the suite does not set one (low for reproducibility).
+const DEFAULT_EVAL_TEMPERATURE: f64 = 0.2;
+
+/// Max characters of a response stored in the report per case.
+const RESPONSE_EXCERPT_CHARS: usize = 400;
+
+/// An eval suite parsed from YAML.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EvalSuite {
+    /// Human description shown in the report.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Model IDs to evaluate (default: the configured default model).
+    #[serde(default)]
+    pub models: Vec<String>,
+    /// Sampling temperature for all cases (default 0.2).
+    #[serde(default)]
+    pub temperature: Option<f64>,
+    /// Prompt/expectation cases, run in order.
+    pub cases: Vec<EvalCase>,
+}
+
+/// A single prompt with its expected behavior.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EvalCase {
+    pub name: String,
+    pub prompt: String,
+    /// Optional system prompt for this case.
+    #[serde(default)]
+    pub system: Option<String>,
+    pub expect: Expectation,
+}
+
+/// How a response is scored. Exactly one variant must be set.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Expectation {
+    /// Response must equal this string (whitespace-trimmed).
+    #[serde(default)]
+    pub exact: Option<String>,
+    /// Response must contain this substring (case-insensitive).
+    #[serde(default)]
+    pub contains: Option<String>,
+    /// Response must match this regex.
+    #[serde(default)]
+    pub regex: Option<String>,
+    /// A second model call judges the response against this criteria,
+    /// answering PASS or FAIL.
+    #[serde(default)]
+    pub llm_judge: Option<String>,
+}
+
+impl Expectation {
+    fn variant_count(&self) -> usize {
+        [
+            self.exact.is_some(),
+            self.contains.is_some(),
+            self.regex.is_some(),
+            self.llm_judge.is_some(),
+        ]
+        .iter()
+        .filter(|set| **set)
+        .count()
+    }
+}
+
+/// Scored outcome for one case.
+#[derive(Debug, Serialize)]
+struct CaseResult {
+    name: String,
+    passed: bool,
+    detail: String,
+    response_excerpt: String,
+    duration_ms: u64,
+}
+
+/// Per-model aggregate.
+#[derive(Debug, Serialize)]
+struct ModelReport {
+    model: String,
+    passed: usize,
+    failed: usize,
+    cases: Vec<CaseResult>,
+}
+
+/// Full report written to `<workspace>/state/eval/`.
+#[derive(Debug, Serialize)]
+struct EvalReport {
+    suite: String,
+    description: Option<String>,
+    provider: String,
+    generated_at_unix: u64,
+    results: Vec<ModelReport>,
+}
+
+/// Load and validate a suite from an explicit path.
+pub fn load_suite(path: &Path) -> Result<EvalSuite> {
+    let raw = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read eval suite: {}", path.display()))?;
+    let suite: EvalSuite = serde_yaml::from_str(&raw)
+        .with_context(|| format!("Invalid eval suite YAML: {}", path.display()))?;
+    validate_suite(&suite)?;
+    Ok(suite)
+}
+
+/// Validate case structure and expectations before running anything.
+fn validate_suite(suite: &EvalSuite) -> Result<()> {
+    if suite.cases.is_empty() {
+        bail!("Eval suite has no cases");
+    }
+
+    let mut seen = std::collections::HashSet::new();
+    for case in &suite.cases {
+        if case.name.trim().is_empty() {
+            bail!("Eval suite has a case with an empty name");
+        }
+        if !seen.insert(case.name.clone()) {
+            bail!("Duplicate case name '{}'", case.name);
+        }
+        if case.prompt.trim().is_empty() {
+            bail!("Case '{}' has an empty prompt", case.name);
+        }
+        match case.expect.variant_count() {
+            1 => {}
+            0 => bail!(
+                "Case '{}' has no expectation (set one of: exact, contains, regex, llm_judge)",
+                case.name
+            ),
+            _ => bail!("Case '{}' sets more than one expectation", case.name),
+        }
+        if let Some(pattern) = &case.expect.regex {
+            Regex::new(pattern)
+                .with_context(|| format!("Case '{}' has an invalid regex", case.name))?;
+        }
+    }
+    Ok(())
+}
+
+/// Score the static expectation kinds. `None` means the expectation needs the
+/// LLM judge (async path).
+fn score_static(response: &str, expect: &Expectation) -> Option<(bool, String)> {
+    if let Some(exact) = &expect.exact {
+        let passed = response.trim() == exact.trim();
+        return Some((passed, format!("exact match against {exact:?}")));
+    }
+    if let Some(needle) = &expect.contains {
+        let passed = response.to_lowercase().contains(&needle.to_lowercase());
+        return Some((passed, format!("contains {needle:?}")));
+    }
+    if let Some(pattern) = &expect.regex {
+        // Pattern validity is checked during suite validation.
+        let passed = Regex::new(pattern).is_ok_and(|re| re.is_match(response));
+        return Some((passed, format!("regex {pattern:?}")));
+    }
+    None
+}
+
+const JUDGE_SYSTEM_PROMPT: &str = "You are an evaluation judge. Decide whether the response \
+satisfies the criteria. Reply with exactly one word: PASS or FAIL.";
+
+/// Parse a judge reply into a verdict; anything not starting with PASS/FAIL
+/// is an error rather than a silent fail.
+fn parse_judge_verdict(reply: &str) -> Result<bool> {
+    let normalized = reply.trim().to_uppercase();
+    if normalized.starts_with("PASS") {
+        Ok(true)
+    } else if normalized.starts_with("FAIL") {
+        Ok(false)
+    } else {
+        bail!("Judge returned an unparseable verdict: {reply:?}")
+    }
+}
+
+async fn score_with_judge(
+    provider: &dyn Provider,
+    model: &str,
+    criteria: &str,
+    response: &str,
+) -> Result<bool> {
+    let judge_input = format!("Criteria: {criteria}\n\nResponse to evaluate:\n{response}");
+    let reply = provider
+        .chat_with_system(Some(JUDGE_SYSTEM_PROMPT), &judge_input, model, 0.0)
+        .await
+        .context("LLM judge call failed")?;
+    parse_judge_verdict(&reply)
+}
+
+fn excerpt(response: &str) -> String {
+    if response.chars().count() <= RESPONSE_EXCERPT_CHARS {
+        response.to_string()
+    } else {
+        response.chars().take(RESPONSE_EXCERPT_CHARS).collect()
+    }
+}
+
+async fn run_case(
+    provider: &dyn Provider,
+    case: &EvalCase,
+    model: &str,
+    temperature: f64,
+) -> CaseResult {
+    let started = Instant::now();
+    let response = tokio::time::timeout(
+        Duration::from_secs(CASE_TIMEOUT_SECS),
+        provider.chat_with_system(case.system.as_deref(), &case.prompt, model, temperature),
+    )
+    .await;
+
+    let response = match response {
+        Ok(Ok(text)) => text,
+        Ok(Err(e)) => {
+            return CaseResult {
+                name: case.name.clone(),
+                passed: false,
+                detail: format!("provider error: {e}"),
+                response_excerpt: String::new(),
+                duration_ms: u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX),
+            };
+        }
+        Err(_elapsed) => {
+            return CaseResult {
+                name: case.name.clone(),
+                passed: false,
+                detail: format!("timed out after {CASE_TIMEOUT_SECS}s"),
+                response_excerpt: String::new(),
+                duration_ms: u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX),
+            };
+        }
+    };
+
+    let (passed, detail) = match score_static(&response, &case.expect) {
+        Some(outcome) => outcome,
+        None => {
+            let criteria = case.expect.llm_judge.as_deref().unwrap_or_default();
+            match score_with_judge(provider, model, criteria, &response).await {
+                Ok(verdict) => (verdict, format!("llm_judge: {criteria}")),
+                Err(e) => (false, format!("llm_judge error: {e}")),
+            }
+        }
+    };
+
+    CaseResult {
+        name: case.name.clone(),
+        passed,
+        detail,
+        response_excerpt: excerpt(&response),
+        duration_ms: u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX),
+    }
+}
+
+/// Directory holding eval reports.
+fn reports_dir(config: &Config) -> PathBuf {
+    config.workspace_dir.join("state").join("eval")
+}
+
+fn now_unix_secs() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs())
+}
+
+/// Run a suite and write the report. Errors when any case fails so CI and
+/// pre-deploy scripts can gate on the exit code.
+pub async fn run_suite(
+    config: &Config,
+    suite_path: &Path,
+    model_overrides: &[String],
+) -> Result<()> {
+    let suite = load_suite(suite_path)?;
+    let suite_name = suite_path
+        .file_stem()
+        .map_or_else(|| "suite".to_string(), |s| s.to_string_lossy().to_string());
+
+    let provider_name = config.default_provider.as_deref().unwrap_or("openrouter");
+    let provider: Box<dyn Provider> = providers::create_provider_with_options(
+        provider_name,
+        config.api_key.as_deref(),
+        &providers::ProviderRuntimeOptions {
+            auth_profile_override: None,
+            zeroclaw_dir: config.config_path.parent().map(PathBuf::from),
+            secrets_encrypt: config.secrets.encrypt,
+            reasoning_enabled: config.runtime.reasoning_enabled,
+        },
+    )
+    .with_context(|| format!("Failed to create provider '{provider_name}'"))?;
+
+    let models: Vec<String> = if model_overrides.is_empty() {
+        if suite.models.is_empty() {
+            vec![config
+                .default_model
+                .clone()
+                .unwrap_or_else(|| "anthropic/claude-sonnet-4".to_string())]
+        } else {
+            suite.models.clone()
+        }
+    } else {
+        model_overrides.to_vec()
+    };
+    let temperature = suite.temperature.unwrap_or(DEFAULT_EVAL_TEMPERATURE);
+
+    let mut results = Vec::new();
+    let mut total_failed = 0usize;
+    for model in &models {
+        println!("Evaluating '{model}' ({} cases):", suite.cases.len());
+        let mut cases = Vec::new();
+        let mut passed = 0usize;
+        let mut failed = 0usize;
+        for case in &suite.cases {
+            let result = run_case(provider.as_ref(), case, model, temperature).await;
+            let marker = if result.passed { "✅" } else { "❌" };
+            println!("  {marker} {} ({})", result.name, result.detail);
+            if result.passed {
+                passed += 1;
+            } else {
+                failed += 1;
+            }
+            cases.push(result);
+        }
+        println!("  {passed}/{} passed", suite.cases.len());
+        total_failed += failed;
+        results.push(ModelReport {
+            model: model.clone(),
+            passed,
+            failed,
+            cases,
+        });
+    }
+
+    let report = EvalReport {
+        suite: suite_name.clone(),
+        description: suite.description.clone(),
+        provider: provider_name.to_string(),
+        generated_at_unix: now_unix_secs(),
+        results,
+    };
+
+    let dir = reports_dir(config);
+    std::fs::create_dir_all(&dir)
+        .with_context(|| format!("failed to create eval report directory {}", dir.display()))?;
+    let report_path = dir.join(format!("{suite_name}-{}.json", report.generated_at_unix));
+    std::fs::write(
+        &report_path,
+        serde_json::to_vec_pretty(&report).context("failed to serialize eval report")?,
+    )
+    .with_context(|| format!("failed to write eval report {}", report_path.display()))?;
+    println!("Report written to {}", report_path.display());
+
+    if total_failed > 0 {
+        bail!(
+            "{total_failed} eval case(s) failed; see {}",
+            report_path.display()
+        );
+    }
+    Ok(())
+}
+
+pub async fn handle_command(command: crate::EvalCommands, config: &Config) -> Result<()> {
+    match command {
+        crate::EvalCommands::Run { suite, model } => run_suite(config, &suite, &model).await,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::providers::traits::Provider as ProviderTrait;
+    use async_trait::async_trait;
+
+    fn suite_from_yaml(yaml: &str) -> Result<EvalSuite> {
+        let suite: EvalSuite = serde_yaml::from_str(yaml)?;
+        validate_suite(&suite)?;
+        Ok(suite)
+    }
+
+    #[test]
+    fn suite_parses_with_all_expectation_kinds() {
+        let suite = suite_from_yaml(
+            r#"
+description: smoke
+cases:
+  - name: a
+    prompt: "p"
+    expect: { exact: "4" }
+  - name: b
+    prompt: "p"
+    expect: { contains: "hello" }
+  - name: c
+    prompt: "p"
+    expect: { regex: "^\\d+$" }
+  - name: d
+    prompt: "p"
+    expect: { llm_judge: "is polite" }
+"#,
+        )
+        .unwrap();
+        assert_eq!(suite.cases.len(), 4);
+    }
+
+    #[test]
+    fn suite_rejects_missing_or_conflicting_expectations() {
+        let err =
+            suite_from_yaml("cases:\n  - name: a\n    prompt: p\n    expect: {}\n").unwrap_err();
+        assert!(err.to_string().contains("no expectation"));
+
+        let err = suite_from_yaml(
+            "cases:\n  - name: a\n    prompt: p\n    expect: { exact: x, contains: y }\n",
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("more than one expectation"));
+    }
+
+    #[test]
+    fn suite_rejects_duplicates_and_bad_regex() {
+        let err = suite_from_yaml(
+            "cases:\n  - name: a\n    prompt: p\n    expect: { exact: x }\n  - name: a\n    prompt: p\n    expect: { exact: x }\n",
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("Duplicate case name"));
+
+        let err =
+            suite_from_yaml("cases:\n  - name: a\n    prompt: p\n    expect: { regex: \"(\" }\n")
+                .unwrap_err();
+        assert!(err.to_string().contains("invalid regex"));
+    }
+
+    #[test]
+    fn static_scoring_covers_exact_contains_regex() {
+        let exact = Expectation {
+            exact: Some("4".into()),
+            contains: None,
+            regex: None,
+            llm_judge: None,
+        };
+        assert_eq!(score_static(" 4 ", &exact).unwrap().0, true);
+        assert_eq!(score_static("four", &exact).unwrap().0, false);
+
+        let contains = Expectation {
+            exact: None,
+            contains: Some("Hello".into()),
+            regex: None,
+            llm_judge: None,
+        };
+        assert_eq!(score_static("well hello there", &contains).unwrap().0, true);
+
+        let regex = Expectation {
+            exact: None,
+            contains: None,
+            regex: Some(r"\d{4}-\d{2}-\d{2}".into()),
+            llm_judge: None,
+        };
+        assert_eq!(
+            score_static("on 2026-01-01 we ship", &regex).unwrap().0,
+            true
+        );
+
+        let judge = Expectation {
+            exact: None,
+            contains: None,
+            regex: None,
+            llm_judge: Some("is polite".into()),
+        };
+        assert!(score_static("anything", &judge).is_none());
+    }
+
+    #[test]
+    fn judge_verdict_parsing_is_strict() {
+        assert!(parse_judge_verdict("PASS").unwrap());
+        assert!(parse_judge_verdict("pass — looks good").unwrap());
+        assert!(!parse_judge_verdict("FAIL: missing detail").unwrap());
+        assert!(parse_judge_verdict("maybe?").is_err());
+    }
+
+    struct StaticProvider {
+        reply: String,
+    }
+
+    #[async_trait]
+    impl ProviderTrait for StaticProvider {
+        async fn chat_with_system(
+            &self,
+            _system_prompt: Option<&str>,
+            _message: &str,
+            _model: &str,
+            _temperature: f64,
+        ) -> anyhow::Result<String> {
+            Ok(self.reply.clone())
+        }
+    }
+
+    #[tokio::test]
+    async fn run_case_scores_response_against_expectation() {
+        let provider = StaticProvider {
+            reply: "the answer is 4".into(),
+        };
+        let case = EvalCase {
+            name: "arith".into(),
+            prompt: "2+2?".into(),
+            system: None,
+            expect: Expectation {
+                exact: None,
+                contains: Some("4".into()),
+                regex: None,
+                llm_judge: None,
+            },
+        };
+
+        let result = run_case(&provider, &case, "test-model", 0.0).await;
+        assert!(result.passed);
+        assert!(result.response_excerpt.contains("4"));
+    }
+
+    #[tokio::test]
+    async fn run_case_records_provider_errors_as_failures() {
+        struct FailingProvider;
+        #[async_trait]
+        impl ProviderTrait for FailingProvider {
+            async fn chat_with_system(
+                &self,
+                _system_prompt: Option<&str>,
+                _message: &str,
+                _model: &str,
+                _temperature: f64,
+            ) -> anyhow::Result<String> {
+                anyhow::bail!("boom")
+            }
+        }
+
+        let case = EvalCase {
+            name: "err".into(),
+            prompt: "p".into(),
+            system: None,
+            expect: Expectation {
+                exact: Some("x".into()),
+                contains: None,
+                regex: None,
+                llm_judge: None,
+            },
+        };
+        let result = run_case(&FailingProvider, &case, "test-model", 0.0).await;
+        assert!(!result.passed);
+        assert!(result.detail.contains("provider error"));
+    }
+}
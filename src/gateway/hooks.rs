@@ -0,0 +1,213 @@
+//! Config-driven inbound webhook routing (`[[gateway.hooks]]`).
+//!
+//! Matching, per-source HMAC verification, dotted-path JSON extraction, and
+//! prompt template rendering for `POST /hooks/route/{name}`. The HTTP
+//! handler itself lives in the parent module alongside the other hooks.
+
+use crate::config::HookRouteConfig;
+use anyhow::{Context, Result};
+use axum::http::HeaderMap;
+use std::collections::HashMap;
+
+/// First rule whose `path` equals the route segment and whose header
+/// matchers all hold. Rules may share a `path` and disambiguate via
+/// headers (e.g. one rule per `X-GitHub-Event` value).
+pub(super) fn find_rule<'a>(
+    rules: &'a [HookRouteConfig],
+    path: &str,
+    headers: &HeaderMap,
+) -> Option<&'a HookRouteConfig> {
+    rules
+        .iter()
+        .find(|rule| rule.path == path && headers_match(rule, headers))
+}
+
+fn headers_match(rule: &HookRouteConfig, headers: &HeaderMap) -> bool {
+    rule.headers.iter().all(|(name, expected)| {
+        headers
+            .get(name.as_str())
+            .and_then(|v| v.to_str().ok())
+            .is_some_and(|value| value == expected)
+    })
+}
+
+/// Verify an HMAC-SHA256 signature over the raw request body.
+///
+/// Accepts both raw hex digests (Grafana-style shared secrets) and the
+/// `sha256=<hex>` prefix form used by GitHub and Meta webhooks.
+pub(super) fn verify_hook_signature(secret: &str, body: &[u8], signature_header: &str) -> bool {
+    use hmac::{Hmac, Mac};
+    use sha2::Sha256;
+
+    let hex_sig = signature_header
+        .strip_prefix("sha256=")
+        .unwrap_or(signature_header);
+    let Ok(expected) = hex::decode(hex_sig) else {
+        return false;
+    };
+    let Ok(mut mac) = Hmac::<Sha256>::new_from_slice(secret.as_bytes()) else {
+        return false;
+    };
+    mac.update(body);
+    mac.verify_slice(&expected).is_ok()
+}
+
+/// Look up a dotted path (`issue.title`, `alerts.0.status`) in a JSON
+/// payload. Numeric segments index arrays.
+pub(super) fn json_lookup<'a>(
+    payload: &'a serde_json::Value,
+    path: &str,
+) -> Option<&'a serde_json::Value> {
+    let mut current = payload;
+    for segment in path.split('.') {
+        current = match current {
+            serde_json::Value::Object(map) => map.get(segment)?,
+            serde_json::Value::Array(items) => items.get(segment.parse::<usize>().ok()?)?,
+            _ => return None,
+        };
+    }
+    Some(current)
+}
+
+/// Resolve all `extract` variables for a rule. A missing path is an
+/// explicit error (the source sent a payload the rule does not fit) rather
+/// than a silently empty variable.
+pub(super) fn extract_variables(
+    rule: &HookRouteConfig,
+    payload: &serde_json::Value,
+) -> Result<HashMap<String, String>> {
+    rule.extract
+        .iter()
+        .map(|(name, path)| {
+            json_lookup(payload, path)
+                .map(|value| (name.clone(), value_to_string(value)))
+                .with_context(|| {
+                    format!("Payload has no value at '{path}' (extract variable '{name}')")
+                })
+        })
+        .collect()
+}
+
+fn value_to_string(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Fill `{var}` placeholders from extracted variables; `{payload}` expands
+/// to the full JSON body.
+pub(super) fn render_template(
+    template: &str,
+    vars: &HashMap<String, String>,
+    payload: &serde_json::Value,
+) -> String {
+    let mut out = template.to_string();
+    for (name, value) in vars {
+        out = out.replace(&format!("{{{name}}}"), value);
+    }
+    out.replace("{payload}", &payload.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(path: &str) -> HookRouteConfig {
+        HookRouteConfig {
+            path: path.to_string(),
+            headers: HashMap::new(),
+            secret: None,
+            signature_header: "X-Hub-Signature-256".to_string(),
+            extract: HashMap::new(),
+            prompt: None,
+            workflow: None,
+        }
+    }
+
+    #[test]
+    fn find_rule_matches_path_and_headers() {
+        let mut push = rule("github");
+        push.headers
+            .insert("X-GitHub-Event".to_string(), "push".to_string());
+        let fallback = rule("github");
+        let rules = vec![push, fallback];
+
+        let mut headers = HeaderMap::new();
+        headers.insert("X-GitHub-Event", "push".parse().unwrap());
+        let matched = find_rule(&rules, "github", &headers).unwrap();
+        assert_eq!(matched.headers.len(), 1);
+
+        let mut headers = HeaderMap::new();
+        headers.insert("X-GitHub-Event", "issues".parse().unwrap());
+        let matched = find_rule(&rules, "github", &headers).unwrap();
+        assert!(matched.headers.is_empty());
+
+        assert!(find_rule(&rules, "stripe", &HeaderMap::new()).is_none());
+    }
+
+    #[test]
+    fn verify_hook_signature_accepts_prefixed_and_raw_hex() {
+        use hmac::{Hmac, Mac};
+        use sha2::Sha256;
+
+        let secret = "hook-secret";
+        let body = b"{\"ok\":true}";
+        let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).unwrap();
+        mac.update(body);
+        let hex_sig = hex::encode(mac.finalize().into_bytes());
+
+        assert!(verify_hook_signature(secret, body, &hex_sig));
+        assert!(verify_hook_signature(
+            secret,
+            body,
+            &format!("sha256={hex_sig}")
+        ));
+        assert!(!verify_hook_signature("wrong-secret", body, &hex_sig));
+        assert!(!verify_hook_signature(secret, b"tampered", &hex_sig));
+        assert!(!verify_hook_signature(secret, body, "not-hex!"));
+    }
+
+    #[test]
+    fn json_lookup_walks_objects_and_arrays() {
+        let payload = serde_json::json!({
+            "issue": {"title": "crash on start"},
+            "alerts": [{"status": "firing"}],
+        });
+
+        assert_eq!(
+            json_lookup(&payload, "issue.title").unwrap(),
+            "crash on start"
+        );
+        assert_eq!(json_lookup(&payload, "alerts.0.status").unwrap(), "firing");
+        assert!(json_lookup(&payload, "issue.body").is_none());
+        assert!(json_lookup(&payload, "alerts.1.status").is_none());
+        assert!(json_lookup(&payload, "issue.title.deeper").is_none());
+    }
+
+    #[test]
+    fn extract_variables_fails_fast_on_missing_path() {
+        let mut r = rule("grafana");
+        r.extract
+            .insert("status".to_string(), "alerts.0.status".to_string());
+        let payload = serde_json::json!({"alerts": [{"status": "firing"}]});
+        let vars = extract_variables(&r, &payload).unwrap();
+        assert_eq!(vars.get("status").unwrap(), "firing");
+
+        let err = extract_variables(&r, &serde_json::json!({})).unwrap_err();
+        assert!(err.to_string().contains("alerts.0.status"));
+    }
+
+    #[test]
+    fn render_template_fills_vars_and_payload() {
+        let mut vars = HashMap::new();
+        vars.insert("status".to_string(), "firing".to_string());
+        let payload = serde_json::json!({"n": 1});
+
+        let rendered = render_template("Alert {status}: {payload}", &vars, &payload);
+        assert_eq!(rendered, "Alert firing: {\"n\":1}");
+
+        let rendered = render_template("No placeholders", &vars, &payload);
+        assert_eq!(rendered, "No placeholders");
+    }
+}
@@ -7,6 +7,9 @@
 //! - Request timeouts (30s) to prevent slow-loris attacks
 //! - Header sanitization (handled by axum/hyper)
 
+mod hooks;
+pub mod oidc;
+
 use crate::channels::{Channel, LinqChannel, SendMessage, WhatsAppChannel};
 use crate::config::Config;
 use crate::memory::{self, Memory, MemoryCategory};
@@ -21,7 +24,7 @@ use axum::{
     body::Bytes,
     extract::{ConnectInfo, Query, State},
     http::{header, HeaderMap, StatusCode},
-    response::{IntoResponse, Json},
+    response::{IntoResponse, Json, Redirect, Response},
     routing::{get, post},
     Router,
 };
@@ -285,6 +288,8 @@ pub struct AppState {
     pub linq_signing_secret: Option<Arc<str>>,
     /// Observability backend for metrics scraping
     pub observer: Arc<dyn crate::observability::Observer>,
+    /// OIDC login state when `[gateway.oidc]` is enabled
+    pub oidc: Option<Arc<oidc::OidcState>>,
 }
 
 /// Run the HTTP gateway using axum with proper HTTP/1.1 compliance.
@@ -331,12 +336,16 @@ pub async fn run_gateway(host: &str, port: u16, config: Config) -> Result<()> {
     )?);
     let runtime: Arc<dyn runtime::RuntimeAdapter> =
         Arc::from(runtime::create_runtime(&config.runtime)?);
-    let security = Arc::new(SecurityPolicy::from_config(
-        &config.autonomy,
-        &config.workspace_dir,
-    ));
+    let security = Arc::new(
+        SecurityPolicy::from_config(&config.autonomy, &config.workspace_dir)
+            .with_audit(&config.security.audit, config.zeroclaw_dir()),
+    );
     let observer: Arc<dyn crate::observability::Observer> =
-        Arc::from(crate::observability::create_observer(&config.observability, config.delegation_log_path()));
+        Arc::from(crate::observability::create_observer(
+            &config.observability,
+            &config.events,
+            config.delegation_log_path(),
+        ));
 
     let (composio_key, composio_entity_id) = if config.composio.enabled {
         (
@@ -434,6 +443,16 @@ pub async fn run_gateway(host: &str, port: u16, config: Config) -> Result<()> {
         })
         .map(Arc::from);
 
+    // ── OIDC login (optional) ─────────────────────────────
+    let oidc_state: Option<Arc<oidc::OidcState>> = if config.gateway.oidc.enabled {
+        Some(Arc::new(
+            oidc::OidcState::new(config.gateway.oidc.clone())
+                .context("invalid [gateway.oidc] configuration")?,
+        ))
+    } else {
+        None
+    };
+
     // ── Pairing guard ──────────────────────────────────────
     let pairing = Arc::new(PairingGuard::new(
         config.gateway.require_pairing,
@@ -490,6 +509,9 @@ pub async fn run_gateway(host: &str, port: u16, config: Config) -> Result<()> {
     }
     println!("  GET  /health    — health check");
     println!("  GET  /metrics   — Prometheus metrics");
+    if oidc_state.is_some() {
+        println!("  GET  /auth/login — sign in via OIDC provider");
+    }
     if let Some(code) = pairing.pairing_code() {
         println!();
         println!("  🔐 PAIRING REQUIRED — use this one-time code:");
@@ -508,7 +530,11 @@ pub async fn run_gateway(host: &str, port: u16, config: Config) -> Result<()> {
 
     // Build shared state
     let observer: Arc<dyn crate::observability::Observer> =
-        Arc::from(crate::observability::create_observer(&config.observability, config.delegation_log_path()));
+        Arc::from(crate::observability::create_observer(
+            &config.observability,
+            &config.events,
+            config.delegation_log_path(),
+        ));
 
     let state = AppState {
         config: config_state,
@@ -527,14 +553,27 @@ pub async fn run_gateway(host: &str, port: u16, config: Config) -> Result<()> {
         linq: linq_channel,
         linq_signing_secret,
         observer,
+        oidc: oidc_state,
     };
 
     // Build router with middleware
     let mut app = Router::new()
         .route("/health", get(handle_health))
+        .route("/healthz", get(handle_healthz))
+        .route("/readyz", get(handle_readyz))
         .route("/metrics", get(handle_metrics))
+        .route("/monitors", get(handle_monitors))
+        .route("/auth/login", get(handle_auth_login))
+        .route("/auth/callback", get(handle_auth_callback))
+        .route("/auth/logout", post(handle_auth_logout))
+        .route("/auth/me", get(handle_auth_me))
         .route("/pair", post(handle_pair))
         .route("/webhook", post(handle_webhook))
+        .route("/hooks/terraform", post(handle_terraform_plan))
+        .route("/hooks/ci", post(handle_ci_failure))
+        .route("/hooks/github", post(handle_github_event))
+        .route("/hooks/pager", post(handle_pager_incident))
+        .route("/hooks/route/{name}", post(handle_routed_hook))
         .route("/whatsapp", get(handle_whatsapp_verify))
         .route("/whatsapp", post(handle_whatsapp_message))
         .route("/linq", post(handle_linq_webhook))
@@ -561,12 +600,12 @@ pub async fn run_gateway(host: &str, port: u16, config: Config) -> Result<()> {
         app = app.nest_service("/", ServeDir::new(&config.gateway.static_dir));
     }
 
-    let app = app
-        .layer(RequestBodyLimitLayer::new(MAX_BODY_SIZE))
-        .layer(TimeoutLayer::with_status_code(
-            StatusCode::REQUEST_TIMEOUT,
-            Duration::from_secs(REQUEST_TIMEOUT_SECS),
-        ));
+    let app =
+        app.layer(RequestBodyLimitLayer::new(MAX_BODY_SIZE))
+            .layer(TimeoutLayer::with_status_code(
+                StatusCode::REQUEST_TIMEOUT,
+                Duration::from_secs(REQUEST_TIMEOUT_SECS),
+            ));
 
     // Run the server
     axum::serve(
@@ -592,6 +631,63 @@ async fn handle_health(State(state): State<AppState>) -> impl IntoResponse {
     Json(body)
 }
 
+/// GET /healthz — liveness probe: 200 while the gateway can serve requests.
+/// Suitable for Docker HEALTHCHECK and systemd watchdog wrappers.
+async fn handle_healthz() -> impl IntoResponse {
+    Json(serde_json::json!({
+        "status": "ok",
+        "pid": std::process::id(),
+    }))
+}
+
+/// Components whose last supervised state is an error (sorted by name, since
+/// the snapshot map is ordered).
+fn failing_components(snapshot: &crate::health::HealthSnapshot) -> Vec<String> {
+    snapshot
+        .components
+        .iter()
+        .filter(|(_, component)| component.status == "error")
+        .map(|(name, _)| name.clone())
+        .collect()
+}
+
+/// GET /readyz — readiness probe for Kubernetes and orchestrators: 200 when
+/// every supervised component (gateway, channels, scheduler, …) reports
+/// healthy and the daemon is not draining for shutdown; 503 otherwise with
+/// the failing components listed.
+async fn handle_readyz() -> impl IntoResponse {
+    if crate::daemon::shutdown::coordinator().is_draining() {
+        let body = serde_json::json!({ "status": "draining" });
+        return (StatusCode::SERVICE_UNAVAILABLE, Json(body));
+    }
+
+    let snapshot = crate::health::snapshot();
+    let failing = failing_components(&snapshot);
+    let components: serde_json::Map<String, serde_json::Value> = snapshot
+        .components
+        .iter()
+        .map(|(name, component)| {
+            (
+                name.clone(),
+                serde_json::Value::String(component.status.clone()),
+            )
+        })
+        .collect();
+
+    let ready = failing.is_empty();
+    let body = serde_json::json!({
+        "status": if ready { "ready" } else { "not_ready" },
+        "components": components,
+        "failing": failing,
+    });
+    let status = if ready {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    (status, Json(body))
+}
+
 /// Prometheus content type for text exposition format.
 const PROMETHEUS_CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
 
@@ -615,6 +711,137 @@ async fn handle_metrics(State(state): State<AppState>) -> impl IntoResponse {
     )
 }
 
+/// GET /monitors — uptime monitor status table (HTML dashboard)
+async fn handle_monitors(State(state): State<AppState>, headers: HeaderMap) -> Response {
+    // When OIDC login is enabled, dashboard pages require a valid session.
+    if let Some(ref oidc) = state.oidc {
+        if oidc.authorize_cookie(cookie_header(&headers)).is_none() {
+            return Redirect::to("/auth/login").into_response();
+        }
+    }
+
+    let config = { state.config.lock().clone() };
+    if !config.monitors.enabled {
+        return (
+            StatusCode::NOT_FOUND,
+            [(header::CONTENT_TYPE, "text/plain; charset=utf-8")],
+            "Uptime monitoring not enabled. Set [monitors] enabled = true in config.toml\n"
+                .to_string(),
+        )
+            .into_response();
+    }
+
+    match crate::monitors::uptime::status_table(&config) {
+        Ok(statuses) => (
+            StatusCode::OK,
+            [(header::CONTENT_TYPE, "text/html; charset=utf-8")],
+            crate::monitors::uptime::render_status_page(&statuses),
+        )
+            .into_response(),
+        Err(e) => {
+            tracing::error!("Failed to load uptime monitor status: {e:#}");
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                [(header::CONTENT_TYPE, "text/plain; charset=utf-8")],
+                "Failed to load uptime monitor status\n".to_string(),
+            )
+                .into_response()
+        }
+    }
+}
+
+/// Extract the raw `Cookie` header value, if present and valid UTF-8.
+fn cookie_header(headers: &HeaderMap) -> Option<&str> {
+    headers.get(header::COOKIE).and_then(|v| v.to_str().ok())
+}
+
+/// 404 for `/auth/*` routes when `[gateway.oidc]` is not enabled.
+fn oidc_not_enabled() -> Response {
+    (
+        StatusCode::NOT_FOUND,
+        "OIDC login not enabled. Set [gateway.oidc] enabled = true in config.toml\n",
+    )
+        .into_response()
+}
+
+/// GET /auth/login — redirect the browser to the OIDC provider
+async fn handle_auth_login(State(state): State<AppState>) -> Response {
+    let Some(oidc) = state.oidc else {
+        return oidc_not_enabled();
+    };
+    match oidc.begin_login().await {
+        Ok(url) => Redirect::to(&url).into_response(),
+        Err(e) => {
+            tracing::error!("OIDC login could not be started: {e:#}");
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Failed to start login. Check gateway logs.\n",
+            )
+                .into_response()
+        }
+    }
+}
+
+/// GET /auth/callback — complete the OIDC code exchange and set the session cookie
+async fn handle_auth_callback(
+    State(state): State<AppState>,
+    Query(params): Query<HashMap<String, String>>,
+) -> Response {
+    let Some(oidc) = state.oidc else {
+        return oidc_not_enabled();
+    };
+    let (Some(login_state), Some(code)) = (params.get("state"), params.get("code")) else {
+        return (StatusCode::BAD_REQUEST, "Missing state or code parameter\n").into_response();
+    };
+    match oidc.complete_login(login_state, code).await {
+        Ok((token, session)) => {
+            tracing::info!(
+                identity = %session.identity,
+                role = %session.role,
+                "OIDC login succeeded"
+            );
+            (
+                [(header::SET_COOKIE, oidc.session_cookie(&token))],
+                Redirect::to("/monitors"),
+            )
+                .into_response()
+        }
+        Err(e) => {
+            // Details stay in the server log; the browser gets no claim data.
+            tracing::warn!("OIDC login rejected: {e:#}");
+            (StatusCode::UNAUTHORIZED, "Login failed\n").into_response()
+        }
+    }
+}
+
+/// POST /auth/logout — revoke the current session and clear the cookie
+async fn handle_auth_logout(State(state): State<AppState>, headers: HeaderMap) -> Response {
+    let Some(oidc) = state.oidc else {
+        return oidc_not_enabled();
+    };
+    oidc.logout(cookie_header(&headers));
+    (
+        [(header::SET_COOKIE, oidc::OidcState::clear_session_cookie())],
+        "Signed out\n",
+    )
+        .into_response()
+}
+
+/// GET /auth/me — identity and role of the current session
+async fn handle_auth_me(State(state): State<AppState>, headers: HeaderMap) -> Response {
+    let Some(oidc) = state.oidc else {
+        return oidc_not_enabled();
+    };
+    match oidc.authorize_cookie(cookie_header(&headers)) {
+        Some(session) => Json(serde_json::json!({
+            "identity": session.identity,
+            "role": session.role,
+        }))
+        .into_response(),
+        None => (StatusCode::UNAUTHORIZED, "Not signed in\n").into_response(),
+    }
+}
+
 /// POST /pair — exchange one-time code for bearer token
 #[axum::debug_handler]
 async fn handle_pair(
@@ -746,22 +973,22 @@ pub struct WebhookBody {
     pub message: String,
 }
 
-/// POST /webhook — main webhook endpoint
-async fn handle_webhook(
-    State(state): State<AppState>,
-    ConnectInfo(peer_addr): ConnectInfo<SocketAddr>,
-    headers: HeaderMap,
-    body: Result<Json<WebhookBody>, axum::extract::rejection::JsonRejection>,
-) -> impl IntoResponse {
-    let rate_key =
-        client_key_from_request(Some(peer_addr), &headers, state.trust_forwarded_headers);
+/// Shared auth for webhook-style endpoints: rate limit, pairing bearer
+/// token, and the optional `X-Webhook-Secret` layer. Returns the error
+/// response to send when the request must be rejected.
+fn authorize_webhook(
+    state: &AppState,
+    peer_addr: Option<SocketAddr>,
+    headers: &HeaderMap,
+) -> Result<(), (StatusCode, Json<serde_json::Value>)> {
+    let rate_key = client_key_from_request(peer_addr, headers, state.trust_forwarded_headers);
     if !state.rate_limiter.allow_webhook(&rate_key) {
         tracing::warn!("/webhook rate limit exceeded");
         let err = serde_json::json!({
             "error": "Too many webhook requests. Please retry later.",
             "retry_after": RATE_LIMIT_WINDOW_SECS,
         });
-        return (StatusCode::TOO_MANY_REQUESTS, Json(err));
+        return Err((StatusCode::TOO_MANY_REQUESTS, Json(err)));
     }
 
     // ── Bearer token auth (pairing) ──
@@ -776,7 +1003,7 @@ async fn handle_webhook(
             let err = serde_json::json!({
                 "error": "Unauthorized — pair first via POST /pair, then send Authorization: Bearer <token>"
             });
-            return (StatusCode::UNAUTHORIZED, Json(err));
+            return Err((StatusCode::UNAUTHORIZED, Json(err)));
         }
     }
 
@@ -793,11 +1020,33 @@ async fn handle_webhook(
             _ => {
                 tracing::warn!("Webhook: rejected request — invalid or missing X-Webhook-Secret");
                 let err = serde_json::json!({"error": "Unauthorized — invalid or missing X-Webhook-Secret header"});
-                return (StatusCode::UNAUTHORIZED, Json(err));
+                return Err((StatusCode::UNAUTHORIZED, Json(err)));
             }
         }
     }
 
+    Ok(())
+}
+
+/// POST /webhook — main webhook endpoint
+async fn handle_webhook(
+    State(state): State<AppState>,
+    ConnectInfo(peer_addr): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+    body: Result<Json<WebhookBody>, axum::extract::rejection::JsonRejection>,
+) -> impl IntoResponse {
+    if let Err(rejection) = authorize_webhook(&state, Some(peer_addr), &headers) {
+        return rejection;
+    }
+
+    // ── Drain check ──
+    // Held for the whole request so daemon shutdown waits for it to finish.
+    let Some(_run_guard) = crate::daemon::shutdown::coordinator().begin_run() else {
+        tracing::info!("Webhook rejected: daemon is draining for shutdown");
+        let err = serde_json::json!({ "error": "Service is shutting down" });
+        return (StatusCode::SERVICE_UNAVAILABLE, Json(err));
+    };
+
     // ── Parse body ──
     let Json(webhook_body) = match body {
         Ok(b) => b,
@@ -928,6 +1177,616 @@ async fn handle_webhook(
     }
 }
 
+/// POST /hooks/route/{name} — config-driven inbound webhook routing.
+///
+/// Maps arbitrary inbound webhooks (GitHub, Stripe, Grafana alerts, ...)
+/// to an agent prompt or workflow invocation per `[[gateway.hooks]]`
+/// rules: the first rule matching the path segment and header matchers
+/// handles the request.
+///
+/// Auth: when the matched rule has a `secret`, its HMAC-SHA256 signature
+/// header is verified against the raw body; otherwise the standard
+/// `/webhook` auth layers apply.
+async fn handle_routed_hook(
+    State(state): State<AppState>,
+    ConnectInfo(peer_addr): ConnectInfo<SocketAddr>,
+    axum::extract::Path(name): axum::extract::Path<String>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> impl IntoResponse {
+    let rules = { state.config.lock().gateway.hooks.clone() };
+    let Some(rule) = hooks::find_rule(&rules, &name, &headers) else {
+        return (
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({
+                "error": "No matching hook route. Configure [[gateway.hooks]] in config.toml"
+            })),
+        );
+    };
+
+    // ── Auth: per-source HMAC when configured, /webhook stack otherwise ──
+    if let Some(ref secret) = rule.secret {
+        let signature = headers
+            .get(rule.signature_header.as_str())
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or("");
+        if !hooks::verify_hook_signature(secret, &body, signature) {
+            tracing::warn!("Hook route '{name}': rejected — signature verification failed");
+            return (
+                StatusCode::UNAUTHORIZED,
+                Json(serde_json::json!({"error": "Invalid webhook signature"})),
+            );
+        }
+    } else if let Err(rejection) = authorize_webhook(&state, Some(peer_addr), &headers) {
+        return rejection;
+    }
+
+    // ── Drain check ──
+    let Some(_run_guard) = crate::daemon::shutdown::coordinator().begin_run() else {
+        tracing::info!("Hook route '{name}' rejected: daemon is draining for shutdown");
+        let err = serde_json::json!({ "error": "Service is shutting down" });
+        return (StatusCode::SERVICE_UNAVAILABLE, Json(err));
+    };
+
+    let Ok(payload) = serde_json::from_slice::<serde_json::Value>(&body) else {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({"error": "Invalid JSON payload"})),
+        );
+    };
+
+    let vars = match hooks::extract_variables(rule, &payload) {
+        Ok(vars) => vars,
+        Err(e) => {
+            tracing::warn!("Hook route '{name}': {e:#}");
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({"error": e.to_string()})),
+            );
+        }
+    };
+    let input = rule.prompt.as_deref().map_or_else(
+        || payload.to_string(),
+        |template| hooks::render_template(template, &vars, &payload),
+    );
+
+    if let Some(ref workflow_name) = rule.workflow {
+        let config = { state.config.lock().clone() };
+        match crate::workflow::run_workflow(&config, workflow_name, &input).await {
+            Ok(output) => (
+                StatusCode::OK,
+                Json(serde_json::json!({
+                    "status": "ok",
+                    "workflow": workflow_name,
+                    "output": output,
+                })),
+            ),
+            Err(e) => {
+                tracing::error!("Hook route '{name}': workflow '{workflow_name}' failed: {e:#}");
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(serde_json::json!({"error": "Workflow execution failed"})),
+                )
+            }
+        }
+    } else if rule.prompt.is_some() {
+        match state
+            .provider
+            .chat_with_system(None, &input, &state.model, state.temperature)
+            .await
+        {
+            Ok(response) => (
+                StatusCode::OK,
+                Json(serde_json::json!({"response": response, "model": state.model})),
+            ),
+            Err(e) => {
+                tracing::error!(
+                    "Hook route '{name}' provider error: {}",
+                    providers::sanitize_api_error(&e.to_string())
+                );
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(serde_json::json!({"error": "LLM request failed"})),
+                )
+            }
+        }
+    } else {
+        tracing::error!("Hook route '{name}': rule has neither prompt nor workflow configured");
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({
+                "error": "Hook route misconfigured: set prompt or workflow"
+            })),
+        )
+    }
+}
+
+/// Optional delivery target for the Terraform plan hook.
+#[derive(serde::Deserialize)]
+pub struct TerraformHookQuery {
+    /// Channel to post the summary to (e.g. `telegram`, `slack`).
+    pub channel: Option<String>,
+    /// Recipient/target within that channel.
+    pub to: Option<String>,
+}
+
+/// POST /hooks/terraform — Terraform plan summarizer hook.
+///
+/// Accepts `terraform plan -json` (JSONL) or `terraform show -json` output
+/// as the raw request body, returns a risk-ranked digest plus an agent
+/// review, and optionally posts it to a channel via `?channel=...&to=...`
+/// for approval workflows. Uses the same auth layers as `/webhook`.
+async fn handle_terraform_plan(
+    State(state): State<AppState>,
+    ConnectInfo(peer_addr): ConnectInfo<SocketAddr>,
+    Query(query): Query<TerraformHookQuery>,
+    headers: HeaderMap,
+    body: String,
+) -> impl IntoResponse {
+    if let Err(rejection) = authorize_webhook(&state, Some(peer_addr), &headers) {
+        return rejection;
+    }
+
+    let digest = match crate::terraform::parse_plan(&body) {
+        Ok(digest) => digest,
+        Err(e) => {
+            let err = serde_json::json!({"error": e.to_string()});
+            return (StatusCode::BAD_REQUEST, Json(err));
+        }
+    };
+
+    let mut summary = digest.render();
+    if !digest.is_empty() {
+        match state
+            .provider
+            .chat_with_system(
+                Some(crate::terraform::REVIEW_SYSTEM_PROMPT),
+                &summary,
+                &state.model,
+                state.temperature,
+            )
+            .await
+        {
+            Ok(review) => summary = format!("{summary}\n{review}"),
+            Err(e) => {
+                tracing::warn!("Terraform plan review failed; returning digest only: {e}");
+            }
+        }
+    }
+
+    if let (Some(channel), Some(target)) = (query.channel.as_deref(), query.to.as_deref()) {
+        let config = { state.config.lock().clone() };
+        if let Err(e) = crate::channels::send_once(&config, channel, target, &summary).await {
+            tracing::error!("Terraform plan summary delivery failed: {e}");
+            let err = serde_json::json!({
+                "error": format!("Plan summarized but channel delivery failed: {e}"),
+                "summary": summary,
+            });
+            return (StatusCode::BAD_GATEWAY, Json(err));
+        }
+    }
+
+    let body = serde_json::json!({"summary": summary, "digest": digest});
+    (StatusCode::OK, Json(body))
+}
+
+/// POST /hooks/ci — CI failure triage hook.
+///
+/// Receives GitHub Actions `workflow_run` or GitLab CI Pipeline Hook
+/// webhooks, fetches the failing job log via the forge API, asks the agent
+/// for a root-cause hypothesis with a suggested fix, and posts it as a PR/MR
+/// comment (when the run belongs to one) or to the configured `[ci]` channel.
+///
+/// Auth: when `[ci] webhook_secret` is set, the forge's native mechanism is
+/// verified (GitHub `X-Hub-Signature-256` HMAC / GitLab `X-Gitlab-Token`);
+/// otherwise the standard `/webhook` auth layers apply.
+async fn handle_ci_failure(
+    State(state): State<AppState>,
+    ConnectInfo(peer_addr): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> impl IntoResponse {
+    let ci = { state.config.lock().ci.clone() };
+    if !ci.enabled {
+        return (
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({
+                "error": "CI triage not enabled. Set [ci] enabled = true in config.toml"
+            })),
+        );
+    }
+
+    let github_event = headers
+        .get("X-GitHub-Event")
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_owned);
+    let gitlab_event = headers
+        .get("X-Gitlab-Event")
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_owned);
+
+    // ── Auth: forge-native secret when configured, /webhook stack otherwise ──
+    if let Some(ref secret) = ci.webhook_secret {
+        let authorized = if github_event.is_some() {
+            let signature = headers
+                .get("X-Hub-Signature-256")
+                .and_then(|v| v.to_str().ok())
+                .unwrap_or("");
+            verify_whatsapp_signature(secret, &body, signature)
+        } else {
+            headers
+                .get("X-Gitlab-Token")
+                .and_then(|v| v.to_str().ok())
+                .is_some_and(|token| constant_time_eq(token, secret))
+        };
+        if !authorized {
+            tracing::warn!("CI hook: rejected request — forge webhook secret verification failed");
+            return (
+                StatusCode::UNAUTHORIZED,
+                Json(serde_json::json!({"error": "Invalid webhook signature"})),
+            );
+        }
+    } else if let Err(rejection) = authorize_webhook(&state, Some(peer_addr), &headers) {
+        return rejection;
+    }
+
+    let Ok(payload) = serde_json::from_slice::<serde_json::Value>(&body) else {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({"error": "Invalid JSON payload"})),
+        );
+    };
+
+    let failure =
+        match crate::ci::parse_event(github_event.as_deref(), gitlab_event.as_deref(), &payload) {
+            Ok(Some(failure)) => failure,
+            Ok(None) => {
+                return (
+                    StatusCode::OK,
+                    Json(serde_json::json!({"status": "ignored"})),
+                );
+            }
+            Err(e) => {
+                return (
+                    StatusCode::BAD_REQUEST,
+                    Json(serde_json::json!({"error": e.to_string()})),
+                );
+            }
+        };
+
+    tracing::info!("CI hook: triaging {}", failure.describe());
+
+    let log_excerpt = match crate::ci::fetch_failure_log(&ci, &failure).await {
+        Ok(log) => log,
+        Err(e) => {
+            tracing::error!("CI hook: failed to fetch job log: {e:#}");
+            let err = serde_json::json!({
+                "error": format!("Failed to fetch failing job log: {e}")
+            });
+            return (StatusCode::BAD_GATEWAY, Json(err));
+        }
+    };
+
+    let triage = match state
+        .provider
+        .chat_with_system(
+            Some(crate::ci::TRIAGE_SYSTEM_PROMPT),
+            &crate::ci::build_triage_prompt(&failure, &log_excerpt),
+            &state.model,
+            state.temperature,
+        )
+        .await
+    {
+        Ok(triage) => triage,
+        Err(e) => {
+            tracing::error!(
+                "CI hook provider error: {}",
+                providers::sanitize_api_error(&e.to_string())
+            );
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": "LLM request failed"})),
+            );
+        }
+    };
+
+    let report = format!("## CI failure triage\n\n{}\n\n{triage}", failure.describe());
+
+    let delivered = match crate::ci::post_comment(&ci, &failure, &report).await {
+        Ok(true) => "comment",
+        Ok(false) => {
+            if let (Some(channel), Some(target)) = (ci.channel.as_deref(), ci.to.as_deref()) {
+                let config = { state.config.lock().clone() };
+                if let Err(e) = crate::channels::send_once(&config, channel, target, &report).await
+                {
+                    tracing::error!("CI hook: channel delivery failed: {e}");
+                    let err = serde_json::json!({
+                        "error": format!("Triage produced but channel delivery failed: {e}"),
+                        "triage": triage,
+                    });
+                    return (StatusCode::BAD_GATEWAY, Json(err));
+                }
+                "channel"
+            } else {
+                "none"
+            }
+        }
+        Err(e) => {
+            tracing::error!("CI hook: comment delivery failed: {e:#}");
+            let err = serde_json::json!({
+                "error": format!("Triage produced but comment delivery failed: {e}"),
+                "triage": triage,
+            });
+            return (StatusCode::BAD_GATEWAY, Json(err));
+        }
+    };
+
+    let body = serde_json::json!({"status": "ok", "delivered": delivered, "triage": triage});
+    (StatusCode::OK, Json(body))
+}
+
+/// POST /hooks/github — GitHub issue auto-triage hook.
+///
+/// Receives GitHub `issues` webhooks for repositories on the
+/// `[integrations.github] allowed_repos` allowlist (empty allowlist denies
+/// all), asks the agent for a triage note, and posts it back as an issue
+/// comment when a token is configured.
+///
+/// Auth: when `[integrations.github] webhook_secret` is set, GitHub's
+/// `X-Hub-Signature-256` HMAC is verified; otherwise the standard `/webhook`
+/// auth layers apply.
+async fn handle_github_event(
+    State(state): State<AppState>,
+    ConnectInfo(peer_addr): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> impl IntoResponse {
+    let github = { state.config.lock().integrations.github.clone() };
+    let Some(github) = github.filter(|settings| settings.enabled) else {
+        return (
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({
+                "error": "GitHub integration not enabled. Configure [integrations.github] in config.toml"
+            })),
+        );
+    };
+
+    // ── Auth: GitHub HMAC when configured, /webhook stack otherwise ──
+    if let Some(ref secret) = github.webhook_secret {
+        let signature = headers
+            .get("X-Hub-Signature-256")
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or("");
+        if !verify_whatsapp_signature(secret, &body, signature) {
+            tracing::warn!("GitHub hook: rejected request — webhook secret verification failed");
+            return (
+                StatusCode::UNAUTHORIZED,
+                Json(serde_json::json!({"error": "Invalid webhook signature"})),
+            );
+        }
+    } else if let Err(rejection) = authorize_webhook(&state, Some(peer_addr), &headers) {
+        return rejection;
+    }
+
+    let Ok(payload) = serde_json::from_slice::<serde_json::Value>(&body) else {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({"error": "Invalid JSON payload"})),
+        );
+    };
+
+    let github_event = headers.get("X-GitHub-Event").and_then(|v| v.to_str().ok());
+
+    let issue = match crate::integrations::github::parse_issue_event(github_event, &payload) {
+        Ok(Some(issue)) => issue,
+        Ok(None) => {
+            return (
+                StatusCode::OK,
+                Json(serde_json::json!({"status": "ignored"})),
+            );
+        }
+        Err(e) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({"error": e.to_string()})),
+            );
+        }
+    };
+
+    // ── Repository allowlist: automation is opt-in per repo ──
+    if !crate::integrations::github::repo_allowed(&github.allowed_repos, &issue.repo) {
+        tracing::warn!(
+            "GitHub hook: rejected event for {} — not on [integrations.github] allowed_repos",
+            issue.repo
+        );
+        return (
+            StatusCode::FORBIDDEN,
+            Json(serde_json::json!({
+                "error": "Repository not on [integrations.github] allowed_repos"
+            })),
+        );
+    }
+
+    tracing::info!(
+        "GitHub hook: triaging issue {}#{}",
+        issue.repo,
+        issue.number
+    );
+
+    let triage = match state
+        .provider
+        .chat_with_system(
+            Some(crate::integrations::github::ISSUE_TRIAGE_SYSTEM_PROMPT),
+            &crate::integrations::github::build_issue_triage_prompt(&issue),
+            &state.model,
+            state.temperature,
+        )
+        .await
+    {
+        Ok(triage) => triage,
+        Err(e) => {
+            tracing::error!(
+                "GitHub hook provider error: {}",
+                providers::sanitize_api_error(&e.to_string())
+            );
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": "LLM request failed"})),
+            );
+        }
+    };
+
+    let delivered = match github.token.as_deref().map(str::trim) {
+        Some(token) if !token.is_empty() => {
+            let report = format!("## Issue triage\n\n{triage}");
+            if let Err(e) = crate::integrations::github::post_issue_comment(
+                token,
+                &issue.repo,
+                issue.number,
+                &report,
+            )
+            .await
+            {
+                tracing::error!("GitHub hook: comment delivery failed: {e:#}");
+                let err = serde_json::json!({
+                    "error": format!("Triage produced but comment delivery failed: {e}"),
+                    "triage": triage,
+                });
+                return (StatusCode::BAD_GATEWAY, Json(err));
+            }
+            "comment"
+        }
+        _ => "none",
+    };
+
+    let body = serde_json::json!({"status": "ok", "delivered": delivered, "triage": triage});
+    (StatusCode::OK, Json(body))
+}
+
+/// POST /hooks/pager — on-call incident hook.
+///
+/// Receives PagerDuty v3 or Opsgenie alert webhooks, asks the agent for a
+/// first-responder brief (blast radius, what to check first, actionable vs
+/// noise), and delivers it to the configured `[pager]` channel.
+///
+/// Auth: when `[pager] webhook_secret` is set, PagerDuty's
+/// `X-PagerDuty-Signature` HMAC is verified when present, otherwise a shared
+/// `X-Webhook-Token` header (Opsgenie custom header) is compared; otherwise
+/// the standard `/webhook` auth layers apply.
+async fn handle_pager_incident(
+    State(state): State<AppState>,
+    ConnectInfo(peer_addr): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> impl IntoResponse {
+    let pager = { state.config.lock().pager.clone() };
+    if !pager.enabled {
+        return (
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({
+                "error": "Pager integration not enabled. Set [pager] enabled = true in config.toml"
+            })),
+        );
+    }
+
+    // ── Auth: pager-native secret when configured, /webhook stack otherwise ──
+    if let Some(ref secret) = pager.webhook_secret {
+        let authorized = if let Some(signature) = headers
+            .get("X-PagerDuty-Signature")
+            .and_then(|v| v.to_str().ok())
+        {
+            crate::pager::verify_pagerduty_signature(secret, &body, signature)
+        } else {
+            headers
+                .get("X-Webhook-Token")
+                .and_then(|v| v.to_str().ok())
+                .is_some_and(|token| constant_time_eq(token, secret))
+        };
+        if !authorized {
+            tracing::warn!("Pager hook: rejected request — webhook secret verification failed");
+            return (
+                StatusCode::UNAUTHORIZED,
+                Json(serde_json::json!({"error": "Invalid webhook signature"})),
+            );
+        }
+    } else if let Err(rejection) = authorize_webhook(&state, Some(peer_addr), &headers) {
+        return rejection;
+    }
+
+    let Ok(payload) = serde_json::from_slice::<serde_json::Value>(&body) else {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({"error": "Invalid JSON payload"})),
+        );
+    };
+
+    let incident = match crate::pager::parse_event(&payload) {
+        Ok(Some(incident)) => incident,
+        Ok(None) => {
+            return (
+                StatusCode::OK,
+                Json(serde_json::json!({"status": "ignored"})),
+            );
+        }
+        Err(e) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({"error": e.to_string()})),
+            );
+        }
+    };
+
+    tracing::info!("Pager hook: briefing {}", incident.describe());
+
+    let brief = match state
+        .provider
+        .chat_with_system(
+            Some(crate::pager::RESPONDER_SYSTEM_PROMPT),
+            &crate::pager::build_responder_prompt(&incident),
+            &state.model,
+            state.temperature,
+        )
+        .await
+    {
+        Ok(brief) => brief,
+        Err(e) => {
+            tracing::error!(
+                "Pager hook provider error: {}",
+                providers::sanitize_api_error(&e.to_string())
+            );
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": "LLM request failed"})),
+            );
+        }
+    };
+
+    let report = format!(
+        "## Incident brief\n\n{}\n\n{brief}\n\nIncident id: `{}`",
+        incident.describe(),
+        incident.id()
+    );
+
+    let delivered =
+        if let (Some(channel), Some(target)) = (pager.channel.as_deref(), pager.to.as_deref()) {
+            let config = { state.config.lock().clone() };
+            if let Err(e) = crate::channels::send_once(&config, channel, target, &report).await {
+                tracing::error!("Pager hook: channel delivery failed: {e}");
+                let err = serde_json::json!({
+                    "error": format!("Brief produced but channel delivery failed: {e}"),
+                    "brief": brief,
+                });
+                return (StatusCode::BAD_GATEWAY, Json(err));
+            }
+            "channel"
+        } else {
+            "none"
+        };
+
+    let body = serde_json::json!({"status": "ok", "delivered": delivered, "brief": brief});
+    (StatusCode::OK, Json(body))
+}
+
 /// `WhatsApp` verification query params
 #[derive(serde::Deserialize)]
 pub struct WhatsAppVerifyQuery {
@@ -1264,6 +2123,42 @@ mod tests {
         assert_clone::<AppState>();
     }
 
+    #[tokio::test]
+    async fn healthz_endpoint_reports_liveness() {
+        let response = handle_healthz().await.into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["status"], "ok");
+        assert!(json["pid"].as_u64().is_some());
+    }
+
+    #[test]
+    fn failing_components_lists_only_errored_entries() {
+        let component = |status: &str| crate::health::ComponentHealth {
+            status: status.to_string(),
+            updated_at: String::new(),
+            last_ok: None,
+            last_error: None,
+            restart_count: 0,
+        };
+        let mut components = std::collections::BTreeMap::new();
+        components.insert("gateway".to_string(), component("ok"));
+        components.insert("channel:telegram".to_string(), component("error"));
+        components.insert("scheduler".to_string(), component("starting"));
+        let snapshot = crate::health::HealthSnapshot {
+            pid: 1,
+            updated_at: String::new(),
+            uptime_seconds: 0,
+            components,
+            channel_messages: std::collections::BTreeMap::new(),
+            tool_metrics: std::collections::BTreeMap::new(),
+        };
+
+        assert_eq!(failing_components(&snapshot), vec!["channel:telegram"]);
+    }
+
     #[tokio::test]
     async fn metrics_endpoint_returns_hint_when_prometheus_is_disabled() {
         let state = AppState {
@@ -1283,6 +2178,7 @@ mod tests {
             linq: None,
             linq_signing_secret: None,
             observer: Arc::new(crate::observability::NoopObserver),
+            oidc: None,
         };
 
         let response = handle_metrics(State(state)).await.into_response();
@@ -1326,6 +2222,7 @@ mod tests {
             linq: None,
             linq_signing_secret: None,
             observer,
+            oidc: None,
         };
 
         let response = handle_metrics(State(state)).await.into_response();
@@ -1686,6 +2583,7 @@ mod tests {
             linq: None,
             linq_signing_secret: None,
             observer: Arc::new(crate::observability::NoopObserver),
+            oidc: None,
         };
 
         let mut headers = HeaderMap::new();
@@ -1744,6 +2642,7 @@ mod tests {
             linq: None,
             linq_signing_secret: None,
             observer: Arc::new(crate::observability::NoopObserver),
+            oidc: None,
         };
 
         let headers = HeaderMap::new();
@@ -1814,6 +2713,7 @@ mod tests {
             linq: None,
             linq_signing_secret: None,
             observer: Arc::new(crate::observability::NoopObserver),
+            oidc: None,
         };
 
         let response = handle_webhook(
@@ -1856,6 +2756,7 @@ mod tests {
             linq: None,
             linq_signing_secret: None,
             observer: Arc::new(crate::observability::NoopObserver),
+            oidc: None,
         };
 
         let mut headers = HeaderMap::new();
@@ -1903,6 +2804,7 @@ mod tests {
             linq: None,
             linq_signing_secret: None,
             observer: Arc::new(crate::observability::NoopObserver),
+            oidc: None,
         };
 
         let mut headers = HeaderMap::new();
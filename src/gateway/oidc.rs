@@ -0,0 +1,588 @@
+//! OIDC login for gateway dashboard routes (`[gateway.oidc]` section).
+//!
+//! Generic OpenID Connect authorization-code flow with PKCE (S256), so a
+//! tunnel-exposed dashboard does not rely solely on static bearer tokens.
+//! Sessions are server-side (random token in an `HttpOnly` cookie, only the
+//! SHA-256 hash kept in memory) and deny-by-default: an identity must be
+//! listed in `allowed_users` to sign in, with `admin_users` mapped to the
+//! `admin` role and everyone else to `viewer`.
+//!
+//! ID-token claims are read from the token-endpoint response, which is
+//! fetched directly from the issuer over TLS; issuer, audience, expiry, and
+//! nonce are all validated before a session is created (OIDC Core 3.1.3.7).
+
+use crate::config::GatewayOidcConfig;
+use anyhow::{bail, Context, Result};
+use base64::Engine as _;
+use parking_lot::Mutex;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Session cookie name.
+pub const SESSION_COOKIE: &str = "zeroclaw_session";
+/// Pending logins expire after this long.
+const PENDING_LOGIN_TTL_SECS: u64 = 600;
+/// Bound on concurrent pending logins to cap memory usage.
+const MAX_PENDING_LOGINS: usize = 128;
+/// Bound on concurrent sessions to cap memory usage.
+const MAX_SESSIONS: usize = 1024;
+
+/// Provider endpoints resolved from `{issuer}/.well-known/openid-configuration`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DiscoveryDocument {
+    pub issuer: String,
+    pub authorization_endpoint: String,
+    pub token_endpoint: String,
+}
+
+/// An authenticated dashboard session.
+#[derive(Debug, Clone)]
+pub struct Session {
+    /// Email when present, otherwise the OIDC subject.
+    pub identity: String,
+    /// `admin` or `viewer`.
+    pub role: String,
+    expires_at: Instant,
+}
+
+/// A login that has been redirected to the provider but not completed.
+struct PendingLogin {
+    pkce_verifier: String,
+    nonce: String,
+    created_at: Instant,
+}
+
+/// OIDC runtime state shared by the gateway's `/auth/*` handlers.
+pub struct OidcState {
+    config: GatewayOidcConfig,
+    http: reqwest::Client,
+    discovery: tokio::sync::OnceCell<DiscoveryDocument>,
+    /// Keyed by the opaque `state` parameter.
+    pending: Mutex<HashMap<String, PendingLogin>>,
+    /// Keyed by SHA-256 hash of the session token.
+    sessions: Mutex<HashMap<String, Session>>,
+}
+
+impl OidcState {
+    /// Validate config and build the runtime state. Fails fast on unusable
+    /// config so misconfiguration surfaces at gateway startup, not first login.
+    pub fn new(config: GatewayOidcConfig) -> Result<Self> {
+        if config.issuer.is_empty() {
+            bail!("[gateway.oidc] issuer is required");
+        }
+        if !config.issuer.starts_with("https://") && !is_loopback_url(&config.issuer) {
+            bail!(
+                "[gateway.oidc] issuer must use https (got: {})",
+                config.issuer
+            );
+        }
+        if config.client_id.is_empty() {
+            bail!("[gateway.oidc] client_id is required");
+        }
+        if config.redirect_base_url.is_empty() {
+            bail!("[gateway.oidc] redirect_base_url is required (e.g. the tunnel URL)");
+        }
+        if config.allowed_users.is_empty() {
+            bail!("[gateway.oidc] allowed_users is empty — nobody could sign in");
+        }
+        Ok(Self {
+            config,
+            http: crate::config::build_runtime_proxy_client_with_timeouts("gateway.oidc", 30, 10),
+            discovery: tokio::sync::OnceCell::new(),
+            pending: Mutex::new(HashMap::new()),
+            sessions: Mutex::new(HashMap::new()),
+        })
+    }
+
+    fn redirect_uri(&self) -> String {
+        format!(
+            "{}/auth/callback",
+            self.config.redirect_base_url.trim_end_matches('/')
+        )
+    }
+
+    async fn discovery(&self) -> Result<&DiscoveryDocument> {
+        self.discovery
+            .get_or_try_init(|| async {
+                let url = format!(
+                    "{}/.well-known/openid-configuration",
+                    self.config.issuer.trim_end_matches('/')
+                );
+                let doc: DiscoveryDocument = self
+                    .http
+                    .get(&url)
+                    .send()
+                    .await
+                    .with_context(|| format!("OIDC discovery request failed: {url}"))?
+                    .error_for_status()
+                    .context("OIDC discovery returned an error status")?
+                    .json()
+                    .await
+                    .context("OIDC discovery returned invalid JSON")?;
+                if doc.issuer.trim_end_matches('/') != self.config.issuer.trim_end_matches('/') {
+                    bail!(
+                        "OIDC discovery issuer mismatch: configured {}, document says {}",
+                        self.config.issuer,
+                        doc.issuer
+                    );
+                }
+                Ok(doc)
+            })
+            .await
+    }
+
+    /// Start a login: store a pending PKCE/nonce pair and return the
+    /// provider authorization URL to redirect the browser to.
+    pub async fn begin_login(&self) -> Result<String> {
+        let discovery = self.discovery().await?;
+        let state = random_token();
+        let nonce = random_token();
+        let (verifier, challenge) = pkce_pair();
+
+        {
+            let mut pending = self.pending.lock();
+            prune_pending(&mut pending);
+            if pending.len() >= MAX_PENDING_LOGINS {
+                bail!("too many pending logins; try again shortly");
+            }
+            pending.insert(
+                state.clone(),
+                PendingLogin {
+                    pkce_verifier: verifier,
+                    nonce: nonce.clone(),
+                    created_at: Instant::now(),
+                },
+            );
+        }
+
+        let mut url = reqwest::Url::parse(&discovery.authorization_endpoint)
+            .context("OIDC authorization_endpoint is not a valid URL")?;
+        url.query_pairs_mut()
+            .append_pair("response_type", "code")
+            .append_pair("client_id", &self.config.client_id)
+            .append_pair("redirect_uri", &self.redirect_uri())
+            .append_pair("scope", &self.config.scopes.join(" "))
+            .append_pair("state", &state)
+            .append_pair("nonce", &nonce)
+            .append_pair("code_challenge", &challenge)
+            .append_pair("code_challenge_method", "S256");
+        Ok(url.into())
+    }
+
+    /// Complete a login from the provider callback: exchange the code,
+    /// validate the ID-token claims, map the role, and mint a session.
+    ///
+    /// Returns the plaintext session token to set as the cookie value.
+    pub async fn complete_login(&self, state: &str, code: &str) -> Result<(String, Session)> {
+        let pending = self
+            .pending
+            .lock()
+            .remove(state)
+            .context("unknown or expired login state")?;
+        if pending.created_at.elapsed() > Duration::from_secs(PENDING_LOGIN_TTL_SECS) {
+            bail!("login attempt expired; start again");
+        }
+
+        let discovery = self.discovery().await?;
+        let redirect_uri = self.redirect_uri();
+        let mut form: Vec<(&str, &str)> = vec![
+            ("grant_type", "authorization_code"),
+            ("code", code),
+            ("redirect_uri", &redirect_uri),
+            ("client_id", &self.config.client_id),
+            ("code_verifier", &pending.pkce_verifier),
+        ];
+        if let Some(secret) = self.config.client_secret.as_deref() {
+            form.push(("client_secret", secret));
+        }
+
+        #[derive(Deserialize)]
+        struct TokenResponse {
+            id_token: String,
+        }
+        let token: TokenResponse = self
+            .http
+            .post(&discovery.token_endpoint)
+            .form(&form)
+            .send()
+            .await
+            .context("OIDC token exchange request failed")?
+            .error_for_status()
+            .context("OIDC token exchange was rejected")?
+            .json()
+            .await
+            .context("OIDC token response missing id_token")?;
+
+        let claims = decode_jwt_claims(&token.id_token)?;
+        let identity = validate_claims(
+            &claims,
+            &self.config.issuer,
+            &self.config.client_id,
+            &pending.nonce,
+            chrono::Utc::now().timestamp(),
+        )?;
+        let role = self.map_role(&identity)?;
+
+        let session_token = random_token();
+        let session = Session {
+            identity,
+            role,
+            expires_at: Instant::now()
+                + Duration::from_secs(self.config.session_ttl_minutes.max(1) * 60),
+        };
+        {
+            let mut sessions = self.sessions.lock();
+            sessions.retain(|_, s| s.expires_at > Instant::now());
+            if sessions.len() >= MAX_SESSIONS {
+                bail!("session limit reached");
+            }
+            sessions.insert(hash_session_token(&session_token), session.clone());
+        }
+        Ok((session_token, session))
+    }
+
+    /// Deny-by-default role mapping: identity must be allowlisted.
+    fn map_role(&self, identity: &str) -> Result<String> {
+        if !self
+            .config
+            .allowed_users
+            .iter()
+            .any(|u| u.eq_ignore_ascii_case(identity))
+        {
+            bail!("identity is not in [gateway.oidc] allowed_users");
+        }
+        let role = if self
+            .config
+            .admin_users
+            .iter()
+            .any(|u| u.eq_ignore_ascii_case(identity))
+        {
+            "admin"
+        } else {
+            "viewer"
+        };
+        Ok(role.to_string())
+    }
+
+    /// Build the `Set-Cookie` value for a freshly minted session token.
+    pub fn session_cookie(&self, token: &str) -> String {
+        format!(
+            "{SESSION_COOKIE}={token}; Path=/; HttpOnly; Secure; SameSite=Lax; Max-Age={}",
+            self.config.session_ttl_minutes.max(1) * 60
+        )
+    }
+
+    /// Build the `Set-Cookie` value that clears the session cookie.
+    pub fn clear_session_cookie() -> String {
+        format!("{SESSION_COOKIE}=; Path=/; HttpOnly; Secure; SameSite=Lax; Max-Age=0")
+    }
+
+    /// Resolve the session for a request `Cookie` header, if valid.
+    pub fn authorize_cookie(&self, cookie_header: Option<&str>) -> Option<Session> {
+        let token = session_cookie_value(cookie_header?)?;
+        let key = hash_session_token(token);
+        let mut sessions = self.sessions.lock();
+        match sessions.get(&key) {
+            Some(session) if session.expires_at > Instant::now() => Some(session.clone()),
+            Some(_) => {
+                sessions.remove(&key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    /// Drop the session referenced by the request `Cookie` header, if any.
+    pub fn logout(&self, cookie_header: Option<&str>) {
+        if let Some(token) = cookie_header.and_then(session_cookie_value) {
+            self.sessions.lock().remove(&hash_session_token(token));
+        }
+    }
+}
+
+fn is_loopback_url(url: &str) -> bool {
+    reqwest::Url::parse(url)
+        .ok()
+        .and_then(|u| u.host_str().map(str::to_owned))
+        .is_some_and(|h| h == "localhost" || h == "127.0.0.1" || h == "[::1]")
+}
+
+fn prune_pending(pending: &mut HashMap<String, PendingLogin>) {
+    pending.retain(|_, p| p.created_at.elapsed() < Duration::from_secs(PENDING_LOGIN_TTL_SECS));
+}
+
+/// 256-bit random URL-safe token (CSPRNG-backed).
+fn random_token() -> String {
+    let bytes: [u8; 32] = rand::random();
+    base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(bytes)
+}
+
+fn hash_session_token(token: &str) -> String {
+    format!("{:x}", Sha256::digest(token.as_bytes()))
+}
+
+/// RFC 7636 PKCE pair: random verifier and its S256 challenge.
+fn pkce_pair() -> (String, String) {
+    let verifier = random_token();
+    let challenge = pkce_challenge(&verifier);
+    (verifier, challenge)
+}
+
+fn pkce_challenge(verifier: &str) -> String {
+    base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(Sha256::digest(verifier.as_bytes()))
+}
+
+/// Extract the session token from a `Cookie` header value.
+fn session_cookie_value(header: &str) -> Option<&str> {
+    header.split(';').find_map(|pair| {
+        let (name, value) = pair.trim().split_once('=')?;
+        (name == SESSION_COOKIE).then_some(value)
+    })
+}
+
+/// Decode the payload of a JWT without verifying its signature.
+///
+/// Only safe because the token is received directly from the issuer's token
+/// endpoint over TLS; claims are still validated by [`validate_claims`].
+fn decode_jwt_claims(jwt: &str) -> Result<serde_json::Value> {
+    let payload = jwt
+        .split('.')
+        .nth(1)
+        .context("ID token is not a JWT (missing payload segment)")?;
+    let bytes = base64::engine::general_purpose::URL_SAFE_NO_PAD
+        .decode(payload)
+        .context("ID token payload is not valid base64url")?;
+    serde_json::from_slice(&bytes).context("ID token payload is not valid JSON")
+}
+
+/// Validate issuer, audience, expiry, and nonce; return the login identity
+/// (email claim when present, otherwise `sub`).
+fn validate_claims(
+    claims: &serde_json::Value,
+    issuer: &str,
+    client_id: &str,
+    expected_nonce: &str,
+    now_epoch_secs: i64,
+) -> Result<String> {
+    let iss = claims.get("iss").and_then(|v| v.as_str()).unwrap_or("");
+    if iss.trim_end_matches('/') != issuer.trim_end_matches('/') {
+        bail!("ID token issuer mismatch");
+    }
+
+    let aud_ok = match claims.get("aud") {
+        Some(serde_json::Value::String(aud)) => aud == client_id,
+        Some(serde_json::Value::Array(auds)) => auds.iter().any(|a| a.as_str() == Some(client_id)),
+        _ => false,
+    };
+    if !aud_ok {
+        bail!("ID token audience does not include this client");
+    }
+
+    let exp = claims.get("exp").and_then(serde_json::Value::as_i64);
+    if exp.is_none_or(|exp| exp <= now_epoch_secs) {
+        bail!("ID token is expired");
+    }
+
+    if claims.get("nonce").and_then(|v| v.as_str()) != Some(expected_nonce) {
+        bail!("ID token nonce mismatch");
+    }
+
+    let identity = claims
+        .get("email")
+        .and_then(|v| v.as_str())
+        .or_else(|| claims.get("sub").and_then(|v| v.as_str()))
+        .context("ID token has neither email nor sub claim")?;
+    Ok(identity.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_oidc_config() -> GatewayOidcConfig {
+        GatewayOidcConfig {
+            enabled: true,
+            issuer: "https://id.example.com".into(),
+            client_id: "zeroclaw-dashboard".into(),
+            redirect_base_url: "https://tunnel.example.com".into(),
+            allowed_users: vec!["operator@example.com".into(), "viewer@example.com".into()],
+            admin_users: vec!["operator@example.com".into()],
+            ..GatewayOidcConfig::default()
+        }
+    }
+
+    fn claims(issuer: &str, aud: &str, exp: i64, nonce: &str) -> serde_json::Value {
+        serde_json::json!({
+            "iss": issuer,
+            "aud": aud,
+            "exp": exp,
+            "nonce": nonce,
+            "sub": "subject-1",
+            "email": "operator@example.com",
+        })
+    }
+
+    #[test]
+    fn new_rejects_unusable_config() {
+        assert!(OidcState::new(GatewayOidcConfig::default()).is_err());
+
+        let mut config = test_oidc_config();
+        config.issuer = "http://id.example.com".into();
+        assert!(OidcState::new(config).is_err());
+
+        let mut config = test_oidc_config();
+        config.allowed_users.clear();
+        assert!(OidcState::new(config).is_err());
+
+        assert!(OidcState::new(test_oidc_config()).is_ok());
+    }
+
+    #[test]
+    fn pkce_challenge_matches_rfc7636_vector() {
+        // Appendix B of RFC 7636.
+        assert_eq!(
+            pkce_challenge("dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk"),
+            "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM"
+        );
+    }
+
+    #[test]
+    fn pkce_pair_is_fresh_per_login() {
+        let (v1, c1) = pkce_pair();
+        let (v2, c2) = pkce_pair();
+        assert_ne!(v1, v2);
+        assert_ne!(c1, c2);
+        assert_eq!(pkce_challenge(&v1), c1);
+    }
+
+    #[test]
+    fn validate_claims_accepts_valid_token() {
+        let claims = claims("https://id.example.com", "zeroclaw-dashboard", 2000, "n1");
+        let identity = validate_claims(
+            &claims,
+            "https://id.example.com",
+            "zeroclaw-dashboard",
+            "n1",
+            1000,
+        )
+        .unwrap();
+        assert_eq!(identity, "operator@example.com");
+    }
+
+    #[test]
+    fn validate_claims_rejects_tampered_tokens() {
+        let good = claims("https://id.example.com", "zeroclaw-dashboard", 2000, "n1");
+        let check = |c: &serde_json::Value| {
+            validate_claims(
+                c,
+                "https://id.example.com",
+                "zeroclaw-dashboard",
+                "n1",
+                1000,
+            )
+        };
+        assert!(check(&good).is_ok());
+
+        let bad_iss = claims("https://evil.example.com", "zeroclaw-dashboard", 2000, "n1");
+        assert!(check(&bad_iss).is_err());
+
+        let bad_aud = claims("https://id.example.com", "other-client", 2000, "n1");
+        assert!(check(&bad_aud).is_err());
+
+        let expired = claims("https://id.example.com", "zeroclaw-dashboard", 999, "n1");
+        assert!(check(&expired).is_err());
+
+        let bad_nonce = claims("https://id.example.com", "zeroclaw-dashboard", 2000, "n2");
+        assert!(check(&bad_nonce).is_err());
+    }
+
+    #[test]
+    fn validate_claims_accepts_audience_arrays() {
+        let mut claims = claims("https://id.example.com", "ignored", 2000, "n1");
+        claims["aud"] = serde_json::json!(["first-client", "zeroclaw-dashboard"]);
+        assert!(validate_claims(
+            &claims,
+            "https://id.example.com",
+            "zeroclaw-dashboard",
+            "n1",
+            1000
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn map_role_is_deny_by_default() {
+        let state = OidcState::new(test_oidc_config()).unwrap();
+        assert_eq!(state.map_role("operator@example.com").unwrap(), "admin");
+        assert_eq!(state.map_role("viewer@example.com").unwrap(), "viewer");
+        assert!(state.map_role("stranger@example.com").is_err());
+    }
+
+    #[test]
+    fn decode_jwt_claims_reads_payload_only() {
+        let payload = base64::engine::general_purpose::URL_SAFE_NO_PAD
+            .encode(serde_json::json!({"sub": "subject-1"}).to_string());
+        let jwt = format!("header.{payload}.signature");
+        let claims = decode_jwt_claims(&jwt).unwrap();
+        assert_eq!(claims["sub"], "subject-1");
+
+        assert!(decode_jwt_claims("not-a-jwt").is_err());
+    }
+
+    #[test]
+    fn session_cookie_value_parses_cookie_header() {
+        assert_eq!(
+            session_cookie_value("zeroclaw_session=abc; other=1"),
+            Some("abc")
+        );
+        assert_eq!(
+            session_cookie_value("other=1; zeroclaw_session=abc"),
+            Some("abc")
+        );
+        assert_eq!(session_cookie_value("other=1"), None);
+    }
+
+    #[test]
+    fn sessions_expire_and_logout_revokes() {
+        let mut config = test_oidc_config();
+        config.session_ttl_minutes = 1;
+        let state = OidcState::new(config).unwrap();
+
+        let token = random_token();
+        state.sessions.lock().insert(
+            hash_session_token(&token),
+            Session {
+                identity: "operator@example.com".into(),
+                role: "admin".into(),
+                expires_at: Instant::now() + Duration::from_secs(60),
+            },
+        );
+        let header = format!("{SESSION_COOKIE}={token}");
+        assert!(state.authorize_cookie(Some(&header)).is_some());
+        assert!(state
+            .authorize_cookie(Some("zeroclaw_session=wrong"))
+            .is_none());
+
+        state.logout(Some(&header));
+        assert!(state.authorize_cookie(Some(&header)).is_none());
+    }
+
+    #[test]
+    fn expired_sessions_are_rejected_and_removed() {
+        let state = OidcState::new(test_oidc_config()).unwrap();
+        let token = random_token();
+        state.sessions.lock().insert(
+            hash_session_token(&token),
+            Session {
+                identity: "viewer@example.com".into(),
+                role: "viewer".into(),
+                expires_at: Instant::now() - Duration::from_secs(1),
+            },
+        );
+        let header = format!("{SESSION_COOKIE}={token}");
+        assert!(state.authorize_cookie(Some(&header)).is_none());
+        assert!(state.sessions.lock().is_empty());
+    }
+}
@@ -3,6 +3,7 @@
 //! See `docs/hardware-peripherals-design.md` for the full design.
 
 pub mod registry;
+pub mod sensors;
 
 #[cfg(all(
     feature = "hardware",
@@ -102,8 +103,8 @@ pub fn config_from_wizard_choice(choice: usize, devices: &[DiscoveredDevice]) ->
 }
 
 /// Handle `zeroclaw hardware` subcommands.
-#[allow(clippy::module_name_repetitions)]
-pub fn handle_command(cmd: crate::HardwareCommands, _config: &Config) -> Result<()> {
+#[allow(clippy::module_name_repetitions, clippy::unused_async)]
+pub async fn handle_command(cmd: crate::HardwareCommands, _config: &Config) -> Result<()> {
     #[cfg(not(feature = "hardware"))]
     {
         let _ = &cmd;
@@ -129,7 +130,9 @@ pub fn handle_command(cmd: crate::HardwareCommands, _config: &Config) -> Result<
     ))]
     match cmd {
         crate::HardwareCommands::Discover => run_discover(),
-        crate::HardwareCommands::Introspect { path } => run_introspect(&path),
+        crate::HardwareCommands::Introspect { path, scan_i2c } => {
+            run_introspect(&path, scan_i2c).await
+        }
         crate::HardwareCommands::Info { chip } => run_info(&chip),
     }
 }
@@ -169,7 +172,7 @@ fn run_discover() -> Result<()> {
     feature = "hardware",
     any(target_os = "linux", target_os = "macos", target_os = "windows")
 ))]
-fn run_introspect(path: &str) -> Result<()> {
+async fn run_introspect(path: &str, scan_i2c: bool) -> Result<()> {
     let result = introspect::introspect_device(path)?;
 
     println!("Device at {}:", result.path);
@@ -187,6 +190,43 @@ fn run_introspect(path: &str) -> Result<()> {
     }
     println!("  Memory map  {}", result.memory_map_note);
 
+    if scan_i2c {
+        println!();
+        run_i2c_scan(path).await?;
+    }
+
+    Ok(())
+}
+
+/// Probe the bridge board's I2C bus and identify known sensors.
+#[cfg(all(
+    feature = "hardware",
+    any(target_os = "linux", target_os = "macos", target_os = "windows")
+))]
+async fn run_i2c_scan(path: &str) -> Result<()> {
+    let board = crate::config::PeripheralBoardConfig {
+        board: "i2c-bridge".into(),
+        path: Some(path.to_string()),
+        ..Default::default()
+    };
+    let bridge = crate::peripherals::serial::SerialPeripheral::connect(&board).await?;
+    let addresses = bridge.transport().i2c_scan().await?;
+
+    if addresses.is_empty() {
+        println!("I2C scan: no devices found on the bus.");
+        return Ok(());
+    }
+
+    println!("I2C scan found {} device(s):", addresses.len());
+    for addr in &addresses {
+        match sensors::lookup_sensor(*addr) {
+            Some(sensor) => println!("  0x{:02X}  {}  {}", addr, sensor.name, sensor.description),
+            None => println!("  0x{addr:02X}  (unknown device)"),
+        }
+    }
+    println!();
+    println!("Suggested config.toml entry:");
+    print!("{}", sensors::config_snippet(path, &addresses));
     Ok(())
 }
 
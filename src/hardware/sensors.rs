@@ -0,0 +1,144 @@
+//! I2C sensor registry — maps I2C addresses to known sensor modules.
+//!
+//! Used by `hardware introspect --scan-i2c` to identify devices found on a
+//! bridge board's I2C bus and suggest ready-to-use peripheral config.
+
+/// Information about a known I2C sensor or module.
+#[derive(Debug, Clone)]
+pub struct SensorInfo {
+    pub name: &'static str,
+    /// I2C addresses the part can appear at (7-bit).
+    pub addresses: &'static [u8],
+    pub description: &'static str,
+}
+
+/// Known I2C address to sensor mappings.
+const KNOWN_SENSORS: &[SensorInfo] = &[
+    SensorInfo {
+        name: "bme280",
+        addresses: &[0x76, 0x77],
+        description: "temperature/humidity/pressure sensor",
+    },
+    SensorInfo {
+        name: "mpu6050",
+        addresses: &[0x68, 0x69],
+        description: "accelerometer/gyroscope",
+    },
+    SensorInfo {
+        name: "ssd1306",
+        addresses: &[0x3C, 0x3D],
+        description: "OLED display",
+    },
+];
+
+/// Look up a sensor by its I2C address.
+pub fn lookup_sensor(address: u8) -> Option<&'static SensorInfo> {
+    KNOWN_SENSORS
+        .iter()
+        .find(|s| s.addresses.contains(&address))
+}
+
+/// Return all known sensor entries.
+pub fn known_sensors() -> &'static [SensorInfo] {
+    KNOWN_SENSORS
+}
+
+/// Parse the firmware `i2c_scan` result into addresses.
+///
+/// Accepts a JSON array of numbers (`[118, 60]`) or hex strings
+/// (`["0x76", "0x3C"]`); anything else yields an empty list.
+pub fn parse_scan_addresses(output: &str) -> Vec<u8> {
+    let Ok(value) = serde_json::from_str::<serde_json::Value>(output) else {
+        return Vec::new();
+    };
+    let Some(items) = value.as_array() else {
+        return Vec::new();
+    };
+    items
+        .iter()
+        .filter_map(|item| {
+            if let Some(n) = item.as_u64() {
+                return u8::try_from(n).ok();
+            }
+            let s = item.as_str()?;
+            let hex = s.strip_prefix("0x").or_else(|| s.strip_prefix("0X"))?;
+            u8::from_str_radix(hex, 16).ok()
+        })
+        .filter(|addr| (0x08..=0x77).contains(addr))
+        .collect()
+}
+
+/// Render a ready-to-use `[[peripherals.boards]]` snippet for a bridge board
+/// with the detected sensors annotated.
+pub fn config_snippet(path: &str, addresses: &[u8]) -> String {
+    use std::fmt::Write as _;
+
+    let mut out = String::from("[[peripherals.boards]]\n");
+    out.push_str("board = \"i2c-bridge\"\n");
+    out.push_str("transport = \"serial\"\n");
+    let _ = writeln!(out, "path = \"{path}\"");
+    for addr in addresses {
+        match lookup_sensor(*addr) {
+            Some(sensor) => {
+                let _ = writeln!(
+                    out,
+                    "# detected: {} @ 0x{:02X} ({})",
+                    sensor.name, addr, sensor.description
+                );
+            }
+            None => {
+                let _ = writeln!(out, "# detected: unknown device @ 0x{addr:02X}");
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lookup_bme280_by_either_address() {
+        assert_eq!(lookup_sensor(0x76).unwrap().name, "bme280");
+        assert_eq!(lookup_sensor(0x77).unwrap().name, "bme280");
+    }
+
+    #[test]
+    fn lookup_unknown_address_returns_none() {
+        assert!(lookup_sensor(0x00).is_none());
+        assert!(lookup_sensor(0x50).is_none());
+    }
+
+    #[test]
+    fn known_sensors_not_empty() {
+        assert!(!known_sensors().is_empty());
+    }
+
+    #[test]
+    fn parse_scan_accepts_numbers_and_hex_strings() {
+        assert_eq!(parse_scan_addresses("[118, 104]"), vec![0x76, 0x68]);
+        assert_eq!(
+            parse_scan_addresses(r#"["0x3C", "0x76"]"#),
+            vec![0x3C, 0x76]
+        );
+    }
+
+    #[test]
+    fn parse_scan_drops_invalid_and_reserved_addresses() {
+        // 0x00-0x07 and above 0x77 are reserved on the 7-bit I2C bus.
+        assert_eq!(parse_scan_addresses("[0, 3, 200, 118]"), vec![0x76]);
+        assert!(parse_scan_addresses("not json").is_empty());
+        assert!(parse_scan_addresses("{\"a\":1}").is_empty());
+    }
+
+    #[test]
+    fn config_snippet_annotates_detected_sensors() {
+        let snippet = config_snippet("/dev/ttyACM0", &[0x76, 0x3C, 0x50]);
+        assert!(snippet.contains("board = \"i2c-bridge\""));
+        assert!(snippet.contains("path = \"/dev/ttyACM0\""));
+        assert!(snippet.contains("bme280 @ 0x76"));
+        assert!(snippet.contains("ssd1306 @ 0x3C"));
+        assert!(snippet.contains("unknown device @ 0x50"));
+    }
+}
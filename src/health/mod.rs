@@ -3,7 +3,15 @@ use parking_lot::Mutex;
 use serde::Serialize;
 use std::collections::BTreeMap;
 use std::sync::OnceLock;
-use std::time::Instant;
+use std::time::{Duration, Instant};
+
+/// Consecutive failures after which a tool is demoted: the agent loop skips
+/// it with a notice instead of executing, so one broken integration does not
+/// degrade every run.
+const TOOL_DEMOTION_THRESHOLD: u32 = 3;
+
+/// How long a demoted tool stays disabled before it may run again.
+const TOOL_DEMOTION_COOLDOWN_SECS: i64 = 300;
 
 #[derive(Debug, Clone, Serialize)]
 pub struct ComponentHealth {
@@ -14,17 +22,36 @@ pub struct ComponentHealth {
     pub restart_count: u64,
 }
 
+/// Cumulative execution metrics for one tool since process start.
+#[derive(Debug, Clone, Serialize)]
+pub struct ToolHealth {
+    pub calls: u64,
+    pub failures: u64,
+    pub consecutive_failures: u32,
+    pub total_duration_ms: u64,
+    pub last_error: Option<String>,
+    /// RFC3339 instant until which the tool is demoted (skipped by the agent
+    /// loop); `None` when the tool is healthy.
+    pub demoted_until: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct HealthSnapshot {
     pub pid: u32,
     pub updated_at: String,
     pub uptime_seconds: u64,
     pub components: BTreeMap<String, ComponentHealth>,
+    /// Cumulative inbound message count per channel since daemon start.
+    pub channel_messages: BTreeMap<String, u64>,
+    /// Cumulative per-tool execution metrics since process start.
+    pub tool_metrics: BTreeMap<String, ToolHealth>,
 }
 
 struct HealthRegistry {
     started_at: Instant,
     components: Mutex<BTreeMap<String, ComponentHealth>>,
+    channel_messages: Mutex<BTreeMap<String, u64>>,
+    tool_metrics: Mutex<BTreeMap<String, ToolHealth>>,
 }
 
 static REGISTRY: OnceLock<HealthRegistry> = OnceLock::new();
@@ -33,6 +60,8 @@ fn registry() -> &'static HealthRegistry {
     REGISTRY.get_or_init(|| HealthRegistry {
         started_at: Instant::now(),
         components: Mutex::new(BTreeMap::new()),
+        channel_messages: Mutex::new(BTreeMap::new()),
+        tool_metrics: Mutex::new(BTreeMap::new()),
     })
 }
 
@@ -82,6 +111,63 @@ pub fn bump_component_restart(component: &str) {
     });
 }
 
+/// Count one inbound channel message (per-channel, since process start).
+pub fn bump_channel_message(channel: &str) {
+    let mut map = registry().channel_messages.lock();
+    let count = map.entry(channel.to_string()).or_insert(0);
+    *count = count.saturating_add(1);
+}
+
+/// Per-channel inbound message counts since process start.
+pub fn channel_message_counts() -> BTreeMap<String, u64> {
+    registry().channel_messages.lock().clone()
+}
+
+/// Record one tool execution: call count, failure count, duration, and last
+/// error. After [`TOOL_DEMOTION_THRESHOLD`] consecutive failures the tool is
+/// demoted for [`TOOL_DEMOTION_COOLDOWN_SECS`]; a success clears demotion.
+pub fn record_tool_call(tool: &str, success: bool, duration: Duration, error: Option<&str>) {
+    let mut map = registry().tool_metrics.lock();
+    let entry = map.entry(tool.to_string()).or_insert_with(|| ToolHealth {
+        calls: 0,
+        failures: 0,
+        consecutive_failures: 0,
+        total_duration_ms: 0,
+        last_error: None,
+        demoted_until: None,
+    });
+
+    entry.calls = entry.calls.saturating_add(1);
+    entry.total_duration_ms = entry
+        .total_duration_ms
+        .saturating_add(u64::try_from(duration.as_millis()).unwrap_or(u64::MAX));
+
+    if success {
+        entry.consecutive_failures = 0;
+        entry.demoted_until = None;
+    } else {
+        entry.failures = entry.failures.saturating_add(1);
+        entry.consecutive_failures = entry.consecutive_failures.saturating_add(1);
+        if let Some(err) = error {
+            entry.last_error = Some(err.to_string());
+        }
+        if entry.consecutive_failures >= TOOL_DEMOTION_THRESHOLD {
+            let until = Utc::now() + chrono::Duration::seconds(TOOL_DEMOTION_COOLDOWN_SECS);
+            entry.demoted_until = Some(until.to_rfc3339());
+        }
+    }
+}
+
+/// Time left until a demoted tool may run again, or `None` when the tool is
+/// healthy (or its cooldown has expired).
+pub fn tool_demotion_remaining(tool: &str) -> Option<Duration> {
+    let map = registry().tool_metrics.lock();
+    let until = map.get(tool)?.demoted_until.as_deref()?;
+    let until = chrono::DateTime::parse_from_rfc3339(until).ok()?;
+    let remaining = until.signed_duration_since(Utc::now());
+    remaining.to_std().ok().filter(|d| !d.is_zero())
+}
+
 pub fn snapshot() -> HealthSnapshot {
     let components = registry().components.lock().clone();
 
@@ -90,6 +176,8 @@ pub fn snapshot() -> HealthSnapshot {
         updated_at: now_rfc3339(),
         uptime_seconds: registry().started_at.elapsed().as_secs(),
         components,
+        channel_messages: registry().channel_messages.lock().clone(),
+        tool_metrics: registry().tool_metrics.lock().clone(),
     }
 }
 
@@ -167,6 +255,69 @@ mod tests {
         assert_eq!(entry.restart_count, 2);
     }
 
+    #[test]
+    fn record_tool_call_accumulates_calls_failures_and_duration() {
+        let tool = unique_component("tool-metrics");
+
+        record_tool_call(&tool, true, Duration::from_millis(40), None);
+        record_tool_call(&tool, false, Duration::from_millis(60), Some("boom"));
+
+        let snapshot = snapshot();
+        let entry = snapshot
+            .tool_metrics
+            .get(&tool)
+            .expect("tool should be present after record_tool_call");
+
+        assert_eq!(entry.calls, 2);
+        assert_eq!(entry.failures, 1);
+        assert_eq!(entry.consecutive_failures, 1);
+        assert_eq!(entry.total_duration_ms, 100);
+        assert_eq!(entry.last_error.as_deref(), Some("boom"));
+        assert!(entry.demoted_until.is_none());
+        assert!(tool_demotion_remaining(&tool).is_none());
+    }
+
+    #[test]
+    fn repeated_tool_failures_trigger_demotion() {
+        let tool = unique_component("tool-demotion");
+
+        for _ in 0..TOOL_DEMOTION_THRESHOLD {
+            record_tool_call(&tool, false, Duration::from_millis(5), Some("unreachable"));
+        }
+
+        let snapshot = snapshot();
+        let entry = snapshot
+            .tool_metrics
+            .get(&tool)
+            .expect("tool should exist after repeated failures");
+        assert!(entry.demoted_until.is_some());
+
+        let remaining =
+            tool_demotion_remaining(&tool).expect("demoted tool should report remaining cooldown");
+        assert!(remaining.as_secs() <= TOOL_DEMOTION_COOLDOWN_SECS.unsigned_abs());
+    }
+
+    #[test]
+    fn tool_success_clears_demotion() {
+        let tool = unique_component("tool-recovery");
+
+        for _ in 0..TOOL_DEMOTION_THRESHOLD {
+            record_tool_call(&tool, false, Duration::from_millis(5), Some("unreachable"));
+        }
+        assert!(tool_demotion_remaining(&tool).is_some());
+
+        record_tool_call(&tool, true, Duration::from_millis(5), None);
+
+        let snapshot = snapshot();
+        let entry = snapshot
+            .tool_metrics
+            .get(&tool)
+            .expect("tool should exist after recovery");
+        assert_eq!(entry.consecutive_failures, 0);
+        assert!(entry.demoted_until.is_none());
+        assert!(tool_demotion_remaining(&tool).is_none());
+    }
+
     #[test]
     fn snapshot_json_contains_registered_component_fields() {
         let component = unique_component("health-json");
@@ -1,4 +1,5 @@
 pub mod engine;
+pub mod probes;
 
 #[cfg(test)]
 mod tests {
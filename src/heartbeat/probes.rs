@@ -0,0 +1,429 @@
+//! Custom heartbeat probes: HTTP GET, shell command, peripheral reachability.
+//!
+//! Each `[[heartbeat.probes]]` entry runs on its own interval inside the
+//! daemon's heartbeat supervision and reports into component health as
+//! `probe:<name>`, so results show up in `zeroclaw status --components`.
+//! Failures log by default; `on_failure = "alert"` delivers down/recovery
+//! transitions through a channel, mirroring the uptime monitor behavior.
+
+use crate::config::{Config, HeartbeatProbeConfig};
+use crate::security::SecurityPolicy;
+use anyhow::{bail, Result};
+use std::process::Stdio;
+use tokio::time::{Duration, Instant};
+
+const PROBE_TICK_SECS: u64 = 5;
+const HTTP_TIMEOUT_SECS: u64 = 10;
+const COMMAND_TIMEOUT_SECS: u64 = 30;
+const DETAIL_PREVIEW_CHARS: usize = 200;
+
+/// Result of one probe run. Failures are part of the result, not errors.
+pub struct ProbeOutcome {
+    pub success: bool,
+    pub detail: String,
+}
+
+impl ProbeOutcome {
+    fn ok(detail: impl Into<String>) -> Self {
+        Self {
+            success: true,
+            detail: detail.into(),
+        }
+    }
+
+    fn fail(detail: impl Into<String>) -> Self {
+        Self {
+            success: false,
+            detail: detail.into(),
+        }
+    }
+}
+
+/// Fail fast on misconfigured probes before the worker starts looping.
+pub fn validate_probes(probes: &[HeartbeatProbeConfig]) -> Result<()> {
+    let mut seen = std::collections::HashSet::new();
+    for probe in probes {
+        let name = probe.name.trim();
+        if name.is_empty() {
+            bail!("heartbeat probe with empty name");
+        }
+        if !seen.insert(name.to_string()) {
+            bail!("duplicate heartbeat probe name: {name}");
+        }
+        if !matches!(probe.kind.as_str(), "http" | "command" | "peripheral") {
+            bail!(
+                "heartbeat probe \"{name}\" has unknown kind \"{}\" (expected http, command, or peripheral)",
+                probe.kind
+            );
+        }
+        if probe.target.trim().is_empty() {
+            bail!("heartbeat probe \"{name}\" has empty target");
+        }
+        if probe.interval_secs == 0 {
+            bail!("heartbeat probe \"{name}\" has interval_secs = 0");
+        }
+        match probe.on_failure.as_str() {
+            "log" => {}
+            "alert" => {
+                if probe.channel.is_none() || probe.to.is_none() {
+                    bail!(
+                        "heartbeat probe \"{name}\" uses on_failure = \"alert\" but has no channel/to"
+                    );
+                }
+            }
+            other => bail!(
+                "heartbeat probe \"{name}\" has unknown on_failure \"{other}\" (expected log or alert)"
+            ),
+        }
+    }
+    Ok(())
+}
+
+/// Run one probe and return the result (never errors — failures are part of
+/// the result).
+pub async fn run_probe(
+    client: &reqwest::Client,
+    security: &SecurityPolicy,
+    config: &Config,
+    probe: &HeartbeatProbeConfig,
+) -> ProbeOutcome {
+    match probe.kind.as_str() {
+        "http" => check_http(client, &probe.target).await,
+        "command" => check_command(security, config, &probe.target).await,
+        "peripheral" => check_peripheral(config, &probe.target).await,
+        other => ProbeOutcome::fail(format!("unknown probe kind \"{other}\"")),
+    }
+}
+
+async fn check_http(client: &reqwest::Client, url: &str) -> ProbeOutcome {
+    match client.get(url).send().await {
+        Ok(response) => {
+            let status = response.status().as_u16();
+            if status == 200 {
+                ProbeOutcome::ok("HTTP 200")
+            } else {
+                ProbeOutcome::fail(format!("expected HTTP 200, got {status}"))
+            }
+        }
+        Err(e) => ProbeOutcome::fail(e.to_string()),
+    }
+}
+
+async fn check_command(security: &SecurityPolicy, config: &Config, command: &str) -> ProbeOutcome {
+    if !security.is_command_allowed(command) {
+        return ProbeOutcome::fail(format!(
+            "blocked by security policy: command not allowed: {command}"
+        ));
+    }
+
+    let child = match tokio::process::Command::new("sh")
+        .arg("-lc")
+        .arg(command)
+        .current_dir(&config.workspace_dir)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn()
+    {
+        Ok(child) => child,
+        Err(e) => return ProbeOutcome::fail(format!("spawn error: {e}")),
+    };
+
+    match tokio::time::timeout(
+        Duration::from_secs(COMMAND_TIMEOUT_SECS),
+        child.wait_with_output(),
+    )
+    .await
+    {
+        Ok(Ok(output)) if output.status.success() => ProbeOutcome::ok("exit 0"),
+        Ok(Ok(output)) => {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            ProbeOutcome::fail(format!(
+                "{}: {}",
+                output.status,
+                crate::util::truncate_with_ellipsis(stderr.trim(), DETAIL_PREVIEW_CHARS)
+            ))
+        }
+        Ok(Err(e)) => ProbeOutcome::fail(format!("spawn error: {e}")),
+        Err(_) => ProbeOutcome::fail(format!("timed out after {COMMAND_TIMEOUT_SECS}s")),
+    }
+}
+
+/// Reachability check for a configured peripheral board: serial/native
+/// boards must have their device present, network boards must accept a TCP
+/// connection. No traffic is sent to the board itself.
+async fn check_peripheral(config: &Config, board_name: &str) -> ProbeOutcome {
+    let Some(board) = config
+        .peripherals
+        .boards
+        .iter()
+        .find(|b| b.board == board_name)
+    else {
+        return ProbeOutcome::fail(format!(
+            "board \"{board_name}\" is not configured in [[peripherals.boards]]"
+        ));
+    };
+
+    match board.transport.as_str() {
+        "serial" => match board
+            .path
+            .as_deref()
+            .map(str::trim)
+            .filter(|p| !p.is_empty())
+        {
+            Some(path) if std::path::Path::new(path).exists() => {
+                ProbeOutcome::ok(format!("device {path} present"))
+            }
+            Some(path) => ProbeOutcome::fail(format!("device {path} not found")),
+            None => ProbeOutcome::fail("board has no serial path configured"),
+        },
+        "network" => {
+            let Some(addr) = board
+                .path
+                .as_deref()
+                .map(str::trim)
+                .filter(|p| !p.is_empty())
+            else {
+                return ProbeOutcome::fail("board has no network address configured");
+            };
+            match tokio::time::timeout(
+                Duration::from_secs(HTTP_TIMEOUT_SECS),
+                tokio::net::TcpStream::connect(addr),
+            )
+            .await
+            {
+                Ok(Ok(_)) => ProbeOutcome::ok(format!("{addr} reachable")),
+                Ok(Err(e)) => ProbeOutcome::fail(format!("cannot connect to {addr}: {e}")),
+                Err(_) => ProbeOutcome::fail(format!("connect to {addr} timed out")),
+            }
+        }
+        "native" if board.board == "rpi-gpio" || board.board == "raspberry-pi" => {
+            if std::path::Path::new("/dev/gpiochip0").exists() {
+                ProbeOutcome::ok("/dev/gpiochip0 present")
+            } else {
+                ProbeOutcome::fail("/dev/gpiochip0 not found")
+            }
+        }
+        other => ProbeOutcome::fail(format!("cannot probe transport \"{other}\"")),
+    }
+}
+
+/// Probe worker loop (runs until cancelled). Daemon entry point.
+pub async fn run_probe_worker(config: Config) -> Result<()> {
+    let probes = config.heartbeat.probes.clone();
+    validate_probes(&probes)?;
+    if probes.is_empty() {
+        return Ok(());
+    }
+
+    let security = SecurityPolicy::from_config(&config.autonomy, &config.workspace_dir);
+    let client = crate::config::build_runtime_proxy_client_with_timeouts(
+        "heartbeat.probes",
+        HTTP_TIMEOUT_SECS,
+        10,
+    );
+    let mut next_due = vec![Instant::now(); probes.len()];
+    let mut last_success: Vec<Option<bool>> = vec![None; probes.len()];
+    let mut interval = tokio::time::interval(Duration::from_secs(PROBE_TICK_SECS));
+    tracing::info!("Heartbeat probes started ({} configured)", probes.len());
+
+    loop {
+        interval.tick().await;
+        let now = Instant::now();
+        for (i, probe) in probes.iter().enumerate() {
+            if next_due[i] > now {
+                continue;
+            }
+            next_due[i] = now + Duration::from_secs(probe.interval_secs);
+
+            let outcome = run_probe(&client, &security, &config, probe).await;
+            let component = format!("probe:{}", probe.name);
+            if outcome.success {
+                crate::health::mark_component_ok(&component);
+            } else {
+                crate::health::mark_component_error(&component, &outcome.detail);
+                tracing::warn!(probe = %probe.name, "heartbeat probe failed: {}", outcome.detail);
+            }
+
+            if let Some(alert) = transition_alert(probe, last_success[i], &outcome) {
+                deliver_alert(&config, probe, &alert).await;
+            }
+            last_success[i] = Some(outcome.success);
+        }
+    }
+}
+
+/// Alert text for a down/recovery transition, if one happened. The very
+/// first run only alerts when the probe is failing, so adding a healthy
+/// probe stays quiet.
+fn transition_alert(
+    probe: &HeartbeatProbeConfig,
+    previous: Option<bool>,
+    outcome: &ProbeOutcome,
+) -> Option<String> {
+    if probe.on_failure != "alert" {
+        return None;
+    }
+    match (previous, outcome.success) {
+        (Some(true) | None, false) => Some(format!(
+            "🔴 Probe \"{}\" failed: {}",
+            probe.name, outcome.detail
+        )),
+        (Some(false), true) => Some(format!("🟢 Probe \"{}\" recovered", probe.name)),
+        _ => None,
+    }
+}
+
+async fn deliver_alert(config: &Config, probe: &HeartbeatProbeConfig, alert: &str) {
+    if let (Some(channel), Some(to)) = (probe.channel.as_deref(), probe.to.as_deref()) {
+        if let Err(e) = crate::channels::send_once(config, channel, to, alert).await {
+            tracing::error!("Heartbeat probe alert delivery failed: {e}");
+        }
+    } else {
+        tracing::warn!("Heartbeat: {alert}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn probe(kind: &str, target: &str) -> HeartbeatProbeConfig {
+        HeartbeatProbeConfig {
+            name: "test-probe".into(),
+            kind: kind.into(),
+            target: target.into(),
+            interval_secs: 60,
+            on_failure: "log".into(),
+            channel: None,
+            to: None,
+        }
+    }
+
+    fn test_security(config: &Config) -> SecurityPolicy {
+        SecurityPolicy::from_config(&config.autonomy, &config.workspace_dir)
+    }
+
+    #[test]
+    fn validate_accepts_well_formed_probes() {
+        let probes = vec![
+            probe("http", "http://127.0.0.1:3000/health"),
+            HeartbeatProbeConfig {
+                name: "board".into(),
+                kind: "peripheral".into(),
+                target: "nucleo-f401re".into(),
+                ..probe("peripheral", "nucleo-f401re")
+            },
+        ];
+        assert!(validate_probes(&probes).is_ok());
+    }
+
+    #[test]
+    fn validate_rejects_unknown_kind() {
+        let err = validate_probes(&[probe("ping", "somewhere")]).unwrap_err();
+        assert!(err.to_string().contains("unknown kind"));
+    }
+
+    #[test]
+    fn validate_rejects_duplicate_names() {
+        let err =
+            validate_probes(&[probe("http", "http://a"), probe("http", "http://b")]).unwrap_err();
+        assert!(err.to_string().contains("duplicate"));
+    }
+
+    #[test]
+    fn validate_rejects_alert_without_delivery_target() {
+        let mut bad = probe("http", "http://a");
+        bad.on_failure = "alert".into();
+        let err = validate_probes(&[bad]).unwrap_err();
+        assert!(err.to_string().contains("no channel/to"));
+    }
+
+    #[test]
+    fn validate_rejects_zero_interval() {
+        let mut bad = probe("http", "http://a");
+        bad.interval_secs = 0;
+        let err = validate_probes(&[bad]).unwrap_err();
+        assert!(err.to_string().contains("interval_secs = 0"));
+    }
+
+    #[tokio::test]
+    async fn command_probe_reports_exit_status() {
+        let tmp = tempfile::tempdir().unwrap();
+        let mut config = Config::default();
+        config.workspace_dir = tmp.path().to_path_buf();
+        let security = test_security(&config);
+
+        let ok = check_command(&security, &config, "echo ok").await;
+        assert!(ok.success);
+
+        let fail = check_command(&security, &config, "cat /nonexistent/zeroclaw_probe").await;
+        assert!(!fail.success);
+        assert!(fail.detail.contains("exit"));
+
+        let blocked = check_command(&security, &config, "shutdown -h now").await;
+        assert!(!blocked.success);
+        assert!(blocked.detail.contains("blocked by security policy"));
+    }
+
+    #[tokio::test]
+    async fn peripheral_probe_fails_for_unconfigured_board() {
+        let config = Config::default();
+        let outcome = check_peripheral(&config, "nucleo-f401re").await;
+        assert!(!outcome.success);
+        assert!(outcome.detail.contains("not configured"));
+    }
+
+    #[tokio::test]
+    async fn peripheral_probe_checks_serial_device_presence() {
+        let tmp = tempfile::tempdir().unwrap();
+        let device = tmp.path().join("ttyACM0");
+        std::fs::write(&device, b"").unwrap();
+
+        let mut config = Config::default();
+        config.peripherals.boards = vec![crate::config::PeripheralBoardConfig {
+            board: "nucleo-f401re".into(),
+            transport: "serial".into(),
+            path: Some(device.display().to_string()),
+            ..Default::default()
+        }];
+
+        let present = check_peripheral(&config, "nucleo-f401re").await;
+        assert!(present.success);
+
+        std::fs::remove_file(&device).unwrap();
+        let missing = check_peripheral(&config, "nucleo-f401re").await;
+        assert!(!missing.success);
+        assert!(missing.detail.contains("not found"));
+    }
+
+    #[test]
+    fn transition_alert_fires_only_on_state_change() {
+        let mut alerting = probe("http", "http://a");
+        alerting.on_failure = "alert".into();
+        let up = ProbeOutcome::ok("HTTP 200");
+        let down = ProbeOutcome::fail("connection refused");
+
+        // First run up: quiet. First run down: alert.
+        assert!(transition_alert(&alerting, None, &up).is_none());
+        assert!(transition_alert(&alerting, None, &down).is_some());
+
+        // Steady states stay quiet; transitions alert.
+        assert!(transition_alert(&alerting, Some(true), &up).is_none());
+        assert!(transition_alert(&alerting, Some(false), &down).is_none());
+        assert!(transition_alert(&alerting, Some(true), &down)
+            .unwrap()
+            .contains("failed"));
+        assert!(transition_alert(&alerting, Some(false), &up)
+            .unwrap()
+            .contains("recovered"));
+    }
+
+    #[test]
+    fn transition_alert_is_silent_for_log_action() {
+        let logging = probe("http", "http://a");
+        let down = ProbeOutcome::fail("connection refused");
+        assert!(transition_alert(&logging, Some(true), &down).is_none());
+    }
+}
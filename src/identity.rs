@@ -981,6 +981,131 @@ pub fn is_aieos_configured(config: &IdentityConfig) -> bool {
     config.format == "aieos" && (config.aieos_path.is_some() || config.aieos_inline.is_some())
 }
 
+// ── Multi-User Roles ────────────────────────────────────────────────────
+// Channel users bound via `[[identity.users]]` get a role (admin, operator,
+// viewer) that scopes which tools the agent may run on their behalf. The
+// role system is opt-in: with no users configured, every sender that passes
+// the channel allowlist keeps full tool access (pre-existing behavior).
+// Once any user is configured, unbound senders are viewers (least privilege).
+
+/// Tools that alter the runtime itself (scheduling, network routing).
+/// Reserved for admins; operators and viewers are rejected.
+const ADMIN_ONLY_TOOLS: &[&str] = &[
+    "cron_add",
+    "cron_remove",
+    "cron_run",
+    "cron_update",
+    "proxy_config",
+];
+
+/// Read-only tools available to viewers.
+const VIEWER_TOOLS: &[&str] = &[
+    "contacts_list",
+    "cron_list",
+    "cron_runs",
+    "file_read",
+    "hardware_board_info",
+    "image_info",
+    "memory_recall",
+    "web_search_tool",
+];
+
+/// Resolve the role for a channel sender.
+///
+/// Returns `None` when no users are configured (role system disabled). When
+/// users exist, a sender without a matching binding gets [`UserRole::Viewer`].
+/// Bindings are `"channel:identity"` pairs compared case-insensitively.
+pub fn resolve_role(
+    users: &[crate::config::UserBindingConfig],
+    channel: &str,
+    sender: &str,
+) -> Option<crate::config::UserRole> {
+    if users.is_empty() {
+        return None;
+    }
+
+    for user in users {
+        for binding in &user.bindings {
+            let Some((bound_channel, bound_identity)) = binding.split_once(':') else {
+                tracing::warn!(
+                    user = user.name.as_str(),
+                    binding = binding.as_str(),
+                    "Ignoring malformed identity binding (expected channel:identity)"
+                );
+                continue;
+            };
+            if bound_channel.trim().eq_ignore_ascii_case(channel)
+                && bound_identity.trim().eq_ignore_ascii_case(sender)
+            {
+                return Some(user.role);
+            }
+        }
+    }
+
+    Some(crate::config::UserRole::Viewer)
+}
+
+/// Whether a role may run a tool.
+pub fn role_allows_tool(role: crate::config::UserRole, tool_name: &str) -> bool {
+    match role {
+        crate::config::UserRole::Admin => true,
+        crate::config::UserRole::Operator => !ADMIN_ONLY_TOOLS.contains(&tool_name),
+        crate::config::UserRole::Viewer => VIEWER_TOOLS.contains(&tool_name),
+    }
+}
+
+/// A tool entry borrowed from a shared registry by index, so a role-scoped
+/// subset can be built without cloning the underlying tools.
+struct RoleScopedTool {
+    registry: std::sync::Arc<Vec<Box<dyn crate::tools::Tool>>>,
+    index: usize,
+}
+
+impl RoleScopedTool {
+    fn inner(&self) -> &dyn crate::tools::Tool {
+        self.registry[self.index].as_ref()
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::tools::Tool for RoleScopedTool {
+    fn name(&self) -> &str {
+        self.inner().name()
+    }
+
+    fn description(&self) -> &str {
+        self.inner().description()
+    }
+
+    fn parameters_schema(&self) -> serde_json::Value {
+        self.inner().parameters_schema()
+    }
+
+    async fn execute(&self, args: serde_json::Value) -> Result<crate::tools::ToolResult> {
+        self.inner().execute(args).await
+    }
+}
+
+/// Build the subset of a shared tool registry that a role may use. Tools the
+/// role is not allowed to run are omitted entirely, so the model never sees
+/// them and cannot invoke them.
+pub fn role_scoped_tools(
+    registry: &std::sync::Arc<Vec<Box<dyn crate::tools::Tool>>>,
+    role: crate::config::UserRole,
+) -> Vec<Box<dyn crate::tools::Tool>> {
+    registry
+        .iter()
+        .enumerate()
+        .filter(|(_, tool)| role_allows_tool(role, tool.name()))
+        .map(|(index, _)| {
+            Box::new(RoleScopedTool {
+                registry: std::sync::Arc::clone(registry),
+                index,
+            }) as Box<dyn crate::tools::Tool>
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1236,6 +1361,7 @@ mod tests {
     #[test]
     fn is_aieos_configured_true_with_path() {
         let config = IdentityConfig {
+            users: Vec::new(),
             format: "aieos".into(),
             aieos_path: Some("identity.json".into()),
             aieos_inline: None,
@@ -1246,6 +1372,7 @@ mod tests {
     #[test]
     fn is_aieos_configured_true_with_inline() {
         let config = IdentityConfig {
+            users: Vec::new(),
             format: "aieos".into(),
             aieos_path: None,
             aieos_inline: Some("{\"identity\":{}}".into()),
@@ -1256,6 +1383,7 @@ mod tests {
     #[test]
     fn is_aieos_configured_false_openclaw_format() {
         let config = IdentityConfig {
+            users: Vec::new(),
             format: "openclaw".into(),
             aieos_path: Some("identity.json".into()),
             aieos_inline: None,
@@ -1266,6 +1394,7 @@ mod tests {
     #[test]
     fn is_aieos_configured_false_no_config() {
         let config = IdentityConfig {
+            users: Vec::new(),
             format: "aieos".into(),
             aieos_path: None,
             aieos_inline: None,
@@ -1440,6 +1569,7 @@ mod tests {
         std::fs::write(&path, json).unwrap();
 
         let config = IdentityConfig {
+            users: Vec::new(),
             format: "aieos".into(),
             aieos_path: Some("identity.json".into()),
             aieos_inline: None,
@@ -1485,4 +1615,146 @@ mod tests {
         let snack_pos = prompt.find("- snack: tea").unwrap();
         assert!(book_pos < snack_pos);
     }
+
+    // ── Multi-user role tests ──
+
+    use crate::config::{UserBindingConfig, UserRole};
+
+    fn bound_user(name: &str, role: UserRole, bindings: &[&str]) -> UserBindingConfig {
+        UserBindingConfig {
+            name: name.into(),
+            role,
+            bindings: bindings.iter().map(|b| (*b).to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn resolve_role_disabled_when_no_users_configured() {
+        assert_eq!(resolve_role(&[], "telegram", "12345"), None);
+    }
+
+    #[test]
+    fn resolve_role_matches_binding_case_insensitively() {
+        let users = vec![bound_user(
+            "zeroclaw_operator",
+            UserRole::Admin,
+            &["Telegram:12345"],
+        )];
+        assert_eq!(
+            resolve_role(&users, "telegram", "12345"),
+            Some(UserRole::Admin)
+        );
+    }
+
+    #[test]
+    fn resolve_role_unbound_sender_defaults_to_viewer() {
+        let users = vec![bound_user(
+            "zeroclaw_operator",
+            UserRole::Admin,
+            &["telegram:12345"],
+        )];
+        assert_eq!(
+            resolve_role(&users, "telegram", "99999"),
+            Some(UserRole::Viewer)
+        );
+        assert_eq!(
+            resolve_role(&users, "discord", "12345"),
+            Some(UserRole::Viewer)
+        );
+    }
+
+    #[test]
+    fn resolve_role_skips_malformed_bindings() {
+        let users = vec![bound_user(
+            "zeroclaw_user",
+            UserRole::Operator,
+            &["not-a-binding", "slack:U0AAA"],
+        )];
+        assert_eq!(
+            resolve_role(&users, "slack", "U0AAA"),
+            Some(UserRole::Operator)
+        );
+    }
+
+    #[test]
+    fn role_allows_tool_admin_has_full_access() {
+        assert!(role_allows_tool(UserRole::Admin, "shell"));
+        assert!(role_allows_tool(UserRole::Admin, "cron_add"));
+        assert!(role_allows_tool(UserRole::Admin, "proxy_config"));
+    }
+
+    #[test]
+    fn role_allows_tool_operator_rejects_admin_only_tools() {
+        assert!(role_allows_tool(UserRole::Operator, "shell"));
+        assert!(role_allows_tool(UserRole::Operator, "file_write"));
+        assert!(!role_allows_tool(UserRole::Operator, "cron_add"));
+        assert!(!role_allows_tool(UserRole::Operator, "cron_remove"));
+        assert!(!role_allows_tool(UserRole::Operator, "proxy_config"));
+    }
+
+    #[test]
+    fn role_allows_tool_viewer_is_read_only() {
+        assert!(role_allows_tool(UserRole::Viewer, "file_read"));
+        assert!(role_allows_tool(UserRole::Viewer, "memory_recall"));
+        assert!(role_allows_tool(UserRole::Viewer, "cron_list"));
+        assert!(!role_allows_tool(UserRole::Viewer, "shell"));
+        assert!(!role_allows_tool(UserRole::Viewer, "file_write"));
+        assert!(!role_allows_tool(UserRole::Viewer, "cron_add"));
+    }
+
+    struct NamedTool(&'static str);
+
+    #[async_trait::async_trait]
+    impl crate::tools::Tool for NamedTool {
+        fn name(&self) -> &str {
+            self.0
+        }
+
+        fn description(&self) -> &str {
+            "test tool"
+        }
+
+        fn parameters_schema(&self) -> serde_json::Value {
+            serde_json::json!({"type": "object", "properties": {}})
+        }
+
+        async fn execute(&self, _args: serde_json::Value) -> Result<crate::tools::ToolResult> {
+            Ok(crate::tools::ToolResult {
+                success: true,
+                output: self.0.to_string(),
+                error: None,
+            })
+        }
+    }
+
+    #[test]
+    fn role_scoped_tools_omits_disallowed_tools() {
+        let registry: std::sync::Arc<Vec<Box<dyn crate::tools::Tool>>> = std::sync::Arc::new(vec![
+            Box::new(NamedTool("shell")),
+            Box::new(NamedTool("file_read")),
+            Box::new(NamedTool("cron_add")),
+        ]);
+
+        let admin = role_scoped_tools(&registry, UserRole::Admin);
+        assert_eq!(admin.len(), 3);
+
+        let operator = role_scoped_tools(&registry, UserRole::Operator);
+        let operator_names: Vec<&str> = operator.iter().map(|t| t.name()).collect();
+        assert_eq!(operator_names, vec!["shell", "file_read"]);
+
+        let viewer = role_scoped_tools(&registry, UserRole::Viewer);
+        let viewer_names: Vec<&str> = viewer.iter().map(|t| t.name()).collect();
+        assert_eq!(viewer_names, vec!["file_read"]);
+    }
+
+    #[tokio::test]
+    async fn role_scoped_tool_delegates_execution_to_registry_entry() {
+        let registry: std::sync::Arc<Vec<Box<dyn crate::tools::Tool>>> =
+            std::sync::Arc::new(vec![Box::new(NamedTool("file_read"))]);
+
+        let viewer = role_scoped_tools(&registry, UserRole::Viewer);
+        let result = viewer[0].execute(serde_json::json!({})).await.unwrap();
+        assert!(result.success);
+        assert_eq!(result.output, "file_read");
+    }
 }
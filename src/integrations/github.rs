@@ -0,0 +1,637 @@
+//! GitHub integration — issues and pull requests via personal access token.
+//!
+//! Besides the agent-facing `github` tool, this module provides the parsing
+//! and delivery helpers behind the gateway's `POST /hooks/github` webhook
+//! endpoint (auto-triage of newly opened issues, gated by a per-repo
+//! allowlist).
+
+use super::traits::Integration;
+use crate::security::policy::ToolOperation;
+use crate::security::SecurityPolicy;
+use crate::tools::{Tool, ToolResult};
+use anyhow::Context;
+use async_trait::async_trait;
+use serde_json::{json, Value};
+use std::sync::Arc;
+
+const GITHUB_API_BASE: &str = "https://api.github.com";
+/// Maximum bytes of a pull-request diff returned by `review_diff`.
+const MAX_DIFF_BYTES: usize = 16_384;
+/// Maximum bytes of an issue body fed into the triage prompt.
+const MAX_ISSUE_BODY_BYTES: usize = 4_096;
+
+/// GitHub integration: exposes the `github` tool when configured.
+pub struct GithubIntegration {
+    token: String,
+    security: Arc<SecurityPolicy>,
+}
+
+impl GithubIntegration {
+    pub fn new(token: String, security: Arc<SecurityPolicy>) -> Self {
+        Self { token, security }
+    }
+}
+
+impl Integration for GithubIntegration {
+    fn name(&self) -> &'static str {
+        "github"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "GitHub"
+    }
+
+    fn credential_label(&self) -> &'static str {
+        "GitHub personal access token"
+    }
+
+    fn tools(&self) -> Vec<Box<dyn Tool>> {
+        vec![Box::new(GithubTool {
+            token: self.token.clone(),
+            security: self.security.clone(),
+        })]
+    }
+}
+
+/// Agent tool for GitHub: issues, comments, and pull requests.
+pub struct GithubTool {
+    token: String,
+    security: Arc<SecurityPolicy>,
+}
+
+/// Authenticated GitHub API request builder shared by the tool and the
+/// webhook delivery path.
+fn api_request(token: &str, method: reqwest::Method, url: &str) -> reqwest::RequestBuilder {
+    crate::config::build_runtime_proxy_client_with_timeouts("integration.github", 30, 10)
+        .request(method, url)
+        .bearer_auth(token)
+        .header("Accept", "application/vnd.github+json")
+        .header("User-Agent", "zeroclaw")
+}
+
+impl GithubTool {
+    fn request(&self, method: reqwest::Method, url: &str) -> reqwest::RequestBuilder {
+        api_request(&self.token, method, url)
+    }
+
+    async fn list_issues(&self, repo: &str) -> anyhow::Result<String> {
+        let url = format!("{GITHUB_API_BASE}/repos/{repo}/issues?state=open&per_page=20");
+        let resp = self.request(reqwest::Method::GET, &url).send().await?;
+        if !resp.status().is_success() {
+            anyhow::bail!("GitHub API error: {}", super::api_error(resp).await);
+        }
+
+        let issues: Vec<serde_json::Value> = resp.json().await?;
+        if issues.is_empty() {
+            return Ok(format!("No open issues in {repo}."));
+        }
+        let lines: Vec<String> = issues
+            .iter()
+            .map(|issue| {
+                format!(
+                    "#{} {}",
+                    issue["number"].as_u64().unwrap_or(0),
+                    issue["title"].as_str().unwrap_or("(untitled)")
+                )
+            })
+            .collect();
+        Ok(format!("Open issues in {repo}:\n{}", lines.join("\n")))
+    }
+
+    async fn create_issue(&self, repo: &str, title: &str, body: &str) -> anyhow::Result<String> {
+        let url = format!("{GITHUB_API_BASE}/repos/{repo}/issues");
+        let resp = self
+            .request(reqwest::Method::POST, &url)
+            .json(&json!({ "title": title, "body": body }))
+            .send()
+            .await?;
+        if !resp.status().is_success() {
+            anyhow::bail!("GitHub API error: {}", super::api_error(resp).await);
+        }
+
+        let issue: serde_json::Value = resp.json().await?;
+        Ok(format!(
+            "Created issue #{}: {}",
+            issue["number"].as_u64().unwrap_or(0),
+            issue["html_url"].as_str().unwrap_or("")
+        ))
+    }
+
+    async fn comment(&self, repo: &str, number: u64, body: &str) -> anyhow::Result<String> {
+        post_issue_comment(&self.token, repo, number, body).await?;
+        Ok(format!("Commented on {repo}#{number}."))
+    }
+
+    async fn create_pr(
+        &self,
+        repo: &str,
+        title: &str,
+        head: &str,
+        base: &str,
+        body: &str,
+    ) -> anyhow::Result<String> {
+        let url = format!("{GITHUB_API_BASE}/repos/{repo}/pulls");
+        let resp = self
+            .request(reqwest::Method::POST, &url)
+            .json(&json!({ "title": title, "head": head, "base": base, "body": body }))
+            .send()
+            .await?;
+        if !resp.status().is_success() {
+            anyhow::bail!("GitHub API error: {}", super::api_error(resp).await);
+        }
+
+        let pr: serde_json::Value = resp.json().await?;
+        Ok(format!(
+            "Created pull request #{}: {}",
+            pr["number"].as_u64().unwrap_or(0),
+            pr["html_url"].as_str().unwrap_or("")
+        ))
+    }
+
+    async fn review_diff(&self, repo: &str, number: u64) -> anyhow::Result<String> {
+        let url = format!("{GITHUB_API_BASE}/repos/{repo}/pulls/{number}");
+        let resp = self
+            .request(reqwest::Method::GET, &url)
+            .header("Accept", "application/vnd.github.diff")
+            .send()
+            .await?;
+        if !resp.status().is_success() {
+            anyhow::bail!("GitHub API error: {}", super::api_error(resp).await);
+        }
+
+        let diff = resp.text().await?;
+        if diff.len() <= MAX_DIFF_BYTES {
+            return Ok(format!("Diff of {repo}#{number}:\n{diff}"));
+        }
+        Ok(format!(
+            "Diff of {repo}#{number} (truncated to {MAX_DIFF_BYTES} bytes):\n{}",
+            head_bytes(&diff, MAX_DIFF_BYTES)
+        ))
+    }
+}
+
+/// First `max_bytes` of `s`, respecting UTF-8 boundaries.
+fn head_bytes(s: &str, max_bytes: usize) -> &str {
+    if s.len() <= max_bytes {
+        return s;
+    }
+    let mut end = max_bytes;
+    while !s.is_char_boundary(end) {
+        end -= 1;
+    }
+    &s[..end]
+}
+
+/// Validate an `owner/name` repository reference before it is interpolated
+/// into an API path.
+fn validate_repo(repo: &str) -> anyhow::Result<()> {
+    let valid = matches!(repo.split('/').collect::<Vec<_>>().as_slice(), [owner, name]
+        if !owner.is_empty()
+            && !name.is_empty()
+            && repo
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | '/')));
+    if !valid {
+        anyhow::bail!("Invalid 'repo' (expected 'owner/name'): {repo}");
+    }
+    Ok(())
+}
+
+// ── Webhook automation (`POST /hooks/github`) ───────────────────
+
+/// A newly opened issue extracted from a GitHub `issues` webhook payload.
+#[derive(Debug, Clone)]
+pub struct OpenedIssue {
+    /// Repository as `owner/name`.
+    pub repo: String,
+    pub number: u64,
+    pub title: String,
+    pub body: String,
+    pub url: String,
+}
+
+/// Parse a GitHub webhook payload into a newly opened issue, keyed by the
+/// `X-GitHub-Event` header.
+///
+/// Returns `Ok(None)` for recognized events that need no automation (`ping`,
+/// other `issues` actions, unrelated event types) and an error only for
+/// payloads that are structurally broken.
+pub fn parse_issue_event(
+    github_event: Option<&str>,
+    payload: &Value,
+) -> anyhow::Result<Option<OpenedIssue>> {
+    if github_event != Some("issues") {
+        return Ok(None);
+    }
+    if payload.get("action").and_then(Value::as_str) != Some("opened") {
+        return Ok(None);
+    }
+
+    let repo = payload
+        .pointer("/repository/full_name")
+        .and_then(Value::as_str)
+        .context("issues event missing repository.full_name")?
+        .to_string();
+    let issue = payload
+        .get("issue")
+        .context("issues event missing `issue` object")?;
+    Ok(Some(OpenedIssue {
+        repo,
+        number: issue
+            .get("number")
+            .and_then(Value::as_u64)
+            .context("issues event missing issue.number")?,
+        title: issue
+            .get("title")
+            .and_then(Value::as_str)
+            .unwrap_or("(untitled)")
+            .to_string(),
+        body: issue
+            .get("body")
+            .and_then(Value::as_str)
+            .unwrap_or("")
+            .to_string(),
+        url: issue
+            .get("html_url")
+            .and_then(Value::as_str)
+            .unwrap_or("")
+            .to_string(),
+    }))
+}
+
+/// Whether `repo` is on the webhook allowlist. An empty allowlist denies
+/// all repositories — automation is opt-in per repo.
+pub fn repo_allowed(allowed_repos: &[String], repo: &str) -> bool {
+    allowed_repos
+        .iter()
+        .any(|allowed| allowed.eq_ignore_ascii_case(repo))
+}
+
+/// System prompt for the issue triage summary.
+pub const ISSUE_TRIAGE_SYSTEM_PROMPT: &str = "You are an issue triage assistant. Given a newly \
+    opened GitHub issue, produce a short triage note: (1) a one-line summary, (2) the likely \
+    area of the codebase involved, (3) what information is missing from the report, if any, \
+    (4) a suggested severity (low/medium/high). Base everything on the issue text; do not \
+    invent details.";
+
+/// Build the user prompt for the issue triage request.
+pub fn build_issue_triage_prompt(issue: &OpenedIssue) -> String {
+    format!(
+        "New issue {}#{}: {}\n{}\n\nIssue body:\n```\n{}\n```",
+        issue.repo,
+        issue.number,
+        issue.title,
+        issue.url,
+        head_bytes(&issue.body, MAX_ISSUE_BODY_BYTES)
+    )
+}
+
+/// Post the triage result as an issue comment.
+pub async fn post_issue_comment(
+    token: &str,
+    repo: &str,
+    number: u64,
+    body: &str,
+) -> anyhow::Result<()> {
+    let url = format!("{GITHUB_API_BASE}/repos/{repo}/issues/{number}/comments");
+    let resp = api_request(token, reqwest::Method::POST, &url)
+        .json(&json!({ "body": body }))
+        .send()
+        .await
+        .context("Failed to post issue comment")?;
+    if !resp.status().is_success() {
+        anyhow::bail!("GitHub API error: {}", super::api_error(resp).await);
+    }
+    Ok(())
+}
+
+#[async_trait]
+impl Tool for GithubTool {
+    fn name(&self) -> &str {
+        "github"
+    }
+
+    fn description(&self) -> &str {
+        "Interact with GitHub: action='list_issues' to list open issues in a repo, \
+         action='create_issue' with title/body to open one, \
+         action='comment' with number/body to comment on an issue or PR, \
+         action='create_pr' with title/head/base to open a pull request, \
+         action='review_diff' with number to fetch a pull request's diff. \
+         'repo' is always 'owner/name'."
+    }
+
+    fn parameters_schema(&self) -> serde_json::Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "action": {
+                    "type": "string",
+                    "description": "The operation to perform",
+                    "enum": ["list_issues", "create_issue", "comment", "create_pr", "review_diff"]
+                },
+                "repo": {
+                    "type": "string",
+                    "description": "Repository as 'owner/name'"
+                },
+                "title": {
+                    "type": "string",
+                    "description": "Issue or PR title (create_issue, create_pr)"
+                },
+                "body": {
+                    "type": "string",
+                    "description": "Issue, comment, or PR body (create_issue, comment, create_pr)"
+                },
+                "number": {
+                    "type": "integer",
+                    "description": "Issue or PR number (comment, review_diff)"
+                },
+                "head": {
+                    "type": "string",
+                    "description": "Branch with the changes (create_pr)"
+                },
+                "base": {
+                    "type": "string",
+                    "description": "Branch to merge into (create_pr)"
+                }
+            },
+            "required": ["action", "repo"]
+        })
+    }
+
+    async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
+        let action = args
+            .get("action")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Missing 'action' parameter"))?;
+        let repo = args
+            .get("repo")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Missing 'repo' parameter"))?;
+        validate_repo(repo)?;
+
+        let result = match action {
+            "list_issues" => self.list_issues(repo).await,
+            "create_issue" => {
+                if let Err(error) = self
+                    .security
+                    .enforce_tool_operation(ToolOperation::Act, "github.create_issue")
+                {
+                    return Ok(ToolResult {
+                        success: false,
+                        output: String::new(),
+                        error: Some(error),
+                    });
+                }
+                let title = args
+                    .get("title")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| anyhow::anyhow!("Missing 'title' for create_issue"))?;
+                let body = args.get("body").and_then(|v| v.as_str()).unwrap_or("");
+                self.create_issue(repo, title, body).await
+            }
+            "comment" => {
+                if let Err(error) = self
+                    .security
+                    .enforce_tool_operation(ToolOperation::Act, "github.comment")
+                {
+                    return Ok(ToolResult {
+                        success: false,
+                        output: String::new(),
+                        error: Some(error),
+                    });
+                }
+                let number = args
+                    .get("number")
+                    .and_then(serde_json::Value::as_u64)
+                    .ok_or_else(|| anyhow::anyhow!("Missing 'number' for comment"))?;
+                let body = args
+                    .get("body")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| anyhow::anyhow!("Missing 'body' for comment"))?;
+                self.comment(repo, number, body).await
+            }
+            "create_pr" => {
+                if let Err(error) = self
+                    .security
+                    .enforce_tool_operation(ToolOperation::Act, "github.create_pr")
+                {
+                    return Ok(ToolResult {
+                        success: false,
+                        output: String::new(),
+                        error: Some(error),
+                    });
+                }
+                let title = args
+                    .get("title")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| anyhow::anyhow!("Missing 'title' for create_pr"))?;
+                let head = args
+                    .get("head")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| anyhow::anyhow!("Missing 'head' for create_pr"))?;
+                let base = args
+                    .get("base")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| anyhow::anyhow!("Missing 'base' for create_pr"))?;
+                let body = args.get("body").and_then(|v| v.as_str()).unwrap_or("");
+                self.create_pr(repo, title, head, base, body).await
+            }
+            "review_diff" => {
+                let number = args
+                    .get("number")
+                    .and_then(serde_json::Value::as_u64)
+                    .ok_or_else(|| anyhow::anyhow!("Missing 'number' for review_diff"))?;
+                self.review_diff(repo, number).await
+            }
+            _ => anyhow::bail!(
+                "Unknown action '{action}'. Use 'list_issues', 'create_issue', 'comment', \
+                 'create_pr', or 'review_diff'."
+            ),
+        };
+
+        match result {
+            Ok(output) => Ok(ToolResult {
+                success: true,
+                output,
+                error: None,
+            }),
+            Err(e) => Ok(ToolResult {
+                success: false,
+                output: String::new(),
+                error: Some(e.to_string()),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::security::AutonomyLevel;
+
+    fn test_tool() -> GithubTool {
+        GithubTool {
+            token: "test-token".into(),
+            security: Arc::new(SecurityPolicy::default()),
+        }
+    }
+
+    #[test]
+    fn integration_exposes_github_tool() {
+        let integration =
+            GithubIntegration::new("test-token".into(), Arc::new(SecurityPolicy::default()));
+        assert_eq!(integration.name(), "github");
+        let tools = integration.tools();
+        assert_eq!(tools.len(), 1);
+        assert_eq!(tools[0].name(), "github");
+    }
+
+    #[test]
+    fn schema_requires_action_and_repo() {
+        let schema = test_tool().parameters_schema();
+        let required = schema["required"].as_array().unwrap();
+        assert!(required.contains(&json!("action")));
+        assert!(required.contains(&json!("repo")));
+    }
+
+    #[test]
+    fn validate_repo_accepts_owner_name_and_rejects_path_tricks() {
+        assert!(validate_repo("zeroclaw/zeroclaw").is_ok());
+        assert!(validate_repo("user-a/repo.name_1").is_ok());
+        assert!(validate_repo("no-slash").is_err());
+        assert!(validate_repo("a/b/c").is_err());
+        assert!(validate_repo("../etc/passwd").is_err());
+        assert!(validate_repo("owner/repo?x=1").is_err());
+    }
+
+    #[tokio::test]
+    async fn execute_missing_action_returns_error() {
+        let result = test_tool().execute(json!({"repo": "a/b"})).await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn execute_unknown_action_returns_error() {
+        let result = test_tool()
+            .execute(json!({"action": "delete_repo", "repo": "a/b"}))
+            .await;
+        assert!(result.is_err());
+    }
+
+    fn opened_issue_payload() -> Value {
+        json!({
+            "action": "opened",
+            "issue": {
+                "number": 7,
+                "title": "Crash on startup",
+                "body": "The agent panics when config.toml is missing.",
+                "html_url": "https://example.com/zeroclaw_user/zeroclaw_project/issues/7"
+            },
+            "repository": {"full_name": "zeroclaw_user/zeroclaw_project"}
+        })
+    }
+
+    #[test]
+    fn parse_issue_event_extracts_opened_issue() {
+        let issue = parse_issue_event(Some("issues"), &opened_issue_payload())
+            .unwrap()
+            .unwrap();
+        assert_eq!(issue.repo, "zeroclaw_user/zeroclaw_project");
+        assert_eq!(issue.number, 7);
+        assert_eq!(issue.title, "Crash on startup");
+        assert!(issue.body.contains("panics"));
+    }
+
+    #[test]
+    fn parse_issue_event_ignores_other_events_and_actions() {
+        assert!(parse_issue_event(Some("ping"), &json!({"zen": "ok"}))
+            .unwrap()
+            .is_none());
+        assert!(parse_issue_event(None, &opened_issue_payload())
+            .unwrap()
+            .is_none());
+
+        let mut closed = opened_issue_payload();
+        closed["action"] = json!("closed");
+        assert!(parse_issue_event(Some("issues"), &closed)
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn parse_issue_event_rejects_broken_payload() {
+        assert!(parse_issue_event(Some("issues"), &json!({"action": "opened"})).is_err());
+    }
+
+    #[test]
+    fn repo_allowed_denies_by_default_and_matches_case_insensitively() {
+        assert!(!repo_allowed(&[], "zeroclaw_user/zeroclaw_project"));
+        let allowed = vec!["zeroclaw_user/zeroclaw_project".to_string()];
+        assert!(repo_allowed(&allowed, "ZeroClaw_User/zeroclaw_project"));
+        assert!(!repo_allowed(&allowed, "user_a/other_repo"));
+    }
+
+    #[test]
+    fn issue_triage_prompt_truncates_long_bodies() {
+        let issue = OpenedIssue {
+            repo: "zeroclaw_user/zeroclaw_project".into(),
+            number: 7,
+            title: "Crash on startup".into(),
+            body: "x".repeat(MAX_ISSUE_BODY_BYTES + 100),
+            url: String::new(),
+        };
+        let prompt = build_issue_triage_prompt(&issue);
+        assert!(prompt.len() < MAX_ISSUE_BODY_BYTES + 300);
+        assert!(prompt.contains("zeroclaw_user/zeroclaw_project#7"));
+    }
+
+    #[tokio::test]
+    async fn create_pr_blocked_in_readonly_mode() {
+        let tool = GithubTool {
+            token: "test-token".into(),
+            security: Arc::new(SecurityPolicy {
+                autonomy: AutonomyLevel::ReadOnly,
+                ..SecurityPolicy::default()
+            }),
+        };
+        let result = tool
+            .execute(json!({"action": "create_pr", "repo": "a/b", "title": "t"}))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result
+            .error
+            .as_deref()
+            .unwrap_or("")
+            .contains("read-only mode"));
+    }
+
+    #[tokio::test]
+    async fn review_diff_requires_number() {
+        let result = test_tool()
+            .execute(json!({"action": "review_diff", "repo": "a/b"}))
+            .await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn create_issue_blocked_in_readonly_mode() {
+        let tool = GithubTool {
+            token: "test-token".into(),
+            security: Arc::new(SecurityPolicy {
+                autonomy: AutonomyLevel::ReadOnly,
+                ..SecurityPolicy::default()
+            }),
+        };
+        let result = tool
+            .execute(json!({"action": "create_issue", "repo": "a/b", "title": "t"}))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result
+            .error
+            .as_deref()
+            .unwrap_or("")
+            .contains("read-only mode"));
+    }
+}
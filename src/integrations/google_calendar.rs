@@ -0,0 +1,311 @@
+//! Google Calendar integration — events via OAuth access token.
+
+use super::traits::Integration;
+use crate::security::policy::ToolOperation;
+use crate::security::SecurityPolicy;
+use crate::tools::{Tool, ToolResult};
+use async_trait::async_trait;
+use serde_json::json;
+use std::sync::Arc;
+
+const CALENDAR_API_BASE: &str = "https://www.googleapis.com/calendar/v3";
+
+/// Google Calendar integration: exposes the `google_calendar` tool when
+/// configured.
+pub struct GoogleCalendarIntegration {
+    token: String,
+    security: Arc<SecurityPolicy>,
+}
+
+impl GoogleCalendarIntegration {
+    pub fn new(token: String, security: Arc<SecurityPolicy>) -> Self {
+        Self { token, security }
+    }
+}
+
+impl Integration for GoogleCalendarIntegration {
+    fn name(&self) -> &'static str {
+        "google_calendar"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Google Calendar"
+    }
+
+    fn credential_label(&self) -> &'static str {
+        "Google OAuth access token (calendar scope)"
+    }
+
+    fn tools(&self) -> Vec<Box<dyn Tool>> {
+        vec![Box::new(GoogleCalendarTool {
+            token: self.token.clone(),
+            security: self.security.clone(),
+        })]
+    }
+}
+
+/// Agent tool for Google Calendar: list upcoming events and create events.
+pub struct GoogleCalendarTool {
+    token: String,
+    security: Arc<SecurityPolicy>,
+}
+
+impl GoogleCalendarTool {
+    fn client(&self) -> reqwest::Client {
+        crate::config::build_runtime_proxy_client_with_timeouts(
+            "integration.google_calendar",
+            30,
+            10,
+        )
+    }
+
+    async fn list_events(&self, calendar_id: &str) -> anyhow::Result<String> {
+        let time_min = chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true);
+        let url = format!(
+            "{CALENDAR_API_BASE}/calendars/{}/events",
+            urlencoding::encode(calendar_id)
+        );
+        let resp = self
+            .client()
+            .get(&url)
+            .bearer_auth(&self.token)
+            .query(&[
+                ("maxResults", "20"),
+                ("singleEvents", "true"),
+                ("orderBy", "startTime"),
+                ("timeMin", time_min.as_str()),
+            ])
+            .send()
+            .await?;
+        if !resp.status().is_success() {
+            anyhow::bail!(
+                "Google Calendar API error: {}",
+                super::api_error(resp).await
+            );
+        }
+
+        let body: serde_json::Value = resp.json().await?;
+        let events = body["items"].as_array().cloned().unwrap_or_default();
+        if events.is_empty() {
+            return Ok(format!("No upcoming events in calendar '{calendar_id}'."));
+        }
+        let lines: Vec<String> = events
+            .iter()
+            .map(|event| {
+                let start = event["start"]["dateTime"]
+                    .as_str()
+                    .or_else(|| event["start"]["date"].as_str())
+                    .unwrap_or("?");
+                format!(
+                    "{start}  {}",
+                    event["summary"].as_str().unwrap_or("(untitled)")
+                )
+            })
+            .collect();
+        Ok(format!("Upcoming events:\n{}", lines.join("\n")))
+    }
+
+    async fn create_event(
+        &self,
+        calendar_id: &str,
+        summary: &str,
+        start: &str,
+        end: &str,
+    ) -> anyhow::Result<String> {
+        let url = format!(
+            "{CALENDAR_API_BASE}/calendars/{}/events",
+            urlencoding::encode(calendar_id)
+        );
+        let resp = self
+            .client()
+            .post(&url)
+            .bearer_auth(&self.token)
+            .json(&json!({
+                "summary": summary,
+                "start": { "dateTime": start },
+                "end": { "dateTime": end },
+            }))
+            .send()
+            .await?;
+        if !resp.status().is_success() {
+            anyhow::bail!(
+                "Google Calendar API error: {}",
+                super::api_error(resp).await
+            );
+        }
+
+        let event: serde_json::Value = resp.json().await?;
+        Ok(format!(
+            "Created event '{summary}': {}",
+            event["htmlLink"].as_str().unwrap_or("")
+        ))
+    }
+}
+
+#[async_trait]
+impl Tool for GoogleCalendarTool {
+    fn name(&self) -> &str {
+        "google_calendar"
+    }
+
+    fn description(&self) -> &str {
+        "Interact with Google Calendar: action='list_events' to list upcoming events, \
+         action='create_event' with summary/start/end (RFC 3339 date-times) to add one. \
+         'calendar_id' defaults to 'primary'."
+    }
+
+    fn parameters_schema(&self) -> serde_json::Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "action": {
+                    "type": "string",
+                    "description": "The operation to perform",
+                    "enum": ["list_events", "create_event"]
+                },
+                "calendar_id": {
+                    "type": "string",
+                    "description": "Calendar ID (defaults to 'primary')"
+                },
+                "summary": {
+                    "type": "string",
+                    "description": "Event title (create_event)"
+                },
+                "start": {
+                    "type": "string",
+                    "description": "Event start as RFC 3339 date-time (create_event)"
+                },
+                "end": {
+                    "type": "string",
+                    "description": "Event end as RFC 3339 date-time (create_event)"
+                }
+            },
+            "required": ["action"]
+        })
+    }
+
+    async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
+        let action = args
+            .get("action")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Missing 'action' parameter"))?;
+        let calendar_id = args
+            .get("calendar_id")
+            .and_then(|v| v.as_str())
+            .unwrap_or("primary");
+
+        let result = match action {
+            "list_events" => self.list_events(calendar_id).await,
+            "create_event" => {
+                if let Err(error) = self
+                    .security
+                    .enforce_tool_operation(ToolOperation::Act, "google_calendar.create_event")
+                {
+                    return Ok(ToolResult {
+                        success: false,
+                        output: String::new(),
+                        error: Some(error),
+                    });
+                }
+                let summary = args
+                    .get("summary")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| anyhow::anyhow!("Missing 'summary' for create_event"))?;
+                let start = args
+                    .get("start")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| anyhow::anyhow!("Missing 'start' for create_event"))?;
+                let end = args
+                    .get("end")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| anyhow::anyhow!("Missing 'end' for create_event"))?;
+                for (field, value) in [("start", start), ("end", end)] {
+                    if chrono::DateTime::parse_from_rfc3339(value).is_err() {
+                        anyhow::bail!("Invalid '{field}' (expected RFC 3339 date-time): {value}");
+                    }
+                }
+                self.create_event(calendar_id, summary, start, end).await
+            }
+            _ => anyhow::bail!("Unknown action '{action}'. Use 'list_events' or 'create_event'."),
+        };
+
+        match result {
+            Ok(output) => Ok(ToolResult {
+                success: true,
+                output,
+                error: None,
+            }),
+            Err(e) => Ok(ToolResult {
+                success: false,
+                output: String::new(),
+                error: Some(e.to_string()),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::security::AutonomyLevel;
+
+    fn test_tool() -> GoogleCalendarTool {
+        GoogleCalendarTool {
+            token: "test-token".into(),
+            security: Arc::new(SecurityPolicy::default()),
+        }
+    }
+
+    #[test]
+    fn integration_exposes_calendar_tool() {
+        let integration = GoogleCalendarIntegration::new(
+            "test-token".into(),
+            Arc::new(SecurityPolicy::default()),
+        );
+        assert_eq!(integration.name(), "google_calendar");
+        let tools = integration.tools();
+        assert_eq!(tools.len(), 1);
+        assert_eq!(tools[0].name(), "google_calendar");
+    }
+
+    #[tokio::test]
+    async fn execute_unknown_action_returns_error() {
+        let result = test_tool().execute(json!({"action": "delete_all"})).await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn create_event_rejects_non_rfc3339_times() {
+        let result = test_tool()
+            .execute(json!({
+                "action": "create_event",
+                "summary": "standup",
+                "start": "tomorrow",
+                "end": "later",
+            }))
+            .await;
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("RFC 3339"));
+    }
+
+    #[tokio::test]
+    async fn create_event_blocked_in_readonly_mode() {
+        let tool = GoogleCalendarTool {
+            token: "test-token".into(),
+            security: Arc::new(SecurityPolicy {
+                autonomy: AutonomyLevel::ReadOnly,
+                ..SecurityPolicy::default()
+            }),
+        };
+        let result = tool
+            .execute(json!({"action": "create_event"}))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result
+            .error
+            .as_deref()
+            .unwrap_or("")
+            .contains("read-only mode"));
+    }
+}
@@ -1,7 +1,15 @@
+pub mod github;
+pub mod google_calendar;
+pub mod notion;
 pub mod registry;
+pub mod traits;
+
+pub use traits::Integration;
 
 use crate::config::Config;
+use crate::security::SecurityPolicy;
 use anyhow::Result;
+use std::sync::Arc;
 
 /// Integration status
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -66,11 +74,158 @@ pub struct IntegrationEntry {
     pub status_fn: fn(&Config) -> IntegrationStatus,
 }
 
+/// Build one runnable integration by config key.
+fn build_integration(
+    name: &str,
+    token: String,
+    security: Arc<SecurityPolicy>,
+) -> Option<Box<dyn Integration>> {
+    match name {
+        "github" => Some(Box::new(github::GithubIntegration::new(token, security))),
+        "google_calendar" => Some(Box::new(google_calendar::GoogleCalendarIntegration::new(
+            token, security,
+        ))),
+        "notion" => Some(Box::new(notion::NotionIntegration::new(token, security))),
+        _ => None,
+    }
+}
+
+/// Integrations that are enabled and have a credential configured.
+///
+/// The tools each returns are merged into the agent's tool registry by
+/// [`all_tools_with_runtime`](crate::tools::all_tools_with_runtime).
+pub fn active_integrations(
+    config: &Config,
+    security: &Arc<SecurityPolicy>,
+) -> Vec<Box<dyn Integration>> {
+    config
+        .integrations
+        .entries()
+        .into_iter()
+        .filter_map(|(name, entry)| {
+            let settings = entry?;
+            if !settings.enabled {
+                return None;
+            }
+            let token = settings.token.as_deref()?.trim();
+            if token.is_empty() {
+                return None;
+            }
+            build_integration(name, token.to_string(), security.clone())
+        })
+        .collect()
+}
+
+/// Extract a compact error string (`HTTP <status>: <message>`) from a failed
+/// API response, without echoing the full body.
+pub(crate) async fn api_error(resp: reqwest::Response) -> String {
+    let status = resp.status().as_u16();
+    let body = resp.text().await.unwrap_or_default();
+    let message = serde_json::from_str::<serde_json::Value>(&body)
+        .ok()
+        .and_then(|parsed| {
+            parsed
+                .get("message")
+                .or_else(|| parsed.get("error").and_then(|e| e.get("message")))
+                .and_then(|v| v.as_str())
+                .map(ToString::to_string)
+        });
+    match message {
+        Some(message) => format!(
+            "HTTP {status}: {}",
+            message.chars().take(240).collect::<String>()
+        ),
+        None => format!("HTTP {status}"),
+    }
+}
+
 /// Handle the `integrations` CLI command
-pub fn handle_command(command: crate::IntegrationCommands, config: &Config) -> Result<()> {
+pub async fn handle_command(command: crate::IntegrationCommands, config: &Config) -> Result<()> {
     match command {
         crate::IntegrationCommands::Info { name } => show_integration_info(config, &name),
+        crate::IntegrationCommands::Enable { name } => set_enabled(config, &name, true).await,
+        crate::IntegrationCommands::Disable { name } => set_enabled(config, &name, false).await,
+        crate::IntegrationCommands::Configure { name, token } => {
+            configure_integration(config, &name, token).await
+        }
+    }
+}
+
+/// Resolve a user-supplied name to a runnable integration's config key.
+fn runnable_key(name: &str) -> Result<&'static str> {
+    match name.to_lowercase().replace('-', "_").as_str() {
+        "github" => Ok("github"),
+        "google_calendar" | "googlecalendar" | "calendar" => Ok("google_calendar"),
+        "notion" => Ok("notion"),
+        _ => anyhow::bail!(
+            "No runnable integration named '{name}'. \
+             Supported: github, google_calendar, notion."
+        ),
+    }
+}
+
+async fn set_enabled(config: &Config, name: &str, enabled: bool) -> Result<()> {
+    let key = runnable_key(name)?;
+    let mut updated = config.clone();
+    let entry = updated
+        .integrations
+        .entry_mut(key)
+        .expect("runnable_key returns known keys");
+    let Some(settings) = entry else {
+        anyhow::bail!(
+            "Integration '{key}' is not configured. \
+             Run `zeroclaw integrations configure {key}` first."
+        );
+    };
+    settings.enabled = enabled;
+    updated.save().await?;
+
+    if enabled {
+        println!("✅ Integration '{key}' enabled. Its tools are registered on next start.");
+    } else {
+        println!("⏸️  Integration '{key}' disabled. Credentials are kept; re-enable anytime.");
+    }
+    Ok(())
+}
+
+async fn configure_integration(config: &Config, name: &str, token: Option<String>) -> Result<()> {
+    let key = runnable_key(name)?;
+    // Built only for its credential prompt label; any token works here.
+    let prompt = build_integration(key, String::new(), Arc::new(SecurityPolicy::default()))
+        .expect("runnable_key returns known keys")
+        .credential_label()
+        .to_string();
+
+    let token = match token {
+        Some(token) => token.trim().to_string(),
+        None => dialoguer::Password::new()
+            .with_prompt(prompt)
+            .allow_empty_password(false)
+            .interact()?
+            .trim()
+            .to_string(),
+    };
+    if token.is_empty() {
+        anyhow::bail!("Credential must not be empty");
     }
+
+    let mut updated = config.clone();
+    let entry = updated
+        .integrations
+        .entry_mut(key)
+        .expect("runnable_key returns known keys");
+    // Preserve webhook settings when re-configuring the credential.
+    let mut settings = entry.take().unwrap_or_default();
+    settings.enabled = true;
+    settings.token = Some(token);
+    *entry = Some(settings);
+    updated.save().await?;
+
+    println!(
+        "✅ Integration '{key}' configured and enabled. \
+         The credential is stored in config.toml (encrypted when [secrets] encrypt = true)."
+    );
+    Ok(())
 }
 
 fn show_integration_info(config: &Config, name: &str) -> Result<()> {
@@ -143,7 +298,20 @@ fn show_integration_info(config: &Config, name: &str) -> Result<()> {
         "GitHub" => {
             println!("  Setup:");
             println!("    1. Create a personal access token at https://github.com/settings/tokens");
-            println!("    2. Add to config: [integrations.github] token = \"ghp_...\"");
+            println!("    2. Run: zeroclaw integrations configure github");
+        }
+        "Google Calendar" => {
+            println!("  Setup:");
+            println!("    1. Obtain an OAuth access token with the calendar scope");
+            println!("    2. Run: zeroclaw integrations configure google_calendar");
+        }
+        "Notion" => {
+            println!("  Setup:");
+            println!(
+                "    1. Create an internal integration at https://www.notion.so/my-integrations"
+            );
+            println!("    2. Share the target pages with the integration");
+            println!("    3. Run: zeroclaw integrations configure notion");
         }
         "Browser" => {
             println!("  Built-in:");
@@ -193,8 +361,8 @@ mod tests {
         assert!(labels.contains(&"Platforms"));
     }
 
-    #[test]
-    fn handle_command_info_is_case_insensitive_for_known_integrations() {
+    #[tokio::test]
+    async fn handle_command_info_is_case_insensitive_for_known_integrations() {
         let config = Config::default();
         let first_name = registry::all_integrations()
             .first()
@@ -205,23 +373,87 @@ mod tests {
         let result = handle_command(
             crate::IntegrationCommands::Info { name: first_name },
             &config,
-        );
+        )
+        .await;
 
         assert!(result.is_ok());
     }
 
-    #[test]
-    fn handle_command_info_returns_error_for_unknown_integration() {
+    #[tokio::test]
+    async fn handle_command_info_returns_error_for_unknown_integration() {
         let config = Config::default();
         let result = handle_command(
             crate::IntegrationCommands::Info {
                 name: "definitely-not-a-real-integration".into(),
             },
             &config,
-        );
+        )
+        .await;
 
         assert!(result.is_err());
         let err = result.unwrap_err().to_string();
         assert!(err.contains("Unknown integration"));
     }
+
+    #[test]
+    fn runnable_key_normalizes_and_rejects_unknown_names() {
+        assert_eq!(runnable_key("GitHub").unwrap(), "github");
+        assert_eq!(runnable_key("google-calendar").unwrap(), "google_calendar");
+        assert_eq!(runnable_key("Notion").unwrap(), "notion");
+        assert!(runnable_key("spotify").is_err());
+    }
+
+    #[test]
+    fn active_integrations_skips_disabled_and_unconfigured_entries() {
+        use crate::config::IntegrationSettings;
+
+        let security = std::sync::Arc::new(SecurityPolicy::default());
+        let mut config = Config::default();
+        assert!(active_integrations(&config, &security).is_empty());
+
+        config.integrations.github = Some(IntegrationSettings {
+            enabled: true,
+            token: Some("test-token".into()),
+            ..Default::default()
+        });
+        config.integrations.notion = Some(IntegrationSettings {
+            enabled: false,
+            token: Some("test-token".into()),
+            ..Default::default()
+        });
+        config.integrations.google_calendar = Some(IntegrationSettings {
+            enabled: true,
+            token: None,
+            ..Default::default()
+        });
+
+        let active = active_integrations(&config, &security);
+        assert_eq!(active.len(), 1);
+        assert_eq!(active[0].name(), "github");
+    }
+
+    #[test]
+    fn active_integrations_builds_every_runnable_kind() {
+        use crate::config::IntegrationSettings;
+
+        let security = std::sync::Arc::new(SecurityPolicy::default());
+        let mut config = Config::default();
+        for entry in [
+            &mut config.integrations.github,
+            &mut config.integrations.google_calendar,
+            &mut config.integrations.notion,
+        ] {
+            *entry = Some(IntegrationSettings {
+                enabled: true,
+                token: Some("test-token".into()),
+                ..Default::default()
+            });
+        }
+
+        let names: Vec<&str> = active_integrations(&config, &security)
+            .iter()
+            .map(|integration| integration.name())
+            .collect();
+        assert_eq!(names, vec!["github", "google_calendar", "notion"]);
+    }
 }
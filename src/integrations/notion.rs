@@ -0,0 +1,355 @@
+//! Notion integration — search and page creation via internal integration token.
+
+use super::traits::Integration;
+use crate::security::policy::ToolOperation;
+use crate::security::SecurityPolicy;
+use crate::tools::{Tool, ToolResult};
+use async_trait::async_trait;
+use serde_json::json;
+use std::sync::Arc;
+
+const NOTION_API_BASE: &str = "https://api.notion.com/v1";
+const NOTION_API_VERSION: &str = "2022-06-28";
+
+/// Notion integration: exposes the `notion` tool when configured.
+pub struct NotionIntegration {
+    token: String,
+    security: Arc<SecurityPolicy>,
+}
+
+impl NotionIntegration {
+    pub fn new(token: String, security: Arc<SecurityPolicy>) -> Self {
+        Self { token, security }
+    }
+}
+
+impl Integration for NotionIntegration {
+    fn name(&self) -> &'static str {
+        "notion"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Notion"
+    }
+
+    fn credential_label(&self) -> &'static str {
+        "Notion internal integration token"
+    }
+
+    fn tools(&self) -> Vec<Box<dyn Tool>> {
+        vec![Box::new(NotionTool {
+            token: self.token.clone(),
+            security: self.security.clone(),
+        })]
+    }
+}
+
+/// Agent tool for Notion: search the workspace and create pages.
+pub struct NotionTool {
+    token: String,
+    security: Arc<SecurityPolicy>,
+}
+
+impl NotionTool {
+    fn client(&self) -> reqwest::Client {
+        crate::config::build_runtime_proxy_client_with_timeouts("integration.notion", 30, 10)
+    }
+
+    fn request(&self, method: reqwest::Method, url: &str) -> reqwest::RequestBuilder {
+        self.client()
+            .request(method, url)
+            .bearer_auth(&self.token)
+            .header("Notion-Version", NOTION_API_VERSION)
+    }
+
+    async fn search(&self, query: &str) -> anyhow::Result<String> {
+        let url = format!("{NOTION_API_BASE}/search");
+        let resp = self
+            .request(reqwest::Method::POST, &url)
+            .json(&json!({ "query": query, "page_size": 20 }))
+            .send()
+            .await?;
+        if !resp.status().is_success() {
+            anyhow::bail!("Notion API error: {}", super::api_error(resp).await);
+        }
+
+        let body: serde_json::Value = resp.json().await?;
+        let results = body["results"].as_array().cloned().unwrap_or_default();
+        if results.is_empty() {
+            return Ok(format!("No Notion results for '{query}'."));
+        }
+        let lines: Vec<String> = results
+            .iter()
+            .map(|item| {
+                format!(
+                    "{} [{}] {}",
+                    page_title(item),
+                    item["object"].as_str().unwrap_or("?"),
+                    item["id"].as_str().unwrap_or("")
+                )
+            })
+            .collect();
+        Ok(format!(
+            "Notion results for '{query}':\n{}",
+            lines.join("\n")
+        ))
+    }
+
+    async fn create_page(
+        &self,
+        parent_page_id: &str,
+        title: &str,
+        content: &str,
+    ) -> anyhow::Result<String> {
+        let url = format!("{NOTION_API_BASE}/pages");
+        let mut body = json!({
+            "parent": { "page_id": parent_page_id },
+            "properties": {
+                "title": { "title": [{ "text": { "content": title } }] }
+            },
+        });
+        if !content.is_empty() {
+            body["children"] = json!([{
+                "object": "block",
+                "type": "paragraph",
+                "paragraph": { "rich_text": [{ "text": { "content": content } }] }
+            }]);
+        }
+
+        let resp = self
+            .request(reqwest::Method::POST, &url)
+            .json(&body)
+            .send()
+            .await?;
+        if !resp.status().is_success() {
+            anyhow::bail!("Notion API error: {}", super::api_error(resp).await);
+        }
+
+        let page: serde_json::Value = resp.json().await?;
+        Ok(format!(
+            "Created page '{title}': {}",
+            page["url"].as_str().unwrap_or("")
+        ))
+    }
+}
+
+/// Create a page in a Notion database — the `capture_note` tool's notion
+/// backend. Uses the `title` property id, which every database title column
+/// resolves to regardless of its display name.
+pub(crate) async fn append_to_database(
+    token: &str,
+    database_id: &str,
+    title: &str,
+    content: &str,
+) -> anyhow::Result<String> {
+    let url = format!("{NOTION_API_BASE}/pages");
+    let mut body = json!({
+        "parent": { "database_id": database_id },
+        "properties": {
+            "title": { "title": [{ "text": { "content": title } }] }
+        },
+    });
+    if !content.is_empty() {
+        body["children"] = json!([{
+            "object": "block",
+            "type": "paragraph",
+            "paragraph": { "rich_text": [{ "text": { "content": content } }] }
+        }]);
+    }
+
+    let resp =
+        crate::config::build_runtime_proxy_client_with_timeouts("integration.notion", 30, 10)
+            .post(&url)
+            .bearer_auth(token)
+            .header("Notion-Version", NOTION_API_VERSION)
+            .json(&body)
+            .send()
+            .await?;
+    if !resp.status().is_success() {
+        anyhow::bail!("Notion API error: {}", super::api_error(resp).await);
+    }
+
+    let page: serde_json::Value = resp.json().await?;
+    Ok(page["url"].as_str().unwrap_or("").to_string())
+}
+
+/// Extract a page/database title from a Notion search result item.
+fn page_title(item: &serde_json::Value) -> String {
+    let title_array = item["properties"]["title"]["title"]
+        .as_array()
+        .or_else(|| item["title"].as_array());
+    title_array
+        .and_then(|parts| {
+            let joined: String = parts
+                .iter()
+                .filter_map(|part| part["plain_text"].as_str())
+                .collect();
+            (!joined.is_empty()).then_some(joined)
+        })
+        .unwrap_or_else(|| "(untitled)".into())
+}
+
+#[async_trait]
+impl Tool for NotionTool {
+    fn name(&self) -> &str {
+        "notion"
+    }
+
+    fn description(&self) -> &str {
+        "Interact with Notion: action='search' with query to find pages and databases \
+         shared with the integration, action='create_page' with parent_page_id/title \
+         (and optional content) to create a page."
+    }
+
+    fn parameters_schema(&self) -> serde_json::Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "action": {
+                    "type": "string",
+                    "description": "The operation to perform",
+                    "enum": ["search", "create_page"]
+                },
+                "query": {
+                    "type": "string",
+                    "description": "Search text (search)"
+                },
+                "parent_page_id": {
+                    "type": "string",
+                    "description": "Parent page ID (create_page)"
+                },
+                "title": {
+                    "type": "string",
+                    "description": "Page title (create_page)"
+                },
+                "content": {
+                    "type": "string",
+                    "description": "Optional first-paragraph text (create_page)"
+                }
+            },
+            "required": ["action"]
+        })
+    }
+
+    async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
+        let action = args
+            .get("action")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Missing 'action' parameter"))?;
+
+        let result = match action {
+            "search" => {
+                let query = args
+                    .get("query")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| anyhow::anyhow!("Missing 'query' for search"))?;
+                self.search(query).await
+            }
+            "create_page" => {
+                if let Err(error) = self
+                    .security
+                    .enforce_tool_operation(ToolOperation::Act, "notion.create_page")
+                {
+                    return Ok(ToolResult {
+                        success: false,
+                        output: String::new(),
+                        error: Some(error),
+                    });
+                }
+                let parent_page_id = args
+                    .get("parent_page_id")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| anyhow::anyhow!("Missing 'parent_page_id' for create_page"))?;
+                let title = args
+                    .get("title")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| anyhow::anyhow!("Missing 'title' for create_page"))?;
+                let content = args.get("content").and_then(|v| v.as_str()).unwrap_or("");
+                self.create_page(parent_page_id, title, content).await
+            }
+            _ => anyhow::bail!("Unknown action '{action}'. Use 'search' or 'create_page'."),
+        };
+
+        match result {
+            Ok(output) => Ok(ToolResult {
+                success: true,
+                output,
+                error: None,
+            }),
+            Err(e) => Ok(ToolResult {
+                success: false,
+                output: String::new(),
+                error: Some(e.to_string()),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::security::AutonomyLevel;
+
+    fn test_tool() -> NotionTool {
+        NotionTool {
+            token: "test-token".into(),
+            security: Arc::new(SecurityPolicy::default()),
+        }
+    }
+
+    #[test]
+    fn integration_exposes_notion_tool() {
+        let integration =
+            NotionIntegration::new("test-token".into(), Arc::new(SecurityPolicy::default()));
+        assert_eq!(integration.name(), "notion");
+        let tools = integration.tools();
+        assert_eq!(tools.len(), 1);
+        assert_eq!(tools[0].name(), "notion");
+    }
+
+    #[test]
+    fn page_title_reads_property_and_top_level_shapes() {
+        let page = json!({
+            "properties": { "title": { "title": [{ "plain_text": "Road" }, { "plain_text": "map" }] } }
+        });
+        assert_eq!(page_title(&page), "Roadmap");
+
+        let database = json!({ "title": [{ "plain_text": "Tasks" }] });
+        assert_eq!(page_title(&database), "Tasks");
+
+        assert_eq!(page_title(&json!({})), "(untitled)");
+    }
+
+    #[tokio::test]
+    async fn search_without_query_returns_error() {
+        let result = test_tool().execute(json!({"action": "search"})).await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn execute_unknown_action_returns_error() {
+        let result = test_tool().execute(json!({"action": "delete_page"})).await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn create_page_blocked_in_readonly_mode() {
+        let tool = NotionTool {
+            token: "test-token".into(),
+            security: Arc::new(SecurityPolicy {
+                autonomy: AutonomyLevel::ReadOnly,
+                ..SecurityPolicy::default()
+            }),
+        };
+        let result = tool
+            .execute(json!({"action": "create_page"}))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result
+            .error
+            .as_deref()
+            .unwrap_or("")
+            .contains("read-only mode"));
+    }
+}
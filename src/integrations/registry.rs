@@ -4,6 +4,23 @@ use crate::providers::{
     is_zai_alias,
 };
 
+/// Status for a runnable integration (`[integrations.<name>]`): active when
+/// enabled with a credential, otherwise available.
+fn runnable_status(entry: Option<&crate::config::IntegrationSettings>) -> IntegrationStatus {
+    match entry {
+        Some(settings)
+            if settings.enabled
+                && settings
+                    .token
+                    .as_deref()
+                    .is_some_and(|token| !token.trim().is_empty()) =>
+        {
+            IntegrationStatus::Active
+        }
+        _ => IntegrationStatus::Available,
+    }
+}
+
 /// Returns the full catalog of integrations
 #[allow(clippy::too_many_lines)]
 pub fn all_integrations() -> Vec<IntegrationEntry> {
@@ -489,13 +506,19 @@ pub fn all_integrations() -> Vec<IntegrationEntry> {
             name: "GitHub",
             description: "Code, issues, PRs",
             category: IntegrationCategory::Productivity,
-            status_fn: |_| IntegrationStatus::ComingSoon,
+            status_fn: |c| runnable_status(c.integrations.github.as_ref()),
+        },
+        IntegrationEntry {
+            name: "Google Calendar",
+            description: "Events & scheduling",
+            category: IntegrationCategory::Productivity,
+            status_fn: |c| runnable_status(c.integrations.google_calendar.as_ref()),
         },
         IntegrationEntry {
             name: "Notion",
             description: "Workspace & databases",
             category: IntegrationCategory::Productivity,
-            status_fn: |_| IntegrationStatus::ComingSoon,
+            status_fn: |c| runnable_status(c.integrations.notion.as_ref()),
         },
         IntegrationEntry {
             name: "Apple Notes",
@@ -871,6 +894,30 @@ mod tests {
         }
     }
 
+    #[test]
+    fn github_active_when_configured_with_token() {
+        let mut config = Config::default();
+        let entries = all_integrations();
+        let gh = entries.iter().find(|e| e.name == "GitHub").unwrap();
+        assert!(matches!(
+            (gh.status_fn)(&config),
+            IntegrationStatus::Available
+        ));
+
+        config.integrations.github = Some(crate::config::IntegrationSettings {
+            enabled: true,
+            token: Some("test-token".into()),
+            ..Default::default()
+        });
+        assert!(matches!((gh.status_fn)(&config), IntegrationStatus::Active));
+
+        config.integrations.github.as_mut().unwrap().enabled = false;
+        assert!(matches!(
+            (gh.status_fn)(&config),
+            IntegrationStatus::Available
+        ));
+    }
+
     #[test]
     fn whatsapp_available_when_not_configured() {
         let config = Config::default();
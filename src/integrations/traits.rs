@@ -0,0 +1,36 @@
+//! Integration trait — external services that expose tools to the agent.
+
+use crate::tools::Tool;
+
+/// An external service integration that registers tools with the agent.
+///
+/// Implement this trait for each runnable integration (GitHub, Google
+/// Calendar, Notion, …) and wire it into
+/// [`active_integrations`](super::active_integrations). An integration is
+/// constructed from its `[integrations.<name>]` config entry — credential
+/// included — and the tools returned by [`tools`](Integration::tools) are
+/// merged into the agent's tool registry when the entry is enabled.
+///
+/// Credentials are collected by `zeroclaw integrations configure <name>` and
+/// stored through the encrypted secret store; implementations receive the
+/// decrypted token and must never log it.
+pub trait Integration: Send + Sync {
+    /// Stable lowercase key used in config and CLI (e.g. `"github"`).
+    ///
+    /// Must match the field name in
+    /// [`IntegrationsConfig`](crate::config::IntegrationsConfig).
+    fn name(&self) -> &'static str;
+
+    /// Human-readable name shown in CLI output (e.g. `"GitHub"`).
+    fn display_name(&self) -> &'static str;
+
+    /// Prompt label for the credential collected by `integrations configure`
+    /// (e.g. `"GitHub personal access token"`).
+    fn credential_label(&self) -> &'static str;
+
+    /// The tools this integration registers with the agent.
+    ///
+    /// Each returned [`Tool`] delegates to the service's API using the
+    /// integration's stored credential.
+    fn tools(&self) -> Vec<Box<dyn Tool>>;
+}
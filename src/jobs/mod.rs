@@ -0,0 +1,65 @@
+use crate::config::Config;
+use anyhow::Result;
+
+mod store;
+mod types;
+
+pub mod worker;
+
+#[allow(unused_imports)]
+pub use store::{
+    cancel_job, claim_next_job, enqueue_job, get_job, list_jobs, mark_finished,
+    requeue_interrupted_jobs,
+};
+pub use types::{BackgroundJob, JobStatus};
+
+#[allow(clippy::needless_pass_by_value)]
+pub fn handle_command(command: crate::JobsCommands, config: &Config) -> Result<()> {
+    match command {
+        crate::JobsCommands::List => {
+            let jobs = list_jobs(config)?;
+            if jobs.is_empty() {
+                println!("No background jobs yet.");
+                println!("\nJobs are enqueued by the agent via the delegate_async tool.");
+                return Ok(());
+            }
+
+            println!("📋 Background jobs ({}):", jobs.len());
+            for job in jobs {
+                println!(
+                    "- {} | {} | created={}",
+                    job.id,
+                    job.status.as_str(),
+                    job.created_at.to_rfc3339(),
+                );
+                println!("    prompt: {}", job.prompt);
+            }
+            Ok(())
+        }
+        crate::JobsCommands::Status { id } => {
+            let job = get_job(config, &id)?;
+            println!("Job {}", job.id);
+            println!("  Status : {}", job.status.as_str());
+            println!("  Prompt : {}", job.prompt);
+            if let Some(model) = &job.model {
+                println!("  Model  : {model}");
+            }
+            println!("  Created: {}", job.created_at.to_rfc3339());
+            if let Some(started) = job.started_at {
+                println!("  Started: {}", started.to_rfc3339());
+            }
+            if let Some(finished) = job.finished_at {
+                println!("  Ended  : {}", finished.to_rfc3339());
+            }
+            if let Some(output) = &job.output {
+                println!("  Output :\n{output}");
+            }
+            Ok(())
+        }
+        crate::JobsCommands::Cancel { id } => {
+            cancel_job(config, &id)?;
+            println!("🛑 Cancelled background job {id}");
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,399 @@
+use crate::config::Config;
+use crate::jobs::{BackgroundJob, JobStatus};
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use rusqlite::{params, Connection};
+use uuid::Uuid;
+
+const MAX_JOB_OUTPUT_BYTES: usize = 16 * 1024;
+const TRUNCATED_OUTPUT_MARKER: &str = "\n...[truncated]";
+
+pub fn enqueue_job(
+    config: &Config,
+    prompt: &str,
+    model: Option<String>,
+    notify_channel: Option<String>,
+    notify_to: Option<String>,
+) -> Result<BackgroundJob> {
+    if prompt.trim().is_empty() {
+        anyhow::bail!("job prompt must not be empty");
+    }
+
+    let id = Uuid::new_v4().to_string();
+    let now = Utc::now();
+
+    with_connection(config, |conn| {
+        conn.execute(
+            "INSERT INTO background_jobs (id, prompt, model, notify_channel, notify_to, status, created_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, 'queued', ?6)",
+            params![id, prompt, model, notify_channel, notify_to, now.to_rfc3339()],
+        )
+        .context("Failed to insert background job")?;
+        Ok(())
+    })?;
+
+    get_job(config, &id)
+}
+
+pub fn list_jobs(config: &Config) -> Result<Vec<BackgroundJob>> {
+    with_connection(config, |conn| {
+        let mut stmt = conn.prepare(
+            "SELECT id, prompt, model, notify_channel, notify_to, status,
+                    created_at, started_at, finished_at, output
+             FROM background_jobs ORDER BY created_at DESC, id DESC",
+        )?;
+
+        let rows = stmt.query_map([], map_job_row)?;
+        let mut jobs = Vec::new();
+        for row in rows {
+            jobs.push(row?);
+        }
+        Ok(jobs)
+    })
+}
+
+pub fn get_job(config: &Config, job_id: &str) -> Result<BackgroundJob> {
+    with_connection(config, |conn| {
+        let mut stmt = conn.prepare(
+            "SELECT id, prompt, model, notify_channel, notify_to, status,
+                    created_at, started_at, finished_at, output
+             FROM background_jobs WHERE id = ?1",
+        )?;
+
+        let mut rows = stmt.query(params![job_id])?;
+        if let Some(row) = rows.next()? {
+            map_job_row(row).map_err(Into::into)
+        } else {
+            anyhow::bail!("Background job '{job_id}' not found")
+        }
+    })
+}
+
+/// Cancel a queued or running job. The jobs worker polls for the status flip
+/// and aborts a running job's agent run mid-flight; finished jobs are
+/// immutable.
+pub fn cancel_job(config: &Config, job_id: &str) -> Result<BackgroundJob> {
+    let changed = with_connection(config, |conn| {
+        conn.execute(
+            "UPDATE background_jobs SET status = 'cancelled', finished_at = ?1
+             WHERE id = ?2 AND status IN ('queued', 'running')",
+            params![Utc::now().to_rfc3339(), job_id],
+        )
+        .context("Failed to cancel background job")
+    })?;
+
+    if changed == 0 {
+        let job = get_job(config, job_id)?;
+        anyhow::bail!(
+            "Background job '{job_id}' is {} — only queued or running jobs can be cancelled",
+            job.status.as_str()
+        );
+    }
+
+    get_job(config, job_id)
+}
+
+/// Atomically claim the oldest queued job, marking it running. Returns `None`
+/// when the queue is empty.
+pub fn claim_next_job(config: &Config) -> Result<Option<BackgroundJob>> {
+    let claimed = with_connection(config, |conn| {
+        let tx = conn.unchecked_transaction()?;
+
+        let next_id: Option<String> = tx
+            .query_row(
+                "SELECT id FROM background_jobs
+                 WHERE status = 'queued'
+                 ORDER BY created_at ASC, id ASC
+                 LIMIT 1",
+                [],
+                |row| row.get(0),
+            )
+            .map(Some)
+            .or_else(|e| match e {
+                rusqlite::Error::QueryReturnedNoRows => Ok(None),
+                other => Err(other),
+            })?;
+
+        if let Some(ref id) = next_id {
+            tx.execute(
+                "UPDATE background_jobs SET status = 'running', started_at = ?1 WHERE id = ?2",
+                params![Utc::now().to_rfc3339(), id],
+            )
+            .context("Failed to mark background job running")?;
+        }
+
+        tx.commit()
+            .context("Failed to commit background job claim")?;
+        Ok(next_id)
+    })?;
+
+    match claimed {
+        Some(id) => get_job(config, &id).map(Some),
+        None => Ok(None),
+    }
+}
+
+pub fn mark_finished(config: &Config, job_id: &str, success: bool, output: &str) -> Result<()> {
+    let status = if success { "done" } else { "failed" };
+    let bounded_output = truncate_job_output(output);
+    with_connection(config, |conn| {
+        conn.execute(
+            "UPDATE background_jobs SET status = ?1, finished_at = ?2, output = ?3
+             WHERE id = ?4",
+            params![status, Utc::now().to_rfc3339(), bounded_output, job_id],
+        )
+        .context("Failed to record background job result")?;
+        Ok(())
+    })
+}
+
+/// Requeue jobs left in `running` by a crashed/restarted daemon so they are
+/// picked up again instead of staying stuck forever. Returns how many jobs
+/// were requeued.
+pub fn requeue_interrupted_jobs(config: &Config) -> Result<usize> {
+    with_connection(config, |conn| {
+        conn.execute(
+            "UPDATE background_jobs SET status = 'queued', started_at = NULL
+             WHERE status = 'running'",
+            [],
+        )
+        .context("Failed to requeue interrupted background jobs")
+    })
+}
+
+fn truncate_job_output(output: &str) -> String {
+    if output.len() <= MAX_JOB_OUTPUT_BYTES {
+        return output.to_string();
+    }
+
+    let mut cutoff = MAX_JOB_OUTPUT_BYTES - TRUNCATED_OUTPUT_MARKER.len();
+    while cutoff > 0 && !output.is_char_boundary(cutoff) {
+        cutoff -= 1;
+    }
+
+    let mut truncated = output[..cutoff].to_string();
+    truncated.push_str(TRUNCATED_OUTPUT_MARKER);
+    truncated
+}
+
+fn parse_rfc3339(raw: &str) -> Result<DateTime<Utc>> {
+    let parsed = DateTime::parse_from_rfc3339(raw)
+        .with_context(|| format!("Invalid RFC3339 timestamp in jobs DB: {raw}"))?;
+    Ok(parsed.with_timezone(&Utc))
+}
+
+fn sql_conversion_error(err: anyhow::Error) -> rusqlite::Error {
+    rusqlite::Error::ToSqlConversionFailure(err.into())
+}
+
+fn map_job_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<BackgroundJob> {
+    let created_at_raw: String = row.get(6)?;
+    let started_at_raw: Option<String> = row.get(7)?;
+    let finished_at_raw: Option<String> = row.get(8)?;
+
+    Ok(BackgroundJob {
+        id: row.get(0)?,
+        prompt: row.get(1)?,
+        model: row.get(2)?,
+        notify_channel: row.get(3)?,
+        notify_to: row.get(4)?,
+        status: JobStatus::parse(&row.get::<_, String>(5)?),
+        created_at: parse_rfc3339(&created_at_raw).map_err(sql_conversion_error)?,
+        started_at: match started_at_raw {
+            Some(raw) => Some(parse_rfc3339(&raw).map_err(sql_conversion_error)?),
+            None => None,
+        },
+        finished_at: match finished_at_raw {
+            Some(raw) => Some(parse_rfc3339(&raw).map_err(sql_conversion_error)?),
+            None => None,
+        },
+        output: row.get(9)?,
+    })
+}
+
+fn with_connection<T>(config: &Config, f: impl FnOnce(&Connection) -> Result<T>) -> Result<T> {
+    let db_path = config.workspace_dir.join("jobs").join("jobs.db");
+    if let Some(parent) = db_path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create jobs directory: {}", parent.display()))?;
+    }
+
+    let conn = Connection::open(&db_path)
+        .with_context(|| format!("Failed to open jobs DB: {}", db_path.display()))?;
+
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS background_jobs (
+            id             TEXT PRIMARY KEY,
+            prompt         TEXT NOT NULL,
+            model          TEXT,
+            notify_channel TEXT,
+            notify_to      TEXT,
+            status         TEXT NOT NULL DEFAULT 'queued',
+            created_at     TEXT NOT NULL,
+            started_at     TEXT,
+            finished_at    TEXT,
+            output         TEXT
+        );
+        CREATE INDEX IF NOT EXISTS idx_background_jobs_status ON background_jobs(status);",
+    )
+    .context("Failed to initialize jobs schema")?;
+
+    f(&conn)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use tempfile::TempDir;
+
+    fn test_config(tmp: &TempDir) -> Config {
+        let config = Config {
+            workspace_dir: tmp.path().join("workspace"),
+            config_path: tmp.path().join("config.toml"),
+            ..Config::default()
+        };
+        std::fs::create_dir_all(&config.workspace_dir).unwrap();
+        config
+    }
+
+    #[test]
+    fn enqueue_list_get_roundtrip() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp);
+
+        let job = enqueue_job(&config, "summarize the logs", None, None, None).unwrap();
+        assert_eq!(job.status, JobStatus::Queued);
+        assert!(job.started_at.is_none());
+
+        let listed = list_jobs(&config).unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].id, job.id);
+
+        let fetched = get_job(&config, &job.id).unwrap();
+        assert_eq!(fetched.prompt, "summarize the logs");
+    }
+
+    #[test]
+    fn enqueue_rejects_empty_prompt() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp);
+
+        let err = enqueue_job(&config, "  ", None, None, None).unwrap_err();
+        assert!(err.to_string().contains("must not be empty"));
+    }
+
+    #[test]
+    fn claim_next_job_marks_running_in_fifo_order() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp);
+
+        let first = enqueue_job(&config, "first task", None, None, None).unwrap();
+        let _second = enqueue_job(&config, "second task", None, None, None).unwrap();
+
+        let claimed = claim_next_job(&config).unwrap().unwrap();
+        assert_eq!(claimed.id, first.id);
+        assert_eq!(claimed.status, JobStatus::Running);
+        assert!(claimed.started_at.is_some());
+
+        let again = claim_next_job(&config).unwrap().unwrap();
+        assert_eq!(again.prompt, "second task");
+
+        assert!(claim_next_job(&config).unwrap().is_none());
+    }
+
+    #[test]
+    fn mark_finished_records_status_and_output() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp);
+
+        let job = enqueue_job(&config, "task", None, None, None).unwrap();
+        let claimed = claim_next_job(&config).unwrap().unwrap();
+        assert_eq!(claimed.id, job.id);
+
+        mark_finished(&config, &job.id, true, "all done").unwrap();
+
+        let finished = get_job(&config, &job.id).unwrap();
+        assert_eq!(finished.status, JobStatus::Done);
+        assert_eq!(finished.output.as_deref(), Some("all done"));
+        assert!(finished.finished_at.is_some());
+    }
+
+    #[test]
+    fn mark_finished_truncates_large_output() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp);
+
+        let job = enqueue_job(&config, "task", None, None, None).unwrap();
+        let output = "x".repeat(MAX_JOB_OUTPUT_BYTES + 512);
+        mark_finished(&config, &job.id, false, &output).unwrap();
+
+        let finished = get_job(&config, &job.id).unwrap();
+        assert_eq!(finished.status, JobStatus::Failed);
+        let stored = finished.output.as_deref().unwrap_or_default();
+        assert!(stored.ends_with(TRUNCATED_OUTPUT_MARKER));
+        assert!(stored.len() <= MAX_JOB_OUTPUT_BYTES);
+    }
+
+    #[test]
+    fn cancel_affects_queued_and_running_jobs() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp);
+
+        let queued = enqueue_job(&config, "queued task", None, None, None).unwrap();
+        let cancelled = cancel_job(&config, &queued.id).unwrap();
+        assert_eq!(cancelled.status, JobStatus::Cancelled);
+
+        let err = cancel_job(&config, &queued.id).unwrap_err();
+        assert!(err.to_string().contains("only queued or running jobs"));
+
+        let running = enqueue_job(&config, "running task", None, None, None).unwrap();
+        let claimed = claim_next_job(&config).unwrap().unwrap();
+        assert_eq!(claimed.id, running.id);
+        let cancelled = cancel_job(&config, &running.id).unwrap();
+        assert_eq!(cancelled.status, JobStatus::Cancelled);
+
+        assert!(
+            claim_next_job(&config).unwrap().is_none(),
+            "cancelled jobs must not be claimed"
+        );
+    }
+
+    #[test]
+    fn cancel_rejects_finished_jobs() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp);
+
+        let job = enqueue_job(&config, "task", None, None, None).unwrap();
+        let _ = claim_next_job(&config).unwrap().unwrap();
+        mark_finished(&config, &job.id, true, "all done").unwrap();
+
+        let err = cancel_job(&config, &job.id).unwrap_err();
+        assert!(err.to_string().contains("only queued or running jobs"));
+    }
+
+    #[test]
+    fn cancel_missing_job_fails() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp);
+
+        let err = cancel_job(&config, "missing-id").unwrap_err();
+        assert!(err.to_string().contains("not found"));
+    }
+
+    #[test]
+    fn requeue_interrupted_jobs_resets_running_state() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp);
+
+        let job = enqueue_job(&config, "task", None, None, None).unwrap();
+        let _ = claim_next_job(&config).unwrap().unwrap();
+
+        let requeued = requeue_interrupted_jobs(&config).unwrap();
+        assert_eq!(requeued, 1);
+
+        let restored = get_job(&config, &job.id).unwrap();
+        assert_eq!(restored.status, JobStatus::Queued);
+        assert!(restored.started_at.is_none());
+    }
+}
@@ -0,0 +1,183 @@
+use crate::config::Config;
+use crate::jobs::{
+    claim_next_job, get_job, mark_finished, requeue_interrupted_jobs, BackgroundJob, JobStatus,
+};
+use crate::observability::{self, ObserverEvent};
+use crate::security::SecurityPolicy;
+use anyhow::Result;
+use tokio::time::{self, Duration};
+
+const POLL_SECONDS: u64 = 5;
+
+/// Outcome of driving one claimed job to completion.
+enum JobOutcome {
+    Finished {
+        success: bool,
+        output: String,
+    },
+    /// The job was cancelled via `zeroclaw jobs cancel` while running; the
+    /// store already holds the cancelled status.
+    Cancelled,
+}
+
+/// Daemon worker that executes queued background jobs one at a time.
+///
+/// Jobs are claimed FIFO from the store; jobs interrupted by a daemon restart
+/// are requeued at startup so nothing is silently lost.
+pub async fn run(config: Config) -> Result<()> {
+    match requeue_interrupted_jobs(&config) {
+        Ok(0) => {}
+        Ok(n) => tracing::info!("Requeued {n} background job(s) interrupted by restart"),
+        Err(e) => tracing::warn!("Failed to requeue interrupted background jobs: {e}"),
+    }
+
+    let security = SecurityPolicy::from_config(&config.autonomy, &config.workspace_dir)
+        .with_audit(&config.security.audit, config.zeroclaw_dir());
+    let observer = observability::create_observer(
+        &config.observability,
+        &config.events,
+        config.delegation_log_path(),
+    );
+
+    let mut interval = time::interval(Duration::from_secs(POLL_SECONDS));
+    interval.set_missed_tick_behavior(time::MissedTickBehavior::Skip);
+
+    crate::health::mark_component_ok("jobs");
+
+    loop {
+        interval.tick().await;
+        crate::health::mark_component_ok("jobs");
+
+        let job = match claim_next_job(&config) {
+            Ok(Some(job)) => job,
+            Ok(None) => continue,
+            Err(e) => {
+                crate::health::mark_component_error("jobs", e.to_string());
+                tracing::warn!("Background job queue query failed: {e}");
+                continue;
+            }
+        };
+
+        tracing::info!("Running background job {}", job.id);
+        match execute_job_until_cancelled(&config, &security, &job).await {
+            JobOutcome::Finished { success, output } => {
+                if let Err(e) = mark_finished(&config, &job.id, success, &output) {
+                    tracing::warn!("Failed to persist background job result: {e}");
+                }
+
+                let marker = if success { "✅" } else { "❌" };
+                let message = format!("{marker} Background job {} finished:\n{output}", job.id);
+                notify_if_configured(&config, &job, &message).await;
+            }
+            JobOutcome::Cancelled => {
+                tracing::info!("Background job {} cancelled", job.id);
+                observer.record_event(&ObserverEvent::RunCancelled {
+                    scope: format!("job:{}", job.id),
+                });
+                let message = format!("🛑 Background job {} cancelled", job.id);
+                notify_if_configured(&config, &job, &message).await;
+            }
+        }
+    }
+}
+
+/// Drive the job's agent run, polling the store so a `zeroclaw jobs cancel`
+/// from another process aborts the run mid-flight instead of waiting for it
+/// to finish.
+async fn execute_job_until_cancelled(
+    config: &Config,
+    security: &SecurityPolicy,
+    job: &BackgroundJob,
+) -> JobOutcome {
+    let work = execute_job(config, security, job);
+    tokio::pin!(work);
+
+    let mut poll = time::interval(Duration::from_secs(POLL_SECONDS));
+    poll.set_missed_tick_behavior(time::MissedTickBehavior::Skip);
+
+    loop {
+        tokio::select! {
+            (success, output) = &mut work => {
+                return JobOutcome::Finished { success, output };
+            }
+            _ = poll.tick() => {
+                match get_job(config, &job.id) {
+                    Ok(current) if current.status == JobStatus::Cancelled => {
+                        return JobOutcome::Cancelled;
+                    }
+                    Ok(_) => {}
+                    Err(e) => {
+                        tracing::warn!("Background job cancellation check failed: {e}");
+                    }
+                }
+            }
+        }
+    }
+}
+
+async fn execute_job(
+    config: &Config,
+    security: &SecurityPolicy,
+    job: &BackgroundJob,
+) -> (bool, String) {
+    if !security.can_act() {
+        return (
+            false,
+            "blocked by security policy: autonomy is read-only".to_string(),
+        );
+    }
+
+    if security.is_rate_limited() {
+        return (
+            false,
+            "blocked by security policy: rate limit exceeded".to_string(),
+        );
+    }
+
+    if !security.record_action() {
+        return (
+            false,
+            "blocked by security policy: action budget exhausted".to_string(),
+        );
+    }
+
+    let prefixed_prompt = format!("[job:{}] {}", job.id, job.prompt);
+    let run_result = crate::agent::run(
+        config.clone(),
+        Some(prefixed_prompt),
+        None,
+        job.model.clone(),
+        config.default_temperature,
+        vec![],
+        false,
+        vec![],
+        vec![],
+    )
+    .await;
+
+    match run_result {
+        Ok(response) => (
+            true,
+            if response.trim().is_empty() {
+                "background job executed".to_string()
+            } else {
+                response
+            },
+        ),
+        Err(e) => (false, format!("background job failed: {e}")),
+    }
+}
+
+/// Deliver an outcome notification back to the originating channel.
+/// Delivery is best-effort: the job outcome is already persisted, so a
+/// notification failure must not fail the job.
+async fn notify_if_configured(config: &Config, job: &BackgroundJob, message: &str) {
+    let (Some(channel), Some(to)) = (job.notify_channel.as_deref(), job.notify_to.as_deref())
+    else {
+        return;
+    };
+
+    if let Err(e) = crate::channels::send_once(config, channel, to, message).await {
+        tracing::warn!("Background job notification failed: {e}");
+    }
+}
@@ -42,32 +42,48 @@ pub mod agent;
 pub(crate) mod approval;
 pub(crate) mod auth;
 pub mod channels;
+pub(crate) mod chaos;
+pub(crate) mod ci;
 pub mod config;
+pub(crate) mod contacts;
 pub(crate) mod cost;
 pub(crate) mod cron;
 pub(crate) mod daemon;
 pub(crate) mod doctor;
+pub(crate) mod eval;
 pub mod gateway;
 pub(crate) mod hardware;
 pub(crate) mod health;
 pub(crate) mod heartbeat;
 pub(crate) mod identity;
 pub(crate) mod integrations;
+pub(crate) mod jobs;
+pub(crate) mod logs;
 pub mod memory;
 pub(crate) mod migration;
+pub(crate) mod monitors;
 pub(crate) mod multimodal;
+pub(crate) mod nodes;
 pub mod observability;
 pub(crate) mod onboard;
+pub(crate) mod pager;
 pub mod peripherals;
 pub mod providers;
+pub(crate) mod quota;
 pub mod rag;
 pub mod runtime;
 pub(crate) mod security;
 pub(crate) mod service;
+pub(crate) mod sessions;
 pub(crate) mod skills;
+pub(crate) mod terraform;
+pub mod tokens;
 pub mod tools;
+pub(crate) mod top;
 pub(crate) mod tunnel;
+pub(crate) mod undo;
 pub(crate) mod util;
+pub(crate) mod workflow;
 
 pub use config::Config;
 
@@ -82,10 +98,40 @@ pub(crate) enum ServiceCommands {
     Stop,
     /// Restart daemon service to apply latest config
     Restart,
+    /// Reload config and skills in the running daemon without restart (SIGHUP)
+    Reload,
     /// Check daemon service status
     Status,
     /// Uninstall daemon service unit
     Uninstall,
+    /// Generate a Dockerfile and docker-compose.yml for containerized deployment
+    GenerateDocker {
+        /// Directory to write the deployment files into
+        #[arg(long, default_value = ".")]
+        dir: std::path::PathBuf,
+    },
+}
+
+/// Provider response cache subcommands
+#[derive(Subcommand, Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub(crate) enum CacheCommands {
+    /// Show cache entry count, size, and hit rate
+    Stats,
+    /// Remove all cached responses
+    Clear,
+}
+
+/// Token counting subcommands
+#[derive(Subcommand, Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub(crate) enum TokensCommands {
+    /// Estimate the token count of a file (or stdin with `-`)
+    Count {
+        /// Model to count for (default: configured default model)
+        #[arg(long)]
+        model: Option<String>,
+        /// File to count, or `-` for stdin
+        file: String,
+    },
 }
 
 /// Channel management subcommands
@@ -137,6 +183,142 @@ Examples:
     },
 }
 
+/// Sub-agent roster subcommands
+#[derive(Subcommand, Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum AgentCommands {
+    /// List configured sub-agents with provider/model and tool scope
+    List,
+    /// Show full details for one sub-agent (API keys redacted)
+    Show {
+        /// Sub-agent name as defined under `[agents.<name>]`
+        name: String,
+    },
+}
+
+/// Contact book subcommands
+#[derive(Subcommand, Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ContactCommands {
+    /// Add a contact with channel identities, email, timezone, and notes
+    #[command(long_about = "\
+Add a contact to the contact book.
+
+Channel identities use <channel>:<identity> pairs (repeatable). \
+Timezones must be IANA names so the agent can compute local times.
+
+Examples:
+  zeroclaw contacts add 'Alice' --channel slack:U12345 --timezone America/New_York
+  zeroclaw contacts add 'Bob' --channel telegram:zeroclaw_user --email bob@example.com")]
+    Add {
+        /// Contact display name
+        name: String,
+        /// Channel identity as <channel>:<identity> (repeatable)
+        #[arg(long)]
+        channel: Vec<String>,
+        /// Email address
+        #[arg(long)]
+        email: Option<String>,
+        /// IANA timezone (e.g. America/New_York)
+        #[arg(long)]
+        timezone: Option<String>,
+        /// Free-form notes
+        #[arg(long)]
+        notes: Option<String>,
+    },
+    /// List all contacts
+    List,
+    /// Show full details for one contact as JSON
+    Show {
+        /// Contact name (case-insensitive)
+        name: String,
+    },
+    /// Remove a contact by name
+    Remove {
+        /// Contact name (case-insensitive)
+        name: String,
+    },
+}
+
+/// Terraform plan summarizer subcommands
+#[derive(Subcommand, Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum TerraformCommands {
+    /// Summarize `terraform plan -json` output with risk ranking
+    #[command(long_about = "\
+Summarize Terraform plan JSON with deterministic risk ranking and an \
+agent-written review for approval workflows.
+
+Accepts the JSONL stream from `terraform plan -json` or the document from \
+`terraform show -json <planfile>`, read from stdin or --file.
+
+Examples:
+  terraform plan -json | zeroclaw terraform summarize
+  zeroclaw terraform summarize --file plan.json --channel telegram --to 123456 ")]
+    Summarize {
+        /// Read plan JSON from a file instead of stdin
+        #[arg(long)]
+        file: Option<std::path::PathBuf>,
+        /// Post the summary to a channel (telegram, discord, slack, mattermost)
+        #[arg(long)]
+        channel: Option<String>,
+        /// Recipient/target within that channel (required with --channel)
+        #[arg(long)]
+        to: Option<String>,
+        /// Skip the agent review; print only the deterministic digest
+        #[arg(long)]
+        no_agent: bool,
+    },
+}
+
+/// Eval harness subcommands
+#[derive(Subcommand, Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum EvalCommands {
+    /// Run an eval suite from a YAML file
+    Run {
+        /// Path to the suite YAML
+        suite: std::path::PathBuf,
+        /// Model ID(s) to evaluate (overrides the suite's `models` list; repeatable)
+        #[arg(long)]
+        model: Vec<String>,
+    },
+}
+
+/// Session transcript subcommands
+#[derive(Subcommand, Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum SessionsCommands {
+    /// List stored session transcripts
+    List,
+    /// Export a stored session transcript for sharing or archiving
+    Export {
+        /// Session ID (or unique ID prefix)
+        id: String,
+        /// Export format: markdown, json, or html
+        #[arg(long, default_value = "markdown")]
+        format: String,
+        /// Write to a file instead of stdout
+        #[arg(long, value_name = "FILE")]
+        output: Option<std::path::PathBuf>,
+    },
+}
+
+/// Workflow pipeline subcommands
+#[derive(Subcommand, Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum WorkflowCommands {
+    /// Run a named workflow from <workspace>/workflows/<name>.yaml
+    Run {
+        /// Workflow name (file stem)
+        name: String,
+        /// Input text available to prompts as {{input}}
+        #[arg(long)]
+        input: Option<String>,
+    },
+    /// List available workflows
+    List,
+    /// Show a workflow's steps
+    Show {
+        /// Workflow name (file stem)
+        name: String,
+    },
+}
+
 /// Skills management subcommands
 #[derive(Subcommand, Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub(crate) enum SkillCommands {
@@ -163,6 +345,30 @@ pub(crate) enum MigrateCommands {
         #[arg(long)]
         source: Option<std::path::PathBuf>,
 
+        /// Validate and preview migration without writing any data
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Import conversations and memories from a `ChatGPT` data export
+    Chatgpt {
+        /// Path to `conversations.json` (or the export directory containing it)
+        #[arg(long)]
+        source: std::path::PathBuf,
+
+        /// Validate and preview migration without writing any data
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Import a `LangChain` / `LlamaIndex` vector store into `ZeroClaw` memory
+    Vectorstore {
+        /// Source store format: chroma or faiss
+        #[arg(long)]
+        from: String,
+
+        /// Path to the persisted vector store directory
+        #[arg(long)]
+        source: std::path::PathBuf,
+
         /// Validate and preview migration without writing any data
         #[arg(long)]
         dry_run: bool,
@@ -284,6 +490,64 @@ Examples:
     },
 }
 
+/// Background job subcommands
+#[derive(Subcommand, Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub(crate) enum JobsCommands {
+    /// List background jobs
+    List,
+    /// Show status and output of a background job
+    Status {
+        /// Job ID
+        id: String,
+    },
+    /// Cancel a queued background job
+    Cancel {
+        /// Job ID
+        id: String,
+    },
+}
+
+/// Uptime monitor subcommands
+#[derive(Subcommand, Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub(crate) enum MonitorCommands {
+    /// Show all uptime monitors with status and uptime percentage
+    List,
+    /// Add an HTTP endpoint to monitor
+    #[command(long_about = "\
+Add an HTTP endpoint to check periodically.
+
+Checks run from the daemon when [monitors] enabled = true. A check \
+succeeds when the response status matches --expect; up/down \
+transitions are alerted to the [monitors] channel.
+
+Examples:
+  zeroclaw monitor add https://example.com --interval 5m --expect 200
+  zeroclaw monitor add https://example.com/health --interval 30s")]
+    Add {
+        /// Endpoint URL (http or https)
+        url: String,
+        /// Check interval (e.g. 30s, 5m, 1h; minimum 30s)
+        #[arg(long, default_value = "5m")]
+        interval: String,
+        /// Expected HTTP status code
+        #[arg(long, default_value_t = 200)]
+        expect: u16,
+    },
+    /// Remove an uptime monitor
+    Remove {
+        /// Monitor ID
+        id: String,
+    },
+    /// Show recent check history for a monitor
+    History {
+        /// Monitor ID
+        id: String,
+        /// Maximum number of checks to show
+        #[arg(long, default_value_t = 20)]
+        limit: usize,
+    },
+}
+
 /// Integration subcommands
 #[derive(Subcommand, Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub(crate) enum IntegrationCommands {
@@ -292,6 +556,24 @@ pub(crate) enum IntegrationCommands {
         /// Integration name
         name: String,
     },
+    /// Enable a configured integration's tools
+    Enable {
+        /// Integration name (github, google_calendar, notion)
+        name: String,
+    },
+    /// Disable an integration without removing its credentials
+    Disable {
+        /// Integration name (github, google_calendar, notion)
+        name: String,
+    },
+    /// Store credentials for an integration (prompts when --token is omitted)
+    Configure {
+        /// Integration name (github, google_calendar, notion)
+        name: String,
+        /// Credential value; prefer the interactive prompt to keep it out of shell history
+        #[arg(long)]
+        token: Option<String>,
+    },
 }
 
 /// Hardware discovery subcommands
@@ -314,12 +596,20 @@ Introspect a device by its serial or device path.
 Opens the specified device path and queries for board information, \
 firmware version, and supported capabilities.
 
+With --scan-i2c, also probes the I2C bus through the bridge board's \
+firmware (i2c_scan command) and identifies known sensors (BME280, \
+MPU6050, SSD1306), printing a ready-to-use peripheral config snippet.
+
 Examples:
   zeroclaw hardware introspect /dev/ttyACM0
-  zeroclaw hardware introspect COM3")]
+  zeroclaw hardware introspect COM3
+  zeroclaw hardware introspect /dev/ttyACM0 --scan-i2c")]
     Introspect {
         /// Serial or device path
         path: String,
+        /// Probe common I2C addresses through the attached bridge board
+        #[arg(long)]
+        scan_i2c: bool,
     },
     /// Get chip info via USB (probe-rs over ST-Link). No firmware needed on target.
     #[command(long_about = "\
@@ -370,14 +660,23 @@ Flash ZeroClaw firmware to an Arduino board.
 Generates the .ino sketch, installs arduino-cli if it is not \
 already available, compiles, and uploads the firmware.
 
+With --board, runs the custom firmware pipeline instead: compiles the \
+project from the board's build profile (workspace \
+firmware-profiles/<board>.toml, PlatformIO or cargo), flashes it via \
+probe-rs, and verifies the tool handshake afterwards.
+
 Examples:
   zeroclaw peripheral flash
   zeroclaw peripheral flash --port /dev/cu.usbmodem12345
-  zeroclaw peripheral flash -p COM3")]
+  zeroclaw peripheral flash -p COM3
+  zeroclaw peripheral flash --board nucleo-f401re")]
     Flash {
         /// Serial port (e.g. /dev/cu.usbmodem12345). If omitted, uses first arduino-uno from config.
         #[arg(short, long)]
         port: Option<String>,
+        /// Build and flash a custom firmware profile (workspace firmware-profiles/<board>.toml)
+        #[arg(long)]
+        board: Option<String>,
     },
     /// Setup Arduino Uno Q Bridge app (deploy GPIO bridge for agent control)
     SetupUnoQ {
@@ -387,4 +686,40 @@ Examples:
     },
     /// Flash ZeroClaw firmware to Nucleo-F401RE (builds + probe-rs run)
     FlashNucleo,
+    /// Monitor live telemetry (temperature, GPIO states) from connected boards
+    #[command(long_about = "\
+Monitor live telemetry readings from connected serial boards.
+
+Samples each board's `telemetry` firmware command on an interval and \
+prints the readings. The same readings feed the telemetry buffer the \
+agent reads through the read_telemetry tool.
+
+Examples:
+  zeroclaw peripheral monitor
+  zeroclaw peripheral monitor --interval 2
+  zeroclaw peripheral monitor --board nucleo-f401re")]
+    Monitor {
+        /// Seconds between samples
+        #[arg(long, default_value_t = 5)]
+        interval: u64,
+        /// Only monitor boards of this type (e.g. nucleo-f401re)
+        #[arg(long)]
+        board: Option<String>,
+    },
+    /// Discover Wi-Fi boards advertising _zeroclaw._tcp via mDNS
+    #[command(long_about = "\
+Discover network boards (e.g. ESP32) on the local network.
+
+Sends an mDNS query for _zeroclaw._tcp.local and lists responding \
+boards with the config snippet needed to register them. Network \
+boards require a token in config; unauthenticated boards are refused.
+
+Examples:
+  zeroclaw peripheral discover
+  zeroclaw peripheral discover --timeout 10")]
+    Discover {
+        /// Seconds to wait for responses
+        #[arg(long, default_value_t = 3)]
+        timeout: u64,
+    },
 }
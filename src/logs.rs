@@ -0,0 +1,544 @@
+//! Daemon log files and the `zeroclaw logs` command.
+//!
+//! Long-running commands (`daemon`, `gateway`) mirror their tracing output
+//! into daily log files under `<zeroclaw dir>/logs/` so the history is
+//! inspectable the same way on every platform instead of requiring
+//! journalctl, launchd logs, or service-wrapper files. Files rotate by UTC
+//! date (`zeroclaw-YYYY-MM-DD.log`) and the oldest are pruned beyond a fixed
+//! retention window.
+//!
+//! The file layer is wired into the global subscriber at startup with an
+//! initially unset target, because the log directory depends on the config,
+//! which loads after logging is initialized. Until [`enable_file_logging`]
+//! runs, the writer discards output, so one-shot CLI commands never create
+//! log files.
+
+use crate::config::Config;
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, NaiveDate, Utc};
+use std::fs::File;
+use std::io::{self, BufRead, Seek, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, OnceLock};
+
+/// Daily files kept before pruning.
+const KEEP_FILES: usize = 7;
+/// Poll interval for `logs --follow`.
+const FOLLOW_POLL_MS: u64 = 500;
+
+static LOG_DIR: OnceLock<PathBuf> = OnceLock::new();
+static CURRENT_FILE: Mutex<Option<(NaiveDate, File)>> = Mutex::new(None);
+static JSON_LOGS: AtomicBool = AtomicBool::new(false);
+
+/// Directory holding the daemon's daily log files.
+pub fn log_dir(config: &Config) -> PathBuf {
+    config.zeroclaw_dir().join("logs")
+}
+
+fn file_name(date: NaiveDate) -> String {
+    format!("zeroclaw-{date}.log")
+}
+
+/// Activate the file layer: subsequent tracing output is also appended to
+/// the daily file in `dir`. Called once by long-running commands after the
+/// config is loaded; a second call is a no-op.
+pub fn enable_file_logging(dir: PathBuf) {
+    if let Err(e) = std::fs::create_dir_all(&dir) {
+        tracing::warn!(
+            "File logging disabled: cannot create {}: {e}",
+            dir.display()
+        );
+        return;
+    }
+    let _ = LOG_DIR.set(dir);
+}
+
+/// Switch log output to JSON lines (`[observability] json_logs`). Called
+/// after the config is loaded; like [`enable_file_logging`], the layer is
+/// installed at startup and consults this flag per event.
+pub fn enable_json_logs() {
+    JSON_LOGS.store(true, Ordering::Relaxed);
+}
+
+pub fn json_logs_enabled() -> bool {
+    JSON_LOGS.load(Ordering::Relaxed)
+}
+
+/// `MakeWriter` for the file layer. Resolves to the current daily file when
+/// file logging is enabled and to a sink otherwise.
+#[derive(Clone, Copy)]
+pub struct FileWriter;
+
+impl<'a> tracing_subscriber::fmt::MakeWriter<'a> for FileWriter {
+    type Writer = FileWriter;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        FileWriter
+    }
+}
+
+impl Write for FileWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let Some(dir) = LOG_DIR.get() else {
+            return Ok(buf.len());
+        };
+        let today = Utc::now().date_naive();
+        let mut current = match CURRENT_FILE.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        if !matches!(&*current, Some((date, _)) if *date == today) {
+            let file = std::fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(dir.join(file_name(today)))?;
+            *current = Some((today, file));
+            prune_old_files(dir);
+        }
+        match &mut *current {
+            Some((_, file)) => file.write(buf),
+            None => Ok(buf.len()),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        let mut current = match CURRENT_FILE.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        match &mut *current {
+            Some((_, file)) => file.flush(),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Fields of a live span, stored in the span's extensions so events can
+/// inherit correlation fields (`run_id`, `channel`, `agent`, …) from the
+/// enclosing spans.
+struct SpanFields(serde_json::Map<String, serde_json::Value>);
+
+/// Tracing layer that writes events as JSON lines with span correlation
+/// fields flattened into each object (`[observability] json_logs`).
+///
+/// Implemented by hand instead of enabling the `tracing-subscriber/json`
+/// feature: the hand-rolled version is small, avoids the extra dependency
+/// tree, and lets span fields be emitted as top-level keys the way
+/// Loki/Elastic queries expect (rather than nested per-span objects).
+pub struct JsonLayer<W> {
+    make_writer: W,
+}
+
+impl<W> JsonLayer<W> {
+    pub fn new(make_writer: W) -> Self {
+        Self { make_writer }
+    }
+}
+
+impl<S, W> tracing_subscriber::Layer<S> for JsonLayer<W>
+where
+    S: tracing::Subscriber + for<'a> tracing_subscriber::registry::LookupSpan<'a>,
+    W: for<'a> tracing_subscriber::fmt::MakeWriter<'a> + 'static,
+{
+    fn on_new_span(
+        &self,
+        attrs: &tracing::span::Attributes<'_>,
+        id: &tracing::span::Id,
+        ctx: tracing_subscriber::layer::Context<'_, S>,
+    ) {
+        let Some(span) = ctx.span(id) else { return };
+        let mut fields = serde_json::Map::new();
+        attrs.record(&mut JsonVisitor(&mut fields));
+        span.extensions_mut().insert(SpanFields(fields));
+    }
+
+    fn on_record(
+        &self,
+        id: &tracing::span::Id,
+        values: &tracing::span::Record<'_>,
+        ctx: tracing_subscriber::layer::Context<'_, S>,
+    ) {
+        let Some(span) = ctx.span(id) else { return };
+        let mut extensions = span.extensions_mut();
+        if let Some(SpanFields(fields)) = extensions.get_mut::<SpanFields>() {
+            values.record(&mut JsonVisitor(fields));
+        }
+    }
+
+    fn on_event(&self, event: &tracing::Event<'_>, ctx: tracing_subscriber::layer::Context<'_, S>) {
+        if !json_logs_enabled() {
+            return;
+        }
+
+        let mut object = serde_json::Map::new();
+        object.insert(
+            "timestamp".into(),
+            Utc::now()
+                .to_rfc3339_opts(chrono::SecondsFormat::Micros, true)
+                .into(),
+        );
+        object.insert("level".into(), event.metadata().level().to_string().into());
+        object.insert("target".into(), event.metadata().target().into());
+
+        // Span fields outermost-first so inner spans win on key conflicts.
+        if let Some(scope) = ctx.event_scope(event) {
+            for span in scope.from_root() {
+                if let Some(SpanFields(fields)) = span.extensions().get::<SpanFields>() {
+                    for (key, value) in fields {
+                        object.insert(key.clone(), value.clone());
+                    }
+                }
+            }
+        }
+
+        let mut fields = serde_json::Map::new();
+        event.record(&mut JsonVisitor(&mut fields));
+        if let Some(message) = fields.remove("message") {
+            object.insert("message".into(), message);
+        }
+        for (key, value) in fields {
+            object.insert(key, value);
+        }
+
+        let mut writer = self.make_writer.make_writer();
+        let _ = writeln!(writer, "{}", serde_json::Value::Object(object));
+    }
+}
+
+/// Collect tracing fields into a JSON map.
+struct JsonVisitor<'a>(&'a mut serde_json::Map<String, serde_json::Value>);
+
+impl tracing::field::Visit for JsonVisitor<'_> {
+    fn record_str(&mut self, field: &tracing::field::Field, value: &str) {
+        self.0.insert(field.name().into(), value.into());
+    }
+
+    fn record_i64(&mut self, field: &tracing::field::Field, value: i64) {
+        self.0.insert(field.name().into(), value.into());
+    }
+
+    fn record_u64(&mut self, field: &tracing::field::Field, value: u64) {
+        self.0.insert(field.name().into(), value.into());
+    }
+
+    fn record_f64(&mut self, field: &tracing::field::Field, value: f64) {
+        self.0.insert(field.name().into(), value.into());
+    }
+
+    fn record_bool(&mut self, field: &tracing::field::Field, value: bool) {
+        self.0.insert(field.name().into(), value.into());
+    }
+
+    fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+        self.0
+            .insert(field.name().into(), format!("{value:?}").into());
+    }
+}
+
+/// Remove log files beyond the retention window (oldest first).
+fn prune_old_files(dir: &Path) {
+    let mut dates = log_file_dates(dir);
+    if dates.len() <= KEEP_FILES {
+        return;
+    }
+    dates.sort();
+    for date in &dates[..dates.len() - KEEP_FILES] {
+        let _ = std::fs::remove_file(dir.join(file_name(*date)));
+    }
+}
+
+/// Dates of existing log files in `dir`, unsorted.
+fn log_file_dates(dir: &Path) -> Vec<NaiveDate> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    entries
+        .filter_map(|entry| {
+            let name = entry.ok()?.file_name();
+            let name = name.to_str()?;
+            let date = name.strip_prefix("zeroclaw-")?.strip_suffix(".log")?;
+            NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()
+        })
+        .collect()
+}
+
+/// Parse a `--since` bound: a bare duration (`45s`, `30m`, `2h`, `7d`), an
+/// RFC 3339 timestamp, or a `YYYY-MM-DD` date (midnight UTC).
+fn parse_since(input: &str) -> Result<DateTime<Utc>> {
+    if let Some(stripped) = input
+        .strip_suffix(['s', 'm', 'h', 'd'])
+        .filter(|s| !s.is_empty() && s.chars().all(|c| c.is_ascii_digit()))
+    {
+        let value: i64 = stripped.parse()?;
+        let duration = match input.chars().last() {
+            Some('s') => chrono::Duration::seconds(value),
+            Some('m') => chrono::Duration::minutes(value),
+            Some('h') => chrono::Duration::hours(value),
+            _ => chrono::Duration::days(value),
+        };
+        return Ok(Utc::now() - duration);
+    }
+    if let Ok(dt) = DateTime::parse_from_rfc3339(input) {
+        return Ok(dt.with_timezone(&Utc));
+    }
+    NaiveDate::parse_from_str(input, "%Y-%m-%d")
+        .map(|date| date.and_time(chrono::NaiveTime::MIN).and_utc())
+        .map_err(|_| {
+            anyhow::anyhow!("invalid --since '{input}' — use 30m/2h/7d, RFC 3339, or YYYY-MM-DD")
+        })
+}
+
+/// Line filter for component and time bounds.
+///
+/// Component matching is a case-insensitive substring check against the
+/// line, so `gateway` matches the `zeroclaw::gateway` target and
+/// `channel:telegram` (needle `telegram`) matches channel-specific output.
+/// Continuation lines without a parseable timestamp inherit the previous
+/// line's time decision.
+struct LineFilter {
+    needle: Option<String>,
+    since: Option<DateTime<Utc>>,
+    last_in_window: bool,
+}
+
+impl LineFilter {
+    fn new(component: Option<&str>, since: Option<DateTime<Utc>>) -> Self {
+        let needle =
+            component.map(|c| c.strip_prefix("channel:").unwrap_or(c).to_ascii_lowercase());
+        Self {
+            needle,
+            since,
+            last_in_window: since.is_none(),
+        }
+    }
+
+    fn matches(&mut self, line: &str) -> bool {
+        if let Some(since) = self.since {
+            if let Some(ts) = line
+                .split_whitespace()
+                .next()
+                .and_then(|raw| DateTime::parse_from_rfc3339(raw).ok())
+            {
+                self.last_in_window = ts.with_timezone(&Utc) >= since;
+            }
+            if !self.last_in_window {
+                return false;
+            }
+        }
+        match &self.needle {
+            Some(needle) => line.to_ascii_lowercase().contains(needle),
+            None => true,
+        }
+    }
+}
+
+/// Handle `zeroclaw logs`.
+pub(crate) async fn handle_command(
+    config: &Config,
+    component: Option<&str>,
+    follow: bool,
+    since: Option<&str>,
+) -> Result<()> {
+    let dir = log_dir(config);
+    let since = since.map(parse_since).transpose()?;
+    let mut filter = LineFilter::new(component, since);
+
+    let mut dates = log_file_dates(&dir);
+    if dates.is_empty() && !follow {
+        bail!(
+            "No log files found in {} — file logging starts with `zeroclaw daemon` or `zeroclaw gateway`",
+            dir.display()
+        );
+    }
+    dates.sort();
+    if let Some(since) = since {
+        dates.retain(|date| *date >= since.date_naive());
+    }
+
+    let mut stdout = io::stdout().lock();
+    for date in &dates {
+        let path = dir.join(file_name(*date));
+        let Ok(file) = File::open(&path) else {
+            continue;
+        };
+        for line in io::BufReader::new(file).lines() {
+            let line = line?;
+            if filter.matches(&line) {
+                writeln!(stdout, "{line}")?;
+            }
+        }
+    }
+    stdout.flush()?;
+
+    if follow {
+        follow_logs(&dir, &mut filter).await?;
+    }
+    Ok(())
+}
+
+/// Tail the current daily file, switching files at the UTC date boundary.
+async fn follow_logs(dir: &Path, filter: &mut LineFilter) -> Result<()> {
+    let mut date = Utc::now().date_naive();
+    let mut offset = std::fs::metadata(dir.join(file_name(date)))
+        .map(|meta| meta.len())
+        .unwrap_or(0);
+
+    loop {
+        tokio::time::sleep(std::time::Duration::from_millis(FOLLOW_POLL_MS)).await;
+
+        let today = Utc::now().date_naive();
+        if today != date {
+            date = today;
+            offset = 0;
+        }
+        let path = dir.join(file_name(date));
+        let Ok(mut file) = File::open(&path) else {
+            continue;
+        };
+        file.seek(io::SeekFrom::Start(offset))
+            .with_context(|| format!("Failed to seek in {}", path.display()))?;
+        let mut reader = io::BufReader::new(file);
+        let mut line = String::new();
+        loop {
+            line.clear();
+            let read = reader.read_line(&mut line)?;
+            // Stop at a partial trailing line; re-read it complete next poll.
+            if read == 0 || !line.ends_with('\n') {
+                break;
+            }
+            offset += read as u64;
+            let line = line.trim_end_matches('\n');
+            if filter.matches(line) {
+                println!("{line}");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `MakeWriter` capturing output in memory for layer tests.
+    #[derive(Clone, Default)]
+    struct CaptureWriter(std::sync::Arc<Mutex<Vec<u8>>>);
+
+    impl Write for CaptureWriter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl<'a> tracing_subscriber::fmt::MakeWriter<'a> for CaptureWriter {
+        type Writer = CaptureWriter;
+
+        fn make_writer(&'a self) -> Self::Writer {
+            self.clone()
+        }
+    }
+
+    #[test]
+    fn json_layer_flattens_span_correlation_fields_into_events() {
+        use tracing_subscriber::layer::SubscriberExt as _;
+
+        enable_json_logs();
+        let capture = CaptureWriter::default();
+        let subscriber = tracing_subscriber::registry().with(JsonLayer::new(capture.clone()));
+        tracing::subscriber::with_default(subscriber, || {
+            let span = tracing::info_span!(
+                "delegation",
+                run_id = "run-1",
+                agent = "researcher",
+                depth = 1u64
+            );
+            let _guard = span.enter();
+            tracing::info!(tool = "shell", "tool finished");
+        });
+
+        let raw = String::from_utf8(capture.0.lock().unwrap().clone()).unwrap();
+        let line: serde_json::Value = serde_json::from_str(raw.lines().next().unwrap()).unwrap();
+        assert_eq!(line["level"], "INFO");
+        assert_eq!(line["message"], "tool finished");
+        assert_eq!(line["run_id"], "run-1");
+        assert_eq!(line["agent"], "researcher");
+        assert_eq!(line["depth"], 1);
+        assert_eq!(line["tool"], "shell");
+        assert!(line["timestamp"].as_str().unwrap().ends_with('Z'));
+    }
+
+    #[test]
+    fn parse_since_accepts_durations_dates_and_timestamps() {
+        let now = Utc::now();
+        let bound = parse_since("2h").unwrap();
+        assert!((now - bound).num_minutes() >= 119);
+        assert!((now - bound).num_minutes() <= 121);
+        assert_eq!(
+            parse_since("2026-08-01").unwrap(),
+            NaiveDate::from_ymd_opt(2026, 8, 1)
+                .unwrap()
+                .and_time(chrono::NaiveTime::MIN)
+                .and_utc()
+        );
+        assert!(parse_since("2026-08-01T10:00:00Z").is_ok());
+        assert!(parse_since("yesterday").is_err());
+        assert!(parse_since("h").is_err());
+    }
+
+    #[test]
+    fn line_filter_matches_component_case_insensitively() {
+        let mut filter = LineFilter::new(Some("channel:telegram"), None);
+        assert!(filter.matches("2026-08-28T10:00:00Z  INFO zeroclaw::channels::telegram: polled"));
+        assert!(!filter.matches("2026-08-28T10:00:00Z  INFO zeroclaw::gateway: request"));
+
+        let mut gateway = LineFilter::new(Some("gateway"), None);
+        assert!(gateway.matches("2026-08-28T10:00:00Z  INFO zeroclaw::gateway: request"));
+    }
+
+    #[test]
+    fn line_filter_applies_since_and_continuation_lines_inherit() {
+        let since = "2026-08-28T10:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        let mut filter = LineFilter::new(None, Some(since));
+        assert!(!filter.matches("2026-08-28T09:59:59.000000Z  INFO old line"));
+        assert!(!filter.matches("  continuation of the old line"));
+        assert!(filter.matches("2026-08-28T10:00:01.000000Z  INFO new line"));
+        assert!(filter.matches("  continuation of the new line"));
+    }
+
+    #[test]
+    fn log_file_dates_ignores_foreign_files() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("zeroclaw-2026-08-27.log"), "x").unwrap();
+        std::fs::write(dir.path().join("zeroclaw-2026-08-28.log"), "x").unwrap();
+        std::fs::write(dir.path().join("notes.txt"), "x").unwrap();
+        std::fs::write(dir.path().join("zeroclaw-bad-date.log"), "x").unwrap();
+        let mut dates = log_file_dates(dir.path());
+        dates.sort();
+        assert_eq!(
+            dates,
+            vec![
+                NaiveDate::from_ymd_opt(2026, 8, 27).unwrap(),
+                NaiveDate::from_ymd_opt(2026, 8, 28).unwrap(),
+            ]
+        );
+    }
+
+    #[test]
+    fn prune_old_files_keeps_retention_window() {
+        let dir = tempfile::tempdir().unwrap();
+        for day in 1..=10 {
+            let date = NaiveDate::from_ymd_opt(2026, 8, day).unwrap();
+            std::fs::write(dir.path().join(file_name(date)), "x").unwrap();
+        }
+        prune_old_files(dir.path());
+        let mut dates = log_file_dates(dir.path());
+        dates.sort();
+        assert_eq!(dates.len(), KEEP_FILES);
+        assert_eq!(dates[0], NaiveDate::from_ymd_opt(2026, 8, 4).unwrap());
+    }
+}
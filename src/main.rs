@@ -32,7 +32,7 @@
     dead_code
 )]
 
-use anyhow::{bail, Result};
+use anyhow::{bail, Context as _, Result};
 use clap::{CommandFactory, Parser, Subcommand, ValueEnum};
 use dialoguer::{Input, Password};
 use serde::{Deserialize, Serialize};
@@ -52,40 +52,59 @@ mod agent;
 mod approval;
 mod auth;
 mod channels;
+mod chaos;
+mod ci;
 mod cost;
 mod rag {
     pub use zeroclaw::rag::*;
 }
 mod config;
+mod contacts;
 mod cron;
 mod daemon;
 mod doctor;
+mod eval;
 mod gateway;
 mod hardware;
 mod health;
 mod heartbeat;
 mod identity;
 mod integrations;
+mod jobs;
+mod logs;
 mod memory;
 mod migration;
+mod monitors;
 mod multimodal;
+mod nodes;
 mod observability;
 mod onboard;
+mod pager;
 mod peripherals;
 mod providers;
+mod quota;
 mod runtime;
 mod security;
 mod service;
+mod sessions;
 mod skillforge;
 mod skills;
+mod terraform;
+mod tokens;
 mod tools;
+mod top;
 mod tunnel;
+mod undo;
 mod util;
+mod workflow;
 
 use config::Config;
 
 // Re-export so binary's hardware/peripherals modules can use crate::HardwareCommands etc.
-pub use zeroclaw::{HardwareCommands, PeripheralCommands};
+pub use zeroclaw::{
+    AgentCommands, ContactCommands, EvalCommands, HardwareCommands, PeripheralCommands,
+    SessionsCommands, TerraformCommands, WorkflowCommands,
+};
 
 /// `ZeroClaw` - Zero overhead. Zero compromise. 100% Rust.
 #[derive(Parser, Debug)]
@@ -94,6 +113,21 @@ pub use zeroclaw::{HardwareCommands, PeripheralCommands};
 #[command(version = "0.1.0")]
 #[command(about = "The fastest, smallest AI assistant.", long_about = None)]
 struct Cli {
+    /// Named config profile overlay from ~/.zeroclaw/profiles/<NAME>.toml
+    /// (equivalent to setting ZEROCLAW_PROFILE)
+    #[arg(long, value_name = "NAME")]
+    profile: Option<String>,
+
+    /// Record all provider interactions to a VCR-style cassette file
+    /// (equivalent to setting ZEROCLAW_RECORD)
+    #[arg(long, value_name = "CASSETTE", conflicts_with = "replay")]
+    record: Option<std::path::PathBuf>,
+
+    /// Replay provider interactions from a cassette instead of calling
+    /// providers (equivalent to setting ZEROCLAW_REPLAY)
+    #[arg(long, value_name = "CASSETTE")]
+    replay: Option<std::path::PathBuf>,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -108,10 +142,38 @@ enum ServiceCommands {
     Stop,
     /// Restart daemon service to apply latest config
     Restart,
+    /// Reload config and skills in the running daemon without restart (SIGHUP)
+    Reload,
     /// Check daemon service status
     Status,
     /// Uninstall daemon service unit
     Uninstall,
+    /// Generate a Dockerfile and docker-compose.yml for containerized deployment
+    GenerateDocker {
+        /// Directory to write the deployment files into
+        #[arg(long, default_value = ".")]
+        dir: std::path::PathBuf,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum CacheCommands {
+    /// Show cache entry count, size, and hit rate
+    Stats,
+    /// Remove all cached responses
+    Clear,
+}
+
+#[derive(Subcommand, Debug)]
+enum TokensCommands {
+    /// Estimate the token count of a file (or stdin with `-`)
+    Count {
+        /// Model to count for (default: configured default model)
+        #[arg(long)]
+        model: Option<String>,
+        /// File to count, or `-` for stdin
+        file: String,
+    },
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, ValueEnum)]
@@ -153,6 +215,14 @@ enum Commands {
         /// Memory backend (sqlite, lucid, markdown, none) - used in quick mode, default: sqlite
         #[arg(long)]
         memory: Option<String>,
+
+        /// Workspace template (ops-bot, research-assistant) - used in quick mode
+        #[arg(long)]
+        template: Option<String>,
+
+        /// Full non-interactive setup from a declarative TOML file (no prompts)
+        #[arg(long, value_name = "PATH")]
+        from_file: Option<std::path::PathBuf>,
     },
 
     /// Start the AI agent loop
@@ -187,6 +257,21 @@ Examples:
         /// Attach a peripheral (board:path, e.g. nucleo-f401re:/dev/ttyACM0)
         #[arg(long)]
         peripheral: Vec<String>,
+
+        /// Dry run: print the tool calls the agent would make without
+        /// executing anything (requires --message)
+        #[arg(long)]
+        plan_only: bool,
+
+        /// Tag delegation events from this run (repeatable, e.g.
+        /// --tag project:foo) for cost attribution in `delegations tags`
+        #[arg(long = "tag")]
+        tags: Vec<String>,
+
+        /// Attach an image to the message for vision-capable models
+        /// (repeatable; requires --message)
+        #[arg(long, value_name = "PATH")]
+        image: Vec<std::path::PathBuf>,
     },
 
     /// Start the gateway server (webhooks, websockets)
@@ -248,10 +333,76 @@ Examples:
     Doctor {
         #[command(subcommand)]
         doctor_command: Option<DoctorCommands>,
+
+        /// Screen-reader friendly output (plain labels, no emoji/tables)
+        #[arg(long, global = true)]
+        accessible: bool,
     },
 
     /// Show system status (full details)
-    Status,
+    Status {
+        /// Screen-reader friendly output (plain labels, no emoji/tables)
+        #[arg(long)]
+        accessible: bool,
+
+        /// Show per-component daemon supervision state (restarts, last error)
+        #[arg(long)]
+        components: bool,
+
+        /// Show per-tool execution metrics (success rate, latency, last error)
+        #[arg(long)]
+        tools: bool,
+    },
+
+    /// Live dashboard of the running daemon (refreshes until Ctrl+C)
+    #[command(long_about = "\
+Live dashboard of the running daemon.
+
+Shows component health, per-channel message throughput, today's cost \
+against the daily budget, in-flight delegations, and the scheduler's \
+next firings, refreshed in place until interrupted. Reads the state \
+the daemon persists on disk, so it works from a separate terminal.
+
+When stdout is not a terminal a single frame is printed instead.
+
+Examples:
+  zeroclaw top
+  zeroclaw top --interval 5")]
+    Top {
+        /// Seconds between refreshes
+        #[arg(long, default_value_t = 2)]
+        interval: u64,
+    },
+
+    /// Read the daemon's log files (with component/time filtering)
+    #[command(long_about = "\
+Read the daemon's log files.
+
+`zeroclaw daemon` and `zeroclaw gateway` mirror their output into daily \
+log files under <zeroclaw dir>/logs/ (7 days retained), so the history \
+is inspectable the same way on every platform.
+
+--component filters case-insensitively by component or module name; \
+--since accepts a duration (30m, 2h, 7d), an RFC 3339 timestamp, or a \
+YYYY-MM-DD date.
+
+Examples:
+  zeroclaw logs
+  zeroclaw logs --component channel:telegram --since 1h
+  zeroclaw logs --component scheduler --follow")]
+    Logs {
+        /// Only lines for this component (e.g. gateway, scheduler, channel:telegram)
+        #[arg(long)]
+        component: Option<String>,
+
+        /// Keep the log open and print new lines as they arrive
+        #[arg(long, short = 'f')]
+        follow: bool,
+
+        /// Only lines newer than this (duration, RFC 3339, or YYYY-MM-DD)
+        #[arg(long)]
+        since: Option<String>,
+    },
 
     /// Configure and manage scheduled tasks
     #[command(long_about = "\
@@ -278,6 +429,40 @@ Examples:
         cron_command: CronCommands,
     },
 
+    /// Manage background jobs
+    #[command(long_about = "\
+Manage background jobs enqueued by the agent via the delegate_async tool.
+
+Jobs run in the daemon one at a time, persist across restarts, and can \
+deliver their result back to the originating channel.
+
+Examples:
+  zeroclaw jobs list
+  zeroclaw jobs status <job-id>
+  zeroclaw jobs cancel <job-id>")]
+    Jobs {
+        #[command(subcommand)]
+        jobs_command: JobsCommands,
+    },
+
+    /// Manage HTTP uptime monitors
+    #[command(long_about = "\
+Manage HTTP uptime monitors.
+
+Monitors check user-defined endpoints on their own interval, keep a \
+local latency history, and alert the [monitors] channel on downtime \
+and recovery. Checks run from the daemon when [monitors] enabled = true.
+
+Examples:
+  zeroclaw monitor add https://example.com --interval 5m --expect 200
+  zeroclaw monitor list
+  zeroclaw monitor history <monitor-id> --limit 50
+  zeroclaw monitor remove <monitor-id>")]
+    Monitor {
+        #[command(subcommand)]
+        monitor_command: MonitorCommands,
+    },
+
     /// Manage provider model catalogs
     Models {
         #[command(subcommand)]
@@ -306,6 +491,111 @@ Examples:
         channel_command: ChannelCommands,
     },
 
+    /// Inspect the configured sub-agent roster used by `delegate`
+    #[command(long_about = "\
+Inspect the sub-agent roster defined under [agents.<name>] in config.toml.
+
+Sub-agents are the only targets the `delegate` tool and the workflow \
+engine will run; each carries its own provider, model, system prompt, \
+temperature, and (in agentic mode) tool allowlist.
+
+Examples:
+  zeroclaw agents list
+  zeroclaw agents show researcher")]
+    Agents {
+        #[command(subcommand)]
+        agent_command: zeroclaw::AgentCommands,
+    },
+
+    /// Manage the contact book (people, channel identities, timezones)
+    #[command(long_about = "\
+Manage the contact book.
+
+Contacts map people to channel identities, email, and IANA timezone \
+so the agent can resolve 'message Alice on Slack' and reason about \
+local times.
+
+Examples:
+  zeroclaw contacts list
+  zeroclaw contacts add 'Alice' --channel slack:U12345 --timezone America/New_York
+  zeroclaw contacts show Alice
+  zeroclaw contacts remove Alice")]
+    Contacts {
+        #[command(subcommand)]
+        contact_command: zeroclaw::ContactCommands,
+    },
+
+    /// Run multi-agent workflow pipelines
+    #[command(long_about = "\
+Run named multi-agent pipelines defined in <workspace>/workflows/<name>.yaml.
+
+Each step delegates to an agent from [agents.<name>] with a prompt template; \
+outputs flow to later steps via {{input}} and {{steps.<name>}} placeholders.
+
+Examples:
+  zeroclaw workflow list
+  zeroclaw workflow show research
+  zeroclaw workflow run research --input 'battery recycling'")]
+    Workflow {
+        #[command(subcommand)]
+        workflow_command: zeroclaw::WorkflowCommands,
+    },
+
+    /// Run prompt/expectation eval suites against the configured provider
+    #[command(after_help = "Suites are YAML files of prompt + expectation cases \
+(exact / contains / regex / llm_judge). Reports are written to \
+<workspace>/state/eval/ and the command exits non-zero when any case fails.
+
+Examples:
+  zeroclaw eval run suites/smoke.yaml
+  zeroclaw eval run suites/smoke.yaml --model model-a --model model-b")]
+    Eval {
+        #[command(subcommand)]
+        eval_command: zeroclaw::EvalCommands,
+    },
+
+    /// Roll back agent-initiated file writes and config edits
+    #[command(
+        after_help = "Each file_write tool write and `config set/unset` edit records a \
+reverse patch in <workspace>/state/undo/journal.jsonl; undo replays them newest-first.
+
+Examples:
+  zeroclaw undo                 # roll back the most recent mutation
+  zeroclaw undo --last 3        # roll back the three most recent mutations
+  zeroclaw undo --run <run-id>  # roll back everything from one agent run
+  zeroclaw undo --list          # inspect the journal without changing files"
+    )]
+    Undo {
+        /// Roll back the most recent N mutations (default 1)
+        #[arg(long, value_name = "N", conflicts_with = "run")]
+        last: Option<usize>,
+        /// Roll back all mutations recorded for an agent run ID
+        #[arg(long, value_name = "RUN_ID")]
+        run: Option<String>,
+        /// List journal entries without rolling anything back
+        #[arg(long)]
+        list: bool,
+    },
+
+    /// List and export stored conversation transcripts
+    #[command(after_help = "CLI agent runs persist their transcript to \
+<workspace>/state/sessions/<id>.json. Exports pass through the secret redactor.
+
+Examples:
+  zeroclaw sessions list
+  zeroclaw sessions export <id>
+  zeroclaw sessions export <id> --format html --output session.html")]
+    Sessions {
+        #[command(subcommand)]
+        sessions_command: zeroclaw::SessionsCommands,
+    },
+
+    /// Summarize Terraform plans for approval workflows
+    Terraform {
+        #[command(subcommand)]
+        terraform_command: zeroclaw::TerraformCommands,
+    },
+
     /// Browse 50+ integrations
     Integrations {
         #[command(subcommand)]
@@ -318,6 +608,22 @@ Examples:
         skill_command: SkillCommands,
     },
 
+    /// SkillForge: generate skill scaffolds from descriptions
+    #[command(long_about = "\
+SkillForge skill scaffolding.
+
+Generates a skeleton skill (manifest, prompt, tool stubs, review \
+examples) from a natural-language description using the configured \
+provider. The scaffold is written disabled (SKILL.toml.disabled) \
+and must be reviewed and renamed to SKILL.toml before it activates.
+
+Examples:
+  zeroclaw skillforge new \"summarize my RSS feeds every morning\"")]
+    Skillforge {
+        #[command(subcommand)]
+        skillforge_command: SkillforgeCommands,
+    },
+
     /// Migrate data from other agent runtimes
     Migrate {
         #[command(subcommand)]
@@ -382,6 +688,24 @@ Examples:
         config_command: ConfigCommands,
     },
 
+    /// Manage named workspaces (isolated config, memory, and cron state)
+    #[command(long_about = "\
+Manage named workspaces.
+
+Each workspace is a fully isolated setup (own config.toml, workspace \
+files, memory, delegation log, and cron tasks) under \
+~/.zeroclaw/workspaces/<name>. 'use' switches which workspace every \
+later command runs against; 'use default' returns to ~/.zeroclaw.
+
+Examples:
+  zeroclaw workspace create ops --template ops-bot
+  zeroclaw workspace list
+  zeroclaw workspace use ops")]
+    Workspace {
+        #[command(subcommand)]
+        workspace_command: WorkspaceCommands,
+    },
+
     /// Inspect agent delegation history from the local log
     #[command(long_about = "\
 Inspect agent delegation history from the local log.
@@ -404,8 +728,16 @@ Examples:
   zeroclaw delegations top --by cost --limit 5  # top 5 by cost
   zeroclaw delegations prune         # keep 20 most recent runs, remove the rest
   zeroclaw delegations prune --keep 5  # keep only 5 most recent runs
+  zeroclaw delegations validate      # check log lines against the event schema
+  zeroclaw delegations validate --quarantine  # move malformed lines aside
+  zeroclaw delegations --since 7d stats       # any report scoped to a window
+  zeroclaw delegations --since 2026-02-16 --until 2026-02-23 cost
   zeroclaw delegations models        # model breakdown: tokens and cost per model
   zeroclaw delegations models --run <id>  # model breakdown for one run
+  zeroclaw delegations tags          # tag breakdown: tokens and cost per tag
+  zeroclaw delegations --tag project:foo cost  # any report scoped to one tag
+  zeroclaw delegations channels      # channel breakdown: tokens and cost per channel
+  zeroclaw delegations channels --run <id>  # channel breakdown for one run
   zeroclaw delegations providers     # provider breakdown: tokens and cost per provider
   zeroclaw delegations providers --run <id>  # provider breakdown for one run
   zeroclaw delegations depth         # depth breakdown: delegations per nesting level
@@ -432,10 +764,116 @@ Examples:
   zeroclaw delegations depth-view 0                   # all root-level delegations, newest first
   zeroclaw delegations depth-view 1 --run <id>        # depth-1 delegations for one run
   zeroclaw delegations daily                           # per-day breakdown across all runs
-  zeroclaw delegations daily --run <id>               # per-day breakdown for one run")]
+  zeroclaw delegations daily --run <id>               # per-day breakdown for one run
+  zeroclaw delegations forecast                        # project end-of-month spend
+  zeroclaw delegations anomalies                       # flag cost/failure-rate outliers
+  zeroclaw delegations anomalies --threshold 3         # only extreme outliers
+  zeroclaw delegations report --html report.html       # shareable HTML dashboard")]
     Delegations {
         #[command(subcommand)]
         delegation_command: Option<DelegationCommands>,
+
+        /// Screen-reader friendly output (plain labels, no emoji/tables)
+        #[arg(long, global = true)]
+        accessible: bool,
+
+        /// Only include events at/after this time (RFC 3339, YYYY-MM-DD,
+        /// or relative like 30m/2h/7d); place before the subcommand
+        #[arg(long)]
+        since: Option<String>,
+
+        /// Only include events before this time (same formats as --since);
+        /// place before the subcommand
+        #[arg(long)]
+        until: Option<String>,
+
+        /// Only include events carrying this tag (set via `agent --tag`
+        /// or cron job tags); place before the subcommand
+        #[arg(long)]
+        tag: Option<String>,
+    },
+
+    /// Inspect and manage the provider response cache
+    #[command(long_about = "\
+Inspect and manage the provider response cache ([cache]).
+
+When [cache] enabled = true, identical provider requests within the TTL
+are served from ~/.zeroclaw/cache/ instead of re-billing tokens.
+
+Examples:
+  zeroclaw cache stats   # entry count, size, hit rate
+  zeroclaw cache clear   # drop all cached responses")]
+    Cache {
+        #[command(subcommand)]
+        cache_command: CacheCommands,
+    },
+
+    /// Estimate token counts per provider/model
+    #[command(long_about = "\
+Estimate token counts per provider/model.
+
+Counts are segmentation-based estimates (within roughly ±15% of billed
+counts); exact counts require each vendor's tokenizer vocabulary.
+
+Examples:
+  zeroclaw tokens count --model gpt-4o notes.md
+  cat prompt.txt | zeroclaw tokens count -")]
+    Tokens {
+        #[command(subcommand)]
+        tokens_command: TokensCommands,
+    },
+
+    /// Inspect and verify the tamper-evident audit log
+    #[command(long_about = "\
+Inspect and verify the hash-chained audit log ([security.audit]).
+
+Every shell command, file write, network call, and approval decision is
+appended as a hash-chained entry; editing or removing past entries
+breaks the chain and is reported by `verify`.
+
+Examples:
+  zeroclaw audit show                      # last 50 entries
+  zeroclaw audit show --limit 200          # last 200 entries
+  zeroclaw audit verify                    # check the hash chain
+  zeroclaw audit export                    # verified JSON to stdout
+  zeroclaw audit export --output audit.json")]
+    Audit {
+        #[command(subcommand)]
+        audit_command: AuditCommands,
+    },
+
+    /// Inspect and dry-run autonomy policy rules
+    #[command(long_about = "\
+Inspect and dry-run the autonomy policy rules ([[autonomy.policies]]).
+
+Rules match tool name, argument patterns, path globs, local-time windows,
+and cost thresholds, and resolve to allow, deny, or require-approval.
+`policy test` evaluates an action against the configured rules without
+executing anything.
+
+Examples:
+  zeroclaw policy test '{\"tool\": \"shell\", \"args\": \"git push\"}'
+  zeroclaw policy test '{\"tool\": \"file_write\", \"path\": \"/etc/hosts\"}'
+  zeroclaw policy test '{\"tool\": \"delegate\", \"cost_cents\": 120}'")]
+    Policy {
+        #[command(subcommand)]
+        policy_command: PolicyCommands,
+    },
+
+    /// Manage secret storage (encrypted file / OS keychain)
+    #[command(long_about = "\
+Manage how ZeroClaw stores secrets ([secrets] in config.toml).
+
+By default secrets are encrypted into config.toml with a key in
+~/.zeroclaw/.secret_key. With `backend = \"keychain\"` they live in the
+OS credential vault (macOS Keychain, Linux Secret Service) and the
+config file holds only opaque keychain references.
+
+Examples:
+  zeroclaw secrets migrate-keychain   # move existing secrets into the keychain")]
+    Secrets {
+        #[command(subcommand)]
+        secrets_command: SecretsCommands,
     },
 
     /// Generate shell completion script to stdout
@@ -455,6 +893,49 @@ Examples:
     },
 }
 
+/// SkillForge subcommands
+#[derive(Subcommand, Debug)]
+enum SkillforgeCommands {
+    /// Generate a disabled skill scaffold from a natural-language description
+    New {
+        /// What the skill should do, in plain language
+        description: String,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum PolicyCommands {
+    /// Dry-run one action (JSON) against the configured policy rules
+    Test {
+        /// Action JSON, e.g. '{"tool": "shell", "args": "git push"}'
+        action: String,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum SecretsCommands {
+    /// Move existing config secrets into the OS keychain (requires `[secrets] backend = "keychain"`)
+    MigrateKeychain,
+}
+
+#[derive(Subcommand, Debug)]
+enum AuditCommands {
+    /// Print the most recent audit entries
+    Show {
+        /// Number of entries to display
+        #[arg(long, default_value_t = 50)]
+        limit: usize,
+    },
+    /// Verify the hash chain and report the first broken entry, if any
+    Verify,
+    /// Export the verified audit log as a JSON array
+    Export {
+        /// Write to a file instead of stdout
+        #[arg(long)]
+        output: Option<std::path::PathBuf>,
+    },
+}
+
 #[derive(Subcommand, Debug)]
 enum DelegationCommands {
     /// List all stored runs, newest first
@@ -471,14 +952,18 @@ enum DelegationCommands {
         #[arg(long)]
         run: Option<String>,
     },
-    /// Export delegation events as JSONL (default) or CSV to stdout
+    /// Export delegation events as JSONL (default), CSV, or OTLP spans
     Export {
         /// Filter to a specific run ID (default: all runs)
         #[arg(long)]
         run: Option<String>,
-        /// Output format: jsonl (one event per line) or csv (DelegationEnd rows only)
+        /// Output format: jsonl (one event per line), csv (DelegationEnd rows only),
+        /// or otlp (replay spans into an OTLP endpoint)
         #[arg(long, value_enum, default_value = "jsonl")]
         format: DelegationExportFormat,
+        /// OTLP endpoint for `--format otlp` (default: `[observability] otel_endpoint`)
+        #[arg(long)]
+        endpoint: Option<String>,
     },
     /// Show global agent leaderboard ranked by tokens or cost (all runs)
     #[command(long_about = "\
@@ -519,6 +1004,28 @@ Examples:
         #[arg(long, default_value_t = 20)]
         keep: usize,
     },
+    /// Check every log line against the versioned event schema
+    #[command(long_about = "\
+Check every delegation log line against the versioned event schema.
+
+Each line is classified as current (carries the schema_version this
+build writes), legacy (pre-versioning line accepted via the lenient
+fallback), or malformed (invalid JSON, missing required fields, or an
+unsupported schema_version). Malformed lines are reported with their
+line number and parse failure reason.
+
+With --quarantine, malformed lines are appended to
+<log>.quarantine and the log is rewritten atomically with only the
+lines that parse, so no data is ever silently discarded.
+
+Examples:
+  zeroclaw delegations validate               # report malformed lines
+  zeroclaw delegations validate --quarantine  # move malformed lines aside")]
+    Validate {
+        /// Move malformed lines to <log>.quarantine and rewrite the log
+        #[arg(long)]
+        quarantine: bool,
+    },
     /// Show per-model token and cost breakdown (all runs or one run)
     #[command(long_about = "\
 Aggregate delegation events by model and print a breakdown table.
@@ -536,6 +1043,47 @@ Examples:
         #[arg(long)]
         run: Option<String>,
     },
+    /// Show per-tag token and cost breakdown (all runs or one run)
+    #[command(long_about = "\
+Aggregate delegation events by tag and print a breakdown table.
+
+Tags are attached to events via `zeroclaw agent --tag <tag>` or a cron
+job's tags, so cost can be attributed to projects. An event carrying
+several tags is counted under each of them. Rows are sorted by
+cumulative tokens descending. Use `--run` to scope to a single process
+invocation; omit it to aggregate across all runs.
+
+Output columns: # | tag | runs | delegations | tokens | cost
+
+Examples:
+  zeroclaw delegations tags              # all runs, sorted by tokens
+  zeroclaw delegations tags --run <id>  # scope to one run")]
+    Tags {
+        /// Scope to a specific run ID (default: aggregate across all runs)
+        #[arg(long)]
+        run: Option<String>,
+    },
+    /// Show per-channel token and cost breakdown (all runs or one run)
+    #[command(long_about = "\
+Aggregate delegation events by originating channel and print a breakdown table.
+
+Runs dispatched by a chat channel (Telegram, Discord, etc.) stamp their
+delegation events with the channel name, so cost can be attributed to the
+surface that triggered the work. CLI and scheduler runs carry no channel
+and are not counted here. Rows are sorted by cumulative tokens descending.
+Use `--run` to scope to a single process invocation; omit it to aggregate
+across all runs.
+
+Output columns: # | channel | runs | delegations | tokens | cost
+
+Examples:
+  zeroclaw delegations channels              # all runs, sorted by tokens
+  zeroclaw delegations channels --run <id>  # scope to one run")]
+    Channels {
+        /// Scope to a specific run ID (default: aggregate across all runs)
+        #[arg(long)]
+        run: Option<String>,
+    },
     /// Show per-provider token and cost breakdown (all runs or one run)
     #[command(long_about = "\
 Aggregate delegation events by provider and print a breakdown table.
@@ -609,6 +1157,29 @@ Examples:
         #[arg(long, default_value_t = 10)]
         limit: usize,
     },
+    /// Show per-tool call analytics: count, failures, latency, output volume
+    #[command(long_about = "\
+Aggregate tool-call events (`ToolCallEnd`) by tool name and print an
+analytics table, sorted by cumulative duration descending so the tools
+that dominate latency appear first.
+
+Use `tools slow` to list the slowest individual tool calls instead of
+the per-tool aggregate.
+
+Output columns: # | tool | calls | fail | success% | total | avg | output
+
+Examples:
+  zeroclaw delegations tools              # per-tool analytics, all runs
+  zeroclaw delegations tools --run <id>  # per-tool analytics for one run
+  zeroclaw delegations tools slow         # 10 slowest individual tool calls
+  zeroclaw delegations tools slow --limit 5  # 5 slowest tool calls")]
+    Tools {
+        /// Scope to a specific run ID (default: aggregate across all runs)
+        #[arg(long)]
+        run: Option<String>,
+        #[command(subcommand)]
+        tool_command: Option<DelegationToolCommands>,
+    },
     /// Show per-run cost breakdown sorted by total cost descending
     #[command(long_about = "\
 Show a per-run cost breakdown table, sorted by total cost descending (most expensive first).
@@ -844,6 +1415,48 @@ Examples:
         #[arg(long)]
         run: Option<String>,
     },
+    /// Project end-of-month spend from the daily cost trend
+    #[command(long_about = "\
+Fit a least-squares line to this month's per-day delegation cost and
+extrapolate it over the remaining days of the month.  Days without
+delegations count as $0, so quiet days pull the trend down.
+
+Prints month-to-date spend, the average and trend per day, and two
+end-of-month projections: one following the fitted trend, one assuming
+the flat daily average continues.
+
+Examples:
+  zeroclaw delegations forecast   # project this month's spend")]
+    Forecast,
+    /// Flag days/agents deviating from their rolling cost or failure-rate average
+    #[command(long_about = "\
+Scan the per-day cost series (overall and per agent) and each agent's
+per-day failure rate for values deviating more than `--threshold`
+standard deviations from the rolling 7-day average.  At least 3 prior
+days of data are required before a day can be judged anomalous.
+
+Examples:
+  zeroclaw delegations anomalies                 # default 2.0σ threshold
+  zeroclaw delegations anomalies --threshold 3   # only extreme outliers")]
+    Anomalies {
+        /// Deviation threshold in standard deviations
+        #[arg(long, default_value_t = 2.0)]
+        threshold: f64,
+    },
+    /// Export a shareable HTML dashboard of the delegation log
+    #[command(long_about = "\
+Render the overall summary, per-agent/model/provider breakdowns, and the
+daily cost chart into a single self-contained HTML file.  Styles, data,
+and the chart script are all inline — no external fetches — so the file
+can be shared as-is with people who don't use the terminal views.
+
+Examples:
+  zeroclaw delegations report --html report.html")]
+    Report {
+        /// Output path for the HTML file
+        #[arg(long)]
+        html: std::path::PathBuf,
+    },
     /// Per-calendar-quarter delegation breakdown, oldest quarter first
     #[command(long_about = "\
 Aggregate all completed delegations by UTC calendar quarter (YYYY-QN),
@@ -1154,23 +1767,51 @@ Examples:
         #[arg(long)]
         run: Option<String>,
     },
-    /// Compare per-agent stats between two runs side by side
+    /// Compare per-agent stats between two runs or two time periods
     #[command(long_about = "\
-Compare per-agent delegation statistics between two runs side-by-side.
+Compare per-agent delegation statistics side-by-side.
 
 Run IDs may be given as a full UUID or any unique prefix.  When <run_b>
 is omitted the most recent stored run that is not <run_a> is used.
 
+With --since (and optionally --until), events from all runs are compared
+by time period instead: B spans [since, until) and A is the preceding
+window of the same length, so '--since <monday>' compares this week
+against last week. Bounds accept RFC 3339 or YYYY-MM-DD (midnight UTC).
+
 Output columns: agent | del_A | del_B | tok_A | tok_B | Δtok | cost_A | cost_B | Δcost
 
 Examples:
   zeroclaw delegations diff f47ac10b          # vs most recent other run
-  zeroclaw delegations diff f47ac10b bbb1bbb2 # explicit pair")]
+  zeroclaw delegations diff f47ac10b bbb1bbb2 # explicit pair
+  zeroclaw delegations diff --since 2026-02-16                     # vs prior week-equivalent
+  zeroclaw delegations diff --since 2026-02-16 --until 2026-02-23  # explicit window")]
     Diff {
         /// First run ID or unique prefix (the baseline)
-        run_a: String,
+        #[arg(conflicts_with_all = ["since", "until"])]
+        run_a: Option<String>,
         /// Second run ID or unique prefix (default: most recent other run)
         run_b: Option<String>,
+        /// Start of period B for a time-based comparison (RFC 3339 or YYYY-MM-DD)
+        #[arg(long)]
+        since: Option<String>,
+        /// End of period B, exclusive (default: now)
+        #[arg(long, requires = "since")]
+        until: Option<String>,
+    },
+}
+
+/// Nested subcommands for `zeroclaw delegations tools`
+#[derive(Subcommand, Debug)]
+enum DelegationToolCommands {
+    /// List the N slowest individual tool calls ranked by duration descending
+    Slow {
+        /// Scope to a specific run ID (default: show all runs)
+        #[arg(long)]
+        run: Option<String>,
+        /// Number of rows to display (default: 10)
+        #[arg(long, default_value_t = 10)]
+        limit: usize,
     },
 }
 
@@ -1192,12 +1833,54 @@ enum DelegationExportFormat {
     /// RFC 4180 CSV — one row per DelegationEnd event
     #[value(name = "csv")]
     Csv,
+    /// Replay events as OpenTelemetry spans into an OTLP endpoint
+    #[value(name = "otlp")]
+    Otlp,
 }
 
 #[derive(Subcommand, Debug)]
 enum ConfigCommands {
     /// Dump the full configuration JSON Schema to stdout
     Schema,
+    /// Print the value at a dotted key path (e.g. `gateway.port`)
+    Get {
+        /// Dotted key path
+        key: String,
+    },
+    /// Set a dotted key path, showing a diff before writing
+    Set {
+        /// Dotted key path
+        key: String,
+        /// Value, parsed as TOML (`true`, `0.9`, `["a"]`); bare words become strings
+        value: String,
+    },
+    /// Remove a dotted key path, showing a diff before writing
+    Unset {
+        /// Dotted key path
+        key: String,
+    },
+    /// Lint the config file: schema conformance, unknown keys, referenced
+    /// paths, and channel required fields; exits non-zero on errors
+    Validate,
+}
+
+#[derive(Subcommand, Debug)]
+enum WorkspaceCommands {
+    /// Create a new named workspace (seeded from the current base config)
+    Create {
+        /// Workspace name (ASCII letters, digits, '-', '_')
+        name: String,
+        /// Workspace template to apply (ops-bot, research-assistant)
+        #[arg(long)]
+        template: Option<String>,
+    },
+    /// List workspaces, marking the active one
+    List,
+    /// Switch the active workspace (`use default` returns to ~/.zeroclaw)
+    Use {
+        /// Workspace name
+        name: String,
+    },
 }
 
 #[derive(Subcommand, Debug)]
@@ -1281,6 +1964,8 @@ enum AuthCommands {
     List,
     /// Show auth status with active profile and token expiry info
     Status,
+    /// Check all profiles for impending expiry and refresh where possible
+    Doctor,
 }
 
 #[derive(Subcommand, Debug)]
@@ -1291,6 +1976,30 @@ enum MigrateCommands {
         #[arg(long)]
         source: Option<std::path::PathBuf>,
 
+        /// Validate and preview migration without writing any data
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Import conversations and memories from a `ChatGPT` data export
+    Chatgpt {
+        /// Path to `conversations.json` (or the export directory containing it)
+        #[arg(long)]
+        source: std::path::PathBuf,
+
+        /// Validate and preview migration without writing any data
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Import a `LangChain` / `LlamaIndex` vector store into `ZeroClaw` memory
+    Vectorstore {
+        /// Source store format: chroma or faiss
+        #[arg(long)]
+        from: String,
+
+        /// Path to the persisted vector store directory
+        #[arg(long)]
+        source: std::path::PathBuf,
+
         /// Validate and preview migration without writing any data
         #[arg(long)]
         dry_run: bool,
@@ -1366,6 +2075,52 @@ enum CronCommands {
     },
 }
 
+#[derive(Subcommand, Debug)]
+enum JobsCommands {
+    /// List background jobs
+    List,
+    /// Show status and output of a background job
+    Status {
+        /// Job ID
+        id: String,
+    },
+    /// Cancel a queued background job
+    Cancel {
+        /// Job ID
+        id: String,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum MonitorCommands {
+    /// Show all uptime monitors with status and uptime percentage
+    List,
+    /// Add an HTTP endpoint to monitor
+    Add {
+        /// Endpoint URL (http or https)
+        url: String,
+        /// Check interval (e.g. 30s, 5m, 1h; minimum 30s)
+        #[arg(long, default_value = "5m")]
+        interval: String,
+        /// Expected HTTP status code
+        #[arg(long, default_value_t = 200)]
+        expect: u16,
+    },
+    /// Remove an uptime monitor
+    Remove {
+        /// Monitor ID
+        id: String,
+    },
+    /// Show recent check history for a monitor
+    History {
+        /// Monitor ID
+        id: String,
+        /// Maximum number of checks to show
+        #[arg(long, default_value_t = 20)]
+        limit: usize,
+    },
+}
+
 #[derive(Subcommand, Debug)]
 enum ModelCommands {
     /// Refresh and cache provider models
@@ -1378,6 +2133,24 @@ enum ModelCommands {
         #[arg(long)]
         force: bool,
     },
+    /// List cached models for a provider
+    List {
+        /// Provider name (defaults to configured default provider)
+        #[arg(long)]
+        provider: Option<String>,
+    },
+    /// Search cached model catalogs by ID substring
+    Search {
+        /// Query matched case-insensitively against model IDs
+        query: String,
+    },
+    /// Compare two cached models (price, context window, capabilities)
+    Compare {
+        /// First model ID
+        model_a: String,
+        /// Second model ID
+        model_b: String,
+    },
 }
 
 #[derive(Subcommand, Debug)]
@@ -1392,6 +2165,22 @@ enum DoctorCommands {
         #[arg(long)]
         use_cache: bool,
     },
+    /// Run a tiny live completion against each configured provider/auth profile
+    Providers {
+        /// Probe a specific provider only (default: all configured targets)
+        #[arg(long)]
+        provider: Option<String>,
+    },
+    /// Collect a sanitized diagnostic archive (tar.gz) to attach to bug reports
+    Bundle {
+        /// Output archive path (default: ./zeroclaw-doctor-bundle-<timestamp>.tar.gz)
+        #[arg(long)]
+        output: Option<std::path::PathBuf>,
+
+        /// Skip the interactive confirmation listing bundle contents
+        #[arg(long)]
+        yes: bool,
+    },
 }
 
 #[derive(Subcommand, Debug)]
@@ -1444,6 +2233,24 @@ enum IntegrationCommands {
         /// Integration name
         name: String,
     },
+    /// Enable a configured integration's tools
+    Enable {
+        /// Integration name (github, google_calendar, notion)
+        name: String,
+    },
+    /// Disable an integration without removing its credentials
+    Disable {
+        /// Integration name (github, google_calendar, notion)
+        name: String,
+    },
+    /// Store credentials for an integration (prompts when --token is omitted)
+    Configure {
+        /// Integration name (github, google_calendar, notion)
+        name: String,
+        /// Credential value; prefer the interactive prompt to keep it out of shell history
+        #[arg(long)]
+        token: Option<String>,
+    },
 }
 
 #[tokio::main]
@@ -1458,6 +2265,21 @@ async fn main() -> Result<()> {
 
     let cli = Cli::parse();
 
+    // Export the selected profile so every config load in this process
+    // (including daemon SIGHUP reloads) layers the same overlay.
+    if let Some(profile) = &cli.profile {
+        std::env::set_var(config::schema::PROFILE_ENV_VAR, profile);
+    }
+
+    // Export record/replay mode so every provider creation in this process
+    // goes through the cassette (clap rejects setting both flags).
+    if let Some(cassette) = &cli.record {
+        std::env::set_var(providers::recorder::RECORD_ENV_VAR, cassette);
+    }
+    if let Some(cassette) = &cli.replay {
+        std::env::set_var(providers::recorder::REPLAY_ENV_VAR, cassette);
+    }
+
     // Completions must remain stdout-only and should not load config or initialize logging.
     // This avoids warnings/log lines corrupting sourced completion scripts.
     if let Commands::Completions { shell } = &cli.command {
@@ -1466,12 +2288,25 @@ async fn main() -> Result<()> {
         return Ok(());
     }
 
-    // Initialize logging - respects RUST_LOG env var, defaults to INFO
-    let subscriber = fmt::Subscriber::builder()
-        .with_env_filter(
-            EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")),
+    // Initialize logging - respects RUST_LOG env var, defaults to INFO.
+    // The file layer writes nothing until a long-running command activates
+    // it via `logs::enable_file_logging`, and the JSON layers replace the
+    // plain ones once `logs::enable_json_logs` runs (both settings depend
+    // on the config, which is not loaded yet).
+    use tracing_subscriber::layer::SubscriberExt as _;
+    use tracing_subscriber::Layer as _;
+    let plain = tracing_subscriber::filter::filter_fn(|_| !logs::json_logs_enabled());
+    let subscriber = tracing_subscriber::registry()
+        .with(EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")))
+        .with(fmt::layer().with_filter(plain.clone()))
+        .with(
+            fmt::layer()
+                .with_ansi(false)
+                .with_writer(logs::FileWriter)
+                .with_filter(plain),
         )
-        .finish();
+        .with(logs::JsonLayer::new(std::io::stdout))
+        .with(logs::JsonLayer::new(logs::FileWriter));
 
     tracing::subscriber::set_global_default(subscriber).expect("setting default subscriber failed");
 
@@ -1486,6 +2321,8 @@ async fn main() -> Result<()> {
         provider,
         model,
         memory,
+        template,
+        from_file,
     } = &cli.command
     {
         let interactive = *interactive;
@@ -1494,16 +2331,35 @@ async fn main() -> Result<()> {
         let provider = provider.clone();
         let model = model.clone();
         let memory = memory.clone();
+        let template = template.clone();
+        let from_file = from_file.clone();
 
         if interactive && channels_only {
             bail!("Use either --interactive or --channels-only, not both");
         }
+        if from_file.is_some()
+            && (interactive
+                || channels_only
+                || api_key.is_some()
+                || provider.is_some()
+                || model.is_some()
+                || memory.is_some()
+                || template.is_some())
+        {
+            bail!("--from-file performs full setup on its own and does not accept other onboard flags");
+        }
         if channels_only
             && (api_key.is_some() || provider.is_some() || model.is_some() || memory.is_some())
         {
             bail!("--channels-only does not accept --api-key, --provider, --model, or --memory");
         }
-        let config = if channels_only {
+        if template.is_some() && (interactive || channels_only) {
+            bail!("--template is only supported in quick setup mode");
+        }
+        let from_file_mode = from_file.is_some();
+        let config = if let Some(setup_path) = from_file {
+            onboard::run_setup_from_file(&setup_path).await
+        } else if channels_only {
             onboard::run_channels_repair_wizard().await
         } else if interactive {
             onboard::run_wizard().await
@@ -1513,9 +2369,16 @@ async fn main() -> Result<()> {
                 provider.as_deref(),
                 model.as_deref(),
                 memory.as_deref(),
+                template.as_deref(),
             )
             .await
         }?;
+        // Offer the optional guided tour of core features (skipped when
+        // stdin is not a terminal, so scripted onboarding is unaffected).
+        // --from-file is fully non-interactive and never prompts.
+        if !channels_only && !from_file_mode {
+            onboard::maybe_offer_tutorial(&config).await?;
+        }
         // Auto-start channels if user said yes during wizard
         if std::env::var("ZEROCLAW_AUTOSTART_CHANNELS").as_deref() == Ok("1") {
             channels::start_channels(config).await?;
@@ -1527,6 +2390,10 @@ async fn main() -> Result<()> {
     let mut config = Config::load_or_init().await?;
     config.apply_env_overrides();
 
+    if config.observability.json_logs {
+        logs::enable_json_logs();
+    }
+
     match cli.command {
         Commands::Onboard { .. } => unreachable!(),
         Commands::Completions { .. } => unreachable!(),
@@ -1537,11 +2404,25 @@ async fn main() -> Result<()> {
             model,
             temperature,
             peripheral,
-        } => agent::run(config, message, provider, model, temperature, peripheral)
-            .await
-            .map(|_| ()),
+            plan_only,
+            tags,
+            image,
+        } => agent::run(
+            config,
+            message,
+            provider,
+            model,
+            temperature,
+            peripheral,
+            plan_only,
+            tags,
+            image,
+        )
+        .await
+        .map(|_| ()),
 
         Commands::Gateway { port, host } => {
+            logs::enable_file_logging(logs::log_dir(&config));
             let port = port.unwrap_or(config.gateway.port);
             let host = host.unwrap_or_else(|| config.gateway.host.clone());
             if port == 0 {
@@ -1553,6 +2434,7 @@ async fn main() -> Result<()> {
         }
 
         Commands::Daemon { port, host } => {
+            logs::enable_file_logging(logs::log_dir(&config));
             let port = port.unwrap_or(config.gateway.port);
             let host = host.unwrap_or_else(|| config.gateway.host.clone());
             if port == 0 {
@@ -1563,30 +2445,45 @@ async fn main() -> Result<()> {
             daemon::run(config, host, port).await
         }
 
-        Commands::Status => {
-            println!("🦀 ZeroClaw Status");
+        Commands::Status {
+            accessible,
+            components,
+            tools,
+        } => {
+            let accessible = accessible || config.ui.accessible;
+            // In accessible mode emoji prefixes are dropped so screen
+            // readers announce the label first.
+            let icon = |emoji: &'static str| if accessible { "" } else { emoji };
+            println!("{}ZeroClaw Status", icon("🦀 "));
             println!();
             println!("Version:     {}", env!("CARGO_PKG_VERSION"));
             println!("Workspace:   {}", config.workspace_dir.display());
             println!("Config:      {}", config.config_path.display());
             println!();
             println!(
-                "🤖 Provider:      {}",
+                "{}Provider:      {}",
+                icon("🤖 "),
                 config.default_provider.as_deref().unwrap_or("openrouter")
             );
             println!(
-                "   Model:         {}",
+                "{}Model:         {}",
+                if accessible { "" } else { "   " },
                 config.default_model.as_deref().unwrap_or("(default)")
             );
-            println!("📊 Observability:  {}", config.observability.backend);
-            println!("🛡️  Autonomy:      {:?}", config.autonomy.level);
-            println!("⚙️  Runtime:       {}", config.runtime.kind);
+            println!(
+                "{}Observability:  {}",
+                icon("📊 "),
+                config.observability.backend
+            );
+            println!("{}Autonomy:      {:?}", icon("🛡️  "), config.autonomy.level);
+            println!("{}Runtime:       {}", icon("⚙️  "), config.runtime.kind);
             let effective_memory_backend = memory::effective_memory_backend_name(
                 &config.memory.backend,
                 Some(&config.storage.provider.config),
             );
             println!(
-                "💓 Heartbeat:      {}",
+                "{}Heartbeat:      {}",
+                icon("💓 "),
                 if config.heartbeat.enabled {
                     format!("every {}min", config.heartbeat.interval_minutes)
                 } else {
@@ -1594,7 +2491,8 @@ async fn main() -> Result<()> {
                 }
             );
             println!(
-                "🧠 Memory:         {} (auto-save: {})",
+                "{}Memory:         {} (auto-save: {})",
+                icon("🧠 "),
                 effective_memory_backend,
                 if config.memory.auto_save { "on" } else { "off" }
             );
@@ -1615,8 +2513,27 @@ async fn main() -> Result<()> {
                 f64::from(config.autonomy.max_cost_per_day_cents) / 100.0
             );
             println!();
+            println!("Quotas:");
+            match quota::QuotaTracker::from_config(
+                &config.quota,
+                &config.cost.prices,
+                &config.workspace_dir,
+            ) {
+                Some(tracker) => {
+                    let lines = tracker.summary_lines();
+                    if lines.is_empty() {
+                        println!("  Enabled (no consumption recorded)");
+                    } else {
+                        for line in lines {
+                            println!("  {line}");
+                        }
+                    }
+                }
+                None => println!("  Disabled"),
+            }
+            println!();
             println!("Channels:");
-            println!("  CLI:      ✅ always");
+            println!("  CLI:      {}always", icon("✅ "));
             for (name, configured) in [
                 ("Telegram", config.channels_config.telegram.is_some()),
                 ("Discord", config.channels_config.discord.is_some()),
@@ -1624,11 +2541,12 @@ async fn main() -> Result<()> {
                 ("Webhook", config.channels_config.webhook.is_some()),
             ] {
                 println!(
-                    "  {name:9} {}",
+                    "  {name:9} {}{}",
+                    icon(if configured { "✅ " } else { "❌ " }),
                     if configured {
-                        "✅ configured"
+                        "configured"
                     } else {
-                        "❌ not configured"
+                        "not configured"
                     }
                 );
             }
@@ -1646,6 +2564,8 @@ async fn main() -> Result<()> {
 
             println!();
             let delegation_log = config.delegation_log_path();
+            // Screen readers skip or misread the em-dash placeholder.
+            let none = if accessible { "none" } else { "—" };
             println!("Delegations:");
             match observability::delegation_report::get_log_summary(&delegation_log) {
                 Ok(Some(s)) => {
@@ -1656,7 +2576,7 @@ async fn main() -> Result<()> {
                         if s.total_tokens > 0 {
                             s.total_tokens.to_string()
                         } else {
-                            "—".to_owned()
+                            none.to_owned()
                         }
                     );
                     println!(
@@ -1664,7 +2584,7 @@ async fn main() -> Result<()> {
                         if s.total_cost_usd > 0.0 {
                             format!("${:.4}", s.total_cost_usd)
                         } else {
-                            "—".to_owned()
+                            none.to_owned()
                         }
                     );
                     if let Some(ts) = s.latest_run_time {
@@ -1675,10 +2595,27 @@ async fn main() -> Result<()> {
                 Err(e) => println!("  (could not read log: {e})"),
             }
 
+            if components {
+                println!();
+                println!("Components:");
+                print!("{}", daemon::components_report(&config, accessible));
+            }
+
+            if tools {
+                println!();
+                println!("Tools:");
+                print!("{}", daemon::tools_report(&config, accessible));
+            }
+
             Ok(())
         }
 
         Commands::Cron { cron_command } => cron::handle_command(cron_command, &config),
+        Commands::Jobs { jobs_command } => jobs::handle_command(jobs_command, &config),
+
+        Commands::Monitor { monitor_command } => {
+            monitors::uptime::handle_command(monitor_command, &config)
+        }
 
         Commands::Models { model_command } => match model_command {
             ModelCommands::Refresh { provider, force } => {
@@ -1689,6 +2626,13 @@ async fn main() -> Result<()> {
                 .await
                 .map_err(|e| anyhow::anyhow!("models refresh task failed: {e}"))?
             }
+            ModelCommands::List { provider } => {
+                onboard::run_models_list(&config, provider.as_deref())
+            }
+            ModelCommands::Search { query } => onboard::run_models_search(&config, &query),
+            ModelCommands::Compare { model_a, model_b } => {
+                onboard::run_models_compare(&config, &model_a, &model_b)
+            }
         },
 
         Commands::Providers => {
@@ -1726,20 +2670,56 @@ async fn main() -> Result<()> {
 
         Commands::Service { service_command } => service::handle_command(&service_command, &config),
 
-        Commands::Doctor { doctor_command } => match doctor_command {
-            Some(DoctorCommands::Models {
-                provider,
-                use_cache,
-            }) => {
-                let config_for_models = config.clone();
-                tokio::task::spawn_blocking(move || {
-                    doctor::run_models(&config_for_models, provider.as_deref(), use_cache)
-                })
-                .await
-                .map_err(|e| anyhow::anyhow!("doctor models task failed: {e}"))?
+        Commands::Cache { cache_command } => {
+            providers::cache::handle_command(&cache_command, &config)
+        }
+
+        Commands::Tokens { tokens_command } => tokens::handle_command(&tokens_command, &config),
+
+        Commands::Top { interval } => top::run(&config, interval).await,
+
+        Commands::Logs {
+            component,
+            follow,
+            since,
+        } => logs::handle_command(&config, component.as_deref(), follow, since.as_deref()).await,
+
+        Commands::Doctor {
+            doctor_command,
+            accessible,
+        } => {
+            let accessible = accessible || config.ui.accessible;
+            match doctor_command {
+                Some(DoctorCommands::Models {
+                    provider,
+                    use_cache,
+                }) => {
+                    let config_for_models = config.clone();
+                    tokio::task::spawn_blocking(move || {
+                        doctor::run_models(
+                            &config_for_models,
+                            provider.as_deref(),
+                            use_cache,
+                            accessible,
+                        )
+                    })
+                    .await
+                    .map_err(|e| anyhow::anyhow!("doctor models task failed: {e}"))?
+                }
+                Some(DoctorCommands::Providers { provider }) => {
+                    doctor::run_providers(&config, provider.as_deref(), accessible).await
+                }
+                Some(DoctorCommands::Bundle { output, yes }) => {
+                    let config_for_bundle = config.clone();
+                    tokio::task::spawn_blocking(move || {
+                        doctor::run_bundle(&config_for_bundle, output.as_deref(), yes)
+                    })
+                    .await
+                    .map_err(|e| anyhow::anyhow!("doctor bundle task failed: {e}"))?
+                }
+                None => doctor::run(&config, accessible),
             }
-            None => doctor::run(&config),
-        },
+        }
 
         Commands::Channel { channel_command } => match channel_command {
             ChannelCommands::Start => channels::start_channels(config).await,
@@ -1747,12 +2727,40 @@ async fn main() -> Result<()> {
             other => channels::handle_command(other, &config).await,
         },
 
+        Commands::Agents { agent_command } => agent::roster::handle_command(agent_command, &config),
+
+        Commands::Contacts { contact_command } => {
+            contacts::handle_command(contact_command, &config)
+        }
+
+        Commands::Workflow { workflow_command } => {
+            workflow::handle_command(workflow_command, &config).await
+        }
+
+        Commands::Eval { eval_command } => eval::handle_command(eval_command, &config).await,
+
+        Commands::Undo { last, run, list } => undo::handle_command(last, run, list, &config),
+
+        Commands::Sessions { sessions_command } => {
+            sessions::handle_command(sessions_command, &config)
+        }
+
+        Commands::Terraform { terraform_command } => {
+            terraform::handle_command(terraform_command, &config).await
+        }
+
         Commands::Integrations {
             integration_command,
-        } => integrations::handle_command(integration_command, &config),
+        } => integrations::handle_command(integration_command, &config).await,
 
         Commands::Skills { skill_command } => skills::handle_command(skill_command, &config),
 
+        Commands::Skillforge { skillforge_command } => match skillforge_command {
+            SkillforgeCommands::New { description } => {
+                skillforge::generate::run_new(&config, &description).await
+            }
+        },
+
         Commands::Migrate { migrate_command } => {
             migration::handle_command(migrate_command, &config).await
         }
@@ -1760,7 +2768,7 @@ async fn main() -> Result<()> {
         Commands::Auth { auth_command } => handle_auth_command(auth_command, &config).await,
 
         Commands::Hardware { hardware_command } => {
-            hardware::handle_command(hardware_command.clone(), &config)
+            hardware::handle_command(hardware_command.clone(), &config).await
         }
 
         Commands::Peripheral { peripheral_command } => {
@@ -1776,32 +2784,212 @@ async fn main() -> Result<()> {
                 );
                 Ok(())
             }
+            ConfigCommands::Get { key } => {
+                let contents = tokio::fs::read_to_string(&config.config_path)
+                    .await
+                    .with_context(|| format!("Failed to read {}", config.config_path.display()))?;
+                match config::edit::get_value(&contents, &key)? {
+                    Some(rendered) => {
+                        println!("{rendered}");
+                        Ok(())
+                    }
+                    None => bail!("Config key '{key}' is not set"),
+                }
+            }
+            ConfigCommands::Set { key, value } => {
+                let contents = tokio::fs::read_to_string(&config.config_path)
+                    .await
+                    .with_context(|| format!("Failed to read {}", config.config_path.display()))?;
+                let updated = config::edit::set_value(&contents, &key, &value)?;
+                config::edit::validate_contents(&updated, Some(&key))?;
+
+                let diff = config::edit::render_diff(&contents, &updated);
+                if diff.is_empty() {
+                    println!("No changes.");
+                    return Ok(());
+                }
+                print!("{diff}");
+                tokio::fs::write(&config.config_path, updated)
+                    .await
+                    .with_context(|| format!("Failed to write {}", config.config_path.display()))?;
+                undo::UndoJournal::new(&config.workspace_dir).record(
+                    "config_edit",
+                    &config.config_path,
+                    Some(contents),
+                    None,
+                );
+                println!("Updated {}", config.config_path.display());
+                Ok(())
+            }
+            ConfigCommands::Unset { key } => {
+                let contents = tokio::fs::read_to_string(&config.config_path)
+                    .await
+                    .with_context(|| format!("Failed to read {}", config.config_path.display()))?;
+                let updated = config::edit::unset_value(&contents, &key)?;
+                config::edit::validate_contents(&updated, None)?;
+
+                let diff = config::edit::render_diff(&contents, &updated);
+                if diff.is_empty() {
+                    println!("No changes.");
+                    return Ok(());
+                }
+                print!("{diff}");
+                tokio::fs::write(&config.config_path, updated)
+                    .await
+                    .with_context(|| format!("Failed to write {}", config.config_path.display()))?;
+                undo::UndoJournal::new(&config.workspace_dir).record(
+                    "config_edit",
+                    &config.config_path,
+                    Some(contents),
+                    None,
+                );
+                println!("Updated {}", config.config_path.display());
+                Ok(())
+            }
+            ConfigCommands::Validate => {
+                let contents = tokio::fs::read_to_string(&config.config_path)
+                    .await
+                    .with_context(|| format!("Failed to read {}", config.config_path.display()))?;
+                config::validate::print_report(&contents)
+            }
         },
 
-        Commands::Delegations { delegation_command } => {
+        Commands::Workspace { workspace_command } => match workspace_command {
+            WorkspaceCommands::Create { name, template } => {
+                // Resolve the template before creating anything so an
+                // unknown name fails without leaving a half-made workspace.
+                let template = template
+                    .as_deref()
+                    .map(onboard::templates::find)
+                    .transpose()?;
+                let config_dir = config::workspaces::create(&name).await?;
+                if let Some(template) = template {
+                    onboard::templates::apply(template, &config_dir.join("workspace"))?;
+                    println!("Applied template '{}'.", template.name);
+                }
+                println!("Created workspace '{name}' at {}", config_dir.display());
+                println!("Switch to it with: zeroclaw workspace use {name}");
+                Ok(())
+            }
+            WorkspaceCommands::List => {
+                for entry in config::workspaces::list().await? {
+                    let marker = if entry.active { "*" } else { " " };
+                    println!("{marker} {} — {}", entry.name, entry.config_dir.display());
+                }
+                if std::env::var("ZEROCLAW_WORKSPACE").is_ok() {
+                    println!("(ZEROCLAW_WORKSPACE is set and overrides the active workspace)");
+                }
+                Ok(())
+            }
+            WorkspaceCommands::Use { name } => {
+                let config_dir = config::workspaces::use_workspace(&name).await?;
+                println!("Active workspace: {name} ({})", config_dir.display());
+                if !config_dir.join("config.toml").exists() {
+                    println!("No config.toml yet — run `zeroclaw onboard` to initialize it.");
+                }
+                Ok(())
+            }
+        },
+
+        Commands::Policy { policy_command } => match policy_command {
+            PolicyCommands::Test { action } => {
+                security::policy_engine::print_test(&config, &action)
+            }
+        },
+
+        Commands::Secrets { secrets_command } => match secrets_command {
+            SecretsCommands::MigrateKeychain => {
+                security::keychain::migrate_config_to_keychain(&config).await
+            }
+        },
+
+        Commands::Audit { audit_command } => {
+            let log_path = config.audit_log_path();
+            match audit_command {
+                AuditCommands::Show { limit } => security::audit::print_show(&log_path, limit),
+                AuditCommands::Verify => security::audit::print_verify(&log_path),
+                AuditCommands::Export { output } => {
+                    security::audit::export(&log_path, output.as_deref())
+                }
+            }
+        }
+
+        Commands::Delegations {
+            delegation_command,
+            accessible,
+            since,
+            until,
+            tag,
+        } => {
             let log_path = config.delegation_log_path();
+            let accessible = accessible || config.ui.accessible;
+            let window = observability::delegation_report::TimeWindow::parse(
+                since.as_deref(),
+                until.as_deref(),
+            )?;
+            if !window.is_unbounded() || tag.is_some() {
+                // Prune rewrites the log and validate checks raw lines; a
+                // filter there would silently discard non-matching data,
+                // so reject the combination instead.
+                if matches!(
+                    delegation_command,
+                    Some(DelegationCommands::Prune { .. } | DelegationCommands::Validate { .. })
+                ) {
+                    anyhow::bail!(
+                        "--since/--until/--tag cannot be combined with prune or validate"
+                    );
+                }
+            }
+            if !window.is_unbounded() {
+                observability::delegation_report::set_time_window(window);
+            }
+            if let Some(tag) = tag {
+                observability::delegation_report::set_tag_filter(tag);
+            }
             match delegation_command {
-                None => observability::delegation_report::print_summary(&log_path),
+                None => observability::delegation_report::print_summary(&log_path, accessible),
                 Some(DelegationCommands::List) => {
-                    observability::delegation_report::print_runs(&log_path)
+                    observability::delegation_report::print_runs(&log_path, accessible)
                 }
                 Some(DelegationCommands::Show { run }) => {
-                    observability::delegation_report::print_tree(&log_path, run.as_deref())
+                    observability::delegation_report::print_tree(
+                        &log_path,
+                        run.as_deref(),
+                        accessible,
+                    )
                 }
                 Some(DelegationCommands::Stats { run }) => {
-                    observability::delegation_report::print_stats(&log_path, run.as_deref())
-                }
-                Some(DelegationCommands::Export { run, format }) => {
-                    let fmt = match format {
-                        DelegationExportFormat::Jsonl => {
-                            observability::delegation_report::ExportFormat::Jsonl
-                        }
-                        DelegationExportFormat::Csv => {
-                            observability::delegation_report::ExportFormat::Csv
-                        }
-                    };
-                    observability::delegation_report::print_export(&log_path, run.as_deref(), fmt)
+                    observability::delegation_report::print_stats(
+                        &log_path,
+                        run.as_deref(),
+                        accessible,
+                    )
                 }
+                Some(DelegationCommands::Export {
+                    run,
+                    format,
+                    endpoint,
+                }) => match format {
+                    DelegationExportFormat::Jsonl => {
+                        observability::delegation_report::print_export(
+                            &log_path,
+                            run.as_deref(),
+                            observability::delegation_report::ExportFormat::Jsonl,
+                        )
+                    }
+                    DelegationExportFormat::Csv => observability::delegation_report::print_export(
+                        &log_path,
+                        run.as_deref(),
+                        observability::delegation_report::ExportFormat::Csv,
+                    ),
+                    DelegationExportFormat::Otlp => observability::delegation_report::export_otlp(
+                        &log_path,
+                        run.as_deref(),
+                        endpoint
+                            .as_deref()
+                            .or(config.observability.otel_endpoint.as_deref()),
+                    ),
+                },
                 Some(DelegationCommands::Top { by, limit }) => {
                     let top_by = match by {
                         DelegationTopBy::Tokens => observability::delegation_report::TopBy::Tokens,
@@ -1812,9 +3000,18 @@ async fn main() -> Result<()> {
                 Some(DelegationCommands::Prune { keep }) => {
                     observability::delegation_report::print_prune(&log_path, keep)
                 }
+                Some(DelegationCommands::Validate { quarantine }) => {
+                    observability::delegation_schema::print_validate(&log_path, quarantine)
+                }
                 Some(DelegationCommands::Models { run }) => {
                     observability::delegation_report::print_models(&log_path, run.as_deref())
                 }
+                Some(DelegationCommands::Tags { run }) => {
+                    observability::delegation_report::print_tags(&log_path, run.as_deref())
+                }
+                Some(DelegationCommands::Channels { run }) => {
+                    observability::delegation_report::print_channels(&log_path, run.as_deref())
+                }
                 Some(DelegationCommands::Providers { run }) => {
                     observability::delegation_report::print_providers(&log_path, run.as_deref())
                 }
@@ -1827,6 +3024,18 @@ async fn main() -> Result<()> {
                 Some(DelegationCommands::Slow { run, limit }) => {
                     observability::delegation_report::print_slow(&log_path, run.as_deref(), limit)
                 }
+                Some(DelegationCommands::Tools { run, tool_command }) => match tool_command {
+                    None => {
+                        observability::delegation_report::print_tools(&log_path, run.as_deref())
+                    }
+                    Some(DelegationToolCommands::Slow { run, limit }) => {
+                        observability::delegation_report::print_tools_slow(
+                            &log_path,
+                            run.as_deref(),
+                            limit,
+                        )
+                    }
+                },
                 Some(DelegationCommands::Cost { run }) => {
                     observability::delegation_report::print_cost(&log_path, run.as_deref())
                 }
@@ -1868,14 +3077,20 @@ async fn main() -> Result<()> {
                 Some(DelegationCommands::Monthly { run }) => {
                     observability::delegation_report::print_monthly(&log_path, run.as_deref())
                 }
+                Some(DelegationCommands::Forecast) => {
+                    observability::delegation_report::print_forecast(&log_path)
+                }
+                Some(DelegationCommands::Anomalies { threshold }) => {
+                    observability::delegation_report::print_anomalies(&log_path, threshold)
+                }
+                Some(DelegationCommands::Report { html }) => {
+                    observability::delegation_report::write_html_report(&log_path, &html)
+                }
                 Some(DelegationCommands::Quarterly { run }) => {
                     observability::delegation_report::print_quarterly(&log_path, run.as_deref())
                 }
                 Some(DelegationCommands::AgentModel { run }) => {
-                    observability::delegation_report::print_agent_model(
-                        &log_path,
-                        run.as_deref(),
-                    )
+                    observability::delegation_report::print_agent_model(&log_path, run.as_deref())
                 }
                 Some(DelegationCommands::ProviderModel { run }) => {
                     observability::delegation_report::print_provider_model(
@@ -1896,58 +3111,31 @@ async fn main() -> Result<()> {
                     )
                 }
                 Some(DelegationCommands::TokenBucket { run }) => {
-                    observability::delegation_report::print_token_bucket(
-                        &log_path,
-                        run.as_deref(),
-                    )
+                    observability::delegation_report::print_token_bucket(&log_path, run.as_deref())
                 }
                 Some(DelegationCommands::CostBucket { run }) => {
-                    observability::delegation_report::print_cost_bucket(
-                        &log_path,
-                        run.as_deref(),
-                    )
+                    observability::delegation_report::print_cost_bucket(&log_path, run.as_deref())
                 }
                 Some(DelegationCommands::Weekday { run }) => {
-                    observability::delegation_report::print_weekday(
-                        &log_path,
-                        run.as_deref(),
-                    )
+                    observability::delegation_report::print_weekday(&log_path, run.as_deref())
                 }
                 Some(DelegationCommands::Weekly { run }) => {
-                    observability::delegation_report::print_weekly(
-                        &log_path,
-                        run.as_deref(),
-                    )
+                    observability::delegation_report::print_weekly(&log_path, run.as_deref())
                 }
                 Some(DelegationCommands::DepthBucket { run }) => {
-                    observability::delegation_report::print_depth_bucket(
-                        &log_path,
-                        run.as_deref(),
-                    )
+                    observability::delegation_report::print_depth_bucket(&log_path, run.as_deref())
                 }
                 Some(DelegationCommands::ModelTier { run }) => {
-                    observability::delegation_report::print_model_tier(
-                        &log_path,
-                        run.as_deref(),
-                    )
+                    observability::delegation_report::print_model_tier(&log_path, run.as_deref())
                 }
                 Some(DelegationCommands::ProviderTier { run }) => {
-                    observability::delegation_report::print_provider_tier(
-                        &log_path,
-                        run.as_deref(),
-                    )
+                    observability::delegation_report::print_provider_tier(&log_path, run.as_deref())
                 }
                 Some(DelegationCommands::TimeOfDay { run }) => {
-                    observability::delegation_report::print_time_of_day(
-                        &log_path,
-                        run.as_deref(),
-                    )
+                    observability::delegation_report::print_time_of_day(&log_path, run.as_deref())
                 }
                 Some(DelegationCommands::DayOfMonth { run }) => {
-                    observability::delegation_report::print_day_of_month(
-                        &log_path,
-                        run.as_deref(),
-                    )
+                    observability::delegation_report::print_day_of_month(&log_path, run.as_deref())
                 }
                 Some(DelegationCommands::TokenEfficiency { run }) => {
                     observability::delegation_report::print_token_efficiency(
@@ -1980,10 +3168,7 @@ async fn main() -> Result<()> {
                     )
                 }
                 Some(DelegationCommands::RunCostRank { run }) => {
-                    observability::delegation_report::print_run_cost_rank(
-                        &log_path,
-                        run.as_deref(),
-                    )
+                    observability::delegation_report::print_run_cost_rank(&log_path, run.as_deref())
                 }
                 Some(DelegationCommands::AgentSuccessRank { run }) => {
                     observability::delegation_report::print_agent_success_rank(
@@ -2057,12 +3242,29 @@ async fn main() -> Result<()> {
                         run.as_deref(),
                     )
                 }
-                Some(DelegationCommands::Diff { run_a, run_b }) => {
-                    observability::delegation_report::print_diff(
-                        &log_path,
-                        &run_a,
-                        run_b.as_deref(),
-                    )
+                Some(DelegationCommands::Diff {
+                    run_a,
+                    run_b,
+                    since,
+                    until,
+                }) => {
+                    if let Some(since) = since {
+                        observability::delegation_report::print_period_diff(
+                            &log_path,
+                            &since,
+                            until.as_deref(),
+                        )
+                    } else if let Some(run_a) = run_a {
+                        observability::delegation_report::print_diff(
+                            &log_path,
+                            &run_a,
+                            run_b.as_deref(),
+                        )
+                    } else {
+                        anyhow::bail!(
+                            "provide a run ID to diff, or --since for a period comparison"
+                        )
+                    }
                 }
             }
         }
@@ -2521,6 +3723,32 @@ async fn handle_auth_command(auth_command: AuthCommands, config: &Config) -> Res
 
             Ok(())
         }
+
+        AuthCommands::Doctor => {
+            let checks = auth_service.check_profiles().await?;
+            if checks.is_empty() {
+                println!("No auth profiles configured.");
+                return Ok(());
+            }
+
+            let mut needs_reauth = false;
+            for check in &checks {
+                let icon = match check.status {
+                    auth::AuthProfileStatus::Healthy => "✅",
+                    auth::AuthProfileStatus::Refreshed => "🔄",
+                    auth::AuthProfileStatus::NeedsReauth => {
+                        needs_reauth = true;
+                        "❌"
+                    }
+                };
+                println!("{icon} {} — {}", check.profile_id, check.detail);
+            }
+
+            if needs_reauth {
+                bail!("One or more auth profiles need manual re-authentication");
+            }
+            Ok(())
+        }
     }
 }
 
@@ -428,6 +428,45 @@ impl SqliteMemory {
 
         Ok(count)
     }
+
+    /// Store an entry with a pre-computed embedding (vector store migration).
+    ///
+    /// The embedding is written as-is — callers are responsible for checking
+    /// that its dimensionality matches the configured embedding space. Pass
+    /// `None` to store without one; such rows are picked up by `reindex`.
+    pub async fn store_with_embedding(
+        &self,
+        key: &str,
+        content: &str,
+        category: MemoryCategory,
+        embedding: Option<&[f32]>,
+    ) -> anyhow::Result<()> {
+        let embedding_bytes = embedding.map(vector::vec_to_bytes);
+
+        let conn = self.conn.clone();
+        let key = key.to_string();
+        let content = content.to_string();
+
+        tokio::task::spawn_blocking(move || -> anyhow::Result<()> {
+            let conn = conn.lock();
+            let now = Local::now().to_rfc3339();
+            let cat = Self::category_to_str(&category);
+            let id = Uuid::new_v4().to_string();
+
+            conn.execute(
+                "INSERT INTO memories (id, key, content, category, embedding, created_at, updated_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+                 ON CONFLICT(key) DO UPDATE SET
+                    content = excluded.content,
+                    category = excluded.category,
+                    embedding = excluded.embedding,
+                    updated_at = excluded.updated_at",
+                params![id, key, content, cat, embedding_bytes, now, now],
+            )?;
+            Ok(())
+        })
+        .await?
+    }
 }
 
 #[async_trait]
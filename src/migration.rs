@@ -28,6 +28,14 @@ pub async fn handle_command(command: crate::MigrateCommands, config: &Config) ->
         crate::MigrateCommands::Openclaw { source, dry_run } => {
             migrate_openclaw_memory(config, source, dry_run).await
         }
+        crate::MigrateCommands::Chatgpt { source, dry_run } => {
+            migrate_chatgpt_export(config, &source, dry_run).await
+        }
+        crate::MigrateCommands::Vectorstore {
+            from,
+            source,
+            dry_run,
+        } => migrate_vectorstore(config, &from, &source, dry_run).await,
     }
 }
 
@@ -72,49 +80,74 @@ async fn migrate_openclaw_memory(
         return Ok(());
     }
 
-    if let Some(backup_dir) = backup_target_memory(&config.workspace_dir)? {
+    if let Some(backup_dir) = backup_target_memory(&config.workspace_dir, "openclaw")? {
         println!("🛟 Backup created: {}", backup_dir.display());
     }
 
     let memory = target_memory_backend(config)?;
 
+    let outcome = import_entries(memory.as_ref(), entries, "openclaw").await?;
+    stats.imported = outcome.imported;
+    stats.skipped_unchanged = outcome.skipped_unchanged;
+    stats.renamed_conflicts = outcome.renamed_conflicts;
+
+    println!("✅ OpenClaw memory migration complete");
+    println!("  Source: {}", source_workspace.display());
+    println!("  Target: {}", config.workspace_dir.display());
+    println!("  Imported:         {}", stats.imported);
+    println!("  Skipped unchanged:{}", stats.skipped_unchanged);
+    println!("  Renamed conflicts:{}", stats.renamed_conflicts);
+    println!("  Source sqlite rows:{}", stats.from_sqlite);
+    println!("  Source markdown:   {}", stats.from_markdown);
+
+    Ok(())
+}
+
+fn target_memory_backend(config: &Config) -> Result<Box<dyn Memory>> {
+    memory::create_memory_for_migration(&config.memory.backend, &config.workspace_dir)
+}
+
+#[derive(Debug, Default)]
+struct ImportOutcome {
+    imported: usize,
+    skipped_unchanged: usize,
+    renamed_conflicts: usize,
+}
+
+/// Import entries into the target backend with the shared conflict policy:
+/// identical content is skipped, conflicting keys are renamed with a
+/// `__<source_tag>_<n>` suffix so nothing in the target is overwritten.
+async fn import_entries(
+    memory: &dyn Memory,
+    entries: Vec<SourceEntry>,
+    source_tag: &str,
+) -> Result<ImportOutcome> {
+    let mut outcome = ImportOutcome::default();
+
     for (idx, entry) in entries.into_iter().enumerate() {
         let mut key = entry.key.trim().to_string();
         if key.is_empty() {
-            key = format!("openclaw_{idx}");
+            key = format!("{source_tag}_{idx}");
         }
 
         if let Some(existing) = memory.get(&key).await? {
             if existing.content.trim() == entry.content.trim() {
-                stats.skipped_unchanged += 1;
+                outcome.skipped_unchanged += 1;
                 continue;
             }
 
-            let renamed = next_available_key(memory.as_ref(), &key).await?;
+            let renamed = next_available_key(memory, &key, source_tag).await?;
             key = renamed;
-            stats.renamed_conflicts += 1;
+            outcome.renamed_conflicts += 1;
         }
 
         memory
             .store(&key, &entry.content, entry.category, None)
             .await?;
-        stats.imported += 1;
+        outcome.imported += 1;
     }
 
-    println!("✅ OpenClaw memory migration complete");
-    println!("  Source: {}", source_workspace.display());
-    println!("  Target: {}", config.workspace_dir.display());
-    println!("  Imported:         {}", stats.imported);
-    println!("  Skipped unchanged:{}", stats.skipped_unchanged);
-    println!("  Renamed conflicts:{}", stats.renamed_conflicts);
-    println!("  Source sqlite rows:{}", stats.from_sqlite);
-    println!("  Source markdown:   {}", stats.from_markdown);
-
-    Ok(())
-}
-
-fn target_memory_backend(config: &Config) -> Result<Box<dyn Memory>> {
-    memory::create_memory_for_migration(&config.memory.backend, &config.workspace_dir)
+    Ok(outcome)
 }
 
 fn collect_source_entries(
@@ -298,6 +331,605 @@ fn parse_structured_memory_line(line: &str) -> Option<(&str, &str)> {
     Some((key, value))
 }
 
+// ── ChatGPT data export import ───────────────────────────────────
+
+#[derive(Debug, Default)]
+struct ChatgptStats {
+    conversations: usize,
+    memories: usize,
+}
+
+async fn migrate_chatgpt_export(config: &Config, source: &Path, dry_run: bool) -> Result<()> {
+    let conversations_path = resolve_chatgpt_conversations_path(source)?;
+
+    let mut stats = ChatgptStats::default();
+    let mut entries = read_chatgpt_conversations(&conversations_path, &mut stats)?;
+
+    // Saved memories, when exported, sit next to conversations.json.
+    let memories_path = conversations_path.with_file_name("memories.json");
+    if memories_path.exists() {
+        let memory_entries = read_chatgpt_memories(&memories_path)?;
+        stats.memories = memory_entries.len();
+        entries.extend(memory_entries);
+    }
+
+    if entries.is_empty() {
+        println!(
+            "No importable content found in {}",
+            conversations_path.display()
+        );
+        return Ok(());
+    }
+
+    if dry_run {
+        println!("🔎 Dry run: ChatGPT migration preview");
+        println!("  Source: {}", conversations_path.display());
+        println!("  Target: {}", config.workspace_dir.display());
+        println!("  Candidates: {}", entries.len());
+        println!("    - conversations: {}", stats.conversations);
+        println!("    - memories:      {}", stats.memories);
+        println!();
+        println!("Run without --dry-run to import these entries.");
+        return Ok(());
+    }
+
+    if let Some(backup_dir) = backup_target_memory(&config.workspace_dir, "chatgpt")? {
+        println!("🛟 Backup created: {}", backup_dir.display());
+    }
+
+    let memory = target_memory_backend(config)?;
+    let outcome = import_entries(memory.as_ref(), entries, "chatgpt").await?;
+
+    println!("✅ ChatGPT export migration complete");
+    println!("  Source: {}", conversations_path.display());
+    println!("  Target: {}", config.workspace_dir.display());
+    println!("  Imported:         {}", outcome.imported);
+    println!("  Skipped unchanged:{}", outcome.skipped_unchanged);
+    println!("  Renamed conflicts:{}", outcome.renamed_conflicts);
+    println!("  Conversations:     {}", stats.conversations);
+    println!("  Memories:          {}", stats.memories);
+
+    Ok(())
+}
+
+fn resolve_chatgpt_conversations_path(source: &Path) -> Result<PathBuf> {
+    let path = if source.is_dir() {
+        source.join("conversations.json")
+    } else {
+        source.to_path_buf()
+    };
+    if !path.exists() {
+        bail!(
+            "ChatGPT export not found at {} (expected conversations.json)",
+            path.display()
+        );
+    }
+    Ok(path)
+}
+
+fn read_chatgpt_conversations(path: &Path, stats: &mut ChatgptStats) -> Result<Vec<SourceEntry>> {
+    let raw =
+        fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    let parsed: serde_json::Value = serde_json::from_str(&raw)
+        .with_context(|| format!("Failed to parse {} as JSON", path.display()))?;
+    let Some(conversations) = parsed.as_array() else {
+        bail!(
+            "Expected a JSON array of conversations in {}",
+            path.display()
+        );
+    };
+
+    let mut entries = Vec::new();
+    for (idx, conversation) in conversations.iter().enumerate() {
+        let Some(transcript) = render_chatgpt_transcript(conversation) else {
+            continue;
+        };
+        let title = conversation
+            .get("title")
+            .and_then(serde_json::Value::as_str)
+            .unwrap_or("untitled");
+        entries.push(SourceEntry {
+            key: format!("chatgpt_{}_{}", slugify_chatgpt_title(title), idx + 1),
+            content: transcript,
+            category: MemoryCategory::Conversation,
+        });
+    }
+
+    stats.conversations = entries.len();
+    Ok(entries)
+}
+
+/// Flatten a ChatGPT conversation `mapping` into a `role: text` transcript,
+/// ordered by message timestamp. Only user/assistant text messages are kept
+/// (system prompts, tool output, and multimodal parts are skipped).
+fn render_chatgpt_transcript(conversation: &serde_json::Value) -> Option<String> {
+    let mapping = conversation.get("mapping")?.as_object()?;
+
+    let mut messages: Vec<(f64, String)> = Vec::new();
+    for node in mapping.values() {
+        let Some(message) = node.get("message") else {
+            continue;
+        };
+        let role = message
+            .pointer("/author/role")
+            .and_then(serde_json::Value::as_str)
+            .unwrap_or("");
+        if role != "user" && role != "assistant" {
+            continue;
+        }
+        if message
+            .pointer("/content/content_type")
+            .and_then(serde_json::Value::as_str)
+            != Some("text")
+        {
+            continue;
+        }
+        let Some(parts) = message
+            .pointer("/content/parts")
+            .and_then(serde_json::Value::as_array)
+        else {
+            continue;
+        };
+        let text = parts
+            .iter()
+            .filter_map(serde_json::Value::as_str)
+            .collect::<Vec<_>>()
+            .join("\n");
+        let text = text.trim();
+        if text.is_empty() {
+            continue;
+        }
+        let timestamp = message
+            .get("create_time")
+            .and_then(serde_json::Value::as_f64)
+            .unwrap_or(0.0);
+        messages.push((timestamp, format!("{role}: {text}")));
+    }
+
+    if messages.is_empty() {
+        return None;
+    }
+    messages.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+    Some(
+        messages
+            .into_iter()
+            .map(|(_, line)| line)
+            .collect::<Vec<_>>()
+            .join("\n"),
+    )
+}
+
+/// Saved memories export: a JSON array of strings or objects carrying a
+/// content-like field.
+fn read_chatgpt_memories(path: &Path) -> Result<Vec<SourceEntry>> {
+    let raw =
+        fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    let parsed: serde_json::Value = serde_json::from_str(&raw)
+        .with_context(|| format!("Failed to parse {} as JSON", path.display()))?;
+    let Some(items) = parsed.as_array() else {
+        bail!("Expected a JSON array of memories in {}", path.display());
+    };
+
+    let mut entries = Vec::new();
+    for (idx, item) in items.iter().enumerate() {
+        let text = item.as_str().or_else(|| {
+            ["content", "memory", "text"]
+                .iter()
+                .find_map(|field| item.get(field).and_then(serde_json::Value::as_str))
+        });
+        let Some(text) = text.map(str::trim).filter(|t| !t.is_empty()) else {
+            continue;
+        };
+        entries.push(SourceEntry {
+            key: format!("chatgpt_memory_{}", idx + 1),
+            content: text.to_string(),
+            category: MemoryCategory::Core,
+        });
+    }
+
+    Ok(entries)
+}
+
+fn slugify_chatgpt_title(title: &str) -> String {
+    let mut slug = String::new();
+    for c in title.chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c.to_ascii_lowercase());
+        } else if !slug.ends_with('_') {
+            slug.push('_');
+        }
+        if slug.len() >= 40 {
+            break;
+        }
+    }
+    let slug = slug.trim_matches('_');
+    if slug.is_empty() {
+        "conversation".to_string()
+    } else {
+        slug.to_string()
+    }
+}
+
+// ── LangChain / LlamaIndex vector store import ───────────────────
+
+#[derive(Debug, Clone)]
+struct VectorEntry {
+    key: String,
+    content: String,
+    category: MemoryCategory,
+    embedding: Option<Vec<f32>>,
+}
+
+#[derive(Debug, Default)]
+struct VectorstoreStats {
+    documents: usize,
+    embeddings_kept: usize,
+    reembed_needed: usize,
+}
+
+async fn migrate_vectorstore(
+    config: &Config,
+    from: &str,
+    source: &Path,
+    dry_run: bool,
+) -> Result<()> {
+    let mut entries = match from {
+        "chroma" => read_chroma_store(source)?,
+        "faiss" => read_faiss_store(source)?,
+        other => bail!("Unsupported vector store '{other}' (supported: chroma, faiss)"),
+    };
+
+    if entries.is_empty() {
+        println!("No importable documents found in {}", source.display());
+        return Ok(());
+    }
+
+    // Keep source embeddings only when they fit the configured embedding
+    // space; mismatched vectors are dropped so the document is re-embedded
+    // by the configured embedding provider instead of polluting the index.
+    let target_dims = config.memory.embedding_dimensions;
+    let mut stats = VectorstoreStats {
+        documents: entries.len(),
+        ..VectorstoreStats::default()
+    };
+    for entry in &mut entries {
+        match &entry.embedding {
+            Some(emb) if emb.len() == target_dims => stats.embeddings_kept += 1,
+            _ => {
+                entry.embedding = None;
+                stats.reembed_needed += 1;
+            }
+        }
+    }
+
+    if dry_run {
+        println!("🔎 Dry run: vector store migration preview");
+        println!("  Source: {} ({from})", source.display());
+        println!("  Target: {}", config.workspace_dir.display());
+        println!("  Documents: {}", stats.documents);
+        println!(
+            "    - embeddings kept ({target_dims}d): {}",
+            stats.embeddings_kept
+        );
+        println!("    - need re-embedding:        {}", stats.reembed_needed);
+        println!();
+        println!("Run without --dry-run to import these documents.");
+        return Ok(());
+    }
+
+    if config.memory.backend != "sqlite" {
+        bail!(
+            "Vector store migration requires the sqlite memory backend (embeddings live in \
+             memory/brain.db); current backend is '{}'",
+            config.memory.backend
+        );
+    }
+
+    if let Some(backup_dir) = backup_target_memory(&config.workspace_dir, "vectorstore")? {
+        println!("🛟 Backup created: {}", backup_dir.display());
+    }
+
+    let memory = memory::SqliteMemory::new(&config.workspace_dir)?;
+    let outcome = import_vector_entries(&memory, entries, from).await?;
+
+    println!("✅ Vector store migration complete");
+    println!("  Source: {} ({from})", source.display());
+    println!("  Target: {}", config.workspace_dir.display());
+    println!("  Imported:         {}", outcome.imported);
+    println!("  Skipped unchanged:{}", outcome.skipped_unchanged);
+    println!("  Renamed conflicts:{}", outcome.renamed_conflicts);
+    println!("  Embeddings kept:   {}", stats.embeddings_kept);
+    println!("  Need re-embedding: {}", stats.reembed_needed);
+
+    Ok(())
+}
+
+/// Import vector entries with the shared conflict policy, writing any kept
+/// source embedding directly so matching-dimension vectors survive the move.
+async fn import_vector_entries(
+    memory: &memory::SqliteMemory,
+    entries: Vec<VectorEntry>,
+    source_tag: &str,
+) -> Result<ImportOutcome> {
+    let mut outcome = ImportOutcome::default();
+
+    for (idx, entry) in entries.into_iter().enumerate() {
+        let mut key = entry.key.trim().to_string();
+        if key.is_empty() {
+            key = format!("{source_tag}_{idx}");
+        }
+
+        if let Some(existing) = memory.get(&key).await? {
+            if existing.content.trim() == entry.content.trim() {
+                outcome.skipped_unchanged += 1;
+                continue;
+            }
+
+            key = next_available_key(memory, &key, source_tag).await?;
+            outcome.renamed_conflicts += 1;
+        }
+
+        memory
+            .store_with_embedding(
+                &key,
+                &entry.content,
+                entry.category,
+                entry.embedding.as_deref(),
+            )
+            .await?;
+        outcome.imported += 1;
+    }
+
+    Ok(outcome)
+}
+
+/// Read a persisted Chroma collection from `<dir>/chroma.sqlite3`.
+///
+/// Documents, metadata, and vectors are replayed from the write-ahead
+/// `embeddings_queue` table (last operation per id wins, deletes drop the
+/// entry), which holds everything we need without touching Chroma's HNSW
+/// binaries.
+fn read_chroma_store(source: &Path) -> Result<Vec<VectorEntry>> {
+    let db_path = source.join("chroma.sqlite3");
+    if !db_path.exists() {
+        bail!(
+            "Chroma store not found at {} (expected chroma.sqlite3)",
+            db_path.display()
+        );
+    }
+
+    let conn = Connection::open_with_flags(&db_path, OpenFlags::SQLITE_OPEN_READ_ONLY)
+        .with_context(|| format!("Failed to open Chroma db {}", db_path.display()))?;
+
+    let table_exists: Option<String> = conn
+        .query_row(
+            "SELECT name FROM sqlite_master WHERE type='table' AND name='embeddings_queue' LIMIT 1",
+            [],
+            |row| row.get(0),
+        )
+        .optional()?;
+    if table_exists.is_none() {
+        bail!(
+            "Unrecognized Chroma database layout in {} (no embeddings_queue table)",
+            db_path.display()
+        );
+    }
+
+    let columns = table_columns(&conn, "embeddings_queue")?;
+    for required in ["id", "vector", "metadata", "operation"] {
+        if !columns.iter().any(|c| c == required) {
+            bail!(
+                "Unrecognized Chroma database layout: embeddings_queue has no '{required}' column"
+            );
+        }
+    }
+
+    let mut stmt = conn
+        .prepare("SELECT id, vector, metadata, operation FROM embeddings_queue ORDER BY seq_id")?;
+    let mut rows = stmt.query([])?;
+
+    // Replay the queue: inserts/upserts overwrite, deletes remove.
+    let mut by_id: Vec<(String, VectorEntry)> = Vec::new();
+    while let Some(row) = rows.next()? {
+        let id: String = row.get(0)?;
+        let vector_blob: Option<Vec<u8>> = row.get(1).unwrap_or(None);
+        let metadata_raw: Option<String> = row.get(2).unwrap_or(None);
+        let operation: i64 = row.get(3).unwrap_or(0);
+
+        by_id.retain(|(existing, _)| existing != &id);
+        // Chroma operation 3 = DELETE; everything else carries a record.
+        if operation == 3 {
+            continue;
+        }
+
+        let metadata: serde_json::Map<String, serde_json::Value> = metadata_raw
+            .as_deref()
+            .and_then(|raw| serde_json::from_str(raw).ok())
+            .unwrap_or_default();
+        let Some(document) = metadata
+            .get("chroma:document")
+            .and_then(serde_json::Value::as_str)
+            .map(str::trim)
+            .filter(|d| !d.is_empty())
+        else {
+            continue; // vector without document text: nothing to store
+        };
+
+        let mut content = document.to_string();
+        let mut category = MemoryCategory::Core;
+        apply_vectorstore_metadata(&metadata, &mut content, &mut category);
+
+        let embedding = vector_blob
+            .as_deref()
+            .map(crate::memory::vector::bytes_to_vec)
+            .filter(|v| !v.is_empty());
+
+        by_id.push((
+            id.clone(),
+            VectorEntry {
+                key: format!("chroma_{id}"),
+                content,
+                category,
+                embedding,
+            },
+        ));
+    }
+
+    Ok(by_id.into_iter().map(|(_, entry)| entry).collect())
+}
+
+/// Read a FAISS-backed store persisted with a JSON docstore (the LlamaIndex
+/// `storage_context.persist()` layout: `docstore.json` plus
+/// `default__vector_store.json`).
+///
+/// LangChain's default FAISS persistence keeps its docstore in `index.pkl`
+/// (Python pickle), which cannot be read safely from Rust — that layout is
+/// rejected with guidance rather than half-imported.
+fn read_faiss_store(source: &Path) -> Result<Vec<VectorEntry>> {
+    let docstore_path = source.join("docstore.json");
+    if !docstore_path.exists() {
+        if source.join("index.pkl").exists() {
+            bail!(
+                "FAISS store at {} keeps its docstore in index.pkl (Python pickle), which \
+                 ZeroClaw does not parse. Re-persist the store with a JSON docstore \
+                 (e.g. LlamaIndex storage_context.persist()) and retry.",
+                source.display()
+            );
+        }
+        bail!(
+            "FAISS store docstore not found at {} (expected docstore.json)",
+            docstore_path.display()
+        );
+    }
+
+    let embeddings = read_faiss_embedding_dict(source)?;
+
+    let raw = fs::read_to_string(&docstore_path)
+        .with_context(|| format!("Failed to read {}", docstore_path.display()))?;
+    let parsed: serde_json::Value = serde_json::from_str(&raw)
+        .with_context(|| format!("Failed to parse {} as JSON", docstore_path.display()))?;
+
+    // LlamaIndex nests records under "docstore/data"; accept a flat
+    // id -> record map as well.
+    let records = parsed
+        .get("docstore/data")
+        .and_then(serde_json::Value::as_object)
+        .or_else(|| parsed.as_object())
+        .cloned()
+        .unwrap_or_default();
+
+    let mut entries = Vec::new();
+    for (id, record) in &records {
+        let data = record.get("__data__").unwrap_or(record);
+        let text = ["text", "page_content", "content"]
+            .iter()
+            .find_map(|field| data.get(field).and_then(serde_json::Value::as_str));
+        let Some(text) = text.map(str::trim).filter(|t| !t.is_empty()) else {
+            continue;
+        };
+
+        let metadata = data
+            .get("metadata")
+            .and_then(serde_json::Value::as_object)
+            .cloned()
+            .unwrap_or_default();
+        let mut content = text.to_string();
+        let mut category = MemoryCategory::Core;
+        apply_vectorstore_metadata(&metadata, &mut content, &mut category);
+
+        entries.push(VectorEntry {
+            key: format!("faiss_{id}"),
+            content,
+            category,
+            embedding: embeddings.get(id.as_str()).cloned(),
+        });
+    }
+
+    Ok(entries)
+}
+
+/// Load the JSON `embedding_dict` that sits next to a FAISS docstore, when
+/// present. Vectors held only in the `index.faiss` binary are skipped — the
+/// binary carries no document ids, so those documents are re-embedded.
+fn read_faiss_embedding_dict(source: &Path) -> Result<std::collections::HashMap<String, Vec<f32>>> {
+    let mut embeddings = std::collections::HashMap::new();
+
+    let candidates = [
+        source.join("default__vector_store.json"),
+        source.join("vector_store.json"),
+    ];
+    let Some(path) = candidates.iter().find(|p| p.exists()) else {
+        return Ok(embeddings);
+    };
+
+    let raw =
+        fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    let parsed: serde_json::Value = serde_json::from_str(&raw)
+        .with_context(|| format!("Failed to parse {} as JSON", path.display()))?;
+    let Some(dict) = parsed
+        .get("embedding_dict")
+        .and_then(serde_json::Value::as_object)
+    else {
+        return Ok(embeddings);
+    };
+
+    for (id, value) in dict {
+        let Some(values) = value.as_array() else {
+            continue;
+        };
+        #[allow(clippy::cast_possible_truncation)]
+        let vector: Vec<f32> = values
+            .iter()
+            .filter_map(serde_json::Value::as_f64)
+            .map(|f| f as f32)
+            .collect();
+        if vector.len() == values.len() && !vector.is_empty() {
+            embeddings.insert(id.clone(), vector);
+        }
+    }
+
+    Ok(embeddings)
+}
+
+/// Map source metadata onto a memory entry: a `category` field selects the
+/// memory category; remaining scalar fields are appended as a compact,
+/// deterministic `[meta]` footer so provenance survives the import.
+fn apply_vectorstore_metadata(
+    metadata: &serde_json::Map<String, serde_json::Value>,
+    content: &mut String,
+    category: &mut MemoryCategory,
+) {
+    let mut extra: Vec<(String, String)> = Vec::new();
+
+    for (key, value) in metadata {
+        if key.starts_with("chroma:") || key.starts_with('_') {
+            continue;
+        }
+        let rendered = match value {
+            serde_json::Value::String(s) => s.clone(),
+            serde_json::Value::Number(n) => n.to_string(),
+            serde_json::Value::Bool(b) => b.to_string(),
+            _ => continue,
+        };
+        if key == "category" {
+            *category = parse_category(&rendered);
+        } else {
+            extra.push((key.clone(), rendered));
+        }
+    }
+
+    if !extra.is_empty() {
+        extra.sort();
+        let footer = extra
+            .into_iter()
+            .map(|(k, v)| format!("{k}={v}"))
+            .collect::<Vec<_>>()
+            .join(" ");
+        content.push_str("\n\n[meta] ");
+        content.push_str(&footer);
+    }
+}
+
 fn parse_category(raw: &str) -> MemoryCategory {
     match raw.trim().to_ascii_lowercase().as_str() {
         "core" | "" => MemoryCategory::Core,
@@ -315,9 +947,9 @@ fn normalize_key(key: &str, fallback_idx: usize) -> String {
     trimmed.to_string()
 }
 
-async fn next_available_key(memory: &dyn Memory, base: &str) -> Result<String> {
+async fn next_available_key(memory: &dyn Memory, base: &str, source_tag: &str) -> Result<String> {
     for i in 1..=10_000 {
-        let candidate = format!("{base}__openclaw_{i}");
+        let candidate = format!("{base}__{source_tag}_{i}");
         if memory.get(&candidate).await?.is_none() {
             return Ok(candidate);
         }
@@ -369,12 +1001,12 @@ fn paths_equal(a: &Path, b: &Path) -> bool {
     }
 }
 
-fn backup_target_memory(workspace_dir: &Path) -> Result<Option<PathBuf>> {
+fn backup_target_memory(workspace_dir: &Path, source_tag: &str) -> Result<Option<PathBuf>> {
     let timestamp = chrono::Local::now().format("%Y%m%d-%H%M%S").to_string();
     let backup_root = workspace_dir
         .join("memory")
         .join("migrations")
-        .join(format!("openclaw-{timestamp}"));
+        .join(format!("{source_tag}-{timestamp}"));
 
     let mut copied_any = false;
     fs::create_dir_all(&backup_root)?;
@@ -627,6 +1259,252 @@ mod tests {
         );
     }
 
+    // ── ChatGPT export import ───────────────────────────────────
+
+    const CHATGPT_EXPORT: &str = r#"[
+        {
+            "title": "Rust build questions!",
+            "mapping": {
+                "n1": {"message": {"author": {"role": "system"}, "content": {"content_type": "text", "parts": ["system prompt"]}, "create_time": 1.0}},
+                "n2": {"message": {"author": {"role": "assistant"}, "content": {"content_type": "text", "parts": ["use cargo build"]}, "create_time": 3.0}},
+                "n3": {"message": {"author": {"role": "user"}, "content": {"content_type": "text", "parts": ["how do I build?"]}, "create_time": 2.0}},
+                "n4": {"message": {"author": {"role": "user"}, "content": {"content_type": "multimodal_text", "parts": [{"asset_pointer": "x"}]}, "create_time": 4.0}}
+            }
+        },
+        {"title": "empty", "mapping": {}}
+    ]"#;
+
+    #[test]
+    fn chatgpt_transcript_orders_messages_and_keeps_only_user_assistant_text() {
+        let mut stats = ChatgptStats::default();
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("conversations.json");
+        fs::write(&path, CHATGPT_EXPORT).unwrap();
+
+        let entries = read_chatgpt_conversations(&path, &mut stats).unwrap();
+
+        assert_eq!(stats.conversations, 1, "empty conversation is skipped");
+        assert_eq!(entries[0].key, "chatgpt_rust_build_questions_1");
+        assert_eq!(
+            entries[0].content,
+            "user: how do I build?\nassistant: use cargo build"
+        );
+        assert_eq!(entries[0].category, MemoryCategory::Conversation);
+    }
+
+    #[test]
+    fn chatgpt_memories_support_strings_and_objects() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("memories.json");
+        fs::write(
+            &path,
+            r#"["prefers Rust", {"content": "timezone is UTC"}, {"other": 1}, "  "]"#,
+        )
+        .unwrap();
+
+        let entries = read_chatgpt_memories(&path).unwrap();
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].key, "chatgpt_memory_1");
+        assert_eq!(entries[0].content, "prefers Rust");
+        assert_eq!(entries[1].content, "timezone is UTC");
+        assert_eq!(entries[0].category, MemoryCategory::Core);
+    }
+
+    #[tokio::test]
+    async fn chatgpt_migration_imports_and_dry_run_does_not_write() {
+        let source = TempDir::new().unwrap();
+        let target = TempDir::new().unwrap();
+        fs::write(source.path().join("conversations.json"), CHATGPT_EXPORT).unwrap();
+        fs::write(source.path().join("memories.json"), r#"["prefers Rust"]"#).unwrap();
+        let config = test_config(target.path());
+
+        migrate_chatgpt_export(&config, source.path(), true)
+            .await
+            .unwrap();
+        let target_mem = SqliteMemory::new(target.path()).unwrap();
+        assert_eq!(target_mem.count().await.unwrap(), 0, "dry run wrote data");
+
+        migrate_chatgpt_export(&config, source.path(), false)
+            .await
+            .unwrap();
+        let all = target_mem.list(None, None).await.unwrap();
+        assert_eq!(all.len(), 2);
+        assert!(all.iter().any(|e| e.key == "chatgpt_memory_1"));
+    }
+
+    #[test]
+    fn chatgpt_missing_source_fails_fast() {
+        let dir = TempDir::new().unwrap();
+        assert!(resolve_chatgpt_conversations_path(dir.path()).is_err());
+    }
+
+    #[test]
+    fn chatgpt_title_slug_is_sanitized() {
+        assert_eq!(
+            slugify_chatgpt_title("Rust: the good parts?"),
+            "rust_the_good_parts"
+        );
+        assert_eq!(slugify_chatgpt_title("!!!"), "conversation");
+    }
+
+    fn write_chroma_fixture(dir: &Path, rows: &[(i64, &str, Option<&[f32]>, Option<&str>)]) {
+        let conn = Connection::open(dir.join("chroma.sqlite3")).unwrap();
+        conn.execute_batch(
+            "CREATE TABLE embeddings_queue (
+                seq_id INTEGER PRIMARY KEY AUTOINCREMENT,
+                operation INTEGER,
+                id TEXT,
+                vector BLOB,
+                metadata TEXT
+            );",
+        )
+        .unwrap();
+        for (operation, id, vector, metadata) in rows {
+            let blob = vector.map(crate::memory::vector::vec_to_bytes);
+            conn.execute(
+                "INSERT INTO embeddings_queue (operation, id, vector, metadata) VALUES (?1, ?2, ?3, ?4)",
+                params![operation, id, blob, metadata],
+            )
+            .unwrap();
+        }
+    }
+
+    #[test]
+    fn chroma_reader_replays_queue_and_maps_metadata() {
+        let dir = TempDir::new().unwrap();
+        write_chroma_fixture(
+            dir.path(),
+            &[
+                (
+                    0,
+                    "doc-1",
+                    Some(&[0.1, 0.2, 0.3]),
+                    Some(r#"{"chroma:document":"Rust notes","topic":"rust","category":"daily"}"#),
+                ),
+                (
+                    0,
+                    "doc-2",
+                    Some(&[0.4, 0.5, 0.6]),
+                    Some(r#"{"chroma:document":"deleted later"}"#),
+                ),
+                (3, "doc-2", None, None),
+            ],
+        );
+
+        let entries = read_chroma_store(dir.path()).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].key, "chroma_doc-1");
+        assert_eq!(entries[0].category, MemoryCategory::Daily);
+        assert!(entries[0].content.starts_with("Rust notes"));
+        assert!(entries[0].content.contains("[meta] topic=rust"));
+        assert_eq!(entries[0].embedding.as_deref(), Some(&[0.1, 0.2, 0.3][..]));
+    }
+
+    #[test]
+    fn faiss_reader_reads_json_docstore_and_embedding_dict() {
+        let dir = TempDir::new().unwrap();
+        fs::write(
+            dir.path().join("docstore.json"),
+            r#"{"docstore/data":{"n1":{"__data__":{"text":"LlamaIndex doc","metadata":{"source":"paper.pdf"}}}}}"#,
+        )
+        .unwrap();
+        fs::write(
+            dir.path().join("default__vector_store.json"),
+            r#"{"embedding_dict":{"n1":[0.5,0.5]}}"#,
+        )
+        .unwrap();
+
+        let entries = read_faiss_store(dir.path()).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].key, "faiss_n1");
+        assert!(entries[0].content.starts_with("LlamaIndex doc"));
+        assert!(entries[0].content.contains("[meta] source=paper.pdf"));
+        assert_eq!(entries[0].embedding.as_deref(), Some(&[0.5, 0.5][..]));
+    }
+
+    #[test]
+    fn faiss_pickle_docstore_fails_fast() {
+        let dir = TempDir::new().unwrap();
+        fs::write(dir.path().join("index.pkl"), b"\x80\x04").unwrap();
+        fs::write(dir.path().join("index.faiss"), b"IxF2").unwrap();
+
+        let err = read_faiss_store(dir.path()).unwrap_err().to_string();
+        assert!(err.contains("pickle"), "unexpected error: {err}");
+    }
+
+    #[tokio::test]
+    async fn vectorstore_migration_keeps_matching_embeddings_and_drops_mismatched() {
+        let source = TempDir::new().unwrap();
+        let target = TempDir::new().unwrap();
+        write_chroma_fixture(
+            source.path(),
+            &[
+                (
+                    0,
+                    "fits",
+                    Some(&[0.1, 0.2, 0.3]),
+                    Some(r#"{"chroma:document":"matching dims"}"#),
+                ),
+                (
+                    0,
+                    "wrong-dims",
+                    Some(&[0.1, 0.2]),
+                    Some(r#"{"chroma:document":"needs re-embedding"}"#),
+                ),
+            ],
+        );
+
+        let mut config = test_config(target.path());
+        config.memory.embedding_dimensions = 3;
+        migrate_vectorstore(&config, "chroma", source.path(), false)
+            .await
+            .unwrap();
+
+        let conn = Connection::open(target.path().join("memory").join("brain.db")).unwrap();
+        let has_embedding = |key: &str| -> bool {
+            conn.query_row(
+                "SELECT embedding IS NOT NULL FROM memories WHERE key = ?1",
+                params![key],
+                |row| row.get::<_, bool>(0),
+            )
+            .unwrap()
+        };
+        assert!(has_embedding("chroma_fits"));
+        assert!(!has_embedding("chroma_wrong-dims"));
+    }
+
+    #[tokio::test]
+    async fn vectorstore_dry_run_does_not_write_and_unknown_kind_fails() {
+        let source = TempDir::new().unwrap();
+        let target = TempDir::new().unwrap();
+        write_chroma_fixture(
+            source.path(),
+            &[(
+                0,
+                "doc-1",
+                Some(&[0.1, 0.2, 0.3]),
+                Some(r#"{"chroma:document":"preview only"}"#),
+            )],
+        );
+
+        let config = test_config(target.path());
+        migrate_vectorstore(&config, "chroma", source.path(), true)
+            .await
+            .unwrap();
+        let target_mem = SqliteMemory::new(target.path()).unwrap();
+        assert_eq!(target_mem.count().await.unwrap(), 0);
+
+        let err = migrate_vectorstore(&config, "pinecone", source.path(), false)
+            .await
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains("Unsupported vector store"),
+            "unexpected error: {err}"
+        );
+    }
+
     #[test]
     fn backup_creates_timestamped_directory() {
         let tmp = TempDir::new().unwrap();
@@ -637,7 +1515,7 @@ mod tests {
         let db_path = mem_dir.join("brain.db");
         std::fs::write(&db_path, "fake db content").unwrap();
 
-        let result = backup_target_memory(tmp.path()).unwrap();
+        let result = backup_target_memory(tmp.path(), "openclaw").unwrap();
         assert!(
             result.is_some(),
             "backup should be created when files exist"
@@ -654,7 +1532,7 @@ mod tests {
     #[test]
     fn backup_returns_none_when_no_files() {
         let tmp = TempDir::new().unwrap();
-        let result = backup_target_memory(tmp.path()).unwrap();
+        let result = backup_target_memory(tmp.path(), "openclaw").unwrap();
         assert!(
             result.is_none(),
             "backup should return None when no files to backup"
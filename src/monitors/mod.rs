@@ -0,0 +1,536 @@
+//! Built-in DNS and certificate monitors (`[monitors]` section).
+//!
+//! Recurring checks people otherwise script by hand: TLS certificate
+//! expiration, domain (registration) expiry via RDAP, and DNS record drift
+//! against a stored baseline, for every domain listed in `[monitors]
+//! domains`. Runs as a daemon component on its own interval and delivers
+//! alerts to the configured channel.
+
+pub mod uptime;
+
+use crate::config::Config;
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio::time::Duration;
+
+/// TCP/TLS probe timeout in seconds.
+const PROBE_TIMEOUT_SECS: u64 = 10;
+/// RDAP request timeout in seconds.
+const RDAP_TIMEOUT_SECS: u64 = 30;
+
+/// Run the monitor loop (runs until cancelled). Daemon entry point.
+pub async fn run(config: Config) -> Result<()> {
+    let monitors = config.monitors.clone();
+    if monitors.domains.is_empty() {
+        tracing::info!("Monitors enabled but [monitors] domains is empty; nothing to check");
+        // Sleep forever rather than exiting, so the supervisor doesn't
+        // treat an intentionally idle component as crashed.
+        loop {
+            tokio::time::sleep(Duration::from_hours(1)).await;
+        }
+    }
+
+    let interval_mins = monitors.interval_minutes.max(15);
+    tracing::info!(
+        "Domain monitors started: {} domain(s), every {interval_mins} minutes",
+        monitors.domains.len()
+    );
+    let mut interval = tokio::time::interval(Duration::from_secs(u64::from(interval_mins) * 60));
+
+    loop {
+        interval.tick().await;
+        let alerts = run_checks(&config).await;
+        if alerts.is_empty() {
+            continue;
+        }
+        let report = format!("## Domain monitor alerts\n\n{}", alerts.join("\n"));
+        if let (Some(channel), Some(to)) = (monitors.channel.as_deref(), monitors.to.as_deref()) {
+            if let Err(e) = crate::channels::send_once(&config, channel, to, &report).await {
+                tracing::error!("Monitors: channel delivery failed: {e}");
+            }
+        } else {
+            for alert in &alerts {
+                tracing::warn!("Monitors: {alert}");
+            }
+        }
+    }
+}
+
+/// Run one round of checks for every configured domain; returns alert lines.
+///
+/// Check failures (unreachable host, RDAP outage) are logged, not alerted,
+/// so transient network issues don't page anyone.
+pub async fn run_checks(config: &Config) -> Vec<String> {
+    let monitors = &config.monitors;
+    let mut alerts = Vec::new();
+    let baseline_path = dns_baseline_path(config);
+    let mut baseline = load_baseline(&baseline_path);
+    let mut baseline_changed = false;
+
+    for domain in &monitors.domains {
+        match cert_not_after(domain).await {
+            Ok(not_after) => {
+                let days = (not_after - Utc::now()).num_days();
+                if days <= i64::from(monitors.cert_expiry_days) {
+                    alerts.push(format!(
+                        "- TLS certificate for {domain} expires in {days} day(s) ({})",
+                        not_after.format("%Y-%m-%d")
+                    ));
+                }
+            }
+            Err(e) => tracing::warn!("Monitors: TLS check failed for {domain}: {e:#}"),
+        }
+
+        match domain_expiration(domain).await {
+            Ok(Some(expires)) => {
+                let days = (expires - Utc::now()).num_days();
+                if days <= i64::from(monitors.domain_expiry_days) {
+                    alerts.push(format!(
+                        "- Domain {domain} registration expires in {days} day(s) ({})",
+                        expires.format("%Y-%m-%d")
+                    ));
+                }
+            }
+            Ok(None) => {
+                tracing::debug!("Monitors: no expiration event in RDAP data for {domain}");
+            }
+            Err(e) => tracing::warn!("Monitors: RDAP check failed for {domain}: {e:#}"),
+        }
+
+        match resolve_sorted(domain).await {
+            Ok(current) => {
+                let first_observation = !baseline.contains_key(domain);
+                if let Some(alert) = dns_drift(&mut baseline, domain, current) {
+                    alerts.push(alert);
+                    baseline_changed = true;
+                } else if first_observation {
+                    baseline_changed = true;
+                }
+            }
+            Err(e) => tracing::warn!("Monitors: DNS lookup failed for {domain}: {e:#}"),
+        }
+    }
+
+    if baseline_changed {
+        if let Err(e) = save_baseline(&baseline_path, &baseline) {
+            tracing::warn!("Monitors: failed to persist DNS baseline: {e:#}");
+        }
+    }
+    alerts
+}
+
+// ─── DNS drift ────────────────────────────────────────────────────────────────
+
+fn dns_baseline_path(config: &Config) -> PathBuf {
+    config
+        .config_path
+        .parent()
+        .unwrap_or(config.workspace_dir.as_path())
+        .join("state")
+        .join("dns_baseline.json")
+}
+
+fn load_baseline(path: &Path) -> BTreeMap<String, Vec<String>> {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn save_baseline(path: &Path, baseline: &BTreeMap<String, Vec<String>>) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, serde_json::to_string_pretty(baseline)?)?;
+    Ok(())
+}
+
+/// Compare `current` addresses against the stored baseline for `domain`.
+///
+/// The first observation records the baseline silently; later changes
+/// produce an alert line and update the baseline so each drift alerts once.
+fn dns_drift(
+    baseline: &mut BTreeMap<String, Vec<String>>,
+    domain: &str,
+    current: Vec<String>,
+) -> Option<String> {
+    match baseline.get(domain) {
+        Some(previous) if *previous != current => {
+            let alert = format!(
+                "- DNS drift for {domain}: [{}] -> [{}]",
+                previous.join(", "),
+                current.join(", ")
+            );
+            baseline.insert(domain.to_string(), current);
+            Some(alert)
+        }
+        Some(_) => None,
+        None => {
+            baseline.insert(domain.to_string(), current);
+            None
+        }
+    }
+}
+
+async fn resolve_sorted(domain: &str) -> Result<Vec<String>> {
+    let addrs = tokio::net::lookup_host((domain, 443))
+        .await
+        .with_context(|| format!("DNS resolution failed for {domain}"))?;
+    let mut ips: Vec<String> = addrs.map(|sa| sa.ip().to_string()).collect();
+    ips.sort();
+    ips.dedup();
+    Ok(ips)
+}
+
+// ─── Domain expiry (RDAP) ─────────────────────────────────────────────────────
+
+/// Fetch the registration expiration date via the RDAP bootstrap service.
+async fn domain_expiration(domain: &str) -> Result<Option<DateTime<Utc>>> {
+    let client =
+        crate::config::build_runtime_proxy_client_with_timeouts("monitors", RDAP_TIMEOUT_SECS, 10);
+    let response: Value = client
+        .get(format!("https://rdap.org/domain/{domain}"))
+        .header("Accept", "application/rdap+json")
+        .send()
+        .await
+        .context("RDAP request failed")?
+        .error_for_status()
+        .context("RDAP service returned an error")?
+        .json()
+        .await
+        .context("Failed to parse RDAP response")?;
+    Ok(rdap_expiration(&response))
+}
+
+/// Extract the `expiration` event date from an RDAP domain object.
+fn rdap_expiration(response: &Value) -> Option<DateTime<Utc>> {
+    response
+        .get("events")?
+        .as_array()?
+        .iter()
+        .find(|ev| ev.get("eventAction").and_then(Value::as_str) == Some("expiration"))?
+        .get("eventDate")
+        .and_then(Value::as_str)
+        .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+// ─── TLS certificate expiry ───────────────────────────────────────────────────
+
+/// Connect to `domain:443` and return the leaf certificate's `notAfter`.
+async fn cert_not_after(domain: &str) -> Result<DateTime<Utc>> {
+    let tcp = tokio::time::timeout(
+        Duration::from_secs(PROBE_TIMEOUT_SECS),
+        tokio::net::TcpStream::connect((domain, 443u16)),
+    )
+    .await
+    .with_context(|| format!("Connection to {domain}:443 timed out"))?
+    .with_context(|| format!("Connection to {domain}:443 failed"))?;
+
+    let tls_config = rustls::ClientConfig::builder()
+        .dangerous()
+        .with_custom_certificate_verifier(Arc::new(ExpiryProbe))
+        .with_no_client_auth();
+    let connector = tokio_rustls::TlsConnector::from(Arc::new(tls_config));
+    let server_name = rustls::pki_types::ServerName::try_from(domain.to_string())
+        .with_context(|| format!("Invalid server name: {domain}"))?;
+    let tls = tokio::time::timeout(
+        Duration::from_secs(PROBE_TIMEOUT_SECS),
+        connector.connect(server_name, tcp),
+    )
+    .await
+    .with_context(|| format!("TLS handshake with {domain} timed out"))?
+    .with_context(|| format!("TLS handshake with {domain} failed"))?;
+
+    let (_, session) = tls.get_ref();
+    let cert = session
+        .peer_certificates()
+        .and_then(|certs| certs.first())
+        .with_context(|| format!("{domain} presented no certificate"))?;
+    parse_not_after(cert.as_ref())
+}
+
+/// Certificate "verifier" that accepts anything — the probe's only purpose
+/// is to read the presented certificate's expiry, which must work even for
+/// certificates that are already expired or misissued. Nothing sensitive is
+/// sent over the resulting session.
+#[derive(Debug)]
+struct ExpiryProbe;
+
+impl rustls::client::danger::ServerCertVerifier for ExpiryProbe {
+    fn verify_server_cert(
+        &self,
+        _end_entity: &rustls::pki_types::CertificateDer<'_>,
+        _intermediates: &[rustls::pki_types::CertificateDer<'_>],
+        _server_name: &rustls::pki_types::ServerName<'_>,
+        _ocsp_response: &[u8],
+        _now: rustls::pki_types::UnixTime,
+    ) -> Result<rustls::client::danger::ServerCertVerified, rustls::Error> {
+        Ok(rustls::client::danger::ServerCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        _message: &[u8],
+        _cert: &rustls::pki_types::CertificateDer<'_>,
+        _dss: &rustls::DigitallySignedStruct,
+    ) -> Result<rustls::client::danger::HandshakeSignatureValid, rustls::Error> {
+        Ok(rustls::client::danger::HandshakeSignatureValid::assertion())
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        _message: &[u8],
+        _cert: &rustls::pki_types::CertificateDer<'_>,
+        _dss: &rustls::DigitallySignedStruct,
+    ) -> Result<rustls::client::danger::HandshakeSignatureValid, rustls::Error> {
+        Ok(rustls::client::danger::HandshakeSignatureValid::assertion())
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<rustls::SignatureScheme> {
+        rustls::crypto::ring::default_provider()
+            .signature_verification_algorithms
+            .supported_schemes()
+    }
+}
+
+// ─── Minimal DER walk to notAfter ─────────────────────────────────────────────
+
+/// Minimal DER reader: just enough to walk an X.509 certificate to its
+/// `notAfter` field. Not a general-purpose ASN.1 parser.
+struct DerReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> DerReader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    fn peek_tag(&self) -> Option<u8> {
+        self.data.get(self.pos).copied()
+    }
+
+    /// Read one tag-length-value element; returns (tag, content).
+    fn read_tlv(&mut self) -> Result<(u8, &'a [u8])> {
+        let tag = *self
+            .data
+            .get(self.pos)
+            .context("Truncated DER: missing tag")?;
+        self.pos += 1;
+        let first = *self
+            .data
+            .get(self.pos)
+            .context("Truncated DER: missing length")?;
+        self.pos += 1;
+        let len = if first & 0x80 == 0 {
+            usize::from(first)
+        } else {
+            let num_bytes = usize::from(first & 0x7f);
+            if num_bytes == 0 || num_bytes > 4 {
+                bail!("Unsupported DER length encoding");
+            }
+            let mut len = 0usize;
+            for _ in 0..num_bytes {
+                let byte = *self
+                    .data
+                    .get(self.pos)
+                    .context("Truncated DER: short length bytes")?;
+                self.pos += 1;
+                len = (len << 8) | usize::from(byte);
+            }
+            len
+        };
+        let end = self
+            .pos
+            .checked_add(len)
+            .filter(|end| *end <= self.data.len())
+            .context("Truncated DER: content exceeds buffer")?;
+        let content = &self.data[self.pos..end];
+        self.pos = end;
+        Ok((tag, content))
+    }
+}
+
+/// Extract `notAfter` from a DER-encoded X.509 certificate.
+fn parse_not_after(cert_der: &[u8]) -> Result<DateTime<Utc>> {
+    let (tag, cert) = DerReader::new(cert_der).read_tlv()?;
+    if tag != 0x30 {
+        bail!("Not a DER certificate (expected outer SEQUENCE)");
+    }
+    let (tag, tbs) = DerReader::new(cert).read_tlv()?;
+    if tag != 0x30 {
+        bail!("Malformed certificate (expected TBSCertificate SEQUENCE)");
+    }
+    let mut tbs = DerReader::new(tbs);
+    // Optional explicit version tag [0].
+    if tbs.peek_tag() == Some(0xA0) {
+        tbs.read_tlv()?;
+    }
+    tbs.read_tlv().context("Missing serialNumber")?;
+    tbs.read_tlv().context("Missing signature algorithm")?;
+    tbs.read_tlv().context("Missing issuer")?;
+    let (tag, validity) = tbs.read_tlv().context("Missing validity")?;
+    if tag != 0x30 {
+        bail!("Malformed certificate (expected validity SEQUENCE)");
+    }
+    let mut validity = DerReader::new(validity);
+    validity.read_tlv().context("Missing notBefore")?;
+    let (tag, not_after) = validity.read_tlv().context("Missing notAfter")?;
+    parse_asn1_time(tag, not_after)
+}
+
+/// Parse an ASN.1 `Time` value (UTCTime or GeneralizedTime, Zulu only).
+fn parse_asn1_time(tag: u8, bytes: &[u8]) -> Result<DateTime<Utc>> {
+    let text = std::str::from_utf8(bytes).context("Non-ASCII ASN.1 time")?;
+    let full = match tag {
+        // UTCTime: YYMMDDHHMMSSZ — RFC 5280 maps YY < 50 to 20YY.
+        0x17 => {
+            let (yy, rest) = text.split_at_checked(2).context("Short UTCTime")?;
+            let yy: u32 = yy.parse().context("Invalid UTCTime year")?;
+            let century = if yy < 50 { 2000 } else { 1900 };
+            format!("{}{rest}", century + yy)
+        }
+        // GeneralizedTime: YYYYMMDDHHMMSSZ.
+        0x18 => text.to_string(),
+        other => bail!("Unexpected ASN.1 time tag: {other:#04x}"),
+    };
+    let naive = chrono::NaiveDateTime::parse_from_str(&full, "%Y%m%d%H%M%SZ")
+        .with_context(|| format!("Invalid ASN.1 time: {text}"))?;
+    Ok(naive.and_utc())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Build one DER TLV (short- or long-form length as needed).
+    fn tlv(tag: u8, content: &[u8]) -> Vec<u8> {
+        let mut out = vec![tag];
+        if content.len() < 128 {
+            #[allow(clippy::cast_possible_truncation)]
+            out.push(content.len() as u8);
+        } else {
+            let len_bytes = content.len().to_be_bytes();
+            let significant: Vec<u8> = len_bytes.iter().copied().skip_while(|b| *b == 0).collect();
+            #[allow(clippy::cast_possible_truncation)]
+            out.push(0x80 | significant.len() as u8);
+            out.extend_from_slice(&significant);
+        }
+        out.extend_from_slice(content);
+        out
+    }
+
+    fn synthetic_cert(not_after_tag: u8, not_after: &[u8], issuer_len: usize) -> Vec<u8> {
+        let version = tlv(0xA0, &tlv(0x02, &[0x02]));
+        let serial = tlv(0x02, &[0x01]);
+        let sig_alg = tlv(0x30, &[]);
+        let issuer = tlv(0x30, &vec![0x00; issuer_len]);
+        let mut times = tlv(0x17, b"260101000000Z");
+        times.extend_from_slice(&tlv(not_after_tag, not_after));
+        let validity = tlv(0x30, &times);
+        let mut tbs_content = Vec::new();
+        for part in [&version, &serial, &sig_alg, &issuer, &validity] {
+            tbs_content.extend_from_slice(part);
+        }
+        tlv(0x30, &tlv(0x30, &tbs_content))
+    }
+
+    #[test]
+    fn parses_not_after_utctime() {
+        let cert = synthetic_cert(0x17, b"271231235959Z", 4);
+        let not_after = parse_not_after(&cert).unwrap();
+        assert_eq!(
+            not_after.format("%Y-%m-%d %H:%M:%S").to_string(),
+            "2027-12-31 23:59:59"
+        );
+    }
+
+    #[test]
+    fn parses_not_after_generalized_time() {
+        let cert = synthetic_cert(0x18, b"20300615120000Z", 4);
+        let not_after = parse_not_after(&cert).unwrap();
+        assert_eq!(not_after.format("%Y-%m-%d").to_string(), "2030-06-15");
+    }
+
+    #[test]
+    fn handles_long_form_der_lengths() {
+        // An issuer longer than 127 bytes forces long-form length encoding.
+        let cert = synthetic_cert(0x17, b"271231235959Z", 200);
+        assert!(parse_not_after(&cert).is_ok());
+    }
+
+    #[test]
+    fn rejects_non_certificate_der() {
+        assert!(parse_not_after(&[]).is_err());
+        assert!(parse_not_after(&[0x02, 0x01, 0x01]).is_err());
+        // Truncated content length.
+        assert!(parse_not_after(&[0x30, 0x10, 0x30]).is_err());
+    }
+
+    #[test]
+    fn utctime_century_mapping_follows_rfc5280() {
+        let a = parse_asn1_time(0x17, b"490101000000Z").unwrap();
+        assert_eq!(a.format("%Y").to_string(), "2049");
+        let b = parse_asn1_time(0x17, b"500101000000Z").unwrap();
+        assert_eq!(b.format("%Y").to_string(), "1950");
+        assert!(parse_asn1_time(0x05, b"whatever").is_err());
+    }
+
+    #[test]
+    fn rdap_expiration_reads_expiration_event() {
+        let response = serde_json::json!({
+            "events": [
+                {"eventAction": "registration", "eventDate": "2020-01-01T00:00:00Z"},
+                {"eventAction": "expiration", "eventDate": "2027-03-15T09:30:00Z"}
+            ]
+        });
+        let expires = rdap_expiration(&response).unwrap();
+        assert_eq!(expires.format("%Y-%m-%d").to_string(), "2027-03-15");
+
+        assert!(rdap_expiration(&serde_json::json!({})).is_none());
+        assert!(rdap_expiration(&serde_json::json!({"events": []})).is_none());
+    }
+
+    #[test]
+    fn dns_drift_records_baseline_then_alerts_on_change() {
+        let mut baseline = BTreeMap::new();
+
+        // First observation: silent baseline record.
+        let first = dns_drift(&mut baseline, "example.com", vec!["10.0.0.1".into()]);
+        assert!(first.is_none());
+        assert_eq!(baseline["example.com"], vec!["10.0.0.1".to_string()]);
+
+        // Unchanged: no alert.
+        assert!(dns_drift(&mut baseline, "example.com", vec!["10.0.0.1".into()]).is_none());
+
+        // Changed: alert once and update the baseline.
+        let alert = dns_drift(&mut baseline, "example.com", vec!["10.0.0.2".into()]).unwrap();
+        assert!(alert.contains("DNS drift"));
+        assert!(alert.contains("10.0.0.1"));
+        assert!(alert.contains("10.0.0.2"));
+        assert_eq!(baseline["example.com"], vec!["10.0.0.2".to_string()]);
+        assert!(dns_drift(&mut baseline, "example.com", vec!["10.0.0.2".into()]).is_none());
+    }
+
+    #[test]
+    fn baseline_round_trips_through_disk() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("state").join("dns_baseline.json");
+        let mut baseline = BTreeMap::new();
+        baseline.insert("example.com".to_string(), vec!["10.0.0.1".to_string()]);
+        save_baseline(&path, &baseline).unwrap();
+        assert_eq!(load_baseline(&path), baseline);
+
+        // Missing or corrupt files load as an empty baseline.
+        assert!(load_baseline(&dir.path().join("missing.json")).is_empty());
+        let corrupt = dir.path().join("corrupt.json");
+        std::fs::write(&corrupt, "not json").unwrap();
+        assert!(load_baseline(&corrupt).is_empty());
+    }
+}
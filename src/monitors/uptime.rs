@@ -0,0 +1,763 @@
+//! Uptime monitoring of user-defined HTTP endpoints.
+//!
+//! Monitors are managed with `zeroclaw monitor add/list/remove/history` and
+//! stored in SQLite alongside a bounded latency history. The daemon's uptime
+//! worker checks each endpoint on its own interval and sends downtime and
+//! recovery alerts to the `[monitors]` channel.
+
+use crate::config::Config;
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Utc};
+use rusqlite::{params, Connection};
+use uuid::Uuid;
+
+/// Checks kept per monitor before the oldest are pruned.
+const MAX_CHECK_HISTORY: i64 = 500;
+/// Smallest allowed check interval.
+const MIN_INTERVAL_SECS: i64 = 30;
+/// How often the worker looks for due monitors.
+const WORKER_TICK_SECS: u64 = 30;
+/// Per-request timeout for endpoint checks.
+const CHECK_TIMEOUT_SECS: u64 = 30;
+
+/// One monitored HTTP endpoint.
+#[derive(Debug, Clone)]
+pub struct UptimeMonitor {
+    pub id: String,
+    pub url: String,
+    pub interval_secs: i64,
+    pub expect_status: u16,
+    pub enabled: bool,
+    pub created_at: DateTime<Utc>,
+    pub next_check: DateTime<Utc>,
+    pub last_check: Option<DateTime<Utc>>,
+    /// "up" or "down" after the first check.
+    pub last_status: Option<String>,
+    pub last_latency_ms: Option<i64>,
+    pub last_error: Option<String>,
+}
+
+/// One stored check result.
+#[derive(Debug, Clone)]
+pub struct UptimeCheck {
+    pub checked_at: DateTime<Utc>,
+    pub success: bool,
+    pub status_code: Option<u16>,
+    pub latency_ms: i64,
+    pub error: Option<String>,
+}
+
+/// Status row for `zeroclaw monitor list` and the gateway status page.
+#[derive(Debug, Clone)]
+pub struct MonitorStatus {
+    pub monitor: UptimeMonitor,
+    /// Percentage of successful checks over the stored history, if any.
+    pub uptime_percent: Option<f64>,
+}
+
+// ─── Store ────────────────────────────────────────────────────────────────────
+
+pub fn add_monitor(
+    config: &Config,
+    url: &str,
+    interval_secs: i64,
+    expect_status: u16,
+) -> Result<UptimeMonitor> {
+    let parsed = reqwest::Url::parse(url).with_context(|| format!("Invalid URL: {url}"))?;
+    if parsed.scheme() != "http" && parsed.scheme() != "https" {
+        bail!(
+            "Only http/https URLs can be monitored, got scheme '{}'",
+            parsed.scheme()
+        );
+    }
+    if interval_secs < MIN_INTERVAL_SECS {
+        bail!("Check interval must be at least {MIN_INTERVAL_SECS}s");
+    }
+    if !(100..=599).contains(&expect_status) {
+        bail!("Expected status must be a valid HTTP status code (100-599)");
+    }
+
+    let id = Uuid::new_v4().to_string();
+    let now = Utc::now();
+    with_connection(config, |conn| {
+        conn.execute(
+            "INSERT INTO uptime_monitors (
+                id, url, interval_secs, expect_status, enabled, created_at, next_check
+             ) VALUES (?1, ?2, ?3, ?4, 1, ?5, ?5)",
+            params![
+                id,
+                parsed.as_str(),
+                interval_secs,
+                expect_status,
+                now.to_rfc3339()
+            ],
+        )
+        .context("Failed to insert uptime monitor")?;
+        Ok(())
+    })?;
+    get_monitor(config, &id)
+}
+
+pub fn get_monitor(config: &Config, id: &str) -> Result<UptimeMonitor> {
+    with_connection(config, |conn| {
+        let mut stmt = conn.prepare(&format!("{MONITOR_SELECT} WHERE id = ?1"))?;
+        let mut rows = stmt.query(params![id])?;
+        if let Some(row) = rows.next()? {
+            map_monitor_row(row).map_err(Into::into)
+        } else {
+            bail!("Monitor '{id}' not found")
+        }
+    })
+}
+
+pub fn list_monitors(config: &Config) -> Result<Vec<UptimeMonitor>> {
+    with_connection(config, |conn| {
+        let mut stmt = conn.prepare(&format!("{MONITOR_SELECT} ORDER BY created_at ASC"))?;
+        let rows = stmt.query_map([], map_monitor_row)?;
+        let mut monitors = Vec::new();
+        for row in rows {
+            monitors.push(row?);
+        }
+        Ok(monitors)
+    })
+}
+
+pub fn remove_monitor(config: &Config, id: &str) -> Result<()> {
+    let changed = with_connection(config, |conn| {
+        conn.execute("DELETE FROM uptime_monitors WHERE id = ?1", params![id])
+            .context("Failed to delete uptime monitor")
+    })?;
+    if changed == 0 {
+        bail!("Monitor '{id}' not found");
+    }
+    Ok(())
+}
+
+pub fn due_monitors(config: &Config, now: DateTime<Utc>) -> Result<Vec<UptimeMonitor>> {
+    with_connection(config, |conn| {
+        let mut stmt = conn.prepare(&format!(
+            "{MONITOR_SELECT} WHERE enabled = 1 AND next_check <= ?1 ORDER BY next_check ASC"
+        ))?;
+        let rows = stmt.query_map(params![now.to_rfc3339()], map_monitor_row)?;
+        let mut monitors = Vec::new();
+        for row in rows {
+            monitors.push(row?);
+        }
+        Ok(monitors)
+    })
+}
+
+/// Persist a check result: append to history (pruned to [`MAX_CHECK_HISTORY`])
+/// and update the monitor's last-check fields and next due time.
+pub fn record_check(config: &Config, monitor: &UptimeMonitor, check: &UptimeCheck) -> Result<()> {
+    let status = if check.success { "up" } else { "down" };
+    let next_check = check.checked_at + chrono::Duration::seconds(monitor.interval_secs);
+    with_connection(config, |conn| {
+        let tx = conn.unchecked_transaction()?;
+        tx.execute(
+            "INSERT INTO uptime_checks (monitor_id, checked_at, success, status_code, latency_ms, error)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![
+                monitor.id,
+                check.checked_at.to_rfc3339(),
+                i64::from(check.success),
+                check.status_code,
+                check.latency_ms,
+                check.error,
+            ],
+        )
+        .context("Failed to insert uptime check")?;
+        tx.execute(
+            "DELETE FROM uptime_checks
+             WHERE monitor_id = ?1
+               AND id NOT IN (
+                 SELECT id FROM uptime_checks
+                 WHERE monitor_id = ?1
+                 ORDER BY checked_at DESC, id DESC
+                 LIMIT ?2
+               )",
+            params![monitor.id, MAX_CHECK_HISTORY],
+        )
+        .context("Failed to prune uptime check history")?;
+        tx.execute(
+            "UPDATE uptime_monitors
+             SET last_check = ?1, last_status = ?2, last_latency_ms = ?3, last_error = ?4,
+                 next_check = ?5
+             WHERE id = ?6",
+            params![
+                check.checked_at.to_rfc3339(),
+                status,
+                check.latency_ms,
+                check.error,
+                next_check.to_rfc3339(),
+                monitor.id,
+            ],
+        )
+        .context("Failed to update uptime monitor state")?;
+        tx.commit().context("Failed to commit uptime check")?;
+        Ok(())
+    })
+}
+
+pub fn list_checks(config: &Config, monitor_id: &str, limit: usize) -> Result<Vec<UptimeCheck>> {
+    with_connection(config, |conn| {
+        let lim = i64::try_from(limit.max(1)).context("Check history limit overflow")?;
+        let mut stmt = conn.prepare(
+            "SELECT checked_at, success, status_code, latency_ms, error
+             FROM uptime_checks
+             WHERE monitor_id = ?1
+             ORDER BY checked_at DESC, id DESC
+             LIMIT ?2",
+        )?;
+        let rows = stmt.query_map(params![monitor_id, lim], |row| {
+            Ok(UptimeCheck {
+                checked_at: parse_rfc3339(&row.get::<_, String>(0)?)
+                    .map_err(sql_conversion_error)?,
+                success: row.get::<_, i64>(1)? != 0,
+                status_code: row.get(2)?,
+                latency_ms: row.get(3)?,
+                error: row.get(4)?,
+            })
+        })?;
+        let mut checks = Vec::new();
+        for row in rows {
+            checks.push(row?);
+        }
+        Ok(checks)
+    })
+}
+
+/// All monitors with their uptime percentage over the stored history.
+pub fn status_table(config: &Config) -> Result<Vec<MonitorStatus>> {
+    let monitors = list_monitors(config)?;
+    let mut statuses = Vec::with_capacity(monitors.len());
+    for monitor in monitors {
+        let uptime_percent = with_connection(config, |conn| {
+            let (total, up): (i64, i64) = conn.query_row(
+                "SELECT COUNT(*), COALESCE(SUM(success), 0)
+                 FROM uptime_checks WHERE monitor_id = ?1",
+                params![monitor.id],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )?;
+            #[allow(clippy::cast_precision_loss)]
+            Ok((total > 0).then(|| up as f64 / total as f64 * 100.0))
+        })?;
+        statuses.push(MonitorStatus {
+            monitor,
+            uptime_percent,
+        });
+    }
+    Ok(statuses)
+}
+
+const MONITOR_SELECT: &str = "SELECT id, url, interval_secs, expect_status, enabled, created_at,
+        next_check, last_check, last_status, last_latency_ms, last_error
+ FROM uptime_monitors";
+
+fn map_monitor_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<UptimeMonitor> {
+    let created_at_raw: String = row.get(5)?;
+    let next_check_raw: String = row.get(6)?;
+    let last_check_raw: Option<String> = row.get(7)?;
+    Ok(UptimeMonitor {
+        id: row.get(0)?,
+        url: row.get(1)?,
+        interval_secs: row.get(2)?,
+        expect_status: row.get(3)?,
+        enabled: row.get::<_, i64>(4)? != 0,
+        created_at: parse_rfc3339(&created_at_raw).map_err(sql_conversion_error)?,
+        next_check: parse_rfc3339(&next_check_raw).map_err(sql_conversion_error)?,
+        last_check: match last_check_raw {
+            Some(raw) => Some(parse_rfc3339(&raw).map_err(sql_conversion_error)?),
+            None => None,
+        },
+        last_status: row.get(8)?,
+        last_latency_ms: row.get(9)?,
+        last_error: row.get(10)?,
+    })
+}
+
+fn parse_rfc3339(raw: &str) -> Result<DateTime<Utc>> {
+    let parsed = DateTime::parse_from_rfc3339(raw)
+        .with_context(|| format!("Invalid RFC3339 timestamp in uptime DB: {raw}"))?;
+    Ok(parsed.with_timezone(&Utc))
+}
+
+fn sql_conversion_error(err: anyhow::Error) -> rusqlite::Error {
+    rusqlite::Error::ToSqlConversionFailure(err.into())
+}
+
+fn with_connection<T>(config: &Config, f: impl FnOnce(&Connection) -> Result<T>) -> Result<T> {
+    let db_path = config.workspace_dir.join("monitors").join("uptime.db");
+    if let Some(parent) = db_path.parent() {
+        std::fs::create_dir_all(parent).with_context(|| {
+            format!("Failed to create monitors directory: {}", parent.display())
+        })?;
+    }
+
+    let conn = Connection::open(&db_path)
+        .with_context(|| format!("Failed to open uptime DB: {}", db_path.display()))?;
+
+    conn.execute_batch(
+        "PRAGMA foreign_keys = ON;
+         CREATE TABLE IF NOT EXISTS uptime_monitors (
+            id              TEXT PRIMARY KEY,
+            url             TEXT NOT NULL,
+            interval_secs   INTEGER NOT NULL,
+            expect_status   INTEGER NOT NULL,
+            enabled         INTEGER NOT NULL DEFAULT 1,
+            created_at      TEXT NOT NULL,
+            next_check      TEXT NOT NULL,
+            last_check      TEXT,
+            last_status     TEXT,
+            last_latency_ms INTEGER,
+            last_error      TEXT
+        );
+        CREATE INDEX IF NOT EXISTS idx_uptime_monitors_next_check ON uptime_monitors(next_check);
+
+        CREATE TABLE IF NOT EXISTS uptime_checks (
+            id          INTEGER PRIMARY KEY AUTOINCREMENT,
+            monitor_id  TEXT NOT NULL,
+            checked_at  TEXT NOT NULL,
+            success     INTEGER NOT NULL,
+            status_code INTEGER,
+            latency_ms  INTEGER NOT NULL,
+            error       TEXT,
+            FOREIGN KEY (monitor_id) REFERENCES uptime_monitors(id) ON DELETE CASCADE
+        );
+        CREATE INDEX IF NOT EXISTS idx_uptime_checks_monitor ON uptime_checks(monitor_id, checked_at);",
+    )
+    .context("Failed to initialize uptime schema")?;
+
+    f(&conn)
+}
+
+// ─── Worker ───────────────────────────────────────────────────────────────────
+
+/// Run one endpoint check and return the result (never errors — failures are
+/// part of the result).
+async fn check_endpoint(client: &reqwest::Client, url: &str, expect_status: u16) -> UptimeCheck {
+    let started = std::time::Instant::now();
+    let checked_at = Utc::now();
+    let outcome = client.get(url).send().await;
+    let latency_ms = i64::try_from(started.elapsed().as_millis()).unwrap_or(i64::MAX);
+    match outcome {
+        Ok(response) => {
+            let status = response.status().as_u16();
+            UptimeCheck {
+                checked_at,
+                success: status == expect_status,
+                status_code: Some(status),
+                latency_ms,
+                error: (status != expect_status)
+                    .then(|| format!("expected status {expect_status}, got {status}")),
+            }
+        }
+        Err(e) => UptimeCheck {
+            checked_at,
+            success: false,
+            status_code: None,
+            latency_ms,
+            error: Some(e.to_string()),
+        },
+    }
+}
+
+/// Uptime worker loop (runs until cancelled). Daemon entry point.
+pub async fn run(config: Config) -> Result<()> {
+    let client = crate::config::build_runtime_proxy_client_with_timeouts(
+        "monitors.uptime",
+        CHECK_TIMEOUT_SECS,
+        10,
+    );
+    let mut interval = tokio::time::interval(std::time::Duration::from_secs(WORKER_TICK_SECS));
+    tracing::info!("Uptime monitor worker started (tick every {WORKER_TICK_SECS}s)");
+
+    loop {
+        interval.tick().await;
+        let due = match due_monitors(&config, Utc::now()) {
+            Ok(due) => due,
+            Err(e) => {
+                tracing::warn!("Uptime: failed to load due monitors: {e:#}");
+                continue;
+            }
+        };
+        for monitor in due {
+            let check = check_endpoint(&client, &monitor.url, monitor.expect_status).await;
+            if let Some(alert) = transition_alert(&monitor, &check) {
+                deliver_alert(&config, &alert).await;
+            }
+            if let Err(e) = record_check(&config, &monitor, &check) {
+                tracing::warn!("Uptime: failed to record check for {}: {e:#}", monitor.url);
+            }
+        }
+    }
+}
+
+/// Alert text for an up→down or down→up transition, if one happened.
+///
+/// The very first check of a monitor only alerts when the endpoint is down,
+/// so adding a healthy endpoint stays quiet.
+fn transition_alert(monitor: &UptimeMonitor, check: &UptimeCheck) -> Option<String> {
+    let was_up = monitor.last_status.as_deref().map(|s| s == "up");
+    match (was_up, check.success) {
+        (Some(true) | None, false) => Some(format!(
+            "🔴 DOWN: {} — {}",
+            monitor.url,
+            check.error.as_deref().unwrap_or("check failed")
+        )),
+        (Some(false), true) => Some(format!(
+            "🟢 RECOVERED: {} ({} ms)",
+            monitor.url, check.latency_ms
+        )),
+        _ => None,
+    }
+}
+
+async fn deliver_alert(config: &Config, alert: &str) {
+    let monitors = &config.monitors;
+    if let (Some(channel), Some(to)) = (monitors.channel.as_deref(), monitors.to.as_deref()) {
+        if let Err(e) = crate::channels::send_once(config, channel, to, alert).await {
+            tracing::error!("Uptime: channel delivery failed: {e}");
+        }
+    } else {
+        tracing::warn!("Uptime: {alert}");
+    }
+}
+
+// ─── CLI ──────────────────────────────────────────────────────────────────────
+
+/// Parse a human interval like "30s", "5m", "1h" into seconds.
+fn parse_interval_secs(input: &str) -> Result<i64> {
+    let input = input.trim();
+    if input.is_empty() {
+        bail!("interval must not be empty");
+    }
+    let split = input
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(input.len());
+    let (num, unit) = input.split_at(split);
+    let amount: i64 = num.parse().context("Invalid interval number")?;
+    let secs = match if unit.is_empty() { "m" } else { unit } {
+        "s" => amount,
+        "m" => amount * 60,
+        "h" => amount * 3600,
+        _ => bail!("unsupported interval unit '{unit}', use s/m/h"),
+    };
+    Ok(secs)
+}
+
+#[allow(clippy::needless_pass_by_value)]
+pub fn handle_command(command: crate::MonitorCommands, config: &Config) -> Result<()> {
+    match command {
+        crate::MonitorCommands::Add {
+            url,
+            interval,
+            expect,
+        } => {
+            let interval_secs = parse_interval_secs(&interval)?;
+            let monitor = add_monitor(config, &url, interval_secs, expect)?;
+            println!("✅ Added uptime monitor {}", monitor.id);
+            println!("  URL     : {}", monitor.url);
+            println!("  Interval: {}s", monitor.interval_secs);
+            println!("  Expect  : HTTP {}", monitor.expect_status);
+            if !config.monitors.enabled {
+                println!("  Note: checks run in daemon mode with [monitors] enabled = true");
+            }
+            Ok(())
+        }
+        crate::MonitorCommands::List => {
+            let statuses = status_table(config)?;
+            if statuses.is_empty() {
+                println!("No uptime monitors yet.");
+                println!("\nUsage:");
+                println!("  zeroclaw monitor add https://example.com --interval 5m --expect 200");
+                return Ok(());
+            }
+            println!("🌐 Uptime monitors ({}):", statuses.len());
+            for status in statuses {
+                let m = &status.monitor;
+                let state = match m.last_status.as_deref() {
+                    Some("up") => "🟢 up",
+                    Some("down") => "🔴 down",
+                    _ => "⚪ pending",
+                };
+                let latency = m
+                    .last_latency_ms
+                    .map_or_else(|| "—".into(), |ms| format!("{ms}ms"));
+                let uptime = status
+                    .uptime_percent
+                    .map_or_else(|| "—".into(), |p| format!("{p:.1}%"));
+                println!(
+                    "- {} | {} | {} | every {}s | latency {} | uptime {}",
+                    m.id, state, m.url, m.interval_secs, latency, uptime,
+                );
+                if let Some(err) = &m.last_error {
+                    println!("    last error: {err}");
+                }
+            }
+            Ok(())
+        }
+        crate::MonitorCommands::Remove { id } => {
+            remove_monitor(config, &id)?;
+            println!("✅ Removed uptime monitor {id}");
+            Ok(())
+        }
+        crate::MonitorCommands::History { id, limit } => {
+            let monitor = get_monitor(config, &id)?;
+            let checks = list_checks(config, &monitor.id, limit)?;
+            if checks.is_empty() {
+                println!("No checks recorded yet for {}", monitor.url);
+                return Ok(());
+            }
+            println!("📈 {} — last {} check(s):", monitor.url, checks.len());
+            for check in checks {
+                let outcome = if check.success { "up" } else { "down" };
+                let code = check
+                    .status_code
+                    .map_or_else(|| "—".into(), |c| c.to_string());
+                print!(
+                    "- {} | {} | HTTP {} | {}ms",
+                    check.checked_at.to_rfc3339(),
+                    outcome,
+                    code,
+                    check.latency_ms,
+                );
+                match &check.error {
+                    Some(err) => println!(" | {err}"),
+                    None => println!(),
+                }
+            }
+            Ok(())
+        }
+    }
+}
+
+// ─── Gateway status page ──────────────────────────────────────────────────────
+
+/// Render the monitor status table as a small self-contained HTML page for
+/// the gateway's `/monitors` route.
+pub fn render_status_page(statuses: &[MonitorStatus]) -> String {
+    use std::fmt::Write as _;
+    let mut html = String::from(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <meta http-equiv=\"refresh\" content=\"60\">\n<title>ZeroClaw Uptime</title>\n<style>\n\
+         body{font-family:system-ui,sans-serif;margin:2rem auto;max-width:860px;padding:0 1rem}\n\
+         table{border-collapse:collapse;width:100%;font-size:.9rem}\n\
+         th,td{border-bottom:1px solid #eee;padding:.4rem .6rem;text-align:left}\n\
+         th{background:#f7f7fa}.up{color:#2b8a3e}.down{color:#c92a2a}\n\
+         </style>\n</head>\n<body>\n<h1>Uptime monitors</h1>\n",
+    );
+    if statuses.is_empty() {
+        html.push_str("<p>No uptime monitors configured.</p>\n");
+    } else {
+        html.push_str(
+            "<table><thead><tr><th>status</th><th>endpoint</th><th>latency</th>\
+             <th>uptime</th><th>last check</th></tr></thead><tbody>\n",
+        );
+        for status in statuses {
+            let m = &status.monitor;
+            let (class, label) = match m.last_status.as_deref() {
+                Some("up") => ("up", "up"),
+                Some("down") => ("down", "down"),
+                _ => ("", "pending"),
+            };
+            let latency = m
+                .last_latency_ms
+                .map_or_else(|| "—".into(), |ms| format!("{ms} ms"));
+            let uptime = status
+                .uptime_percent
+                .map_or_else(|| "—".into(), |p| format!("{p:.1}%"));
+            let last_check = m.last_check.map_or_else(
+                || "—".into(),
+                |t| t.format("%Y-%m-%d %H:%M UTC").to_string(),
+            );
+            let _ = writeln!(
+                html,
+                "<tr><td class=\"{class}\">{label}</td><td>{}</td><td>{latency}</td>\
+                 <td>{uptime}</td><td>{last_check}</td></tr>",
+                escape_html(&m.url),
+            );
+        }
+        html.push_str("</tbody></table>\n");
+    }
+    html.push_str("</body>\n</html>\n");
+    html
+}
+
+fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn test_config(tmp: &TempDir) -> Config {
+        let config = Config {
+            workspace_dir: tmp.path().join("workspace"),
+            config_path: tmp.path().join("config.toml"),
+            ..Config::default()
+        };
+        std::fs::create_dir_all(&config.workspace_dir).unwrap();
+        config
+    }
+
+    fn make_check(success: bool, latency_ms: i64) -> UptimeCheck {
+        UptimeCheck {
+            checked_at: Utc::now(),
+            success,
+            status_code: Some(if success { 200 } else { 503 }),
+            latency_ms,
+            error: (!success).then(|| "expected status 200, got 503".to_owned()),
+        }
+    }
+
+    #[test]
+    fn add_list_remove_roundtrip() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp);
+
+        let monitor = add_monitor(&config, "https://example.com/health", 300, 200).unwrap();
+        assert_eq!(monitor.expect_status, 200);
+        assert!(monitor.enabled);
+
+        let listed = list_monitors(&config).unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].id, monitor.id);
+
+        remove_monitor(&config, &monitor.id).unwrap();
+        assert!(list_monitors(&config).unwrap().is_empty());
+        assert!(remove_monitor(&config, &monitor.id).is_err());
+    }
+
+    #[test]
+    fn add_monitor_rejects_invalid_input() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp);
+
+        assert!(add_monitor(&config, "not a url", 300, 200).is_err());
+        assert!(add_monitor(&config, "ftp://example.com", 300, 200).is_err());
+        assert!(add_monitor(&config, "https://example.com", 5, 200).is_err());
+        assert!(add_monitor(&config, "https://example.com", 300, 999).is_err());
+    }
+
+    #[test]
+    fn record_check_updates_state_and_schedules_next() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp);
+        let monitor = add_monitor(&config, "https://example.com", 300, 200).unwrap();
+
+        let check = make_check(true, 42);
+        record_check(&config, &monitor, &check).unwrap();
+
+        let stored = get_monitor(&config, &monitor.id).unwrap();
+        assert_eq!(stored.last_status.as_deref(), Some("up"));
+        assert_eq!(stored.last_latency_ms, Some(42));
+        assert!(stored.next_check > check.checked_at);
+
+        let history = list_checks(&config, &monitor.id, 10).unwrap();
+        assert_eq!(history.len(), 1);
+        assert!(history[0].success);
+    }
+
+    #[test]
+    fn due_monitors_respects_next_check() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp);
+        let monitor = add_monitor(&config, "https://example.com", 300, 200).unwrap();
+
+        // New monitors are due immediately.
+        assert_eq!(due_monitors(&config, Utc::now()).unwrap().len(), 1);
+
+        record_check(&config, &monitor, &make_check(true, 10)).unwrap();
+        assert!(due_monitors(&config, Utc::now()).unwrap().is_empty());
+        let later = Utc::now() + chrono::Duration::seconds(301);
+        assert_eq!(due_monitors(&config, later).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn status_table_computes_uptime_percent() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp);
+        let monitor = add_monitor(&config, "https://example.com", 300, 200).unwrap();
+
+        record_check(&config, &monitor, &make_check(true, 10)).unwrap();
+        record_check(&config, &monitor, &make_check(true, 12)).unwrap();
+        record_check(&config, &monitor, &make_check(false, 30_000)).unwrap();
+
+        let statuses = status_table(&config).unwrap();
+        assert_eq!(statuses.len(), 1);
+        let pct = statuses[0].uptime_percent.unwrap();
+        assert!((pct - 66.666).abs() < 0.1);
+        assert_eq!(statuses[0].monitor.last_status.as_deref(), Some("down"));
+    }
+
+    #[test]
+    fn transition_alert_fires_only_on_state_change() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp);
+        let mut monitor = add_monitor(&config, "https://example.com", 300, 200).unwrap();
+
+        // First check up: quiet. First check down: alert.
+        assert!(transition_alert(&monitor, &make_check(true, 10)).is_none());
+        assert!(transition_alert(&monitor, &make_check(false, 10)).is_some());
+
+        monitor.last_status = Some("up".into());
+        assert!(transition_alert(&monitor, &make_check(true, 10)).is_none());
+        let down = transition_alert(&monitor, &make_check(false, 10)).unwrap();
+        assert!(down.contains("DOWN"));
+        assert!(down.contains("example.com"));
+
+        monitor.last_status = Some("down".into());
+        assert!(transition_alert(&monitor, &make_check(false, 10)).is_none());
+        let up = transition_alert(&monitor, &make_check(true, 10)).unwrap();
+        assert!(up.contains("RECOVERED"));
+    }
+
+    #[test]
+    fn parse_interval_accepts_s_m_h() {
+        assert_eq!(parse_interval_secs("30s").unwrap(), 30);
+        assert_eq!(parse_interval_secs("5m").unwrap(), 300);
+        assert_eq!(parse_interval_secs("1h").unwrap(), 3600);
+        assert_eq!(parse_interval_secs("5").unwrap(), 300);
+        assert!(parse_interval_secs("").is_err());
+        assert!(parse_interval_secs("5d").is_err());
+    }
+
+    #[test]
+    fn status_page_renders_and_escapes() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp);
+        assert!(render_status_page(&[]).contains("No uptime monitors"));
+
+        let monitor = add_monitor(&config, "https://example.com/health", 300, 200).unwrap();
+        record_check(&config, &monitor, &make_check(true, 10)).unwrap();
+        let html = render_status_page(&status_table(&config).unwrap());
+        assert!(html.contains("https://example.com/health"));
+        assert!(html.contains("10 ms"));
+        assert!(html.contains("100.0%"));
+
+        // Log-sourced strings are escaped before they reach the page.
+        assert_eq!(
+            escape_html("<img src=\"x\"> & more"),
+            "&lt;img src=&quot;x&quot;&gt; &amp; more"
+        );
+    }
+
+    #[test]
+    fn check_history_is_pruned() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp);
+        let monitor = add_monitor(&config, "https://example.com", 300, 200).unwrap();
+
+        for _ in 0..(MAX_CHECK_HISTORY + 5) {
+            record_check(&config, &monitor, &make_check(true, 1)).unwrap();
+        }
+        let history = list_checks(&config, &monitor.id, 10_000).unwrap();
+        assert_eq!(history.len(), usize::try_from(MAX_CHECK_HISTORY).unwrap());
+    }
+}
@@ -0,0 +1,190 @@
+//! Federated worker nodes (`[nodes]` section).
+//!
+//! Lets a primary daemon dispatch delegations to worker ZeroClaw instances
+//! on other machines (e.g. a GPU box running local models). A worker is just
+//! a normal ZeroClaw gateway: dispatch POSTs the sub-agent prompt to its
+//! `/webhook` endpoint with the worker's paired bearer token and relays the
+//! response. Which node executed a task is recorded in the delegation log;
+//! failover behaviour on unreachable workers is controlled by
+//! `fallback_local`.
+
+use crate::config::{NodesConfig, WorkerNodeConfig};
+use anyhow::{bail, Context, Result};
+use std::collections::HashSet;
+
+/// Timeout for one remote delegation round-trip, matching the local
+/// sub-agent provider-call timeout.
+const DISPATCH_TIMEOUT_SECS: u64 = 120;
+
+/// Resolves worker nodes by name and dispatches delegation prompts to them.
+pub struct NodeDispatcher {
+    workers: Vec<WorkerNodeConfig>,
+    fallback_local: bool,
+    client: reqwest::Client,
+}
+
+impl NodeDispatcher {
+    /// Build a dispatcher from `[nodes]` config.
+    ///
+    /// Returns `Ok(None)` when remote dispatch is disabled. Fails fast on
+    /// unusable config (duplicate node names, invalid or non-HTTP URLs) so
+    /// misconfiguration surfaces at startup, not first delegation.
+    pub fn from_config(config: &NodesConfig) -> Result<Option<Self>> {
+        if !config.enabled {
+            return Ok(None);
+        }
+        if config.workers.is_empty() {
+            bail!("[nodes] enabled = true but no [[nodes.workers]] are defined");
+        }
+        let mut seen = HashSet::new();
+        for worker in &config.workers {
+            if worker.name.trim().is_empty() {
+                bail!("[nodes] worker name must not be empty");
+            }
+            if !seen.insert(worker.name.as_str()) {
+                bail!("[nodes] duplicate worker name: {}", worker.name);
+            }
+            let url = reqwest::Url::parse(&worker.url)
+                .with_context(|| format!("[nodes] worker '{}' has an invalid url", worker.name))?;
+            if url.scheme() != "http" && url.scheme() != "https" {
+                bail!(
+                    "[nodes] worker '{}' url must be http or https (got: {})",
+                    worker.name,
+                    url.scheme()
+                );
+            }
+        }
+        Ok(Some(Self {
+            workers: config.workers.clone(),
+            fallback_local: config.fallback_local,
+            client: crate::config::build_runtime_proxy_client_with_timeouts(
+                "nodes",
+                DISPATCH_TIMEOUT_SECS,
+                10,
+            ),
+        }))
+    }
+
+    /// Look up a worker by name.
+    pub fn worker(&self, name: &str) -> Option<&WorkerNodeConfig> {
+        self.workers.iter().find(|w| w.name == name)
+    }
+
+    /// Whether delegations should fall back to local execution when the
+    /// worker is unreachable.
+    pub fn fallback_local(&self) -> bool {
+        self.fallback_local
+    }
+
+    /// Dispatch a delegation prompt to a worker and return its response text.
+    ///
+    /// POSTs `{"message": prompt}` to the worker's `/webhook` endpoint with
+    /// the worker's bearer token and extracts `response` from the reply.
+    pub async fn dispatch(&self, worker: &WorkerNodeConfig, prompt: &str) -> Result<String> {
+        let url = format!("{}/webhook", worker.url.trim_end_matches('/'));
+        let mut request = self
+            .client
+            .post(&url)
+            .json(&serde_json::json!({ "message": prompt }));
+        if let Some(token) = worker.token.as_deref() {
+            request = request.bearer_auth(token);
+        }
+        let response = request
+            .send()
+            .await
+            .with_context(|| format!("node '{}' is unreachable", worker.name))?;
+        let status = response.status();
+        if !status.is_success() {
+            // Body may carry provider details; keep the error small and non-sensitive.
+            bail!("node '{}' rejected the delegation ({status})", worker.name);
+        }
+        let body: serde_json::Value = response
+            .json()
+            .await
+            .with_context(|| format!("node '{}' returned an invalid response", worker.name))?;
+        body.get("response")
+            .and_then(|v| v.as_str())
+            .map(str::to_owned)
+            .with_context(|| {
+                format!(
+                    "node '{}' response is missing the response field",
+                    worker.name
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn worker(name: &str, url: &str) -> WorkerNodeConfig {
+        WorkerNodeConfig {
+            name: name.into(),
+            url: url.into(),
+            token: Some("zc_test_token".into()),
+        }
+    }
+
+    fn enabled_config(workers: Vec<WorkerNodeConfig>) -> NodesConfig {
+        NodesConfig {
+            enabled: true,
+            workers,
+            ..NodesConfig::default()
+        }
+    }
+
+    #[test]
+    fn disabled_config_builds_no_dispatcher() {
+        let dispatcher = NodeDispatcher::from_config(&NodesConfig::default()).unwrap();
+        assert!(dispatcher.is_none());
+    }
+
+    #[test]
+    fn enabled_without_workers_errors() {
+        assert!(NodeDispatcher::from_config(&enabled_config(vec![])).is_err());
+    }
+
+    #[test]
+    fn duplicate_worker_names_error() {
+        let config = enabled_config(vec![
+            worker("gpu-box", "http://10.0.0.2:3000"),
+            worker("gpu-box", "http://10.0.0.3:3000"),
+        ]);
+        assert!(NodeDispatcher::from_config(&config).is_err());
+    }
+
+    #[test]
+    fn invalid_worker_url_errors() {
+        let config = enabled_config(vec![worker("gpu-box", "not a url")]);
+        assert!(NodeDispatcher::from_config(&config).is_err());
+
+        let config = enabled_config(vec![worker("gpu-box", "ftp://10.0.0.2")]);
+        assert!(NodeDispatcher::from_config(&config).is_err());
+    }
+
+    #[test]
+    fn worker_lookup_finds_by_name() {
+        let config = enabled_config(vec![
+            worker("gpu-box", "http://10.0.0.2:3000"),
+            worker("cpu-box", "http://10.0.0.3:3000"),
+        ]);
+        let dispatcher = NodeDispatcher::from_config(&config).unwrap().unwrap();
+        assert_eq!(
+            dispatcher.worker("gpu-box").map(|w| w.url.as_str()),
+            Some("http://10.0.0.2:3000")
+        );
+        assert!(dispatcher.worker("unknown").is_none());
+        assert!(dispatcher.fallback_local());
+    }
+
+    #[tokio::test]
+    async fn dispatch_to_unreachable_worker_errors() {
+        // Reserved TEST-NET-1 address — connection should fail fast.
+        let config = enabled_config(vec![worker("dead-node", "http://192.0.2.1:9")]);
+        let dispatcher = NodeDispatcher::from_config(&config).unwrap().unwrap();
+        let target = dispatcher.worker("dead-node").unwrap();
+        let err = dispatcher.dispatch(target, "ping").await.unwrap_err();
+        assert!(err.to_string().contains("dead-node"));
+    }
+}
@@ -6,19 +6,43 @@
 ///!
 ///! Each observer instance is assigned a unique `run_id` (UUID) at creation time,
 ///! which is written into every JSONL event to allow the UI to filter by run.
-
 use super::traits::{Observer, ObserverEvent, ObserverMetric};
 use std::any::Any;
 use std::fs::OpenOptions;
 use std::io::Write;
 use std::path::PathBuf;
+use std::sync::Mutex;
+
+/// Ambient originating channel for the active agent run (e.g. `"telegram"`,
+/// `"discord"`), set by the channel dispatcher around the tool-call loop so
+/// delegation events can be attributed to the channel that triggered them.
+///
+/// Best-effort, like the undo journal's current-run id: concurrent runs in
+/// one process share the slot, so an overlapping run may go unattributed.
+static CURRENT_CHANNEL: Mutex<Option<String>> = Mutex::new(None);
+
+/// Mark (`Some`) or clear (`None`) the channel that originated the active run.
+pub fn set_current_channel(channel: Option<&str>) {
+    if let Ok(mut current) = CURRENT_CHANNEL.lock() {
+        *current = channel.map(str::to_string);
+    }
+}
+
+/// The originating channel of the active run, when inside a channel-dispatched one.
+pub fn current_channel() -> Option<String> {
+    CURRENT_CHANNEL
+        .lock()
+        .ok()
+        .and_then(|current| current.clone())
+}
 
 /// Observer that logs delegation events to JSONL file.
 ///
-/// Only writes `DelegationStart` and `DelegationEnd` events, ignoring
-/// all other event types. Events are written in append-only mode with
-/// ISO8601 timestamps and a `run_id` for consumption by the Streamlit
-/// delegation parser.
+/// Writes `DelegationStart`/`DelegationEnd` events plus per-tool
+/// `ToolCallStart`/`ToolCallEnd` analytics events (tool name, duration,
+/// success, output bytes), ignoring all other event types. Events are
+/// written in append-only mode with ISO8601 timestamps and a `run_id`
+/// for consumption by the Streamlit delegation parser.
 ///
 /// The `run_id` is a UUID generated at observer creation time. All events
 /// from a single process invocation share the same `run_id`, allowing the
@@ -31,6 +55,7 @@ pub struct DelegationEventObserver {
     log_file: PathBuf,
     run_id: String,
     max_runs: usize,
+    tags: Vec<String>,
 }
 
 impl DelegationEventObserver {
@@ -68,11 +93,20 @@ impl DelegationEventObserver {
             log_file,
             run_id: uuid::Uuid::new_v4().to_string(),
             max_runs,
+            tags: Vec::new(),
         };
         observer.prune_old_runs();
         observer
     }
 
+    /// Attach user-defined tags (e.g. `project:foo`) written into every
+    /// event from this run, so reports can attribute cost per project.
+    #[must_use]
+    pub fn with_tags(mut self, tags: Vec<String>) -> Self {
+        self.tags = tags;
+        self
+    }
+
     /// Prune the JSONL log so that at most `max_runs` distinct run IDs are retained.
     ///
     /// Run IDs are ordered by first appearance in the file (oldest first). If the
@@ -144,13 +178,24 @@ impl DelegationEventObserver {
     }
 
     /// Write a JSON object to the log file (append-only, one line per event).
-    fn write_json(&self, json: &serde_json::Value) {
+    ///
+    /// Every line is stamped with the current `schema_version` so the strict
+    /// parser (`delegation_schema`) can distinguish new records from legacy
+    /// pre-versioning lines.
+    fn write_json(&self, mut json: serde_json::Value) {
+        json["schema_version"] = serde_json::Value::from(super::delegation_schema::SCHEMA_VERSION);
+        if !self.tags.is_empty() {
+            json["tags"] = serde_json::Value::from(self.tags.clone());
+        }
+        if let Some(channel) = current_channel() {
+            json["channel"] = serde_json::Value::from(channel);
+        }
         if let Ok(mut file) = OpenOptions::new()
             .create(true)
             .append(true)
             .open(&self.log_file)
         {
-            if let Ok(line) = serde_json::to_string(json) {
+            if let Ok(line) = serde_json::to_string(&json) {
                 writeln!(file, "{}", line).ok();
             }
         }
@@ -166,6 +211,7 @@ impl Observer for DelegationEventObserver {
                 model,
                 depth,
                 agentic,
+                workflow,
             } => {
                 let json = serde_json::json!({
                     "event_type": "DelegationStart",
@@ -175,9 +221,10 @@ impl Observer for DelegationEventObserver {
                     "model": model,
                     "depth": depth,
                     "agentic": agentic,
+                    "workflow": workflow,
                     "timestamp": chrono::Utc::now().to_rfc3339(),
                 });
-                self.write_json(&json);
+                self.write_json(json);
             }
             ObserverEvent::DelegationEnd {
                 agent_name,
@@ -189,11 +236,14 @@ impl Observer for DelegationEventObserver {
                 error_message,
                 tokens_used,
                 cost_usd,
+                workflow,
+                node,
             } => {
                 let json = serde_json::json!({
                     "event_type": "DelegationEnd",
                     "run_id": self.run_id,
                     "agent_name": agent_name,
+                    "workflow": workflow,
                     "provider": provider,
                     "model": model,
                     "depth": depth,
@@ -202,9 +252,55 @@ impl Observer for DelegationEventObserver {
                     "error_message": error_message,
                     "tokens_used": tokens_used,
                     "cost_usd": cost_usd,
+                    "node": node,
+                    "timestamp": chrono::Utc::now().to_rfc3339(),
+                });
+                self.write_json(json);
+            }
+            ObserverEvent::ToolCallStart { tool } => {
+                let json = serde_json::json!({
+                    "event_type": "ToolCallStart",
+                    "run_id": self.run_id,
+                    "tool": tool,
                     "timestamp": chrono::Utc::now().to_rfc3339(),
                 });
-                self.write_json(&json);
+                self.write_json(json);
+            }
+            ObserverEvent::ToolCall {
+                tool,
+                duration,
+                success,
+                output_bytes,
+            } => {
+                let json = serde_json::json!({
+                    "event_type": "ToolCallEnd",
+                    "run_id": self.run_id,
+                    "tool": tool,
+                    "duration_ms": duration.as_millis() as u64,
+                    "success": success,
+                    "output_bytes": output_bytes,
+                    "timestamp": chrono::Utc::now().to_rfc3339(),
+                });
+                self.write_json(json);
+            }
+            ObserverEvent::CacheHit { provider, model } => {
+                let json = serde_json::json!({
+                    "event_type": "CacheHit",
+                    "run_id": self.run_id,
+                    "provider": provider,
+                    "model": model,
+                    "timestamp": chrono::Utc::now().to_rfc3339(),
+                });
+                self.write_json(json);
+            }
+            ObserverEvent::RunCancelled { scope } => {
+                let json = serde_json::json!({
+                    "event_type": "RunCancelled",
+                    "run_id": self.run_id,
+                    "scope": scope,
+                    "timestamp": chrono::Utc::now().to_rfc3339(),
+                });
+                self.write_json(json);
             }
             // Ignore all other events
             _ => {}
@@ -219,6 +315,10 @@ impl Observer for DelegationEventObserver {
         "delegation-logger"
     }
 
+    fn run_id(&self) -> Option<&str> {
+        Some(&self.run_id)
+    }
+
     fn as_any(&self) -> &dyn Any {
         self
     }
@@ -261,6 +361,7 @@ mod tests {
             model: "claude-sonnet-4".into(),
             depth: 0,
             agentic: true,
+            workflow: None,
         });
         observer.record_event(&ObserverEvent::DelegationEnd {
             agent_name: "agent-a".into(),
@@ -272,6 +373,8 @@ mod tests {
             error_message: None,
             tokens_used: None,
             cost_usd: None,
+            workflow: None,
+            node: None,
         });
 
         let content = std::fs::read_to_string(temp_file.path()).unwrap();
@@ -308,6 +411,7 @@ mod tests {
             model: "claude-sonnet-4".into(),
             depth: 1,
             agentic: true,
+            workflow: None,
         });
 
         let content = std::fs::read_to_string(temp_file.path()).unwrap();
@@ -335,6 +439,8 @@ mod tests {
             error_message: None,
             tokens_used: Some(1234),
             cost_usd: Some(0.0042),
+            workflow: None,
+            node: None,
         });
 
         let content = std::fs::read_to_string(temp_file.path()).unwrap();
@@ -344,6 +450,22 @@ mod tests {
         assert!(content.contains(&format!("\"run_id\":\"{}\"", expected_run_id)));
     }
 
+    #[test]
+    fn writes_run_cancelled_event_with_scope() {
+        let temp_file = NamedTempFile::new().unwrap();
+        let observer = DelegationEventObserver::new(temp_file.path().to_path_buf());
+        let expected_run_id = observer.run_id().to_string();
+
+        observer.record_event(&ObserverEvent::RunCancelled {
+            scope: "job:job-1".into(),
+        });
+
+        let content = std::fs::read_to_string(temp_file.path()).unwrap();
+        assert!(content.contains("\"event_type\":\"RunCancelled\""));
+        assert!(content.contains("\"scope\":\"job:job-1\""));
+        assert!(content.contains(&format!("\"run_id\":\"{}\"", expected_run_id)));
+    }
+
     #[test]
     fn writes_tokens_and_cost_in_delegation_end() {
         let temp_file = NamedTempFile::new().unwrap();
@@ -359,6 +481,8 @@ mod tests {
             error_message: None,
             tokens_used: Some(500),
             cost_usd: Some(0.0015),
+            workflow: None,
+            node: None,
         });
 
         let content = std::fs::read_to_string(temp_file.path()).unwrap();
@@ -382,6 +506,8 @@ mod tests {
             error_message: Some("timeout".into()),
             tokens_used: None,
             cost_usd: None,
+            workflow: None,
+            node: None,
         });
 
         let content = std::fs::read_to_string(temp_file.path()).unwrap();
@@ -390,6 +516,112 @@ mod tests {
         assert!(parsed["cost_usd"].is_null());
     }
 
+    #[test]
+    fn writes_tool_call_events_with_run_id() {
+        let temp_file = NamedTempFile::new().unwrap();
+        let observer = DelegationEventObserver::new(temp_file.path().to_path_buf());
+        let expected_run_id = observer.run_id().to_string();
+
+        observer.record_event(&ObserverEvent::ToolCallStart {
+            tool: "shell".into(),
+        });
+        observer.record_event(&ObserverEvent::ToolCall {
+            tool: "shell".into(),
+            duration: Duration::from_millis(250),
+            success: true,
+            output_bytes: Some(1024),
+        });
+
+        let content = std::fs::read_to_string(temp_file.path()).unwrap();
+        assert!(content.contains("\"event_type\":\"ToolCallStart\""));
+        assert!(content.contains("\"event_type\":\"ToolCallEnd\""));
+        assert!(content.contains("\"tool\":\"shell\""));
+        assert!(content.contains("\"duration_ms\":250"));
+        assert!(content.contains("\"output_bytes\":1024"));
+        assert_eq!(content.matches(&expected_run_id).count(), 2);
+    }
+
+    #[test]
+    fn writes_null_output_bytes_when_tool_errored() {
+        let temp_file = NamedTempFile::new().unwrap();
+        let observer = DelegationEventObserver::new(temp_file.path().to_path_buf());
+
+        observer.record_event(&ObserverEvent::ToolCall {
+            tool: "browser".into(),
+            duration: Duration::from_millis(50),
+            success: false,
+            output_bytes: None,
+        });
+
+        let content = std::fs::read_to_string(temp_file.path()).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(content.trim()).unwrap();
+        assert_eq!(parsed["success"], false);
+        assert!(parsed["output_bytes"].is_null());
+    }
+
+    #[test]
+    fn writes_tags_into_every_event_when_set() {
+        let temp_file = NamedTempFile::new().unwrap();
+        let observer = DelegationEventObserver::new(temp_file.path().to_path_buf())
+            .with_tags(vec!["project:foo".into(), "batch".into()]);
+
+        observer.record_event(&ObserverEvent::DelegationStart {
+            agent_name: "research".into(),
+            provider: "anthropic".into(),
+            model: "claude-sonnet-4".into(),
+            depth: 0,
+            agentic: true,
+            workflow: None,
+        });
+        observer.record_event(&ObserverEvent::ToolCallStart {
+            tool: "shell".into(),
+        });
+
+        let content = std::fs::read_to_string(temp_file.path()).unwrap();
+        for line in content.lines() {
+            let parsed: serde_json::Value = serde_json::from_str(line).unwrap();
+            assert_eq!(parsed["tags"][0], "project:foo");
+            assert_eq!(parsed["tags"][1], "batch");
+        }
+    }
+
+    #[test]
+    fn writes_ambient_channel_into_events_and_omits_it_when_cleared() {
+        let temp_file = NamedTempFile::new().unwrap();
+        let observer = DelegationEventObserver::new(temp_file.path().to_path_buf());
+
+        set_current_channel(Some("telegram"));
+        observer.record_event(&ObserverEvent::ToolCallStart {
+            tool: "shell".into(),
+        });
+        set_current_channel(None);
+        observer.record_event(&ObserverEvent::ToolCallStart {
+            tool: "shell".into(),
+        });
+
+        let content = std::fs::read_to_string(temp_file.path()).unwrap();
+        let lines: Vec<&str> = content.lines().collect();
+        assert_eq!(lines.len(), 2);
+        let attributed: serde_json::Value = serde_json::from_str(lines[0]).unwrap();
+        assert_eq!(attributed["channel"], "telegram");
+        let cleared: serde_json::Value = serde_json::from_str(lines[1]).unwrap();
+        assert!(cleared.get("channel").is_none());
+    }
+
+    #[test]
+    fn omits_tags_key_when_untagged() {
+        let temp_file = NamedTempFile::new().unwrap();
+        let observer = DelegationEventObserver::new(temp_file.path().to_path_buf());
+
+        observer.record_event(&ObserverEvent::ToolCallStart {
+            tool: "shell".into(),
+        });
+
+        let content = std::fs::read_to_string(temp_file.path()).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(content.trim()).unwrap();
+        assert!(parsed.get("tags").is_none());
+    }
+
     #[test]
     fn ignores_non_delegation_events() {
         let temp_file = NamedTempFile::new().unwrap();
@@ -454,8 +686,14 @@ mod tests {
 
         let content = std::fs::read_to_string(path).unwrap();
         assert!(!content.contains("run-oldest"), "oldest run must be pruned");
-        assert!(content.contains("run-middle"), "middle run must be preserved");
-        assert!(content.contains("run-newest"), "newest run must be preserved");
+        assert!(
+            content.contains("run-middle"),
+            "middle run must be preserved"
+        );
+        assert!(
+            content.contains("run-newest"),
+            "newest run must be preserved"
+        );
     }
 
     #[test]
@@ -545,6 +783,7 @@ mod tests {
             model: "claude-sonnet-4".into(),
             depth: 0,
             agentic: true,
+            workflow: None,
         });
 
         observer.record_event(&ObserverEvent::DelegationEnd {
@@ -557,6 +796,8 @@ mod tests {
             error_message: None,
             tokens_used: None,
             cost_usd: None,
+            workflow: None,
+            node: None,
         });
 
         let content = std::fs::read_to_string(temp_file.path()).unwrap();
@@ -7,13 +7,18 @@
 //! - [`print_stats`]: per-agent aggregated statistics table.
 //! - [`print_export`]: stream delegation events as JSONL or CSV.
 //! - [`print_diff`]: side-by-side comparison of two runs with token/cost deltas.
+//! - [`print_period_diff`]: same table for two time periods (`--since`/`--until`).
 //! - [`print_top`]: global agent leaderboard ranked by tokens or cost.
 //! - [`print_prune`]: remove old runs from the log, keeping the N most recent.
 //! - [`print_models`]: per-model breakdown table across all (or one) run.
 //! - [`print_providers`]: per-provider breakdown table across all (or one) run.
+//! - [`print_tags`]: per-tag cost attribution table across all (or one) run.
+//! - [`print_channels`]: per-channel cost attribution table across all (or one) run.
 //! - [`print_depth`]: per-depth-level breakdown table across all (or one) run.
 //! - [`print_errors`]: list failed delegations with agent, duration, and error message.
 //! - [`print_slow`]: list the N slowest delegations ranked by duration descending.
+//! - [`print_tools`]: per-tool call analytics (count, failures, latency, output bytes).
+//! - [`print_tools_slow`]: list the N slowest individual tool calls.
 //! - [`print_cost`]: per-run cost breakdown table sorted by total cost descending.
 //! - [`print_recent`]: list the N most recently completed delegations, newest first.
 //! - [`print_active`]: list currently in-flight delegations (starts without matching ends).
@@ -23,7 +28,11 @@
 //! - [`print_run`]: show all completed delegations for a specific run, oldest first.
 //! - [`print_depth_view`]: show all completed delegations at a given nesting depth, newest first.
 //! - [`print_daily`]: per-calendar-day delegation breakdown table, oldest day first.
+//! - [`print_forecast`]: project end-of-month spend from the daily cost trend.
+//! - [`print_anomalies`]: flag days/agents deviating from their rolling average.
+//! - [`write_html_report`]: self-contained HTML dashboard for sharing outside the terminal.
 //! - [`get_log_summary`]: programmatic aggregate for `zeroclaw status`.
+//! - [`agent_costs_since`]: programmatic per-agent cost window for the daemon self-report.
 //!
 //! All parsing is done via `serde_json::Value` — no new dependencies.
 
@@ -49,6 +58,8 @@ struct AgentStats {
     end_count: usize,
     success_count: usize,
     total_duration_ms: u64,
+    /// Per-delegation durations, sorted ascending for percentile lookups.
+    durations_ms: Vec<u64>,
     total_tokens: u64,
     total_cost_usd: f64,
 }
@@ -77,6 +88,22 @@ struct ProviderRow {
     total_cost_usd: f64,
 }
 
+struct TagRow {
+    tag: String,
+    run_count: usize,
+    delegation_count: usize,
+    total_tokens: u64,
+    total_cost_usd: f64,
+}
+
+struct ChannelRow {
+    channel: String,
+    run_count: usize,
+    delegation_count: usize,
+    total_tokens: u64,
+    total_cost_usd: f64,
+}
+
 struct DepthRow {
     depth: u32,
     delegation_count: usize,
@@ -97,6 +124,120 @@ struct ReportNode {
     start_ts: Option<DateTime<Utc>>,
 }
 
+// ─── Time-window filtering ────────────────────────────────────────────────────
+
+/// Optional time window applied to every event read by [`read_all_events`].
+///
+/// Built from the CLI-level `--since`/`--until` options on
+/// `zeroclaw delegations`, so all report subcommands (stats, models, cost,
+/// errors, …) can be scoped to a window instead of only all-time or per-run.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TimeWindow {
+    since: Option<DateTime<Utc>>,
+    until: Option<DateTime<Utc>>,
+}
+
+impl TimeWindow {
+    /// Parse CLI bounds into a window.
+    ///
+    /// Each bound accepts RFC 3339, a bare `YYYY-MM-DD` date (midnight UTC),
+    /// or a relative duration like `30m` / `2h` / `7d` measured back from now.
+    pub fn parse(since: Option<&str>, until: Option<&str>) -> Result<Self> {
+        let parse_bound = |input: &str| -> Result<DateTime<Utc>> {
+            if let Some(delta) = parse_relative_duration(input) {
+                return Ok(Utc::now() - delta);
+            }
+            parse_time_bound(input)
+        };
+        let window = Self {
+            since: since.map(parse_bound).transpose()?,
+            until: until.map(parse_bound).transpose()?,
+        };
+        if let (Some(s), Some(u)) = (window.since, window.until) {
+            if s >= u {
+                bail!("--since must be earlier than --until");
+            }
+        }
+        Ok(window)
+    }
+
+    /// True when no bound is set (the window matches everything).
+    pub fn is_unbounded(&self) -> bool {
+        self.since.is_none() && self.until.is_none()
+    }
+
+    /// True when `ts` falls inside `[since, until)`.
+    fn contains(&self, ts: DateTime<Utc>) -> bool {
+        self.since.map_or(true, |s| ts >= s) && self.until.map_or(true, |u| ts < u)
+    }
+}
+
+/// Parse a relative duration suffix: `<n>m` (minutes), `<n>h` (hours),
+/// `<n>d` (days). Returns `None` for anything else.
+fn parse_relative_duration(input: &str) -> Option<chrono::Duration> {
+    let (value, unit) = input.split_at(input.len().checked_sub(1)?);
+    let value: i64 = value.parse().ok().filter(|v| *v > 0)?;
+    match unit {
+        "m" => Some(chrono::Duration::minutes(value)),
+        "h" => Some(chrono::Duration::hours(value)),
+        "d" => Some(chrono::Duration::days(value)),
+        _ => None,
+    }
+}
+
+/// Process-wide time window installed once from CLI dispatch before any
+/// report runs. Defaults to unbounded (no filtering) — the daemon-side
+/// consumers (`agent_costs_since`, self-report) never set it.
+static TIME_WINDOW: std::sync::OnceLock<TimeWindow> = std::sync::OnceLock::new();
+
+/// Install the time window applied by [`read_all_events`].
+///
+/// Call at most once, from CLI dispatch; later calls are ignored.
+pub fn set_time_window(window: TimeWindow) {
+    let _ = TIME_WINDOW.set(window);
+}
+
+/// Process-wide tag filter (`--tag` on `zeroclaw delegations`), installed
+/// once from CLI dispatch alongside the time window.
+static TAG_FILTER: std::sync::OnceLock<String> = std::sync::OnceLock::new();
+
+/// Install the tag filter applied by [`read_all_events`].
+///
+/// Call at most once, from CLI dispatch; later calls are ignored.
+pub fn set_tag_filter(tag: String) {
+    let _ = TAG_FILTER.set(tag);
+}
+
+/// Keep only events whose `tags` array contains `tag`. Untagged events are
+/// dropped — they cannot belong to the requested project.
+fn filter_by_tag(events: Vec<Value>, tag: &str) -> Vec<Value> {
+    events
+        .into_iter()
+        .filter(|e| {
+            e.get("tags")
+                .and_then(Value::as_array)
+                .is_some_and(|tags| tags.iter().any(|t| t.as_str() == Some(tag)))
+        })
+        .collect()
+}
+
+/// Drop events whose `timestamp` falls outside `window`. Events without a
+/// parseable timestamp are dropped too when a bound is active — they cannot
+/// be placed in time, and keeping them would silently widen the window.
+fn filter_by_window(events: Vec<Value>, window: &TimeWindow) -> Vec<Value> {
+    if window.is_unbounded() {
+        return events;
+    }
+    events
+        .into_iter()
+        .filter(|e| {
+            e.get("timestamp")
+                .and_then(parse_ts)
+                .is_some_and(|ts| window.contains(ts))
+        })
+        .collect()
+}
+
 // ─── File I/O ─────────────────────────────────────────────────────────────────
 
 fn read_all_events(log_path: &Path) -> Result<Vec<Value>> {
@@ -113,6 +254,10 @@ fn read_all_events(log_path: &Path) -> Result<Vec<Value>> {
             out.push(v);
         }
     }
+    let mut out = filter_by_window(out, TIME_WINDOW.get().unwrap_or(&TimeWindow::default()));
+    if let Some(tag) = TAG_FILTER.get() {
+        out = filter_by_tag(out, tag);
+    }
     Ok(out)
 }
 
@@ -174,6 +319,7 @@ fn collect_agent_stats(events: &[Value]) -> Vec<AgentStats> {
             end_count: 0,
             success_count: 0,
             total_duration_ms: 0,
+            durations_ms: Vec::new(),
             total_tokens: 0,
             total_cost_usd: 0.0,
         });
@@ -186,6 +332,7 @@ fn collect_agent_stats(events: &[Value]) -> Vec<AgentStats> {
                 }
                 if let Some(dur) = ev.get("duration_ms").and_then(|x| x.as_u64()) {
                     entry.total_duration_ms += dur;
+                    entry.durations_ms.push(dur);
                 }
                 if let Some(tok) = ev.get("tokens_used").and_then(|x| x.as_u64()) {
                     entry.total_tokens += tok;
@@ -198,6 +345,9 @@ fn collect_agent_stats(events: &[Value]) -> Vec<AgentStats> {
         }
     }
     let mut stats: Vec<AgentStats> = map.into_values().collect();
+    for s in &mut stats {
+        s.durations_ms.sort_unstable();
+    }
     // Heaviest first (most tokens), then alphabetical as tiebreaker.
     stats.sort_by(|a, b| {
         b.total_tokens
@@ -271,6 +421,18 @@ fn build_nodes(events: &[Value]) -> Vec<ReportNode> {
 
 // ─── Formatting helpers ───────────────────────────────────────────────────────
 
+/// Nearest-rank percentile over an ascending-sorted sample set.
+///
+/// `pct` is a whole percentage (50, 90, 99). Returns 0 for an empty
+/// sample set so callers can print it as "no data" alongside other zeros.
+fn percentile(sorted: &[u64], pct: usize) -> u64 {
+    if sorted.is_empty() {
+        return 0;
+    }
+    let rank = (sorted.len() * pct).div_ceil(100).max(1);
+    sorted[rank - 1]
+}
+
 fn fmt_duration(ms: u64) -> String {
     if ms < 1000 {
         format!("{ms}ms")
@@ -279,6 +441,16 @@ fn fmt_duration(ms: u64) -> String {
     }
 }
 
+fn fmt_bytes(bytes: u64) -> String {
+    if bytes < 1024 {
+        format!("{bytes}B")
+    } else if bytes < 1024 * 1024 {
+        format!("{:.1}KB", bytes as f64 / 1024.0)
+    } else {
+        format!("{:.1}MB", bytes as f64 / (1024.0 * 1024.0))
+    }
+}
+
 // ─── CSV helpers ─────────────────────────────────────────────────────────────
 
 /// Wrap `s` in double-quotes when it contains a comma, double-quote, or newline.
@@ -312,6 +484,17 @@ fn fmt_delta_tokens(delta: i64) -> String {
     }
 }
 
+/// Parse a `--since`/`--until` bound: an RFC 3339 timestamp or a bare
+/// `YYYY-MM-DD` date (interpreted as midnight UTC).
+fn parse_time_bound(input: &str) -> Result<DateTime<Utc>> {
+    if let Ok(dt) = DateTime::parse_from_rfc3339(input) {
+        return Ok(dt.with_timezone(&Utc));
+    }
+    chrono::NaiveDate::parse_from_str(input, "%Y-%m-%d")
+        .map(|date| date.and_time(chrono::NaiveTime::MIN).and_utc())
+        .map_err(|_| anyhow::anyhow!("invalid time bound '{input}' — use RFC 3339 or YYYY-MM-DD"))
+}
+
 /// Format a signed cost delta for display (e.g. `+$0.0030`, `-$0.0010`, `$0.0000`).
 fn fmt_delta_cost(delta: f64) -> String {
     if delta > 0.000_05 {
@@ -343,6 +526,22 @@ pub enum ExportFormat {
     Csv,
 }
 
+/// Per-agent token/cost totals for a time window.
+///
+/// Returned by [`agent_costs_since`] for callers that need the data
+/// programmatically (e.g. the daemon's weekly self-report).
+#[derive(Debug, Clone)]
+pub struct AgentCost {
+    /// Agent name as recorded in the delegation log.
+    pub agent_name: String,
+    /// Number of `DelegationStart` events in the window.
+    pub delegations: usize,
+    /// Cumulative tokens across `DelegationEnd` events in the window.
+    pub total_tokens: u64,
+    /// Cumulative cost (USD) across `DelegationEnd` events in the window.
+    pub total_cost_usd: f64,
+}
+
 /// Aggregate statistics extracted from the delegation log.
 ///
 /// Returned by [`get_log_summary`] for callers that need the data
@@ -426,8 +625,41 @@ pub fn print_diff(log_path: &Path, run_a: &str, run_b: Option<&str>) -> Result<(
         .cloned()
         .collect();
 
-    let stats_a = collect_agent_stats(&events_a);
-    let stats_b = collect_agent_stats(&events_b);
+    // Run metadata for the header.
+    let info_a = runs.iter().find(|r| r.run_id == resolved_a);
+    let info_b = runs.iter().find(|r| r.run_id == resolved_b);
+    let ts_a = info_a
+        .and_then(|r| r.start_time)
+        .map(|t| t.format("%Y-%m-%d %H:%M:%S UTC").to_string())
+        .unwrap_or_else(|| "unknown".to_owned());
+    let ts_b = info_b
+        .and_then(|r| r.start_time)
+        .map(|t| t.format("%Y-%m-%d %H:%M:%S UTC").to_string())
+        .unwrap_or_else(|| "unknown".to_owned());
+
+    println!("Delegation Diff");
+    println!(
+        "  A: [{}]  {}",
+        &resolved_a[..8.min(resolved_a.len())],
+        ts_a
+    );
+    println!(
+        "  B: [{}]  {}",
+        &resolved_b[..8.min(resolved_b.len())],
+        ts_b
+    );
+    println!();
+    print_agent_diff_table(&events_a, &events_b);
+    println!();
+    println!("Use `zeroclaw delegations diff <run_a> <run_b>` to compare two specific runs.");
+    Ok(())
+}
+
+/// Print the per-agent A/B comparison table shared by [`print_diff`] and
+/// [`print_period_diff`].
+fn print_agent_diff_table(events_a: &[Value], events_b: &[Value]) {
+    let stats_a = collect_agent_stats(events_a);
+    let stats_b = collect_agent_stats(events_b);
 
     // Build lookup maps.
     let map_a: HashMap<String, &AgentStats> =
@@ -454,30 +686,6 @@ pub fn print_diff(log_path: &Path, run_a: &str, run_b: Option<&str>) -> Result<(
         w_y.cmp(&w_x).then(nx.cmp(ny))
     });
 
-    // Run metadata for the header.
-    let info_a = runs.iter().find(|r| r.run_id == resolved_a);
-    let info_b = runs.iter().find(|r| r.run_id == resolved_b);
-    let ts_a = info_a
-        .and_then(|r| r.start_time)
-        .map(|t| t.format("%Y-%m-%d %H:%M:%S UTC").to_string())
-        .unwrap_or_else(|| "unknown".to_owned());
-    let ts_b = info_b
-        .and_then(|r| r.start_time)
-        .map(|t| t.format("%Y-%m-%d %H:%M:%S UTC").to_string())
-        .unwrap_or_else(|| "unknown".to_owned());
-
-    println!("Delegation Diff");
-    println!(
-        "  A: [{}]  {}",
-        &resolved_a[..8.min(resolved_a.len())],
-        ts_a
-    );
-    println!(
-        "  B: [{}]  {}",
-        &resolved_b[..8.min(resolved_b.len())],
-        ts_b
-    );
-    println!();
     println!(
         "{:<22} {:>6} {:>6}  {:>8} {:>8} {:>8}  {:>8} {:>8} {:>8}",
         "agent", "del_A", "del_B", "tok_A", "tok_B", "Δtok", "cost_A", "cost_B", "Δcost"
@@ -593,8 +801,86 @@ pub fn print_diff(log_path: &Path, run_a: &str, run_b: Option<&str>) -> Result<(
         },
         total_cost_delta_str,
     );
+}
+
+/// Print a per-agent comparison of two time periods to stdout.
+///
+/// Period B spans `[since, until)` (`until` defaults to now); period A is the
+/// immediately preceding window of the same length, so
+/// `--since <monday>` compares this week against last week. Bounds accept
+/// RFC 3339 timestamps or bare `YYYY-MM-DD` dates (midnight UTC).
+///
+/// Events from **all** runs are bucketed by their own timestamps, which makes
+/// the comparison track trends over time (e.g. whether a prompt change
+/// reduced tokens) rather than a single run pair. The table layout matches
+/// [`print_diff`].
+pub fn print_period_diff(log_path: &Path, since: &str, until: Option<&str>) -> Result<()> {
+    let start_b = parse_time_bound(since)?;
+    let end_b = match until {
+        Some(u) => parse_time_bound(u)?,
+        None => Utc::now(),
+    };
+    if end_b <= start_b {
+        bail!(
+            "--until ({}) must be after --since ({})",
+            end_b.format("%Y-%m-%d %H:%M:%S UTC"),
+            start_b.format("%Y-%m-%d %H:%M:%S UTC")
+        );
+    }
+    let start_a = start_b - (end_b - start_b);
+
+    let all_events = read_all_events(log_path)?;
+    if all_events.is_empty() {
+        println!("No delegation data found at: {}", log_path.display());
+        println!("Run ZeroClaw with a workflow that uses the `delegate` tool.");
+        return Ok(());
+    }
+
+    let in_window = |ev: &&Value, from: DateTime<Utc>, to: DateTime<Utc>| {
+        ev.get("timestamp")
+            .and_then(parse_ts)
+            .is_some_and(|ts| ts >= from && ts < to)
+    };
+    let events_a: Vec<Value> = all_events
+        .iter()
+        .filter(|e| in_window(e, start_a, start_b))
+        .cloned()
+        .collect();
+    let events_b: Vec<Value> = all_events
+        .iter()
+        .filter(|e| in_window(e, start_b, end_b))
+        .cloned()
+        .collect();
+
+    if events_a.is_empty() && events_b.is_empty() {
+        println!(
+            "No delegation events between {} and {}.",
+            start_a.format("%Y-%m-%d %H:%M:%S UTC"),
+            end_b.format("%Y-%m-%d %H:%M:%S UTC")
+        );
+        return Ok(());
+    }
+
+    let fmt_window = |from: DateTime<Utc>, to: DateTime<Utc>| {
+        format!(
+            "{} .. {}",
+            from.format("%Y-%m-%d %H:%M UTC"),
+            to.format("%Y-%m-%d %H:%M UTC")
+        )
+    };
+    println!("Delegation Period Diff");
+    println!(
+        "  A: {}  ({} events)",
+        fmt_window(start_a, start_b),
+        events_a.len()
+    );
+    println!(
+        "  B: {}  ({} events)",
+        fmt_window(start_b, end_b),
+        events_b.len()
+    );
     println!();
-    println!("Use `zeroclaw delegations diff <run_a> <run_b>` to compare two specific runs.");
+    print_agent_diff_table(&events_a, &events_b);
     Ok(())
 }
 
@@ -1078,11 +1364,14 @@ pub fn print_providers(log_path: &Path, run_id: Option<&str>) -> Result<()> {
     Ok(())
 }
 
-/// Aggregate delegation events by `depth` level and print a breakdown table.
+/// Aggregate delegation events by user-defined tag and print a breakdown table.
 ///
-/// Rows are sorted by depth ascending (root level first). When `run_id` is
-/// `Some`, only events from that run are included.
-pub fn print_depth(log_path: &Path, run_id: Option<&str>) -> Result<()> {
+/// Events may carry several tags and are counted under each, so rows are
+/// attributions rather than a partition (totals can exceed the log total).
+/// Rows are sorted by total tokens descending; alpha tiebreak on tag. When
+/// `run_id` is `Some`, only events from that run are included and the `runs`
+/// column shows `"—"`.
+pub fn print_tags(log_path: &Path, run_id: Option<&str>) -> Result<()> {
     let all_events = read_all_events(log_path)?;
     if all_events.is_empty() {
         println!("No delegation data found at: {}", log_path.display());
@@ -1104,63 +1393,70 @@ pub fn print_depth(log_path: &Path, run_id: Option<&str>) -> Result<()> {
         return Ok(());
     }
 
-    // Aggregate per depth level.
-    let mut rows: HashMap<u32, DepthRow> = HashMap::new();
+    // Aggregate per tag; track distinct runs via a side-table.
+    let mut rows: HashMap<String, TagRow> = HashMap::new();
+    let mut tag_runs: HashMap<String, HashSet<String>> = HashMap::new();
 
     for ev in &events {
-        let Some(depth) = ev.get("depth").and_then(|x| x.as_u64()).map(|d| d as u32) else {
+        let Some(tags) = ev.get("tags").and_then(Value::as_array) else {
             continue;
         };
-        let entry = rows.entry(depth).or_insert_with(|| DepthRow {
-            depth,
-            delegation_count: 0,
-            end_count: 0,
-            success_count: 0,
-            total_tokens: 0,
-            total_cost_usd: 0.0,
-        });
-        match ev.get("event_type").and_then(|x| x.as_str()) {
-            Some("DelegationStart") => entry.delegation_count += 1,
-            Some("DelegationEnd") => {
-                entry.end_count += 1;
-                if ev.get("success").and_then(|x| x.as_bool()).unwrap_or(false) {
-                    entry.success_count += 1;
-                }
-                if let Some(tok) = ev.get("tokens_used").and_then(|x| x.as_u64()) {
-                    entry.total_tokens += tok;
-                }
-                if let Some(cost) = ev.get("cost_usd").and_then(|x| x.as_f64()) {
-                    entry.total_cost_usd += cost;
+        let rid = ev.get("run_id").and_then(|x| x.as_str()).unwrap_or("");
+        for tag in tags.iter().filter_map(Value::as_str) {
+            if !rid.is_empty() {
+                tag_runs
+                    .entry(tag.to_owned())
+                    .or_default()
+                    .insert(rid.to_owned());
+            }
+            let entry = rows.entry(tag.to_owned()).or_insert_with(|| TagRow {
+                tag: tag.to_owned(),
+                run_count: 0,
+                delegation_count: 0,
+                total_tokens: 0,
+                total_cost_usd: 0.0,
+            });
+            match ev.get("event_type").and_then(|x| x.as_str()) {
+                Some("DelegationStart") => entry.delegation_count += 1,
+                Some("DelegationEnd") => {
+                    if let Some(tok) = ev.get("tokens_used").and_then(|x| x.as_u64()) {
+                        entry.total_tokens += tok;
+                    }
+                    if let Some(cost) = ev.get("cost_usd").and_then(|x| x.as_f64()) {
+                        entry.total_cost_usd += cost;
+                    }
                 }
+                _ => {}
             }
-            _ => {}
         }
     }
 
-    // Sort by depth ascending.
-    let mut sorted: Vec<DepthRow> = rows.into_values().collect();
-    sorted.sort_by_key(|r| r.depth);
+    if rows.is_empty() {
+        println!("No tagged delegation events found.");
+        println!("Start tagged runs with `zeroclaw agent --tag project:foo`.");
+        return Ok(());
+    }
+
+    // Fill run counts from the side-table.
+    for (tag, row) in &mut rows {
+        row.run_count = tag_runs.get(tag).map_or(0, |s| s.len());
+    }
+
+    let mut sorted: Vec<TagRow> = rows.into_values().collect();
+    sorted.sort_by(|a, b| b.total_tokens.cmp(&a.total_tokens).then(a.tag.cmp(&b.tag)));
 
     let scope = run_id
         .map(|r| format!("  (run: {r})"))
         .unwrap_or_else(|| "  (all runs)".to_owned());
-    println!("Depth Breakdown{scope}");
+    println!("Tag Breakdown{scope}");
     println!();
     println!(
-        "{:>5}  {:>11}  {:>6}  {:>9}  {:>10}  {:>10}",
-        "depth", "delegations", "ended", "success%", "tokens", "cost"
+        "{:>3}  {:<32} {:>5}  {:>11}  {:>10}  {:>10}",
+        "#", "tag", "runs", "delegations", "tokens", "cost"
     );
-    println!("{}", "─".repeat(60));
+    println!("{}", "─".repeat(80));
 
-    for row in &sorted {
-        let success_pct = if row.end_count > 0 {
-            format!(
-                "{:.1}%",
-                100.0 * row.success_count as f64 / row.end_count as f64
-            )
-        } else {
-            "—".to_owned()
-        };
+    for (i, row) in sorted.iter().enumerate() {
         let tok = if row.total_tokens > 0 {
             row.total_tokens.to_string()
         } else {
@@ -1171,29 +1467,292 @@ pub fn print_depth(log_path: &Path, run_id: Option<&str>) -> Result<()> {
         } else {
             "—".to_owned()
         };
+        let runs_col = if run_id.is_some() {
+            "—".to_owned()
+        } else {
+            row.run_count.to_string()
+        };
         println!(
-            "{:>5}  {:>11}  {:>6}  {:>9}  {:>10}  {:>10}",
-            row.depth, row.delegation_count, row.end_count, success_pct, tok, cost,
+            "{:>3}  {:<32} {:>5}  {:>11}  {:>10}  {:>10}",
+            i + 1,
+            row.tag,
+            runs_col,
+            row.delegation_count,
+            tok,
+            cost,
         );
     }
 
-    println!("{}", "─".repeat(60));
-    let total_tok: u64 = sorted.iter().map(|r| r.total_tokens).sum();
-    let total_cost: f64 = sorted.iter().map(|r| r.total_cost_usd).sum();
-    println!(
-        "{:>5}  {:>11}  {:>6}  {:>9}  {:>10}  {:>10}",
-        "TOTAL",
-        sorted.iter().map(|r| r.delegation_count).sum::<usize>(),
-        sorted.iter().map(|r| r.end_count).sum::<usize>(),
-        "",
-        if total_tok > 0 {
-            total_tok.to_string()
-        } else {
-            "—".to_owned()
-        },
-        if total_cost > 0.0 {
-            format!("${total_cost:.4}")
-        } else {
+    println!();
+    println!("Use `--run <id>` to scope to a single run, or `--tag <tag>` on");
+    println!("any other report to filter it to one project.");
+    Ok(())
+}
+
+/// Aggregate delegation events by originating channel and print a breakdown table.
+///
+/// The `channel` field is stamped onto events from runs dispatched by a chat
+/// channel (Telegram, Discord, etc.); CLI and scheduler runs carry none and
+/// are not counted here. Rows are sorted by total tokens descending; alpha
+/// tiebreak on channel name. When `run_id` is `Some`, only events from that
+/// run are included and the `runs` column shows `"—"`.
+pub fn print_channels(log_path: &Path, run_id: Option<&str>) -> Result<()> {
+    let all_events = read_all_events(log_path)?;
+    if all_events.is_empty() {
+        println!("No delegation data found at: {}", log_path.display());
+        println!("Run ZeroClaw with a workflow that uses the `delegate` tool.");
+        return Ok(());
+    }
+
+    let events: Vec<&Value> = if let Some(rid) = run_id {
+        all_events
+            .iter()
+            .filter(|e| e.get("run_id").and_then(|x| x.as_str()) == Some(rid))
+            .collect()
+    } else {
+        all_events.iter().collect()
+    };
+
+    if events.is_empty() {
+        println!("No events found for run: {}", run_id.unwrap_or("?"));
+        return Ok(());
+    }
+
+    // Aggregate per channel; track distinct runs via a side-table.
+    let mut rows: HashMap<String, ChannelRow> = HashMap::new();
+    let mut channel_runs: HashMap<String, HashSet<String>> = HashMap::new();
+
+    for ev in &events {
+        let Some(channel) = ev.get("channel").and_then(|x| x.as_str()) else {
+            continue;
+        };
+        let rid = ev.get("run_id").and_then(|x| x.as_str()).unwrap_or("");
+        if !rid.is_empty() {
+            channel_runs
+                .entry(channel.to_owned())
+                .or_default()
+                .insert(rid.to_owned());
+        }
+        let entry = rows
+            .entry(channel.to_owned())
+            .or_insert_with(|| ChannelRow {
+                channel: channel.to_owned(),
+                run_count: 0,
+                delegation_count: 0,
+                total_tokens: 0,
+                total_cost_usd: 0.0,
+            });
+        match ev.get("event_type").and_then(|x| x.as_str()) {
+            Some("DelegationStart") => entry.delegation_count += 1,
+            Some("DelegationEnd") => {
+                if let Some(tok) = ev.get("tokens_used").and_then(|x| x.as_u64()) {
+                    entry.total_tokens += tok;
+                }
+                if let Some(cost) = ev.get("cost_usd").and_then(|x| x.as_f64()) {
+                    entry.total_cost_usd += cost;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if rows.is_empty() {
+        println!("No channel-attributed delegation events found.");
+        println!("Channel attribution is recorded for runs dispatched by chat channels.");
+        return Ok(());
+    }
+
+    // Fill run counts from the side-table.
+    for (channel, row) in &mut rows {
+        row.run_count = channel_runs.get(channel).map_or(0, |s| s.len());
+    }
+
+    let mut sorted: Vec<ChannelRow> = rows.into_values().collect();
+    sorted.sort_by(|a, b| {
+        b.total_tokens
+            .cmp(&a.total_tokens)
+            .then(a.channel.cmp(&b.channel))
+    });
+
+    let scope = run_id
+        .map(|r| format!("  (run: {r})"))
+        .unwrap_or_else(|| "  (all runs)".to_owned());
+    println!("Channel Breakdown{scope}");
+    println!();
+    println!(
+        "{:>3}  {:<20} {:>5}  {:>11}  {:>10}  {:>10}",
+        "#", "channel", "runs", "delegations", "tokens", "cost"
+    );
+    println!("{}", "─".repeat(68));
+
+    for (i, row) in sorted.iter().enumerate() {
+        let tok = if row.total_tokens > 0 {
+            row.total_tokens.to_string()
+        } else {
+            "—".to_owned()
+        };
+        let cost = if row.total_cost_usd > 0.0 {
+            format!("${:.4}", row.total_cost_usd)
+        } else {
+            "—".to_owned()
+        };
+        let runs_col = if run_id.is_some() {
+            "—".to_owned()
+        } else {
+            row.run_count.to_string()
+        };
+        println!(
+            "{:>3}  {:<20} {:>5}  {:>11}  {:>10}  {:>10}",
+            i + 1,
+            row.channel,
+            runs_col,
+            row.delegation_count,
+            tok,
+            cost,
+        );
+    }
+
+    println!("{}", "─".repeat(68));
+    let total_tok: u64 = sorted.iter().map(|r| r.total_tokens).sum();
+    let total_cost: f64 = sorted.iter().map(|r| r.total_cost_usd).sum();
+    println!(
+        "{:>3}  {:<20} {:>5}  {:>11}  {:>10}  {:>10}",
+        "",
+        "TOTAL",
+        "",
+        sorted.iter().map(|r| r.delegation_count).sum::<usize>(),
+        if total_tok > 0 {
+            total_tok.to_string()
+        } else {
+            "—".to_owned()
+        },
+        if total_cost > 0.0 {
+            format!("${total_cost:.4}")
+        } else {
+            "—".to_owned()
+        },
+    );
+    println!();
+    println!("Use `--run <id>` to scope to a single run.");
+    Ok(())
+}
+
+/// Aggregate delegation events by `depth` level and print a breakdown table.
+///
+/// Rows are sorted by depth ascending (root level first). When `run_id` is
+/// `Some`, only events from that run are included.
+pub fn print_depth(log_path: &Path, run_id: Option<&str>) -> Result<()> {
+    let all_events = read_all_events(log_path)?;
+    if all_events.is_empty() {
+        println!("No delegation data found at: {}", log_path.display());
+        println!("Run ZeroClaw with a workflow that uses the `delegate` tool.");
+        return Ok(());
+    }
+
+    let events: Vec<&Value> = if let Some(rid) = run_id {
+        all_events
+            .iter()
+            .filter(|e| e.get("run_id").and_then(|x| x.as_str()) == Some(rid))
+            .collect()
+    } else {
+        all_events.iter().collect()
+    };
+
+    if events.is_empty() {
+        println!("No events found for run: {}", run_id.unwrap_or("?"));
+        return Ok(());
+    }
+
+    // Aggregate per depth level.
+    let mut rows: HashMap<u32, DepthRow> = HashMap::new();
+
+    for ev in &events {
+        let Some(depth) = ev.get("depth").and_then(|x| x.as_u64()).map(|d| d as u32) else {
+            continue;
+        };
+        let entry = rows.entry(depth).or_insert_with(|| DepthRow {
+            depth,
+            delegation_count: 0,
+            end_count: 0,
+            success_count: 0,
+            total_tokens: 0,
+            total_cost_usd: 0.0,
+        });
+        match ev.get("event_type").and_then(|x| x.as_str()) {
+            Some("DelegationStart") => entry.delegation_count += 1,
+            Some("DelegationEnd") => {
+                entry.end_count += 1;
+                if ev.get("success").and_then(|x| x.as_bool()).unwrap_or(false) {
+                    entry.success_count += 1;
+                }
+                if let Some(tok) = ev.get("tokens_used").and_then(|x| x.as_u64()) {
+                    entry.total_tokens += tok;
+                }
+                if let Some(cost) = ev.get("cost_usd").and_then(|x| x.as_f64()) {
+                    entry.total_cost_usd += cost;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // Sort by depth ascending.
+    let mut sorted: Vec<DepthRow> = rows.into_values().collect();
+    sorted.sort_by_key(|r| r.depth);
+
+    let scope = run_id
+        .map(|r| format!("  (run: {r})"))
+        .unwrap_or_else(|| "  (all runs)".to_owned());
+    println!("Depth Breakdown{scope}");
+    println!();
+    println!(
+        "{:>5}  {:>11}  {:>6}  {:>9}  {:>10}  {:>10}",
+        "depth", "delegations", "ended", "success%", "tokens", "cost"
+    );
+    println!("{}", "─".repeat(60));
+
+    for row in &sorted {
+        let success_pct = if row.end_count > 0 {
+            format!(
+                "{:.1}%",
+                100.0 * row.success_count as f64 / row.end_count as f64
+            )
+        } else {
+            "—".to_owned()
+        };
+        let tok = if row.total_tokens > 0 {
+            row.total_tokens.to_string()
+        } else {
+            "—".to_owned()
+        };
+        let cost = if row.total_cost_usd > 0.0 {
+            format!("${:.4}", row.total_cost_usd)
+        } else {
+            "—".to_owned()
+        };
+        println!(
+            "{:>5}  {:>11}  {:>6}  {:>9}  {:>10}  {:>10}",
+            row.depth, row.delegation_count, row.end_count, success_pct, tok, cost,
+        );
+    }
+
+    println!("{}", "─".repeat(60));
+    let total_tok: u64 = sorted.iter().map(|r| r.total_tokens).sum();
+    let total_cost: f64 = sorted.iter().map(|r| r.total_cost_usd).sum();
+    println!(
+        "{:>5}  {:>11}  {:>6}  {:>9}  {:>10}  {:>10}",
+        "TOTAL",
+        sorted.iter().map(|r| r.delegation_count).sum::<usize>(),
+        sorted.iter().map(|r| r.end_count).sum::<usize>(),
+        "",
+        if total_tok > 0 {
+            total_tok.to_string()
+        } else {
+            "—".to_owned()
+        },
+        if total_cost > 0.0 {
+            format!("${total_cost:.4}")
+        } else {
             "—".to_owned()
         },
     );
@@ -1419,21 +1978,14 @@ pub fn print_slow(log_path: &Path, run_id: Option<&str>, limit: usize) -> Result
     Ok(())
 }
 
-/// Stream delegation events to stdout as JSONL or CSV.
-///
-/// `ExportFormat::Jsonl` (default): emits one raw event JSON object per line,
-/// suitable for piping to `jq` or re-importing into another tool.
-///
-/// Print a per-run cost breakdown table sorted by total cost descending.
+/// Show per-tool call analytics: call count, failures, latency, and output volume.
 ///
-/// One row per stored run. When `run_id` is `Some`, only that run is shown.
-///
-/// Columns: `#` | `run` (8-char prefix) | `start (UTC)` | `delegations` |
-///          `tokens` | `cost` | `avg/del`
+/// Reads `ToolCallEnd` events, optionally filtered to a single run, aggregates
+/// by tool name, and sorts by cumulative duration descending so the tools that
+/// dominate latency appear first.
 ///
-/// `avg/del` is the average cost per completed delegation for that run,
-/// shown as `—` when no delegation ends were recorded.
-pub fn print_cost(log_path: &Path, run_id: Option<&str>) -> Result<()> {
+/// Columns: `#` | `tool` | `calls` | `fail` | `success%` | `total` | `avg` | `output`
+pub fn print_tools(log_path: &Path, run_id: Option<&str>) -> Result<()> {
     let all_events = read_all_events(log_path)?;
     if all_events.is_empty() {
         println!("No delegation data found at: {}", log_path.display());
@@ -1441,7 +1993,202 @@ pub fn print_cost(log_path: &Path, run_id: Option<&str>) -> Result<()> {
         return Ok(());
     }
 
-    // Filter to the requested run before aggregating if a run_id is given.
+    struct ToolStats {
+        calls: u64,
+        failures: u64,
+        total_duration_ms: u64,
+        total_output_bytes: u64,
+    }
+
+    let mut map: HashMap<String, ToolStats> = HashMap::new();
+    for ev in &all_events {
+        if ev.get("event_type").and_then(|x| x.as_str()) != Some("ToolCallEnd") {
+            continue;
+        }
+        if let Some(rid) = run_id {
+            if ev.get("run_id").and_then(|x| x.as_str()) != Some(rid) {
+                continue;
+            }
+        }
+        let tool = ev.get("tool").and_then(|x| x.as_str()).unwrap_or("?");
+        let entry = map.entry(tool.to_owned()).or_insert(ToolStats {
+            calls: 0,
+            failures: 0,
+            total_duration_ms: 0,
+            total_output_bytes: 0,
+        });
+        entry.calls += 1;
+        if ev.get("success").and_then(serde_json::Value::as_bool) != Some(true) {
+            entry.failures += 1;
+        }
+        entry.total_duration_ms += ev.get("duration_ms").and_then(|x| x.as_u64()).unwrap_or(0);
+        entry.total_output_bytes += ev.get("output_bytes").and_then(|x| x.as_u64()).unwrap_or(0);
+    }
+
+    let scope = run_id
+        .map(|r| format!("  (run: {r})"))
+        .unwrap_or_else(|| "  (all runs)".to_owned());
+    println!("Tool Call Analytics{scope}");
+    println!();
+
+    if map.is_empty() {
+        println!("No tool calls recorded.");
+        println!("Tool analytics require runs logged after tool-call events were added.");
+        return Ok(());
+    }
+
+    let mut rows: Vec<(String, ToolStats)> = map.into_iter().collect();
+    // Sort by cumulative duration descending — latency hogs first.
+    rows.sort_by(|a, b| b.1.total_duration_ms.cmp(&a.1.total_duration_ms));
+
+    println!(
+        "{:>3}  {:<22}  {:>6}  {:>5}  {:>8}  {:>9}  {:>9}  {:>9}",
+        "#", "tool", "calls", "fail", "success%", "total", "avg", "output"
+    );
+    println!("{}", "─".repeat(86));
+
+    for (i, (tool, stats)) in rows.iter().enumerate() {
+        // calls is always >= 1 (entries are only created when an event is seen).
+        let success_pct = format!(
+            "{:.1}%",
+            (stats.calls - stats.failures) as f64 / stats.calls as f64 * 100.0
+        );
+        let avg = fmt_duration(stats.total_duration_ms / stats.calls.max(1));
+        println!(
+            "{:>3}  {:<22}  {:>6}  {:>5}  {:>8}  {:>9}  {:>9}  {:>9}",
+            i + 1,
+            tool,
+            stats.calls,
+            stats.failures,
+            success_pct,
+            fmt_duration(stats.total_duration_ms),
+            avg,
+            fmt_bytes(stats.total_output_bytes),
+        );
+    }
+
+    println!("{}", "─".repeat(86));
+    println!("{} tool(s) recorded.", rows.len());
+    Ok(())
+}
+
+/// List the N slowest individual tool calls ranked by duration descending.
+///
+/// Reads `ToolCallEnd` events, optionally filtered to a single run, then sorts
+/// by `duration_ms` descending and prints the top `limit` rows.
+///
+/// Columns: `#` | `run` (8-char prefix) | `tool` | `duration` | `ok` | `output` | `finished (UTC)`
+pub fn print_tools_slow(log_path: &Path, run_id: Option<&str>, limit: usize) -> Result<()> {
+    let all_events = read_all_events(log_path)?;
+    if all_events.is_empty() {
+        println!("No delegation data found at: {}", log_path.display());
+        println!("Run ZeroClaw with a workflow that uses the `delegate` tool.");
+        return Ok(());
+    }
+
+    let mut ends: Vec<&Value> = all_events
+        .iter()
+        .filter(|e| {
+            e.get("event_type").and_then(|x| x.as_str()) == Some("ToolCallEnd")
+                && run_id.is_none_or(|rid| e.get("run_id").and_then(|x| x.as_str()) == Some(rid))
+        })
+        .collect();
+
+    // Sort slowest first.
+    ends.sort_by(|a, b| {
+        let da = a.get("duration_ms").and_then(|x| x.as_u64()).unwrap_or(0);
+        let db = b.get("duration_ms").and_then(|x| x.as_u64()).unwrap_or(0);
+        db.cmp(&da)
+    });
+
+    let scope = run_id
+        .map(|r| format!("  (run: {r})"))
+        .unwrap_or_else(|| "  (all runs)".to_owned());
+    let shown = ends.len().min(limit);
+    println!(
+        "Slowest Tool Calls{scope}  [showing {shown} of {}]",
+        ends.len()
+    );
+    println!();
+
+    if ends.is_empty() {
+        println!("No tool calls recorded.");
+        return Ok(());
+    }
+
+    println!(
+        "{:>3}  {:<10}  {:<22}  {:>9}  {:>3}  {:>9}  {}",
+        "#", "run", "tool", "duration", "ok", "output", "finished (UTC)"
+    );
+    println!("{}", "─".repeat(90));
+
+    for (i, ev) in ends.iter().take(limit).enumerate() {
+        let run = ev
+            .get("run_id")
+            .and_then(|x| x.as_str())
+            .map(|r| r.chars().take(8).collect::<String>())
+            .unwrap_or_else(|| "?".to_owned());
+        let tool = ev.get("tool").and_then(|x| x.as_str()).unwrap_or("?");
+        let duration = ev
+            .get("duration_ms")
+            .and_then(|x| x.as_u64())
+            .map(fmt_duration)
+            .unwrap_or_else(|| "—".to_owned());
+        let ok = match ev.get("success").and_then(serde_json::Value::as_bool) {
+            Some(true) => "✓",
+            Some(false) => "✗",
+            None => "?",
+        };
+        let output = ev
+            .get("output_bytes")
+            .and_then(|x| x.as_u64())
+            .map(fmt_bytes)
+            .unwrap_or_else(|| "—".to_owned());
+        let finished = ev
+            .get("timestamp")
+            .and_then(|x| x.as_str())
+            .map(|t| t.chars().take(19).collect::<String>())
+            .unwrap_or_else(|| "—".to_owned());
+        println!(
+            "{:>3}  {:<10}  {:<22}  {:>9}  {:>3}  {:>9}  {}",
+            i + 1,
+            run,
+            tool,
+            duration,
+            ok,
+            output,
+            finished,
+        );
+    }
+
+    println!("{}", "─".repeat(90));
+    println!("Top {shown} slowest of {} tool call(s).", ends.len());
+    Ok(())
+}
+
+/// Stream delegation events to stdout as JSONL or CSV.
+///
+/// `ExportFormat::Jsonl` (default): emits one raw event JSON object per line,
+/// suitable for piping to `jq` or re-importing into another tool.
+///
+/// Print a per-run cost breakdown table sorted by total cost descending.
+///
+/// One row per stored run. When `run_id` is `Some`, only that run is shown.
+///
+/// Columns: `#` | `run` (8-char prefix) | `start (UTC)` | `delegations` |
+///          `tokens` | `cost` | `avg/del`
+///
+/// `avg/del` is the average cost per completed delegation for that run,
+/// shown as `—` when no delegation ends were recorded.
+pub fn print_cost(log_path: &Path, run_id: Option<&str>) -> Result<()> {
+    let all_events = read_all_events(log_path)?;
+    if all_events.is_empty() {
+        println!("No delegation data found at: {}", log_path.display());
+        println!("Run ZeroClaw with a workflow that uses the `delegate` tool.");
+        return Ok(());
+    }
+
+    // Filter to the requested run before aggregating if a run_id is given.
     let events: Vec<&Value> = if let Some(rid) = run_id {
         all_events
             .iter()
@@ -2611,18 +3358,9 @@ pub fn print_daily(log_path: &Path, run_id: Option<&str>) -> Result<()> {
             continue;
         }
         let date = ts[..10].to_owned();
-        let success = ev
-            .get("success")
-            .and_then(|x| x.as_bool())
-            .unwrap_or(false);
-        let tokens = ev
-            .get("tokens_used")
-            .and_then(|x| x.as_u64())
-            .unwrap_or(0);
-        let cost = ev
-            .get("cost_usd")
-            .and_then(|x| x.as_f64())
-            .unwrap_or(0.0);
+        let success = ev.get("success").and_then(|x| x.as_bool()).unwrap_or(false);
+        let tokens = ev.get("tokens_used").and_then(|x| x.as_u64()).unwrap_or(0);
+        let cost = ev.get("cost_usd").and_then(|x| x.as_f64()).unwrap_or(0.0);
         let entry = map.entry(date).or_insert((0usize, 0usize, 0u64, 0.0f64));
         entry.0 += 1;
         if success {
@@ -2734,18 +3472,9 @@ pub fn print_hourly(log_path: &Path, run_id: Option<&str>) -> Result<()> {
             continue;
         }
         let hour = ts[11..13].to_owned();
-        let success = ev
-            .get("success")
-            .and_then(|x| x.as_bool())
-            .unwrap_or(false);
-        let tokens = ev
-            .get("tokens_used")
-            .and_then(|x| x.as_u64())
-            .unwrap_or(0);
-        let cost = ev
-            .get("cost_usd")
-            .and_then(|x| x.as_f64())
-            .unwrap_or(0.0);
+        let success = ev.get("success").and_then(|x| x.as_bool()).unwrap_or(false);
+        let tokens = ev.get("tokens_used").and_then(|x| x.as_u64()).unwrap_or(0);
+        let cost = ev.get("cost_usd").and_then(|x| x.as_f64()).unwrap_or(0.0);
         let entry = map.entry(hour).or_insert((0usize, 0usize, 0u64, 0.0f64));
         entry.0 += 1;
         if success {
@@ -2790,7 +3519,11 @@ pub fn print_hourly(log_path: &Path, run_id: Option<&str>) -> Result<()> {
         };
         println!(
             "{:<8}  {:>7}  {:>8}  {:>10}  {:>10}",
-            format!("{hour}:xx"), count, ok_pct, tok_str, cost_str,
+            format!("{hour}:xx"),
+            count,
+            ok_pct,
+            tok_str,
+            cost_str,
         );
         total_count += count;
         total_success += success_count;
@@ -2858,18 +3591,9 @@ pub fn print_monthly(log_path: &Path, run_id: Option<&str>) -> Result<()> {
             continue;
         }
         let month = ts[..7].to_owned();
-        let success = ev
-            .get("success")
-            .and_then(|x| x.as_bool())
-            .unwrap_or(false);
-        let tokens = ev
-            .get("tokens_used")
-            .and_then(|x| x.as_u64())
-            .unwrap_or(0);
-        let cost = ev
-            .get("cost_usd")
-            .and_then(|x| x.as_f64())
-            .unwrap_or(0.0);
+        let success = ev.get("success").and_then(|x| x.as_bool()).unwrap_or(false);
+        let tokens = ev.get("tokens_used").and_then(|x| x.as_u64()).unwrap_or(0);
+        let cost = ev.get("cost_usd").and_then(|x| x.as_f64()).unwrap_or(0.0);
         let entry = map.entry(month).or_insert((0usize, 0usize, 0u64, 0.0f64));
         entry.0 += 1;
         if success {
@@ -2991,18 +3715,9 @@ pub fn print_quarterly(log_path: &Path, run_id: Option<&str>) -> Result<()> {
             _ => continue,
         };
         let key = format!("{year}-Q{quarter}");
-        let success = ev
-            .get("success")
-            .and_then(|x| x.as_bool())
-            .unwrap_or(false);
-        let tokens = ev
-            .get("tokens_used")
-            .and_then(|x| x.as_u64())
-            .unwrap_or(0);
-        let cost = ev
-            .get("cost_usd")
-            .and_then(|x| x.as_f64())
-            .unwrap_or(0.0);
+        let success = ev.get("success").and_then(|x| x.as_bool()).unwrap_or(false);
+        let tokens = ev.get("tokens_used").and_then(|x| x.as_u64()).unwrap_or(0);
+        let cost = ev.get("cost_usd").and_then(|x| x.as_f64()).unwrap_or(0.0);
         let entry = map.entry(key).or_insert((0usize, 0usize, 0u64, 0.0f64));
         entry.0 += 1;
         if success {
@@ -3110,14 +3825,8 @@ pub fn print_agent_model(log_path: &Path, run_id: Option<&str>) -> Result<()> {
             .and_then(|x| x.as_str())
             .unwrap_or("unknown");
         let key = format!("{agent}/{model}");
-        let tokens = ev
-            .get("tokens_used")
-            .and_then(|x| x.as_u64())
-            .unwrap_or(0);
-        let cost = ev
-            .get("cost_usd")
-            .and_then(|x| x.as_f64())
-            .unwrap_or(0.0);
+        let tokens = ev.get("tokens_used").and_then(|x| x.as_u64()).unwrap_or(0);
+        let cost = ev.get("cost_usd").and_then(|x| x.as_f64()).unwrap_or(0.0);
         let entry = map.entry(key).or_insert((0, 0, 0.0));
         entry.0 += 1;
         entry.1 += tokens;
@@ -3146,9 +3855,7 @@ pub fn print_agent_model(log_path: &Path, run_id: Option<&str>) -> Result<()> {
     let mut total_cost: f64 = 0.0;
 
     for (rank, (key, count, tokens, cost)) in rows.iter().enumerate() {
-        let (agent, model) = key
-            .split_once('/')
-            .unwrap_or((key.as_str(), "unknown"));
+        let (agent, model) = key.split_once('/').unwrap_or((key.as_str(), "unknown"));
         let tok_str = if *tokens > 0 {
             tokens.to_string()
         } else {
@@ -3227,14 +3934,8 @@ pub fn print_provider_model(log_path: &Path, run_id: Option<&str>) -> Result<()>
             .and_then(|x| x.as_str())
             .unwrap_or("unknown");
         let key = format!("{provider}/{model}");
-        let tokens = ev
-            .get("tokens_used")
-            .and_then(|x| x.as_u64())
-            .unwrap_or(0);
-        let cost = ev
-            .get("cost_usd")
-            .and_then(|x| x.as_f64())
-            .unwrap_or(0.0);
+        let tokens = ev.get("tokens_used").and_then(|x| x.as_u64()).unwrap_or(0);
+        let cost = ev.get("cost_usd").and_then(|x| x.as_f64()).unwrap_or(0.0);
         let entry = map.entry(key).or_insert((0, 0, 0.0));
         entry.0 += 1;
         entry.1 += tokens;
@@ -3263,9 +3964,7 @@ pub fn print_provider_model(log_path: &Path, run_id: Option<&str>) -> Result<()>
     let mut total_cost: f64 = 0.0;
 
     for (rank, (key, count, tokens, cost)) in rows.iter().enumerate() {
-        let (provider, model) = key
-            .split_once('/')
-            .unwrap_or((key.as_str(), "unknown"));
+        let (provider, model) = key.split_once('/').unwrap_or((key.as_str(), "unknown"));
         let tok_str = if *tokens > 0 {
             tokens.to_string()
         } else {
@@ -3344,14 +4043,8 @@ pub fn print_agent_provider(log_path: &Path, run_id: Option<&str>) -> Result<()>
             .and_then(|x| x.as_str())
             .unwrap_or("unknown");
         let key = format!("{agent}/{provider}");
-        let tokens = ev
-            .get("tokens_used")
-            .and_then(|x| x.as_u64())
-            .unwrap_or(0);
-        let cost = ev
-            .get("cost_usd")
-            .and_then(|x| x.as_f64())
-            .unwrap_or(0.0);
+        let tokens = ev.get("tokens_used").and_then(|x| x.as_u64()).unwrap_or(0);
+        let cost = ev.get("cost_usd").and_then(|x| x.as_f64()).unwrap_or(0.0);
         let entry = map.entry(key).or_insert((0, 0, 0.0));
         entry.0 += 1;
         entry.1 += tokens;
@@ -3380,9 +4073,7 @@ pub fn print_agent_provider(log_path: &Path, run_id: Option<&str>) -> Result<()>
     let mut total_cost: f64 = 0.0;
 
     for (rank, (key, count, tokens, cost)) in rows.iter().enumerate() {
-        let (agent, provider) = key
-            .split_once('/')
-            .unwrap_or((key.as_str(), "unknown"));
+        let (agent, provider) = key.split_once('/').unwrap_or((key.as_str(), "unknown"));
         let tok_str = if *tokens > 0 {
             tokens.to_string()
         } else {
@@ -3459,10 +4150,7 @@ pub fn print_duration_bucket(log_path: &Path, run_id: Option<&str>) -> Result<()
         if ev.get("event_type").and_then(|x| x.as_str()) != Some("DelegationEnd") {
             continue;
         }
-        let duration_ms = ev
-            .get("duration_ms")
-            .and_then(|x| x.as_u64())
-            .unwrap_or(0);
+        let duration_ms = ev.get("duration_ms").and_then(|x| x.as_u64()).unwrap_or(0);
         let idx = match duration_ms {
             0..=499 => 0,
             500..=1999 => 1,
@@ -3471,14 +4159,8 @@ pub fn print_duration_bucket(log_path: &Path, run_id: Option<&str>) -> Result<()
             _ => 4,
         };
         let ok = ev.get("success").and_then(|x| x.as_bool()).unwrap_or(false);
-        let tokens = ev
-            .get("tokens_used")
-            .and_then(|x| x.as_u64())
-            .unwrap_or(0);
-        let cost = ev
-            .get("cost_usd")
-            .and_then(|x| x.as_f64())
-            .unwrap_or(0.0);
+        let tokens = ev.get("tokens_used").and_then(|x| x.as_u64()).unwrap_or(0);
+        let cost = ev.get("cost_usd").and_then(|x| x.as_f64()).unwrap_or(0.0);
         buckets[idx].0 += 1;
         if ok {
             buckets[idx].1 += 1;
@@ -3549,7 +4231,13 @@ pub fn print_duration_bucket(log_path: &Path, run_id: Option<&str>) -> Result<()
 ///
 /// Mirrors `zeroclaw delegations token-bucket`.
 pub fn print_token_bucket(log_path: &Path, run_id: Option<&str>) -> Result<()> {
-    const LABELS: [&str; 5] = ["0\u{2013}99", "100\u{2013}999", "1k\u{2013}9.9k", "10k\u{2013}99.9k", "100k+"];
+    const LABELS: [&str; 5] = [
+        "0\u{2013}99",
+        "100\u{2013}999",
+        "1k\u{2013}9.9k",
+        "10k\u{2013}99.9k",
+        "100k+",
+    ];
 
     let all_events = read_all_events(log_path)?;
     if all_events.is_empty() {
@@ -3579,10 +4267,7 @@ pub fn print_token_bucket(log_path: &Path, run_id: Option<&str>) -> Result<()> {
         if ev.get("event_type").and_then(|x| x.as_str()) != Some("DelegationEnd") {
             continue;
         }
-        let tokens_used = ev
-            .get("tokens_used")
-            .and_then(|x| x.as_u64())
-            .unwrap_or(0);
+        let tokens_used = ev.get("tokens_used").and_then(|x| x.as_u64()).unwrap_or(0);
         let idx = match tokens_used {
             0..=99 => 0,
             100..=999 => 1,
@@ -3592,10 +4277,7 @@ pub fn print_token_bucket(log_path: &Path, run_id: Option<&str>) -> Result<()> {
         };
         let ok = ev.get("success").and_then(|x| x.as_bool()).unwrap_or(false);
         let tokens = tokens_used;
-        let cost = ev
-            .get("cost_usd")
-            .and_then(|x| x.as_f64())
-            .unwrap_or(0.0);
+        let cost = ev.get("cost_usd").and_then(|x| x.as_f64()).unwrap_or(0.0);
         buckets[idx].0 += 1;
         if ok {
             buckets[idx].1 += 1;
@@ -3702,10 +4384,7 @@ pub fn print_cost_bucket(log_path: &Path, run_id: Option<&str>) -> Result<()> {
         if ev.get("event_type").and_then(|x| x.as_str()) != Some("DelegationEnd") {
             continue;
         }
-        let cost_usd = ev
-            .get("cost_usd")
-            .and_then(|x| x.as_f64())
-            .unwrap_or(0.0);
+        let cost_usd = ev.get("cost_usd").and_then(|x| x.as_f64()).unwrap_or(0.0);
         let idx = if cost_usd < 0.001 {
             0
         } else if cost_usd < 0.01 {
@@ -3718,10 +4397,7 @@ pub fn print_cost_bucket(log_path: &Path, run_id: Option<&str>) -> Result<()> {
             4
         };
         let ok = ev.get("success").and_then(|x| x.as_bool()).unwrap_or(false);
-        let tokens = ev
-            .get("tokens_used")
-            .and_then(|x| x.as_u64())
-            .unwrap_or(0);
+        let tokens = ev.get("tokens_used").and_then(|x| x.as_u64()).unwrap_or(0);
         buckets[idx].0 += 1;
         if ok {
             buckets[idx].1 += 1;
@@ -3830,14 +4506,8 @@ pub fn print_weekday(log_path: &Path, run_id: Option<&str>) -> Result<()> {
         };
         let idx = dt.weekday().num_days_from_monday() as usize;
         let ok = ev.get("success").and_then(|x| x.as_bool()).unwrap_or(false);
-        let tokens = ev
-            .get("tokens_used")
-            .and_then(|x| x.as_u64())
-            .unwrap_or(0);
-        let cost = ev
-            .get("cost_usd")
-            .and_then(|x| x.as_f64())
-            .unwrap_or(0.0);
+        let tokens = ev.get("tokens_used").and_then(|x| x.as_u64()).unwrap_or(0);
+        let cost = ev.get("cost_usd").and_then(|x| x.as_f64()).unwrap_or(0.0);
         slots[idx].0 += 1;
         if ok {
             slots[idx].1 += 1;
@@ -3949,18 +4619,9 @@ pub fn print_weekly(log_path: &Path, run_id: Option<&str>) -> Result<()> {
         };
         let iw = dt.iso_week();
         let key = format!("{}-W{:02}", iw.year(), iw.week());
-        let success = ev
-            .get("success")
-            .and_then(|x| x.as_bool())
-            .unwrap_or(false);
-        let tokens = ev
-            .get("tokens_used")
-            .and_then(|x| x.as_u64())
-            .unwrap_or(0);
-        let cost = ev
-            .get("cost_usd")
-            .and_then(|x| x.as_f64())
-            .unwrap_or(0.0);
+        let success = ev.get("success").and_then(|x| x.as_bool()).unwrap_or(false);
+        let tokens = ev.get("tokens_used").and_then(|x| x.as_u64()).unwrap_or(0);
+        let cost = ev.get("cost_usd").and_then(|x| x.as_f64()).unwrap_or(0.0);
         let entry = map.entry(key).or_insert((0usize, 0usize, 0u64, 0.0f64));
         entry.0 += 1;
         if success {
@@ -4040,7 +4701,13 @@ pub fn print_weekly(log_path: &Path, run_id: Option<&str>) -> Result<()> {
 ///
 /// Output columns: depth | count | ok% | tokens | cost
 pub fn print_depth_bucket(log_path: &Path, run_id: Option<&str>) -> Result<()> {
-    const LABELS: [&str; 5] = ["root (0)", "sub (1)", "deep (2)", "deeper (3)", "very deep (4+)"];
+    const LABELS: [&str; 5] = [
+        "root (0)",
+        "sub (1)",
+        "deep (2)",
+        "deeper (3)",
+        "very deep (4+)",
+    ];
 
     let all_events = read_all_events(log_path)?;
     if all_events.is_empty() {
@@ -4070,10 +4737,7 @@ pub fn print_depth_bucket(log_path: &Path, run_id: Option<&str>) -> Result<()> {
         if ev.get("event_type").and_then(|x| x.as_str()) != Some("DelegationEnd") {
             continue;
         }
-        let depth = ev
-            .get("depth")
-            .and_then(|x| x.as_u64())
-            .unwrap_or(0) as usize;
+        let depth = ev.get("depth").and_then(|x| x.as_u64()).unwrap_or(0) as usize;
         let idx = match depth {
             0 => 0,
             1 => 1,
@@ -4082,14 +4746,8 @@ pub fn print_depth_bucket(log_path: &Path, run_id: Option<&str>) -> Result<()> {
             _ => 4,
         };
         let ok = ev.get("success").and_then(|x| x.as_bool()).unwrap_or(false);
-        let tokens = ev
-            .get("tokens_used")
-            .and_then(|x| x.as_u64())
-            .unwrap_or(0);
-        let cost = ev
-            .get("cost_usd")
-            .and_then(|x| x.as_f64())
-            .unwrap_or(0.0);
+        let tokens = ev.get("tokens_used").and_then(|x| x.as_u64()).unwrap_or(0);
+        let cost = ev.get("cost_usd").and_then(|x| x.as_f64()).unwrap_or(0.0);
         buckets[idx].0 += 1;
         if ok {
             buckets[idx].1 += 1;
@@ -4214,14 +4872,8 @@ pub fn print_model_tier(log_path: &Path, run_id: Option<&str>) -> Result<()> {
             3
         };
         let ok = ev.get("success").and_then(|x| x.as_bool()).unwrap_or(false);
-        let tokens = ev
-            .get("tokens_used")
-            .and_then(|x| x.as_u64())
-            .unwrap_or(0);
-        let cost = ev
-            .get("cost_usd")
-            .and_then(|x| x.as_f64())
-            .unwrap_or(0.0);
+        let tokens = ev.get("tokens_used").and_then(|x| x.as_u64()).unwrap_or(0);
+        let cost = ev.get("cost_usd").and_then(|x| x.as_f64()).unwrap_or(0.0);
         tiers[idx].0 += 1;
         if ok {
             tiers[idx].1 += 1;
@@ -4346,14 +4998,8 @@ pub fn print_provider_tier(log_path: &Path, run_id: Option<&str>) -> Result<()>
             3
         };
         let ok = ev.get("success").and_then(|x| x.as_bool()).unwrap_or(false);
-        let tokens = ev
-            .get("tokens_used")
-            .and_then(|x| x.as_u64())
-            .unwrap_or(0);
-        let cost = ev
-            .get("cost_usd")
-            .and_then(|x| x.as_f64())
-            .unwrap_or(0.0);
+        let tokens = ev.get("tokens_used").and_then(|x| x.as_u64()).unwrap_or(0);
+        let cost = ev.get("cost_usd").and_then(|x| x.as_f64()).unwrap_or(0.0);
         tiers[idx].0 += 1;
         if ok {
             tiers[idx].1 += 1;
@@ -4461,7 +5107,12 @@ pub fn print_time_of_day(log_path: &Path, run_id: Option<&str>) -> Result<()> {
         let cost = ev.get("cost_usd").and_then(|v| v.as_f64()).unwrap_or(0.0);
         let success = ev.get("success").and_then(|v| v.as_bool()).unwrap_or(false);
         let (c, s, t, co) = buckets[idx];
-        buckets[idx] = (c + 1, s + if success { 1 } else { 0 }, t + tokens, co + cost);
+        buckets[idx] = (
+            c + 1,
+            s + if success { 1 } else { 0 },
+            t + tokens,
+            co + cost,
+        );
     }
 
     if buckets.iter().all(|(c, ..)| *c == 0) {
@@ -4469,7 +5120,10 @@ pub fn print_time_of_day(log_path: &Path, run_id: Option<&str>) -> Result<()> {
     }
 
     let sep = "\u{2500}".repeat(61);
-    println!("{:<18}  {:>7}  {:>8}  {:>10}  {:>10}", "period", "count", "ok%", "tokens", "cost ($)");
+    println!(
+        "{:<18}  {:>7}  {:>8}  {:>10}  {:>10}",
+        "period", "count", "ok%", "tokens", "cost ($)"
+    );
     println!("{sep}");
 
     let mut total_count = 0usize;
@@ -4551,7 +5205,10 @@ pub fn print_day_of_month(log_path: &Path, run_id: Option<&str>) -> Result<()> {
     }
 
     let sep = "\u{2500}".repeat(47);
-    println!("{:<4}  {:>7}  {:>8}  {:>10}  {:>10}", "day", "count", "ok%", "tokens", "cost ($)");
+    println!(
+        "{:<4}  {:>7}  {:>8}  {:>10}  {:>10}",
+        "day", "count", "ok%", "tokens", "cost ($)"
+    );
     println!("{sep}");
 
     let mut total_count = 0usize;
@@ -4622,7 +5279,12 @@ pub fn print_token_efficiency(log_path: &Path, run_id: Option<&str>) -> Result<(
         };
         let success = ev.get("success").and_then(|v| v.as_bool()).unwrap_or(false);
         let (c, s, t, co) = buckets[idx];
-        buckets[idx] = (c + 1, s + if success { 1 } else { 0 }, t + tokens, co + cost);
+        buckets[idx] = (
+            c + 1,
+            s + if success { 1 } else { 0 },
+            t + tokens,
+            co + cost,
+        );
     }
 
     if buckets.iter().all(|(c, ..)| *c == 0) {
@@ -4630,7 +5292,10 @@ pub fn print_token_efficiency(log_path: &Path, run_id: Option<&str>) -> Result<(
     }
 
     let sep = "\u{2500}".repeat(53);
-    println!("{:<10}  {:>7}  {:>8}  {:>10}  {:>10}", "tier", "count", "ok%", "tokens", "cost ($)");
+    println!(
+        "{:<10}  {:>7}  {:>8}  {:>10}  {:>10}",
+        "tier", "count", "ok%", "tokens", "cost ($)"
+    );
     println!("{sep}");
 
     let mut total_count = 0usize;
@@ -4702,7 +5367,10 @@ pub fn print_success_breakdown(log_path: &Path, run_id: Option<&str>) -> Result<
 
     let total_count: usize = buckets.iter().map(|(c, ..)| c).sum();
     let sep = "\u{2500}".repeat(53);
-    println!("{:<10}  {:>7}  {:>8}  {:>10}  {:>10}", "outcome", "count", "share%", "tokens", "cost ($)");
+    println!(
+        "{:<10}  {:>7}  {:>8}  {:>10}  {:>10}",
+        "outcome", "count", "share%", "tokens", "cost ($)"
+    );
     println!("{sep}");
 
     let mut total_tokens = 0u64;
@@ -4796,7 +5464,9 @@ pub fn print_agent_cost_rank(log_path: &Path, run_id: Option<&str>) -> Result<()
     rows.sort_by(|a, b| {
         let avg_a = a.4 / a.1 as f64;
         let avg_b = b.4 / b.1 as f64;
-        avg_b.partial_cmp(&avg_a).unwrap_or(std::cmp::Ordering::Equal)
+        avg_b
+            .partial_cmp(&avg_a)
+            .unwrap_or(std::cmp::Ordering::Equal)
             .then_with(|| a.0.cmp(&b.0))
     });
 
@@ -4901,7 +5571,9 @@ pub fn print_model_cost_rank(log_path: &Path, run_id: Option<&str>) -> Result<()
     rows.sort_by(|a, b| {
         let avg_a = a.4 / a.1 as f64;
         let avg_b = b.4 / b.1 as f64;
-        avg_b.partial_cmp(&avg_a).unwrap_or(std::cmp::Ordering::Equal)
+        avg_b
+            .partial_cmp(&avg_a)
+            .unwrap_or(std::cmp::Ordering::Equal)
             .then_with(|| a.0.cmp(&b.0))
     });
 
@@ -5006,7 +5678,9 @@ pub fn print_provider_cost_rank(log_path: &Path, run_id: Option<&str>) -> Result
     rows.sort_by(|a, b| {
         let avg_a = a.4 / a.1 as f64;
         let avg_b = b.4 / b.1 as f64;
-        avg_b.partial_cmp(&avg_a).unwrap_or(std::cmp::Ordering::Equal)
+        avg_b
+            .partial_cmp(&avg_a)
+            .unwrap_or(std::cmp::Ordering::Equal)
             .then_with(|| a.0.cmp(&b.0))
     });
 
@@ -5082,18 +5756,9 @@ pub fn print_run_cost_rank(log_path: &Path, run_id: Option<&str>) -> Result<()>
                 continue;
             }
         }
-        let success = ev
-            .get("success")
-            .and_then(|x| x.as_bool())
-            .unwrap_or(false);
-        let tokens = ev
-            .get("tokens_used")
-            .and_then(|x| x.as_u64())
-            .unwrap_or(0);
-        let cost = ev
-            .get("cost_usd")
-            .and_then(|x| x.as_f64())
-            .unwrap_or(0.0);
+        let success = ev.get("success").and_then(|x| x.as_bool()).unwrap_or(false);
+        let tokens = ev.get("tokens_used").and_then(|x| x.as_u64()).unwrap_or(0);
+        let cost = ev.get("cost_usd").and_then(|x| x.as_f64()).unwrap_or(0.0);
         let entry = run_map.entry(rid.to_owned()).or_insert((0, 0, 0, 0.0));
         entry.0 += 1;
         if success {
@@ -5128,8 +5793,16 @@ pub fn print_run_cost_rank(log_path: &Path, run_id: Option<&str>) -> Result<()>
     );
     println!("{}", "─".repeat(90));
     for (i, (rid, count, ok, tokens, cost)) in rows.iter().enumerate() {
-        let avg_cost = if *count > 0 { cost / *count as f64 } else { 0.0 };
-        let avg_tok = if *count > 0 { tokens / *count as u64 } else { 0 };
+        let avg_cost = if *count > 0 {
+            cost / *count as f64
+        } else {
+            0.0
+        };
+        let avg_tok = if *count > 0 {
+            tokens / *count as u64
+        } else {
+            0
+        };
         let ok_pct = if *count > 0 {
             100.0 * *ok as f64 / *count as f64
         } else {
@@ -5190,18 +5863,9 @@ pub fn print_agent_success_rank(log_path: &Path, run_id: Option<&str>) -> Result
                 continue;
             }
         }
-        let success = ev
-            .get("success")
-            .and_then(|x| x.as_bool())
-            .unwrap_or(false);
-        let tokens = ev
-            .get("tokens_used")
-            .and_then(|x| x.as_u64())
-            .unwrap_or(0);
-        let cost = ev
-            .get("cost_usd")
-            .and_then(|x| x.as_f64())
-            .unwrap_or(0.0);
+        let success = ev.get("success").and_then(|x| x.as_bool()).unwrap_or(false);
+        let tokens = ev.get("tokens_used").and_then(|x| x.as_u64()).unwrap_or(0);
+        let cost = ev.get("cost_usd").and_then(|x| x.as_f64()).unwrap_or(0.0);
         let entry = agent_map.entry(agent.to_owned()).or_insert((0, 0, 0, 0.0));
         entry.0 += 1;
         if success {
@@ -5222,8 +5886,16 @@ pub fn print_agent_success_rank(log_path: &Path, run_id: Option<&str>) -> Result
         .collect();
     // Sort: ok_pct desc, ties by count desc, then name asc
     rows.sort_by(|a, b| {
-        let ok_a = if a.1 > 0 { a.2 as f64 / a.1 as f64 } else { 0.0 };
-        let ok_b = if b.1 > 0 { b.2 as f64 / b.1 as f64 } else { 0.0 };
+        let ok_a = if a.1 > 0 {
+            a.2 as f64 / a.1 as f64
+        } else {
+            0.0
+        };
+        let ok_b = if b.1 > 0 {
+            b.2 as f64 / b.1 as f64
+        } else {
+            0.0
+        };
         ok_b.partial_cmp(&ok_a)
             .unwrap_or(std::cmp::Ordering::Equal)
             .then(b.1.cmp(&a.1))
@@ -5240,8 +5912,16 @@ pub fn print_agent_success_rank(log_path: &Path, run_id: Option<&str>) -> Result
     println!("{}", "─".repeat(84));
     for (i, (agent, count, ok, tokens, cost)) in rows.iter().enumerate() {
         let failures = count - ok;
-        let avg_cost = if *count > 0 { cost / *count as f64 } else { 0.0 };
-        let avg_tok = if *count > 0 { tokens / *count as u64 } else { 0 };
+        let avg_cost = if *count > 0 {
+            cost / *count as f64
+        } else {
+            0.0
+        };
+        let avg_tok = if *count > 0 {
+            tokens / *count as u64
+        } else {
+            0
+        };
         let ok_pct = if *count > 0 {
             100.0 * *ok as f64 / *count as f64
         } else {
@@ -5303,18 +5983,9 @@ pub fn print_model_success_rank(log_path: &Path, run_id: Option<&str>) -> Result
                 continue;
             }
         }
-        let success = ev
-            .get("success")
-            .and_then(|x| x.as_bool())
-            .unwrap_or(false);
-        let tokens = ev
-            .get("tokens_used")
-            .and_then(|x| x.as_u64())
-            .unwrap_or(0);
-        let cost = ev
-            .get("cost_usd")
-            .and_then(|x| x.as_f64())
-            .unwrap_or(0.0);
+        let success = ev.get("success").and_then(|x| x.as_bool()).unwrap_or(false);
+        let tokens = ev.get("tokens_used").and_then(|x| x.as_u64()).unwrap_or(0);
+        let cost = ev.get("cost_usd").and_then(|x| x.as_f64()).unwrap_or(0.0);
         let entry = model_map.entry(model).or_insert((0, 0, 0, 0.0));
         entry.0 += 1;
         if success {
@@ -5335,8 +6006,16 @@ pub fn print_model_success_rank(log_path: &Path, run_id: Option<&str>) -> Result
         .collect();
     // Sort: ok_pct desc, ties by count desc, then model name asc
     rows.sort_by(|a, b| {
-        let ok_a = if a.1 > 0 { a.2 as f64 / a.1 as f64 } else { 0.0 };
-        let ok_b = if b.1 > 0 { b.2 as f64 / b.1 as f64 } else { 0.0 };
+        let ok_a = if a.1 > 0 {
+            a.2 as f64 / a.1 as f64
+        } else {
+            0.0
+        };
+        let ok_b = if b.1 > 0 {
+            b.2 as f64 / b.1 as f64
+        } else {
+            0.0
+        };
         ok_b.partial_cmp(&ok_a)
             .unwrap_or(std::cmp::Ordering::Equal)
             .then(b.1.cmp(&a.1))
@@ -5353,8 +6032,16 @@ pub fn print_model_success_rank(log_path: &Path, run_id: Option<&str>) -> Result
     println!("{}", "─".repeat(92));
     for (i, (model, count, ok, tokens, cost)) in rows.iter().enumerate() {
         let failures = count - ok;
-        let avg_cost = if *count > 0 { cost / *count as f64 } else { 0.0 };
-        let avg_tok = if *count > 0 { tokens / *count as u64 } else { 0 };
+        let avg_cost = if *count > 0 {
+            cost / *count as f64
+        } else {
+            0.0
+        };
+        let avg_tok = if *count > 0 {
+            tokens / *count as u64
+        } else {
+            0
+        };
         let ok_pct = if *count > 0 {
             100.0 * *ok as f64 / *count as f64
         } else {
@@ -5416,18 +6103,9 @@ pub fn print_provider_success_rank(log_path: &Path, run_id: Option<&str>) -> Res
                 continue;
             }
         }
-        let success = ev
-            .get("success")
-            .and_then(|x| x.as_bool())
-            .unwrap_or(false);
-        let tokens = ev
-            .get("tokens_used")
-            .and_then(|x| x.as_u64())
-            .unwrap_or(0);
-        let cost = ev
-            .get("cost_usd")
-            .and_then(|x| x.as_f64())
-            .unwrap_or(0.0);
+        let success = ev.get("success").and_then(|x| x.as_bool()).unwrap_or(false);
+        let tokens = ev.get("tokens_used").and_then(|x| x.as_u64()).unwrap_or(0);
+        let cost = ev.get("cost_usd").and_then(|x| x.as_f64()).unwrap_or(0.0);
         let entry = provider_map.entry(provider).or_insert((0, 0, 0, 0.0));
         entry.0 += 1;
         if success {
@@ -5448,8 +6126,16 @@ pub fn print_provider_success_rank(log_path: &Path, run_id: Option<&str>) -> Res
         .collect();
     // Sort: ok_pct desc, ties by count desc, then provider name asc
     rows.sort_by(|a, b| {
-        let ok_a = if a.1 > 0 { a.2 as f64 / a.1 as f64 } else { 0.0 };
-        let ok_b = if b.1 > 0 { b.2 as f64 / b.1 as f64 } else { 0.0 };
+        let ok_a = if a.1 > 0 {
+            a.2 as f64 / a.1 as f64
+        } else {
+            0.0
+        };
+        let ok_b = if b.1 > 0 {
+            b.2 as f64 / b.1 as f64
+        } else {
+            0.0
+        };
         ok_b.partial_cmp(&ok_a)
             .unwrap_or(std::cmp::Ordering::Equal)
             .then(b.1.cmp(&a.1))
@@ -5466,8 +6152,16 @@ pub fn print_provider_success_rank(log_path: &Path, run_id: Option<&str>) -> Res
     println!("{}", "─".repeat(76));
     for (i, (provider, count, ok, tokens, cost)) in rows.iter().enumerate() {
         let failures = count - ok;
-        let avg_cost = if *count > 0 { cost / *count as f64 } else { 0.0 };
-        let avg_tok = if *count > 0 { tokens / *count as u64 } else { 0 };
+        let avg_cost = if *count > 0 {
+            cost / *count as f64
+        } else {
+            0.0
+        };
+        let avg_tok = if *count > 0 {
+            tokens / *count as u64
+        } else {
+            0
+        };
         let ok_pct = if *count > 0 {
             100.0 * *ok as f64 / *count as f64
         } else {
@@ -5512,8 +6206,8 @@ pub fn print_agent_token_rank(log_path: &Path, run_id: Option<&str>) -> Result<(
         return Ok(());
     }
 
-    // agent_name → (count, success_count, tokens, total_cost)
-    let mut agent_map: HashMap<String, (usize, usize, u64, f64)> = HashMap::new();
+    // agent_name → (count, success_count, tokens, total_cost, samples)
+    let mut agent_map: HashMap<String, (usize, usize, u64, f64, Vec<u64>)> = HashMap::new();
 
     for ev in &all_events {
         if ev.get("event_type").and_then(|x| x.as_str()) != Some("DelegationEnd") {
@@ -5527,25 +6221,19 @@ pub fn print_agent_token_rank(log_path: &Path, run_id: Option<&str>) -> Result<(
                 continue;
             }
         }
-        let success = ev
-            .get("success")
-            .and_then(|x| x.as_bool())
-            .unwrap_or(false);
-        let tokens = ev
-            .get("tokens_used")
-            .and_then(|x| x.as_u64())
-            .unwrap_or(0);
-        let cost = ev
-            .get("cost_usd")
-            .and_then(|x| x.as_f64())
-            .unwrap_or(0.0);
-        let entry = agent_map.entry(agent.to_owned()).or_insert((0, 0, 0, 0.0));
+        let success = ev.get("success").and_then(|x| x.as_bool()).unwrap_or(false);
+        let tokens = ev.get("tokens_used").and_then(|x| x.as_u64()).unwrap_or(0);
+        let cost = ev.get("cost_usd").and_then(|x| x.as_f64()).unwrap_or(0.0);
+        let entry = agent_map
+            .entry(agent.to_owned())
+            .or_insert((0, 0, 0, 0.0, Vec::new()));
         entry.0 += 1;
         if success {
             entry.1 += 1;
         }
         entry.2 += tokens;
         entry.3 += cost;
+        entry.4.push(tokens);
     }
 
     if agent_map.is_empty() {
@@ -5553,9 +6241,12 @@ pub fn print_agent_token_rank(log_path: &Path, run_id: Option<&str>) -> Result<(
         return Ok(());
     }
 
-    let mut rows: Vec<(String, usize, usize, u64, f64)> = agent_map
+    let mut rows: Vec<(String, usize, usize, u64, f64, Vec<u64>)> = agent_map
         .into_iter()
-        .map(|(agent, (c, ok, tok, cost))| (agent, c, ok, tok, cost))
+        .map(|(agent, (c, ok, tok, cost, mut samples))| {
+            samples.sort_unstable();
+            (agent, c, ok, tok, cost, samples)
+        })
         .collect();
     // Sort: avg_tok desc, ties by agent name asc
     rows.sort_by(|a, b| {
@@ -5564,34 +6255,45 @@ pub fn print_agent_token_rank(log_path: &Path, run_id: Option<&str>) -> Result<(
         avg_b.cmp(&avg_a).then(a.0.cmp(&b.0))
     });
 
-    let total_delegations: usize = rows.iter().map(|(_, c, _, _, _)| c).sum();
-    let total_tokens: u64 = rows.iter().map(|(_, _, _, tok, _)| tok).sum();
+    let total_delegations: usize = rows.iter().map(|(_, c, _, _, _, _)| c).sum();
+    let total_tokens: u64 = rows.iter().map(|(_, _, _, tok, _, _)| tok).sum();
 
     println!(
-        " {:<3} {:<26} {:>11} {:>9} {:>10} {:>6} {:>11}",
-        "#", "agent", "delegations", "avg_tok", "avg_cost", "ok%", "total_tok"
+        " {:<3} {:<26} {:>11} {:>9} {:>8} {:>8} {:>8} {:>10} {:>6} {:>11}",
+        "#", "agent", "delegations", "avg_tok", "p50", "p90", "p99", "avg_cost", "ok%", "total_tok"
     );
-    println!("{}", "─".repeat(84));
-    for (i, (agent, count, ok, tokens, cost)) in rows.iter().enumerate() {
-        let avg_tok = if *count > 0 { tokens / *count as u64 } else { 0 };
-        let avg_cost = if *count > 0 { cost / *count as f64 } else { 0.0 };
+    println!("{}", "─".repeat(111));
+    for (i, (agent, count, ok, tokens, cost, samples)) in rows.iter().enumerate() {
+        let avg_tok = if *count > 0 {
+            tokens / *count as u64
+        } else {
+            0
+        };
+        let avg_cost = if *count > 0 {
+            cost / *count as f64
+        } else {
+            0.0
+        };
         let ok_pct = if *count > 0 {
             100.0 * *ok as f64 / *count as f64
         } else {
             0.0
         };
         println!(
-            " {:<3} {:<26} {:>11} {:>9} {:>10.4} {:>5.1}% {:>11}",
+            " {:<3} {:<26} {:>11} {:>9} {:>8} {:>8} {:>8} {:>10.4} {:>5.1}% {:>11}",
             i + 1,
             agent,
             count,
             avg_tok,
+            percentile(samples, 50),
+            percentile(samples, 90),
+            percentile(samples, 99),
             avg_cost,
             ok_pct,
             tokens,
         );
     }
-    println!("{}", "─".repeat(84));
+    println!("{}", "─".repeat(111));
     println!(
         "{} agent(s) \u{2022} {} total delegations \u{2022} {} total tokens",
         rows.len(),
@@ -5610,8 +6312,8 @@ pub fn print_model_token_rank(log_path: &Path, run_id: Option<&str>) -> Result<(
         return Ok(());
     }
 
-    // model → (count, success_count, tokens, total_cost)
-    let mut model_map: HashMap<String, (usize, usize, u64, f64)> = HashMap::new();
+    // model → (count, success_count, tokens, total_cost, samples)
+    let mut model_map: HashMap<String, (usize, usize, u64, f64, Vec<u64>)> = HashMap::new();
 
     for ev in &all_events {
         if ev.get("event_type").and_then(|x| x.as_str()) != Some("DelegationEnd") {
@@ -5627,25 +6329,17 @@ pub fn print_model_token_rank(log_path: &Path, run_id: Option<&str>) -> Result<(
                 continue;
             }
         }
-        let success = ev
-            .get("success")
-            .and_then(|x| x.as_bool())
-            .unwrap_or(false);
-        let tokens = ev
-            .get("tokens_used")
-            .and_then(|x| x.as_u64())
-            .unwrap_or(0);
-        let cost = ev
-            .get("cost_usd")
-            .and_then(|x| x.as_f64())
-            .unwrap_or(0.0);
-        let entry = model_map.entry(model).or_insert((0, 0, 0, 0.0));
+        let success = ev.get("success").and_then(|x| x.as_bool()).unwrap_or(false);
+        let tokens = ev.get("tokens_used").and_then(|x| x.as_u64()).unwrap_or(0);
+        let cost = ev.get("cost_usd").and_then(|x| x.as_f64()).unwrap_or(0.0);
+        let entry = model_map.entry(model).or_insert((0, 0, 0, 0.0, Vec::new()));
         entry.0 += 1;
         if success {
             entry.1 += 1;
         }
         entry.2 += tokens;
         entry.3 += cost;
+        entry.4.push(tokens);
     }
 
     if model_map.is_empty() {
@@ -5653,9 +6347,12 @@ pub fn print_model_token_rank(log_path: &Path, run_id: Option<&str>) -> Result<(
         return Ok(());
     }
 
-    let mut rows: Vec<(String, usize, usize, u64, f64)> = model_map
+    let mut rows: Vec<(String, usize, usize, u64, f64, Vec<u64>)> = model_map
         .into_iter()
-        .map(|(model, (c, ok, tok, cost))| (model, c, ok, tok, cost))
+        .map(|(model, (c, ok, tok, cost, mut samples))| {
+            samples.sort_unstable();
+            (model, c, ok, tok, cost, samples)
+        })
         .collect();
     // Sort: avg_tok desc, ties by model name asc
     rows.sort_by(|a, b| {
@@ -5664,34 +6361,45 @@ pub fn print_model_token_rank(log_path: &Path, run_id: Option<&str>) -> Result<(
         avg_b.cmp(&avg_a).then(a.0.cmp(&b.0))
     });
 
-    let total_delegations: usize = rows.iter().map(|(_, c, _, _, _)| c).sum();
-    let total_tokens: u64 = rows.iter().map(|(_, _, _, tok, _)| tok).sum();
+    let total_delegations: usize = rows.iter().map(|(_, c, _, _, _, _)| c).sum();
+    let total_tokens: u64 = rows.iter().map(|(_, _, _, tok, _, _)| tok).sum();
 
     println!(
-        " {:<3} {:<34} {:>11} {:>9} {:>10} {:>6} {:>11}",
-        "#", "model", "delegations", "avg_tok", "avg_cost", "ok%", "total_tok"
+        " {:<3} {:<34} {:>11} {:>9} {:>8} {:>8} {:>8} {:>10} {:>6} {:>11}",
+        "#", "model", "delegations", "avg_tok", "p50", "p90", "p99", "avg_cost", "ok%", "total_tok"
     );
-    println!("{}", "─".repeat(92));
-    for (i, (model, count, ok, tokens, cost)) in rows.iter().enumerate() {
-        let avg_tok = if *count > 0 { tokens / *count as u64 } else { 0 };
-        let avg_cost = if *count > 0 { cost / *count as f64 } else { 0.0 };
+    println!("{}", "─".repeat(119));
+    for (i, (model, count, ok, tokens, cost, samples)) in rows.iter().enumerate() {
+        let avg_tok = if *count > 0 {
+            tokens / *count as u64
+        } else {
+            0
+        };
+        let avg_cost = if *count > 0 {
+            cost / *count as f64
+        } else {
+            0.0
+        };
         let ok_pct = if *count > 0 {
             100.0 * *ok as f64 / *count as f64
         } else {
             0.0
         };
         println!(
-            " {:<3} {:<34} {:>11} {:>9} {:>10.4} {:>5.1}% {:>11}",
+            " {:<3} {:<34} {:>11} {:>9} {:>8} {:>8} {:>8} {:>10.4} {:>5.1}% {:>11}",
             i + 1,
             model,
             count,
             avg_tok,
+            percentile(samples, 50),
+            percentile(samples, 90),
+            percentile(samples, 99),
             avg_cost,
             ok_pct,
             tokens,
         );
     }
-    println!("{}", "─".repeat(92));
+    println!("{}", "─".repeat(119));
     println!(
         "{} model(s) \u{2022} {} total delegations \u{2022} {} total tokens",
         rows.len(),
@@ -5710,8 +6418,8 @@ pub fn print_provider_token_rank(log_path: &Path, run_id: Option<&str>) -> Resul
         return Ok(());
     }
 
-    // provider → (count, success_count, tokens, total_cost)
-    let mut provider_map: HashMap<String, (usize, usize, u64, f64)> = HashMap::new();
+    // provider → (count, success_count, tokens, total_cost, samples)
+    let mut provider_map: HashMap<String, (usize, usize, u64, f64, Vec<u64>)> = HashMap::new();
 
     for ev in &all_events {
         if ev.get("event_type").and_then(|x| x.as_str()) != Some("DelegationEnd") {
@@ -5727,25 +6435,19 @@ pub fn print_provider_token_rank(log_path: &Path, run_id: Option<&str>) -> Resul
                 continue;
             }
         }
-        let success = ev
-            .get("success")
-            .and_then(|x| x.as_bool())
-            .unwrap_or(false);
-        let tokens = ev
-            .get("tokens_used")
-            .and_then(|x| x.as_u64())
-            .unwrap_or(0);
-        let cost = ev
-            .get("cost_usd")
-            .and_then(|x| x.as_f64())
-            .unwrap_or(0.0);
-        let entry = provider_map.entry(provider).or_insert((0, 0, 0, 0.0));
+        let success = ev.get("success").and_then(|x| x.as_bool()).unwrap_or(false);
+        let tokens = ev.get("tokens_used").and_then(|x| x.as_u64()).unwrap_or(0);
+        let cost = ev.get("cost_usd").and_then(|x| x.as_f64()).unwrap_or(0.0);
+        let entry = provider_map
+            .entry(provider)
+            .or_insert((0, 0, 0, 0.0, Vec::new()));
         entry.0 += 1;
         if success {
             entry.1 += 1;
         }
         entry.2 += tokens;
         entry.3 += cost;
+        entry.4.push(tokens);
     }
 
     if provider_map.is_empty() {
@@ -5753,9 +6455,12 @@ pub fn print_provider_token_rank(log_path: &Path, run_id: Option<&str>) -> Resul
         return Ok(());
     }
 
-    let mut rows: Vec<(String, usize, usize, u64, f64)> = provider_map
+    let mut rows: Vec<(String, usize, usize, u64, f64, Vec<u64>)> = provider_map
         .into_iter()
-        .map(|(prov, (c, ok, tok, cost))| (prov, c, ok, tok, cost))
+        .map(|(prov, (c, ok, tok, cost, mut samples))| {
+            samples.sort_unstable();
+            (prov, c, ok, tok, cost, samples)
+        })
         .collect();
     // Sort: avg_tok desc, ties by provider name asc
     rows.sort_by(|a, b| {
@@ -5764,34 +6469,54 @@ pub fn print_provider_token_rank(log_path: &Path, run_id: Option<&str>) -> Resul
         avg_b.cmp(&avg_a).then(a.0.cmp(&b.0))
     });
 
-    let total_delegations: usize = rows.iter().map(|(_, c, _, _, _)| c).sum();
-    let total_tokens: u64 = rows.iter().map(|(_, _, _, tok, _)| tok).sum();
+    let total_delegations: usize = rows.iter().map(|(_, c, _, _, _, _)| c).sum();
+    let total_tokens: u64 = rows.iter().map(|(_, _, _, tok, _, _)| tok).sum();
 
     println!(
-        " {:<3} {:<18} {:>11} {:>9} {:>10} {:>6} {:>11}",
-        "#", "provider", "delegations", "avg_tok", "avg_cost", "ok%", "total_tok"
+        " {:<3} {:<18} {:>11} {:>9} {:>8} {:>8} {:>8} {:>10} {:>6} {:>11}",
+        "#",
+        "provider",
+        "delegations",
+        "avg_tok",
+        "p50",
+        "p90",
+        "p99",
+        "avg_cost",
+        "ok%",
+        "total_tok"
     );
-    println!("{}", "─".repeat(76));
-    for (i, (provider, count, ok, tokens, cost)) in rows.iter().enumerate() {
-        let avg_tok = if *count > 0 { tokens / *count as u64 } else { 0 };
-        let avg_cost = if *count > 0 { cost / *count as f64 } else { 0.0 };
+    println!("{}", "─".repeat(103));
+    for (i, (provider, count, ok, tokens, cost, samples)) in rows.iter().enumerate() {
+        let avg_tok = if *count > 0 {
+            tokens / *count as u64
+        } else {
+            0
+        };
+        let avg_cost = if *count > 0 {
+            cost / *count as f64
+        } else {
+            0.0
+        };
         let ok_pct = if *count > 0 {
             100.0 * *ok as f64 / *count as f64
         } else {
             0.0
         };
         println!(
-            " {:<3} {:<18} {:>11} {:>9} {:>10.4} {:>5.1}% {:>11}",
+            " {:<3} {:<18} {:>11} {:>9} {:>8} {:>8} {:>8} {:>10.4} {:>5.1}% {:>11}",
             i + 1,
             provider,
             count,
             avg_tok,
+            percentile(samples, 50),
+            percentile(samples, 90),
+            percentile(samples, 99),
             avg_cost,
             ok_pct,
             tokens,
         );
     }
-    println!("{}", "─".repeat(76));
+    println!("{}", "─".repeat(103));
     println!(
         "{} provider(s) \u{2022} {} total delegations \u{2022} {} total tokens",
         rows.len(),
@@ -5810,8 +6535,8 @@ pub fn print_agent_duration_rank(log_path: &Path, run_id: Option<&str>) -> Resul
         return Ok(());
     }
 
-    // agent_name → (count, success_count, total_duration_ms, total_cost)
-    let mut agent_map: HashMap<String, (usize, usize, u64, f64)> = HashMap::new();
+    // agent_name → (count, success_count, total_duration_ms, total_cost, samples)
+    let mut agent_map: HashMap<String, (usize, usize, u64, f64, Vec<u64>)> = HashMap::new();
 
     for ev in &all_events {
         if ev.get("event_type").and_then(|x| x.as_str()) != Some("DelegationEnd") {
@@ -5828,21 +6553,18 @@ pub fn print_agent_duration_rank(log_path: &Path, run_id: Option<&str>) -> Resul
                 continue;
             }
         }
-        let success = ev
-            .get("success")
-            .and_then(|x| x.as_bool())
-            .unwrap_or(false);
-        let cost = ev
-            .get("cost_usd")
-            .and_then(|x| x.as_f64())
-            .unwrap_or(0.0);
-        let entry = agent_map.entry(agent.to_owned()).or_insert((0, 0, 0, 0.0));
+        let success = ev.get("success").and_then(|x| x.as_bool()).unwrap_or(false);
+        let cost = ev.get("cost_usd").and_then(|x| x.as_f64()).unwrap_or(0.0);
+        let entry = agent_map
+            .entry(agent.to_owned())
+            .or_insert((0, 0, 0, 0.0, Vec::new()));
         entry.0 += 1;
         if success {
             entry.1 += 1;
         }
         entry.2 += duration_ms;
         entry.3 += cost;
+        entry.4.push(duration_ms);
     }
 
     if agent_map.is_empty() {
@@ -5850,9 +6572,12 @@ pub fn print_agent_duration_rank(log_path: &Path, run_id: Option<&str>) -> Resul
         return Ok(());
     }
 
-    let mut rows: Vec<(String, usize, usize, u64, f64)> = agent_map
+    let mut rows: Vec<(String, usize, usize, u64, f64, Vec<u64>)> = agent_map
         .into_iter()
-        .map(|(agent, (c, ok, dur, cost))| (agent, c, ok, dur, cost))
+        .map(|(agent, (c, ok, dur, cost, mut samples))| {
+            samples.sort_unstable();
+            (agent, c, ok, dur, cost, samples)
+        })
         .collect();
     // Sort: avg_dur desc, ties by agent name asc
     rows.sort_by(|a, b| {
@@ -5861,34 +6586,45 @@ pub fn print_agent_duration_rank(log_path: &Path, run_id: Option<&str>) -> Resul
         avg_b.cmp(&avg_a).then(a.0.cmp(&b.0))
     });
 
-    let total_delegations: usize = rows.iter().map(|(_, c, _, _, _)| c).sum();
-    let total_duration_ms: u64 = rows.iter().map(|(_, _, _, dur, _)| dur).sum();
+    let total_delegations: usize = rows.iter().map(|(_, c, _, _, _, _)| c).sum();
+    let total_duration_ms: u64 = rows.iter().map(|(_, _, _, dur, _, _)| dur).sum();
 
     println!(
-        " {:<3} {:<26} {:>11} {:>9} {:>10} {:>6} {:>11}",
-        "#", "agent", "delegations", "avg_dur", "avg_cost", "ok%", "total_dur"
+        " {:<3} {:<26} {:>11} {:>9} {:>8} {:>8} {:>8} {:>10} {:>6} {:>11}",
+        "#", "agent", "delegations", "avg_dur", "p50", "p90", "p99", "avg_cost", "ok%", "total_dur"
     );
-    println!("{}", "─".repeat(84));
-    for (i, (agent, count, ok, duration_ms, cost)) in rows.iter().enumerate() {
-        let avg_dur = if *count > 0 { duration_ms / *count as u64 } else { 0 };
-        let avg_cost = if *count > 0 { cost / *count as f64 } else { 0.0 };
+    println!("{}", "─".repeat(111));
+    for (i, (agent, count, ok, duration_ms, cost, samples)) in rows.iter().enumerate() {
+        let avg_dur = if *count > 0 {
+            duration_ms / *count as u64
+        } else {
+            0
+        };
+        let avg_cost = if *count > 0 {
+            cost / *count as f64
+        } else {
+            0.0
+        };
         let ok_pct = if *count > 0 {
             100.0 * *ok as f64 / *count as f64
         } else {
             0.0
         };
         println!(
-            " {:<3} {:<26} {:>11} {:>9} {:>10.4} {:>5.1}% {:>11}",
+            " {:<3} {:<26} {:>11} {:>9} {:>8} {:>8} {:>8} {:>10.4} {:>5.1}% {:>11}",
             i + 1,
             agent,
             count,
             avg_dur,
+            percentile(samples, 50),
+            percentile(samples, 90),
+            percentile(samples, 99),
             avg_cost,
             ok_pct,
             duration_ms,
         );
     }
-    println!("{}", "─".repeat(84));
+    println!("{}", "─".repeat(111));
     println!(
         "{} agent(s) \u{2022} {} total delegations \u{2022} {}ms total duration",
         rows.len(),
@@ -5907,8 +6643,8 @@ pub fn print_model_duration_rank(log_path: &Path, run_id: Option<&str>) -> Resul
         return Ok(());
     }
 
-    // model → (count, success_count, total_duration_ms, total_cost)
-    let mut model_map: HashMap<String, (usize, usize, u64, f64)> = HashMap::new();
+    // model → (count, success_count, total_duration_ms, total_cost, samples)
+    let mut model_map: HashMap<String, (usize, usize, u64, f64, Vec<u64>)> = HashMap::new();
 
     for ev in &all_events {
         if ev.get("event_type").and_then(|x| x.as_str()) != Some("DelegationEnd") {
@@ -5927,21 +6663,16 @@ pub fn print_model_duration_rank(log_path: &Path, run_id: Option<&str>) -> Resul
                 continue;
             }
         }
-        let success = ev
-            .get("success")
-            .and_then(|x| x.as_bool())
-            .unwrap_or(false);
-        let cost = ev
-            .get("cost_usd")
-            .and_then(|x| x.as_f64())
-            .unwrap_or(0.0);
-        let entry = model_map.entry(model).or_insert((0, 0, 0, 0.0));
+        let success = ev.get("success").and_then(|x| x.as_bool()).unwrap_or(false);
+        let cost = ev.get("cost_usd").and_then(|x| x.as_f64()).unwrap_or(0.0);
+        let entry = model_map.entry(model).or_insert((0, 0, 0, 0.0, Vec::new()));
         entry.0 += 1;
         if success {
             entry.1 += 1;
         }
         entry.2 += duration_ms;
         entry.3 += cost;
+        entry.4.push(duration_ms);
     }
 
     if model_map.is_empty() {
@@ -5949,9 +6680,12 @@ pub fn print_model_duration_rank(log_path: &Path, run_id: Option<&str>) -> Resul
         return Ok(());
     }
 
-    let mut rows: Vec<(String, usize, usize, u64, f64)> = model_map
+    let mut rows: Vec<(String, usize, usize, u64, f64, Vec<u64>)> = model_map
         .into_iter()
-        .map(|(model, (c, ok, dur, cost))| (model, c, ok, dur, cost))
+        .map(|(model, (c, ok, dur, cost, mut samples))| {
+            samples.sort_unstable();
+            (model, c, ok, dur, cost, samples)
+        })
         .collect();
     // Sort: avg_dur desc, ties by model name asc
     rows.sort_by(|a, b| {
@@ -5960,34 +6694,45 @@ pub fn print_model_duration_rank(log_path: &Path, run_id: Option<&str>) -> Resul
         avg_b.cmp(&avg_a).then(a.0.cmp(&b.0))
     });
 
-    let total_delegations: usize = rows.iter().map(|(_, c, _, _, _)| c).sum();
-    let total_duration_ms: u64 = rows.iter().map(|(_, _, _, dur, _)| dur).sum();
+    let total_delegations: usize = rows.iter().map(|(_, c, _, _, _, _)| c).sum();
+    let total_duration_ms: u64 = rows.iter().map(|(_, _, _, dur, _, _)| dur).sum();
 
     println!(
-        " {:<3} {:<34} {:>11} {:>9} {:>10} {:>6} {:>11}",
-        "#", "model", "delegations", "avg_dur", "avg_cost", "ok%", "total_dur"
+        " {:<3} {:<34} {:>11} {:>9} {:>8} {:>8} {:>8} {:>10} {:>6} {:>11}",
+        "#", "model", "delegations", "avg_dur", "p50", "p90", "p99", "avg_cost", "ok%", "total_dur"
     );
-    println!("{}", "─".repeat(92));
-    for (i, (model, count, ok, duration_ms, cost)) in rows.iter().enumerate() {
-        let avg_dur = if *count > 0 { duration_ms / *count as u64 } else { 0 };
-        let avg_cost = if *count > 0 { cost / *count as f64 } else { 0.0 };
+    println!("{}", "─".repeat(119));
+    for (i, (model, count, ok, duration_ms, cost, samples)) in rows.iter().enumerate() {
+        let avg_dur = if *count > 0 {
+            duration_ms / *count as u64
+        } else {
+            0
+        };
+        let avg_cost = if *count > 0 {
+            cost / *count as f64
+        } else {
+            0.0
+        };
         let ok_pct = if *count > 0 {
             100.0 * *ok as f64 / *count as f64
         } else {
             0.0
         };
         println!(
-            " {:<3} {:<34} {:>11} {:>9} {:>10.4} {:>5.1}% {:>11}",
+            " {:<3} {:<34} {:>11} {:>9} {:>8} {:>8} {:>8} {:>10.4} {:>5.1}% {:>11}",
             i + 1,
             model,
             count,
             avg_dur,
+            percentile(samples, 50),
+            percentile(samples, 90),
+            percentile(samples, 99),
             avg_cost,
             ok_pct,
             duration_ms,
         );
     }
-    println!("{}", "─".repeat(92));
+    println!("{}", "─".repeat(119));
     println!(
         "{} model(s) \u{2022} {} total delegations \u{2022} {}ms total duration",
         rows.len(),
@@ -6006,8 +6751,8 @@ pub fn print_provider_duration_rank(log_path: &Path, run_id: Option<&str>) -> Re
         return Ok(());
     }
 
-    // provider → (count, success_count, total_duration_ms, total_cost)
-    let mut provider_map: HashMap<String, (usize, usize, u64, f64)> = HashMap::new();
+    // provider → (count, success_count, total_duration_ms, total_cost, samples)
+    let mut provider_map: HashMap<String, (usize, usize, u64, f64, Vec<u64>)> = HashMap::new();
 
     for ev in &all_events {
         if ev.get("event_type").and_then(|x| x.as_str()) != Some("DelegationEnd") {
@@ -6026,21 +6771,18 @@ pub fn print_provider_duration_rank(log_path: &Path, run_id: Option<&str>) -> Re
                 continue;
             }
         }
-        let success = ev
-            .get("success")
-            .and_then(|x| x.as_bool())
-            .unwrap_or(false);
-        let cost = ev
-            .get("cost_usd")
-            .and_then(|x| x.as_f64())
-            .unwrap_or(0.0);
-        let entry = provider_map.entry(provider).or_insert((0, 0, 0, 0.0));
+        let success = ev.get("success").and_then(|x| x.as_bool()).unwrap_or(false);
+        let cost = ev.get("cost_usd").and_then(|x| x.as_f64()).unwrap_or(0.0);
+        let entry = provider_map
+            .entry(provider)
+            .or_insert((0, 0, 0, 0.0, Vec::new()));
         entry.0 += 1;
         if success {
             entry.1 += 1;
         }
         entry.2 += duration_ms;
         entry.3 += cost;
+        entry.4.push(duration_ms);
     }
 
     if provider_map.is_empty() {
@@ -6048,9 +6790,12 @@ pub fn print_provider_duration_rank(log_path: &Path, run_id: Option<&str>) -> Re
         return Ok(());
     }
 
-    let mut rows: Vec<(String, usize, usize, u64, f64)> = provider_map
+    let mut rows: Vec<(String, usize, usize, u64, f64, Vec<u64>)> = provider_map
         .into_iter()
-        .map(|(prov, (c, ok, dur, cost))| (prov, c, ok, dur, cost))
+        .map(|(prov, (c, ok, dur, cost, mut samples))| {
+            samples.sort_unstable();
+            (prov, c, ok, dur, cost, samples)
+        })
         .collect();
     // Sort: avg_dur desc, ties by provider name asc
     rows.sort_by(|a, b| {
@@ -6059,34 +6804,54 @@ pub fn print_provider_duration_rank(log_path: &Path, run_id: Option<&str>) -> Re
         avg_b.cmp(&avg_a).then(a.0.cmp(&b.0))
     });
 
-    let total_delegations: usize = rows.iter().map(|(_, c, _, _, _)| c).sum();
-    let total_duration_ms: u64 = rows.iter().map(|(_, _, _, dur, _)| dur).sum();
+    let total_delegations: usize = rows.iter().map(|(_, c, _, _, _, _)| c).sum();
+    let total_duration_ms: u64 = rows.iter().map(|(_, _, _, dur, _, _)| dur).sum();
 
     println!(
-        " {:<3} {:<18} {:>11} {:>9} {:>10} {:>6} {:>11}",
-        "#", "provider", "delegations", "avg_dur", "avg_cost", "ok%", "total_dur"
+        " {:<3} {:<18} {:>11} {:>9} {:>8} {:>8} {:>8} {:>10} {:>6} {:>11}",
+        "#",
+        "provider",
+        "delegations",
+        "avg_dur",
+        "p50",
+        "p90",
+        "p99",
+        "avg_cost",
+        "ok%",
+        "total_dur"
     );
-    println!("{}", "─".repeat(76));
-    for (i, (provider, count, ok, duration_ms, cost)) in rows.iter().enumerate() {
-        let avg_dur = if *count > 0 { duration_ms / *count as u64 } else { 0 };
-        let avg_cost = if *count > 0 { cost / *count as f64 } else { 0.0 };
+    println!("{}", "─".repeat(103));
+    for (i, (provider, count, ok, duration_ms, cost, samples)) in rows.iter().enumerate() {
+        let avg_dur = if *count > 0 {
+            duration_ms / *count as u64
+        } else {
+            0
+        };
+        let avg_cost = if *count > 0 {
+            cost / *count as f64
+        } else {
+            0.0
+        };
         let ok_pct = if *count > 0 {
             100.0 * *ok as f64 / *count as f64
         } else {
             0.0
         };
         println!(
-            " {:<3} {:<18} {:>11} {:>9} {:>10.4} {:>5.1}% {:>11}",
+            " {:<3} {:<18} {:>11} {:>9} {:>8} {:>8} {:>8} {:>10.4} {:>5.1}% {:>11}",
             i + 1,
             provider,
             count,
             avg_dur,
+            percentile(samples, 50),
+            percentile(samples, 90),
+            percentile(samples, 99),
             avg_cost,
             ok_pct,
             duration_ms,
         );
     }
-    println!("{}", "─".repeat(76));
+    println!("{}", "─".repeat(103));
     println!(
         "{} provider(s) \u{2022} {} total delegations \u{2022} {}ms total duration",
         rows.len(),
@@ -6129,18 +6894,9 @@ pub fn print_run_token_rank(log_path: &Path, run_id: Option<&str>) -> Result<()>
                 continue;
             }
         }
-        let success = ev
-            .get("success")
-            .and_then(|x| x.as_bool())
-            .unwrap_or(false);
-        let tokens = ev
-            .get("tokens_used")
-            .and_then(|x| x.as_u64())
-            .unwrap_or(0);
-        let cost = ev
-            .get("cost_usd")
-            .and_then(|x| x.as_f64())
-            .unwrap_or(0.0);
+        let success = ev.get("success").and_then(|x| x.as_bool()).unwrap_or(false);
+        let tokens = ev.get("tokens_used").and_then(|x| x.as_u64()).unwrap_or(0);
+        let cost = ev.get("cost_usd").and_then(|x| x.as_f64()).unwrap_or(0.0);
         let entry = run_map.entry(rid.to_owned()).or_insert((0, 0, 0, 0.0));
         entry.0 += 1;
         if success {
@@ -6163,9 +6919,7 @@ pub fn print_run_token_rank(log_path: &Path, run_id: Option<&str>) -> Result<()>
     rows.sort_by(|a, b| {
         let avg_a = if a.1 > 0 { a.3 / a.1 as u64 } else { 0 };
         let avg_b = if b.1 > 0 { b.3 / b.1 as u64 } else { 0 };
-        avg_b
-            .cmp(&avg_a)
-            .then(a.0.cmp(&b.0))
+        avg_b.cmp(&avg_a).then(a.0.cmp(&b.0))
     });
 
     let total_delegations: usize = rows.iter().map(|(_, c, _, _, _)| c).sum();
@@ -6177,8 +6931,16 @@ pub fn print_run_token_rank(log_path: &Path, run_id: Option<&str>) -> Result<()>
     );
     println!("{}", "─".repeat(90));
     for (i, (rid, count, ok, tokens, cost)) in rows.iter().enumerate() {
-        let avg_tok = if *count > 0 { tokens / *count as u64 } else { 0 };
-        let avg_cost = if *count > 0 { cost / *count as f64 } else { 0.0 };
+        let avg_tok = if *count > 0 {
+            tokens / *count as u64
+        } else {
+            0
+        };
+        let avg_cost = if *count > 0 {
+            cost / *count as f64
+        } else {
+            0.0
+        };
         let ok_pct = if *count > 0 {
             100.0 * *ok as f64 / *count as f64
         } else {
@@ -6242,14 +7004,8 @@ pub fn print_run_duration_rank(log_path: &Path, run_id: Option<&str>) -> Result<
         let Some(duration_ms) = ev.get("duration_ms").and_then(|x| x.as_u64()) else {
             continue;
         };
-        let success = ev
-            .get("success")
-            .and_then(|x| x.as_bool())
-            .unwrap_or(false);
-        let cost = ev
-            .get("cost_usd")
-            .and_then(|x| x.as_f64())
-            .unwrap_or(0.0);
+        let success = ev.get("success").and_then(|x| x.as_bool()).unwrap_or(false);
+        let cost = ev.get("cost_usd").and_then(|x| x.as_f64()).unwrap_or(0.0);
         let entry = run_map.entry(rid.to_owned()).or_insert((0, 0, 0, 0.0));
         entry.0 += 1;
         if success {
@@ -6272,9 +7028,7 @@ pub fn print_run_duration_rank(log_path: &Path, run_id: Option<&str>) -> Result<
     rows.sort_by(|a, b| {
         let avg_a = if a.1 > 0 { a.3 / a.1 as u64 } else { 0 };
         let avg_b = if b.1 > 0 { b.3 / b.1 as u64 } else { 0 };
-        avg_b
-            .cmp(&avg_a)
-            .then(a.0.cmp(&b.0))
+        avg_b.cmp(&avg_a).then(a.0.cmp(&b.0))
     });
 
     let total_delegations: usize = rows.iter().map(|(_, c, _, _, _)| c).sum();
@@ -6286,8 +7040,16 @@ pub fn print_run_duration_rank(log_path: &Path, run_id: Option<&str>) -> Result<
     );
     println!("{}", "─".repeat(90));
     for (i, (rid, count, ok, duration_ms, cost)) in rows.iter().enumerate() {
-        let avg_dur = if *count > 0 { duration_ms / *count as u64 } else { 0 };
-        let avg_cost = if *count > 0 { cost / *count as f64 } else { 0.0 };
+        let avg_dur = if *count > 0 {
+            duration_ms / *count as u64
+        } else {
+            0
+        };
+        let avg_cost = if *count > 0 {
+            cost / *count as f64
+        } else {
+            0.0
+        };
         let ok_pct = if *count > 0 {
             100.0 * *ok as f64 / *count as f64
         } else {
@@ -6348,18 +7110,9 @@ pub fn print_run_success_rank(log_path: &Path, run_id: Option<&str>) -> Result<(
                 continue;
             }
         }
-        let success = ev
-            .get("success")
-            .and_then(|x| x.as_bool())
-            .unwrap_or(false);
-        let tokens = ev
-            .get("tokens_used")
-            .and_then(|x| x.as_u64())
-            .unwrap_or(0);
-        let cost = ev
-            .get("cost_usd")
-            .and_then(|x| x.as_f64())
-            .unwrap_or(0.0);
+        let success = ev.get("success").and_then(|x| x.as_bool()).unwrap_or(false);
+        let tokens = ev.get("tokens_used").and_then(|x| x.as_u64()).unwrap_or(0);
+        let cost = ev.get("cost_usd").and_then(|x| x.as_f64()).unwrap_or(0.0);
         let entry = run_map.entry(rid.to_owned()).or_insert((0, 0, 0, 0.0));
         entry.0 += 1;
         if success {
@@ -6380,8 +7133,16 @@ pub fn print_run_success_rank(log_path: &Path, run_id: Option<&str>) -> Result<(
         .collect();
     // Sort: ok_pct desc, ties by count desc, then run_id asc
     rows.sort_by(|a, b| {
-        let ok_a = if a.1 > 0 { a.2 as f64 / a.1 as f64 } else { 0.0 };
-        let ok_b = if b.1 > 0 { b.2 as f64 / b.1 as f64 } else { 0.0 };
+        let ok_a = if a.1 > 0 {
+            a.2 as f64 / a.1 as f64
+        } else {
+            0.0
+        };
+        let ok_b = if b.1 > 0 {
+            b.2 as f64 / b.1 as f64
+        } else {
+            0.0
+        };
         ok_b.partial_cmp(&ok_a)
             .unwrap_or(std::cmp::Ordering::Equal)
             .then(b.1.cmp(&a.1))
@@ -6398,8 +7159,16 @@ pub fn print_run_success_rank(log_path: &Path, run_id: Option<&str>) -> Result<(
     println!("{}", "─".repeat(90));
     for (i, (rid, count, ok, tokens, cost)) in rows.iter().enumerate() {
         let failures = count - ok;
-        let avg_cost = if *count > 0 { cost / *count as f64 } else { 0.0 };
-        let avg_tok = if *count > 0 { tokens / *count as u64 } else { 0 };
+        let avg_cost = if *count > 0 {
+            cost / *count as f64
+        } else {
+            0.0
+        };
+        let avg_tok = if *count > 0 {
+            tokens / *count as u64
+        } else {
+            0
+        };
         let ok_pct = if *count > 0 {
             100.0 * *ok as f64 / *count as f64
         } else {
@@ -6494,6 +7263,72 @@ pub fn print_export(log_path: &Path, run_id: Option<&str>, format: ExportFormat)
     Ok(())
 }
 
+/// `zeroclaw delegations export --format otlp` — replay stored events into an
+/// OTLP endpoint as spans for viewing in Jaeger/Tempo.
+pub fn export_otlp(log_path: &Path, run_id: Option<&str>, endpoint: Option<&str>) -> Result<()> {
+    let all_events = read_all_events(log_path)?;
+    if all_events.is_empty() {
+        println!("No delegation data found at: {}", log_path.display());
+        println!("Run ZeroClaw with a workflow that uses the `delegate` tool.");
+        return Ok(());
+    }
+
+    let events: Vec<Value> = if let Some(rid) = run_id {
+        all_events
+            .into_iter()
+            .filter(|e| e.get("run_id").and_then(|x| x.as_str()) == Some(rid))
+            .collect()
+    } else {
+        all_events
+    };
+    if events.is_empty() {
+        println!("No events found for run: {}", run_id.unwrap_or("?"));
+        return Ok(());
+    }
+
+    let exported = crate::observability::otel::replay_delegation_events(&events, endpoint, None)
+        .map_err(|e| anyhow::anyhow!(e))?;
+    println!(
+        "Exported {exported} span(s) to {}",
+        endpoint.unwrap_or("http://localhost:4318")
+    );
+    Ok(())
+}
+
+/// Return per-agent token/cost totals for events at or after `since`,
+/// sorted by cost descending (token count as tie-break).
+///
+/// Events from all runs are bucketed by their own timestamps. Returns an
+/// empty vector when the log is absent or has no events in the window.
+pub fn agent_costs_since(log_path: &Path, since: DateTime<Utc>) -> Result<Vec<AgentCost>> {
+    let events: Vec<Value> = read_all_events(log_path)?
+        .into_iter()
+        .filter(|e| {
+            e.get("timestamp")
+                .and_then(parse_ts)
+                .is_some_and(|ts| ts >= since)
+        })
+        .collect();
+
+    let mut costs: Vec<AgentCost> = collect_agent_stats(&events)
+        .into_iter()
+        .map(|s| AgentCost {
+            agent_name: s.agent_name,
+            delegations: s.delegation_count,
+            total_tokens: s.total_tokens,
+            total_cost_usd: s.total_cost_usd,
+        })
+        .collect();
+    costs.sort_by(|a, b| {
+        b.total_cost_usd
+            .partial_cmp(&a.total_cost_usd)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then(b.total_tokens.cmp(&a.total_tokens))
+            .then(a.agent_name.cmp(&b.agent_name))
+    });
+    Ok(costs)
+}
+
 /// Return aggregate statistics from the delegation log, or `None` if the
 /// log does not exist or contains no parseable run data.
 pub fn get_log_summary(log_path: &Path) -> Result<Option<LogSummary>> {
@@ -6525,7 +7360,7 @@ pub fn get_log_summary(log_path: &Path) -> Result<Option<LogSummary>> {
 ///
 /// Columns: agent | count | ok% | avg_dur | tokens | cost
 /// Rows are sorted by total tokens descending (heaviest agent first).
-pub fn print_stats(log_path: &Path, run_id: Option<&str>) -> Result<()> {
+pub fn print_stats(log_path: &Path, run_id: Option<&str>, accessible: bool) -> Result<()> {
     let all_events = read_all_events(log_path)?;
     if all_events.is_empty() {
         println!("No delegation data found at: {}", log_path.display());
@@ -6559,11 +7394,81 @@ pub fn print_stats(log_path: &Path, run_id: Option<&str>) -> Result<()> {
         .unwrap_or_else(|| "  (all runs)".to_owned());
     println!("Delegation Stats{scope}");
     println!();
+
+    // Accessible mode: one labeled block per agent instead of an aligned
+    // table, so screen readers announce each value with its field name.
+    if accessible {
+        let mut total_count = 0usize;
+        let mut total_tokens = 0u64;
+        let mut total_cost = 0.0f64;
+        for s in &stats {
+            total_count += s.delegation_count;
+            total_tokens += s.total_tokens;
+            total_cost += s.total_cost_usd;
+            println!("Agent: {}", s.agent_name);
+            println!("  delegations: {}", s.delegation_count);
+            if s.end_count > 0 {
+                println!(
+                    "  success rate: {:.1}%",
+                    100.0 * s.success_count as f64 / s.end_count as f64
+                );
+                println!(
+                    "  average duration: {}",
+                    fmt_duration(s.total_duration_ms / s.end_count as u64)
+                );
+                println!(
+                    "  duration p50/p90/p99: {} / {} / {}",
+                    fmt_duration(percentile(&s.durations_ms, 50)),
+                    fmt_duration(percentile(&s.durations_ms, 90)),
+                    fmt_duration(percentile(&s.durations_ms, 99))
+                );
+            } else {
+                println!("  success rate: none");
+                println!("  average duration: none");
+                println!("  duration p50/p90/p99: none");
+            }
+            println!(
+                "  tokens: {}",
+                if s.total_tokens > 0 {
+                    s.total_tokens.to_string()
+                } else {
+                    "none".to_owned()
+                }
+            );
+            println!(
+                "  cost: {}",
+                if s.total_cost_usd > 0.0 {
+                    format!("${:.4}", s.total_cost_usd)
+                } else {
+                    "none".to_owned()
+                }
+            );
+            println!();
+        }
+        println!(
+            "Totals: {} delegations, {} tokens, {}",
+            total_count,
+            if total_tokens > 0 {
+                total_tokens.to_string()
+            } else {
+                "none".to_owned()
+            },
+            if total_cost > 0.0 {
+                format!("${total_cost:.4}")
+            } else {
+                "no cost".to_owned()
+            }
+        );
+        println!();
+        println!("Use `zeroclaw delegations stats --run <id>` to scope to one run.");
+        return Ok(());
+    }
+
     println!(
-        "{:<26} {:>6}  {:>6}  {:>8}  {:>10}  {:>10}",
-        "agent", "count", "ok%", "avg_dur", "tokens", "cost"
+        "{:<26} {:>6}  {:>6}  {:>8}  {:>8}  {:>8}  {:>8}  {:>10}  {:>10}",
+        "agent", "count", "ok%", "avg_dur", "p50", "p90", "p99", "tokens", "cost"
     );
-    println!("{}", "─".repeat(76));
+    println!("{}", "─".repeat(106));
 
     for s in &stats {
         let ok_pct = if s.end_count > 0 {
@@ -6574,10 +7479,20 @@ pub fn print_stats(log_path: &Path, run_id: Option<&str>) -> Result<()> {
         } else {
             "—".to_owned()
         };
-        let avg_dur = if s.end_count > 0 {
-            fmt_duration(s.total_duration_ms / s.end_count as u64)
+        let (avg_dur, p50, p90, p99) = if s.end_count > 0 {
+            (
+                fmt_duration(s.total_duration_ms / s.end_count as u64),
+                fmt_duration(percentile(&s.durations_ms, 50)),
+                fmt_duration(percentile(&s.durations_ms, 90)),
+                fmt_duration(percentile(&s.durations_ms, 99)),
+            )
         } else {
-            "—".to_owned()
+            (
+                "—".to_owned(),
+                "—".to_owned(),
+                "—".to_owned(),
+                "—".to_owned(),
+            )
         };
         let tokens = if s.total_tokens > 0 {
             s.total_tokens.to_string()
@@ -6590,21 +7505,24 @@ pub fn print_stats(log_path: &Path, run_id: Option<&str>) -> Result<()> {
             "—".to_owned()
         };
         println!(
-            "{:<26} {:>6}  {:>6}  {:>8}  {:>10}  {:>10}",
-            s.agent_name, s.delegation_count, ok_pct, avg_dur, tokens, cost
+            "{:<26} {:>6}  {:>6}  {:>8}  {:>8}  {:>8}  {:>8}  {:>10}  {:>10}",
+            s.agent_name, s.delegation_count, ok_pct, avg_dur, p50, p90, p99, tokens, cost
         );
     }
 
-    println!("{}", "─".repeat(76));
+    println!("{}", "─".repeat(106));
     let total_count: usize = stats.iter().map(|s| s.delegation_count).sum();
     let total_tokens: u64 = stats.iter().map(|s| s.total_tokens).sum();
     let total_cost: f64 = stats.iter().map(|s| s.total_cost_usd).sum();
     println!(
-        "{:<26} {:>6}  {:>6}  {:>8}  {:>10}  {:>10}",
+        "{:<26} {:>6}  {:>6}  {:>8}  {:>8}  {:>8}  {:>8}  {:>10}  {:>10}",
         "TOTAL",
         total_count,
         "",
         "",
+        "",
+        "",
+        "",
         if total_tokens > 0 {
             total_tokens.to_string()
         } else {
@@ -6622,7 +7540,7 @@ pub fn print_stats(log_path: &Path, run_id: Option<&str>) -> Result<()> {
 }
 
 /// Print a one-line summary of all stored delegation runs to stdout.
-pub fn print_summary(log_path: &Path) -> Result<()> {
+pub fn print_summary(log_path: &Path, accessible: bool) -> Result<()> {
     let events = read_all_events(log_path)?;
     if events.is_empty() {
         println!("No delegation data found at: {}", log_path.display());
@@ -6634,6 +7552,8 @@ pub fn print_summary(log_path: &Path) -> Result<()> {
     let total_tokens: u64 = runs.iter().map(|r| r.total_tokens).sum();
     let total_cost: f64 = runs.iter().map(|r| r.total_cost_usd).sum();
 
+    // Screen readers skip or misread the em-dash placeholder.
+    let none = if accessible { "none" } else { "—" };
     println!("Delegation Log: {}", log_path.display());
     println!();
     println!("  Runs stored:      {}", runs.len());
@@ -6643,7 +7563,7 @@ pub fn print_summary(log_path: &Path) -> Result<()> {
         if total_tokens > 0 {
             total_tokens.to_string()
         } else {
-            "—".to_owned()
+            none.to_owned()
         }
     );
     println!(
@@ -6651,7 +7571,7 @@ pub fn print_summary(log_path: &Path) -> Result<()> {
         if total_cost > 0.0 {
             format!("${total_cost:.4}")
         } else {
-            "—".to_owned()
+            none.to_owned()
         }
     );
     if let Some(newest) = runs.first() {
@@ -6668,13 +7588,48 @@ pub fn print_summary(log_path: &Path) -> Result<()> {
 }
 
 /// Print a table of all stored runs to stdout, newest first.
-pub fn print_runs(log_path: &Path) -> Result<()> {
+pub fn print_runs(log_path: &Path, accessible: bool) -> Result<()> {
     let events = read_all_events(log_path)?;
     if events.is_empty() {
         println!("No delegation data found at: {}", log_path.display());
         return Ok(());
     }
     let runs = collect_runs(&events);
+
+    // Accessible mode: one labeled block per run instead of an aligned table.
+    if accessible {
+        println!("Delegation runs ({} stored, newest first)", runs.len());
+        for (i, run) in runs.iter().enumerate() {
+            println!();
+            println!("Run {}:", i + 1);
+            println!(
+                "  start (UTC): {}",
+                run.start_time
+                    .map(|t| t.format("%Y-%m-%d %H:%M:%S").to_string())
+                    .unwrap_or_else(|| "unknown".to_owned())
+            );
+            println!("  delegations: {}", run.delegation_count);
+            println!(
+                "  tokens: {}",
+                if run.total_tokens > 0 {
+                    run.total_tokens.to_string()
+                } else {
+                    "none".to_owned()
+                }
+            );
+            println!(
+                "  cost: {}",
+                if run.total_cost_usd > 0.0 {
+                    format!("${:.4}", run.total_cost_usd)
+                } else {
+                    "none".to_owned()
+                }
+            );
+            println!("  run id: {}", run.run_id);
+        }
+        return Ok(());
+    }
+
     println!(
         "{:<4} {:<23} {:>11} {:>10} {:>10}  run_id",
         "#", "start (UTC)", "delegations", "tokens", "cost"
@@ -6705,92 +7660,711 @@ pub fn print_runs(log_path: &Path) -> Result<()> {
             run.run_id
         );
     }
-    Ok(())
+    Ok(())
+}
+
+/// Print the delegation tree for a run to stdout.
+///
+/// Defaults to the most recent run when `run_id` is `None`.
+pub fn print_tree(log_path: &Path, run_id: Option<&str>, accessible: bool) -> Result<()> {
+    let all_events = read_all_events(log_path)?;
+    if all_events.is_empty() {
+        println!("No delegation data found at: {}", log_path.display());
+        return Ok(());
+    }
+
+    // Resolve run_id to most recent when not specified.
+    let resolved = if let Some(rid) = run_id {
+        rid.to_owned()
+    } else {
+        let runs = collect_runs(&all_events);
+        match runs.into_iter().next() {
+            Some(r) => r.run_id,
+            None => {
+                println!("No runs found.");
+                return Ok(());
+            }
+        }
+    };
+
+    let run_events: Vec<Value> = all_events
+        .into_iter()
+        .filter(|e| e.get("run_id").and_then(|x| x.as_str()) == Some(resolved.as_str()))
+        .collect();
+
+    if run_events.is_empty() {
+        println!("No events found for run: {resolved}");
+        return Ok(());
+    }
+
+    let nodes = build_nodes(&run_events);
+
+    println!("Run: {resolved}");
+
+    // Accessible mode: one labeled block per delegation; nesting is given
+    // as an explicit depth number instead of indentation.
+    if accessible {
+        for (i, node) in nodes.iter().enumerate() {
+            println!();
+            println!("Delegation {}:", i + 1);
+            println!("  agent: {}", node.agent_name);
+            println!("  model: {}", node.model);
+            println!("  depth: {}", node.depth);
+            println!(
+                "  duration: {}",
+                node.duration_ms
+                    .map(fmt_duration)
+                    .unwrap_or_else(|| "still running".to_owned())
+            );
+            println!(
+                "  tokens: {}",
+                node.tokens_used
+                    .map(|t| t.to_string())
+                    .unwrap_or_else(|| "none".to_owned())
+            );
+            println!(
+                "  cost: {}",
+                node.cost_usd
+                    .map(|c| format!("${c:.4}"))
+                    .unwrap_or_else(|| "none".to_owned())
+            );
+            println!(
+                "  status: {}",
+                match node.success {
+                    Some(true) => "ok",
+                    Some(false) => "failed",
+                    None => "running",
+                }
+            );
+        }
+        println!();
+        let total_tokens: u64 = nodes.iter().filter_map(|n| n.tokens_used).sum();
+        let total_cost: f64 = nodes.iter().filter_map(|n| n.cost_usd).sum();
+        println!(
+            "Total: {} delegations, {} tokens, ${:.4}",
+            nodes.len(),
+            if total_tokens > 0 {
+                total_tokens.to_string()
+            } else {
+                "no".to_owned()
+            },
+            total_cost
+        );
+        return Ok(());
+    }
+
+    println!("{}", "─".repeat(78));
+    println!(
+        "{:<42} {:>8} {:>8} {:>10}  status",
+        "agent (model)", "dur", "tokens", "cost"
+    );
+    println!("{}", "─".repeat(78));
+
+    for node in &nodes {
+        let indent = "  ".repeat(node.depth as usize);
+        let label = format!("{}{}  [{}]", indent, node.agent_name, node.model);
+        let dur = node
+            .duration_ms
+            .map(fmt_duration)
+            .unwrap_or_else(|| "…".to_owned());
+        let tok = node
+            .tokens_used
+            .map(|t| t.to_string())
+            .unwrap_or_else(|| "—".to_owned());
+        let cost = node
+            .cost_usd
+            .map(|c| format!("${c:.4}"))
+            .unwrap_or_else(|| "—".to_owned());
+        let status = match node.success {
+            Some(true) => "OK",
+            Some(false) => "FAIL",
+            None => "running",
+        };
+        println!(
+            "{:<42} {:>8} {:>8} {:>10}  {}",
+            label, dur, tok, cost, status
+        );
+    }
+
+    println!("{}", "─".repeat(78));
+    let total_tokens: u64 = nodes.iter().filter_map(|n| n.tokens_used).sum();
+    let total_cost: f64 = nodes.iter().filter_map(|n| n.cost_usd).sum();
+    println!(
+        "Total: {} delegations  |  {} tokens  |  ${:.4}",
+        nodes.len(),
+        if total_tokens > 0 {
+            total_tokens.to_string()
+        } else {
+            "—".to_owned()
+        },
+        total_cost
+    );
+    Ok(())
+}
+
+// ─── Forecast & anomalies ─────────────────────────────────────────────────────
+
+/// Rolling-window size (days) for anomaly baselines.
+const ANOMALY_WINDOW_DAYS: usize = 7;
+/// Minimum prior days required before a value can be judged anomalous.
+const ANOMALY_MIN_PRIOR_DAYS: usize = 3;
+
+/// Per-UTC-day cost totals from `DelegationEnd` events, oldest day first.
+fn daily_cost_series(events: &[Value]) -> Vec<(String, f64)> {
+    let mut map: std::collections::BTreeMap<String, f64> = std::collections::BTreeMap::new();
+    for ev in events {
+        if ev.get("event_type").and_then(|x| x.as_str()) != Some("DelegationEnd") {
+            continue;
+        }
+        let Some(ts) = ev.get("timestamp").and_then(|x| x.as_str()) else {
+            continue;
+        };
+        if ts.len() < 10 {
+            continue;
+        }
+        let cost = ev.get("cost_usd").and_then(|x| x.as_f64()).unwrap_or(0.0);
+        *map.entry(ts[..10].to_owned()).or_insert(0.0) += cost;
+    }
+    map.into_iter().collect()
+}
+
+/// Least-squares linear fit over `values` indexed 0..n; returns (slope, intercept).
+fn linear_trend(values: &[f64]) -> (f64, f64) {
+    let n = values.len();
+    if n == 0 {
+        return (0.0, 0.0);
+    }
+    #[allow(clippy::cast_precision_loss)]
+    let n_f = n as f64;
+    let mean_x = (n_f - 1.0) / 2.0;
+    let mean_y = values.iter().sum::<f64>() / n_f;
+    let mut cov = 0.0;
+    let mut var = 0.0;
+    for (i, y) in values.iter().enumerate() {
+        #[allow(clippy::cast_precision_loss)]
+        let dx = i as f64 - mean_x;
+        cov += dx * (y - mean_y);
+        var += dx * dx;
+    }
+    let slope = if var > 0.0 { cov / var } else { 0.0 };
+    (slope, mean_y - slope * mean_x)
+}
+
+struct MonthForecast {
+    days_elapsed: u32,
+    month_to_date: f64,
+    avg_daily: f64,
+    trend_per_day: f64,
+    projected_trend: f64,
+    projected_flat: f64,
+}
+
+/// Project end-of-month spend for `month` (`YYYY-MM`) from the daily cost
+/// series. Days without delegations count as $0 so quiet days pull the trend
+/// down rather than being skipped.
+fn forecast_month(
+    series: &[(String, f64)],
+    month: &str,
+    today_day: u32,
+    days_in_month: u32,
+) -> MonthForecast {
+    let by_date: HashMap<&str, f64> = series.iter().map(|(d, c)| (d.as_str(), *c)).collect();
+    let daily: Vec<f64> = (1..=today_day)
+        .map(|day| {
+            by_date
+                .get(format!("{month}-{day:02}").as_str())
+                .copied()
+                .unwrap_or(0.0)
+        })
+        .collect();
+    let month_to_date: f64 = daily.iter().sum();
+    #[allow(clippy::cast_precision_loss)]
+    let avg_daily = month_to_date / daily.len().max(1) as f64;
+    let (slope, intercept) = linear_trend(&daily);
+
+    let mut projected_trend = month_to_date;
+    for day in (today_day + 1)..=days_in_month {
+        #[allow(clippy::cast_precision_loss)]
+        let predicted = slope * f64::from(day - 1) + intercept;
+        projected_trend += predicted.max(0.0);
+    }
+    let projected_flat = month_to_date + avg_daily * f64::from(days_in_month - today_day);
+
+    MonthForecast {
+        days_elapsed: today_day,
+        month_to_date,
+        avg_daily,
+        trend_per_day: slope,
+        projected_trend,
+        projected_flat,
+    }
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    let (next_year, next_month) = if month == 12 {
+        (year + 1, 1)
+    } else {
+        (year, month + 1)
+    };
+    chrono::NaiveDate::from_ymd_opt(next_year, next_month, 1)
+        .and_then(|d| d.pred_opt())
+        .map_or(30, |d| d.day())
+}
+
+/// Project end-of-month spend from the daily cost trend in the delegation log.
+///
+/// Fits a least-squares line to this month's per-day cost (missing days count
+/// as $0) and extrapolates it over the remaining days, alongside a flat
+/// average-based projection for comparison.
+pub fn print_forecast(log_path: &Path) -> Result<()> {
+    let all_events = read_all_events(log_path)?;
+    if all_events.is_empty() {
+        println!("No delegation data found at: {}", log_path.display());
+        println!("Run ZeroClaw with a workflow that uses the `delegate` tool.");
+        return Ok(());
+    }
+
+    let series = daily_cost_series(&all_events);
+    if series.is_empty() {
+        println!("No completed delegations found.");
+        return Ok(());
+    }
+
+    let now = Utc::now();
+    let month = now.format("%Y-%m").to_string();
+    let total_days = days_in_month(now.year(), now.month());
+    let f = forecast_month(&series, &month, now.day(), total_days);
+
+    println!("Cost Forecast  ({month})");
+    println!();
+    println!(
+        "  Month to date:      ${:.4}  ({} of {} days)",
+        f.month_to_date, f.days_elapsed, total_days
+    );
+    println!("  Average per day:    ${:.4}", f.avg_daily);
+    println!(
+        "  Daily trend:        {}${:.4}/day",
+        if f.trend_per_day >= 0.0 { "+" } else { "-" },
+        f.trend_per_day.abs()
+    );
+    println!("  Projected (trend):  ${:.4}", f.projected_trend);
+    println!("  Projected (flat):   ${:.4}", f.projected_flat);
+    if (f.days_elapsed as usize) < ANOMALY_MIN_PRIOR_DAYS {
+        println!();
+        println!(
+            "  Note: only {} day(s) of data this month — projections are rough.",
+            f.days_elapsed
+        );
+    }
+    Ok(())
+}
+
+/// One flagged deviation from a rolling baseline.
+struct AnomalyHit {
+    date: String,
+    value: f64,
+    mean: f64,
+    sigmas: f64,
+}
+
+/// Flag points deviating more than `threshold` standard deviations from the
+/// rolling average of the preceding window (up to [`ANOMALY_WINDOW_DAYS`]
+/// points, at least [`ANOMALY_MIN_PRIOR_DAYS`]). A zero-variance baseline
+/// flags any differing value as infinitely deviant.
+fn rolling_anomalies(series: &[(String, f64)], threshold: f64) -> Vec<AnomalyHit> {
+    let mut out = Vec::new();
+    for i in ANOMALY_MIN_PRIOR_DAYS..series.len() {
+        let start = i.saturating_sub(ANOMALY_WINDOW_DAYS);
+        let window: Vec<f64> = series[start..i].iter().map(|(_, v)| *v).collect();
+        #[allow(clippy::cast_precision_loss)]
+        let mean = window.iter().sum::<f64>() / window.len() as f64;
+        #[allow(clippy::cast_precision_loss)]
+        let std =
+            (window.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / window.len() as f64).sqrt();
+        let value = series[i].1;
+        let deviation = (value - mean).abs();
+        let sigmas = if std > 1e-9 {
+            deviation / std
+        } else if deviation > 1e-9 {
+            f64::INFINITY
+        } else {
+            0.0
+        };
+        if sigmas > threshold {
+            out.push(AnomalyHit {
+                date: series[i].0.clone(),
+                value,
+                mean,
+                sigmas,
+            });
+        }
+    }
+    out
+}
+
+fn format_sigmas(sigmas: f64) -> String {
+    if sigmas.is_infinite() {
+        "inf".to_owned()
+    } else {
+        format!("{sigmas:.1}σ")
+    }
+}
+
+/// Flag days and agents whose cost or failure rate deviates from their
+/// rolling average by more than `threshold` standard deviations.
+pub fn print_anomalies(log_path: &Path, threshold: f64) -> Result<()> {
+    let all_events = read_all_events(log_path)?;
+    if all_events.is_empty() {
+        println!("No delegation data found at: {}", log_path.display());
+        println!("Run ZeroClaw with a workflow that uses the `delegate` tool.");
+        return Ok(());
+    }
+
+    // Per-agent per-day (end_count, failure_count, cost).
+    let mut per_agent: HashMap<String, std::collections::BTreeMap<String, (usize, usize, f64)>> =
+        HashMap::new();
+    for ev in &all_events {
+        if ev.get("event_type").and_then(|x| x.as_str()) != Some("DelegationEnd") {
+            continue;
+        }
+        let Some(agent) = ev.get("agent_name").and_then(|x| x.as_str()) else {
+            continue;
+        };
+        let Some(ts) = ev.get("timestamp").and_then(|x| x.as_str()) else {
+            continue;
+        };
+        if ts.len() < 10 {
+            continue;
+        }
+        let entry = per_agent
+            .entry(agent.to_owned())
+            .or_default()
+            .entry(ts[..10].to_owned())
+            .or_insert((0, 0, 0.0));
+        entry.0 += 1;
+        if !ev.get("success").and_then(|x| x.as_bool()).unwrap_or(false) {
+            entry.1 += 1;
+        }
+        entry.2 += ev.get("cost_usd").and_then(|x| x.as_f64()).unwrap_or(0.0);
+    }
+
+    println!(
+        "Delegation Anomalies  (> {threshold:.1}σ from rolling {ANOMALY_WINDOW_DAYS}-day average)"
+    );
+    println!();
+
+    let mut found = false;
+
+    let overall = daily_cost_series(&all_events);
+    let cost_hits = rolling_anomalies(&overall, threshold);
+    if !cost_hits.is_empty() {
+        found = true;
+        println!("Daily cost (all agents):");
+        for hit in &cost_hits {
+            println!(
+                "  {}  ${:.4}  (baseline ${:.4}, {})",
+                hit.date,
+                hit.value,
+                hit.mean,
+                format_sigmas(hit.sigmas)
+            );
+        }
+        println!();
+    }
+
+    let mut agents: Vec<&String> = per_agent.keys().collect();
+    agents.sort();
+    for agent in agents {
+        let days = &per_agent[agent];
+        let cost_series: Vec<(String, f64)> =
+            days.iter().map(|(d, (_, _, c))| (d.clone(), *c)).collect();
+        #[allow(clippy::cast_precision_loss)]
+        let failure_series: Vec<(String, f64)> = days
+            .iter()
+            .map(|(d, (count, failures, _))| (d.clone(), *failures as f64 / (*count).max(1) as f64))
+            .collect();
+
+        let cost_hits = rolling_anomalies(&cost_series, threshold);
+        let failure_hits = rolling_anomalies(&failure_series, threshold);
+        if cost_hits.is_empty() && failure_hits.is_empty() {
+            continue;
+        }
+        found = true;
+        println!("Agent '{agent}':");
+        for hit in &cost_hits {
+            println!(
+                "  {}  cost ${:.4}  (baseline ${:.4}, {})",
+                hit.date,
+                hit.value,
+                hit.mean,
+                format_sigmas(hit.sigmas)
+            );
+        }
+        for hit in &failure_hits {
+            println!(
+                "  {}  failure rate {:.0}%  (baseline {:.0}%, {})",
+                hit.date,
+                hit.value * 100.0,
+                hit.mean * 100.0,
+                format_sigmas(hit.sigmas)
+            );
+        }
+        println!();
+    }
+
+    if !found {
+        println!("No anomalies detected.");
+    }
+    Ok(())
+}
+
+// ─── HTML report ──────────────────────────────────────────────────────────────
+
+/// Escape text for embedding in HTML element content or attribute values.
+fn html_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+/// One row of a per-field breakdown (agent, model, or provider).
+struct FieldRow {
+    name: String,
+    delegation_count: usize,
+    end_count: usize,
+    success_count: usize,
+    total_tokens: u64,
+    total_cost_usd: f64,
+}
+
+/// Aggregate events by a string `field` (`agent_name`, `model`, or `provider`).
+///
+/// Rows are sorted by total tokens descending; alpha tiebreak on name.
+fn breakdown_by_field(events: &[Value], field: &str) -> Vec<FieldRow> {
+    let mut map: HashMap<String, FieldRow> = HashMap::new();
+    for ev in events {
+        let Some(name) = ev.get(field).and_then(|x| x.as_str()) else {
+            continue;
+        };
+        let entry = map.entry(name.to_owned()).or_insert_with(|| FieldRow {
+            name: name.to_owned(),
+            delegation_count: 0,
+            end_count: 0,
+            success_count: 0,
+            total_tokens: 0,
+            total_cost_usd: 0.0,
+        });
+        match ev.get("event_type").and_then(|x| x.as_str()) {
+            Some("DelegationStart") => entry.delegation_count += 1,
+            Some("DelegationEnd") => {
+                entry.end_count += 1;
+                if ev.get("success").and_then(|x| x.as_bool()).unwrap_or(false) {
+                    entry.success_count += 1;
+                }
+                if let Some(tok) = ev.get("tokens_used").and_then(|x| x.as_u64()) {
+                    entry.total_tokens += tok;
+                }
+                if let Some(cost) = ev.get("cost_usd").and_then(|x| x.as_f64()) {
+                    entry.total_cost_usd += cost;
+                }
+            }
+            _ => {}
+        }
+    }
+    let mut rows: Vec<FieldRow> = map.into_values().collect();
+    rows.sort_by(|a, b| {
+        b.total_tokens
+            .cmp(&a.total_tokens)
+            .then(a.name.cmp(&b.name))
+    });
+    rows
+}
+
+/// Render one breakdown table section as HTML.
+fn render_breakdown_table(title: &str, label: &str, rows: &[FieldRow]) -> String {
+    use std::fmt::Write as _;
+    let mut out = String::new();
+    let _ = writeln!(out, "<h2>{}</h2>", html_escape(title));
+    let _ = writeln!(
+        out,
+        "<table><thead><tr><th>{}</th><th>delegations</th><th>success</th>\
+         <th>tokens</th><th>cost</th></tr></thead><tbody>",
+        html_escape(label)
+    );
+    for row in rows {
+        #[allow(clippy::cast_precision_loss)]
+        let success = if row.end_count > 0 {
+            format!(
+                "{:.0}%",
+                row.success_count as f64 / row.end_count as f64 * 100.0
+            )
+        } else {
+            "—".to_owned()
+        };
+        let _ = writeln!(
+            out,
+            "<tr><td>{}</td><td class=\"num\">{}</td><td class=\"num\">{}</td>\
+             <td class=\"num\">{}</td><td class=\"num\">${:.4}</td></tr>",
+            html_escape(&row.name),
+            row.delegation_count,
+            success,
+            row.total_tokens,
+            row.total_cost_usd,
+        );
+    }
+    out.push_str("</tbody></table>\n");
+    out
 }
 
-/// Print the delegation tree for a run to stdout.
+/// Render the full self-contained HTML dashboard for `events`.
 ///
-/// Defaults to the most recent run when `run_id` is `None`.
-pub fn print_tree(log_path: &Path, run_id: Option<&str>) -> Result<()> {
-    let all_events = read_all_events(log_path)?;
-    if all_events.is_empty() {
-        println!("No delegation data found at: {}", log_path.display());
-        return Ok(());
-    }
+/// Everything is inline (CSS, data, chart script) — no external fetches, so
+/// the file can be shared as-is.
+fn render_html_report(events: &[Value], generated_at: &str) -> String {
+    use std::fmt::Write as _;
 
-    // Resolve run_id to most recent when not specified.
-    let resolved = if let Some(rid) = run_id {
-        rid.to_owned()
-    } else {
-        let runs = collect_runs(&all_events);
-        match runs.into_iter().next() {
-            Some(r) => r.run_id,
-            None => {
-                println!("No runs found.");
-                return Ok(());
-            }
+    let runs = collect_runs(events);
+    let total_delegations: usize = runs.iter().map(|r| r.delegation_count).sum();
+    let total_tokens: u64 = runs.iter().map(|r| r.total_tokens).sum();
+    let total_cost: f64 = runs.iter().map(|r| r.total_cost_usd).sum();
+    let (end_count, success_count) = events.iter().fold((0usize, 0usize), |(ends, ok), ev| {
+        if ev.get("event_type").and_then(|x| x.as_str()) == Some("DelegationEnd") {
+            let succeeded = ev.get("success").and_then(|x| x.as_bool()).unwrap_or(false);
+            (ends + 1, ok + usize::from(succeeded))
+        } else {
+            (ends, ok)
         }
+    });
+    #[allow(clippy::cast_precision_loss)]
+    let success_rate = if end_count > 0 {
+        format!("{:.0}%", success_count as f64 / end_count as f64 * 100.0)
+    } else {
+        "—".to_owned()
     };
 
-    let run_events: Vec<Value> = all_events
-        .into_iter()
-        .filter(|e| e.get("run_id").and_then(|x| x.as_str()) == Some(resolved.as_str()))
-        .collect();
-
-    if run_events.is_empty() {
-        println!("No events found for run: {resolved}");
-        return Ok(());
-    }
-
-    let nodes = build_nodes(&run_events);
-
-    println!("Run: {resolved}");
-    println!("{}", "─".repeat(78));
-    println!(
-        "{:<42} {:>8} {:>8} {:>10}  status",
-        "agent (model)", "dur", "tokens", "cost"
+    let daily = daily_cost_series(events);
+    // `<`-escape so log-sourced strings can never close the script tag.
+    let chart_data = serde_json::json!({
+        "labels": daily.iter().map(|(d, _)| d.as_str()).collect::<Vec<_>>(),
+        "values": daily.iter().map(|(_, c)| *c).collect::<Vec<_>>(),
+    })
+    .to_string()
+    .replace('<', "\\u003c");
+
+    let mut html = String::new();
+    html.push_str(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <title>ZeroClaw Delegation Report</title>\n<style>\n\
+         body{font-family:system-ui,sans-serif;margin:2rem auto;max-width:960px;\
+         color:#1a1a2e;padding:0 1rem}\n\
+         h1{font-size:1.5rem}h2{font-size:1.1rem;margin-top:2rem}\n\
+         .meta{color:#666;font-size:.85rem}\n\
+         .cards{display:flex;gap:1rem;flex-wrap:wrap;margin:1rem 0}\n\
+         .card{border:1px solid #ddd;border-radius:8px;padding:.75rem 1.25rem;min-width:8rem}\n\
+         .card .label{color:#666;font-size:.75rem;text-transform:uppercase}\n\
+         .card .value{font-size:1.3rem;font-weight:600}\n\
+         table{border-collapse:collapse;width:100%;font-size:.9rem}\n\
+         th,td{border-bottom:1px solid #eee;padding:.4rem .6rem;text-align:left}\n\
+         th{background:#f7f7fa}td.num{text-align:right;font-variant-numeric:tabular-nums}\n\
+         canvas{border:1px solid #eee;border-radius:8px;max-width:100%}\n\
+         </style>\n</head>\n<body>\n<h1>ZeroClaw Delegation Report</h1>\n",
+    );
+    let _ = writeln!(
+        html,
+        "<p class=\"meta\">Generated {}</p>",
+        html_escape(generated_at)
     );
-    println!("{}", "─".repeat(78));
 
-    for node in &nodes {
-        let indent = "  ".repeat(node.depth as usize);
-        let label = format!("{}{}  [{}]", indent, node.agent_name, node.model);
-        let dur = node
-            .duration_ms
-            .map(fmt_duration)
-            .unwrap_or_else(|| "…".to_owned());
-        let tok = node
-            .tokens_used
-            .map(|t| t.to_string())
-            .unwrap_or_else(|| "—".to_owned());
-        let cost = node
-            .cost_usd
-            .map(|c| format!("${c:.4}"))
-            .unwrap_or_else(|| "—".to_owned());
-        let status = match node.success {
-            Some(true) => "OK",
-            Some(false) => "FAIL",
-            None => "running",
-        };
-        println!(
-            "{:<42} {:>8} {:>8} {:>10}  {}",
-            label, dur, tok, cost, status
+    html.push_str("<div class=\"cards\">\n");
+    for (label, value) in [
+        ("Runs", runs.len().to_string()),
+        ("Delegations", total_delegations.to_string()),
+        ("Success rate", success_rate),
+        ("Tokens", total_tokens.to_string()),
+        ("Cost", format!("${total_cost:.4}")),
+    ] {
+        let _ = writeln!(
+            html,
+            "<div class=\"card\"><div class=\"label\">{label}</div>\
+             <div class=\"value\">{value}</div></div>"
         );
     }
+    html.push_str("</div>\n");
 
-    println!("{}", "─".repeat(78));
-    let total_tokens: u64 = nodes.iter().filter_map(|n| n.tokens_used).sum();
-    let total_cost: f64 = nodes.iter().filter_map(|n| n.cost_usd).sum();
-    println!(
-        "Total: {} delegations  |  {} tokens  |  ${:.4}",
-        nodes.len(),
-        if total_tokens > 0 {
-            total_tokens.to_string()
-        } else {
-            "—".to_owned()
-        },
-        total_cost
+    html.push_str(
+        "<h2>Daily cost</h2>\n<canvas id=\"cost-chart\" width=\"920\" height=\"260\"></canvas>\n",
     );
+    let _ = writeln!(html, "<script>\nconst data = {chart_data};");
+    html.push_str(
+        "const canvas = document.getElementById('cost-chart');\n\
+         const ctx = canvas.getContext('2d');\n\
+         const pad = {left: 56, right: 12, top: 12, bottom: 36};\n\
+         const plotW = canvas.width - pad.left - pad.right;\n\
+         const plotH = canvas.height - pad.top - pad.bottom;\n\
+         const max = Math.max(...data.values, 0.0001);\n\
+         const barW = Math.max(1, plotW / Math.max(data.labels.length, 1) - 2);\n\
+         ctx.font = '11px system-ui';\n\
+         ctx.fillStyle = '#666';\n\
+         ctx.textAlign = 'right';\n\
+         for (let g = 0; g <= 4; g++) {\n\
+           const y = pad.top + plotH - (g / 4) * plotH;\n\
+           ctx.fillText('$' + (max * g / 4).toFixed(4), pad.left - 6, y + 4);\n\
+           ctx.strokeStyle = '#eee';\n\
+           ctx.beginPath();\n\
+           ctx.moveTo(pad.left, y);\n\
+           ctx.lineTo(canvas.width - pad.right, y);\n\
+           ctx.stroke();\n\
+         }\n\
+         data.values.forEach((v, i) => {\n\
+           const h = (v / max) * plotH;\n\
+           const x = pad.left + i * (plotW / Math.max(data.labels.length, 1)) + 1;\n\
+           ctx.fillStyle = '#4c6ef5';\n\
+           ctx.fillRect(x, pad.top + plotH - h, barW, h);\n\
+         });\n\
+         ctx.fillStyle = '#666';\n\
+         if (data.labels.length > 0) {\n\
+           ctx.textAlign = 'left';\n\
+           ctx.fillText(data.labels[0], pad.left, canvas.height - 12);\n\
+           ctx.textAlign = 'right';\n\
+           ctx.fillText(data.labels[data.labels.length - 1], canvas.width - pad.right, canvas.height - 12);\n\
+         }\n\
+         </script>\n",
+    );
+
+    html.push_str(&render_breakdown_table(
+        "Agent breakdown",
+        "agent",
+        &breakdown_by_field(events, "agent_name"),
+    ));
+    html.push_str(&render_breakdown_table(
+        "Model breakdown",
+        "model",
+        &breakdown_by_field(events, "model"),
+    ));
+    html.push_str(&render_breakdown_table(
+        "Provider breakdown",
+        "provider",
+        &breakdown_by_field(events, "provider"),
+    ));
+
+    html.push_str("</body>\n</html>\n");
+    html
+}
+
+/// `zeroclaw delegations report --html <file>` — write the dashboard to disk.
+pub fn write_html_report(log_path: &Path, output: &Path) -> Result<()> {
+    let events = read_all_events(log_path)?;
+    if events.is_empty() {
+        println!("No delegation data found at: {}", log_path.display());
+        println!("Run ZeroClaw with a workflow that uses the `delegate` tool.");
+        return Ok(());
+    }
+    let generated_at = Utc::now().format("%Y-%m-%d %H:%M UTC").to_string();
+    let html = render_html_report(&events, &generated_at);
+    std::fs::write(output, html)?;
+    println!("Wrote delegation report to {}", output.display());
     Ok(())
 }
 
@@ -6948,7 +8522,7 @@ mod tests {
         let path = dir.join("zeroclaw_test_report_empty.jsonl");
         // Ensure file does not exist
         let _ = std::fs::remove_file(&path);
-        let result = print_summary(&path);
+        let result = print_summary(&path, false);
         assert!(result.is_ok());
     }
 
@@ -6974,11 +8548,40 @@ mod tests {
             .unwrap(),
         );
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
-        let result = print_runs(&path);
+        let result = print_runs(&path, false);
         let _ = std::fs::remove_file(&path);
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn print_reports_accessible_mode_succeeds() {
+        let dir = std::env::temp_dir();
+        let path = dir.join("zeroclaw_test_report_accessible.jsonl");
+        let mut lines = Vec::new();
+        lines.push(
+            serde_json::to_string(&make_start("run-test", "main", 0, "2026-01-01T10:00:00Z"))
+                .unwrap(),
+        );
+        lines.push(
+            serde_json::to_string(&make_end(
+                "run-test",
+                "main",
+                0,
+                "2026-01-01T10:00:05Z",
+                500,
+                0.001,
+                true,
+            ))
+            .unwrap(),
+        );
+        std::fs::write(&path, lines.join("\n") + "\n").unwrap();
+        assert!(print_summary(&path, true).is_ok());
+        assert!(print_runs(&path, true).is_ok());
+        assert!(print_tree(&path, None, true).is_ok());
+        assert!(print_stats(&path, None, true).is_ok());
+        let _ = std::fs::remove_file(&path);
+    }
+
     #[test]
     fn print_tree_defaults_to_most_recent_run() {
         let dir = std::env::temp_dir();
@@ -7006,7 +8609,7 @@ mod tests {
         );
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
         // print_tree with no run_id should pick run-recent (newest)
-        let result = print_tree(&path, None);
+        let result = print_tree(&path, None, false);
         let _ = std::fs::remove_file(&path);
         assert!(result.is_ok());
     }
@@ -7023,7 +8626,7 @@ mod tests {
         ))
         .unwrap();
         std::fs::write(&path, line + "\n").unwrap();
-        let result = print_tree(&path, Some("run-specific"));
+        let result = print_tree(&path, Some("run-specific"), false);
         let _ = std::fs::remove_file(&path);
         assert!(result.is_ok());
     }
@@ -7035,6 +8638,39 @@ mod tests {
         assert_eq!(fmt_duration(2500), "2.50s");
     }
 
+    #[test]
+    fn percentile_uses_nearest_rank_on_sorted_samples() {
+        let samples: Vec<u64> = (1..=100).collect();
+        assert_eq!(percentile(&samples, 50), 50);
+        assert_eq!(percentile(&samples, 90), 90);
+        assert_eq!(percentile(&samples, 99), 99);
+        // Small sample sets: ranks round up, never past the last element.
+        assert_eq!(percentile(&[10, 20, 30], 50), 20);
+        assert_eq!(percentile(&[10, 20, 30], 99), 30);
+        assert_eq!(percentile(&[42], 50), 42);
+    }
+
+    #[test]
+    fn percentile_returns_zero_for_empty_samples() {
+        assert_eq!(percentile(&[], 50), 0);
+        assert_eq!(percentile(&[], 99), 0);
+    }
+
+    #[test]
+    fn collect_agent_stats_collects_sorted_duration_samples() {
+        let events = vec![
+            make_start("run-a", "main", 0, "2026-01-01T10:00:00Z"),
+            make_end("run-a", "main", 0, "2026-01-01T10:00:05Z", 100, 0.001, true),
+            make_start("run-a", "main", 0, "2026-01-01T11:00:00Z"),
+            make_end("run-a", "main", 0, "2026-01-01T11:00:05Z", 200, 0.002, true),
+        ];
+        let stats = collect_agent_stats(&events);
+        let main = stats.iter().find(|s| s.agent_name == "main").unwrap();
+        // duration_ms from make_end is always 1000 (see fixture above)
+        assert_eq!(main.durations_ms, vec![1000, 1000]);
+        assert_eq!(percentile(&main.durations_ms, 99), 1000);
+    }
+
     #[test]
     fn collect_agent_stats_aggregates_by_agent_name() {
         let events = vec![
@@ -7129,7 +8765,7 @@ mod tests {
     fn print_stats_on_missing_log_succeeds() {
         let path = std::env::temp_dir().join("zeroclaw_test_stats_missing.jsonl");
         let _ = std::fs::remove_file(&path);
-        assert!(print_stats(&path, None).is_ok());
+        assert!(print_stats(&path, None, false).is_ok());
     }
 
     #[test]
@@ -7157,7 +8793,7 @@ mod tests {
                 .unwrap(),
         );
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
-        let result = print_stats(&path, Some("run-keep"));
+        let result = print_stats(&path, Some("run-keep"), false);
         let _ = std::fs::remove_file(&path);
         assert!(result.is_ok());
     }
@@ -7633,43 +9269,276 @@ mod tests {
                 "run-older",
                 "main",
                 0,
-                "2026-01-01T10:00:05Z",
-                1000,
-                0.003,
+                "2026-01-01T10:00:05Z",
+                1000,
+                0.003,
+                true,
+            ))
+            .unwrap(),
+        );
+        lines.push(
+            serde_json::to_string(&make_start("run-newer", "main", 0, "2026-01-02T10:00:00Z"))
+                .unwrap(),
+        );
+        lines.push(
+            serde_json::to_string(&make_end(
+                "run-newer",
+                "main",
+                0,
+                "2026-01-02T10:00:05Z",
+                2000,
+                0.006,
+                true,
+            ))
+            .unwrap(),
+        );
+        std::fs::write(&path, lines.join("\n") + "\n").unwrap();
+        let result = print_diff(&path, "run-older", None);
+        let _ = std::fs::remove_file(&path);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn print_diff_unknown_run_returns_error() {
+        let path = std::env::temp_dir().join("zeroclaw_test_diff_bad_run.jsonl");
+        let line =
+            serde_json::to_string(&make_start("run-real", "main", 0, "2026-01-01T10:00:00Z"))
+                .unwrap();
+        std::fs::write(&path, line + "\n").unwrap();
+        let result = print_diff(&path, "run-nonexistent", None);
+        let _ = std::fs::remove_file(&path);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn parse_time_bound_accepts_rfc3339_and_bare_date() {
+        let ts = parse_time_bound("2026-02-16T12:30:00Z").unwrap();
+        assert_eq!(ts.to_rfc3339(), "2026-02-16T12:30:00+00:00");
+
+        let midnight = parse_time_bound("2026-02-16").unwrap();
+        assert_eq!(midnight.to_rfc3339(), "2026-02-16T00:00:00+00:00");
+
+        assert!(parse_time_bound("last tuesday").is_err());
+        assert!(parse_time_bound("2026-13-99").is_err());
+    }
+
+    #[test]
+    fn parse_relative_duration_accepts_m_h_d_suffixes() {
+        assert_eq!(
+            parse_relative_duration("30m"),
+            Some(chrono::Duration::minutes(30))
+        );
+        assert_eq!(
+            parse_relative_duration("2h"),
+            Some(chrono::Duration::hours(2))
+        );
+        assert_eq!(
+            parse_relative_duration("7d"),
+            Some(chrono::Duration::days(7))
+        );
+
+        assert_eq!(parse_relative_duration("7w"), None);
+        assert_eq!(parse_relative_duration("0d"), None);
+        assert_eq!(parse_relative_duration("-1h"), None);
+        assert_eq!(parse_relative_duration("d"), None);
+        assert_eq!(parse_relative_duration(""), None);
+        assert_eq!(parse_relative_duration("2026-02-16"), None);
+    }
+
+    #[test]
+    fn time_window_parse_accepts_absolute_and_relative_bounds() {
+        let window = TimeWindow::parse(Some("2026-02-16"), Some("2026-02-23")).unwrap();
+        assert!(!window.is_unbounded());
+        assert!(window.contains(parse_time_bound("2026-02-16T12:00:00Z").unwrap()));
+        assert!(!window.contains(parse_time_bound("2026-02-23T00:00:00Z").unwrap()));
+        assert!(!window.contains(parse_time_bound("2026-02-15T23:59:59Z").unwrap()));
+
+        // Relative bound: 7d back from now must include "just now".
+        let window = TimeWindow::parse(Some("7d"), None).unwrap();
+        assert!(window.contains(Utc::now()));
+        assert!(!window.contains(Utc::now() - chrono::Duration::days(8)));
+
+        assert!(TimeWindow::parse(None, None).unwrap().is_unbounded());
+    }
+
+    #[test]
+    fn time_window_parse_rejects_inverted_bounds() {
+        assert!(TimeWindow::parse(Some("2026-02-23"), Some("2026-02-16")).is_err());
+        assert!(TimeWindow::parse(Some("not-a-time"), None).is_err());
+    }
+
+    #[test]
+    fn filter_by_tag_keeps_only_matching_events() {
+        let mut tagged = make_start("run-a", "researcher", 0, "2026-02-10T09:00:00Z");
+        tagged["tags"] = serde_json::json!(["project:foo", "batch"]);
+        let mut other = make_start("run-b", "researcher", 0, "2026-02-10T09:00:00Z");
+        other["tags"] = serde_json::json!(["project:bar"]);
+        let untagged = make_start("run-c", "researcher", 0, "2026-02-10T09:00:00Z");
+
+        let kept = filter_by_tag(vec![tagged, other, untagged], "project:foo");
+        assert_eq!(kept.len(), 1);
+        assert_eq!(kept[0]["run_id"], "run-a");
+    }
+
+    #[test]
+    fn print_tags_succeeds_with_and_without_tagged_events() {
+        let path = std::env::temp_dir().join("zeroclaw_test_print_tags.jsonl");
+        let mut start = make_start("run-a", "researcher", 0, "2026-02-10T09:00:00Z");
+        start["tags"] = serde_json::json!(["project:foo"]);
+        let mut end = make_end(
+            "run-a",
+            "researcher",
+            0,
+            "2026-02-10T09:01:00Z",
+            500,
+            0.01,
+            true,
+        );
+        end["tags"] = serde_json::json!(["project:foo"]);
+        let untagged = make_start("run-b", "researcher", 0, "2026-02-10T10:00:00Z");
+
+        let content = [start, end, untagged]
+            .iter()
+            .map(|e| serde_json::to_string(e).unwrap())
+            .collect::<Vec<_>>()
+            .join("\n");
+        std::fs::write(&path, content).unwrap();
+
+        assert!(print_tags(&path, None).is_ok());
+        assert!(print_tags(&path, Some("run-a")).is_ok());
+        assert!(print_tags(&path, Some("run-b")).is_ok());
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn print_channels_on_missing_log_succeeds() {
+        let path = std::env::temp_dir().join("zeroclaw_test_channels_missing.jsonl");
+        let _ = std::fs::remove_file(&path);
+        assert!(print_channels(&path, None).is_ok());
+    }
+
+    #[test]
+    fn print_channels_succeeds_with_and_without_channel_events() {
+        let path = std::env::temp_dir().join("zeroclaw_test_print_channels.jsonl");
+        let mut start = make_start("run-a", "researcher", 0, "2026-02-10T09:00:00Z");
+        start["channel"] = serde_json::json!("telegram");
+        let mut end = make_end(
+            "run-a",
+            "researcher",
+            0,
+            "2026-02-10T09:01:00Z",
+            500,
+            0.01,
+            true,
+        );
+        end["channel"] = serde_json::json!("telegram");
+        let unattributed = make_start("run-b", "researcher", 0, "2026-02-10T10:00:00Z");
+
+        let content = [start, end, unattributed]
+            .iter()
+            .map(|e| serde_json::to_string(e).unwrap())
+            .collect::<Vec<_>>()
+            .join("\n");
+        std::fs::write(&path, content).unwrap();
+
+        assert!(print_channels(&path, None).is_ok());
+        assert!(print_channels(&path, Some("run-a")).is_ok());
+        assert!(print_channels(&path, Some("run-b")).is_ok());
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn print_channels_aggregates_across_channels() {
+        let path = std::env::temp_dir().join("zeroclaw_test_channels_agg.jsonl");
+        let mut lines = Vec::new();
+        for (run, channel, tokens, cost) in [
+            ("run-a", "discord", 5000u64, 0.015),
+            ("run-b", "telegram", 100u64, 0.0001),
+        ] {
+            let mut start = make_start(run, "main", 0, "2026-02-10T09:00:00Z");
+            start["channel"] = serde_json::json!(channel);
+            let mut end = make_end(run, "main", 0, "2026-02-10T09:01:00Z", tokens, cost, true);
+            end["channel"] = serde_json::json!(channel);
+            lines.push(serde_json::to_string(&start).unwrap());
+            lines.push(serde_json::to_string(&end).unwrap());
+        }
+        std::fs::write(&path, lines.join("\n") + "\n").unwrap();
+        assert!(print_channels(&path, None).is_ok());
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn filter_by_window_scopes_events_and_drops_unstampable_lines() {
+        let events = vec![
+            make_start("run-a", "researcher", 0, "2026-02-10T09:00:00Z"),
+            make_start("run-b", "researcher", 0, "2026-02-17T09:00:00Z"),
+            serde_json::json!({"event_type": "DelegationStart", "run_id": "run-c"}),
+        ];
+
+        // Unbounded window keeps everything, including the stamp-less line.
+        let kept = filter_by_window(events.clone(), &TimeWindow::default());
+        assert_eq!(kept.len(), 3);
+
+        let window = TimeWindow::parse(Some("2026-02-16"), Some("2026-02-23")).unwrap();
+        let kept = filter_by_window(events, &window);
+        assert_eq!(kept.len(), 1);
+        assert_eq!(kept[0]["run_id"], "run-b");
+    }
+
+    #[test]
+    fn print_period_diff_on_empty_log_succeeds() {
+        let path = std::env::temp_dir().join("zeroclaw_test_period_diff_empty.jsonl");
+        let _ = std::fs::remove_file(&path);
+        let result = print_period_diff(&path, "2026-02-16", None);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn print_period_diff_two_windows_succeeds() {
+        let path = std::env::temp_dir().join("zeroclaw_test_period_diff_two.jsonl");
+        let events = vec![
+            // Period A: week starting Feb 9.
+            make_start("run-old", "researcher", 0, "2026-02-10T09:00:00Z"),
+            make_end(
+                "run-old",
+                "researcher",
+                0,
+                "2026-02-10T09:01:00Z",
+                2000,
+                0.02,
                 true,
-            ))
-            .unwrap(),
-        );
-        lines.push(
-            serde_json::to_string(&make_start("run-newer", "main", 0, "2026-01-02T10:00:00Z"))
-                .unwrap(),
-        );
-        lines.push(
-            serde_json::to_string(&make_end(
-                "run-newer",
-                "main",
+            ),
+            // Period B: week starting Feb 16.
+            make_start("run-new", "researcher", 0, "2026-02-18T09:00:00Z"),
+            make_end(
+                "run-new",
+                "researcher",
                 0,
-                "2026-01-02T10:00:05Z",
-                2000,
-                0.006,
+                "2026-02-18T09:01:00Z",
+                1000,
+                0.01,
                 true,
-            ))
-            .unwrap(),
-        );
-        std::fs::write(&path, lines.join("\n") + "\n").unwrap();
-        let result = print_diff(&path, "run-older", None);
+            ),
+        ];
+        let content: String = events
+            .iter()
+            .map(|e| serde_json::to_string(e).unwrap() + "\n")
+            .collect();
+        std::fs::write(&path, content).unwrap();
+        let result = print_period_diff(&path, "2026-02-16", Some("2026-02-23"));
         let _ = std::fs::remove_file(&path);
         assert!(result.is_ok());
     }
 
     #[test]
-    fn print_diff_unknown_run_returns_error() {
-        let path = std::env::temp_dir().join("zeroclaw_test_diff_bad_run.jsonl");
-        let line =
-            serde_json::to_string(&make_start("run-real", "main", 0, "2026-01-01T10:00:00Z"))
-                .unwrap();
+    fn print_period_diff_rejects_inverted_window() {
+        let path = std::env::temp_dir().join("zeroclaw_test_period_diff_inverted.jsonl");
+        let line = serde_json::to_string(&make_start("run-any", "main", 0, "2026-02-16T10:00:00Z"))
+            .unwrap();
         std::fs::write(&path, line + "\n").unwrap();
-        let result = print_diff(&path, "run-nonexistent", None);
+        let result = print_period_diff(&path, "2026-02-23", Some("2026-02-16"));
         let _ = std::fs::remove_file(&path);
         assert!(result.is_err());
     }
@@ -8599,6 +10468,96 @@ mod tests {
         let _ = std::fs::remove_file(&path);
     }
 
+    fn make_tool_end(run_id: &str, tool: &str, duration_ms: u64, success: bool, ts: &str) -> Value {
+        serde_json::json!({
+            "event_type": "ToolCallEnd",
+            "run_id": run_id,
+            "tool": tool,
+            "duration_ms": duration_ms,
+            "success": success,
+            "output_bytes": 512u64,
+            "timestamp": ts
+        })
+    }
+
+    #[test]
+    fn fmt_bytes_scales_units() {
+        assert_eq!(fmt_bytes(512), "512B");
+        assert_eq!(fmt_bytes(2048), "2.0KB");
+        assert_eq!(fmt_bytes(3 * 1024 * 1024), "3.0MB");
+    }
+
+    #[test]
+    fn print_tools_on_missing_log_succeeds() {
+        let path = std::env::temp_dir().join("zeroclaw_test_tools_missing.jsonl");
+        let _ = std::fs::remove_file(&path);
+        assert!(print_tools(&path, None).is_ok());
+    }
+
+    #[test]
+    fn print_tools_with_no_tool_events_reports_empty() {
+        let path = std::env::temp_dir().join("zeroclaw_test_tools_noevents.jsonl");
+        let lines =
+            vec![
+                serde_json::to_string(&make_start("run-a", "main", 0, "2026-01-01T10:00:00Z"))
+                    .unwrap(),
+            ];
+        std::fs::write(&path, lines.join("\n") + "\n").unwrap();
+        let result = print_tools(&path, None);
+        let _ = std::fs::remove_file(&path);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn print_tools_aggregates_per_tool() {
+        let path = std::env::temp_dir().join("zeroclaw_test_tools_agg.jsonl");
+        let events = [
+            make_tool_end("run-a", "shell", 300, true, "2026-01-01T10:00:01Z"),
+            make_tool_end("run-a", "shell", 700, false, "2026-01-01T10:00:02Z"),
+            make_tool_end("run-a", "file_read", 50, true, "2026-01-01T10:00:03Z"),
+        ];
+        let lines: Vec<String> = events
+            .iter()
+            .map(|e| serde_json::to_string(e).unwrap())
+            .collect();
+        std::fs::write(&path, lines.join("\n") + "\n").unwrap();
+        assert!(print_tools(&path, None).is_ok());
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn print_tools_filters_by_run() {
+        let path = std::env::temp_dir().join("zeroclaw_test_tools_run_filter.jsonl");
+        let events = [
+            make_tool_end("run-keep", "shell", 300, true, "2026-01-01T10:00:01Z"),
+            make_tool_end("run-skip", "browser", 900, true, "2026-01-01T11:00:01Z"),
+        ];
+        let lines: Vec<String> = events
+            .iter()
+            .map(|e| serde_json::to_string(e).unwrap())
+            .collect();
+        std::fs::write(&path, lines.join("\n") + "\n").unwrap();
+        assert!(print_tools(&path, Some("run-keep")).is_ok());
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn print_tools_slow_sorts_and_respects_limit() {
+        let path = std::env::temp_dir().join("zeroclaw_test_tools_slow.jsonl");
+        let events = [
+            make_tool_end("run-a", "fast_tool", 100, true, "2026-01-01T10:00:01Z"),
+            make_tool_end("run-a", "slow_tool", 5000, false, "2026-01-01T10:00:02Z"),
+            make_tool_end("run-a", "medium_tool", 800, true, "2026-01-01T10:00:03Z"),
+        ];
+        let lines: Vec<String> = events
+            .iter()
+            .map(|e| serde_json::to_string(e).unwrap())
+            .collect();
+        std::fs::write(&path, lines.join("\n") + "\n").unwrap();
+        assert!(print_tools_slow(&path, None, 2).is_ok());
+        let _ = std::fs::remove_file(&path);
+    }
+
     #[test]
     fn print_slow_filters_by_run() {
         let path = std::env::temp_dir().join("zeroclaw_test_slow_run_filter.jsonl");
@@ -9441,16 +11400,8 @@ mod tests {
         let mut lines = Vec::new();
         for ts in &["2026-01-01T10:00:02Z", "2026-01-01T10:00:01Z"] {
             lines.push(
-                serde_json::to_string(&make_end(
-                    "run-alpha",
-                    "research",
-                    1,
-                    ts,
-                    100,
-                    0.001,
-                    true,
-                ))
-                .unwrap(),
+                serde_json::to_string(&make_end("run-alpha", "research", 1, ts, 100, 0.001, true))
+                    .unwrap(),
             );
         }
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
@@ -9554,16 +11505,8 @@ mod tests {
         let mut lines = Vec::new();
         for ts in &["2026-01-01T10:00:02Z", "2026-01-01T10:00:01Z"] {
             lines.push(
-                serde_json::to_string(&make_end(
-                    "run-a",
-                    "research",
-                    0,
-                    ts,
-                    100,
-                    0.001,
-                    true,
-                ))
-                .unwrap(),
+                serde_json::to_string(&make_end("run-a", "research", 0, ts, 100, 0.001, true))
+                    .unwrap(),
             );
         }
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
@@ -9605,16 +11548,7 @@ mod tests {
             ("run-skip", "2026-01-02T10:00:01Z"),
         ] {
             lines.push(
-                serde_json::to_string(&make_end(
-                    run,
-                    "research",
-                    0,
-                    ts,
-                    200,
-                    0.001,
-                    true,
-                ))
-                .unwrap(),
+                serde_json::to_string(&make_end(run, "research", 0, ts, 200, 0.001, true)).unwrap(),
             );
         }
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
@@ -9705,8 +11639,7 @@ mod tests {
             ("run-skip", "2026-01-02T10:00:00Z"),
         ] {
             lines.push(
-                serde_json::to_string(&make_end(run, "research", 0, ts, 100, 0.001, true))
-                    .unwrap(),
+                serde_json::to_string(&make_end(run, "research", 0, ts, 100, 0.001, true)).unwrap(),
             );
         }
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
@@ -9797,8 +11730,7 @@ mod tests {
             ("run-skip", "2026-01-01T11:00:00Z"),
         ] {
             lines.push(
-                serde_json::to_string(&make_end(run, "research", 0, ts, 100, 0.001, true))
-                    .unwrap(),
+                serde_json::to_string(&make_end(run, "research", 0, ts, 100, 0.001, true)).unwrap(),
             );
         }
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
@@ -9889,8 +11821,7 @@ mod tests {
             ("run-skip", "2026-02-01T10:00:00Z"),
         ] {
             lines.push(
-                serde_json::to_string(&make_end(run, "research", 0, ts, 100, 0.001, true))
-                    .unwrap(),
+                serde_json::to_string(&make_end(run, "research", 0, ts, 100, 0.001, true)).unwrap(),
             );
         }
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
@@ -9981,8 +11912,7 @@ mod tests {
             ("run-skip", "2026-04-01T10:00:00Z"),
         ] {
             lines.push(
-                serde_json::to_string(&make_end(run, "research", 0, ts, 100, 0.001, true))
-                    .unwrap(),
+                serde_json::to_string(&make_end(run, "research", 0, ts, 100, 0.001, true)).unwrap(),
             );
         }
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
@@ -9993,16 +11923,14 @@ mod tests {
 
     #[test]
     fn print_agent_model_missing_log() {
-        let path =
-            std::path::PathBuf::from("/tmp/zeroclaw_no_such_file_agentmodel.jsonl");
+        let path = std::path::PathBuf::from("/tmp/zeroclaw_no_such_file_agentmodel.jsonl");
         let result = print_agent_model(&path, None);
         assert!(result.is_ok());
     }
 
     #[test]
     fn print_agent_model_empty_log() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_agent_model_empty.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_agent_model_empty.jsonl");
         std::fs::write(&path, "").unwrap();
         let result = print_agent_model(&path, None);
         let _ = std::fs::remove_file(&path);
@@ -10011,8 +11939,7 @@ mod tests {
 
     #[test]
     fn print_agent_model_no_ends() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_agent_model_noends.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_agent_model_noends.jsonl");
         let ev = serde_json::json!({
             "event_type": "DelegationStart",
             "run_id": "run-1",
@@ -10028,8 +11955,7 @@ mod tests {
 
     #[test]
     fn print_agent_model_groups_by_pair() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_agent_model_pairs.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_agent_model_pairs.jsonl");
         let mut lines = Vec::new();
         for (agent, model) in &[
             ("researcher", "claude-sonnet-4-6"),
@@ -10038,7 +11964,14 @@ mod tests {
         ] {
             lines.push(
                 serde_json::to_string(&make_end_m(
-                    "run-1", agent, model, 0, "2026-02-01T10:00:00Z", 100, 0.001, true,
+                    "run-1",
+                    agent,
+                    model,
+                    0,
+                    "2026-02-01T10:00:00Z",
+                    100,
+                    0.001,
+                    true,
                 ))
                 .unwrap(),
             );
@@ -10051,8 +11984,7 @@ mod tests {
 
     #[test]
     fn print_agent_model_tokens_desc() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_agent_model_tokdesc.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_agent_model_tokdesc.jsonl");
         let mut lines = Vec::new();
         for (agent, model, tokens) in &[
             ("coder", "claude-opus-4-6", 500u64),
@@ -10060,7 +11992,13 @@ mod tests {
         ] {
             lines.push(
                 serde_json::to_string(&make_end_m(
-                    "run-1", agent, model, 0, "2026-02-01T10:00:00Z", *tokens, 0.001,
+                    "run-1",
+                    agent,
+                    model,
+                    0,
+                    "2026-02-01T10:00:00Z",
+                    *tokens,
+                    0.001,
                     true,
                 ))
                 .unwrap(),
@@ -10074,8 +12012,7 @@ mod tests {
 
     #[test]
     fn print_agent_model_filters_by_run() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_agent_model_runfilter.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_agent_model_runfilter.jsonl");
         let mut lines = Vec::new();
         for (run, agent, model) in &[
             ("run-keep", "researcher", "claude-sonnet-4-6"),
@@ -10083,7 +12020,14 @@ mod tests {
         ] {
             lines.push(
                 serde_json::to_string(&make_end_m(
-                    run, agent, model, 0, "2026-02-01T10:00:00Z", 100, 0.001, true,
+                    run,
+                    agent,
+                    model,
+                    0,
+                    "2026-02-01T10:00:00Z",
+                    100,
+                    0.001,
+                    true,
                 ))
                 .unwrap(),
             );
@@ -10096,16 +12040,14 @@ mod tests {
 
     #[test]
     fn print_provider_model_missing_log() {
-        let path =
-            std::path::PathBuf::from("/tmp/zeroclaw_no_such_file_provmodel.jsonl");
+        let path = std::path::PathBuf::from("/tmp/zeroclaw_no_such_file_provmodel.jsonl");
         let result = print_provider_model(&path, None);
         assert!(result.is_ok());
     }
 
     #[test]
     fn print_provider_model_empty_log() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_prov_model_empty.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_prov_model_empty.jsonl");
         std::fs::write(&path, "").unwrap();
         let result = print_provider_model(&path, None);
         let _ = std::fs::remove_file(&path);
@@ -10114,8 +12056,7 @@ mod tests {
 
     #[test]
     fn print_provider_model_no_ends() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_prov_model_noends.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_prov_model_noends.jsonl");
         let ev = serde_json::json!({
             "event_type": "DelegationStart",
             "run_id": "run-1",
@@ -10132,8 +12073,7 @@ mod tests {
 
     #[test]
     fn print_provider_model_groups_by_pair() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_prov_model_pairs.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_prov_model_pairs.jsonl");
         let mut lines = Vec::new();
         for (provider, model) in &[
             ("anthropic", "claude-sonnet-4-6"),
@@ -10142,8 +12082,15 @@ mod tests {
         ] {
             lines.push(
                 serde_json::to_string(&make_end_p(
-                    "run-1", "researcher", provider, model, 0,
-                    "2026-02-01T10:00:00Z", 100, 0.001, true,
+                    "run-1",
+                    "researcher",
+                    provider,
+                    model,
+                    0,
+                    "2026-02-01T10:00:00Z",
+                    100,
+                    0.001,
+                    true,
                 ))
                 .unwrap(),
             );
@@ -10156,8 +12103,7 @@ mod tests {
 
     #[test]
     fn print_provider_model_tokens_desc() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_prov_model_tokdesc.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_prov_model_tokdesc.jsonl");
         let mut lines = Vec::new();
         for (provider, model, tokens) in &[
             ("openai", "gpt-4o", 500u64),
@@ -10165,8 +12111,15 @@ mod tests {
         ] {
             lines.push(
                 serde_json::to_string(&make_end_p(
-                    "run-1", "researcher", provider, model, 0,
-                    "2026-02-01T10:00:00Z", *tokens, 0.001, true,
+                    "run-1",
+                    "researcher",
+                    provider,
+                    model,
+                    0,
+                    "2026-02-01T10:00:00Z",
+                    *tokens,
+                    0.001,
+                    true,
                 ))
                 .unwrap(),
             );
@@ -10179,8 +12132,7 @@ mod tests {
 
     #[test]
     fn print_provider_model_filters_by_run() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_prov_model_runfilter.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_prov_model_runfilter.jsonl");
         let mut lines = Vec::new();
         for (run, provider, model) in &[
             ("run-keep", "anthropic", "claude-sonnet-4-6"),
@@ -10188,8 +12140,15 @@ mod tests {
         ] {
             lines.push(
                 serde_json::to_string(&make_end_p(
-                    run, "researcher", provider, model, 0,
-                    "2026-02-01T10:00:00Z", 100, 0.001, true,
+                    run,
+                    "researcher",
+                    provider,
+                    model,
+                    0,
+                    "2026-02-01T10:00:00Z",
+                    100,
+                    0.001,
+                    true,
                 ))
                 .unwrap(),
             );
@@ -10202,16 +12161,14 @@ mod tests {
 
     #[test]
     fn print_agent_provider_missing_log() {
-        let path =
-            std::path::PathBuf::from("/tmp/zeroclaw_no_such_file_agentprov.jsonl");
+        let path = std::path::PathBuf::from("/tmp/zeroclaw_no_such_file_agentprov.jsonl");
         let result = print_agent_provider(&path, None);
         assert!(result.is_ok());
     }
 
     #[test]
     fn print_agent_provider_empty_log() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_agent_prov_empty.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_agent_prov_empty.jsonl");
         std::fs::write(&path, "").unwrap();
         let result = print_agent_provider(&path, None);
         let _ = std::fs::remove_file(&path);
@@ -10220,8 +12177,7 @@ mod tests {
 
     #[test]
     fn print_agent_provider_no_ends() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_agent_prov_noends.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_agent_prov_noends.jsonl");
         let ev = serde_json::json!({
             "event_type": "DelegationStart",
             "run_id": "run-1",
@@ -10237,8 +12193,7 @@ mod tests {
 
     #[test]
     fn print_agent_provider_groups_by_pair() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_agent_prov_pairs.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_agent_prov_pairs.jsonl");
         let mut lines = Vec::new();
         for (agent, provider) in &[
             ("researcher", "anthropic"),
@@ -10247,8 +12202,15 @@ mod tests {
         ] {
             lines.push(
                 serde_json::to_string(&make_end_p(
-                    "run-1", agent, provider, "claude-sonnet-4-6", 0,
-                    "2026-02-01T10:00:00Z", 100, 0.001, true,
+                    "run-1",
+                    agent,
+                    provider,
+                    "claude-sonnet-4-6",
+                    0,
+                    "2026-02-01T10:00:00Z",
+                    100,
+                    0.001,
+                    true,
                 ))
                 .unwrap(),
             );
@@ -10261,8 +12223,7 @@ mod tests {
 
     #[test]
     fn print_agent_provider_tokens_desc() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_agent_prov_tokdesc.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_agent_prov_tokdesc.jsonl");
         let mut lines = Vec::new();
         for (agent, provider, tokens) in &[
             ("coder", "openai", 500u64),
@@ -10270,8 +12231,15 @@ mod tests {
         ] {
             lines.push(
                 serde_json::to_string(&make_end_p(
-                    "run-1", agent, provider, "claude-sonnet-4-6", 0,
-                    "2026-02-01T10:00:00Z", *tokens, 0.001, true,
+                    "run-1",
+                    agent,
+                    provider,
+                    "claude-sonnet-4-6",
+                    0,
+                    "2026-02-01T10:00:00Z",
+                    *tokens,
+                    0.001,
+                    true,
                 ))
                 .unwrap(),
             );
@@ -10284,8 +12252,7 @@ mod tests {
 
     #[test]
     fn print_agent_provider_filters_by_run() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_agent_prov_runfilter.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_agent_prov_runfilter.jsonl");
         let mut lines = Vec::new();
         for (run, agent, provider) in &[
             ("run-keep", "researcher", "anthropic"),
@@ -10293,8 +12260,15 @@ mod tests {
         ] {
             lines.push(
                 serde_json::to_string(&make_end_p(
-                    run, agent, provider, "claude-sonnet-4-6", 0,
-                    "2026-02-01T10:00:00Z", 100, 0.001, true,
+                    run,
+                    agent,
+                    provider,
+                    "claude-sonnet-4-6",
+                    0,
+                    "2026-02-01T10:00:00Z",
+                    100,
+                    0.001,
+                    true,
                 ))
                 .unwrap(),
             );
@@ -10307,16 +12281,14 @@ mod tests {
 
     #[test]
     fn print_duration_bucket_missing_log() {
-        let path =
-            std::path::PathBuf::from("/tmp/zeroclaw_no_such_file_durbucket.jsonl");
+        let path = std::path::PathBuf::from("/tmp/zeroclaw_no_such_file_durbucket.jsonl");
         let result = print_duration_bucket(&path, None);
         assert!(result.is_ok());
     }
 
     #[test]
     fn print_duration_bucket_empty_log() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_dur_bucket_empty.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_dur_bucket_empty.jsonl");
         std::fs::write(&path, "").unwrap();
         let result = print_duration_bucket(&path, None);
         let _ = std::fs::remove_file(&path);
@@ -10325,8 +12297,7 @@ mod tests {
 
     #[test]
     fn print_duration_bucket_no_ends() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_dur_bucket_noends.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_dur_bucket_noends.jsonl");
         let ev = serde_json::json!({
             "event_type": "DelegationStart",
             "run_id": "run-1",
@@ -10341,8 +12312,7 @@ mod tests {
 
     #[test]
     fn print_duration_bucket_groups_by_bucket() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_dur_bucket_groups.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_dur_bucket_groups.jsonl");
         let mut lines = Vec::new();
         for duration_ms in &[200u64, 1000u64, 5000u64] {
             let ev = serde_json::json!({
@@ -10365,8 +12335,7 @@ mod tests {
 
     #[test]
     fn print_duration_bucket_fastest_first() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_dur_bucket_order.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_dur_bucket_order.jsonl");
         let mut lines = Vec::new();
         for duration_ms in &[70000u64, 100u64] {
             let ev = serde_json::json!({
@@ -10389,8 +12358,7 @@ mod tests {
 
     #[test]
     fn print_duration_bucket_filters_by_run() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_dur_bucket_runfilter.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_dur_bucket_runfilter.jsonl");
         let mut lines = Vec::new();
         for (run, duration_ms) in &[("run-keep", 500u64), ("run-skip", 1000u64)] {
             let ev = serde_json::json!({
@@ -10413,16 +12381,14 @@ mod tests {
 
     #[test]
     fn print_token_bucket_missing_log() {
-        let path =
-            std::path::PathBuf::from("/tmp/zeroclaw_no_such_file_tokbucket.jsonl");
+        let path = std::path::PathBuf::from("/tmp/zeroclaw_no_such_file_tokbucket.jsonl");
         let result = print_token_bucket(&path, None);
         assert!(result.is_ok());
     }
 
     #[test]
     fn print_token_bucket_empty_log() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_tok_bucket_empty.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_tok_bucket_empty.jsonl");
         std::fs::write(&path, "").unwrap();
         let result = print_token_bucket(&path, None);
         let _ = std::fs::remove_file(&path);
@@ -10431,8 +12397,7 @@ mod tests {
 
     #[test]
     fn print_token_bucket_no_ends() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_tok_bucket_noends.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_tok_bucket_noends.jsonl");
         let ev = serde_json::json!({
             "event_type": "DelegationStart",
             "run_id": "run-1",
@@ -10447,8 +12412,7 @@ mod tests {
 
     #[test]
     fn print_token_bucket_groups_by_bucket() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_tok_bucket_groups.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_tok_bucket_groups.jsonl");
         let mut lines = Vec::new();
         for tokens_used in &[50u64, 500u64, 5000u64] {
             let ev = serde_json::json!({
@@ -10471,8 +12435,7 @@ mod tests {
 
     #[test]
     fn print_token_bucket_smallest_first() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_tok_bucket_order.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_tok_bucket_order.jsonl");
         let mut lines = Vec::new();
         for tokens_used in &[200_000u64, 30u64] {
             let ev = serde_json::json!({
@@ -10495,8 +12458,7 @@ mod tests {
 
     #[test]
     fn print_token_bucket_filters_by_run() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_tok_bucket_runfilter.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_tok_bucket_runfilter.jsonl");
         let mut lines = Vec::new();
         for (run, tokens_used) in &[("run-keep", 500u64), ("run-skip", 50000u64)] {
             let ev = serde_json::json!({
@@ -10519,16 +12481,14 @@ mod tests {
 
     #[test]
     fn print_cost_bucket_missing_log() {
-        let path =
-            std::path::PathBuf::from("/tmp/zeroclaw_no_such_file_costbucket.jsonl");
+        let path = std::path::PathBuf::from("/tmp/zeroclaw_no_such_file_costbucket.jsonl");
         let result = print_cost_bucket(&path, None);
         assert!(result.is_ok());
     }
 
     #[test]
     fn print_cost_bucket_empty_log() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_cost_bucket_empty.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_cost_bucket_empty.jsonl");
         std::fs::write(&path, "").unwrap();
         let result = print_cost_bucket(&path, None);
         let _ = std::fs::remove_file(&path);
@@ -10537,8 +12497,7 @@ mod tests {
 
     #[test]
     fn print_cost_bucket_no_ends() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_cost_bucket_noends.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_cost_bucket_noends.jsonl");
         let ev = serde_json::json!({
             "event_type": "DelegationStart",
             "run_id": "run-1",
@@ -10553,8 +12512,7 @@ mod tests {
 
     #[test]
     fn print_cost_bucket_groups_by_bucket() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_cost_bucket_groups.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_cost_bucket_groups.jsonl");
         let mut lines = Vec::new();
         for cost_usd in &[0.0005f64, 0.005f64, 0.05f64] {
             let ev = serde_json::json!({
@@ -10577,8 +12535,7 @@ mod tests {
 
     #[test]
     fn print_cost_bucket_cheapest_first() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_cost_bucket_order.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_cost_bucket_order.jsonl");
         let mut lines = Vec::new();
         for cost_usd in &[2.50f64, 0.0001f64] {
             let ev = serde_json::json!({
@@ -10601,8 +12558,7 @@ mod tests {
 
     #[test]
     fn print_cost_bucket_filters_by_run() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_cost_bucket_runfilter.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_cost_bucket_runfilter.jsonl");
         let mut lines = Vec::new();
         for (run, cost_usd) in &[("run-keep", 0.005f64), ("run-skip", 0.50f64)] {
             let ev = serde_json::json!({
@@ -10625,16 +12581,14 @@ mod tests {
 
     #[test]
     fn print_weekday_missing_log() {
-        let path =
-            std::path::PathBuf::from("/tmp/zeroclaw_no_such_file_weekday.jsonl");
+        let path = std::path::PathBuf::from("/tmp/zeroclaw_no_such_file_weekday.jsonl");
         let result = print_weekday(&path, None);
         assert!(result.is_ok());
     }
 
     #[test]
     fn print_weekday_empty_log() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_weekday_empty.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_weekday_empty.jsonl");
         std::fs::write(&path, "").unwrap();
         let result = print_weekday(&path, None);
         let _ = std::fs::remove_file(&path);
@@ -10643,8 +12597,7 @@ mod tests {
 
     #[test]
     fn print_weekday_no_ends() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_weekday_noends.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_weekday_noends.jsonl");
         let ev = serde_json::json!({
             "event_type": "DelegationStart",
             "run_id": "run-1",
@@ -10659,8 +12612,7 @@ mod tests {
 
     #[test]
     fn print_weekday_groups_by_day() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_weekday_groups.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_weekday_groups.jsonl");
         let mut lines = Vec::new();
         // 2026-02-23 = Monday, 2026-02-25 = Wednesday
         for ts in &["2026-02-23T10:00:00Z", "2026-02-25T10:00:00Z"] {
@@ -10684,8 +12636,7 @@ mod tests {
 
     #[test]
     fn print_weekday_mon_first() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_weekday_order.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_weekday_order.jsonl");
         let mut lines = Vec::new();
         // 2026-02-22 = Sunday, 2026-02-23 = Monday
         for ts in &["2026-02-22T10:00:00Z", "2026-02-23T10:00:00Z"] {
@@ -10709,8 +12660,7 @@ mod tests {
 
     #[test]
     fn print_weekday_filters_by_run() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_weekday_runfilter.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_weekday_runfilter.jsonl");
         let mut lines = Vec::new();
         for (run, ts) in &[
             ("run-keep", "2026-02-23T10:00:00Z"),
@@ -10738,16 +12688,14 @@ mod tests {
 
     #[test]
     fn print_weekly_missing_log() {
-        let path =
-            std::path::PathBuf::from("/tmp/zeroclaw_no_such_file_weekly.jsonl");
+        let path = std::path::PathBuf::from("/tmp/zeroclaw_no_such_file_weekly.jsonl");
         let result = print_weekly(&path, None);
         assert!(result.is_ok());
     }
 
     #[test]
     fn print_weekly_empty_log() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_weekly_empty.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_weekly_empty.jsonl");
         std::fs::write(&path, "").unwrap();
         let result = print_weekly(&path, None);
         let _ = std::fs::remove_file(&path);
@@ -10756,8 +12704,7 @@ mod tests {
 
     #[test]
     fn print_weekly_no_ends() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_weekly_noends.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_weekly_noends.jsonl");
         let ev = serde_json::json!({
             "event_type": "DelegationStart",
             "run_id": "run-1",
@@ -10772,8 +12719,7 @@ mod tests {
 
     #[test]
     fn print_weekly_groups_by_week() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_weekly_groups.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_weekly_groups.jsonl");
         let mut lines = Vec::new();
         // 2026-01-05 = 2026-W02, 2026-01-12 = 2026-W03
         for ts in &["2026-01-05T10:00:00Z", "2026-01-12T10:00:00Z"] {
@@ -10797,8 +12743,7 @@ mod tests {
 
     #[test]
     fn print_weekly_same_week_aggregated() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_weekly_same_week.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_weekly_same_week.jsonl");
         let mut lines = Vec::new();
         // 2026-02-23 (Mon) and 2026-02-27 (Fri) are both in 2026-W09
         for ts in &["2026-02-23T10:00:00Z", "2026-02-27T10:00:00Z"] {
@@ -10822,8 +12767,7 @@ mod tests {
 
     #[test]
     fn print_weekly_filters_by_run() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_weekly_runfilter.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_weekly_runfilter.jsonl");
         let mut lines = Vec::new();
         for (run, ts) in &[
             ("run-keep", "2026-02-09T10:00:00Z"),
@@ -10851,16 +12795,14 @@ mod tests {
 
     #[test]
     fn print_depth_bucket_missing_log() {
-        let path =
-            std::path::PathBuf::from("/tmp/zeroclaw_no_such_file_depth_bucket.jsonl");
+        let path = std::path::PathBuf::from("/tmp/zeroclaw_no_such_file_depth_bucket.jsonl");
         let result = print_depth_bucket(&path, None);
         assert!(result.is_ok());
     }
 
     #[test]
     fn print_depth_bucket_empty_log() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_depth_bucket_empty.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_depth_bucket_empty.jsonl");
         std::fs::write(&path, "").unwrap();
         let result = print_depth_bucket(&path, None);
         let _ = std::fs::remove_file(&path);
@@ -10869,8 +12811,7 @@ mod tests {
 
     #[test]
     fn print_depth_bucket_no_ends() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_depth_bucket_noends.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_depth_bucket_noends.jsonl");
         let ev = serde_json::json!({
             "event_type": "DelegationStart",
             "run_id": "run-1",
@@ -10886,8 +12827,7 @@ mod tests {
 
     #[test]
     fn print_depth_bucket_groups_by_depth() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_depth_bucket_groups.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_depth_bucket_groups.jsonl");
         let mut lines = Vec::new();
         for depth in &[0u32, 1, 2, 3, 5] {
             let ev = serde_json::json!({
@@ -10911,8 +12851,7 @@ mod tests {
 
     #[test]
     fn print_depth_bucket_deep_goes_to_last_bucket() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_depth_bucket_deep.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_depth_bucket_deep.jsonl");
         let mut lines = Vec::new();
         // depths 4, 7, 10 should all map to bucket index 4 (very deep)
         for depth in &[4u32, 7, 10] {
@@ -10937,8 +12876,7 @@ mod tests {
 
     #[test]
     fn print_depth_bucket_filters_by_run() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_depth_bucket_runfilter.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_depth_bucket_runfilter.jsonl");
         let mut lines = Vec::new();
         for (run, depth) in &[("run-keep", 0u32), ("run-skip", 2u32)] {
             let ev = serde_json::json!({
@@ -10964,16 +12902,14 @@ mod tests {
 
     #[test]
     fn print_model_tier_missing_log() {
-        let path =
-            std::path::PathBuf::from("/tmp/zeroclaw_no_such_file_model_tier.jsonl");
+        let path = std::path::PathBuf::from("/tmp/zeroclaw_no_such_file_model_tier.jsonl");
         let result = print_model_tier(&path, None);
         assert!(result.is_ok());
     }
 
     #[test]
     fn print_model_tier_empty_log() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_model_tier_empty.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_model_tier_empty.jsonl");
         std::fs::write(&path, "").unwrap();
         let result = print_model_tier(&path, None);
         let _ = std::fs::remove_file(&path);
@@ -10982,8 +12918,7 @@ mod tests {
 
     #[test]
     fn print_model_tier_no_ends() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_model_tier_noends.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_model_tier_noends.jsonl");
         let ev = serde_json::json!({
             "event_type": "DelegationStart",
             "run_id": "run-1",
@@ -10999,10 +12934,14 @@ mod tests {
 
     #[test]
     fn print_model_tier_groups_by_family() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_model_tier_groups.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_model_tier_groups.jsonl");
         let mut lines = Vec::new();
-        for model in &["claude-haiku-4-5", "claude-sonnet-4-6", "claude-opus-4-6", "gpt-4o"] {
+        for model in &[
+            "claude-haiku-4-5",
+            "claude-sonnet-4-6",
+            "claude-opus-4-6",
+            "gpt-4o",
+        ] {
             let ev = serde_json::json!({
                 "event_type": "DelegationEnd",
                 "run_id": "run-1",
@@ -11025,8 +12964,7 @@ mod tests {
 
     #[test]
     fn print_model_tier_case_insensitive() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_model_tier_case.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_model_tier_case.jsonl");
         let mut lines = Vec::new();
         for model in &["Claude-Sonnet-4", "CLAUDE-HAIKU-3"] {
             let ev = serde_json::json!({
@@ -11051,8 +12989,7 @@ mod tests {
 
     #[test]
     fn print_model_tier_filters_by_run() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_model_tier_runfilter.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_model_tier_runfilter.jsonl");
         let mut lines = Vec::new();
         for (run, model) in &[
             ("run-keep", "claude-sonnet-4-6"),
@@ -11106,21 +13043,19 @@ mod tests {
 
     #[test]
     fn print_provider_tier_all_providers() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_provider_tier_all.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_provider_tier_all.jsonl");
         let lines = vec![
             make_provider_tier_event(
-                "run1", "anthropic", 200, 0.002, true, "2026-02-01T10:00:00Z",
-            ),
-            make_provider_tier_event(
-                "run1", "openai", 300, 0.003, true, "2026-02-01T11:00:00Z",
-            ),
-            make_provider_tier_event(
-                "run1", "google", 150, 0.001, false, "2026-02-01T12:00:00Z",
-            ),
-            make_provider_tier_event(
-                "run1", "bedrock", 100, 0.001, true, "2026-02-01T13:00:00Z",
+                "run1",
+                "anthropic",
+                200,
+                0.002,
+                true,
+                "2026-02-01T10:00:00Z",
             ),
+            make_provider_tier_event("run1", "openai", 300, 0.003, true, "2026-02-01T11:00:00Z"),
+            make_provider_tier_event("run1", "google", 150, 0.001, false, "2026-02-01T12:00:00Z"),
+            make_provider_tier_event("run1", "bedrock", 100, 0.001, true, "2026-02-01T13:00:00Z"),
         ];
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
         let result = print_provider_tier(&path, None);
@@ -11130,8 +13065,7 @@ mod tests {
 
     #[test]
     fn print_provider_tier_empty() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_provider_tier_empty.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_provider_tier_empty.jsonl");
         std::fs::write(&path, "").unwrap();
         let result = print_provider_tier(&path, None);
         let _ = std::fs::remove_file(&path);
@@ -11140,17 +13074,24 @@ mod tests {
 
     #[test]
     fn print_provider_tier_case_insensitive() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_provider_tier_case.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_provider_tier_case.jsonl");
         let lines = vec![
             make_provider_tier_event(
-                "run1", "Anthropic", 200, 0.002, true, "2026-02-01T10:00:00Z",
-            ),
-            make_provider_tier_event(
-                "run1", "OPENAI", 300, 0.003, true, "2026-02-01T11:00:00Z",
+                "run1",
+                "Anthropic",
+                200,
+                0.002,
+                true,
+                "2026-02-01T10:00:00Z",
             ),
+            make_provider_tier_event("run1", "OPENAI", 300, 0.003, true, "2026-02-01T11:00:00Z"),
             make_provider_tier_event(
-                "run1", "Google-Vertex", 150, 0.001, true, "2026-02-01T12:00:00Z",
+                "run1",
+                "Google-Vertex",
+                150,
+                0.001,
+                true,
+                "2026-02-01T12:00:00Z",
             ),
         ];
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
@@ -11161,17 +13102,31 @@ mod tests {
 
     #[test]
     fn print_provider_tier_aggregates_costs() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_provider_tier_agg.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_provider_tier_agg.jsonl");
         let lines = vec![
             make_provider_tier_event(
-                "run1", "anthropic", 100, 0.001, true, "2026-02-01T10:00:00Z",
+                "run1",
+                "anthropic",
+                100,
+                0.001,
+                true,
+                "2026-02-01T10:00:00Z",
             ),
             make_provider_tier_event(
-                "run1", "anthropic", 200, 0.002, false, "2026-02-01T11:00:00Z",
+                "run1",
+                "anthropic",
+                200,
+                0.002,
+                false,
+                "2026-02-01T11:00:00Z",
             ),
             make_provider_tier_event(
-                "run1", "anthropic", 300, 0.003, true, "2026-02-01T12:00:00Z",
+                "run1",
+                "anthropic",
+                300,
+                0.003,
+                true,
+                "2026-02-01T12:00:00Z",
             ),
         ];
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
@@ -11182,8 +13137,7 @@ mod tests {
 
     #[test]
     fn print_provider_tier_only_delegation_end() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_provider_tier_evtype.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_provider_tier_evtype.jsonl");
         let start_ev = serde_json::to_string(&serde_json::json!({
             "event_type": "DelegationStart",
             "run_id": "run1",
@@ -11193,9 +13147,8 @@ mod tests {
             "timestamp": "2026-02-01T10:00:00Z",
         }))
         .unwrap();
-        let end_ev = make_provider_tier_event(
-            "run1", "openai", 400, 0.004, true, "2026-02-01T10:01:00Z",
-        );
+        let end_ev =
+            make_provider_tier_event("run1", "openai", 400, 0.004, true, "2026-02-01T10:01:00Z");
         std::fs::write(&path, format!("{start_ev}\n{end_ev}\n")).unwrap();
         let result = print_provider_tier(&path, None);
         let _ = std::fs::remove_file(&path);
@@ -11204,14 +13157,23 @@ mod tests {
 
     #[test]
     fn print_provider_tier_filters_by_run() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_provider_tier_runfilter.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_provider_tier_runfilter.jsonl");
         let lines = vec![
             make_provider_tier_event(
-                "run-keep", "anthropic", 500, 0.005, true, "2026-02-01T10:00:00Z",
+                "run-keep",
+                "anthropic",
+                500,
+                0.005,
+                true,
+                "2026-02-01T10:00:00Z",
             ),
             make_provider_tier_event(
-                "run-skip", "openai", 500, 0.005, true, "2026-02-01T11:00:00Z",
+                "run-skip",
+                "openai",
+                500,
+                0.005,
+                true,
+                "2026-02-01T11:00:00Z",
             ),
         ];
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
@@ -11222,13 +13184,7 @@ mod tests {
 
     // ── Phase 83: print_time_of_day ──────────────────────────────────────────
 
-    fn make_tod_event(
-        run_id: &str,
-        tokens: u64,
-        cost: f64,
-        success: bool,
-        ts: &str,
-    ) -> String {
+    fn make_tod_event(run_id: &str, tokens: u64, cost: f64, success: bool, ts: &str) -> String {
         serde_json::to_string(&serde_json::json!({
             "event_type": "DelegationEnd",
             "run_id": run_id,
@@ -11247,8 +13203,7 @@ mod tests {
 
     #[test]
     fn print_time_of_day_all_periods() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_tod_all.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_tod_all.jsonl");
         let lines = vec![
             // night: 03:00
             make_tod_event("run1", 100, 0.001, true, "2026-02-09T03:00:00Z"),
@@ -11267,8 +13222,7 @@ mod tests {
 
     #[test]
     fn print_time_of_day_empty_log() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_tod_empty.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_tod_empty.jsonl");
         std::fs::write(&path, "").unwrap();
         let result = print_time_of_day(&path, None);
         let _ = std::fs::remove_file(&path);
@@ -11277,8 +13231,7 @@ mod tests {
 
     #[test]
     fn print_time_of_day_missing_log() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_tod_missing_XXXX.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_tod_missing_XXXX.jsonl");
         let _ = std::fs::remove_file(&path);
         let result = print_time_of_day(&path, None);
         assert!(result.is_ok());
@@ -11286,8 +13239,7 @@ mod tests {
 
     #[test]
     fn print_time_of_day_only_delegation_end() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_tod_evtype.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_tod_evtype.jsonl");
         let start_ev = serde_json::to_string(&serde_json::json!({
             "event_type": "DelegationStart",
             "run_id": "run1",
@@ -11306,8 +13258,7 @@ mod tests {
 
     #[test]
     fn print_time_of_day_boundary_hours() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_tod_boundary.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_tod_boundary.jsonl");
         let lines = vec![
             // boundary of night (00:00) → night bucket
             make_tod_event("run1", 100, 0.001, true, "2026-02-09T00:00:00Z"),
@@ -11328,8 +13279,7 @@ mod tests {
 
     #[test]
     fn print_time_of_day_filters_by_run() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_tod_runfilter.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_tod_runfilter.jsonl");
         let lines = vec![
             make_tod_event("run-keep", 500, 0.005, true, "2026-02-09T09:00:00Z"),
             make_tod_event("run-skip", 500, 0.005, true, "2026-02-09T15:00:00Z"),
@@ -11342,13 +13292,7 @@ mod tests {
 
     // ── Phase 85: print_day_of_month ─────────────────────────────────────────
 
-    fn make_dom_event(
-        run_id: &str,
-        tokens: u64,
-        cost: f64,
-        success: bool,
-        ts: &str,
-    ) -> String {
+    fn make_dom_event(run_id: &str, tokens: u64, cost: f64, success: bool, ts: &str) -> String {
         serde_json::to_string(&serde_json::json!({
             "event_type": "DelegationEnd",
             "run_id": run_id,
@@ -11367,8 +13311,7 @@ mod tests {
 
     #[test]
     fn print_day_of_month_multiple_days() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_dom_multi.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_dom_multi.jsonl");
         let lines = vec![
             // day 1
             make_dom_event("run1", 100, 0.001, true, "2026-02-01T10:00:00Z"),
@@ -11387,8 +13330,7 @@ mod tests {
 
     #[test]
     fn print_day_of_month_empty_log() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_dom_empty.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_dom_empty.jsonl");
         std::fs::write(&path, "").unwrap();
         let result = print_day_of_month(&path, None);
         let _ = std::fs::remove_file(&path);
@@ -11397,8 +13339,7 @@ mod tests {
 
     #[test]
     fn print_day_of_month_missing_log() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_dom_missing_XXXX.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_dom_missing_XXXX.jsonl");
         let _ = std::fs::remove_file(&path);
         let result = print_day_of_month(&path, None);
         assert!(result.is_ok());
@@ -11406,8 +13347,7 @@ mod tests {
 
     #[test]
     fn print_day_of_month_only_delegation_end() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_dom_evtype.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_dom_evtype.jsonl");
         let start_ev = serde_json::to_string(&serde_json::json!({
             "event_type": "DelegationStart",
             "run_id": "run1",
@@ -11427,8 +13367,7 @@ mod tests {
     #[test]
     fn print_day_of_month_sorted_numerically() {
         // Events on day 28, 1, 5 — BTreeMap ensures output in order 1, 5, 28
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_dom_sort.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_dom_sort.jsonl");
         let lines = vec![
             make_dom_event("run1", 100, 0.001, true, "2026-02-28T10:00:00Z"),
             make_dom_event("run1", 100, 0.001, true, "2026-02-01T10:00:00Z"),
@@ -11442,8 +13381,7 @@ mod tests {
 
     #[test]
     fn print_day_of_month_filters_by_run() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_dom_runfilter.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_dom_runfilter.jsonl");
         let lines = vec![
             make_dom_event("run-keep", 500, 0.005, true, "2026-02-10T09:00:00Z"),
             make_dom_event("run-skip", 500, 0.005, true, "2026-02-20T15:00:00Z"),
@@ -11456,13 +13394,7 @@ mod tests {
 
     // ── Phase 87: print_token_efficiency ─────────────────────────────────────
 
-    fn make_eff_event(
-        run_id: &str,
-        tokens: u64,
-        cost: f64,
-        success: bool,
-        ts: &str,
-    ) -> String {
+    fn make_eff_event(run_id: &str, tokens: u64, cost: f64, success: bool, ts: &str) -> String {
         serde_json::to_string(&serde_json::json!({
             "event_type": "DelegationEnd",
             "run_id": run_id,
@@ -11481,8 +13413,7 @@ mod tests {
 
     #[test]
     fn print_token_efficiency_all_buckets() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_eff_all.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_eff_all.jsonl");
         let lines = vec![
             // very cheap: 1000 tokens, $0.001 → $0.001/1k < $0.002
             make_eff_event("run1", 1000, 0.001, true, "2026-02-01T10:00:00Z"),
@@ -11501,8 +13432,7 @@ mod tests {
 
     #[test]
     fn print_token_efficiency_empty_log() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_eff_empty.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_eff_empty.jsonl");
         std::fs::write(&path, "").unwrap();
         let result = print_token_efficiency(&path, None);
         let _ = std::fs::remove_file(&path);
@@ -11511,8 +13441,7 @@ mod tests {
 
     #[test]
     fn print_token_efficiency_missing_log() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_eff_missing_XXXX.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_eff_missing_XXXX.jsonl");
         let _ = std::fs::remove_file(&path);
         let result = print_token_efficiency(&path, None);
         assert!(result.is_ok());
@@ -11520,8 +13449,7 @@ mod tests {
 
     #[test]
     fn print_token_efficiency_skips_zero_tokens() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_eff_zerotok.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_eff_zerotok.jsonl");
         // event with 0 tokens should be skipped entirely
         let zero_ev = serde_json::to_string(&serde_json::json!({
             "event_type": "DelegationEnd",
@@ -11542,8 +13470,7 @@ mod tests {
 
     #[test]
     fn print_token_efficiency_only_delegation_end() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_eff_evtype.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_eff_evtype.jsonl");
         let start_ev = serde_json::to_string(&serde_json::json!({
             "event_type": "DelegationStart",
             "run_id": "run1",
@@ -11562,8 +13489,7 @@ mod tests {
 
     #[test]
     fn print_token_efficiency_filters_by_run() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_eff_runfilter.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_eff_runfilter.jsonl");
         let lines = vec![
             make_eff_event("run-keep", 1000, 0.003, true, "2026-02-01T10:00:00Z"),
             make_eff_event("run-skip", 1000, 0.015, true, "2026-02-01T11:00:00Z"),
@@ -11576,13 +13502,7 @@ mod tests {
 
     // ── Phase 89: print_success_breakdown ────────────────────────────────────
 
-    fn make_sb_event(
-        run_id: &str,
-        tokens: u64,
-        cost: f64,
-        success: bool,
-        ts: &str,
-    ) -> String {
+    fn make_sb_event(run_id: &str, tokens: u64, cost: f64, success: bool, ts: &str) -> String {
         serde_json::to_string(&serde_json::json!({
             "event_type": "DelegationEnd",
             "run_id": run_id,
@@ -11601,12 +13521,11 @@ mod tests {
 
     #[test]
     fn print_success_breakdown_both_outcomes() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_sb_both.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_sb_both.jsonl");
         let lines = vec![
-            make_sb_event("run1", 1000, 0.010, true,  "2026-02-01T10:00:00Z"),
-            make_sb_event("run1", 2000, 0.020, true,  "2026-02-01T11:00:00Z"),
-            make_sb_event("run1", 500,  0.005, false, "2026-02-01T12:00:00Z"),
+            make_sb_event("run1", 1000, 0.010, true, "2026-02-01T10:00:00Z"),
+            make_sb_event("run1", 2000, 0.020, true, "2026-02-01T11:00:00Z"),
+            make_sb_event("run1", 500, 0.005, false, "2026-02-01T12:00:00Z"),
         ];
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
         let result = print_success_breakdown(&path, None);
@@ -11616,8 +13535,7 @@ mod tests {
 
     #[test]
     fn print_success_breakdown_empty_log() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_sb_empty.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_sb_empty.jsonl");
         std::fs::write(&path, "").unwrap();
         let result = print_success_breakdown(&path, None);
         let _ = std::fs::remove_file(&path);
@@ -11626,8 +13544,7 @@ mod tests {
 
     #[test]
     fn print_success_breakdown_missing_log() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_sb_missing_XXXX.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_sb_missing_XXXX.jsonl");
         let _ = std::fs::remove_file(&path);
         let result = print_success_breakdown(&path, None);
         assert!(result.is_ok());
@@ -11635,8 +13552,7 @@ mod tests {
 
     #[test]
     fn print_success_breakdown_all_success() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_sb_allsuccess.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_sb_allsuccess.jsonl");
         let lines = vec![
             make_sb_event("run1", 1000, 0.010, true, "2026-02-01T10:00:00Z"),
             make_sb_event("run1", 1500, 0.015, true, "2026-02-01T11:00:00Z"),
@@ -11649,8 +13565,7 @@ mod tests {
 
     #[test]
     fn print_success_breakdown_only_delegation_end() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_sb_evtype.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_sb_evtype.jsonl");
         let start_ev = serde_json::to_string(&serde_json::json!({
             "event_type": "DelegationStart",
             "run_id": "run1",
@@ -11669,10 +13584,9 @@ mod tests {
 
     #[test]
     fn print_success_breakdown_filters_by_run() {
-        let path =
-            std::env::temp_dir().join("zeroclaw_test_sb_runfilter.jsonl");
+        let path = std::env::temp_dir().join("zeroclaw_test_sb_runfilter.jsonl");
         let lines = vec![
-            make_sb_event("run-keep", 1000, 0.010, true,  "2026-02-01T10:00:00Z"),
+            make_sb_event("run-keep", 1000, 0.010, true, "2026-02-01T10:00:00Z"),
             make_sb_event("run-skip", 2000, 0.020, false, "2026-02-01T11:00:00Z"),
         ];
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
@@ -11683,7 +13597,14 @@ mod tests {
 
     // ── print_agent_cost_rank ──────────────────────────────────────────────
 
-    fn make_acr_event(run_id: &str, agent: &str, tokens: u64, cost: f64, success: bool, ts: &str) -> String {
+    fn make_acr_event(
+        run_id: &str,
+        agent: &str,
+        tokens: u64,
+        cost: f64,
+        success: bool,
+        ts: &str,
+    ) -> String {
         format!(
             r#"{{"event_type":"DelegationEnd","run_id":"{run_id}","agent_name":"{agent}","tokens_used":{tokens},"cost_usd":{cost},"success":{success},"timestamp":"{ts}"}}"#
         )
@@ -11696,9 +13617,23 @@ mod tests {
         // expensive_agent: 1 delegation at $0.50 → avg $0.50
         // cheap_agent: 2 delegations at $0.02 each → avg $0.02
         let lines = vec![
-            make_acr_event("r1", "expensive_agent", 5000, 0.50, true,  "2026-02-01T10:00:00Z"),
-            make_acr_event("r1", "cheap_agent",     500,  0.02, true,  "2026-02-01T10:01:00Z"),
-            make_acr_event("r1", "cheap_agent",     600,  0.02, false, "2026-02-01T10:02:00Z"),
+            make_acr_event(
+                "r1",
+                "expensive_agent",
+                5000,
+                0.50,
+                true,
+                "2026-02-01T10:00:00Z",
+            ),
+            make_acr_event("r1", "cheap_agent", 500, 0.02, true, "2026-02-01T10:01:00Z"),
+            make_acr_event(
+                "r1",
+                "cheap_agent",
+                600,
+                0.02,
+                false,
+                "2026-02-01T10:02:00Z",
+            ),
         ];
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
         let result = print_agent_cost_rank(&path, None);
@@ -11744,8 +13679,8 @@ mod tests {
         let path = dir.path().join("a.jsonl");
         let lines = vec![
             make_acr_event("r1", "medium_agt", 2000, 0.10, true, "2026-02-01T10:00:00Z"),
-            make_acr_event("r1", "high_agt",   8000, 0.30, true, "2026-02-01T10:01:00Z"),
-            make_acr_event("r1", "low_agt",     100, 0.01, true, "2026-02-01T10:02:00Z"),
+            make_acr_event("r1", "high_agt", 8000, 0.30, true, "2026-02-01T10:01:00Z"),
+            make_acr_event("r1", "low_agt", 100, 0.01, true, "2026-02-01T10:02:00Z"),
         ];
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
         let result = print_agent_cost_rank(&path, None);
@@ -11758,8 +13693,22 @@ mod tests {
         let dir = tempfile::tempdir().unwrap();
         let path = dir.path().join("a.jsonl");
         let lines = vec![
-            make_acr_event("run-keep", "agt_a", 1000, 0.05, true,  "2026-02-01T10:00:00Z"),
-            make_acr_event("run-skip", "agt_b", 2000, 0.10, false, "2026-02-01T10:01:00Z"),
+            make_acr_event(
+                "run-keep",
+                "agt_a",
+                1000,
+                0.05,
+                true,
+                "2026-02-01T10:00:00Z",
+            ),
+            make_acr_event(
+                "run-skip",
+                "agt_b",
+                2000,
+                0.10,
+                false,
+                "2026-02-01T10:01:00Z",
+            ),
         ];
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
         let result = print_agent_cost_rank(&path, Some("run-keep"));
@@ -11769,7 +13718,14 @@ mod tests {
 
     // ── print_model_cost_rank ──────────────────────────────────────────────
 
-    fn make_mcr_event(run_id: &str, model: &str, tokens: u64, cost: f64, success: bool, ts: &str) -> String {
+    fn make_mcr_event(
+        run_id: &str,
+        model: &str,
+        tokens: u64,
+        cost: f64,
+        success: bool,
+        ts: &str,
+    ) -> String {
         format!(
             r#"{{"event_type":"DelegationEnd","run_id":"{run_id}","model":"{model}","tokens_used":{tokens},"cost_usd":{cost},"success":{success},"timestamp":"{ts}"}}"#
         )
@@ -11782,9 +13738,30 @@ mod tests {
         // opus: 1 delegation at $0.80 → avg $0.80
         // haiku: 2 delegations at $0.01 each → avg $0.01
         let lines = vec![
-            make_mcr_event("r1", "claude-opus-4-6",  8000, 0.80, true,  "2026-02-01T10:00:00Z"),
-            make_mcr_event("r1", "claude-haiku-4-5",  400, 0.01, true,  "2026-02-01T10:01:00Z"),
-            make_mcr_event("r1", "claude-haiku-4-5",  500, 0.01, false, "2026-02-01T10:02:00Z"),
+            make_mcr_event(
+                "r1",
+                "claude-opus-4-6",
+                8000,
+                0.80,
+                true,
+                "2026-02-01T10:00:00Z",
+            ),
+            make_mcr_event(
+                "r1",
+                "claude-haiku-4-5",
+                400,
+                0.01,
+                true,
+                "2026-02-01T10:01:00Z",
+            ),
+            make_mcr_event(
+                "r1",
+                "claude-haiku-4-5",
+                500,
+                0.01,
+                false,
+                "2026-02-01T10:02:00Z",
+            ),
         ];
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
         let result = print_model_cost_rank(&path, None);
@@ -11815,7 +13792,14 @@ mod tests {
         let dir = tempfile::tempdir().unwrap();
         let path = dir.path().join("m.jsonl");
         let start = r#"{"event_type":"DelegationStart","run_id":"r1","model":"claude-sonnet-4-6","timestamp":"2026-02-01T10:00:00Z"}"#;
-        let end = make_mcr_event("r1", "claude-sonnet-4-6", 2000, 0.05, true, "2026-02-01T10:01:00Z");
+        let end = make_mcr_event(
+            "r1",
+            "claude-sonnet-4-6",
+            2000,
+            0.05,
+            true,
+            "2026-02-01T10:01:00Z",
+        );
         std::fs::write(&path, format!("{start}\n{end}\n")).unwrap();
         let result = print_model_cost_rank(&path, None);
         let _ = std::fs::remove_file(&path);
@@ -11828,9 +13812,30 @@ mod tests {
         let path = dir.path().join("m.jsonl");
         // sonnet: $0.10, opus: $0.50, haiku: $0.005 → expected order: opus, sonnet, haiku
         let lines = vec![
-            make_mcr_event("r1", "claude-sonnet-4-6", 3000, 0.10, true, "2026-02-01T10:00:00Z"),
-            make_mcr_event("r1", "claude-opus-4-6",   9000, 0.50, true, "2026-02-01T10:01:00Z"),
-            make_mcr_event("r1", "claude-haiku-4-5",   200, 0.005, true, "2026-02-01T10:02:00Z"),
+            make_mcr_event(
+                "r1",
+                "claude-sonnet-4-6",
+                3000,
+                0.10,
+                true,
+                "2026-02-01T10:00:00Z",
+            ),
+            make_mcr_event(
+                "r1",
+                "claude-opus-4-6",
+                9000,
+                0.50,
+                true,
+                "2026-02-01T10:01:00Z",
+            ),
+            make_mcr_event(
+                "r1",
+                "claude-haiku-4-5",
+                200,
+                0.005,
+                true,
+                "2026-02-01T10:02:00Z",
+            ),
         ];
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
         let result = print_model_cost_rank(&path, None);
@@ -11843,8 +13848,22 @@ mod tests {
         let dir = tempfile::tempdir().unwrap();
         let path = dir.path().join("m.jsonl");
         let lines = vec![
-            make_mcr_event("run-keep", "claude-sonnet-4-6", 2000, 0.04, true,  "2026-02-01T10:00:00Z"),
-            make_mcr_event("run-skip", "claude-opus-4-6",   8000, 0.80, false, "2026-02-01T10:01:00Z"),
+            make_mcr_event(
+                "run-keep",
+                "claude-sonnet-4-6",
+                2000,
+                0.04,
+                true,
+                "2026-02-01T10:00:00Z",
+            ),
+            make_mcr_event(
+                "run-skip",
+                "claude-opus-4-6",
+                8000,
+                0.80,
+                false,
+                "2026-02-01T10:01:00Z",
+            ),
         ];
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
         let result = print_model_cost_rank(&path, Some("run-keep"));
@@ -11854,7 +13873,14 @@ mod tests {
 
     // ── print_provider_cost_rank ───────────────────────────────────────────
 
-    fn make_pcr_event(run_id: &str, provider: &str, tokens: u64, cost: f64, success: bool, ts: &str) -> String {
+    fn make_pcr_event(
+        run_id: &str,
+        provider: &str,
+        tokens: u64,
+        cost: f64,
+        success: bool,
+        ts: &str,
+    ) -> String {
         format!(
             r#"{{"event_type":"DelegationEnd","run_id":"{run_id}","provider":"{provider}","tokens_used":{tokens},"cost_usd":{cost},"success":{success},"timestamp":"{ts}"}}"#
         )
@@ -11866,9 +13892,9 @@ mod tests {
         let path = dir.path().join("p.jsonl");
         // anthropic: 1 at $0.50 → avg $0.50; openai: 2 at $0.08 each → avg $0.08
         let lines = vec![
-            make_pcr_event("r1", "anthropic", 5000, 0.50, true,  "2026-02-01T10:00:00Z"),
-            make_pcr_event("r1", "openai",    2000, 0.08, true,  "2026-02-01T10:01:00Z"),
-            make_pcr_event("r1", "openai",    1800, 0.08, false, "2026-02-01T10:02:00Z"),
+            make_pcr_event("r1", "anthropic", 5000, 0.50, true, "2026-02-01T10:00:00Z"),
+            make_pcr_event("r1", "openai", 2000, 0.08, true, "2026-02-01T10:01:00Z"),
+            make_pcr_event("r1", "openai", 1800, 0.08, false, "2026-02-01T10:02:00Z"),
         ];
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
         let result = print_provider_cost_rank(&path, None);
@@ -11912,9 +13938,9 @@ mod tests {
         let path = dir.path().join("p.jsonl");
         // google: $0.02, anthropic: $0.40, openai: $0.15 → expected: anthropic, openai, google
         let lines = vec![
-            make_pcr_event("r1", "google",    500,  0.02, true, "2026-02-01T10:00:00Z"),
+            make_pcr_event("r1", "google", 500, 0.02, true, "2026-02-01T10:00:00Z"),
             make_pcr_event("r1", "anthropic", 8000, 0.40, true, "2026-02-01T10:01:00Z"),
-            make_pcr_event("r1", "openai",    3000, 0.15, true, "2026-02-01T10:02:00Z"),
+            make_pcr_event("r1", "openai", 3000, 0.15, true, "2026-02-01T10:02:00Z"),
         ];
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
         let result = print_provider_cost_rank(&path, None);
@@ -11927,8 +13953,22 @@ mod tests {
         let dir = tempfile::tempdir().unwrap();
         let path = dir.path().join("p.jsonl");
         let lines = vec![
-            make_pcr_event("run-keep", "anthropic", 2000, 0.04, true,  "2026-02-01T10:00:00Z"),
-            make_pcr_event("run-skip", "openai",    4000, 0.20, false, "2026-02-01T10:01:00Z"),
+            make_pcr_event(
+                "run-keep",
+                "anthropic",
+                2000,
+                0.04,
+                true,
+                "2026-02-01T10:00:00Z",
+            ),
+            make_pcr_event(
+                "run-skip",
+                "openai",
+                4000,
+                0.20,
+                false,
+                "2026-02-01T10:01:00Z",
+            ),
         ];
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
         let result = print_provider_cost_rank(&path, Some("run-keep"));
@@ -11938,7 +13978,14 @@ mod tests {
 
     // ── print_run_cost_rank ────────────────────────────────────────────────
 
-    fn make_rcr_event(run_id: &str, agent: &str, tokens: u64, cost: f64, success: bool, ts: &str) -> String {
+    fn make_rcr_event(
+        run_id: &str,
+        agent: &str,
+        tokens: u64,
+        cost: f64,
+        success: bool,
+        ts: &str,
+    ) -> String {
         format!(
             r#"{{"event_type":"DelegationEnd","run_id":"{run_id}","agent_name":"{agent}","tokens_used":{tokens},"cost_usd":{cost},"success":{success},"timestamp":"{ts}"}}"#
         )
@@ -11949,9 +13996,30 @@ mod tests {
         let dir = tempfile::tempdir().unwrap();
         let path = dir.path().join("p.jsonl");
         let lines = vec![
-            make_rcr_event("run-alpha", "agent-a", 5000, 1.25, true,  "2026-02-01T10:00:00Z"),
-            make_rcr_event("run-beta",  "agent-b", 2000, 0.40, true,  "2026-02-01T10:01:00Z"),
-            make_rcr_event("run-gamma", "agent-c",  500, 0.05, false, "2026-02-01T10:02:00Z"),
+            make_rcr_event(
+                "run-alpha",
+                "agent-a",
+                5000,
+                1.25,
+                true,
+                "2026-02-01T10:00:00Z",
+            ),
+            make_rcr_event(
+                "run-beta",
+                "agent-b",
+                2000,
+                0.40,
+                true,
+                "2026-02-01T10:01:00Z",
+            ),
+            make_rcr_event(
+                "run-gamma",
+                "agent-c",
+                500,
+                0.05,
+                false,
+                "2026-02-01T10:02:00Z",
+            ),
         ];
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
         let result = print_run_cost_rank(&path, None);
@@ -11993,9 +14061,30 @@ mod tests {
         let path = dir.path().join("p.jsonl");
         // run-cheap: 1 del at $0.10 total; run-expensive: 2 dels at $3.00 total
         let lines = vec![
-            make_rcr_event("run-cheap",     "agent-a", 1000, 0.10, true,  "2026-02-01T10:00:00Z"),
-            make_rcr_event("run-expensive", "agent-b", 8000, 1.50, true,  "2026-02-01T10:01:00Z"),
-            make_rcr_event("run-expensive", "agent-c", 6000, 1.50, false, "2026-02-01T10:02:00Z"),
+            make_rcr_event(
+                "run-cheap",
+                "agent-a",
+                1000,
+                0.10,
+                true,
+                "2026-02-01T10:00:00Z",
+            ),
+            make_rcr_event(
+                "run-expensive",
+                "agent-b",
+                8000,
+                1.50,
+                true,
+                "2026-02-01T10:01:00Z",
+            ),
+            make_rcr_event(
+                "run-expensive",
+                "agent-c",
+                6000,
+                1.50,
+                false,
+                "2026-02-01T10:02:00Z",
+            ),
         ];
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
         let result = print_run_cost_rank(&path, None);
@@ -12008,8 +14097,22 @@ mod tests {
         let dir = tempfile::tempdir().unwrap();
         let path = dir.path().join("p.jsonl");
         let lines = vec![
-            make_rcr_event("run-keep", "agent-a", 3000, 0.60, true,  "2026-02-01T10:00:00Z"),
-            make_rcr_event("run-skip", "agent-b", 1000, 0.10, false, "2026-02-01T10:01:00Z"),
+            make_rcr_event(
+                "run-keep",
+                "agent-a",
+                3000,
+                0.60,
+                true,
+                "2026-02-01T10:00:00Z",
+            ),
+            make_rcr_event(
+                "run-skip",
+                "agent-b",
+                1000,
+                0.10,
+                false,
+                "2026-02-01T10:01:00Z",
+            ),
         ];
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
         let result = print_run_cost_rank(&path, Some("run-keep"));
@@ -12019,7 +14122,14 @@ mod tests {
 
     // ── print_agent_success_rank ───────────────────────────────────────────
 
-    fn make_asr_event(run_id: &str, agent: &str, tokens: u64, cost: f64, success: bool, ts: &str) -> String {
+    fn make_asr_event(
+        run_id: &str,
+        agent: &str,
+        tokens: u64,
+        cost: f64,
+        success: bool,
+        ts: &str,
+    ) -> String {
         format!(
             r#"{{"event_type":"DelegationEnd","run_id":"{run_id}","agent_name":"{agent}","tokens_used":{tokens},"cost_usd":{cost},"success":{success},"timestamp":"{ts}"}}"#
         )
@@ -12030,10 +14140,38 @@ mod tests {
         let dir = tempfile::tempdir().unwrap();
         let path = dir.path().join("p.jsonl");
         let lines = vec![
-            make_asr_event("run-a", "orchestrator", 1000, 0.10, true,  "2026-02-01T10:00:00Z"),
-            make_asr_event("run-a", "orchestrator", 1200, 0.12, true,  "2026-02-01T10:01:00Z"),
-            make_asr_event("run-a", "research",     3000, 0.30, true,  "2026-02-01T10:02:00Z"),
-            make_asr_event("run-a", "research",     2500, 0.25, false, "2026-02-01T10:03:00Z"),
+            make_asr_event(
+                "run-a",
+                "orchestrator",
+                1000,
+                0.10,
+                true,
+                "2026-02-01T10:00:00Z",
+            ),
+            make_asr_event(
+                "run-a",
+                "orchestrator",
+                1200,
+                0.12,
+                true,
+                "2026-02-01T10:01:00Z",
+            ),
+            make_asr_event(
+                "run-a",
+                "research",
+                3000,
+                0.30,
+                true,
+                "2026-02-01T10:02:00Z",
+            ),
+            make_asr_event(
+                "run-a",
+                "research",
+                2500,
+                0.25,
+                false,
+                "2026-02-01T10:03:00Z",
+            ),
         ];
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
         let result = print_agent_success_rank(&path, None);
@@ -12075,10 +14213,24 @@ mod tests {
         let path = dir.path().join("p.jsonl");
         // reliable: 10/10 = 100%; flaky: 5/10 = 50%
         let lines = vec![
-            make_asr_event("run-a", "reliable", 1000, 0.10, true,  "2026-02-01T10:00:00Z"),
-            make_asr_event("run-a", "reliable", 1000, 0.10, true,  "2026-02-01T10:01:00Z"),
-            make_asr_event("run-a", "flaky",    1000, 0.10, true,  "2026-02-01T10:02:00Z"),
-            make_asr_event("run-a", "flaky",    1000, 0.10, false, "2026-02-01T10:03:00Z"),
+            make_asr_event(
+                "run-a",
+                "reliable",
+                1000,
+                0.10,
+                true,
+                "2026-02-01T10:00:00Z",
+            ),
+            make_asr_event(
+                "run-a",
+                "reliable",
+                1000,
+                0.10,
+                true,
+                "2026-02-01T10:01:00Z",
+            ),
+            make_asr_event("run-a", "flaky", 1000, 0.10, true, "2026-02-01T10:02:00Z"),
+            make_asr_event("run-a", "flaky", 1000, 0.10, false, "2026-02-01T10:03:00Z"),
         ];
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
         let result = print_agent_success_rank(&path, None);
@@ -12091,8 +14243,22 @@ mod tests {
         let dir = tempfile::tempdir().unwrap();
         let path = dir.path().join("p.jsonl");
         let lines = vec![
-            make_asr_event("run-keep", "agent-a", 2000, 0.20, true,  "2026-02-01T10:00:00Z"),
-            make_asr_event("run-skip", "agent-b", 1000, 0.10, false, "2026-02-01T10:01:00Z"),
+            make_asr_event(
+                "run-keep",
+                "agent-a",
+                2000,
+                0.20,
+                true,
+                "2026-02-01T10:00:00Z",
+            ),
+            make_asr_event(
+                "run-skip",
+                "agent-b",
+                1000,
+                0.10,
+                false,
+                "2026-02-01T10:01:00Z",
+            ),
         ];
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
         let result = print_agent_success_rank(&path, Some("run-keep"));
@@ -12102,7 +14268,14 @@ mod tests {
 
     // ── print_model_success_rank ───────────────────────────────────────────
 
-    fn make_msr_event(run_id: &str, model: &str, tokens: u64, cost: f64, success: bool, ts: &str) -> String {
+    fn make_msr_event(
+        run_id: &str,
+        model: &str,
+        tokens: u64,
+        cost: f64,
+        success: bool,
+        ts: &str,
+    ) -> String {
         format!(
             r#"{{"event_type":"DelegationEnd","run_id":"{run_id}","model":"{model}","tokens_used":{tokens},"cost_usd":{cost},"success":{success},"timestamp":"{ts}"}}"#
         )
@@ -12113,10 +14286,38 @@ mod tests {
         let dir = tempfile::tempdir().unwrap();
         let path = dir.path().join("p.jsonl");
         let lines = vec![
-            make_msr_event("run-a", "claude-sonnet-4-6", 3000, 0.40, true,  "2026-02-01T10:00:00Z"),
-            make_msr_event("run-a", "claude-sonnet-4-6", 2500, 0.33, true,  "2026-02-01T10:01:00Z"),
-            make_msr_event("run-a", "claude-haiku-4-5",  800, 0.01, true,  "2026-02-01T10:02:00Z"),
-            make_msr_event("run-a", "claude-haiku-4-5",  700, 0.01, false, "2026-02-01T10:03:00Z"),
+            make_msr_event(
+                "run-a",
+                "claude-sonnet-4-6",
+                3000,
+                0.40,
+                true,
+                "2026-02-01T10:00:00Z",
+            ),
+            make_msr_event(
+                "run-a",
+                "claude-sonnet-4-6",
+                2500,
+                0.33,
+                true,
+                "2026-02-01T10:01:00Z",
+            ),
+            make_msr_event(
+                "run-a",
+                "claude-haiku-4-5",
+                800,
+                0.01,
+                true,
+                "2026-02-01T10:02:00Z",
+            ),
+            make_msr_event(
+                "run-a",
+                "claude-haiku-4-5",
+                700,
+                0.01,
+                false,
+                "2026-02-01T10:03:00Z",
+            ),
         ];
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
         let result = print_model_success_rank(&path, None);
@@ -12158,10 +14359,38 @@ mod tests {
         let path = dir.path().join("p.jsonl");
         // reliable-model: 2/2 = 100%; flaky-model: 1/2 = 50%
         let lines = vec![
-            make_msr_event("run-a", "reliable-model", 1000, 0.10, true,  "2026-02-01T10:00:00Z"),
-            make_msr_event("run-a", "reliable-model", 1000, 0.10, true,  "2026-02-01T10:01:00Z"),
-            make_msr_event("run-a", "flaky-model",    1000, 0.10, true,  "2026-02-01T10:02:00Z"),
-            make_msr_event("run-a", "flaky-model",    1000, 0.10, false, "2026-02-01T10:03:00Z"),
+            make_msr_event(
+                "run-a",
+                "reliable-model",
+                1000,
+                0.10,
+                true,
+                "2026-02-01T10:00:00Z",
+            ),
+            make_msr_event(
+                "run-a",
+                "reliable-model",
+                1000,
+                0.10,
+                true,
+                "2026-02-01T10:01:00Z",
+            ),
+            make_msr_event(
+                "run-a",
+                "flaky-model",
+                1000,
+                0.10,
+                true,
+                "2026-02-01T10:02:00Z",
+            ),
+            make_msr_event(
+                "run-a",
+                "flaky-model",
+                1000,
+                0.10,
+                false,
+                "2026-02-01T10:03:00Z",
+            ),
         ];
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
         let result = print_model_success_rank(&path, None);
@@ -12174,8 +14403,22 @@ mod tests {
         let dir = tempfile::tempdir().unwrap();
         let path = dir.path().join("p.jsonl");
         let lines = vec![
-            make_msr_event("run-keep", "claude-sonnet-4-6", 2000, 0.27, true,  "2026-02-01T10:00:00Z"),
-            make_msr_event("run-skip", "claude-haiku-4-5",  500, 0.01, false, "2026-02-01T10:01:00Z"),
+            make_msr_event(
+                "run-keep",
+                "claude-sonnet-4-6",
+                2000,
+                0.27,
+                true,
+                "2026-02-01T10:00:00Z",
+            ),
+            make_msr_event(
+                "run-skip",
+                "claude-haiku-4-5",
+                500,
+                0.01,
+                false,
+                "2026-02-01T10:01:00Z",
+            ),
         ];
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
         let result = print_model_success_rank(&path, Some("run-keep"));
@@ -12185,7 +14428,14 @@ mod tests {
 
     // ── print_provider_success_rank ────────────────────────────────────────
 
-    fn make_psr_event(run_id: &str, provider: &str, tokens: u64, cost: f64, success: bool, ts: &str) -> String {
+    fn make_psr_event(
+        run_id: &str,
+        provider: &str,
+        tokens: u64,
+        cost: f64,
+        success: bool,
+        ts: &str,
+    ) -> String {
         format!(
             r#"{{"event_type":"DelegationEnd","run_id":"{run_id}","provider":"{provider}","tokens_used":{tokens},"cost_usd":{cost},"success":{success},"timestamp":"{ts}"}}"#
         )
@@ -12196,10 +14446,24 @@ mod tests {
         let dir = tempfile::tempdir().unwrap();
         let path = dir.path().join("p.jsonl");
         let lines = vec![
-            make_psr_event("run-a", "anthropic", 3000, 0.40, true,  "2026-02-01T10:00:00Z"),
-            make_psr_event("run-a", "anthropic", 2500, 0.33, true,  "2026-02-01T10:01:00Z"),
-            make_psr_event("run-a", "openai",    1000, 0.10, true,  "2026-02-01T10:02:00Z"),
-            make_psr_event("run-a", "openai",     800, 0.08, false, "2026-02-01T10:03:00Z"),
+            make_psr_event(
+                "run-a",
+                "anthropic",
+                3000,
+                0.40,
+                true,
+                "2026-02-01T10:00:00Z",
+            ),
+            make_psr_event(
+                "run-a",
+                "anthropic",
+                2500,
+                0.33,
+                true,
+                "2026-02-01T10:01:00Z",
+            ),
+            make_psr_event("run-a", "openai", 1000, 0.10, true, "2026-02-01T10:02:00Z"),
+            make_psr_event("run-a", "openai", 800, 0.08, false, "2026-02-01T10:03:00Z"),
         ];
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
         let result = print_provider_success_rank(&path, None);
@@ -12241,10 +14505,38 @@ mod tests {
         let path = dir.path().join("p.jsonl");
         // reliable-prov: 2/2 = 100%; flaky-prov: 1/2 = 50%
         let lines = vec![
-            make_psr_event("run-a", "reliable-prov", 1000, 0.10, true,  "2026-02-01T10:00:00Z"),
-            make_psr_event("run-a", "reliable-prov", 1000, 0.10, true,  "2026-02-01T10:01:00Z"),
-            make_psr_event("run-a", "flaky-prov",    1000, 0.10, true,  "2026-02-01T10:02:00Z"),
-            make_psr_event("run-a", "flaky-prov",    1000, 0.10, false, "2026-02-01T10:03:00Z"),
+            make_psr_event(
+                "run-a",
+                "reliable-prov",
+                1000,
+                0.10,
+                true,
+                "2026-02-01T10:00:00Z",
+            ),
+            make_psr_event(
+                "run-a",
+                "reliable-prov",
+                1000,
+                0.10,
+                true,
+                "2026-02-01T10:01:00Z",
+            ),
+            make_psr_event(
+                "run-a",
+                "flaky-prov",
+                1000,
+                0.10,
+                true,
+                "2026-02-01T10:02:00Z",
+            ),
+            make_psr_event(
+                "run-a",
+                "flaky-prov",
+                1000,
+                0.10,
+                false,
+                "2026-02-01T10:03:00Z",
+            ),
         ];
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
         let result = print_provider_success_rank(&path, None);
@@ -12257,8 +14549,22 @@ mod tests {
         let dir = tempfile::tempdir().unwrap();
         let path = dir.path().join("p.jsonl");
         let lines = vec![
-            make_psr_event("run-keep", "anthropic", 2000, 0.27, true,  "2026-02-01T10:00:00Z"),
-            make_psr_event("run-skip", "openai",     500, 0.05, false, "2026-02-01T10:01:00Z"),
+            make_psr_event(
+                "run-keep",
+                "anthropic",
+                2000,
+                0.27,
+                true,
+                "2026-02-01T10:00:00Z",
+            ),
+            make_psr_event(
+                "run-skip",
+                "openai",
+                500,
+                0.05,
+                false,
+                "2026-02-01T10:01:00Z",
+            ),
         ];
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
         let result = print_provider_success_rank(&path, Some("run-keep"));
@@ -12268,7 +14574,14 @@ mod tests {
 
     // ── print_agent_token_rank ─────────────────────────────────────────────
 
-    fn make_atr_event(run_id: &str, agent: &str, tokens: u64, cost: f64, success: bool, ts: &str) -> String {
+    fn make_atr_event(
+        run_id: &str,
+        agent: &str,
+        tokens: u64,
+        cost: f64,
+        success: bool,
+        ts: &str,
+    ) -> String {
         format!(
             r#"{{"event_type":"DelegationEnd","run_id":"{run_id}","agent_name":"{agent}","tokens_used":{tokens},"cost_usd":{cost},"success":{success},"timestamp":"{ts}"}}"#
         )
@@ -12279,9 +14592,23 @@ mod tests {
         let dir = tempfile::tempdir().unwrap();
         let path = dir.path().join("p.jsonl");
         let lines = vec![
-            make_atr_event("run-a", "research",     8000, 0.80, true,  "2026-02-01T10:00:00Z"),
-            make_atr_event("run-a", "orchestrator", 1000, 0.10, true,  "2026-02-01T10:01:00Z"),
-            make_atr_event("run-a", "writer",       3000, 0.30, false, "2026-02-01T10:02:00Z"),
+            make_atr_event(
+                "run-a",
+                "research",
+                8000,
+                0.80,
+                true,
+                "2026-02-01T10:00:00Z",
+            ),
+            make_atr_event(
+                "run-a",
+                "orchestrator",
+                1000,
+                0.10,
+                true,
+                "2026-02-01T10:01:00Z",
+            ),
+            make_atr_event("run-a", "writer", 3000, 0.30, false, "2026-02-01T10:02:00Z"),
         ];
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
         let result = print_agent_token_rank(&path, None);
@@ -12323,8 +14650,22 @@ mod tests {
         let path = dir.path().join("p.jsonl");
         // heavy-agent: avg 5000 tok; light-agent: avg 500 tok
         let lines = vec![
-            make_atr_event("run-a", "heavy-agent", 5000, 0.50, true, "2026-02-01T10:00:00Z"),
-            make_atr_event("run-a", "light-agent",  500, 0.05, true, "2026-02-01T10:01:00Z"),
+            make_atr_event(
+                "run-a",
+                "heavy-agent",
+                5000,
+                0.50,
+                true,
+                "2026-02-01T10:00:00Z",
+            ),
+            make_atr_event(
+                "run-a",
+                "light-agent",
+                500,
+                0.05,
+                true,
+                "2026-02-01T10:01:00Z",
+            ),
         ];
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
         let result = print_agent_token_rank(&path, None);
@@ -12337,8 +14678,22 @@ mod tests {
         let dir = tempfile::tempdir().unwrap();
         let path = dir.path().join("p.jsonl");
         let lines = vec![
-            make_atr_event("run-keep", "research",     6000, 0.60, true,  "2026-02-01T10:00:00Z"),
-            make_atr_event("run-skip", "orchestrator", 1000, 0.10, false, "2026-02-01T10:01:00Z"),
+            make_atr_event(
+                "run-keep",
+                "research",
+                6000,
+                0.60,
+                true,
+                "2026-02-01T10:00:00Z",
+            ),
+            make_atr_event(
+                "run-skip",
+                "orchestrator",
+                1000,
+                0.10,
+                false,
+                "2026-02-01T10:01:00Z",
+            ),
         ];
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
         let result = print_agent_token_rank(&path, Some("run-keep"));
@@ -12348,7 +14703,14 @@ mod tests {
 
     // ── print_model_token_rank ─────────────────────────────────────────────
 
-    fn make_mtr_event(run_id: &str, model: &str, tokens: u64, cost: f64, success: bool, ts: &str) -> String {
+    fn make_mtr_event(
+        run_id: &str,
+        model: &str,
+        tokens: u64,
+        cost: f64,
+        success: bool,
+        ts: &str,
+    ) -> String {
         format!(
             r#"{{"event_type":"DelegationEnd","run_id":"{run_id}","model":"{model}","tokens_used":{tokens},"cost_usd":{cost},"success":{success},"timestamp":"{ts}"}}"#
         )
@@ -12359,9 +14721,30 @@ mod tests {
         let dir = tempfile::tempdir().unwrap();
         let path = dir.path().join("p.jsonl");
         let lines = vec![
-            make_mtr_event("run-a", "claude-opus-4-5",    12000, 1.20, true,  "2026-02-01T10:00:00Z"),
-            make_mtr_event("run-a", "claude-sonnet-4-5",   5000, 0.30, true,  "2026-02-01T10:01:00Z"),
-            make_mtr_event("run-a", "claude-haiku-4-5",    1500, 0.05, false, "2026-02-01T10:02:00Z"),
+            make_mtr_event(
+                "run-a",
+                "claude-opus-4-5",
+                12000,
+                1.20,
+                true,
+                "2026-02-01T10:00:00Z",
+            ),
+            make_mtr_event(
+                "run-a",
+                "claude-sonnet-4-5",
+                5000,
+                0.30,
+                true,
+                "2026-02-01T10:01:00Z",
+            ),
+            make_mtr_event(
+                "run-a",
+                "claude-haiku-4-5",
+                1500,
+                0.05,
+                false,
+                "2026-02-01T10:02:00Z",
+            ),
         ];
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
         let result = print_model_token_rank(&path, None);
@@ -12403,8 +14786,22 @@ mod tests {
         let path = dir.path().join("p.jsonl");
         // heavy-model: avg 8000 tok; light-model: avg 400 tok
         let lines = vec![
-            make_mtr_event("run-a", "heavy-model", 8000, 0.80, true, "2026-02-01T10:00:00Z"),
-            make_mtr_event("run-a", "light-model",  400, 0.04, true, "2026-02-01T10:01:00Z"),
+            make_mtr_event(
+                "run-a",
+                "heavy-model",
+                8000,
+                0.80,
+                true,
+                "2026-02-01T10:00:00Z",
+            ),
+            make_mtr_event(
+                "run-a",
+                "light-model",
+                400,
+                0.04,
+                true,
+                "2026-02-01T10:01:00Z",
+            ),
         ];
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
         let result = print_model_token_rank(&path, None);
@@ -12417,8 +14814,22 @@ mod tests {
         let dir = tempfile::tempdir().unwrap();
         let path = dir.path().join("p.jsonl");
         let lines = vec![
-            make_mtr_event("run-keep", "claude-opus-4-5",   9000, 0.90, true,  "2026-02-01T10:00:00Z"),
-            make_mtr_event("run-skip", "claude-haiku-4-5",  1000, 0.04, false, "2026-02-01T10:01:00Z"),
+            make_mtr_event(
+                "run-keep",
+                "claude-opus-4-5",
+                9000,
+                0.90,
+                true,
+                "2026-02-01T10:00:00Z",
+            ),
+            make_mtr_event(
+                "run-skip",
+                "claude-haiku-4-5",
+                1000,
+                0.04,
+                false,
+                "2026-02-01T10:01:00Z",
+            ),
         ];
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
         let result = print_model_token_rank(&path, Some("run-keep"));
@@ -12428,7 +14839,14 @@ mod tests {
 
     // ── print_provider_token_rank ──────────────────────────────────────────
 
-    fn make_ptr_event(run_id: &str, provider: &str, tokens: u64, cost: f64, success: bool, ts: &str) -> String {
+    fn make_ptr_event(
+        run_id: &str,
+        provider: &str,
+        tokens: u64,
+        cost: f64,
+        success: bool,
+        ts: &str,
+    ) -> String {
         format!(
             r#"{{"event_type":"DelegationEnd","run_id":"{run_id}","provider":"{provider}","tokens_used":{tokens},"cost_usd":{cost},"success":{success},"timestamp":"{ts}"}}"#
         )
@@ -12439,9 +14857,16 @@ mod tests {
         let dir = tempfile::tempdir().unwrap();
         let path = dir.path().join("p.jsonl");
         let lines = vec![
-            make_ptr_event("run-a", "anthropic", 9000, 0.90, true,  "2026-02-01T10:00:00Z"),
-            make_ptr_event("run-a", "openai",    5000, 0.30, true,  "2026-02-01T10:01:00Z"),
-            make_ptr_event("run-a", "google",    1500, 0.05, false, "2026-02-01T10:02:00Z"),
+            make_ptr_event(
+                "run-a",
+                "anthropic",
+                9000,
+                0.90,
+                true,
+                "2026-02-01T10:00:00Z",
+            ),
+            make_ptr_event("run-a", "openai", 5000, 0.30, true, "2026-02-01T10:01:00Z"),
+            make_ptr_event("run-a", "google", 1500, 0.05, false, "2026-02-01T10:02:00Z"),
         ];
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
         let result = print_provider_token_rank(&path, None);
@@ -12483,8 +14908,22 @@ mod tests {
         let path = dir.path().join("p.jsonl");
         // heavy-prov: avg 7000 tok; light-prov: avg 300 tok
         let lines = vec![
-            make_ptr_event("run-a", "heavy-prov", 7000, 0.70, true, "2026-02-01T10:00:00Z"),
-            make_ptr_event("run-a", "light-prov",  300, 0.03, true, "2026-02-01T10:01:00Z"),
+            make_ptr_event(
+                "run-a",
+                "heavy-prov",
+                7000,
+                0.70,
+                true,
+                "2026-02-01T10:00:00Z",
+            ),
+            make_ptr_event(
+                "run-a",
+                "light-prov",
+                300,
+                0.03,
+                true,
+                "2026-02-01T10:01:00Z",
+            ),
         ];
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
         let result = print_provider_token_rank(&path, None);
@@ -12497,8 +14936,22 @@ mod tests {
         let dir = tempfile::tempdir().unwrap();
         let path = dir.path().join("p.jsonl");
         let lines = vec![
-            make_ptr_event("run-keep", "anthropic", 8000, 0.80, true,  "2026-02-01T10:00:00Z"),
-            make_ptr_event("run-skip", "google",     500, 0.02, false, "2026-02-01T10:01:00Z"),
+            make_ptr_event(
+                "run-keep",
+                "anthropic",
+                8000,
+                0.80,
+                true,
+                "2026-02-01T10:00:00Z",
+            ),
+            make_ptr_event(
+                "run-skip",
+                "google",
+                500,
+                0.02,
+                false,
+                "2026-02-01T10:01:00Z",
+            ),
         ];
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
         let result = print_provider_token_rank(&path, Some("run-keep"));
@@ -12508,7 +14961,14 @@ mod tests {
 
     // ── print_agent_duration_rank ──────────────────────────────────────────
 
-    fn make_adr_event(run_id: &str, agent: &str, duration_ms: u64, cost: f64, success: bool, ts: &str) -> String {
+    fn make_adr_event(
+        run_id: &str,
+        agent: &str,
+        duration_ms: u64,
+        cost: f64,
+        success: bool,
+        ts: &str,
+    ) -> String {
         format!(
             r#"{{"event_type":"DelegationEnd","run_id":"{run_id}","agent_name":"{agent}","duration_ms":{duration_ms},"cost_usd":{cost},"success":{success},"timestamp":"{ts}"}}"#
         )
@@ -12519,9 +14979,30 @@ mod tests {
         let dir = tempfile::tempdir().unwrap();
         let path = dir.path().join("p.jsonl");
         let lines = vec![
-            make_adr_event("run-a", "research",     45_000, 0.80, true,  "2026-02-01T10:00:00Z"),
-            make_adr_event("run-a", "orchestrator",  5_000, 0.10, true,  "2026-02-01T10:01:00Z"),
-            make_adr_event("run-a", "writer",       12_000, 0.30, false, "2026-02-01T10:02:00Z"),
+            make_adr_event(
+                "run-a",
+                "research",
+                45_000,
+                0.80,
+                true,
+                "2026-02-01T10:00:00Z",
+            ),
+            make_adr_event(
+                "run-a",
+                "orchestrator",
+                5_000,
+                0.10,
+                true,
+                "2026-02-01T10:01:00Z",
+            ),
+            make_adr_event(
+                "run-a",
+                "writer",
+                12_000,
+                0.30,
+                false,
+                "2026-02-01T10:02:00Z",
+            ),
         ];
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
         let result = print_agent_duration_rank(&path, None);
@@ -12563,8 +15044,22 @@ mod tests {
         let path = dir.path().join("p.jsonl");
         // slow-agent: avg 30000ms; fast-agent: avg 1000ms
         let lines = vec![
-            make_adr_event("run-a", "slow-agent", 30_000, 0.50, true, "2026-02-01T10:00:00Z"),
-            make_adr_event("run-a", "fast-agent",  1_000, 0.05, true, "2026-02-01T10:01:00Z"),
+            make_adr_event(
+                "run-a",
+                "slow-agent",
+                30_000,
+                0.50,
+                true,
+                "2026-02-01T10:00:00Z",
+            ),
+            make_adr_event(
+                "run-a",
+                "fast-agent",
+                1_000,
+                0.05,
+                true,
+                "2026-02-01T10:01:00Z",
+            ),
         ];
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
         let result = print_agent_duration_rank(&path, None);
@@ -12577,8 +15072,22 @@ mod tests {
         let dir = tempfile::tempdir().unwrap();
         let path = dir.path().join("p.jsonl");
         let lines = vec![
-            make_adr_event("run-keep", "research",     20_000, 0.60, true,  "2026-02-01T10:00:00Z"),
-            make_adr_event("run-skip", "orchestrator",  3_000, 0.10, false, "2026-02-01T10:01:00Z"),
+            make_adr_event(
+                "run-keep",
+                "research",
+                20_000,
+                0.60,
+                true,
+                "2026-02-01T10:00:00Z",
+            ),
+            make_adr_event(
+                "run-skip",
+                "orchestrator",
+                3_000,
+                0.10,
+                false,
+                "2026-02-01T10:01:00Z",
+            ),
         ];
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
         let result = print_agent_duration_rank(&path, Some("run-keep"));
@@ -12588,7 +15097,14 @@ mod tests {
 
     // ── print_model_duration_rank ──────────────────────────────────────────
 
-    fn make_mdr_event(run_id: &str, model: &str, duration_ms: u64, cost: f64, success: bool, ts: &str) -> String {
+    fn make_mdr_event(
+        run_id: &str,
+        model: &str,
+        duration_ms: u64,
+        cost: f64,
+        success: bool,
+        ts: &str,
+    ) -> String {
         format!(
             r#"{{"event_type":"DelegationEnd","run_id":"{run_id}","model":"{model}","duration_ms":{duration_ms},"cost_usd":{cost},"success":{success},"timestamp":"{ts}"}}"#
         )
@@ -12599,9 +15115,30 @@ mod tests {
         let dir = tempfile::tempdir().unwrap();
         let path = dir.path().join("p.jsonl");
         let lines = vec![
-            make_mdr_event("run-a", "claude-opus-4-5",    55_000, 1.20, true,  "2026-02-01T10:00:00Z"),
-            make_mdr_event("run-a", "claude-sonnet-4-5",  18_000, 0.30, true,  "2026-02-01T10:01:00Z"),
-            make_mdr_event("run-a", "claude-haiku-4-5",    3_000, 0.05, false, "2026-02-01T10:02:00Z"),
+            make_mdr_event(
+                "run-a",
+                "claude-opus-4-5",
+                55_000,
+                1.20,
+                true,
+                "2026-02-01T10:00:00Z",
+            ),
+            make_mdr_event(
+                "run-a",
+                "claude-sonnet-4-5",
+                18_000,
+                0.30,
+                true,
+                "2026-02-01T10:01:00Z",
+            ),
+            make_mdr_event(
+                "run-a",
+                "claude-haiku-4-5",
+                3_000,
+                0.05,
+                false,
+                "2026-02-01T10:02:00Z",
+            ),
         ];
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
         let result = print_model_duration_rank(&path, None);
@@ -12643,8 +15180,22 @@ mod tests {
         let path = dir.path().join("p.jsonl");
         // slow-model: avg 40000ms; fast-model: avg 2000ms
         let lines = vec![
-            make_mdr_event("run-a", "slow-model", 40_000, 0.80, true, "2026-02-01T10:00:00Z"),
-            make_mdr_event("run-a", "fast-model",  2_000, 0.04, true, "2026-02-01T10:01:00Z"),
+            make_mdr_event(
+                "run-a",
+                "slow-model",
+                40_000,
+                0.80,
+                true,
+                "2026-02-01T10:00:00Z",
+            ),
+            make_mdr_event(
+                "run-a",
+                "fast-model",
+                2_000,
+                0.04,
+                true,
+                "2026-02-01T10:01:00Z",
+            ),
         ];
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
         let result = print_model_duration_rank(&path, None);
@@ -12657,8 +15208,22 @@ mod tests {
         let dir = tempfile::tempdir().unwrap();
         let path = dir.path().join("p.jsonl");
         let lines = vec![
-            make_mdr_event("run-keep", "claude-opus-4-5",   30_000, 0.90, true,  "2026-02-01T10:00:00Z"),
-            make_mdr_event("run-skip", "claude-haiku-4-5",   2_000, 0.04, false, "2026-02-01T10:01:00Z"),
+            make_mdr_event(
+                "run-keep",
+                "claude-opus-4-5",
+                30_000,
+                0.90,
+                true,
+                "2026-02-01T10:00:00Z",
+            ),
+            make_mdr_event(
+                "run-skip",
+                "claude-haiku-4-5",
+                2_000,
+                0.04,
+                false,
+                "2026-02-01T10:01:00Z",
+            ),
         ];
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
         let result = print_model_duration_rank(&path, Some("run-keep"));
@@ -12668,7 +15233,14 @@ mod tests {
 
     // ── print_provider_duration_rank ───────────────────────────────────────
 
-    fn make_pdr_event(run_id: &str, provider: &str, duration_ms: u64, cost: f64, success: bool, ts: &str) -> String {
+    fn make_pdr_event(
+        run_id: &str,
+        provider: &str,
+        duration_ms: u64,
+        cost: f64,
+        success: bool,
+        ts: &str,
+    ) -> String {
         format!(
             r#"{{"event_type":"DelegationEnd","run_id":"{run_id}","provider":"{provider}","duration_ms":{duration_ms},"cost_usd":{cost},"success":{success},"timestamp":"{ts}"}}"#
         )
@@ -12679,9 +15251,30 @@ mod tests {
         let dir = tempfile::tempdir().unwrap();
         let path = dir.path().join("p.jsonl");
         let lines = vec![
-            make_pdr_event("run-a", "anthropic", 40_000, 0.90, true,  "2026-02-01T10:00:00Z"),
-            make_pdr_event("run-a", "openai",    15_000, 0.30, true,  "2026-02-01T10:01:00Z"),
-            make_pdr_event("run-a", "google",     2_000, 0.05, false, "2026-02-01T10:02:00Z"),
+            make_pdr_event(
+                "run-a",
+                "anthropic",
+                40_000,
+                0.90,
+                true,
+                "2026-02-01T10:00:00Z",
+            ),
+            make_pdr_event(
+                "run-a",
+                "openai",
+                15_000,
+                0.30,
+                true,
+                "2026-02-01T10:01:00Z",
+            ),
+            make_pdr_event(
+                "run-a",
+                "google",
+                2_000,
+                0.05,
+                false,
+                "2026-02-01T10:02:00Z",
+            ),
         ];
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
         let result = print_provider_duration_rank(&path, None);
@@ -12723,8 +15316,22 @@ mod tests {
         let path = dir.path().join("p.jsonl");
         // slow-prov: avg 35000ms; fast-prov: avg 1500ms
         let lines = vec![
-            make_pdr_event("run-a", "slow-prov", 35_000, 0.70, true, "2026-02-01T10:00:00Z"),
-            make_pdr_event("run-a", "fast-prov",  1_500, 0.03, true, "2026-02-01T10:01:00Z"),
+            make_pdr_event(
+                "run-a",
+                "slow-prov",
+                35_000,
+                0.70,
+                true,
+                "2026-02-01T10:00:00Z",
+            ),
+            make_pdr_event(
+                "run-a",
+                "fast-prov",
+                1_500,
+                0.03,
+                true,
+                "2026-02-01T10:01:00Z",
+            ),
         ];
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
         let result = print_provider_duration_rank(&path, None);
@@ -12737,8 +15344,22 @@ mod tests {
         let dir = tempfile::tempdir().unwrap();
         let path = dir.path().join("p.jsonl");
         let lines = vec![
-            make_pdr_event("run-keep", "anthropic", 25_000, 0.80, true,  "2026-02-01T10:00:00Z"),
-            make_pdr_event("run-skip", "google",     1_000, 0.02, false, "2026-02-01T10:01:00Z"),
+            make_pdr_event(
+                "run-keep",
+                "anthropic",
+                25_000,
+                0.80,
+                true,
+                "2026-02-01T10:00:00Z",
+            ),
+            make_pdr_event(
+                "run-skip",
+                "google",
+                1_000,
+                0.02,
+                false,
+                "2026-02-01T10:01:00Z",
+            ),
         ];
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
         let result = print_provider_duration_rank(&path, Some("run-keep"));
@@ -12748,7 +15369,14 @@ mod tests {
 
     // ── print_run_token_rank ───────────────────────────────────────────────
 
-    fn make_rtr_event(run_id: &str, agent: &str, tokens: u64, cost: f64, success: bool, ts: &str) -> String {
+    fn make_rtr_event(
+        run_id: &str,
+        agent: &str,
+        tokens: u64,
+        cost: f64,
+        success: bool,
+        ts: &str,
+    ) -> String {
         format!(
             r#"{{"event_type":"DelegationEnd","run_id":"{run_id}","agent_name":"{agent}","tokens_used":{tokens},"cost_usd":{cost},"success":{success},"timestamp":"{ts}"}}"#
         )
@@ -12759,9 +15387,30 @@ mod tests {
         let dir = tempfile::tempdir().unwrap();
         let path = dir.path().join("p.jsonl");
         let lines = vec![
-            make_rtr_event("run-alpha", "agent-a", 20_000, 2.00, true,  "2026-02-01T10:00:00Z"),
-            make_rtr_event("run-beta",  "agent-b",  8_000, 0.80, true,  "2026-02-01T10:01:00Z"),
-            make_rtr_event("run-gamma", "agent-c",  2_000, 0.20, false, "2026-02-01T10:02:00Z"),
+            make_rtr_event(
+                "run-alpha",
+                "agent-a",
+                20_000,
+                2.00,
+                true,
+                "2026-02-01T10:00:00Z",
+            ),
+            make_rtr_event(
+                "run-beta",
+                "agent-b",
+                8_000,
+                0.80,
+                true,
+                "2026-02-01T10:01:00Z",
+            ),
+            make_rtr_event(
+                "run-gamma",
+                "agent-c",
+                2_000,
+                0.20,
+                false,
+                "2026-02-01T10:02:00Z",
+            ),
         ];
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
         let result = print_run_token_rank(&path, None);
@@ -12803,9 +15452,30 @@ mod tests {
         let path = dir.path().join("p.jsonl");
         // run-light: 1 del at 1k tok avg; run-heavy: 2 dels at 15k tok avg
         let lines = vec![
-            make_rtr_event("run-light", "agent-a",  1_000, 0.10, true, "2026-02-01T10:00:00Z"),
-            make_rtr_event("run-heavy", "agent-b", 20_000, 2.00, true, "2026-02-01T10:01:00Z"),
-            make_rtr_event("run-heavy", "agent-c", 10_000, 1.00, true, "2026-02-01T10:02:00Z"),
+            make_rtr_event(
+                "run-light",
+                "agent-a",
+                1_000,
+                0.10,
+                true,
+                "2026-02-01T10:00:00Z",
+            ),
+            make_rtr_event(
+                "run-heavy",
+                "agent-b",
+                20_000,
+                2.00,
+                true,
+                "2026-02-01T10:01:00Z",
+            ),
+            make_rtr_event(
+                "run-heavy",
+                "agent-c",
+                10_000,
+                1.00,
+                true,
+                "2026-02-01T10:02:00Z",
+            ),
         ];
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
         let result = print_run_token_rank(&path, None);
@@ -12818,8 +15488,22 @@ mod tests {
         let dir = tempfile::tempdir().unwrap();
         let path = dir.path().join("p.jsonl");
         let lines = vec![
-            make_rtr_event("run-keep", "agent-a", 12_000, 1.20, true,  "2026-02-01T10:00:00Z"),
-            make_rtr_event("run-skip", "agent-b",  3_000, 0.30, false, "2026-02-01T10:01:00Z"),
+            make_rtr_event(
+                "run-keep",
+                "agent-a",
+                12_000,
+                1.20,
+                true,
+                "2026-02-01T10:00:00Z",
+            ),
+            make_rtr_event(
+                "run-skip",
+                "agent-b",
+                3_000,
+                0.30,
+                false,
+                "2026-02-01T10:01:00Z",
+            ),
         ];
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
         let result = print_run_token_rank(&path, Some("run-keep"));
@@ -12829,7 +15513,14 @@ mod tests {
 
     // ── print_run_duration_rank ────────────────────────────────────────────
 
-    fn make_rdr_event(run_id: &str, agent: &str, duration_ms: u64, cost: f64, success: bool, ts: &str) -> String {
+    fn make_rdr_event(
+        run_id: &str,
+        agent: &str,
+        duration_ms: u64,
+        cost: f64,
+        success: bool,
+        ts: &str,
+    ) -> String {
         format!(
             r#"{{"event_type":"DelegationEnd","run_id":"{run_id}","agent_name":"{agent}","duration_ms":{duration_ms},"cost_usd":{cost},"success":{success},"timestamp":"{ts}"}}"#
         )
@@ -12840,9 +15531,30 @@ mod tests {
         let dir = tempfile::tempdir().unwrap();
         let path = dir.path().join("p.jsonl");
         let lines = vec![
-            make_rdr_event("run-alpha", "agent-a", 60_000, 2.00, true,  "2026-02-01T10:00:00Z"),
-            make_rdr_event("run-beta",  "agent-b", 20_000, 0.80, true,  "2026-02-01T10:01:00Z"),
-            make_rdr_event("run-gamma", "agent-c",  3_000, 0.20, false, "2026-02-01T10:02:00Z"),
+            make_rdr_event(
+                "run-alpha",
+                "agent-a",
+                60_000,
+                2.00,
+                true,
+                "2026-02-01T10:00:00Z",
+            ),
+            make_rdr_event(
+                "run-beta",
+                "agent-b",
+                20_000,
+                0.80,
+                true,
+                "2026-02-01T10:01:00Z",
+            ),
+            make_rdr_event(
+                "run-gamma",
+                "agent-c",
+                3_000,
+                0.20,
+                false,
+                "2026-02-01T10:02:00Z",
+            ),
         ];
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
         let result = print_run_duration_rank(&path, None);
@@ -12896,9 +15608,30 @@ mod tests {
         let path = dir.path().join("p.jsonl");
         // run-fast: 1 del at 1k ms avg; run-slow: 2 dels at 40k ms avg
         let lines = vec![
-            make_rdr_event("run-fast", "agent-a",  1_000, 0.10, true, "2026-02-01T10:00:00Z"),
-            make_rdr_event("run-slow", "agent-b", 50_000, 2.00, true, "2026-02-01T10:01:00Z"),
-            make_rdr_event("run-slow", "agent-c", 30_000, 1.00, true, "2026-02-01T10:02:00Z"),
+            make_rdr_event(
+                "run-fast",
+                "agent-a",
+                1_000,
+                0.10,
+                true,
+                "2026-02-01T10:00:00Z",
+            ),
+            make_rdr_event(
+                "run-slow",
+                "agent-b",
+                50_000,
+                2.00,
+                true,
+                "2026-02-01T10:01:00Z",
+            ),
+            make_rdr_event(
+                "run-slow",
+                "agent-c",
+                30_000,
+                1.00,
+                true,
+                "2026-02-01T10:02:00Z",
+            ),
         ];
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
         let result = print_run_duration_rank(&path, None);
@@ -12911,8 +15644,22 @@ mod tests {
         let dir = tempfile::tempdir().unwrap();
         let path = dir.path().join("p.jsonl");
         let lines = vec![
-            make_rdr_event("run-keep", "agent-a", 45_000, 1.50, true,  "2026-02-01T10:00:00Z"),
-            make_rdr_event("run-skip", "agent-b",  2_000, 0.05, false, "2026-02-01T10:01:00Z"),
+            make_rdr_event(
+                "run-keep",
+                "agent-a",
+                45_000,
+                1.50,
+                true,
+                "2026-02-01T10:00:00Z",
+            ),
+            make_rdr_event(
+                "run-skip",
+                "agent-b",
+                2_000,
+                0.05,
+                false,
+                "2026-02-01T10:01:00Z",
+            ),
         ];
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
         let result = print_run_duration_rank(&path, Some("run-keep"));
@@ -12922,7 +15669,14 @@ mod tests {
 
     // ── print_run_success_rank ─────────────────────────────────────────────
 
-    fn make_rsr_event(run_id: &str, agent: &str, tokens: u64, cost: f64, success: bool, ts: &str) -> String {
+    fn make_rsr_event(
+        run_id: &str,
+        agent: &str,
+        tokens: u64,
+        cost: f64,
+        success: bool,
+        ts: &str,
+    ) -> String {
         format!(
             r#"{{"event_type":"DelegationEnd","run_id":"{run_id}","agent_name":"{agent}","tokens_used":{tokens},"cost_usd":{cost},"success":{success},"timestamp":"{ts}"}}"#
         )
@@ -12933,9 +15687,30 @@ mod tests {
         let dir = tempfile::tempdir().unwrap();
         let path = dir.path().join("p.jsonl");
         let lines = vec![
-            make_rsr_event("run-alpha", "agent-a", 5_000, 0.50, true,  "2026-02-01T10:00:00Z"),
-            make_rsr_event("run-beta",  "agent-b", 3_000, 0.30, true,  "2026-02-01T10:01:00Z"),
-            make_rsr_event("run-gamma", "agent-c", 1_000, 0.10, false, "2026-02-01T10:02:00Z"),
+            make_rsr_event(
+                "run-alpha",
+                "agent-a",
+                5_000,
+                0.50,
+                true,
+                "2026-02-01T10:00:00Z",
+            ),
+            make_rsr_event(
+                "run-beta",
+                "agent-b",
+                3_000,
+                0.30,
+                true,
+                "2026-02-01T10:01:00Z",
+            ),
+            make_rsr_event(
+                "run-gamma",
+                "agent-c",
+                1_000,
+                0.10,
+                false,
+                "2026-02-01T10:02:00Z",
+            ),
         ];
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
         let result = print_run_success_rank(&path, None);
@@ -12977,10 +15752,38 @@ mod tests {
         let path = dir.path().join("p.jsonl");
         // run-reliable: 2/2 = 100%; run-flaky: 1/2 = 50%
         let lines = vec![
-            make_rsr_event("run-reliable", "agent-a", 4_000, 0.40, true,  "2026-02-01T10:00:00Z"),
-            make_rsr_event("run-reliable", "agent-b", 4_000, 0.40, true,  "2026-02-01T10:01:00Z"),
-            make_rsr_event("run-flaky",    "agent-c", 3_000, 0.30, true,  "2026-02-01T10:02:00Z"),
-            make_rsr_event("run-flaky",    "agent-d", 3_000, 0.30, false, "2026-02-01T10:03:00Z"),
+            make_rsr_event(
+                "run-reliable",
+                "agent-a",
+                4_000,
+                0.40,
+                true,
+                "2026-02-01T10:00:00Z",
+            ),
+            make_rsr_event(
+                "run-reliable",
+                "agent-b",
+                4_000,
+                0.40,
+                true,
+                "2026-02-01T10:01:00Z",
+            ),
+            make_rsr_event(
+                "run-flaky",
+                "agent-c",
+                3_000,
+                0.30,
+                true,
+                "2026-02-01T10:02:00Z",
+            ),
+            make_rsr_event(
+                "run-flaky",
+                "agent-d",
+                3_000,
+                0.30,
+                false,
+                "2026-02-01T10:03:00Z",
+            ),
         ];
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
         let result = print_run_success_rank(&path, None);
@@ -12993,12 +15796,231 @@ mod tests {
         let dir = tempfile::tempdir().unwrap();
         let path = dir.path().join("p.jsonl");
         let lines = vec![
-            make_rsr_event("run-keep", "agent-a", 5_000, 0.50, true,  "2026-02-01T10:00:00Z"),
-            make_rsr_event("run-skip", "agent-b", 2_000, 0.20, false, "2026-02-01T10:01:00Z"),
+            make_rsr_event(
+                "run-keep",
+                "agent-a",
+                5_000,
+                0.50,
+                true,
+                "2026-02-01T10:00:00Z",
+            ),
+            make_rsr_event(
+                "run-skip",
+                "agent-b",
+                2_000,
+                0.20,
+                false,
+                "2026-02-01T10:01:00Z",
+            ),
         ];
         std::fs::write(&path, lines.join("\n") + "\n").unwrap();
         let result = print_run_success_rank(&path, Some("run-keep"));
         let _ = std::fs::remove_file(&path);
         assert!(result.is_ok());
     }
+
+    // ── Forecast & anomalies ──────────────────────────────────
+
+    #[test]
+    fn daily_cost_series_sums_per_day_in_order() {
+        let events = vec![
+            make_end("r", "a", 0, "2026-03-02T10:00:00Z", 100, 0.20, true),
+            make_end("r", "a", 0, "2026-03-01T10:00:00Z", 100, 0.10, true),
+            make_end("r", "b", 0, "2026-03-02T11:00:00Z", 100, 0.30, true),
+        ];
+        let series = daily_cost_series(&events);
+        assert_eq!(series.len(), 2);
+        assert_eq!(series[0].0, "2026-03-01");
+        assert!((series[0].1 - 0.10).abs() < 1e-9);
+        assert_eq!(series[1].0, "2026-03-02");
+        assert!((series[1].1 - 0.50).abs() < 1e-9);
+    }
+
+    #[test]
+    fn linear_trend_fits_slope_and_intercept() {
+        let (slope, intercept) = linear_trend(&[1.0, 2.0, 3.0, 4.0]);
+        assert!((slope - 1.0).abs() < 1e-9);
+        assert!((intercept - 1.0).abs() < 1e-9);
+
+        let (slope, intercept) = linear_trend(&[5.0, 5.0, 5.0]);
+        assert!(slope.abs() < 1e-9);
+        assert!((intercept - 5.0).abs() < 1e-9);
+
+        let (slope, _) = linear_trend(&[]);
+        assert!(slope.abs() < 1e-9);
+    }
+
+    #[test]
+    fn forecast_month_projects_trend_and_flat() {
+        // $1/day flat for the first 10 days of a 30-day month.
+        let series: Vec<(String, f64)> =
+            (1..=10).map(|d| (format!("2026-04-{d:02}"), 1.0)).collect();
+        let f = forecast_month(&series, "2026-04", 10, 30);
+        assert!((f.month_to_date - 10.0).abs() < 1e-9);
+        assert!((f.avg_daily - 1.0).abs() < 1e-9);
+        assert!((f.projected_trend - 30.0).abs() < 1e-6);
+        assert!((f.projected_flat - 30.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn forecast_month_counts_missing_days_as_zero() {
+        let series = vec![("2026-04-01".to_owned(), 10.0)];
+        let f = forecast_month(&series, "2026-04", 10, 30);
+        assert!((f.month_to_date - 10.0).abs() < 1e-9);
+        assert!((f.avg_daily - 1.0).abs() < 1e-9);
+        // Declining trend never projects negative daily spend.
+        assert!(f.projected_trend >= f.month_to_date);
+    }
+
+    #[test]
+    fn days_in_month_handles_year_end_and_leap() {
+        assert_eq!(days_in_month(2026, 12), 31);
+        assert_eq!(days_in_month(2026, 2), 28);
+        assert_eq!(days_in_month(2028, 2), 29);
+        assert_eq!(days_in_month(2026, 4), 30);
+    }
+
+    #[test]
+    fn rolling_anomalies_flags_spike_after_stable_baseline() {
+        let mut series: Vec<(String, f64)> = (1..=7)
+            .map(|d| (format!("2026-05-{d:02}"), 1.0 + 0.1 * f64::from(d % 2)))
+            .collect();
+        series.push(("2026-05-08".to_owned(), 9.0));
+        let hits = rolling_anomalies(&series, 2.0);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].date, "2026-05-08");
+        assert!(hits[0].sigmas > 2.0);
+    }
+
+    #[test]
+    fn rolling_anomalies_needs_minimum_history() {
+        let series = vec![
+            ("2026-05-01".to_owned(), 1.0),
+            ("2026-05-02".to_owned(), 100.0),
+        ];
+        assert!(rolling_anomalies(&series, 2.0).is_empty());
+    }
+
+    #[test]
+    fn rolling_anomalies_zero_variance_baseline_flags_any_change() {
+        let mut series: Vec<(String, f64)> =
+            (1..=5).map(|d| (format!("2026-05-{d:02}"), 2.0)).collect();
+        series.push(("2026-05-06".to_owned(), 2.5));
+        let hits = rolling_anomalies(&series, 3.0);
+        assert_eq!(hits.len(), 1);
+        assert!(hits[0].sigmas.is_infinite());
+
+        // A steady series stays quiet.
+        let steady: Vec<(String, f64)> =
+            (1..=6).map(|d| (format!("2026-05-{d:02}"), 2.0)).collect();
+        assert!(rolling_anomalies(&steady, 3.0).is_empty());
+    }
+
+    #[test]
+    fn print_forecast_and_anomalies_handle_log_states() {
+        let dir = tempfile::tempdir().unwrap();
+        let missing = dir.path().join("missing.jsonl");
+        assert!(print_forecast(&missing).is_ok());
+        assert!(print_anomalies(&missing, 2.0).is_ok());
+
+        let path = dir.path().join("d.jsonl");
+        let lines: Vec<String> = (1..=8)
+            .map(|d| {
+                make_end(
+                    "run-a",
+                    "agent-a",
+                    0,
+                    &format!("2026-05-{d:02}T10:00:00Z"),
+                    1_000,
+                    if d == 8 { 5.0 } else { 0.10 },
+                    d != 8,
+                )
+                .to_string()
+            })
+            .collect();
+        std::fs::write(&path, lines.join("\n") + "\n").unwrap();
+        assert!(print_forecast(&path).is_ok());
+        assert!(print_anomalies(&path, 2.0).is_ok());
+    }
+
+    #[test]
+    fn html_escape_neutralizes_markup() {
+        assert_eq!(
+            html_escape("<script>\"a\" & 'b'</script>"),
+            "&lt;script&gt;&quot;a&quot; &amp; &#39;b&#39;&lt;/script&gt;"
+        );
+        assert_eq!(html_escape("plain"), "plain");
+    }
+
+    #[test]
+    fn breakdown_by_field_aggregates_and_sorts_by_tokens() {
+        let events = vec![
+            make_start("r1", "agent-a", 0, "2026-01-01T10:00:00Z"),
+            make_end("r1", "agent-a", 0, "2026-01-01T10:00:05Z", 100, 0.01, true),
+            make_start("r1", "agent-b", 0, "2026-01-01T11:00:00Z"),
+            make_end("r1", "agent-b", 0, "2026-01-01T11:00:05Z", 900, 0.09, false),
+        ];
+        let rows = breakdown_by_field(&events, "agent_name");
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].name, "agent-b");
+        assert_eq!(rows[0].total_tokens, 900);
+        assert_eq!(rows[0].success_count, 0);
+        assert_eq!(rows[1].name, "agent-a");
+        assert_eq!(rows[1].delegation_count, 1);
+        assert_eq!(rows[1].success_count, 1);
+        assert!((rows[1].total_cost_usd - 0.01).abs() < 1e-9);
+    }
+
+    #[test]
+    fn render_html_report_is_self_contained() {
+        let events = vec![
+            make_start("r1", "agent-a", 0, "2026-01-01T10:00:00Z"),
+            make_end("r1", "agent-a", 0, "2026-01-01T10:00:05Z", 500, 0.05, true),
+            make_end("r1", "agent-a", 0, "2026-01-02T10:00:05Z", 500, 0.10, true),
+        ];
+        let html = render_html_report(&events, "2026-01-03 00:00 UTC");
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("Agent breakdown"));
+        assert!(html.contains("Model breakdown"));
+        assert!(html.contains("Provider breakdown"));
+        assert!(html.contains("agent-a"));
+        assert!(html.contains("2026-01-01"));
+        // No external fetches: everything inline.
+        assert!(!html.contains("http://"));
+        assert!(!html.contains("https://"));
+        assert!(!html.contains("src="));
+    }
+
+    #[test]
+    fn render_html_report_escapes_log_sourced_strings() {
+        let mut start = make_start("r1", "x", 0, "2026-01-01T10:00:00Z");
+        start["agent_name"] = Value::String("<img src=x>".to_owned());
+        let mut end = make_end("r1", "x", 0, "2026-01-01T10:00:05Z", 100, 0.01, true);
+        end["agent_name"] = Value::String("</script><b>".to_owned());
+        let html = render_html_report(&[start, end], "2026-01-03 00:00 UTC");
+        assert!(!html.contains("<img src=x>"));
+        assert!(html.contains("&lt;img src=x&gt;"));
+        assert!(!html.contains("</script><b>"));
+    }
+
+    #[test]
+    fn write_html_report_writes_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let log = dir.path().join("d.jsonl");
+        let lines = vec![
+            make_start("r1", "agent-a", 0, "2026-01-01T10:00:00Z").to_string(),
+            make_end("r1", "agent-a", 0, "2026-01-01T10:00:05Z", 500, 0.05, true).to_string(),
+        ];
+        std::fs::write(&log, lines.join("\n") + "\n").unwrap();
+        let out = dir.path().join("report.html");
+        write_html_report(&log, &out).unwrap();
+        let html = std::fs::read_to_string(&out).unwrap();
+        assert!(html.contains("ZeroClaw Delegation Report"));
+
+        // Empty log: no file written, no error.
+        let missing = dir.path().join("missing.jsonl");
+        let out2 = dir.path().join("report2.html");
+        assert!(write_html_report(&missing, &out2).is_ok());
+        assert!(!out2.exists());
+    }
 }
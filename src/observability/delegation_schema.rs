@@ -0,0 +1,584 @@
+//! Versioned delegation log schema and strict JSONL parser.
+//!
+//! The delegation log (`~/.zeroclaw/state/delegation.jsonl`) is consumed by
+//! the reporting CLI and external UIs. The reporters parse each line as an
+//! untyped `serde_json::Value` and silently drop anything that doesn't fit —
+//! which hides corruption. This module defines the typed, versioned record
+//! shapes the logger writes, plus a strict line parser used by
+//! `zeroclaw delegations validate` to surface (and optionally quarantine)
+//! malformed lines.
+//!
+//! Lines written before versioning was introduced carry no `schema_version`
+//! field; the parser accepts them as legacy records rather than rejecting
+//! the entire historical log.
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Current schema version stamped onto every newly written log line.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// One delegation log line: an optional schema version plus the event record.
+///
+/// `schema_version` is `None` for legacy lines written before versioning
+/// existed; new lines always carry [`SCHEMA_VERSION`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DelegationLogLine {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema_version: Option<u32>,
+    #[serde(flatten)]
+    pub record: DelegationRecord,
+}
+
+/// Typed event record, tagged by the `event_type` field the logger writes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "event_type")]
+pub enum DelegationRecord {
+    DelegationStart(DelegationStartRecord),
+    DelegationEnd(DelegationEndRecord),
+    ToolCallStart(ToolCallStartRecord),
+    ToolCallEnd(ToolCallEndRecord),
+    CacheHit(CacheHitRecord),
+    RunCancelled(RunCancelledRecord),
+}
+
+/// A delegation began (agent spawned).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DelegationStartRecord {
+    pub run_id: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel: Option<String>,
+    pub agent_name: String,
+    pub provider: String,
+    pub model: String,
+    pub depth: u32,
+    pub agentic: bool,
+    pub workflow: Option<String>,
+    pub timestamp: String,
+}
+
+/// A delegation completed (successfully or not).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DelegationEndRecord {
+    pub run_id: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel: Option<String>,
+    pub agent_name: String,
+    pub workflow: Option<String>,
+    pub provider: String,
+    pub model: String,
+    pub depth: u32,
+    pub duration_ms: u64,
+    pub success: bool,
+    pub error_message: Option<String>,
+    pub tokens_used: Option<u64>,
+    pub cost_usd: Option<f64>,
+    pub node: Option<String>,
+    pub timestamp: String,
+}
+
+/// A tool call started.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToolCallStartRecord {
+    pub run_id: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel: Option<String>,
+    pub tool: String,
+    pub timestamp: String,
+}
+
+/// A tool call finished.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToolCallEndRecord {
+    pub run_id: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel: Option<String>,
+    pub tool: String,
+    pub duration_ms: u64,
+    pub success: bool,
+    pub output_bytes: Option<u64>,
+    pub timestamp: String,
+}
+
+/// A provider cache hit.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CacheHitRecord {
+    pub run_id: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel: Option<String>,
+    pub provider: String,
+    pub model: String,
+    pub timestamp: String,
+}
+
+/// An in-flight run was cancelled.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunCancelledRecord {
+    pub run_id: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel: Option<String>,
+    pub scope: String,
+    pub timestamp: String,
+}
+
+/// Outcome of strictly parsing one log line.
+#[derive(Debug)]
+pub enum ParsedLine {
+    /// Line carries the current `schema_version` and parses cleanly.
+    Current(DelegationLogLine),
+    /// Pre-versioning line (no `schema_version` field) that parses cleanly.
+    Legacy(DelegationLogLine),
+}
+
+/// Strictly parse one delegation log line.
+///
+/// Returns an error when the line is not valid JSON, is missing required
+/// fields for its `event_type`, or carries a `schema_version` this build
+/// doesn't understand (fail fast rather than misreading future formats).
+pub fn parse_line(line: &str) -> Result<ParsedLine> {
+    let parsed: DelegationLogLine =
+        serde_json::from_str(line).context("not a valid delegation record")?;
+    match parsed.schema_version {
+        None => Ok(ParsedLine::Legacy(parsed)),
+        Some(SCHEMA_VERSION) => Ok(ParsedLine::Current(parsed)),
+        Some(other) => bail!(
+            "unsupported schema_version {other} (this build understands up to {SCHEMA_VERSION})"
+        ),
+    }
+}
+
+/// One malformed line found during validation.
+#[derive(Debug)]
+pub struct LineIssue {
+    /// 1-based line number in the log file.
+    pub line_number: usize,
+    /// Human-readable parse failure reason.
+    pub reason: String,
+}
+
+/// Aggregate result of validating a delegation log.
+#[derive(Debug, Default)]
+pub struct ValidationReport {
+    /// Non-empty lines examined.
+    pub total: usize,
+    /// Lines carrying the current schema version.
+    pub current: usize,
+    /// Pre-versioning lines accepted via the legacy fallback.
+    pub legacy: usize,
+    /// Lines that failed strict parsing.
+    pub issues: Vec<LineIssue>,
+}
+
+/// Validate every line of the delegation log against the typed schema.
+///
+/// Empty lines are skipped; everything else is classified as current,
+/// legacy, or malformed. A missing log file yields an empty report.
+pub fn validate_log(log_path: &Path) -> Result<ValidationReport> {
+    let mut report = ValidationReport::default();
+    if !log_path.exists() {
+        return Ok(report);
+    }
+    let content = std::fs::read_to_string(log_path)?;
+    for (idx, line) in content.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        report.total += 1;
+        match parse_line(line) {
+            Ok(ParsedLine::Current(_)) => report.current += 1,
+            Ok(ParsedLine::Legacy(_)) => report.legacy += 1,
+            Err(e) => report.issues.push(LineIssue {
+                line_number: idx + 1,
+                reason: format!("{e:#}"),
+            }),
+        }
+    }
+    Ok(report)
+}
+
+/// Path of the quarantine file that sits next to the log.
+fn quarantine_path(log_path: &Path) -> PathBuf {
+    let mut s = log_path.as_os_str().to_owned();
+    s.push(".quarantine");
+    PathBuf::from(s)
+}
+
+/// Move malformed lines out of the log into `<log>.quarantine`.
+///
+/// Malformed raw lines are appended to the quarantine file (so repeated
+/// runs never lose data), then the log is rewritten atomically (temp file
+/// then rename) containing only the lines that parse. Returns the number
+/// of lines quarantined.
+pub fn quarantine_malformed(log_path: &Path) -> Result<usize> {
+    let content = std::fs::read_to_string(log_path)?;
+    let mut kept = String::new();
+    let mut bad = String::new();
+    let mut bad_count = 0usize;
+    for line in content.lines() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        if parse_line(line).is_ok() {
+            kept.push_str(line);
+            kept.push('\n');
+        } else {
+            bad.push_str(line);
+            bad.push('\n');
+            bad_count += 1;
+        }
+    }
+    if bad_count == 0 {
+        return Ok(0);
+    }
+
+    use std::io::Write as _;
+    let mut quarantine = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(quarantine_path(log_path))?;
+    quarantine.write_all(bad.as_bytes())?;
+
+    // Atomic write: serialize to a temp file, then rename over the original.
+    let tmp_path = {
+        let mut s = log_path.as_os_str().to_owned();
+        s.push(".tmp");
+        PathBuf::from(s)
+    };
+    std::fs::write(&tmp_path, kept)?;
+    std::fs::rename(&tmp_path, log_path)?;
+
+    Ok(bad_count)
+}
+
+/// Print a schema validation report for the delegation log.
+///
+/// With `quarantine = true`, malformed lines are moved to
+/// `<log>.quarantine` and the log is rewritten with only valid lines.
+pub fn print_validate(log_path: &Path, quarantine: bool) -> Result<()> {
+    if !log_path.exists() {
+        println!("No delegation log found at: {}", log_path.display());
+        println!("Nothing to validate.");
+        return Ok(());
+    }
+
+    let report = validate_log(log_path)?;
+    if report.total == 0 {
+        println!("Log is empty — nothing to validate.");
+        return Ok(());
+    }
+
+    println!("Validated {} line(s):", report.total);
+    println!("  Current (v{}):  {}", SCHEMA_VERSION, report.current);
+    println!("  Legacy:        {}", report.legacy);
+    println!("  Malformed:     {}", report.issues.len());
+
+    if report.issues.is_empty() {
+        println!("No malformed lines found.");
+        return Ok(());
+    }
+
+    println!();
+    for issue in &report.issues {
+        println!("  line {}: {}", issue.line_number, issue.reason);
+    }
+
+    if quarantine {
+        let moved = quarantine_malformed(log_path)?;
+        println!();
+        println!(
+            "Moved {} malformed line(s) to: {}",
+            moved,
+            quarantine_path(log_path).display()
+        );
+    } else {
+        println!();
+        println!(
+            "Run `zeroclaw delegations validate --quarantine` to move malformed \
+             lines to {}.",
+            quarantine_path(log_path).display()
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn current_start_line() -> String {
+        serde_json::json!({
+            "schema_version": SCHEMA_VERSION,
+            "event_type": "DelegationStart",
+            "run_id": "run-aaa",
+            "agent_name": "research",
+            "provider": "anthropic",
+            "model": "claude-sonnet-4",
+            "depth": 0,
+            "agentic": true,
+            "workflow": null,
+            "timestamp": "2026-01-01T00:00:00Z",
+        })
+        .to_string()
+    }
+
+    fn legacy_end_line() -> String {
+        serde_json::json!({
+            "event_type": "DelegationEnd",
+            "run_id": "run-aaa",
+            "agent_name": "research",
+            "workflow": null,
+            "provider": "anthropic",
+            "model": "claude-sonnet-4",
+            "depth": 0,
+            "duration_ms": 120,
+            "success": true,
+            "error_message": null,
+            "tokens_used": 500,
+            "cost_usd": 0.0015,
+            "node": null,
+            "timestamp": "2026-01-01T00:00:01Z",
+        })
+        .to_string()
+    }
+
+    #[test]
+    fn parse_line_accepts_current_version() {
+        match parse_line(&current_start_line()).unwrap() {
+            ParsedLine::Current(line) => {
+                assert_eq!(line.schema_version, Some(SCHEMA_VERSION));
+                assert!(matches!(line.record, DelegationRecord::DelegationStart(_)));
+            }
+            ParsedLine::Legacy(_) => panic!("versioned line must classify as current"),
+        }
+    }
+
+    #[test]
+    fn parse_line_accepts_legacy_without_version() {
+        match parse_line(&legacy_end_line()).unwrap() {
+            ParsedLine::Legacy(line) => {
+                assert_eq!(line.schema_version, None);
+                assert!(matches!(line.record, DelegationRecord::DelegationEnd(_)));
+            }
+            ParsedLine::Current(_) => panic!("unversioned line must classify as legacy"),
+        }
+    }
+
+    #[test]
+    fn parse_line_rejects_future_version() {
+        let line = current_start_line().replace(
+            &format!("\"schema_version\":{SCHEMA_VERSION}"),
+            "\"schema_version\":99",
+        );
+        let err = parse_line(&line).unwrap_err().to_string();
+        assert!(err.contains("unsupported schema_version 99"), "got: {err}");
+    }
+
+    #[test]
+    fn parse_line_accepts_tagged_records() {
+        let line = serde_json::json!({
+            "schema_version": SCHEMA_VERSION,
+            "event_type": "ToolCallStart",
+            "run_id": "run-aaa",
+            "tags": ["project:foo"],
+            "tool": "shell",
+            "timestamp": "2026-01-01T00:00:00Z",
+        })
+        .to_string();
+        match parse_line(&line).unwrap() {
+            ParsedLine::Current(parsed) => match parsed.record {
+                DelegationRecord::ToolCallStart(rec) => {
+                    assert_eq!(rec.tags, vec!["project:foo".to_string()]);
+                }
+                other => panic!("unexpected record: {other:?}"),
+            },
+            ParsedLine::Legacy(_) => panic!("versioned line must classify as current"),
+        }
+    }
+
+    #[test]
+    fn parse_line_accepts_channel_attributed_records() {
+        let line = serde_json::json!({
+            "schema_version": SCHEMA_VERSION,
+            "event_type": "ToolCallStart",
+            "run_id": "run-aaa",
+            "channel": "telegram",
+            "tool": "shell",
+            "timestamp": "2026-01-01T00:00:00Z",
+        })
+        .to_string();
+        match parse_line(&line).unwrap() {
+            ParsedLine::Current(parsed) => match parsed.record {
+                DelegationRecord::ToolCallStart(rec) => {
+                    assert_eq!(rec.channel.as_deref(), Some("telegram"));
+                }
+                other => panic!("unexpected record: {other:?}"),
+            },
+            ParsedLine::Legacy(_) => panic!("versioned line must classify as current"),
+        }
+    }
+
+    #[test]
+    fn parse_line_rejects_missing_required_field() {
+        // DelegationStart without run_id
+        let line = serde_json::json!({
+            "event_type": "DelegationStart",
+            "agent_name": "research",
+            "provider": "anthropic",
+            "model": "claude-sonnet-4",
+            "depth": 0,
+            "agentic": true,
+            "timestamp": "2026-01-01T00:00:00Z",
+        })
+        .to_string();
+        assert!(parse_line(&line).is_err());
+    }
+
+    #[test]
+    fn parse_line_accepts_run_cancelled() {
+        let line = serde_json::json!({
+            "schema_version": SCHEMA_VERSION,
+            "event_type": "RunCancelled",
+            "run_id": "run-aaa",
+            "scope": "job:job-1",
+            "timestamp": "2026-01-01T00:00:00Z",
+        })
+        .to_string();
+        match parse_line(&line).unwrap() {
+            ParsedLine::Current(parsed) => match parsed.record {
+                DelegationRecord::RunCancelled(rec) => {
+                    assert_eq!(rec.scope, "job:job-1");
+                }
+                other => panic!("unexpected record: {other:?}"),
+            },
+            ParsedLine::Legacy(_) => panic!("versioned line must classify as current"),
+        }
+    }
+
+    #[test]
+    fn parse_line_rejects_unknown_event_type() {
+        let line = serde_json::json!({
+            "event_type": "NotARealEvent",
+            "run_id": "run-aaa",
+        })
+        .to_string();
+        assert!(parse_line(&line).is_err());
+    }
+
+    #[test]
+    fn parse_line_rejects_invalid_json() {
+        assert!(parse_line("{not json").is_err());
+    }
+
+    #[test]
+    fn validate_log_counts_current_legacy_and_malformed() {
+        let tmp = tempfile::NamedTempFile::new().unwrap();
+        let content = format!(
+            "{}\n{}\n\n{{broken\n",
+            current_start_line(),
+            legacy_end_line()
+        );
+        std::fs::write(tmp.path(), content).unwrap();
+
+        let report = validate_log(tmp.path()).unwrap();
+        assert_eq!(report.total, 3);
+        assert_eq!(report.current, 1);
+        assert_eq!(report.legacy, 1);
+        assert_eq!(report.issues.len(), 1);
+        assert_eq!(report.issues[0].line_number, 4);
+    }
+
+    #[test]
+    fn validate_log_missing_file_is_empty_report() {
+        let report = validate_log(Path::new("/nonexistent/delegation.jsonl")).unwrap();
+        assert_eq!(report.total, 0);
+        assert!(report.issues.is_empty());
+    }
+
+    #[test]
+    fn quarantine_moves_malformed_and_keeps_valid() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let log = tmp.path().join("delegation.jsonl");
+        let content = format!(
+            "{}\n{{broken\n{}\n",
+            current_start_line(),
+            legacy_end_line()
+        );
+        std::fs::write(&log, content).unwrap();
+
+        let moved = quarantine_malformed(&log).unwrap();
+        assert_eq!(moved, 1);
+
+        let kept = std::fs::read_to_string(&log).unwrap();
+        assert_eq!(kept.lines().count(), 2);
+        assert!(!kept.contains("{broken"));
+
+        let quarantined = std::fs::read_to_string(quarantine_path(&log)).unwrap();
+        assert_eq!(quarantined.trim(), "{broken");
+    }
+
+    #[test]
+    fn quarantine_is_noop_on_clean_log() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let log = tmp.path().join("delegation.jsonl");
+        std::fs::write(&log, format!("{}\n", current_start_line())).unwrap();
+
+        assert_eq!(quarantine_malformed(&log).unwrap(), 0);
+        assert!(!quarantine_path(&log).exists());
+    }
+
+    #[test]
+    fn quarantine_appends_across_runs() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let log = tmp.path().join("delegation.jsonl");
+
+        std::fs::write(&log, "{bad-one\n").unwrap();
+        quarantine_malformed(&log).unwrap();
+        std::fs::write(&log, "{bad-two\n").unwrap();
+        quarantine_malformed(&log).unwrap();
+
+        let quarantined = std::fs::read_to_string(quarantine_path(&log)).unwrap();
+        assert!(quarantined.contains("{bad-one"));
+        assert!(quarantined.contains("{bad-two"));
+    }
+
+    #[test]
+    fn logger_output_round_trips_through_schema() {
+        use crate::observability::traits::{Observer as _, ObserverEvent};
+        use crate::observability::DelegationEventObserver;
+
+        let tmp = tempfile::NamedTempFile::new().unwrap();
+        let observer = DelegationEventObserver::new(tmp.path().to_path_buf());
+        observer.record_event(&ObserverEvent::DelegationStart {
+            agent_name: "research".into(),
+            provider: "anthropic".into(),
+            model: "claude-sonnet-4".into(),
+            depth: 0,
+            agentic: true,
+            workflow: None,
+        });
+        observer.record_event(&ObserverEvent::ToolCall {
+            tool: "shell".into(),
+            duration: std::time::Duration::from_millis(42),
+            success: true,
+            output_bytes: Some(10),
+        });
+
+        let report = validate_log(tmp.path()).unwrap();
+        assert_eq!(report.total, 2);
+        assert_eq!(report.current, 2);
+        assert!(report.issues.is_empty());
+    }
+}
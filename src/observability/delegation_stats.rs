@@ -8,7 +8,6 @@
 /// Useful for health-check endpoints, CLI summaries, and test assertions
 /// that need programmatic access to delegation stats without parsing the
 /// JSONL log.
-
 use super::traits::{Observer, ObserverEvent, ObserverMetric};
 use std::any::Any;
 use std::sync::{Arc, Mutex};
@@ -70,10 +69,7 @@ impl DelegationStatsObserver {
     /// Acquires the internal lock for the duration of the copy only.
     /// Safe to call from any thread at any time.
     pub fn snapshot(&self) -> DelegationStatsSnapshot {
-        self.inner
-            .lock()
-            .unwrap_or_else(|e| e.into_inner())
-            .clone()
+        self.inner.lock().unwrap_or_else(|e| e.into_inner()).clone()
     }
 }
 
@@ -143,6 +139,7 @@ mod tests {
             model: "claude-sonnet-4-6".into(),
             depth,
             agentic: true,
+            workflow: None,
         }
     }
 
@@ -157,6 +154,8 @@ mod tests {
             error_message: None,
             tokens_used: tokens,
             cost_usd: cost,
+            workflow: None,
+            node: None,
         }
     }
 
@@ -171,6 +170,8 @@ mod tests {
             error_message: Some("timeout".into()),
             tokens_used: None,
             cost_usd: None,
+            workflow: None,
+            node: None,
         }
     }
 
@@ -0,0 +1,502 @@
+//! Event-bus publisher — pushes significant runtime events to MQTT or NATS.
+//!
+//! Publishes delegation ends, task completions, approval requests, and
+//! alerts as JSON to `[events.bus]` topics so home-automation and other
+//! systems can react to ZeroClaw activity without polling. Uses minimal
+//! hand-rolled MQTT 3.1.1 (QoS 0) and NATS clients over plain TCP to keep
+//! the dependency footprint flat; publishing is fire-and-forget and never
+//! blocks the agent runtime.
+
+use super::traits::{Observer, ObserverEvent, ObserverMetric};
+use crate::config::EventBusConfig;
+use std::any::Any;
+use std::io::{BufRead, BufReader, Read as _, Write};
+use std::net::TcpStream;
+use std::sync::mpsc;
+use std::time::Duration;
+
+/// Queued events waiting for the publisher thread; beyond this, events drop.
+const QUEUE_CAPACITY: usize = 256;
+/// TCP connect/read/write timeout for broker I/O.
+const IO_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// One message handed to the publisher thread.
+struct Publication {
+    /// Topic suffix (e.g. `delegation/end`); prefixed per protocol.
+    suffix: &'static str,
+    payload: String,
+}
+
+/// Observer that forwards significant events to an MQTT or NATS broker.
+///
+/// `record_event` only serializes and enqueues; a dedicated thread owns the
+/// broker connection and reconnects as needed. A full queue or broker
+/// outage drops events with a warning rather than back-pressuring the
+/// agent loop.
+pub struct EventBusObserver {
+    tx: mpsc::SyncSender<Publication>,
+}
+
+impl EventBusObserver {
+    /// Spawn the publisher thread for the given `[events.bus]` config.
+    ///
+    /// Fails fast on an unsupported protocol so a typo cannot silently
+    /// disable event publishing.
+    pub fn new(config: &EventBusConfig) -> anyhow::Result<Self> {
+        match config.protocol.as_str() {
+            "mqtt" | "nats" => {}
+            other => anyhow::bail!(
+                "Unsupported [events.bus] protocol '{other}' (expected \"mqtt\" or \"nats\")"
+            ),
+        }
+        let (tx, rx) = mpsc::sync_channel(QUEUE_CAPACITY);
+        let worker_config = config.clone();
+        std::thread::Builder::new()
+            .name("event-bus".to_string())
+            .spawn(move || publisher_loop(&worker_config, &rx))
+            .map_err(|e| anyhow::anyhow!("Failed to spawn event-bus publisher thread: {e}"))?;
+        Ok(Self { tx })
+    }
+}
+
+impl Observer for EventBusObserver {
+    fn record_event(&self, event: &ObserverEvent) {
+        let Some(publication) = publication_for(event) else {
+            return;
+        };
+        if self.tx.try_send(publication).is_err() {
+            tracing::warn!("Event bus: queue full or publisher stopped; event dropped");
+        }
+    }
+
+    fn record_metric(&self, _metric: &ObserverMetric) {}
+
+    fn name(&self) -> &str {
+        "event_bus"
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Map a runtime event to its bus message; `None` for events that are not
+/// published (per-call noise like LLM requests and tool starts).
+fn publication_for(event: &ObserverEvent) -> Option<Publication> {
+    let (suffix, body) = match event {
+        ObserverEvent::DelegationEnd {
+            agent_name,
+            provider,
+            model,
+            depth,
+            duration,
+            success,
+            error_message,
+            tokens_used,
+            cost_usd,
+            workflow,
+            node,
+        } => (
+            "delegation/end",
+            serde_json::json!({
+                "event": "delegation_end",
+                "agent": agent_name,
+                "provider": provider,
+                "model": model,
+                "depth": depth,
+                "duration_ms": u64::try_from(duration.as_millis()).unwrap_or(u64::MAX),
+                "success": success,
+                "error": error_message,
+                "tokens_used": tokens_used,
+                "cost_usd": cost_usd,
+                "workflow": workflow,
+                "node": node,
+            }),
+        ),
+        ObserverEvent::AgentEnd {
+            provider,
+            model,
+            duration,
+            tokens_used,
+            cost_usd,
+        } => (
+            "task/finished",
+            serde_json::json!({
+                "event": "task_finished",
+                "provider": provider,
+                "model": model,
+                "duration_ms": u64::try_from(duration.as_millis()).unwrap_or(u64::MAX),
+                "tokens_used": tokens_used,
+                "cost_usd": cost_usd,
+            }),
+        ),
+        ObserverEvent::ApprovalRequested { tool, channel } => (
+            "approval/requested",
+            serde_json::json!({
+                "event": "approval_requested",
+                "tool": tool,
+                "channel": channel,
+            }),
+        ),
+        ObserverEvent::Error { component, message } => (
+            "alert",
+            serde_json::json!({
+                "event": "alert",
+                "component": component,
+                "message": message,
+            }),
+        ),
+        _ => return None,
+    };
+    let mut body = body;
+    if let Some(map) = body.as_object_mut() {
+        map.insert(
+            "ts".to_string(),
+            serde_json::json!(chrono::Utc::now().timestamp()),
+        );
+    }
+    Some(Publication {
+        suffix,
+        payload: body.to_string(),
+    })
+}
+
+/// Full topic for a suffix: `/`-separated for MQTT, `.`-separated for NATS.
+fn topic_for(config: &EventBusConfig, suffix: &str) -> String {
+    let joined = format!("{}/{}", config.topic_prefix.trim_end_matches('/'), suffix);
+    if config.protocol == "nats" {
+        joined.replace('/', ".")
+    } else {
+        joined
+    }
+}
+
+/// Publisher thread: drain the queue, lazily (re)connecting to the broker.
+/// A failed publish retries once on a fresh connection, then drops.
+fn publisher_loop(config: &EventBusConfig, rx: &mpsc::Receiver<Publication>) {
+    let mut connection: Option<BusConnection> = None;
+    while let Ok(publication) = rx.recv() {
+        let topic = topic_for(config, publication.suffix);
+        for attempt in 0..2 {
+            if connection.is_none() {
+                match BusConnection::connect(config) {
+                    Ok(conn) => connection = Some(conn),
+                    Err(e) => {
+                        tracing::warn!("Event bus: broker connection failed: {e:#}");
+                        break;
+                    }
+                }
+            }
+            match connection
+                .as_mut()
+                .expect("connection set above")
+                .publish(&topic, publication.payload.as_bytes())
+            {
+                Ok(()) => break,
+                Err(e) => {
+                    connection = None;
+                    if attempt == 1 {
+                        tracing::warn!("Event bus: publish to '{topic}' failed: {e:#}");
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A live broker connection, already past the protocol handshake.
+struct BusConnection {
+    stream: TcpStream,
+    protocol: Protocol,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Protocol {
+    Mqtt,
+    Nats,
+}
+
+impl BusConnection {
+    fn connect(config: &EventBusConfig) -> anyhow::Result<Self> {
+        use anyhow::Context as _;
+
+        let addr = config
+            .url
+            .parse::<std::net::SocketAddr>()
+            .or_else(|_| {
+                use std::net::ToSocketAddrs as _;
+                config
+                    .url
+                    .to_socket_addrs()?
+                    .next()
+                    .ok_or_else(|| std::io::Error::other("no addresses resolved"))
+            })
+            .with_context(|| format!("Invalid [events.bus] url '{}'", config.url))?;
+        let stream = TcpStream::connect_timeout(&addr, IO_TIMEOUT)
+            .with_context(|| format!("Failed to connect to event bus at {}", config.url))?;
+        stream.set_read_timeout(Some(IO_TIMEOUT))?;
+        stream.set_write_timeout(Some(IO_TIMEOUT))?;
+
+        let mut conn = match config.protocol.as_str() {
+            "mqtt" => Self {
+                stream,
+                protocol: Protocol::Mqtt,
+            },
+            _ => Self {
+                stream,
+                protocol: Protocol::Nats,
+            },
+        };
+        match conn.protocol {
+            Protocol::Mqtt => conn.mqtt_handshake(config)?,
+            Protocol::Nats => conn.nats_handshake()?,
+        }
+        Ok(conn)
+    }
+
+    /// MQTT 3.1.1 CONNECT / CONNACK exchange (clean session, QoS 0 only).
+    fn mqtt_handshake(&mut self, config: &EventBusConfig) -> anyhow::Result<()> {
+        let client_id = format!("zeroclaw-{}", std::process::id());
+        let packet = encode_mqtt_connect(
+            &client_id,
+            config.username.as_deref(),
+            config.password.as_deref(),
+        );
+        self.stream.write_all(&packet)?;
+
+        let mut connack = [0u8; 4];
+        self.stream.read_exact(&mut connack)?;
+        anyhow::ensure!(
+            connack[0] == 0x20 && connack[3] == 0x00,
+            "MQTT broker refused connection (CONNACK return code {})",
+            connack[3]
+        );
+        Ok(())
+    }
+
+    /// NATS INFO / CONNECT exchange (no auth; verbose off).
+    fn nats_handshake(&mut self) -> anyhow::Result<()> {
+        let mut reader = BufReader::new(self.stream.try_clone()?);
+        let mut info_line = String::new();
+        reader.read_line(&mut info_line)?;
+        anyhow::ensure!(
+            info_line.starts_with("INFO"),
+            "Unexpected NATS server greeting"
+        );
+        self.stream
+            .write_all(b"CONNECT {\"verbose\":false,\"name\":\"zeroclaw\"}\r\n")?;
+        Ok(())
+    }
+
+    fn publish(&mut self, topic: &str, payload: &[u8]) -> anyhow::Result<()> {
+        match self.protocol {
+            Protocol::Mqtt => {
+                let packet = encode_mqtt_publish(topic, payload);
+                self.stream.write_all(&packet)?;
+            }
+            Protocol::Nats => {
+                let header = format!("PUB {topic} {}\r\n", payload.len());
+                self.stream.write_all(header.as_bytes())?;
+                self.stream.write_all(payload)?;
+                self.stream.write_all(b"\r\n")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// MQTT remaining-length varint (up to 4 bytes, 7 bits each).
+fn encode_mqtt_remaining_length(mut len: usize) -> Vec<u8> {
+    let mut out = Vec::with_capacity(4);
+    loop {
+        let mut byte = u8::try_from(len % 128).unwrap_or(0);
+        len /= 128;
+        if len > 0 {
+            byte |= 0x80;
+        }
+        out.push(byte);
+        if len == 0 {
+            return out;
+        }
+    }
+}
+
+fn push_mqtt_string(out: &mut Vec<u8>, value: &str) {
+    let len = u16::try_from(value.len()).unwrap_or(u16::MAX);
+    out.extend_from_slice(&len.to_be_bytes());
+    out.extend_from_slice(&value.as_bytes()[..usize::from(len)]);
+}
+
+/// MQTT 3.1.1 CONNECT packet: clean session, keepalive off, optional
+/// username/password.
+fn encode_mqtt_connect(client_id: &str, username: Option<&str>, password: Option<&str>) -> Vec<u8> {
+    let mut flags: u8 = 0x02; // clean session
+    if username.is_some() {
+        flags |= 0x80;
+    }
+    if password.is_some() {
+        flags |= 0x40;
+    }
+
+    let mut body = Vec::new();
+    push_mqtt_string(&mut body, "MQTT");
+    body.push(0x04); // protocol level 3.1.1
+    body.push(flags);
+    body.extend_from_slice(&0u16.to_be_bytes()); // keepalive disabled
+    push_mqtt_string(&mut body, client_id);
+    if let Some(username) = username {
+        push_mqtt_string(&mut body, username);
+    }
+    if let Some(password) = password {
+        push_mqtt_string(&mut body, password);
+    }
+
+    let mut packet = vec![0x10];
+    packet.extend_from_slice(&encode_mqtt_remaining_length(body.len()));
+    packet.extend_from_slice(&body);
+    packet
+}
+
+/// MQTT PUBLISH packet at QoS 0 (no packet identifier).
+fn encode_mqtt_publish(topic: &str, payload: &[u8]) -> Vec<u8> {
+    let mut body = Vec::new();
+    push_mqtt_string(&mut body, topic);
+    body.extend_from_slice(payload);
+
+    let mut packet = vec![0x30];
+    packet.extend_from_slice(&encode_mqtt_remaining_length(body.len()));
+    packet.extend_from_slice(&body);
+    packet
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bus_config(protocol: &str) -> EventBusConfig {
+        EventBusConfig {
+            protocol: protocol.to_string(),
+            url: "127.0.0.1:1".to_string(),
+            topic_prefix: "zeroclaw".to_string(),
+            username: None,
+            password: None,
+        }
+    }
+
+    #[test]
+    fn observer_rejects_unknown_protocol() {
+        let err = EventBusObserver::new(&bus_config("amqp")).err().unwrap();
+        assert!(err.to_string().contains("amqp"));
+    }
+
+    #[test]
+    fn observer_name_is_event_bus() {
+        let observer = EventBusObserver::new(&bus_config("nats")).unwrap();
+        assert_eq!(observer.name(), "event_bus");
+    }
+
+    #[test]
+    fn publication_covers_significant_events_only() {
+        let end = ObserverEvent::AgentEnd {
+            provider: "openrouter".into(),
+            model: "test-model".into(),
+            duration: Duration::from_millis(1200),
+            tokens_used: Some(42),
+            cost_usd: Some(0.01),
+        };
+        let publication = publication_for(&end).unwrap();
+        assert_eq!(publication.suffix, "task/finished");
+        let body: serde_json::Value = serde_json::from_str(&publication.payload).unwrap();
+        assert_eq!(body["event"], "task_finished");
+        assert_eq!(body["duration_ms"], 1200);
+        assert!(body["ts"].is_i64());
+
+        let approval = ObserverEvent::ApprovalRequested {
+            tool: "shell".into(),
+            channel: "cli".into(),
+        };
+        assert_eq!(
+            publication_for(&approval).unwrap().suffix,
+            "approval/requested"
+        );
+
+        assert!(publication_for(&ObserverEvent::TurnComplete).is_none());
+        assert!(publication_for(&ObserverEvent::HeartbeatTick).is_none());
+        assert!(publication_for(&ObserverEvent::ToolCallStart {
+            tool: "shell".into()
+        })
+        .is_none());
+    }
+
+    #[test]
+    fn topic_separator_follows_protocol() {
+        assert_eq!(
+            topic_for(&bus_config("mqtt"), "delegation/end"),
+            "zeroclaw/delegation/end"
+        );
+        assert_eq!(
+            topic_for(&bus_config("nats"), "delegation/end"),
+            "zeroclaw.delegation.end"
+        );
+    }
+
+    #[test]
+    fn mqtt_remaining_length_uses_varint_encoding() {
+        assert_eq!(encode_mqtt_remaining_length(0), vec![0x00]);
+        assert_eq!(encode_mqtt_remaining_length(127), vec![0x7F]);
+        assert_eq!(encode_mqtt_remaining_length(128), vec![0x80, 0x01]);
+        assert_eq!(encode_mqtt_remaining_length(321), vec![0xC1, 0x02]);
+    }
+
+    #[test]
+    fn mqtt_connect_packet_carries_auth_flags() {
+        let anonymous = encode_mqtt_connect("zeroclaw-test", None, None);
+        assert_eq!(anonymous[0], 0x10);
+        // Variable header: "MQTT" string (6 bytes) + level + flags.
+        assert_eq!(anonymous[9], 0x02);
+
+        let with_auth = encode_mqtt_connect("zeroclaw-test", Some("zeroclaw_user"), Some("pw"));
+        assert_eq!(with_auth[9], 0x02 | 0x80 | 0x40);
+    }
+
+    #[test]
+    fn mqtt_publish_packet_embeds_topic_and_payload() {
+        let packet = encode_mqtt_publish("zeroclaw/alert", b"{}");
+        assert_eq!(packet[0], 0x30);
+        let topic_len = usize::from(u16::from_be_bytes([packet[2], packet[3]]));
+        assert_eq!(topic_len, "zeroclaw/alert".len());
+        assert_eq!(&packet[4..4 + topic_len], b"zeroclaw/alert");
+        assert_eq!(&packet[4 + topic_len..], b"{}");
+    }
+
+    #[test]
+    fn nats_publish_round_trips_through_local_broker() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut writer = stream.try_clone().unwrap();
+            writer.write_all(b"INFO {}\r\n").unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut connect_line = String::new();
+            reader.read_line(&mut connect_line).unwrap();
+            let mut pub_line = String::new();
+            reader.read_line(&mut pub_line).unwrap();
+            let mut payload = vec![0u8; 2];
+            reader.read_exact(&mut payload).unwrap();
+            (connect_line, pub_line, payload)
+        });
+
+        let config = EventBusConfig {
+            url: addr.to_string(),
+            ..bus_config("nats")
+        };
+        let mut conn = BusConnection::connect(&config).unwrap();
+        conn.publish("zeroclaw.alert", b"{}").unwrap();
+
+        let (connect_line, pub_line, payload) = server.join().unwrap();
+        assert!(connect_line.starts_with("CONNECT"));
+        assert_eq!(pub_line, "PUB zeroclaw.alert 2\r\n");
+        assert_eq!(payload, b"{}");
+    }
+}
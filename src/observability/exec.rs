@@ -0,0 +1,304 @@
+//! Exec observer — pipes runtime events as JSONL to a user subprocess.
+//!
+//! `observability.backend = "exec:<path>"` spawns `<path>` once and writes
+//! one JSON object per line to its stdin for every event and metric, so
+//! users can ship telemetry to custom systems without forking the crate.
+//! The program is executed directly (no shell); its stderr passes through
+//! for debugging and its stdout is discarded.
+
+use super::traits::{Observer, ObserverEvent, ObserverMetric};
+use std::any::Any;
+use std::io::Write;
+use std::process::{Child, ChildStdin, Command, Stdio};
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Grace period for the subprocess to exit after stdin closes on drop.
+const SHUTDOWN_GRACE: Duration = Duration::from_secs(2);
+
+/// Observer that forwards events to a long-running subprocess as JSONL.
+///
+/// Writes are best-effort: if the subprocess exits or its pipe breaks, the
+/// observer logs one warning and stops exporting rather than failing the
+/// agent runtime.
+pub struct ExecObserver {
+    stdin: Mutex<Option<ChildStdin>>,
+    child: Mutex<Child>,
+}
+
+impl ExecObserver {
+    /// Spawn the observer subprocess for an `exec:<path>` backend.
+    pub fn new(command: &str) -> anyhow::Result<Self> {
+        let command = command.trim();
+        anyhow::ensure!(!command.is_empty(), "exec observer: empty command path");
+        let mut child = Command::new(command)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .spawn()
+            .map_err(|e| anyhow::anyhow!("Failed to spawn observer subprocess '{command}': {e}"))?;
+        let stdin = child
+            .stdin
+            .take()
+            .ok_or_else(|| anyhow::anyhow!("Observer subprocess has no stdin pipe"))?;
+        Ok(Self {
+            stdin: Mutex::new(Some(stdin)),
+            child: Mutex::new(child),
+        })
+    }
+
+    fn write_line(&self, value: &serde_json::Value) {
+        let mut guard = self.stdin.lock().unwrap_or_else(|e| e.into_inner());
+        let Some(stdin) = guard.as_mut() else {
+            return;
+        };
+        let line = format!("{value}\n");
+        if stdin
+            .write_all(line.as_bytes())
+            .and_then(|()| stdin.flush())
+            .is_err()
+        {
+            tracing::warn!("Exec observer: subprocess pipe closed; stopping event export");
+            *guard = None;
+        }
+    }
+}
+
+impl Drop for ExecObserver {
+    fn drop(&mut self) {
+        // Close stdin so the subprocess sees EOF, give it a short grace to
+        // flush, then reap (killing if it ignores EOF).
+        if let Ok(mut guard) = self.stdin.lock() {
+            guard.take();
+        }
+        if let Ok(mut child) = self.child.lock() {
+            let deadline = std::time::Instant::now() + SHUTDOWN_GRACE;
+            while std::time::Instant::now() < deadline {
+                match child.try_wait() {
+                    Ok(Some(_)) => return,
+                    Ok(None) => std::thread::sleep(Duration::from_millis(50)),
+                    Err(_) => break,
+                }
+            }
+            let _ = child.kill();
+            let _ = child.wait();
+        }
+    }
+}
+
+impl Observer for ExecObserver {
+    fn record_event(&self, event: &ObserverEvent) {
+        self.write_line(&event_to_json(event));
+    }
+
+    fn record_metric(&self, metric: &ObserverMetric) {
+        self.write_line(&metric_to_json(metric));
+    }
+
+    fn name(&self) -> &str {
+        "exec"
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+fn duration_ms(duration: &Duration) -> u64 {
+    u64::try_from(duration.as_millis()).unwrap_or(u64::MAX)
+}
+
+/// One flat JSON object per event, keyed by `"event"`. Durations are
+/// reported in milliseconds.
+#[allow(clippy::too_many_lines)]
+fn event_to_json(event: &ObserverEvent) -> serde_json::Value {
+    match event {
+        ObserverEvent::AgentStart { provider, model } => serde_json::json!({
+            "event": "agent_start", "provider": provider, "model": model,
+        }),
+        ObserverEvent::LlmRequest {
+            provider,
+            model,
+            messages_count,
+        } => serde_json::json!({
+            "event": "llm_request", "provider": provider, "model": model,
+            "messages_count": messages_count,
+        }),
+        ObserverEvent::LlmResponse {
+            provider,
+            model,
+            duration,
+            success,
+            error_message,
+        } => serde_json::json!({
+            "event": "llm_response", "provider": provider, "model": model,
+            "duration_ms": duration_ms(duration), "success": success,
+            "error": error_message,
+        }),
+        ObserverEvent::AgentEnd {
+            provider,
+            model,
+            duration,
+            tokens_used,
+            cost_usd,
+        } => serde_json::json!({
+            "event": "agent_end", "provider": provider, "model": model,
+            "duration_ms": duration_ms(duration), "tokens_used": tokens_used,
+            "cost_usd": cost_usd,
+        }),
+        ObserverEvent::ToolCallStart { tool } => serde_json::json!({
+            "event": "tool_call_start", "tool": tool,
+        }),
+        ObserverEvent::ToolCall {
+            tool,
+            duration,
+            success,
+            output_bytes,
+        } => serde_json::json!({
+            "event": "tool_call", "tool": tool,
+            "duration_ms": duration_ms(duration), "success": success,
+            "output_bytes": output_bytes,
+        }),
+        ObserverEvent::TurnComplete => serde_json::json!({"event": "turn_complete"}),
+        ObserverEvent::ChannelMessage { channel, direction } => serde_json::json!({
+            "event": "channel_message", "channel": channel, "direction": direction,
+        }),
+        ObserverEvent::ChannelMessageDeduplicated { channel } => serde_json::json!({
+            "event": "channel_message_deduplicated", "channel": channel,
+        }),
+        ObserverEvent::HeartbeatTick => serde_json::json!({"event": "heartbeat_tick"}),
+        ObserverEvent::CacheHit { provider, model } => serde_json::json!({
+            "event": "cache_hit", "provider": provider, "model": model,
+        }),
+        ObserverEvent::Error { component, message } => serde_json::json!({
+            "event": "error", "component": component, "message": message,
+        }),
+        ObserverEvent::DelegationStart {
+            agent_name,
+            provider,
+            model,
+            depth,
+            agentic,
+            workflow,
+        } => serde_json::json!({
+            "event": "delegation_start", "agent": agent_name, "provider": provider,
+            "model": model, "depth": depth, "agentic": agentic, "workflow": workflow,
+        }),
+        ObserverEvent::DelegationEnd {
+            agent_name,
+            provider,
+            model,
+            depth,
+            duration,
+            success,
+            error_message,
+            tokens_used,
+            cost_usd,
+            workflow,
+            node,
+        } => serde_json::json!({
+            "event": "delegation_end", "agent": agent_name, "provider": provider,
+            "model": model, "depth": depth, "duration_ms": duration_ms(duration),
+            "success": success, "error": error_message, "tokens_used": tokens_used,
+            "cost_usd": cost_usd, "workflow": workflow, "node": node,
+        }),
+        ObserverEvent::SecretsRedacted { scope, count } => serde_json::json!({
+            "event": "secrets_redacted", "scope": scope, "count": count,
+        }),
+        ObserverEvent::ApprovalRequested { tool, channel } => serde_json::json!({
+            "event": "approval_requested", "tool": tool, "channel": channel,
+        }),
+        ObserverEvent::RunCancelled { scope } => serde_json::json!({
+            "event": "run_cancelled", "scope": scope,
+        }),
+    }
+}
+
+/// One flat JSON object per metric, keyed by `"metric"`.
+fn metric_to_json(metric: &ObserverMetric) -> serde_json::Value {
+    match metric {
+        ObserverMetric::RequestLatency(duration) => serde_json::json!({
+            "metric": "request_latency", "value_ms": duration_ms(duration),
+        }),
+        ObserverMetric::TokensUsed(tokens) => serde_json::json!({
+            "metric": "tokens_used", "value": tokens,
+        }),
+        ObserverMetric::ActiveSessions(count) => serde_json::json!({
+            "metric": "active_sessions", "value": count,
+        }),
+        ObserverMetric::QueueDepth(depth) => serde_json::json!({
+            "metric": "queue_depth", "value": depth,
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn event_json_is_flat_and_tagged() {
+        let event = ObserverEvent::ToolCall {
+            tool: "shell".into(),
+            duration: Duration::from_millis(42),
+            success: true,
+            output_bytes: Some(128),
+        };
+        let json = event_to_json(&event);
+        assert_eq!(json["event"], "tool_call");
+        assert_eq!(json["tool"], "shell");
+        assert_eq!(json["duration_ms"], 42);
+        assert_eq!(json["output_bytes"], 128);
+
+        let json = event_to_json(&ObserverEvent::ApprovalRequested {
+            tool: "shell".into(),
+            channel: "cli".into(),
+        });
+        assert_eq!(json["event"], "approval_requested");
+    }
+
+    #[test]
+    fn metric_json_is_tagged() {
+        let json = metric_to_json(&ObserverMetric::TokensUsed(99));
+        assert_eq!(json["metric"], "tokens_used");
+        assert_eq!(json["value"], 99);
+    }
+
+    #[test]
+    fn new_rejects_empty_command() {
+        assert!(ExecObserver::new("  ").is_err());
+    }
+
+    #[test]
+    fn new_fails_for_missing_program() {
+        assert!(ExecObserver::new("/nonexistent/zeroclaw-observer-plugin").is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn events_reach_subprocess_as_jsonl() {
+        use std::os::unix::fs::PermissionsExt as _;
+
+        let tmp = tempfile::TempDir::new().unwrap();
+        let out_path = tmp.path().join("events.jsonl");
+        let script_path = tmp.path().join("sink.sh");
+        std::fs::write(
+            &script_path,
+            format!("#!/bin/sh\ncat > {}\n", out_path.display()),
+        )
+        .unwrap();
+        std::fs::set_permissions(&script_path, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        let observer = ExecObserver::new(script_path.to_str().unwrap()).unwrap();
+        observer.record_event(&ObserverEvent::TurnComplete);
+        observer.record_metric(&ObserverMetric::QueueDepth(3));
+        drop(observer); // closes stdin and waits for the subprocess
+
+        let contents = std::fs::read_to_string(&out_path).unwrap();
+        let lines: Vec<&str> = contents.lines().collect();
+        assert_eq!(lines.len(), 2);
+        let first: serde_json::Value = serde_json::from_str(lines[0]).unwrap();
+        assert_eq!(first["event"], "turn_complete");
+        let second: serde_json::Value = serde_json::from_str(lines[1]).unwrap();
+        assert_eq!(second["metric"], "queue_depth");
+    }
+}
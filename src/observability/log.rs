@@ -34,9 +34,16 @@ impl Observer for LogObserver {
                 tool,
                 duration,
                 success,
+                output_bytes,
             } => {
                 let ms = u64::try_from(duration.as_millis()).unwrap_or(u64::MAX);
-                info!(tool = %tool, duration_ms = ms, success = success, "tool.call");
+                info!(
+                    tool = %tool,
+                    duration_ms = ms,
+                    success = success,
+                    output_bytes = output_bytes,
+                    "tool.call"
+                );
             }
             ObserverEvent::TurnComplete => {
                 info!("turn.complete");
@@ -44,12 +51,27 @@ impl Observer for LogObserver {
             ObserverEvent::ChannelMessage { channel, direction } => {
                 info!(channel = %channel, direction = %direction, "channel.message");
             }
+            ObserverEvent::ChannelMessageDeduplicated { channel } => {
+                info!(channel = %channel, "channel.message.deduplicated");
+            }
             ObserverEvent::HeartbeatTick => {
                 info!("heartbeat.tick");
             }
+            ObserverEvent::CacheHit { provider, model } => {
+                info!(provider = %provider, model = %model, "cache.hit");
+            }
             ObserverEvent::Error { component, message } => {
                 info!(component = %component, error = %message, "error");
             }
+            ObserverEvent::SecretsRedacted { scope, count } => {
+                info!(scope = %scope, count = count, "security.redaction");
+            }
+            ObserverEvent::ApprovalRequested { tool, channel } => {
+                info!(tool = %tool, channel = %channel, "approval.requested");
+            }
+            ObserverEvent::RunCancelled { scope } => {
+                info!(scope = %scope, "run.cancelled");
+            }
             ObserverEvent::LlmRequest {
                 provider,
                 model,
@@ -85,9 +107,11 @@ impl Observer for LogObserver {
                 model,
                 depth,
                 agentic,
+                workflow,
             } => {
                 info!(
                     agent_name = %agent_name,
+                    workflow = workflow.as_deref().unwrap_or("-"),
                     provider = %provider,
                     model = %model,
                     depth = depth,
@@ -105,10 +129,13 @@ impl Observer for LogObserver {
                 error_message,
                 tokens_used,
                 cost_usd,
+                workflow,
+                node: _,
             } => {
                 let ms = u64::try_from(duration.as_millis()).unwrap_or(u64::MAX);
                 info!(
                     agent_name = %agent_name,
+                    workflow = workflow.as_deref().unwrap_or("-"),
                     provider = %provider,
                     model = %model,
                     depth = depth,
@@ -185,6 +212,7 @@ mod tests {
             tool: "shell".into(),
             duration: Duration::from_millis(10),
             success: false,
+            output_bytes: None,
         });
         obs.record_event(&ObserverEvent::ChannelMessage {
             channel: "telegram".into(),
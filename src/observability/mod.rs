@@ -1,6 +1,9 @@
 pub mod delegation_logger;
 pub mod delegation_report;
+pub mod delegation_schema;
 pub mod delegation_stats;
+pub mod event_bus;
+pub mod exec;
 pub mod log;
 pub mod multi;
 pub mod noop;
@@ -9,13 +12,15 @@ pub mod prometheus;
 pub mod traits;
 pub mod verbose;
 
-pub use delegation_logger::DelegationEventObserver;
-#[allow(unused_imports)]
-pub use delegation_stats::{DelegationStatsObserver, DelegationStatsSnapshot};
 #[allow(unused_imports)]
 pub use self::log::LogObserver;
 #[allow(unused_imports)]
 pub use self::multi::MultiObserver;
+pub use delegation_logger::DelegationEventObserver;
+#[allow(unused_imports)]
+pub use delegation_stats::{DelegationStatsObserver, DelegationStatsSnapshot};
+pub use event_bus::EventBusObserver;
+pub use exec::ExecObserver;
 pub use noop::NoopObserver;
 pub use otel::OtelObserver;
 pub use prometheus::PrometheusObserver;
@@ -23,7 +28,7 @@ pub use traits::{Observer, ObserverEvent};
 #[allow(unused_imports)]
 pub use verbose::VerboseObserver;
 
-use crate::config::ObservabilityConfig;
+use crate::config::{EventsConfig, ObservabilityConfig};
 use std::path::PathBuf;
 
 /// Factory: create the right observer from config.
@@ -31,47 +36,90 @@ use std::path::PathBuf;
 /// `delegation_log` is the path where delegation events are written (e.g.
 /// `config.delegation_log_path()`). Callers own path computation so the log
 /// location stays consistent with the rest of the zeroclaw state directory.
-pub fn create_observer(config: &ObservabilityConfig, delegation_log: PathBuf) -> Box<dyn Observer> {
+/// When `[events.bus]` is configured, an event-bus publisher joins the
+/// observer stack. A backend of `exec:<path>` spawns `<path>` and pipes
+/// events to it as JSONL for custom integrations.
+pub fn create_observer(
+    config: &ObservabilityConfig,
+    events: &EventsConfig,
+    delegation_log: PathBuf,
+) -> Box<dyn Observer> {
+    create_observer_with_tags(config, events, delegation_log, Vec::new())
+}
+
+/// Like [`create_observer`], but attaches user-defined run tags (e.g.
+/// `project:foo`) to every delegation log event, so `zeroclaw delegations`
+/// reports can attribute cost per project. Used by the `zeroclaw agent
+/// --tag` path and tagged cron jobs.
+pub fn create_observer_with_tags(
+    config: &ObservabilityConfig,
+    events: &EventsConfig,
+    delegation_log: PathBuf,
+    tags: Vec<String>,
+) -> Box<dyn Observer> {
     // Create primary observer based on config
-    let primary: Box<dyn Observer> = match config.backend.as_str() {
-        "log" => Box::new(LogObserver::new()),
-        "prometheus" => Box::new(PrometheusObserver::new()),
-        "otel" | "opentelemetry" | "otlp" => {
-            match OtelObserver::new(
-                config.otel_endpoint.as_deref(),
-                config.otel_service_name.as_deref(),
-            ) {
-                Ok(obs) => {
-                    tracing::info!(
-                        endpoint = config
-                            .otel_endpoint
-                            .as_deref()
-                            .unwrap_or("http://localhost:4318"),
-                        "OpenTelemetry observer initialized"
-                    );
-                    Box::new(obs)
-                }
-                Err(e) => {
-                    tracing::error!("Failed to create OTel observer: {e}. Falling back to noop.");
-                    Box::new(NoopObserver)
-                }
+    let primary: Box<dyn Observer> = if let Some(command) = config.backend.strip_prefix("exec:") {
+        // Custom backend: pipe events as JSONL to a user subprocess.
+        match ExecObserver::new(command) {
+            Ok(obs) => Box::new(obs),
+            Err(e) => {
+                tracing::error!("Failed to create exec observer: {e}. Falling back to noop.");
+                Box::new(NoopObserver)
             }
         }
-        "none" | "noop" => Box::new(NoopObserver),
-        _ => {
-            tracing::warn!(
-                "Unknown observability backend '{}', falling back to noop",
-                config.backend
-            );
-            Box::new(NoopObserver)
+    } else {
+        match config.backend.as_str() {
+            "log" => Box::new(LogObserver::new()),
+            "prometheus" => Box::new(PrometheusObserver::new()),
+            "otel" | "opentelemetry" | "otlp" => {
+                match OtelObserver::new(
+                    config.otel_endpoint.as_deref(),
+                    config.otel_service_name.as_deref(),
+                ) {
+                    Ok(obs) => {
+                        tracing::info!(
+                            endpoint = config
+                                .otel_endpoint
+                                .as_deref()
+                                .unwrap_or("http://localhost:4318"),
+                            "OpenTelemetry observer initialized"
+                        );
+                        Box::new(obs)
+                    }
+                    Err(e) => {
+                        tracing::error!(
+                            "Failed to create OTel observer: {e}. Falling back to noop."
+                        );
+                        Box::new(NoopObserver)
+                    }
+                }
+            }
+            "none" | "noop" => Box::new(NoopObserver),
+            _ => {
+                tracing::warn!(
+                    "Unknown observability backend '{}', falling back to noop",
+                    config.backend
+                );
+                Box::new(NoopObserver)
+            }
         }
     };
 
     // Add delegation event logger (writes to the caller-supplied path).
-    let delegation_logger: Box<dyn Observer> = Box::new(DelegationEventObserver::new(delegation_log));
+    let delegation_logger: Box<dyn Observer> =
+        Box::new(DelegationEventObserver::new(delegation_log).with_tags(tags));
 
-    // Combine both observers using MultiObserver
-    Box::new(MultiObserver::new(vec![primary, delegation_logger]))
+    let mut observers = vec![primary, delegation_logger];
+
+    // Event-bus publisher ([events.bus]) — optional, additive.
+    if let Some(ref bus) = events.bus {
+        match EventBusObserver::new(bus) {
+            Ok(observer) => observers.push(Box::new(observer)),
+            Err(e) => tracing::error!("Failed to create event-bus publisher: {e:#}"),
+        }
+    }
+
+    Box::new(MultiObserver::new(observers))
 }
 
 #[cfg(test)]
@@ -89,7 +137,10 @@ mod tests {
             ..ObservabilityConfig::default()
         };
         // Factory now returns MultiObserver wrapping primary + delegation logger
-        assert_eq!(create_observer(&cfg, test_log()).name(), "multi");
+        assert_eq!(
+            create_observer(&cfg, &EventsConfig::default(), test_log()).name(),
+            "multi"
+        );
     }
 
     #[test]
@@ -98,7 +149,10 @@ mod tests {
             backend: "noop".into(),
             ..ObservabilityConfig::default()
         };
-        assert_eq!(create_observer(&cfg, test_log()).name(), "multi");
+        assert_eq!(
+            create_observer(&cfg, &EventsConfig::default(), test_log()).name(),
+            "multi"
+        );
     }
 
     #[test]
@@ -107,7 +161,10 @@ mod tests {
             backend: "log".into(),
             ..ObservabilityConfig::default()
         };
-        assert_eq!(create_observer(&cfg, test_log()).name(), "multi");
+        assert_eq!(
+            create_observer(&cfg, &EventsConfig::default(), test_log()).name(),
+            "multi"
+        );
     }
 
     #[test]
@@ -116,7 +173,10 @@ mod tests {
             backend: "prometheus".into(),
             ..ObservabilityConfig::default()
         };
-        assert_eq!(create_observer(&cfg, test_log()).name(), "multi");
+        assert_eq!(
+            create_observer(&cfg, &EventsConfig::default(), test_log()).name(),
+            "multi"
+        );
     }
 
     #[test]
@@ -125,8 +185,12 @@ mod tests {
             backend: "otel".into(),
             otel_endpoint: Some("http://127.0.0.1:19999".into()),
             otel_service_name: Some("test".into()),
+            ..ObservabilityConfig::default()
         };
-        assert_eq!(create_observer(&cfg, test_log()).name(), "multi");
+        assert_eq!(
+            create_observer(&cfg, &EventsConfig::default(), test_log()).name(),
+            "multi"
+        );
     }
 
     #[test]
@@ -135,8 +199,12 @@ mod tests {
             backend: "opentelemetry".into(),
             otel_endpoint: Some("http://127.0.0.1:19999".into()),
             otel_service_name: Some("test".into()),
+            ..ObservabilityConfig::default()
         };
-        assert_eq!(create_observer(&cfg, test_log()).name(), "multi");
+        assert_eq!(
+            create_observer(&cfg, &EventsConfig::default(), test_log()).name(),
+            "multi"
+        );
     }
 
     #[test]
@@ -145,8 +213,25 @@ mod tests {
             backend: "otlp".into(),
             otel_endpoint: Some("http://127.0.0.1:19999".into()),
             otel_service_name: Some("test".into()),
+            ..ObservabilityConfig::default()
+        };
+        assert_eq!(
+            create_observer(&cfg, &EventsConfig::default(), test_log()).name(),
+            "multi"
+        );
+    }
+
+    #[test]
+    fn factory_exec_bad_path_returns_multi() {
+        // Spawn failure falls back to noop inside the multi stack.
+        let cfg = ObservabilityConfig {
+            backend: "exec:/nonexistent/zeroclaw-observer-plugin".into(),
+            ..ObservabilityConfig::default()
         };
-        assert_eq!(create_observer(&cfg, test_log()).name(), "multi");
+        assert_eq!(
+            create_observer(&cfg, &EventsConfig::default(), test_log()).name(),
+            "multi"
+        );
     }
 
     #[test]
@@ -155,7 +240,10 @@ mod tests {
             backend: "xyzzy_unknown".into(),
             ..ObservabilityConfig::default()
         };
-        assert_eq!(create_observer(&cfg, test_log()).name(), "multi");
+        assert_eq!(
+            create_observer(&cfg, &EventsConfig::default(), test_log()).name(),
+            "multi"
+        );
     }
 
     #[test]
@@ -164,7 +252,10 @@ mod tests {
             backend: String::new(),
             ..ObservabilityConfig::default()
         };
-        assert_eq!(create_observer(&cfg, test_log()).name(), "multi");
+        assert_eq!(
+            create_observer(&cfg, &EventsConfig::default(), test_log()).name(),
+            "multi"
+        );
     }
 
     #[test]
@@ -173,6 +264,9 @@ mod tests {
             backend: "xyzzy_garbage_123".into(),
             ..ObservabilityConfig::default()
         };
-        assert_eq!(create_observer(&cfg, test_log()).name(), "multi");
+        assert_eq!(
+            create_observer(&cfg, &EventsConfig::default(), test_log()).name(),
+            "multi"
+        );
     }
 }
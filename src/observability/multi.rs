@@ -35,6 +35,10 @@ impl Observer for MultiObserver {
         "multi"
     }
 
+    fn run_id(&self) -> Option<&str> {
+        self.observers.iter().find_map(|obs| obs.run_id())
+    }
+
     fn as_any(&self) -> &dyn Any {
         self
     }
@@ -14,6 +14,7 @@ pub struct PrometheusObserver {
     channel_messages: IntCounterVec,
     heartbeat_ticks: prometheus::IntCounter,
     errors: IntCounterVec,
+    redactions: IntCounterVec,
 
     // Histograms
     agent_duration: HistogramVec,
@@ -58,6 +59,15 @@ impl PrometheusObserver {
             prometheus::IntCounter::new("zeroclaw_heartbeat_ticks_total", "Total heartbeat ticks")
                 .expect("valid metric");
 
+        let redactions = IntCounterVec::new(
+            prometheus::Opts::new(
+                "zeroclaw_redactions_total",
+                "Total secrets redacted before reaching a provider",
+            ),
+            &["scope"],
+        )
+        .expect("valid metric");
+
         let errors = IntCounterVec::new(
             prometheus::Opts::new("zeroclaw_errors_total", "Total errors by component"),
             &["component"],
@@ -154,6 +164,7 @@ impl PrometheusObserver {
         registry.register(Box::new(channel_messages.clone())).ok();
         registry.register(Box::new(heartbeat_ticks.clone())).ok();
         registry.register(Box::new(errors.clone())).ok();
+        registry.register(Box::new(redactions.clone())).ok();
         registry.register(Box::new(agent_duration.clone())).ok();
         registry.register(Box::new(tool_duration.clone())).ok();
         registry.register(Box::new(request_latency.clone())).ok();
@@ -161,7 +172,9 @@ impl PrometheusObserver {
         registry.register(Box::new(active_sessions.clone())).ok();
         registry.register(Box::new(queue_depth.clone())).ok();
         registry.register(Box::new(delegations_total.clone())).ok();
-        registry.register(Box::new(delegation_duration.clone())).ok();
+        registry
+            .register(Box::new(delegation_duration.clone()))
+            .ok();
         registry
             .register(Box::new(delegation_tokens_total.clone()))
             .ok();
@@ -176,6 +189,7 @@ impl PrometheusObserver {
             channel_messages,
             heartbeat_ticks,
             errors,
+            redactions,
             agent_duration,
             tool_duration,
             request_latency,
@@ -223,6 +237,9 @@ impl Observer for PrometheusObserver {
                 }
             }
             ObserverEvent::ToolCallStart { tool: _ }
+            | ObserverEvent::ApprovalRequested { .. }
+            | ObserverEvent::ChannelMessageDeduplicated { .. }
+            | ObserverEvent::RunCancelled { .. }
             | ObserverEvent::TurnComplete
             | ObserverEvent::LlmRequest { .. }
             | ObserverEvent::LlmResponse { .. } => {}
@@ -230,6 +247,7 @@ impl Observer for PrometheusObserver {
                 tool,
                 duration,
                 success,
+                output_bytes: _,
             } => {
                 let success_str = if *success { "true" } else { "false" };
                 self.tool_calls
@@ -247,12 +265,19 @@ impl Observer for PrometheusObserver {
             ObserverEvent::HeartbeatTick => {
                 self.heartbeat_ticks.inc();
             }
+            ObserverEvent::CacheHit { .. } => {
+                // Cache hits are recorded in the delegation log; no
+                // Prometheus counter yet.
+            }
             ObserverEvent::Error {
                 component,
                 message: _,
             } => {
                 self.errors.with_label_values(&[component]).inc();
             }
+            ObserverEvent::SecretsRedacted { scope, count } => {
+                self.redactions.with_label_values(&[scope]).inc_by(*count);
+            }
             ObserverEvent::DelegationStart { .. } => {
                 // Counted on DelegationEnd so we have outcome data
             }
@@ -356,11 +381,13 @@ mod tests {
             tool: "shell".into(),
             duration: Duration::from_millis(10),
             success: true,
+            output_bytes: None,
         });
         obs.record_event(&ObserverEvent::ToolCall {
             tool: "file_read".into(),
             duration: Duration::from_millis(5),
             success: false,
+            output_bytes: None,
         });
         obs.record_event(&ObserverEvent::ChannelMessage {
             channel: "telegram".into(),
@@ -377,6 +404,7 @@ mod tests {
             model: "claude-sonnet-4".into(),
             depth: 1,
             agentic: true,
+            workflow: None,
         });
         obs.record_event(&ObserverEvent::DelegationEnd {
             agent_name: "worker".into(),
@@ -388,6 +416,8 @@ mod tests {
             error_message: None,
             tokens_used: Some(400),
             cost_usd: Some(0.0012),
+            workflow: None,
+            node: None,
         });
     }
 
@@ -412,6 +442,7 @@ mod tests {
             tool: "shell".into(),
             duration: Duration::from_millis(100),
             success: true,
+            output_bytes: None,
         });
         obs.record_event(&ObserverEvent::HeartbeatTick);
         obs.record_metric(&ObserverMetric::RequestLatency(Duration::from_millis(250)));
@@ -443,16 +474,19 @@ mod tests {
             tool: "shell".into(),
             duration: Duration::from_millis(10),
             success: true,
+            output_bytes: None,
         });
         obs.record_event(&ObserverEvent::ToolCall {
             tool: "shell".into(),
             duration: Duration::from_millis(10),
             success: true,
+            output_bytes: None,
         });
         obs.record_event(&ObserverEvent::ToolCall {
             tool: "shell".into(),
             duration: Duration::from_millis(10),
             success: false,
+            output_bytes: None,
         });
 
         let output = obs.encode();
@@ -505,6 +539,8 @@ mod tests {
             error_message: None,
             tokens_used: None,
             cost_usd: None,
+            workflow: None,
+            node: None,
         });
         obs.record_event(&ObserverEvent::DelegationEnd {
             agent_name: "helper".into(),
@@ -516,6 +552,8 @@ mod tests {
             error_message: Some("timeout".into()),
             tokens_used: None,
             cost_usd: None,
+            workflow: None,
+            node: None,
         });
         obs.record_event(&ObserverEvent::DelegationEnd {
             agent_name: "deep".into(),
@@ -527,6 +565,8 @@ mod tests {
             error_message: None,
             tokens_used: None,
             cost_usd: None,
+            workflow: None,
+            node: None,
         });
 
         let output = obs.encode();
@@ -564,6 +604,8 @@ mod tests {
             error_message: None,
             tokens_used: None,
             cost_usd: None,
+            workflow: None,
+            node: None,
         });
 
         let output = obs.encode();
@@ -592,6 +634,8 @@ mod tests {
                 error_message: None,
                 tokens_used: Some(tokens),
                 cost_usd: None,
+                workflow: None,
+                node: None,
             });
         }
 
@@ -618,6 +662,8 @@ mod tests {
             error_message: None,
             tokens_used: None,
             cost_usd: Some(0.005),
+            workflow: None,
+            node: None,
         });
         obs.record_event(&ObserverEvent::DelegationEnd {
             agent_name: "worker".into(),
@@ -629,6 +675,8 @@ mod tests {
             error_message: None,
             tokens_used: None,
             cost_usd: Some(0.003),
+            workflow: None,
+            node: None,
         });
 
         let output = obs.encode();
@@ -672,6 +720,8 @@ mod tests {
             error_message: Some("provider error".into()),
             tokens_used: None,
             cost_usd: None,
+            workflow: None,
+            node: None,
         });
         let output = obs.encode();
         assert!(output.contains("zeroclaw_delegations_total"));
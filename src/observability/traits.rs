@@ -44,6 +44,10 @@ pub enum ObserverEvent {
         tool: String,
         duration: Duration,
         success: bool,
+        /// Size of the tool's output in bytes.
+        ///
+        /// `None` when the call errored before producing any output.
+        output_bytes: Option<u64>,
     },
     /// The agent produced a final answer for the current user message.
     TurnComplete,
@@ -54,8 +58,17 @@ pub enum ObserverEvent {
         /// `"inbound"` or `"outbound"`.
         direction: String,
     },
+    /// An inbound channel event was dropped as a webhook redelivery of an
+    /// event already processed within the dedup window.
+    ChannelMessageDeduplicated {
+        /// Channel name (e.g., `"telegram"`, `"slack"`).
+        channel: String,
+    },
     /// Periodic heartbeat tick from the runtime keep-alive loop.
     HeartbeatTick,
+    /// A provider response was served from the local response cache
+    /// instead of a billed provider call.
+    CacheHit { provider: String, model: String },
     /// An error occurred in a named component.
     Error {
         /// Subsystem where the error originated (e.g., `"provider"`, `"gateway"`).
@@ -78,6 +91,9 @@ pub enum ObserverEvent {
         depth: u32,
         /// Whether this is an agentic delegation (full agent loop) or simple (single call).
         agentic: bool,
+        /// Workflow run context as `<workflow>/<step>` when the delegation was
+        /// launched by the workflow engine; `None` for ad-hoc delegations.
+        workflow: Option<String>,
     },
     /// A sub-agent delegation has completed.
     ///
@@ -107,6 +123,40 @@ pub enum ObserverEvent {
         ///
         /// `None` when no cost data is available from the provider.
         cost_usd: Option<f64>,
+        /// Workflow run context as `<workflow>/<step>`; see [`ObserverEvent::DelegationStart`].
+        workflow: Option<String>,
+        /// Worker node that executed the delegation (`[nodes]` section);
+        /// `None` when the delegation ran locally.
+        node: Option<String>,
+    },
+    /// Secret-shaped text was redacted before reaching a provider.
+    ///
+    /// Emitted by the redaction pass in the agent loop. Carries only the
+    /// match count, never the redacted content itself.
+    SecretsRedacted {
+        /// Where the redaction applied: `"prompt"` or `"tool_output"`.
+        scope: String,
+        /// Number of matches replaced with the placeholder.
+        count: u64,
+    },
+    /// An approval-gated tool call is waiting for a human decision.
+    ///
+    /// Emitted before the approval prompt (or automatic denial on
+    /// non-interactive channels). Carries only the tool name, never the
+    /// tool arguments.
+    ApprovalRequested {
+        /// Name of the tool awaiting approval.
+        tool: String,
+        /// Channel the request originated from (e.g., `"cli"`, `"telegram"`).
+        channel: String,
+    },
+    /// An in-flight agent run was cancelled before completing.
+    ///
+    /// Emitted when a run is aborted by Ctrl-C in interactive mode,
+    /// `zeroclaw jobs cancel`, or a channel `/cancel` command.
+    RunCancelled {
+        /// What was cancelled: `"cli"`, `"job:<id>"`, or a channel name.
+        scope: String,
     },
 }
 
@@ -156,6 +206,13 @@ pub trait Observer: Send + Sync + 'static {
     /// that write synchronously.
     fn flush(&self) {}
 
+    /// Delegation-log run identifier for this process, when the observer
+    /// stack includes the delegation logger. Used to attach `run_id`
+    /// correlation fields to tracing spans (see `[observability] json_logs`).
+    fn run_id(&self) -> Option<&str> {
+        None
+    }
+
     /// Return the human-readable name of this observer backend.
     ///
     /// Used in logs and diagnostics (e.g., `"console"`, `"prometheus"`,
@@ -231,6 +288,7 @@ mod tests {
             tool: "shell".into(),
             duration: Duration::from_millis(10),
             success: true,
+            output_bytes: Some(64),
         };
         let metric = ObserverMetric::RequestLatency(Duration::from_millis(8));
 
@@ -40,6 +40,7 @@ impl Observer for VerboseObserver {
                 tool,
                 duration,
                 success,
+                output_bytes: _,
             } => {
                 let ms = u64::try_from(duration.as_millis()).unwrap_or(u64::MAX);
                 eprintln!("< Tool {tool} (success={success}, duration_ms={ms})");
@@ -95,6 +96,7 @@ mod tests {
             tool: "shell".into(),
             duration: Duration::from_millis(2),
             success: true,
+            output_bytes: None,
         });
         obs.record_event(&ObserverEvent::TurnComplete);
     }
@@ -1,6 +1,13 @@
+pub mod templates;
+pub mod tutorial;
 pub mod wizard;
 
-pub use wizard::{run_channels_repair_wizard, run_models_refresh, run_quick_setup, run_wizard};
+pub use tutorial::maybe_offer_tutorial;
+pub use wizard::{
+    cached_model_ids, lookup_model_metadata, run_channels_repair_wizard, run_models_compare,
+    run_models_list, run_models_refresh, run_models_search, run_quick_setup, run_setup_from_file,
+    run_wizard, ModelMetadata,
+};
 
 #[cfg(test)]
 mod tests {
@@ -14,5 +21,6 @@ mod tests {
         assert_reexport_exists(run_channels_repair_wizard);
         assert_reexport_exists(run_quick_setup);
         assert_reexport_exists(run_models_refresh);
+        assert_reexport_exists(maybe_offer_tutorial);
     }
 }
@@ -0,0 +1,219 @@
+//! Workspace templates for `zeroclaw onboard --template` and
+//! `zeroclaw workspace create --template`.
+//!
+//! A template pre-fills workspace identity files and seeds cron tasks so a
+//! fresh workspace starts with a role (ops bot, research assistant) instead
+//! of a blank slate. Template files are only written when missing and cron
+//! jobs are only seeded once (matched by name), so applying a template is
+//! idempotent and never overwrites user edits.
+
+use anyhow::{Context, Result};
+use std::path::Path;
+
+#[derive(Debug)]
+pub struct WorkspaceTemplate {
+    pub name: &'static str,
+    pub description: &'static str,
+    files: &'static [(&'static str, &'static str)],
+    cron_jobs: &'static [TemplateCronJob],
+}
+
+#[derive(Debug)]
+struct TemplateCronJob {
+    name: &'static str,
+    expression: &'static str,
+    prompt: &'static str,
+}
+
+const OPS_BOT_IDENTITY: &str = "\
+# IDENTITY.md — Who Am I?
+
+- **Name:** ZeroClaw
+- **Role:** Operations bot — monitors, maintenance, and incident follow-up
+- **Vibe:** Calm under pressure. Reports facts, flags risks, never guesses.
+- **Emoji:** \u{1f6e0}\u{fe0f}
+
+---
+
+Update this file as you evolve. Your identity is yours to shape.
+";
+
+const OPS_BOT_HEARTBEAT: &str = "\
+# HEARTBEAT.md
+
+# Periodic operations checks. Edit to match your infrastructure.
+- Check disk usage on this host; flag anything above 85%
+- Run `git status` on actively deployed repositories and note uncommitted drift
+- Review recent monitor alerts and summarize anything unresolved
+";
+
+const RESEARCH_IDENTITY: &str = "\
+# IDENTITY.md — Who Am I?
+
+- **Name:** ZeroClaw
+- **Role:** Research assistant — reading, summarizing, and tracking topics
+- **Vibe:** Curious and precise. Cites sources, separates facts from takes.
+- **Emoji:** \u{1f50e}
+
+---
+
+Update this file as you evolve. Your identity is yours to shape.
+";
+
+const RESEARCH_HEARTBEAT: &str = "\
+# HEARTBEAT.md
+
+# Periodic research upkeep. Edit to match your active topics.
+- Review `MEMORY.md` open loops and note topics missing recent findings
+- File new findings under the matching topic heading in MEMORY.md
+";
+
+pub const TEMPLATES: &[WorkspaceTemplate] = &[
+    WorkspaceTemplate {
+        name: "ops-bot",
+        description: "Operations bot: infra checks, daily ops summary, weekly maintenance review",
+        files: &[
+            ("IDENTITY.md", OPS_BOT_IDENTITY),
+            ("HEARTBEAT.md", OPS_BOT_HEARTBEAT),
+        ],
+        cron_jobs: &[
+            TemplateCronJob {
+                name: "daily-ops-summary",
+                expression: "0 9 * * *",
+                prompt: "Review overnight monitor alerts and heartbeat notes, then write a short \
+                         ops summary: what broke, what recovered, what needs a human.",
+            },
+            TemplateCronJob {
+                name: "weekly-maintenance-review",
+                expression: "0 10 * * 1",
+                prompt: "Review this week's maintenance items and open loops in MEMORY.md, then \
+                         propose a prioritized maintenance list for the coming week.",
+            },
+        ],
+    },
+    WorkspaceTemplate {
+        name: "research-assistant",
+        description: "Research assistant: topic tracking, weekday morning briefing",
+        files: &[
+            ("IDENTITY.md", RESEARCH_IDENTITY),
+            ("HEARTBEAT.md", RESEARCH_HEARTBEAT),
+        ],
+        cron_jobs: &[TemplateCronJob {
+            name: "morning-briefing",
+            expression: "0 8 * * 1-5",
+            prompt: "Compile a short morning briefing on the active research topics tracked in \
+                     MEMORY.md: new findings, open questions, and suggested next reading.",
+        }],
+    },
+];
+
+/// Look up a template by name; unknown names fail fast with the known list.
+pub fn find(name: &str) -> Result<&'static WorkspaceTemplate> {
+    TEMPLATES.iter().find(|t| t.name == name).ok_or_else(|| {
+        let available: Vec<&str> = TEMPLATES.iter().map(|t| t.name).collect();
+        anyhow::anyhow!(
+            "Unknown workspace template '{name}' (available: {})",
+            available.join(", ")
+        )
+    })
+}
+
+/// Write the template's workspace files (skipping existing ones) and seed
+/// its cron tasks (skipping jobs whose name is already present).
+pub fn apply(template: &WorkspaceTemplate, workspace_dir: &Path) -> Result<()> {
+    std::fs::create_dir_all(workspace_dir).with_context(|| {
+        format!(
+            "Failed to create workspace directory {}",
+            workspace_dir.display()
+        )
+    })?;
+
+    for (filename, contents) in template.files {
+        let path = workspace_dir.join(filename);
+        if !path.exists() {
+            std::fs::write(&path, contents)
+                .with_context(|| format!("Failed to write template file {filename}"))?;
+        }
+    }
+
+    // Cron storage only needs the workspace dir; no full config load required.
+    let cron_config = crate::config::Config {
+        workspace_dir: workspace_dir.to_path_buf(),
+        ..crate::config::Config::default()
+    };
+    let existing: Vec<String> = crate::cron::list_jobs(&cron_config)?
+        .into_iter()
+        .filter_map(|job| job.name)
+        .collect();
+    for job in template.cron_jobs {
+        if existing.iter().any(|name| name == job.name) {
+            continue;
+        }
+        crate::cron::add_agent_job(
+            &cron_config,
+            Some(job.name.to_string()),
+            crate::cron::Schedule::Cron {
+                expr: job.expression.to_string(),
+                tz: None,
+            },
+            job.prompt,
+            crate::cron::SessionTarget::Isolated,
+            None,
+            Vec::new(),
+            None,
+            false,
+        )
+        .with_context(|| format!("Failed to seed template cron task '{}'", job.name))?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_resolves_known_templates_and_rejects_unknown_names() {
+        assert_eq!(find("ops-bot").unwrap().name, "ops-bot");
+        assert_eq!(
+            find("research-assistant").unwrap().name,
+            "research-assistant"
+        );
+
+        let err = find("mystery").unwrap_err().to_string();
+        assert!(err.contains("ops-bot"), "unexpected error: {err}");
+        assert!(
+            err.contains("research-assistant"),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[test]
+    fn apply_writes_files_and_seeds_cron_jobs_idempotently() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let workspace_dir = tmp.path().join("workspace");
+        let template = find("ops-bot").unwrap();
+
+        apply(template, &workspace_dir).unwrap();
+
+        let identity = std::fs::read_to_string(workspace_dir.join("IDENTITY.md")).unwrap();
+        assert!(identity.contains("Operations bot"));
+
+        let cron_config = crate::config::Config {
+            workspace_dir: workspace_dir.clone(),
+            ..crate::config::Config::default()
+        };
+        let jobs = crate::cron::list_jobs(&cron_config).unwrap();
+        assert_eq!(jobs.len(), 2);
+
+        // Re-applying must not duplicate jobs or overwrite edited files.
+        std::fs::write(workspace_dir.join("IDENTITY.md"), "edited").unwrap();
+        apply(template, &workspace_dir).unwrap();
+        assert_eq!(crate::cron::list_jobs(&cron_config).unwrap().len(), 2);
+        assert_eq!(
+            std::fs::read_to_string(workspace_dir.join("IDENTITY.md")).unwrap(),
+            "edited"
+        );
+    }
+}
@@ -0,0 +1,247 @@
+//! First-run guided tutorial.
+//!
+//! Offered right after onboarding completes: walks the user through the
+//! core features (memory, cron, channels, skills) with short explanations
+//! and, where it is safe, by exercising the real feature. Everything the
+//! tutorial executes is scoped to the workspace — it stores, recalls, and
+//! removes one sample memory and reads config/skills. It never runs shell
+//! commands, touches channels, or makes network calls.
+
+use crate::config::Config;
+use crate::memory::{self, MemoryCategory};
+use anyhow::Result;
+use console::style;
+use dialoguer::Confirm;
+use std::io::IsTerminal;
+
+/// Key used for the sample memory entry so the demo can clean up after itself.
+const TUTORIAL_MEMORY_KEY: &str = "tutorial_sample";
+
+/// Offer the guided tour after onboarding. Declining — or running in a
+/// non-interactive context (CI, piped stdin) — is a silent no-op, and
+/// nothing in the tour can fail onboarding itself.
+pub async fn maybe_offer_tutorial(config: &Config) -> Result<()> {
+    if !std::io::stdin().is_terminal() {
+        return Ok(());
+    }
+
+    println!();
+    let take_tour = Confirm::new()
+        .with_prompt("  Take a two-minute tour of ZeroClaw's core features?")
+        .default(true)
+        .interact()
+        .unwrap_or(false);
+    if !take_tour {
+        println!(
+            "  {} Skipped. Run `zeroclaw agent -m \"Hello!\"` whenever you're ready.",
+            style("→").dim()
+        );
+        return Ok(());
+    }
+
+    run_tutorial(config).await;
+    Ok(())
+}
+
+async fn run_tutorial(config: &Config) {
+    println!();
+    println!("  {}", style("ZeroClaw tour").white().bold());
+    print_bullet("Four quick stops: memory, scheduled tasks, channels, skills.");
+    print_bullet("Everything the tour runs stays inside your workspace — no shell, no network.");
+
+    tour_memory(config).await;
+    tour_cron();
+    tour_channels(config);
+    tour_skills(config);
+
+    println!();
+    println!(
+        "  {} Tour complete. Useful next commands:",
+        style("🎉").cyan()
+    );
+    println!("    zeroclaw agent -m \"Hello!\"   # chat with the agent");
+    println!("    zeroclaw status              # configuration summary");
+    println!("    zeroclaw doctor              # diagnostics");
+    println!();
+}
+
+/// Stop 1: store, recall, and remove one sample memory using the real
+/// configured backend.
+async fn tour_memory(config: &Config) {
+    print_stop(1, "Memory");
+    print_bullet("ZeroClaw remembers facts and preferences across conversations.");
+    print_bullet(
+        "In chat, the agent saves them automatically; you can also ask it to remember things.",
+    );
+
+    if config.memory.backend == "none" {
+        print_bullet("Memory backend is set to \"none\", so there is nothing to demo here.");
+        return;
+    }
+
+    let try_it = Confirm::new()
+        .with_prompt("  Store and recall a sample memory now?")
+        .default(true)
+        .interact()
+        .unwrap_or(false);
+    if !try_it {
+        return;
+    }
+
+    if let Err(e) = memory_demo(config).await {
+        println!(
+            "  {} Memory demo failed (non-fatal): {e}",
+            style("⚠️").yellow()
+        );
+    }
+}
+
+async fn memory_demo(config: &Config) -> Result<()> {
+    let mem = memory::create_memory_with_storage(
+        &config.memory,
+        Some(&config.storage.provider.config),
+        &config.workspace_dir,
+        config.api_key.as_deref(),
+    )?;
+
+    let sample = "zeroclaw_user took the first-run tour";
+    mem.store(TUTORIAL_MEMORY_KEY, sample, MemoryCategory::Core, None)
+        .await?;
+    println!("  {} Stored: {sample:?}", style("✓").green().bold());
+
+    let recalled = mem.recall("first-run tour", 1, None).await?;
+    match recalled.first() {
+        Some(entry) => println!(
+            "  {} Recalled via keyword search: {:?}",
+            style("✓").green().bold(),
+            entry.content
+        ),
+        None => println!(
+            "  {} Recall returned nothing — the backend may index asynchronously.",
+            style("⚠️").yellow()
+        ),
+    }
+
+    // Leave the workspace the way we found it.
+    mem.forget(TUTORIAL_MEMORY_KEY).await?;
+    println!(
+        "  {} Removed the sample again — memory is back to clean.",
+        style("✓").green().bold()
+    );
+    Ok(())
+}
+
+/// Stop 2: scheduled tasks (read-only — cron jobs are real side effects).
+fn tour_cron() {
+    print_stop(2, "Scheduled tasks");
+    print_bullet(
+        "The built-in scheduler runs prompts on a cron cadence — reports, reminders, checks.",
+    );
+    println!("    zeroclaw cron add \"0 9 * * *\" \"Summarize yesterday's notes\"");
+    println!("    zeroclaw cron list");
+    print_bullet("Nothing is scheduled by the tour; try it when you have a recurring task.");
+}
+
+/// Stop 3: channels currently configured (read-only config view).
+fn tour_channels(config: &Config) {
+    print_stop(3, "Channels");
+    print_bullet(
+        "Channels connect the agent to messaging platforms (Telegram, Discord, Slack, ...).",
+    );
+    let configured = configured_channel_names(config);
+    if configured.is_empty() {
+        print_bullet("None configured yet — `zeroclaw onboard --channels-only` sets one up.");
+    } else {
+        print_bullet(&format!("Configured now: {}.", configured.join(", ")));
+        print_bullet("Start them with `zeroclaw channel start` (or run `zeroclaw daemon`).");
+    }
+}
+
+/// Stop 4: skills present in the workspace.
+fn tour_skills(config: &Config) {
+    print_stop(4, "Skills");
+    print_bullet("Skills are markdown playbooks in workspace/skills/ that extend what the agent knows how to do.");
+    let skills = crate::skills::load_skills_with_config(&config.workspace_dir, config);
+    if skills.is_empty() {
+        print_bullet("No skills installed yet — browse and install with `zeroclaw skills`.");
+    } else {
+        let names: Vec<&str> = skills.iter().take(5).map(|s| s.name.as_str()).collect();
+        print_bullet(&format!(
+            "{} installed: {}{}",
+            skills.len(),
+            names.join(", "),
+            if skills.len() > names.len() {
+                ", ..."
+            } else {
+                ""
+            }
+        ));
+    }
+}
+
+/// Channel names with a config section present, in display order.
+fn configured_channel_names(config: &Config) -> Vec<&'static str> {
+    let cc = &config.channels_config;
+    let sections: [(&'static str, bool); 15] = [
+        ("telegram", cc.telegram.is_some()),
+        ("discord", cc.discord.is_some()),
+        ("slack", cc.slack.is_some()),
+        ("mattermost", cc.mattermost.is_some()),
+        ("webhook", cc.webhook.is_some()),
+        ("imessage", cc.imessage.is_some()),
+        ("matrix", cc.matrix.is_some()),
+        ("signal", cc.signal.is_some()),
+        ("whatsapp", cc.whatsapp.is_some()),
+        ("linq", cc.linq.is_some()),
+        ("email", cc.email.is_some()),
+        ("irc", cc.irc.is_some()),
+        ("lark", cc.lark.is_some()),
+        ("dingtalk", cc.dingtalk.is_some()),
+        ("qq", cc.qq.is_some()),
+    ];
+    sections
+        .into_iter()
+        .filter_map(|(name, configured)| configured.then_some(name))
+        .collect()
+}
+
+fn print_stop(number: u8, title: &str) {
+    println!();
+    println!(
+        "  {} {}",
+        style(format!("Stop {number}/4:")).cyan().bold(),
+        style(title).white().bold()
+    );
+}
+
+fn print_bullet(text: &str) {
+    println!("    {} {text}", style("•").dim());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn configured_channel_names_reflect_config_sections() {
+        let mut config = Config::default();
+        assert!(configured_channel_names(&config).is_empty());
+
+        config.channels_config.telegram = Some(crate::config::TelegramConfig {
+            bot_token: "token".into(),
+            allowed_users: vec![],
+            stream_mode: crate::config::StreamMode::default(),
+            draft_update_interval_ms: 1000,
+            interrupt_on_new_message: false,
+            mention_only: false,
+        });
+        config.channels_config.imessage = Some(crate::config::IMessageConfig {
+            allowed_contacts: vec![],
+        });
+
+        assert_eq!(
+            configured_channel_names(&config),
+            vec!["telegram", "imessage"]
+        );
+    }
+}
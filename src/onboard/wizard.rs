@@ -3,8 +3,9 @@ use crate::config::schema::{
 };
 use crate::config::{
     AutonomyConfig, BrowserConfig, ChannelsConfig, ComposioConfig, Config, DiscordConfig,
-    HeartbeatConfig, IMessageConfig, LarkConfig, MatrixConfig, MemoryConfig, ObservabilityConfig,
-    RuntimeConfig, SecretsConfig, SlackConfig, StorageConfig, TelegramConfig, WebhookConfig,
+    HeartbeatConfig, IMessageConfig, IntegrationsConfig, LarkConfig, MatrixConfig, MemoryConfig,
+    NotesConfig, ObservabilityConfig, RuntimeConfig, SecretsConfig, SlackConfig, StorageConfig,
+    TelegramConfig, UiConfig, WebhookConfig,
 };
 use crate::hardware::{self, HardwareConfig};
 use crate::memory::{
@@ -117,6 +118,7 @@ pub async fn run_wizard() -> Result<Config> {
     let (workspace_dir, config_path) = setup_workspace()?;
 
     print_step(2, 9, "AI Provider & API Key");
+    offer_provider_key_import(&config_path)?;
     let (provider, api_key, model, provider_api_url) = setup_provider(&workspace_dir)?;
 
     print_step(3, 9, "Channels (How You Talk to ZeroClaw)");
@@ -155,6 +157,7 @@ pub async fn run_wizard() -> Result<Config> {
         default_model: Some(model),
         default_temperature: 0.7,
         observability: ObservabilityConfig::default(),
+        ui: UiConfig::default(),
         autonomy: AutonomyConfig::default(),
         runtime: RuntimeConfig::default(),
         reliability: crate::config::ReliabilityConfig::default(),
@@ -162,6 +165,7 @@ pub async fn run_wizard() -> Result<Config> {
         agent: crate::config::schema::AgentConfig::default(),
         skills: crate::config::SkillsConfig::default(),
         model_routes: Vec::new(),
+        providers: crate::config::ProvidersConfig::default(),
         embedding_routes: Vec::new(),
         heartbeat: HeartbeatConfig::default(),
         cron: crate::config::CronConfig::default(),
@@ -171,14 +175,30 @@ pub async fn run_wizard() -> Result<Config> {
         tunnel: tunnel_config,
         gateway: crate::config::GatewayConfig::default(),
         composio: composio_config,
+        integrations: IntegrationsConfig::default(),
+        notes: NotesConfig::default(),
         secrets: secrets_config,
         browser: BrowserConfig::default(),
         http_request: crate::config::HttpRequestConfig::default(),
+        run_code: crate::config::RunCodeConfig::default(),
+        containers: crate::config::ContainersConfig::default(),
+        ci: crate::config::CiConfig::default(),
+        pager: crate::config::PagerConfig::default(),
+        network_scan: crate::config::NetworkScanConfig::default(),
+        monitors: crate::config::MonitorsConfig::default(),
+        self_report: crate::config::SelfReportConfig::default(),
+        digest: crate::config::DigestConfig::default(),
+        events: crate::config::EventsConfig::default(),
+        delegation: crate::config::DelegationConfig::default(),
+        nodes: crate::config::NodesConfig::default(),
+        security: crate::config::SecurityConfig::default(),
         multimodal: crate::config::MultimodalConfig::default(),
         web_search: crate::config::WebSearchConfig::default(),
         proxy: crate::config::ProxyConfig::default(),
         identity: crate::config::IdentityConfig::default(),
         cost: crate::config::CostConfig::default(),
+        quota: crate::config::QuotaConfig::default(),
+        cache: crate::config::CacheConfig::default(),
         peripherals: crate::config::PeripheralsConfig::default(),
         agents: std::collections::HashMap::new(),
         hardware: hardware_config,
@@ -332,6 +352,7 @@ pub async fn run_quick_setup(
     provider: Option<&str>,
     model_override: Option<&str>,
     memory_backend: Option<&str>,
+    template: Option<&str>,
 ) -> Result<Config> {
     let home = directories::UserDirs::new()
         .map(|u| u.home_dir().to_path_buf())
@@ -342,6 +363,7 @@ pub async fn run_quick_setup(
         provider,
         model_override,
         memory_backend,
+        template,
         &home,
     )
     .await
@@ -353,6 +375,7 @@ async fn run_quick_setup_with_home(
     provider: Option<&str>,
     model_override: Option<&str>,
     memory_backend: Option<&str>,
+    template: Option<&str>,
     home: &Path,
 ) -> Result<Config> {
     println!("{}", style(BANNER).cyan().bold());
@@ -368,6 +391,10 @@ async fn run_quick_setup_with_home(
     let workspace_dir = zeroclaw_dir.join("workspace");
     let config_path = zeroclaw_dir.join("config.toml");
 
+    // Resolve the template up front so an unknown name fails before any
+    // files are written.
+    let template = template.map(crate::onboard::templates::find).transpose()?;
+
     fs::create_dir_all(&workspace_dir).context("Failed to create workspace directory")?;
 
     let provider_name = provider.unwrap_or("openrouter").to_string();
@@ -394,6 +421,7 @@ async fn run_quick_setup_with_home(
         default_model: Some(model.clone()),
         default_temperature: 0.7,
         observability: ObservabilityConfig::default(),
+        ui: UiConfig::default(),
         autonomy: AutonomyConfig::default(),
         runtime: RuntimeConfig::default(),
         reliability: crate::config::ReliabilityConfig::default(),
@@ -401,6 +429,7 @@ async fn run_quick_setup_with_home(
         agent: crate::config::schema::AgentConfig::default(),
         skills: crate::config::SkillsConfig::default(),
         model_routes: Vec::new(),
+        providers: crate::config::ProvidersConfig::default(),
         embedding_routes: Vec::new(),
         heartbeat: HeartbeatConfig::default(),
         cron: crate::config::CronConfig::default(),
@@ -410,14 +439,30 @@ async fn run_quick_setup_with_home(
         tunnel: crate::config::TunnelConfig::default(),
         gateway: crate::config::GatewayConfig::default(),
         composio: ComposioConfig::default(),
+        integrations: IntegrationsConfig::default(),
+        notes: NotesConfig::default(),
         secrets: SecretsConfig::default(),
         browser: BrowserConfig::default(),
         http_request: crate::config::HttpRequestConfig::default(),
+        run_code: crate::config::RunCodeConfig::default(),
+        containers: crate::config::ContainersConfig::default(),
+        ci: crate::config::CiConfig::default(),
+        pager: crate::config::PagerConfig::default(),
+        network_scan: crate::config::NetworkScanConfig::default(),
+        monitors: crate::config::MonitorsConfig::default(),
+        self_report: crate::config::SelfReportConfig::default(),
+        digest: crate::config::DigestConfig::default(),
+        events: crate::config::EventsConfig::default(),
+        delegation: crate::config::DelegationConfig::default(),
+        nodes: crate::config::NodesConfig::default(),
+        security: crate::config::SecurityConfig::default(),
         multimodal: crate::config::MultimodalConfig::default(),
         web_search: crate::config::WebSearchConfig::default(),
         proxy: crate::config::ProxyConfig::default(),
         identity: crate::config::IdentityConfig::default(),
         cost: crate::config::CostConfig::default(),
+        quota: crate::config::QuotaConfig::default(),
+        cache: crate::config::CacheConfig::default(),
         peripherals: crate::config::PeripheralsConfig::default(),
         agents: std::collections::HashMap::new(),
         hardware: crate::config::HardwareConfig::default(),
@@ -427,6 +472,18 @@ async fn run_quick_setup_with_home(
     config.save().await?;
     persist_workspace_selection(&config.config_path).await?;
 
+    // Template files are written before the generic scaffold so the
+    // scaffold's skip-existing behavior leaves them in place.
+    if let Some(template) = template {
+        crate::onboard::templates::apply(template, &workspace_dir)?;
+        println!(
+            "  {} Template:   {} ({})",
+            style("✓").green().bold(),
+            style(template.name).green(),
+            template.description
+        );
+    }
+
     // Scaffold minimal workspace files
     let default_ctx = ProjectContext {
         user_name: std::env::var("USER").unwrap_or_else(|_| "User".into()),
@@ -520,6 +577,158 @@ async fn run_quick_setup_with_home(
     Ok(config)
 }
 
+// ── Non-interactive setup from a declarative file ─────────────────
+
+/// A cron task declared in a setup file. Exactly one of `prompt` (agent job)
+/// or `command` (shell job) must be set.
+#[derive(Debug, Deserialize)]
+struct SetupCronJob {
+    schedule: String,
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    prompt: Option<String>,
+    #[serde(default)]
+    command: Option<String>,
+}
+
+/// Full non-interactive setup from a declarative file (`onboard --from-file`).
+///
+/// The file uses the regular `config.toml` schema (provider, channels, memory,
+/// autonomy, ...) plus an optional `[[cron_jobs]]` array for seeding scheduled
+/// tasks, so one file can provision a whole fleet of devices reproducibly.
+pub async fn run_setup_from_file(setup_path: &Path) -> Result<Config> {
+    let home = directories::UserDirs::new()
+        .map(|u| u.home_dir().to_path_buf())
+        .context("Could not find home directory")?;
+    run_setup_from_file_with_home(setup_path, &home).await
+}
+
+async fn run_setup_from_file_with_home(setup_path: &Path, home: &Path) -> Result<Config> {
+    let contents = fs::read_to_string(setup_path)
+        .with_context(|| format!("Failed to read setup file {}", setup_path.display()))?;
+    let mut value: toml::Value = toml::from_str(&contents)
+        .with_context(|| format!("Failed to parse setup file {}", setup_path.display()))?;
+
+    // Split out [[cron_jobs]] before the remainder parses as a config.
+    let cron_jobs: Vec<SetupCronJob> = match value
+        .as_table_mut()
+        .and_then(|table| table.remove("cron_jobs"))
+    {
+        Some(jobs) => jobs
+            .try_into()
+            .context("Failed to parse [[cron_jobs]] in setup file")?,
+        None => Vec::new(),
+    };
+    for job in &cron_jobs {
+        if job.prompt.is_some() == job.command.is_some() {
+            bail!(
+                "Each [[cron_jobs]] entry needs exactly one of `prompt` (agent job) or `command` (shell job)"
+            );
+        }
+    }
+
+    let file_sets_model = value
+        .as_table()
+        .is_some_and(|table| table.contains_key("default_model"));
+
+    // Layer the setup file over full defaults so partial sections (for
+    // example `[memory]` with only `backend`) work like profile overlays.
+    let mut merged =
+        toml::Value::try_from(Config::default()).context("Failed to serialize default config")?;
+    crate::config::schema::merge_toml_values(&mut merged, value);
+    let mut config: Config = merged
+        .try_into()
+        .with_context(|| format!("Invalid config in setup file {}", setup_path.display()))?;
+
+    let zeroclaw_dir = home.join(".zeroclaw");
+    config.workspace_dir = zeroclaw_dir.join("workspace");
+    config.config_path = zeroclaw_dir.join("config.toml");
+    fs::create_dir_all(&config.workspace_dir).context("Failed to create workspace directory")?;
+
+    let provider_name = config
+        .default_provider
+        .get_or_insert_with(|| "openrouter".to_string())
+        .clone();
+    if !file_sets_model {
+        config.default_model = Some(default_model_for_provider(&provider_name));
+    }
+
+    config.save().await?;
+    persist_workspace_selection(&config.config_path).await?;
+
+    let default_ctx = ProjectContext {
+        user_name: std::env::var("USER").unwrap_or_else(|_| "User".into()),
+        timezone: "UTC".into(),
+        agent_name: "ZeroClaw".into(),
+        communication_style:
+            "Be warm, natural, and clear. Use occasional relevant emojis (1-2 max) and avoid robotic phrasing."
+                .into(),
+    };
+    scaffold_workspace(&config.workspace_dir, &default_ctx)?;
+
+    let seeded = seed_cron_jobs_from_setup(&config, &cron_jobs)?;
+
+    println!(
+        "  {} Provisioned from {}",
+        style("✓").green().bold(),
+        style(setup_path.display()).green()
+    );
+    println!(
+        "  {} Provider:   {} | Model: {}",
+        style("✓").green().bold(),
+        style(&provider_name).green(),
+        style(config.default_model.as_deref().unwrap_or("default")).green()
+    );
+    println!(
+        "  {} Memory:     {}",
+        style("✓").green().bold(),
+        style(&config.memory.backend).green()
+    );
+    if seeded > 0 {
+        println!(
+            "  {} Cron tasks: {seeded} seeded",
+            style("✓").green().bold()
+        );
+    }
+    println!(
+        "  {} Config saved: {}",
+        style("✓").green().bold(),
+        style(config.config_path.display()).green()
+    );
+
+    Ok(config)
+}
+
+fn seed_cron_jobs_from_setup(config: &Config, cron_jobs: &[SetupCronJob]) -> Result<usize> {
+    let mut seeded = 0;
+    for job in cron_jobs {
+        let schedule = crate::cron::Schedule::Cron {
+            expr: job.schedule.clone(),
+            tz: None,
+        };
+        if let Some(prompt) = &job.prompt {
+            crate::cron::add_agent_job(
+                config,
+                job.name.clone(),
+                schedule,
+                prompt,
+                crate::cron::SessionTarget::Isolated,
+                None,
+                Vec::new(),
+                None,
+                false,
+            )
+            .with_context(|| format!("Failed to seed cron job '{}'", job.schedule))?;
+        } else if let Some(command) = &job.command {
+            crate::cron::add_shell_job(config, job.name.clone(), schedule, command)
+                .with_context(|| format!("Failed to seed cron job '{}'", job.schedule))?;
+        }
+        seeded += 1;
+    }
+    Ok(seeded)
+}
+
 fn canonical_provider_name(provider_name: &str) -> &str {
     if is_qwen_oauth_alias(provider_name) {
         return "qwen-code";
@@ -1061,6 +1270,82 @@ fn parse_openai_compatible_model_ids(payload: &Value) -> Vec<String> {
     normalize_model_ids(models)
 }
 
+/// Extract per-model metadata from an OpenRouter-style catalog payload.
+///
+/// OpenRouter reports `context_length`, `architecture.input_modalities`, and
+/// `supported_parameters`; plain OpenAI-compatible `/models` endpoints report
+/// none of these, so the result stays sparse (only models with at least one
+/// known field get an entry).
+fn parse_openai_compatible_model_metadata(payload: &Value) -> Vec<ModelMetadata> {
+    let Some(data) = payload
+        .get("data")
+        .and_then(Value::as_array)
+        .or_else(|| payload.as_array())
+    else {
+        return Vec::new();
+    };
+
+    let mut metadata = Vec::new();
+    for model in data {
+        let Some(id) = model.get("id").and_then(Value::as_str) else {
+            continue;
+        };
+
+        let context_length = model.get("context_length").and_then(Value::as_u64);
+        let input_modalities: Vec<String> = model
+            .get("architecture")
+            .and_then(|arch| arch.get("input_modalities"))
+            .and_then(Value::as_array)
+            .map(|items| {
+                items
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default();
+        let supports_tools = model
+            .get("supported_parameters")
+            .and_then(Value::as_array)
+            .map(|params| params.iter().any(|p| p.as_str() == Some("tools")));
+        let prompt_price = parse_catalog_price(model.get("pricing").and_then(|p| p.get("prompt")));
+        let completion_price =
+            parse_catalog_price(model.get("pricing").and_then(|p| p.get("completion")));
+
+        if context_length.is_none()
+            && input_modalities.is_empty()
+            && supports_tools.is_none()
+            && prompt_price.is_none()
+            && completion_price.is_none()
+        {
+            continue;
+        }
+
+        metadata.push(ModelMetadata {
+            id: id.trim().to_string(),
+            context_length,
+            input_modalities,
+            supports_tools,
+            prompt_price,
+            completion_price,
+        });
+    }
+
+    metadata
+}
+
+/// Parse a catalog price value (OpenRouter reports USD-per-token strings like
+/// `"0.000003"`; plain numbers are accepted too).
+fn parse_catalog_price(value: Option<&Value>) -> Option<f64> {
+    let value = value?;
+    let price = match value {
+        Value::Number(n) => n.as_f64()?,
+        Value::String(s) => s.trim().parse::<f64>().ok()?,
+        _ => return None,
+    };
+    (price >= 0.0).then_some(price)
+}
+
 fn parse_gemini_model_ids(payload: &Value) -> Vec<String> {
     let Some(models) = payload.get("models").and_then(Value::as_array) else {
         return Vec::new();
@@ -1108,7 +1393,7 @@ fn fetch_openai_compatible_models(
     endpoint: &str,
     api_key: Option<&str>,
     allow_unauthenticated: bool,
-) -> Result<Vec<String>> {
+) -> Result<(Vec<String>, Vec<ModelMetadata>)> {
     let client = build_model_fetch_client()?;
     let mut request = client.get(endpoint);
 
@@ -1125,10 +1410,13 @@ fn fetch_openai_compatible_models(
         .json()
         .context("failed to parse model list response")?;
 
-    Ok(parse_openai_compatible_model_ids(&payload))
+    Ok((
+        parse_openai_compatible_model_ids(&payload),
+        parse_openai_compatible_model_metadata(&payload),
+    ))
 }
 
-fn fetch_openrouter_models(api_key: Option<&str>) -> Result<Vec<String>> {
+fn fetch_openrouter_models(api_key: Option<&str>) -> Result<(Vec<String>, Vec<ModelMetadata>)> {
     let client = build_model_fetch_client()?;
     let mut request = client.get("https://openrouter.ai/api/v1/models");
     if let Some(api_key) = api_key {
@@ -1142,7 +1430,10 @@ fn fetch_openrouter_models(api_key: Option<&str>) -> Result<Vec<String>> {
         .json()
         .context("failed to parse OpenRouter model list response")?;
 
-    Ok(parse_openai_compatible_model_ids(&payload))
+    Ok((
+        parse_openai_compatible_model_ids(&payload),
+        parse_openai_compatible_model_metadata(&payload),
+    ))
 }
 
 fn fetch_anthropic_models(api_key: Option<&str>) -> Result<Vec<String>> {
@@ -1211,7 +1502,10 @@ fn fetch_ollama_models() -> Result<Vec<String>> {
     Ok(parse_ollama_model_ids(&payload))
 }
 
-fn resolve_live_models_endpoint(provider_name: &str, provider_api_url: Option<&str>) -> Option<String> {
+fn resolve_live_models_endpoint(
+    provider_name: &str,
+    provider_api_url: Option<&str>,
+) -> Option<String> {
     if canonical_provider_name(provider_name) == "llamacpp" {
         if let Some(url) = provider_api_url
             .map(str::trim)
@@ -1232,7 +1526,7 @@ fn fetch_live_models_for_provider(
     provider_name: &str,
     api_key: &str,
     provider_api_url: Option<&str>,
-) -> Result<Vec<String>> {
+) -> Result<(Vec<String>, Vec<ModelMetadata>)> {
     let requested_provider_name = provider_name;
     let provider_name = canonical_provider_name(provider_name);
     let api_key = if api_key.trim().is_empty() {
@@ -1254,27 +1548,30 @@ fn fetch_live_models_for_provider(
         Some(api_key.trim().to_string())
     };
 
-    let models = match provider_name {
+    let (models, metadata) = match provider_name {
         "openrouter" => fetch_openrouter_models(api_key.as_deref())?,
-        "anthropic" => fetch_anthropic_models(api_key.as_deref())?,
-        "gemini" => fetch_gemini_models(api_key.as_deref())?,
+        "anthropic" => (fetch_anthropic_models(api_key.as_deref())?, Vec::new()),
+        "gemini" => (fetch_gemini_models(api_key.as_deref())?, Vec::new()),
         "ollama" => {
             if api_key.as_deref().map_or(true, |k| k.trim().is_empty()) {
                 // Key is None or empty, assume local Ollama
-                fetch_ollama_models()?
+                (fetch_ollama_models()?, Vec::new())
             } else {
                 // Key is present, assume Ollama Cloud and return hardcoded list
-                vec![
-                    "glm-5:cloud".to_string(),
-                    "glm-4.7:cloud".to_string(),
-                    "gpt-oss:cloud".to_string(),
-                    "gemini-3-flash-preview:cloud".to_string(),
-                    "qwen2.5-coder:1.5b".to_string(),
-                    "qwen2.5-coder:3b".to_string(),
-                    "qwen2.5:cloud".to_string(),
-                    "minimax-m2.5:cloud".to_string(),
-                    "deepseek-v3.1:cloud".to_string(),
-                ]
+                (
+                    vec![
+                        "glm-5:cloud".to_string(),
+                        "glm-4.7:cloud".to_string(),
+                        "gpt-oss:cloud".to_string(),
+                        "gemini-3-flash-preview:cloud".to_string(),
+                        "qwen2.5-coder:1.5b".to_string(),
+                        "qwen2.5-coder:3b".to_string(),
+                        "qwen2.5:cloud".to_string(),
+                        "minimax-m2.5:cloud".to_string(),
+                        "deepseek-v3.1:cloud".to_string(),
+                    ],
+                    Vec::new(),
+                )
             }
         }
         _ => {
@@ -1289,12 +1586,46 @@ fn fetch_live_models_for_provider(
                     allow_unauthenticated,
                 )?
             } else {
-                Vec::new()
+                (Vec::new(), Vec::new())
             }
         }
     };
 
-    Ok(models)
+    Ok((models, metadata))
+}
+
+/// Per-model metadata captured from the provider catalog when available.
+///
+/// Fields stay `None`/empty when the provider does not report them — callers
+/// must treat unknown as "no information", never as "unsupported".
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModelMetadata {
+    pub id: String,
+    /// Context window in tokens, when the catalog reports it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context_length: Option<u64>,
+    /// Input modalities (e.g. "text", "image"), when the catalog reports them.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub input_modalities: Vec<String>,
+    /// Whether the model supports native function calling, when reported.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub supports_tools: Option<bool>,
+    /// Prompt price in USD per token, when the catalog reports it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prompt_price: Option<f64>,
+    /// Completion price in USD per token, when the catalog reports it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completion_price: Option<f64>,
+}
+
+impl ModelMetadata {
+    /// Whether the model accepts image input. `None` when modalities are unknown.
+    pub fn supports_image_input(&self) -> Option<bool> {
+        if self.input_modalities.is_empty() {
+            return None;
+        }
+        Some(self.input_modalities.iter().any(|m| m == "image"))
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -1302,6 +1633,9 @@ struct ModelCacheEntry {
     provider: String,
     fetched_at_unix: u64,
     models: Vec<String>,
+    /// Sparse: only models the provider reported metadata for.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    metadata: Vec<ModelMetadata>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -1362,6 +1696,7 @@ fn cache_live_models_for_provider(
     workspace_dir: &Path,
     provider_name: &str,
     models: &[String],
+    metadata: &[ModelMetadata],
 ) -> Result<()> {
     let normalized_models = normalize_model_ids(models.to_vec());
     if normalized_models.is_empty() {
@@ -1378,17 +1713,51 @@ fn cache_live_models_for_provider(
     {
         entry.fetched_at_unix = now;
         entry.models = normalized_models;
+        entry.metadata = metadata.to_vec();
     } else {
         state.entries.push(ModelCacheEntry {
             provider: provider_name.to_string(),
             fetched_at_unix: now,
             models: normalized_models,
+            metadata: metadata.to_vec(),
         });
     }
 
     save_model_cache_state(workspace_dir, &state)
 }
 
+/// Look up cached catalog metadata for a model, ignoring the cache TTL
+/// (stale metadata beats no metadata for advisory checks).
+pub fn lookup_model_metadata(
+    workspace_dir: &Path,
+    provider_name: &str,
+    model_id: &str,
+) -> Option<ModelMetadata> {
+    let state = load_model_cache_state(workspace_dir).ok()?;
+    state
+        .entries
+        .into_iter()
+        .find(|entry| entry.provider == provider_name)?
+        .metadata
+        .into_iter()
+        .find(|meta| meta.id == model_id)
+}
+
+/// Return the cached model IDs for a provider, ignoring the cache TTL.
+/// Empty when the provider has never been refreshed.
+pub fn cached_model_ids(workspace_dir: &Path, provider_name: &str) -> Vec<String> {
+    load_model_cache_state(workspace_dir)
+        .ok()
+        .and_then(|state| {
+            state
+                .entries
+                .into_iter()
+                .find(|entry| entry.provider == provider_name)
+        })
+        .map(|entry| entry.models)
+        .unwrap_or_default()
+}
+
 fn load_cached_models_for_provider_internal(
     workspace_dir: &Path,
     provider_name: &str,
@@ -1512,12 +1881,22 @@ pub fn run_models_refresh(
     let api_key = config.api_key.clone().unwrap_or_default();
 
     match fetch_live_models_for_provider(&provider_name, &api_key, config.api_url.as_deref()) {
-        Ok(models) if !models.is_empty() => {
-            cache_live_models_for_provider(&config.workspace_dir, &provider_name, &models)?;
+        Ok((models, metadata)) if !models.is_empty() => {
+            cache_live_models_for_provider(
+                &config.workspace_dir,
+                &provider_name,
+                &models,
+                &metadata,
+            )?;
             println!(
-                "Refreshed '{}' model cache with {} models.",
+                "Refreshed '{}' model cache with {} models{}.",
                 provider_name,
-                models.len()
+                models.len(),
+                if metadata.is_empty() {
+                    String::new()
+                } else {
+                    format!(" ({} with metadata)", metadata.len())
+                }
             );
             print_model_preview(&models);
             Ok(())
@@ -1555,6 +1934,220 @@ pub fn run_models_refresh(
     }
 }
 
+// ── Catalog browsing (`models list/search/compare`) ──────────────
+
+fn format_context_length(tokens: u64) -> String {
+    if tokens >= 1000 && tokens.is_multiple_of(1000) {
+        format!("{}k", tokens / 1000)
+    } else {
+        tokens.to_string()
+    }
+}
+
+/// Format a USD-per-token price as USD per million tokens.
+fn format_price_per_million(price: f64) -> String {
+    format!("${:.2}/M", price * 1_000_000.0)
+}
+
+/// One-line metadata suffix for list/search output; empty when nothing is known.
+fn describe_model_suffix(meta: Option<&ModelMetadata>) -> String {
+    let Some(meta) = meta else {
+        return String::new();
+    };
+
+    let mut parts = Vec::new();
+    if let Some(ctx) = meta.context_length {
+        parts.push(format!("ctx {}", format_context_length(ctx)));
+    }
+    if !meta.input_modalities.is_empty() {
+        parts.push(meta.input_modalities.join("+"));
+    }
+    if meta.supports_tools == Some(true) {
+        parts.push("tools".to_string());
+    }
+    if let (Some(prompt), Some(completion)) = (meta.prompt_price, meta.completion_price) {
+        parts.push(format!(
+            "{} in / {} out",
+            format_price_per_million(prompt),
+            format_price_per_million(completion)
+        ));
+    }
+
+    if parts.is_empty() {
+        String::new()
+    } else {
+        format!("  ({})", parts.join(", "))
+    }
+}
+
+pub fn run_models_list(config: &Config, provider_override: Option<&str>) -> Result<()> {
+    let provider_name = provider_override
+        .or(config.default_provider.as_deref())
+        .unwrap_or("openrouter")
+        .trim()
+        .to_string();
+
+    if provider_name.is_empty() {
+        anyhow::bail!("Provider name cannot be empty");
+    }
+
+    let state = load_model_cache_state(&config.workspace_dir)?;
+    let Some(entry) = state
+        .entries
+        .iter()
+        .find(|entry| entry.provider == provider_name)
+    else {
+        anyhow::bail!(
+            "No cached catalog for provider '{provider_name}'. \
+             Run `zeroclaw models refresh --provider {provider_name}` first."
+        );
+    };
+
+    println!(
+        "Cached models for '{}' ({} models, updated {} ago):",
+        provider_name,
+        entry.models.len(),
+        humanize_age(now_unix_secs().saturating_sub(entry.fetched_at_unix))
+    );
+    for model in &entry.models {
+        let meta = entry.metadata.iter().find(|meta| &meta.id == model);
+        println!("  {model}{}", describe_model_suffix(meta));
+    }
+
+    Ok(())
+}
+
+pub fn run_models_search(config: &Config, query: &str) -> Result<()> {
+    let query = query.trim();
+    if query.is_empty() {
+        anyhow::bail!("Search query cannot be empty");
+    }
+
+    let state = load_model_cache_state(&config.workspace_dir)?;
+    if state.entries.is_empty() {
+        anyhow::bail!("No cached model catalogs. Run `zeroclaw models refresh` first.");
+    }
+
+    let needle = query.to_lowercase();
+    let mut matched = 0usize;
+    for entry in &state.entries {
+        let hits: Vec<&String> = entry
+            .models
+            .iter()
+            .filter(|model| model.to_lowercase().contains(&needle))
+            .collect();
+        if hits.is_empty() {
+            continue;
+        }
+
+        println!("{} ({} matches):", entry.provider, hits.len());
+        for model in hits {
+            let meta = entry.metadata.iter().find(|meta| &meta.id == model);
+            println!("  {model}{}", describe_model_suffix(meta));
+            matched += 1;
+        }
+    }
+
+    if matched == 0 {
+        println!("No cached models match '{query}'.");
+    }
+
+    Ok(())
+}
+
+/// A model located in the cached catalogs, with its owning provider.
+struct CatalogModel {
+    provider: String,
+    metadata: Option<ModelMetadata>,
+}
+
+fn find_catalog_model(state: &ModelCacheState, model_id: &str) -> Option<CatalogModel> {
+    state
+        .entries
+        .iter()
+        .find(|entry| entry.models.iter().any(|model| model == model_id))
+        .map(|entry| CatalogModel {
+            provider: entry.provider.clone(),
+            metadata: entry
+                .metadata
+                .iter()
+                .find(|meta| meta.id == model_id)
+                .cloned(),
+        })
+}
+
+pub fn run_models_compare(config: &Config, model_a: &str, model_b: &str) -> Result<()> {
+    let state = load_model_cache_state(&config.workspace_dir)?;
+    if state.entries.is_empty() {
+        anyhow::bail!("No cached model catalogs. Run `zeroclaw models refresh` first.");
+    }
+
+    let lookup = |model_id: &str| -> Result<CatalogModel> {
+        find_catalog_model(&state, model_id).with_context(|| {
+            format!(
+                "Model '{model_id}' not found in any cached catalog. \
+                 Run `zeroclaw models refresh` or check the ID with `zeroclaw models search`."
+            )
+        })
+    };
+    let a = lookup(model_a)?;
+    let b = lookup(model_b)?;
+
+    let unknown = || "unknown".to_string();
+    let cell = |model: &CatalogModel, f: &dyn Fn(&ModelMetadata) -> Option<String>| {
+        model.metadata.as_ref().and_then(f).unwrap_or_else(unknown)
+    };
+
+    let rows: Vec<(&str, String, String)> = vec![
+        ("provider", a.provider.clone(), b.provider.clone()),
+        (
+            "context length",
+            cell(&a, &|m| m.context_length.map(format_context_length)),
+            cell(&b, &|m| m.context_length.map(format_context_length)),
+        ),
+        (
+            "input modalities",
+            cell(&a, &|m| {
+                (!m.input_modalities.is_empty()).then(|| m.input_modalities.join(", "))
+            }),
+            cell(&b, &|m| {
+                (!m.input_modalities.is_empty()).then(|| m.input_modalities.join(", "))
+            }),
+        ),
+        (
+            "native tools",
+            cell(&a, &|m| {
+                m.supports_tools
+                    .map(|v| if v { "yes" } else { "no" }.to_string())
+            }),
+            cell(&b, &|m| {
+                m.supports_tools
+                    .map(|v| if v { "yes" } else { "no" }.to_string())
+            }),
+        ),
+        (
+            "prompt price",
+            cell(&a, &|m| m.prompt_price.map(format_price_per_million)),
+            cell(&b, &|m| m.prompt_price.map(format_price_per_million)),
+        ),
+        (
+            "completion price",
+            cell(&a, &|m| m.completion_price.map(format_price_per_million)),
+            cell(&b, &|m| m.completion_price.map(format_price_per_million)),
+        ),
+    ];
+
+    let col_a = model_a
+        .len()
+        .max(rows.iter().map(|r| r.1.len()).max().unwrap_or(0));
+    println!("{:<18} {:<col_a$}  {}", "", model_a, model_b);
+    for (label, value_a, value_b) in rows {
+        println!("{label:<18} {value_a:<col_a$}  {value_b}");
+    }
+
+    Ok(())
+}
+
 // ── Step helpers ─────────────────────────────────────────────────
 
 fn print_step(current: u8, total: u8, title: &str) {
@@ -1629,6 +2222,246 @@ fn setup_workspace() -> Result<(PathBuf, PathBuf)> {
 
 // ── Step 2: Provider & API Key ───────────────────────────────────
 
+/// A provider API key discovered in the environment or another tool's config.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct DetectedProviderKey {
+    provider: String,
+    source: String,
+    key: String,
+}
+
+fn map_external_provider_name(name: &str) -> Option<&'static str> {
+    match name.trim().to_ascii_lowercase().as_str() {
+        "openai" => Some("openai"),
+        "anthropic" | "claude" => Some("anthropic"),
+        "openrouter" => Some("openrouter"),
+        _ => None,
+    }
+}
+
+fn detect_env_provider_keys() -> Vec<DetectedProviderKey> {
+    let mut detected = Vec::new();
+    for (env_var, provider) in [
+        ("OPENAI_API_KEY", "openai"),
+        ("ANTHROPIC_API_KEY", "anthropic"),
+    ] {
+        if let Ok(value) = std::env::var(env_var) {
+            if !value.trim().is_empty() {
+                detected.push(DetectedProviderKey {
+                    provider: provider.to_string(),
+                    source: format!("{env_var} environment variable"),
+                    key: value.trim().to_string(),
+                });
+            }
+        }
+    }
+    detected
+}
+
+/// Parse provider keys from an aider config file (`~/.aider.conf.yml`).
+fn parse_aider_conf_keys(contents: &str) -> Vec<(String, String)> {
+    let Ok(doc) = serde_yaml::from_str::<serde_yaml::Value>(contents) else {
+        return Vec::new();
+    };
+    let mut keys = Vec::new();
+    for (yaml_key, provider) in [
+        ("openai-api-key", "openai"),
+        ("anthropic-api-key", "anthropic"),
+    ] {
+        if let Some(value) = doc.get(yaml_key).and_then(serde_yaml::Value::as_str) {
+            if !value.trim().is_empty() {
+                keys.push((provider.to_string(), value.trim().to_string()));
+            }
+        }
+    }
+    keys
+}
+
+/// Parse provider keys from the `llm` CLI key store
+/// (`~/.config/io.datasette.llm/keys.json`).
+fn parse_llm_keys_json(contents: &str) -> Vec<(String, String)> {
+    let Ok(doc) = serde_json::from_str::<Value>(contents) else {
+        return Vec::new();
+    };
+    let Some(map) = doc.as_object() else {
+        return Vec::new();
+    };
+    map.iter()
+        .filter_map(|(name, value)| {
+            let provider = map_external_provider_name(name)?;
+            let key = value.as_str()?.trim();
+            if key.is_empty() {
+                None
+            } else {
+                Some((provider.to_string(), key.to_string()))
+            }
+        })
+        .collect()
+}
+
+/// Parse provider keys from a Continue config file (`~/.continue/config.json`).
+fn parse_continue_config_keys(contents: &str) -> Vec<(String, String)> {
+    let Ok(doc) = serde_json::from_str::<Value>(contents) else {
+        return Vec::new();
+    };
+    let Some(models) = doc.get("models").and_then(Value::as_array) else {
+        return Vec::new();
+    };
+    models
+        .iter()
+        .filter_map(|model| {
+            let provider = map_external_provider_name(model.get("provider")?.as_str()?)?;
+            let key = model.get("apiKey")?.as_str()?.trim();
+            if key.is_empty() {
+                None
+            } else {
+                Some((provider.to_string(), key.to_string()))
+            }
+        })
+        .collect()
+}
+
+type ToolKeyParser = fn(&str) -> Vec<(String, String)>;
+
+fn detect_tool_config_keys(home_dir: &Path) -> Vec<DetectedProviderKey> {
+    let sources: [(PathBuf, &str, ToolKeyParser); 3] = [
+        (
+            home_dir.join(".aider.conf.yml"),
+            "aider config",
+            parse_aider_conf_keys,
+        ),
+        (
+            home_dir.join(".config/io.datasette.llm/keys.json"),
+            "llm key store",
+            parse_llm_keys_json,
+        ),
+        (
+            home_dir.join(".continue/config.json"),
+            "Continue config",
+            parse_continue_config_keys,
+        ),
+    ];
+
+    let mut detected = Vec::new();
+    for (path, label, parse) in sources {
+        let Ok(contents) = fs::read_to_string(&path) else {
+            continue;
+        };
+        for (provider, key) in parse(&contents) {
+            detected.push(DetectedProviderKey {
+                provider,
+                source: format!("{label} ({})", path.display()),
+                key,
+            });
+        }
+    }
+    detected
+}
+
+/// Keep the first detection per provider (env vars win over tool configs).
+fn dedupe_detected_keys(detected: Vec<DetectedProviderKey>) -> Vec<DetectedProviderKey> {
+    let mut seen = BTreeSet::new();
+    detected
+        .into_iter()
+        .filter(|entry| seen.insert(entry.provider.clone()))
+        .collect()
+}
+
+/// Live ping: list the provider's models with the candidate key.
+fn verify_provider_key(provider: &str, api_key: &str) -> Result<()> {
+    match provider {
+        "anthropic" => fetch_anthropic_models(Some(api_key)).map(|_| ()),
+        "openrouter" => fetch_openrouter_models(Some(api_key)).map(|_| ()),
+        _ => {
+            let endpoint = models_endpoint_for_provider(provider)
+                .ok_or_else(|| anyhow::anyhow!("no verification endpoint for '{provider}'"))?;
+            fetch_openai_compatible_models(endpoint, Some(api_key), false).map(|_| ())
+        }
+    }
+}
+
+/// Offer to import provider keys found in the environment or in aider/llm/
+/// Continue config files into the auth profile store, verifying each with a
+/// live model-list ping first. Key material is never printed.
+fn offer_provider_key_import(config_path: &Path) -> Result<()> {
+    let mut detected = detect_env_provider_keys();
+    if let Some(user_dirs) = directories::UserDirs::new() {
+        detected.extend(detect_tool_config_keys(user_dirs.home_dir()));
+    }
+    let detected = dedupe_detected_keys(detected);
+    if detected.is_empty() {
+        return Ok(());
+    }
+
+    println!();
+    print_bullet(&format!(
+        "Found {} existing provider key(s) you can reuse:",
+        detected.len()
+    ));
+    for entry in &detected {
+        print_bullet(&format!(
+            "{} — from {}",
+            style(&entry.provider).green(),
+            entry.source
+        ));
+    }
+    println!();
+
+    let state_dir = config_path
+        .parent()
+        .map_or_else(|| PathBuf::from("."), Path::to_path_buf);
+    let auth = crate::auth::AuthService::new(&state_dir, false);
+
+    for entry in detected {
+        let import = Confirm::new()
+            .with_prompt(format!(
+                "  Import {} key from {} into auth profiles?",
+                entry.provider, entry.source
+            ))
+            .default(true)
+            .interact()?;
+        if !import {
+            continue;
+        }
+
+        let verified = match verify_provider_key(&entry.provider, &entry.key) {
+            Ok(()) => {
+                println!(
+                    "  {} {} key verified with a live ping",
+                    style("✓").green().bold(),
+                    entry.provider
+                );
+                true
+            }
+            Err(e) => {
+                println!(
+                    "  {} {} key failed verification: {e}",
+                    style("✗").red().bold(),
+                    entry.provider
+                );
+                Confirm::new()
+                    .with_prompt("  Import it anyway?")
+                    .default(false)
+                    .interact()?
+            }
+        };
+        if !verified {
+            continue;
+        }
+
+        let mut metadata = std::collections::HashMap::new();
+        metadata.insert("source".to_string(), entry.source.clone());
+        auth.store_provider_token(&entry.provider, "imported", &entry.key, metadata, true)?;
+        println!(
+            "  {} Saved as auth profile {}",
+            style("✓").green().bold(),
+            style(format!("{}:imported", entry.provider)).green()
+        );
+    }
+
+    Ok(())
+}
+
 #[allow(clippy::too_many_lines)]
 fn setup_provider(workspace_dir: &Path) -> Result<(String, String, String, Option<String>)> {
     // ── Tier selection ──
@@ -2113,11 +2946,12 @@ fn setup_provider(workspace_dir: &Path) -> Result<(String, String, String, Optio
                     &api_key,
                     provider_api_url.as_deref(),
                 ) {
-                    Ok(live_model_ids) if !live_model_ids.is_empty() => {
+                    Ok((live_model_ids, live_metadata)) if !live_model_ids.is_empty() => {
                         cache_live_models_for_provider(
                             workspace_dir,
                             provider_name,
                             &live_model_ids,
+                            &live_metadata,
                         )?;
 
                         let fetched_count = live_model_ids.len();
@@ -2352,7 +3186,10 @@ fn setup_tool_mode() -> Result<(ComposioConfig, SecretsConfig)> {
         .default(true)
         .interact()?;
 
-    let secrets_config = SecretsConfig { encrypt };
+    let secrets_config = SecretsConfig {
+        encrypt,
+        ..Default::default()
+    };
 
     if encrypt {
         println!(
@@ -4879,6 +5716,7 @@ mod tests {
             Some("openrouter"),
             Some("custom-model-946"),
             Some("sqlite"),
+            None,
             tmp.path(),
         )
         .await
@@ -4902,6 +5740,7 @@ mod tests {
             Some("anthropic"),
             None,
             Some("sqlite"),
+            None,
             tmp.path(),
         )
         .await
@@ -4912,6 +5751,90 @@ mod tests {
         assert_eq!(config.default_model.as_deref(), Some(expected.as_str()));
     }
 
+    // ── setup from file ─────────────────────────────────────────
+
+    #[tokio::test]
+    async fn setup_from_file_provisions_config_and_cron_jobs() {
+        let tmp = TempDir::new().unwrap();
+        let setup_path = tmp.path().join("setup.toml");
+        std::fs::write(
+            &setup_path,
+            r#"
+default_provider = "anthropic"
+api_key = "sk-fleet-test"
+
+[memory]
+backend = "sqlite"
+
+[channels_config]
+cli = true
+
+[[cron_jobs]]
+schedule = "0 9 * * *"
+name = "morning-report"
+prompt = "Summarize overnight activity"
+
+[[cron_jobs]]
+schedule = "*/30 * * * *"
+command = "echo heartbeat"
+"#,
+        )
+        .unwrap();
+
+        let config = run_setup_from_file_with_home(&setup_path, tmp.path())
+            .await
+            .unwrap();
+
+        assert_eq!(config.default_provider.as_deref(), Some("anthropic"));
+        assert_eq!(config.api_key.as_deref(), Some("sk-fleet-test"));
+        let expected_model = default_model_for_provider("anthropic");
+        assert_eq!(
+            config.default_model.as_deref(),
+            Some(expected_model.as_str())
+        );
+        assert!(config.config_path.exists());
+        assert!(config.workspace_dir.join("IDENTITY.md").exists());
+
+        let jobs = crate::cron::list_jobs(&config).unwrap();
+        assert_eq!(jobs.len(), 2);
+        assert!(jobs
+            .iter()
+            .any(|j| j.name.as_deref() == Some("morning-report")
+                && matches!(j.job_type, crate::cron::JobType::Agent)));
+        assert!(jobs
+            .iter()
+            .any(|j| j.command == "echo heartbeat"
+                && matches!(j.job_type, crate::cron::JobType::Shell)));
+    }
+
+    #[tokio::test]
+    async fn setup_from_file_rejects_ambiguous_cron_entry() {
+        let tmp = TempDir::new().unwrap();
+        let setup_path = tmp.path().join("setup.toml");
+        std::fs::write(
+            &setup_path,
+            "[[cron_jobs]]\nschedule = \"0 9 * * *\"\nprompt = \"a\"\ncommand = \"b\"\n",
+        )
+        .unwrap();
+
+        let err = run_setup_from_file_with_home(&setup_path, tmp.path())
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("exactly one of"));
+    }
+
+    #[tokio::test]
+    async fn setup_from_file_rejects_invalid_toml() {
+        let tmp = TempDir::new().unwrap();
+        let setup_path = tmp.path().join("setup.toml");
+        std::fs::write(&setup_path, "not valid toml :::").unwrap();
+
+        let err = run_setup_from_file_with_home(&setup_path, tmp.path())
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("Failed to parse setup file"));
+    }
+
     // ── scaffold_workspace: basic file creation ─────────────────
 
     #[test]
@@ -5788,7 +6711,7 @@ mod tests {
         let tmp = TempDir::new().unwrap();
         let models = vec!["gpt-5.1".to_string(), "gpt-5-mini".to_string()];
 
-        cache_live_models_for_provider(tmp.path(), "openai", &models).unwrap();
+        cache_live_models_for_provider(tmp.path(), "openai", &models, &[]).unwrap();
 
         let cached =
             load_cached_models_for_provider(tmp.path(), "openai", MODEL_CACHE_TTL_SECS).unwrap();
@@ -5807,6 +6730,7 @@ mod tests {
                 provider: "openai".to_string(),
                 fetched_at_unix: now_unix_secs().saturating_sub(MODEL_CACHE_TTL_SECS + 120),
                 models: vec!["gpt-5.1".to_string()],
+                metadata: Vec::new(),
             }],
         };
 
@@ -5820,11 +6744,215 @@ mod tests {
         assert!(stale_any.is_some());
     }
 
+    #[test]
+    fn parse_model_metadata_extracts_openrouter_fields() {
+        let payload = json!({
+            "data": [
+                {
+                    "id": "vendor/vision-model",
+                    "context_length": 200_000,
+                    "architecture": {"input_modalities": ["text", "image"]},
+                    "supported_parameters": ["tools", "temperature"]
+                },
+                {
+                    "id": "vendor/text-model",
+                    "context_length": 32_768,
+                    "architecture": {"input_modalities": ["text"]},
+                    "supported_parameters": ["temperature"]
+                },
+                {"id": "vendor/bare-model"}
+            ]
+        });
+
+        let metadata = parse_openai_compatible_model_metadata(&payload);
+        assert_eq!(
+            metadata.len(),
+            2,
+            "bare entries without metadata are skipped"
+        );
+
+        let vision = &metadata[0];
+        assert_eq!(vision.id, "vendor/vision-model");
+        assert_eq!(vision.context_length, Some(200_000));
+        assert_eq!(vision.supports_image_input(), Some(true));
+        assert_eq!(vision.supports_tools, Some(true));
+
+        let text = &metadata[1];
+        assert_eq!(text.supports_image_input(), Some(false));
+        assert_eq!(text.supports_tools, Some(false));
+    }
+
+    #[test]
+    fn model_metadata_unknown_modalities_stay_unknown() {
+        let meta = ModelMetadata {
+            id: "vendor/model".to_string(),
+            context_length: Some(8192),
+            input_modalities: Vec::new(),
+            supports_tools: None,
+            prompt_price: None,
+            completion_price: None,
+        };
+        assert_eq!(meta.supports_image_input(), None);
+    }
+
+    #[test]
+    fn model_cache_round_trips_metadata_and_lookup() {
+        let tmp = TempDir::new().unwrap();
+        let models = vec!["vendor/vision-model".to_string()];
+        let metadata = vec![ModelMetadata {
+            id: "vendor/vision-model".to_string(),
+            context_length: Some(128_000),
+            input_modalities: vec!["text".to_string(), "image".to_string()],
+            supports_tools: Some(true),
+            prompt_price: Some(3e-6),
+            completion_price: Some(1.5e-5),
+        }];
+
+        cache_live_models_for_provider(tmp.path(), "openrouter", &models, &metadata).unwrap();
+
+        let found = lookup_model_metadata(tmp.path(), "openrouter", "vendor/vision-model")
+            .expect("expected cached metadata");
+        assert_eq!(found.context_length, Some(128_000));
+        assert_eq!(found.supports_image_input(), Some(true));
+
+        assert!(lookup_model_metadata(tmp.path(), "openrouter", "vendor/other").is_none());
+        assert!(lookup_model_metadata(tmp.path(), "openai", "vendor/vision-model").is_none());
+    }
+
+    #[test]
+    fn model_cache_without_metadata_field_still_loads() {
+        // Caches written before metadata existed must keep loading.
+        let tmp = TempDir::new().unwrap();
+        let path = model_cache_path(tmp.path());
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(
+            &path,
+            r#"{"entries":[{"provider":"openai","fetched_at_unix":1,"models":["gpt-5.1"]}]}"#,
+        )
+        .unwrap();
+
+        assert_eq!(cached_model_ids(tmp.path(), "openai"), vec!["gpt-5.1"]);
+        assert!(lookup_model_metadata(tmp.path(), "openai", "gpt-5.1").is_none());
+    }
+
+    #[test]
+    fn cached_model_ids_empty_for_unknown_provider() {
+        let tmp = TempDir::new().unwrap();
+        assert!(cached_model_ids(tmp.path(), "openrouter").is_empty());
+    }
+
+    #[test]
+    fn parse_catalog_price_accepts_strings_and_numbers() {
+        assert_eq!(parse_catalog_price(Some(&json!("0.000003"))), Some(3e-6));
+        assert_eq!(parse_catalog_price(Some(&json!(0.000_015))), Some(1.5e-5));
+        assert_eq!(parse_catalog_price(Some(&json!("-1"))), None);
+        assert_eq!(parse_catalog_price(Some(&json!("free"))), None);
+        assert_eq!(parse_catalog_price(None), None);
+    }
+
+    #[test]
+    fn format_helpers_render_human_readable_values() {
+        assert_eq!(format_context_length(200_000), "200k");
+        assert_eq!(format_context_length(4096), "4096");
+        assert_eq!(format_price_per_million(3e-6), "$3.00/M");
+    }
+
+    #[test]
+    fn describe_model_suffix_skips_unknown_fields() {
+        assert_eq!(describe_model_suffix(None), "");
+
+        let meta = ModelMetadata {
+            id: "vendor/model".to_string(),
+            context_length: Some(128_000),
+            input_modalities: vec!["text".to_string(), "image".to_string()],
+            supports_tools: Some(true),
+            prompt_price: None,
+            completion_price: None,
+        };
+        let suffix = describe_model_suffix(Some(&meta));
+        assert!(suffix.contains("ctx 128k"));
+        assert!(suffix.contains("text+image"));
+        assert!(suffix.contains("tools"));
+        assert!(!suffix.contains("$"));
+    }
+
+    #[test]
+    fn find_catalog_model_reports_owning_provider() {
+        let state = ModelCacheState {
+            entries: vec![ModelCacheEntry {
+                provider: "openrouter".to_string(),
+                fetched_at_unix: now_unix_secs(),
+                models: vec!["vendor/model".to_string()],
+                metadata: Vec::new(),
+            }],
+        };
+
+        let found = find_catalog_model(&state, "vendor/model").unwrap();
+        assert_eq!(found.provider, "openrouter");
+        assert!(found.metadata.is_none());
+        assert!(find_catalog_model(&state, "vendor/other").is_none());
+    }
+
+    #[test]
+    fn models_list_requires_cached_catalog() {
+        let tmp = TempDir::new().unwrap();
+        let config = Config {
+            workspace_dir: tmp.path().to_path_buf(),
+            default_provider: Some("openrouter".to_string()),
+            ..Config::default()
+        };
+
+        let err = run_models_list(&config, None).unwrap_err();
+        assert!(err.to_string().contains("models refresh"));
+
+        cache_live_models_for_provider(
+            tmp.path(),
+            "openrouter",
+            &["vendor/model".to_string()],
+            &[],
+        )
+        .unwrap();
+        run_models_list(&config, None).unwrap();
+    }
+
+    #[test]
+    fn models_search_and_compare_use_cached_catalogs() {
+        let tmp = TempDir::new().unwrap();
+        let config = Config {
+            workspace_dir: tmp.path().to_path_buf(),
+            ..Config::default()
+        };
+
+        assert!(run_models_search(&config, "vendor").is_err());
+
+        cache_live_models_for_provider(
+            tmp.path(),
+            "openrouter",
+            &["vendor/model-a".to_string(), "vendor/model-b".to_string()],
+            &[ModelMetadata {
+                id: "vendor/model-a".to_string(),
+                context_length: Some(200_000),
+                input_modalities: vec!["text".to_string()],
+                supports_tools: Some(true),
+                prompt_price: Some(3e-6),
+                completion_price: Some(1.5e-5),
+            }],
+        )
+        .unwrap();
+
+        run_models_search(&config, "MODEL-A").unwrap();
+        run_models_compare(&config, "vendor/model-a", "vendor/model-b").unwrap();
+
+        let err = run_models_compare(&config, "vendor/model-a", "vendor/missing").unwrap_err();
+        assert!(err.to_string().contains("not found in any cached catalog"));
+    }
+
     #[test]
     fn run_models_refresh_uses_fresh_cache_without_network() {
         let tmp = TempDir::new().unwrap();
 
-        cache_live_models_for_provider(tmp.path(), "openai", &["gpt-5.1".to_string()]).unwrap();
+        cache_live_models_for_provider(tmp.path(), "openai", &["gpt-5.1".to_string()], &[])
+            .unwrap();
 
         let config = Config {
             workspace_dir: tmp.path().to_path_buf(),
@@ -5852,6 +6980,93 @@ mod tests {
             .contains("does not support live model discovery"));
     }
 
+    // ── provider key import ─────────────────────────────────────
+
+    #[test]
+    fn parse_aider_conf_keys_extracts_known_providers() {
+        let yaml =
+            "model: gpt-4o\nopenai-api-key: sk-test-openai\nanthropic-api-key: sk-ant-test\n";
+        let keys = parse_aider_conf_keys(yaml);
+        assert!(keys.contains(&("openai".to_string(), "sk-test-openai".to_string())));
+        assert!(keys.contains(&("anthropic".to_string(), "sk-ant-test".to_string())));
+    }
+
+    #[test]
+    fn parse_aider_conf_keys_ignores_invalid_yaml_and_blanks() {
+        assert!(parse_aider_conf_keys(": not yaml :").is_empty());
+        assert!(parse_aider_conf_keys("openai-api-key: \"\"\n").is_empty());
+    }
+
+    #[test]
+    fn parse_llm_keys_json_maps_provider_names() {
+        let json = r#"{"// Note": "keys live here", "openai": "sk-test", "claude": "sk-ant-test", "unrelated": "x"}"#;
+        let keys = parse_llm_keys_json(json);
+        assert!(keys.contains(&("openai".to_string(), "sk-test".to_string())));
+        assert!(keys.contains(&("anthropic".to_string(), "sk-ant-test".to_string())));
+        assert_eq!(keys.len(), 2);
+    }
+
+    #[test]
+    fn parse_continue_config_keys_reads_model_entries() {
+        let json = r#"{"models": [
+            {"provider": "openai", "apiKey": "sk-test", "model": "gpt-4o"},
+            {"provider": "ollama", "model": "llama3"},
+            {"provider": "anthropic", "apiKey": "  "}
+        ]}"#;
+        let keys = parse_continue_config_keys(json);
+        assert_eq!(keys, vec![("openai".to_string(), "sk-test".to_string())]);
+    }
+
+    #[test]
+    fn dedupe_detected_keys_keeps_first_per_provider() {
+        let detected = vec![
+            DetectedProviderKey {
+                provider: "openai".into(),
+                source: "OPENAI_API_KEY environment variable".into(),
+                key: "sk-env".into(),
+            },
+            DetectedProviderKey {
+                provider: "openai".into(),
+                source: "aider config".into(),
+                key: "sk-aider".into(),
+            },
+            DetectedProviderKey {
+                provider: "anthropic".into(),
+                source: "llm key store".into(),
+                key: "sk-ant".into(),
+            },
+        ];
+        let deduped = dedupe_detected_keys(detected);
+        assert_eq!(deduped.len(), 2);
+        assert_eq!(deduped[0].key, "sk-env");
+        assert_eq!(deduped[1].provider, "anthropic");
+    }
+
+    #[test]
+    fn detect_tool_config_keys_reads_supported_files() {
+        let tmp = TempDir::new().unwrap();
+        std::fs::write(
+            tmp.path().join(".aider.conf.yml"),
+            "openai-api-key: sk-aider-test\n",
+        )
+        .unwrap();
+        std::fs::create_dir_all(tmp.path().join(".config/io.datasette.llm")).unwrap();
+        std::fs::write(
+            tmp.path().join(".config/io.datasette.llm/keys.json"),
+            r#"{"claude": "sk-ant-llm"}"#,
+        )
+        .unwrap();
+
+        let detected = detect_tool_config_keys(tmp.path());
+        assert_eq!(detected.len(), 2);
+        assert!(detected
+            .iter()
+            .any(|k| k.provider == "openai" && k.key == "sk-aider-test"));
+        assert!(detected
+            .iter()
+            .any(|k| k.provider == "anthropic" && k.source.contains("llm key store")));
+    }
+
     // ── provider_env_var ────────────────────────────────────────
 
     #[test]
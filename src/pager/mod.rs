@@ -0,0 +1,441 @@
+//! On-call pager integration: incident webhooks plus acknowledge/resolve actions.
+//!
+//! `POST /hooks/pager` on the gateway receives PagerDuty v3 webhooks or
+//! Opsgenie alert webhooks, has the agent produce a first-responder brief
+//! (likely blast radius, what to check first, suggested next steps), and
+//! delivers it to the configured `[pager]` channel. The `pager` tool exposes
+//! approval-gated acknowledge/resolve actions against the paging provider's
+//! REST API so the operator can close the loop from chat.
+
+use crate::config::PagerConfig;
+use anyhow::{bail, Context, Result};
+use serde_json::Value;
+
+/// Pager API request timeout in seconds.
+const API_TIMEOUT_SECS: u64 = 30;
+
+/// An incident extracted from a paging provider webhook payload.
+#[derive(Debug, Clone)]
+pub enum PagerIncident {
+    PagerDuty {
+        /// Incident id used by the REST API (e.g. `Q1AB2C3DEF4GH5`).
+        id: String,
+        title: String,
+        urgency: String,
+        /// Impacted service name from the incident payload.
+        service: String,
+        url: String,
+    },
+    Opsgenie {
+        /// Alert id used by the REST API.
+        id: String,
+        message: String,
+        priority: String,
+        /// Alert source (monitoring system that raised it).
+        source: String,
+    },
+}
+
+impl PagerIncident {
+    /// One-line description used in prompts and log lines.
+    pub fn describe(&self) -> String {
+        match self {
+            Self::PagerDuty {
+                title,
+                urgency,
+                service,
+                url,
+                ..
+            } => format!("PagerDuty incident ({urgency}) on service '{service}': {title} ({url})"),
+            Self::Opsgenie {
+                message,
+                priority,
+                source,
+                ..
+            } => format!("Opsgenie alert ({priority}) from {source}: {message}"),
+        }
+    }
+
+    /// Incident/alert id for REST API actions.
+    pub fn id(&self) -> &str {
+        match self {
+            Self::PagerDuty { id, .. } | Self::Opsgenie { id, .. } => id,
+        }
+    }
+}
+
+/// Parse a pager webhook payload into an incident, keyed by payload shape.
+///
+/// Returns `Ok(None)` for recognized events that need no response (webhook
+/// test pings, non-trigger lifecycle events like acknowledgements we caused
+/// ourselves) and an error for payloads that are not pager events at all.
+pub fn parse_event(payload: &Value) -> Result<Option<PagerIncident>> {
+    if let Some(event) = payload.get("event") {
+        return parse_pagerduty_event(event);
+    }
+    if payload.get("alert").is_some() {
+        return parse_opsgenie_event(payload);
+    }
+    bail!(
+        "Unsupported pager event. Expected a PagerDuty v3 webhook (`event` object) \
+         or an Opsgenie alert webhook (`alert` object)"
+    );
+}
+
+fn parse_pagerduty_event(event: &Value) -> Result<Option<PagerIncident>> {
+    let event_type = event
+        .get("event_type")
+        .and_then(Value::as_str)
+        .context("PagerDuty webhook missing `event.event_type`")?;
+    // Only newly triggered incidents need a first-responder brief; ack/resolve
+    // lifecycle events (often caused by our own tool actions) are ignored.
+    if event_type != "incident.triggered" {
+        return Ok(None);
+    }
+    let data = event
+        .get("data")
+        .context("PagerDuty webhook missing `event.data`")?;
+    let id = data
+        .get("id")
+        .and_then(Value::as_str)
+        .context("PagerDuty incident missing `id`")?
+        .to_string();
+    let title = data
+        .get("title")
+        .and_then(Value::as_str)
+        .unwrap_or("(untitled incident)")
+        .to_string();
+    let urgency = data
+        .get("urgency")
+        .and_then(Value::as_str)
+        .unwrap_or("unknown")
+        .to_string();
+    let service = data
+        .pointer("/service/summary")
+        .and_then(Value::as_str)
+        .unwrap_or("(unknown service)")
+        .to_string();
+    let url = data
+        .get("html_url")
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .to_string();
+
+    Ok(Some(PagerIncident::PagerDuty {
+        id,
+        title,
+        urgency,
+        service,
+        url,
+    }))
+}
+
+fn parse_opsgenie_event(payload: &Value) -> Result<Option<PagerIncident>> {
+    // Only alert creation needs a response; Close/Acknowledge/AddNote
+    // lifecycle callbacks are ignored.
+    if payload.get("action").and_then(Value::as_str) != Some("Create") {
+        return Ok(None);
+    }
+    let alert = payload
+        .get("alert")
+        .context("Opsgenie webhook missing `alert` object")?;
+    let id = alert
+        .get("alertId")
+        .or_else(|| alert.get("id"))
+        .and_then(Value::as_str)
+        .context("Opsgenie alert missing `alertId`")?
+        .to_string();
+    let message = alert
+        .get("message")
+        .and_then(Value::as_str)
+        .unwrap_or("(no message)")
+        .to_string();
+    let priority = alert
+        .get("priority")
+        .and_then(Value::as_str)
+        .unwrap_or("unknown")
+        .to_string();
+    let source = alert
+        .get("source")
+        .and_then(Value::as_str)
+        .unwrap_or("(unknown source)")
+        .to_string();
+
+    Ok(Some(PagerIncident::Opsgenie {
+        id,
+        message,
+        priority,
+        source,
+    }))
+}
+
+/// Verify a PagerDuty v3 webhook signature header against the shared secret.
+///
+/// Header format: `X-PagerDuty-Signature: v1=<hex>[,v1=<hex>...]` — multiple
+/// entries appear during secret rotation; any valid entry authorizes.
+pub fn verify_pagerduty_signature(secret: &str, body: &[u8], signature_header: &str) -> bool {
+    use hmac::{Hmac, Mac};
+    use sha2::Sha256;
+
+    signature_header
+        .split(',')
+        .filter_map(|entry| entry.trim().strip_prefix("v1="))
+        .any(|hex_sig| {
+            let Ok(expected) = hex::decode(hex_sig) else {
+                return false;
+            };
+            let Ok(mut mac) = Hmac::<Sha256>::new_from_slice(secret.as_bytes()) else {
+                return false;
+            };
+            mac.update(body);
+            mac.verify_slice(&expected).is_ok()
+        })
+}
+
+fn http_client() -> reqwest::Client {
+    crate::config::build_runtime_proxy_client_with_timeouts(
+        "integration.pager",
+        API_TIMEOUT_SECS,
+        10,
+    )
+}
+
+/// An incident action performed via the paging provider's REST API.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PagerAction {
+    Acknowledge,
+    Resolve,
+}
+
+impl PagerAction {
+    fn pagerduty_status(self) -> &'static str {
+        match self {
+            Self::Acknowledge => "acknowledged",
+            Self::Resolve => "resolved",
+        }
+    }
+
+    fn opsgenie_path(self) -> &'static str {
+        match self {
+            Self::Acknowledge => "acknowledge",
+            Self::Resolve => "close",
+        }
+    }
+}
+
+/// Validate an incident/alert id before interpolating it into an API URL.
+pub fn validate_incident_id(id: &str) -> Result<(), String> {
+    let valid = !id.is_empty()
+        && id.len() <= 64
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if valid {
+        Ok(())
+    } else {
+        Err(format!("Invalid incident id: '{id}'"))
+    }
+}
+
+/// Acknowledge or resolve an incident via the configured provider's API.
+pub async fn perform_action(pager: &PagerConfig, action: PagerAction, id: &str) -> Result<()> {
+    let api_key = pager
+        .api_key
+        .as_deref()
+        .context("[pager] api_key is required for acknowledge/resolve actions")?;
+
+    match pager.provider.as_str() {
+        "pagerduty" => {
+            let from = pager.from_email.as_deref().context(
+                "[pager] from_email is required for PagerDuty actions (the API attributes \
+                 them to this user)",
+            )?;
+            http_client()
+                .put(format!("https://api.pagerduty.com/incidents/{id}"))
+                .header("Authorization", format!("Token token={api_key}"))
+                .header("From", from)
+                .json(&serde_json::json!({
+                    "incident": {
+                        "type": "incident_reference",
+                        "status": action.pagerduty_status(),
+                    }
+                }))
+                .send()
+                .await
+                .context("Failed to reach PagerDuty API")?
+                .error_for_status()
+                .context("PagerDuty incidents API returned an error")?;
+            Ok(())
+        }
+        "opsgenie" => {
+            http_client()
+                .post(format!(
+                    "https://api.opsgenie.com/v2/alerts/{id}/{}",
+                    action.opsgenie_path()
+                ))
+                .header("Authorization", format!("GenieKey {api_key}"))
+                .json(&serde_json::json!({ "source": "zeroclaw" }))
+                .send()
+                .await
+                .context("Failed to reach Opsgenie API")?
+                .error_for_status()
+                .context("Opsgenie alerts API returned an error")?;
+            Ok(())
+        }
+        other => bail!("Unsupported pager provider '{other}' (supported: pagerduty, opsgenie)"),
+    }
+}
+
+/// System prompt for the first-responder brief.
+pub const RESPONDER_SYSTEM_PROMPT: &str = "You are an on-call first-responder assistant. Given \
+    a newly triggered incident, produce a short brief: (1) likely blast radius based on the \
+    impacted service, (2) the first three things to check (recent deploys, related alerts, \
+    resource saturation), (3) whether this looks actionable or likely noise. Be concrete and \
+    terse; the reader is mid-page. Do not invent facts not present in the incident.";
+
+/// Build the user prompt for the responder brief.
+pub fn build_responder_prompt(incident: &PagerIncident) -> String {
+    format!(
+        "{}\n\nIf more context is needed, the operator can ask follow-up questions — suggest \
+         which tools or dashboards to consult.",
+        incident.describe()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pagerduty_trigger_payload() -> Value {
+        serde_json::json!({
+            "event": {
+                "event_type": "incident.triggered",
+                "data": {
+                    "id": "Q1AB2C3DEF4GH5",
+                    "title": "High error rate on checkout",
+                    "urgency": "high",
+                    "service": {"summary": "zeroclaw_service"},
+                    "html_url": "https://example.pagerduty.com/incidents/Q1AB2C3DEF4GH5"
+                }
+            }
+        })
+    }
+
+    fn opsgenie_create_payload() -> Value {
+        serde_json::json!({
+            "action": "Create",
+            "alert": {
+                "alertId": "a1b2c3d4-e5f6-1234-5678-abcdef012345",
+                "message": "Disk usage above 90%",
+                "priority": "P2",
+                "source": "prometheus"
+            }
+        })
+    }
+
+    #[test]
+    fn parses_pagerduty_triggered_incident() {
+        let incident = parse_event(&pagerduty_trigger_payload())
+            .unwrap()
+            .expect("incident should be extracted");
+        match incident {
+            PagerIncident::PagerDuty {
+                id,
+                urgency,
+                service,
+                ..
+            } => {
+                assert_eq!(id, "Q1AB2C3DEF4GH5");
+                assert_eq!(urgency, "high");
+                assert_eq!(service, "zeroclaw_service");
+            }
+            PagerIncident::Opsgenie { .. } => panic!("expected PagerDuty incident"),
+        }
+    }
+
+    #[test]
+    fn ignores_pagerduty_lifecycle_events() {
+        let mut payload = pagerduty_trigger_payload();
+        payload["event"]["event_type"] = Value::String("incident.acknowledged".into());
+        assert!(parse_event(&payload).unwrap().is_none());
+    }
+
+    #[test]
+    fn parses_opsgenie_created_alert() {
+        let incident = parse_event(&opsgenie_create_payload())
+            .unwrap()
+            .expect("incident should be extracted");
+        match incident {
+            PagerIncident::Opsgenie {
+                id,
+                priority,
+                source,
+                ..
+            } => {
+                assert_eq!(id, "a1b2c3d4-e5f6-1234-5678-abcdef012345");
+                assert_eq!(priority, "P2");
+                assert_eq!(source, "prometheus");
+            }
+            PagerIncident::PagerDuty { .. } => panic!("expected Opsgenie alert"),
+        }
+    }
+
+    #[test]
+    fn ignores_opsgenie_lifecycle_actions() {
+        let mut payload = opsgenie_create_payload();
+        payload["action"] = Value::String("Acknowledge".into());
+        assert!(parse_event(&payload).unwrap().is_none());
+    }
+
+    #[test]
+    fn rejects_unrecognized_payloads() {
+        assert!(parse_event(&serde_json::json!({"message": "hello"})).is_err());
+    }
+
+    #[test]
+    fn validate_incident_id_rejects_injection() {
+        assert!(validate_incident_id("Q1AB2C3DEF4GH5").is_ok());
+        assert!(validate_incident_id("a1b2c3d4-e5f6-1234-5678-abcdef012345").is_ok());
+        assert!(validate_incident_id("").is_err());
+        assert!(validate_incident_id("../other-endpoint").is_err());
+        assert!(validate_incident_id("id?query=1").is_err());
+    }
+
+    #[test]
+    fn pagerduty_signature_verifies_any_rotation_entry() {
+        use hmac::{Hmac, Mac};
+        use sha2::Sha256;
+
+        let secret = "pager-test-secret";
+        let body = b"{\"event\":{}}";
+        let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).unwrap();
+        mac.update(body);
+        let sig = hex::encode(mac.finalize().into_bytes());
+
+        assert!(verify_pagerduty_signature(
+            secret,
+            body,
+            &format!("v1={sig}")
+        ));
+        assert!(verify_pagerduty_signature(
+            secret,
+            body,
+            &format!("v1=deadbeef,v1={sig}")
+        ));
+        assert!(!verify_pagerduty_signature(secret, body, "v1=deadbeef"));
+        assert!(!verify_pagerduty_signature(secret, body, ""));
+        assert!(!verify_pagerduty_signature(
+            "wrong",
+            body,
+            &format!("v1={sig}")
+        ));
+    }
+
+    #[test]
+    fn responder_prompt_includes_incident_context() {
+        let incident = parse_event(&pagerduty_trigger_payload()).unwrap().unwrap();
+        let prompt = build_responder_prompt(&incident);
+        assert!(prompt.contains("zeroclaw_service"));
+        assert!(prompt.contains("High error rate on checkout"));
+    }
+}
@@ -0,0 +1,342 @@
+//! Firmware build-and-flash pipeline for custom peripheral sketches.
+//!
+//! Compiles a firmware project (PlatformIO or cargo for STM32), flashes the
+//! artifact via probe-rs, then verifies the board answers the tool handshake.
+//! Per-board build profiles live under the workspace:
+//!
+//! ```toml
+//! # <workspace>/firmware-profiles/nucleo-f401re.toml
+//! builder = "cargo"                  # or "platformio"
+//! project_dir = "~/projects/custom-firmware"
+//! chip = "STM32F401RETx"             # probe-rs chip id
+//! target = "thumbv7em-none-eabihf"   # cargo builder only
+//! # environment = "nucleo_f401re"    # platformio builder only
+//! # artifact = "custom-name"         # override artifact file name
+//! ```
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Directory under the workspace holding per-board build profiles.
+const PROFILE_DIR: &str = "firmware-profiles";
+
+/// Handshake retries after flashing (board needs time to reboot).
+const HANDSHAKE_RETRIES: u32 = 5;
+const HANDSHAKE_RETRY_DELAY_SECS: u64 = 2;
+
+/// Per-board firmware build profile (`firmware-profiles/<board>.toml`).
+#[derive(Debug, Deserialize)]
+pub struct FirmwareProfile {
+    /// Builder: "cargo" or "platformio".
+    pub builder: String,
+    /// Firmware project directory (tilde expanded).
+    pub project_dir: String,
+    /// probe-rs chip id (e.g. "STM32F401RETx").
+    pub chip: String,
+    /// Cargo target triple (cargo builder only).
+    #[serde(default)]
+    pub target: Option<String>,
+    /// PlatformIO environment name (platformio builder only).
+    #[serde(default)]
+    pub environment: Option<String>,
+    /// Artifact file name override when it differs from the project name.
+    #[serde(default)]
+    pub artifact: Option<String>,
+}
+
+impl FirmwareProfile {
+    /// Parse and validate a profile from TOML.
+    pub fn parse(contents: &str) -> Result<Self> {
+        let profile: Self = toml::from_str(contents).context("Invalid firmware profile TOML")?;
+        match profile.builder.as_str() {
+            "cargo" => {
+                if profile.target.is_none() {
+                    anyhow::bail!("Cargo builder requires 'target' (e.g. thumbv7em-none-eabihf)");
+                }
+            }
+            "platformio" => {}
+            other => anyhow::bail!(
+                "Unsupported builder: {}. Supported: \"cargo\", \"platformio\"",
+                other
+            ),
+        }
+        if profile.chip.trim().is_empty() {
+            anyhow::bail!("Profile requires 'chip' (probe-rs chip id)");
+        }
+        Ok(profile)
+    }
+
+    /// Expected artifact path after a successful build.
+    pub fn artifact_path(&self, project_dir: &Path) -> PathBuf {
+        match self.builder.as_str() {
+            "platformio" => {
+                let env = self.environment.as_deref().unwrap_or("default");
+                project_dir
+                    .join(".pio")
+                    .join("build")
+                    .join(env)
+                    .join("firmware.elf")
+            }
+            _ => {
+                // Cargo: target/<triple>/release/<name>, name defaulting to
+                // the project directory name.
+                let name = self.artifact.clone().unwrap_or_else(|| {
+                    project_dir
+                        .file_name()
+                        .map(|n| n.to_string_lossy().into_owned())
+                        .unwrap_or_default()
+                });
+                project_dir
+                    .join("target")
+                    .join(self.target.as_deref().unwrap_or_default())
+                    .join("release")
+                    .join(name)
+            }
+        }
+    }
+}
+
+/// Path of the build profile for a board under the workspace.
+///
+/// Board names are restricted to simple identifiers so a profile lookup can
+/// never escape the profile directory.
+pub fn profile_path(workspace_dir: &Path, board: &str) -> Result<PathBuf> {
+    if board.is_empty()
+        || !board
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        anyhow::bail!("Invalid board name for profile lookup: {}", board);
+    }
+    Ok(workspace_dir
+        .join(PROFILE_DIR)
+        .join(format!("{board}.toml")))
+}
+
+/// Load the build profile for a board, with setup guidance when missing.
+fn load_profile(workspace_dir: &Path, board: &str) -> Result<FirmwareProfile> {
+    let path = profile_path(workspace_dir, board)?;
+    let contents = std::fs::read_to_string(&path).with_context(|| {
+        format!(
+            "No build profile for {} at {}. Create it with builder, project_dir, chip \
+             (see docs/commands-reference.md, 'peripheral flash --board').",
+            board,
+            path.display()
+        )
+    })?;
+    FirmwareProfile::parse(&contents)
+        .with_context(|| format!("Invalid profile at {}", path.display()))
+}
+
+/// Run the full pipeline: build, flash via probe-rs, verify handshake.
+pub async fn run_pipeline(config: &crate::config::Config, board: &str) -> Result<()> {
+    let profile = load_profile(&config.workspace_dir, board)?;
+    let project_dir = PathBuf::from(shellexpand::tilde(&profile.project_dir).into_owned());
+    if !project_dir.is_dir() {
+        anyhow::bail!("Firmware project not found: {}", project_dir.display());
+    }
+
+    println!("Building firmware for {} ({})...", board, profile.builder);
+    build_firmware(&profile, &project_dir)?;
+
+    let artifact = profile.artifact_path(&project_dir);
+    if !artifact.exists() {
+        anyhow::bail!("Built artifact not found at {}", artifact.display());
+    }
+
+    println!("Flashing {} via probe-rs ({})...", board, profile.chip);
+    flash_artifact(&profile.chip, &artifact)?;
+
+    verify_handshake(config, board).await
+}
+
+/// Compile the firmware project with the configured builder.
+fn build_firmware(profile: &FirmwareProfile, project_dir: &Path) -> Result<()> {
+    let mut cmd = match profile.builder.as_str() {
+        "platformio" => {
+            let mut cmd = Command::new("pio");
+            cmd.arg("run");
+            if let Some(env) = &profile.environment {
+                cmd.args(["--environment", env]);
+            }
+            cmd
+        }
+        _ => {
+            let mut cmd = Command::new("cargo");
+            cmd.args(["build", "--release", "--target"]);
+            cmd.arg(profile.target.as_deref().unwrap_or_default());
+            cmd
+        }
+    };
+    let output = cmd
+        .current_dir(project_dir)
+        .output()
+        .with_context(|| format!("Failed to run {} builder", profile.builder))?;
+    if !output.status.success() {
+        anyhow::bail!("Build failed:\n{}", String::from_utf8_lossy(&output.stderr));
+    }
+    Ok(())
+}
+
+/// Flash the artifact and reset the board so the new firmware boots.
+fn flash_artifact(chip: &str, artifact: &Path) -> Result<()> {
+    if !super::nucleo_flash::probe_rs_available() {
+        anyhow::bail!("probe-rs not found. Install it:\n  cargo install probe-rs-tools --locked");
+    }
+    let artifact_str = artifact
+        .to_str()
+        .context("Artifact path is not valid UTF-8")?;
+    // download + reset rather than `probe-rs run`: run attaches to the target
+    // and never returns, while the pipeline must continue to the handshake.
+    let download = Command::new("probe-rs")
+        .args(["download", "--chip", chip, artifact_str])
+        .output()
+        .context("probe-rs download failed")?;
+    if !download.status.success() {
+        anyhow::bail!(
+            "Flash failed:\n{}",
+            String::from_utf8_lossy(&download.stderr)
+        );
+    }
+    let reset = Command::new("probe-rs")
+        .args(["reset", "--chip", chip])
+        .output()
+        .context("probe-rs reset failed")?;
+    if !reset.status.success() {
+        anyhow::bail!("Reset failed:\n{}", String::from_utf8_lossy(&reset.stderr));
+    }
+    Ok(())
+}
+
+/// Verify the flashed board answers the tool handshake (serial ping).
+async fn verify_handshake(config: &crate::config::Config, board: &str) -> Result<()> {
+    let Some(entry) = config
+        .peripherals
+        .boards
+        .iter()
+        .find(|b| b.board == board && b.transport == "serial" && b.path.is_some())
+    else {
+        println!(
+            "Flashed. Skipping handshake: {} has no serial entry in [peripherals]. \
+             Add one with: zeroclaw peripheral add {} <path>",
+            board, board
+        );
+        return Ok(());
+    };
+
+    println!("Verifying tool handshake...");
+    for attempt in 1..=HANDSHAKE_RETRIES {
+        tokio::time::sleep(std::time::Duration::from_secs(HANDSHAKE_RETRY_DELAY_SECS)).await;
+        let Ok(peripheral) = super::serial::SerialPeripheral::connect(entry).await else {
+            continue;
+        };
+        if super::Peripheral::health_check(&peripheral).await {
+            println!("Handshake OK: {} answers ping after flash.", board);
+            return Ok(());
+        }
+        tracing::debug!("Handshake attempt {attempt} failed for {board}");
+    }
+    anyhow::bail!(
+        "Board {} did not answer the tool handshake after {} attempts. \
+         Check the firmware implements ping/capabilities over serial.",
+        board,
+        HANDSHAKE_RETRIES
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn profile_parse_accepts_cargo_with_target() {
+        let profile = FirmwareProfile::parse(
+            r#"
+builder = "cargo"
+project_dir = "~/projects/custom-firmware"
+chip = "STM32F401RETx"
+target = "thumbv7em-none-eabihf"
+"#,
+        )
+        .unwrap();
+        assert_eq!(profile.builder, "cargo");
+        assert_eq!(profile.chip, "STM32F401RETx");
+    }
+
+    #[test]
+    fn profile_parse_rejects_cargo_without_target() {
+        let err = FirmwareProfile::parse(
+            r#"
+builder = "cargo"
+project_dir = "fw"
+chip = "STM32F401RETx"
+"#,
+        )
+        .map(|_| ())
+        .unwrap_err();
+        assert!(err.to_string().contains("target"));
+    }
+
+    #[test]
+    fn profile_parse_rejects_unknown_builder() {
+        let err = FirmwareProfile::parse(
+            r#"
+builder = "make"
+project_dir = "fw"
+chip = "STM32F401RETx"
+"#,
+        )
+        .map(|_| ())
+        .unwrap_err();
+        assert!(err.to_string().contains("Unsupported builder"));
+    }
+
+    #[test]
+    fn artifact_path_for_cargo_uses_project_name() {
+        let profile = FirmwareProfile::parse(
+            r#"
+builder = "cargo"
+project_dir = "fw"
+chip = "STM32F401RETx"
+target = "thumbv7em-none-eabihf"
+"#,
+        )
+        .unwrap();
+        let path = profile.artifact_path(Path::new("/home/zeroclaw_user/custom-firmware"));
+        assert_eq!(
+            path,
+            Path::new(
+                "/home/zeroclaw_user/custom-firmware/target/thumbv7em-none-eabihf/release/custom-firmware"
+            )
+        );
+    }
+
+    #[test]
+    fn artifact_path_for_platformio_uses_environment() {
+        let profile = FirmwareProfile::parse(
+            r#"
+builder = "platformio"
+project_dir = "fw"
+chip = "ESP32"
+environment = "nucleo_f401re"
+"#,
+        )
+        .unwrap();
+        let path = profile.artifact_path(Path::new("/fw"));
+        assert_eq!(path, Path::new("/fw/.pio/build/nucleo_f401re/firmware.elf"));
+    }
+
+    #[test]
+    fn profile_path_rejects_traversal() {
+        let workspace = Path::new("/workspace");
+        assert!(profile_path(workspace, "../evil").is_err());
+        assert!(profile_path(workspace, "").is_err());
+        let ok = profile_path(workspace, "nucleo-f401re").unwrap();
+        assert_eq!(
+            ok,
+            Path::new("/workspace/firmware-profiles/nucleo-f401re.toml")
+        );
+    }
+}
@@ -3,6 +3,7 @@
 //! Peripherals extend the agent with physical capabilities. See
 //! `docs/hardware-peripherals-design.md` for the full design.
 
+pub mod telemetry;
 pub mod traits;
 
 #[cfg(feature = "hardware")]
@@ -15,8 +16,14 @@ pub mod arduino_upload;
 #[cfg(feature = "hardware")]
 pub mod capabilities_tool;
 #[cfg(feature = "hardware")]
+pub mod firmware_pipeline;
+#[cfg(feature = "hardware")]
+pub mod network;
+#[cfg(feature = "hardware")]
 pub mod nucleo_flash;
 #[cfg(feature = "hardware")]
+pub mod rpi_camera;
+#[cfg(feature = "hardware")]
 pub mod uno_q_bridge;
 #[cfg(feature = "hardware")]
 pub mod uno_q_setup;
@@ -94,12 +101,17 @@ pub async fn handle_command(cmd: crate::PeripheralCommands, config: &Config) ->
                 transport: transport.to_string(),
                 path: path_opt,
                 baud: 115_200,
+                token: None,
             });
             cfg.save().await?;
             println!("Added {} at {}. Restart daemon to apply.", board, path);
         }
         #[cfg(feature = "hardware")]
-        crate::PeripheralCommands::Flash { port } => {
+        crate::PeripheralCommands::Flash { port, board } => {
+            if let Some(board) = board {
+                firmware_pipeline::run_pipeline(config, &board).await?;
+                return Ok(());
+            }
             let port_str = arduino_flash::resolve_port(config, port.as_deref())
                 .or_else(|| port.clone())
                 .ok_or_else(|| anyhow::anyhow!(
@@ -125,6 +137,45 @@ pub async fn handle_command(cmd: crate::PeripheralCommands, config: &Config) ->
         crate::PeripheralCommands::FlashNucleo => {
             nucleo_flash::flash_nucleo_firmware()?;
         }
+        #[cfg(feature = "hardware")]
+        crate::PeripheralCommands::Monitor { interval, board } => {
+            monitor_telemetry(config, interval, board.as_deref()).await?;
+        }
+        #[cfg(not(feature = "hardware"))]
+        crate::PeripheralCommands::Monitor { .. } => {
+            println!("Telemetry monitoring requires the 'hardware' feature.");
+            println!("Build with: cargo build --features hardware");
+        }
+        #[cfg(feature = "hardware")]
+        crate::PeripheralCommands::Discover { timeout } => {
+            println!(
+                "Discovering {} boards ({timeout}s)...",
+                network::MDNS_SERVICE
+            );
+            let boards = network::discover(timeout).await?;
+            if boards.is_empty() {
+                println!(
+                    "No boards found. Check the board firmware is running and on the same network."
+                );
+            } else {
+                println!("Found {} board(s):", boards.len());
+                for b in &boards {
+                    println!("  {}  {}", b.instance, network::board_path(b));
+                }
+                println!();
+                println!("Register one with config.toml (token required):");
+                println!("  [[peripherals.boards]]");
+                println!("  board = \"esp32\"");
+                println!("  transport = \"network\"");
+                println!("  path = \"{}\"", network::board_path(&boards[0]));
+                println!("  token = \"<shared token flashed into the firmware>\"");
+            }
+        }
+        #[cfg(not(feature = "hardware"))]
+        crate::PeripheralCommands::Discover { .. } => {
+            println!("Board discovery requires the 'hardware' feature.");
+            println!("Build with: cargo build --features hardware");
+        }
         #[cfg(not(feature = "hardware"))]
         crate::PeripheralCommands::FlashNucleo => {
             println!("Nucleo flash requires the 'hardware' feature.");
@@ -155,6 +206,20 @@ pub async fn create_peripheral_tools(config: &PeripheralsConfig) -> Result<Vec<B
             continue;
         }
 
+        // Native transport: RPi camera (libcamera apps)
+        if board.transport == "native" && board.board == "rpi-camera" {
+            match rpi_camera::RpiCameraPeripheral::connect_from_config(board).await {
+                Ok(peripheral) => {
+                    tools.extend(peripheral.tools());
+                    tracing::info!(board = %board.board, "RPi camera peripheral connected");
+                }
+                Err(e) => {
+                    tracing::warn!("Failed to connect RPi camera: {}", e);
+                }
+            }
+            continue;
+        }
+
         // Native transport: RPi GPIO (Linux only)
         #[cfg(all(feature = "peripheral-rpi", target_os = "linux"))]
         if board.transport == "native"
@@ -172,6 +237,20 @@ pub async fn create_peripheral_tools(config: &PeripheralsConfig) -> Result<Vec<B
             continue;
         }
 
+        // Network transport: ESP32 and similar boards over Wi-Fi
+        if board.transport == "network" {
+            match network::NetworkPeripheral::connect(board).await {
+                Ok(peripheral) => {
+                    tools.extend(peripheral.tools());
+                    tracing::info!(board = %board.board, "Network peripheral connected");
+                }
+                Err(e) => {
+                    tracing::warn!("Failed to connect network board {}: {}", board.board, e);
+                }
+            }
+            continue;
+        }
+
         // Serial transport (STM32, ESP32, Arduino, etc.)
         if board.transport != "serial" {
             continue;
@@ -217,6 +296,15 @@ pub async fn create_peripheral_tools(config: &PeripheralsConfig) -> Result<Vec<B
         )));
     }
 
+    // Telemetry: expose the shared buffer to the agent and start background
+    // sampling when configured.
+    if !tools.is_empty() {
+        tools.push(Box::new(crate::tools::ReadTelemetryTool));
+    }
+    if config.telemetry_interval_secs > 0 && !serial_transports.is_empty() {
+        spawn_telemetry_sampler(serial_transports.clone(), config.telemetry_interval_secs);
+    }
+
     // Phase C: Add hardware_capabilities tool when any serial boards
     if !serial_transports.is_empty() {
         tools.push(Box::new(capabilities_tool::HardwareCapabilitiesTool::new(
@@ -227,6 +315,81 @@ pub async fn create_peripheral_tools(config: &PeripheralsConfig) -> Result<Vec<B
     Ok(tools)
 }
 
+/// Background task: periodically sample each serial board's telemetry and
+/// push the readings into the process-wide buffer.
+#[cfg(feature = "hardware")]
+fn spawn_telemetry_sampler(
+    transports: Vec<(String, std::sync::Arc<serial::SerialTransport>)>,
+    interval_secs: u64,
+) {
+    tokio::spawn(async move {
+        let mut ticker =
+            tokio::time::interval(std::time::Duration::from_secs(interval_secs.max(1)));
+        loop {
+            ticker.tick().await;
+            for (board, transport) in &transports {
+                match transport.sample_telemetry().await {
+                    Ok(payload) => {
+                        for reading in telemetry::readings_from_payload(board, &payload) {
+                            telemetry::global().push(reading);
+                        }
+                    }
+                    Err(e) => tracing::debug!("Telemetry sample failed for {board}: {e}"),
+                }
+            }
+        }
+    });
+}
+
+/// `zeroclaw peripheral monitor` — connect to serial boards and print
+/// readings as they are sampled (also feeds the telemetry buffer).
+#[cfg(feature = "hardware")]
+async fn monitor_telemetry(
+    config: &Config,
+    interval: u64,
+    board_filter: Option<&str>,
+) -> Result<()> {
+    let mut transports: Vec<(String, std::sync::Arc<serial::SerialTransport>)> = Vec::new();
+    for board in &config.peripherals.boards {
+        if board.transport != "serial" || board.path.is_none() {
+            continue;
+        }
+        if board_filter.is_some_and(|f| !board.board.eq_ignore_ascii_case(f)) {
+            continue;
+        }
+        match serial::SerialPeripheral::connect(board).await {
+            Ok(p) => transports.push((p.name().to_string(), p.transport())),
+            Err(e) => tracing::warn!("Failed to connect {}: {}", board.board, e),
+        }
+    }
+    if transports.is_empty() {
+        anyhow::bail!(
+            "No serial boards to monitor. Add one with: zeroclaw peripheral add <board> <path>"
+        );
+    }
+
+    println!(
+        "Monitoring telemetry from {} board(s) every {}s. Ctrl-C to stop.",
+        transports.len(),
+        interval.max(1)
+    );
+    let mut ticker = tokio::time::interval(std::time::Duration::from_secs(interval.max(1)));
+    loop {
+        ticker.tick().await;
+        for (board, transport) in &transports {
+            match transport.sample_telemetry().await {
+                Ok(payload) => {
+                    for reading in telemetry::readings_from_payload(board, &payload) {
+                        println!("{}", reading.describe());
+                        telemetry::global().push(reading);
+                    }
+                }
+                Err(e) => println!("{board}: telemetry unavailable ({e})"),
+            }
+        }
+    }
+}
+
 #[cfg(not(feature = "hardware"))]
 pub async fn create_peripheral_tools(_config: &PeripheralsConfig) -> Result<Vec<Box<dyn Tool>>> {
     Ok(Vec::new())
@@ -246,8 +409,9 @@ mod tests {
                 transport: "serial".into(),
                 path: Some("/dev/ttyACM0".into()),
                 baud: 115_200,
+                token: None,
             }],
-            datasheet_dir: None,
+            ..Default::default()
         };
         let result = list_configured_boards(&config);
         assert!(
@@ -266,15 +430,17 @@ mod tests {
                     transport: "serial".into(),
                     path: Some("/dev/ttyACM0".into()),
                     baud: 115_200,
+                    token: None,
                 },
                 PeripheralBoardConfig {
                     board: "rpi-gpio".into(),
                     transport: "native".into(),
                     path: None,
                     baud: 115_200,
+                    token: None,
                 },
             ],
-            datasheet_dir: None,
+            ..Default::default()
         };
         let result = list_configured_boards(&config);
         assert_eq!(result.len(), 2);
@@ -287,7 +453,7 @@ mod tests {
         let config = PeripheralsConfig {
             enabled: true,
             boards: vec![],
-            datasheet_dir: None,
+            ..Default::default()
         };
         let result = list_configured_boards(&config);
         assert!(
@@ -301,7 +467,7 @@ mod tests {
         let config = PeripheralsConfig {
             enabled: false,
             boards: vec![],
-            datasheet_dir: None,
+            ..Default::default()
         };
         let tools = create_peripheral_tools(&config).await.unwrap();
         assert!(
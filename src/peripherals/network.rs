@@ -0,0 +1,513 @@
+//! Network peripheral — ESP32 and similar boards over Wi-Fi (TCP).
+//!
+//! Same newline-delimited JSON protocol as serial boards, carried over a TCP
+//! connection so boards don't need a USB cable to the host. The firmware
+//! advertises `_zeroclaw._tcp.local` via mDNS for discovery and requires a
+//! shared token before accepting commands:
+//!
+//! Auth:     {"id":"0","cmd":"auth","args":{"token":"..."}}
+//! Request:  {"id":"1","cmd":"gpio_write","args":{"pin":13,"value":1}}
+//! Response: {"id":"1","ok":true,"result":"done"}
+
+use super::traits::Peripheral;
+use crate::config::PeripheralBoardConfig;
+use crate::tools::traits::{Tool, ToolResult};
+use async_trait::async_trait;
+use serde_json::{json, Value};
+use std::net::{IpAddr, SocketAddr};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpStream, UdpSocket};
+use tokio::sync::Mutex;
+
+/// mDNS service type advertised by ZeroClaw board firmware.
+pub const MDNS_SERVICE: &str = "_zeroclaw._tcp.local";
+
+/// Timeout for network request/response (seconds).
+const NETWORK_TIMEOUT_SECS: u64 = 5;
+
+/// JSON request/response over an established TCP stream.
+async fn send_request(
+    stream: &mut BufReader<TcpStream>,
+    cmd: &str,
+    args: Value,
+) -> anyhow::Result<Value> {
+    static ID: AtomicU64 = AtomicU64::new(0);
+    let id = ID.fetch_add(1, Ordering::Relaxed);
+    let id_str = id.to_string();
+
+    let req = json!({
+        "id": id_str,
+        "cmd": cmd,
+        "args": args
+    });
+    let line = format!("{req}\n");
+
+    stream.get_mut().write_all(line.as_bytes()).await?;
+    stream.get_mut().flush().await?;
+
+    let mut resp_line = String::new();
+    if stream.read_line(&mut resp_line).await? == 0 {
+        anyhow::bail!("Board closed the connection");
+    }
+    let resp: Value = serde_json::from_str(resp_line.trim())?;
+    let resp_id = resp["id"].as_str().unwrap_or("");
+    if resp_id != id_str {
+        anyhow::bail!("Response id mismatch: expected {}, got {}", id_str, resp_id);
+    }
+    Ok(resp)
+}
+
+/// Shared TCP transport for tools.
+pub(crate) struct NetworkTransport {
+    stream: Mutex<BufReader<TcpStream>>,
+}
+
+impl NetworkTransport {
+    async fn request(&self, cmd: &str, args: Value) -> anyhow::Result<ToolResult> {
+        let mut stream = self.stream.lock().await;
+        let resp = tokio::time::timeout(
+            std::time::Duration::from_secs(NETWORK_TIMEOUT_SECS),
+            send_request(&mut stream, cmd, args),
+        )
+        .await
+        .map_err(|_| {
+            anyhow::anyhow!("Network request timed out after {}s", NETWORK_TIMEOUT_SECS)
+        })??;
+
+        let ok = resp["ok"].as_bool().unwrap_or(false);
+        let result = resp["result"]
+            .as_str()
+            .map(String::from)
+            .unwrap_or_else(|| resp["result"].to_string());
+        let error = resp["error"].as_str().map(String::from);
+
+        Ok(ToolResult {
+            success: ok,
+            output: result,
+            error,
+        })
+    }
+}
+
+/// Network peripheral for ESP32 and similar boards over Wi-Fi.
+pub struct NetworkPeripheral {
+    name: String,
+    board_type: String,
+    transport: Arc<NetworkTransport>,
+}
+
+impl NetworkPeripheral {
+    /// Connect to a network board and authenticate with its token.
+    pub async fn connect(config: &PeripheralBoardConfig) -> anyhow::Result<Self> {
+        let addr = config
+            .path
+            .as_deref()
+            .ok_or_else(|| anyhow::anyhow!("Network peripheral requires path (host:port)"))?;
+        if !addr.contains(':') {
+            anyhow::bail!("Network peripheral path must be host:port, got: {}", addr);
+        }
+        let token = config.token.as_deref().ok_or_else(|| {
+            anyhow::anyhow!(
+                "Network board {} has no token; refusing unauthenticated connection",
+                config.board
+            )
+        })?;
+
+        let stream = tokio::time::timeout(
+            std::time::Duration::from_secs(NETWORK_TIMEOUT_SECS),
+            TcpStream::connect(addr),
+        )
+        .await
+        .map_err(|_| anyhow::anyhow!("Connection to {} timed out", addr))?
+        .map_err(|e| anyhow::anyhow!("Failed to connect to {}: {}", addr, e))?;
+
+        let transport = Arc::new(NetworkTransport {
+            stream: Mutex::new(BufReader::new(stream)),
+        });
+
+        let auth = transport.request("auth", json!({ "token": token })).await?;
+        if !auth.success {
+            anyhow::bail!(
+                "Board {} rejected auth token: {}",
+                config.board,
+                auth.error.unwrap_or_else(|| "no reason given".into())
+            );
+        }
+
+        Ok(Self {
+            name: format!("{}-{}", config.board, addr.replace(':', "_")),
+            board_type: config.board.clone(),
+            transport,
+        })
+    }
+}
+
+#[async_trait]
+impl Peripheral for NetworkPeripheral {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn board_type(&self) -> &str {
+        &self.board_type
+    }
+
+    async fn connect(&mut self) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    async fn disconnect(&mut self) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    async fn health_check(&self) -> bool {
+        self.transport
+            .request("ping", json!({}))
+            .await
+            .map(|r| r.success)
+            .unwrap_or(false)
+    }
+
+    fn tools(&self) -> Vec<Box<dyn Tool>> {
+        vec![
+            Box::new(NetworkGpioReadTool {
+                transport: self.transport.clone(),
+            }),
+            Box::new(NetworkGpioWriteTool {
+                transport: self.transport.clone(),
+            }),
+        ]
+    }
+}
+
+/// Tool: read GPIO pin value on a network board.
+struct NetworkGpioReadTool {
+    transport: Arc<NetworkTransport>,
+}
+
+#[async_trait]
+impl Tool for NetworkGpioReadTool {
+    fn name(&self) -> &str {
+        "gpio_read"
+    }
+
+    fn description(&self) -> &str {
+        "Read the value (0 or 1) of a GPIO pin on a Wi-Fi connected peripheral (e.g. ESP32)"
+    }
+
+    fn parameters_schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "pin": {
+                    "type": "integer",
+                    "description": "GPIO pin number"
+                }
+            },
+            "required": ["pin"]
+        })
+    }
+
+    async fn execute(&self, args: Value) -> anyhow::Result<ToolResult> {
+        let pin = args
+            .get("pin")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| anyhow::anyhow!("Missing 'pin' parameter"))?;
+        self.transport
+            .request("gpio_read", json!({ "pin": pin }))
+            .await
+    }
+}
+
+/// Tool: write GPIO pin value on a network board.
+struct NetworkGpioWriteTool {
+    transport: Arc<NetworkTransport>,
+}
+
+#[async_trait]
+impl Tool for NetworkGpioWriteTool {
+    fn name(&self) -> &str {
+        "gpio_write"
+    }
+
+    fn description(&self) -> &str {
+        "Set a GPIO pin high (1) or low (0) on a Wi-Fi connected peripheral (e.g. ESP32)"
+    }
+
+    fn parameters_schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "pin": {
+                    "type": "integer",
+                    "description": "GPIO pin number"
+                },
+                "value": {
+                    "type": "integer",
+                    "description": "0 for low, 1 for high"
+                }
+            },
+            "required": ["pin", "value"]
+        })
+    }
+
+    async fn execute(&self, args: Value) -> anyhow::Result<ToolResult> {
+        let pin = args
+            .get("pin")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| anyhow::anyhow!("Missing 'pin' parameter"))?;
+        let value = args
+            .get("value")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| anyhow::anyhow!("Missing 'value' parameter"))?;
+        self.transport
+            .request("gpio_write", json!({ "pin": pin, "value": value }))
+            .await
+    }
+}
+
+// ── mDNS discovery ───────────────────────────────────────────────
+
+/// A board found via mDNS discovery.
+#[derive(Debug, Clone)]
+pub struct DiscoveredBoard {
+    /// Instance name from the PTR record (e.g. `zeroclaw-esp32-kitchen`).
+    pub instance: String,
+    /// IP address the response came from.
+    pub host: IpAddr,
+    /// TCP port from the SRV record.
+    pub port: u16,
+}
+
+/// Query `_zeroclaw._tcp.local` over mDNS and collect responding boards.
+///
+/// Sends a unicast-response PTR question to the mDNS multicast group and
+/// listens for replies until the timeout elapses. Responders are deduplicated
+/// by address.
+pub async fn discover(timeout_secs: u64) -> anyhow::Result<Vec<DiscoveredBoard>> {
+    let socket = UdpSocket::bind("0.0.0.0:0").await?;
+    let query = build_mdns_query();
+    socket.send_to(&query, "224.0.0.251:5353").await?;
+
+    let deadline =
+        tokio::time::Instant::now() + std::time::Duration::from_secs(timeout_secs.max(1));
+    let mut boards: Vec<DiscoveredBoard> = Vec::new();
+    let mut buf = [0u8; 1500];
+    loop {
+        let remaining = deadline.saturating_duration_since(tokio::time::Instant::now());
+        if remaining.is_zero() {
+            break;
+        }
+        let Ok(received) = tokio::time::timeout(remaining, socket.recv_from(&mut buf)).await else {
+            break;
+        };
+        let (len, peer) = received?;
+        if let Some((instance, port)) = parse_mdns_response(&buf[..len]) {
+            if !boards.iter().any(|b| b.host == peer.ip()) {
+                boards.push(DiscoveredBoard {
+                    instance,
+                    host: peer.ip(),
+                    port,
+                });
+            }
+        }
+    }
+    Ok(boards)
+}
+
+/// Suggested `[[peripherals.boards]]` path value for a discovered board.
+pub fn board_path(board: &DiscoveredBoard) -> String {
+    SocketAddr::new(board.host, board.port).to_string()
+}
+
+/// Build a PTR question for [`MDNS_SERVICE`] with the unicast-response bit set.
+fn build_mdns_query() -> Vec<u8> {
+    let mut packet = Vec::with_capacity(12 + MDNS_SERVICE.len() + 6);
+    // Header: id 0, flags 0, one question, no records.
+    packet.extend_from_slice(&[0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0]);
+    for label in MDNS_SERVICE.split('.') {
+        packet.push(u8::try_from(label.len()).unwrap_or(0));
+        packet.extend_from_slice(label.as_bytes());
+    }
+    packet.push(0);
+    packet.extend_from_slice(&[0, 12]); // QTYPE PTR
+    packet.extend_from_slice(&[0x80, 0x01]); // QCLASS IN, unicast-response bit
+    packet
+}
+
+/// Extract (instance name, SRV port) from an mDNS response packet.
+///
+/// Returns `None` unless the packet is a well-formed response containing both
+/// a PTR record for our service and an SRV record with the TCP port.
+fn parse_mdns_response(packet: &[u8]) -> Option<(String, u16)> {
+    if packet.len() < 12 {
+        return None;
+    }
+    let questions = u16::from_be_bytes([packet[4], packet[5]]) as usize;
+    let records = (u16::from_be_bytes([packet[6], packet[7]]) as usize)
+        + (u16::from_be_bytes([packet[8], packet[9]]) as usize)
+        + (u16::from_be_bytes([packet[10], packet[11]]) as usize);
+
+    let mut pos = 12;
+    for _ in 0..questions {
+        pos = skip_name(packet, pos)?;
+        pos = pos.checked_add(4)?; // QTYPE + QCLASS
+    }
+
+    let mut instance: Option<String> = None;
+    let mut port: Option<u16> = None;
+    for _ in 0..records {
+        let name_end = skip_name(packet, pos)?;
+        let header = packet.get(name_end..name_end + 10)?;
+        let rtype = u16::from_be_bytes([header[0], header[1]]);
+        let rdlen = u16::from_be_bytes([header[8], header[9]]) as usize;
+        let rdata_start = name_end + 10;
+        let rdata = packet.get(rdata_start..rdata_start + rdlen)?;
+
+        match rtype {
+            // PTR: rdata is the instance name; keep its first label.
+            12 => {
+                let target = read_name(packet, rdata_start)?;
+                instance = Some(target.split('.').next().unwrap_or(&target).to_string());
+            }
+            // SRV: priority(2) weight(2) port(2) target.
+            33 if rdlen >= 6 => {
+                port = Some(u16::from_be_bytes([rdata[4], rdata[5]]));
+            }
+            _ => {}
+        }
+        pos = rdata_start + rdlen;
+    }
+    Some((instance?, port?))
+}
+
+/// Advance past a (possibly compressed) DNS name, returning the next offset.
+fn skip_name(packet: &[u8], mut pos: usize) -> Option<usize> {
+    loop {
+        let len = *packet.get(pos)?;
+        if len == 0 {
+            return Some(pos + 1);
+        }
+        if len & 0xC0 == 0xC0 {
+            return Some(pos + 2);
+        }
+        pos += 1 + len as usize;
+    }
+}
+
+/// Decode a DNS name (following compression pointers) into dotted labels.
+fn read_name(packet: &[u8], mut pos: usize) -> Option<String> {
+    let mut labels: Vec<String> = Vec::new();
+    let mut jumps = 0;
+    loop {
+        let len = *packet.get(pos)?;
+        if len == 0 {
+            break;
+        }
+        if len & 0xC0 == 0xC0 {
+            // Compression pointer; bound jumps to reject malicious loops.
+            jumps += 1;
+            if jumps > 8 {
+                return None;
+            }
+            let low = *packet.get(pos + 1)?;
+            pos = usize::from(u16::from_be_bytes([len & 0x3F, low]));
+            continue;
+        }
+        let label = packet.get(pos + 1..pos + 1 + len as usize)?;
+        labels.push(String::from_utf8_lossy(label).into_owned());
+        pos += 1 + len as usize;
+    }
+    Some(labels.join("."))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mdns_query_encodes_service_question() {
+        let query = build_mdns_query();
+        assert_eq!(&query[..12], &[0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(query[12], 9); // "_zeroclaw"
+        assert_eq!(&query[13..22], b"_zeroclaw");
+        let tail = &query[query.len() - 4..];
+        assert_eq!(tail, &[0, 12, 0x80, 0x01], "PTR question, unicast bit set");
+    }
+
+    /// Response with a PTR record pointing at `zeroclaw_node.<service>` and an
+    /// SRV record carrying port 9900. Names use a compression pointer back to
+    /// the service name at offset 12.
+    fn sample_response() -> Vec<u8> {
+        let mut p = Vec::new();
+        p.extend_from_slice(&[0, 0, 0x84, 0, 0, 0, 0, 2, 0, 0, 0, 0]);
+        // PTR record: name = service name inline at offset 12,
+        // rdata = "zeroclaw_node" + pointer back to the service name.
+        for label in MDNS_SERVICE.split('.') {
+            p.push(u8::try_from(label.len()).unwrap());
+            p.extend_from_slice(label.as_bytes());
+        }
+        p.push(0);
+        p.extend_from_slice(&[0, 12, 0, 1, 0, 0, 0, 120]);
+        p.extend_from_slice(&[0, 16]); // rdlen: 1 + 13 + 2
+        let instance_offset = u8::try_from(p.len()).unwrap();
+        p.push(13);
+        p.extend_from_slice(b"zeroclaw_node");
+        p.extend_from_slice(&[0xC0, 12]);
+        // SRV record: name = pointer to the instance name, port 9900.
+        p.extend_from_slice(&[0xC0, instance_offset]);
+        p.extend_from_slice(&[0, 33, 0, 1, 0, 0, 0, 120]);
+        p.extend_from_slice(&[0, 8]); // rdlen: prio + weight + port + root
+        p.extend_from_slice(&[0, 0, 0, 0]);
+        p.extend_from_slice(&9900u16.to_be_bytes());
+        p.extend_from_slice(&[0xC0, 12]);
+        p
+    }
+
+    #[test]
+    fn mdns_response_yields_instance_and_port() {
+        let (instance, port) = parse_mdns_response(&sample_response()).unwrap();
+        assert_eq!(instance, "zeroclaw_node");
+        assert_eq!(port, 9900);
+    }
+
+    #[test]
+    fn mdns_response_rejects_garbage() {
+        assert!(parse_mdns_response(&[]).is_none());
+        assert!(parse_mdns_response(&[0u8; 11]).is_none());
+        assert!(parse_mdns_response(&[0xFFu8; 40]).is_none());
+    }
+
+    #[tokio::test]
+    async fn network_peripheral_refuses_missing_token() {
+        let config = PeripheralBoardConfig {
+            board: "esp32".into(),
+            transport: "network".into(),
+            path: Some("192.0.2.1:9900".into()),
+            ..Default::default()
+        };
+        let err = NetworkPeripheral::connect(&config)
+            .await
+            .map(|_| ())
+            .unwrap_err();
+        assert!(err.to_string().contains("no token"));
+    }
+
+    #[tokio::test]
+    async fn network_peripheral_requires_host_port_path() {
+        let config = PeripheralBoardConfig {
+            board: "esp32".into(),
+            transport: "network".into(),
+            path: Some("not-an-address".into()),
+            token: Some("secret".into()),
+            ..Default::default()
+        };
+        let err = NetworkPeripheral::connect(&config)
+            .await
+            .map(|_| ())
+            .unwrap_err();
+        assert!(err.to_string().contains("host:port"));
+    }
+}
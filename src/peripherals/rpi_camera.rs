@@ -0,0 +1,298 @@
+//! Raspberry Pi camera peripheral — stills and short clips via libcamera.
+//!
+//! Shells out to the libcamera apps (`rpicam-still`/`rpicam-vid`, with the
+//! older `libcamera-still`/`libcamera-vid` names as fallback). Captured stills
+//! are returned with an `[IMAGE:<path>]` marker so the multimodal pipeline can
+//! feed them to a vision-capable provider — the agent can answer "what's in
+//! front of the camera?" and cron tasks can do periodic visual checks.
+//!
+//! Config: `board = "rpi-camera"`, `transport = "native"`. `path` optionally
+//! sets the capture output directory (default: system temp dir).
+
+use super::traits::Peripheral;
+use crate::config::PeripheralBoardConfig;
+use crate::tools::traits::{Tool, ToolResult};
+use async_trait::async_trait;
+use serde_json::{json, Value};
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Maximum time to wait for a still capture.
+const STILL_TIMEOUT_SECS: u64 = 20;
+/// Longest clip the record tool will capture (seconds).
+const MAX_CLIP_SECS: u64 = 30;
+/// Default clip length when `duration_secs` is omitted.
+const DEFAULT_CLIP_SECS: u64 = 5;
+
+/// libcamera app names, newest first.
+const STILL_BINARIES: &[&str] = &["rpicam-still", "libcamera-still"];
+const VIDEO_BINARIES: &[&str] = &["rpicam-vid", "libcamera-vid"];
+
+/// Find the first available binary from a candidate list.
+async fn find_binary(candidates: &[&str]) -> Option<String> {
+    for name in candidates {
+        let available = tokio::process::Command::new(name)
+            .arg("--version")
+            .output()
+            .await
+            .map(|o| o.status.success())
+            .unwrap_or(false);
+        if available {
+            return Some((*name).to_string());
+        }
+    }
+    None
+}
+
+/// Clamp a requested clip duration into the allowed range.
+fn clamp_clip_secs(requested: Option<u64>) -> u64 {
+    requested
+        .unwrap_or(DEFAULT_CLIP_SECS)
+        .clamp(1, MAX_CLIP_SECS)
+}
+
+/// Raspberry Pi camera peripheral (libcamera apps).
+pub struct RpiCameraPeripheral {
+    name: String,
+    output_dir: PathBuf,
+}
+
+impl RpiCameraPeripheral {
+    /// Create the peripheral, verifying a libcamera still app is present.
+    pub async fn connect_from_config(board: &PeripheralBoardConfig) -> anyhow::Result<Self> {
+        if find_binary(STILL_BINARIES).await.is_none() {
+            anyhow::bail!(
+                "No libcamera app found (tried rpicam-still, libcamera-still). \
+                 Install rpicam-apps (Raspberry Pi OS: apt install rpicam-apps)."
+            );
+        }
+        let output_dir = board
+            .path
+            .as_deref()
+            .map_or_else(std::env::temp_dir, PathBuf::from);
+        Ok(Self {
+            name: board.board.clone(),
+            output_dir,
+        })
+    }
+}
+
+#[async_trait]
+impl Peripheral for RpiCameraPeripheral {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn board_type(&self) -> &str {
+        "rpi-camera"
+    }
+
+    async fn connect(&mut self) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    async fn disconnect(&mut self) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    async fn health_check(&self) -> bool {
+        find_binary(STILL_BINARIES).await.is_some()
+    }
+
+    fn tools(&self) -> Vec<Box<dyn Tool>> {
+        vec![
+            Box::new(CameraCaptureTool {
+                output_dir: self.output_dir.clone(),
+            }),
+            Box::new(CameraRecordTool {
+                output_dir: self.output_dir.clone(),
+            }),
+        ]
+    }
+}
+
+/// Tool: capture a still image from the Pi camera.
+struct CameraCaptureTool {
+    output_dir: PathBuf,
+}
+
+#[async_trait]
+impl Tool for CameraCaptureTool {
+    fn name(&self) -> &str {
+        "camera_capture"
+    }
+
+    fn description(&self) -> &str {
+        "Capture a still image from the Raspberry Pi camera. Returns the saved \
+         path with an [IMAGE:...] marker so the image can be inspected by a \
+         vision-capable model (e.g. to answer what is in front of the camera)."
+    }
+
+    fn parameters_schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {}
+        })
+    }
+
+    async fn execute(&self, _args: Value) -> anyhow::Result<ToolResult> {
+        let Some(binary) = find_binary(STILL_BINARIES).await else {
+            return Ok(ToolResult {
+                success: false,
+                output: String::new(),
+                error: Some("No libcamera still app found (rpicam-still/libcamera-still)".into()),
+            });
+        };
+
+        let timestamp = chrono::Utc::now().format("%Y%m%d_%H%M%S");
+        let output_path = self.output_dir.join(format!("camera_{timestamp}.jpg"));
+        let output_str = output_path.to_string_lossy().to_string();
+
+        let result = tokio::time::timeout(
+            Duration::from_secs(STILL_TIMEOUT_SECS),
+            tokio::process::Command::new(&binary)
+                .args(["-n", "-o", &output_str])
+                .output(),
+        )
+        .await;
+
+        match result {
+            Ok(Ok(output)) if output.status.success() => Ok(ToolResult {
+                success: true,
+                output: format!("Still captured to {output_str}\n[IMAGE:{output_str}]"),
+                error: None,
+            }),
+            Ok(Ok(output)) => Ok(ToolResult {
+                success: false,
+                output: String::new(),
+                error: Some(format!(
+                    "{binary} failed: {}",
+                    String::from_utf8_lossy(&output.stderr)
+                )),
+            }),
+            Ok(Err(e)) => Ok(ToolResult {
+                success: false,
+                output: String::new(),
+                error: Some(format!("Failed to run {binary}: {e}")),
+            }),
+            Err(_) => Ok(ToolResult {
+                success: false,
+                output: String::new(),
+                error: Some(format!("Capture timed out after {STILL_TIMEOUT_SECS}s")),
+            }),
+        }
+    }
+}
+
+/// Tool: record a short clip from the Pi camera.
+struct CameraRecordTool {
+    output_dir: PathBuf,
+}
+
+#[async_trait]
+impl Tool for CameraRecordTool {
+    fn name(&self) -> &str {
+        "camera_record"
+    }
+
+    fn description(&self) -> &str {
+        "Record a short video clip (H.264) from the Raspberry Pi camera. \
+         Duration is capped at 30 seconds. Returns the saved file path."
+    }
+
+    fn parameters_schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "duration_secs": {
+                    "type": "integer",
+                    "description": "Clip length in seconds (default 5, max 30)"
+                }
+            }
+        })
+    }
+
+    async fn execute(&self, args: Value) -> anyhow::Result<ToolResult> {
+        let Some(binary) = find_binary(VIDEO_BINARIES).await else {
+            return Ok(ToolResult {
+                success: false,
+                output: String::new(),
+                error: Some("No libcamera video app found (rpicam-vid/libcamera-vid)".into()),
+            });
+        };
+
+        let duration_secs = clamp_clip_secs(args.get("duration_secs").and_then(Value::as_u64));
+        let duration_ms = (duration_secs * 1000).to_string();
+
+        let timestamp = chrono::Utc::now().format("%Y%m%d_%H%M%S");
+        let output_path = self.output_dir.join(format!("clip_{timestamp}.h264"));
+        let output_str = output_path.to_string_lossy().to_string();
+
+        let result = tokio::time::timeout(
+            // Recording runs for the clip length plus camera start-up slack.
+            Duration::from_secs(duration_secs + STILL_TIMEOUT_SECS),
+            tokio::process::Command::new(&binary)
+                .args(["-n", "-t", &duration_ms, "-o", &output_str])
+                .output(),
+        )
+        .await;
+
+        match result {
+            Ok(Ok(output)) if output.status.success() => Ok(ToolResult {
+                success: true,
+                output: format!("Clip ({duration_secs}s) recorded to {output_str}"),
+                error: None,
+            }),
+            Ok(Ok(output)) => Ok(ToolResult {
+                success: false,
+                output: String::new(),
+                error: Some(format!(
+                    "{binary} failed: {}",
+                    String::from_utf8_lossy(&output.stderr)
+                )),
+            }),
+            Ok(Err(e)) => Ok(ToolResult {
+                success: false,
+                output: String::new(),
+                error: Some(format!("Failed to run {binary}: {e}")),
+            }),
+            Err(_) => Ok(ToolResult {
+                success: false,
+                output: String::new(),
+                error: Some("Recording timed out".into()),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clip_duration_defaults_and_clamps() {
+        assert_eq!(clamp_clip_secs(None), DEFAULT_CLIP_SECS);
+        assert_eq!(clamp_clip_secs(Some(0)), 1);
+        assert_eq!(clamp_clip_secs(Some(10)), 10);
+        assert_eq!(clamp_clip_secs(Some(600)), MAX_CLIP_SECS);
+    }
+
+    #[test]
+    fn camera_tools_expose_capture_and_record() {
+        let peripheral = RpiCameraPeripheral {
+            name: "rpi-camera".into(),
+            output_dir: std::env::temp_dir(),
+        };
+        let tools = peripheral.tools();
+        let names: Vec<&str> = tools.iter().map(|t| t.name()).collect();
+        assert_eq!(names, vec!["camera_capture", "camera_record"]);
+    }
+
+    #[test]
+    fn capture_description_mentions_image_marker() {
+        let tool = CameraCaptureTool {
+            output_dir: std::env::temp_dir(),
+        };
+        assert!(tool.description().contains("[IMAGE:"));
+    }
+}
@@ -101,6 +101,38 @@ impl SerialTransport {
     pub async fn capabilities(&self) -> anyhow::Result<ToolResult> {
         self.request("capabilities", json!({})).await
     }
+
+    /// Fetch one telemetry sample from the device firmware.
+    ///
+    /// Expects the firmware to answer the `telemetry` command with a JSON
+    /// object (e.g. `{"temperature": 23.5, "gpio": {"13": 1}}`); see
+    /// `telemetry::readings_from_payload` for the parsed shape.
+    pub(crate) async fn sample_telemetry(&self) -> anyhow::Result<Value> {
+        let result = self.request("telemetry", json!({})).await?;
+        if !result.success {
+            anyhow::bail!(result
+                .error
+                .unwrap_or_else(|| "telemetry command failed".into()));
+        }
+        serde_json::from_str(&result.output)
+            .map_err(|_| anyhow::anyhow!("Firmware returned non-JSON telemetry payload"))
+    }
+
+    /// Scan the bridge board's I2C bus for device addresses.
+    ///
+    /// Expects the firmware to answer the `i2c_scan` command with a JSON
+    /// array of addresses (`[118, 60]` or `["0x76", "0x3C"]`).
+    pub(crate) async fn i2c_scan(&self) -> anyhow::Result<Vec<u8>> {
+        let result = self.request("i2c_scan", json!({})).await?;
+        if !result.success {
+            anyhow::bail!(result
+                .error
+                .unwrap_or_else(|| "i2c_scan command failed".into()));
+        }
+        Ok(crate::hardware::sensors::parse_scan_addresses(
+            &result.output,
+        ))
+    }
 }
 
 /// Serial peripheral for STM32, Arduino, etc. over USB CDC.
@@ -0,0 +1,212 @@
+//! Telemetry buffer — recent sensor readings pushed by peripheral boards.
+//!
+//! Boards push periodic readings (temperature, GPIO states) into a bounded
+//! process-wide ring buffer. The agent reads it through the `read_telemetry`
+//! tool, `zeroclaw peripheral monitor` tails it live, and cron tasks can
+//! alert on thresholds by asking the agent to inspect recent readings.
+
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use std::collections::VecDeque;
+use std::sync::{LazyLock, Mutex};
+
+/// Readings kept in the process-wide buffer before the oldest are evicted.
+const DEFAULT_CAPACITY: usize = 1024;
+
+/// One sensor reading pushed by a board.
+#[derive(Debug, Clone)]
+pub struct TelemetryReading {
+    /// Board instance name (e.g. `nucleo-f401re-_dev_ttyACM0`).
+    pub board: String,
+    /// Metric name (e.g. `temperature`, `gpio.13`).
+    pub metric: String,
+    pub value: f64,
+    /// Unit label when the firmware reports one (e.g. `C`).
+    pub unit: Option<String>,
+    pub timestamp: DateTime<Utc>,
+}
+
+impl TelemetryReading {
+    /// One-line rendering used by the monitor CLI and the tool output.
+    pub fn describe(&self) -> String {
+        format!(
+            "{}  {}  {} = {}{}",
+            self.timestamp.format("%Y-%m-%d %H:%M:%S"),
+            self.board,
+            self.metric,
+            self.value,
+            self.unit.as_deref().unwrap_or("")
+        )
+    }
+}
+
+/// Bounded ring buffer of recent readings.
+pub struct TelemetryBuffer {
+    readings: Mutex<VecDeque<TelemetryReading>>,
+    capacity: usize,
+}
+
+impl TelemetryBuffer {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            readings: Mutex::new(VecDeque::with_capacity(capacity.min(DEFAULT_CAPACITY))),
+            capacity: capacity.max(1),
+        }
+    }
+
+    /// Append a reading, evicting the oldest when at capacity.
+    pub fn push(&self, reading: TelemetryReading) {
+        let mut readings = self.readings.lock().unwrap_or_else(|e| e.into_inner());
+        if readings.len() == self.capacity {
+            readings.pop_front();
+        }
+        readings.push_back(reading);
+    }
+
+    /// Most recent readings, newest first, optionally filtered by board
+    /// and/or metric.
+    pub fn recent(
+        &self,
+        board: Option<&str>,
+        metric: Option<&str>,
+        limit: usize,
+    ) -> Vec<TelemetryReading> {
+        let readings = self.readings.lock().unwrap_or_else(|e| e.into_inner());
+        readings
+            .iter()
+            .rev()
+            .filter(|r| board.is_none_or(|b| r.board.eq_ignore_ascii_case(b)))
+            .filter(|r| metric.is_none_or(|m| r.metric.eq_ignore_ascii_case(m)))
+            .take(limit)
+            .cloned()
+            .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.readings
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Process-wide buffer all boards push into.
+pub fn global() -> &'static TelemetryBuffer {
+    static GLOBAL: LazyLock<TelemetryBuffer> =
+        LazyLock::new(|| TelemetryBuffer::new(DEFAULT_CAPACITY));
+    &GLOBAL
+}
+
+/// Record one reading into the process-wide buffer.
+pub fn record(board: &str, metric: &str, value: f64, unit: Option<&str>) {
+    global().push(TelemetryReading {
+        board: board.to_string(),
+        metric: metric.to_string(),
+        value,
+        unit: unit.map(str::to_string),
+        timestamp: Utc::now(),
+    });
+}
+
+/// Parse a firmware telemetry payload into readings.
+///
+/// Expected shape (all keys optional):
+/// `{"temperature": 23.5, "gpio": {"13": 1, "5": 0}}` — scalar top-level
+/// keys become metrics, `gpio` pin states become `gpio.<pin>` metrics.
+pub fn readings_from_payload(board: &str, payload: &Value) -> Vec<TelemetryReading> {
+    let Some(map) = payload.as_object() else {
+        return Vec::new();
+    };
+    let now = Utc::now();
+    let mut readings = Vec::new();
+    for (key, value) in map {
+        if key == "gpio" {
+            if let Some(pins) = value.as_object() {
+                for (pin, state) in pins {
+                    if let Some(state) = state.as_f64() {
+                        readings.push(TelemetryReading {
+                            board: board.to_string(),
+                            metric: format!("gpio.{pin}"),
+                            value: state,
+                            unit: None,
+                            timestamp: now,
+                        });
+                    }
+                }
+            }
+        } else if let Some(value) = value.as_f64() {
+            readings.push(TelemetryReading {
+                board: board.to_string(),
+                metric: key.clone(),
+                value,
+                unit: (key == "temperature").then(|| "C".to_string()),
+                timestamp: now,
+            });
+        }
+    }
+    readings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn reading(board: &str, metric: &str, value: f64) -> TelemetryReading {
+        TelemetryReading {
+            board: board.into(),
+            metric: metric.into(),
+            value,
+            unit: None,
+            timestamp: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn push_evicts_oldest_at_capacity() {
+        let buffer = TelemetryBuffer::new(3);
+        for i in 0..5 {
+            buffer.push(reading("zeroclaw_node", "temperature", f64::from(i)));
+        }
+        assert_eq!(buffer.len(), 3);
+        let recent = buffer.recent(None, None, 10);
+        assert_eq!(recent[0].value, 4.0, "newest first");
+        assert_eq!(recent[2].value, 2.0, "oldest evicted");
+    }
+
+    #[test]
+    fn recent_filters_by_board_and_metric() {
+        let buffer = TelemetryBuffer::new(16);
+        buffer.push(reading("board_a", "temperature", 21.0));
+        buffer.push(reading("board_b", "temperature", 22.0));
+        buffer.push(reading("board_a", "gpio.13", 1.0));
+
+        assert_eq!(buffer.recent(Some("board_a"), None, 10).len(), 2);
+        assert_eq!(buffer.recent(None, Some("temperature"), 10).len(), 2);
+        assert_eq!(buffer.recent(Some("board_a"), Some("gpio.13"), 10).len(), 1);
+        assert!(buffer.recent(Some("board_c"), None, 10).is_empty());
+    }
+
+    #[test]
+    fn readings_from_payload_parses_scalars_and_gpio() {
+        let payload = json!({"temperature": 23.5, "gpio": {"13": 1, "5": 0}, "label": "ok"});
+        let mut readings = readings_from_payload("zeroclaw_node", &payload);
+        readings.sort_by(|a, b| a.metric.cmp(&b.metric));
+        assert_eq!(readings.len(), 3);
+        assert_eq!(readings[0].metric, "gpio.13");
+        assert_eq!(readings[0].value, 1.0);
+        assert_eq!(readings[1].metric, "gpio.5");
+        assert_eq!(readings[2].metric, "temperature");
+        assert_eq!(readings[2].unit.as_deref(), Some("C"));
+    }
+
+    #[test]
+    fn readings_from_payload_ignores_non_object() {
+        assert!(readings_from_payload("zeroclaw_node", &json!("nope")).is_empty());
+        assert!(readings_from_payload("zeroclaw_node", &json!(null)).is_empty());
+    }
+}
@@ -0,0 +1,352 @@
+//! Load-balancing provider wrapper — spreads stateless requests across a
+//! pool of providers serving the same model family.
+//!
+//! Three strategies (`[providers] strategy`):
+//! - `round_robin`: rotate through the pool in order.
+//! - `lowest_latency`: pick the member with the lowest mean latency over the
+//!   most recent requests; members without samples are probed first.
+//! - `least_cost`: pick the member with the cheapest expected request, using
+//!   the configured `cost_per_mtok` scaled by recently observed token usage.
+
+use super::traits::{ChatMessage, ChatRequest, ChatResponse};
+use super::Provider;
+use async_trait::async_trait;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::Instant;
+
+/// How many recent samples feed the latency/cost averages.
+const STATS_WINDOW: usize = 32;
+
+/// Nominal token count (in millions) assumed for members without samples, so
+/// `least_cost` can rank the pool by configured price before any traffic.
+const NOMINAL_MTOK_PER_REQUEST: f64 = 0.001;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BalanceStrategy {
+    RoundRobin,
+    LeastCost,
+    LowestLatency,
+}
+
+impl BalanceStrategy {
+    pub fn parse(value: &str) -> anyhow::Result<Self> {
+        match value {
+            "round_robin" => Ok(Self::RoundRobin),
+            "least_cost" => Ok(Self::LeastCost),
+            "lowest_latency" => Ok(Self::LowestLatency),
+            other => anyhow::bail!(
+                "unknown providers.strategy '{other}' (expected round_robin, least_cost, or lowest_latency)"
+            ),
+        }
+    }
+}
+
+#[derive(Default)]
+struct RollingStats {
+    latencies_ms: VecDeque<f64>,
+    mtok_per_request: VecDeque<f64>,
+}
+
+fn push_sample(window: &mut VecDeque<f64>, value: f64) {
+    if window.len() == STATS_WINDOW {
+        window.pop_front();
+    }
+    window.push_back(value);
+}
+
+fn mean(window: &VecDeque<f64>) -> Option<f64> {
+    if window.is_empty() {
+        None
+    } else {
+        Some(window.iter().sum::<f64>() / window.len() as f64)
+    }
+}
+
+struct PoolMember {
+    name: String,
+    cost_per_mtok: f64,
+    provider: Box<dyn Provider>,
+    stats: Mutex<RollingStats>,
+}
+
+/// Balances stateless chat requests across a pool of providers.
+pub struct BalancedProvider {
+    strategy: BalanceStrategy,
+    members: Vec<PoolMember>,
+    next: AtomicUsize,
+}
+
+impl BalancedProvider {
+    /// `members` is a list of (name, cost_per_mtok, provider) tuples.
+    pub fn new(strategy: BalanceStrategy, members: Vec<(String, f64, Box<dyn Provider>)>) -> Self {
+        Self {
+            strategy,
+            members: members
+                .into_iter()
+                .map(|(name, cost_per_mtok, provider)| PoolMember {
+                    name,
+                    cost_per_mtok,
+                    provider,
+                    stats: Mutex::new(RollingStats::default()),
+                })
+                .collect(),
+            next: AtomicUsize::new(0),
+        }
+    }
+
+    fn pick(&self) -> usize {
+        match self.strategy {
+            BalanceStrategy::RoundRobin => {
+                self.next.fetch_add(1, Ordering::Relaxed) % self.members.len()
+            }
+            BalanceStrategy::LowestLatency => self.pick_by_score(|member| {
+                let stats = member.stats.lock().unwrap_or_else(|e| e.into_inner());
+                // Unsampled members score 0 so they get probed first.
+                mean(&stats.latencies_ms).unwrap_or(0.0)
+            }),
+            BalanceStrategy::LeastCost => self.pick_by_score(|member| {
+                let stats = member.stats.lock().unwrap_or_else(|e| e.into_inner());
+                let mtok = mean(&stats.mtok_per_request).unwrap_or(NOMINAL_MTOK_PER_REQUEST);
+                member.cost_per_mtok * mtok
+            }),
+        }
+    }
+
+    fn pick_by_score(&self, score: impl Fn(&PoolMember) -> f64) -> usize {
+        let mut best = 0;
+        let mut best_score = f64::INFINITY;
+        for (index, member) in self.members.iter().enumerate() {
+            let member_score = score(member);
+            if member_score < best_score {
+                best = index;
+                best_score = member_score;
+            }
+        }
+        best
+    }
+
+    fn record(&self, index: usize, elapsed_ms: f64, tokens: Option<u64>) {
+        let member = &self.members[index];
+        let mut stats = member.stats.lock().unwrap_or_else(|e| e.into_inner());
+        push_sample(&mut stats.latencies_ms, elapsed_ms);
+        if let Some(tokens) = tokens {
+            push_sample(&mut stats.mtok_per_request, tokens as f64 / 1_000_000.0);
+        }
+    }
+}
+
+#[async_trait]
+impl Provider for BalancedProvider {
+    async fn chat_with_system(
+        &self,
+        system_prompt: Option<&str>,
+        message: &str,
+        model: &str,
+        temperature: f64,
+    ) -> anyhow::Result<String> {
+        let index = self.pick();
+        let member = &self.members[index];
+        tracing::debug!(
+            provider = member.name.as_str(),
+            "Balancer dispatching request"
+        );
+        let started = Instant::now();
+        let result = member
+            .provider
+            .chat_with_system(system_prompt, message, model, temperature)
+            .await;
+        if result.is_ok() {
+            self.record(index, started.elapsed().as_secs_f64() * 1000.0, None);
+        }
+        result
+    }
+
+    async fn chat_with_history(
+        &self,
+        messages: &[ChatMessage],
+        model: &str,
+        temperature: f64,
+    ) -> anyhow::Result<String> {
+        let index = self.pick();
+        let member = &self.members[index];
+        let started = Instant::now();
+        let result = member
+            .provider
+            .chat_with_history(messages, model, temperature)
+            .await;
+        if result.is_ok() {
+            self.record(index, started.elapsed().as_secs_f64() * 1000.0, None);
+        }
+        result
+    }
+
+    async fn chat(
+        &self,
+        request: ChatRequest<'_>,
+        model: &str,
+        temperature: f64,
+    ) -> anyhow::Result<ChatResponse> {
+        let index = self.pick();
+        let member = &self.members[index];
+        let started = Instant::now();
+        let result = member.provider.chat(request, model, temperature).await;
+        if let Ok(response) = &result {
+            let tokens = response
+                .usage
+                .as_ref()
+                .map(|u| u.prompt_tokens + u.completion_tokens);
+            self.record(index, started.elapsed().as_secs_f64() * 1000.0, tokens);
+        }
+        result
+    }
+
+    async fn chat_with_tools(
+        &self,
+        messages: &[ChatMessage],
+        tools: &[serde_json::Value],
+        model: &str,
+        temperature: f64,
+    ) -> anyhow::Result<ChatResponse> {
+        let index = self.pick();
+        let member = &self.members[index];
+        let started = Instant::now();
+        let result = member
+            .provider
+            .chat_with_tools(messages, tools, model, temperature)
+            .await;
+        if let Ok(response) = &result {
+            let tokens = response
+                .usage
+                .as_ref()
+                .map(|u| u.prompt_tokens + u.completion_tokens);
+            self.record(index, started.elapsed().as_secs_f64() * 1000.0, tokens);
+        }
+        result
+    }
+
+    fn supports_native_tools(&self) -> bool {
+        // Any member may serve a request, so only advertise native tools when
+        // the whole pool supports them.
+        self.members
+            .iter()
+            .all(|member| member.provider.supports_native_tools())
+    }
+
+    fn supports_vision(&self) -> bool {
+        self.members
+            .iter()
+            .all(|member| member.provider.supports_vision())
+    }
+
+    async fn warmup(&self) -> anyhow::Result<()> {
+        for member in &self.members {
+            tracing::info!(provider = member.name.as_str(), "Warming up pool provider");
+            if let Err(e) = member.provider.warmup().await {
+                tracing::warn!(
+                    provider = member.name.as_str(),
+                    "Warmup failed (non-fatal): {e}"
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    struct CountingProvider {
+        calls: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl Provider for CountingProvider {
+        async fn chat_with_system(
+            &self,
+            _system_prompt: Option<&str>,
+            _message: &str,
+            _model: &str,
+            _temperature: f64,
+        ) -> anyhow::Result<String> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            Ok("ok".to_string())
+        }
+    }
+
+    fn make_pool(
+        strategy: BalanceStrategy,
+        costs: &[f64],
+    ) -> (BalancedProvider, Vec<Arc<AtomicUsize>>) {
+        let mut members: Vec<(String, f64, Box<dyn Provider>)> = Vec::new();
+        let mut counters = Vec::new();
+        for (i, cost) in costs.iter().enumerate() {
+            let calls = Arc::new(AtomicUsize::new(0));
+            counters.push(calls.clone());
+            members.push((
+                format!("provider-{i}"),
+                *cost,
+                Box::new(CountingProvider { calls }),
+            ));
+        }
+        (BalancedProvider::new(strategy, members), counters)
+    }
+
+    #[tokio::test]
+    async fn round_robin_rotates_through_pool() {
+        let (pool, counters) = make_pool(BalanceStrategy::RoundRobin, &[1.0, 1.0, 1.0]);
+        for _ in 0..6 {
+            pool.chat_with_system(None, "hi", "m", 0.7).await.unwrap();
+        }
+        for counter in &counters {
+            assert_eq!(counter.load(Ordering::SeqCst), 2);
+        }
+    }
+
+    #[tokio::test]
+    async fn least_cost_prefers_cheapest_member() {
+        let (pool, counters) = make_pool(BalanceStrategy::LeastCost, &[3.0, 0.5, 1.0]);
+        for _ in 0..4 {
+            pool.chat_with_system(None, "hi", "m", 0.7).await.unwrap();
+        }
+        assert_eq!(counters[1].load(Ordering::SeqCst), 4);
+        assert_eq!(counters[0].load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn lowest_latency_probes_unsampled_then_picks_fastest() {
+        let (pool, _) = make_pool(BalanceStrategy::LowestLatency, &[1.0, 1.0]);
+        // Both unsampled: first member is probed first.
+        assert_eq!(pool.pick(), 0);
+        pool.record(0, 200.0, None);
+        // Member 1 still unsampled, so it is probed next.
+        assert_eq!(pool.pick(), 1);
+        pool.record(1, 50.0, None);
+        // With samples on both, the faster member wins.
+        assert_eq!(pool.pick(), 1);
+        pool.record(1, 900.0, None);
+        pool.record(1, 900.0, None);
+        assert_eq!(pool.pick(), 0);
+    }
+
+    #[test]
+    fn strategy_parse_rejects_unknown_values() {
+        assert!(BalanceStrategy::parse("round_robin").is_ok());
+        assert!(BalanceStrategy::parse("least_cost").is_ok());
+        assert!(BalanceStrategy::parse("lowest_latency").is_ok());
+        assert!(BalanceStrategy::parse("random").is_err());
+    }
+
+    #[test]
+    fn rolling_stats_window_is_bounded() {
+        let mut window = VecDeque::new();
+        for i in 0..(STATS_WINDOW + 10) {
+            push_sample(&mut window, i as f64);
+        }
+        assert_eq!(window.len(), STATS_WINDOW);
+        assert_eq!(window.front().copied(), Some(10.0));
+    }
+}
@@ -641,6 +641,7 @@ impl Provider for BedrockProvider {
         ProviderCapabilities {
             native_tool_calling: true,
             vision: false,
+            structured_output: false,
         }
     }
 
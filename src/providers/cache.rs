@@ -0,0 +1,614 @@
+//! Optional disk-backed provider response cache.
+//!
+//! [`CachedProvider`] wraps the outermost provider chain and serves repeated
+//! identical `chat` requests from `~/.zeroclaw/cache/` instead of re-billing
+//! tokens — the common case being cron tasks and retried runs that resend
+//! the exact same prompt. Entries are keyed on (provider, model,
+//! temperature, normalized messages, tool state hash) and expire after the
+//! configured TTL. Only constructed when `[cache] enabled = true`; see
+//! [`maybe_wrap`].
+
+use super::traits::{
+    ChatMessage, ChatRequest, ChatResponse, ProviderCapabilities, StreamChunk, StreamOptions,
+    StreamResult, ToolsPayload,
+};
+use super::Provider;
+use crate::observability::{Observer, ObserverEvent};
+use crate::tools::ToolSpec;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use futures_util::stream;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// One cached provider response plus its creation time for TTL checks.
+#[derive(Serialize, Deserialize)]
+struct CacheEntry {
+    created_at: u64,
+    response: ChatResponse,
+}
+
+/// Best-effort hit/miss counters persisted next to the entries so
+/// `zeroclaw cache stats` reflects history across processes.
+#[derive(Serialize, Deserialize, Default)]
+struct CacheCounters {
+    hits: u64,
+    misses: u64,
+}
+
+/// Disk-backed store of provider responses with TTL-based expiry.
+///
+/// Entries are individual JSON files named by the request key hash, so
+/// lookups are O(1) path probes and `clear` is a directory sweep —
+/// inspectable with standard tools and safe to delete wholesale.
+pub struct ResponseCache {
+    dir: PathBuf,
+    ttl: Duration,
+}
+
+impl ResponseCache {
+    pub fn new(dir: PathBuf, ttl_secs: u64) -> Self {
+        Self {
+            dir,
+            ttl: Duration::from_secs(ttl_secs),
+        }
+    }
+
+    /// Stable request key: provider, model, temperature bits, normalized
+    /// messages (roles + trimmed content), and a hash of the tool
+    /// definitions the model can see. Any change to tool availability or
+    /// schemas changes the key, so stale tool behavior is never replayed.
+    fn key(
+        &self,
+        provider: &str,
+        model: &str,
+        temperature: f64,
+        messages: &[ChatMessage],
+        tools: Option<&[ToolSpec]>,
+    ) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(provider.as_bytes());
+        hasher.update([0]);
+        hasher.update(model.as_bytes());
+        hasher.update([0]);
+        hasher.update(temperature.to_bits().to_le_bytes());
+        for message in messages {
+            hasher.update([0]);
+            hasher.update(message.role.as_bytes());
+            hasher.update([0]);
+            hasher.update(message.content.trim().as_bytes());
+        }
+        hasher.update([0]);
+        hasher.update(tool_state_hash(tools));
+        hex_digest(hasher)
+    }
+
+    fn entry_path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{key}.json"))
+    }
+
+    /// Fetch a cached response, lazily removing it when expired.
+    fn lookup(&self, key: &str) -> Option<ChatResponse> {
+        let path = self.entry_path(key);
+        let data = std::fs::read_to_string(&path).ok()?;
+        let entry: CacheEntry = serde_json::from_str(&data).ok()?;
+        if unix_now().saturating_sub(entry.created_at) > self.ttl.as_secs() {
+            let _ = std::fs::remove_file(&path);
+            return None;
+        }
+        Some(entry.response)
+    }
+
+    /// Store a response under `key` (best-effort — a write failure only
+    /// costs a future cache miss, never the current request).
+    fn store(&self, key: &str, response: &ChatResponse) {
+        if std::fs::create_dir_all(&self.dir).is_err() {
+            return;
+        }
+        let entry = CacheEntry {
+            created_at: unix_now(),
+            response: response.clone(),
+        };
+        if let Ok(json) = serde_json::to_string(&entry) {
+            let _ = std::fs::write(self.entry_path(key), json);
+        }
+    }
+
+    fn record_hit(&self) {
+        self.bump_counters(|c| c.hits += 1);
+    }
+
+    fn record_miss(&self) {
+        self.bump_counters(|c| c.misses += 1);
+    }
+
+    fn bump_counters(&self, update: impl FnOnce(&mut CacheCounters)) {
+        if std::fs::create_dir_all(&self.dir).is_err() {
+            return;
+        }
+        let path = self.dir.join(COUNTERS_FILE);
+        let mut counters: CacheCounters = std::fs::read_to_string(&path)
+            .ok()
+            .and_then(|data| serde_json::from_str(&data).ok())
+            .unwrap_or_default();
+        update(&mut counters);
+        if let Ok(json) = serde_json::to_string(&counters) {
+            let _ = std::fs::write(&path, json);
+        }
+    }
+}
+
+const COUNTERS_FILE: &str = "stats.json";
+
+fn unix_now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+fn hex_digest(hasher: Sha256) -> String {
+    let digest = hasher.finalize();
+    let mut out = String::with_capacity(digest.len() * 2);
+    for byte in digest {
+        use std::fmt::Write as _;
+        let _ = write!(out, "{byte:02x}");
+    }
+    out
+}
+
+/// Hash of the tool definitions visible to the model (empty hash when no
+/// tools are offered), folded into the cache key as the "tool state".
+fn tool_state_hash(tools: Option<&[ToolSpec]>) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    if let Some(tools) = tools {
+        for tool in tools {
+            hasher.update(tool.name.as_bytes());
+            hasher.update([0]);
+            hasher.update(tool.description.as_bytes());
+            hasher.update([0]);
+            hasher.update(tool.parameters.to_string().as_bytes());
+            hasher.update([0]);
+        }
+    }
+    hasher.finalize().into()
+}
+
+/// Provider wrapper that serves repeated identical `chat` requests from the
+/// response cache. All other surfaces (streaming, one-shot helpers) forward
+/// to the inner provider uncached.
+pub struct CachedProvider {
+    inner: Box<dyn Provider>,
+    provider_name: String,
+    cache: ResponseCache,
+    observer: Option<Arc<dyn Observer>>,
+}
+
+impl CachedProvider {
+    pub fn new(
+        inner: Box<dyn Provider>,
+        provider_name: impl Into<String>,
+        cache: ResponseCache,
+        observer: Option<Arc<dyn Observer>>,
+    ) -> Self {
+        Self {
+            inner,
+            provider_name: provider_name.into(),
+            cache,
+            observer,
+        }
+    }
+}
+
+/// Wrap `inner` with the response cache when `[cache] enabled = true`;
+/// otherwise return it unchanged. `zeroclaw_dir` is the config directory
+/// (entries live in its `cache/` subdirectory).
+pub fn maybe_wrap(
+    inner: Box<dyn Provider>,
+    provider_name: &str,
+    config: &crate::config::CacheConfig,
+    zeroclaw_dir: PathBuf,
+    observer: Option<Arc<dyn Observer>>,
+) -> Box<dyn Provider> {
+    if !config.enabled {
+        return inner;
+    }
+    let cache = ResponseCache::new(cache_dir(&zeroclaw_dir), config.ttl_secs);
+    Box::new(CachedProvider::new(inner, provider_name, cache, observer))
+}
+
+fn cache_dir(zeroclaw_dir: &Path) -> PathBuf {
+    zeroclaw_dir.join("cache")
+}
+
+#[async_trait]
+impl Provider for CachedProvider {
+    fn capabilities(&self) -> ProviderCapabilities {
+        self.inner.capabilities()
+    }
+
+    fn convert_tools(&self, tools: &[ToolSpec]) -> ToolsPayload {
+        self.inner.convert_tools(tools)
+    }
+
+    async fn warmup(&self) -> Result<()> {
+        self.inner.warmup().await
+    }
+
+    async fn chat_with_system(
+        &self,
+        system_prompt: Option<&str>,
+        message: &str,
+        model: &str,
+        temperature: f64,
+    ) -> Result<String> {
+        self.inner
+            .chat_with_system(system_prompt, message, model, temperature)
+            .await
+    }
+
+    async fn chat_with_history(
+        &self,
+        messages: &[ChatMessage],
+        model: &str,
+        temperature: f64,
+    ) -> Result<String> {
+        self.inner
+            .chat_with_history(messages, model, temperature)
+            .await
+    }
+
+    async fn chat(
+        &self,
+        request: ChatRequest<'_>,
+        model: &str,
+        temperature: f64,
+    ) -> Result<ChatResponse> {
+        let key = self.cache.key(
+            &self.provider_name,
+            model,
+            temperature,
+            request.messages,
+            request.tools,
+        );
+        if let Some(cached) = self.cache.lookup(&key) {
+            self.cache.record_hit();
+            tracing::debug!(provider = %self.provider_name, model, "Provider response served from cache");
+            if let Some(observer) = &self.observer {
+                observer.record_event(&ObserverEvent::CacheHit {
+                    provider: self.provider_name.clone(),
+                    model: model.to_string(),
+                });
+            }
+            return Ok(cached);
+        }
+        self.cache.record_miss();
+        let response = self.inner.chat(request, model, temperature).await?;
+        self.cache.store(&key, &response);
+        Ok(response)
+    }
+
+    async fn chat_with_tools(
+        &self,
+        messages: &[ChatMessage],
+        tools: &[serde_json::Value],
+        model: &str,
+        temperature: f64,
+    ) -> Result<ChatResponse> {
+        self.inner
+            .chat_with_tools(messages, tools, model, temperature)
+            .await
+    }
+
+    fn supports_native_tools(&self) -> bool {
+        self.inner.supports_native_tools()
+    }
+
+    fn supports_vision(&self) -> bool {
+        self.inner.supports_vision()
+    }
+
+    fn supports_streaming(&self) -> bool {
+        self.inner.supports_streaming()
+    }
+
+    fn stream_chat_with_system(
+        &self,
+        system_prompt: Option<&str>,
+        message: &str,
+        model: &str,
+        temperature: f64,
+        options: StreamOptions,
+    ) -> stream::BoxStream<'static, StreamResult<StreamChunk>> {
+        self.inner
+            .stream_chat_with_system(system_prompt, message, model, temperature, options)
+    }
+
+    fn stream_chat_with_history(
+        &self,
+        messages: &[ChatMessage],
+        model: &str,
+        temperature: f64,
+        options: StreamOptions,
+    ) -> stream::BoxStream<'static, StreamResult<StreamChunk>> {
+        self.inner
+            .stream_chat_with_history(messages, model, temperature, options)
+    }
+}
+
+/// Handle `zeroclaw cache <subcommand>` CLI commands.
+pub(crate) fn handle_command(
+    command: &crate::CacheCommands,
+    config: &crate::config::Config,
+) -> Result<()> {
+    let dir = cache_dir(&config.zeroclaw_dir());
+    match command {
+        crate::CacheCommands::Stats => print_stats(&dir, &config.cache),
+        crate::CacheCommands::Clear => clear(&dir),
+    }
+}
+
+fn print_stats(dir: &Path, config: &crate::config::CacheConfig) -> Result<()> {
+    println!(
+        "Response cache: {}",
+        if config.enabled {
+            "✅ enabled"
+        } else {
+            "❌ disabled ([cache] enabled = false)"
+        }
+    );
+    println!("Directory: {}", dir.display());
+    println!("TTL: {}s", config.ttl_secs);
+
+    let mut entries = 0u64;
+    let mut expired = 0u64;
+    let mut total_bytes = 0u64;
+    if let Ok(read_dir) = std::fs::read_dir(dir) {
+        for dir_entry in read_dir.flatten() {
+            let path = dir_entry.path();
+            if path.file_name().is_some_and(|n| n == COUNTERS_FILE)
+                || path.extension().is_none_or(|ext| ext != "json")
+            {
+                continue;
+            }
+            entries += 1;
+            if let Ok(meta) = dir_entry.metadata() {
+                total_bytes += meta.len();
+            }
+            let is_expired = std::fs::read_to_string(&path)
+                .ok()
+                .and_then(|data| serde_json::from_str::<CacheEntry>(&data).ok())
+                .is_some_and(|e| unix_now().saturating_sub(e.created_at) > config.ttl_secs);
+            if is_expired {
+                expired += 1;
+            }
+        }
+    }
+
+    let counters: CacheCounters = std::fs::read_to_string(dir.join(COUNTERS_FILE))
+        .ok()
+        .and_then(|data| serde_json::from_str(&data).ok())
+        .unwrap_or_default();
+
+    println!("Entries: {entries} ({expired} expired)");
+    println!("Size: {total_bytes} bytes");
+    let total_lookups = counters.hits + counters.misses;
+    if total_lookups > 0 {
+        println!(
+            "Lookups: {} hits / {} misses ({:.0}% hit rate)",
+            counters.hits,
+            counters.misses,
+            counters.hits as f64 / total_lookups as f64 * 100.0
+        );
+    } else {
+        println!("Lookups: none recorded");
+    }
+    Ok(())
+}
+
+fn clear(dir: &Path) -> Result<()> {
+    if !dir.exists() {
+        println!("✅ Cache is already empty");
+        return Ok(());
+    }
+    let mut removed = 0u64;
+    for dir_entry in std::fs::read_dir(dir)
+        .with_context(|| format!("Failed to read cache directory {}", dir.display()))?
+        .flatten()
+    {
+        let path = dir_entry.path();
+        if path.extension().is_some_and(|ext| ext == "json") && std::fs::remove_file(&path).is_ok()
+        {
+            removed += 1;
+        }
+    }
+    println!("✅ Cleared {removed} cache file(s)");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn messages() -> Vec<ChatMessage> {
+        vec![
+            ChatMessage::system("You are ZeroClawAgent."),
+            ChatMessage {
+                role: "user".into(),
+                content: "check system health".into(),
+            },
+        ]
+    }
+
+    fn tool_specs() -> Vec<ToolSpec> {
+        vec![ToolSpec {
+            name: "shell".into(),
+            description: "Run a shell command".into(),
+            parameters: serde_json::json!({"type": "object"}),
+        }]
+    }
+
+    #[test]
+    fn key_is_stable_for_identical_requests() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let cache = ResponseCache::new(tmp.path().to_path_buf(), 60);
+        let specs = tool_specs();
+
+        let a = cache.key("openrouter", "model-a", 0.7, &messages(), Some(&specs));
+        let b = cache.key("openrouter", "model-a", 0.7, &messages(), Some(&specs));
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn key_changes_with_model_messages_and_tool_state() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let cache = ResponseCache::new(tmp.path().to_path_buf(), 60);
+        let specs = tool_specs();
+        let base = cache.key("openrouter", "model-a", 0.7, &messages(), Some(&specs));
+
+        assert_ne!(
+            base,
+            cache.key("openrouter", "model-b", 0.7, &messages(), Some(&specs))
+        );
+        assert_ne!(
+            base,
+            cache.key("anthropic", "model-a", 0.7, &messages(), Some(&specs))
+        );
+        assert_ne!(
+            base,
+            cache.key(
+                "openrouter",
+                "model-a",
+                0.7,
+                &[ChatMessage::user("different prompt")],
+                Some(&specs)
+            )
+        );
+        assert_ne!(
+            base,
+            cache.key("openrouter", "model-a", 0.7, &messages(), None)
+        );
+    }
+
+    #[test]
+    fn key_normalizes_message_whitespace() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let cache = ResponseCache::new(tmp.path().to_path_buf(), 60);
+        let padded = vec![ChatMessage::user("  check system health \n")];
+        let trimmed = vec![ChatMessage::user("check system health")];
+
+        assert_eq!(
+            cache.key("openrouter", "model-a", 0.7, &padded, None),
+            cache.key("openrouter", "model-a", 0.7, &trimmed, None)
+        );
+    }
+
+    #[test]
+    fn lookup_returns_stored_response_within_ttl() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let cache = ResponseCache::new(tmp.path().to_path_buf(), 60);
+        let response = ChatResponse {
+            text: Some("cached answer".into()),
+            tool_calls: Vec::new(),
+            usage: None,
+        };
+
+        cache.store("abc123", &response);
+        let found = cache.lookup("abc123").expect("entry should be fresh");
+        assert_eq!(found.text.as_deref(), Some("cached answer"));
+    }
+
+    #[test]
+    fn lookup_expires_entries_past_ttl() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let cache = ResponseCache::new(tmp.path().to_path_buf(), 60);
+        let path = cache.entry_path("stale");
+        std::fs::create_dir_all(tmp.path()).unwrap();
+        let entry = CacheEntry {
+            created_at: unix_now() - 120,
+            response: ChatResponse::default(),
+        };
+        std::fs::write(&path, serde_json::to_string(&entry).unwrap()).unwrap();
+
+        assert!(cache.lookup("stale").is_none());
+        assert!(!path.exists(), "expired entry should be removed lazily");
+    }
+
+    #[test]
+    fn clear_removes_cache_files() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let cache = ResponseCache::new(tmp.path().to_path_buf(), 60);
+        cache.store("one", &ChatResponse::default());
+        cache.store("two", &ChatResponse::default());
+
+        clear(tmp.path()).unwrap();
+        assert!(cache.lookup("one").is_none());
+        assert!(cache.lookup("two").is_none());
+    }
+
+    struct CountingProvider {
+        calls: std::sync::Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl Provider for CountingProvider {
+        async fn chat_with_system(
+            &self,
+            _system_prompt: Option<&str>,
+            _message: &str,
+            _model: &str,
+            _temperature: f64,
+        ) -> Result<String> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            Ok("fresh answer".into())
+        }
+    }
+
+    #[tokio::test]
+    async fn cached_provider_serves_repeat_chat_without_inner_call() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let calls = std::sync::Arc::new(AtomicUsize::new(0));
+        let inner = Box::new(CountingProvider {
+            calls: calls.clone(),
+        });
+        let cache = ResponseCache::new(tmp.path().to_path_buf(), 60);
+        let provider = CachedProvider::new(inner, "openrouter", cache, None);
+        let history = messages();
+        let request = ChatRequest {
+            messages: &history,
+            tools: None,
+        };
+
+        let first = provider.chat(request, "model-a", 0.7).await.unwrap();
+        let second = provider.chat(request, "model-a", 0.7).await.unwrap();
+
+        assert_eq!(first.text.as_deref(), Some("fresh answer"));
+        assert_eq!(second.text.as_deref(), Some("fresh answer"));
+        assert_eq!(calls.load(Ordering::SeqCst), 1, "second call must be a hit");
+    }
+
+    #[tokio::test]
+    async fn cached_provider_does_not_serve_other_models_from_cache() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let calls = std::sync::Arc::new(AtomicUsize::new(0));
+        let inner = Box::new(CountingProvider {
+            calls: calls.clone(),
+        });
+        let cache = ResponseCache::new(tmp.path().to_path_buf(), 60);
+        let provider = CachedProvider::new(inner, "openrouter", cache, None);
+        let history = messages();
+        let request = ChatRequest {
+            messages: &history,
+            tools: None,
+        };
+
+        provider.chat(request, "model-a", 0.7).await.unwrap();
+        provider.chat(request, "model-b", 0.7).await.unwrap();
+
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+}
@@ -0,0 +1,146 @@
+//! Provider wrapper that injects synthetic failures when chaos mode is active.
+//!
+//! [`ChaosProvider`] wraps each concrete provider inside the resilient chain,
+//! failing a configurable fraction of calls with a synthetic retryable error
+//! so [`ReliableProvider`](super::reliable::ReliableProvider) retry and
+//! failover logic is exercised against real failure handling. Only
+//! constructed when `ZEROCLAW_CHAOS` is set; see [`crate::chaos`].
+
+use super::traits::{
+    ChatMessage, ChatResponse, ProviderCapabilities, StreamChunk, StreamError, StreamOptions,
+    StreamResult, ToolsPayload,
+};
+use super::Provider;
+use crate::tools::ToolSpec;
+use async_trait::async_trait;
+use futures_util::{stream, StreamExt};
+
+pub struct ChaosProvider {
+    inner: Box<dyn Provider>,
+}
+
+impl ChaosProvider {
+    pub fn new(inner: Box<dyn Provider>) -> Self {
+        Self { inner }
+    }
+
+    fn maybe_fail(&self, surface: &'static str) -> anyhow::Result<()> {
+        if crate::chaos::inject_provider_failure() {
+            tracing::warn!(surface, "chaos: injecting synthetic provider failure");
+            anyhow::bail!("chaos: injected provider failure (synthetic 503)");
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Provider for ChaosProvider {
+    fn capabilities(&self) -> ProviderCapabilities {
+        self.inner.capabilities()
+    }
+
+    fn convert_tools(&self, tools: &[ToolSpec]) -> ToolsPayload {
+        self.inner.convert_tools(tools)
+    }
+
+    async fn warmup(&self) -> anyhow::Result<()> {
+        self.inner.warmup().await
+    }
+
+    async fn chat_with_system(
+        &self,
+        system_prompt: Option<&str>,
+        message: &str,
+        model: &str,
+        temperature: f64,
+    ) -> anyhow::Result<String> {
+        self.maybe_fail("chat_with_system")?;
+        self.inner
+            .chat_with_system(system_prompt, message, model, temperature)
+            .await
+    }
+
+    async fn chat_with_history(
+        &self,
+        messages: &[ChatMessage],
+        model: &str,
+        temperature: f64,
+    ) -> anyhow::Result<String> {
+        self.maybe_fail("chat_with_history")?;
+        self.inner
+            .chat_with_history(messages, model, temperature)
+            .await
+    }
+
+    async fn chat_with_tools(
+        &self,
+        messages: &[ChatMessage],
+        tools: &[serde_json::Value],
+        model: &str,
+        temperature: f64,
+    ) -> anyhow::Result<ChatResponse> {
+        self.maybe_fail("chat_with_tools")?;
+        self.inner
+            .chat_with_tools(messages, tools, model, temperature)
+            .await
+    }
+
+    fn supports_native_tools(&self) -> bool {
+        self.inner.supports_native_tools()
+    }
+
+    fn supports_vision(&self) -> bool {
+        self.inner.supports_vision()
+    }
+
+    fn supports_streaming(&self) -> bool {
+        self.inner.supports_streaming()
+    }
+
+    fn stream_chat_with_system(
+        &self,
+        system_prompt: Option<&str>,
+        message: &str,
+        model: &str,
+        temperature: f64,
+        options: StreamOptions,
+    ) -> stream::BoxStream<'static, StreamResult<StreamChunk>> {
+        if crate::chaos::inject_provider_failure() {
+            tracing::warn!(
+                surface = "stream_chat_with_system",
+                "chaos: injecting synthetic provider failure"
+            );
+            return stream::once(async {
+                Err(StreamError::Provider(
+                    "chaos: injected provider failure (synthetic 503)".to_string(),
+                ))
+            })
+            .boxed();
+        }
+        self.inner
+            .stream_chat_with_system(system_prompt, message, model, temperature, options)
+    }
+
+    fn stream_chat_with_history(
+        &self,
+        messages: &[ChatMessage],
+        model: &str,
+        temperature: f64,
+        options: StreamOptions,
+    ) -> stream::BoxStream<'static, StreamResult<StreamChunk>> {
+        if crate::chaos::inject_provider_failure() {
+            tracing::warn!(
+                surface = "stream_chat_with_history",
+                "chaos: injecting synthetic provider failure"
+            );
+            return stream::once(async {
+                Err(StreamError::Provider(
+                    "chaos: injected provider failure (synthetic 503)".to_string(),
+                ))
+            })
+            .boxed();
+        }
+        self.inner
+            .stream_chat_with_history(messages, model, temperature, options)
+    }
+}
@@ -17,7 +17,10 @@
 //! in [`create_provider_with_url`]. See `AGENTS.md` §7.1 for the full change playbook.
 
 pub mod anthropic;
+pub mod balancer;
 pub mod bedrock;
+pub mod cache;
+pub mod chaos;
 pub mod compatible;
 pub mod copilot;
 pub mod gemini;
@@ -25,8 +28,11 @@ pub mod ollama;
 pub mod openai;
 pub mod openai_codex;
 pub mod openrouter;
+pub mod rate_limit;
+pub mod recorder;
 pub mod reliable;
 pub mod router;
+pub mod structured;
 pub mod traits;
 
 #[allow(unused_imports)]
@@ -913,7 +919,7 @@ pub fn create_provider_with_options(
 ) -> anyhow::Result<Box<dyn Provider>> {
     match name {
         "openai-codex" | "openai_codex" | "codex" => {
-            Ok(Box::new(openai_codex::OpenAiCodexProvider::new(options)))
+            recorder::apply_mode(|| Ok(Box::new(openai_codex::OpenAiCodexProvider::new(options))))
         }
         _ => create_provider_with_url_and_options(name, api_key, None, options),
     }
@@ -928,13 +934,24 @@ pub fn create_provider_with_url(
     create_provider_with_url_and_options(name, api_key, api_url, &ProviderRuntimeOptions::default())
 }
 
-/// Factory: create provider with optional base URL and runtime options.
-#[allow(clippy::too_many_lines)]
+/// Factory: create provider with optional base URL and runtime options,
+/// honoring record/replay mode (`ZEROCLAW_RECORD` / `ZEROCLAW_REPLAY`).
 fn create_provider_with_url_and_options(
     name: &str,
     api_key: Option<&str>,
     api_url: Option<&str>,
     options: &ProviderRuntimeOptions,
+) -> anyhow::Result<Box<dyn Provider>> {
+    recorder::apply_mode(|| build_provider_with_url_and_options(name, api_key, api_url, options))
+}
+
+/// Factory: construct the concrete provider for `name`.
+#[allow(clippy::too_many_lines)]
+fn build_provider_with_url_and_options(
+    name: &str,
+    api_key: Option<&str>,
+    api_url: Option<&str>,
+    options: &ProviderRuntimeOptions,
 ) -> anyhow::Result<Box<dyn Provider>> {
     let qwen_oauth_context = is_qwen_oauth_alias(name).then(|| resolve_qwen_oauth_context(api_key));
 
@@ -1218,13 +1235,31 @@ pub fn create_resilient_provider_with_options(
         }
     }
 
+    // Chaos mode (ZEROCLAW_CHAOS): wrap each concrete provider so injected
+    // failures exercise the retry/failover logic below, not bypass it.
+    let providers = if crate::chaos::enabled() {
+        providers
+            .into_iter()
+            .map(|(name, provider)| {
+                let wrapped: Box<dyn Provider> = Box::new(chaos::ChaosProvider::new(provider));
+                (name, wrapped)
+            })
+            .collect()
+    } else {
+        providers
+    };
+
     let reliable = ReliableProvider::new(
         providers,
         reliability.provider_retries,
         reliability.provider_backoff_ms,
     )
     .with_api_keys(reliability.api_keys.clone())
-    .with_model_fallbacks(reliability.model_fallbacks.clone());
+    .with_model_fallbacks(reliability.model_fallbacks.clone())
+    .with_circuit_breaker(
+        reliability.circuit_breaker_failures,
+        reliability.circuit_breaker_cooldown_secs,
+    );
 
     Ok(Box::new(reliable))
 }
@@ -1329,6 +1364,63 @@ pub fn create_routed_provider_with_options(
     )))
 }
 
+/// Wrap the primary provider in a [`balancer::BalancedProvider`] when
+/// `[providers]` load balancing is configured. Returns the primary unchanged
+/// when no strategy is set.
+///
+/// Pool members other than the primary are built with their own resilience
+/// wrapper; members that fail to initialize are skipped with a warning, and an
+/// error is returned only when no pool member is usable.
+pub fn maybe_wrap_balanced(
+    primary: Box<dyn Provider>,
+    primary_name: &str,
+    providers_config: &crate::config::ProvidersConfig,
+    reliability: &crate::config::ReliabilityConfig,
+    options: &ProviderRuntimeOptions,
+) -> anyhow::Result<Box<dyn Provider>> {
+    if providers_config.strategy.is_empty() || providers_config.pool.is_empty() {
+        return Ok(primary);
+    }
+
+    let strategy = balancer::BalanceStrategy::parse(&providers_config.strategy)?;
+
+    let mut primary = Some(primary);
+    let mut members: Vec<(String, f64, Box<dyn Provider>)> = Vec::new();
+    for entry in &providers_config.pool {
+        if members.iter().any(|(name, _, _)| name == &entry.name) {
+            continue;
+        }
+        if entry.name == primary_name {
+            if let Some(provider) = primary.take() {
+                members.push((entry.name.clone(), entry.cost_per_mtok, provider));
+                continue;
+            }
+        }
+        // Pool members resolve their own credential (entry override or
+        // provider-specific env var), mirroring fallback-provider behavior.
+        let key = entry
+            .api_key
+            .as_deref()
+            .map(str::trim)
+            .filter(|k| !k.is_empty());
+        match create_resilient_provider_with_options(&entry.name, key, None, reliability, options) {
+            Ok(provider) => members.push((entry.name.clone(), entry.cost_per_mtok, provider)),
+            Err(_error) => {
+                tracing::warn!(
+                    pool_provider = entry.name.as_str(),
+                    "Ignoring pool provider that failed to initialize"
+                );
+            }
+        }
+    }
+
+    if members.is_empty() {
+        anyhow::bail!("providers.pool has no usable members; check provider names and credentials");
+    }
+
+    Ok(Box::new(balancer::BalancedProvider::new(strategy, members)))
+}
+
 /// Information about a supported provider for display purposes.
 pub struct ProviderInfo {
     /// Canonical name used in config (e.g. `"openrouter"`)
@@ -2198,6 +2290,9 @@ mod tests {
             channel_max_backoff_secs: 60,
             scheduler_poll_secs: 15,
             scheduler_retries: 2,
+            shutdown_drain_secs: 30,
+            circuit_breaker_failures: 5,
+            circuit_breaker_cooldown_secs: 30,
         };
 
         let provider = create_resilient_provider(
@@ -2237,6 +2332,9 @@ mod tests {
             channel_max_backoff_secs: 60,
             scheduler_poll_secs: 15,
             scheduler_retries: 2,
+            shutdown_drain_secs: 30,
+            circuit_breaker_failures: 5,
+            circuit_breaker_cooldown_secs: 30,
         };
 
         // Primary uses a ZAI key; fallbacks (lmstudio, ollama) should NOT
@@ -2259,6 +2357,9 @@ mod tests {
             channel_max_backoff_secs: 60,
             scheduler_poll_secs: 15,
             scheduler_retries: 2,
+            shutdown_drain_secs: 30,
+            circuit_breaker_failures: 5,
+            circuit_breaker_cooldown_secs: 30,
         };
 
         let provider =
@@ -2285,6 +2386,9 @@ mod tests {
             channel_max_backoff_secs: 60,
             scheduler_poll_secs: 15,
             scheduler_retries: 2,
+            shutdown_drain_secs: 30,
+            circuit_breaker_failures: 5,
+            circuit_breaker_cooldown_secs: 30,
         };
 
         let provider = create_resilient_provider("zai", Some("zai-test-key"), None, &reliability);
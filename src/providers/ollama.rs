@@ -446,6 +446,7 @@ impl Provider for OllamaProvider {
         ProviderCapabilities {
             native_tool_calling: true,
             vision: true,
+            structured_output: false,
         }
     }
 
@@ -614,7 +615,11 @@ impl Provider for OllamaProvider {
             } else {
                 Some(response.message.content)
             };
-            return Ok(ChatResponse { text, tool_calls, usage: None });
+            return Ok(ChatResponse {
+                text,
+                tool_calls,
+                usage: None,
+            });
         }
 
         // Plain text response.
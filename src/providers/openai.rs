@@ -64,6 +64,15 @@ struct NativeChatRequest {
     tool_choice: Option<String>,
 }
 
+/// Request shape for native structured outputs (`response_format: json_schema`).
+#[derive(Debug, Serialize)]
+struct StructuredChatRequest {
+    model: String,
+    messages: Vec<Message>,
+    temperature: f64,
+    response_format: serde_json::Value,
+}
+
 #[derive(Debug, Serialize)]
 struct NativeMessage {
     role: String,
@@ -260,7 +269,11 @@ impl OpenAiProvider {
             })
             .collect::<Vec<_>>();
 
-        ProviderChatResponse { text, tool_calls, usage: None }
+        ProviderChatResponse {
+            text,
+            tool_calls,
+            usage: None,
+        }
     }
 
     fn http_client(&self) -> Client {
@@ -368,6 +381,64 @@ impl Provider for OpenAiProvider {
         true
     }
 
+    fn supports_structured_output(&self) -> bool {
+        true
+    }
+
+    /// Native structured output via `response_format: json_schema` — the API
+    /// enforces the schema, so no prompt injection is needed.
+    async fn chat_structured(
+        &self,
+        messages: &[ChatMessage],
+        schema: &serde_json::Value,
+        model: &str,
+        temperature: f64,
+    ) -> anyhow::Result<String> {
+        let credential = self.credential.as_ref().ok_or_else(|| {
+            anyhow::anyhow!("OpenAI API key not set. Set OPENAI_API_KEY or edit config.toml.")
+        })?;
+
+        let request = StructuredChatRequest {
+            model: model.to_string(),
+            messages: messages
+                .iter()
+                .map(|m| Message {
+                    role: m.role.clone(),
+                    content: m.content.clone(),
+                })
+                .collect(),
+            temperature,
+            response_format: serde_json::json!({
+                "type": "json_schema",
+                "json_schema": {
+                    "name": "structured_response",
+                    "strict": true,
+                    "schema": schema
+                }
+            }),
+        };
+
+        let response = self
+            .http_client()
+            .post(format!("{}/chat/completions", self.base_url))
+            .header("Authorization", format!("Bearer {credential}"))
+            .json(&request)
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(super::api_error("OpenAI", response).await);
+        }
+
+        let chat_response: ChatResponse = response.json().await?;
+        chat_response
+            .choices
+            .into_iter()
+            .next()
+            .map(|c| c.message.effective_content())
+            .ok_or_else(|| anyhow::anyhow!("No response from OpenAI"))
+    }
+
     async fn chat_with_tools(
         &self,
         messages: &[ChatMessage],
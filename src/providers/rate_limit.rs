@@ -0,0 +1,342 @@
+//! Token-bucket rate limiting for provider calls.
+//!
+//! Configured via `[providers.rate_limits]` (global) and
+//! `[[providers.rate_limits.per_provider]]` (per provider name). Requests are
+//! queued — the wrapper sleeps until the bucket refills — rather than failed,
+//! so bursty cron schedules don't trip provider 429 bans.
+//!
+//! Requests are charged up front (one per call). Token usage is only known
+//! after a response, so token buckets are post-charged: a call waits until the
+//! token bucket is positive, then debits the actual usage afterwards, which
+//! may drive the bucket negative and delay subsequent calls.
+
+use super::traits::{ChatMessage, ChatRequest, ChatResponse};
+use super::Provider;
+use async_trait::async_trait;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+struct Bucket {
+    /// Maximum and starting level — the per-minute budget.
+    capacity: f64,
+    available: f64,
+    refill_per_sec: f64,
+    last_refill: Instant,
+}
+
+impl Bucket {
+    fn new(per_minute: u64) -> Self {
+        let capacity = per_minute as f64;
+        Self {
+            capacity,
+            available: capacity,
+            refill_per_sec: capacity / 60.0,
+            last_refill: Instant::now(),
+        }
+    }
+
+    fn refill(&mut self) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+        self.available = (self.available + elapsed * self.refill_per_sec).min(self.capacity);
+        self.last_refill = now;
+    }
+
+    /// Try to take `cost` from the bucket; on shortfall, return how long to
+    /// wait before retrying.
+    fn try_take(&mut self, cost: f64) -> Option<Duration> {
+        self.refill();
+        if self.available >= cost {
+            self.available -= cost;
+            None
+        } else {
+            let shortfall = cost - self.available;
+            Some(Duration::from_secs_f64(shortfall / self.refill_per_sec))
+        }
+    }
+
+    /// Debit spend that is only known after the fact. The bucket may go
+    /// negative (delaying later calls) but is clamped at one budget of debt so
+    /// a single huge response cannot stall the limiter indefinitely.
+    fn debit(&mut self, cost: f64) {
+        self.refill();
+        self.available = (self.available - cost).max(-self.capacity);
+    }
+}
+
+async fn acquire(bucket: &Mutex<Bucket>, cost: f64) {
+    loop {
+        let wait = bucket
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .try_take(cost);
+        match wait {
+            None => return,
+            Some(duration) => tokio::time::sleep(duration).await,
+        }
+    }
+}
+
+/// One request-per-minute / tokens-per-minute limit pair. A zero rate means
+/// that dimension is unlimited.
+struct Limiter {
+    requests: Option<Mutex<Bucket>>,
+    tokens: Option<Mutex<Bucket>>,
+}
+
+impl Limiter {
+    fn new(requests_per_minute: u64, tokens_per_minute: u64) -> Self {
+        Self {
+            requests: (requests_per_minute > 0)
+                .then(|| Mutex::new(Bucket::new(requests_per_minute))),
+            tokens: (tokens_per_minute > 0).then(|| Mutex::new(Bucket::new(tokens_per_minute))),
+        }
+    }
+
+    fn is_noop(&self) -> bool {
+        self.requests.is_none() && self.tokens.is_none()
+    }
+
+    async fn before_request(&self) {
+        if let Some(bucket) = &self.requests {
+            acquire(bucket, 1.0).await;
+        }
+        if let Some(bucket) = &self.tokens {
+            // Token usage is post-charged; just wait until the bucket has
+            // recovered from any deficit.
+            acquire(bucket, 0.0).await;
+        }
+    }
+
+    fn after_response(&self, tokens: u64) {
+        if let Some(bucket) = &self.tokens {
+            bucket
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .debit(tokens as f64);
+        }
+    }
+}
+
+/// Provider wrapper that queues calls behind the configured rate limits.
+pub struct RateLimitedProvider {
+    inner: Box<dyn Provider>,
+    limiters: Vec<Limiter>,
+}
+
+impl RateLimitedProvider {
+    /// Wrap `inner` with the limits that apply to `provider_name`: the global
+    /// limit plus any matching `per_provider` entry. Returns `inner` unchanged
+    /// when no limit applies.
+    pub fn maybe_wrap(
+        inner: Box<dyn Provider>,
+        provider_name: &str,
+        config: &crate::config::RateLimitsConfig,
+    ) -> Box<dyn Provider> {
+        let mut limiters = Vec::new();
+        let global = Limiter::new(config.requests_per_minute, config.tokens_per_minute);
+        if !global.is_noop() {
+            limiters.push(global);
+        }
+        if let Some(entry) = config.per_provider.iter().find(|e| e.name == provider_name) {
+            let scoped = Limiter::new(entry.requests_per_minute, entry.tokens_per_minute);
+            if !scoped.is_noop() {
+                limiters.push(scoped);
+            }
+        }
+        if limiters.is_empty() {
+            return inner;
+        }
+        Box::new(Self { inner, limiters })
+    }
+
+    async fn before_request(&self) {
+        for limiter in &self.limiters {
+            limiter.before_request().await;
+        }
+    }
+
+    fn after_response(&self, tokens: Option<u64>) {
+        if let Some(tokens) = tokens {
+            for limiter in &self.limiters {
+                limiter.after_response(tokens);
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl Provider for RateLimitedProvider {
+    async fn chat_with_system(
+        &self,
+        system_prompt: Option<&str>,
+        message: &str,
+        model: &str,
+        temperature: f64,
+    ) -> anyhow::Result<String> {
+        self.before_request().await;
+        self.inner
+            .chat_with_system(system_prompt, message, model, temperature)
+            .await
+    }
+
+    async fn chat_with_history(
+        &self,
+        messages: &[ChatMessage],
+        model: &str,
+        temperature: f64,
+    ) -> anyhow::Result<String> {
+        self.before_request().await;
+        self.inner
+            .chat_with_history(messages, model, temperature)
+            .await
+    }
+
+    async fn chat(
+        &self,
+        request: ChatRequest<'_>,
+        model: &str,
+        temperature: f64,
+    ) -> anyhow::Result<ChatResponse> {
+        self.before_request().await;
+        let result = self.inner.chat(request, model, temperature).await;
+        if let Ok(response) = &result {
+            self.after_response(
+                response
+                    .usage
+                    .as_ref()
+                    .map(|u| u.prompt_tokens + u.completion_tokens),
+            );
+        }
+        result
+    }
+
+    async fn chat_with_tools(
+        &self,
+        messages: &[ChatMessage],
+        tools: &[serde_json::Value],
+        model: &str,
+        temperature: f64,
+    ) -> anyhow::Result<ChatResponse> {
+        self.before_request().await;
+        let result = self
+            .inner
+            .chat_with_tools(messages, tools, model, temperature)
+            .await;
+        if let Ok(response) = &result {
+            self.after_response(
+                response
+                    .usage
+                    .as_ref()
+                    .map(|u| u.prompt_tokens + u.completion_tokens),
+            );
+        }
+        result
+    }
+
+    fn supports_native_tools(&self) -> bool {
+        self.inner.supports_native_tools()
+    }
+
+    fn supports_vision(&self) -> bool {
+        self.inner.supports_vision()
+    }
+
+    async fn warmup(&self) -> anyhow::Result<()> {
+        self.inner.warmup().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bucket_starts_full_and_charges_requests() {
+        let mut bucket = Bucket::new(60);
+        assert!(bucket.try_take(1.0).is_none());
+        assert!(bucket.available <= 59.0);
+    }
+
+    #[test]
+    fn bucket_reports_wait_when_empty() {
+        let mut bucket = Bucket::new(60);
+        bucket.available = 0.0;
+        bucket.last_refill = Instant::now();
+        let wait = bucket.try_take(1.0).expect("empty bucket should queue");
+        // 60/min refills one unit per second.
+        assert!(wait <= Duration::from_secs(1));
+        assert!(wait >= Duration::from_millis(900));
+    }
+
+    #[test]
+    fn bucket_refill_is_capped_at_capacity() {
+        let mut bucket = Bucket::new(60);
+        bucket.last_refill = Instant::now() - Duration::from_secs(600);
+        bucket.refill();
+        assert!(bucket.available <= 60.0);
+    }
+
+    #[test]
+    fn bucket_debit_clamps_debt_to_one_budget() {
+        let mut bucket = Bucket::new(100);
+        bucket.debit(1_000_000.0);
+        assert!(bucket.available >= -100.0);
+    }
+
+    #[test]
+    fn limiter_with_zero_rates_is_noop() {
+        assert!(Limiter::new(0, 0).is_noop());
+        assert!(!Limiter::new(10, 0).is_noop());
+        assert!(!Limiter::new(0, 1000).is_noop());
+    }
+
+    struct EchoProvider;
+
+    #[async_trait]
+    impl Provider for EchoProvider {
+        async fn chat_with_system(
+            &self,
+            _system_prompt: Option<&str>,
+            _message: &str,
+            _model: &str,
+            _temperature: f64,
+        ) -> anyhow::Result<String> {
+            Ok("ok".to_string())
+        }
+    }
+
+    #[test]
+    fn maybe_wrap_is_identity_without_limits() {
+        let config = crate::config::RateLimitsConfig::default();
+        let wrapped =
+            RateLimitedProvider::maybe_wrap(Box::new(EchoProvider), "openrouter", &config);
+        // A no-op config must not introduce a limiter layer; verify by
+        // confirming the default config produces no limiters directly.
+        let global = Limiter::new(config.requests_per_minute, config.tokens_per_minute);
+        assert!(global.is_noop());
+        drop(wrapped);
+    }
+
+    #[tokio::test]
+    async fn generous_limits_do_not_block_requests() {
+        let config = crate::config::RateLimitsConfig {
+            requests_per_minute: 10_000,
+            tokens_per_minute: 1_000_000,
+            per_provider: vec![crate::config::ProviderRateLimit {
+                name: "openrouter".to_string(),
+                requests_per_minute: 10_000,
+                tokens_per_minute: 0,
+            }],
+        };
+        let provider =
+            RateLimitedProvider::maybe_wrap(Box::new(EchoProvider), "openrouter", &config);
+        for _ in 0..5 {
+            let reply = provider
+                .chat_with_system(None, "hi", "m", 0.7)
+                .await
+                .unwrap();
+            assert_eq!(reply, "ok");
+        }
+    }
+}
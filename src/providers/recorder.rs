@@ -0,0 +1,708 @@
+//! VCR-style record-and-replay for provider interactions.
+//!
+//! `zeroclaw --record <cassette> ...` wraps every concrete provider in a
+//! [`RecordingProvider`] that captures each request/response pair into a JSON
+//! cassette file. `zeroclaw --replay <cassette> ...` swaps providers for a
+//! [`ReplayProvider`] that serves the recorded responses back deterministically
+//! and never touches the network — enabling offline integration tests for
+//! skills and workflows and reproducible bug reports.
+//!
+//! The flags are equivalent to setting `ZEROCLAW_RECORD` / `ZEROCLAW_REPLAY`,
+//! which is how the mode reaches the provider factory (same mechanism as
+//! `ZEROCLAW_CHAOS`). Interactions are keyed on (surface, model, temperature,
+//! normalized messages, tool/schema state) — the provider name is deliberately
+//! excluded so a cassette stays usable when the configured provider changes.
+//! Repeated identical requests replay in recorded order. Streaming passes
+//! through unrecorded in record mode and is unsupported in replay mode.
+
+use super::traits::{
+    ChatMessage, ChatRequest, ChatResponse, ProviderCapabilities, StreamChunk, StreamOptions,
+    StreamResult, ToolsPayload,
+};
+use super::Provider;
+use crate::tools::ToolSpec;
+use anyhow::{bail, Context, Result};
+use async_trait::async_trait;
+use futures_util::stream;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, VecDeque};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock};
+
+/// Environment variable holding the cassette path for record mode.
+pub const RECORD_ENV_VAR: &str = "ZEROCLAW_RECORD";
+/// Environment variable holding the cassette path for replay mode.
+pub const REPLAY_ENV_VAR: &str = "ZEROCLAW_REPLAY";
+
+/// Active recorder mode, resolved from the environment.
+pub enum RecorderMode {
+    Record(PathBuf),
+    Replay(PathBuf),
+}
+
+/// Resolve the recorder mode from `ZEROCLAW_RECORD` / `ZEROCLAW_REPLAY`.
+/// Setting both is an explicit error rather than a silent precedence choice.
+pub fn mode_from_env() -> Result<Option<RecorderMode>> {
+    let record = std::env::var(RECORD_ENV_VAR).ok().filter(|v| !v.is_empty());
+    let replay = std::env::var(REPLAY_ENV_VAR).ok().filter(|v| !v.is_empty());
+    match (record, replay) {
+        (Some(_), Some(_)) => {
+            bail!("Set only one of {RECORD_ENV_VAR} and {REPLAY_ENV_VAR} (record or replay, not both)")
+        }
+        (Some(path), None) => Ok(Some(RecorderMode::Record(PathBuf::from(path)))),
+        (None, Some(path)) => Ok(Some(RecorderMode::Replay(PathBuf::from(path)))),
+        (None, None) => Ok(None),
+    }
+}
+
+/// Apply the active recorder mode to a provider under construction.
+///
+/// Replay mode never runs `build` (no credentials needed, nothing goes
+/// online); record mode builds the real provider and wraps it. Called from
+/// the provider factory so every creation path is covered.
+pub fn apply_mode(build: impl FnOnce() -> Result<Box<dyn Provider>>) -> Result<Box<dyn Provider>> {
+    match mode_from_env()? {
+        None => build(),
+        Some(RecorderMode::Replay(path)) => Ok(Box::new(ReplayProvider::load(&path)?)),
+        Some(RecorderMode::Record(path)) => {
+            let inner = build()?;
+            Ok(Box::new(RecordingProvider::new(
+                inner,
+                shared_recorder(&path),
+            )))
+        }
+    }
+}
+
+/// One recorded request/response pair. `text` is set for text-returning
+/// surfaces, `response` for the structured `chat`/`chat_with_tools` surfaces.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Interaction {
+    key: String,
+    kind: String,
+    model: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    text: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    response: Option<ChatResponse>,
+}
+
+/// On-disk cassette format: interactions in recorded order.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Cassette {
+    interactions: Vec<Interaction>,
+}
+
+/// Stable interaction key over everything that determines a response.
+fn interaction_key(
+    kind: &str,
+    model: &str,
+    temperature: f64,
+    messages: &[ChatMessage],
+    extra_state: &str,
+) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(kind.as_bytes());
+    hasher.update([0]);
+    hasher.update(model.as_bytes());
+    hasher.update([0]);
+    hasher.update(temperature.to_bits().to_le_bytes());
+    for message in messages {
+        hasher.update([0]);
+        hasher.update(message.role.as_bytes());
+        hasher.update([0]);
+        hasher.update(message.content.trim().as_bytes());
+    }
+    hasher.update([0]);
+    hasher.update(extra_state.as_bytes());
+    let digest = hasher.finalize();
+    let mut out = String::with_capacity(digest.len() * 2);
+    for byte in digest {
+        use std::fmt::Write as _;
+        let _ = write!(out, "{byte:02x}");
+    }
+    out
+}
+
+/// Fingerprint of the tool definitions offered with a request.
+fn tool_specs_state(tools: Option<&[ToolSpec]>) -> String {
+    let Some(tools) = tools else {
+        return String::new();
+    };
+    let mut state = String::new();
+    for tool in tools {
+        state.push_str(&tool.name);
+        state.push('\0');
+        state.push_str(&tool.parameters.to_string());
+        state.push('\0');
+    }
+    state
+}
+
+/// Shared append-only cassette writer for one record session. All
+/// [`RecordingProvider`] instances in the process (e.g. a resilient fallback
+/// chain) write to the same store so the cassette stays complete.
+struct CassetteRecorder {
+    path: PathBuf,
+    interactions: Mutex<Vec<Interaction>>,
+}
+
+impl CassetteRecorder {
+    fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            interactions: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Append an interaction and rewrite the cassette. A write failure is
+    /// logged rather than failing the live request — recording must never
+    /// break the run it observes.
+    fn append(&self, interaction: Interaction) {
+        let Ok(mut interactions) = self.interactions.lock() else {
+            return;
+        };
+        interactions.push(interaction);
+        let cassette = Cassette {
+            interactions: interactions.clone(),
+        };
+        if let Some(parent) = self.path.parent() {
+            let _ = std::fs::create_dir_all(parent);
+        }
+        match serde_json::to_vec_pretty(&cassette) {
+            Ok(json) => {
+                if let Err(e) = std::fs::write(&self.path, json) {
+                    tracing::warn!(cassette = %self.path.display(), "Failed to write cassette: {e}");
+                }
+            }
+            Err(e) => {
+                tracing::warn!(cassette = %self.path.display(), "Failed to serialize cassette: {e}");
+            }
+        }
+    }
+}
+
+/// Process-wide recorder so every provider instance in a record session
+/// shares one cassette (same pattern as the chaos config singleton).
+fn shared_recorder(path: &Path) -> Arc<CassetteRecorder> {
+    static ACTIVE: OnceLock<Arc<CassetteRecorder>> = OnceLock::new();
+    ACTIVE
+        .get_or_init(|| Arc::new(CassetteRecorder::new(path.to_path_buf())))
+        .clone()
+}
+
+/// Provider wrapper that captures all chat surfaces into the cassette and
+/// otherwise forwards to the inner provider unchanged.
+pub struct RecordingProvider {
+    inner: Box<dyn Provider>,
+    recorder: Arc<CassetteRecorder>,
+}
+
+impl RecordingProvider {
+    fn new(inner: Box<dyn Provider>, recorder: Arc<CassetteRecorder>) -> Self {
+        Self { inner, recorder }
+    }
+
+    fn record_text(&self, key: String, kind: &str, model: &str, text: &str) {
+        self.recorder.append(Interaction {
+            key,
+            kind: kind.to_string(),
+            model: model.to_string(),
+            text: Some(text.to_string()),
+            response: None,
+        });
+    }
+
+    fn record_response(&self, key: String, kind: &str, model: &str, response: &ChatResponse) {
+        self.recorder.append(Interaction {
+            key,
+            kind: kind.to_string(),
+            model: model.to_string(),
+            text: None,
+            response: Some(response.clone()),
+        });
+    }
+}
+
+/// Build the normalized message list for the `chat_with_system` surface so
+/// record and replay hash identically.
+fn system_surface_messages(system_prompt: Option<&str>, message: &str) -> Vec<ChatMessage> {
+    let mut messages = Vec::new();
+    if let Some(system) = system_prompt {
+        messages.push(ChatMessage::system(system));
+    }
+    messages.push(ChatMessage::user(message));
+    messages
+}
+
+#[async_trait]
+impl Provider for RecordingProvider {
+    fn capabilities(&self) -> ProviderCapabilities {
+        self.inner.capabilities()
+    }
+
+    fn convert_tools(&self, tools: &[ToolSpec]) -> ToolsPayload {
+        self.inner.convert_tools(tools)
+    }
+
+    async fn warmup(&self) -> Result<()> {
+        self.inner.warmup().await
+    }
+
+    async fn chat_with_system(
+        &self,
+        system_prompt: Option<&str>,
+        message: &str,
+        model: &str,
+        temperature: f64,
+    ) -> Result<String> {
+        let key = interaction_key(
+            "chat_with_system",
+            model,
+            temperature,
+            &system_surface_messages(system_prompt, message),
+            "",
+        );
+        let text = self
+            .inner
+            .chat_with_system(system_prompt, message, model, temperature)
+            .await?;
+        self.record_text(key, "chat_with_system", model, &text);
+        Ok(text)
+    }
+
+    async fn chat_with_history(
+        &self,
+        messages: &[ChatMessage],
+        model: &str,
+        temperature: f64,
+    ) -> Result<String> {
+        let key = interaction_key("chat_with_history", model, temperature, messages, "");
+        let text = self
+            .inner
+            .chat_with_history(messages, model, temperature)
+            .await?;
+        self.record_text(key, "chat_with_history", model, &text);
+        Ok(text)
+    }
+
+    async fn chat(
+        &self,
+        request: ChatRequest<'_>,
+        model: &str,
+        temperature: f64,
+    ) -> Result<ChatResponse> {
+        let key = interaction_key(
+            "chat",
+            model,
+            temperature,
+            request.messages,
+            &tool_specs_state(request.tools),
+        );
+        let response = self.inner.chat(request, model, temperature).await?;
+        self.record_response(key, "chat", model, &response);
+        Ok(response)
+    }
+
+    async fn chat_with_tools(
+        &self,
+        messages: &[ChatMessage],
+        tools: &[serde_json::Value],
+        model: &str,
+        temperature: f64,
+    ) -> Result<ChatResponse> {
+        let tool_state = tools
+            .iter()
+            .map(std::string::ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("\0");
+        let key = interaction_key("chat_with_tools", model, temperature, messages, &tool_state);
+        let response = self
+            .inner
+            .chat_with_tools(messages, tools, model, temperature)
+            .await?;
+        self.record_response(key, "chat_with_tools", model, &response);
+        Ok(response)
+    }
+
+    async fn chat_structured(
+        &self,
+        messages: &[ChatMessage],
+        schema: &serde_json::Value,
+        model: &str,
+        temperature: f64,
+    ) -> Result<String> {
+        let key = interaction_key(
+            "chat_structured",
+            model,
+            temperature,
+            messages,
+            &schema.to_string(),
+        );
+        let text = self
+            .inner
+            .chat_structured(messages, schema, model, temperature)
+            .await?;
+        self.record_text(key, "chat_structured", model, &text);
+        Ok(text)
+    }
+
+    fn supports_native_tools(&self) -> bool {
+        self.inner.supports_native_tools()
+    }
+
+    fn supports_vision(&self) -> bool {
+        self.inner.supports_vision()
+    }
+
+    fn supports_structured_output(&self) -> bool {
+        self.inner.supports_structured_output()
+    }
+
+    fn supports_streaming(&self) -> bool {
+        self.inner.supports_streaming()
+    }
+
+    fn stream_chat_with_system(
+        &self,
+        system_prompt: Option<&str>,
+        message: &str,
+        model: &str,
+        temperature: f64,
+        options: StreamOptions,
+    ) -> stream::BoxStream<'static, StreamResult<StreamChunk>> {
+        self.inner
+            .stream_chat_with_system(system_prompt, message, model, temperature, options)
+    }
+
+    fn stream_chat_with_history(
+        &self,
+        messages: &[ChatMessage],
+        model: &str,
+        temperature: f64,
+        options: StreamOptions,
+    ) -> stream::BoxStream<'static, StreamResult<StreamChunk>> {
+        self.inner
+            .stream_chat_with_history(messages, model, temperature, options)
+    }
+}
+
+/// Offline provider that serves recorded interactions back deterministically.
+/// Requests with no recorded match fail explicitly — never a live call.
+pub struct ReplayProvider {
+    path: PathBuf,
+    interactions: Mutex<HashMap<String, VecDeque<Interaction>>>,
+}
+
+impl ReplayProvider {
+    /// Load a cassette from disk.
+    pub fn load(path: &Path) -> Result<Self> {
+        let raw = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read cassette: {}", path.display()))?;
+        let cassette: Cassette = serde_json::from_str(&raw)
+            .with_context(|| format!("Invalid cassette JSON: {}", path.display()))?;
+
+        let mut interactions: HashMap<String, VecDeque<Interaction>> = HashMap::new();
+        for interaction in cassette.interactions {
+            interactions
+                .entry(interaction.key.clone())
+                .or_default()
+                .push_back(interaction);
+        }
+        Ok(Self {
+            path: path.to_path_buf(),
+            interactions: Mutex::new(interactions),
+        })
+    }
+
+    /// Take the next recorded interaction for `key`, in recorded order.
+    fn take(&self, key: &str, kind: &str, model: &str) -> Result<Interaction> {
+        let mut interactions = self
+            .interactions
+            .lock()
+            .map_err(|_| anyhow::anyhow!("cassette state poisoned"))?;
+        let next = interactions.get_mut(key).and_then(VecDeque::pop_front);
+        next.with_context(|| {
+            format!(
+                "No recorded interaction matches this {kind} request (model '{model}') in cassette {} — re-record with --record",
+                self.path.display()
+            )
+        })
+    }
+
+    fn take_text(&self, key: &str, kind: &str, model: &str) -> Result<String> {
+        let interaction = self.take(key, kind, model)?;
+        interaction.text.with_context(|| {
+            format!(
+                "Recorded {kind} interaction in cassette {} has no text payload",
+                self.path.display()
+            )
+        })
+    }
+
+    fn take_response(&self, key: &str, kind: &str, model: &str) -> Result<ChatResponse> {
+        let interaction = self.take(key, kind, model)?;
+        interaction.response.with_context(|| {
+            format!(
+                "Recorded {kind} interaction in cassette {} has no response payload",
+                self.path.display()
+            )
+        })
+    }
+}
+
+#[async_trait]
+impl Provider for ReplayProvider {
+    fn capabilities(&self) -> ProviderCapabilities {
+        // Permissive: replay serves whatever was recorded, so capability
+        // gates must not reject flows the cassette actually contains.
+        ProviderCapabilities {
+            native_tool_calling: true,
+            vision: true,
+            structured_output: false,
+        }
+    }
+
+    async fn chat_with_system(
+        &self,
+        system_prompt: Option<&str>,
+        message: &str,
+        model: &str,
+        temperature: f64,
+    ) -> Result<String> {
+        let key = interaction_key(
+            "chat_with_system",
+            model,
+            temperature,
+            &system_surface_messages(system_prompt, message),
+            "",
+        );
+        self.take_text(&key, "chat_with_system", model)
+    }
+
+    async fn chat_with_history(
+        &self,
+        messages: &[ChatMessage],
+        model: &str,
+        temperature: f64,
+    ) -> Result<String> {
+        let key = interaction_key("chat_with_history", model, temperature, messages, "");
+        self.take_text(&key, "chat_with_history", model)
+    }
+
+    async fn chat(
+        &self,
+        request: ChatRequest<'_>,
+        model: &str,
+        temperature: f64,
+    ) -> Result<ChatResponse> {
+        let key = interaction_key(
+            "chat",
+            model,
+            temperature,
+            request.messages,
+            &tool_specs_state(request.tools),
+        );
+        self.take_response(&key, "chat", model)
+    }
+
+    async fn chat_with_tools(
+        &self,
+        messages: &[ChatMessage],
+        tools: &[serde_json::Value],
+        model: &str,
+        temperature: f64,
+    ) -> Result<ChatResponse> {
+        let tool_state = tools
+            .iter()
+            .map(std::string::ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("\0");
+        let key = interaction_key("chat_with_tools", model, temperature, messages, &tool_state);
+        self.take_response(&key, "chat_with_tools", model)
+    }
+
+    async fn chat_structured(
+        &self,
+        messages: &[ChatMessage],
+        schema: &serde_json::Value,
+        model: &str,
+        temperature: f64,
+    ) -> Result<String> {
+        let key = interaction_key(
+            "chat_structured",
+            model,
+            temperature,
+            messages,
+            &schema.to_string(),
+        );
+        self.take_text(&key, "chat_structured", model)
+    }
+
+    fn supports_streaming(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct ScriptedProvider {
+        replies: Mutex<VecDeque<String>>,
+    }
+
+    impl ScriptedProvider {
+        fn new(replies: &[&str]) -> Self {
+            Self {
+                replies: Mutex::new(replies.iter().map(|r| (*r).to_string()).collect()),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl Provider for ScriptedProvider {
+        async fn chat_with_system(
+            &self,
+            _system_prompt: Option<&str>,
+            _message: &str,
+            _model: &str,
+            _temperature: f64,
+        ) -> Result<String> {
+            self.replies
+                .lock()
+                .unwrap()
+                .pop_front()
+                .context("scripted provider exhausted")
+        }
+    }
+
+    fn recording(tmp: &tempfile::TempDir, replies: &[&str]) -> (RecordingProvider, PathBuf) {
+        let path = tmp.path().join("cassette.json");
+        let recorder = Arc::new(CassetteRecorder::new(path.clone()));
+        let provider = RecordingProvider::new(Box::new(ScriptedProvider::new(replies)), recorder);
+        (provider, path)
+    }
+
+    #[tokio::test]
+    async fn record_then_replay_round_trips_chat_surfaces() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let (provider, path) = recording(&tmp, &["first answer", "second answer"]);
+
+        provider
+            .chat_with_system(Some("system"), "hello", "model-a", 0.7)
+            .await
+            .unwrap();
+        let history = vec![ChatMessage::user("follow-up")];
+        provider
+            .chat_with_history(&history, "model-a", 0.7)
+            .await
+            .unwrap();
+
+        let replay = ReplayProvider::load(&path).unwrap();
+        assert_eq!(
+            replay
+                .chat_with_system(Some("system"), "hello", "model-a", 0.7)
+                .await
+                .unwrap(),
+            "first answer"
+        );
+        assert_eq!(
+            replay
+                .chat_with_history(&history, "model-a", 0.7)
+                .await
+                .unwrap(),
+            "second answer"
+        );
+    }
+
+    #[tokio::test]
+    async fn replay_serves_repeated_identical_requests_in_recorded_order() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let (provider, path) = recording(&tmp, &["take one", "take two"]);
+
+        for _ in 0..2 {
+            provider
+                .chat_with_system(None, "same prompt", "model-a", 0.7)
+                .await
+                .unwrap();
+        }
+
+        let replay = ReplayProvider::load(&path).unwrap();
+        assert_eq!(
+            replay
+                .chat_with_system(None, "same prompt", "model-a", 0.7)
+                .await
+                .unwrap(),
+            "take one"
+        );
+        assert_eq!(
+            replay
+                .chat_with_system(None, "same prompt", "model-a", 0.7)
+                .await
+                .unwrap(),
+            "take two"
+        );
+    }
+
+    #[tokio::test]
+    async fn replay_fails_explicitly_for_unrecorded_requests() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let (provider, path) = recording(&tmp, &["answer"]);
+        provider
+            .chat_with_system(None, "recorded prompt", "model-a", 0.7)
+            .await
+            .unwrap();
+
+        let replay = ReplayProvider::load(&path).unwrap();
+        let err = replay
+            .chat_with_system(None, "different prompt", "model-a", 0.7)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("No recorded interaction"));
+    }
+
+    #[tokio::test]
+    async fn interaction_key_distinguishes_model_messages_and_surface() {
+        let messages = vec![ChatMessage::user("prompt")];
+        let base = interaction_key("chat_with_history", "model-a", 0.7, &messages, "");
+
+        assert_ne!(
+            base,
+            interaction_key("chat_with_history", "model-b", 0.7, &messages, "")
+        );
+        assert_ne!(
+            base,
+            interaction_key(
+                "chat_with_history",
+                "model-a",
+                0.7,
+                &[ChatMessage::user("other")],
+                ""
+            )
+        );
+        assert_ne!(
+            base,
+            interaction_key("chat_with_system", "model-a", 0.7, &messages, "")
+        );
+        assert_ne!(
+            base,
+            interaction_key("chat_with_history", "model-a", 0.7, &messages, "tools")
+        );
+    }
+
+    #[test]
+    fn load_rejects_missing_or_invalid_cassettes() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let missing = tmp.path().join("missing.json");
+        assert!(ReplayProvider::load(&missing).is_err());
+
+        let invalid = tmp.path().join("invalid.json");
+        std::fs::write(&invalid, "not json").unwrap();
+        let err = ReplayProvider::load(&invalid)
+            .err()
+            .expect("load must fail");
+        assert!(err.to_string().contains("Invalid cassette JSON"));
+    }
+}
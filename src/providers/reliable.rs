@@ -4,7 +4,12 @@ use async_trait::async_trait;
 use futures_util::{stream, StreamExt};
 use std::collections::HashMap;
 use std::sync::atomic::{AtomicUsize, Ordering};
-use std::time::Duration;
+use std::time::{Duration, Instant};
+
+/// Consecutive failures against one provider before its circuit opens.
+const DEFAULT_CIRCUIT_BREAKER_FAILURES: u32 = 5;
+/// How long an open circuit skips its provider before a half-open probe.
+const DEFAULT_CIRCUIT_BREAKER_COOLDOWN_SECS: u64 = 30;
 
 // ── Error Classification ─────────────────────────────────────────────────
 // Errors are split into retryable (transient server/network failures) and
@@ -209,6 +214,80 @@ fn push_failure(
     ));
 }
 
+// ── Circuit Breaker ──────────────────────────────────────────────────────
+// Trips open after N consecutive failed attempts against one provider, then
+// skips that provider until the cooldown elapses. The first call after the
+// cooldown acts as a half-open probe: success closes the circuit, failure
+// re-opens it immediately. Transitions are mirrored into the health registry
+// as `provider:<name>` components so they surface in
+// `zeroclaw status --components`.
+
+struct CircuitBreaker {
+    failure_threshold: u32,
+    cooldown: Duration,
+    state: parking_lot::Mutex<HashMap<String, BreakerState>>,
+}
+
+#[derive(Default)]
+struct BreakerState {
+    consecutive_failures: u32,
+    open_until: Option<Instant>,
+}
+
+impl CircuitBreaker {
+    fn new(failure_threshold: u32, cooldown: Duration) -> Self {
+        Self {
+            failure_threshold: failure_threshold.max(1),
+            cooldown,
+            state: parking_lot::Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// True while the provider's circuit is open and still cooling down.
+    fn is_open(&self, provider: &str) -> bool {
+        let state = self.state.lock();
+        state
+            .get(provider)
+            .and_then(|s| s.open_until)
+            .is_some_and(|until| Instant::now() < until)
+    }
+
+    fn record_success(&self, provider: &str) {
+        let mut state = self.state.lock();
+        let entry = state.entry(provider.to_owned()).or_default();
+        let was_open = entry.open_until.is_some();
+        entry.consecutive_failures = 0;
+        entry.open_until = None;
+        drop(state);
+        if was_open {
+            tracing::info!(provider, "Circuit breaker closed after successful probe");
+        }
+        crate::health::mark_component_ok(&format!("provider:{provider}"));
+    }
+
+    fn record_failure(&self, provider: &str) {
+        let mut state = self.state.lock();
+        let entry = state.entry(provider.to_owned()).or_default();
+        entry.consecutive_failures = entry.consecutive_failures.saturating_add(1);
+        let failures = entry.consecutive_failures;
+        if failures < self.failure_threshold {
+            return;
+        }
+        entry.open_until = Some(Instant::now() + self.cooldown);
+        drop(state);
+        tracing::warn!(
+            provider,
+            consecutive_failures = failures,
+            cooldown_secs = self.cooldown.as_secs(),
+            "Circuit breaker tripped; skipping provider while cooling down"
+        );
+        crate::health::mark_component_error(
+            &format!("provider:{provider}"),
+            format!("circuit open after {failures} consecutive failures"),
+        );
+    }
+}
+
 // ── Resilient Provider Wrapper ────────────────────────────────────────────
 // Three-level failover strategy: model chain → provider chain → retry loop.
 //   Outer loop:  iterate model fallback chain (original model first, then
@@ -229,6 +308,8 @@ pub struct ReliableProvider {
     key_index: AtomicUsize,
     /// Per-model fallback chains: model_name → [fallback_model_1, fallback_model_2, ...]
     model_fallbacks: HashMap<String, Vec<String>>,
+    /// Per-provider circuit breaker shared across all call paths.
+    breaker: CircuitBreaker,
 }
 
 impl ReliableProvider {
@@ -244,9 +325,19 @@ impl ReliableProvider {
             api_keys: Vec::new(),
             key_index: AtomicUsize::new(0),
             model_fallbacks: HashMap::new(),
+            breaker: CircuitBreaker::new(
+                DEFAULT_CIRCUIT_BREAKER_FAILURES,
+                Duration::from_secs(DEFAULT_CIRCUIT_BREAKER_COOLDOWN_SECS),
+            ),
         }
     }
 
+    /// Override the circuit breaker's failure threshold and cooldown.
+    pub fn with_circuit_breaker(mut self, failure_threshold: u32, cooldown_secs: u64) -> Self {
+        self.breaker = CircuitBreaker::new(failure_threshold, Duration::from_secs(cooldown_secs));
+        self
+    }
+
     /// Set additional API keys for round-robin rotation on rate-limit errors.
     pub fn with_api_keys(mut self, keys: Vec<String>) -> Self {
         self.api_keys = keys;
@@ -278,12 +369,17 @@ impl ReliableProvider {
     }
 
     /// Compute backoff duration, respecting Retry-After if present.
+    ///
+    /// Adds up to 250ms of jitter so concurrent callers retrying the same
+    /// provider do not synchronize into a thundering herd (same pattern as
+    /// the scheduler's retry path).
     fn compute_backoff(&self, base: u64, err: &anyhow::Error) -> u64 {
+        let jitter_ms = u64::from(chrono::Utc::now().timestamp_subsec_millis() % 250);
         if let Some(retry_after) = parse_retry_after_ms(err) {
             // Use Retry-After but cap at 30s to avoid indefinite waits
-            retry_after.min(30_000).max(base)
+            retry_after.min(30_000).max(base) + jitter_ms
         } else {
-            base
+            base + jitter_ms
         }
     }
 }
@@ -316,6 +412,12 @@ impl Provider for ReliableProvider {
         // retryable error, sleep with exponential backoff and retry.
         for current_model in &models {
             for (provider_name, provider) in &self.providers {
+                if self.breaker.is_open(provider_name) {
+                    failures.push(format!(
+                        "provider={provider_name} model={current_model}: circuit_open; skipped while cooling down"
+                    ));
+                    continue;
+                }
                 let mut backoff_ms = self.base_backoff_ms;
 
                 for attempt in 0..=self.max_retries {
@@ -324,6 +426,7 @@ impl Provider for ReliableProvider {
                         .await
                     {
                         Ok(resp) => {
+                            self.breaker.record_success(provider_name);
                             if attempt > 0 || *current_model != model {
                                 tracing::info!(
                                     provider = provider_name,
@@ -336,6 +439,7 @@ impl Provider for ReliableProvider {
                             return Ok(resp);
                         }
                         Err(e) => {
+                            self.breaker.record_failure(provider_name);
                             let non_retryable_rate_limit = is_non_retryable_rate_limit(&e);
                             let non_retryable = is_non_retryable(&e) || non_retryable_rate_limit;
                             let rate_limited = is_rate_limited(&e);
@@ -436,6 +540,12 @@ impl Provider for ReliableProvider {
 
         for current_model in &models {
             for (provider_name, provider) in &self.providers {
+                if self.breaker.is_open(provider_name) {
+                    failures.push(format!(
+                        "provider={provider_name} model={current_model}: circuit_open; skipped while cooling down"
+                    ));
+                    continue;
+                }
                 let mut backoff_ms = self.base_backoff_ms;
 
                 for attempt in 0..=self.max_retries {
@@ -444,6 +554,7 @@ impl Provider for ReliableProvider {
                         .await
                     {
                         Ok(resp) => {
+                            self.breaker.record_success(provider_name);
                             if attempt > 0 || *current_model != model {
                                 tracing::info!(
                                     provider = provider_name,
@@ -456,6 +567,7 @@ impl Provider for ReliableProvider {
                             return Ok(resp);
                         }
                         Err(e) => {
+                            self.breaker.record_failure(provider_name);
                             let non_retryable_rate_limit = is_non_retryable_rate_limit(&e);
                             let non_retryable = is_non_retryable(&e) || non_retryable_rate_limit;
                             let rate_limited = is_rate_limited(&e);
@@ -560,6 +672,12 @@ impl Provider for ReliableProvider {
 
         for current_model in &models {
             for (provider_name, provider) in &self.providers {
+                if self.breaker.is_open(provider_name) {
+                    failures.push(format!(
+                        "provider={provider_name} model={current_model}: circuit_open; skipped while cooling down"
+                    ));
+                    continue;
+                }
                 let mut backoff_ms = self.base_backoff_ms;
 
                 for attempt in 0..=self.max_retries {
@@ -568,6 +686,7 @@ impl Provider for ReliableProvider {
                         .await
                     {
                         Ok(resp) => {
+                            self.breaker.record_success(provider_name);
                             if attempt > 0 || *current_model != model {
                                 tracing::info!(
                                     provider = provider_name,
@@ -580,6 +699,7 @@ impl Provider for ReliableProvider {
                             return Ok(resp);
                         }
                         Err(e) => {
+                            self.breaker.record_failure(provider_name);
                             let non_retryable_rate_limit = is_non_retryable_rate_limit(&e);
                             let non_retryable = is_non_retryable(&e) || non_retryable_rate_limit;
                             let rate_limited = is_rate_limited(&e);
@@ -677,6 +797,9 @@ impl Provider for ReliableProvider {
             if !provider.supports_streaming() || !options.enabled {
                 continue;
             }
+            if self.breaker.is_open(provider_name) {
+                continue;
+            }
 
             // Clone provider data for the stream
             let provider_clone = provider_name.clone();
@@ -1309,25 +1432,107 @@ mod tests {
         );
     }
 
+    // Backoff values carry up to 250ms of jitter, so assertions check the
+    // [expected, expected + 250) band instead of exact equality.
     #[test]
     fn compute_backoff_uses_retry_after() {
         let provider = ReliableProvider::new(vec![], 0, 500);
         let err = anyhow::anyhow!("429 Retry-After: 3");
-        assert_eq!(provider.compute_backoff(500, &err), 3000);
+        let wait = provider.compute_backoff(500, &err);
+        assert!((3000..3250).contains(&wait), "wait = {wait}");
     }
 
     #[test]
     fn compute_backoff_caps_at_30s() {
         let provider = ReliableProvider::new(vec![], 0, 500);
         let err = anyhow::anyhow!("429 Retry-After: 120");
-        assert_eq!(provider.compute_backoff(500, &err), 30_000);
+        let wait = provider.compute_backoff(500, &err);
+        assert!((30_000..30_250).contains(&wait), "wait = {wait}");
     }
 
     #[test]
     fn compute_backoff_falls_back_to_base() {
         let provider = ReliableProvider::new(vec![], 0, 500);
         let err = anyhow::anyhow!("500 Server Error");
-        assert_eq!(provider.compute_backoff(500, &err), 500);
+        let wait = provider.compute_backoff(500, &err);
+        assert!((500..750).contains(&wait), "wait = {wait}");
+    }
+
+    // ── Circuit breaker tests ────────────────────────────────
+
+    #[test]
+    fn circuit_breaker_opens_after_threshold_and_recovers() {
+        let breaker = CircuitBreaker::new(3, Duration::from_secs(60));
+        let provider = format!("test-{}", uuid::Uuid::new_v4());
+
+        breaker.record_failure(&provider);
+        breaker.record_failure(&provider);
+        assert!(!breaker.is_open(&provider), "below threshold stays closed");
+
+        breaker.record_failure(&provider);
+        assert!(
+            breaker.is_open(&provider),
+            "threshold failure opens circuit"
+        );
+
+        breaker.record_success(&provider);
+        assert!(!breaker.is_open(&provider), "success closes the circuit");
+    }
+
+    #[test]
+    fn circuit_breaker_success_resets_consecutive_failures() {
+        let breaker = CircuitBreaker::new(3, Duration::from_secs(60));
+        let provider = format!("test-{}", uuid::Uuid::new_v4());
+
+        breaker.record_failure(&provider);
+        breaker.record_failure(&provider);
+        breaker.record_success(&provider);
+        breaker.record_failure(&provider);
+        breaker.record_failure(&provider);
+        assert!(
+            !breaker.is_open(&provider),
+            "failure streak must be consecutive to trip"
+        );
+    }
+
+    #[test]
+    fn circuit_breaker_cooldown_elapse_allows_probe() {
+        let breaker = CircuitBreaker::new(1, Duration::from_millis(0));
+        let provider = format!("test-{}", uuid::Uuid::new_v4());
+
+        breaker.record_failure(&provider);
+        assert!(
+            !breaker.is_open(&provider),
+            "zero cooldown means the probe is allowed immediately"
+        );
+    }
+
+    #[tokio::test]
+    async fn open_circuit_skips_provider_and_reports_in_failures() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let provider = ReliableProvider::new(
+            vec![(
+                "always-fails".into(),
+                Box::new(MockProvider {
+                    calls,
+                    fail_until_attempt: usize::MAX,
+                    response: "never",
+                    error: "500 Server Error",
+                }),
+            )],
+            0,
+            50,
+        )
+        .with_circuit_breaker(1, 3600);
+
+        // First call trips the breaker (threshold 1, no retries).
+        let first = provider.chat_with_system(None, "hi", "model-x", 0.0).await;
+        assert!(first.is_err());
+
+        // Second call must skip the provider entirely and say why.
+        let second = provider.chat_with_system(None, "hi", "model-x", 0.0).await;
+        let msg = second.unwrap_err().to_string();
+        assert!(msg.contains("circuit_open"), "unexpected error: {msg}");
     }
 
     // ── §2.1 API auth error (401/403) tests ──────────────────
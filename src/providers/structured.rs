@@ -0,0 +1,384 @@
+//! Structured output: JSON-Schema-conforming responses from any provider.
+//!
+//! Callers (tools, workflows, the gateway) request a response that conforms
+//! to a JSON Schema via [`generate_structured`]. Providers that can enforce a
+//! schema natively (capability `structured_output`) do so in one request;
+//! everyone else gets prompt-guided generation with validate-and-repair
+//! retries against a local validator.
+//!
+//! The validator supports the schema subset the runtime actually uses:
+//! `type`, `properties`, `required`, `items`, `enum`, and
+//! `additionalProperties: false`. Unknown keywords are ignored rather than
+//! rejected so provider-authored schemas keep working.
+
+use super::traits::{ChatMessage, Provider};
+use serde_json::Value;
+
+/// Default number of validate-and-repair retries after the first attempt.
+pub const DEFAULT_REPAIR_ATTEMPTS: usize = 2;
+
+/// Build the prompt-injection text instructing the model to emit schema-conforming JSON.
+pub fn build_structured_output_instructions(schema: &Value) -> String {
+    format!(
+        "Respond with a single JSON value that conforms to this JSON Schema. \
+         Output only the JSON — no prose, no markdown fences.\n\nSchema:\n{}",
+        serde_json::to_string_pretty(schema).unwrap_or_else(|_| schema.to_string())
+    )
+}
+
+/// Extract the first JSON value from raw model output.
+///
+/// Tolerates markdown fences and surrounding prose by scanning for the first
+/// balanced `{...}` or `[...]` region.
+pub fn extract_json_payload(text: &str) -> Option<Value> {
+    let trimmed = text.trim();
+    if let Ok(value) = serde_json::from_str::<Value>(trimmed) {
+        return Some(value);
+    }
+
+    let start = trimmed.find(['{', '['])?;
+    let bytes = trimmed.as_bytes();
+    let (open, close) = if bytes[start] == b'{' {
+        (b'{', b'}')
+    } else {
+        (b'[', b']')
+    };
+
+    let mut depth = 0usize;
+    let mut in_string = false;
+    let mut escaped = false;
+    for (offset, &byte) in bytes.iter().enumerate().skip(start) {
+        if in_string {
+            if escaped {
+                escaped = false;
+            } else if byte == b'\\' {
+                escaped = true;
+            } else if byte == b'"' {
+                in_string = false;
+            }
+            continue;
+        }
+        match byte {
+            b'"' => in_string = true,
+            b if b == open => depth += 1,
+            b if b == close => {
+                depth = depth.saturating_sub(1);
+                if depth == 0 {
+                    return serde_json::from_str(&trimmed[start..=offset]).ok();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+fn json_type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(n) => {
+            if n.is_i64() || n.is_u64() {
+                "integer"
+            } else {
+                "number"
+            }
+        }
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+fn type_matches(value: &Value, expected: &str) -> bool {
+    match expected {
+        // Every integer is also a number.
+        "number" => value.is_number(),
+        other => json_type_name(value) == other,
+    }
+}
+
+fn validate_at(value: &Value, schema: &Value, path: &str, errors: &mut Vec<String>) {
+    let Some(schema_obj) = schema.as_object() else {
+        return;
+    };
+
+    if let Some(expected) = schema_obj.get("type").and_then(Value::as_str) {
+        if !type_matches(value, expected) {
+            errors.push(format!(
+                "{path}: expected type '{expected}', got '{}'",
+                json_type_name(value)
+            ));
+            return;
+        }
+    }
+
+    if let Some(allowed) = schema_obj.get("enum").and_then(Value::as_array) {
+        if !allowed.contains(value) {
+            errors.push(format!("{path}: value not in enum {allowed:?}"));
+        }
+    }
+
+    if let (Some(object), Some(properties)) = (
+        value.as_object(),
+        schema_obj.get("properties").and_then(Value::as_object),
+    ) {
+        for (key, property_schema) in properties {
+            if let Some(property_value) = object.get(key) {
+                validate_at(
+                    property_value,
+                    property_schema,
+                    &format!("{path}.{key}"),
+                    errors,
+                );
+            }
+        }
+
+        if schema_obj.get("additionalProperties") == Some(&Value::Bool(false)) {
+            for key in object.keys() {
+                if !properties.contains_key(key) {
+                    errors.push(format!("{path}: unexpected property '{key}'"));
+                }
+            }
+        }
+    }
+
+    if let (Some(object), Some(required)) = (
+        value.as_object(),
+        schema_obj.get("required").and_then(Value::as_array),
+    ) {
+        for key in required.iter().filter_map(Value::as_str) {
+            if !object.contains_key(key) {
+                errors.push(format!("{path}: missing required property '{key}'"));
+            }
+        }
+    }
+
+    if let (Some(items), Some(item_schema)) = (value.as_array(), schema_obj.get("items")) {
+        for (index, item) in items.iter().enumerate() {
+            validate_at(item, item_schema, &format!("{path}[{index}]"), errors);
+        }
+    }
+}
+
+/// Validate a value against the supported JSON Schema subset.
+/// Returns path-prefixed error messages; empty means valid.
+pub fn validate_against_schema(value: &Value, schema: &Value) -> Vec<String> {
+    let mut errors = Vec::new();
+    validate_at(value, schema, "$", &mut errors);
+    errors
+}
+
+/// Request a schema-conforming response from a provider.
+///
+/// Uses the provider's native structured output when declared; otherwise the
+/// prompt-guided default. Either way the payload is validated locally, and on
+/// failure the model is asked to repair its output up to
+/// `max_repair_attempts` additional times before an explicit error.
+pub async fn generate_structured(
+    provider: &dyn Provider,
+    messages: &[ChatMessage],
+    schema: &Value,
+    model: &str,
+    temperature: f64,
+    max_repair_attempts: usize,
+) -> anyhow::Result<Value> {
+    let mut working_messages = messages.to_vec();
+    let mut last_errors: Vec<String> = Vec::new();
+
+    for _attempt in 0..=max_repair_attempts {
+        let raw = provider
+            .chat_structured(&working_messages, schema, model, temperature)
+            .await?;
+
+        let (payload, errors) = match extract_json_payload(&raw) {
+            Some(payload) => {
+                let errors = validate_against_schema(&payload, schema);
+                (Some(payload), errors)
+            }
+            None => (None, vec!["response did not contain JSON".to_string()]),
+        };
+
+        if errors.is_empty() {
+            if let Some(payload) = payload {
+                return Ok(payload);
+            }
+        }
+
+        working_messages.push(ChatMessage::assistant(raw));
+        working_messages.push(ChatMessage::user(format!(
+            "Your previous response did not conform to the required JSON Schema:\n- {}\n\
+             Reply again with only the corrected JSON.",
+            errors.join("\n- ")
+        )));
+        last_errors = errors;
+    }
+
+    anyhow::bail!(
+        "structured output failed schema validation after {} attempt(s): {}",
+        max_repair_attempts + 1,
+        last_errors.join("; ")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::providers::traits::ProviderCapabilities;
+    use async_trait::async_trait;
+    use serde_json::json;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn sample_schema() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "status": {"type": "string", "enum": ["ok", "error"]},
+                "count": {"type": "integer"}
+            },
+            "required": ["status", "count"],
+            "additionalProperties": false
+        })
+    }
+
+    #[test]
+    fn extract_json_payload_handles_fences_and_prose() {
+        assert_eq!(extract_json_payload("{\"a\": 1}"), Some(json!({"a": 1})));
+        assert_eq!(
+            extract_json_payload("Sure! Here you go:\n```json\n{\"a\": 1}\n```"),
+            Some(json!({"a": 1}))
+        );
+        assert_eq!(extract_json_payload("list: [1, 2]"), Some(json!([1, 2])));
+        assert_eq!(
+            extract_json_payload("{\"s\": \"brace } in string\"}"),
+            Some(json!({"s": "brace } in string"}))
+        );
+        assert_eq!(extract_json_payload("no json here"), None);
+    }
+
+    #[test]
+    fn validate_accepts_conforming_value() {
+        let value = json!({"status": "ok", "count": 3});
+        assert!(validate_against_schema(&value, &sample_schema()).is_empty());
+    }
+
+    #[test]
+    fn validate_reports_type_required_enum_and_extra_errors() {
+        let value = json!({"status": "unknown", "extra": true});
+        let errors = validate_against_schema(&value, &sample_schema());
+        assert!(errors.iter().any(|e| e.contains("not in enum")));
+        assert!(errors
+            .iter()
+            .any(|e| e.contains("missing required property 'count'")));
+        assert!(errors
+            .iter()
+            .any(|e| e.contains("unexpected property 'extra'")));
+
+        let wrong_type = json!({"status": "ok", "count": "three"});
+        let errors = validate_against_schema(&wrong_type, &sample_schema());
+        assert!(errors.iter().any(|e| e.contains("expected type 'integer'")));
+    }
+
+    #[test]
+    fn validate_checks_array_items() {
+        let schema = json!({"type": "array", "items": {"type": "integer"}});
+        assert!(validate_against_schema(&json!([1, 2]), &schema).is_empty());
+        let errors = validate_against_schema(&json!([1, "two"]), &schema);
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].contains("$[1]"));
+    }
+
+    #[test]
+    fn integer_values_satisfy_number_type() {
+        let schema = json!({"type": "number"});
+        assert!(validate_against_schema(&json!(3), &schema).is_empty());
+        assert!(validate_against_schema(&json!(3.5), &schema).is_empty());
+    }
+
+    /// Returns invalid JSON on the first call, then a conforming payload.
+    struct RepairMockProvider {
+        calls: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl Provider for RepairMockProvider {
+        fn capabilities(&self) -> ProviderCapabilities {
+            ProviderCapabilities::default()
+        }
+
+        async fn chat_with_system(
+            &self,
+            _system_prompt: Option<&str>,
+            _message: &str,
+            _model: &str,
+            _temperature: f64,
+        ) -> anyhow::Result<String> {
+            let call = self.calls.fetch_add(1, Ordering::SeqCst);
+            if call == 0 {
+                Ok("{\"status\": \"ok\"}".to_string())
+            } else {
+                Ok("{\"status\": \"ok\", \"count\": 2}".to_string())
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn generate_structured_repairs_invalid_first_attempt() {
+        let provider = RepairMockProvider {
+            calls: AtomicUsize::new(0),
+        };
+        let messages = vec![ChatMessage::user("summarize")];
+
+        let value = generate_structured(
+            &provider,
+            &messages,
+            &sample_schema(),
+            "test-model",
+            0.0,
+            DEFAULT_REPAIR_ATTEMPTS,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(value, json!({"status": "ok", "count": 2}));
+        assert_eq!(provider.calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn generate_structured_fails_explicitly_after_retries() {
+        struct AlwaysInvalid;
+        #[async_trait]
+        impl Provider for AlwaysInvalid {
+            async fn chat_with_system(
+                &self,
+                _system_prompt: Option<&str>,
+                _message: &str,
+                _model: &str,
+                _temperature: f64,
+            ) -> anyhow::Result<String> {
+                Ok("not json".to_string())
+            }
+        }
+
+        let err = generate_structured(
+            &AlwaysInvalid,
+            &[ChatMessage::user("go")],
+            &sample_schema(),
+            "test-model",
+            0.0,
+            1,
+        )
+        .await
+        .unwrap_err();
+        assert!(err.to_string().contains("after 2 attempt(s)"));
+        assert!(err.to_string().contains("did not contain JSON"));
+    }
+
+    #[test]
+    fn instructions_embed_the_schema() {
+        let text = build_structured_output_instructions(&sample_schema());
+        assert!(text.contains("JSON Schema"));
+        assert!(text.contains("\"required\""));
+    }
+}
@@ -53,7 +53,7 @@ pub struct ToolCall {
 ///
 /// Populated when the provider exposes usage data (e.g. OpenRouter, Anthropic).
 /// `None` when the provider does not report token counts.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProviderUsage {
     /// Input (prompt) tokens consumed.
     pub prompt_tokens: u64,
@@ -62,7 +62,7 @@ pub struct ProviderUsage {
 }
 
 /// An LLM response that may contain text, tool calls, or both.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ChatResponse {
     /// Text content of the response (may be empty if only tool calls).
     pub text: Option<String>,
@@ -152,9 +152,9 @@ impl StreamChunk {
         }
     }
 
-    /// Estimate tokens (rough approximation: ~4 chars per token).
+    /// Estimate tokens for this chunk (see [`crate::tokens`]).
     pub fn with_token_estimate(mut self) -> Self {
-        self.token_count = self.delta.len().div_ceil(4);
+        self.token_count = crate::tokens::estimate_text(&self.delta);
         self
     }
 }
@@ -230,6 +230,12 @@ pub struct ProviderCapabilities {
     pub native_tool_calling: bool,
     /// Whether the provider supports vision / image inputs.
     pub vision: bool,
+    /// Whether the provider can enforce a JSON Schema natively (e.g. OpenAI
+    /// `response_format: json_schema`).
+    ///
+    /// When `false`, structured output falls back to prompt-guided generation
+    /// with validate-and-repair retries.
+    pub structured_output: bool,
 }
 
 /// Provider-specific tool payload formats.
@@ -383,6 +389,40 @@ pub trait Provider: Send + Sync {
         self.capabilities().vision
     }
 
+    /// Whether provider can enforce a JSON Schema natively.
+    fn supports_structured_output(&self) -> bool {
+        self.capabilities().structured_output
+    }
+
+    /// One completion that should conform to the given JSON Schema.
+    ///
+    /// The default implementation is prompt-guided: schema instructions are
+    /// injected into the system message and the raw text is returned for the
+    /// caller to validate (see `providers::structured::generate_structured`,
+    /// which adds validate-and-repair retries). Providers with native schema
+    /// enforcement should override this and declare `structured_output` in
+    /// their capabilities.
+    async fn chat_structured(
+        &self,
+        messages: &[ChatMessage],
+        schema: &serde_json::Value,
+        model: &str,
+        temperature: f64,
+    ) -> anyhow::Result<String> {
+        let instructions = super::structured::build_structured_output_instructions(schema);
+        let mut modified_messages = messages.to_vec();
+        if let Some(system_message) = modified_messages.iter_mut().find(|m| m.role == "system") {
+            if !system_message.content.is_empty() {
+                system_message.content.push_str("\n\n");
+            }
+            system_message.content.push_str(&instructions);
+        } else {
+            modified_messages.insert(0, ChatMessage::system(instructions));
+        }
+        self.chat_with_history(&modified_messages, model, temperature)
+            .await
+    }
+
     /// Warm up the HTTP connection pool (TLS handshake, DNS, HTTP/2 setup).
     /// Default implementation is a no-op; providers with HTTP clients should override.
     async fn warmup(&self) -> anyhow::Result<()> {
@@ -492,6 +532,7 @@ mod tests {
             ProviderCapabilities {
                 native_tool_calling: true,
                 vision: true,
+                structured_output: false,
             }
         }
 
@@ -583,14 +624,17 @@ mod tests {
         let caps1 = ProviderCapabilities {
             native_tool_calling: true,
             vision: false,
+            structured_output: false,
         };
         let caps2 = ProviderCapabilities {
             native_tool_calling: true,
             vision: false,
+            structured_output: false,
         };
         let caps3 = ProviderCapabilities {
             native_tool_calling: false,
             vision: false,
+            structured_output: false,
         };
 
         assert_eq!(caps1, caps2);
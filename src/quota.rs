@@ -0,0 +1,427 @@
+//! Per-channel and per-sender usage quotas.
+//!
+//! [`QuotaTracker`] enforces the `[quota]` limits (messages per rolling
+//! hour, estimated tokens per UTC day, estimated cost per UTC day) before a
+//! channel message is dispatched to the provider. Each message is counted
+//! against two scopes — the channel it arrived on and the sender who wrote
+//! it — and each scope resolves its limits from the per-scope override with
+//! fallback to the `[quota]` defaults. Token counts come from the heuristic
+//! estimator in `tokens`; cost uses the `[cost].prices` table. Consumption
+//! state persists across restarts in `<workspace>/state/quota/usage.json`
+//! and is visible in `zeroclaw status`.
+
+use crate::config::{ModelPricing, QuotaConfig, QuotaLimits};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+const HOUR_SECS: u64 = 3600;
+
+/// A quota check that failed, with enough context for a friendly reply.
+#[derive(Debug, Clone)]
+pub struct QuotaExceeded {
+    pub scope: String,
+    pub reason: String,
+}
+
+impl QuotaExceeded {
+    /// The reply sent back on the channel instead of a model response.
+    pub fn user_message(&self) -> String {
+        format!(
+            "⏳ Usage quota exceeded for {}: {}. Please try again later.",
+            self.scope, self.reason
+        )
+    }
+}
+
+/// Rolling consumption for one scope (a channel or a sender).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct ScopeUsage {
+    /// Unix timestamps of messages in the last hour (pruned on touch).
+    message_times_unix: Vec<u64>,
+    /// UTC day (`YYYY-MM-DD`) the daily counters belong to.
+    day: String,
+    tokens_today: u64,
+    cost_today_usd: f64,
+}
+
+impl ScopeUsage {
+    /// Drop expired hourly entries and reset daily counters on day roll.
+    fn refresh(&mut self, now: u64, today: &str) {
+        let cutoff = now.saturating_sub(HOUR_SECS);
+        self.message_times_unix.retain(|ts| *ts > cutoff);
+        if self.day != today {
+            self.day = today.to_string();
+            self.tokens_today = 0;
+            self.cost_today_usd = 0.0;
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct QuotaState {
+    scopes: HashMap<String, ScopeUsage>,
+}
+
+/// Enforces `[quota]` limits and persists consumption per workspace.
+pub struct QuotaTracker {
+    config: QuotaConfig,
+    prices: HashMap<String, ModelPricing>,
+    state_path: PathBuf,
+    state: Mutex<QuotaState>,
+}
+
+impl QuotaTracker {
+    /// Build a tracker when quotas are enabled; `None` means no enforcement.
+    pub fn from_config(
+        config: &QuotaConfig,
+        prices: &HashMap<String, ModelPricing>,
+        workspace_dir: &Path,
+    ) -> Option<Self> {
+        if !config.enabled {
+            return None;
+        }
+        Some(Self::new(config.clone(), prices.clone(), workspace_dir))
+    }
+
+    fn new(
+        config: QuotaConfig,
+        prices: HashMap<String, ModelPricing>,
+        workspace_dir: &Path,
+    ) -> Self {
+        let state_path = workspace_dir.join("state").join("quota").join("usage.json");
+        let state = std::fs::read_to_string(&state_path)
+            .ok()
+            .and_then(|raw| serde_json::from_str(&raw).ok())
+            .unwrap_or_default();
+        Self {
+            config,
+            prices,
+            state_path,
+            state: Mutex::new(state),
+        }
+    }
+
+    /// Effective limits for a scope: per-scope override, then `[quota]`
+    /// defaults, then unlimited.
+    fn effective_limits(
+        defaults: &QuotaLimits,
+        scope_override: Option<&QuotaLimits>,
+    ) -> QuotaLimits {
+        QuotaLimits {
+            messages_per_hour: scope_override
+                .and_then(|limits| limits.messages_per_hour)
+                .or(defaults.messages_per_hour),
+            tokens_per_day: scope_override
+                .and_then(|limits| limits.tokens_per_day)
+                .or(defaults.tokens_per_day),
+            cost_per_day_usd: scope_override
+                .and_then(|limits| limits.cost_per_day_usd)
+                .or(defaults.cost_per_day_usd),
+        }
+    }
+
+    fn scope_checks(&self, channel: &str, sender: &str) -> Vec<(String, String, QuotaLimits)> {
+        vec![
+            (
+                format!("channel:{channel}"),
+                format!("channel `{channel}`"),
+                Self::effective_limits(&self.config.defaults, self.config.channels.get(channel)),
+            ),
+            (
+                format!("user:{sender}"),
+                "this user".to_string(),
+                Self::effective_limits(&self.config.defaults, self.config.users.get(sender)),
+            ),
+        ]
+    }
+
+    /// Check whether `channel`/`sender` may send one more message.
+    /// Returns `None` when allowed.
+    pub fn check(&self, channel: &str, sender: &str) -> Option<QuotaExceeded> {
+        let now = now_unix_secs();
+        let today = today_utc();
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+
+        for (key, scope, limits) in self.scope_checks(channel, sender) {
+            let usage = state.scopes.entry(key).or_default();
+            usage.refresh(now, &today);
+
+            if let Some(limit) = limits.messages_per_hour {
+                if usage.message_times_unix.len() >= limit as usize {
+                    return Some(QuotaExceeded {
+                        scope,
+                        reason: format!("message limit reached ({limit} messages/hour)"),
+                    });
+                }
+            }
+            if let Some(limit) = limits.tokens_per_day {
+                if usage.tokens_today >= limit {
+                    return Some(QuotaExceeded {
+                        scope,
+                        reason: format!("daily token limit reached ({limit} tokens/day)"),
+                    });
+                }
+            }
+            if let Some(limit) = limits.cost_per_day_usd {
+                if usage.cost_today_usd >= limit {
+                    return Some(QuotaExceeded {
+                        scope,
+                        reason: format!("daily cost limit reached (${limit:.2}/day)"),
+                    });
+                }
+            }
+        }
+        None
+    }
+
+    /// Record one dispatched message and its estimated token consumption
+    /// against both scopes. Persistence is best-effort.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn record(
+        &self,
+        channel: &str,
+        sender: &str,
+        model: &str,
+        input_tokens: u64,
+        output_tokens: u64,
+    ) {
+        let now = now_unix_secs();
+        let today = today_utc();
+        let cost_usd = self.prices.get(model).map_or(0.0, |pricing| {
+            (input_tokens as f64 / 1_000_000.0) * pricing.input
+                + (output_tokens as f64 / 1_000_000.0) * pricing.output
+        });
+
+        {
+            let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+            for (key, _scope, _limits) in self.scope_checks(channel, sender) {
+                let usage = state.scopes.entry(key).or_default();
+                usage.refresh(now, &today);
+                usage.message_times_unix.push(now);
+                usage.tokens_today += input_tokens + output_tokens;
+                usage.cost_today_usd += cost_usd;
+            }
+        }
+        self.persist();
+    }
+
+    fn persist(&self) {
+        let result = (|| -> anyhow::Result<()> {
+            if let Some(parent) = self.state_path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            let state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+            std::fs::write(&self.state_path, serde_json::to_string_pretty(&*state)?)?;
+            Ok(())
+        })();
+        if let Err(e) = result {
+            tracing::warn!(
+                state = %self.state_path.display(),
+                "Failed to persist quota state: {e}"
+            );
+        }
+    }
+
+    /// Human-readable consumption lines for `zeroclaw status`.
+    pub fn summary_lines(&self) -> Vec<String> {
+        let now = now_unix_secs();
+        let today = today_utc();
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+
+        let mut lines: Vec<String> = state
+            .scopes
+            .iter_mut()
+            .filter_map(|(key, usage)| {
+                usage.refresh(now, &today);
+                if usage.message_times_unix.is_empty() && usage.tokens_today == 0 {
+                    return None;
+                }
+                Some(format!(
+                    "{key}: {} msg(s)/last hour, {} tokens today, ${:.4} today",
+                    usage.message_times_unix.len(),
+                    usage.tokens_today,
+                    usage.cost_today_usd
+                ))
+            })
+            .collect();
+        lines.sort();
+        lines
+    }
+}
+
+fn now_unix_secs() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs())
+}
+
+fn today_utc() -> String {
+    chrono::Utc::now().format("%Y-%m-%d").to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn tracker(config: QuotaConfig, tmp: &TempDir) -> QuotaTracker {
+        let mut prices = HashMap::new();
+        prices.insert(
+            "test-model".to_string(),
+            ModelPricing {
+                input: 1.0,
+                output: 2.0,
+            },
+        );
+        QuotaTracker::new(config, prices, tmp.path())
+    }
+
+    fn enabled_config(defaults: QuotaLimits) -> QuotaConfig {
+        QuotaConfig {
+            enabled: true,
+            defaults,
+            channels: HashMap::new(),
+            users: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn from_config_returns_none_when_disabled() {
+        let tmp = TempDir::new().unwrap();
+        let config = QuotaConfig::default();
+        assert!(QuotaTracker::from_config(&config, &HashMap::new(), tmp.path()).is_none());
+    }
+
+    #[test]
+    fn message_limit_blocks_after_quota_consumed() {
+        let tmp = TempDir::new().unwrap();
+        let tracker = tracker(
+            enabled_config(QuotaLimits {
+                messages_per_hour: Some(2),
+                ..QuotaLimits::default()
+            }),
+            &tmp,
+        );
+
+        assert!(tracker.check("telegram", "user_a").is_none());
+        tracker.record("telegram", "user_a", "test-model", 10, 10);
+        assert!(tracker.check("telegram", "user_a").is_none());
+        tracker.record("telegram", "user_a", "test-model", 10, 10);
+
+        let exceeded = tracker
+            .check("telegram", "user_a")
+            .expect("third message must be blocked");
+        assert!(exceeded.reason.contains("2 messages/hour"), "{exceeded:?}");
+    }
+
+    #[test]
+    fn token_limit_blocks_after_daily_budget_spent() {
+        let tmp = TempDir::new().unwrap();
+        let tracker = tracker(
+            enabled_config(QuotaLimits {
+                tokens_per_day: Some(100),
+                ..QuotaLimits::default()
+            }),
+            &tmp,
+        );
+
+        tracker.record("discord", "user_b", "test-model", 60, 50);
+
+        let exceeded = tracker
+            .check("discord", "user_b")
+            .expect("token budget is spent");
+        assert!(exceeded.reason.contains("token limit"), "{exceeded:?}");
+    }
+
+    #[test]
+    fn cost_limit_uses_model_pricing() {
+        let tmp = TempDir::new().unwrap();
+        let tracker = tracker(
+            enabled_config(QuotaLimits {
+                cost_per_day_usd: Some(0.001),
+                ..QuotaLimits::default()
+            }),
+            &tmp,
+        );
+
+        // 1M input tokens at $1/1M = $1.00, well past the $0.001 cap.
+        tracker.record("slack", "user_c", "test-model", 1_000_000, 0);
+
+        let exceeded = tracker
+            .check("slack", "user_c")
+            .expect("cost budget is spent");
+        assert!(exceeded.reason.contains("cost limit"), "{exceeded:?}");
+    }
+
+    #[test]
+    fn per_user_override_takes_precedence_over_defaults() {
+        let tmp = TempDir::new().unwrap();
+        let mut config = enabled_config(QuotaLimits {
+            messages_per_hour: Some(100),
+            ..QuotaLimits::default()
+        });
+        config.users.insert(
+            "restricted_user".to_string(),
+            QuotaLimits {
+                messages_per_hour: Some(1),
+                ..QuotaLimits::default()
+            },
+        );
+        let tracker = tracker(config, &tmp);
+
+        tracker.record("telegram", "restricted_user", "test-model", 1, 1);
+
+        assert!(tracker.check("telegram", "restricted_user").is_some());
+        assert!(tracker.check("telegram", "other_user").is_none());
+    }
+
+    #[test]
+    fn channel_scope_blocks_all_senders_on_that_channel() {
+        let tmp = TempDir::new().unwrap();
+        let mut config = enabled_config(QuotaLimits::default());
+        config.channels.insert(
+            "telegram".to_string(),
+            QuotaLimits {
+                messages_per_hour: Some(1),
+                ..QuotaLimits::default()
+            },
+        );
+        let tracker = tracker(config, &tmp);
+
+        tracker.record("telegram", "user_a", "test-model", 1, 1);
+
+        assert!(tracker.check("telegram", "user_b").is_some());
+        assert!(tracker.check("discord", "user_b").is_none());
+    }
+
+    #[test]
+    fn state_persists_across_tracker_instances() {
+        let tmp = TempDir::new().unwrap();
+        let config = enabled_config(QuotaLimits {
+            messages_per_hour: Some(1),
+            ..QuotaLimits::default()
+        });
+
+        tracker(config.clone(), &tmp).record("telegram", "user_a", "test-model", 1, 1);
+
+        let reloaded = tracker(config, &tmp);
+        assert!(
+            reloaded.check("telegram", "user_a").is_some(),
+            "consumption must survive a restart"
+        );
+    }
+
+    #[test]
+    fn summary_lines_report_active_scopes() {
+        let tmp = TempDir::new().unwrap();
+        let tracker = tracker(enabled_config(QuotaLimits::default()), &tmp);
+        tracker.record("telegram", "user_a", "test-model", 10, 5);
+
+        let lines = tracker.summary_lines();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("channel:telegram:"), "{lines:?}");
+        assert!(lines[1].starts_with("user:user_a:"), "{lines:?}");
+    }
+}
@@ -1,21 +1,32 @@
 //! Audit logging for security events
+//!
+//! Events are appended as JSONL with a SHA-256 hash chain: each entry
+//! stores the hash of the previous entry plus its own content hash, so
+//! any edit, deletion, or reordering of past entries breaks the chain
+//! and is reported by `zeroclaw audit verify`.
 
 use crate::config::AuditConfig;
-use anyhow::Result;
+use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
 use parking_lot::Mutex;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::fs::OpenOptions;
 use std::io::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use uuid::Uuid;
 
+/// `prev_hash` of the first entry in a fresh audit log.
+pub const GENESIS_HASH: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
 /// Audit event types
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum AuditEventType {
     CommandExecution,
     FileAccess,
+    NetworkAccess,
+    ApprovalDecision,
     ConfigChange,
     AuthSuccess,
     AuthFailure,
@@ -67,6 +78,15 @@ pub struct AuditEvent {
     pub action: Option<Action>,
     pub result: Option<ExecutionResult>,
     pub security: SecurityContext,
+    /// Hash of the previous entry in the chain (hex SHA-256);
+    /// [`GENESIS_HASH`] for the first entry of a fresh log. Set by the
+    /// logger at write time.
+    #[serde(default)]
+    pub prev_hash: String,
+    /// SHA-256 (hex) over this entry serialized with `hash` empty.
+    /// Covers `prev_hash`, which links the entry into the chain.
+    #[serde(default)]
+    pub hash: String,
 }
 
 impl AuditEvent {
@@ -84,6 +104,8 @@ impl AuditEvent {
                 rate_limit_remaining: None,
                 sandbox_backend: None,
             },
+            prev_hash: String::new(),
+            hash: String::new(),
         }
     }
 
@@ -141,13 +163,33 @@ impl AuditEvent {
         self.security.sandbox_backend = sandbox_backend;
         self
     }
+
+    /// Mark this event as a policy violation (blocked action).
+    pub fn with_policy_violation(mut self) -> Self {
+        self.security.policy_violation = true;
+        self
+    }
+}
+
+/// Compute the chain hash for an event: SHA-256 (hex) over the entry
+/// serialized with the `hash` field empty. `prev_hash` is part of the
+/// serialized content, which links each entry to its predecessor.
+fn compute_hash(event: &AuditEvent) -> Result<String> {
+    let mut unsealed = event.clone();
+    unsealed.hash = String::new();
+    let canonical = serde_json::to_string(&unsealed)?;
+    let digest = Sha256::digest(canonical.as_bytes());
+    Ok(hex::encode(digest))
 }
 
 /// Audit logger
+#[derive(Debug)]
 pub struct AuditLogger {
     log_path: PathBuf,
     config: AuditConfig,
-    buffer: Mutex<Vec<AuditEvent>>,
+    /// Hash of the most recently written entry; seeded lazily from the
+    /// tail of the log file so the chain continues across restarts.
+    last_hash: Mutex<Option<String>>,
 }
 
 /// Structured command execution details for audit logging.
@@ -169,11 +211,11 @@ impl AuditLogger {
         Ok(Self {
             log_path,
             config,
-            buffer: Mutex::new(Vec::new()),
+            last_hash: Mutex::new(None),
         })
     }
 
-    /// Log an event
+    /// Log an event, sealing it into the hash chain.
     pub fn log(&self, event: &AuditEvent) -> Result<()> {
         if !self.config.enabled {
             return Ok(());
@@ -182,8 +224,19 @@ impl AuditLogger {
         // Check log size and rotate if needed
         self.rotate_if_needed()?;
 
-        // Serialize and write
-        let line = serde_json::to_string(event)?;
+        // Hold the chain lock across the write so concurrent events
+        // cannot interleave and fork the chain.
+        let mut last_hash = self.last_hash.lock();
+        let prev_hash = match last_hash.take() {
+            Some(hash) => hash,
+            None => self.tail_hash(),
+        };
+
+        let mut sealed = event.clone();
+        sealed.prev_hash = prev_hash;
+        sealed.hash = compute_hash(&sealed)?;
+
+        let line = serde_json::to_string(&sealed)?;
         let mut file = OpenOptions::new()
             .create(true)
             .append(true)
@@ -192,9 +245,25 @@ impl AuditLogger {
         writeln!(file, "{}", line)?;
         file.sync_all()?;
 
+        *last_hash = Some(sealed.hash);
         Ok(())
     }
 
+    /// Hash of the last entry currently in the log file, or
+    /// [`GENESIS_HASH`] when the file is empty or missing.
+    fn tail_hash(&self) -> String {
+        let Ok(content) = std::fs::read_to_string(&self.log_path) else {
+            return GENESIS_HASH.to_string();
+        };
+        content
+            .lines()
+            .rev()
+            .find(|line| !line.trim().is_empty())
+            .and_then(|line| serde_json::from_str::<AuditEvent>(line).ok())
+            .filter(|event| !event.hash.is_empty())
+            .map_or_else(|| GENESIS_HASH.to_string(), |event| event.hash)
+    }
+
     /// Log a command execution event.
     pub fn log_command_event(&self, entry: CommandExecutionLog<'_>) -> Result<()> {
         let event = AuditEvent::new(AuditEventType::CommandExecution)
@@ -258,6 +327,192 @@ impl AuditLogger {
     }
 }
 
+/// Outcome of verifying the audit log's hash chain.
+#[derive(Debug)]
+pub struct ChainVerification {
+    /// Number of entries checked.
+    pub entries: usize,
+    /// First broken entry as `(line_number, reason)`; `None` when the
+    /// chain is intact.
+    pub first_error: Option<(usize, String)>,
+}
+
+impl ChainVerification {
+    pub fn is_valid(&self) -> bool {
+        self.first_error.is_none()
+    }
+}
+
+/// Read all audit events from a JSONL log file, oldest first.
+pub fn read_events(log_path: &Path) -> Result<Vec<AuditEvent>> {
+    let content = std::fs::read_to_string(log_path)
+        .with_context(|| format!("cannot read audit log at {}", log_path.display()))?;
+    let mut events = Vec::new();
+    for line in content.lines().filter(|line| !line.trim().is_empty()) {
+        events.push(serde_json::from_str::<AuditEvent>(line).context("invalid audit log entry")?);
+    }
+    Ok(events)
+}
+
+/// Verify the hash chain of an audit log file.
+///
+/// Checks that every entry's stored hash matches its content and that
+/// each entry's `prev_hash` links to the preceding entry. The first
+/// entry's `prev_hash` is accepted as-is: it is [`GENESIS_HASH`] for a
+/// fresh log, or the tail hash of an earlier rotated file.
+pub fn verify_chain(log_path: &Path) -> Result<ChainVerification> {
+    let content = std::fs::read_to_string(log_path)
+        .with_context(|| format!("cannot read audit log at {}", log_path.display()))?;
+    let mut entries = 0;
+    let mut expected_prev: Option<String> = None;
+    for (index, line) in content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+    {
+        entries += 1;
+        let line_number = index + 1;
+        let event: AuditEvent = match serde_json::from_str(line) {
+            Ok(event) => event,
+            Err(e) => {
+                return Ok(ChainVerification {
+                    entries,
+                    first_error: Some((line_number, format!("unparseable entry: {e}"))),
+                });
+            }
+        };
+        if event.hash.is_empty() {
+            return Ok(ChainVerification {
+                entries,
+                first_error: Some((line_number, "entry is not hash-chained".to_string())),
+            });
+        }
+        if let Some(expected) = &expected_prev {
+            if event.prev_hash != *expected {
+                return Ok(ChainVerification {
+                    entries,
+                    first_error: Some((
+                        line_number,
+                        "prev_hash does not match the preceding entry (entries altered, \
+                         removed, or reordered)"
+                            .to_string(),
+                    )),
+                });
+            }
+        }
+        let recomputed = compute_hash(&event)?;
+        if recomputed != event.hash {
+            return Ok(ChainVerification {
+                entries,
+                first_error: Some((
+                    line_number,
+                    "stored hash does not match entry content (entry modified)".to_string(),
+                )),
+            });
+        }
+        expected_prev = Some(event.hash);
+    }
+    Ok(ChainVerification {
+        entries,
+        first_error: None,
+    })
+}
+
+/// Print the most recent audit entries (`zeroclaw audit show`).
+pub fn print_show(log_path: &Path, limit: usize) -> Result<()> {
+    if !log_path.exists() {
+        println!("No audit log found at {}", log_path.display());
+        return Ok(());
+    }
+    let events = read_events(log_path)?;
+    if events.is_empty() {
+        println!("Audit log is empty.");
+        return Ok(());
+    }
+    let start = events.len().saturating_sub(limit);
+    println!(
+        "Audit log: {} entries (showing last {})\n",
+        events.len(),
+        events.len() - start
+    );
+    for event in &events[start..] {
+        let event_type = serde_json::to_value(&event.event_type)
+            .ok()
+            .and_then(|v| v.as_str().map(str::to_owned))
+            .unwrap_or_else(|| "unknown".to_string());
+        let action = event
+            .action
+            .as_ref()
+            .and_then(|a| a.command.as_deref())
+            .unwrap_or("-");
+        let outcome = match &event.result {
+            Some(result) if result.success => "ok",
+            Some(_) => "failed",
+            None => "-",
+        };
+        let violation = if event.security.policy_violation {
+            " [policy violation]"
+        } else {
+            ""
+        };
+        println!(
+            "{} | {:17} | {:6} | {}{}",
+            event.timestamp.format("%Y-%m-%d %H:%M:%S"),
+            event_type,
+            outcome,
+            action,
+            violation
+        );
+    }
+    Ok(())
+}
+
+/// Verify the log and print the result (`zeroclaw audit verify`).
+pub fn print_verify(log_path: &Path) -> Result<()> {
+    if !log_path.exists() {
+        println!("No audit log found at {}", log_path.display());
+        return Ok(());
+    }
+    let verification = verify_chain(log_path)?;
+    match verification.first_error {
+        None => {
+            println!("OK: hash chain intact ({} entries)", verification.entries);
+            Ok(())
+        }
+        Some((line_number, reason)) => {
+            anyhow::bail!("audit log FAILED verification at line {line_number}: {reason}")
+        }
+    }
+}
+
+/// Export the audit log as a JSON array (`zeroclaw audit export`).
+///
+/// Writes to `output` when given, otherwise to stdout. Verifies the
+/// chain first so a tampered log is never exported silently.
+pub fn export(log_path: &Path, output: Option<&Path>) -> Result<()> {
+    let verification = verify_chain(log_path)?;
+    if let Some((line_number, reason)) = verification.first_error {
+        anyhow::bail!(
+            "refusing to export: audit log failed verification at line {line_number}: {reason}"
+        );
+    }
+    let events = read_events(log_path)?;
+    let json = serde_json::to_string_pretty(&events)?;
+    match output {
+        Some(path) => {
+            std::fs::write(path, json)
+                .with_context(|| format!("cannot write export to {}", path.display()))?;
+            println!(
+                "Exported {} verified entries to {}",
+                events.len(),
+                path.display()
+            );
+        }
+        None => println!("{json}"),
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -396,6 +651,125 @@ mod tests {
         Ok(())
     }
 
+    // ── Hash chain tests ────────────────────────────────────
+
+    fn enabled_logger(dir: &Path) -> AuditLogger {
+        let config = AuditConfig {
+            enabled: true,
+            max_size_mb: 10,
+            ..Default::default()
+        };
+        AuditLogger::new(config, dir.to_path_buf()).expect("logger")
+    }
+
+    fn sample_event(command: &str) -> AuditEvent {
+        AuditEvent::new(AuditEventType::CommandExecution)
+            .with_actor("cli".to_string(), None, None)
+            .with_action(command.to_string(), "low".to_string(), false, true)
+            .with_result(true, Some(0), 5, None)
+    }
+
+    #[test]
+    fn log_seals_entries_into_hash_chain() -> Result<()> {
+        let tmp = TempDir::new()?;
+        let logger = enabled_logger(tmp.path());
+        logger.log(&sample_event("echo one"))?;
+        logger.log(&sample_event("echo two"))?;
+
+        let events = read_events(&tmp.path().join("audit.log"))?;
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].prev_hash, GENESIS_HASH);
+        assert_eq!(events[1].prev_hash, events[0].hash);
+        assert!(!events[1].hash.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn verify_passes_for_intact_chain() -> Result<()> {
+        let tmp = TempDir::new()?;
+        let logger = enabled_logger(tmp.path());
+        for i in 0..5 {
+            logger.log(&sample_event(&format!("echo {i}")))?;
+        }
+
+        let verification = verify_chain(&tmp.path().join("audit.log"))?;
+        assert!(verification.is_valid());
+        assert_eq!(verification.entries, 5);
+        Ok(())
+    }
+
+    #[test]
+    fn verify_detects_modified_entry() -> Result<()> {
+        let tmp = TempDir::new()?;
+        let logger = enabled_logger(tmp.path());
+        logger.log(&sample_event("echo safe"))?;
+        logger.log(&sample_event("echo also safe"))?;
+
+        let log_path = tmp.path().join("audit.log");
+        let tampered = std::fs::read_to_string(&log_path)?.replace("echo safe", "rm -rf /");
+        std::fs::write(&log_path, tampered)?;
+
+        let verification = verify_chain(&log_path)?;
+        assert!(!verification.is_valid());
+        let (line_number, reason) = verification.first_error.unwrap();
+        assert_eq!(line_number, 1);
+        assert!(reason.contains("does not match entry content"));
+        Ok(())
+    }
+
+    #[test]
+    fn verify_detects_removed_entry() -> Result<()> {
+        let tmp = TempDir::new()?;
+        let logger = enabled_logger(tmp.path());
+        for i in 0..3 {
+            logger.log(&sample_event(&format!("echo {i}")))?;
+        }
+
+        let log_path = tmp.path().join("audit.log");
+        let content = std::fs::read_to_string(&log_path)?;
+        let without_middle: Vec<&str> = content
+            .lines()
+            .enumerate()
+            .filter(|(i, _)| *i != 1)
+            .map(|(_, line)| line)
+            .collect();
+        std::fs::write(&log_path, without_middle.join("\n"))?;
+
+        let verification = verify_chain(&log_path)?;
+        assert!(!verification.is_valid());
+        let (_, reason) = verification.first_error.unwrap();
+        assert!(reason.contains("prev_hash"));
+        Ok(())
+    }
+
+    #[test]
+    fn chain_continues_across_logger_restarts() -> Result<()> {
+        let tmp = TempDir::new()?;
+        enabled_logger(tmp.path()).log(&sample_event("echo first"))?;
+        enabled_logger(tmp.path()).log(&sample_event("echo second"))?;
+
+        let events = read_events(&tmp.path().join("audit.log"))?;
+        assert_eq!(events[1].prev_hash, events[0].hash);
+        assert!(verify_chain(&tmp.path().join("audit.log"))?.is_valid());
+        Ok(())
+    }
+
+    #[test]
+    fn export_refuses_tampered_log() -> Result<()> {
+        let tmp = TempDir::new()?;
+        let logger = enabled_logger(tmp.path());
+        logger.log(&sample_event("echo ok"))?;
+
+        let log_path = tmp.path().join("audit.log");
+        let tampered = std::fs::read_to_string(&log_path)?.replace("echo ok", "echo edited");
+        std::fs::write(&log_path, tampered)?;
+
+        let result = export(&log_path, Some(&tmp.path().join("out.json")));
+        assert!(result.is_err());
+        assert!(!tmp.path().join("out.json").exists());
+        Ok(())
+    }
+
     #[test]
     fn audit_rotation_creates_numbered_backup() -> Result<()> {
         let tmp = TempDir::new()?;
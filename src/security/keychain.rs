@@ -0,0 +1,320 @@
+//! OS keychain backend for the secret store.
+//!
+//! When `[secrets] backend = "keychain"` is set, secrets are stored in the
+//! operating system's credential vault instead of the encrypted key file:
+//! the macOS Keychain (via the `security` CLI) or the freedesktop Secret
+//! Service on Linux (via `secret-tool`). The config file then holds only an
+//! opaque `keychain:<account>` reference, never ciphertext or plaintext.
+//!
+//! Platform tools are invoked as subprocesses — matching how the repo talks
+//! to `systemctl`, `icacls`, and AppleScript — so no credential-vault client
+//! library is linked into the binary. Secrets are passed over stdin, never
+//! as process arguments. Unsupported platforms error out explicitly.
+
+use anyhow::{Context, Result};
+use std::io::Write as _;
+use std::process::{Command, Stdio};
+
+/// Prefix marking a config value as a keychain reference.
+pub const KEYCHAIN_REF_PREFIX: &str = "keychain:";
+
+/// Service name under which all ZeroClaw entries are registered.
+const KEYCHAIN_SERVICE: &str = "zeroclaw";
+
+/// True when a config value is a `keychain:<account>` reference.
+pub fn is_keychain_ref(value: &str) -> bool {
+    value.starts_with(KEYCHAIN_REF_PREFIX)
+}
+
+/// Extract and validate the account name from a `keychain:<account>` value.
+///
+/// Account names come from the config file and are passed to platform CLI
+/// tools, so they are restricted to a safe charset and must not look like
+/// a command-line flag.
+pub fn account_from_ref(value: &str) -> Result<&str> {
+    let account = value
+        .strip_prefix(KEYCHAIN_REF_PREFIX)
+        .context("Not a keychain reference")?;
+    anyhow::ensure!(
+        is_valid_account(account),
+        "Invalid keychain account name {account:?} — allowed: [A-Za-z0-9._/-], not starting with '-'"
+    );
+    Ok(account)
+}
+
+/// Account-name charset guard (see [`account_from_ref`]).
+fn is_valid_account(account: &str) -> bool {
+    !account.is_empty()
+        && !account.starts_with('-')
+        && account
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '/' | '-'))
+}
+
+/// Store a secret under the given account, overwriting any existing entry.
+pub fn store(account: &str, secret: &str) -> Result<()> {
+    anyhow::ensure!(
+        is_valid_account(account),
+        "Invalid keychain account name {account:?}"
+    );
+    platform::store(account, secret)
+}
+
+/// Look up the secret stored under the given account.
+pub fn lookup(account: &str) -> Result<String> {
+    anyhow::ensure!(
+        is_valid_account(account),
+        "Invalid keychain account name {account:?}"
+    );
+    platform::lookup(account)
+}
+
+#[cfg(target_os = "macos")]
+mod platform {
+    use super::{run_with_stdin, KEYCHAIN_SERVICE};
+    use anyhow::{Context, Result};
+    use std::process::Command;
+
+    pub fn store(account: &str, secret: &str) -> Result<()> {
+        // `security -i` reads commands from stdin, keeping the secret out
+        // of the process argument list.
+        let command = format!(
+            "add-generic-password -U -s {KEYCHAIN_SERVICE} -a {account} -w {}\n",
+            shell_quote(secret)
+        );
+        run_with_stdin(Command::new("security").arg("-i"), &command)
+            .context("Failed to store secret in macOS Keychain")?;
+        Ok(())
+    }
+
+    pub fn lookup(account: &str) -> Result<String> {
+        let output = Command::new("security")
+            .args([
+                "find-generic-password",
+                "-s",
+                KEYCHAIN_SERVICE,
+                "-a",
+                account,
+                "-w",
+            ])
+            .output()
+            .context("Failed to run `security find-generic-password`")?;
+        anyhow::ensure!(
+            output.status.success(),
+            "Keychain entry '{account}' not found (service '{KEYCHAIN_SERVICE}')"
+        );
+        Ok(String::from_utf8(output.stdout)
+            .context("Keychain entry is not valid UTF-8")?
+            .trim_end_matches('\n')
+            .to_string())
+    }
+
+    /// Quote a value for the `security -i` command parser.
+    fn shell_quote(value: &str) -> String {
+        format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod platform {
+    use super::{run_with_stdin, KEYCHAIN_SERVICE};
+    use anyhow::{Context, Result};
+    use std::process::Command;
+
+    pub fn store(account: &str, secret: &str) -> Result<()> {
+        // `secret-tool store` reads the secret from stdin.
+        run_with_stdin(
+            Command::new("secret-tool").args([
+                "store",
+                "--label",
+                &format!("ZeroClaw {account}"),
+                "service",
+                KEYCHAIN_SERVICE,
+                "account",
+                account,
+            ]),
+            secret,
+        )
+        .context(
+            "Failed to store secret via secret-tool — is a Secret Service \
+             (gnome-keyring/KWallet) running and `secret-tool` installed?",
+        )?;
+        Ok(())
+    }
+
+    pub fn lookup(account: &str) -> Result<String> {
+        let output = Command::new("secret-tool")
+            .args(["lookup", "service", KEYCHAIN_SERVICE, "account", account])
+            .output()
+            .context("Failed to run `secret-tool lookup` — is `secret-tool` installed?")?;
+        anyhow::ensure!(
+            output.status.success(),
+            "Keychain entry '{account}' not found (service '{KEYCHAIN_SERVICE}')"
+        );
+        Ok(String::from_utf8(output.stdout)
+            .context("Keychain entry is not valid UTF-8")?
+            .trim_end_matches('\n')
+            .to_string())
+    }
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "linux")))]
+mod platform {
+    use anyhow::Result;
+
+    pub fn store(_account: &str, _secret: &str) -> Result<()> {
+        anyhow::bail!(
+            "The keychain secrets backend is not supported on this platform yet \
+             (supported: macOS Keychain, Linux Secret Service). \
+             Use `[secrets] backend = \"encrypted-file\"`."
+        )
+    }
+
+    pub fn lookup(_account: &str) -> Result<String> {
+        anyhow::bail!(
+            "The keychain secrets backend is not supported on this platform yet \
+             (supported: macOS Keychain, Linux Secret Service). \
+             Use `[secrets] backend = \"encrypted-file\"`."
+        )
+    }
+}
+
+/// Move every encrypted/plaintext secret in the loaded config into the OS
+/// keychain and rewrite the config to hold `keychain:<account>` references.
+///
+/// Requires `[secrets] backend = "keychain"` so newly saved secrets keep
+/// going to the keychain afterwards. Idempotent: values that are already
+/// keychain references are left alone.
+pub async fn migrate_config_to_keychain(config: &crate::config::Config) -> Result<()> {
+    anyhow::ensure!(
+        config.secrets.backend == crate::config::SecretsBackend::Keychain,
+        "Set `backend = \"keychain\"` under `[secrets]` in config.toml first, \
+         then rerun `zeroclaw secrets migrate-keychain`"
+    );
+
+    let mut updated = config.clone();
+    let mut migrated = 0usize;
+
+    move_secret(&mut updated.api_key, "config.api_key", &mut migrated)?;
+    move_secret(
+        &mut updated.composio.api_key,
+        "config.composio.api_key",
+        &mut migrated,
+    )?;
+    move_secret(
+        &mut updated.browser.computer_use.api_key,
+        "config.browser.computer_use.api_key",
+        &mut migrated,
+    )?;
+    move_secret(
+        &mut updated.web_search.brave_api_key,
+        "config.web_search.brave_api_key",
+        &mut migrated,
+    )?;
+    move_secret(
+        &mut updated.storage.provider.config.db_url,
+        "config.storage.provider.config.db_url",
+        &mut migrated,
+    )?;
+    let agent_names: Vec<String> = updated.agents.keys().cloned().collect();
+    for name in agent_names {
+        let account = format!("config.agents.{}.api_key", sanitize_account_part(&name));
+        if let Some(agent) = updated.agents.get_mut(&name) {
+            move_secret(&mut agent.api_key, &account, &mut migrated)?;
+        }
+    }
+
+    if migrated == 0 {
+        println!(
+            "No secrets to migrate — config holds no secrets or they are already in the keychain."
+        );
+        return Ok(());
+    }
+
+    updated.save().await.context("Failed to save config")?;
+    println!("✅ Moved {migrated} secret(s) into the OS keychain.");
+    println!(
+        "   config.toml now holds only keychain references. Once no `enc:`/`enc2:` \
+         values remain anywhere, `.secret_key` can be deleted."
+    );
+    Ok(())
+}
+
+/// Store one (already decrypted) config secret in the keychain and replace
+/// the config value with its reference. No-op for empty values and values
+/// that are already keychain references.
+fn move_secret(value: &mut Option<String>, account: &str, migrated: &mut usize) -> Result<()> {
+    let Some(plaintext) = value.clone() else {
+        return Ok(());
+    };
+    if plaintext.is_empty() || is_keychain_ref(&plaintext) {
+        return Ok(());
+    }
+    store(account, &plaintext).with_context(|| format!("Failed to migrate {account}"))?;
+    *value = Some(format!("{KEYCHAIN_REF_PREFIX}{account}"));
+    *migrated += 1;
+    println!("  🔑 {account}");
+    Ok(())
+}
+
+/// Map an arbitrary identifier (for example an agent name) onto the safe
+/// keychain account charset.
+fn sanitize_account_part(part: &str) -> String {
+    part.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-') {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect()
+}
+
+/// Run a command feeding `input` on stdin; error on non-zero exit.
+#[cfg_attr(not(any(target_os = "macos", target_os = "linux")), allow(dead_code))]
+fn run_with_stdin(command: &mut Command, input: &str) -> Result<()> {
+    let mut child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+    child
+        .stdin
+        .take()
+        .context("Failed to open child stdin")?
+        .write_all(input.as_bytes())?;
+    let status = child.wait()?;
+    anyhow::ensure!(status.success(), "Command exited with {status}");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keychain_ref_roundtrip_parses_account() {
+        assert!(is_keychain_ref("keychain:config.api_key"));
+        assert!(!is_keychain_ref("enc2:abcdef"));
+        assert!(!is_keychain_ref("plaintext"));
+
+        let account = account_from_ref("keychain:config.api_key").unwrap();
+        assert_eq!(account, "config.api_key");
+    }
+
+    #[test]
+    fn account_validation_rejects_unsafe_names() {
+        assert!(account_from_ref("keychain:").is_err());
+        assert!(account_from_ref("keychain:-w").is_err());
+        assert!(account_from_ref("keychain:bad name").is_err());
+        assert!(account_from_ref("keychain:bad;name").is_err());
+        assert!(account_from_ref("keychain:config.agents/helper.api_key").is_ok());
+    }
+
+    #[test]
+    fn store_rejects_invalid_account_before_spawning() {
+        assert!(store("-w", "secret").is_err());
+        assert!(lookup("bad name").is_err());
+    }
+}
@@ -25,10 +25,13 @@ pub mod detect;
 pub mod docker;
 #[cfg(target_os = "linux")]
 pub mod firejail;
+pub mod keychain;
 #[cfg(feature = "sandbox-landlock")]
 pub mod landlock;
 pub mod pairing;
 pub mod policy;
+pub mod policy_engine;
+pub mod redaction;
 pub mod secrets;
 pub mod traits;
 
@@ -40,6 +43,8 @@ pub use detect::create_sandbox;
 pub use pairing::PairingGuard;
 pub use policy::{AutonomyLevel, SecurityPolicy};
 #[allow(unused_imports)]
+pub use redaction::SecretRedactor;
+#[allow(unused_imports)]
 pub use secrets::SecretStore;
 #[allow(unused_imports)]
 pub use traits::{NoopSandbox, Sandbox};
@@ -1,9 +1,11 @@
+use super::audit::{AuditEvent, AuditLogger};
 use chrono::Utc;
 use parking_lot::Mutex;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::io::BufRead;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use std::time::Instant;
 
 /// How much autonomy the agent has
@@ -27,6 +29,17 @@ pub enum CommandRiskLevel {
     High,
 }
 
+impl CommandRiskLevel {
+    /// Lowercase label for logs and audit entries.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Low => "low",
+            Self::Medium => "medium",
+            Self::High => "high",
+        }
+    }
+}
+
 /// Classifies whether a tool operation is read-only or side-effecting.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ToolOperation {
@@ -96,6 +109,12 @@ pub struct SecurityPolicy {
     pub require_approval_for_medium_risk: bool,
     pub block_high_risk_commands: bool,
     pub tracker: ActionTracker,
+    /// Fine-grained policy rules (`[[autonomy.policies]]`), evaluated
+    /// first-match-wins before the flat allowlist. Empty = disabled.
+    pub policies: Vec<crate::config::PolicyRuleConfig>,
+    /// Hash-chained audit logger (`[security.audit]`); `None` when audit
+    /// logging is disabled or the policy was built outside a runtime path.
+    pub audit: Option<Arc<AuditLogger>>,
 }
 
 impl Default for SecurityPolicy {
@@ -150,6 +169,8 @@ impl Default for SecurityPolicy {
             require_approval_for_medium_risk: true,
             block_high_risk_commands: true,
             tracker: ActionTracker::new(),
+            policies: vec![],
+            audit: None,
         }
     }
 }
@@ -506,6 +527,9 @@ impl SecurityPolicy {
 
     // ── Command Execution Policy Gate ──────────────────────────────────────
     // Validation follows a strict precedence order:
+    //   0. Policy rules ([[autonomy.policies]], first match wins): deny
+    //      blocks, require-approval gates on `approved`, allow short-circuits
+    //      the allowlist and risk gates below.
     //   1. Allowlist check (is the base command permitted at all?)
     //   2. Risk classification (high / medium / low)
     //   3. Policy flags (block_high_risk_commands, require_approval_for_medium_risk)
@@ -513,12 +537,38 @@ impl SecurityPolicy {
     // This ordering ensures deny-by-default: unknown commands are rejected
     // before any risk or autonomy logic runs.
 
-    /// Validate full command execution policy (allowlist + risk gate).
+    /// Validate full command execution policy (policy rules + allowlist + risk gate).
     pub fn validate_command_execution(
         &self,
         command: &str,
         approved: bool,
     ) -> Result<CommandRiskLevel, String> {
+        match self.evaluate_policy_rules(&super::policy_engine::PolicyAction {
+            tool: "shell",
+            args: Some(command),
+            ..Default::default()
+        })? {
+            Some(crate::config::PolicyOutcome::Deny) => {
+                return Err(format!("Command denied by autonomy policy: {command}"));
+            }
+            Some(crate::config::PolicyOutcome::RequireApproval) if !approved => {
+                return Err(
+                    "Command requires explicit approval (approved=true): autonomy policy rule"
+                        .into(),
+                );
+            }
+            Some(crate::config::PolicyOutcome::Allow) => {
+                // An explicit allow rule is a deliberate operator decision:
+                // it bypasses the flat allowlist and approval gates, but
+                // read-only mode still blocks all execution.
+                if self.autonomy == AutonomyLevel::ReadOnly {
+                    return Err("Command blocked: read-only mode".into());
+                }
+                return Ok(self.command_risk_level(command));
+            }
+            _ => {}
+        }
+
         if !self.is_command_allowed(command) {
             return Err(format!("Command not allowed by security policy: {command}"));
         }
@@ -767,6 +817,20 @@ impl SecurityPolicy {
                     ));
                 }
 
+                // Policy rules: only deny is enforced here — require-approval
+                // routing happens in the approval flow before execution, and
+                // allow has nothing to bypass for non-shell tools.
+                if let Some(crate::config::PolicyOutcome::Deny) =
+                    self.evaluate_policy_rules(&super::policy_engine::PolicyAction {
+                        tool: operation_name,
+                        ..Default::default()
+                    })?
+                {
+                    return Err(format!(
+                        "Action '{operation_name}' denied by autonomy policy"
+                    ));
+                }
+
                 if !self.record_action() {
                     return Err("Rate limit exceeded: action budget exhausted".to_string());
                 }
@@ -776,6 +840,25 @@ impl SecurityPolicy {
         }
     }
 
+    /// Evaluate `[[autonomy.policies]]` rules against one action.
+    ///
+    /// Returns the outcome of the first matching rule, `None` when no rule
+    /// matches, and an error when a rule itself is malformed — a broken
+    /// rule must never be silently skipped.
+    fn evaluate_policy_rules(
+        &self,
+        action: &super::policy_engine::PolicyAction,
+    ) -> Result<Option<crate::config::PolicyOutcome>, String> {
+        if self.policies.is_empty() {
+            return Ok(None);
+        }
+        let now = chrono::Local::now().time();
+        match super::policy_engine::evaluate(&self.policies, action, now) {
+            Ok(matched) => Ok(matched.map(|m| m.rule.outcome)),
+            Err(e) => Err(format!("Autonomy policy configuration error: {e:#}")),
+        }
+    }
+
     /// Record an action and check if the rate limit has been exceeded.
     /// Returns `true` if the action is allowed, `false` if rate-limited.
     pub fn record_action(&self) -> bool {
@@ -852,6 +935,42 @@ impl SecurityPolicy {
             require_approval_for_medium_risk: autonomy_config.require_approval_for_medium_risk,
             block_high_risk_commands: autonomy_config.block_high_risk_commands,
             tracker: ActionTracker::new(),
+            policies: autonomy_config.policies.clone(),
+            audit: None,
+        }
+    }
+
+    /// Attach the hash-chained audit logger from `[security.audit]`.
+    ///
+    /// No-op when audit logging is disabled in config; a construction
+    /// failure is logged and the policy continues without auditing
+    /// rather than blocking startup.
+    pub fn with_audit(
+        mut self,
+        audit_config: &crate::config::AuditConfig,
+        zeroclaw_dir: PathBuf,
+    ) -> Self {
+        if !audit_config.enabled {
+            return self;
+        }
+        match AuditLogger::new(audit_config.clone(), zeroclaw_dir) {
+            Ok(logger) => self.audit = Some(Arc::new(logger)),
+            Err(e) => {
+                tracing::warn!("Audit logging disabled, logger setup failed: {e:#}");
+            }
+        }
+        self
+    }
+
+    /// Append an event to the audit log when auditing is enabled.
+    ///
+    /// Write failures are logged, never propagated: an audit outage must
+    /// not take the runtime down with it.
+    pub fn audit_event(&self, event: &AuditEvent) {
+        if let Some(audit) = &self.audit {
+            if let Err(e) = audit.log(event) {
+                tracing::warn!("Failed to write audit log entry: {e:#}");
+            }
         }
     }
 }
@@ -0,0 +1,390 @@
+//! Rule-based policy engine for autonomy decisions.
+//!
+//! Evaluates `[[autonomy.policies]]` rules against a single action (tool
+//! name, argument text, touched path, estimated cost) at a given local
+//! time. Rules are checked in config order and the first match wins; all
+//! conditions present on a rule must match (AND). Actions with no matching
+//! rule fall through to the flat allowlist and risk gates in
+//! [`super::policy::SecurityPolicy`], so an empty rule list behaves exactly
+//! like before the engine existed.
+//!
+//! Outcomes:
+//! - `deny` — the action is blocked outright.
+//! - `require-approval` — the action needs explicit approval (the
+//!   interactive prompt for tools, `approved=true` for shell).
+//! - `allow` — the action skips the approval prompt; for shell it also
+//!   bypasses the flat command allowlist. Use sparingly.
+//!
+//! A malformed rule (for example an unparseable `hours` window) is a hard
+//! evaluation error, never a silent skip: skipping a broken `deny` rule
+//! would silently broaden permissions.
+
+use crate::config::{PolicyOutcome, PolicyRuleConfig};
+use anyhow::{Context, Result};
+use chrono::NaiveTime;
+
+/// One action as seen by the policy engine. Fields that are unknown for a
+/// given call site stay `None` and only match rules without that condition.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PolicyAction<'a> {
+    /// Tool name, e.g. `"shell"`, `"file_write"`, `"memory_store"`.
+    pub tool: &'a str,
+    /// Full command or argument text (shell: the command line).
+    pub args: Option<&'a str>,
+    /// Filesystem path the action touches, when known.
+    pub path: Option<&'a str>,
+    /// Estimated cost of the action in cents, when known.
+    pub cost_cents: Option<u32>,
+}
+
+/// The first rule that matched an action.
+#[derive(Debug, Clone, Copy)]
+pub struct PolicyMatch<'a> {
+    pub rule: &'a PolicyRuleConfig,
+    /// Zero-based position of the rule in `[[autonomy.policies]]`.
+    pub index: usize,
+}
+
+impl PolicyMatch<'_> {
+    /// Human-readable rule reference for error messages and dry-run output.
+    pub fn display_name(&self) -> String {
+        match &self.rule.name {
+            Some(name) => format!("rule #{} ('{}')", self.index + 1, name),
+            None => format!("rule #{}", self.index + 1),
+        }
+    }
+}
+
+/// Evaluate rules in order against an action; first match wins.
+///
+/// `now` is the local wall-clock time used for `hours` windows; callers
+/// pass `chrono::Local::now().time()` outside tests.
+pub fn evaluate<'a>(
+    rules: &'a [PolicyRuleConfig],
+    action: &PolicyAction,
+    now: NaiveTime,
+) -> Result<Option<PolicyMatch<'a>>> {
+    for (index, rule) in rules.iter().enumerate() {
+        if rule_matches(rule, action, now)
+            .with_context(|| format!("Invalid autonomy policy rule #{}", index + 1))?
+        {
+            return Ok(Some(PolicyMatch { rule, index }));
+        }
+    }
+    Ok(None)
+}
+
+/// Check whether every condition on a rule matches the action.
+fn rule_matches(rule: &PolicyRuleConfig, action: &PolicyAction, now: NaiveTime) -> Result<bool> {
+    if !glob_match(&rule.tool, action.tool) {
+        return Ok(false);
+    }
+
+    if let Some(args_pattern) = &rule.args {
+        match action.args {
+            Some(args) if glob_match(args_pattern, args) => {}
+            _ => return Ok(false),
+        }
+    }
+
+    if !rule.paths.is_empty() {
+        match action.path {
+            Some(path) if rule.paths.iter().any(|p| glob_match(p, path)) => {}
+            _ => return Ok(false),
+        }
+    }
+
+    if let Some(window) = &rule.hours {
+        let (start, end) = parse_hours_window(window)?;
+        if !window_contains(start, end, now) {
+            return Ok(false);
+        }
+    }
+
+    if let Some(min) = rule.min_cost_cents {
+        // Unknown cost counts as zero: a cost-threshold rule only fires
+        // when the action is known to be at least that expensive.
+        if action.cost_cents.unwrap_or(0) < min {
+            return Ok(false);
+        }
+    }
+
+    Ok(true)
+}
+
+/// Minimal glob matcher: `*` matches any run of characters (including
+/// none); everything else is literal. Case-sensitive.
+pub fn glob_match(pattern: &str, text: &str) -> bool {
+    fn inner(pattern: &[u8], text: &[u8]) -> bool {
+        match pattern.first() {
+            None => text.is_empty(),
+            Some(b'*') => {
+                // Collapse consecutive stars, then try every split point.
+                let rest = &pattern[1..];
+                (0..=text.len()).any(|i| inner(rest, &text[i..]))
+            }
+            Some(&c) => text.first() == Some(&c) && inner(&pattern[1..], &text[1..]),
+        }
+    }
+    inner(pattern.as_bytes(), text.as_bytes())
+}
+
+/// Parse an `"HH:MM-HH:MM"` local-time window.
+fn parse_hours_window(spec: &str) -> Result<(NaiveTime, NaiveTime)> {
+    let (start_str, end_str) = spec
+        .split_once('-')
+        .with_context(|| format!("hours window {spec:?} must be \"HH:MM-HH:MM\""))?;
+    let start = NaiveTime::parse_from_str(start_str.trim(), "%H:%M")
+        .with_context(|| format!("hours window {spec:?} has an invalid start time"))?;
+    let end = NaiveTime::parse_from_str(end_str.trim(), "%H:%M")
+        .with_context(|| format!("hours window {spec:?} has an invalid end time"))?;
+    Ok((start, end))
+}
+
+/// Inclusive-start, exclusive-end window check. A window whose end is not
+/// after its start wraps midnight (e.g. `"22:00-06:00"`).
+fn window_contains(start: NaiveTime, end: NaiveTime, now: NaiveTime) -> bool {
+    if start < end {
+        now >= start && now < end
+    } else {
+        now >= start || now < end
+    }
+}
+
+/// `zeroclaw policy test '<action json>'` — dry-run an action against the
+/// configured rules without executing anything.
+///
+/// The action JSON mirrors [`PolicyAction`]:
+/// `{"tool": "shell", "args": "git push", "path": "/tmp/x", "cost_cents": 40}`
+/// (`tool` required, the rest optional).
+pub fn print_test(config: &crate::config::Config, action_json: &str) -> Result<()> {
+    #[derive(serde::Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct TestAction {
+        tool: String,
+        #[serde(default)]
+        args: Option<String>,
+        #[serde(default)]
+        path: Option<String>,
+        #[serde(default)]
+        cost_cents: Option<u32>,
+    }
+
+    let parsed: TestAction = serde_json::from_str(action_json).context(
+        "Invalid action JSON — expected e.g. {\"tool\": \"shell\", \"args\": \"git push\"}",
+    )?;
+
+    let rules = &config.autonomy.policies;
+    let action = PolicyAction {
+        tool: &parsed.tool,
+        args: parsed.args.as_deref(),
+        path: parsed.path.as_deref(),
+        cost_cents: parsed.cost_cents,
+    };
+    let now = chrono::Local::now().time();
+
+    println!(
+        "Policy dry-run  (rules: {}, local time: {})",
+        rules.len(),
+        now.format("%H:%M")
+    );
+    println!("  tool:  {}", parsed.tool);
+    if let Some(args) = &parsed.args {
+        println!("  args:  {args}");
+    }
+    if let Some(path) = &parsed.path {
+        println!("  path:  {path}");
+    }
+    if let Some(cost) = parsed.cost_cents {
+        println!("  cost:  {cost} cents");
+    }
+    println!();
+
+    match evaluate(rules, &action, now)? {
+        Some(m) => {
+            let outcome = match m.rule.outcome {
+                PolicyOutcome::Allow => "allow",
+                PolicyOutcome::Deny => "deny",
+                PolicyOutcome::RequireApproval => "require-approval",
+            };
+            println!("Matched {} → {outcome}", m.display_name());
+        }
+        None => {
+            println!("No rule matched — flat allowlist and risk gates apply.");
+        }
+    }
+
+    // For shell actions also show what the full command gate would decide,
+    // so the dry-run reflects allowlist + risk classification too.
+    if parsed.tool == "shell" {
+        if let Some(command) = &parsed.args {
+            let policy =
+                super::policy::SecurityPolicy::from_config(&config.autonomy, &config.workspace_dir);
+            println!();
+            match policy.validate_command_execution(command, false) {
+                Ok(risk) => println!(
+                    "Shell gate (unapproved): allowed, risk level {}",
+                    risk.label()
+                ),
+                Err(e) => println!("Shell gate (unapproved): blocked — {e}"),
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::PolicyOutcome;
+
+    fn rule(tool: &str, outcome: PolicyOutcome) -> PolicyRuleConfig {
+        PolicyRuleConfig {
+            name: None,
+            tool: tool.into(),
+            args: None,
+            paths: vec![],
+            hours: None,
+            min_cost_cents: None,
+            outcome,
+        }
+    }
+
+    fn noon() -> NaiveTime {
+        NaiveTime::from_hms_opt(12, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn glob_match_supports_star_wildcard() {
+        assert!(glob_match("*", "anything"));
+        assert!(glob_match("shell", "shell"));
+        assert!(glob_match("memory_*", "memory_store"));
+        assert!(glob_match("*rm*", "git rm --cached x"));
+        assert!(glob_match("/workspace/*", "/workspace/notes.md"));
+        assert!(!glob_match("memory_*", "shell"));
+        assert!(!glob_match("shell", "Shell"));
+    }
+
+    #[test]
+    fn first_matching_rule_wins() {
+        let rules = vec![
+            rule("memory_*", PolicyOutcome::Allow),
+            rule("*", PolicyOutcome::Deny),
+        ];
+        let action = PolicyAction {
+            tool: "memory_store",
+            ..PolicyAction::default()
+        };
+        let m = evaluate(&rules, &action, noon()).unwrap().unwrap();
+        assert_eq!(m.index, 0);
+        assert_eq!(m.rule.outcome, PolicyOutcome::Allow);
+
+        let other = PolicyAction {
+            tool: "shell",
+            ..PolicyAction::default()
+        };
+        let m = evaluate(&rules, &other, noon()).unwrap().unwrap();
+        assert_eq!(m.index, 1);
+    }
+
+    #[test]
+    fn rule_conditions_all_must_match() {
+        let mut r = rule("shell", PolicyOutcome::Deny);
+        r.args = Some("*push*".into());
+        r.min_cost_cents = Some(50);
+
+        let cheap_push = PolicyAction {
+            tool: "shell",
+            args: Some("git push origin main"),
+            cost_cents: Some(10),
+            ..PolicyAction::default()
+        };
+        assert!(evaluate(&[r.clone()], &cheap_push, noon())
+            .unwrap()
+            .is_none());
+
+        let pricey_push = PolicyAction {
+            cost_cents: Some(80),
+            ..cheap_push
+        };
+        assert!(evaluate(&[r.clone()], &pricey_push, noon())
+            .unwrap()
+            .is_some());
+
+        let pricey_pull = PolicyAction {
+            args: Some("git pull"),
+            ..pricey_push
+        };
+        assert!(evaluate(&[r], &pricey_pull, noon()).unwrap().is_none());
+    }
+
+    #[test]
+    fn path_globs_match_any_listed_pattern() {
+        let mut r = rule("file_write", PolicyOutcome::Deny);
+        r.paths = vec!["/etc/*".into(), "*/secrets/*".into()];
+
+        let hit = PolicyAction {
+            tool: "file_write",
+            path: Some("/workspace/secrets/key.pem"),
+            ..PolicyAction::default()
+        };
+        assert!(evaluate(&[r.clone()], &hit, noon()).unwrap().is_some());
+
+        let miss = PolicyAction {
+            path: Some("/workspace/notes.md"),
+            ..hit
+        };
+        assert!(evaluate(&[r.clone()], &miss, noon()).unwrap().is_none());
+
+        let no_path = PolicyAction { path: None, ..hit };
+        assert!(evaluate(&[r], &no_path, noon()).unwrap().is_none());
+    }
+
+    #[test]
+    fn hours_window_matches_inside_and_wraps_midnight() {
+        let mut r = rule("*", PolicyOutcome::Deny);
+        r.hours = Some("09:00-17:00".into());
+        let action = PolicyAction {
+            tool: "shell",
+            ..PolicyAction::default()
+        };
+
+        assert!(evaluate(std::slice::from_ref(&r), &action, noon())
+            .unwrap()
+            .is_some());
+        let night = NaiveTime::from_hms_opt(20, 0, 0).unwrap();
+        assert!(evaluate(std::slice::from_ref(&r), &action, night)
+            .unwrap()
+            .is_none());
+
+        // Overnight curfew window.
+        r.hours = Some("22:00-06:00".into());
+        let late = NaiveTime::from_hms_opt(23, 30, 0).unwrap();
+        assert!(evaluate(std::slice::from_ref(&r), &action, late)
+            .unwrap()
+            .is_some());
+        assert!(evaluate(&[r], &action, noon()).unwrap().is_none());
+    }
+
+    #[test]
+    fn invalid_hours_window_is_an_error_not_a_skip() {
+        let mut r = rule("*", PolicyOutcome::Deny);
+        r.hours = Some("9am-5pm".into());
+        let action = PolicyAction {
+            tool: "shell",
+            ..PolicyAction::default()
+        };
+        let err = evaluate(&[r], &action, noon()).unwrap_err();
+        assert!(err.to_string().contains("rule #1"));
+    }
+
+    #[test]
+    fn no_rules_means_no_match() {
+        let action = PolicyAction {
+            tool: "shell",
+            ..PolicyAction::default()
+        };
+        assert!(evaluate(&[], &action, noon()).unwrap().is_none());
+    }
+}
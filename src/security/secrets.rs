@@ -39,17 +39,29 @@ pub struct SecretStore {
     key_path: PathBuf,
     /// Whether encryption is enabled
     enabled: bool,
+    /// Where new secrets are written (`[secrets] backend`). Reads handle
+    /// every format regardless of this setting.
+    backend: crate::config::SecretsBackend,
 }
 
 impl SecretStore {
-    /// Create a new secret store rooted at the given directory.
+    /// Create a new secret store rooted at the given directory, using the
+    /// default encrypted-file backend.
     pub fn new(zeroclaw_dir: &Path, enabled: bool) -> Self {
         Self {
             key_path: zeroclaw_dir.join(".secret_key"),
             enabled,
+            backend: crate::config::SecretsBackend::default(),
         }
     }
 
+    /// Select the backend new secrets are written to (`[secrets] backend`).
+    #[must_use]
+    pub fn with_backend(mut self, backend: crate::config::SecretsBackend) -> Self {
+        self.backend = backend;
+        self
+    }
+
     /// Encrypt a plaintext secret. Returns hex-encoded ciphertext prefixed with `enc2:`.
     /// Format: `enc2:<hex(nonce ‖ ciphertext ‖ tag)>` (12 + N + 16 bytes).
     /// If encryption is disabled, returns the plaintext as-is.
@@ -58,6 +70,10 @@ impl SecretStore {
             return Ok(plaintext.to_string());
         }
 
+        if self.backend == crate::config::SecretsBackend::Keychain {
+            return store_in_keychain(plaintext);
+        }
+
         let key_bytes = self.load_or_create_key()?;
         let key = Key::from_slice(&key_bytes);
         let cipher = ChaCha20Poly1305::new(key);
@@ -76,6 +92,7 @@ impl SecretStore {
     }
 
     /// Decrypt a secret.
+    /// - `keychain:` prefix → resolved from the OS keychain
     /// - `enc2:` prefix → ChaCha20-Poly1305 (current format)
     /// - `enc:` prefix → legacy XOR cipher (backward compatibility for migration)
     /// - No prefix → returned as-is (plaintext config)
@@ -83,6 +100,10 @@ impl SecretStore {
     /// **Warning**: Legacy `enc:` values are insecure. Use `decrypt_and_migrate` to
     /// automatically upgrade them to the secure `enc2:` format.
     pub fn decrypt(&self, value: &str) -> Result<String> {
+        if super::keychain::is_keychain_ref(value) {
+            let account = super::keychain::account_from_ref(value)?;
+            return super::keychain::lookup(account);
+        }
         if let Some(hex_str) = value.strip_prefix("enc2:") {
             self.decrypt_chacha20(hex_str)
         } else if let Some(hex_str) = value.strip_prefix("enc:") {
@@ -99,6 +120,10 @@ impl SecretStore {
     ///
     /// This allows callers to persist the upgraded value back to config.
     pub fn decrypt_and_migrate(&self, value: &str) -> Result<(String, Option<String>)> {
+        if super::keychain::is_keychain_ref(value) {
+            // Keychain references are already in the strongest format.
+            return Ok((self.decrypt(value)?, None));
+        }
         if let Some(hex_str) = value.strip_prefix("enc2:") {
             // Already using secure format — no migration needed
             let plaintext = self.decrypt_chacha20(hex_str)?;
@@ -157,9 +182,12 @@ impl SecretStore {
             .context("Decrypted legacy secret is not valid UTF-8 — wrong key or corrupt data")
     }
 
-    /// Check if a value is already encrypted (current or legacy format).
+    /// Check if a value is already encrypted or externalized
+    /// (keychain reference, current, or legacy format).
     pub fn is_encrypted(value: &str) -> bool {
-        value.starts_with("enc2:") || value.starts_with("enc:")
+        super::keychain::is_keychain_ref(value)
+            || value.starts_with("enc2:")
+            || value.starts_with("enc:")
     }
 
     /// Check if a value uses the secure `enc2:` format.
@@ -237,6 +265,14 @@ fn xor_cipher(data: &[u8], key: &[u8]) -> Vec<u8> {
         .collect()
 }
 
+/// Store a secret in the OS keychain under a fresh random account and
+/// return the `keychain:<account>` reference for the config file.
+fn store_in_keychain(plaintext: &str) -> Result<String> {
+    let account = format!("secret-{}", hex_encode(&generate_random_key()[..8]));
+    super::keychain::store(&account, plaintext)?;
+    Ok(format!("{}{account}", super::keychain::KEYCHAIN_REF_PREFIX))
+}
+
 /// Generate a random 256-bit key using the OS CSPRNG.
 ///
 /// Uses `OsRng` (via `getrandom`) directly, providing full 256-bit entropy
@@ -330,10 +366,20 @@ mod tests {
     fn is_encrypted_detects_prefix() {
         assert!(SecretStore::is_encrypted("enc2:aabbcc"));
         assert!(SecretStore::is_encrypted("enc:aabbcc")); // legacy
+        assert!(SecretStore::is_encrypted("keychain:config.api_key"));
         assert!(!SecretStore::is_encrypted("sk-plaintext"));
         assert!(!SecretStore::is_encrypted(""));
     }
 
+    #[test]
+    fn keychain_ref_with_invalid_account_fails_decrypt() {
+        let tmp = TempDir::new().unwrap();
+        let store = SecretStore::new(tmp.path(), true);
+        // Account names that could be misread as CLI flags are rejected
+        // before any platform tool is spawned.
+        assert!(store.decrypt("keychain:-w").is_err());
+    }
+
     #[tokio::test]
     async fn key_file_created_on_first_encrypt() {
         let tmp = TempDir::new().unwrap();
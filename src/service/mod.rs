@@ -17,8 +17,10 @@ pub fn handle_command(command: &crate::ServiceCommands, config: &Config) -> Resu
         crate::ServiceCommands::Start => start(config),
         crate::ServiceCommands::Stop => stop(config),
         crate::ServiceCommands::Restart => restart(config),
+        crate::ServiceCommands::Reload => reload(config),
         crate::ServiceCommands::Status => status(config),
         crate::ServiceCommands::Uninstall => uninstall(config),
+        crate::ServiceCommands::GenerateDocker { dir } => generate_docker(config, dir),
     }
 }
 
@@ -30,7 +32,7 @@ fn install(config: &Config) -> Result<()> {
     } else if cfg!(target_os = "windows") {
         install_windows(config)
     } else {
-        anyhow::bail!("Service management is supported on macOS and Linux only");
+        anyhow::bail!("Service management is supported on macOS, Linux, and Windows only");
     }
 }
 
@@ -53,7 +55,7 @@ fn start(config: &Config) -> Result<()> {
         Ok(())
     } else {
         let _ = config;
-        anyhow::bail!("Service management is supported on macOS and Linux only")
+        anyhow::bail!("Service management is supported on macOS, Linux, and Windows only")
     }
 }
 
@@ -74,14 +76,20 @@ fn stop(config: &Config) -> Result<()> {
         println!("✅ Service stopped");
         Ok(())
     } else if cfg!(target_os = "windows") {
-        let _ = config;
         let task_name = windows_task_name();
         let _ = run_checked(Command::new("schtasks").args(["/End", "/TN", task_name]));
+        // `schtasks /End` stops the task but can leave the daemon process
+        // tree behind the wrapper script running; kill the recorded daemon
+        // PID as well (best-effort — the state file may be stale or absent).
+        if let Ok(pid) = daemon_pid_from_state(config) {
+            let _ =
+                run_checked(Command::new("taskkill").args(["/PID", &pid.to_string(), "/T", "/F"]));
+        }
         println!("✅ Service stopped");
         Ok(())
     } else {
         let _ = config;
-        anyhow::bail!("Service management is supported on macOS and Linux only")
+        anyhow::bail!("Service management is supported on macOS, Linux, and Windows only")
     }
 }
 
@@ -92,6 +100,38 @@ fn restart(config: &Config) -> Result<()> {
     Ok(())
 }
 
+/// Signal the running daemon (SIGHUP) to re-read config and restart its
+/// components in place. The target PID comes from `daemon_state.json`,
+/// which the daemon refreshes every few seconds.
+fn reload(config: &Config) -> Result<()> {
+    if cfg!(target_os = "windows") {
+        anyhow::bail!("Reload is not supported on Windows; use 'zeroclaw service restart'");
+    }
+
+    let pid = daemon_pid_from_state(config)?;
+    run_checked(Command::new("kill").args(["-HUP", &pid.to_string()]))?;
+    println!("✅ Sent reload signal to daemon (pid {pid})");
+    Ok(())
+}
+
+/// PID of the running daemon from `daemon_state.json`, which the daemon
+/// refreshes every few seconds.
+fn daemon_pid_from_state(config: &Config) -> Result<u64> {
+    let state_path = crate::daemon::state_file_path(config);
+    let data = fs::read_to_string(&state_path).with_context(|| {
+        format!(
+            "Failed to read daemon state at {} (is the daemon running?)",
+            state_path.display()
+        )
+    })?;
+    let state: serde_json::Value =
+        serde_json::from_str(&data).context("Failed to parse daemon state")?;
+    state
+        .get("pid")
+        .and_then(serde_json::Value::as_u64)
+        .context("Daemon state has no PID; restart the daemon once to record it")
+}
+
 fn status(config: &Config) -> Result<()> {
     if cfg!(target_os = "macos") {
         let out = run_capture(Command::new("launchctl").arg("list"))?;
@@ -121,7 +161,6 @@ fn status(config: &Config) -> Result<()> {
     }
 
     if cfg!(target_os = "windows") {
-        let _ = config;
         let task_name = windows_task_name();
         let out =
             run_capture(Command::new("schtasks").args(["/Query", "/TN", task_name, "/FO", "LIST"]));
@@ -137,6 +176,12 @@ fn status(config: &Config) -> Result<()> {
                     }
                 );
                 println!("Task: {}", task_name);
+                let logs_dir = config
+                    .config_path
+                    .parent()
+                    .map_or_else(|| PathBuf::from("."), PathBuf::from)
+                    .join("logs");
+                println!("Logs: {}", logs_dir.display());
             }
             Err(_) => {
                 println!("Service: ❌ not installed");
@@ -145,7 +190,7 @@ fn status(config: &Config) -> Result<()> {
         return Ok(());
     }
 
-    anyhow::bail!("Service management is supported on macOS and Linux only")
+    anyhow::bail!("Service management is supported on macOS, Linux, and Windows only")
 }
 
 fn uninstall(config: &Config) -> Result<()> {
@@ -189,7 +234,7 @@ fn uninstall(config: &Config) -> Result<()> {
         return Ok(());
     }
 
-    anyhow::bail!("Service management is supported on macOS and Linux only")
+    anyhow::bail!("Service management is supported on macOS, Linux, and Windows only")
 }
 
 fn install_macos(config: &Config) -> Result<()> {
@@ -251,12 +296,7 @@ fn install_linux(config: &Config) -> Result<()> {
     }
 
     let exe = std::env::current_exe().context("Failed to resolve current executable")?;
-    let unit = format!(
-        "[Unit]\nDescription=ZeroClaw daemon\nAfter=network.target\n\n[Service]\nType=simple\nExecStart={} daemon\nRestart=always\nRestartSec=3\n\n[Install]\nWantedBy=default.target\n",
-        exe.display()
-    );
-
-    fs::write(&file, unit)?;
+    fs::write(&file, linux_unit_contents(&exe))?;
     let _ = run_checked(Command::new("systemctl").args(["--user", "daemon-reload"]));
     let _ = run_checked(Command::new("systemctl").args(["--user", "enable", "zeroclaw.service"]));
     println!("✅ Installed systemd user service: {}", file.display());
@@ -323,6 +363,16 @@ fn macos_service_file() -> Result<PathBuf> {
         .join(format!("{SERVICE_LABEL}.plist")))
 }
 
+/// systemd user unit for the daemon. `Type=notify` + `WatchdogSec` pair with
+/// the daemon's `sd_notify` readiness and watchdog pings (sent every state
+/// flush, well inside the 30s window), so systemd restarts a wedged daemon.
+fn linux_unit_contents(exe: &std::path::Path) -> String {
+    format!(
+        "[Unit]\nDescription=ZeroClaw daemon\nAfter=network.target\n\n[Service]\nType=notify\nNotifyAccess=main\nExecStart={} daemon\nWatchdogSec=30\nRestart=always\nRestartSec=3\n\n[Install]\nWantedBy=default.target\n",
+        exe.display()
+    )
+}
+
 fn linux_service_file(config: &Config) -> Result<PathBuf> {
     let home = directories::UserDirs::new()
         .map(|u| u.home_dir().to_path_buf())
@@ -335,6 +385,110 @@ fn linux_service_file(config: &Config) -> Result<PathBuf> {
         .join("zeroclaw.service"))
 }
 
+/// Dockerfile for a standalone deployment directory. Extends the published
+/// image rather than rebuilding from source, so it works outside the
+/// source tree; the gateway port comes from the operator's `[gateway]`
+/// config so the container exposes what the daemon actually binds.
+fn dockerfile_contents(port: u16) -> String {
+    format!(
+        r#"# Generated by `zeroclaw service generate-docker`.
+FROM ghcr.io/zeroclaw-labs/zeroclaw:latest
+
+# Gateway port from [gateway] in config.toml; keep in sync with docker-compose.yml.
+EXPOSE {port}
+
+# The release image is distroless (no shell/curl), so probe via the CLI.
+# With a curl-capable base, prefer: curl -fsS http://127.0.0.1:{port}/healthz
+HEALTHCHECK --interval=60s --timeout=10s --start-period=10s --retries=3 \
+    CMD ["zeroclaw", "status"]
+
+CMD ["daemon"]
+"#
+    )
+}
+
+/// docker-compose.yml paired with [`dockerfile_contents`]: named volume for
+/// `/zeroclaw-data` (config + workspace), gateway port mapping, and provider
+/// secrets injected from the host environment or a `.env` file — never baked
+/// into the image.
+fn compose_contents(port: u16) -> String {
+    format!(
+        r#"# Generated by `zeroclaw service generate-docker`.
+#
+# Quick start:
+#   1. Export your provider API key (or put it in a .env file next to this file)
+#   2. Run: docker compose up -d
+#   3. Check: curl http://localhost:{port}/healthz
+
+services:
+  zeroclaw:
+    build: .
+    container_name: zeroclaw
+    restart: unless-stopped
+
+    environment:
+      # Provider secrets come from the host environment / .env at runtime;
+      # they are never written into the image or this file.
+      - API_KEY=${{API_KEY:-}}
+      - OPENROUTER_API_KEY=${{OPENROUTER_API_KEY:-}}
+      - ANTHROPIC_API_KEY=${{ANTHROPIC_API_KEY:-}}
+      - OPENAI_API_KEY=${{OPENAI_API_KEY:-}}
+      # Required for container networking; the gateway must bind beyond
+      # localhost to be reachable through the published port.
+      - ZEROCLAW_ALLOW_PUBLIC_BIND=true
+
+    volumes:
+      # Persist config and workspace (HOME inside the image is /zeroclaw-data)
+      - zeroclaw-data:/zeroclaw-data
+
+    ports:
+      # Gateway port from [gateway] in config.toml
+      - "{port}:{port}"
+
+    healthcheck:
+      test: ["CMD", "zeroclaw", "status"]
+      interval: 60s
+      timeout: 10s
+      retries: 3
+      start_period: 10s
+
+volumes:
+  zeroclaw-data:
+"#
+    )
+}
+
+/// Write Dockerfile + docker-compose.yml into `dir`, pre-wired from the
+/// operator's config. Refuses to overwrite existing files so it never
+/// clobbers a hand-tuned deployment.
+fn generate_docker(config: &Config, dir: &std::path::Path) -> Result<()> {
+    fs::create_dir_all(dir)
+        .with_context(|| format!("Failed to create directory {}", dir.display()))?;
+
+    let dockerfile = dir.join("Dockerfile");
+    let compose = dir.join("docker-compose.yml");
+    for existing in [&dockerfile, &compose] {
+        if existing.exists() {
+            anyhow::bail!(
+                "{} already exists; move it aside or use --dir to target an empty directory",
+                existing.display()
+            );
+        }
+    }
+
+    let port = config.gateway.port;
+    fs::write(&dockerfile, dockerfile_contents(port))
+        .with_context(|| format!("Failed to write {}", dockerfile.display()))?;
+    fs::write(&compose, compose_contents(port))
+        .with_context(|| format!("Failed to write {}", compose.display()))?;
+
+    println!("✅ Wrote {}", dockerfile.display());
+    println!("✅ Wrote {}", compose.display());
+    println!("   Next: export your provider API key, then run: docker compose up -d");
+    println!("   Probe: curl http://localhost:{port}/healthz");
+    Ok(())
+}
+
 fn run_checked(command: &mut Command) -> Result<()> {
     let output = command.output().context("Failed to spawn command")?;
     if !output.status.success() {
@@ -395,6 +549,44 @@ mod tests {
         assert!(err.to_string().contains("Command failed"));
     }
 
+    #[test]
+    fn daemon_pid_from_state_reads_recorded_pid() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let config = Config {
+            config_path: tmp.path().join("config.toml"),
+            ..Config::default()
+        };
+        std::fs::write(
+            crate::daemon::state_file_path(&config),
+            r#"{"pid": 4242, "components": {}}"#,
+        )
+        .unwrap();
+
+        assert_eq!(daemon_pid_from_state(&config).unwrap(), 4242);
+    }
+
+    #[test]
+    fn daemon_pid_from_state_errors_without_state_file() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let config = Config {
+            config_path: tmp.path().join("config.toml"),
+            ..Config::default()
+        };
+
+        let err = daemon_pid_from_state(&config).unwrap_err();
+        assert!(err.to_string().contains("daemon state"));
+    }
+
+    #[test]
+    fn linux_unit_enables_notify_and_watchdog() {
+        let unit = linux_unit_contents(std::path::Path::new("/usr/local/bin/zeroclaw"));
+        assert!(unit.contains("Type=notify"));
+        assert!(unit.contains("NotifyAccess=main"));
+        assert!(unit.contains("WatchdogSec=30"));
+        assert!(unit.contains("ExecStart=/usr/local/bin/zeroclaw daemon"));
+        assert!(unit.contains("Restart=always"));
+    }
+
     #[cfg(not(target_os = "windows"))]
     #[test]
     fn linux_service_file_has_expected_suffix() {
@@ -403,6 +595,40 @@ mod tests {
         assert!(path.ends_with(".config/systemd/user/zeroclaw.service"));
     }
 
+    #[test]
+    fn dockerfile_wires_port_and_healthcheck() {
+        let dockerfile = dockerfile_contents(4100);
+        assert!(dockerfile.contains("EXPOSE 4100"));
+        assert!(dockerfile.contains("HEALTHCHECK"));
+        assert!(dockerfile.contains("CMD [\"daemon\"]"));
+    }
+
+    #[test]
+    fn compose_wires_port_volume_and_env_injection() {
+        let compose = compose_contents(4100);
+        assert!(compose.contains("\"4100:4100\""));
+        assert!(compose.contains("zeroclaw-data:/zeroclaw-data"));
+        assert!(compose.contains("OPENROUTER_API_KEY=${OPENROUTER_API_KEY:-}"));
+        assert!(compose.contains("ANTHROPIC_API_KEY=${ANTHROPIC_API_KEY:-}"));
+        assert!(compose.contains("healthcheck:"));
+        // Secrets are injected at runtime, never written literally.
+        assert!(!compose.contains("sk-"));
+    }
+
+    #[test]
+    fn generate_docker_writes_files_and_refuses_overwrite() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let config = Config::default();
+        let dir = tmp.path().join("deploy");
+
+        generate_docker(&config, &dir).unwrap();
+        assert!(dir.join("Dockerfile").exists());
+        assert!(dir.join("docker-compose.yml").exists());
+
+        let err = generate_docker(&config, &dir).unwrap_err();
+        assert!(err.to_string().contains("already exists"));
+    }
+
     #[test]
     fn windows_task_name_is_constant() {
         assert_eq!(windows_task_name(), "ZeroClaw Daemon");
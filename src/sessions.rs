@@ -0,0 +1,362 @@
+//! Conversation transcript storage and export.
+//!
+//! Every CLI agent run persists its full conversation (system prompt, user
+//! messages, tool-call exchanges, final responses) as a JSON record under
+//! `<workspace>/state/sessions/<id>.json`, keyed by the same run ID used by
+//! the undo journal. `zeroclaw sessions list` shows stored sessions and
+//! `zeroclaw sessions export <id> --format markdown|json|html` renders one
+//! for sharing or archiving. Exports always pass through the security
+//! module's secret redactor, so tool outputs and responses captured before
+//! redaction patterns were tightened cannot leak credentials.
+
+use crate::providers::ChatMessage;
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// One stored conversation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionRecord {
+    pub id: String,
+    pub started_at_unix: u64,
+    pub provider: String,
+    pub model: String,
+    pub messages: Vec<ChatMessage>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tokens_used: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cost_usd: Option<f64>,
+}
+
+/// Filesystem store for session transcripts in one workspace.
+pub struct SessionStore {
+    dir: PathBuf,
+}
+
+impl SessionStore {
+    pub fn new(workspace_dir: &Path) -> Self {
+        Self {
+            dir: workspace_dir.join("state").join("sessions"),
+        }
+    }
+
+    /// Persist a transcript (best-effort: a storage failure is logged and
+    /// never fails the agent run that produced it).
+    pub fn save(&self, record: &SessionRecord) {
+        if let Err(e) = self.save_inner(record) {
+            tracing::warn!(
+                session = %record.id,
+                "Failed to persist session transcript: {e}"
+            );
+        }
+    }
+
+    fn save_inner(&self, record: &SessionRecord) -> Result<()> {
+        std::fs::create_dir_all(&self.dir)?;
+        let path = self.dir.join(format!("{}.json", record.id));
+        std::fs::write(&path, serde_json::to_string_pretty(record)?)?;
+        Ok(())
+    }
+
+    /// All stored sessions, newest first.
+    pub fn list(&self) -> Result<Vec<SessionRecord>> {
+        if !self.dir.exists() {
+            return Ok(Vec::new());
+        }
+        let mut records = Vec::new();
+        for entry in std::fs::read_dir(&self.dir)? {
+            let path = entry?.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+                continue;
+            }
+            match std::fs::read_to_string(&path)
+                .map_err(anyhow::Error::from)
+                .and_then(|raw| serde_json::from_str::<SessionRecord>(&raw).map_err(Into::into))
+            {
+                Ok(record) => records.push(record),
+                Err(e) => {
+                    tracing::warn!("Skipping unreadable session file {}: {e}", path.display());
+                }
+            }
+        }
+        records.sort_by_key(|record| std::cmp::Reverse(record.started_at_unix));
+        Ok(records)
+    }
+
+    /// Load one session by exact ID, or by unique ID prefix.
+    pub fn load(&self, id: &str) -> Result<SessionRecord> {
+        let exact = self.dir.join(format!("{id}.json"));
+        if exact.exists() {
+            let raw = std::fs::read_to_string(&exact)
+                .with_context(|| format!("Failed to read session {}", exact.display()))?;
+            return serde_json::from_str(&raw)
+                .with_context(|| format!("Failed to parse session {}", exact.display()));
+        }
+
+        let matches: Vec<SessionRecord> = self
+            .list()?
+            .into_iter()
+            .filter(|record| record.id.starts_with(id))
+            .collect();
+        match matches.len() {
+            0 => bail!("No session found with ID '{id}' (see `zeroclaw sessions list`)"),
+            1 => Ok(matches.into_iter().next().expect("length checked")),
+            n => bail!("Session ID prefix '{id}' is ambiguous ({n} matches)"),
+        }
+    }
+}
+
+/// Return a copy of the record with every message scrubbed by the active
+/// secret redactor.
+fn redacted(record: &SessionRecord) -> SessionRecord {
+    let redactor = crate::security::redaction::runtime_redactor();
+    let mut clean = record.clone();
+    for message in &mut clean.messages {
+        let (text, _) = redactor.redact(&message.content);
+        message.content = text;
+    }
+    clean
+}
+
+fn format_timestamp(unix: u64) -> String {
+    chrono::DateTime::from_timestamp(i64::try_from(unix).unwrap_or(0), 0).map_or_else(
+        || unix.to_string(),
+        |dt| dt.format("%Y-%m-%d %H:%M:%S UTC").to_string(),
+    )
+}
+
+/// Render a session in the requested export format. Redaction is applied
+/// here, immediately before anything leaves the store.
+pub fn render(record: &SessionRecord, format: &str) -> Result<String> {
+    let clean = redacted(record);
+    match format {
+        "markdown" => Ok(render_markdown(&clean)),
+        "json" => serde_json::to_string_pretty(&clean).map_err(Into::into),
+        "html" => Ok(render_html(&clean)),
+        other => bail!("Unknown export format '{other}' (expected markdown, json, or html)"),
+    }
+}
+
+fn render_markdown(record: &SessionRecord) -> String {
+    use std::fmt::Write as _;
+    let mut out = String::new();
+    let _ = writeln!(out, "# ZeroClaw session {}\n", record.id);
+    let _ = writeln!(
+        out,
+        "- Started: {}",
+        format_timestamp(record.started_at_unix)
+    );
+    let _ = writeln!(out, "- Provider: {} ({})", record.provider, record.model);
+    if let Some(tokens) = record.tokens_used {
+        let _ = writeln!(out, "- Tokens used: {tokens}");
+    }
+    if let Some(cost) = record.cost_usd {
+        let _ = writeln!(out, "- Cost: ${cost:.4}");
+    }
+    for message in &record.messages {
+        let _ = writeln!(out, "\n## {}\n", message.role);
+        let _ = writeln!(out, "{}", message.content.trim_end());
+    }
+    out
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn render_html(record: &SessionRecord) -> String {
+    use std::fmt::Write as _;
+    let mut out = String::new();
+    let _ = writeln!(out, "<!DOCTYPE html>");
+    let _ = writeln!(out, "<html lang=\"en\"><head><meta charset=\"utf-8\">");
+    let _ = writeln!(
+        out,
+        "<title>ZeroClaw session {}</title>",
+        escape_html(&record.id)
+    );
+    let _ = writeln!(
+        out,
+        "<style>body{{font-family:sans-serif;max-width:48rem;margin:2rem auto;padding:0 1rem}}\
+.message{{border:1px solid #ddd;border-radius:6px;margin:1rem 0;padding:0.5rem 1rem}}\
+.role{{font-weight:bold;text-transform:uppercase;font-size:0.8rem;color:#666}}\
+pre{{white-space:pre-wrap;word-wrap:break-word;margin:0.5rem 0}}</style>"
+    );
+    let _ = writeln!(out, "</head><body>");
+    let _ = writeln!(out, "<h1>ZeroClaw session {}</h1>", escape_html(&record.id));
+    let _ = writeln!(
+        out,
+        "<p>Started: {} — Provider: {} ({}){}{}</p>",
+        escape_html(&format_timestamp(record.started_at_unix)),
+        escape_html(&record.provider),
+        escape_html(&record.model),
+        record
+            .tokens_used
+            .map(|tokens| format!(" — Tokens: {tokens}"))
+            .unwrap_or_default(),
+        record
+            .cost_usd
+            .map(|cost| format!(" — Cost: ${cost:.4}"))
+            .unwrap_or_default(),
+    );
+    for message in &record.messages {
+        let _ = writeln!(
+            out,
+            "<div class=\"message\"><div class=\"role\">{}</div><pre>{}</pre></div>",
+            escape_html(&message.role),
+            escape_html(message.content.trim_end())
+        );
+    }
+    let _ = writeln!(out, "</body></html>");
+    out
+}
+
+/// Handle `zeroclaw sessions <subcommand>`.
+pub fn handle_command(
+    command: crate::SessionsCommands,
+    config: &crate::config::Config,
+) -> Result<()> {
+    let store = SessionStore::new(&config.workspace_dir);
+    match command {
+        crate::SessionsCommands::List => {
+            let records = store.list()?;
+            if records.is_empty() {
+                println!("No stored sessions.");
+                return Ok(());
+            }
+            println!("{} stored session(s), newest first:", records.len());
+            for record in records {
+                println!(
+                    "  {}  {}  {} ({})  {} message(s)",
+                    record.id,
+                    format_timestamp(record.started_at_unix),
+                    record.provider,
+                    record.model,
+                    record.messages.len()
+                );
+            }
+            Ok(())
+        }
+        crate::SessionsCommands::Export { id, format, output } => {
+            let record = store.load(&id)?;
+            let rendered = render(&record, &format)?;
+            match output {
+                Some(path) => {
+                    std::fs::write(&path, rendered)
+                        .with_context(|| format!("Failed to write {}", path.display()))?;
+                    println!("Exported session {} to {}", record.id, path.display());
+                }
+                None => print!("{rendered}"),
+            }
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn sample_record(id: &str, started_at_unix: u64) -> SessionRecord {
+        SessionRecord {
+            id: id.to_string(),
+            started_at_unix,
+            provider: "openrouter".into(),
+            model: "test-model".into(),
+            messages: vec![
+                ChatMessage::system("You are a helpful agent."),
+                ChatMessage::user("What time is it?"),
+                ChatMessage::assistant("It is noon."),
+            ],
+            tokens_used: Some(42),
+            cost_usd: Some(0.0012),
+        }
+    }
+
+    #[test]
+    fn save_and_load_round_trip() {
+        let tmp = TempDir::new().unwrap();
+        let store = SessionStore::new(tmp.path());
+        store.save(&sample_record("session-one", 100));
+
+        let loaded = store.load("session-one").unwrap();
+        assert_eq!(loaded.id, "session-one");
+        assert_eq!(loaded.messages.len(), 3);
+        assert_eq!(loaded.tokens_used, Some(42));
+    }
+
+    #[test]
+    fn list_returns_sessions_newest_first() {
+        let tmp = TempDir::new().unwrap();
+        let store = SessionStore::new(tmp.path());
+        store.save(&sample_record("older", 100));
+        store.save(&sample_record("newer", 200));
+
+        let records = store.list().unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].id, "newer");
+        assert_eq!(records[1].id, "older");
+    }
+
+    #[test]
+    fn load_accepts_unique_prefix_and_rejects_ambiguous() {
+        let tmp = TempDir::new().unwrap();
+        let store = SessionStore::new(tmp.path());
+        store.save(&sample_record("abc-123", 100));
+        store.save(&sample_record("abd-456", 200));
+
+        assert_eq!(store.load("abc").unwrap().id, "abc-123");
+        let err = store.load("ab").unwrap_err().to_string();
+        assert!(err.contains("ambiguous"), "unexpected error: {err}");
+        let err = store.load("zzz").unwrap_err().to_string();
+        assert!(err.contains("No session found"), "unexpected error: {err}");
+    }
+
+    #[test]
+    fn markdown_export_includes_metadata_and_messages() {
+        let record = sample_record("md-session", 0);
+        let out = render(&record, "markdown").unwrap();
+
+        assert!(out.contains("# ZeroClaw session md-session"));
+        assert!(out.contains("- Provider: openrouter (test-model)"));
+        assert!(out.contains("- Tokens used: 42"));
+        assert!(out.contains("## user"));
+        assert!(out.contains("What time is it?"));
+    }
+
+    #[test]
+    fn html_export_escapes_message_content() {
+        let mut record = sample_record("html-session", 0);
+        record
+            .messages
+            .push(ChatMessage::assistant("<script>alert(1)</script>"));
+
+        let out = render(&record, "html").unwrap();
+        assert!(out.contains("&lt;script&gt;"));
+        assert!(!out.contains("<script>alert"));
+    }
+
+    #[test]
+    fn export_redacts_secret_shaped_content() {
+        let mut record = sample_record("secret-session", 0);
+        record.messages.push(ChatMessage::assistant(
+            "the key is sk-abc123def456ghi789jkl",
+        ));
+
+        for format in ["markdown", "json", "html"] {
+            let out = render(&record, format).unwrap();
+            assert!(!out.contains("sk-abc123def456ghi789jkl"), "{format} leaked");
+            assert!(out.contains("[REDACTED]"), "{format} missing placeholder");
+        }
+    }
+
+    #[test]
+    fn unknown_format_is_rejected() {
+        let record = sample_record("fmt-session", 0);
+        let err = render(&record, "pdf").unwrap_err().to_string();
+        assert!(err.contains("Unknown export format"));
+    }
+}
@@ -0,0 +1,315 @@
+//! Generator — scaffold a new skill from a natural-language description.
+//!
+//! Uses the configured provider to draft a skill spec (manifest metadata,
+//! prompt, tool stubs, review examples), then writes the scaffold into the
+//! workspace skills directory in a disabled state (`SKILL.toml.disabled`)
+//! so nothing is live until a human reviews and renames the manifest.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::config::Config;
+
+// ---------------------------------------------------------------------------
+// ScaffoldSpec — the JSON contract we ask the model to produce
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScaffoldSpec {
+    pub name: String,
+    pub description: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// The system-prompt text injected when the skill is active.
+    pub prompt: String,
+    #[serde(default)]
+    pub tools: Vec<ScaffoldTool>,
+    /// Example invocations for manual review/testing of the skill.
+    #[serde(default)]
+    pub examples: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScaffoldTool {
+    pub name: String,
+    pub description: String,
+    /// "shell", "http", "script"
+    #[serde(default = "default_tool_kind")]
+    pub kind: String,
+    /// Command/URL stub — intentionally a placeholder pending review.
+    #[serde(default)]
+    pub command: String,
+}
+
+fn default_tool_kind() -> String {
+    "shell".to_string()
+}
+
+const SCAFFOLD_SYSTEM_PROMPT: &str = "\
+You are a skill scaffolder for the ZeroClaw agent runtime. Given a natural-language \
+description of a capability, respond with ONLY a JSON object (no prose, no markdown \
+fences) with these fields:
+  name: short kebab-case skill name
+  description: one-line summary
+  tags: array of lowercase topic tags
+  prompt: system-prompt text telling the agent how to use this skill
+  tools: array of {name, description, kind, command} tool stubs, where kind is \
+\"shell\", \"http\", or \"script\" and command is a placeholder to be filled in by \
+a human reviewer
+  examples: array of example user requests this skill should handle";
+
+/// Parse the model response into a [`ScaffoldSpec`], tolerating markdown fences.
+pub fn parse_scaffold_response(response: &str) -> Result<ScaffoldSpec> {
+    let trimmed = response.trim();
+    let body = if let Some(start) = trimmed.find('{') {
+        let end = trimmed
+            .rfind('}')
+            .context("Model response has no closing brace")?;
+        &trimmed[start..=end]
+    } else {
+        trimmed
+    };
+
+    let spec: ScaffoldSpec =
+        serde_json::from_str(body).context("Model response is not a valid skill scaffold JSON")?;
+
+    if spec.name.trim().is_empty() {
+        bail!("Generated skill has an empty name");
+    }
+    if spec.prompt.trim().is_empty() {
+        bail!("Generated skill has an empty prompt");
+    }
+    Ok(spec)
+}
+
+/// Write the scaffold under `<skills_dir>/<name>/` in a disabled state.
+///
+/// The manifest is written as `SKILL.toml.disabled` so the skill loader does
+/// not pick it up; renaming it to `SKILL.toml` after review activates it. The
+/// human-readable summary goes to `README.md` (not `SKILL.md`, which the
+/// loader would treat as a live skill).
+pub fn write_scaffold(skills_dir: &Path, spec: &ScaffoldSpec) -> Result<PathBuf> {
+    let safe_name = super::integrate::sanitize_path_component(&spec.name)?;
+    let skill_dir = skills_dir.join(&safe_name);
+    if skill_dir.join("SKILL.toml").exists() || skill_dir.join("SKILL.toml.disabled").exists() {
+        bail!(
+            "Skill '{safe_name}' already exists at {}",
+            skill_dir.display()
+        );
+    }
+    fs::create_dir_all(&skill_dir)
+        .with_context(|| format!("Failed to create dir: {}", skill_dir.display()))?;
+
+    fs::write(skill_dir.join("SKILL.toml.disabled"), manifest_toml(spec))
+        .context("Failed to write SKILL.toml.disabled")?;
+    fs::write(skill_dir.join("README.md"), skill_md(spec)).context("Failed to write README.md")?;
+
+    Ok(skill_dir)
+}
+
+fn manifest_toml(spec: &ScaffoldSpec) -> String {
+    let mut out = String::new();
+    out.push_str("# Generated by `zeroclaw skillforge new` — review before enabling.\n");
+    out.push_str("# To activate: rename this file to SKILL.toml\n\n");
+    out.push_str("[skill]\n");
+    out.push_str(&format!("name = {}\n", toml_string(&spec.name)));
+    out.push_str(&format!(
+        "description = {}\n",
+        toml_string(&spec.description)
+    ));
+    out.push_str("version = \"0.1.0\"\n");
+    out.push_str("author = \"skillforge\"\n");
+    if spec.tags.is_empty() {
+        out.push_str("tags = []\n");
+    } else {
+        let tags: Vec<String> = spec.tags.iter().map(|t| toml_string(t)).collect();
+        out.push_str(&format!("tags = [{}]\n", tags.join(", ")));
+    }
+
+    out.push_str(&format!("\nprompts = [{}]\n", toml_string(&spec.prompt)));
+
+    for tool in &spec.tools {
+        out.push_str("\n[[tools]]\n");
+        out.push_str(&format!("name = {}\n", toml_string(&tool.name)));
+        out.push_str(&format!(
+            "description = {}\n",
+            toml_string(&tool.description)
+        ));
+        out.push_str(&format!("kind = {}\n", toml_string(&tool.kind)));
+        let command = if tool.command.trim().is_empty() {
+            "echo 'TODO: implement this tool before enabling the skill'".to_string()
+        } else {
+            tool.command.clone()
+        };
+        out.push_str(&format!("command = {}\n", toml_string(&command)));
+    }
+    out
+}
+
+fn skill_md(spec: &ScaffoldSpec) -> String {
+    let mut out = format!("# {}\n\n{}\n\n", spec.name, spec.description);
+    out.push_str("## Prompt\n\n");
+    out.push_str(&spec.prompt);
+    out.push('\n');
+    if !spec.tools.is_empty() {
+        out.push_str("\n## Tool stubs (review required)\n\n");
+        for tool in &spec.tools {
+            out.push_str(&format!(
+                "- `{}` ({}): {}\n",
+                tool.name, tool.kind, tool.description
+            ));
+        }
+    }
+    if !spec.examples.is_empty() {
+        out.push_str("\n## Review examples\n\nVerify the skill handles these before enabling:\n\n");
+        for example in &spec.examples {
+            out.push_str(&format!("- {example}\n"));
+        }
+    }
+    out.push_str("\n---\nGenerated by `zeroclaw skillforge new`. Disabled until `SKILL.toml.disabled` is renamed to `SKILL.toml`.\n");
+    out
+}
+
+fn toml_string(value: &str) -> String {
+    format!("{:?}", value)
+}
+
+/// CLI entry: generate a scaffold from a description using the configured provider.
+pub async fn run_new(config: &Config, description: &str) -> Result<()> {
+    let description = description.trim();
+    if description.is_empty() {
+        bail!("Description cannot be empty");
+    }
+
+    let provider_name = config
+        .default_provider
+        .clone()
+        .unwrap_or_else(|| "openrouter".to_string());
+    let model = config
+        .default_model
+        .clone()
+        .context("No default_model configured — set one in config.toml or run onboarding")?;
+
+    let provider = crate::providers::create_provider_with_url(
+        &provider_name,
+        config.api_key.as_deref(),
+        config.api_url.as_deref(),
+    )?;
+
+    println!("🛠️  Drafting skill scaffold with {provider_name}/{model}...");
+    let response = provider
+        .chat_with_system(
+            Some(SCAFFOLD_SYSTEM_PROMPT),
+            description,
+            &model,
+            config.default_temperature,
+        )
+        .await
+        .context("Provider call failed while drafting the skill scaffold")?;
+
+    let spec = parse_scaffold_response(&response)?;
+    let skills_dir = config.workspace_dir.join("skills");
+    let skill_dir = write_scaffold(&skills_dir, &spec)?;
+
+    println!(
+        "✅ Scaffolded skill '{}' at {}",
+        spec.name,
+        skill_dir.display()
+    );
+    println!("   Written disabled — review the files, then activate with:");
+    println!(
+        "   mv {} {}",
+        skill_dir.join("SKILL.toml.disabled").display(),
+        skill_dir.join("SKILL.toml").display()
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn sample_spec() -> ScaffoldSpec {
+        ScaffoldSpec {
+            name: "weather-report".into(),
+            description: "Fetch and summarize the local weather".into(),
+            tags: vec!["weather".into()],
+            prompt: "When the user asks about weather, use the fetch_weather tool.".into(),
+            tools: vec![ScaffoldTool {
+                name: "fetch_weather".into(),
+                description: "Fetch weather for a city".into(),
+                kind: "http".into(),
+                command: String::new(),
+            }],
+            examples: vec!["What's the weather in Tokyo?".into()],
+        }
+    }
+
+    #[test]
+    fn parse_plain_json() {
+        let json = serde_json::to_string(&sample_spec()).unwrap();
+        let spec = parse_scaffold_response(&json).unwrap();
+        assert_eq!(spec.name, "weather-report");
+        assert_eq!(spec.tools.len(), 1);
+    }
+
+    #[test]
+    fn parse_fenced_json() {
+        let json = serde_json::to_string(&sample_spec()).unwrap();
+        let fenced = format!("```json\n{json}\n```");
+        let spec = parse_scaffold_response(&fenced).unwrap();
+        assert_eq!(spec.name, "weather-report");
+    }
+
+    #[test]
+    fn parse_rejects_prose() {
+        assert!(parse_scaffold_response("I cannot generate that skill.").is_err());
+    }
+
+    #[test]
+    fn parse_rejects_empty_name() {
+        let result = parse_scaffold_response(r#"{"name": " ", "description": "d", "prompt": "p"}"#);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn write_scaffold_is_disabled_by_default() {
+        let tmp = TempDir::new().unwrap();
+        let skills_dir = tmp.path().join("skills");
+        let dir = write_scaffold(&skills_dir, &sample_spec()).unwrap();
+        assert!(dir.join("SKILL.toml.disabled").exists());
+        assert!(dir.join("README.md").exists());
+        assert!(!dir.join("SKILL.toml").exists());
+
+        // The loader must not pick up the disabled scaffold.
+        let skills = crate::skills::load_skills(tmp.path());
+        assert!(skills.iter().all(|s| s.name != "weather-report"));
+    }
+
+    #[test]
+    fn write_scaffold_manifest_parses_when_enabled() {
+        let tmp = TempDir::new().unwrap();
+        let skills_dir = tmp.path().join("skills");
+        let dir = write_scaffold(&skills_dir, &sample_spec()).unwrap();
+        fs::rename(dir.join("SKILL.toml.disabled"), dir.join("SKILL.toml")).unwrap();
+
+        let skills = crate::skills::load_skills(tmp.path());
+        let skill = skills
+            .iter()
+            .find(|s| s.name == "weather-report")
+            .expect("enabled scaffold should load");
+        assert_eq!(skill.tools.len(), 1);
+        assert!(skill.tools[0].command.contains("TODO"));
+    }
+
+    #[test]
+    fn write_scaffold_rejects_duplicate() {
+        let tmp = TempDir::new().unwrap();
+        write_scaffold(tmp.path(), &sample_spec()).unwrap();
+        assert!(write_scaffold(tmp.path(), &sample_spec()).is_err());
+    }
+}
@@ -149,7 +149,7 @@ fn escape_toml(s: &str) -> String {
 
 /// Sanitize a string for use as a single path component.
 /// Rejects empty names, "..", and names containing path separators or NUL.
-fn sanitize_path_component(name: &str) -> Result<String> {
+pub(crate) fn sanitize_path_component(name: &str) -> Result<String> {
     let trimmed = name.trim().trim_matches('.');
     if trimmed.is_empty() {
         bail!("Skill name is empty or only dots after sanitization");
@@ -5,6 +5,7 @@
 //! ZeroClaw-compatible manifests for qualified candidates.
 
 pub mod evaluate;
+pub mod generate;
 pub mod integrate;
 pub mod scout;
 
@@ -0,0 +1,384 @@
+//! Terraform plan summarizer: risk-ranked change review for IaC setups.
+//!
+//! Accepts machine-readable plan output — either the JSONL stream from
+//! `terraform plan -json` or the single JSON document from
+//! `terraform show -json <planfile>` — via CLI pipe/file or the gateway's
+//! `POST /hooks/terraform` endpoint. Changes are risk-ranked deterministically
+//! (destroys/replaces and sensitive resource types first), then the default
+//! provider turns the digest into a reviewer-facing summary that can be
+//! posted to a channel for approval.
+
+use crate::config::Config;
+use anyhow::{bail, Context, Result};
+use serde::Serialize;
+
+/// Keywords marking resource types whose changes deserve extra scrutiny.
+const SENSITIVE_TYPE_KEYWORDS: &[&str] = &[
+    "iam",
+    "security_group",
+    "firewall",
+    "acl",
+    "policy",
+    "secret",
+    "kms",
+    "certificate",
+    "role",
+];
+
+/// Risk rank for a single planned change, highest first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Risk {
+    High,
+    Medium,
+    Low,
+}
+
+impl Risk {
+    fn label(self) -> &'static str {
+        match self {
+            Self::High => "HIGH",
+            Self::Medium => "MEDIUM",
+            Self::Low => "LOW",
+        }
+    }
+}
+
+/// One planned resource change extracted from the plan output.
+#[derive(Debug, Clone, Serialize)]
+pub struct PlannedChange {
+    /// Resource address (e.g. `aws_iam_role.deploy`).
+    pub address: String,
+    /// Normalized action: create, update, delete, or replace.
+    pub action: String,
+    /// Deterministic risk rank.
+    pub risk: Risk,
+}
+
+/// Deterministic digest of a Terraform plan.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct PlanDigest {
+    pub creates: usize,
+    pub updates: usize,
+    pub deletes: usize,
+    pub replaces: usize,
+    pub changes: Vec<PlannedChange>,
+}
+
+impl PlanDigest {
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Render the digest as markdown, grouped by risk (highest first).
+    pub fn render(&self) -> String {
+        use std::fmt::Write;
+
+        if self.is_empty() {
+            return "No changes. Infrastructure matches the configuration.".to_string();
+        }
+
+        let mut out = format!(
+            "Plan: {} to add, {} to change, {} to destroy, {} to replace\n",
+            self.creates, self.updates, self.deletes, self.replaces
+        );
+        for risk in [Risk::High, Risk::Medium, Risk::Low] {
+            let group: Vec<&PlannedChange> =
+                self.changes.iter().filter(|c| c.risk == risk).collect();
+            if group.is_empty() {
+                continue;
+            }
+            let _ = write!(out, "\n{} risk:\n", risk.label());
+            for change in group {
+                let _ = writeln!(out, "- {} {}", change.action, change.address);
+            }
+        }
+        out
+    }
+}
+
+/// Rank a change by action and resource type.
+fn rank(address: &str, action: &str) -> Risk {
+    let sensitive = {
+        let address = address.to_ascii_lowercase();
+        SENSITIVE_TYPE_KEYWORDS
+            .iter()
+            .any(|keyword| address.contains(keyword))
+    };
+    match action {
+        "delete" | "replace" => Risk::High,
+        "update" if sensitive => Risk::High,
+        "update" => Risk::Medium,
+        "create" if sensitive => Risk::Medium,
+        _ => Risk::Low,
+    }
+}
+
+/// Parse plan output in either supported shape into a digest.
+///
+/// Tries the single-document `terraform show -json` form first
+/// (`resource_changes` array), then falls back to the JSONL stream from
+/// `terraform plan -json` (`planned_change` lines).
+pub fn parse_plan(raw: &str) -> Result<PlanDigest> {
+    let raw = raw.trim();
+    if raw.is_empty() {
+        bail!("Empty plan input. Pipe `terraform plan -json` or `terraform show -json <planfile>`");
+    }
+
+    if let Ok(doc) = serde_json::from_str::<serde_json::Value>(raw) {
+        if let Some(resource_changes) = doc.get("resource_changes").and_then(|v| v.as_array()) {
+            return Ok(digest_from_resource_changes(resource_changes));
+        }
+    }
+
+    let mut digest = PlanDigest::default();
+    let mut saw_plan_line = false;
+    for line in raw.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let Ok(event) = serde_json::from_str::<serde_json::Value>(line) else {
+            continue;
+        };
+        if event.get("type").and_then(|v| v.as_str()).is_some() {
+            saw_plan_line = true;
+        }
+        if event.get("type").and_then(|v| v.as_str()) != Some("planned_change") {
+            continue;
+        }
+        let Some(change) = event.get("change") else {
+            continue;
+        };
+        let address = change
+            .pointer("/resource/addr")
+            .and_then(|v| v.as_str())
+            .unwrap_or("(unknown)")
+            .to_string();
+        let action = change
+            .get("action")
+            .and_then(|v| v.as_str())
+            .unwrap_or("unknown")
+            .to_string();
+        record_change(&mut digest, address, &action);
+    }
+
+    if !saw_plan_line {
+        bail!(
+            "Input is not recognizable Terraform plan JSON \
+             (expected `resource_changes` or `planned_change` events)"
+        );
+    }
+    digest.changes.sort_by_key(|c| c.risk);
+    Ok(digest)
+}
+
+fn digest_from_resource_changes(resource_changes: &[serde_json::Value]) -> PlanDigest {
+    let mut digest = PlanDigest::default();
+    for resource in resource_changes {
+        let address = resource
+            .get("address")
+            .and_then(|v| v.as_str())
+            .unwrap_or("(unknown)")
+            .to_string();
+        let actions: Vec<&str> = resource
+            .pointer("/change/actions")
+            .and_then(|v| v.as_array())
+            .map(|a| a.iter().filter_map(|v| v.as_str()).collect())
+            .unwrap_or_default();
+        let action = match actions.as_slice() {
+            ["no-op" | "read"] | [] => continue,
+            ["delete", "create"] | ["create", "delete"] => "replace",
+            ["create"] => "create",
+            ["delete"] => "delete",
+            _ => "update",
+        };
+        record_change(&mut digest, address, action);
+    }
+    digest.changes.sort_by_key(|c| c.risk);
+    digest
+}
+
+fn record_change(digest: &mut PlanDigest, address: String, action: &str) {
+    match action {
+        "create" => digest.creates += 1,
+        "update" => digest.updates += 1,
+        "delete" => digest.deletes += 1,
+        "replace" => digest.replaces += 1,
+        _ => return,
+    }
+    let risk = rank(&address, action);
+    digest.changes.push(PlannedChange {
+        address,
+        action: action.to_string(),
+        risk,
+    });
+}
+
+/// System prompt for the reviewer summary.
+pub const REVIEW_SYSTEM_PROMPT: &str = "You are an infrastructure change reviewer for a \
+    homelab/IaC setup. Given a risk-ranked Terraform plan digest, produce a short summary for \
+    a human approver: lead with the highest-risk changes and why they matter, call out anything \
+    destructive or security-relevant, and end with a clear APPROVE / REVIEW CAREFULLY / BLOCK \
+    recommendation. Be concise; do not invent changes that are not in the digest.";
+
+/// Ask the default provider for a reviewer-facing summary of the digest.
+pub async fn review_with_agent(config: &Config, digest: &PlanDigest) -> Result<String> {
+    let provider_name = config
+        .default_provider
+        .as_deref()
+        .context("No default provider configured")?;
+    let model = config
+        .default_model
+        .as_deref()
+        .context("No default model configured")?;
+    let provider = crate::providers::create_provider_with_url(
+        provider_name,
+        config.api_key.as_deref(),
+        config.api_url.as_deref(),
+    )?;
+    provider
+        .chat_with_system(
+            Some(REVIEW_SYSTEM_PROMPT),
+            &digest.render(),
+            model,
+            config.default_temperature,
+        )
+        .await
+}
+
+/// Summarize a plan: digest + optional agent review + optional channel post.
+pub async fn summarize(
+    config: &Config,
+    raw: &str,
+    use_agent: bool,
+    channel: Option<&str>,
+    target: Option<&str>,
+) -> Result<String> {
+    let digest = parse_plan(raw)?;
+    let summary = if use_agent && !digest.is_empty() {
+        let review = review_with_agent(config, &digest).await?;
+        format!("{}\n{review}", digest.render())
+    } else {
+        digest.render()
+    };
+
+    if let Some(channel) = channel {
+        let target = target.context("--to is required when --channel is set")?;
+        crate::channels::send_once(config, channel, target, &summary).await?;
+        println!("📤 Posted plan summary to {channel}:{target}");
+    }
+
+    Ok(summary)
+}
+
+#[allow(clippy::needless_pass_by_value)]
+pub async fn handle_command(command: crate::TerraformCommands, config: &Config) -> Result<()> {
+    match command {
+        crate::TerraformCommands::Summarize {
+            file,
+            channel,
+            to,
+            no_agent,
+        } => {
+            let raw = match file {
+                Some(path) => std::fs::read_to_string(&path)
+                    .with_context(|| format!("Failed to read plan file: {}", path.display()))?,
+                None => {
+                    use std::io::Read;
+                    let mut buffer = String::new();
+                    std::io::stdin()
+                        .read_to_string(&mut buffer)
+                        .context("Failed to read plan JSON from stdin")?;
+                    buffer
+                }
+            };
+            let summary =
+                summarize(config, &raw, !no_agent, channel.as_deref(), to.as_deref()).await?;
+            println!("{summary}");
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SHOW_JSON: &str = r#"{
+        "resource_changes": [
+            {"address": "aws_instance.web", "change": {"actions": ["create"]}},
+            {"address": "aws_iam_role.deploy", "change": {"actions": ["update"]}},
+            {"address": "aws_db_instance.main", "change": {"actions": ["delete", "create"]}},
+            {"address": "aws_s3_bucket.logs", "change": {"actions": ["no-op"]}}
+        ]
+    }"#;
+
+    #[test]
+    fn parses_show_json_document() {
+        let digest = parse_plan(SHOW_JSON).unwrap();
+        assert_eq!(digest.creates, 1);
+        assert_eq!(digest.updates, 1);
+        assert_eq!(digest.replaces, 1);
+        assert_eq!(digest.deletes, 0);
+        assert_eq!(digest.changes.len(), 3);
+    }
+
+    #[test]
+    fn parses_plan_jsonl_stream() {
+        let raw = concat!(
+            r#"{"type":"version","terraform":"1.9.0"}"#,
+            "\n",
+            r#"{"type":"planned_change","change":{"resource":{"addr":"aws_instance.web"},"action":"create"}}"#,
+            "\n",
+            r#"{"type":"planned_change","change":{"resource":{"addr":"aws_security_group.edge"},"action":"update"}}"#,
+            "\n",
+            r#"{"type":"change_summary","changes":{"add":1,"change":1,"remove":0}}"#,
+        );
+        let digest = parse_plan(raw).unwrap();
+        assert_eq!(digest.creates, 1);
+        assert_eq!(digest.updates, 1);
+    }
+
+    #[test]
+    fn rejects_non_plan_input() {
+        assert!(parse_plan("").is_err());
+        assert!(parse_plan("just some text").is_err());
+        assert!(parse_plan(r#"{"message": "hello"}"#).is_err());
+    }
+
+    #[test]
+    fn deletes_and_replaces_rank_high() {
+        assert_eq!(rank("aws_instance.web", "delete"), Risk::High);
+        assert_eq!(rank("aws_instance.web", "replace"), Risk::High);
+    }
+
+    #[test]
+    fn sensitive_updates_rank_high() {
+        assert_eq!(rank("aws_iam_role.deploy", "update"), Risk::High);
+        assert_eq!(rank("aws_security_group.edge", "update"), Risk::High);
+        assert_eq!(rank("aws_instance.web", "update"), Risk::Medium);
+    }
+
+    #[test]
+    fn sensitive_creates_rank_medium() {
+        assert_eq!(rank("aws_kms_key.main", "create"), Risk::Medium);
+        assert_eq!(rank("aws_instance.web", "create"), Risk::Low);
+    }
+
+    #[test]
+    fn render_groups_by_risk_highest_first() {
+        let digest = parse_plan(SHOW_JSON).unwrap();
+        let rendered = digest.render();
+        let high = rendered.find("HIGH risk:").unwrap();
+        let low = rendered.find("LOW risk:").unwrap();
+        assert!(high < low);
+        assert!(rendered.contains("replace aws_db_instance.main"));
+    }
+
+    #[test]
+    fn render_empty_plan() {
+        let digest = parse_plan(r#"{"resource_changes": []}"#).unwrap();
+        assert!(digest.render().contains("No changes"));
+    }
+}
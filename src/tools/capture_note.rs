@@ -0,0 +1,347 @@
+//! `capture_note` — append agent-captured notes to an Obsidian vault or a
+//! Notion database, selected by the `[notes]` config section.
+//!
+//! Besides direct agent use, the memory auto-save flow calls this tool when
+//! a message asks the agent to remember something (see
+//! [`is_remember_request`]).
+
+use super::traits::{Tool, ToolResult};
+use crate::config::Config;
+use crate::security::policy::ToolOperation;
+use crate::security::SecurityPolicy;
+use async_trait::async_trait;
+use serde_json::json;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// Where captured notes are appended.
+enum NoteBackend {
+    /// Daily markdown file under `<vault>/zeroclaw-notes/`.
+    Obsidian { vault_dir: PathBuf },
+    /// New page in a Notion database, authenticated with the
+    /// `[integrations.notion]` token.
+    Notion { token: String, database_id: String },
+}
+
+/// Agent tool that captures a note to the configured backend.
+pub struct CaptureNoteTool {
+    backend: NoteBackend,
+    security: Arc<SecurityPolicy>,
+}
+
+impl CaptureNoteTool {
+    /// Build the tool from `[notes]` config. Returns `None` (with a warning
+    /// when enabled but incomplete) if the selected backend is not fully
+    /// configured.
+    pub fn from_config(config: &Config, security: Arc<SecurityPolicy>) -> Option<Self> {
+        if !config.notes.enabled {
+            return None;
+        }
+        let backend = match config.notes.backend.as_str() {
+            "obsidian" => match config.notes.obsidian_vault_dir.as_deref() {
+                Some(dir) if !dir.trim().is_empty() => NoteBackend::Obsidian {
+                    vault_dir: PathBuf::from(shellexpand::tilde(dir.trim()).into_owned()),
+                },
+                _ => {
+                    tracing::warn!(
+                        "[notes] enabled with backend = \"obsidian\" but obsidian_vault_dir is not set — capture_note tool not registered"
+                    );
+                    return None;
+                }
+            },
+            "notion" => {
+                let token = config
+                    .integrations
+                    .notion
+                    .as_ref()
+                    .and_then(|settings| settings.token.as_deref())
+                    .map(str::trim)
+                    .filter(|token| !token.is_empty());
+                let database_id = config
+                    .notes
+                    .notion_database_id
+                    .as_deref()
+                    .map(str::trim)
+                    .filter(|id| !id.is_empty());
+                match (token, database_id) {
+                    (Some(token), Some(database_id)) => NoteBackend::Notion {
+                        token: token.to_string(),
+                        database_id: database_id.to_string(),
+                    },
+                    _ => {
+                        tracing::warn!(
+                            "[notes] enabled with backend = \"notion\" but notion_database_id or the [integrations.notion] token is not set — capture_note tool not registered"
+                        );
+                        return None;
+                    }
+                }
+            }
+            other => {
+                tracing::warn!(
+                    "[notes] unknown backend '{other}' (expected \"obsidian\" or \"notion\") — capture_note tool not registered"
+                );
+                return None;
+            }
+        };
+        Some(Self { backend, security })
+    }
+
+    async fn capture(&self, title: &str, content: &str) -> anyhow::Result<String> {
+        match &self.backend {
+            NoteBackend::Obsidian { vault_dir } => {
+                let notes_dir = vault_dir.join("zeroclaw-notes");
+                tokio::fs::create_dir_all(&notes_dir).await?;
+                let now = chrono::Local::now();
+                let file = notes_dir.join(format!("{}.md", now.format("%Y-%m-%d")));
+                let mut entry = format!("\n## {} {title}\n\n", now.format("%H:%M"));
+                entry.push_str(content.trim());
+                entry.push('\n');
+
+                let mut existing = tokio::fs::read_to_string(&file)
+                    .await
+                    .unwrap_or_else(|_| format!("# Notes {}\n", now.format("%Y-%m-%d")));
+                existing.push_str(&entry);
+                tokio::fs::write(&file, existing).await?;
+                Ok(format!("Note appended to {}", file.display()))
+            }
+            NoteBackend::Notion { token, database_id } => {
+                let url = crate::integrations::notion::append_to_database(
+                    token,
+                    database_id,
+                    title,
+                    content,
+                )
+                .await?;
+                Ok(format!("Note captured to Notion: {url}"))
+            }
+        }
+    }
+}
+
+/// Part of memory auto-save: mirror "remember this" style requests to the
+/// note backend when `capture_note` is registered (`[notes]` configured).
+/// Best-effort — capture failures are logged, never surfaced to the turn.
+pub async fn capture_from_autosave(tools_registry: &[Box<dyn Tool>], message: &str) {
+    if !is_remember_request(message) {
+        return;
+    }
+    let Some(tool) = tools_registry.iter().find(|t| t.name() == "capture_note") else {
+        return;
+    };
+    match tool.execute(json!({ "content": message })).await {
+        Ok(result) if !result.success => {
+            tracing::warn!(
+                "capture_note auto-save failed: {}",
+                result.error.unwrap_or_default()
+            );
+        }
+        Err(e) => tracing::warn!("capture_note auto-save failed: {e}"),
+        Ok(_) => {}
+    }
+}
+
+/// Heuristic used by the memory auto-save flow: does this message ask the
+/// agent to remember or note something down?
+pub fn is_remember_request(message: &str) -> bool {
+    let lower = message.to_lowercase();
+    [
+        "remember this",
+        "remember that",
+        "take a note",
+        "note this down",
+        "write this down",
+    ]
+    .iter()
+    .any(|trigger| lower.contains(trigger))
+}
+
+#[async_trait]
+impl Tool for CaptureNoteTool {
+    fn name(&self) -> &str {
+        "capture_note"
+    }
+
+    fn description(&self) -> &str {
+        "Capture a note for the user. Appends to the configured note backend \
+         (Obsidian vault or Notion database). Use when the user asks to remember, \
+         capture, or write something down for later."
+    }
+
+    fn parameters_schema(&self) -> serde_json::Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "content": {
+                    "type": "string",
+                    "description": "The note text to capture"
+                },
+                "title": {
+                    "type": "string",
+                    "description": "Optional short title for the note"
+                }
+            },
+            "required": ["content"]
+        })
+    }
+
+    async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
+        let content = args
+            .get("content")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Missing 'content' parameter"))?;
+        if content.trim().is_empty() {
+            anyhow::bail!("'content' must not be empty");
+        }
+        let title = args.get("title").and_then(|v| v.as_str()).unwrap_or("Note");
+
+        if let Err(error) = self
+            .security
+            .enforce_tool_operation(ToolOperation::Act, "capture_note")
+        {
+            return Ok(ToolResult {
+                success: false,
+                output: String::new(),
+                error: Some(error),
+            });
+        }
+
+        match self.capture(title, content).await {
+            Ok(output) => Ok(ToolResult {
+                success: true,
+                output,
+                error: None,
+            }),
+            Err(e) => Ok(ToolResult {
+                success: false,
+                output: String::new(),
+                error: Some(format!("Failed to capture note: {e}")),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::security::AutonomyLevel;
+    use tempfile::TempDir;
+
+    fn obsidian_config(vault_dir: &std::path::Path) -> Config {
+        let mut config = Config::default();
+        config.notes.enabled = true;
+        config.notes.obsidian_vault_dir = Some(vault_dir.to_string_lossy().into_owned());
+        config
+    }
+
+    fn obsidian_tool(vault_dir: &std::path::Path) -> CaptureNoteTool {
+        CaptureNoteTool::from_config(
+            &obsidian_config(vault_dir),
+            Arc::new(SecurityPolicy::default()),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn disabled_or_incomplete_config_yields_no_tool() {
+        let security = Arc::new(SecurityPolicy::default());
+        assert!(CaptureNoteTool::from_config(&Config::default(), security.clone()).is_none());
+
+        // Enabled but no vault dir
+        let mut config = Config::default();
+        config.notes.enabled = true;
+        assert!(CaptureNoteTool::from_config(&config, security.clone()).is_none());
+
+        // Notion backend without database id / token
+        config.notes.backend = "notion".into();
+        assert!(CaptureNoteTool::from_config(&config, security.clone()).is_none());
+
+        // Unknown backend
+        config.notes.backend = "carrier-pigeon".into();
+        assert!(CaptureNoteTool::from_config(&config, security).is_none());
+    }
+
+    #[test]
+    fn notion_backend_requires_integration_token() {
+        let mut config = Config::default();
+        config.notes.enabled = true;
+        config.notes.backend = "notion".into();
+        config.notes.notion_database_id = Some("db-id".into());
+        let security = Arc::new(SecurityPolicy::default());
+        assert!(CaptureNoteTool::from_config(&config, security.clone()).is_none());
+
+        config.integrations.notion = Some(crate::config::IntegrationSettings {
+            enabled: true,
+            token: Some("test-token".into()),
+            ..Default::default()
+        });
+        assert!(CaptureNoteTool::from_config(&config, security).is_some());
+    }
+
+    #[tokio::test]
+    async fn obsidian_backend_appends_to_daily_file() {
+        let tmp = TempDir::new().unwrap();
+        let tool = obsidian_tool(tmp.path());
+
+        let result = tool
+            .execute(json!({"content": "Ship the release notes", "title": "Release"}))
+            .await
+            .unwrap();
+        assert!(result.success, "{:?}", result.error);
+
+        let result = tool
+            .execute(json!({"content": "Second note"}))
+            .await
+            .unwrap();
+        assert!(result.success);
+
+        let notes_dir = tmp.path().join("zeroclaw-notes");
+        let file = notes_dir.join(format!("{}.md", chrono::Local::now().format("%Y-%m-%d")));
+        let contents = std::fs::read_to_string(file).unwrap();
+        assert!(contents.contains("Ship the release notes"));
+        assert!(contents.contains("## "));
+        assert!(contents.contains("Second note"));
+    }
+
+    #[tokio::test]
+    async fn execute_missing_content_returns_error() {
+        let tmp = TempDir::new().unwrap();
+        let tool = obsidian_tool(tmp.path());
+        assert!(tool.execute(json!({})).await.is_err());
+        assert!(tool.execute(json!({"content": "  "})).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn capture_blocked_in_readonly_mode() {
+        let tmp = TempDir::new().unwrap();
+        let tool = CaptureNoteTool::from_config(
+            &obsidian_config(tmp.path()),
+            Arc::new(SecurityPolicy {
+                autonomy: AutonomyLevel::ReadOnly,
+                ..SecurityPolicy::default()
+            }),
+        )
+        .unwrap();
+        let result = tool
+            .execute(json!({"content": "secret plan"}))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result
+            .error
+            .as_deref()
+            .unwrap_or("")
+            .contains("read-only mode"));
+        assert!(!tmp.path().join("zeroclaw-notes").exists());
+    }
+
+    #[test]
+    fn is_remember_request_matches_trigger_phrases() {
+        assert!(is_remember_request("Please remember this for next time"));
+        assert!(is_remember_request("Remember THAT the deploy runs at noon"));
+        assert!(is_remember_request(
+            "take a note: rotate the pager schedule"
+        ));
+        assert!(!is_remember_request("I remember the old behavior"));
+        assert!(!is_remember_request("what's the weather"));
+    }
+}
@@ -0,0 +1,200 @@
+use super::traits::{Tool, ToolResult};
+use crate::config::Config;
+use crate::contacts;
+use crate::security::policy::ToolOperation;
+use crate::security::SecurityPolicy;
+use async_trait::async_trait;
+use serde_json::json;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+/// Let the agent add people to the contact book.
+pub struct ContactsAddTool {
+    config: Arc<Config>,
+    security: Arc<SecurityPolicy>,
+}
+
+impl ContactsAddTool {
+    pub fn new(config: Arc<Config>, security: Arc<SecurityPolicy>) -> Self {
+        Self { config, security }
+    }
+}
+
+#[async_trait]
+impl Tool for ContactsAddTool {
+    fn name(&self) -> &str {
+        "contacts_add"
+    }
+
+    fn description(&self) -> &str {
+        "Add a person to the contact book with their channel identities (e.g. slack:U12345, telegram:zeroclaw_user), email, IANA timezone, and notes. Use this when the user introduces someone or shares contact details."
+    }
+
+    fn parameters_schema(&self) -> serde_json::Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "name": {
+                    "type": "string",
+                    "description": "Display name of the contact (e.g. 'Alice')"
+                },
+                "channels": {
+                    "type": "object",
+                    "description": "Channel identities keyed by channel type, e.g. {\"slack\": \"U12345\", \"telegram\": \"zeroclaw_user\"}",
+                    "additionalProperties": { "type": "string" }
+                },
+                "email": {
+                    "type": "string",
+                    "description": "Email address"
+                },
+                "timezone": {
+                    "type": "string",
+                    "description": "IANA timezone name (e.g. 'America/New_York')"
+                },
+                "notes": {
+                    "type": "string",
+                    "description": "Free-form notes about the contact"
+                }
+            },
+            "required": ["name"]
+        })
+    }
+
+    async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
+        let name = args
+            .get("name")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Missing 'name' parameter"))?;
+
+        let mut channels = BTreeMap::new();
+        if let Some(map) = args.get("channels").and_then(|v| v.as_object()) {
+            for (channel_type, identity) in map {
+                let identity = identity.as_str().ok_or_else(|| {
+                    anyhow::anyhow!("Channel identity for '{channel_type}' must be a string")
+                })?;
+                channels.insert(channel_type.to_lowercase(), identity.to_string());
+            }
+        }
+
+        let email = args
+            .get("email")
+            .and_then(|v| v.as_str())
+            .map(ToString::to_string);
+        let timezone = args
+            .get("timezone")
+            .and_then(|v| v.as_str())
+            .map(ToString::to_string);
+        let notes = args
+            .get("notes")
+            .and_then(|v| v.as_str())
+            .map(ToString::to_string);
+
+        if let Err(error) = self
+            .security
+            .enforce_tool_operation(ToolOperation::Act, "contacts_add")
+        {
+            return Ok(ToolResult {
+                success: false,
+                output: String::new(),
+                error: Some(error),
+            });
+        }
+
+        match contacts::add_contact(&self.config, name, channels, email, timezone, notes) {
+            Ok(contact) => Ok(ToolResult {
+                success: true,
+                output: format!("Added contact: {}", contact.summary()),
+                error: None,
+            }),
+            Err(e) => Ok(ToolResult {
+                success: false,
+                output: String::new(),
+                error: Some(format!("Failed to add contact: {e}")),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::security::AutonomyLevel;
+    use tempfile::TempDir;
+
+    fn test_setup(tmp: &TempDir) -> (Arc<Config>, Arc<SecurityPolicy>) {
+        let config = Config {
+            workspace_dir: tmp.path().join("workspace"),
+            config_path: tmp.path().join("config.toml"),
+            ..Config::default()
+        };
+        (Arc::new(config), Arc::new(SecurityPolicy::default()))
+    }
+
+    #[test]
+    fn name_and_schema() {
+        let tmp = TempDir::new().unwrap();
+        let (config, security) = test_setup(&tmp);
+        let tool = ContactsAddTool::new(config, security);
+        assert_eq!(tool.name(), "contacts_add");
+        let schema = tool.parameters_schema();
+        assert!(schema["properties"]["name"].is_object());
+        assert!(schema["properties"]["channels"].is_object());
+    }
+
+    #[tokio::test]
+    async fn add_with_channels_and_timezone() {
+        let tmp = TempDir::new().unwrap();
+        let (config, security) = test_setup(&tmp);
+        let tool = ContactsAddTool::new(config.clone(), security);
+        let result = tool
+            .execute(json!({
+                "name": "user_a",
+                "channels": {"slack": "U12345"},
+                "timezone": "Europe/London"
+            }))
+            .await
+            .unwrap();
+        assert!(result.success, "error: {:?}", result.error);
+
+        let contact = contacts::get_contact(&config, "user_a").unwrap().unwrap();
+        assert_eq!(
+            contact.channels.get("slack").map(String::as_str),
+            Some("U12345")
+        );
+    }
+
+    #[tokio::test]
+    async fn add_missing_name_errors() {
+        let tmp = TempDir::new().unwrap();
+        let (config, security) = test_setup(&tmp);
+        let tool = ContactsAddTool::new(config, security);
+        let result = tool.execute(json!({"email": "user_a@example.com"})).await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn add_invalid_timezone_fails() {
+        let tmp = TempDir::new().unwrap();
+        let (config, security) = test_setup(&tmp);
+        let tool = ContactsAddTool::new(config, security);
+        let result = tool
+            .execute(json!({"name": "user_a", "timezone": "Not/AZone"}))
+            .await
+            .unwrap();
+        assert!(!result.success);
+    }
+
+    #[tokio::test]
+    async fn add_blocked_in_readonly_mode() {
+        let tmp = TempDir::new().unwrap();
+        let (config, _) = test_setup(&tmp);
+        let readonly = Arc::new(SecurityPolicy {
+            autonomy: AutonomyLevel::ReadOnly,
+            ..SecurityPolicy::default()
+        });
+        let tool = ContactsAddTool::new(config.clone(), readonly);
+        let result = tool.execute(json!({"name": "user_a"})).await.unwrap();
+        assert!(!result.success);
+        assert!(contacts::get_contact(&config, "user_a").unwrap().is_none());
+    }
+}
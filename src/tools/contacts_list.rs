@@ -0,0 +1,136 @@
+use super::traits::{Tool, ToolResult};
+use crate::config::Config;
+use crate::contacts;
+use async_trait::async_trait;
+use serde_json::json;
+use std::sync::Arc;
+
+/// Let the agent look up people in the contact book.
+pub struct ContactsListTool {
+    config: Arc<Config>,
+}
+
+impl ContactsListTool {
+    pub fn new(config: Arc<Config>) -> Self {
+        Self { config }
+    }
+}
+
+#[async_trait]
+impl Tool for ContactsListTool {
+    fn name(&self) -> &str {
+        "contacts_list"
+    }
+
+    fn description(&self) -> &str {
+        "Look up people in the contact book. Without arguments, lists all contacts. With 'name', returns full details for one contact (channel identities, email, IANA timezone, notes). Use this to resolve 'message Alice on Slack' to a channel identity or to reason about someone's local time."
+    }
+
+    fn parameters_schema(&self) -> serde_json::Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "name": {
+                    "type": "string",
+                    "description": "Contact name to look up (case-insensitive). Omit to list everyone."
+                }
+            }
+        })
+    }
+
+    async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
+        if let Some(name) = args.get("name").and_then(|v| v.as_str()) {
+            return match contacts::get_contact(&self.config, name)? {
+                Some(contact) => Ok(ToolResult {
+                    success: true,
+                    output: serde_json::to_string_pretty(&contact)?,
+                    error: None,
+                }),
+                None => Ok(ToolResult {
+                    success: false,
+                    output: String::new(),
+                    error: Some(format!("No contact named '{name}'")),
+                }),
+            };
+        }
+
+        let contacts = contacts::list_contacts(&self.config)?;
+        if contacts.is_empty() {
+            return Ok(ToolResult {
+                success: true,
+                output: "No contacts stored yet.".into(),
+                error: None,
+            });
+        }
+
+        let lines: Vec<String> = contacts.iter().map(contacts::Contact::summary).collect();
+        Ok(ToolResult {
+            success: true,
+            output: lines.join("\n"),
+            error: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+    use tempfile::TempDir;
+
+    fn test_config(tmp: &TempDir) -> Arc<Config> {
+        Arc::new(Config {
+            workspace_dir: tmp.path().join("workspace"),
+            config_path: tmp.path().join("config.toml"),
+            ..Config::default()
+        })
+    }
+
+    #[test]
+    fn name_and_schema() {
+        let tmp = TempDir::new().unwrap();
+        let tool = ContactsListTool::new(test_config(&tmp));
+        assert_eq!(tool.name(), "contacts_list");
+        assert!(tool.parameters_schema()["properties"]["name"].is_object());
+    }
+
+    #[tokio::test]
+    async fn list_empty() {
+        let tmp = TempDir::new().unwrap();
+        let tool = ContactsListTool::new(test_config(&tmp));
+        let result = tool.execute(json!({})).await.unwrap();
+        assert!(result.success);
+        assert!(result.output.contains("No contacts"));
+    }
+
+    #[tokio::test]
+    async fn lookup_by_name_returns_details() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp);
+        let mut channels = BTreeMap::new();
+        channels.insert("slack".to_string(), "U12345".to_string());
+        contacts::add_contact(
+            &config,
+            "user_a",
+            channels,
+            None,
+            Some("Asia/Tokyo".into()),
+            None,
+        )
+        .unwrap();
+
+        let tool = ContactsListTool::new(config);
+        let result = tool.execute(json!({"name": "USER_A"})).await.unwrap();
+        assert!(result.success);
+        assert!(result.output.contains("U12345"));
+        assert!(result.output.contains("Asia/Tokyo"));
+    }
+
+    #[tokio::test]
+    async fn lookup_missing_contact_fails() {
+        let tmp = TempDir::new().unwrap();
+        let tool = ContactsListTool::new(test_config(&tmp));
+        let result = tool.execute(json!({"name": "nobody"})).await.unwrap();
+        assert!(!result.success);
+    }
+}
@@ -0,0 +1,334 @@
+use super::traits::{Tool, ToolResult};
+use crate::config::ContainersConfig;
+use crate::security::policy::ToolOperation;
+use crate::security::SecurityPolicy;
+use async_trait::async_trait;
+use serde_json::json;
+use std::process::Stdio;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Maximum combined stdout/stderr size in bytes (256KB).
+const MAX_OUTPUT_BYTES: usize = 262_144;
+/// Default number of log lines fetched by the `logs` action.
+const DEFAULT_LOG_TAIL: u64 = 100;
+
+/// Read-only actions available without approval.
+const READ_ACTIONS: &[&str] = &["list", "images", "logs", "inspect"];
+/// Mutating actions that always require explicit approval.
+const MUTATING_ACTIONS: &[&str] = &["restart", "prune"];
+
+/// Container management tool (Docker/Podman CLI).
+///
+/// Read-only actions (list, images, logs, inspect) run freely; mutating
+/// actions (restart, prune) are approval-gated — the model must pass
+/// `approved: true` after the user confirms. The engine endpoint is
+/// configurable via `containers.endpoint` (`DOCKER_HOST`).
+pub struct ContainersTool {
+    config: ContainersConfig,
+    security: Arc<SecurityPolicy>,
+}
+
+impl ContainersTool {
+    pub fn new(config: ContainersConfig, security: Arc<SecurityPolicy>) -> Self {
+        Self { config, security }
+    }
+
+    /// Validate a container/image reference to prevent flag injection.
+    fn validate_target(target: &str) -> Result<(), String> {
+        let valid = !target.is_empty()
+            && !target.starts_with('-')
+            && target
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-' | ':' | '/'));
+        if valid {
+            Ok(())
+        } else {
+            Err(format!("Invalid container/image reference: '{target}'"))
+        }
+    }
+
+    fn engine_args(action: &str, target: Option<&str>, tail: u64) -> Vec<String> {
+        match action {
+            "list" => vec!["ps".into(), "--all".into()],
+            "images" => vec!["images".into()],
+            "logs" => vec![
+                "logs".into(),
+                "--tail".into(),
+                tail.to_string(),
+                target.unwrap_or_default().into(),
+            ],
+            "inspect" => vec!["inspect".into(), target.unwrap_or_default().into()],
+            "restart" => vec!["restart".into(), target.unwrap_or_default().into()],
+            "prune" => vec!["system".into(), "prune".into(), "--force".into()],
+            _ => Vec::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl Tool for ContainersTool {
+    fn name(&self) -> &str {
+        "containers"
+    }
+
+    fn description(&self) -> &str {
+        "Manage containers via Docker/Podman. Read-only actions: 'list' (all containers), 'images', 'logs' (needs target, optional tail), 'inspect' (needs target). Mutating actions 'restart' (needs target) and 'prune' require user confirmation first — pass approved=true only after the user explicitly agrees."
+    }
+
+    fn parameters_schema(&self) -> serde_json::Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "action": {
+                    "type": "string",
+                    "enum": ["list", "images", "logs", "inspect", "restart", "prune"],
+                    "description": "Container operation to perform"
+                },
+                "target": {
+                    "type": "string",
+                    "description": "Container or image name/ID (required for logs, inspect, restart)"
+                },
+                "tail": {
+                    "type": "integer",
+                    "description": "Number of log lines to fetch (logs action, default 100)"
+                },
+                "approved": {
+                    "type": "boolean",
+                    "description": "Set true to confirm a mutating action (restart, prune) after explicit user approval",
+                    "default": false
+                }
+            },
+            "required": ["action"]
+        })
+    }
+
+    async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
+        let action = args
+            .get("action")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Missing 'action' parameter"))?;
+        let target = args.get("target").and_then(|v| v.as_str());
+        let tail = args
+            .get("tail")
+            .and_then(|v| v.as_u64())
+            .unwrap_or(DEFAULT_LOG_TAIL);
+        let approved = args
+            .get("approved")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+
+        let mutating = MUTATING_ACTIONS.contains(&action);
+        if !mutating && !READ_ACTIONS.contains(&action) {
+            return Ok(ToolResult {
+                success: false,
+                output: String::new(),
+                error: Some(format!(
+                    "Unknown action '{action}' (supported: list, images, logs, inspect, restart, prune)"
+                )),
+            });
+        }
+
+        if matches!(action, "logs" | "inspect" | "restart") {
+            let Some(target) = target else {
+                return Ok(ToolResult {
+                    success: false,
+                    output: String::new(),
+                    error: Some(format!("Action '{action}' requires a 'target'")),
+                });
+            };
+            if let Err(reason) = Self::validate_target(target) {
+                return Ok(ToolResult {
+                    success: false,
+                    output: String::new(),
+                    error: Some(reason),
+                });
+            }
+        }
+
+        if mutating && !approved {
+            return Ok(ToolResult {
+                success: false,
+                output: String::new(),
+                error: Some(format!(
+                    "Action '{action}' mutates container state and requires explicit user approval (approved=true)"
+                )),
+            });
+        }
+
+        let operation = if mutating {
+            ToolOperation::Act
+        } else {
+            ToolOperation::Read
+        };
+        if let Err(error) = self
+            .security
+            .enforce_tool_operation(operation, "containers")
+        {
+            return Ok(ToolResult {
+                success: false,
+                output: String::new(),
+                error: Some(error),
+            });
+        }
+
+        let mut cmd = tokio::process::Command::new(self.config.engine.trim());
+        cmd.args(Self::engine_args(action, target, tail));
+        cmd.env_clear();
+        if let Ok(path) = std::env::var("PATH") {
+            cmd.env("PATH", path);
+        }
+        if let Some(endpoint) = &self.config.endpoint {
+            cmd.env("DOCKER_HOST", endpoint.trim());
+        }
+        cmd.stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true);
+
+        let result =
+            tokio::time::timeout(Duration::from_secs(self.config.timeout_secs), cmd.output()).await;
+
+        match result {
+            Ok(Ok(output)) => {
+                let mut stdout = String::from_utf8_lossy(&output.stdout).to_string();
+                let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+                if stdout.len() > MAX_OUTPUT_BYTES {
+                    stdout.truncate(MAX_OUTPUT_BYTES);
+                    stdout.push_str("\n...[truncated]");
+                }
+
+                if output.status.success() {
+                    Ok(ToolResult {
+                        success: true,
+                        output: if stdout.trim().is_empty() {
+                            format!("(no output from '{action}')")
+                        } else {
+                            stdout
+                        },
+                        error: None,
+                    })
+                } else {
+                    Ok(ToolResult {
+                        success: false,
+                        output: stdout,
+                        error: Some(format!("Engine command failed: {}", stderr.trim())),
+                    })
+                }
+            }
+            Ok(Err(e)) => Ok(ToolResult {
+                success: false,
+                output: String::new(),
+                error: Some(format!(
+                    "Failed to run container engine '{}': {e}. Is it installed?",
+                    self.config.engine
+                )),
+            }),
+            Err(_) => Ok(ToolResult {
+                success: false,
+                output: String::new(),
+                error: Some(format!(
+                    "Engine command timed out after {}s",
+                    self.config.timeout_secs
+                )),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::security::AutonomyLevel;
+
+    fn test_tool() -> ContainersTool {
+        ContainersTool::new(
+            ContainersConfig::default(),
+            Arc::new(SecurityPolicy::default()),
+        )
+    }
+
+    #[test]
+    fn name_and_schema() {
+        let tool = test_tool();
+        assert_eq!(tool.name(), "containers");
+        let schema = tool.parameters_schema();
+        assert!(schema["properties"]["action"].is_object());
+        assert!(schema["properties"]["approved"].is_object());
+    }
+
+    #[test]
+    fn validate_target_accepts_references() {
+        assert!(ContainersTool::validate_target("zeroclaw_node").is_ok());
+        assert!(ContainersTool::validate_target("registry.example.com/app:1.0").is_ok());
+        assert!(ContainersTool::validate_target("a1b2c3d4").is_ok());
+    }
+
+    #[test]
+    fn validate_target_rejects_flags_and_shell() {
+        assert!(ContainersTool::validate_target("--privileged").is_err());
+        assert!(ContainersTool::validate_target("a; rm -rf /").is_err());
+        assert!(ContainersTool::validate_target("").is_err());
+    }
+
+    #[tokio::test]
+    async fn unknown_action_fails() {
+        let tool = test_tool();
+        let result = tool.execute(json!({"action": "exec"})).await.unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("Unknown action"));
+    }
+
+    #[tokio::test]
+    async fn logs_requires_target() {
+        let tool = test_tool();
+        let result = tool.execute(json!({"action": "logs"})).await.unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("requires a 'target'"));
+    }
+
+    #[tokio::test]
+    async fn mutating_action_requires_approval() {
+        let tool = test_tool();
+        let result = tool
+            .execute(json!({"action": "restart", "target": "zeroclaw_node"}))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("approved=true"));
+    }
+
+    #[tokio::test]
+    async fn prune_requires_approval() {
+        let tool = test_tool();
+        let result = tool.execute(json!({"action": "prune"})).await.unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("approved=true"));
+    }
+
+    #[tokio::test]
+    async fn approved_mutation_blocked_in_readonly_mode() {
+        let readonly = Arc::new(SecurityPolicy {
+            autonomy: AutonomyLevel::ReadOnly,
+            ..SecurityPolicy::default()
+        });
+        let tool = ContainersTool::new(ContainersConfig::default(), readonly);
+        let result = tool
+            .execute(json!({"action": "restart", "target": "zeroclaw_node", "approved": true}))
+            .await
+            .unwrap();
+        assert!(!result.success);
+    }
+
+    #[tokio::test]
+    async fn missing_engine_reports_error() {
+        let config = ContainersConfig {
+            engine: "definitely-not-a-container-engine".into(),
+            ..ContainersConfig::default()
+        };
+        let tool = ContainersTool::new(config, Arc::new(SecurityPolicy::default()));
+        let result = tool.execute(json!({"action": "list"})).await.unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("Is it installed?"));
+    }
+}
@@ -41,6 +41,11 @@ impl Tool for CronAddTool {
                 "prompt": { "type": "string" },
                 "session_target": { "type": "string", "enum": ["isolated", "main"] },
                 "model": { "type": "string" },
+                "tags": {
+                    "type": "array",
+                    "items": { "type": "string" },
+                    "description": "Tags (e.g. 'project:foo') written into delegation events for cost attribution"
+                },
                 "delivery": { "type": "object" },
                 "delete_after_run": { "type": "boolean" }
             },
@@ -161,6 +166,18 @@ impl Tool for CronAddTool {
                     .and_then(serde_json::Value::as_str)
                     .map(str::to_string);
 
+                let tags: Vec<String> = args
+                    .get("tags")
+                    .and_then(serde_json::Value::as_array)
+                    .map(|items| {
+                        items
+                            .iter()
+                            .filter_map(serde_json::Value::as_str)
+                            .map(str::to_string)
+                            .collect()
+                    })
+                    .unwrap_or_default();
+
                 let delivery = match args.get("delivery") {
                     Some(v) => match serde_json::from_value::<DeliveryConfig>(v.clone()) {
                         Ok(cfg) => Some(cfg),
@@ -182,6 +199,7 @@ impl Tool for CronAddTool {
                     prompt,
                     session_target,
                     model,
+                    tags,
                     delivery,
                     delete_after_run,
                 )
@@ -1,6 +1,6 @@
 use super::traits::{Tool, ToolResult};
 use crate::agent::loop_::run_tool_call_loop;
-use crate::config::DelegateAgentConfig;
+use crate::config::{DelegateAgentConfig, DelegationConfig};
 use crate::observability::traits::{Observer, ObserverEvent, ObserverMetric};
 use crate::providers::{self, ChatMessage, Provider};
 use crate::security::policy::ToolOperation;
@@ -11,12 +11,72 @@ use serde_json::json;
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
+use tracing::Instrument as _;
 
 /// Default timeout for sub-agent provider calls.
 const DELEGATE_TIMEOUT_SECS: u64 = 120;
 /// Default timeout for agentic sub-agent runs.
 const DELEGATE_AGENTIC_TIMEOUT_SECS: u64 = 300;
 
+/// Run-level delegation counters enforcing the `[delegation]` caps
+/// (concurrent sub-agents, total delegations per run). Shared by all
+/// delegations within one tool registry; a limit of `0` disables that guard.
+pub struct DelegationGuards {
+    config: DelegationConfig,
+    /// (active sub-agents, total delegation attempts this run)
+    counters: Mutex<(usize, usize)>,
+}
+
+impl DelegationGuards {
+    pub fn new(config: DelegationConfig) -> Self {
+        Self {
+            config,
+            counters: Mutex::new((0, 0)),
+        }
+    }
+
+    fn max_depth(&self) -> u32 {
+        self.config.max_depth
+    }
+
+    /// Admit one delegation, or explain which cap would be exceeded.
+    /// The returned permit releases the concurrency slot on drop.
+    fn try_acquire(self: &Arc<Self>) -> Result<DelegationPermit, String> {
+        let mut counters = self.counters.lock();
+        let (active, total) = *counters;
+        if self.config.max_per_run > 0 && total >= self.config.max_per_run {
+            return Err(format!(
+                "Delegation budget exhausted ({total}/{} this run). \
+                 Raise [delegation] max_per_run to allow more.",
+                self.config.max_per_run
+            ));
+        }
+        if self.config.max_concurrent > 0 && active >= self.config.max_concurrent {
+            return Err(format!(
+                "Too many concurrent sub-agents ({active}/{}). \
+                 Wait for running delegations to finish or raise [delegation] max_concurrent.",
+                self.config.max_concurrent
+            ));
+        }
+        *counters = (active + 1, total + 1);
+        Ok(DelegationPermit {
+            guards: Arc::clone(self),
+        })
+    }
+}
+
+/// RAII permit for one running delegation; frees the concurrency slot on drop.
+struct DelegationPermit {
+    guards: Arc<DelegationGuards>,
+}
+
+impl Drop for DelegationPermit {
+    fn drop(&mut self) {
+        let mut counters = self.guards.counters.lock();
+        counters.0 = counters.0.saturating_sub(1);
+    }
+}
+
 /// Tool that delegates a subtask to a named agent with a different
 /// provider/model configuration. Enables multi-agent workflows where
 /// a primary agent can hand off specialized work (research, coding,
@@ -38,6 +98,12 @@ pub struct DelegateTool {
     /// When present, child agent events are forwarded to this observer
     /// instead of being discarded, enabling delegation tree visibility.
     parent_observer: Option<Arc<dyn Observer>>,
+    /// Run-level guards shared across nested delegations.
+    guards: Arc<DelegationGuards>,
+    /// Dispatcher for federated worker nodes (`[nodes]` section).
+    /// `None` when remote dispatch is disabled; agents pinned to a node
+    /// then fail fast instead of silently running locally.
+    nodes: Option<Arc<crate::nodes::NodeDispatcher>>,
 }
 
 impl DelegateTool {
@@ -69,6 +135,8 @@ impl DelegateTool {
             parent_tools: Arc::new(Vec::new()),
             multimodal_config: crate::config::MultimodalConfig::default(),
             parent_observer: None,
+            guards: Arc::new(DelegationGuards::new(DelegationConfig::default())),
+            nodes: None,
         }
     }
 
@@ -106,6 +174,8 @@ impl DelegateTool {
             parent_tools: Arc::new(Vec::new()),
             multimodal_config: crate::config::MultimodalConfig::default(),
             parent_observer: None,
+            guards: Arc::new(DelegationGuards::new(DelegationConfig::default())),
+            nodes: None,
         }
     }
 
@@ -130,6 +200,18 @@ impl DelegateTool {
         self.multimodal_config = config;
         self
     }
+
+    /// Attach run-level delegation guards from `[delegation]` config.
+    pub fn with_guards(mut self, config: DelegationConfig) -> Self {
+        self.guards = Arc::new(DelegationGuards::new(config));
+        self
+    }
+
+    /// Attach the worker-node dispatcher from `[nodes]` config.
+    pub fn with_nodes(mut self, nodes: Option<Arc<crate::nodes::NodeDispatcher>>) -> Self {
+        self.nodes = nodes;
+        self
+    }
 }
 
 #[async_trait]
@@ -232,17 +314,24 @@ impl Tool for DelegateTool {
             }
         };
 
-        // Check recursion depth (immutable — set at construction, incremented for sub-agents)
-        if self.depth >= agent_config.max_depth {
+        // Check recursion depth (immutable — set at construction, incremented
+        // for sub-agents). The effective limit is the smaller of the agent's
+        // max_depth and the global [delegation] cap.
+        let mut max_depth = agent_config.max_depth;
+        if self.guards.max_depth() > 0 {
+            max_depth = max_depth.min(self.guards.max_depth());
+        }
+        if self.depth >= max_depth {
+            let reason = format!(
+                "Delegation depth limit reached ({depth}/{max_depth}). \
+                 Cannot delegate further to prevent infinite loops.",
+                depth = self.depth,
+            );
+            self.record_blocked(agent_name, agent_config, &reason);
             return Ok(ToolResult {
                 success: false,
                 output: String::new(),
-                error: Some(format!(
-                    "Delegation depth limit reached ({depth}/{max}). \
-                     Cannot delegate further to prevent infinite loops.",
-                    depth = self.depth,
-                    max = agent_config.max_depth
-                )),
+                error: Some(reason),
             });
         }
 
@@ -257,6 +346,49 @@ impl Tool for DelegateTool {
             });
         }
 
+        // Fan-out guards: per-run budget and concurrency. The permit holds a
+        // concurrency slot for the duration of the sub-agent run.
+        let _permit = match self.guards.try_acquire() {
+            Ok(permit) => permit,
+            Err(reason) => {
+                self.record_blocked(agent_name, agent_config, &reason);
+                return Ok(ToolResult {
+                    success: false,
+                    output: String::new(),
+                    error: Some(reason),
+                });
+            }
+        };
+
+        // Build the message
+        let full_prompt = if context.is_empty() {
+            prompt.to_string()
+        } else {
+            format!("[Context]\n{context}\n\n[Task]\n{prompt}")
+        };
+
+        // Correlation span: log lines from this delegation carry the
+        // delegation-log run_id, agent, and depth in JSON log mode.
+        let span = tracing::info_span!(
+            "delegation",
+            run_id = self.parent_observer.as_deref().and_then(Observer::run_id),
+            agent = %agent_name,
+            depth = self.depth + 1,
+        );
+
+        // Remote dispatch: agents pinned to a worker node run on that
+        // node's gateway instead of creating a local provider. Returns
+        // `None` only when local failover is allowed.
+        if let Some(node_name) = agent_config.node.as_deref() {
+            if let Some(result) = self
+                .execute_remote(agent_name, agent_config, node_name, &full_prompt)
+                .instrument(span.clone())
+                .await
+            {
+                return Ok(result);
+            }
+        }
+
         // Create provider for this agent
         let provider_credential_owned = agent_config
             .api_key
@@ -283,13 +415,6 @@ impl Tool for DelegateTool {
             }
         };
 
-        // Build the message
-        let full_prompt = if context.is_empty() {
-            prompt.to_string()
-        } else {
-            format!("[Context]\n{context}\n\n[Task]\n{prompt}")
-        };
-
         let temperature = agent_config.temperature.unwrap_or(0.7);
 
         // Agentic mode: run full tool-call loop with allowlisted tools.
@@ -302,42 +427,47 @@ impl Tool for DelegateTool {
                     &full_prompt,
                     temperature,
                 )
+                .instrument(span)
                 .await;
         }
 
+        // Emit DelegationStart so single-shot runs show up in the
+        // delegation log alongside agentic ones.
+        if let Some(parent) = &self.parent_observer {
+            parent.record_event(&ObserverEvent::DelegationStart {
+                agent_name: agent_name.to_string(),
+                provider: agent_config.provider.clone(),
+                model: agent_config.model.clone(),
+                depth: self.depth + 1,
+                agentic: false,
+                workflow: None,
+            });
+        }
+
+        let start_time = std::time::Instant::now();
         // Wrap the provider call in a timeout to prevent indefinite blocking
         let result = tokio::time::timeout(
             Duration::from_secs(DELEGATE_TIMEOUT_SECS),
-            provider.chat_with_system(
-                agent_config.system_prompt.as_deref(),
-                &full_prompt,
-                &agent_config.model,
-                temperature,
-            ),
+            provider
+                .chat_with_system(
+                    agent_config.system_prompt.as_deref(),
+                    &full_prompt,
+                    &agent_config.model,
+                    temperature,
+                )
+                .instrument(span),
         )
         .await;
+        let duration = start_time.elapsed();
 
-        let result = match result {
-            Ok(inner) => inner,
-            Err(_elapsed) => {
-                return Ok(ToolResult {
-                    success: false,
-                    output: String::new(),
-                    error: Some(format!(
-                        "Agent '{agent_name}' timed out after {DELEGATE_TIMEOUT_SECS}s"
-                    )),
-                });
-            }
-        };
-
-        match result {
-            Ok(response) => {
+        let tool_result = match result {
+            Ok(Ok(response)) => {
                 let mut rendered = response;
                 if rendered.trim().is_empty() {
                     rendered = "[Empty response]".to_string();
                 }
 
-                Ok(ToolResult {
+                ToolResult {
                     success: true,
                     output: format!(
                         "[Agent '{agent_name}' ({provider}/{model})]\n{rendered}",
@@ -345,18 +475,181 @@ impl Tool for DelegateTool {
                         model = agent_config.model
                     ),
                     error: None,
-                })
+                }
             }
-            Err(e) => Ok(ToolResult {
+            Ok(Err(e)) => ToolResult {
                 success: false,
                 output: String::new(),
-                error: Some(format!("Agent '{agent_name}' failed: {e}",)),
-            }),
+                error: Some(format!("Agent '{agent_name}' failed: {e}")),
+            },
+            Err(_elapsed) => ToolResult {
+                success: false,
+                output: String::new(),
+                error: Some(format!(
+                    "Agent '{agent_name}' timed out after {DELEGATE_TIMEOUT_SECS}s"
+                )),
+            },
+        };
+
+        if let Some(parent) = &self.parent_observer {
+            parent.record_event(&ObserverEvent::DelegationEnd {
+                agent_name: agent_name.to_string(),
+                provider: agent_config.provider.clone(),
+                model: agent_config.model.clone(),
+                depth: self.depth + 1,
+                duration,
+                success: tool_result.success,
+                error_message: tool_result.error.clone(),
+                tokens_used: None,
+                cost_usd: None,
+                workflow: None,
+                node: None,
+            });
         }
+
+        Ok(tool_result)
     }
 }
 
 impl DelegateTool {
+    /// Record a guard-blocked delegation in the delegation log as a
+    /// start/end pair so blocked attempts are visible alongside real runs.
+    fn record_blocked(&self, agent_name: &str, agent_config: &DelegateAgentConfig, reason: &str) {
+        let Some(parent) = &self.parent_observer else {
+            return;
+        };
+        parent.record_event(&ObserverEvent::DelegationStart {
+            agent_name: agent_name.to_string(),
+            provider: agent_config.provider.clone(),
+            model: agent_config.model.clone(),
+            depth: self.depth + 1,
+            agentic: agent_config.agentic,
+            workflow: None,
+        });
+        parent.record_event(&ObserverEvent::DelegationEnd {
+            agent_name: agent_name.to_string(),
+            provider: agent_config.provider.clone(),
+            model: agent_config.model.clone(),
+            depth: self.depth + 1,
+            duration: Duration::ZERO,
+            success: false,
+            error_message: Some(reason.to_string()),
+            tokens_used: None,
+            cost_usd: None,
+            workflow: None,
+            node: None,
+        });
+    }
+
+    /// Dispatch a delegation to the worker node the agent is pinned to.
+    ///
+    /// Returns `Some(result)` when the delegation was handled remotely
+    /// (success or hard failure) and `None` when the worker was
+    /// unreachable and `[nodes].fallback_local` permits running the
+    /// agent locally instead. Configuration errors (nodes disabled,
+    /// unknown worker name) never fall back — they fail fast so a typo
+    /// cannot silently reroute work to the local machine.
+    async fn execute_remote(
+        &self,
+        agent_name: &str,
+        agent_config: &DelegateAgentConfig,
+        node_name: &str,
+        full_prompt: &str,
+    ) -> Option<ToolResult> {
+        let Some(dispatcher) = &self.nodes else {
+            return Some(ToolResult {
+                success: false,
+                output: String::new(),
+                error: Some(format!(
+                    "Agent '{agent_name}' is pinned to node '{node_name}' but [nodes] \
+                     is not enabled or failed validation"
+                )),
+            });
+        };
+        let Some(worker) = dispatcher.worker(node_name) else {
+            return Some(ToolResult {
+                success: false,
+                output: String::new(),
+                error: Some(format!(
+                    "Agent '{agent_name}' references unknown node '{node_name}'. \
+                     Check [[nodes.workers]] in config.toml"
+                )),
+            });
+        };
+
+        if let Some(parent) = &self.parent_observer {
+            parent.record_event(&ObserverEvent::DelegationStart {
+                agent_name: agent_name.to_string(),
+                provider: agent_config.provider.clone(),
+                model: agent_config.model.clone(),
+                depth: self.depth + 1,
+                agentic: agent_config.agentic,
+                workflow: None,
+            });
+        }
+
+        let start_time = std::time::Instant::now();
+        let dispatch = dispatcher.dispatch(worker, full_prompt).await;
+        let duration = start_time.elapsed();
+
+        let tool_result = match dispatch {
+            Ok(response) => ToolResult {
+                success: true,
+                output: format!("[Agent '{agent_name}' @ node '{node_name}']\n{response}"),
+                error: None,
+            },
+            Err(e) => {
+                let error = format!("Agent '{agent_name}' failed on node '{node_name}': {e:#}");
+                if let Some(parent) = &self.parent_observer {
+                    parent.record_event(&ObserverEvent::DelegationEnd {
+                        agent_name: agent_name.to_string(),
+                        provider: agent_config.provider.clone(),
+                        model: agent_config.model.clone(),
+                        depth: self.depth + 1,
+                        duration,
+                        success: false,
+                        error_message: Some(error.clone()),
+                        tokens_used: None,
+                        cost_usd: None,
+                        workflow: None,
+                        node: Some(node_name.to_string()),
+                    });
+                }
+                if dispatcher.fallback_local() {
+                    tracing::warn!(
+                        node = %node_name,
+                        agent = %agent_name,
+                        "node dispatch failed, falling back to local execution: {e:#}"
+                    );
+                    return None;
+                }
+                return Some(ToolResult {
+                    success: false,
+                    output: String::new(),
+                    error: Some(error),
+                });
+            }
+        };
+
+        if let Some(parent) = &self.parent_observer {
+            parent.record_event(&ObserverEvent::DelegationEnd {
+                agent_name: agent_name.to_string(),
+                provider: agent_config.provider.clone(),
+                model: agent_config.model.clone(),
+                depth: self.depth + 1,
+                duration,
+                success: true,
+                error_message: None,
+                tokens_used: None,
+                cost_usd: None,
+                workflow: None,
+                node: Some(node_name.to_string()),
+            });
+        }
+
+        Some(tool_result)
+    }
+
     async fn execute_agentic(
         &self,
         agent_name: &str,
@@ -430,6 +723,7 @@ impl DelegateTool {
                 model: agent_config.model.clone(),
                 depth: self.depth + 1,
                 agentic: true,
+                workflow: None,
             });
         }
 
@@ -507,6 +801,8 @@ impl DelegateTool {
                 error_message: tool_result.as_ref().ok().and_then(|r| r.error.clone()),
                 tokens_used,
                 cost_usd,
+                workflow: None,
+                node: None,
             });
         }
 
@@ -677,6 +973,7 @@ mod tests {
                 agentic: false,
                 allowed_tools: Vec::new(),
                 max_iterations: 10,
+                node: None,
             },
         );
         agents.insert(
@@ -691,6 +988,7 @@ mod tests {
                 agentic: false,
                 allowed_tools: Vec::new(),
                 max_iterations: 10,
+                node: None,
             },
         );
         agents
@@ -841,6 +1139,7 @@ mod tests {
             agentic: true,
             allowed_tools,
             max_iterations,
+            node: None,
         }
     }
 
@@ -949,6 +1248,7 @@ mod tests {
                 agentic: false,
                 allowed_tools: Vec::new(),
                 max_iterations: 10,
+                node: None,
             },
         );
         let tool = DelegateTool::new(agents, None, test_security());
@@ -1055,6 +1355,7 @@ mod tests {
                 agentic: false,
                 allowed_tools: Vec::new(),
                 max_iterations: 10,
+                node: None,
             },
         );
         let tool = DelegateTool::new(agents, None, test_security());
@@ -1090,6 +1391,7 @@ mod tests {
                 agentic: false,
                 allowed_tools: Vec::new(),
                 max_iterations: 10,
+                node: None,
             },
         );
         let tool = DelegateTool::new(agents, None, test_security());
@@ -1235,6 +1537,94 @@ mod tests {
             .contains("maximum tool iterations (2)"));
     }
 
+    #[test]
+    fn guards_limit_concurrent_delegations() {
+        let guards = Arc::new(DelegationGuards::new(DelegationConfig {
+            max_depth: 0,
+            max_concurrent: 1,
+            max_per_run: 0,
+        }));
+
+        let first = guards.try_acquire().unwrap();
+        let blocked = guards.try_acquire().map(|_| ()).unwrap_err();
+        assert!(blocked.contains("concurrent sub-agents"));
+
+        drop(first);
+        assert!(guards.try_acquire().is_ok());
+    }
+
+    #[test]
+    fn guards_zero_limits_disable_caps() {
+        let guards = Arc::new(DelegationGuards::new(DelegationConfig {
+            max_depth: 0,
+            max_concurrent: 0,
+            max_per_run: 0,
+        }));
+        let permits: Vec<_> = (0..100).map(|_| guards.try_acquire().unwrap()).collect();
+        assert_eq!(permits.len(), 100);
+    }
+
+    #[tokio::test]
+    async fn guards_limit_delegations_per_run() {
+        let mut agents = HashMap::new();
+        agents.insert(
+            "tester".to_string(),
+            DelegateAgentConfig {
+                provider: "invalid-for-test".to_string(),
+                model: "test-model".to_string(),
+                system_prompt: None,
+                api_key: None,
+                temperature: None,
+                max_depth: 3,
+                agentic: false,
+                allowed_tools: Vec::new(),
+                max_iterations: 10,
+                node: None,
+            },
+        );
+        let tool = DelegateTool::new(agents, None, test_security()).with_guards(DelegationConfig {
+            max_depth: 0,
+            max_concurrent: 0,
+            max_per_run: 1,
+        });
+
+        // First attempt consumes the budget (fails at provider creation,
+        // but the delegation was admitted).
+        let first = tool
+            .execute(json!({"agent": "tester", "prompt": "test"}))
+            .await
+            .unwrap();
+        assert!(first.error.unwrap().contains("Failed to create provider"));
+
+        let second = tool
+            .execute(json!({"agent": "tester", "prompt": "test"}))
+            .await
+            .unwrap();
+        assert!(!second.success);
+        assert!(second
+            .error
+            .unwrap()
+            .contains("Delegation budget exhausted"));
+    }
+
+    #[tokio::test]
+    async fn global_depth_cap_tightens_per_agent_limit() {
+        // researcher allows max_depth=3, but the global cap of 1 wins.
+        let tool = DelegateTool::with_depth(sample_agents(), None, test_security(), 1).with_guards(
+            DelegationConfig {
+                max_depth: 1,
+                max_concurrent: 0,
+                max_per_run: 0,
+            },
+        );
+        let result = tool
+            .execute(json!({"agent": "researcher", "prompt": "test"}))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("depth limit"));
+    }
+
     #[tokio::test]
     async fn execute_agentic_propagates_provider_errors() {
         let config = agentic_config(vec!["echo_tool".to_string()], 10);
@@ -0,0 +1,212 @@
+use super::traits::{Tool, ToolResult};
+use crate::config::Config;
+use crate::jobs;
+use crate::security::SecurityPolicy;
+use async_trait::async_trait;
+use serde_json::json;
+use std::sync::Arc;
+
+/// Enqueue a long-running task as a background job executed by the daemon's
+/// jobs worker. Returns immediately with the job id; progress is visible via
+/// `zeroclaw jobs status <id>`.
+pub struct DelegateAsyncTool {
+    config: Arc<Config>,
+    security: Arc<SecurityPolicy>,
+}
+
+impl DelegateAsyncTool {
+    pub fn new(config: Arc<Config>, security: Arc<SecurityPolicy>) -> Self {
+        Self { config, security }
+    }
+}
+
+#[async_trait]
+impl Tool for DelegateAsyncTool {
+    fn name(&self) -> &str {
+        "delegate_async"
+    }
+
+    fn description(&self) -> &str {
+        "Enqueue a long-running task as a background job (runs in the daemon, survives restarts). Returns the job id immediately; set notify_channel/notify_to to deliver the result back to the requesting chat."
+    }
+
+    fn parameters_schema(&self) -> serde_json::Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "task": {
+                    "type": "string",
+                    "description": "Prompt describing the long-running task to execute in the background"
+                },
+                "model": {
+                    "type": "string",
+                    "description": "Optional model override for the background run"
+                },
+                "notify_channel": {
+                    "type": "string",
+                    "description": "Channel to notify on completion (e.g. 'telegram'); requires notify_to"
+                },
+                "notify_to": {
+                    "type": "string",
+                    "description": "Recipient/chat id for the completion notification; requires notify_channel"
+                }
+            },
+            "required": ["task"]
+        })
+    }
+
+    async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
+        if !self.security.record_action() {
+            return Ok(ToolResult {
+                success: false,
+                output: String::new(),
+                error: Some("Rate limit exceeded: action budget exhausted".to_string()),
+            });
+        }
+
+        let task = match args.get("task").and_then(serde_json::Value::as_str) {
+            Some(task) if !task.trim().is_empty() => task,
+            _ => {
+                return Ok(ToolResult {
+                    success: false,
+                    output: String::new(),
+                    error: Some("Missing 'task' parameter".to_string()),
+                });
+            }
+        };
+
+        let model = args
+            .get("model")
+            .and_then(serde_json::Value::as_str)
+            .map(str::to_string);
+        let notify_channel = args
+            .get("notify_channel")
+            .and_then(serde_json::Value::as_str)
+            .map(str::to_string);
+        let notify_to = args
+            .get("notify_to")
+            .and_then(serde_json::Value::as_str)
+            .map(str::to_string);
+
+        if notify_channel.is_some() != notify_to.is_some() {
+            return Ok(ToolResult {
+                success: false,
+                output: String::new(),
+                error: Some("notify_channel and notify_to must be provided together".to_string()),
+            });
+        }
+
+        match jobs::enqueue_job(&self.config, task, model, notify_channel, notify_to) {
+            Ok(job) => Ok(ToolResult {
+                success: true,
+                output: serde_json::to_string_pretty(&json!({
+                    "id": job.id,
+                    "status": job.status,
+                    "created_at": job.created_at,
+                    "hint": format!("Check progress with `zeroclaw jobs status {}`", job.id)
+                }))?,
+                error: None,
+            }),
+            Err(e) => Ok(ToolResult {
+                success: false,
+                output: String::new(),
+                error: Some(e.to_string()),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::jobs::JobStatus;
+    use tempfile::TempDir;
+
+    async fn test_config(tmp: &TempDir) -> Arc<Config> {
+        let config = Config {
+            workspace_dir: tmp.path().join("workspace"),
+            config_path: tmp.path().join("config.toml"),
+            ..Config::default()
+        };
+        tokio::fs::create_dir_all(&config.workspace_dir)
+            .await
+            .unwrap();
+        Arc::new(config)
+    }
+
+    fn test_security(cfg: &Config) -> Arc<SecurityPolicy> {
+        Arc::new(SecurityPolicy::from_config(
+            &cfg.autonomy,
+            &cfg.workspace_dir,
+        ))
+    }
+
+    #[tokio::test]
+    async fn enqueues_background_job() {
+        let tmp = TempDir::new().unwrap();
+        let cfg = test_config(&tmp).await;
+        let tool = DelegateAsyncTool::new(cfg.clone(), test_security(&cfg));
+
+        let result = tool
+            .execute(json!({"task": "summarize the monthly logs"}))
+            .await
+            .unwrap();
+        assert!(result.success, "{:?}", result.error);
+        assert!(result.output.contains("queued"));
+
+        let jobs = jobs::list_jobs(&cfg).unwrap();
+        assert_eq!(jobs.len(), 1);
+        assert_eq!(jobs[0].status, JobStatus::Queued);
+        assert_eq!(jobs[0].prompt, "summarize the monthly logs");
+    }
+
+    #[tokio::test]
+    async fn records_notification_target() {
+        let tmp = TempDir::new().unwrap();
+        let cfg = test_config(&tmp).await;
+        let tool = DelegateAsyncTool::new(cfg.clone(), test_security(&cfg));
+
+        let result = tool
+            .execute(json!({
+                "task": "long analysis",
+                "notify_channel": "telegram",
+                "notify_to": "12345"
+            }))
+            .await
+            .unwrap();
+        assert!(result.success, "{:?}", result.error);
+
+        let jobs = jobs::list_jobs(&cfg).unwrap();
+        assert_eq!(jobs[0].notify_channel.as_deref(), Some("telegram"));
+        assert_eq!(jobs[0].notify_to.as_deref(), Some("12345"));
+    }
+
+    #[tokio::test]
+    async fn rejects_missing_task() {
+        let tmp = TempDir::new().unwrap();
+        let cfg = test_config(&tmp).await;
+        let tool = DelegateAsyncTool::new(cfg.clone(), test_security(&cfg));
+
+        let result = tool.execute(json!({})).await.unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap_or_default().contains("Missing 'task'"));
+    }
+
+    #[tokio::test]
+    async fn rejects_partial_notification_target() {
+        let tmp = TempDir::new().unwrap();
+        let cfg = test_config(&tmp).await;
+        let tool = DelegateAsyncTool::new(cfg.clone(), test_security(&cfg));
+
+        let result = tool
+            .execute(json!({"task": "t", "notify_channel": "telegram"}))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result
+            .error
+            .unwrap_or_default()
+            .contains("must be provided together"));
+    }
+}
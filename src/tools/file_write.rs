@@ -1,5 +1,5 @@
 use super::traits::{Tool, ToolResult};
-use crate::security::SecurityPolicy;
+use crate::security::{AuditEvent, AuditEventType, SecurityPolicy};
 use async_trait::async_trait;
 use serde_json::json;
 use std::sync::Arc;
@@ -71,6 +71,11 @@ impl Tool for FileWriteTool {
 
         // Security check: validate path is within workspace
         if !self.security.is_path_allowed(path) {
+            self.security.audit_event(
+                &AuditEvent::new(AuditEventType::FileAccess)
+                    .with_action(format!("write {path}"), "medium".to_string(), false, false)
+                    .with_policy_violation(),
+            );
             return Ok(ToolResult {
                 success: false,
                 output: String::new(),
@@ -146,18 +151,54 @@ impl Tool for FileWriteTool {
             });
         }
 
-        match tokio::fs::write(&resolved_target, content).await {
-            Ok(()) => Ok(ToolResult {
-                success: true,
-                output: format!("Written {} bytes to {path}", content.len()),
-                error: None,
-            }),
-            Err(e) => Ok(ToolResult {
+        // Capture the previous state for the undo journal before overwriting.
+        // A binary (non-UTF-8) original cannot be journaled as text, so it is
+        // skipped rather than risking a lossy restore.
+        let previous = match tokio::fs::read(&resolved_target).await {
+            Ok(bytes) => match String::from_utf8(bytes) {
+                Ok(text) => Some(Some(text)),
+                Err(_) => {
+                    tracing::warn!(
+                        path = %resolved_target.display(),
+                        "Skipping undo journal entry: existing file is not UTF-8 text"
+                    );
+                    None
+                }
+            },
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Some(None),
+            Err(_) => None,
+        };
+
+        let tool_result = match tokio::fs::write(&resolved_target, content).await {
+            Ok(()) => {
+                if let Some(previous) = previous {
+                    crate::undo::UndoJournal::new(&self.security.workspace_dir).record(
+                        "file_write",
+                        &resolved_target,
+                        previous,
+                        crate::undo::current_run_id().as_deref(),
+                    );
+                }
+                ToolResult {
+                    success: true,
+                    output: format!("Written {} bytes to {path}", content.len()),
+                    error: None,
+                }
+            }
+            Err(e) => ToolResult {
                 success: false,
                 output: String::new(),
                 error: Some(format!("Failed to write file: {e}")),
-            }),
-        }
+            },
+        };
+
+        self.security.audit_event(
+            &AuditEvent::new(AuditEventType::FileAccess)
+                .with_action(format!("write {path}"), "medium".to_string(), false, true)
+                .with_result(tool_result.success, None, 0, None),
+        );
+
+        Ok(tool_result)
     }
 }
 
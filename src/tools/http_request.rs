@@ -1,5 +1,5 @@
 use super::traits::{Tool, ToolResult};
-use crate::security::SecurityPolicy;
+use crate::security::{AuditEvent, AuditEventType, SecurityPolicy};
 use async_trait::async_trait;
 use serde_json::json;
 use std::sync::Arc;
@@ -236,7 +236,11 @@ impl Tool for HttpRequestTool {
 
         let request_headers = self.parse_headers(&headers_val);
 
-        match self
+        // Audit target: method plus URL with the query string stripped, so
+        // credentials passed as query parameters never reach the audit log.
+        let audit_target = format!("{method} {}", url.split('?').next().unwrap_or(url.as_str()));
+
+        let tool_result = match self
             .execute_request(&url, method, request_headers, body)
             .await
         {
@@ -272,7 +276,7 @@ impl Tool for HttpRequestTool {
                     response_text
                 );
 
-                Ok(ToolResult {
+                ToolResult {
                     success: status.is_success(),
                     output,
                     error: if status.is_client_error() || status.is_server_error() {
@@ -280,14 +284,22 @@ impl Tool for HttpRequestTool {
                     } else {
                         None
                     },
-                })
+                }
             }
-            Err(e) => Ok(ToolResult {
+            Err(e) => ToolResult {
                 success: false,
                 output: String::new(),
                 error: Some(format!("HTTP request failed: {e}")),
-            }),
-        }
+            },
+        };
+
+        self.security.audit_event(
+            &AuditEvent::new(AuditEventType::NetworkAccess)
+                .with_action(audit_target, "medium".to_string(), false, true)
+                .with_result(tool_result.success, None, 0, None),
+        );
+
+        Ok(tool_result)
     }
 }
 
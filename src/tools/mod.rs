@@ -17,7 +17,11 @@
 
 pub mod browser;
 pub mod browser_open;
+pub mod capture_note;
 pub mod composio;
+pub mod contacts_add;
+pub mod contacts_list;
+pub mod containers;
 pub mod cron_add;
 pub mod cron_list;
 pub mod cron_remove;
@@ -25,6 +29,7 @@ pub mod cron_run;
 pub mod cron_runs;
 pub mod cron_update;
 pub mod delegate;
+pub mod delegate_async;
 pub mod file_read;
 pub mod file_write;
 pub mod git_operations;
@@ -36,18 +41,28 @@ pub mod image_info;
 pub mod memory_forget;
 pub mod memory_recall;
 pub mod memory_store;
+pub mod network_scan;
+pub mod pager;
 pub mod proxy_config;
 pub mod pushover;
+pub mod read_attachment;
+pub mod read_telemetry;
+pub mod run_code;
 pub mod schedule;
 pub mod schema;
 pub mod screenshot;
 pub mod shell;
 pub mod traits;
+pub mod truncation;
 pub mod web_search_tool;
 
 pub use browser::{BrowserTool, ComputerUseConfig};
 pub use browser_open::BrowserOpenTool;
+pub use capture_note::CaptureNoteTool;
 pub use composio::ComposioTool;
+pub use contacts_add::ContactsAddTool;
+pub use contacts_list::ContactsListTool;
+pub use containers::ContainersTool;
 pub use cron_add::CronAddTool;
 pub use cron_list::CronListTool;
 pub use cron_remove::CronRemoveTool;
@@ -55,6 +70,7 @@ pub use cron_run::CronRunTool;
 pub use cron_runs::CronRunsTool;
 pub use cron_update::CronUpdateTool;
 pub use delegate::DelegateTool;
+pub use delegate_async::DelegateAsyncTool;
 pub use file_read::FileReadTool;
 pub use file_write::FileWriteTool;
 pub use git_operations::GitOperationsTool;
@@ -66,8 +82,13 @@ pub use image_info::ImageInfoTool;
 pub use memory_forget::MemoryForgetTool;
 pub use memory_recall::MemoryRecallTool;
 pub use memory_store::MemoryStoreTool;
+pub use network_scan::NetworkScanTool;
+pub use pager::PagerTool;
 pub use proxy_config::ProxyConfigTool;
 pub use pushover::PushoverTool;
+pub use read_attachment::ReadAttachmentTool;
+pub use read_telemetry::ReadTelemetryTool;
+pub use run_code::RunCodeTool;
 pub use schedule::ScheduleTool;
 #[allow(unused_imports)]
 pub use schema::{CleaningStrategy, SchemaCleanr};
@@ -190,15 +211,19 @@ pub fn all_tools_with_runtime(
         Arc::new(ShellTool::new(security.clone(), runtime)),
         Arc::new(FileReadTool::new(security.clone())),
         Arc::new(FileWriteTool::new(security.clone())),
+        Arc::new(ReadAttachmentTool::new(security.clone())),
         Arc::new(CronAddTool::new(config.clone(), security.clone())),
         Arc::new(CronListTool::new(config.clone())),
         Arc::new(CronRemoveTool::new(config.clone())),
         Arc::new(CronUpdateTool::new(config.clone(), security.clone())),
         Arc::new(CronRunTool::new(config.clone())),
         Arc::new(CronRunsTool::new(config.clone())),
+        Arc::new(DelegateAsyncTool::new(config.clone(), security.clone())),
         Arc::new(MemoryStoreTool::new(memory.clone(), security.clone())),
         Arc::new(MemoryRecallTool::new(memory.clone())),
         Arc::new(MemoryForgetTool::new(memory, security.clone())),
+        Arc::new(ContactsAddTool::new(config.clone(), security.clone())),
+        Arc::new(ContactsListTool::new(config.clone())),
         Arc::new(ScheduleTool::new(security.clone(), root_config.clone())),
         Arc::new(ProxyConfigTool::new(config.clone(), security.clone())),
         Arc::new(GitOperationsTool::new(
@@ -247,6 +272,34 @@ pub fn all_tools_with_runtime(
         )));
     }
 
+    if root_config.run_code.enabled {
+        tool_arcs.push(Arc::new(RunCodeTool::new(
+            root_config.run_code.clone(),
+            security.clone(),
+        )));
+    }
+
+    if root_config.containers.enabled {
+        tool_arcs.push(Arc::new(ContainersTool::new(
+            root_config.containers.clone(),
+            security.clone(),
+        )));
+    }
+
+    if root_config.pager.enabled {
+        tool_arcs.push(Arc::new(PagerTool::new(
+            root_config.pager.clone(),
+            security.clone(),
+        )));
+    }
+
+    if root_config.network_scan.enabled {
+        tool_arcs.push(Arc::new(NetworkScanTool::new(
+            root_config.network_scan.clone(),
+            security.clone(),
+        )));
+    }
+
     // Web search tool (enabled by default for GLM and other models)
     if root_config.web_search.enabled {
         tool_arcs.push(Arc::new(WebSearchTool::new(
@@ -271,6 +324,19 @@ pub fn all_tools_with_runtime(
         }
     }
 
+    // First-party integrations (GitHub, Google Calendar, Notion) register
+    // their tools when enabled and configured.
+    for integration in crate::integrations::active_integrations(root_config, security) {
+        for tool in integration.tools() {
+            tool_arcs.push(Arc::from(tool));
+        }
+    }
+
+    // Note capture ([notes]): Obsidian vault or Notion database backend.
+    if let Some(tool) = CaptureNoteTool::from_config(root_config, security.clone()) {
+        tool_arcs.push(Arc::new(tool));
+    }
+
     // Add delegation tool when agents are configured
     if !agents.is_empty() {
         let delegate_agents: HashMap<String, DelegateAgentConfig> = agents
@@ -282,6 +348,13 @@ pub fn all_tools_with_runtime(
             (!trimmed_value.is_empty()).then(|| trimmed_value.to_owned())
         });
         let parent_tools = Arc::new(tool_arcs.clone());
+        let node_dispatcher = match crate::nodes::NodeDispatcher::from_config(&root_config.nodes) {
+            Ok(dispatcher) => dispatcher.map(Arc::new),
+            Err(e) => {
+                tracing::error!("Invalid [nodes] configuration, remote dispatch disabled: {e:#}");
+                None
+            }
+        };
         let delegate_tool = DelegateTool::new_with_options(
             delegate_agents,
             delegate_fallback_credential,
@@ -298,7 +371,9 @@ pub fn all_tools_with_runtime(
         )
         .with_parent_tools(parent_tools)
         .with_multimodal_config(root_config.multimodal.clone())
-        .with_parent_observer(observer.clone());
+        .with_parent_observer(observer.clone())
+        .with_guards(root_config.delegation.clone())
+        .with_nodes(node_dispatcher);
         tool_arcs.push(Arc::new(delegate_tool));
     }
 
@@ -527,6 +602,7 @@ mod tests {
                 agentic: false,
                 allowed_tools: Vec::new(),
                 max_iterations: 10,
+                node: None,
             },
         );
 
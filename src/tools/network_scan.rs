@@ -0,0 +1,466 @@
+use super::traits::{Tool, ToolResult};
+use crate::config::NetworkScanConfig;
+use crate::security::policy::ToolOperation;
+use crate::security::SecurityPolicy;
+use async_trait::async_trait;
+use parking_lot::Mutex;
+use serde_json::json;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Ports probed by a subnet sweep when none are given.
+const DEFAULT_SWEEP_PORTS: &[u16] = &[22, 80, 443];
+/// Maximum ports accepted per port-check request.
+const MAX_PORTS_PER_CHECK: usize = 64;
+/// Concurrent connection probes per batch.
+const PROBE_BATCH_SIZE: usize = 32;
+
+/// Local network scanner tool (opt-in).
+///
+/// Answers "is my NAS up?" style questions with a TCP connect sweep of an
+/// RFC1918 subnet or a port check on one private host. Deliberately narrow:
+/// targets outside RFC1918 are refused regardless of arguments, subnet size
+/// is capped, and scans are rate-limited to one per configured interval.
+pub struct NetworkScanTool {
+    config: NetworkScanConfig,
+    security: Arc<SecurityPolicy>,
+    /// Completion time of the most recent scan, for rate limiting.
+    last_scan: Mutex<Option<Instant>>,
+}
+
+impl NetworkScanTool {
+    pub fn new(config: NetworkScanConfig, security: Arc<SecurityPolicy>) -> Self {
+        Self {
+            config,
+            security,
+            last_scan: Mutex::new(None),
+        }
+    }
+
+    /// Enforce the scan interval; records the current scan on success.
+    fn check_rate_limit(&self) -> Result<(), String> {
+        let mut last = self.last_scan.lock();
+        if let Some(prev) = *last {
+            let min_interval = Duration::from_secs(self.config.min_scan_interval_secs);
+            let elapsed = prev.elapsed();
+            if elapsed < min_interval {
+                let remaining = min_interval.saturating_sub(elapsed).as_secs().max(1);
+                return Err(format!(
+                    "Scan rate limit: wait {}s between scans ({remaining}s remaining)",
+                    self.config.min_scan_interval_secs
+                ));
+            }
+        }
+        *last = Some(Instant::now());
+        Ok(())
+    }
+
+    /// Parse `a.b.c.d/nn` into the contained RFC1918 host addresses.
+    ///
+    /// The prefix must keep the sweep within `max_hosts` (e.g. /24 for the
+    /// default 256) and the whole range must be RFC1918.
+    fn expand_cidr(&self, cidr: &str) -> Result<Vec<Ipv4Addr>, String> {
+        let (base, prefix) = cidr
+            .split_once('/')
+            .ok_or_else(|| format!("Invalid CIDR '{cidr}' (expected e.g. 192.168.1.0/24)"))?;
+        let base: Ipv4Addr = base
+            .parse()
+            .map_err(|_| format!("Invalid IPv4 address in CIDR '{cidr}'"))?;
+        let prefix: u32 = prefix
+            .parse()
+            .map_err(|_| format!("Invalid prefix length in CIDR '{cidr}'"))?;
+        if !(16..=32).contains(&prefix) {
+            return Err(format!("Prefix /{prefix} out of range (expected /16–/32)"));
+        }
+        // prefix >= 16 keeps the shift (and count) within u32.
+        let host_count = 1u32 << (32 - prefix);
+        if usize::try_from(host_count).unwrap_or(usize::MAX) > self.config.max_hosts {
+            return Err(format!(
+                "Subnet /{prefix} spans {host_count} addresses; the scan cap is {} \
+                 ([network_scan] max_hosts)",
+                self.config.max_hosts
+            ));
+        }
+        let mask = if prefix == 32 {
+            u32::MAX
+        } else {
+            u32::MAX << (32 - prefix)
+        };
+        let network = u32::from(base) & mask;
+        let hosts: Vec<Ipv4Addr> = (0..host_count)
+            .map(|offset| Ipv4Addr::from(network + offset))
+            .collect();
+        if let Some(outside) = hosts.iter().find(|ip| !ip.is_private()) {
+            return Err(format!(
+                "Address {outside} is outside RFC1918 private ranges; scans are \
+                 restricted to 10/8, 172.16/12 and 192.168/16"
+            ));
+        }
+        Ok(hosts)
+    }
+
+    /// Parse and bound a `ports` argument; falls back to the sweep defaults.
+    fn parse_ports(args: &serde_json::Value) -> Result<Vec<u16>, String> {
+        let Some(list) = args.get("ports") else {
+            return Ok(DEFAULT_SWEEP_PORTS.to_vec());
+        };
+        let list = list
+            .as_array()
+            .ok_or_else(|| "'ports' must be an array of port numbers".to_string())?;
+        if list.is_empty() || list.len() > MAX_PORTS_PER_CHECK {
+            return Err(format!(
+                "'ports' must list between 1 and {MAX_PORTS_PER_CHECK} ports"
+            ));
+        }
+        list.iter()
+            .map(|v| {
+                v.as_u64()
+                    .and_then(|p| u16::try_from(p).ok())
+                    .filter(|p| *p != 0)
+                    .ok_or_else(|| format!("Invalid port number: {v}"))
+            })
+            .collect()
+    }
+
+    /// Probe `targets` concurrently; returns the (host, port) pairs that accepted.
+    async fn probe(&self, targets: Vec<SocketAddr>) -> Vec<SocketAddr> {
+        let timeout = Duration::from_millis(self.config.connect_timeout_ms);
+        let mut open = Vec::new();
+        for batch in targets.chunks(PROBE_BATCH_SIZE) {
+            let probes = batch.iter().map(|addr| {
+                let addr = *addr;
+                async move {
+                    let connect = tokio::net::TcpStream::connect(addr);
+                    match tokio::time::timeout(timeout, connect).await {
+                        Ok(Ok(_)) => Some(addr),
+                        _ => None,
+                    }
+                }
+            });
+            open.extend(
+                futures::future::join_all(probes)
+                    .await
+                    .into_iter()
+                    .flatten(),
+            );
+        }
+        open
+    }
+}
+
+#[async_trait]
+impl Tool for NetworkScanTool {
+    fn name(&self) -> &str {
+        "network_scan"
+    }
+
+    fn description(&self) -> &str {
+        "Scan the local network (RFC1918 ranges only). Actions: 'sweep' (TCP probe every host in a private CIDR like 192.168.1.0/24 on a few common ports), 'ports' (check specific ports on one private host). Use for questions like 'is my NAS up?'. Rate-limited; public addresses are refused."
+    }
+
+    fn parameters_schema(&self) -> serde_json::Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "action": {
+                    "type": "string",
+                    "enum": ["sweep", "ports"],
+                    "description": "Scan type: subnet sweep or per-host port check"
+                },
+                "cidr": {
+                    "type": "string",
+                    "description": "RFC1918 subnet to sweep, e.g. 192.168.1.0/24 (sweep action)"
+                },
+                "host": {
+                    "type": "string",
+                    "description": "RFC1918 IPv4 address to check, e.g. 192.168.1.10 (ports action)"
+                },
+                "ports": {
+                    "type": "array",
+                    "items": {"type": "integer"},
+                    "description": "Ports to probe (default: 22, 80, 443; max 64)"
+                }
+            },
+            "required": ["action"]
+        })
+    }
+
+    async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
+        let action = args
+            .get("action")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Missing 'action' parameter"))?;
+
+        if let Err(error) = self
+            .security
+            .enforce_tool_operation(ToolOperation::Read, "network_scan")
+        {
+            return Ok(ToolResult {
+                success: false,
+                output: String::new(),
+                error: Some(error),
+            });
+        }
+
+        let ports = match Self::parse_ports(&args) {
+            Ok(ports) => ports,
+            Err(reason) => {
+                return Ok(ToolResult {
+                    success: false,
+                    output: String::new(),
+                    error: Some(reason),
+                });
+            }
+        };
+
+        let targets: Vec<SocketAddr> = match action {
+            "sweep" => {
+                let Some(cidr) = args.get("cidr").and_then(|v| v.as_str()) else {
+                    return Ok(ToolResult {
+                        success: false,
+                        output: String::new(),
+                        error: Some("Action 'sweep' requires a 'cidr'".into()),
+                    });
+                };
+                match self.expand_cidr(cidr) {
+                    Ok(hosts) => hosts
+                        .into_iter()
+                        .flat_map(|ip| {
+                            ports
+                                .iter()
+                                .map(move |port| SocketAddr::new(IpAddr::V4(ip), *port))
+                        })
+                        .collect(),
+                    Err(reason) => {
+                        return Ok(ToolResult {
+                            success: false,
+                            output: String::new(),
+                            error: Some(reason),
+                        });
+                    }
+                }
+            }
+            "ports" => {
+                let Some(host) = args.get("host").and_then(|v| v.as_str()) else {
+                    return Ok(ToolResult {
+                        success: false,
+                        output: String::new(),
+                        error: Some("Action 'ports' requires a 'host'".into()),
+                    });
+                };
+                let Ok(ip) = host.parse::<Ipv4Addr>() else {
+                    return Ok(ToolResult {
+                        success: false,
+                        output: String::new(),
+                        error: Some(format!(
+                            "Invalid host '{host}' (expected an RFC1918 IPv4 address)"
+                        )),
+                    });
+                };
+                if !ip.is_private() {
+                    return Ok(ToolResult {
+                        success: false,
+                        output: String::new(),
+                        error: Some(format!(
+                            "Host {ip} is outside RFC1918 private ranges; scans are \
+                             restricted to 10/8, 172.16/12 and 192.168/16"
+                        )),
+                    });
+                }
+                ports
+                    .iter()
+                    .map(|port| SocketAddr::new(IpAddr::V4(ip), *port))
+                    .collect()
+            }
+            other => {
+                return Ok(ToolResult {
+                    success: false,
+                    output: String::new(),
+                    error: Some(format!(
+                        "Unknown action '{other}' (supported: sweep, ports)"
+                    )),
+                });
+            }
+        };
+
+        if let Err(reason) = self.check_rate_limit() {
+            return Ok(ToolResult {
+                success: false,
+                output: String::new(),
+                error: Some(reason),
+            });
+        }
+
+        let probed = targets.len();
+        let open = self.probe(targets).await;
+
+        use std::fmt::Write;
+        let mut output = format!(
+            "Probed {probed} host:port pair(s); {} responded.\n",
+            open.len()
+        );
+        if open.is_empty() {
+            output.push_str("No open ports found.");
+        } else {
+            let mut by_host: std::collections::BTreeMap<IpAddr, Vec<u16>> =
+                std::collections::BTreeMap::new();
+            for addr in open {
+                by_host.entry(addr.ip()).or_default().push(addr.port());
+            }
+            for (ip, ports) in by_host {
+                let ports: Vec<String> = ports.iter().map(u16::to_string).collect();
+                let _ = writeln!(output, "- {ip}: {}", ports.join(", "));
+            }
+        }
+
+        Ok(ToolResult {
+            success: true,
+            output: output.trim_end().to_string(),
+            error: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::security::AutonomyLevel;
+
+    fn test_tool() -> NetworkScanTool {
+        NetworkScanTool::new(
+            NetworkScanConfig::default(),
+            Arc::new(SecurityPolicy::default()),
+        )
+    }
+
+    #[test]
+    fn name_and_schema() {
+        let tool = test_tool();
+        assert_eq!(tool.name(), "network_scan");
+        let schema = tool.parameters_schema();
+        assert!(schema["properties"]["action"].is_object());
+        assert!(schema["properties"]["cidr"].is_object());
+    }
+
+    #[test]
+    fn expand_cidr_accepts_private_subnets() {
+        let tool = test_tool();
+        let hosts = tool.expand_cidr("192.168.1.0/30").unwrap();
+        assert_eq!(hosts.len(), 4);
+        assert_eq!(hosts[0], Ipv4Addr::new(192, 168, 1, 0));
+        assert_eq!(hosts[3], Ipv4Addr::new(192, 168, 1, 3));
+
+        assert_eq!(tool.expand_cidr("10.0.0.0/24").unwrap().len(), 256);
+        assert_eq!(tool.expand_cidr("172.16.5.1/32").unwrap().len(), 1);
+    }
+
+    #[test]
+    fn expand_cidr_rejects_public_and_oversized_ranges() {
+        let tool = test_tool();
+        assert!(tool
+            .expand_cidr("8.8.8.0/24")
+            .unwrap_err()
+            .contains("RFC1918"));
+        // Loopback is not RFC1918 either.
+        assert!(tool
+            .expand_cidr("127.0.0.0/30")
+            .unwrap_err()
+            .contains("RFC1918"));
+        assert!(tool.expand_cidr("10.0.0.0/16").unwrap_err().contains("cap"));
+        assert!(tool.expand_cidr("10.0.0.0/8").unwrap_err().contains("/16"));
+        assert!(tool.expand_cidr("not-a-cidr").is_err());
+        assert!(tool.expand_cidr("192.168.1.0").is_err());
+    }
+
+    #[test]
+    fn parse_ports_defaults_and_bounds() {
+        assert_eq!(
+            NetworkScanTool::parse_ports(&json!({})).unwrap(),
+            DEFAULT_SWEEP_PORTS.to_vec()
+        );
+        assert_eq!(
+            NetworkScanTool::parse_ports(&json!({"ports": [8080, 445]})).unwrap(),
+            vec![8080, 445]
+        );
+        assert!(NetworkScanTool::parse_ports(&json!({"ports": []})).is_err());
+        assert!(NetworkScanTool::parse_ports(&json!({"ports": [0]})).is_err());
+        assert!(NetworkScanTool::parse_ports(&json!({"ports": [70000]})).is_err());
+        let too_many: Vec<u16> = (1..=65).collect();
+        assert!(NetworkScanTool::parse_ports(&json!({ "ports": too_many })).is_err());
+    }
+
+    #[tokio::test]
+    async fn ports_action_rejects_public_host() {
+        let tool = test_tool();
+        let result = tool
+            .execute(json!({"action": "ports", "host": "1.1.1.1"}))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("RFC1918"));
+    }
+
+    #[tokio::test]
+    async fn ports_action_rejects_hostnames() {
+        let tool = test_tool();
+        let result = tool
+            .execute(json!({"action": "ports", "host": "nas.local"}))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("IPv4 address"));
+    }
+
+    #[tokio::test]
+    async fn unknown_action_fails() {
+        let tool = test_tool();
+        let result = tool.execute(json!({"action": "arp"})).await.unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("Unknown action"));
+    }
+
+    #[tokio::test]
+    async fn rate_limit_blocks_back_to_back_scans() {
+        let tool = test_tool();
+        // First scan: one unroutable-but-private host, short timeout.
+        let args = json!({"action": "ports", "host": "10.255.255.254", "ports": [9]});
+        let first = tool.execute(args.clone()).await.unwrap();
+        assert!(first.success);
+        let second = tool.execute(args).await.unwrap();
+        assert!(!second.success);
+        assert!(second.error.unwrap().contains("rate limit"));
+    }
+
+    #[tokio::test]
+    async fn validation_failures_do_not_consume_rate_limit() {
+        let tool = test_tool();
+        let bad = tool
+            .execute(json!({"action": "ports", "host": "1.1.1.1"}))
+            .await
+            .unwrap();
+        assert!(!bad.success);
+        let good = tool
+            .execute(json!({"action": "ports", "host": "10.255.255.254", "ports": [9]}))
+            .await
+            .unwrap();
+        assert!(good.success);
+    }
+
+    #[tokio::test]
+    async fn blocked_in_readonly_mode_respects_policy() {
+        // Read-only autonomy still permits read operations; verify the scan
+        // is gated by the security policy rather than bypassing it.
+        let policy = Arc::new(SecurityPolicy {
+            autonomy: AutonomyLevel::ReadOnly,
+            ..SecurityPolicy::default()
+        });
+        let tool = NetworkScanTool::new(NetworkScanConfig::default(), policy);
+        let result = tool
+            .execute(json!({"action": "ports", "host": "10.255.255.254", "ports": [9]}))
+            .await
+            .unwrap();
+        // Read operations are allowed in read-only mode.
+        assert!(result.success);
+    }
+}
@@ -0,0 +1,222 @@
+use super::traits::{Tool, ToolResult};
+use crate::config::PagerConfig;
+use crate::pager::{self, PagerAction};
+use crate::security::policy::ToolOperation;
+use crate::security::SecurityPolicy;
+use async_trait::async_trait;
+use serde_json::json;
+use std::sync::Arc;
+
+/// On-call pager tool (PagerDuty/Opsgenie).
+///
+/// Exposes incident lifecycle actions against the configured paging
+/// provider's REST API. Both actions mutate real incident state and are
+/// approval-gated — the model must pass `approved: true` only after the
+/// operator explicitly confirms.
+pub struct PagerTool {
+    config: PagerConfig,
+    security: Arc<SecurityPolicy>,
+}
+
+impl PagerTool {
+    pub fn new(config: PagerConfig, security: Arc<SecurityPolicy>) -> Self {
+        Self { config, security }
+    }
+}
+
+#[async_trait]
+impl Tool for PagerTool {
+    fn name(&self) -> &str {
+        "pager"
+    }
+
+    fn description(&self) -> &str {
+        "Acknowledge or resolve an on-call incident (PagerDuty/Opsgenie). Actions: 'acknowledge' (claim the page), 'resolve' (close the incident). Both mutate incident state and require user confirmation first — pass approved=true only after the user explicitly agrees."
+    }
+
+    fn parameters_schema(&self) -> serde_json::Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "action": {
+                    "type": "string",
+                    "enum": ["acknowledge", "resolve"],
+                    "description": "Incident lifecycle action to perform"
+                },
+                "incident_id": {
+                    "type": "string",
+                    "description": "Incident/alert id from the pager notification"
+                },
+                "approved": {
+                    "type": "boolean",
+                    "description": "Set true to confirm the action after explicit user approval",
+                    "default": false
+                }
+            },
+            "required": ["action", "incident_id"]
+        })
+    }
+
+    async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
+        let action = match args.get("action").and_then(|v| v.as_str()) {
+            Some("acknowledge") => PagerAction::Acknowledge,
+            Some("resolve") => PagerAction::Resolve,
+            Some(other) => {
+                return Ok(ToolResult {
+                    success: false,
+                    output: String::new(),
+                    error: Some(format!(
+                        "Unknown action '{other}' (supported: acknowledge, resolve)"
+                    )),
+                });
+            }
+            None => anyhow::bail!("Missing 'action' parameter"),
+        };
+        let Some(incident_id) = args.get("incident_id").and_then(|v| v.as_str()) else {
+            return Ok(ToolResult {
+                success: false,
+                output: String::new(),
+                error: Some("Missing 'incident_id' parameter".into()),
+            });
+        };
+        let approved = args
+            .get("approved")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+
+        if let Err(reason) = pager::validate_incident_id(incident_id) {
+            return Ok(ToolResult {
+                success: false,
+                output: String::new(),
+                error: Some(reason),
+            });
+        }
+
+        if !approved {
+            return Ok(ToolResult {
+                success: false,
+                output: String::new(),
+                error: Some(format!(
+                    "Pager action '{}' mutates incident state and requires explicit user approval (approved=true)",
+                    match action {
+                        PagerAction::Acknowledge => "acknowledge",
+                        PagerAction::Resolve => "resolve",
+                    }
+                )),
+            });
+        }
+
+        if let Err(error) = self
+            .security
+            .enforce_tool_operation(ToolOperation::Act, "pager")
+        {
+            return Ok(ToolResult {
+                success: false,
+                output: String::new(),
+                error: Some(error),
+            });
+        }
+
+        match pager::perform_action(&self.config, action, incident_id).await {
+            Ok(()) => Ok(ToolResult {
+                success: true,
+                output: format!(
+                    "Incident {incident_id} {}",
+                    match action {
+                        PagerAction::Acknowledge => "acknowledged",
+                        PagerAction::Resolve => "resolved",
+                    }
+                ),
+                error: None,
+            }),
+            Err(e) => Ok(ToolResult {
+                success: false,
+                output: String::new(),
+                error: Some(format!("Pager action failed: {e:#}")),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::security::AutonomyLevel;
+
+    fn test_tool() -> PagerTool {
+        PagerTool::new(PagerConfig::default(), Arc::new(SecurityPolicy::default()))
+    }
+
+    #[test]
+    fn name_and_schema() {
+        let tool = test_tool();
+        assert_eq!(tool.name(), "pager");
+        let schema = tool.parameters_schema();
+        assert!(schema["properties"]["action"].is_object());
+        assert!(schema["properties"]["approved"].is_object());
+    }
+
+    #[tokio::test]
+    async fn unknown_action_fails() {
+        let tool = test_tool();
+        let result = tool
+            .execute(json!({"action": "escalate", "incident_id": "Q1AB2C3DEF4GH5"}))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("Unknown action"));
+    }
+
+    #[tokio::test]
+    async fn actions_require_approval() {
+        let tool = test_tool();
+        for action in ["acknowledge", "resolve"] {
+            let result = tool
+                .execute(json!({"action": action, "incident_id": "Q1AB2C3DEF4GH5"}))
+                .await
+                .unwrap();
+            assert!(!result.success);
+            assert!(result.error.unwrap().contains("approved=true"));
+        }
+    }
+
+    #[tokio::test]
+    async fn invalid_incident_id_rejected() {
+        let tool = test_tool();
+        let result = tool
+            .execute(json!({"action": "resolve", "incident_id": "../alerts", "approved": true}))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("Invalid incident id"));
+    }
+
+    #[tokio::test]
+    async fn approved_action_blocked_in_readonly_mode() {
+        let readonly = Arc::new(SecurityPolicy {
+            autonomy: AutonomyLevel::ReadOnly,
+            ..SecurityPolicy::default()
+        });
+        let tool = PagerTool::new(PagerConfig::default(), readonly);
+        let result = tool
+            .execute(
+                json!({"action": "acknowledge", "incident_id": "Q1AB2C3DEF4GH5", "approved": true}),
+            )
+            .await
+            .unwrap();
+        assert!(!result.success);
+    }
+
+    #[tokio::test]
+    async fn missing_api_key_reports_error() {
+        let tool = test_tool();
+        let result = tool
+            .execute(
+                json!({"action": "acknowledge", "incident_id": "Q1AB2C3DEF4GH5", "approved": true}),
+            )
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("api_key"));
+    }
+}
@@ -0,0 +1,287 @@
+use super::traits::{Tool, ToolResult};
+use crate::security::SecurityPolicy;
+use async_trait::async_trait;
+use serde_json::json;
+use std::sync::Arc;
+
+const MAX_ATTACHMENT_BYTES: u64 = 5 * 1024 * 1024;
+
+/// Read files received over channels from the workspace inbox.
+pub struct ReadAttachmentTool {
+    security: Arc<SecurityPolicy>,
+}
+
+impl ReadAttachmentTool {
+    pub fn new(security: Arc<SecurityPolicy>) -> Self {
+        Self { security }
+    }
+
+    /// Inbox location; mirrors `channels::attachments` (`<workspace>/inbox`).
+    fn inbox_dir(&self) -> std::path::PathBuf {
+        self.security.workspace_dir.join("inbox")
+    }
+
+    async fn list_inbox(&self) -> anyhow::Result<ToolResult> {
+        let dir = self.inbox_dir();
+        let mut entries = match tokio::fs::read_dir(&dir).await {
+            Ok(entries) => entries,
+            Err(_) => {
+                return Ok(ToolResult {
+                    success: true,
+                    output: "Inbox is empty (no attachments received yet)".into(),
+                    error: None,
+                });
+            }
+        };
+
+        let mut lines: Vec<String> = Vec::new();
+        while let Some(entry) = entries.next_entry().await? {
+            let meta = entry.metadata().await?;
+            if meta.is_file() {
+                lines.push(format!(
+                    "{} ({} bytes)",
+                    entry.file_name().to_string_lossy(),
+                    meta.len()
+                ));
+            }
+        }
+
+        if lines.is_empty() {
+            return Ok(ToolResult {
+                success: true,
+                output: "Inbox is empty (no attachments received yet)".into(),
+                error: None,
+            });
+        }
+
+        lines.sort();
+        Ok(ToolResult {
+            success: true,
+            output: lines.join("\n"),
+            error: None,
+        })
+    }
+}
+
+#[async_trait]
+impl Tool for ReadAttachmentTool {
+    fn name(&self) -> &str {
+        "read_attachment"
+    }
+
+    fn description(&self) -> &str {
+        "Read a file received over a channel (Telegram/Slack upload) from the workspace inbox. Omit 'name' to list available attachments."
+    }
+
+    fn parameters_schema(&self) -> serde_json::Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "name": {
+                    "type": "string",
+                    "description": "File name inside the inbox (as shown in the '[attachment saved: ...]' note). Omit to list attachments."
+                }
+            },
+            "required": []
+        })
+    }
+
+    async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
+        if self.security.is_rate_limited() {
+            return Ok(ToolResult {
+                success: false,
+                output: String::new(),
+                error: Some("Rate limit exceeded: too many actions in the last hour".into()),
+            });
+        }
+
+        if !self.security.record_action() {
+            return Ok(ToolResult {
+                success: false,
+                output: String::new(),
+                error: Some("Rate limit exceeded: action budget exhausted".into()),
+            });
+        }
+
+        let Some(name) = args.get("name").and_then(|v| v.as_str()) else {
+            return self.list_inbox().await;
+        };
+
+        // Inbox names are flat; any path component is a traversal attempt.
+        if name.contains('/') || name.contains('\\') || name.contains("..") {
+            return Ok(ToolResult {
+                success: false,
+                output: String::new(),
+                error: Some(format!("Invalid attachment name: {name}")),
+            });
+        }
+
+        let path = self.inbox_dir().join(name);
+        let meta = match tokio::fs::metadata(&path).await {
+            Ok(meta) => meta,
+            Err(_) => {
+                return Ok(ToolResult {
+                    success: false,
+                    output: String::new(),
+                    error: Some(format!(
+                        "Attachment not found: {name} (omit 'name' to list the inbox)"
+                    )),
+                });
+            }
+        };
+
+        if meta.len() > MAX_ATTACHMENT_BYTES {
+            return Ok(ToolResult {
+                success: false,
+                output: String::new(),
+                error: Some(format!(
+                    "Attachment too large to read: {} bytes (limit: {MAX_ATTACHMENT_BYTES} bytes)",
+                    meta.len()
+                )),
+            });
+        }
+
+        let bytes = tokio::fs::read(&path).await?;
+        match String::from_utf8(bytes) {
+            Ok(text) => Ok(ToolResult {
+                success: true,
+                output: text,
+                error: None,
+            }),
+            Err(e) => Ok(ToolResult {
+                success: true,
+                output: format!(
+                    "Binary attachment: {name} ({} bytes, not UTF-8 text). Full path: {}",
+                    e.as_bytes().len(),
+                    path.display()
+                ),
+                error: None,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::security::SecurityPolicy;
+
+    fn test_tool(workspace: std::path::PathBuf) -> ReadAttachmentTool {
+        ReadAttachmentTool::new(Arc::new(SecurityPolicy {
+            workspace_dir: workspace,
+            ..SecurityPolicy::default()
+        }))
+    }
+
+    async fn setup_inbox(label: &str) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!("zeroclaw_test_read_attachment_{label}"));
+        let _ = tokio::fs::remove_dir_all(&dir).await;
+        tokio::fs::create_dir_all(dir.join("inbox")).await.unwrap();
+        dir
+    }
+
+    #[test]
+    fn read_attachment_name_and_schema() {
+        let tool = test_tool(std::env::temp_dir());
+        assert_eq!(tool.name(), "read_attachment");
+        let schema = tool.parameters_schema();
+        assert!(schema["properties"]["name"].is_object());
+    }
+
+    #[tokio::test]
+    async fn read_attachment_reads_text_file() {
+        let dir = setup_inbox("text").await;
+        tokio::fs::write(dir.join("inbox/report.csv"), "a,b\n1,2")
+            .await
+            .unwrap();
+
+        let tool = test_tool(dir.clone());
+        let result = tool.execute(json!({"name": "report.csv"})).await.unwrap();
+        assert!(result.success);
+        assert_eq!(result.output, "a,b\n1,2");
+
+        let _ = tokio::fs::remove_dir_all(&dir).await;
+    }
+
+    #[tokio::test]
+    async fn read_attachment_reports_binary_file() {
+        let dir = setup_inbox("binary").await;
+        tokio::fs::write(dir.join("inbox/blob.pdf"), [0xff, 0xfe, 0x00])
+            .await
+            .unwrap();
+
+        let tool = test_tool(dir.clone());
+        let result = tool.execute(json!({"name": "blob.pdf"})).await.unwrap();
+        assert!(result.success);
+        assert!(result.output.contains("Binary attachment: blob.pdf"));
+
+        let _ = tokio::fs::remove_dir_all(&dir).await;
+    }
+
+    #[tokio::test]
+    async fn read_attachment_rejects_traversal() {
+        let tool = test_tool(std::env::temp_dir());
+        for name in ["../secret", "a/b.txt", "a\\b.txt", ".."] {
+            let result = tool.execute(json!({"name": name})).await.unwrap();
+            assert!(!result.success);
+            assert!(result.error.as_ref().unwrap().contains("Invalid"));
+        }
+    }
+
+    #[tokio::test]
+    async fn read_attachment_rejects_oversized_file() {
+        let dir = setup_inbox("large").await;
+        let big = vec![b'x'; (MAX_ATTACHMENT_BYTES + 1) as usize];
+        tokio::fs::write(dir.join("inbox/huge.log"), &big)
+            .await
+            .unwrap();
+
+        let tool = test_tool(dir.clone());
+        let result = tool.execute(json!({"name": "huge.log"})).await.unwrap();
+        assert!(!result.success);
+        assert!(result.error.as_ref().unwrap().contains("too large"));
+
+        let _ = tokio::fs::remove_dir_all(&dir).await;
+    }
+
+    #[tokio::test]
+    async fn read_attachment_lists_inbox_when_name_omitted() {
+        let dir = setup_inbox("list").await;
+        tokio::fs::write(dir.join("inbox/a.txt"), "x")
+            .await
+            .unwrap();
+        tokio::fs::write(dir.join("inbox/b.log"), "yy")
+            .await
+            .unwrap();
+
+        let tool = test_tool(dir.clone());
+        let result = tool.execute(json!({})).await.unwrap();
+        assert!(result.success);
+        assert!(result.output.contains("a.txt (1 bytes)"));
+        assert!(result.output.contains("b.log (2 bytes)"));
+
+        let _ = tokio::fs::remove_dir_all(&dir).await;
+    }
+
+    #[tokio::test]
+    async fn read_attachment_empty_inbox_message() {
+        let dir = setup_inbox("empty").await;
+        let tool = test_tool(dir.clone());
+        let result = tool.execute(json!({})).await.unwrap();
+        assert!(result.success);
+        assert!(result.output.contains("Inbox is empty"));
+
+        let _ = tokio::fs::remove_dir_all(&dir).await;
+    }
+
+    #[tokio::test]
+    async fn read_attachment_missing_file() {
+        let dir = setup_inbox("missing").await;
+        let tool = test_tool(dir.clone());
+        let result = tool.execute(json!({"name": "nope.txt"})).await.unwrap();
+        assert!(!result.success);
+        assert!(result.error.as_ref().unwrap().contains("not found"));
+
+        let _ = tokio::fs::remove_dir_all(&dir).await;
+    }
+}
@@ -0,0 +1,122 @@
+//! Read telemetry tool — recent sensor readings from peripheral boards.
+//!
+//! Reads the process-wide telemetry buffer that connected boards push into
+//! (see `peripherals::telemetry`). Lets the agent reason over temperature /
+//! GPIO trends, and lets cron tasks alert when a reading crosses a threshold.
+
+use super::traits::{Tool, ToolResult};
+use async_trait::async_trait;
+use serde_json::json;
+
+/// Default number of readings returned when `limit` is omitted.
+const DEFAULT_LIMIT: usize = 20;
+/// Hard cap on readings returned per call.
+const MAX_LIMIT: usize = 200;
+
+/// Tool: read recent telemetry readings from the shared buffer.
+pub struct ReadTelemetryTool;
+
+#[async_trait]
+impl Tool for ReadTelemetryTool {
+    fn name(&self) -> &str {
+        "read_telemetry"
+    }
+
+    fn description(&self) -> &str {
+        "Read recent sensor readings (temperature, GPIO states) pushed by connected \
+         peripheral boards, newest first. Filter by board and/or metric (e.g. \
+         'temperature', 'gpio.13'). Use to reason over trends or check thresholds."
+    }
+
+    fn parameters_schema(&self) -> serde_json::Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "board": {
+                    "type": "string",
+                    "description": "Only readings from this board instance"
+                },
+                "metric": {
+                    "type": "string",
+                    "description": "Only readings for this metric (e.g. 'temperature', 'gpio.13')"
+                },
+                "limit": {
+                    "type": "integer",
+                    "description": "Maximum readings to return (default 20, max 200)"
+                }
+            }
+        })
+    }
+
+    async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
+        let board = args.get("board").and_then(|v| v.as_str());
+        let metric = args.get("metric").and_then(|v| v.as_str());
+        let limit = args
+            .get("limit")
+            .and_then(serde_json::Value::as_u64)
+            .map_or(DEFAULT_LIMIT, |l| {
+                usize::try_from(l).unwrap_or(MAX_LIMIT).clamp(1, MAX_LIMIT)
+            });
+
+        let readings = crate::peripherals::telemetry::global().recent(board, metric, limit);
+        if readings.is_empty() {
+            return Ok(ToolResult {
+                success: true,
+                output: "No telemetry readings recorded yet. Boards push readings when \
+                         [peripherals] telemetry_interval_secs > 0."
+                    .into(),
+                error: None,
+            });
+        }
+
+        let lines: Vec<String> = readings.iter().map(|r| r.describe()).collect();
+        Ok(ToolResult {
+            success: true,
+            output: format!(
+                "{} reading(s), newest first:\n{}",
+                readings.len(),
+                lines.join("\n")
+            ),
+            error: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::peripherals::telemetry;
+
+    #[test]
+    fn schema_has_optional_filters_only() {
+        let schema = ReadTelemetryTool.parameters_schema();
+        assert!(schema.get("required").is_none());
+        assert!(schema["properties"]["board"].is_object());
+        assert!(schema["properties"]["metric"].is_object());
+    }
+
+    #[tokio::test]
+    async fn execute_returns_filtered_readings() {
+        // Use a board name unique to this test: the buffer is process-global.
+        telemetry::record("telemetry_test_board", "temperature", 42.5, Some("C"));
+        telemetry::record("telemetry_test_board", "gpio.13", 1.0, None);
+
+        let result = ReadTelemetryTool
+            .execute(json!({"board": "telemetry_test_board", "metric": "temperature"}))
+            .await
+            .unwrap();
+        assert!(result.success);
+        assert!(result.output.contains("temperature = 42.5C"));
+        assert!(!result.output.contains("gpio.13"));
+    }
+
+    #[tokio::test]
+    async fn execute_with_unknown_board_reports_empty() {
+        let result = ReadTelemetryTool
+            .execute(json!({"board": "telemetry_absent_board"}))
+            .await
+            .unwrap();
+        assert!(result.success);
+        assert!(result.output.contains("No telemetry readings"));
+    }
+}
@@ -0,0 +1,310 @@
+use super::traits::{Tool, ToolResult};
+use crate::config::RunCodeConfig;
+use crate::security::policy::ToolOperation;
+use crate::security::SecurityPolicy;
+use async_trait::async_trait;
+use serde_json::json;
+use std::process::Stdio;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::AsyncWriteExt;
+
+/// Maximum combined stdout/stderr size in bytes (256KB).
+const MAX_OUTPUT_BYTES: usize = 262_144;
+/// Maximum snippet size in bytes (64KB).
+const MAX_CODE_BYTES: usize = 65_536;
+
+/// Execute short code snippets inside a disposable container.
+///
+/// Each run spawns a fresh container via the configured engine (Docker or
+/// Podman) with CPU/memory/PID limits and — by default — no network. The
+/// snippet is piped over stdin, so nothing from the host filesystem is
+/// mounted into the container.
+pub struct RunCodeTool {
+    config: RunCodeConfig,
+    security: Arc<SecurityPolicy>,
+}
+
+impl RunCodeTool {
+    pub fn new(config: RunCodeConfig, security: Arc<SecurityPolicy>) -> Self {
+        Self { config, security }
+    }
+
+    /// Image and in-container command for a supported language.
+    fn language_spec(&self, language: &str) -> anyhow::Result<(String, &'static str)> {
+        match language {
+            "python" => Ok((self.config.python_image.clone(), "python3 -")),
+            "javascript" => Ok((self.config.javascript_image.clone(), "node -")),
+            "rust" => Ok((
+                self.config.rust_image.clone(),
+                "cat > /tmp/snippet.rs && rustc -O /tmp/snippet.rs -o /tmp/snippet && /tmp/snippet",
+            )),
+            other => anyhow::bail!(
+                "Unsupported language '{other}' (supported: python, javascript, rust)"
+            ),
+        }
+    }
+}
+
+#[async_trait]
+impl Tool for RunCodeTool {
+    fn name(&self) -> &str {
+        "run_code"
+    }
+
+    fn description(&self) -> &str {
+        "Execute a short Python, JavaScript, or Rust snippet in an isolated container with CPU/memory/time limits and no network access. Use for calculations, data transforms, and quick verification. Print results to stdout."
+    }
+
+    fn parameters_schema(&self) -> serde_json::Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "language": {
+                    "type": "string",
+                    "enum": ["python", "javascript", "rust"],
+                    "description": "Language of the snippet"
+                },
+                "code": {
+                    "type": "string",
+                    "description": "The code to run. Results must be printed to stdout."
+                }
+            },
+            "required": ["language", "code"]
+        })
+    }
+
+    async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
+        let language = args
+            .get("language")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Missing 'language' parameter"))?;
+        let code = args
+            .get("code")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Missing 'code' parameter"))?;
+
+        if code.len() > MAX_CODE_BYTES {
+            return Ok(ToolResult {
+                success: false,
+                output: String::new(),
+                error: Some(format!(
+                    "Snippet too large: {} bytes (max {MAX_CODE_BYTES})",
+                    code.len()
+                )),
+            });
+        }
+
+        let (image, container_command) = match self.language_spec(language) {
+            Ok(spec) => spec,
+            Err(e) => {
+                return Ok(ToolResult {
+                    success: false,
+                    output: String::new(),
+                    error: Some(e.to_string()),
+                });
+            }
+        };
+
+        if let Err(error) = self
+            .security
+            .enforce_tool_operation(ToolOperation::Act, "run_code")
+        {
+            return Ok(ToolResult {
+                success: false,
+                output: String::new(),
+                error: Some(error),
+            });
+        }
+
+        let mut cmd = tokio::process::Command::new(self.config.engine.trim());
+        cmd.arg("run")
+            .arg("--rm")
+            .arg("--init")
+            .arg("--interactive")
+            .arg("--network")
+            .arg(self.config.network.trim())
+            .arg("--memory")
+            .arg(format!("{}m", self.config.memory_limit_mb))
+            .arg("--cpus")
+            .arg(self.config.cpu_limit.to_string())
+            .arg("--pids-limit")
+            .arg("256")
+            .arg(image.trim())
+            .arg("sh")
+            .arg("-c")
+            .arg(container_command);
+        cmd.env_clear();
+        if let Ok(path) = std::env::var("PATH") {
+            cmd.env("PATH", path);
+        }
+        cmd.stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true);
+
+        let mut child = match cmd.spawn() {
+            Ok(child) => child,
+            Err(e) => {
+                return Ok(ToolResult {
+                    success: false,
+                    output: String::new(),
+                    error: Some(format!(
+                        "Failed to start container engine '{}': {e}. Is it installed and running?",
+                        self.config.engine
+                    )),
+                });
+            }
+        };
+
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin.write_all(code.as_bytes()).await?;
+            drop(stdin);
+        }
+
+        let result = tokio::time::timeout(
+            Duration::from_secs(self.config.timeout_secs),
+            child.wait_with_output(),
+        )
+        .await;
+
+        match result {
+            Ok(Ok(output)) => {
+                let mut stdout = String::from_utf8_lossy(&output.stdout).to_string();
+                let mut stderr = String::from_utf8_lossy(&output.stderr).to_string();
+                if stdout.len() > MAX_OUTPUT_BYTES {
+                    stdout.truncate(MAX_OUTPUT_BYTES);
+                    stdout.push_str("\n...[truncated]");
+                }
+                if stderr.len() > MAX_OUTPUT_BYTES {
+                    stderr.truncate(MAX_OUTPUT_BYTES);
+                    stderr.push_str("\n...[truncated]");
+                }
+
+                if output.status.success() {
+                    Ok(ToolResult {
+                        success: true,
+                        output: if stderr.is_empty() {
+                            stdout
+                        } else {
+                            format!("{stdout}\n[stderr]\n{stderr}")
+                        },
+                        error: None,
+                    })
+                } else {
+                    Ok(ToolResult {
+                        success: false,
+                        output: stdout,
+                        error: Some(format!(
+                            "Exited with {}: {stderr}",
+                            output.status.code().map_or_else(
+                                || "signal".to_string(),
+                                |code| format!("code {code}")
+                            )
+                        )),
+                    })
+                }
+            }
+            Ok(Err(e)) => Ok(ToolResult {
+                success: false,
+                output: String::new(),
+                error: Some(format!("Failed to run snippet: {e}")),
+            }),
+            Err(_) => Ok(ToolResult {
+                success: false,
+                output: String::new(),
+                error: Some(format!(
+                    "Snippet timed out after {}s and was killed",
+                    self.config.timeout_secs
+                )),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::security::AutonomyLevel;
+
+    fn test_tool() -> RunCodeTool {
+        RunCodeTool::new(
+            RunCodeConfig::default(),
+            Arc::new(SecurityPolicy::default()),
+        )
+    }
+
+    #[test]
+    fn name_and_schema() {
+        let tool = test_tool();
+        assert_eq!(tool.name(), "run_code");
+        let schema = tool.parameters_schema();
+        assert!(schema["properties"]["language"].is_object());
+        assert!(schema["properties"]["code"].is_object());
+    }
+
+    #[test]
+    fn language_spec_known_languages() {
+        let tool = test_tool();
+        for lang in ["python", "javascript", "rust"] {
+            assert!(tool.language_spec(lang).is_ok(), "language {lang}");
+        }
+    }
+
+    #[test]
+    fn language_spec_rejects_unknown() {
+        let tool = test_tool();
+        assert!(tool.language_spec("perl").is_err());
+    }
+
+    #[test]
+    fn default_config_has_no_network() {
+        let config = RunCodeConfig::default();
+        assert_eq!(config.network, "none");
+        assert!(!config.enabled);
+    }
+
+    #[tokio::test]
+    async fn missing_language_errors() {
+        let tool = test_tool();
+        let result = tool.execute(json!({"code": "print(1)"})).await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn unsupported_language_fails() {
+        let tool = test_tool();
+        let result = tool
+            .execute(json!({"language": "perl", "code": "print 1"}))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("Unsupported language"));
+    }
+
+    #[tokio::test]
+    async fn oversized_snippet_rejected() {
+        let tool = test_tool();
+        let code = "x".repeat(MAX_CODE_BYTES + 1);
+        let result = tool
+            .execute(json!({"language": "python", "code": code}))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("too large"));
+    }
+
+    #[tokio::test]
+    async fn blocked_in_readonly_mode() {
+        let readonly = Arc::new(SecurityPolicy {
+            autonomy: AutonomyLevel::ReadOnly,
+            ..SecurityPolicy::default()
+        });
+        let tool = RunCodeTool::new(RunCodeConfig::default(), readonly);
+        let result = tool
+            .execute(json!({"language": "python", "code": "print(1)"}))
+            .await
+            .unwrap();
+        assert!(!result.success);
+    }
+}
@@ -1,6 +1,6 @@
 use super::traits::{Tool, ToolResult};
 use crate::runtime::RuntimeAdapter;
-use crate::security::SecurityPolicy;
+use crate::security::{AuditEvent, AuditEventType, SecurityPolicy};
 use async_trait::async_trait;
 use serde_json::json;
 use std::sync::Arc;
@@ -85,16 +85,29 @@ impl Tool for ShellTool {
             });
         }
 
-        match self.security.validate_command_execution(command, approved) {
-            Ok(_) => {}
+        let risk = match self.security.validate_command_execution(command, approved) {
+            Ok(risk) => risk,
             Err(reason) => {
+                self.security.audit_event(
+                    &AuditEvent::new(AuditEventType::CommandExecution)
+                        .with_action(
+                            command.to_string(),
+                            self.security
+                                .command_risk_level(command)
+                                .label()
+                                .to_string(),
+                            approved,
+                            false,
+                        )
+                        .with_policy_violation(),
+                );
                 return Ok(ToolResult {
                     success: false,
                     output: String::new(),
                     error: Some(reason),
                 });
             }
-        }
+        };
 
         if !self.security.record_action() {
             return Ok(ToolResult {
@@ -128,10 +141,12 @@ impl Tool for ShellTool {
             }
         }
 
+        let started = std::time::Instant::now();
         let result =
             tokio::time::timeout(Duration::from_secs(SHELL_TIMEOUT_SECS), cmd.output()).await;
+        let duration_ms = u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX);
 
-        match result {
+        let tool_result = match result {
             Ok(Ok(output)) => {
                 let mut stdout = String::from_utf8_lossy(&output.stdout).to_string();
                 let mut stderr = String::from_utf8_lossy(&output.stderr).to_string();
@@ -146,7 +161,7 @@ impl Tool for ShellTool {
                     stderr.push_str("\n... [stderr truncated at 1MB]");
                 }
 
-                Ok(ToolResult {
+                ToolResult {
                     success: output.status.success(),
                     output: stdout,
                     error: if stderr.is_empty() {
@@ -154,21 +169,34 @@ impl Tool for ShellTool {
                     } else {
                         Some(stderr)
                     },
-                })
+                }
             }
-            Ok(Err(e)) => Ok(ToolResult {
+            Ok(Err(e)) => ToolResult {
                 success: false,
                 output: String::new(),
                 error: Some(format!("Failed to execute command: {e}")),
-            }),
-            Err(_) => Ok(ToolResult {
+            },
+            Err(_) => ToolResult {
                 success: false,
                 output: String::new(),
                 error: Some(format!(
                     "Command timed out after {SHELL_TIMEOUT_SECS}s and was killed"
                 )),
-            }),
-        }
+            },
+        };
+
+        self.security.audit_event(
+            &AuditEvent::new(AuditEventType::CommandExecution)
+                .with_action(
+                    command.to_string(),
+                    risk.label().to_string(),
+                    approved,
+                    true,
+                )
+                .with_result(tool_result.success, None, duration_ms, None),
+        );
+
+        Ok(tool_result)
     }
 }
 
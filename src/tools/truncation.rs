@@ -0,0 +1,206 @@
+//! Tool-output truncation policy.
+//!
+//! A single tool call can return megabytes (a `cat` of a big file, a verbose
+//! build log) and blow the model's context window in one turn. The agent loop
+//! pipes every successful tool output through [`truncate_output`], which
+//! middle-elides past the configured byte/line budget: the head and tail of
+//! the output are kept (errors usually live at the ends), the middle is
+//! replaced with an elision marker, and the full result is spilled to a file
+//! in the workspace so nothing is lost — the marker tells the model where to
+//! find it.
+
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+/// Byte/line budget for a single tool result handed back to the model.
+///
+/// A limit of `0` disables that dimension; both at `0` disables truncation
+/// entirely.
+#[derive(Debug, Clone)]
+pub struct TruncationPolicy {
+    /// Maximum bytes of tool output kept in the conversation.
+    pub max_bytes: usize,
+    /// Maximum lines of tool output kept in the conversation.
+    pub max_lines: usize,
+    /// Directory for spilled full outputs; `None` disables the handoff file.
+    pub spill_dir: Option<PathBuf>,
+}
+
+impl Default for TruncationPolicy {
+    fn default() -> Self {
+        Self {
+            max_bytes: 65_536,
+            max_lines: 1_000,
+            spill_dir: None,
+        }
+    }
+}
+
+static POLICY: OnceLock<TruncationPolicy> = OnceLock::new();
+
+/// Install the process-wide truncation policy (first caller wins).
+pub fn set_policy(policy: TruncationPolicy) {
+    let _ = POLICY.set(policy);
+}
+
+/// Truncate one tool output according to the process-wide policy.
+pub fn truncate_output(output: String) -> String {
+    match POLICY.get() {
+        Some(policy) => truncate_with(output, policy),
+        None => truncate_with(output, &TruncationPolicy::default()),
+    }
+}
+
+/// Truncate `output` according to `policy`, middle-eliding past the budget.
+fn truncate_with(output: String, policy: &TruncationPolicy) -> String {
+    if policy.max_bytes == 0 && policy.max_lines == 0 {
+        return output;
+    }
+    let over_bytes = policy.max_bytes > 0 && output.len() > policy.max_bytes;
+    let over_lines = policy.max_lines > 0 && output.lines().count() > policy.max_lines;
+    if !over_bytes && !over_lines {
+        return output;
+    }
+
+    // Spill the full result first so the marker can reference it (best
+    // effort: truncation must not fail the tool call).
+    let spill_path = policy.spill_dir.as_ref().and_then(|dir| {
+        let path = dir.join(format!("tool-output-{}.txt", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(dir).ok()?;
+        std::fs::write(&path, &output).ok()?;
+        Some(path)
+    });
+
+    // Budget the head larger than the tail: leading context usually matters
+    // more, but errors often live at the end, so both survive.
+    let byte_budget = if policy.max_bytes > 0 {
+        policy.max_bytes
+    } else {
+        usize::MAX
+    };
+    let line_budget = if policy.max_lines > 0 {
+        policy.max_lines
+    } else {
+        usize::MAX
+    };
+    let head = collect_lines(
+        output.lines(),
+        byte_budget / 3 * 2,
+        line_budget.div_ceil(3) * 2,
+    );
+    let tail_lines = {
+        let mut reversed = collect_lines(output.lines().rev(), byte_budget / 3, line_budget / 3);
+        reversed.reverse();
+        reversed
+    };
+
+    let kept_bytes: usize = head.iter().chain(&tail_lines).map(|l| l.len()).sum();
+    let omitted_bytes = output.len().saturating_sub(kept_bytes);
+    let marker = match &spill_path {
+        Some(path) => format!(
+            "\n[... {omitted_bytes} of {} bytes elided; full result stored at {}, summarized above ...]\n",
+            output.len(),
+            path.display()
+        ),
+        None => format!(
+            "\n[... {omitted_bytes} of {} bytes elided ...]\n",
+            output.len()
+        ),
+    };
+
+    let mut truncated = head.join("\n");
+    truncated.push_str(&marker);
+    truncated.push_str(&tail_lines.join("\n"));
+    truncated
+}
+
+/// Take lines from `lines` until either budget would be exceeded.
+fn collect_lines<'a>(
+    lines: impl Iterator<Item = &'a str>,
+    max_bytes: usize,
+    max_lines: usize,
+) -> Vec<&'a str> {
+    let mut taken = Vec::new();
+    let mut bytes = 0usize;
+    for line in lines.take(max_lines) {
+        // +1 accounts for the newline that joins kept lines back together.
+        if bytes + line.len() + 1 > max_bytes {
+            break;
+        }
+        bytes += line.len() + 1;
+        taken.push(line);
+    }
+    taken
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn policy(max_bytes: usize, max_lines: usize, spill_dir: Option<PathBuf>) -> TruncationPolicy {
+        TruncationPolicy {
+            max_bytes,
+            max_lines,
+            spill_dir,
+        }
+    }
+
+    #[test]
+    fn output_within_budget_passes_through_unchanged() {
+        let output = "line one\nline two".to_string();
+        let result = truncate_with(output.clone(), &policy(1024, 100, None));
+        assert_eq!(result, output);
+    }
+
+    #[test]
+    fn zero_limits_disable_truncation() {
+        let output = "x".repeat(1_000_000);
+        let result = truncate_with(output.clone(), &policy(0, 0, None));
+        assert_eq!(result.len(), output.len());
+    }
+
+    #[test]
+    fn middle_elision_keeps_head_and_tail_past_line_budget() {
+        let output: String = (0..100)
+            .map(|i| format!("line-{i}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let result = truncate_with(output, &policy(0, 30, None));
+        assert!(result.starts_with("line-0"), "head missing: {result}");
+        assert!(result.ends_with("line-99"), "tail missing: {result}");
+        assert!(result.contains("bytes elided"), "marker missing: {result}");
+        assert!(!result.contains("line-50"), "middle should be elided");
+    }
+
+    #[test]
+    fn middle_elision_enforces_byte_budget() {
+        let output: String = (0..1000)
+            .map(|i| format!("line-{i:04}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let result = truncate_with(output, &policy(1000, 0, None));
+        assert!(result.len() < 1200, "result too large: {}", result.len());
+        assert!(result.starts_with("line-0000"));
+        assert!(result.ends_with("line-0999"));
+    }
+
+    #[test]
+    fn oversized_output_spills_full_result_and_references_path() {
+        let tmp = TempDir::new().unwrap();
+        let output: String = (0..100)
+            .map(|i| format!("line-{i}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let result = truncate_with(
+            output.clone(),
+            &policy(0, 10, Some(tmp.path().to_path_buf())),
+        );
+        assert!(result.contains("full result stored at"), "marker: {result}");
+
+        let spilled: Vec<_> = std::fs::read_dir(tmp.path()).unwrap().collect();
+        assert_eq!(spilled.len(), 1, "exactly one spill file expected");
+        let content = std::fs::read_to_string(spilled[0].as_ref().unwrap().path()).unwrap();
+        assert_eq!(content, output, "spill file must hold the full output");
+    }
+}
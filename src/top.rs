@@ -0,0 +1,604 @@
+//! Live terminal dashboard (`zeroclaw top`).
+//!
+//! Renders a periodically refreshing overview of a running daemon: component
+//! health, per-channel message throughput, today's spend against the daily
+//! budget, in-flight delegations, and the scheduler's next firings. Health
+//! data comes live from the daemon's control socket when it is running (see
+//! [`crate::daemon::control`]) with the persisted state file as fallback;
+//! the rest is read from state the daemon already persists (the delegation
+//! log, the cost ledger, and the cron store).
+//!
+//! Rendering is plain text with an ANSI clear-and-redraw per tick; a full TUI
+//! dependency would conflict with the binary-size goals for what is a
+//! read-only status view. When stdout is not a terminal a single frame is
+//! printed and the command exits, so the output can be captured in scripts.
+
+use crate::config::Config;
+use anyhow::Result;
+use chrono::Utc;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::io::{IsTerminal, Write};
+use std::path::Path;
+
+/// Scheduler rows shown before truncating.
+const MAX_NEXT_FIRINGS: usize = 5;
+/// Active delegation rows shown before truncating.
+const MAX_ACTIVE_ROWS: usize = 8;
+
+/// One refresh worth of dashboard data.
+struct Dashboard {
+    daemon: DaemonState,
+    active: Vec<ActiveDelegation>,
+    cost_today: Option<CostToday>,
+    next_firings: Vec<NextFiring>,
+}
+
+enum DaemonState {
+    /// No state file: the daemon is not running (or never ran).
+    NotRunning,
+    /// State file exists but cannot be parsed.
+    Invalid(String),
+    Running {
+        pid: u64,
+        uptime_seconds: u64,
+        /// Seconds since the daemon last flushed its state file, when the
+        /// timestamp is parseable. Large values mean the daemon likely died.
+        state_age_secs: Option<i64>,
+        /// Component name -> (status, last error detail).
+        components: Vec<(String, String, Option<String>)>,
+        /// Cumulative inbound message count per channel.
+        channel_messages: BTreeMap<String, u64>,
+    },
+}
+
+struct ActiveDelegation {
+    agent: String,
+    depth: u64,
+    since: String,
+}
+
+struct CostToday {
+    spent_usd: f64,
+    daily_limit_usd: f64,
+}
+
+struct NextFiring {
+    next_run: chrono::DateTime<Utc>,
+    label: String,
+}
+
+/// Run the dashboard loop until interrupted (Ctrl+C terminates the process).
+pub(crate) async fn run(config: &Config, interval_secs: u64) -> Result<()> {
+    let interval = interval_secs.max(1);
+    let interactive = std::io::stdout().is_terminal();
+    let mut prev_channels: Option<BTreeMap<String, u64>> = None;
+
+    loop {
+        let data = collect(config);
+        let rates = prev_channels
+            .as_ref()
+            .map(|prev| channel_rates(prev, &data, interval));
+        let frame = render(&data, rates.as_ref(), interval);
+
+        if interactive {
+            // Clear screen and move the cursor home, then redraw.
+            print!("\x1b[2J\x1b[H{frame}");
+            std::io::stdout().flush().ok();
+        } else {
+            print!("{frame}");
+            return Ok(());
+        }
+
+        if let DaemonState::Running {
+            channel_messages, ..
+        } = &data.daemon
+        {
+            prev_channels = Some(channel_messages.clone());
+        }
+        tokio::time::sleep(std::time::Duration::from_secs(interval)).await;
+    }
+}
+
+fn collect(config: &Config) -> Dashboard {
+    let cost_today = if config.cost.enabled {
+        crate::cost::CostTracker::new(config.cost.clone(), &config.workspace_dir)
+            .and_then(|tracker| tracker.get_daily_cost(Utc::now().date_naive()))
+            .ok()
+            .map(|spent_usd| CostToday {
+                spent_usd,
+                daily_limit_usd: config.cost.daily_limit_usd,
+            })
+    } else {
+        None
+    };
+
+    let mut next_firings: Vec<NextFiring> = crate::cron::list_jobs(config)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|job| job.enabled)
+        .map(|job| NextFiring {
+            next_run: job.next_run,
+            label: job.name.unwrap_or(job.command),
+        })
+        .collect();
+    next_firings.sort_by_key(|firing| firing.next_run);
+    next_firings.truncate(MAX_NEXT_FIRINGS);
+
+    Dashboard {
+        daemon: daemon_state(config),
+        active: active_delegations(&config.delegation_log_path()),
+        cost_today,
+        next_firings,
+    }
+}
+
+/// Live snapshot from the daemon's control socket when it is running,
+/// otherwise the last state file flush.
+fn daemon_state(config: &Config) -> DaemonState {
+    if let Some(snapshot) = crate::daemon::control::query(config, "status") {
+        return daemon_state_from_snapshot(&snapshot);
+    }
+    read_daemon_state(&crate::daemon::state_file_path(config))
+}
+
+fn read_daemon_state(path: &Path) -> DaemonState {
+    let raw = match std::fs::read_to_string(path) {
+        Ok(raw) => raw,
+        Err(_) => return DaemonState::NotRunning,
+    };
+    match serde_json::from_str::<Value>(&raw) {
+        Ok(snapshot) => daemon_state_from_snapshot(&snapshot),
+        Err(e) => DaemonState::Invalid(e.to_string()),
+    }
+}
+
+fn daemon_state_from_snapshot(snapshot: &Value) -> DaemonState {
+    let state_age_secs = snapshot
+        .get("written_at")
+        .and_then(|v| v.as_str())
+        .and_then(|raw| chrono::DateTime::parse_from_rfc3339(raw).ok())
+        .map(|written| (Utc::now() - written.with_timezone(&Utc)).num_seconds());
+
+    let mut components: Vec<(String, String, Option<String>)> = snapshot
+        .get("components")
+        .and_then(|v| v.as_object())
+        .map(|map| {
+            map.iter()
+                .map(|(name, component)| {
+                    let status = component
+                        .get("status")
+                        .and_then(|v| v.as_str())
+                        .unwrap_or("unknown")
+                        .to_string();
+                    let detail = component
+                        .get("last_error")
+                        .and_then(|v| v.as_str())
+                        .map(str::to_string);
+                    (name.clone(), status, detail)
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    components.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let channel_messages = snapshot
+        .get("channel_messages")
+        .and_then(|v| v.as_object())
+        .map(|map| {
+            map.iter()
+                .filter_map(|(name, count)| count.as_u64().map(|c| (name.clone(), c)))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    DaemonState::Running {
+        pid: snapshot.get("pid").and_then(|v| v.as_u64()).unwrap_or(0),
+        uptime_seconds: snapshot
+            .get("uptime_seconds")
+            .and_then(|v| v.as_u64())
+            .unwrap_or(0),
+        state_age_secs,
+        components,
+        channel_messages,
+    }
+}
+
+/// In-flight delegations from the delegation log: `DelegationStart` events
+/// without a matching `DelegationEnd` for the same (run, agent, depth) key.
+/// Same pairing rule as `delegations active`, reduced to the fields the
+/// dashboard shows.
+fn active_delegations(log_path: &Path) -> Vec<ActiveDelegation> {
+    let raw = match std::fs::read_to_string(log_path) {
+        Ok(raw) => raw,
+        Err(_) => return Vec::new(),
+    };
+
+    type Key = (String, String, u64);
+    let mut starts: BTreeMap<Key, Vec<ActiveDelegation>> = BTreeMap::new();
+    let mut ends: BTreeMap<Key, usize> = BTreeMap::new();
+
+    for line in raw.lines() {
+        let Ok(event) = serde_json::from_str::<Value>(line) else {
+            continue;
+        };
+        let field = |name: &str| {
+            event
+                .get(name)
+                .and_then(|v| v.as_str())
+                .unwrap_or("")
+                .to_string()
+        };
+        let depth = event.get("depth").and_then(|v| v.as_u64()).unwrap_or(0);
+        let key = (field("run_id"), field("agent_name"), depth);
+        match event.get("event_type").and_then(|v| v.as_str()) {
+            Some("DelegationStart") => starts.entry(key).or_default().push(ActiveDelegation {
+                agent: field("agent_name"),
+                depth,
+                since: field("timestamp"),
+            }),
+            Some("DelegationEnd") => *ends.entry(key).or_default() += 1,
+            _ => {}
+        }
+    }
+
+    let mut active: Vec<ActiveDelegation> = starts
+        .into_iter()
+        .flat_map(|(key, queue)| {
+            let matched = ends.get(&key).copied().unwrap_or(0);
+            queue.into_iter().skip(matched)
+        })
+        .collect();
+    active.sort_by(|a, b| a.since.cmp(&b.since));
+    active
+}
+
+/// Per-channel messages per minute since the previous refresh.
+fn channel_rates(
+    prev: &BTreeMap<String, u64>,
+    data: &Dashboard,
+    interval_secs: u64,
+) -> BTreeMap<String, f64> {
+    let DaemonState::Running {
+        channel_messages, ..
+    } = &data.daemon
+    else {
+        return BTreeMap::new();
+    };
+    channel_messages
+        .iter()
+        .map(|(name, count)| {
+            let delta = count.saturating_sub(prev.get(name).copied().unwrap_or(0));
+            (name.clone(), delta as f64 * 60.0 / interval_secs as f64)
+        })
+        .collect()
+}
+
+fn render(data: &Dashboard, rates: Option<&BTreeMap<String, f64>>, interval_secs: u64) -> String {
+    let mut out = String::new();
+    let push = |out: &mut String, line: &str| {
+        out.push_str(line);
+        out.push('\n');
+    };
+
+    push(
+        &mut out,
+        &format!(
+            "ZeroClaw Top — {} (refresh {interval_secs}s, Ctrl+C to quit)",
+            Utc::now().format("%Y-%m-%d %H:%M:%S UTC")
+        ),
+    );
+    push(&mut out, "");
+
+    match &data.daemon {
+        DaemonState::NotRunning => {
+            push(&mut out, "Daemon: not running (no state file)");
+        }
+        DaemonState::Invalid(e) => {
+            push(&mut out, &format!("Daemon: state file is not valid: {e}"));
+        }
+        DaemonState::Running {
+            pid,
+            uptime_seconds,
+            state_age_secs,
+            components,
+            channel_messages,
+        } => {
+            let stale = match state_age_secs {
+                Some(age) if *age > 30 => {
+                    format!(" — state is {age}s old, daemon may have stopped")
+                }
+                _ => String::new(),
+            };
+            push(
+                &mut out,
+                &format!(
+                    "Daemon: up {} (pid {pid}){stale}",
+                    format_duration(*uptime_seconds)
+                ),
+            );
+            for (name, status, detail) in components {
+                let detail = match detail {
+                    Some(detail) if status != "ok" => format!("  {detail}"),
+                    _ => String::new(),
+                };
+                push(&mut out, &format!("  {name:<18} {status}{detail}"));
+            }
+
+            push(&mut out, "");
+            push(&mut out, "Channels (messages since daemon start):");
+            if channel_messages.is_empty() {
+                push(&mut out, "  (no messages yet)");
+            }
+            for (name, count) in channel_messages {
+                let rate = rates
+                    .and_then(|rates| rates.get(name))
+                    .map(|rate| format!("  ({rate:.1}/min)"))
+                    .unwrap_or_default();
+                push(&mut out, &format!("  {name:<18} {count}{rate}"));
+            }
+        }
+    }
+
+    push(&mut out, "");
+    match &data.cost_today {
+        Some(cost) => {
+            let percent = if cost.daily_limit_usd > 0.0 {
+                cost.spent_usd / cost.daily_limit_usd * 100.0
+            } else {
+                0.0
+            };
+            push(
+                &mut out,
+                &format!(
+                    "Cost today: ${:.2} / ${:.2} daily limit ({percent:.0}%)",
+                    cost.spent_usd, cost.daily_limit_usd
+                ),
+            );
+        }
+        None => push(&mut out, "Cost today: tracking disabled ([cost] enabled)"),
+    }
+
+    push(&mut out, "");
+    push(
+        &mut out,
+        &format!("Active delegations: {}", data.active.len()),
+    );
+    for delegation in data.active.iter().take(MAX_ACTIVE_ROWS) {
+        push(
+            &mut out,
+            &format!(
+                "  {} (depth {}) since {}",
+                delegation.agent, delegation.depth, delegation.since
+            ),
+        );
+    }
+    if data.active.len() > MAX_ACTIVE_ROWS {
+        push(
+            &mut out,
+            &format!("  … and {} more", data.active.len() - MAX_ACTIVE_ROWS),
+        );
+    }
+
+    push(&mut out, "");
+    push(&mut out, "Scheduler — next firings:");
+    if data.next_firings.is_empty() {
+        push(&mut out, "  (no enabled jobs)");
+    }
+    for firing in &data.next_firings {
+        push(
+            &mut out,
+            &format!(
+                "  {}  {}",
+                firing.next_run.format("%Y-%m-%d %H:%M UTC"),
+                firing.label
+            ),
+        );
+    }
+
+    out
+}
+
+fn format_duration(total_secs: u64) -> String {
+    let days = total_secs / 86_400;
+    let hours = (total_secs % 86_400) / 3_600;
+    let mins = (total_secs % 3_600) / 60;
+    if days > 0 {
+        format!("{days}d {hours}h {mins}m")
+    } else if hours > 0 {
+        format!("{hours}h {mins}m")
+    } else {
+        format!("{mins}m {}s", total_secs % 60)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn running_state() -> DaemonState {
+        DaemonState::Running {
+            pid: 42,
+            uptime_seconds: 7_500,
+            state_age_secs: Some(2),
+            components: vec![
+                ("channels".into(), "ok".into(), None),
+                (
+                    "scheduler".into(),
+                    "error".into(),
+                    Some("job failed".into()),
+                ),
+            ],
+            channel_messages: BTreeMap::from([("telegram".into(), 12)]),
+        }
+    }
+
+    #[test]
+    fn render_shows_all_dashboard_sections() {
+        let data = Dashboard {
+            daemon: running_state(),
+            active: vec![ActiveDelegation {
+                agent: "researcher".into(),
+                depth: 1,
+                since: "2026-08-28T11:58:03Z".into(),
+            }],
+            cost_today: Some(CostToday {
+                spent_usd: 0.42,
+                daily_limit_usd: 10.0,
+            }),
+            next_firings: vec![NextFiring {
+                next_run: Utc::now(),
+                label: "morning-brief".into(),
+            }],
+        };
+        let frame = render(&data, None, 2);
+        assert!(frame.contains("Daemon: up 2h 5m (pid 42)"));
+        assert!(frame.contains("scheduler"));
+        assert!(frame.contains("job failed"));
+        assert!(frame.contains("telegram"));
+        assert!(frame.contains("Cost today: $0.42 / $10.00 daily limit (4%)"));
+        assert!(frame.contains("Active delegations: 1"));
+        assert!(frame.contains("researcher (depth 1)"));
+        assert!(frame.contains("morning-brief"));
+    }
+
+    #[test]
+    fn render_reports_missing_daemon_and_disabled_cost_tracking() {
+        let data = Dashboard {
+            daemon: DaemonState::NotRunning,
+            active: Vec::new(),
+            cost_today: None,
+            next_firings: Vec::new(),
+        };
+        let frame = render(&data, None, 2);
+        assert!(frame.contains("Daemon: not running"));
+        assert!(frame.contains("tracking disabled"));
+        assert!(frame.contains("Active delegations: 0"));
+        assert!(frame.contains("(no enabled jobs)"));
+    }
+
+    #[test]
+    fn render_flags_stale_daemon_state() {
+        let DaemonState::Running {
+            pid,
+            uptime_seconds,
+            components,
+            channel_messages,
+            ..
+        } = running_state()
+        else {
+            unreachable!()
+        };
+        let data = Dashboard {
+            daemon: DaemonState::Running {
+                pid,
+                uptime_seconds,
+                state_age_secs: Some(120),
+                components,
+                channel_messages,
+            },
+            active: Vec::new(),
+            cost_today: None,
+            next_firings: Vec::new(),
+        };
+        let frame = render(&data, None, 2);
+        assert!(frame.contains("state is 120s old"));
+    }
+
+    #[test]
+    fn active_delegations_pairs_starts_with_ends() {
+        let dir = tempfile::tempdir().unwrap();
+        let log = dir.path().join("delegations.jsonl");
+        std::fs::write(
+            &log,
+            concat!(
+                r#"{"event_type":"DelegationStart","run_id":"r1","agent_name":"researcher","depth":1,"timestamp":"2026-08-28T10:00:00Z"}"#,
+                "\n",
+                r#"{"event_type":"DelegationStart","run_id":"r1","agent_name":"writer","depth":1,"timestamp":"2026-08-28T10:01:00Z"}"#,
+                "\n",
+                r#"{"event_type":"DelegationEnd","run_id":"r1","agent_name":"researcher","depth":1,"timestamp":"2026-08-28T10:02:00Z"}"#,
+                "\n",
+            ),
+        )
+        .unwrap();
+
+        let active = active_delegations(&log);
+        assert_eq!(active.len(), 1);
+        assert_eq!(active[0].agent, "writer");
+        assert_eq!(active[0].depth, 1);
+    }
+
+    #[test]
+    fn active_delegations_handles_missing_log() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(active_delegations(&dir.path().join("missing.jsonl")).is_empty());
+    }
+
+    #[test]
+    fn channel_rates_are_per_minute_deltas() {
+        let data = Dashboard {
+            daemon: DaemonState::Running {
+                pid: 1,
+                uptime_seconds: 60,
+                state_age_secs: None,
+                components: Vec::new(),
+                channel_messages: BTreeMap::from([("telegram".into(), 10)]),
+            },
+            active: Vec::new(),
+            cost_today: None,
+            next_firings: Vec::new(),
+        };
+        let prev = BTreeMap::from([("telegram".into(), 4)]);
+        let rates = channel_rates(&prev, &data, 30);
+        assert_eq!(rates.get("telegram"), Some(&12.0));
+    }
+
+    #[test]
+    fn format_duration_picks_largest_unit() {
+        assert_eq!(format_duration(45), "0m 45s");
+        assert_eq!(format_duration(3_660), "1h 1m");
+        assert_eq!(format_duration(90_000), "1d 1h 0m");
+    }
+
+    #[test]
+    fn read_daemon_state_parses_state_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("daemon_state.json");
+        std::fs::write(
+            &path,
+            serde_json::json!({
+                "pid": 7,
+                "uptime_seconds": 90,
+                "written_at": Utc::now().to_rfc3339(),
+                "components": {
+                    "gateway": {"status": "ok"},
+                    "scheduler": {"status": "error", "last_error": "boom"}
+                },
+                "channel_messages": {"discord": 3}
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        match read_daemon_state(&path) {
+            DaemonState::Running {
+                pid,
+                uptime_seconds,
+                components,
+                channel_messages,
+                ..
+            } => {
+                assert_eq!(pid, 7);
+                assert_eq!(uptime_seconds, 90);
+                assert_eq!(components.len(), 2);
+                assert_eq!(components[1].2.as_deref(), Some("boom"));
+                assert_eq!(channel_messages.get("discord"), Some(&3));
+            }
+            _ => panic!("expected running state"),
+        }
+        assert!(matches!(
+            read_daemon_state(&dir.path().join("missing.json")),
+            DaemonState::NotRunning
+        ));
+    }
+}
@@ -0,0 +1,377 @@
+//! Undo journal for agent-initiated file and config mutations.
+//!
+//! Every journaled mutation stores a reverse patch — the target's previous
+//! contents, or the fact that it did not exist — in
+//! `<workspace>/state/undo/journal.jsonl` (append-only JSONL, inspectable
+//! with standard tools). `zeroclaw undo [--last N | --run <id>]` rolls
+//! mutations back newest-first and drops the undone entries from the
+//! journal. Currently journaled: `file_write` tool writes and
+//! `zeroclaw config set/unset` edits.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// Ambient run ID for the current agent run, so journal entries made by
+/// tools can be rolled back together with `undo --run <id>`.
+static CURRENT_RUN_ID: Mutex<Option<String>> = Mutex::new(None);
+
+/// Mark the active agent run; called once at the start of `agent::run`.
+pub fn set_current_run_id(run_id: &str) {
+    if let Ok(mut current) = CURRENT_RUN_ID.lock() {
+        *current = Some(run_id.to_string());
+    }
+}
+
+/// The active agent run ID, when inside one.
+pub fn current_run_id() -> Option<String> {
+    CURRENT_RUN_ID
+        .lock()
+        .ok()
+        .and_then(|current| current.clone())
+}
+
+/// One journaled mutation with everything needed to reverse it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UndoEntry {
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub run_id: Option<String>,
+    pub recorded_at_unix: u64,
+    /// Mutation source: `file_write` or `config_edit`.
+    pub action: String,
+    /// Absolute path of the mutated file.
+    pub path: PathBuf,
+    /// Contents before the mutation; `None` means the file did not exist
+    /// (rollback removes it).
+    pub previous: Option<String>,
+}
+
+/// Append-only journal of reversible mutations for one workspace.
+pub struct UndoJournal {
+    path: PathBuf,
+}
+
+impl UndoJournal {
+    pub fn new(workspace_dir: &Path) -> Self {
+        Self {
+            path: workspace_dir
+                .join("state")
+                .join("undo")
+                .join("journal.jsonl"),
+        }
+    }
+
+    /// Record a mutation (best-effort: a journal failure is logged and never
+    /// fails the mutation itself, it only costs undo coverage).
+    pub fn record(
+        &self,
+        action: &str,
+        target: &Path,
+        previous: Option<String>,
+        run_id: Option<&str>,
+    ) {
+        let entry = UndoEntry {
+            id: uuid::Uuid::new_v4().to_string(),
+            run_id: run_id.map(str::to_string),
+            recorded_at_unix: now_unix_secs(),
+            action: action.to_string(),
+            path: target.to_path_buf(),
+            previous,
+        };
+        if let Err(e) = self.append(&entry) {
+            tracing::warn!(
+                journal = %self.path.display(),
+                "Failed to record undo entry: {e}"
+            );
+        }
+    }
+
+    fn append(&self, entry: &UndoEntry) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let mut line = serde_json::to_string(entry)?;
+        line.push('\n');
+        use std::io::Write as _;
+        std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?
+            .write_all(line.as_bytes())?;
+        Ok(())
+    }
+
+    /// All journal entries, oldest first. Malformed lines are skipped with a
+    /// warning rather than blocking rollback of the valid ones.
+    pub fn entries(&self) -> Result<Vec<UndoEntry>> {
+        if !self.path.exists() {
+            return Ok(Vec::new());
+        }
+        let raw = std::fs::read_to_string(&self.path)
+            .with_context(|| format!("Failed to read undo journal {}", self.path.display()))?;
+        let mut entries = Vec::new();
+        for line in raw.lines().filter(|line| !line.trim().is_empty()) {
+            match serde_json::from_str::<UndoEntry>(line) {
+                Ok(entry) => entries.push(entry),
+                Err(e) => tracing::warn!("Skipping malformed undo journal line: {e}"),
+            }
+        }
+        Ok(entries)
+    }
+
+    /// Roll back the most recent `count` mutations. Returns the entries that
+    /// were rolled back, newest first.
+    pub fn undo_last(&self, count: usize) -> Result<Vec<UndoEntry>> {
+        let entries = self.entries()?;
+        let keep = entries.len().saturating_sub(count);
+        self.rollback(entries, |index, _entry| index >= keep)
+    }
+
+    /// Roll back every mutation recorded for `run_id`, newest first.
+    pub fn undo_run(&self, run_id: &str) -> Result<Vec<UndoEntry>> {
+        let entries = self.entries()?;
+        self.rollback(entries, |_index, entry| {
+            entry.run_id.as_deref() == Some(run_id)
+        })
+    }
+
+    /// Apply rollback for entries selected by `select`, newest first, then
+    /// rewrite the journal without the undone entries.
+    fn rollback(
+        &self,
+        entries: Vec<UndoEntry>,
+        select: impl Fn(usize, &UndoEntry) -> bool,
+    ) -> Result<Vec<UndoEntry>> {
+        let mut undone = Vec::new();
+        let mut kept = Vec::new();
+        for (index, entry) in entries.iter().enumerate() {
+            if !select(index, entry) {
+                kept.push(entry.clone());
+            }
+        }
+
+        for (index, entry) in entries.iter().enumerate().rev() {
+            if !select(index, entry) {
+                continue;
+            }
+            rollback_entry(entry).with_context(|| {
+                format!(
+                    "Failed to roll back {} ({})",
+                    entry.path.display(),
+                    entry.id
+                )
+            })?;
+            undone.push(entry.clone());
+        }
+
+        if !undone.is_empty() {
+            self.rewrite(&kept)?;
+        }
+        Ok(undone)
+    }
+
+    fn rewrite(&self, entries: &[UndoEntry]) -> Result<()> {
+        if entries.is_empty() {
+            if self.path.exists() {
+                std::fs::remove_file(&self.path)?;
+            }
+            return Ok(());
+        }
+        let mut out = String::new();
+        for entry in entries {
+            out.push_str(&serde_json::to_string(entry)?);
+            out.push('\n');
+        }
+        std::fs::write(&self.path, out)?;
+        Ok(())
+    }
+}
+
+/// Reverse one mutation: restore the previous contents, or remove the file
+/// when it did not exist before the mutation.
+fn rollback_entry(entry: &UndoEntry) -> Result<()> {
+    match &entry.previous {
+        Some(previous) => {
+            if let Some(parent) = entry.path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(&entry.path, previous)?;
+        }
+        None => {
+            if entry.path.exists() {
+                std::fs::remove_file(&entry.path)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn now_unix_secs() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs())
+}
+
+/// Handle `zeroclaw undo [--last N | --run <id> | --list]`.
+pub fn handle_command(
+    last: Option<usize>,
+    run: Option<String>,
+    list: bool,
+    config: &crate::config::Config,
+) -> Result<()> {
+    let journal = UndoJournal::new(&config.workspace_dir);
+
+    if list {
+        let entries = journal.entries()?;
+        if entries.is_empty() {
+            println!("Undo journal is empty.");
+            return Ok(());
+        }
+        println!("{} journaled mutation(s), oldest first:", entries.len());
+        for entry in entries {
+            let state = if entry.previous.is_some() {
+                "overwrote"
+            } else {
+                "created"
+            };
+            println!(
+                "  {}  {}  {} {} (run: {})",
+                entry.id,
+                entry.action,
+                state,
+                entry.path.display(),
+                entry.run_id.as_deref().unwrap_or("-")
+            );
+        }
+        return Ok(());
+    }
+
+    let undone = if let Some(run_id) = run {
+        journal.undo_run(&run_id)?
+    } else {
+        journal.undo_last(last.unwrap_or(1))?
+    };
+
+    if undone.is_empty() {
+        println!("Nothing to undo.");
+        return Ok(());
+    }
+    for entry in &undone {
+        let verb = if entry.previous.is_some() {
+            "restored"
+        } else {
+            "removed"
+        };
+        println!("↩️  {verb} {}", entry.path.display());
+    }
+    println!("Rolled back {} mutation(s).", undone.len());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn journal(tmp: &TempDir) -> UndoJournal {
+        UndoJournal::new(tmp.path())
+    }
+
+    #[test]
+    fn record_and_entries_round_trip() {
+        let tmp = TempDir::new().unwrap();
+        let journal = journal(&tmp);
+        journal.record(
+            "file_write",
+            &tmp.path().join("a.txt"),
+            Some("old".into()),
+            Some("run-1"),
+        );
+        journal.record("config_edit", &tmp.path().join("config.toml"), None, None);
+
+        let entries = journal.entries().unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].action, "file_write");
+        assert_eq!(entries[0].run_id.as_deref(), Some("run-1"));
+        assert_eq!(entries[1].previous, None);
+    }
+
+    #[test]
+    fn undo_last_restores_previous_content() {
+        let tmp = TempDir::new().unwrap();
+        let journal = journal(&tmp);
+        let target = tmp.path().join("notes.md");
+
+        std::fs::write(&target, "original").unwrap();
+        journal.record("file_write", &target, Some("original".into()), None);
+        std::fs::write(&target, "mutated").unwrap();
+
+        let undone = journal.undo_last(1).unwrap();
+        assert_eq!(undone.len(), 1);
+        assert_eq!(std::fs::read_to_string(&target).unwrap(), "original");
+        assert!(journal.entries().unwrap().is_empty());
+    }
+
+    #[test]
+    fn undo_last_removes_files_that_did_not_exist() {
+        let tmp = TempDir::new().unwrap();
+        let journal = journal(&tmp);
+        let target = tmp.path().join("created.txt");
+
+        journal.record("file_write", &target, None, None);
+        std::fs::write(&target, "new file").unwrap();
+
+        let undone = journal.undo_last(1).unwrap();
+        assert_eq!(undone.len(), 1);
+        assert!(!target.exists());
+    }
+
+    #[test]
+    fn undo_last_rolls_back_newest_first_and_keeps_older_entries() {
+        let tmp = TempDir::new().unwrap();
+        let journal = journal(&tmp);
+        let target = tmp.path().join("seq.txt");
+
+        journal.record("file_write", &target, None, None);
+        journal.record("file_write", &target, Some("v1".into()), None);
+        std::fs::write(&target, "v2").unwrap();
+
+        let undone = journal.undo_last(1).unwrap();
+        assert_eq!(undone.len(), 1);
+        assert_eq!(std::fs::read_to_string(&target).unwrap(), "v1");
+        // The older "creation" entry must survive for a second undo.
+        assert_eq!(journal.entries().unwrap().len(), 1);
+
+        journal.undo_last(1).unwrap();
+        assert!(!target.exists());
+    }
+
+    #[test]
+    fn undo_run_rolls_back_only_matching_run() {
+        let tmp = TempDir::new().unwrap();
+        let journal = journal(&tmp);
+        let run_file = tmp.path().join("run.txt");
+        let other_file = tmp.path().join("other.txt");
+
+        journal.record("file_write", &run_file, None, Some("run-a"));
+        journal.record("file_write", &other_file, None, Some("run-b"));
+        std::fs::write(&run_file, "a").unwrap();
+        std::fs::write(&other_file, "b").unwrap();
+
+        let undone = journal.undo_run("run-a").unwrap();
+        assert_eq!(undone.len(), 1);
+        assert!(!run_file.exists());
+        assert!(other_file.exists());
+        assert_eq!(journal.entries().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn undo_on_empty_journal_is_a_noop() {
+        let tmp = TempDir::new().unwrap();
+        let journal = journal(&tmp);
+        assert!(journal.undo_last(3).unwrap().is_empty());
+        assert!(journal.undo_run("missing").unwrap().is_empty());
+    }
+}
@@ -43,6 +43,21 @@ pub fn truncate_with_ellipsis(s: &str, max_chars: usize) -> String {
     }
 }
 
+/// Best-effort human-readable message from a panic payload, as returned by
+/// `std::panic::catch_unwind` or `futures::FutureExt::catch_unwind`.
+///
+/// Panic payloads are almost always `&str` or `String`; anything else is
+/// reported generically rather than dropped.
+pub fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        (*s).to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "non-string panic payload".to_string()
+    }
+}
+
 /// Utility enum for handling optional values.
 pub enum MaybeSet<T> {
     Set(T),
@@ -142,4 +157,19 @@ mod tests {
         // Edge case: max_chars = 0
         assert_eq!(truncate_with_ellipsis("hello", 0), "...");
     }
+
+    #[test]
+    fn panic_message_extracts_str_and_string_payloads() {
+        let str_payload = std::panic::catch_unwind(|| panic!("static message")).unwrap_err();
+        assert_eq!(panic_message(str_payload.as_ref()), "static message");
+
+        let string_payload = std::panic::catch_unwind(|| panic!("formatted {}", 42)).unwrap_err();
+        assert_eq!(panic_message(string_payload.as_ref()), "formatted 42");
+    }
+
+    #[test]
+    fn panic_message_reports_non_string_payloads_generically() {
+        let payload = std::panic::catch_unwind(|| std::panic::panic_any(7_i32)).unwrap_err();
+        assert_eq!(panic_message(payload.as_ref()), "non-string panic payload");
+    }
 }
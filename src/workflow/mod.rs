@@ -0,0 +1,587 @@
+//! Multi-agent workflow engine: named YAML pipelines over delegate agents.
+//!
+//! Workflows live in `<workspace>/workflows/<name>.yaml`. Each step runs a
+//! delegate agent from `[agents.<name>]` with a prompt template; step outputs
+//! feed later steps via `{{input}}` and `{{steps.<name>}}` placeholders.
+//! A step can gate on a previous step's output (`when`) or fan out to
+//! parallel sub-steps. Every step emits `DelegationStart`/`DelegationEnd`
+//! events tagged `workflow = "<workflow>/<step>"` so delegation reports can
+//! group by pipeline.
+//!
+//! Example definition:
+//!
+//! ```yaml
+//! description: Research and summarize a topic
+//! steps:
+//!   - name: research
+//!     agent: researcher
+//!     prompt: "Research this topic: {{input}}"
+//!   - name: fanout
+//!     parallel:
+//!       - name: risks
+//!         agent: analyst
+//!         prompt: "List risks in: {{steps.research}}"
+//!       - name: opportunities
+//!         agent: analyst
+//!         prompt: "List opportunities in: {{steps.research}}"
+//!   - name: summary
+//!     agent: writer
+//!     when: { step: research, contains: "" }
+//!     prompt: "Summarize: {{steps.risks}} {{steps.opportunities}}"
+//! ```
+
+use crate::config::{Config, DelegateAgentConfig};
+use crate::observability::{Observer, ObserverEvent};
+use crate::providers::{self, Provider};
+use anyhow::{bail, Context, Result};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Per-step provider call timeout.
+const STEP_TIMEOUT_SECS: u64 = 300;
+
+/// A workflow definition parsed from YAML.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct WorkflowDefinition {
+    /// Human description shown by `workflow list`.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Ordered pipeline steps.
+    pub steps: Vec<WorkflowStep>,
+}
+
+/// A single pipeline step: either one agent prompt, or a parallel fan-out.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct WorkflowStep {
+    /// Step name; outputs are referenced as `{{steps.<name>}}`.
+    pub name: String,
+    /// Delegate agent from `[agents.<name>]` (required unless `parallel`).
+    #[serde(default)]
+    pub agent: Option<String>,
+    /// Model override for this step (defaults to the agent's model).
+    #[serde(default)]
+    pub model: Option<String>,
+    /// Prompt template; supports `{{input}}` and `{{steps.<name>}}`.
+    #[serde(default)]
+    pub prompt: Option<String>,
+    /// Skip this step unless the condition holds.
+    #[serde(default)]
+    pub when: Option<WhenClause>,
+    /// Parallel fan-out: sub-steps run concurrently, each stored by name.
+    #[serde(default)]
+    pub parallel: Vec<WorkflowStep>,
+}
+
+/// Condition gating a step on a previous step's output.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct WhenClause {
+    /// Name of an earlier step whose output is inspected.
+    pub step: String,
+    /// Substring that must appear in that output (case-insensitive).
+    pub contains: String,
+    /// Invert the check: run only when the substring is absent.
+    #[serde(default)]
+    pub negate: bool,
+}
+
+impl WhenClause {
+    fn holds(&self, outputs: &BTreeMap<String, String>) -> Result<bool> {
+        let output = outputs.get(&self.step).with_context(|| {
+            format!(
+                "Condition references step '{}' which has not produced output yet",
+                self.step
+            )
+        })?;
+        let found = output
+            .to_lowercase()
+            .contains(&self.contains.to_lowercase());
+        Ok(found != self.negate)
+    }
+}
+
+/// Directory holding workflow YAML files.
+pub fn workflows_dir(config: &Config) -> PathBuf {
+    config.workspace_dir.join("workflows")
+}
+
+/// Load and validate a workflow by name from `<workspace>/workflows/`.
+pub fn load_workflow(config: &Config, name: &str) -> Result<WorkflowDefinition> {
+    if name.is_empty()
+        || !name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-'))
+    {
+        bail!("Invalid workflow name '{name}' (use letters, digits, '-', '_')");
+    }
+
+    let dir = workflows_dir(config);
+    let path = ["yaml", "yml"]
+        .iter()
+        .map(|ext| dir.join(format!("{name}.{ext}")))
+        .find(|p| p.exists())
+        .with_context(|| {
+            format!(
+                "Workflow '{name}' not found (expected {}/{name}.yaml)",
+                dir.display()
+            )
+        })?;
+
+    let raw = std::fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read workflow: {}", path.display()))?;
+    let definition: WorkflowDefinition = serde_yaml::from_str(&raw)
+        .with_context(|| format!("Invalid workflow YAML: {}", path.display()))?;
+    validate_workflow(&definition, &config.agents)?;
+    Ok(definition)
+}
+
+/// Validate step structure and agent references before running anything.
+fn validate_workflow(
+    definition: &WorkflowDefinition,
+    agents: &std::collections::HashMap<String, DelegateAgentConfig>,
+) -> Result<()> {
+    if definition.steps.is_empty() {
+        bail!("Workflow has no steps");
+    }
+
+    let mut seen = std::collections::HashSet::new();
+    let mut flat: Vec<&WorkflowStep> = Vec::new();
+    for step in &definition.steps {
+        flat.push(step);
+        for sub in &step.parallel {
+            if !sub.parallel.is_empty() {
+                bail!("Step '{}': parallel groups cannot nest", sub.name);
+            }
+            flat.push(sub);
+        }
+    }
+
+    for step in flat {
+        if step.name.trim().is_empty() {
+            bail!("Workflow has a step with an empty name");
+        }
+        if !seen.insert(step.name.clone()) {
+            bail!("Duplicate step name '{}'", step.name);
+        }
+        if step.parallel.is_empty() {
+            let agent = step
+                .agent
+                .as_deref()
+                .with_context(|| format!("Step '{}' has no agent", step.name))?;
+            if !agents.contains_key(agent) {
+                bail!(
+                    "Step '{}' references unknown agent '{agent}' (configure [agents.{agent}])",
+                    step.name
+                );
+            }
+            if step.prompt.as_deref().unwrap_or("").trim().is_empty() {
+                bail!("Step '{}' has no prompt", step.name);
+            }
+        } else if step.agent.is_some() || step.prompt.is_some() {
+            bail!(
+                "Step '{}' mixes 'parallel' with 'agent'/'prompt'; move them into sub-steps",
+                step.name
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Substitute `{{input}}` and `{{steps.<name>}}` placeholders in a template.
+fn render_prompt(template: &str, input: &str, outputs: &BTreeMap<String, String>) -> String {
+    let mut rendered = template.replace("{{input}}", input);
+    for (name, output) in outputs {
+        rendered = rendered.replace(&format!("{{{{steps.{name}}}}}"), output);
+    }
+    rendered
+}
+
+/// List workflow names (file stems) available in the workspace.
+pub fn list_workflows(config: &Config) -> Result<Vec<String>> {
+    let dir = workflows_dir(config);
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+    let mut names = Vec::new();
+    for entry in std::fs::read_dir(&dir)? {
+        let path = entry?.path();
+        let is_yaml = matches!(
+            path.extension().and_then(|e| e.to_str()),
+            Some("yaml" | "yml")
+        );
+        if is_yaml {
+            if let Some(stem) = path.file_stem().and_then(|s| s.to_str()) {
+                names.push(stem.to_string());
+            }
+        }
+    }
+    names.sort();
+    Ok(names)
+}
+
+/// Run a named workflow and return the final step's output.
+pub async fn run_workflow(config: &Config, name: &str, input: &str) -> Result<String> {
+    let definition = load_workflow(config, name)?;
+    let observer: Arc<dyn Observer> = Arc::from(crate::observability::create_observer(
+        &config.observability,
+        &config.events,
+        config.delegation_log_path(),
+    ));
+
+    let mut outputs: BTreeMap<String, String> = BTreeMap::new();
+    let mut last_output = String::new();
+
+    for step in &definition.steps {
+        if let Some(when) = &step.when {
+            if !when.holds(&outputs)? {
+                println!("⏭️  Step '{}' skipped (condition not met)", step.name);
+                continue;
+            }
+        }
+
+        if step.parallel.is_empty() {
+            println!("▶️  Step '{}'", step.name);
+            let output = run_step(config, name, step, input, &outputs, &observer).await?;
+            last_output = output.clone();
+            outputs.insert(step.name.clone(), output);
+        } else {
+            println!(
+                "▶️  Step '{}' (parallel fan-out: {})",
+                step.name,
+                step.parallel.len()
+            );
+            let futures: Vec<_> = step
+                .parallel
+                .iter()
+                .map(|sub| run_step(config, name, sub, input, &outputs, &observer))
+                .collect();
+            let results = futures::future::join_all(futures).await;
+            let mut combined = Vec::new();
+            for (sub, result) in step.parallel.iter().zip(results) {
+                let output = result?;
+                combined.push(format!("[{}]\n{}", sub.name, output));
+                outputs.insert(sub.name.clone(), output);
+            }
+            last_output = combined.join("\n\n");
+            outputs.insert(step.name.clone(), last_output.clone());
+        }
+    }
+
+    Ok(last_output)
+}
+
+/// Run one leaf step: resolve the agent, call its provider, record events.
+async fn run_step(
+    config: &Config,
+    workflow_name: &str,
+    step: &WorkflowStep,
+    input: &str,
+    outputs: &BTreeMap<String, String>,
+    observer: &Arc<dyn Observer>,
+) -> Result<String> {
+    let agent_name = step.agent.as_deref().unwrap_or_default();
+    let agent_config = config
+        .agents
+        .get(agent_name)
+        .with_context(|| format!("Unknown agent '{agent_name}'"))?;
+    let model = step.model.as_deref().unwrap_or(&agent_config.model);
+    let prompt = render_prompt(step.prompt.as_deref().unwrap_or_default(), input, outputs);
+
+    let credential = agent_config
+        .api_key
+        .clone()
+        .or_else(|| config.api_key.clone());
+    let provider: Box<dyn Provider> = providers::create_provider_with_options(
+        &agent_config.provider,
+        credential.as_deref(),
+        &providers::ProviderRuntimeOptions {
+            auth_profile_override: None,
+            zeroclaw_dir: config.config_path.parent().map(PathBuf::from),
+            secrets_encrypt: config.secrets.encrypt,
+            reasoning_enabled: config.runtime.reasoning_enabled,
+        },
+    )
+    .with_context(|| {
+        format!(
+            "Failed to create provider '{}' for step '{}'",
+            agent_config.provider, step.name
+        )
+    })?;
+
+    let workflow_context = Some(format!("{workflow_name}/{}", step.name));
+    observer.record_event(&ObserverEvent::DelegationStart {
+        agent_name: agent_name.to_string(),
+        provider: agent_config.provider.clone(),
+        model: model.to_string(),
+        depth: 0,
+        agentic: false,
+        workflow: workflow_context.clone(),
+    });
+
+    let started = Instant::now();
+    let result = tokio::time::timeout(
+        Duration::from_secs(STEP_TIMEOUT_SECS),
+        provider.chat_with_system(
+            agent_config.system_prompt.as_deref(),
+            &prompt,
+            model,
+            agent_config.temperature.unwrap_or(0.7),
+        ),
+    )
+    .await;
+
+    let result = match result {
+        Ok(inner) => inner,
+        Err(_elapsed) => Err(anyhow::anyhow!(
+            "Step '{}' timed out after {STEP_TIMEOUT_SECS}s",
+            step.name
+        )),
+    };
+
+    observer.record_event(&ObserverEvent::DelegationEnd {
+        agent_name: agent_name.to_string(),
+        provider: agent_config.provider.clone(),
+        model: model.to_string(),
+        depth: 0,
+        duration: started.elapsed(),
+        success: result.is_ok(),
+        error_message: result.as_ref().err().map(ToString::to_string),
+        tokens_used: None,
+        cost_usd: None,
+        workflow: workflow_context,
+        node: None,
+    });
+
+    result.with_context(|| format!("Step '{}' failed", step.name))
+}
+
+#[allow(clippy::needless_pass_by_value)]
+pub async fn handle_command(command: crate::WorkflowCommands, config: &Config) -> Result<()> {
+    match command {
+        crate::WorkflowCommands::Run { name, input } => {
+            let output = run_workflow(config, &name, input.as_deref().unwrap_or("")).await?;
+            println!("\n✅ Workflow '{name}' complete:\n{output}");
+            Ok(())
+        }
+        crate::WorkflowCommands::List => {
+            let names = list_workflows(config)?;
+            if names.is_empty() {
+                println!("No workflows yet.");
+                println!(
+                    "\nCreate one at {}/<name>.yaml",
+                    workflows_dir(config).display()
+                );
+                return Ok(());
+            }
+            println!("🧩 Workflows ({}):", names.len());
+            for name in names {
+                match load_workflow(config, &name) {
+                    Ok(definition) => println!(
+                        "- {name} ({} steps){}",
+                        definition.steps.len(),
+                        definition
+                            .description
+                            .map(|d| format!(" — {d}"))
+                            .unwrap_or_default()
+                    ),
+                    Err(e) => println!("- {name} (invalid: {e})"),
+                }
+            }
+            Ok(())
+        }
+        crate::WorkflowCommands::Show { name } => {
+            let definition = load_workflow(config, &name)?;
+            if let Some(description) = &definition.description {
+                println!("{description}\n");
+            }
+            for step in &definition.steps {
+                if step.parallel.is_empty() {
+                    println!(
+                        "- {} (agent: {})",
+                        step.name,
+                        step.agent.as_deref().unwrap_or("?")
+                    );
+                } else {
+                    println!("- {} (parallel):", step.name);
+                    for sub in &step.parallel {
+                        println!(
+                            "    - {} (agent: {})",
+                            sub.name,
+                            sub.agent.as_deref().unwrap_or("?")
+                        );
+                    }
+                }
+            }
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn test_config(tmp: &TempDir) -> Config {
+        let mut config = Config {
+            workspace_dir: tmp.path().join("workspace"),
+            config_path: tmp.path().join("config.toml"),
+            ..Config::default()
+        };
+        config.agents.insert(
+            "researcher".into(),
+            DelegateAgentConfig {
+                provider: "openrouter".into(),
+                model: "model-a".into(),
+                system_prompt: None,
+                api_key: None,
+                temperature: None,
+                max_depth: 3,
+                agentic: false,
+                allowed_tools: vec![],
+                max_iterations: 10,
+                node: None,
+            },
+        );
+        config
+    }
+
+    fn write_workflow(config: &Config, name: &str, yaml: &str) {
+        let dir = workflows_dir(config);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join(format!("{name}.yaml")), yaml).unwrap();
+    }
+
+    #[test]
+    fn load_valid_workflow() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp);
+        write_workflow(
+            &config,
+            "research",
+            "description: test\nsteps:\n  - name: one\n    agent: researcher\n    prompt: 'Do: {{input}}'\n",
+        );
+        let definition = load_workflow(&config, "research").unwrap();
+        assert_eq!(definition.steps.len(), 1);
+        assert_eq!(definition.description.as_deref(), Some("test"));
+    }
+
+    #[test]
+    fn load_missing_workflow_errors() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp);
+        assert!(load_workflow(&config, "nope").is_err());
+    }
+
+    #[test]
+    fn load_rejects_traversal_names() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp);
+        assert!(load_workflow(&config, "../evil").is_err());
+        assert!(load_workflow(&config, "").is_err());
+    }
+
+    #[test]
+    fn validate_rejects_unknown_agent() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp);
+        write_workflow(
+            &config,
+            "bad",
+            "steps:\n  - name: one\n    agent: nobody\n    prompt: hi\n",
+        );
+        let err = load_workflow(&config, "bad").unwrap_err().to_string();
+        assert!(err.contains("unknown agent"), "{err}");
+    }
+
+    #[test]
+    fn validate_rejects_duplicate_step_names() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp);
+        write_workflow(
+            &config,
+            "dup",
+            "steps:\n  - name: one\n    agent: researcher\n    prompt: a\n  - name: one\n    agent: researcher\n    prompt: b\n",
+        );
+        assert!(load_workflow(&config, "dup").is_err());
+    }
+
+    #[test]
+    fn validate_rejects_mixed_parallel_and_prompt() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp);
+        write_workflow(
+            &config,
+            "mixed",
+            "steps:\n  - name: one\n    agent: researcher\n    prompt: a\n    parallel:\n      - name: two\n        agent: researcher\n        prompt: b\n",
+        );
+        assert!(load_workflow(&config, "mixed").is_err());
+    }
+
+    #[test]
+    fn validate_rejects_nested_parallel() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp);
+        write_workflow(
+            &config,
+            "nested",
+            "steps:\n  - name: fan\n    parallel:\n      - name: inner\n        parallel:\n          - name: deep\n            agent: researcher\n            prompt: a\n",
+        );
+        assert!(load_workflow(&config, "nested").is_err());
+    }
+
+    #[test]
+    fn render_prompt_substitutes_placeholders() {
+        let mut outputs = BTreeMap::new();
+        outputs.insert("research".to_string(), "FINDINGS".to_string());
+        let rendered = render_prompt(
+            "Input {{input}}; prior {{steps.research}}",
+            "topic",
+            &outputs,
+        );
+        assert_eq!(rendered, "Input topic; prior FINDINGS");
+    }
+
+    #[test]
+    fn when_clause_matches_case_insensitively() {
+        let mut outputs = BTreeMap::new();
+        outputs.insert("triage".to_string(), "Severity: HIGH".to_string());
+        let clause = WhenClause {
+            step: "triage".into(),
+            contains: "high".into(),
+            negate: false,
+        };
+        assert!(clause.holds(&outputs).unwrap());
+
+        let negated = WhenClause {
+            step: "triage".into(),
+            contains: "low".into(),
+            negate: true,
+        };
+        assert!(negated.holds(&outputs).unwrap());
+    }
+
+    #[test]
+    fn when_clause_unknown_step_errors() {
+        let clause = WhenClause {
+            step: "missing".into(),
+            contains: "x".into(),
+            negate: false,
+        };
+        assert!(clause.holds(&BTreeMap::new()).is_err());
+    }
+
+    #[test]
+    fn list_workflows_sorted() {
+        let tmp = TempDir::new().unwrap();
+        let config = test_config(&tmp);
+        write_workflow(&config, "beta", "steps: []\n");
+        write_workflow(&config, "alpha", "steps: []\n");
+        assert_eq!(list_workflows(&config).unwrap(), vec!["alpha", "beta"]);
+    }
+}